@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// A single result of a batch scan, printed as one line of JSON.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchScanResult {
+    pub address: u64,
+    /// The module containing the result, if any, such that the result can be found again after the module is relocated.
+    pub module: Option<String>,
+    pub module_offset: Option<u64>,
+    pub value: String,
+}
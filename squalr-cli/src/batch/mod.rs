@@ -0,0 +1,261 @@
+pub mod batch_scan_result;
+
+use crate::batch::batch_scan_result::BatchScanResult;
+use crate::cli::Cli;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use squalr_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use squalr_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use std::sync::Arc;
+use structopt::StructOpt;
+
+#[derive(Clone, Debug, StructOpt)]
+#[structopt(
+    name = "scan",
+    about = "Attaches to a process, runs a single scan, and prints the results as JSON lines, without an interactive session."
+)]
+pub struct BatchScanArgs {
+    /// The id of the process to scan.
+    #[structopt(short = "p", long, required_unless = "process-name")]
+    pub process_id: Option<u32>,
+
+    /// The name of the process to scan, matched case insensitively.
+    #[structopt(short = "n", long, conflicts_with = "process-id")]
+    pub process_name: Option<String>,
+
+    /// The data type to scan for, ie `i32`, `f32`, or `string_utf8`.
+    #[structopt(short = "d", long, default_value = "i32")]
+    pub data_type: DataTypeRef,
+
+    /// The comparison to scan with: `==`, `!=`, `>`, `>=`, `<`, `<=`, or `..` for an inclusive range written as `lower..upper`.
+    #[structopt(short = "c", long, default_value = "==")]
+    pub compare: ScanCompareType,
+
+    /// The value to compare against, in the default format of the data type.
+    #[structopt(short = "v", long)]
+    pub value: String,
+
+    /// The most results to print. Every result is still counted.
+    #[structopt(short = "l", long, default_value = "100")]
+    pub limit: u64,
+}
+
+/// Runs a scan from the command line in one shot, for scripts. Results are printed to stdout as one JSON object per line, and the
+/// outcome is reported through the exit code, such that scripts can tell a missing process from a scan that found nothing.
+pub struct BatchScan {}
+
+impl BatchScan {
+    pub const EXIT_CODE_SUCCESS: i32 = 0;
+    /// The engine failed to scan, ie the scan could not be started or the request was rejected.
+    pub const EXIT_CODE_ENGINE_ERROR: i32 = 1;
+    pub const EXIT_CODE_PROCESS_NOT_FOUND: i32 = 2;
+    pub const EXIT_CODE_NO_RESULTS: i32 = 3;
+    pub const EXIT_CODE_INVALID_ARGUMENTS: i32 = 4;
+
+    /// Runs the scan, returning the exit code for the process.
+    pub fn run(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        batch_scan_args: &BatchScanArgs,
+    ) -> i32 {
+        let scan_constraint = match Self::create_scan_constraint(batch_scan_args) {
+            Ok(scan_constraint) => scan_constraint,
+            Err(error) => {
+                eprintln!("{}", error);
+                return Self::EXIT_CODE_INVALID_ARGUMENTS;
+            }
+        };
+
+        // The scan only reads memory, so it attaches read only rather than contending with other instances attached to the process.
+        let process_open_request = ProcessOpenRequest {
+            process_id: batch_scan_args.process_id,
+            search_name: batch_scan_args.process_name.clone(),
+            match_case: false,
+            read_only: true,
+            takeover: false,
+        };
+
+        match Self::send_and_wait(engine_unprivileged_state, &process_open_request) {
+            Some(process_open_response) if process_open_response.opened_process_info.is_some() => {}
+            Some(_process_open_response) => {
+                eprintln!("No process matching the given id or name was found.");
+                return Self::EXIT_CODE_PROCESS_NOT_FOUND;
+            }
+            None => return Self::EXIT_CODE_ENGINE_ERROR,
+        }
+
+        if Self::send_and_wait(engine_unprivileged_state, &ScanNewRequest {}).is_none() {
+            return Self::EXIT_CODE_ENGINE_ERROR;
+        }
+
+        let (scan_finished_sender, scan_finished_receiver) = crossbeam_channel::unbounded();
+        let _scan_results_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<ScanResultsUpdatedEvent>(
+            |scan_results_updated_event| !scan_results_updated_event.is_new_scan,
+            move |_scan_results_updated_event| {
+                let _ = scan_finished_sender.send(());
+            },
+        );
+        let element_scan_request = ElementScanRequest {
+            scan_constraints: vec![scan_constraint],
+            data_type_refs: vec![batch_scan_args.data_type.clone()],
+            is_unknown_initial_scan: false,
+        };
+
+        match Self::send_and_wait(engine_unprivileged_state, &element_scan_request) {
+            Some(element_scan_response) if element_scan_response.trackable_task_handle.is_some() => {}
+            _ => {
+                eprintln!("The scan could not be started.");
+                return Self::EXIT_CODE_ENGINE_ERROR;
+            }
+        }
+
+        if scan_finished_receiver.recv().is_err() {
+            return Self::EXIT_CODE_ENGINE_ERROR;
+        }
+
+        Self::print_results(engine_unprivileged_state, batch_scan_args)
+    }
+
+    /// Builds the scan constraint from the compare type and value. Values are checked up front, such that a mistyped value is
+    /// reported as an invalid argument rather than as a failed scan.
+    fn create_scan_constraint(batch_scan_args: &BatchScanArgs) -> Result<AnonymousScanConstraint, String> {
+        if !matches!(batch_scan_args.compare, ScanCompareType::Immediate(_)) {
+            return Err("Batch scans are a single first scan, so only compare types against a value are supported.".to_string());
+        }
+
+        Cli::create_scan_constraint(&batch_scan_args.data_type, batch_scan_args.compare, Some(&batch_scan_args.value))
+    }
+
+    /// Prints up to the result limit as JSON lines, paging through the results of the scan.
+    fn print_results(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        batch_scan_args: &BatchScanArgs,
+    ) -> i32 {
+        let anonymous_value_string_format = SymbolRegistry::get_instance().get_default_anonymous_value_string_format(&batch_scan_args.data_type);
+        let page_size = batch_scan_args.limit.clamp(1, u32::MAX as u64) as u32;
+        let mut printed_result_count = 0;
+        let mut page_index = 0;
+
+        loop {
+            let scan_results_query_request = ScanResultsQueryRequest {
+                page_index,
+                page_size: Some(page_size),
+            };
+            let Some(scan_results_query_response) = Self::send_and_wait(engine_unprivileged_state, &scan_results_query_request) else {
+                return Self::EXIT_CODE_ENGINE_ERROR;
+            };
+
+            if scan_results_query_response.result_count == 0 {
+                eprintln!("The scan found no results.");
+                return Self::EXIT_CODE_NO_RESULTS;
+            }
+
+            for scan_result in &scan_results_query_response.scan_results {
+                if printed_result_count >= batch_scan_args.limit {
+                    break;
+                }
+
+                let batch_scan_result = BatchScanResult {
+                    address: scan_result.get_address(),
+                    module: scan_result
+                        .is_module()
+                        .then(|| scan_result.get_module().to_string()),
+                    module_offset: scan_result.is_module().then(|| scan_result.get_module_offset()),
+                    value: scan_result
+                        .get_display_value_string(anonymous_value_string_format)
+                        .to_string(),
+                };
+
+                match serde_json::to_string(&batch_scan_result) {
+                    Ok(json) => println!("{}", json),
+                    Err(error) => {
+                        eprintln!("Error serializing scan result: {}", error);
+                        return Self::EXIT_CODE_ENGINE_ERROR;
+                    }
+                }
+
+                printed_result_count += 1;
+            }
+
+            if printed_result_count >= batch_scan_args.limit || page_index >= scan_results_query_response.last_page_index {
+                eprintln!("Printed {} of {} result(s).", printed_result_count, scan_results_query_response.result_count);
+
+                return Self::EXIT_CODE_SUCCESS;
+            }
+
+            page_index += 1;
+        }
+    }
+
+    /// Sends a request to the engine, blocking until it responds. Returns `None` if the engine never responds.
+    fn send_and_wait<Request>(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        request: &Request,
+    ) -> Option<Request::ResponseType>
+    where
+        Request: PrivilegedCommandRequest,
+        Request::ResponseType: TypedPrivilegedCommandResponse + Send + 'static,
+    {
+        let (response_sender, response_receiver) = crossbeam_channel::bounded(1);
+
+        request.send(engine_unprivileged_state, move |response| {
+            let _ = response_sender.send(response);
+        });
+
+        match response_receiver.recv() {
+            Ok(response) => Some(response),
+            Err(_error) => {
+                eprintln!("The engine did not respond.");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BatchScan, BatchScanArgs};
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use structopt::StructOpt;
+
+    fn parse_args(args: &[&str]) -> BatchScanArgs {
+        BatchScanArgs::from_iter_safe(std::iter::once("scan").chain(args.iter().copied())).unwrap()
+    }
+
+    #[test]
+    fn scan_arguments_build_constraints_and_require_a_process() {
+        let batch_scan_args = parse_args(&["--process-name", "game.exe", "--value", "100", "--limit", "5"]);
+        let scan_constraint = BatchScan::create_scan_constraint(&batch_scan_args).unwrap();
+
+        assert_eq!(batch_scan_args.data_type.get_data_type_id(), "i32");
+        assert_eq!(
+            scan_constraint.get_scan_compare_type(),
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal)
+        );
+
+        let batch_scan_args = parse_args(&["-p", "1234", "-d", "f32", "-c", "..", "-v", "1.5..2.5"]);
+        let scan_constraint = BatchScan::create_scan_constraint(&batch_scan_args).unwrap();
+
+        assert_eq!(batch_scan_args.process_id, Some(1234));
+        assert_eq!(
+            scan_constraint.get_scan_compare_type(),
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Between)
+        );
+
+        // Ranges need both bounds, and a first scan has no earlier values for a relative comparison.
+        assert!(BatchScan::create_scan_constraint(&parse_args(&["-p", "1", "-c", "..", "-v", "5"])).is_err());
+        assert!(BatchScan::create_scan_constraint(&parse_args(&["-p", "1", "-c", "c", "-v", "5"])).is_err());
+        assert!(BatchScan::create_scan_constraint(&parse_args(&["-p", "1", "-d", "u8", "-v", "256"])).is_err());
+        assert!(BatchScan::create_scan_constraint(&parse_args(&["-p", "1", "-d", "not_a_type", "-v", "5"])).is_err());
+        assert!(BatchScanArgs::from_iter_safe(["scan", "--value", "5"]).is_err());
+        assert!(BatchScanArgs::from_iter_safe(["scan", "-p", "1", "-n", "game.exe", "--value", "5"]).is_err());
+    }
+}
@@ -0,0 +1,326 @@
+pub mod scan_benchmark_report;
+
+use crate::bench::scan_benchmark_report::{ScanBenchmarkReport, ScanBenchmarkResult};
+use squalr_engine::command_executors::scan::element_scan::element_scan_request_executor::create_scan_constraints_by_data_type;
+use squalr_engine_api::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_scanning::benchmarks::synthetic_snapshot::SyntheticSnapshot;
+use squalr_engine_scanning::scanners::element_scan_executor_task::ElementScanExecutorTask;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use structopt::StructOpt;
+
+#[derive(Clone, Debug, StructOpt)]
+#[structopt(name = "bench", about = "Measures scan throughput over a synthetic snapshot, without attaching to a process.")]
+pub struct ScanBenchmarkArgs {
+    /// The size of the synthetic snapshot, in megabytes.
+    #[structopt(long, default_value = "64")]
+    pub size_mb: u64,
+
+    /// The seed used to generate snapshot values. Result counts are only comparable between runs using the same seed and size.
+    #[structopt(long, default_value = "1")]
+    pub seed: u64,
+
+    /// How many times each scan is run. The fastest run is reported, as it is the least disturbed by other work on the machine.
+    #[structopt(long, default_value = "3")]
+    pub iterations: u32,
+
+    /// Writes the report to this file, in addition to printing it.
+    #[structopt(short, long, parse(from_os_str))]
+    pub output: Option<PathBuf>,
+
+    /// Compares the report against a baseline report, exiting with a non-zero code if any scan regressed.
+    #[structopt(long, parse(from_os_str))]
+    pub compare: Option<PathBuf>,
+
+    /// The largest drop in throughput, as a percentage of the baseline, that is not considered a regression. Shared CI
+    /// machines are noisy, so small drops are tolerated by default.
+    #[structopt(long, default_value = "25")]
+    pub max_regression_percent: f64,
+}
+
+/// A scan in the benchmark suite, run through the element scan pipeline exactly as a scan request from a user would be.
+struct ScanBenchmarkCase {
+    name: &'static str,
+    data_type_id: &'static str,
+    scan_constraints: Vec<AnonymousScanConstraint>,
+    memory_alignment: MemoryAlignment,
+}
+
+/// Runs a fixed suite of scans over a synthetic snapshot, reporting the throughput and result count of each as JSON.
+/// A baseline for the default arguments, taken from a release build, is kept in `squalr-cli/bench/scan_baseline.json`.
+pub struct ScanBenchmark {}
+
+impl ScanBenchmark {
+    /// Runs the benchmark, returning the exit code for the process.
+    pub fn run(scan_benchmark_args: &ScanBenchmarkArgs) -> i32 {
+        let scan_benchmark_report = Self::create_report(
+            scan_benchmark_args.size_mb * 1024 * 1024,
+            scan_benchmark_args.seed,
+            scan_benchmark_args.iterations.max(1),
+        );
+        let report_json = match serde_json::to_string_pretty(&scan_benchmark_report) {
+            Ok(report_json) => report_json,
+            Err(error) => {
+                eprintln!("Failed to serialize benchmark report: {}", error);
+                return 1;
+            }
+        };
+
+        println!("{}", report_json);
+
+        let write_result = match &scan_benchmark_args.output {
+            Some(output_path) => {
+                std::fs::write(output_path, &report_json).map_err(|error| format!("Failed to write benchmark report to {}: {}", output_path.display(), error))
+            }
+            None => Ok(()),
+        };
+
+        if let Err(error) = write_result {
+            eprintln!("{}", error);
+            return 1;
+        }
+
+        let Some(baseline_path) = &scan_benchmark_args.compare else {
+            return 0;
+        };
+
+        let baseline_report = match std::fs::read_to_string(baseline_path)
+            .map_err(|error| error.to_string())
+            .and_then(|baseline_json| serde_json::from_str::<ScanBenchmarkReport>(&baseline_json).map_err(|error| error.to_string()))
+        {
+            Ok(baseline_report) => baseline_report,
+            Err(error) => {
+                eprintln!("Failed to read baseline report {}: {}", baseline_path.display(), error);
+                return 1;
+            }
+        };
+        let regressions = scan_benchmark_report.find_regressions(&baseline_report, scan_benchmark_args.max_regression_percent);
+
+        if regressions.is_empty() {
+            eprintln!("No regressions against {}.", baseline_path.display());
+            return 0;
+        }
+
+        for regression in &regressions {
+            eprintln!("Regression: {}", regression);
+        }
+
+        1
+    }
+
+    pub fn create_report(
+        snapshot_size_in_bytes: u64,
+        seed: u64,
+        iterations: u32,
+    ) -> ScanBenchmarkReport {
+        let scans = Self::create_cases()
+            .iter()
+            .map(|scan_benchmark_case| {
+                let mut fastest_elapsed = Duration::MAX;
+                let mut result_count = 0;
+
+                for _ in 0..iterations {
+                    // Every scan is a first scan over a fresh snapshot, such that earlier scans do not narrow down later ones.
+                    let snapshot = SyntheticSnapshot::create(snapshot_size_in_bytes, seed);
+                    let (elapsed, scan_result_count) = Self::run_case(scan_benchmark_case, snapshot);
+
+                    fastest_elapsed = fastest_elapsed.min(elapsed);
+                    result_count = scan_result_count;
+                }
+
+                let elapsed_seconds = fastest_elapsed.as_secs_f64().max(f64::EPSILON);
+
+                ScanBenchmarkResult {
+                    name: scan_benchmark_case.name.to_string(),
+                    throughput_mb_per_second: snapshot_size_in_bytes as f64 / (1024.0 * 1024.0) / elapsed_seconds,
+                    elapsed_milliseconds: elapsed_seconds * 1000.0,
+                    result_count,
+                }
+            })
+            .collect();
+
+        ScanBenchmarkReport {
+            seed,
+            snapshot_size_in_bytes,
+            scans,
+        }
+    }
+
+    fn create_cases() -> Vec<ScanBenchmarkCase> {
+        let immediate = |scan_compare_type_immediate, value: &str, anonymous_value_string_format| {
+            AnonymousScanConstraint::new(
+                ScanCompareType::Immediate(scan_compare_type_immediate),
+                Some(AnonymousValueString::new(value.to_string(), anonymous_value_string_format, ContainerType::None)),
+            )
+        };
+        let marker_bytes = SyntheticSnapshot::MARKER_BYTES
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        vec![
+            ScanBenchmarkCase {
+                name: "equal_i32",
+                data_type_id: DataTypeI32::DATA_TYPE_ID,
+                scan_constraints: vec![immediate(
+                    ScanCompareTypeImmediate::Equal,
+                    "100",
+                    AnonymousValueStringFormat::Decimal,
+                )],
+                memory_alignment: MemoryAlignment::Alignment4,
+            },
+            ScanBenchmarkCase {
+                name: "between_i32",
+                data_type_id: DataTypeI32::DATA_TYPE_ID,
+                scan_constraints: vec![
+                    immediate(ScanCompareTypeImmediate::GreaterThanOrEqual, "100", AnonymousValueStringFormat::Decimal),
+                    immediate(ScanCompareTypeImmediate::LessThanOrEqual, "200", AnonymousValueStringFormat::Decimal),
+                ],
+                memory_alignment: MemoryAlignment::Alignment4,
+            },
+            ScanBenchmarkCase {
+                name: "changed_i32",
+                data_type_id: DataTypeI32::DATA_TYPE_ID,
+                scan_constraints: vec![AnonymousScanConstraint::new(
+                    ScanCompareType::Relative(ScanCompareTypeRelative::Changed),
+                    None,
+                )],
+                memory_alignment: MemoryAlignment::Alignment4,
+            },
+            ScanBenchmarkCase {
+                name: "string_utf8",
+                data_type_id: DataTypeStringUtf8::DATA_TYPE_ID,
+                scan_constraints: vec![immediate(
+                    ScanCompareTypeImmediate::Equal,
+                    SyntheticSnapshot::MARKER_STRING,
+                    AnonymousValueStringFormat::String,
+                )],
+                memory_alignment: MemoryAlignment::Alignment1,
+            },
+            ScanBenchmarkCase {
+                name: "aob",
+                data_type_id: DataTypeAob::DATA_TYPE_ID,
+                scan_constraints: vec![immediate(
+                    ScanCompareTypeImmediate::Equal,
+                    &marker_bytes,
+                    AnonymousValueStringFormat::Hexadecimal,
+                )],
+                memory_alignment: MemoryAlignment::Alignment1,
+            },
+            ScanBenchmarkCase {
+                name: "aob_wildcards",
+                data_type_id: DataTypeAob::DATA_TYPE_ID,
+                scan_constraints: vec![immediate(
+                    ScanCompareTypeImmediate::Equal,
+                    "DE AD ?? EF 13 ?? C0 DE",
+                    AnonymousValueStringFormat::Hexadecimal,
+                )],
+                memory_alignment: MemoryAlignment::Alignment1,
+            },
+        ]
+    }
+
+    /// Runs a single scan over the given snapshot, returning how long the scan took and how many results it found.
+    fn run_case(
+        scan_benchmark_case: &ScanBenchmarkCase,
+        snapshot: Snapshot,
+    ) -> (Duration, u64) {
+        let ScanBenchmarkCase {
+            data_type_id,
+            scan_constraints,
+            memory_alignment,
+            ..
+        } = scan_benchmark_case;
+
+        // Pin every setting that affects the scan, such that the user's scan settings do not skew the measurement.
+        // Values are never read, as they are already held by the synthetic snapshot.
+        let floating_point_tolerance = FloatingPointTolerance::default();
+        let element_scan_plan = ElementScanPlan::new(
+            create_scan_constraints_by_data_type(scan_constraints, &[DataTypeRef::new(data_type_id)], floating_point_tolerance),
+            *memory_alignment,
+            floating_point_tolerance,
+            MemoryReadMode::Skip,
+            false,
+            false,
+        );
+        let process_info = OpenedProcessInfo::new(0, "bench".to_string(), 0, Bitness::Bit64, None);
+        let snapshot = Arc::new(RwLock::new(snapshot));
+        let start_time = Instant::now();
+
+        ElementScanExecutorTask::start_task(process_info, snapshot.clone(), element_scan_plan, false).wait_for_completion();
+
+        let elapsed = start_time.elapsed();
+        let result_count = snapshot
+            .read()
+            .map(|snapshot| snapshot.get_number_of_results())
+            .unwrap_or(0);
+
+        (elapsed, result_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanBenchmark;
+    use squalr_engine_scanning::benchmarks::synthetic_snapshot::SyntheticSnapshot;
+
+    #[test]
+    fn benchmark_finds_the_same_results_for_the_same_seed_and_flags_regressions() {
+        let snapshot_size_in_bytes = 3 * 1024 * 1024;
+        let report = ScanBenchmark::create_report(snapshot_size_in_bytes, 7, 1);
+        let repeated_report = ScanBenchmark::create_report(snapshot_size_in_bytes, 7, 1);
+        let result_counts = |report: &super::ScanBenchmarkReport| {
+            report
+                .scans
+                .iter()
+                .map(|scan| scan.result_count)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(result_counts(&report), result_counts(&repeated_report));
+
+        // The marker string and marker bytes are each planted once every 64 KB, identically in both samples.
+        let marker_count = snapshot_size_in_bytes / (64 * 1024);
+        let result_count = |name: &str| {
+            report
+                .scans
+                .iter()
+                .find(|scan| scan.name == name)
+                .unwrap()
+                .result_count
+        };
+
+        assert_eq!(result_count("string_utf8"), marker_count);
+        assert_eq!(result_count("aob"), marker_count);
+        assert_eq!(result_count("aob_wildcards"), marker_count);
+        assert!(result_count("changed_i32") > 0);
+        assert!(result_count("between_i32") > result_count("equal_i32"));
+        assert_eq!(SyntheticSnapshot::create(snapshot_size_in_bytes, 7).get_byte_count(), snapshot_size_in_bytes);
+
+        let mut slower_report = report.clone();
+        slower_report.scans[0].throughput_mb_per_second = report.scans[0].throughput_mb_per_second * 0.5;
+        slower_report.scans[1].result_count += 1;
+
+        assert!(report.find_regressions(&report, 10.0).is_empty());
+        assert_eq!(slower_report.find_regressions(&report, 10.0).len(), 2);
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a single scan in the benchmark suite.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScanBenchmarkResult {
+    pub name: String,
+    pub throughput_mb_per_second: f64,
+    pub elapsed_milliseconds: f64,
+    pub result_count: u64,
+}
+
+/// The outcome of a full benchmark run, serialized as JSON such that runs can be tracked over time and compared against a baseline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScanBenchmarkReport {
+    pub seed: u64,
+    pub snapshot_size_in_bytes: u64,
+    pub scans: Vec<ScanBenchmarkResult>,
+}
+
+impl ScanBenchmarkReport {
+    /// Compares this run against a baseline, returning a description of every regression found. Throughput regresses when it
+    /// drops by more than the given percentage. Result counts are only compared when both runs scanned the same snapshot.
+    pub fn find_regressions(
+        &self,
+        baseline: &ScanBenchmarkReport,
+        max_throughput_drop_percent: f64,
+    ) -> Vec<String> {
+        let is_same_snapshot = self.seed == baseline.seed && self.snapshot_size_in_bytes == baseline.snapshot_size_in_bytes;
+        let mut regressions = Vec::new();
+
+        for baseline_scan in &baseline.scans {
+            let Some(scan) = self.scans.iter().find(|scan| scan.name == baseline_scan.name) else {
+                regressions.push(format!("{}: missing from this run.", baseline_scan.name));
+                continue;
+            };
+
+            let minimum_throughput = baseline_scan.throughput_mb_per_second * (1.0 - max_throughput_drop_percent / 100.0);
+
+            if scan.throughput_mb_per_second < minimum_throughput {
+                regressions.push(format!(
+                    "{}: throughput dropped from {:.1} MB/s to {:.1} MB/s ({:.1}%).",
+                    scan.name,
+                    baseline_scan.throughput_mb_per_second,
+                    scan.throughput_mb_per_second,
+                    (1.0 - scan.throughput_mb_per_second / baseline_scan.throughput_mb_per_second) * 100.0
+                ));
+            }
+
+            if is_same_snapshot && scan.result_count != baseline_scan.result_count {
+                regressions.push(format!(
+                    "{}: found {} results, but the baseline found {}.",
+                    scan.name, scan.result_count, baseline_scan.result_count
+                ));
+            }
+        }
+
+        regressions
+    }
+}
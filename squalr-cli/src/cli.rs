@@ -1,6 +1,13 @@
-use crate::response_handlers::handle_engine_response;
+use crate::repl::output_mode::OutputMode;
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
 use std::io;
 use std::io::Write;
 use std::sync::Arc;
@@ -10,6 +17,9 @@ pub struct Cli {}
 
 /// Implements a command line listener polls for text input commands to control the engine.
 impl Cli {
+    /// Appended to any command to print its response serialized as JSON, rather than as text.
+    pub const JSON_FLAG: &'static str = "--json";
+
     pub fn run_loop(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) {
         let stdin = io::stdin();
         let mut stdout = io::stdout();
@@ -46,40 +56,98 @@ impl Cli {
         log::error!("Exiting cli.");
     }
 
-    fn handle_input(
-        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
-        input: &str,
-    ) -> bool {
-        if input.eq_ignore_ascii_case("exit") || input.eq_ignore_ascii_case("close") || input.eq_ignore_ascii_case("quit") {
-            return false;
-        }
+    /// Determines whether the given input is a request to leave the CLI.
+    pub fn is_exit_command(input: &str) -> bool {
+        input.eq_ignore_ascii_case("exit") || input.eq_ignore_ascii_case("close") || input.eq_ignore_ascii_case("quit")
+    }
 
+    /// Parses a line of user input into an engine command, logging any parse errors. The `--json` flag may be given to any
+    /// command, in which case the output mode it requests is returned alongside the command.
+    pub fn parse_command(input: &str) -> Option<(PrivilegedCommand, Option<OutputMode>)> {
         let mut cli_command = match shlex::split(input) {
             Some(cli_command) => cli_command,
             None => {
                 log::error!("Error parsing input");
-                return true;
+                return None;
             }
         };
 
+        let cli_command_length = cli_command.len();
+
+        cli_command.retain(|argument| argument != Self::JSON_FLAG);
+
+        let output_mode = (cli_command.len() != cli_command_length).then_some(OutputMode::Json);
+
         if cli_command.is_empty() {
-            return true;
+            return None;
         }
 
         // Little bit of a hack, but our command system seems to require the first command to be typed twice so just insert it.
         // We could structopt(flatten) our commands to avoid this, but then this creates even stranger command conflict issues.
         cli_command.insert(0, cli_command[0].clone());
 
-        let engine_command = match PrivilegedCommand::from_iter_safe(&cli_command) {
-            Ok(engine_command) => engine_command,
+        match PrivilegedCommand::from_iter_safe(&cli_command) {
+            Ok(engine_command) => Some((engine_command, output_mode)),
             Err(error) => {
                 log::error!("Error parsing engine command: {}", error);
-                return true;
+                None
+            }
+        }
+    }
+
+    /// Builds a scan constraint from a compare type and a value typed in the default format of the data type, splitting the value
+    /// into bounds for range scans. Relative scans compare against earlier values, and so must not be given a value.
+    pub fn create_scan_constraint(
+        data_type_ref: &DataTypeRef,
+        scan_compare_type: ScanCompareType,
+        value: Option<&str>,
+    ) -> Result<AnonymousScanConstraint, String> {
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        if !symbol_registry.is_valid(data_type_ref) {
+            return Err(format!("Unknown data type '{}'.", data_type_ref.get_data_type_id()));
+        }
+
+        let anonymous_value_string_format = symbol_registry.get_default_anonymous_value_string_format(data_type_ref);
+        let create_value = |value: &str| {
+            let anonymous_value_string = AnonymousValueString::new(value.trim().to_string(), anonymous_value_string_format, ContainerType::None);
+
+            match symbol_registry.deanonymize_value_string(data_type_ref, &anonymous_value_string) {
+                Ok(_data_value) => Ok(anonymous_value_string),
+                Err(_error) => Err(format!("'{}' is not a valid {} value.", value.trim(), data_type_ref.get_data_type_id())),
+            }
+        };
+
+        match (scan_compare_type, value) {
+            (ScanCompareType::Immediate(ScanCompareTypeImmediate::Between), Some(value)) => match value.split_once("..") {
+                Some((lower_bound, upper_bound)) => Ok(AnonymousScanConstraint::new_between(create_value(lower_bound)?, create_value(upper_bound)?)),
+                None => Err("Range scans require a value written as `lower..upper`.".to_string()),
+            },
+            (ScanCompareType::Relative(_), None) => Ok(AnonymousScanConstraint::new(scan_compare_type, None)),
+            (ScanCompareType::Relative(_), Some(_value)) => Err("Relative scans compare against the previous values, and take no value.".to_string()),
+            (ScanCompareType::Immediate(_) | ScanCompareType::Delta(_), Some(value)) => {
+                Ok(AnonymousScanConstraint::new(scan_compare_type, Some(create_value(value)?)))
             }
+            (ScanCompareType::Immediate(_) | ScanCompareType::Delta(_), None) => Err("A value to compare against is required.".to_string()),
+        }
+    }
+
+    fn handle_input(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        input: &str,
+    ) -> bool {
+        if Self::is_exit_command(input) {
+            return false;
+        }
+
+        let (engine_command, output_mode) = match Self::parse_command(input) {
+            Some(parsed_command) => parsed_command,
+            None => return true,
         };
+        let output_mode = output_mode.unwrap_or(OutputMode::Text);
 
-        engine_unprivileged_state.dispatch_command(engine_command, |engine_command| {
-            handle_engine_response(engine_command);
+        engine_unprivileged_state.dispatch_command(engine_command, move |engine_command| {
+            output_mode.write_response(engine_command);
         });
 
         true
@@ -1,12 +1,45 @@
+mod batch;
+mod bench;
 mod cli;
+mod repl;
 mod response_handlers;
 
+use batch::{BatchScan, BatchScanArgs};
+use bench::{ScanBenchmark, ScanBenchmarkArgs};
 use cli::Cli;
+use repl::Repl;
 use squalr_engine::engine_mode::EngineMode;
 use squalr_engine::squalr_engine::SqualrEngine;
+use std::io::IsTerminal;
+use structopt::StructOpt;
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
+
+    // Benchmarks scan a synthetic snapshot, and so need neither the engine nor an opened process.
+    if args.get(1).map(String::as_str) == Some("bench") {
+        std::process::exit(ScanBenchmark::run(&ScanBenchmarkArgs::from_iter(args.iter().skip(1))));
+    }
+
+    // Batch scans print results to stdout for scripts, so arguments are checked before the engine starts, and the log goes to stderr.
+    let batch_scan_args = if args.get(1).map(String::as_str) == Some("scan") {
+        match BatchScanArgs::from_iter_safe(args.iter().skip(1)) {
+            Ok(batch_scan_args) => Some(batch_scan_args),
+            Err(error) if error.use_stderr() => {
+                eprintln!("{}", error.message);
+                std::process::exit(BatchScan::EXIT_CODE_INVALID_ARGUMENTS);
+            }
+            Err(error) => error.exit(),
+        }
+    } else {
+        None
+    };
+
+    if batch_scan_args.is_some() {
+        // Safety: no other threads have started yet, so nothing can be reading the environment concurrently.
+        unsafe { std::env::set_var("SQUALR_LOG_TARGET", "stderr") };
+    }
+
     let engine_mode = if args.contains(&"--ipc-mode".to_string()) {
         EngineMode::PrivilegedShell
     } else {
@@ -26,10 +59,25 @@ fn main() {
     if engine_mode == EngineMode::Standalone {
         let engine_unprivileged_state = squalr_engine.get_engine_unprivileged_state().as_ref().unwrap();
 
+        if let Some(batch_scan_args) = &batch_scan_args {
+            let exit_code = BatchScan::run(engine_unprivileged_state, batch_scan_args);
+
+            // Exiting skips destructors, so the engine is dropped first to restore any memory it left trapped in the process.
+            drop(squalr_engine);
+            std::process::exit(exit_code);
+        }
+
+        // Use the interactive REPL when explicitly requested, or when a user is at the terminal. Piped input keeps the plain line reader.
+        let is_repl_requested = args.iter().skip(1).any(|arg| arg == "repl") || (args.len() <= 1 && std::io::stdin().is_terminal());
+
         // Listen for user input.
         // Note that the "Cli", when listening for input, is considered unprivileged, as it is considered the "UI".
         // Internally, these commands then get dispatched to an abstracted away privileged component.
-        Cli::run_loop(engine_unprivileged_state);
+        if is_repl_requested {
+            Repl::new(engine_unprivileged_state).run();
+        } else {
+            Cli::run_loop(engine_unprivileged_state);
+        }
     } else if engine_mode == EngineMode::PrivilegedShell {
         log::info!("CLI running as a privileged IPC shell.");
 
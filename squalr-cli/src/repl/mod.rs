@@ -0,0 +1,285 @@
+pub mod output_mode;
+pub mod repl_completer;
+pub mod repl_shorthand;
+
+use crate::cli::Cli;
+use crate::repl::output_mode::OutputMode;
+use crate::repl::repl_completer::ReplCompleter;
+use crate::repl::repl_shorthand::ReplShorthand;
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use rustyline::config::Config;
+use rustyline::error::ReadlineError;
+use rustyline::history::DefaultHistory;
+use rustyline::{CompletionType, Editor};
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+use squalr_engine_api::commands::trackable_tasks::cancel::trackable_tasks_cancel_request::TrackableTasksCancelRequest;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::events::pointer_scan_results::updated::pointer_scan_results_updated_event::PointerScanResultsUpdatedEvent;
+use squalr_engine_api::events::scan_benchmark::finished::scan_benchmark_finished_event::ScanBenchmarkFinishedEvent;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// An interactive prompt that keeps a single engine session alive across commands, with line editing, history, and tab completion.
+/// Lines are parsed exactly as they are in the line-based CLI loop, except for the shorthand commands of `ReplShorthand`.
+pub struct Repl {
+    engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    output_mode: OutputMode,
+    data_type_ref: DataTypeRef,
+    interrupt_requested: Arc<AtomicBool>,
+    task_finished_receiver: Receiver<()>,
+}
+
+impl Repl {
+    const PROMPT: &'static str = "squalr> ";
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    pub fn new(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) -> Self {
+        let interrupt_requested = Arc::new(AtomicBool::new(false));
+        let interrupt_requested_clone = interrupt_requested.clone();
+
+        // Ctrl+C while at the prompt is handled by the line editor. Ctrl+C while a command is running cancels that command instead of exiting.
+        if let Err(error) = ctrlc::set_handler(move || interrupt_requested_clone.store(true, Ordering::SeqCst)) {
+            log::error!("Failed to register Ctrl+C handler: {}", error);
+        }
+
+        let (task_finished_sender, task_finished_receiver) = crossbeam_channel::unbounded();
+
+        Self::listen_for_task_completion(engine_unprivileged_state, task_finished_sender);
+
+        Self {
+            engine_unprivileged_state: engine_unprivileged_state.clone(),
+            output_mode: OutputMode::Text,
+            data_type_ref: DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            interrupt_requested,
+            task_finished_receiver,
+        }
+    }
+
+    pub fn run(&mut self) {
+        let config = Config::builder()
+            .completion_type(CompletionType::List)
+            .auto_add_history(true)
+            .build();
+        let mut editor: Editor<ReplCompleter, DefaultHistory> = match Editor::with_config(config) {
+            Ok(editor) => editor,
+            Err(error) => {
+                log::error!("Failed to initialize line editor: {}", error);
+                return;
+            }
+        };
+        let history_path = Self::get_history_path();
+
+        editor.set_helper(Some(ReplCompleter::new()));
+
+        // A missing history file is expected on first run.
+        let _ = editor.load_history(&history_path);
+
+        loop {
+            match editor.readline(Self::PROMPT) {
+                Ok(line) => {
+                    if !self.handle_line(line.trim()) {
+                        break;
+                    }
+                }
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(error) => {
+                    log::error!("Error reading input: {}", error);
+                    break;
+                }
+            }
+        }
+
+        if let Err(error) = editor.save_history(&history_path) {
+            log::warn!("Failed to save CLI history: {}", error);
+        }
+    }
+
+    /// Handles a single line of input. Returns false if the REPL should exit.
+    fn handle_line(
+        &mut self,
+        line: &str,
+    ) -> bool {
+        if line.is_empty() {
+            return true;
+        }
+
+        if Cli::is_exit_command(line) {
+            return false;
+        }
+
+        let mut words = line.split_whitespace();
+
+        if words.next() == Some(ReplCompleter::OUTPUT_COMMAND) {
+            match words.next() {
+                Some(output_mode) => match output_mode.parse::<OutputMode>() {
+                    Ok(output_mode) => self.output_mode = output_mode,
+                    Err(error) => log::error!("{}", error),
+                },
+                None => println!("Output mode: {:?}", self.output_mode),
+            }
+
+            return true;
+        }
+
+        if let Some(repl_shorthand) = ReplShorthand::parse(line, &self.data_type_ref) {
+            match repl_shorthand {
+                Ok(repl_shorthand) => {
+                    if let Some(data_type_ref) = repl_shorthand.get_data_type_ref() {
+                        self.data_type_ref = data_type_ref.clone();
+                    }
+
+                    for engine_command in repl_shorthand.to_engine_commands() {
+                        self.execute(engine_command, self.output_mode);
+                    }
+                }
+                Err(error) => log::error!("{}", error),
+            }
+
+            return true;
+        }
+
+        if let Some((engine_command, output_mode)) = Cli::parse_command(line) {
+            self.execute(engine_command, output_mode.unwrap_or(self.output_mode));
+        }
+
+        true
+    }
+
+    /// Dispatches a command and blocks until it, and any task it started, completes or is canceled with Ctrl+C.
+    fn execute(
+        &self,
+        engine_command: PrivilegedCommand,
+        output_mode: OutputMode,
+    ) {
+        let (response_sender, response_receiver) = crossbeam_channel::bounded(1);
+
+        self.interrupt_requested.store(false, Ordering::SeqCst);
+
+        // Discard completion notices from tasks that finished while nothing was waiting on them.
+        while self.task_finished_receiver.try_recv().is_ok() {}
+
+        self.engine_unprivileged_state
+            .dispatch_command(engine_command, move |engine_response| {
+                let _ = response_sender.send(engine_response);
+            });
+
+        let engine_response = loop {
+            match response_receiver.recv_timeout(Self::POLL_INTERVAL) {
+                Ok(engine_response) => break engine_response,
+                Err(RecvTimeoutError::Timeout) => {
+                    if self.interrupt_requested.swap(false, Ordering::SeqCst) {
+                        log::warn!("This command cannot be canceled. Waiting for it to finish...");
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        };
+
+        let trackable_task_handle = Self::get_trackable_task_handle(&engine_response);
+
+        output_mode.write_response(engine_response);
+
+        if let Some(trackable_task_handle) = trackable_task_handle {
+            self.wait_for_task(trackable_task_handle);
+        }
+    }
+
+    fn wait_for_task(
+        &self,
+        trackable_task_handle: TrackableTaskHandle,
+    ) {
+        loop {
+            match self.task_finished_receiver.recv_timeout(Self::POLL_INTERVAL) {
+                Ok(()) | Err(RecvTimeoutError::Disconnected) => return,
+                Err(RecvTimeoutError::Timeout) => {
+                    if self.interrupt_requested.swap(false, Ordering::SeqCst) {
+                        let task_identifier = trackable_task_handle.task_identifier.clone();
+                        let trackable_tasks_cancel_request = TrackableTasksCancelRequest {
+                            task_id: trackable_task_handle.task_identifier,
+                        };
+
+                        trackable_tasks_cancel_request.send(&self.engine_unprivileged_state, |_trackable_tasks_cancel_response| {});
+                        log::info!("Canceled task {} ({}).", trackable_task_handle.name, task_identifier);
+
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Gets the task started by a command, if any. Only scans currently run as background tasks.
+    fn get_trackable_task_handle(engine_response: &PrivilegedCommandResponse) -> Option<TrackableTaskHandle> {
+        match engine_response {
+            PrivilegedCommandResponse::Scan(scan_response) => match scan_response {
+                ScanResponse::CollectValues { scan_value_collector_response } => scan_value_collector_response.trackable_task_handle.clone(),
+                ScanResponse::ElementScan { element_scan_response } => element_scan_response.trackable_task_handle.clone(),
+                ScanResponse::PointerScan { pointer_scan_response } => pointer_scan_response.trackable_task_handle.clone(),
+                ScanResponse::PointerScanRescan { pointer_scan_rescan_response } => pointer_scan_rescan_response.trackable_task_handle.clone(),
+                ScanResponse::StructScan { struct_scan_response } => struct_scan_response.trackable_task_handle.clone(),
+                ScanResponse::StrideScan { stride_scan_response } => stride_scan_response.trackable_task_handle.clone(),
+                ScanResponse::Benchmark { scan_benchmark_response } => scan_benchmark_response.trackable_task_handle.clone(),
+                ScanResponse::SnapshotCompare { scan_snapshot_compare_response } => scan_snapshot_compare_response.trackable_task_handle.clone(),
+                ScanResponse::Reset { .. }
+                | ScanResponse::New { .. }
+                | ScanResponse::Revalidate { .. }
+                | ScanResponse::HistoryList { .. }
+                | ScanResponse::HistoryRevert { .. }
+                | ScanResponse::SnapshotSave { .. }
+                | ScanResponse::ElementScanPreview { .. } => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Background scans signal completion by publishing updated results.
+    fn listen_for_task_completion(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        task_finished_sender: Sender<()>,
+    ) {
+        let pointer_scan_task_finished_sender = task_finished_sender.clone();
+        let scan_benchmark_task_finished_sender = task_finished_sender.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<ScanResultsUpdatedEvent>(move |scan_results_updated_event| {
+            if !scan_results_updated_event.is_new_scan {
+                let _ = task_finished_sender.send(());
+            }
+        });
+
+        engine_unprivileged_state.listen_for_engine_event::<PointerScanResultsUpdatedEvent>(move |_pointer_scan_results_updated_event| {
+            let _ = pointer_scan_task_finished_sender.send(());
+        });
+
+        // Benchmarks do not change the results, so they instead signal completion with the speed of each configuration.
+        engine_unprivileged_state.listen_for_engine_event::<ScanBenchmarkFinishedEvent>(move |scan_benchmark_finished_event| {
+            for scan_configuration_benchmark in &scan_benchmark_finished_event.scan_configuration_benchmarks {
+                log::info!(
+                    "{} thread(s), {:?} vectors: {:.0} elements/s",
+                    scan_configuration_benchmark.scan_thread_count,
+                    scan_configuration_benchmark.vector_width,
+                    scan_configuration_benchmark.elements_per_second
+                );
+            }
+
+            let _ = scan_benchmark_task_finished_sender.send(());
+        });
+    }
+
+    fn get_history_path() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_default()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("cli_history.txt")
+    }
+}
@@ -0,0 +1,39 @@
+use crate::response_handlers::handle_engine_response;
+use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
+use std::str::FromStr;
+
+/// Controls how engine responses are presented in the REPL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Human readable output produced by the response handlers.
+    Text,
+    /// One serialized response per line, for piping into other tools.
+    Json,
+}
+
+impl OutputMode {
+    pub fn write_response(
+        &self,
+        response: PrivilegedCommandResponse,
+    ) {
+        match self {
+            OutputMode::Text => handle_engine_response(response),
+            OutputMode::Json => match serde_json::to_string(&response) {
+                Ok(json) => println!("{}", json),
+                Err(error) => log::error!("Error serializing engine response: {}", error),
+            },
+        }
+    }
+}
+
+impl FromStr for OutputMode {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "text" => Ok(OutputMode::Text),
+            "json" => Ok(OutputMode::Json),
+            _ => Err(format!("Unknown output mode '{}'. Expected 'text' or 'json'.", value)),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::cli::Cli;
+use crate::repl::repl_shorthand::ReplShorthand;
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use structopt::StructOpt;
+use structopt::clap::App;
+
+/// A command (or subcommand) known to the REPL, along with the names that can be typed to select it.
+struct ReplCommandNode {
+    names: Vec<String>,
+    flags: Vec<String>,
+    children: Vec<ReplCommandNode>,
+}
+
+impl ReplCommandNode {
+    fn from_app(app: &App) -> Self {
+        let mut names = vec![app.get_name().to_string()];
+
+        if let Some(aliases) = &app.p.meta.aliases {
+            names.extend(aliases.iter().map(|(alias, _is_visible)| alias.to_string()));
+        }
+
+        let long_flags = app
+            .p
+            .flags
+            .iter()
+            .filter_map(|flag| flag.s.long)
+            .chain(app.p.opts.iter().filter_map(|opt| opt.s.long))
+            .map(|long| format!("--{}", long));
+        let short_flags = app
+            .p
+            .flags
+            .iter()
+            .filter_map(|flag| flag.s.short)
+            .chain(app.p.opts.iter().filter_map(|opt| opt.s.short))
+            .map(|short| format!("-{}", short));
+        let mut flags: Vec<String> = long_flags.chain(short_flags).collect();
+
+        if !flags.iter().any(|flag| flag == "--help") {
+            flags.push("--help".to_string());
+        }
+
+        flags.push(Cli::JSON_FLAG.to_string());
+
+        let children = app
+            .p
+            .subcommands
+            .iter()
+            .map(|subcommand| ReplCommandNode::from_app(subcommand))
+            .collect();
+
+        Self { names, flags, children }
+    }
+
+    fn find_child(
+        &self,
+        name: &str,
+    ) -> Option<&ReplCommandNode> {
+        self.children
+            .iter()
+            .find(|child| child.names.iter().any(|child_name| child_name == name))
+    }
+}
+
+/// Provides tab completion of command names and flag names for the REPL, derived from the same command definitions used for parsing.
+pub struct ReplCompleter {
+    root: ReplCommandNode,
+    builtin_commands: Vec<String>,
+}
+
+impl ReplCompleter {
+    pub const OUTPUT_COMMAND: &'static str = "output";
+    const OUTPUT_MODES: [&'static str; 2] = ["json", "text"];
+
+    pub fn new() -> Self {
+        Self {
+            root: ReplCommandNode::from_app(&PrivilegedCommand::clap()),
+            builtin_commands: [Self::OUTPUT_COMMAND, "exit", "quit"]
+                .into_iter()
+                .chain(ReplShorthand::COMMAND_NAMES)
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+
+    /// Gets the start offset of the word being completed, and all candidates for that word.
+    fn get_candidates(
+        &self,
+        line: &str,
+    ) -> (usize, Vec<String>) {
+        let partial_word_start = line
+            .rfind(char::is_whitespace)
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let partial_word = &line[partial_word_start..];
+        let completed_words: Vec<&str> = line[..partial_word_start].split_whitespace().collect();
+
+        if completed_words.first() == Some(&Self::OUTPUT_COMMAND) {
+            let candidates = if completed_words.len() == 1 {
+                Self::OUTPUT_MODES
+                    .iter()
+                    .filter(|mode| mode.starts_with(partial_word))
+                    .map(|mode| mode.to_string())
+                    .collect()
+            } else {
+                vec![]
+            };
+
+            return (partial_word_start, candidates);
+        }
+
+        let mut node = &self.root;
+
+        for word in &completed_words {
+            if word.starts_with('-') {
+                continue;
+            }
+
+            // Words that do not match a subcommand are flag values, and do not change the current command.
+            if let Some(child) = node.find_child(word) {
+                node = child;
+            }
+        }
+
+        let mut candidates: Vec<String> = if partial_word.starts_with('-') {
+            node.flags
+                .iter()
+                .filter(|flag| flag.starts_with(partial_word))
+                .cloned()
+                .collect()
+        } else {
+            node.children
+                .iter()
+                .map(|child| &child.names[0])
+                .filter(|name| name.starts_with(partial_word))
+                .cloned()
+                .collect()
+        };
+
+        if completed_words.is_empty() {
+            candidates.extend(
+                self.builtin_commands
+                    .iter()
+                    .filter(|name| name.starts_with(partial_word))
+                    .cloned(),
+            );
+        }
+
+        candidates.sort();
+        candidates.dedup();
+
+        (partial_word_start, candidates)
+    }
+}
+
+impl Completer for ReplCompleter {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _context: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, candidates) = self.get_candidates(&line[..pos]);
+        let pairs = candidates
+            .into_iter()
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: format!("{} ", candidate),
+            })
+            .collect();
+
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for ReplCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for ReplCompleter {}
+
+impl Validator for ReplCompleter {}
+
+impl Helper for ReplCompleter {}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplCompleter;
+
+    #[test]
+    fn completes_commands_subcommands_and_flags() {
+        let completer = ReplCompleter::new();
+
+        let (start, candidates) = completer.get_candidates("pro");
+        assert_eq!(start, 0);
+        assert_eq!(candidates, vec!["process".to_string()]);
+
+        // Aliases resolve to the same command when walking into subcommands.
+        let (start, candidates) = completer.get_candidates("proc op");
+        assert_eq!(start, 5);
+        assert_eq!(candidates, vec!["open".to_string()]);
+
+        let (_start, candidates) = completer.get_candidates("process open --");
+        assert!(candidates.contains(&"--help".to_string()));
+        assert!(candidates.iter().all(|candidate| candidate.starts_with("--")));
+
+        // Short flags are offered once only a single dash has been typed.
+        let (_start, candidates) = completer.get_candidates("settings memory set -");
+        assert!(candidates.contains(&"-m".to_string()));
+        assert!(candidates.contains(&"--memory-type-mapped".to_string()));
+
+        let (start, candidates) = completer.get_candidates("output j");
+        assert_eq!(start, 7);
+        assert_eq!(candidates, vec!["json".to_string()]);
+    }
+}
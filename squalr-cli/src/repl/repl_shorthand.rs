@@ -0,0 +1,363 @@
+use crate::cli::Cli;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use squalr_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use squalr_engine_api::commands::scan::new::scan_new_request::ScanNewRequest;
+use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use squalr_engine_api::commands::scan_results::list::scan_results_list_request::ScanResultsListRequest;
+use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use structopt::StructOpt;
+
+/// A short command for the common flow of attaching, scanning, and acting on results, which expands to one or more engine
+/// commands. Scans remember their data type, such that later scans and writes in the session use it by default.
+#[derive(Debug)]
+pub enum ReplShorthand {
+    /// `open <pid|name>`
+    Open { process_open_request: ProcessOpenRequest },
+    /// `scan [compare] <value> [data_type]`, starting over from a new snapshot.
+    Scan {
+        data_type_ref: DataTypeRef,
+        scan_constraint: AnonymousScanConstraint,
+    },
+    /// `next [compare] [value]`, narrowing down the current results.
+    Next {
+        data_type_ref: DataTypeRef,
+        scan_constraint: AnonymousScanConstraint,
+    },
+    /// `results [page]`
+    Results { page_index: u64 },
+    /// `write <address> <value> [data_type]`
+    Write { memory_write_request: MemoryWriteRequest },
+    /// `freeze <index>` and `unfreeze <index>`
+    Freeze { scan_results_freeze_request: ScanResultsFreezeRequest },
+}
+
+impl ReplShorthand {
+    pub const COMMAND_NAMES: [&'static str; 7] = ["open", "scan", "next", "results", "write", "freeze", "unfreeze"];
+
+    /// Parses a line as a shorthand command, returning `None` if the line is not one. Lines naming an engine command and one of
+    /// its subcommands or flags, ie `scan element-scan ...` or `results --help`, are left to the engine command parser.
+    pub fn parse(
+        line: &str,
+        data_type_ref: &DataTypeRef,
+    ) -> Option<Result<Self, String>> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let (command_name, arguments) = words.split_first()?;
+
+        if !Self::COMMAND_NAMES.contains(command_name) || Self::is_engine_command(command_name, arguments.first().copied()) {
+            return None;
+        }
+
+        Some(match *command_name {
+            "open" => Self::parse_open(arguments),
+            "scan" => Self::parse_scan(arguments, data_type_ref, true),
+            "next" => Self::parse_scan(arguments, data_type_ref, false),
+            "results" => Self::parse_results(arguments),
+            "write" => Self::parse_write(arguments, data_type_ref),
+            _ => Self::parse_freeze(arguments, *command_name == "freeze"),
+        })
+    }
+
+    /// Gets the data type that later scans and writes should default to after this command, if it changes it.
+    pub fn get_data_type_ref(&self) -> Option<&DataTypeRef> {
+        match self {
+            ReplShorthand::Scan { data_type_ref, .. } => Some(data_type_ref),
+            _ => None,
+        }
+    }
+
+    pub fn to_engine_commands(&self) -> Vec<PrivilegedCommand> {
+        match self {
+            ReplShorthand::Open { process_open_request } => vec![process_open_request.to_engine_command()],
+            ReplShorthand::Scan {
+                data_type_ref,
+                scan_constraint,
+            } => vec![
+                ScanNewRequest {}.to_engine_command(),
+                Self::create_element_scan_request(data_type_ref, scan_constraint).to_engine_command(),
+            ],
+            ReplShorthand::Next {
+                data_type_ref,
+                scan_constraint,
+            } => vec![Self::create_element_scan_request(data_type_ref, scan_constraint).to_engine_command()],
+            ReplShorthand::Results { page_index } => vec![ScanResultsListRequest { page_index: *page_index }.to_engine_command()],
+            ReplShorthand::Write { memory_write_request } => vec![memory_write_request.to_engine_command()],
+            ReplShorthand::Freeze { scan_results_freeze_request } => vec![scan_results_freeze_request.to_engine_command()],
+        }
+    }
+
+    /// Determines whether the words name an engine command, rather than a shorthand of the same name.
+    fn is_engine_command(
+        command_name: &str,
+        first_argument: Option<&str>,
+    ) -> bool {
+        let Some(first_argument) = first_argument else {
+            return false;
+        };
+
+        if first_argument.starts_with("--") {
+            return true;
+        }
+
+        PrivilegedCommand::clap()
+            .p
+            .subcommands
+            .iter()
+            .filter(|command| Self::is_named(command, command_name))
+            .any(|command| {
+                command
+                    .p
+                    .subcommands
+                    .iter()
+                    .any(|subcommand| Self::is_named(subcommand, first_argument))
+            })
+    }
+
+    fn is_named(
+        command: &structopt::clap::App,
+        name: &str,
+    ) -> bool {
+        command.get_name() == name
+            || command
+                .p
+                .meta
+                .aliases
+                .as_ref()
+                .is_some_and(|aliases| aliases.iter().any(|(alias, _is_visible)| *alias == name))
+    }
+
+    fn parse_open(arguments: &[&str]) -> Result<Self, String> {
+        let process = match arguments {
+            [] => return Err("Usage: open <pid|name>".to_string()),
+            _ => arguments.join(" "),
+        };
+        let process_id = process.parse::<u32>().ok();
+
+        Ok(ReplShorthand::Open {
+            process_open_request: ProcessOpenRequest {
+                process_id,
+                search_name: process_id.is_none().then_some(process),
+                match_case: false,
+                read_only: false,
+                takeover: false,
+            },
+        })
+    }
+
+    /// Parses `[compare] [value] [data_type]`, where the compare type defaults to equality. Only new scans may change the data type.
+    fn parse_scan(
+        arguments: &[&str],
+        data_type_ref: &DataTypeRef,
+        is_new_scan: bool,
+    ) -> Result<Self, String> {
+        let (scan_compare_type, arguments) = match arguments.split_first() {
+            Some((compare, remaining_arguments)) => match compare.parse::<ScanCompareType>() {
+                Ok(scan_compare_type) => (scan_compare_type, remaining_arguments),
+                Err(_error) => (ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal), arguments),
+            },
+            None if is_new_scan => return Err("Usage: scan [compare] <value> [data_type]".to_string()),
+            None => return Err("Usage: next [compare] [value]".to_string()),
+        };
+        let data_type_ref = match (is_new_scan, arguments) {
+            (_, [] | [_]) => data_type_ref.clone(),
+            (true, [_value, data_type]) => DataTypeRef::new(data_type),
+            (true, _) => return Err("Usage: scan [compare] <value> [data_type]".to_string()),
+            (false, _) => return Err("Usage: next [compare] [value]".to_string()),
+        };
+
+        if is_new_scan && matches!(scan_compare_type, ScanCompareType::Relative(_) | ScanCompareType::Delta(_)) {
+            return Err("A new scan has no previous values to compare against. Use `next` to compare against them.".to_string());
+        }
+
+        let scan_constraint = Cli::create_scan_constraint(&data_type_ref, scan_compare_type, arguments.first().copied())?;
+
+        Ok(if is_new_scan {
+            ReplShorthand::Scan {
+                data_type_ref,
+                scan_constraint,
+            }
+        } else {
+            ReplShorthand::Next {
+                data_type_ref,
+                scan_constraint,
+            }
+        })
+    }
+
+    fn parse_results(arguments: &[&str]) -> Result<Self, String> {
+        let page_index = match arguments {
+            [] => 0,
+            [page_index] => page_index
+                .parse::<u64>()
+                .map_err(|_error| format!("'{}' is not a page number.", page_index))?,
+            _ => return Err("Usage: results [page]".to_string()),
+        };
+
+        Ok(ReplShorthand::Results { page_index })
+    }
+
+    /// Parses `<address> <value> [data_type]`. Addresses are hexadecimal, with or without a `0x` prefix.
+    fn parse_write(
+        arguments: &[&str],
+        data_type_ref: &DataTypeRef,
+    ) -> Result<Self, String> {
+        let (address, value, data_type_ref) = match arguments {
+            [address, value] => (address, value, data_type_ref.clone()),
+            [address, value, data_type] => (address, value, DataTypeRef::new(data_type)),
+            _ => return Err("Usage: write <address> <value> [data_type]".to_string()),
+        };
+        let address = Conversions::parse_hex_address(address).map_err(|_error| format!("'{}' is not a hexadecimal address.", address))?;
+        let symbol_registry = SymbolRegistry::get_instance();
+        let anonymous_value_string = AnonymousValueString::new(
+            value.to_string(),
+            symbol_registry.get_default_anonymous_value_string_format(&data_type_ref),
+            ContainerType::None,
+        );
+        let data_value = symbol_registry
+            .deanonymize_value_string(&data_type_ref, &anonymous_value_string)
+            .map_err(|_error| format!("'{}' is not a valid {} value.", value, data_type_ref.get_data_type_id()))?;
+
+        Ok(ReplShorthand::Write {
+            memory_write_request: MemoryWriteRequest {
+                address,
+                module_name: String::new(),
+                value: data_value.get_value_bytes().to_vec(),
+            },
+        })
+    }
+
+    fn parse_freeze(
+        arguments: &[&str],
+        is_frozen: bool,
+    ) -> Result<Self, String> {
+        let scan_result_refs = match arguments {
+            [] => return Err(format!("Usage: {} <index>...", if is_frozen { "freeze" } else { "unfreeze" })),
+            _ => arguments
+                .iter()
+                .map(|index| {
+                    index
+                        .parse::<u64>()
+                        .map(ScanResultRef::new)
+                        .map_err(|_error| format!("'{}' is not a result index.", index))
+                })
+                .collect::<Result<Vec<ScanResultRef>, String>>()?,
+        };
+
+        Ok(ReplShorthand::Freeze {
+            scan_results_freeze_request: ScanResultsFreezeRequest {
+                scan_result_refs,
+                is_frozen,
+                allow_stale: false,
+            },
+        })
+    }
+
+    fn create_element_scan_request(
+        data_type_ref: &DataTypeRef,
+        scan_constraint: &AnonymousScanConstraint,
+    ) -> ElementScanRequest {
+        ElementScanRequest {
+            scan_constraints: vec![scan_constraint.clone()],
+            data_type_refs: vec![data_type_ref.clone()],
+            is_unknown_initial_scan: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReplShorthand;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
+
+    fn parse(line: &str) -> Option<Result<ReplShorthand, String>> {
+        ReplShorthand::parse(line, &DataTypeRef::new("i32"))
+    }
+
+    #[test]
+    fn shorthands_expand_to_engine_requests() {
+        match parse("open 1234") {
+            Some(Ok(ReplShorthand::Open { process_open_request })) => {
+                assert_eq!(process_open_request.process_id, Some(1234));
+                assert_eq!(process_open_request.search_name, None);
+            }
+            other => panic!("Unexpected parse: {:?}", other),
+        }
+
+        match parse("open Game Name.exe") {
+            Some(Ok(ReplShorthand::Open { process_open_request })) => assert_eq!(process_open_request.search_name.as_deref(), Some("Game Name.exe")),
+            other => panic!("Unexpected parse: {:?}", other),
+        }
+
+        match parse("scan > 100 u16") {
+            Some(Ok(ReplShorthand::Scan {
+                data_type_ref,
+                scan_constraint,
+            })) => {
+                assert_eq!(data_type_ref.get_data_type_id(), "u16");
+                assert_eq!(
+                    scan_constraint.get_scan_compare_type(),
+                    ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThan)
+                );
+            }
+            other => panic!("Unexpected parse: {:?}", other),
+        }
+
+        match parse("next c") {
+            Some(Ok(ReplShorthand::Next {
+                data_type_ref,
+                scan_constraint,
+            })) => {
+                assert_eq!(data_type_ref.get_data_type_id(), "i32");
+                assert_eq!(
+                    scan_constraint.get_scan_compare_type(),
+                    ScanCompareType::Relative(ScanCompareTypeRelative::Changed)
+                );
+            }
+            other => panic!("Unexpected parse: {:?}", other),
+        }
+
+        match parse("write 0x7FF6A000 100") {
+            Some(Ok(ReplShorthand::Write { memory_write_request })) => {
+                assert_eq!(memory_write_request.address, 0x7FF6_A000);
+                assert_eq!(memory_write_request.value, 100i32.to_le_bytes().to_vec());
+            }
+            other => panic!("Unexpected parse: {:?}", other),
+        }
+
+        assert!(matches!(parse("results"), Some(Ok(ReplShorthand::Results { page_index: 0 }))));
+        assert!(matches!(parse("results 3"), Some(Ok(ReplShorthand::Results { page_index: 3 }))));
+        assert!(matches!(
+            parse("unfreeze 4"),
+            Some(Ok(ReplShorthand::Freeze { scan_results_freeze_request })) if !scan_results_freeze_request.is_frozen
+        ));
+    }
+
+    #[test]
+    fn engine_commands_and_mistakes_are_not_shorthands() {
+        // Engine commands sharing a name with a shorthand are still parsed as engine commands.
+        assert!(parse("scan element-scan -d i32").is_none());
+        assert!(parse("results list -p 0").is_none());
+        assert!(parse("results --help").is_none());
+        assert!(parse("process open -n game").is_none());
+
+        assert!(matches!(parse("scan"), Some(Err(_))));
+        assert!(matches!(parse("scan c"), Some(Err(_))));
+        assert!(matches!(parse("scan 300 u8"), Some(Err(_))));
+        assert!(matches!(parse("next c 5"), Some(Err(_))));
+        assert!(matches!(parse("write zz 5"), Some(Err(_))));
+        assert!(matches!(parse("freeze first"), Some(Err(_))));
+    }
+}
@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::address::resolve::address_resolve_response::AddressResolveResponse;
+
+pub fn handle_address_resolve_response(address_resolve_response: AddressResolveResponse) {
+    match address_resolve_response.resolved_address {
+        Ok(resolved_address) => log::info!("{}", resolved_address),
+        Err(error) => log::error!("Unable to resolve address: {}", error),
+    }
+}
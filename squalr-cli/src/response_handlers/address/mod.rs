@@ -0,0 +1,10 @@
+pub mod handler_address_resolve_response;
+
+use crate::response_handlers::address::handler_address_resolve_response::handle_address_resolve_response;
+use squalr_engine_api::commands::address::address_response::AddressResponse;
+
+pub fn handle_address_response(response: AddressResponse) {
+    match response {
+        AddressResponse::Resolve { address_resolve_response } => handle_address_resolve_response(address_resolve_response),
+    }
+}
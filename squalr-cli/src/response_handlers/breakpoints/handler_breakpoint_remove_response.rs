@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::breakpoints::remove::breakpoint_remove_response::BreakpointRemoveResponse;
+
+pub fn handle_breakpoint_remove_response(breakpoint_remove_response: BreakpointRemoveResponse) {
+    match breakpoint_remove_response.error_message {
+        Some(error_message) => log::error!("Failed to remove breakpoint: {}", error_message),
+        None => log::info!("{} breakpoint(s) remain set.", breakpoint_remove_response.breakpoint_addresses.len()),
+    }
+}
@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::breakpoints::set::breakpoint_set_response::BreakpointSetResponse;
+
+pub fn handle_breakpoint_set_response(breakpoint_set_response: BreakpointSetResponse) {
+    match breakpoint_set_response.error_message {
+        Some(error_message) => log::error!("Failed to set breakpoint: {}", error_message),
+        None => log::info!("{} breakpoint(s) set.", breakpoint_set_response.breakpoint_addresses.len()),
+    }
+}
@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::breakpoints::list::breakpoints_list_response::BreakpointsListResponse;
+
+pub fn handle_breakpoints_list_response(breakpoints_list_response: BreakpointsListResponse) {
+    if breakpoints_list_response.breakpoint_addresses.is_empty() {
+        log::info!("No breakpoints are set.");
+    }
+
+    for breakpoint_address in breakpoints_list_response.breakpoint_addresses {
+        log::info!("0x{:X}", breakpoint_address);
+    }
+}
@@ -0,0 +1,16 @@
+pub mod handler_breakpoint_remove_response;
+pub mod handler_breakpoint_set_response;
+pub mod handler_breakpoints_list_response;
+
+use crate::response_handlers::breakpoints::handler_breakpoint_remove_response::handle_breakpoint_remove_response;
+use crate::response_handlers::breakpoints::handler_breakpoint_set_response::handle_breakpoint_set_response;
+use crate::response_handlers::breakpoints::handler_breakpoints_list_response::handle_breakpoints_list_response;
+use squalr_engine_api::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+
+pub fn handle_breakpoints_response(response: BreakpointsResponse) {
+    match response {
+        BreakpointsResponse::Set { breakpoint_set_response } => handle_breakpoint_set_response(breakpoint_set_response),
+        BreakpointsResponse::Remove { breakpoint_remove_response } => handle_breakpoint_remove_response(breakpoint_remove_response),
+        BreakpointsResponse::List { breakpoints_list_response } => handle_breakpoints_list_response(breakpoints_list_response),
+    }
+}
@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::code::assemble::code_assemble_response::CodeAssembleResponse;
+
+pub fn handle_code_assemble_response(code_assemble_response: CodeAssembleResponse) {
+    match code_assemble_response.error_message {
+        Some(error_message) => log::error!("Failed to assemble: {}", error_message),
+        None => log::info!("{:02X?}", code_assemble_response.bytes),
+    }
+}
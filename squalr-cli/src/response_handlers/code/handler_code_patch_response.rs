@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::code::patch::code_patch_response::CodePatchResponse;
+
+pub fn handle_code_patch_response(code_patch_response: CodePatchResponse) {
+    match code_patch_response.error_message {
+        Some(error_message) => log::error!("Failed to patch code: {}", error_message),
+        None => log::info!("{} code patch(es) applied.", code_patch_response.code_patches.len()),
+    }
+}
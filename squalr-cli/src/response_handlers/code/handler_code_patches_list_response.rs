@@ -0,0 +1,16 @@
+use squalr_engine_api::commands::code::list::code_patches_list_response::CodePatchesListResponse;
+
+pub fn handle_code_patches_list_response(code_patches_list_response: CodePatchesListResponse) {
+    if code_patches_list_response.code_patches.is_empty() {
+        log::info!("No code patches are applied.");
+    }
+
+    for code_patch in code_patches_list_response.code_patches {
+        log::info!(
+            "0x{:X}: {:02X?} (was {:02X?})",
+            code_patch.address,
+            code_patch.patched_bytes,
+            code_patch.original_bytes
+        );
+    }
+}
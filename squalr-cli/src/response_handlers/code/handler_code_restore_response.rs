@@ -0,0 +1,8 @@
+use squalr_engine_api::commands::code::restore::code_restore_response::CodeRestoreResponse;
+
+pub fn handle_code_restore_response(code_restore_response: CodeRestoreResponse) {
+    match code_restore_response.error_message {
+        Some(error_message) => log::error!("Failed to restore code: {}", error_message),
+        None => log::info!("{} code patch(es) remain applied.", code_restore_response.code_patches.len()),
+    }
+}
@@ -0,0 +1,19 @@
+pub mod handler_code_assemble_response;
+pub mod handler_code_patch_response;
+pub mod handler_code_patches_list_response;
+pub mod handler_code_restore_response;
+
+use crate::response_handlers::code::handler_code_assemble_response::handle_code_assemble_response;
+use crate::response_handlers::code::handler_code_patch_response::handle_code_patch_response;
+use crate::response_handlers::code::handler_code_patches_list_response::handle_code_patches_list_response;
+use crate::response_handlers::code::handler_code_restore_response::handle_code_restore_response;
+use squalr_engine_api::commands::code::code_response::CodeResponse;
+
+pub fn handle_code_response(response: CodeResponse) {
+    match response {
+        CodeResponse::Assemble { code_assemble_response } => handle_code_assemble_response(code_assemble_response),
+        CodeResponse::Patch { code_patch_response } => handle_code_patch_response(code_patch_response),
+        CodeResponse::Restore { code_restore_response } => handle_code_restore_response(code_restore_response),
+        CodeResponse::List { code_patches_list_response } => handle_code_patches_list_response(code_patches_list_response),
+    }
+}
@@ -0,0 +1,13 @@
+use squalr_engine_api::commands::data_types::register::data_types_register_response::DataTypesRegisterResponse;
+
+pub fn handle_data_types_register_response(data_types_register_response: DataTypesRegisterResponse) {
+    for error_message in &data_types_register_response.error_messages {
+        log::error!("Failed to register custom data type: {}", error_message);
+    }
+
+    log::info!(
+        "{} custom data type(s) registered. {} registered in total.",
+        data_types_register_response.registered_count,
+        data_types_register_response.definitions.len()
+    );
+}
@@ -0,0 +1,12 @@
+use squalr_engine_api::commands::data_types::unregister::data_types_unregister_response::DataTypesUnregisterResponse;
+
+pub fn handle_data_types_unregister_response(data_types_unregister_response: DataTypesUnregisterResponse) {
+    if data_types_unregister_response.was_unregistered {
+        log::info!(
+            "Custom data type unregistered. {} registered in total.",
+            data_types_unregister_response.definitions.len()
+        );
+    } else {
+        log::warn!("No custom data type is registered with that id.");
+    }
+}
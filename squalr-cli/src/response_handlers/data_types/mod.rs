@@ -0,0 +1,15 @@
+pub mod handler_data_types_register_response;
+pub mod handler_data_types_unregister_response;
+
+use crate::response_handlers::data_types::handler_data_types_register_response::handle_data_types_register_response;
+use crate::response_handlers::data_types::handler_data_types_unregister_response::handle_data_types_unregister_response;
+use squalr_engine_api::commands::data_types::data_types_response::DataTypesResponse;
+
+pub fn handle_data_types_response(response: DataTypesResponse) {
+    match response {
+        DataTypesResponse::Register { data_types_register_response } => handle_data_types_register_response(data_types_register_response),
+        DataTypesResponse::Unregister {
+            data_types_unregister_response,
+        } => handle_data_types_unregister_response(data_types_unregister_response),
+    }
+}
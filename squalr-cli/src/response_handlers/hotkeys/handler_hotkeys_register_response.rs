@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::hotkeys::register::hotkeys_register_response::HotkeysRegisterResponse;
+
+pub fn handle_hotkeys_register_response(hotkeys_register_response: HotkeysRegisterResponse) {
+    if hotkeys_register_response.is_supported {
+        log::info!("{} hotkey(s) registered.", hotkeys_register_response.registered_chord_count);
+    } else {
+        log::warn!("Global hotkeys are not supported on this platform.");
+    }
+}
@@ -0,0 +1,10 @@
+pub mod handler_hotkeys_register_response;
+
+use crate::response_handlers::hotkeys::handler_hotkeys_register_response::handle_hotkeys_register_response;
+use squalr_engine_api::commands::hotkeys::hotkeys_response::HotkeysResponse;
+
+pub fn handle_hotkeys_response(response: HotkeysResponse) {
+    match response {
+        HotkeysResponse::Register { hotkeys_register_response } => handle_hotkeys_register_response(hotkeys_register_response),
+    }
+}
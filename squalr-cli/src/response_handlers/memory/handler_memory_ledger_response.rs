@@ -0,0 +1,13 @@
+use squalr_engine_api::commands::memory::ledger::memory_ledger_response::MemoryLedgerResponse;
+
+pub fn handle_memory_ledger_response(memory_ledger_response: MemoryLedgerResponse) {
+    log::info!(
+        "{} modified address(es), {} original byte(s) recorded.",
+        memory_ledger_response.address_count,
+        memory_ledger_response.byte_count
+    );
+
+    if memory_ledger_response.is_capacity_exceeded {
+        log::warn!("The modification ledger is full. Some writes cannot be restored.");
+    }
+}
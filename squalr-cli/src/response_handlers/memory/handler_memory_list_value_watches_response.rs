@@ -0,0 +1,23 @@
+use squalr_engine_api::commands::memory::list_value_watches::memory_list_value_watches_response::MemoryListValueWatchesResponse;
+
+pub fn handle_memory_list_value_watches_response(memory_list_value_watches_response: MemoryListValueWatchesResponse) {
+    if memory_list_value_watches_response.value_watches.is_empty() {
+        log::info!("No values are being watched.");
+    }
+
+    for value_watch in memory_list_value_watches_response.value_watches {
+        log::info!(
+            "[{}] {} 0x{:X}{} ({}), notify on {}",
+            value_watch.watch_id,
+            value_watch.label,
+            value_watch.address,
+            if value_watch.module_name.is_empty() {
+                String::new()
+            } else {
+                format!(" in {}", value_watch.module_name)
+            },
+            value_watch.data_type_ref,
+            value_watch.condition.get_description()
+        );
+    }
+}
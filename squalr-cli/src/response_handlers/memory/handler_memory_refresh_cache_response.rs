@@ -0,0 +1,5 @@
+use squalr_engine_api::commands::memory::refresh_cache::memory_refresh_cache_response::MemoryRefreshCacheResponse;
+
+pub fn handle_memory_refresh_cache_response(memory_refresh_cache_response: MemoryRefreshCacheResponse) {
+    log::info!("Process query cache: {}", memory_refresh_cache_response.cache_stats);
+}
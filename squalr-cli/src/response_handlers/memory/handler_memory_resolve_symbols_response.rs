@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::memory::resolve_symbols::memory_resolve_symbols_response::MemoryResolveSymbolsResponse;
+
+pub fn handle_memory_resolve_symbols_response(memory_resolve_symbols_response: MemoryResolveSymbolsResponse) {
+    for (address_index, symbol) in memory_resolve_symbols_response.symbols.iter().enumerate() {
+        match symbol {
+            Some(symbol) => log::info!("{}: {}", address_index + 1, symbol),
+            // Symbols load in the background, so repeating the command shortly after usually resolves these.
+            None => log::info!("{}: (unresolved, or symbols still loading)", address_index + 1),
+        }
+    }
+}
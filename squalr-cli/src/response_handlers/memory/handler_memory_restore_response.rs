@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::memory::restore::memory_restore_response::MemoryRestoreResponse;
+
+pub fn handle_memory_restore_response(memory_restore_response: MemoryRestoreResponse) {
+    for restore_result in &memory_restore_response.restore_results {
+        if restore_result.success {
+            log::info!("Restored {} byte(s) at 0x{:X}.", restore_result.byte_count, restore_result.address);
+        } else {
+            log::error!("Failed to restore {} byte(s) at 0x{:X}.", restore_result.byte_count, restore_result.address);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use squalr_engine_api::commands::memory::search::memory_search_response::MemorySearchResponse;
+
+pub fn handle_memory_search_response(memory_search_response: MemorySearchResponse) {
+    for search_match in &memory_search_response.matches {
+        if search_match.region.module_name.is_empty() {
+            log::info!("{:016X} (region {:016X})", search_match.address, search_match.region.base_address);
+        } else {
+            log::info!(
+                "{:016X} ({}+{:X})",
+                search_match.address,
+                search_match.region.module_name,
+                search_match
+                    .address
+                    .saturating_sub(search_match.region.base_address)
+                    .saturating_add(search_match.region.module_offset)
+            );
+        }
+    }
+
+    log::info!("{} match(es) found.", memory_search_response.matches.len());
+
+    if memory_search_response.is_truncated {
+        log::warn!("The search stopped at the maximum number of results. Narrow the pattern to see the rest.");
+    }
+}
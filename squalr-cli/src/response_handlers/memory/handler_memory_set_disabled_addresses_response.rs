@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_response::MemorySetDisabledAddressesResponse;
+
+pub fn handle_memory_set_disabled_addresses_response(memory_set_disabled_addresses_response: MemorySetDisabledAddressesResponse) {
+    if memory_set_disabled_addresses_response.success {
+        log::info!("Updated disabled addresses.");
+    } else {
+        log::error!("Failed to update disabled addresses.");
+    }
+}
@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::unwatch::memory_unwatch_response::MemoryUnwatchResponse;
+
+pub fn handle_memory_unwatch_response(memory_unwatch_response: MemoryUnwatchResponse) {
+    if memory_unwatch_response.success {
+        log::info!("Stopped memory watch.");
+    } else {
+        log::error!("No running memory watch with that id.");
+    }
+}
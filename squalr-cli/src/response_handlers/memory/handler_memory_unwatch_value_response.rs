@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::unwatch_value::memory_unwatch_value_response::MemoryUnwatchValueResponse;
+
+pub fn handle_memory_unwatch_value_response(memory_unwatch_value_response: MemoryUnwatchValueResponse) {
+    if memory_unwatch_value_response.success {
+        log::info!("Stopped value watch.");
+    } else {
+        log::error!("No value watch with that id.");
+    }
+}
@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::watch::memory_watch_response::MemoryWatchResponse;
+
+pub fn handle_memory_watch_response(memory_watch_response: MemoryWatchResponse) {
+    match (memory_watch_response.watch_id, memory_watch_response.error_message) {
+        (Some(watch_id), _) => log::info!("Started memory watch {}. Stop it with: memory unwatch -w {}", watch_id, watch_id),
+        (None, Some(error_message)) => log::error!("Failed to start memory watch: {}", error_message),
+        (None, None) => log::error!("Failed to start memory watch."),
+    }
+}
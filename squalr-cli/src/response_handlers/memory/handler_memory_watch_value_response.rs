@@ -0,0 +1,9 @@
+use squalr_engine_api::commands::memory::watch_value::memory_watch_value_response::MemoryWatchValueResponse;
+
+pub fn handle_memory_watch_value_response(memory_watch_value_response: MemoryWatchValueResponse) {
+    match (memory_watch_value_response.watch_id, memory_watch_value_response.error_message) {
+        (Some(watch_id), _) => log::info!("Started value watch {}. Stop it with: memory unwatch-value -w {}", watch_id, watch_id),
+        (None, Some(error_message)) => log::error!("Failed to start value watch: {}", error_message),
+        (None, None) => log::error!("Failed to start value watch."),
+    }
+}
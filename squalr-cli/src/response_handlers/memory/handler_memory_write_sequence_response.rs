@@ -0,0 +1,23 @@
+use squalr_engine_api::commands::memory::write_sequence::memory_write_sequence_response::MemoryWriteSequenceResponse;
+
+pub fn handle_memory_write_sequence_response(memory_write_sequence_response: MemoryWriteSequenceResponse) {
+    match memory_write_sequence_response.trackable_task_handle {
+        Some(trackable_task_handle) => log::info!(
+            "Started write sequence. Cancel it with: trackable-tasks cancel -t {}",
+            trackable_task_handle.task_identifier
+        ),
+        None if !memory_write_sequence_response
+            .unresolved_step_indices
+            .is_empty() =>
+        {
+            let unresolved_steps: Vec<String> = memory_write_sequence_response
+                .unresolved_step_indices
+                .iter()
+                .map(|step_index| (step_index + 1).to_string())
+                .collect();
+
+            log::error!("Write sequence was not started. Could not resolve steps: {}", unresolved_steps.join(", "));
+        }
+        None => log::error!("Failed to start write sequence."),
+    }
+}
@@ -1,8 +1,32 @@
+pub mod handler_memory_ledger_response;
+pub mod handler_memory_list_value_watches_response;
 pub mod handler_memory_read_response;
+pub mod handler_memory_refresh_cache_response;
+pub mod handler_memory_resolve_symbols_response;
+pub mod handler_memory_restore_response;
+pub mod handler_memory_search_response;
+pub mod handler_memory_set_disabled_addresses_response;
+pub mod handler_memory_unwatch_response;
+pub mod handler_memory_unwatch_value_response;
+pub mod handler_memory_watch_response;
+pub mod handler_memory_watch_value_response;
 pub mod handler_memory_write_response;
+pub mod handler_memory_write_sequence_response;
 
+use crate::response_handlers::memory::handler_memory_ledger_response::handle_memory_ledger_response;
+use crate::response_handlers::memory::handler_memory_list_value_watches_response::handle_memory_list_value_watches_response;
 use crate::response_handlers::memory::handler_memory_read_response::handle_memory_read_response;
+use crate::response_handlers::memory::handler_memory_refresh_cache_response::handle_memory_refresh_cache_response;
+use crate::response_handlers::memory::handler_memory_resolve_symbols_response::handle_memory_resolve_symbols_response;
+use crate::response_handlers::memory::handler_memory_restore_response::handle_memory_restore_response;
+use crate::response_handlers::memory::handler_memory_search_response::handle_memory_search_response;
+use crate::response_handlers::memory::handler_memory_set_disabled_addresses_response::handle_memory_set_disabled_addresses_response;
+use crate::response_handlers::memory::handler_memory_unwatch_response::handle_memory_unwatch_response;
+use crate::response_handlers::memory::handler_memory_unwatch_value_response::handle_memory_unwatch_value_response;
+use crate::response_handlers::memory::handler_memory_watch_response::handle_memory_watch_response;
+use crate::response_handlers::memory::handler_memory_watch_value_response::handle_memory_watch_value_response;
 use crate::response_handlers::memory::handler_memory_write_response::handle_memory_response_write;
+use crate::response_handlers::memory::handler_memory_write_sequence_response::handle_memory_write_sequence_response;
 use squalr_engine_api::commands::memory::memory_response::MemoryResponse;
 
 pub fn handle_memory_response(cmd: MemoryResponse) {
@@ -12,5 +36,25 @@ pub fn handle_memory_response(cmd: MemoryResponse) {
         MemoryResponse::Regions { memory_regions_response } => {
             log::info!("{:?}", memory_regions_response);
         }
+        MemoryResponse::Ledger { memory_ledger_response } => handle_memory_ledger_response(memory_ledger_response),
+        MemoryResponse::Restore { memory_restore_response } => handle_memory_restore_response(memory_restore_response),
+        MemoryResponse::Search { memory_search_response } => handle_memory_search_response(memory_search_response),
+        MemoryResponse::RefreshCache { memory_refresh_cache_response } => handle_memory_refresh_cache_response(memory_refresh_cache_response),
+        MemoryResponse::Watch { memory_watch_response } => handle_memory_watch_response(memory_watch_response),
+        MemoryResponse::Unwatch { memory_unwatch_response } => handle_memory_unwatch_response(memory_unwatch_response),
+        MemoryResponse::WriteSequence {
+            memory_write_sequence_response,
+        } => handle_memory_write_sequence_response(memory_write_sequence_response),
+        MemoryResponse::ResolveSymbols {
+            memory_resolve_symbols_response,
+        } => handle_memory_resolve_symbols_response(memory_resolve_symbols_response),
+        MemoryResponse::WatchValue { memory_watch_value_response } => handle_memory_watch_value_response(memory_watch_value_response),
+        MemoryResponse::UnwatchValue { memory_unwatch_value_response } => handle_memory_unwatch_value_response(memory_unwatch_value_response),
+        MemoryResponse::ListValueWatches {
+            memory_list_value_watches_response,
+        } => handle_memory_list_value_watches_response(memory_list_value_watches_response),
+        MemoryResponse::SetDisabledAddresses {
+            memory_set_disabled_addresses_response,
+        } => handle_memory_set_disabled_addresses_response(memory_set_disabled_addresses_response),
     }
 }
@@ -1,3 +1,8 @@
+mod address;
+mod breakpoints;
+mod code;
+mod data_types;
+mod hotkeys;
 mod memory;
 mod process;
 mod project;
@@ -5,6 +10,11 @@ mod scan;
 mod scan_results;
 mod settings;
 
+use crate::response_handlers::address::handle_address_response;
+use crate::response_handlers::breakpoints::handle_breakpoints_response;
+use crate::response_handlers::code::handle_code_response;
+use crate::response_handlers::data_types::handle_data_types_response;
+use crate::response_handlers::hotkeys::handle_hotkeys_response;
 use crate::response_handlers::memory::handle_memory_response;
 use crate::response_handlers::process::handle_process_response;
 use crate::response_handlers::project::handle_project_response;
@@ -25,5 +35,10 @@ pub fn handle_engine_response(response: PrivilegedCommandResponse) {
         PrivilegedCommandResponse::ProjectItems(_response) => {}
         PrivilegedCommandResponse::Settings(response) => handle_settings_response(response),
         PrivilegedCommandResponse::TrackableTasks(_response) => {}
+        PrivilegedCommandResponse::Address(response) => handle_address_response(response),
+        PrivilegedCommandResponse::Breakpoints(response) => handle_breakpoints_response(response),
+        PrivilegedCommandResponse::Code(response) => handle_code_response(response),
+        PrivilegedCommandResponse::Hotkeys(response) => handle_hotkeys_response(response),
+        PrivilegedCommandResponse::DataTypes(response) => handle_data_types_response(response),
     }
 }
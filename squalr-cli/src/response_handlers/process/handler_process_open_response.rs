@@ -10,6 +10,11 @@ pub fn handle_process_open_response(process_response: ProcessResponse) {
                 process_info.get_process_id_raw(),
                 process_info.get_name()
             );
+        } else if let Some(attach_conflict) = process_open_response.attach_conflict {
+            log::warn!(
+                "{}. Open it again with --read-only to inspect it, or with --takeover to take it over.",
+                attach_conflict
+            );
         } else {
             log::error!("Failed to open process");
         }
@@ -0,0 +1,24 @@
+use squalr_engine_api::commands::process::process_response::ProcessResponse;
+
+pub fn handle_process_thumbnail_response(process_response: ProcessResponse) {
+    if let ProcessResponse::Thumbnail { process_thumbnail_response } = process_response {
+        let process_id = process_thumbnail_response.process_id;
+
+        match process_thumbnail_response.thumbnail.get_image() {
+            Some(image) => log::info!(
+                "Captured window thumbnail for process_id: {}, size: {}x{}",
+                process_id,
+                image.get_width(),
+                image.get_height()
+            ),
+            None => log::info!(
+                "No window thumbnail for process_id: {} ({})",
+                process_id,
+                process_thumbnail_response
+                    .thumbnail
+                    .get_unavailable_reason()
+                    .unwrap_or_default()
+            ),
+        }
+    }
+}
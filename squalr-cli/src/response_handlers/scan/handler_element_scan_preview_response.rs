@@ -0,0 +1,14 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_element_scan_preview_response(scan_response: ScanResponse) {
+    if let ScanResponse::ElementScanPreview { element_scan_preview_response } = scan_response {
+        match element_scan_preview_response.preview_result_count {
+            Some(preview_result_count) => log::info!(
+                "Scan would keep {} of {} results.",
+                preview_result_count,
+                element_scan_preview_response.current_result_count
+            ),
+            None => log::info!("Preview unavailable for {} results.", element_scan_preview_response.current_result_count),
+        }
+    }
+}
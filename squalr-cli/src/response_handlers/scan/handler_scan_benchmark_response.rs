@@ -0,0 +1,13 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_benchmark_response(scan_response: ScanResponse) {
+    if let ScanResponse::Benchmark { scan_benchmark_response } = scan_response {
+        match scan_benchmark_response.trackable_task_handle {
+            Some(trackable_task_handle) => log::info!(
+                "Started scan benchmark. Cancel it with: trackable-tasks cancel -t {}",
+                trackable_task_handle.task_identifier
+            ),
+            None => log::error!("Failed to start scan benchmark."),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_history_list_response(scan_response: ScanResponse) {
+    if let ScanResponse::HistoryList { scan_history_list_response } = scan_response {
+        if scan_history_list_response.scan_history_items.is_empty() {
+            log::info!("No scans have been performed yet.");
+            return;
+        }
+
+        let scan_history = scan_history_list_response
+            .scan_history_items
+            .iter()
+            .map(|scan_history_item| format!("Scan {}: {} results", scan_history_item.get_scan_number(), scan_history_item.get_result_count()))
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        log::info!("{}", scan_history);
+    }
+}
@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_history_revert_response(scan_response: ScanResponse) {
+    if let ScanResponse::HistoryRevert { scan_history_revert_response } = scan_response {
+        if scan_history_revert_response.success {
+            log::info!("Reverted scan, {} result(s) restored.", scan_history_revert_response.result_count);
+        } else {
+            log::error!("Failed to revert scan. Use `scan history-list` to see which scans can be reverted to.");
+        }
+    }
+}
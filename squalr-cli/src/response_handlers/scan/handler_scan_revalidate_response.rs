@@ -0,0 +1,11 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_revalidate_response(scan_response: ScanResponse) {
+    if let ScanResponse::Revalidate { scan_revalidate_response } = scan_response {
+        log::info!(
+            "Revalidated {} stale range(s), discarding {} result(s).",
+            scan_revalidate_response.revalidated_range_count,
+            scan_revalidate_response.discarded_result_count
+        );
+    }
+}
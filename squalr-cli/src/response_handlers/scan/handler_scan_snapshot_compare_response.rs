@@ -0,0 +1,13 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_snapshot_compare_response(scan_response: ScanResponse) {
+    if let ScanResponse::SnapshotCompare {
+        scan_snapshot_compare_response,
+    } = scan_response
+    {
+        match scan_snapshot_compare_response.compared_result_count {
+            Ok(compared_result_count) => log::info!("Comparing {} saved scan result(s) against their current values.", compared_result_count),
+            Err(error) => log::error!("Failed to compare against scan snapshot: {}", error),
+        }
+    }
+}
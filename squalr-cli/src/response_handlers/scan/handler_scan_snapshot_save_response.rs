@@ -0,0 +1,10 @@
+use squalr_engine_api::commands::scan::scan_response::ScanResponse;
+
+pub fn handle_scan_snapshot_save_response(scan_response: ScanResponse) {
+    if let ScanResponse::SnapshotSave { scan_snapshot_save_response } = scan_response {
+        match scan_snapshot_save_response.saved_result_count {
+            Ok(saved_result_count) => log::info!("Saved {} scan result(s) to the scan snapshot.", saved_result_count),
+            Err(error) => log::error!("Failed to save scan snapshot: {}", error),
+        }
+    }
+}
@@ -1,20 +1,42 @@
+pub mod handler_element_scan_preview_response;
+pub mod handler_scan_benchmark_response;
 pub mod handler_scan_collect_values_response;
+pub mod handler_scan_history_list_response;
+pub mod handler_scan_history_revert_response;
 pub mod handler_scan_new_response;
 pub mod handler_scan_reset_response;
+pub mod handler_scan_revalidate_response;
+pub mod handler_scan_snapshot_compare_response;
+pub mod handler_scan_snapshot_save_response;
 
+use crate::response_handlers::scan::handler_element_scan_preview_response::handle_element_scan_preview_response;
+use crate::response_handlers::scan::handler_scan_benchmark_response::handle_scan_benchmark_response;
 use crate::response_handlers::scan::handler_scan_collect_values_response::handle_scan_collect_values_response;
+use crate::response_handlers::scan::handler_scan_history_list_response::handle_scan_history_list_response;
+use crate::response_handlers::scan::handler_scan_history_revert_response::handle_scan_history_revert_response;
 use crate::response_handlers::scan::handler_scan_new_response::handle_scan_new_response;
 use crate::response_handlers::scan::handler_scan_reset_response::handle_scan_reset_response;
+use crate::response_handlers::scan::handler_scan_revalidate_response::handle_scan_revalidate_response;
+use crate::response_handlers::scan::handler_scan_snapshot_compare_response::handle_scan_snapshot_compare_response;
+use crate::response_handlers::scan::handler_scan_snapshot_save_response::handle_scan_snapshot_save_response;
 use squalr_engine_api::commands::scan::scan_response::ScanResponse;
 
 pub fn handle_scan_response(cmd: ScanResponse) {
     match cmd {
         ScanResponse::Reset { .. } => handle_scan_reset_response(cmd),
         ScanResponse::New { .. } => handle_scan_new_response(cmd),
+        ScanResponse::Revalidate { .. } => handle_scan_revalidate_response(cmd),
+        ScanResponse::HistoryList { .. } => handle_scan_history_list_response(cmd),
+        ScanResponse::HistoryRevert { .. } => handle_scan_history_revert_response(cmd),
+        ScanResponse::SnapshotSave { .. } => handle_scan_snapshot_save_response(cmd),
+        ScanResponse::SnapshotCompare { .. } => handle_scan_snapshot_compare_response(cmd),
         ScanResponse::CollectValues { .. } => handle_scan_collect_values_response(cmd),
         // The CLI focuses on core scan flows; ignore other scan responses for now.
         ScanResponse::ElementScan { .. } => {}
-        ScanResponse::PointerScan { .. } => {}
+        ScanResponse::ElementScanPreview { .. } => handle_element_scan_preview_response(cmd),
+        ScanResponse::PointerScan { .. } | ScanResponse::PointerScanRescan { .. } => {}
         ScanResponse::StructScan { .. } => {}
+        ScanResponse::StrideScan { .. } => {}
+        ScanResponse::Benchmark { .. } => handle_scan_benchmark_response(cmd),
     }
 }
@@ -0,0 +1,20 @@
+use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
+
+pub fn handle_scan_results_freeze_response(scan_results_freeze_response: ScanResultsFreezeResponse) {
+    if scan_results_freeze_response
+        .failed_freeze_toggle_scan_result_refs
+        .is_empty()
+    {
+        log::info!("Updated the freeze state of the result(s).");
+        return;
+    }
+
+    let failed_indices = scan_results_freeze_response
+        .failed_freeze_toggle_scan_result_refs
+        .iter()
+        .map(|scan_result_ref| scan_result_ref.get_scan_result_global_index().to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    log::error!("Failed to update the freeze state of result(s) {}.", failed_indices);
+}
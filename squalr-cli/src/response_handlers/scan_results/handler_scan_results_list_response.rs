@@ -1,14 +1,25 @@
 use squalr_engine_api::commands::scan_results::list::scan_results_list_response::ScanResultsListResponse;
-use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 
 pub fn handle_scan_results_list_response(results_list_response: ScanResultsListResponse) {
-    for scan_result in results_list_response.scan_results {
+    let symbol_registry = SymbolRegistry::get_instance();
+
+    // Results are listed by their index, such that they can be referred to by commands that act on results, ie freezing them.
+    for scan_result in &results_list_response.scan_results {
+        let scan_result_index = scan_result
+            .get_base_result()
+            .get_scan_result_ref()
+            .get_scan_result_global_index();
         let address = scan_result.get_address();
-        let value = scan_result
-            .get_current_display_value(AnonymousValueStringFormat::String)
-            .map(|value| value.get_anonymous_value_string())
-            .unwrap_or("??");
+        let value = scan_result.get_display_value_string(symbol_registry.get_default_anonymous_value_string_format(scan_result.get_data_type_ref()));
 
-        log::info!("0x{:X}\t{}", address, value);
+        log::info!("[{}]\t0x{:X}\t{}", scan_result_index, address, value);
     }
+
+    log::info!(
+        "Page {} of {}, {} result(s) in total.",
+        results_list_response.page_index,
+        results_list_response.last_page_index,
+        results_list_response.result_count
+    );
 }
@@ -0,0 +1,11 @@
+use crate::commands::address::resolve::address_resolve_request::AddressResolveRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum AddressCommand {
+    Resolve {
+        #[structopt(flatten)]
+        address_resolve_request: AddressResolveRequest,
+    },
+}
@@ -0,0 +1,7 @@
+use crate::commands::address::resolve::address_resolve_response::AddressResolveResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum AddressResponse {
+    Resolve { address_resolve_response: AddressResolveResponse },
+}
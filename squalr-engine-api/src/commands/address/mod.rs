@@ -0,0 +1,3 @@
+pub mod address_command;
+pub mod address_response;
+pub mod resolve;
@@ -0,0 +1,30 @@
+use crate::commands::address::address_command::AddressCommand;
+use crate::commands::address::address_response::AddressResponse;
+use crate::commands::address::resolve::address_resolve_response::AddressResolveResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Resolves a user entered address expression, ie `game.exe+1A0`, against the opened process. See `AddressExpression` for the grammar.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct AddressResolveRequest {
+    #[structopt(short = "e", long)]
+    pub expression: String,
+}
+
+impl PrivilegedCommandRequest for AddressResolveRequest {
+    type ResponseType = AddressResolveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Address(AddressCommand::Resolve {
+            address_resolve_request: self.clone(),
+        })
+    }
+}
+
+impl From<AddressResolveResponse> for AddressResponse {
+    fn from(address_resolve_response: AddressResolveResponse) -> Self {
+        AddressResponse::Resolve { address_resolve_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::address::address_response::AddressResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::expressions::address_resolve_error::AddressResolveError;
+use crate::structures::memory::resolved_address::ResolvedAddress;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AddressResolveResponse {
+    pub resolved_address: Result<ResolvedAddress, AddressResolveError>,
+}
+
+impl TypedPrivilegedCommandResponse for AddressResolveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Address(AddressResponse::Resolve {
+            address_resolve_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Address(AddressResponse::Resolve { address_resolve_response }) = response {
+            Ok(address_resolve_response)
+        } else {
+            Err(response)
+        }
+    }
+}
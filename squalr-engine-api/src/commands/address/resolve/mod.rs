@@ -0,0 +1,2 @@
+pub mod address_resolve_request;
+pub mod address_resolve_response;
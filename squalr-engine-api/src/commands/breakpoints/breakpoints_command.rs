@@ -0,0 +1,21 @@
+use crate::commands::breakpoints::list::breakpoints_list_request::BreakpointsListRequest;
+use crate::commands::breakpoints::remove::breakpoint_remove_request::BreakpointRemoveRequest;
+use crate::commands::breakpoints::set::breakpoint_set_request::BreakpointSetRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum BreakpointsCommand {
+    Set {
+        #[structopt(flatten)]
+        breakpoint_set_request: BreakpointSetRequest,
+    },
+    Remove {
+        #[structopt(flatten)]
+        breakpoint_remove_request: BreakpointRemoveRequest,
+    },
+    List {
+        #[structopt(flatten)]
+        breakpoints_list_request: BreakpointsListRequest,
+    },
+}
@@ -0,0 +1,11 @@
+use crate::commands::breakpoints::list::breakpoints_list_response::BreakpointsListResponse;
+use crate::commands::breakpoints::remove::breakpoint_remove_response::BreakpointRemoveResponse;
+use crate::commands::breakpoints::set::breakpoint_set_response::BreakpointSetResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BreakpointsResponse {
+    Set { breakpoint_set_response: BreakpointSetResponse },
+    Remove { breakpoint_remove_response: BreakpointRemoveResponse },
+    List { breakpoints_list_response: BreakpointsListResponse },
+}
@@ -0,0 +1,27 @@
+use crate::commands::breakpoints::breakpoints_command::BreakpointsCommand;
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::breakpoints::list::breakpoints_list_response::BreakpointsListResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Lists the addresses of all software breakpoints set in the opened process.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct BreakpointsListRequest {}
+
+impl PrivilegedCommandRequest for BreakpointsListRequest {
+    type ResponseType = BreakpointsListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Breakpoints(BreakpointsCommand::List {
+            breakpoints_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<BreakpointsListResponse> for BreakpointsResponse {
+    fn from(breakpoints_list_response: BreakpointsListResponse) -> Self {
+        BreakpointsResponse::List { breakpoints_list_response }
+    }
+}
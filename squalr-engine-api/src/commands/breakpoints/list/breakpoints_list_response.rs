@@ -0,0 +1,26 @@
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BreakpointsListResponse {
+    /// The addresses of all active breakpoints, in ascending order.
+    pub breakpoint_addresses: Vec<u64>,
+}
+
+impl TypedPrivilegedCommandResponse for BreakpointsListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Breakpoints(BreakpointsResponse::List {
+            breakpoints_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Breakpoints(BreakpointsResponse::List { breakpoints_list_response }) = response {
+            Ok(breakpoints_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod breakpoints_list_request;
+pub mod breakpoints_list_response;
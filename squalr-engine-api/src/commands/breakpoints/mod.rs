@@ -0,0 +1,5 @@
+pub mod breakpoints_command;
+pub mod breakpoints_response;
+pub mod list;
+pub mod remove;
+pub mod set;
@@ -0,0 +1,31 @@
+use crate::commands::breakpoints::breakpoints_command::BreakpointsCommand;
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::breakpoints::remove::breakpoint_remove_response::BreakpointRemoveResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Removes a software breakpoint, restoring the original byte of the instruction at the given address.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct BreakpointRemoveRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+}
+
+impl PrivilegedCommandRequest for BreakpointRemoveRequest {
+    type ResponseType = BreakpointRemoveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Breakpoints(BreakpointsCommand::Remove {
+            breakpoint_remove_request: self.clone(),
+        })
+    }
+}
+
+impl From<BreakpointRemoveResponse> for BreakpointsResponse {
+    fn from(breakpoint_remove_response: BreakpointRemoveResponse) -> Self {
+        BreakpointsResponse::Remove { breakpoint_remove_response }
+    }
+}
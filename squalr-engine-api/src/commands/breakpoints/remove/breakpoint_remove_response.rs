@@ -0,0 +1,27 @@
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BreakpointRemoveResponse {
+    /// The addresses of all active breakpoints after the request, in ascending order.
+    pub breakpoint_addresses: Vec<u64>,
+    pub error_message: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for BreakpointRemoveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Breakpoints(BreakpointsResponse::Remove {
+            breakpoint_remove_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Breakpoints(BreakpointsResponse::Remove { breakpoint_remove_response }) = response {
+            Ok(breakpoint_remove_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod breakpoint_remove_request;
+pub mod breakpoint_remove_response;
@@ -0,0 +1,33 @@
+use crate::commands::breakpoints::breakpoints_command::BreakpointsCommand;
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::breakpoints::set::breakpoint_set_response::BreakpointSetResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Sets a software breakpoint by patching an `int3` over the first byte of the instruction at the given address. The original byte
+/// is kept by the engine, such that removing the breakpoint restores it. Hits are not yet caught by a debugger, so executing the
+/// instruction raises a breakpoint exception in the target.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct BreakpointSetRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+}
+
+impl PrivilegedCommandRequest for BreakpointSetRequest {
+    type ResponseType = BreakpointSetResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Breakpoints(BreakpointsCommand::Set {
+            breakpoint_set_request: self.clone(),
+        })
+    }
+}
+
+impl From<BreakpointSetResponse> for BreakpointsResponse {
+    fn from(breakpoint_set_response: BreakpointSetResponse) -> Self {
+        BreakpointsResponse::Set { breakpoint_set_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod breakpoint_set_request;
+pub mod breakpoint_set_response;
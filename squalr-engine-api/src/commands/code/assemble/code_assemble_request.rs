@@ -0,0 +1,35 @@
+use crate::commands::code::assemble::code_assemble_response::CodeAssembleResponse;
+use crate::commands::code::code_command::CodeCommand;
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Assembles a single x86-64 instruction in Intel syntax, as it would be encoded at the given address. Nothing is written to the
+/// process, such that the caller can check the length of the result before patching it in.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct CodeAssembleRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "i", long)]
+    pub instruction: String,
+}
+
+impl PrivilegedCommandRequest for CodeAssembleRequest {
+    type ResponseType = CodeAssembleResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Code(CodeCommand::Assemble {
+            code_assemble_request: self.clone(),
+        })
+    }
+}
+
+impl From<CodeAssembleResponse> for CodeResponse {
+    fn from(code_assemble_response: CodeAssembleResponse) -> Self {
+        CodeResponse::Assemble { code_assemble_response }
+    }
+}
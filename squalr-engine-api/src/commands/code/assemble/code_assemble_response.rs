@@ -0,0 +1,27 @@
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CodeAssembleResponse {
+    /// The encoded instruction, empty if it could not be assembled.
+    pub bytes: Vec<u8>,
+    pub error_message: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for CodeAssembleResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Code(CodeResponse::Assemble {
+            code_assemble_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Code(CodeResponse::Assemble { code_assemble_response }) = response {
+            Ok(code_assemble_response)
+        } else {
+            Err(response)
+        }
+    }
+}
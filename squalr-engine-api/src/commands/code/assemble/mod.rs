@@ -0,0 +1,2 @@
+pub mod code_assemble_request;
+pub mod code_assemble_response;
@@ -0,0 +1,26 @@
+use crate::commands::code::assemble::code_assemble_request::CodeAssembleRequest;
+use crate::commands::code::list::code_patches_list_request::CodePatchesListRequest;
+use crate::commands::code::patch::code_patch_request::CodePatchRequest;
+use crate::commands::code::restore::code_restore_request::CodeRestoreRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum CodeCommand {
+    Assemble {
+        #[structopt(flatten)]
+        code_assemble_request: CodeAssembleRequest,
+    },
+    Patch {
+        #[structopt(flatten)]
+        code_patch_request: CodePatchRequest,
+    },
+    Restore {
+        #[structopt(flatten)]
+        code_restore_request: CodeRestoreRequest,
+    },
+    List {
+        #[structopt(flatten)]
+        code_patches_list_request: CodePatchesListRequest,
+    },
+}
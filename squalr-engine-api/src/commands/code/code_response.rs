@@ -0,0 +1,13 @@
+use crate::commands::code::assemble::code_assemble_response::CodeAssembleResponse;
+use crate::commands::code::list::code_patches_list_response::CodePatchesListResponse;
+use crate::commands::code::patch::code_patch_response::CodePatchResponse;
+use crate::commands::code::restore::code_restore_response::CodeRestoreResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum CodeResponse {
+    Assemble { code_assemble_response: CodeAssembleResponse },
+    Patch { code_patch_response: CodePatchResponse },
+    Restore { code_restore_response: CodeRestoreResponse },
+    List { code_patches_list_response: CodePatchesListResponse },
+}
@@ -0,0 +1,27 @@
+use crate::commands::code::code_command::CodeCommand;
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::code::list::code_patches_list_response::CodePatchesListResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Lists all code patches applied to the opened process.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct CodePatchesListRequest {}
+
+impl PrivilegedCommandRequest for CodePatchesListRequest {
+    type ResponseType = CodePatchesListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Code(CodeCommand::List {
+            code_patches_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<CodePatchesListResponse> for CodeResponse {
+    fn from(code_patches_list_response: CodePatchesListResponse) -> Self {
+        CodeResponse::List { code_patches_list_response }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::code_patch::CodePatch;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CodePatchesListResponse {
+    /// All code patches, in ascending order of address.
+    pub code_patches: Vec<CodePatch>,
+}
+
+impl TypedPrivilegedCommandResponse for CodePatchesListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Code(CodeResponse::List {
+            code_patches_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Code(CodeResponse::List { code_patches_list_response }) = response {
+            Ok(code_patches_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod code_patches_list_request;
+pub mod code_patches_list_response;
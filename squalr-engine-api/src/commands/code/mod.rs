@@ -0,0 +1,6 @@
+pub mod assemble;
+pub mod code_command;
+pub mod code_response;
+pub mod list;
+pub mod patch;
+pub mod restore;
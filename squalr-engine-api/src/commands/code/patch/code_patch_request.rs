@@ -0,0 +1,35 @@
+use crate::commands::code::code_command::CodeCommand;
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::code::patch::code_patch_response::CodePatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Writes bytes over the code at the given address, keeping the original bytes engine side such that the patch can be restored.
+/// Patching within an existing patch replaces its bytes, while keeping the code from before the first patch as the original.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct CodePatchRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "b", long)]
+    pub bytes: Vec<u8>,
+}
+
+impl PrivilegedCommandRequest for CodePatchRequest {
+    type ResponseType = CodePatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Code(CodeCommand::Patch {
+            code_patch_request: self.clone(),
+        })
+    }
+}
+
+impl From<CodePatchResponse> for CodeResponse {
+    fn from(code_patch_response: CodePatchResponse) -> Self {
+        CodeResponse::Patch { code_patch_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::code_patch::CodePatch;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CodePatchResponse {
+    /// All code patches after the request, in ascending order of address.
+    pub code_patches: Vec<CodePatch>,
+    pub error_message: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for CodePatchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Code(CodeResponse::Patch {
+            code_patch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Code(CodeResponse::Patch { code_patch_response }) = response {
+            Ok(code_patch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod code_patch_request;
+pub mod code_patch_response;
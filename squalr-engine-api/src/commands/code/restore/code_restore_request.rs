@@ -0,0 +1,31 @@
+use crate::commands::code::code_command::CodeCommand;
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::code::restore::code_restore_response::CodeRestoreResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Restores the original code of the patch containing the given address.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct CodeRestoreRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+}
+
+impl PrivilegedCommandRequest for CodeRestoreRequest {
+    type ResponseType = CodeRestoreResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Code(CodeCommand::Restore {
+            code_restore_request: self.clone(),
+        })
+    }
+}
+
+impl From<CodeRestoreResponse> for CodeResponse {
+    fn from(code_restore_response: CodeRestoreResponse) -> Self {
+        CodeResponse::Restore { code_restore_response }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod code_restore_request;
+pub mod code_restore_response;
@@ -0,0 +1,16 @@
+use crate::commands::data_types::register::data_types_register_request::DataTypesRegisterRequest;
+use crate::commands::data_types::unregister::data_types_unregister_request::DataTypesUnregisterRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum DataTypesCommand {
+    Register {
+        #[structopt(flatten)]
+        data_types_register_request: DataTypesRegisterRequest,
+    },
+    Unregister {
+        #[structopt(flatten)]
+        data_types_unregister_request: DataTypesUnregisterRequest,
+    },
+}
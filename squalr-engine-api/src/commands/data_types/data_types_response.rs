@@ -0,0 +1,13 @@
+use crate::commands::data_types::register::data_types_register_response::DataTypesRegisterResponse;
+use crate::commands::data_types::unregister::data_types_unregister_response::DataTypesUnregisterResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DataTypesResponse {
+    Register {
+        data_types_register_response: DataTypesRegisterResponse,
+    },
+    Unregister {
+        data_types_unregister_response: DataTypesUnregisterResponse,
+    },
+}
@@ -0,0 +1,4 @@
+pub mod data_types_command;
+pub mod data_types_response;
+pub mod register;
+pub mod unregister;
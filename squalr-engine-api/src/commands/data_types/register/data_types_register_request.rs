@@ -0,0 +1,32 @@
+use crate::commands::data_types::data_types_command::DataTypesCommand;
+use crate::commands::data_types::data_types_response::DataTypesResponse;
+use crate::commands::data_types::register::data_types_register_response::DataTypesRegisterResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Registers user-defined struct data types with the engine, replacing any existing types with the same ids. Definitions use
+/// the one line form, ie `Entity header = u32 id, u32 flags, f32 hp`.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct DataTypesRegisterRequest {
+    #[structopt(short = "d", long)]
+    pub definitions: Vec<CustomDataTypeDefinition>,
+}
+
+impl PrivilegedCommandRequest for DataTypesRegisterRequest {
+    type ResponseType = DataTypesRegisterResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::DataTypes(DataTypesCommand::Register {
+            data_types_register_request: self.clone(),
+        })
+    }
+}
+
+impl From<DataTypesRegisterResponse> for DataTypesResponse {
+    fn from(data_types_register_response: DataTypesRegisterResponse) -> Self {
+        DataTypesResponse::Register { data_types_register_response }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::commands::data_types::data_types_response::DataTypesResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DataTypesRegisterResponse {
+    pub registered_count: u64,
+    /// The reason each rejected definition could not be registered.
+    pub error_messages: Vec<String>,
+    /// Every custom data type registered with the engine after the request, such that clients can mirror them.
+    pub definitions: Vec<CustomDataTypeDefinition>,
+}
+
+impl TypedPrivilegedCommandResponse for DataTypesRegisterResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::DataTypes(DataTypesResponse::Register {
+            data_types_register_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::DataTypes(DataTypesResponse::Register { data_types_register_response }) = response {
+            Ok(data_types_register_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod data_types_register_request;
+pub mod data_types_register_response;
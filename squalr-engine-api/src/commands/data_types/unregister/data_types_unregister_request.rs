@@ -0,0 +1,32 @@
+use crate::commands::data_types::data_types_command::DataTypesCommand;
+use crate::commands::data_types::data_types_response::DataTypesResponse;
+use crate::commands::data_types::unregister::data_types_unregister_response::DataTypesUnregisterResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Removes a user-defined struct data type from the engine by its data type id.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct DataTypesUnregisterRequest {
+    #[structopt(short = "i", long)]
+    pub data_type_id: String,
+}
+
+impl PrivilegedCommandRequest for DataTypesUnregisterRequest {
+    type ResponseType = DataTypesUnregisterResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::DataTypes(DataTypesCommand::Unregister {
+            data_types_unregister_request: self.clone(),
+        })
+    }
+}
+
+impl From<DataTypesUnregisterResponse> for DataTypesResponse {
+    fn from(data_types_unregister_response: DataTypesUnregisterResponse) -> Self {
+        DataTypesResponse::Unregister {
+            data_types_unregister_response,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::commands::data_types::data_types_response::DataTypesResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DataTypesUnregisterResponse {
+    pub was_unregistered: bool,
+    /// Every custom data type registered with the engine after the request, such that clients can mirror them.
+    pub definitions: Vec<CustomDataTypeDefinition>,
+}
+
+impl TypedPrivilegedCommandResponse for DataTypesUnregisterResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::DataTypes(DataTypesResponse::Unregister {
+            data_types_unregister_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::DataTypes(DataTypesResponse::Unregister {
+            data_types_unregister_response,
+        }) = response
+        {
+            Ok(data_types_unregister_response)
+        } else {
+            Err(response)
+        }
+    }
+}
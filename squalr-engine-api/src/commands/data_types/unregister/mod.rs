@@ -0,0 +1,2 @@
+pub mod data_types_unregister_request;
+pub mod data_types_unregister_response;
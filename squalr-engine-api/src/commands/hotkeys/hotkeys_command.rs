@@ -0,0 +1,11 @@
+use crate::commands::hotkeys::register::hotkeys_register_request::HotkeysRegisterRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub enum HotkeysCommand {
+    Register {
+        #[structopt(flatten)]
+        hotkeys_register_request: HotkeysRegisterRequest,
+    },
+}
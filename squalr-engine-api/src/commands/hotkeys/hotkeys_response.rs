@@ -0,0 +1,7 @@
+use crate::commands::hotkeys::register::hotkeys_register_response::HotkeysRegisterResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum HotkeysResponse {
+    Register { hotkeys_register_response: HotkeysRegisterResponse },
+}
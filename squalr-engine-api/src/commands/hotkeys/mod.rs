@@ -0,0 +1,3 @@
+pub mod hotkeys_command;
+pub mod hotkeys_response;
+pub mod register;
@@ -0,0 +1,32 @@
+use crate::commands::hotkeys::hotkeys_command::HotkeysCommand;
+use crate::commands::hotkeys::hotkeys_response::HotkeysResponse;
+use crate::commands::hotkeys::register::hotkeys_register_response::HotkeysRegisterResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Replaces the chords that fire a hotkey triggered event when pressed, regardless of which window has focus. Registering an
+/// empty list stops listening for hotkeys.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct HotkeysRegisterRequest {
+    #[structopt(short = "c", long)]
+    pub chords: Vec<HotkeyChord>,
+}
+
+impl PrivilegedCommandRequest for HotkeysRegisterRequest {
+    type ResponseType = HotkeysRegisterResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Hotkeys(HotkeysCommand::Register {
+            hotkeys_register_request: self.clone(),
+        })
+    }
+}
+
+impl From<HotkeysRegisterResponse> for HotkeysResponse {
+    fn from(hotkeys_register_response: HotkeysRegisterResponse) -> Self {
+        HotkeysResponse::Register { hotkeys_register_response }
+    }
+}
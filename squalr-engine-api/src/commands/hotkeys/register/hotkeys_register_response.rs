@@ -0,0 +1,27 @@
+use crate::commands::hotkeys::hotkeys_response::HotkeysResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HotkeysRegisterResponse {
+    /// Whether global hotkeys can be detected on the platform the engine runs on. Chords are still registered if not.
+    pub is_supported: bool,
+    pub registered_chord_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for HotkeysRegisterResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Hotkeys(HotkeysResponse::Register {
+            hotkeys_register_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Hotkeys(HotkeysResponse::Register { hotkeys_register_response }) = response {
+            Ok(hotkeys_register_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod hotkeys_register_request;
+pub mod hotkeys_register_response;
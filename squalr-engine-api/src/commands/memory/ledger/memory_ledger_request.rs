@@ -0,0 +1,27 @@
+use crate::commands::memory::ledger::memory_ledger_response::MemoryLedgerResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Queries how many addresses have been modified this session and can be restored.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryLedgerRequest {}
+
+impl PrivilegedCommandRequest for MemoryLedgerRequest {
+    type ResponseType = MemoryLedgerResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Ledger {
+            memory_ledger_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryLedgerResponse> for MemoryResponse {
+    fn from(memory_ledger_response: MemoryLedgerResponse) -> Self {
+        MemoryResponse::Ledger { memory_ledger_response }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryLedgerResponse {
+    pub address_count: u64,
+    pub byte_count: u64,
+    /// Whether some writes were not recorded because the ledger reached its memory cap.
+    pub is_capacity_exceeded: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryLedgerResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Ledger {
+            memory_ledger_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Ledger { memory_ledger_response }) = response {
+            Ok(memory_ledger_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_ledger_request;
+pub mod memory_ledger_response;
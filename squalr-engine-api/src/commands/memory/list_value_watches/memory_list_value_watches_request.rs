@@ -0,0 +1,29 @@
+use crate::commands::memory::list_value_watches::memory_list_value_watches_response::MemoryListValueWatchesResponse;
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Lists all values being watched for changes.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryListValueWatchesRequest {}
+
+impl PrivilegedCommandRequest for MemoryListValueWatchesRequest {
+    type ResponseType = MemoryListValueWatchesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::ListValueWatches {
+            memory_list_value_watches_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryListValueWatchesResponse> for MemoryResponse {
+    fn from(memory_list_value_watches_response: MemoryListValueWatchesResponse) -> Self {
+        MemoryResponse::ListValueWatches {
+            memory_list_value_watches_response,
+        }
+    }
+}
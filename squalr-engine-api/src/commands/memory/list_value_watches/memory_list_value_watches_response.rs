@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::value_watch::ValueWatch;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryListValueWatchesResponse {
+    pub value_watches: Vec<ValueWatch>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryListValueWatchesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::ListValueWatches {
+            memory_list_value_watches_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::ListValueWatches {
+            memory_list_value_watches_response,
+        }) = response
+        {
+            Ok(memory_list_value_watches_response)
+        } else {
+            Err(response)
+        }
+    }
+}
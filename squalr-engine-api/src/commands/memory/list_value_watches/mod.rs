@@ -0,0 +1,2 @@
+pub mod memory_list_value_watches_request;
+pub mod memory_list_value_watches_response;
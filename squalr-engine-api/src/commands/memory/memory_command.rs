@@ -1,6 +1,18 @@
+use crate::commands::memory::ledger::memory_ledger_request::MemoryLedgerRequest;
+use crate::commands::memory::list_value_watches::memory_list_value_watches_request::MemoryListValueWatchesRequest;
 use crate::commands::memory::read::memory_read_request::MemoryReadRequest;
+use crate::commands::memory::refresh_cache::memory_refresh_cache_request::MemoryRefreshCacheRequest;
 use crate::commands::memory::regions::memory_regions_request::MemoryRegionsRequest;
+use crate::commands::memory::resolve_symbols::memory_resolve_symbols_request::MemoryResolveSymbolsRequest;
+use crate::commands::memory::restore::memory_restore_request::MemoryRestoreRequest;
+use crate::commands::memory::search::memory_search_request::MemorySearchRequest;
+use crate::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_request::MemorySetDisabledAddressesRequest;
+use crate::commands::memory::unwatch::memory_unwatch_request::MemoryUnwatchRequest;
+use crate::commands::memory::unwatch_value::memory_unwatch_value_request::MemoryUnwatchValueRequest;
+use crate::commands::memory::watch::memory_watch_request::MemoryWatchRequest;
+use crate::commands::memory::watch_value::memory_watch_value_request::MemoryWatchValueRequest;
 use crate::commands::memory::write::memory_write_request::MemoryWriteRequest;
+use crate::commands::memory::write_sequence::memory_write_sequence_request::MemoryWriteSequenceRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -18,4 +30,52 @@ pub enum MemoryCommand {
         #[structopt(flatten)]
         memory_regions_request: MemoryRegionsRequest,
     },
+    Ledger {
+        #[structopt(flatten)]
+        memory_ledger_request: MemoryLedgerRequest,
+    },
+    Restore {
+        #[structopt(flatten)]
+        memory_restore_request: MemoryRestoreRequest,
+    },
+    Search {
+        #[structopt(flatten)]
+        memory_search_request: MemorySearchRequest,
+    },
+    RefreshCache {
+        #[structopt(flatten)]
+        memory_refresh_cache_request: MemoryRefreshCacheRequest,
+    },
+    Watch {
+        #[structopt(flatten)]
+        memory_watch_request: MemoryWatchRequest,
+    },
+    Unwatch {
+        #[structopt(flatten)]
+        memory_unwatch_request: MemoryUnwatchRequest,
+    },
+    WriteSequence {
+        #[structopt(flatten)]
+        memory_write_sequence_request: MemoryWriteSequenceRequest,
+    },
+    ResolveSymbols {
+        #[structopt(flatten)]
+        memory_resolve_symbols_request: MemoryResolveSymbolsRequest,
+    },
+    WatchValue {
+        #[structopt(flatten)]
+        memory_watch_value_request: MemoryWatchValueRequest,
+    },
+    UnwatchValue {
+        #[structopt(flatten)]
+        memory_unwatch_value_request: MemoryUnwatchValueRequest,
+    },
+    ListValueWatches {
+        #[structopt(flatten)]
+        memory_list_value_watches_request: MemoryListValueWatchesRequest,
+    },
+    SetDisabledAddresses {
+        #[structopt(flatten)]
+        memory_set_disabled_addresses_request: MemorySetDisabledAddressesRequest,
+    },
 }
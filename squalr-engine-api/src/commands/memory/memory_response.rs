@@ -1,11 +1,65 @@
+use crate::commands::memory::ledger::memory_ledger_response::MemoryLedgerResponse;
+use crate::commands::memory::list_value_watches::memory_list_value_watches_response::MemoryListValueWatchesResponse;
 use crate::commands::memory::read::memory_read_response::MemoryReadResponse;
+use crate::commands::memory::refresh_cache::memory_refresh_cache_response::MemoryRefreshCacheResponse;
 use crate::commands::memory::regions::memory_regions_response::MemoryRegionsResponse;
+use crate::commands::memory::resolve_symbols::memory_resolve_symbols_response::MemoryResolveSymbolsResponse;
+use crate::commands::memory::restore::memory_restore_response::MemoryRestoreResponse;
+use crate::commands::memory::search::memory_search_response::MemorySearchResponse;
+use crate::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_response::MemorySetDisabledAddressesResponse;
+use crate::commands::memory::unwatch::memory_unwatch_response::MemoryUnwatchResponse;
+use crate::commands::memory::unwatch_value::memory_unwatch_value_response::MemoryUnwatchValueResponse;
+use crate::commands::memory::watch::memory_watch_response::MemoryWatchResponse;
+use crate::commands::memory::watch_value::memory_watch_value_response::MemoryWatchValueResponse;
 use crate::commands::memory::write::memory_write_response::MemoryWriteResponse;
+use crate::commands::memory::write_sequence::memory_write_sequence_response::MemoryWriteSequenceResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum MemoryResponse {
-    Read { memory_read_response: MemoryReadResponse },
-    Write { memory_write_response: MemoryWriteResponse },
-    Regions { memory_regions_response: MemoryRegionsResponse },
+    Read {
+        memory_read_response: MemoryReadResponse,
+    },
+    Write {
+        memory_write_response: MemoryWriteResponse,
+    },
+    Regions {
+        memory_regions_response: MemoryRegionsResponse,
+    },
+    Ledger {
+        memory_ledger_response: MemoryLedgerResponse,
+    },
+    Restore {
+        memory_restore_response: MemoryRestoreResponse,
+    },
+    Search {
+        memory_search_response: MemorySearchResponse,
+    },
+    RefreshCache {
+        memory_refresh_cache_response: MemoryRefreshCacheResponse,
+    },
+    Watch {
+        memory_watch_response: MemoryWatchResponse,
+    },
+    Unwatch {
+        memory_unwatch_response: MemoryUnwatchResponse,
+    },
+    WriteSequence {
+        memory_write_sequence_response: MemoryWriteSequenceResponse,
+    },
+    ResolveSymbols {
+        memory_resolve_symbols_response: MemoryResolveSymbolsResponse,
+    },
+    WatchValue {
+        memory_watch_value_response: MemoryWatchValueResponse,
+    },
+    UnwatchValue {
+        memory_unwatch_value_response: MemoryUnwatchValueResponse,
+    },
+    ListValueWatches {
+        memory_list_value_watches_response: MemoryListValueWatchesResponse,
+    },
+    SetDisabledAddresses {
+        memory_set_disabled_addresses_response: MemorySetDisabledAddressesResponse,
+    },
 }
@@ -1,5 +1,17 @@
+pub mod ledger;
+pub mod list_value_watches;
 pub mod memory_command;
 pub mod memory_response;
 pub mod regions;
 pub mod read;
+pub mod refresh_cache;
+pub mod resolve_symbols;
+pub mod restore;
+pub mod search;
+pub mod set_disabled_addresses;
+pub mod unwatch;
+pub mod unwatch_value;
+pub mod watch;
+pub mod watch_value;
 pub mod write;
+pub mod write_sequence;
@@ -0,0 +1,31 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::refresh_cache::memory_refresh_cache_response::MemoryRefreshCacheResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Discards the cached module and region lists of the opened process, such that they are enumerated again on next use.
+#[derive(Clone, StructOpt, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryRefreshCacheRequest {
+    /// Reports the cache diagnostics without discarding the cached lists.
+    #[structopt(short = "s", long)]
+    pub stats_only: bool,
+}
+
+impl PrivilegedCommandRequest for MemoryRefreshCacheRequest {
+    type ResponseType = MemoryRefreshCacheResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::RefreshCache {
+            memory_refresh_cache_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryRefreshCacheResponse> for MemoryResponse {
+    fn from(memory_refresh_cache_response: MemoryRefreshCacheResponse) -> Self {
+        MemoryResponse::RefreshCache { memory_refresh_cache_response }
+    }
+}
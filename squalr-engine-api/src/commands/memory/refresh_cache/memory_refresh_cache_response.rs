@@ -0,0 +1,26 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::process_query_cache_stats::ProcessQueryCacheStats;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryRefreshCacheResponse {
+    pub cache_stats: ProcessQueryCacheStats,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryRefreshCacheResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::RefreshCache {
+            memory_refresh_cache_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::RefreshCache { memory_refresh_cache_response }) = response {
+            Ok(memory_refresh_cache_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_refresh_cache_request;
+pub mod memory_refresh_cache_response;
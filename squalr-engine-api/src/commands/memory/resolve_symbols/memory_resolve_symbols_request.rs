@@ -0,0 +1,34 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::resolve_symbols::memory_resolve_symbols_response::MemoryResolveSymbolsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Resolves addresses to the exported symbols preceding them. Modules are parsed in the background the first time an address within
+/// them is requested, so addresses in modules that are still loading are returned unresolved until their loaded event fires.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryResolveSymbolsRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub addresses: Vec<u64>,
+}
+
+impl PrivilegedCommandRequest for MemoryResolveSymbolsRequest {
+    type ResponseType = MemoryResolveSymbolsResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::ResolveSymbols {
+            memory_resolve_symbols_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryResolveSymbolsResponse> for MemoryResponse {
+    fn from(memory_resolve_symbols_response: MemoryResolveSymbolsResponse) -> Self {
+        MemoryResponse::ResolveSymbols {
+            memory_resolve_symbols_response,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryResolveSymbolsResponse {
+    /// The symbol of each requested address, in request order, or none if it is not yet known.
+    pub symbols: Vec<Option<String>>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryResolveSymbolsResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::ResolveSymbols {
+            memory_resolve_symbols_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::ResolveSymbols {
+            memory_resolve_symbols_response,
+        }) = response
+        {
+            Ok(memory_resolve_symbols_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::restore::memory_restore_response::MemoryRestoreResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Restores every byte modified this session to its original value, and clears all freezes.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryRestoreRequest {}
+
+impl PrivilegedCommandRequest for MemoryRestoreRequest {
+    type ResponseType = MemoryRestoreResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Restore {
+            memory_restore_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryRestoreResponse> for MemoryResponse {
+    fn from(memory_restore_response: MemoryRestoreResponse) -> Self {
+        MemoryResponse::Restore { memory_restore_response }
+    }
+}
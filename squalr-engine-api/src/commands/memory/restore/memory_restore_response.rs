@@ -0,0 +1,26 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::memory::memory_restore_result::MemoryRestoreResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryRestoreResponse {
+    pub restore_results: Vec<MemoryRestoreResult>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryRestoreResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Restore {
+            memory_restore_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Restore { memory_restore_response }) = response {
+            Ok(memory_restore_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_restore_request;
+pub mod memory_restore_response;
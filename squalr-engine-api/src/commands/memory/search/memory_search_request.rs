@@ -0,0 +1,34 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::search::memory_search_response::MemorySearchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::byte_pattern::BytePattern;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Searches all readable memory regions of the opened process for a byte pattern, which may contain ?? wildcards.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemorySearchRequest {
+    #[structopt(short = "p", long, parse(try_from_str = BytePattern::parse_hex))]
+    pub pattern: BytePattern,
+
+    #[structopt(short = "n", long, default_value = "1000")]
+    pub max_results: u64,
+}
+
+impl PrivilegedCommandRequest for MemorySearchRequest {
+    type ResponseType = MemorySearchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Search {
+            memory_search_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemorySearchResponse> for MemoryResponse {
+    fn from(memory_search_response: MemorySearchResponse) -> Self {
+        MemoryResponse::Search { memory_search_response }
+    }
+}
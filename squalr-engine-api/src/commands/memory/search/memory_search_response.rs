@@ -0,0 +1,35 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemorySearchMatch {
+    pub address: u64,
+    /// The region containing the match, for display alongside the address.
+    pub region: MemoryRegionInfo,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemorySearchResponse {
+    pub matches: Vec<MemorySearchMatch>,
+    /// Whether the search stopped early after reaching the requested maximum number of results.
+    pub is_truncated: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemorySearchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Search {
+            memory_search_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Search { memory_search_response }) = response {
+            Ok(memory_search_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_search_request;
+pub mod memory_search_response;
@@ -0,0 +1,35 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_response::MemorySetDisabledAddressesResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::memory_address_target::MemoryAddressTarget;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Replaces the set of disabled addresses, which the freeze and value watch loops skip. Frozen values and watches on a disabled
+/// address are kept, such that enabling the address again resumes them as they were.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemorySetDisabledAddressesRequest {
+    /// Addresses in the form `<address>[@<module>]`. Passing none enables every address again.
+    #[structopt(short = "t", long)]
+    pub targets: Vec<MemoryAddressTarget>,
+}
+
+impl PrivilegedCommandRequest for MemorySetDisabledAddressesRequest {
+    type ResponseType = MemorySetDisabledAddressesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::SetDisabledAddresses {
+            memory_set_disabled_addresses_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemorySetDisabledAddressesResponse> for MemoryResponse {
+    fn from(memory_set_disabled_addresses_response: MemorySetDisabledAddressesResponse) -> Self {
+        MemoryResponse::SetDisabledAddresses {
+            memory_set_disabled_addresses_response,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemorySetDisabledAddressesResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemorySetDisabledAddressesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::SetDisabledAddresses {
+            memory_set_disabled_addresses_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::SetDisabledAddresses {
+            memory_set_disabled_addresses_response,
+        }) = response
+        {
+            Ok(memory_set_disabled_addresses_response)
+        } else {
+            Err(response)
+        }
+    }
+}
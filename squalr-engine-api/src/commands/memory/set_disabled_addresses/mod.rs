@@ -0,0 +1,2 @@
+pub mod memory_set_disabled_addresses_request;
+pub mod memory_set_disabled_addresses_response;
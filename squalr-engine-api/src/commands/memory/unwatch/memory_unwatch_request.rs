@@ -0,0 +1,30 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::unwatch::memory_unwatch_response::MemoryUnwatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Stops a memory watch, restoring the original protection of the trapped pages and detaching from the process.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryUnwatchRequest {
+    #[structopt(short = "w", long)]
+    pub watch_id: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryUnwatchRequest {
+    type ResponseType = MemoryUnwatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Unwatch {
+            memory_unwatch_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryUnwatchResponse> for MemoryResponse {
+    fn from(memory_unwatch_response: MemoryUnwatchResponse) -> Self {
+        MemoryResponse::Unwatch { memory_unwatch_response }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryUnwatchResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryUnwatchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Unwatch {
+            memory_unwatch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Unwatch { memory_unwatch_response }) = response {
+            Ok(memory_unwatch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
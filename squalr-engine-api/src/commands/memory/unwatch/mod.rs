@@ -0,0 +1,2 @@
+pub mod memory_unwatch_request;
+pub mod memory_unwatch_response;
@@ -0,0 +1,30 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::unwatch_value::memory_unwatch_value_response::MemoryUnwatchValueResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Stops notifying about changes to a watched value.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryUnwatchValueRequest {
+    #[structopt(short = "w", long)]
+    pub watch_id: u64,
+}
+
+impl PrivilegedCommandRequest for MemoryUnwatchValueRequest {
+    type ResponseType = MemoryUnwatchValueResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::UnwatchValue {
+            memory_unwatch_value_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryUnwatchValueResponse> for MemoryResponse {
+    fn from(memory_unwatch_value_response: MemoryUnwatchValueResponse) -> Self {
+        MemoryResponse::UnwatchValue { memory_unwatch_value_response }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryUnwatchValueResponse {
+    pub success: bool,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryUnwatchValueResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::UnwatchValue {
+            memory_unwatch_value_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::UnwatchValue { memory_unwatch_value_response }) = response {
+            Ok(memory_unwatch_value_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_unwatch_value_request;
+pub mod memory_unwatch_value_response;
@@ -0,0 +1,43 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::watch::memory_watch_response::MemoryWatchResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::memory::memory_watch_type::MemoryWatchType;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Starts reporting the instructions that access a range of memory. The pages containing the range are trapped, such that every
+/// access to those pages stops the accessing thread under a debugger. This slows down the target considerably when the pages are
+/// busy, even if the watched range itself is not. Write watches only report writes, though on Windows reads still stop the thread.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryWatchRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+
+    #[structopt(short = "s", long, default_value = "4")]
+    pub size: u64,
+
+    #[structopt(short = "t", long, default_value = "access")]
+    pub watch_type: MemoryWatchType,
+}
+
+impl PrivilegedCommandRequest for MemoryWatchRequest {
+    type ResponseType = MemoryWatchResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::Watch {
+            memory_watch_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryWatchResponse> for MemoryResponse {
+    fn from(memory_watch_response: MemoryWatchResponse) -> Self {
+        MemoryResponse::Watch { memory_watch_response }
+    }
+}
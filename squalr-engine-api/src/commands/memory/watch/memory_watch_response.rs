@@ -0,0 +1,27 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryWatchResponse {
+    /// The identifier of the started watch, used to match hit events and to stop the watch.
+    pub watch_id: Option<u64>,
+    pub error_message: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryWatchResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::Watch {
+            memory_watch_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::Watch { memory_watch_response }) = response {
+            Ok(memory_watch_response)
+        } else {
+            Err(response)
+        }
+    }
+}
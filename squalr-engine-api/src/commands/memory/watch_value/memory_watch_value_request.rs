@@ -0,0 +1,48 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::watch_value::memory_watch_value_response::MemoryWatchValueResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::value_watch_condition::ValueWatchCondition;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Starts comparing a value every refresh tick, firing an event when it changes in a way that meets the condition. Unlike a memory
+/// watch, this polls the value rather than trapping its page, so it does not slow down the target but can miss brief changes.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryWatchValueRequest {
+    #[structopt(short = "a", long, parse(try_from_str = Conversions::parse_hex_or_int))]
+    pub address: u64,
+
+    #[structopt(short = "m", long, default_value = "")]
+    pub module_name: String,
+
+    #[structopt(short = "d", long)]
+    pub data_type_ref: DataTypeRef,
+
+    /// Either `any`, `crosses:<value>`, or `equals:<value>`.
+    #[structopt(short = "c", long, default_value = "any")]
+    pub condition: ValueWatchCondition,
+
+    /// The name reported when the watch triggers.
+    #[structopt(short = "l", long, default_value = "")]
+    pub label: String,
+}
+
+impl PrivilegedCommandRequest for MemoryWatchValueRequest {
+    type ResponseType = MemoryWatchValueResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::WatchValue {
+            memory_watch_value_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryWatchValueResponse> for MemoryResponse {
+    fn from(memory_watch_value_response: MemoryWatchValueResponse) -> Self {
+        MemoryResponse::WatchValue { memory_watch_value_response }
+    }
+}
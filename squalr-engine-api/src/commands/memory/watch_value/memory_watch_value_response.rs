@@ -0,0 +1,27 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryWatchValueResponse {
+    /// The identifier of the started watch, used to match triggered events and to stop the watch.
+    pub watch_id: Option<u64>,
+    pub error_message: Option<String>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryWatchValueResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::WatchValue {
+            memory_watch_value_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::WatchValue { memory_watch_value_response }) = response {
+            Ok(memory_watch_value_response)
+        } else {
+            Err(response)
+        }
+    }
+}
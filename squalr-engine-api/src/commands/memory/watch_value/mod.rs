@@ -0,0 +1,2 @@
+pub mod memory_watch_value_request;
+pub mod memory_watch_value_response;
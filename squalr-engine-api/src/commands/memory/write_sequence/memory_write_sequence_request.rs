@@ -0,0 +1,39 @@
+use crate::commands::memory::memory_command::MemoryCommand;
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::memory::write_sequence::memory_write_sequence_response::MemoryWriteSequenceResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::memory::memory_write_sequence_step::MemoryWriteSequenceStep;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Runs an ordered list of writes, delays, and freeze toggles as a trackable task. Every targeted address is checked before the first
+/// step runs, such that a sequence never stops halfway because an address went away before it started.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct MemoryWriteSequenceRequest {
+    #[structopt(short = "s", long)]
+    pub steps: Vec<MemoryWriteSequenceStep>,
+
+    /// Whether the remaining steps still run after a failed step, rather than stopping the sequence.
+    #[structopt(long)]
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+impl PrivilegedCommandRequest for MemoryWriteSequenceRequest {
+    type ResponseType = MemoryWriteSequenceResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Memory(MemoryCommand::WriteSequence {
+            memory_write_sequence_request: self.clone(),
+        })
+    }
+}
+
+impl From<MemoryWriteSequenceResponse> for MemoryResponse {
+    fn from(memory_write_sequence_response: MemoryWriteSequenceResponse) -> Self {
+        MemoryResponse::WriteSequence {
+            memory_write_sequence_response,
+        }
+    }
+}
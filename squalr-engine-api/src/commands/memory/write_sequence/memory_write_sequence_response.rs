@@ -0,0 +1,33 @@
+use crate::commands::memory::memory_response::MemoryResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryWriteSequenceResponse {
+    /// The task running the sequence, or none if the sequence was not started.
+    pub trackable_task_handle: Option<TrackableTaskHandle>,
+
+    /// The steps whose address could not be resolved or read, which prevent the sequence from starting.
+    pub unresolved_step_indices: Vec<usize>,
+}
+
+impl TypedPrivilegedCommandResponse for MemoryWriteSequenceResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Memory(MemoryResponse::WriteSequence {
+            memory_write_sequence_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Memory(MemoryResponse::WriteSequence {
+            memory_write_sequence_response,
+        }) = response
+        {
+            Ok(memory_write_sequence_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod memory_write_sequence_request;
+pub mod memory_write_sequence_response;
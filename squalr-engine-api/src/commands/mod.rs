@@ -1,3 +1,8 @@
+pub mod address;
+pub mod breakpoints;
+pub mod code;
+pub mod data_types;
+pub mod hotkeys;
 pub mod memory;
 pub mod pointer_scan_results;
 pub mod privileged_command;
@@ -0,0 +1,2 @@
+pub mod pointer_scan_results_add_to_project_request;
+pub mod pointer_scan_results_add_to_project_response;
@@ -0,0 +1,37 @@
+use crate::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_response::PointerScanResultsAddToProjectResponse;
+use crate::commands::pointer_scan_results::pointer_scan_results_command::PointerScanResultsCommand;
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsAddToProjectRequest {
+    /// The pointer paths to add, ie `game.exe+1A0 -> [10, 8]`.
+    #[structopt(short = "p", long)]
+    pub pointer_scan_results: Vec<PointerScanResult>,
+    /// The data type of the value each pointer path leads to.
+    #[structopt(short = "d", long)]
+    pub data_type_ref: DataTypeRef,
+}
+
+impl PrivilegedCommandRequest for PointerScanResultsAddToProjectRequest {
+    type ResponseType = PointerScanResultsAddToProjectResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::PointerScanResults(PointerScanResultsCommand::AddToProject {
+            results_add_to_project_request: self.clone(),
+        })
+    }
+}
+
+impl From<PointerScanResultsAddToProjectResponse> for PointerScanResultsResponse {
+    fn from(pointer_scan_results_add_to_project_response: PointerScanResultsAddToProjectResponse) -> Self {
+        PointerScanResultsResponse::AddToProject {
+            pointer_scan_results_add_to_project_response,
+        }
+    }
+}
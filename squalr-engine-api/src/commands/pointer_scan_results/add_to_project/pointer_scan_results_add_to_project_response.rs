@@ -0,0 +1,31 @@
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::structures::projects::project_items::project_item::ProjectItem;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PointerScanResultsAddToProjectResponse {
+    /// The pointer entries created for the requested paths, in request order. The opened project belongs to the unprivileged side,
+    /// which adds these to it.
+    pub project_items: Vec<ProjectItem>,
+}
+
+impl TypedPrivilegedCommandResponse for PointerScanResultsAddToProjectResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::AddToProject {
+            pointer_scan_results_add_to_project_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::AddToProject {
+            pointer_scan_results_add_to_project_response,
+        }) = response
+        {
+            Ok(pointer_scan_results_add_to_project_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::pointer_scan_results::load::pointer_scan_results_load_response::PointerScanResultsLoadResponse;
+use crate::commands::pointer_scan_results::pointer_scan_results_command::PointerScanResultsCommand;
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsLoadRequest {
+    /// A file written by saving pointer scan results, which replace the current results once loaded.
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for PointerScanResultsLoadRequest {
+    type ResponseType = PointerScanResultsLoadResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::PointerScanResults(PointerScanResultsCommand::Load {
+            results_load_request: self.clone(),
+        })
+    }
+}
+
+impl From<PointerScanResultsLoadResponse> for PointerScanResultsResponse {
+    fn from(pointer_scan_results_load_response: PointerScanResultsLoadResponse) -> Self {
+        PointerScanResultsResponse::Load {
+            pointer_scan_results_load_response,
+        }
+    }
+}
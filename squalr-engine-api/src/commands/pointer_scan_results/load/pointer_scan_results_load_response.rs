@@ -0,0 +1,29 @@
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsLoadResponse {
+    /// The number of results loaded, or why the file could not be loaded into the opened process.
+    pub loaded_result_count: Result<u64, String>,
+}
+
+impl TypedPrivilegedCommandResponse for PointerScanResultsLoadResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::Load {
+            pointer_scan_results_load_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::Load {
+            pointer_scan_results_load_response,
+        }) = response
+        {
+            Ok(pointer_scan_results_load_response)
+        } else {
+            Err(response)
+        }
+    }
+}
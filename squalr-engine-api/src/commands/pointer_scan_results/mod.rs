@@ -1,3 +1,6 @@
+pub mod add_to_project;
+pub mod load;
 pub mod pointer_scan_results_command;
 pub mod pointer_scan_results_response;
 pub mod query;
+pub mod save;
@@ -1,4 +1,7 @@
+use crate::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_request::PointerScanResultsAddToProjectRequest;
+use crate::commands::pointer_scan_results::load::pointer_scan_results_load_request::PointerScanResultsLoadRequest;
 use crate::commands::pointer_scan_results::query::pointer_scan_results_query_request::PointerScanResultsQueryRequest;
+use crate::commands::pointer_scan_results::save::pointer_scan_results_save_request::PointerScanResultsSaveRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -9,4 +12,19 @@ pub enum PointerScanResultsCommand {
         #[structopt(flatten)]
         results_query_request: PointerScanResultsQueryRequest,
     },
+    /// Create project entries for pointer paths found by a pointer scan.
+    AddToProject {
+        #[structopt(flatten)]
+        results_add_to_project_request: PointerScanResultsAddToProjectRequest,
+    },
+    /// Save every pointer scan result to a file.
+    Save {
+        #[structopt(flatten)]
+        results_save_request: PointerScanResultsSaveRequest,
+    },
+    /// Replace the pointer scan results with results saved to a file.
+    Load {
+        #[structopt(flatten)]
+        results_load_request: PointerScanResultsLoadRequest,
+    },
 }
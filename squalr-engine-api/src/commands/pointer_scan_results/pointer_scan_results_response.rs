@@ -1,4 +1,7 @@
+use crate::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_response::PointerScanResultsAddToProjectResponse;
+use crate::commands::pointer_scan_results::load::pointer_scan_results_load_response::PointerScanResultsLoadResponse;
 use crate::commands::pointer_scan_results::query::pointer_scan_results_query_response::PointerScanResultsQueryResponse;
+use crate::commands::pointer_scan_results::save::pointer_scan_results_save_response::PointerScanResultsSaveResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -6,4 +9,13 @@ pub enum PointerScanResultsResponse {
     Query {
         pointer_scan_results_query_response: PointerScanResultsQueryResponse,
     },
+    AddToProject {
+        pointer_scan_results_add_to_project_response: PointerScanResultsAddToProjectResponse,
+    },
+    Save {
+        pointer_scan_results_save_response: PointerScanResultsSaveResponse,
+    },
+    Load {
+        pointer_scan_results_load_response: PointerScanResultsLoadResponse,
+    },
 }
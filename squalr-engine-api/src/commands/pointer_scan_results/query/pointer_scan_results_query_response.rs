@@ -11,6 +11,9 @@ pub struct PointerScanResultsQueryResponse {
     pub last_page_index: u64,
     pub page_size: u64,
     pub result_count: u64,
+    /// The depth the scan stopped at after reaching its memory cap, if it was cut short.
+    #[serde(default)]
+    pub memory_capped_depth: Option<u64>,
 }
 
 impl TypedPrivilegedCommandResponse for PointerScanResultsQueryResponse {
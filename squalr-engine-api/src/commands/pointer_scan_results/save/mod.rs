@@ -0,0 +1,2 @@
+pub mod pointer_scan_results_save_request;
+pub mod pointer_scan_results_save_response;
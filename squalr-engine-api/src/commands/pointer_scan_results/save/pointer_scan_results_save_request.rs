@@ -0,0 +1,33 @@
+use crate::commands::pointer_scan_results::pointer_scan_results_command::PointerScanResultsCommand;
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::pointer_scan_results::save::pointer_scan_results_save_response::PointerScanResultsSaveResponse;
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsSaveRequest {
+    /// The file to write every pointer scan result to, ie `player_health.ptr`.
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for PointerScanResultsSaveRequest {
+    type ResponseType = PointerScanResultsSaveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::PointerScanResults(PointerScanResultsCommand::Save {
+            results_save_request: self.clone(),
+        })
+    }
+}
+
+impl From<PointerScanResultsSaveResponse> for PointerScanResultsResponse {
+    fn from(pointer_scan_results_save_response: PointerScanResultsSaveResponse) -> Self {
+        PointerScanResultsResponse::Save {
+            pointer_scan_results_save_response,
+        }
+    }
+}
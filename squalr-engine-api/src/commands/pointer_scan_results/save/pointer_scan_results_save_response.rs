@@ -0,0 +1,29 @@
+use crate::commands::pointer_scan_results::pointer_scan_results_response::PointerScanResultsResponse;
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsSaveResponse {
+    /// The number of results written, or why they could not be saved.
+    pub saved_result_count: Result<u64, String>,
+}
+
+impl TypedPrivilegedCommandResponse for PointerScanResultsSaveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::Save {
+            pointer_scan_results_save_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::PointerScanResults(PointerScanResultsResponse::Save {
+            pointer_scan_results_save_response,
+        }) = response
+        {
+            Ok(pointer_scan_results_save_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::commands::address::address_command::AddressCommand;
+use crate::commands::breakpoints::breakpoints_command::BreakpointsCommand;
+use crate::commands::code::code_command::CodeCommand;
+use crate::commands::data_types::data_types_command::DataTypesCommand;
+use crate::commands::hotkeys::hotkeys_command::HotkeysCommand;
 use crate::commands::memory::memory_command::MemoryCommand;
 use crate::commands::process::process_command::ProcessCommand;
 use crate::commands::scan::scan_command::ScanCommand;
@@ -30,4 +35,19 @@ pub enum PrivilegedCommand {
 
     #[structopt(alias = "set", alias = "st")]
     TrackableTasks(TrackableTasksCommand),
+
+    #[structopt(alias = "addr", alias = "a")]
+    Address(AddressCommand),
+
+    #[structopt(alias = "bp", alias = "b")]
+    Breakpoints(BreakpointsCommand),
+
+    #[structopt(alias = "asm", alias = "c")]
+    Code(CodeCommand),
+
+    #[structopt(alias = "hk")]
+    Hotkeys(HotkeysCommand),
+
+    #[structopt(alias = "dt")]
+    DataTypes(DataTypesCommand),
 }
@@ -1,3 +1,8 @@
+use crate::commands::address::address_response::AddressResponse;
+use crate::commands::breakpoints::breakpoints_response::BreakpointsResponse;
+use crate::commands::code::code_response::CodeResponse;
+use crate::commands::data_types::data_types_response::DataTypesResponse;
+use crate::commands::hotkeys::hotkeys_response::HotkeysResponse;
 use crate::commands::memory::memory_response::MemoryResponse;
 use crate::commands::process::process_response::ProcessResponse;
 use crate::commands::project::project_response::ProjectResponse;
@@ -20,6 +25,11 @@ pub enum PrivilegedCommandResponse {
     Scan(ScanResponse),
     Settings(SettingsResponse),
     TrackableTasks(TrackableTasksResponse),
+    Address(AddressResponse),
+    Breakpoints(BreakpointsResponse),
+    Code(CodeResponse),
+    Hotkeys(HotkeysResponse),
+    DataTypes(DataTypesResponse),
 }
 
 pub trait TypedPrivilegedCommandResponse: Sized {
@@ -3,3 +3,4 @@ pub mod list;
 pub mod open;
 pub mod process_command;
 pub mod process_response;
+pub mod thumbnail;
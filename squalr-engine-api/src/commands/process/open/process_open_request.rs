@@ -14,6 +14,14 @@ pub struct ProcessOpenRequest {
     pub search_name: Option<String>,
     #[structopt(short = "m", long)]
     pub match_case: bool,
+    /// Attaches without writing or freezing anything, leaving the process to another Squalr instance already attached to it.
+    #[structopt(short = "r", long, conflicts_with = "takeover")]
+    #[serde(default)]
+    pub read_only: bool,
+    /// Asks another Squalr instance attached to the process to stop freezing it, taking over its attach lock.
+    #[structopt(short = "t", long)]
+    #[serde(default)]
+    pub takeover: bool,
 }
 
 impl PrivilegedCommandRequest for ProcessOpenRequest {
@@ -2,11 +2,16 @@ use crate::commands::privileged_command_response::PrivilegedCommandResponse;
 use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use crate::commands::process::process_response::ProcessResponse;
 use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+use crate::structures::processes::process_attach_conflict::ProcessAttachConflict;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProcessOpenResponse {
     pub opened_process_info: Option<OpenedProcessInfo>,
+    /// Set when the process was not opened because another Squalr instance is attached to it, such that the caller can retry
+    /// read-only or as a takeover.
+    #[serde(default)]
+    pub attach_conflict: Option<ProcessAttachConflict>,
 }
 
 impl TypedPrivilegedCommandResponse for ProcessOpenResponse {
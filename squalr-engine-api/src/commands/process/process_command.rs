@@ -1,6 +1,7 @@
 use crate::commands::process::close::process_close_request::ProcessCloseRequest;
 use crate::commands::process::list::process_list_request::ProcessListRequest;
 use crate::commands::process::open::process_open_request::ProcessOpenRequest;
+use crate::commands::process::thumbnail::process_thumbnail_request::ProcessThumbnailRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -18,4 +19,8 @@ pub enum ProcessCommand {
         #[structopt(flatten)]
         process_close_request: ProcessCloseRequest,
     },
+    Thumbnail {
+        #[structopt(flatten)]
+        process_thumbnail_request: ProcessThumbnailRequest,
+    },
 }
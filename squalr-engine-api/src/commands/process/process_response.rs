@@ -1,6 +1,7 @@
 use crate::commands::process::close::process_close_response::ProcessCloseResponse;
 use crate::commands::process::list::process_list_response::ProcessListResponse;
 use crate::commands::process::open::process_open_response::ProcessOpenResponse;
+use crate::commands::process::thumbnail::process_thumbnail_response::ProcessThumbnailResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -8,4 +9,5 @@ pub enum ProcessResponse {
     List { process_list_response: ProcessListResponse },
     Close { process_close_response: ProcessCloseResponse },
     Open { process_open_response: ProcessOpenResponse },
+    Thumbnail { process_thumbnail_response: ProcessThumbnailResponse },
 }
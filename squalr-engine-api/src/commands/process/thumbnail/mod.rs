@@ -0,0 +1,2 @@
+pub mod process_thumbnail_request;
+pub mod process_thumbnail_response;
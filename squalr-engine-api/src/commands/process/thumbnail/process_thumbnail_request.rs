@@ -0,0 +1,33 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::process::process_command::ProcessCommand;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::commands::process::thumbnail::process_thumbnail_response::ProcessThumbnailResponse;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProcessThumbnailRequest {
+    #[structopt(short = "p", long)]
+    pub process_id: u32,
+    #[structopt(long, default_value = "256")]
+    pub max_width: u32,
+    #[structopt(long, default_value = "160")]
+    pub max_height: u32,
+}
+
+impl PrivilegedCommandRequest for ProcessThumbnailRequest {
+    type ResponseType = ProcessThumbnailResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Process(ProcessCommand::Thumbnail {
+            process_thumbnail_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProcessThumbnailResponse> for ProcessResponse {
+    fn from(process_thumbnail_response: ProcessThumbnailResponse) -> Self {
+        ProcessResponse::Thumbnail { process_thumbnail_response }
+    }
+}
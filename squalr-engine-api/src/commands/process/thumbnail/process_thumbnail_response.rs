@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::process::process_response::ProcessResponse;
+use crate::structures::processes::process_thumbnail::ProcessThumbnail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProcessThumbnailResponse {
+    pub process_id: u32,
+    pub thumbnail: ProcessThumbnail,
+}
+
+impl TypedPrivilegedCommandResponse for ProcessThumbnailResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Process(ProcessResponse::Thumbnail {
+            process_thumbnail_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Process(ProcessResponse::Thumbnail { process_thumbnail_response }) = response {
+            Ok(process_thumbnail_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_items_create_group_request;
+pub mod project_items_create_group_response;
@@ -0,0 +1,35 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::{
+    project_items::create_group::project_items_create_group_response::ProjectItemsCreateGroupResponse, unprivileged_command_request::UnprivilegedCommandRequest,
+};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsCreateGroupRequest {
+    #[structopt(short = "n", long)]
+    pub group_name: String,
+    /// The group to create the new group within. Groups are created at the root of the project if not given.
+    #[structopt(short = "p", long)]
+    pub parent_group_path: Option<String>,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsCreateGroupRequest {
+    type ResponseType = ProjectItemsCreateGroupResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::CreateGroup {
+            project_items_create_group_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsCreateGroupResponse> for ProjectItemsResponse {
+    fn from(project_items_create_group_response: ProjectItemsCreateGroupResponse) -> Self {
+        ProjectItemsResponse::CreateGroup {
+            project_items_create_group_response,
+        }
+    }
+}
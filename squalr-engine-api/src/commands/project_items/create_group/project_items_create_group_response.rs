@@ -0,0 +1,28 @@
+use crate::commands::unprivileged_command_response::UnprivilegedCommandResponse;
+use crate::commands::{project_items::project_items_response::ProjectItemsResponse, unprivileged_command_response::TypedUnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectItemsCreateGroupResponse {
+    pub success: bool,
+    pub group_path: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectItemsCreateGroupResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::CreateGroup {
+            project_items_create_group_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::CreateGroup {
+            project_items_create_group_response,
+        }) = response
+        {
+            Ok(project_items_create_group_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod project_items_delete_request;
+pub mod project_items_delete_response;
@@ -0,0 +1,2 @@
+pub mod project_items_enable_request;
+pub mod project_items_enable_response;
@@ -0,0 +1,35 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::{
+    project_items::enable::project_items_enable_response::ProjectItemsEnableResponse, unprivileged_command_request::UnprivilegedCommandRequest,
+};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsEnableRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_paths: Vec<String>,
+    #[structopt(short = "e", long)]
+    pub is_enabled: bool,
+    /// Also enables or disables every item within the given groups.
+    #[structopt(short = "r", long)]
+    pub is_recursive: bool,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsEnableRequest {
+    type ResponseType = ProjectItemsEnableResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::Enable {
+            project_items_enable_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsEnableResponse> for ProjectItemsResponse {
+    fn from(project_items_enable_response: ProjectItemsEnableResponse) -> Self {
+        ProjectItemsResponse::Enable { project_items_enable_response }
+    }
+}
@@ -1,4 +1,10 @@
 pub mod activate;
+pub mod create_group;
+pub mod delete;
+pub mod enable;
 pub mod list;
+pub mod move_to_group;
 pub mod project_items_command;
 pub mod project_items_response;
+pub mod remove_hotkey;
+pub mod set_hotkey;
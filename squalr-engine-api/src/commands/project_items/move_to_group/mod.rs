@@ -0,0 +1,2 @@
+pub mod project_items_move_to_group_request;
+pub mod project_items_move_to_group_response;
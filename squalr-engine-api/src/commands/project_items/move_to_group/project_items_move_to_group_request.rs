@@ -0,0 +1,36 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::{
+    project_items::move_to_group::project_items_move_to_group_response::ProjectItemsMoveToGroupResponse,
+    unprivileged_command_request::UnprivilegedCommandRequest,
+};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsMoveToGroupRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_paths: Vec<String>,
+    /// The group to move the items into. Items are moved to the root of the project if not given.
+    #[structopt(short = "t", long)]
+    pub target_group_path: Option<String>,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsMoveToGroupRequest {
+    type ResponseType = ProjectItemsMoveToGroupResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::MoveToGroup {
+            project_items_move_to_group_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsMoveToGroupResponse> for ProjectItemsResponse {
+    fn from(project_items_move_to_group_response: ProjectItemsMoveToGroupResponse) -> Self {
+        ProjectItemsResponse::MoveToGroup {
+            project_items_move_to_group_response,
+        }
+    }
+}
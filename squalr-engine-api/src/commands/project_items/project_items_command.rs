@@ -1,5 +1,8 @@
 use crate::commands::project_items::{
-    activate::project_items_activate_request::ProjectItemsActivateRequest, list::project_items_list_request::ProjectItemsListRequest,
+    activate::project_items_activate_request::ProjectItemsActivateRequest, create_group::project_items_create_group_request::ProjectItemsCreateGroupRequest,
+    delete::project_items_delete_request::ProjectItemsDeleteRequest, enable::project_items_enable_request::ProjectItemsEnableRequest,
+    list::project_items_list_request::ProjectItemsListRequest, move_to_group::project_items_move_to_group_request::ProjectItemsMoveToGroupRequest,
+    remove_hotkey::project_items_remove_hotkey_request::ProjectItemsRemoveHotkeyRequest, set_hotkey::project_items_set_hotkey_request::ProjectItemsSetHotkeyRequest,
 };
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
@@ -11,9 +14,39 @@ pub enum ProjectItemsCommand {
         #[structopt(flatten)]
         project_items_activate_request: ProjectItemsActivateRequest,
     },
+    /// Creates an empty group to hold project items.
+    CreateGroup {
+        #[structopt(flatten)]
+        project_items_create_group_request: ProjectItemsCreateGroupRequest,
+    },
+    /// Deletes project items, and optionally keeps the items within deleted groups.
+    Delete {
+        #[structopt(flatten)]
+        project_items_delete_request: ProjectItemsDeleteRequest,
+    },
+    /// Enables or disables project items, independently of whether they are activated.
+    Enable {
+        #[structopt(flatten)]
+        project_items_enable_request: ProjectItemsEnableRequest,
+    },
     /// Lists opened project items.
     List {
         #[structopt(flatten)]
         project_items_list_request: ProjectItemsListRequest,
     },
+    /// Moves project items into a group.
+    MoveToGroup {
+        #[structopt(flatten)]
+        project_items_move_to_group_request: ProjectItemsMoveToGroupRequest,
+    },
+    /// Unbinds a hotkey from a project item.
+    RemoveHotkey {
+        #[structopt(flatten)]
+        project_items_remove_hotkey_request: ProjectItemsRemoveHotkeyRequest,
+    },
+    /// Binds a global hotkey that sets the value of an address entry, or toggles whether it is frozen.
+    SetHotkey {
+        #[structopt(flatten)]
+        project_items_set_hotkey_request: ProjectItemsSetHotkeyRequest,
+    },
 }
@@ -1,5 +1,10 @@
 use crate::commands::project_items::{
-    activate::project_items_activate_response::ProjectItemsActivateResponse, list::project_items_list_response::ProjectItemsListResponse,
+    activate::project_items_activate_response::ProjectItemsActivateResponse,
+    create_group::project_items_create_group_response::ProjectItemsCreateGroupResponse, delete::project_items_delete_response::ProjectItemsDeleteResponse,
+    enable::project_items_enable_response::ProjectItemsEnableResponse, list::project_items_list_response::ProjectItemsListResponse,
+    move_to_group::project_items_move_to_group_response::ProjectItemsMoveToGroupResponse,
+    remove_hotkey::project_items_remove_hotkey_response::ProjectItemsRemoveHotkeyResponse,
+    set_hotkey::project_items_set_hotkey_response::ProjectItemsSetHotkeyResponse,
 };
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +13,25 @@ pub enum ProjectItemsResponse {
     Activate {
         project_items_activate_response: ProjectItemsActivateResponse,
     },
+    CreateGroup {
+        project_items_create_group_response: ProjectItemsCreateGroupResponse,
+    },
+    Delete {
+        project_items_delete_response: ProjectItemsDeleteResponse,
+    },
+    Enable {
+        project_items_enable_response: ProjectItemsEnableResponse,
+    },
     List {
         project_items_list_response: ProjectItemsListResponse,
     },
+    MoveToGroup {
+        project_items_move_to_group_response: ProjectItemsMoveToGroupResponse,
+    },
+    RemoveHotkey {
+        project_items_remove_hotkey_response: ProjectItemsRemoveHotkeyResponse,
+    },
+    SetHotkey {
+        project_items_set_hotkey_response: ProjectItemsSetHotkeyResponse,
+    },
 }
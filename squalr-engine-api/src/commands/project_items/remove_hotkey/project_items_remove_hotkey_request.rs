@@ -0,0 +1,36 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::{
+    project_items::remove_hotkey::project_items_remove_hotkey_response::ProjectItemsRemoveHotkeyResponse,
+    unprivileged_command_request::UnprivilegedCommandRequest,
+};
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsRemoveHotkeyRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_path: String,
+    #[structopt(short = "c", long)]
+    pub chord: HotkeyChord,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsRemoveHotkeyRequest {
+    type ResponseType = ProjectItemsRemoveHotkeyResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::RemoveHotkey {
+            project_items_remove_hotkey_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsRemoveHotkeyResponse> for ProjectItemsResponse {
+    fn from(project_items_remove_hotkey_response: ProjectItemsRemoveHotkeyResponse) -> Self {
+        ProjectItemsResponse::RemoveHotkey {
+            project_items_remove_hotkey_response,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::commands::unprivileged_command_response::UnprivilegedCommandResponse;
+use crate::commands::{project_items::project_items_response::ProjectItemsResponse, unprivileged_command_response::TypedUnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectItemsRemoveHotkeyResponse {
+    pub success: bool,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectItemsRemoveHotkeyResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::RemoveHotkey {
+            project_items_remove_hotkey_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::RemoveHotkey {
+            project_items_remove_hotkey_response,
+        }) = response
+        {
+            Ok(project_items_remove_hotkey_response)
+        } else {
+            Err(response)
+        }
+    }
+}
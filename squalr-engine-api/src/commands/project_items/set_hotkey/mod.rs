@@ -0,0 +1,2 @@
+pub mod project_items_set_hotkey_request;
+pub mod project_items_set_hotkey_response;
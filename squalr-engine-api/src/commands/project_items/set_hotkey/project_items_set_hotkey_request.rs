@@ -0,0 +1,40 @@
+use crate::commands::project_items::project_items_command::ProjectItemsCommand;
+use crate::commands::project_items::project_items_response::ProjectItemsResponse;
+use crate::commands::unprivileged_command::UnprivilegedCommand;
+use crate::commands::{
+    project_items::set_hotkey::project_items_set_hotkey_response::ProjectItemsSetHotkeyResponse, unprivileged_command_request::UnprivilegedCommandRequest,
+};
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use crate::structures::projects::project_items::project_item_hotkey::ProjectItemHotkeyAction;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ProjectItemsSetHotkeyRequest {
+    #[structopt(short = "p", long)]
+    pub project_item_path: String,
+    /// The chord to bind, ie `Ctrl+Numpad1`. Binding a chord the entry already uses replaces its action.
+    #[structopt(short = "c", long)]
+    pub chord: HotkeyChord,
+    /// The action to perform when the chord is pressed, ie `freeze` or `set:999;dec;none`.
+    #[structopt(short = "a", long)]
+    pub action: ProjectItemHotkeyAction,
+}
+
+impl UnprivilegedCommandRequest for ProjectItemsSetHotkeyRequest {
+    type ResponseType = ProjectItemsSetHotkeyResponse;
+
+    fn to_engine_command(&self) -> UnprivilegedCommand {
+        UnprivilegedCommand::ProjectItems(ProjectItemsCommand::SetHotkey {
+            project_items_set_hotkey_request: self.clone(),
+        })
+    }
+}
+
+impl From<ProjectItemsSetHotkeyResponse> for ProjectItemsResponse {
+    fn from(project_items_set_hotkey_response: ProjectItemsSetHotkeyResponse) -> Self {
+        ProjectItemsResponse::SetHotkey {
+            project_items_set_hotkey_response,
+        }
+    }
+}
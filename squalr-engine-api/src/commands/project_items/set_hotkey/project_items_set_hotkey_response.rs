@@ -0,0 +1,29 @@
+use crate::commands::unprivileged_command_response::UnprivilegedCommandResponse;
+use crate::commands::{project_items::project_items_response::ProjectItemsResponse, unprivileged_command_response::TypedUnprivilegedCommandResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ProjectItemsSetHotkeyResponse {
+    pub success: bool,
+    /// Why the hotkey was not bound, ie the chord is already bound to another entry.
+    pub error_message: Option<String>,
+}
+
+impl TypedUnprivilegedCommandResponse for ProjectItemsSetHotkeyResponse {
+    fn to_engine_response(&self) -> UnprivilegedCommandResponse {
+        UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::SetHotkey {
+            project_items_set_hotkey_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: UnprivilegedCommandResponse) -> Result<Self, UnprivilegedCommandResponse> {
+        if let UnprivilegedCommandResponse::ProjectItems(ProjectItemsResponse::SetHotkey {
+            project_items_set_hotkey_response,
+        }) = response
+        {
+            Ok(project_items_set_hotkey_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod scan_benchmark_request;
+pub mod scan_benchmark_response;
@@ -0,0 +1,30 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::benchmark::scan_benchmark_response::ScanBenchmarkResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Measures the speed of scans with each combination of thread count and vector width against a synthetic snapshot, as a trackable task.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanBenchmarkRequest {
+    /// The size of the synthetic snapshot to scan, in megabytes.
+    #[structopt(short = "s", long, default_value = "32")]
+    pub size_mb: u64,
+}
+
+impl PrivilegedCommandRequest for ScanBenchmarkRequest {
+    type ResponseType = ScanBenchmarkResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::Benchmark {
+            scan_benchmark_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanBenchmarkResponse> for ScanResponse {
+    fn from(scan_benchmark_response: ScanBenchmarkResponse) -> Self {
+        ScanResponse::Benchmark { scan_benchmark_response }
+    }
+}
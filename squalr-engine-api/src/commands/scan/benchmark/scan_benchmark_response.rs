@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanBenchmarkResponse {
+    /// The task running the benchmark, whose results are sent with the scan benchmark finished event.
+    pub trackable_task_handle: Option<TrackableTaskHandle>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanBenchmarkResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::Benchmark {
+            scan_benchmark_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::Benchmark { scan_benchmark_response }) = response {
+            Ok(scan_benchmark_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -13,6 +13,11 @@ pub struct ElementScanRequest {
     pub scan_constraints: Vec<AnonymousScanConstraint>,
     #[structopt(short = "d", long)]
     pub data_type_refs: Vec<DataTypeRef>,
+    /// Keeps every element of the snapshot without comparing, such that the values can be narrowed down by relative scans later.
+    /// Scan constraints are ignored, and may be empty.
+    #[structopt(short = "u", long)]
+    #[serde(default)]
+    pub is_unknown_initial_scan: bool,
 }
 
 impl PrivilegedCommandRequest for ElementScanRequest {
@@ -0,0 +1,38 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::element_scan_preview::element_scan_preview_response::ElementScanPreviewResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use crate::{commands::privileged_command::PrivilegedCommand, structures::data_types::data_type_ref::DataTypeRef};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Counts how many of the current scan results would remain after an element scan, without modifying the results.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ElementScanPreviewRequest {
+    #[structopt(short = "c", long)]
+    pub scan_constraints: Vec<AnonymousScanConstraint>,
+    #[structopt(short = "d", long)]
+    pub data_type_refs: Vec<DataTypeRef>,
+}
+
+impl ElementScanPreviewRequest {
+    /// Previews are skipped above this many results, since a preview costs roughly as much as the scan itself.
+    pub const MAX_PREVIEW_RESULT_COUNT: u64 = 1_000_000;
+}
+
+impl PrivilegedCommandRequest for ElementScanPreviewRequest {
+    type ResponseType = ElementScanPreviewResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::ElementScanPreview {
+            element_scan_preview_request: self.clone(),
+        })
+    }
+}
+
+impl From<ElementScanPreviewResponse> for ScanResponse {
+    fn from(element_scan_preview_response: ElementScanPreviewResponse) -> Self {
+        ScanResponse::ElementScanPreview { element_scan_preview_response }
+    }
+}
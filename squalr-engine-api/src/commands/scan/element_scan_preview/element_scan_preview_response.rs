@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ElementScanPreviewResponse {
+    /// The number of results before the previewed scan.
+    pub current_result_count: u64,
+
+    /// The number of results the scan would keep, or `None` if a preview was not possible (no results yet, or too many to preview).
+    pub preview_result_count: Option<u64>,
+}
+
+impl TypedPrivilegedCommandResponse for ElementScanPreviewResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::ElementScanPreview {
+            element_scan_preview_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::ElementScanPreview { element_scan_preview_response }) = response {
+            Ok(element_scan_preview_response)
+        } else {
+            Err(response)
+        }
+    }
+}
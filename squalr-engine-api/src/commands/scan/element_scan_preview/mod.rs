@@ -0,0 +1,2 @@
+pub mod element_scan_preview_request;
+pub mod element_scan_preview_response;
@@ -0,0 +1,2 @@
+pub mod scan_history_list_request;
+pub mod scan_history_list_response;
@@ -0,0 +1,26 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::history_list::scan_history_list_response::ScanHistoryListResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Lists the earlier scans that the current scan can be reverted to, along with the current scan itself.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanHistoryListRequest {}
+
+impl PrivilegedCommandRequest for ScanHistoryListRequest {
+    type ResponseType = ScanHistoryListResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::HistoryList {
+            scan_history_list_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanHistoryListResponse> for ScanResponse {
+    fn from(scan_history_list_response: ScanHistoryListResponse) -> Self {
+        ScanResponse::HistoryList { scan_history_list_response }
+    }
+}
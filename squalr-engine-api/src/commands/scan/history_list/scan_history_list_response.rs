@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::snapshots::snapshot_scan_history::SnapshotScanHistoryItem;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanHistoryListResponse {
+    /// The scans that can be reverted to, oldest first, followed by the current scan. Empty if nothing has been scanned.
+    pub scan_history_items: Vec<SnapshotScanHistoryItem>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanHistoryListResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::HistoryList {
+            scan_history_list_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::HistoryList { scan_history_list_response }) = response {
+            Ok(scan_history_list_response)
+        } else {
+            Err(response)
+        }
+    }
+}
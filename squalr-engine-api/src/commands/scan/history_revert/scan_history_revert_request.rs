@@ -0,0 +1,30 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::history_revert::scan_history_revert_response::ScanHistoryRevertResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Restores the results of an earlier scan from the scan history, discarding the results of every scan after it.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanHistoryRevertRequest {
+    /// The number of the scan to revert to, as listed by the scan history.
+    #[structopt(short = "n", long)]
+    pub scan_number: u64,
+}
+
+impl PrivilegedCommandRequest for ScanHistoryRevertRequest {
+    type ResponseType = ScanHistoryRevertResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::HistoryRevert {
+            scan_history_revert_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanHistoryRevertResponse> for ScanResponse {
+    fn from(scan_history_revert_response: ScanHistoryRevertResponse) -> Self {
+        ScanResponse::HistoryRevert { scan_history_revert_response }
+    }
+}
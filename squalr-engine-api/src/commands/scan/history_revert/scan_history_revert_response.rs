@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanHistoryRevertResponse {
+    /// Whether the scan was found in the scan history and its results restored.
+    pub success: bool,
+
+    /// The number of results after reverting.
+    pub result_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanHistoryRevertResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::HistoryRevert {
+            scan_history_revert_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::HistoryRevert { scan_history_revert_response }) = response {
+            Ok(scan_history_revert_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,8 +1,17 @@
+pub mod benchmark;
 pub mod collect_values;
 pub mod element_scan;
+pub mod element_scan_preview;
+pub mod history_list;
+pub mod history_revert;
 pub mod new;
 pub mod pointer_scan;
+pub mod pointer_scan_rescan;
 pub mod reset;
+pub mod revalidate;
 pub mod scan_command;
 pub mod scan_response;
+pub mod snapshot_compare;
+pub mod snapshot_save;
+pub mod stride_scan;
 pub mod struct_scan;
@@ -17,10 +17,40 @@ pub struct PointerScanRequest {
     pub max_depth: u64,
     #[structopt(short = "o", long)]
     pub offset_size: u64,
+    /// The smallest offset to follow from a pointer value, which may be negative to reach addresses below it. Defaults to zero.
+    #[structopt(long, allow_hyphen_values = true)]
+    #[serde(default)]
+    pub min_offset: Option<i64>,
+    /// The largest offset to follow from a pointer value, which may be negative. Defaults to the offset size.
+    #[structopt(long, allow_hyphen_values = true)]
+    #[serde(default)]
+    pub max_offset: Option<i64>,
+    /// The most distinct offsets followed from each address on a level, keeping the offsets closest to zero. Defaults to every
+    /// offset within the offset range.
+    #[structopt(long)]
+    #[serde(default)]
+    pub max_offsets_per_level: Option<u64>,
     #[structopt(long)]
     pub scan_statics: bool,
     #[structopt(long)]
     pub scan_heaps: bool,
+    /// The alignment that pointer storage addresses must have. Defaults to the pointer size of the target.
+    #[structopt(long)]
+    #[serde(default)]
+    pub pointer_alignment: Option<u64>,
+    /// Keeps pointers whose values fall outside of mapped memory, which are otherwise discarded.
+    #[structopt(long)]
+    #[serde(default)]
+    pub allow_unmapped_targets: bool,
+    /// Discards pointers whose values are not aligned to the pointer alignment.
+    #[structopt(long)]
+    #[serde(default)]
+    pub require_aligned_targets: bool,
+    /// The most memory the pointer map and its levels may use. Once reached, the scan stops deepening and keeps the results found
+    /// so far. Defaults to `PointerScanParameters::DEFAULT_MAX_MEMORY_BYTES`.
+    #[structopt(long)]
+    #[serde(default)]
+    pub max_memory_bytes: Option<u64>,
 }
 
 impl PrivilegedCommandRequest for PointerScanRequest {
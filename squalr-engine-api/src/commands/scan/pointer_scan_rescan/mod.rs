@@ -0,0 +1,2 @@
+pub mod pointer_scan_rescan_request;
+pub mod pointer_scan_rescan_response;
@@ -0,0 +1,35 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::pointer_scan_rescan::pointer_scan_rescan_response::PointerScanRescanResponse;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Filters the current pointer scan results against a new target address, ie after restarting the target process. Each pointer path
+/// is resolved again in the opened process, and only the paths that still lead to the new target address are kept.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct PointerScanRescanRequest {
+    #[structopt(short = "a", long)]
+    pub target_address: AnonymousValueString,
+    #[structopt(short = "d", long)]
+    pub pointer_data_type_ref: DataTypeRef,
+}
+
+impl PrivilegedCommandRequest for PointerScanRescanRequest {
+    type ResponseType = PointerScanRescanResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::PointerScanRescan {
+            pointer_scan_rescan_request: self.clone(),
+        })
+    }
+}
+
+impl From<PointerScanRescanResponse> for ScanResponse {
+    fn from(pointer_scan_rescan_response: PointerScanRescanResponse) -> Self {
+        ScanResponse::PointerScanRescan { pointer_scan_rescan_response }
+    }
+}
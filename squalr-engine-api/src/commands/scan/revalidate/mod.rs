@@ -0,0 +1,2 @@
+pub mod scan_revalidate_request;
+pub mod scan_revalidate_response;
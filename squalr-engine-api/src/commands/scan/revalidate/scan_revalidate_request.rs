@@ -0,0 +1,27 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::revalidate::scan_revalidate_response::ScanRevalidateResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Re-snapshots only the ranges whose backing memory changed since they were snapshotted. Results over memory that no longer
+/// exists or no longer matches the memory settings are discarded, and the remaining stale results have their values re-read.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanRevalidateRequest {}
+
+impl PrivilegedCommandRequest for ScanRevalidateRequest {
+    type ResponseType = ScanRevalidateResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::Revalidate {
+            scan_revalidate_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanRevalidateResponse> for ScanResponse {
+    fn from(scan_revalidate_response: ScanRevalidateResponse) -> Self {
+        ScanResponse::Revalidate { scan_revalidate_response }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanRevalidateResponse {
+    /// The number of stale ranges that were re-snapshotted.
+    pub revalidated_range_count: u64,
+
+    /// The number of results discarded because the memory under them no longer exists or no longer matches the memory settings.
+    pub discarded_result_count: u64,
+}
+
+impl TypedPrivilegedCommandResponse for ScanRevalidateResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::Revalidate {
+            scan_revalidate_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::Revalidate { scan_revalidate_response }) = response {
+            Ok(scan_revalidate_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -1,8 +1,17 @@
+use crate::commands::scan::benchmark::scan_benchmark_request::ScanBenchmarkRequest;
 use crate::commands::scan::collect_values::scan_collect_values_request::ScanCollectValuesRequest;
 use crate::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use crate::commands::scan::element_scan_preview::element_scan_preview_request::ElementScanPreviewRequest;
+use crate::commands::scan::history_list::scan_history_list_request::ScanHistoryListRequest;
+use crate::commands::scan::history_revert::scan_history_revert_request::ScanHistoryRevertRequest;
 use crate::commands::scan::new::scan_new_request::ScanNewRequest;
 use crate::commands::scan::pointer_scan::pointer_scan_request::PointerScanRequest;
+use crate::commands::scan::pointer_scan_rescan::pointer_scan_rescan_request::PointerScanRescanRequest;
 use crate::commands::scan::reset::scan_reset_request::ScanResetRequest;
+use crate::commands::scan::revalidate::scan_revalidate_request::ScanRevalidateRequest;
+use crate::commands::scan::snapshot_compare::scan_snapshot_compare_request::ScanSnapshotCompareRequest;
+use crate::commands::scan::snapshot_save::scan_snapshot_save_request::ScanSnapshotSaveRequest;
+use crate::commands::scan::stride_scan::stride_scan_request::StrideScanRequest;
 use crate::commands::scan::struct_scan::struct_scan_request::StructScanRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
@@ -20,6 +29,31 @@ pub enum ScanCommand {
         #[structopt(flatten)]
         scan_new_request: ScanNewRequest,
     },
+    /// Re-snapshots the ranges of the current scan whose backing memory was freed or re-protected since they were snapshotted.
+    Revalidate {
+        #[structopt(flatten)]
+        scan_revalidate_request: ScanRevalidateRequest,
+    },
+    /// Lists the earlier scans that the current scan can be reverted to.
+    HistoryList {
+        #[structopt(flatten)]
+        scan_history_list_request: ScanHistoryListRequest,
+    },
+    /// Reverts the current scan to the results of an earlier scan, discarding the results of every scan after it.
+    HistoryRevert {
+        #[structopt(flatten)]
+        scan_history_revert_request: ScanHistoryRevertRequest,
+    },
+    /// Saves the current scan results and their values to a file, such that they can be compared against in a later session.
+    SnapshotSave {
+        #[structopt(flatten)]
+        scan_snapshot_save_request: ScanSnapshotSaveRequest,
+    },
+    /// Replaces the current scan results with those saved to a file, keeping those whose values differ from, or match, the saved values.
+    SnapshotCompare {
+        #[structopt(flatten)]
+        scan_snapshot_compare_request: ScanSnapshotCompareRequest,
+    },
     /// Collect values for the current scan if one exist, otherwise collect initial values.
     CollectValues {
         #[structopt(flatten)]
@@ -30,14 +64,34 @@ pub enum ScanCommand {
         #[structopt(flatten)]
         element_scan_request: ElementScanRequest,
     },
+    /// Counts how many current results an element scan would keep, without modifying the results.
+    ElementScanPreview {
+        #[structopt(flatten)]
+        element_scan_preview_request: ElementScanPreviewRequest,
+    },
     /// Performs an pointer scan based on the provided parameters.
     PointerScan {
         #[structopt(flatten)]
         pointer_scan_request: PointerScanRequest,
     },
+    /// Keeps only the current pointer scan results that still lead to the provided target address, ie after a restart.
+    PointerScanRescan {
+        #[structopt(flatten)]
+        pointer_scan_rescan_request: PointerScanRescanRequest,
+    },
     /// Performs an struct-wise scan, potentially collecting values depending on the provided parameters.
     StructScan {
         #[structopt(flatten)]
         struct_scan_request: StructScanRequest,
     },
+    /// Finds arrays of structs whose field holds the same value at a fixed stride, keeping the base address of each array.
+    StrideScan {
+        #[structopt(flatten)]
+        stride_scan_request: StrideScanRequest,
+    },
+    /// Measures how quickly scans run with each thread count and vector width, such that the fastest can be chosen for this machine.
+    Benchmark {
+        #[structopt(flatten)]
+        scan_benchmark_request: ScanBenchmarkRequest,
+    },
 }
@@ -1,17 +1,65 @@
+use crate::commands::scan::benchmark::scan_benchmark_response::ScanBenchmarkResponse;
 use crate::commands::scan::collect_values::scan_collect_values_response::ScanCollectValuesResponse;
 use crate::commands::scan::element_scan::element_scan_response::ElementScanResponse;
+use crate::commands::scan::element_scan_preview::element_scan_preview_response::ElementScanPreviewResponse;
+use crate::commands::scan::history_list::scan_history_list_response::ScanHistoryListResponse;
+use crate::commands::scan::history_revert::scan_history_revert_response::ScanHistoryRevertResponse;
 use crate::commands::scan::new::scan_new_response::ScanNewResponse;
 use crate::commands::scan::pointer_scan::pointer_scan_response::PointerScanResponse;
+use crate::commands::scan::pointer_scan_rescan::pointer_scan_rescan_response::PointerScanRescanResponse;
 use crate::commands::scan::reset::scan_reset_response::ScanResetResponse;
+use crate::commands::scan::revalidate::scan_revalidate_response::ScanRevalidateResponse;
+use crate::commands::scan::snapshot_compare::scan_snapshot_compare_response::ScanSnapshotCompareResponse;
+use crate::commands::scan::snapshot_save::scan_snapshot_save_response::ScanSnapshotSaveResponse;
+use crate::commands::scan::stride_scan::stride_scan_response::StrideScanResponse;
 use crate::commands::scan::struct_scan::struct_scan_response::StructScanResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum ScanResponse {
-    New { scan_new_response: ScanNewResponse },
-    Reset { scan_reset_response: ScanResetResponse },
-    CollectValues { scan_value_collector_response: ScanCollectValuesResponse },
-    ElementScan { element_scan_response: ElementScanResponse },
-    PointerScan { pointer_scan_response: PointerScanResponse },
-    StructScan { struct_scan_response: StructScanResponse },
+    New {
+        scan_new_response: ScanNewResponse,
+    },
+    Reset {
+        scan_reset_response: ScanResetResponse,
+    },
+    Revalidate {
+        scan_revalidate_response: ScanRevalidateResponse,
+    },
+    HistoryList {
+        scan_history_list_response: ScanHistoryListResponse,
+    },
+    HistoryRevert {
+        scan_history_revert_response: ScanHistoryRevertResponse,
+    },
+    SnapshotSave {
+        scan_snapshot_save_response: ScanSnapshotSaveResponse,
+    },
+    SnapshotCompare {
+        scan_snapshot_compare_response: ScanSnapshotCompareResponse,
+    },
+    CollectValues {
+        scan_value_collector_response: ScanCollectValuesResponse,
+    },
+    ElementScan {
+        element_scan_response: ElementScanResponse,
+    },
+    ElementScanPreview {
+        element_scan_preview_response: ElementScanPreviewResponse,
+    },
+    PointerScan {
+        pointer_scan_response: PointerScanResponse,
+    },
+    PointerScanRescan {
+        pointer_scan_rescan_response: PointerScanRescanResponse,
+    },
+    StructScan {
+        struct_scan_response: StructScanResponse,
+    },
+    StrideScan {
+        stride_scan_response: StrideScanResponse,
+    },
+    Benchmark {
+        scan_benchmark_response: ScanBenchmarkResponse,
+    },
 }
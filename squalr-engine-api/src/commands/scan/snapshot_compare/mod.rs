@@ -0,0 +1,2 @@
+pub mod scan_snapshot_compare_request;
+pub mod scan_snapshot_compare_response;
@@ -0,0 +1,37 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::scan::snapshot_compare::scan_snapshot_compare_response::ScanSnapshotCompareResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Replaces the current scan results with those saved in a scan snapshot file, keeping only the results whose value now differs
+/// from the saved value, or only those whose value still matches it. The saved values become the previous values of the results.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotCompareRequest {
+    /// The scan snapshot file to compare against, ie `before_restart.snap`.
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+    /// Keeps the results whose value matches the saved value, rather than those whose value differs from it.
+    #[structopt(short = "m", long)]
+    pub keep_matching_values: bool,
+}
+
+impl PrivilegedCommandRequest for ScanSnapshotCompareRequest {
+    type ResponseType = ScanSnapshotCompareResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::SnapshotCompare {
+            scan_snapshot_compare_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanSnapshotCompareResponse> for ScanResponse {
+    fn from(scan_snapshot_compare_response: ScanSnapshotCompareResponse) -> Self {
+        ScanResponse::SnapshotCompare {
+            scan_snapshot_compare_response,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::structures::tasks::trackable_task_handle::TrackableTaskHandle;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotCompareResponse {
+    /// The number of saved results being compared against, or why the snapshot could not be compared against.
+    pub compared_result_count: Result<u64, String>,
+
+    /// The scan comparing the saved results against their current values, which reports updated results once it completes.
+    pub trackable_task_handle: Option<TrackableTaskHandle>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanSnapshotCompareResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::SnapshotCompare {
+            scan_snapshot_compare_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::SnapshotCompare {
+            scan_snapshot_compare_response,
+        }) = response
+        {
+            Ok(scan_snapshot_compare_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod scan_snapshot_save_request;
+pub mod scan_snapshot_save_response;
@@ -0,0 +1,31 @@
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::scan::snapshot_save::scan_snapshot_save_response::ScanSnapshotSaveResponse;
+use crate::commands::{privileged_command::PrivilegedCommand, scan::scan_command::ScanCommand};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// Saves the current scan results and their values to disk, such that they can be compared against in a later session.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotSaveRequest {
+    /// The file to write the scan results and their values to, ie `before_restart.snap`.
+    #[structopt(short = "f", long)]
+    pub file_path: PathBuf,
+}
+
+impl PrivilegedCommandRequest for ScanSnapshotSaveRequest {
+    type ResponseType = ScanSnapshotSaveResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::SnapshotSave {
+            scan_snapshot_save_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanSnapshotSaveResponse> for ScanResponse {
+    fn from(scan_snapshot_save_response: ScanSnapshotSaveResponse) -> Self {
+        ScanResponse::SnapshotSave { scan_snapshot_save_response }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan::scan_response::ScanResponse;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotSaveResponse {
+    /// The number of results written, or why they could not be saved.
+    pub saved_result_count: Result<u64, String>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanSnapshotSaveResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Scan(ScanResponse::SnapshotSave {
+            scan_snapshot_save_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Scan(ScanResponse::SnapshotSave { scan_snapshot_save_response }) = response {
+            Ok(scan_snapshot_save_response)
+        } else {
+            Err(response)
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod stride_scan_request;
+pub mod stride_scan_response;
@@ -0,0 +1,41 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan::scan_command::ScanCommand;
+use crate::commands::scan::scan_response::ScanResponse;
+use crate::commands::scan::stride_scan::stride_scan_response::StrideScanResponse;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct StrideScanRequest {
+    /// The value of the field that repeats across the array.
+    #[structopt(short = "v", long)]
+    pub scan_value: AnonymousValueString,
+    /// The data type of the field that repeats across the array.
+    #[structopt(short = "d", long)]
+    pub data_type_ref: DataTypeRef,
+    /// The distance in bytes between the field of one array element and the next, ie the size of the struct.
+    #[structopt(short = "s", long)]
+    pub stride: u64,
+    /// The fewest times the value must repeat, including the first, for the array to be kept as a result.
+    #[structopt(short = "m", long)]
+    pub minimum_repeat_count: u64,
+}
+
+impl PrivilegedCommandRequest for StrideScanRequest {
+    type ResponseType = StrideScanResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Scan(ScanCommand::StrideScan {
+            stride_scan_request: self.clone(),
+        })
+    }
+}
+
+impl From<StrideScanResponse> for ScanResponse {
+    fn from(stride_scan_response: StrideScanResponse) -> Self {
+        ScanResponse::StrideScan { stride_scan_response }
+    }
+}
@@ -13,6 +13,10 @@ pub struct ScanResultsFreezeRequest {
     pub scan_result_refs: Vec<ScanResultRef>,
     #[structopt(short = "f", long)]
     pub is_frozen: bool,
+    /// Whether to also freeze results whose backing memory changed since it was snapshotted, which are skipped otherwise.
+    #[structopt(long)]
+    #[serde(default)]
+    pub allow_stale: bool,
 }
 
 impl PrivilegedCommandRequest for ScanResultsFreezeRequest {
@@ -6,4 +6,6 @@ pub mod query;
 pub mod refresh;
 pub mod scan_results_command;
 pub mod scan_results_response;
+pub mod set_data_type;
 pub mod set_property;
+pub mod set_values;
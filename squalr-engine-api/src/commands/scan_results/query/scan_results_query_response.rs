@@ -2,6 +2,7 @@ use crate::commands::privileged_command_response::PrivilegedCommandResponse;
 use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
 use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
 use crate::structures::scan_results::scan_result::ScanResult;
+use crate::structures::scanning::statistics::scan_statistics::ScanStatistics;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -12,6 +13,9 @@ pub struct ScanResultsQueryResponse {
     pub page_size: u64,
     pub result_count: u64,
     pub total_size_in_bytes: u64,
+    pub last_read_size_in_bytes: u64,
+    pub last_scan_statistics: Option<ScanStatistics>,
+    pub scan_generation: u64,
 }
 
 impl TypedPrivilegedCommandResponse for ScanResultsQueryResponse {
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ScanResultsRefreshResponse {
     pub scan_results: Vec<ScanResult>,
+    pub scan_generation: u64,
 }
 
 impl TypedPrivilegedCommandResponse for ScanResultsRefreshResponse {
@@ -4,7 +4,9 @@ use crate::commands::scan_results::freeze::scan_results_freeze_request::ScanResu
 use crate::commands::scan_results::list::scan_results_list_request::ScanResultsListRequest;
 use crate::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use crate::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
+use crate::commands::scan_results::set_data_type::scan_results_set_data_type_request::ScanResultsSetDataTypeRequest;
 use crate::commands::scan_results::set_property::scan_results_set_property_request::ScanResultsSetPropertyRequest;
+use crate::commands::scan_results::set_values::scan_results_set_values_request::ScanResultsSetValuesRequest;
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
@@ -40,6 +42,16 @@ pub enum ScanResultsCommand {
         #[structopt(flatten)]
         results_set_property_request: ScanResultsSetPropertyRequest,
     },
+    /// Writes a separate value to each of a specified set of scan results.
+    SetValues {
+        #[structopt(flatten)]
+        results_set_values_request: ScanResultsSetValuesRequest,
+    },
+    /// Reinterprets a specified set of scan results as a different data type until the next scan.
+    SetDataType {
+        #[structopt(flatten)]
+        results_set_data_type_request: ScanResultsSetDataTypeRequest,
+    },
     /// Deletes a specified set of scan results.
     Delete {
         #[structopt(flatten)]
@@ -4,7 +4,9 @@ use crate::commands::scan_results::freeze::scan_results_freeze_response::ScanRes
 use crate::commands::scan_results::list::scan_results_list_response::ScanResultsListResponse;
 use crate::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
 use crate::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
+use crate::commands::scan_results::set_data_type::scan_results_set_data_type_response::ScanResultsSetDataTypeResponse;
 use crate::commands::scan_results::set_property::scan_results_set_property_response::ScanResultsSetPropertyResponse;
+use crate::commands::scan_results::set_values::scan_results_set_values_response::ScanResultsSetValuesResponse;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -27,6 +29,12 @@ pub enum ScanResultsResponse {
     SetProperty {
         scan_results_set_property_response: ScanResultsSetPropertyResponse,
     },
+    SetValues {
+        scan_results_set_values_response: ScanResultsSetValuesResponse,
+    },
+    SetDataType {
+        scan_results_set_data_type_response: ScanResultsSetDataTypeResponse,
+    },
     Delete {
         scan_results_delete_response: ScanResultsDeleteResponse,
     },
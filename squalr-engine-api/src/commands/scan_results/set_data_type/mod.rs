@@ -0,0 +1,2 @@
+pub mod scan_results_set_data_type_request;
+pub mod scan_results_set_data_type_response;
@@ -0,0 +1,37 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::commands::scan_results::set_data_type::scan_results_set_data_type_response::ScanResultsSetDataTypeResponse;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::scan_results::scan_result_ref::ScanResultRef;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Reinterprets a set of scan results as a different data type, such that their values are read and written with the width and
+/// format of the new type. Scans compare a result set as a single data type, so these conversions only last until the next scan.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanResultsSetDataTypeRequest {
+    #[structopt(short = "s", long)]
+    pub scan_result_refs: Vec<ScanResultRef>,
+    #[structopt(short = "d", long)]
+    pub data_type_ref: DataTypeRef,
+}
+
+impl PrivilegedCommandRequest for ScanResultsSetDataTypeRequest {
+    type ResponseType = ScanResultsSetDataTypeResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Results(ScanResultsCommand::SetDataType {
+            results_set_data_type_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanResultsSetDataTypeResponse> for ScanResultsResponse {
+    fn from(scan_results_set_data_type_response: ScanResultsSetDataTypeResponse) -> Self {
+        ScanResultsResponse::SetDataType {
+            scan_results_set_data_type_response,
+        }
+    }
+}
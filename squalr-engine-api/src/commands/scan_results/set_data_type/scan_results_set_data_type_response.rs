@@ -0,0 +1,33 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_result_ref::ScanResultRef;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanResultsSetDataTypeResponse {
+    /// The number of scan results now shown as the requested data type.
+    pub converted_count: u64,
+
+    /// The scan results that could not be converted, ie because they no longer resolve or the data type is unknown.
+    pub failed_scan_result_refs: Vec<ScanResultRef>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanResultsSetDataTypeResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Results(ScanResultsResponse::SetDataType {
+            scan_results_set_data_type_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Results(ScanResultsResponse::SetDataType {
+            scan_results_set_data_type_response,
+        }) = response
+        {
+            Ok(scan_results_set_data_type_response)
+        } else {
+            Err(response)
+        }
+    }
+}
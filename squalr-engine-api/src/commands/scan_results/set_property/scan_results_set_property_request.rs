@@ -15,6 +15,10 @@ pub struct ScanResultsSetPropertyRequest {
     pub anonymous_value_string: AnonymousValueString,
     #[structopt(short = "f", long)]
     pub field_namespace: String,
+    /// Whether to also write to results whose backing memory changed since it was snapshotted, which are skipped otherwise.
+    #[structopt(long)]
+    #[serde(default)]
+    pub allow_stale: bool,
 }
 
 impl PrivilegedCommandRequest for ScanResultsSetPropertyRequest {
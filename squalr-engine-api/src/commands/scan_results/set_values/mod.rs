@@ -0,0 +1,2 @@
+pub mod scan_results_set_values_request;
+pub mod scan_results_set_values_response;
@@ -0,0 +1,41 @@
+use crate::commands::privileged_command::PrivilegedCommand;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::commands::scan_results::scan_results_command::ScanResultsCommand;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::commands::scan_results::set_values::scan_results_set_values_response::ScanResultsSetValuesResponse;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::scan_results::scan_result_ref::ScanResultRef;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Writes a separate value to each scan result, where each value is interpreted as the data type of its own scan result.
+/// Values are paired with scan result refs by position.
+#[derive(Clone, StructOpt, Debug, Serialize, Deserialize)]
+pub struct ScanResultsSetValuesRequest {
+    #[structopt(short = "s", long)]
+    pub scan_result_refs: Vec<ScanResultRef>,
+    #[structopt(short = "v", long)]
+    pub anonymous_value_strings: Vec<AnonymousValueString>,
+    /// Whether to also write to results whose backing memory changed since it was snapshotted, which fail otherwise.
+    #[structopt(long)]
+    #[serde(default)]
+    pub allow_stale: bool,
+}
+
+impl PrivilegedCommandRequest for ScanResultsSetValuesRequest {
+    type ResponseType = ScanResultsSetValuesResponse;
+
+    fn to_engine_command(&self) -> PrivilegedCommand {
+        PrivilegedCommand::Results(ScanResultsCommand::SetValues {
+            results_set_values_request: self.clone(),
+        })
+    }
+}
+
+impl From<ScanResultsSetValuesResponse> for ScanResultsResponse {
+    fn from(scan_results_set_values_response: ScanResultsSetValuesResponse) -> Self {
+        ScanResultsResponse::SetValues {
+            scan_results_set_values_response,
+        }
+    }
+}
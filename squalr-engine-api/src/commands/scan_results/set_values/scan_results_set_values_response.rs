@@ -0,0 +1,30 @@
+use crate::commands::privileged_command_response::PrivilegedCommandResponse;
+use crate::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+use crate::commands::scan_results::scan_results_response::ScanResultsResponse;
+use crate::structures::scan_results::scan_result_ref::ScanResultRef;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ScanResultsSetValuesResponse {
+    /// The scan results that could not be written, ie because they no longer resolve or the value did not fit their data type.
+    pub failed_scan_result_refs: Vec<ScanResultRef>,
+}
+
+impl TypedPrivilegedCommandResponse for ScanResultsSetValuesResponse {
+    fn to_engine_response(&self) -> PrivilegedCommandResponse {
+        PrivilegedCommandResponse::Results(ScanResultsResponse::SetValues {
+            scan_results_set_values_response: self.clone(),
+        })
+    }
+
+    fn from_engine_response(response: PrivilegedCommandResponse) -> Result<Self, PrivilegedCommandResponse> {
+        if let PrivilegedCommandResponse::Results(ScanResultsResponse::SetValues {
+            scan_results_set_values_response,
+        }) = response
+        {
+            Ok(scan_results_set_values_response)
+        } else {
+            Err(response)
+        }
+    }
+}
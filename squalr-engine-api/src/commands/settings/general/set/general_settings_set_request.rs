@@ -9,8 +9,12 @@ use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Default, Serialize, Deserialize)]
 pub struct GeneralSettingsSetRequest {
-    #[structopt(short = "r_delay", long)]
+    #[structopt(short = "r", long)]
     pub engine_request_delay: Option<u64>,
+    #[structopt(long)]
+    pub last_opened_process_name: Option<String>,
+    #[structopt(long)]
+    pub auto_reattach: Option<bool>,
 }
 
 impl PrivilegedCommandRequest for GeneralSettingsSetRequest {
@@ -6,21 +6,23 @@ use crate::commands::settings::settings_command::SettingsCommand;
 use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::scanning::memory_read_mode::MemoryReadMode;
 use crate::structures::settings::scan_thread_priority::ScanThreadPriority;
+use crate::structures::settings::scan_vector_width::ScanVectorWidth;
+use crate::structures::settings::value_overflow_policy::ValueOverflowPolicy;
 use crate::{commands::privileged_command::PrivilegedCommand, structures::memory::memory_alignment::MemoryAlignment};
 use serde::{Deserialize, Serialize};
 use structopt::StructOpt;
 
 #[derive(Clone, StructOpt, Debug, Default, Serialize, Deserialize)]
 pub struct ScanSettingsSetRequest {
-    #[structopt(short = "sb_kb", long)]
+    #[structopt(short = "b", long)]
     pub scan_buffer_kb: Option<u32>,
-    #[structopt(short = "tp", long)]
+    #[structopt(short = "t", long)]
     pub thread_priority: Option<ScanThreadPriority>,
-    #[structopt(short = "fast", long)]
+    #[structopt(short = "f", long)]
     pub fast_scan_enabled: Option<bool>,
-    #[structopt(short = "fast_align", long)]
+    #[structopt(short = "a", long)]
     pub fast_scan_alignment: Option<MemoryAlignment>,
-    #[structopt(short = "fast_last", long)]
+    #[structopt(short = "l", long)]
     pub fast_scan_last_digits: Option<u8>,
     #[structopt(long)]
     pub clear_fast_scan_alignment: Option<bool>,
@@ -28,31 +30,37 @@ pub struct ScanSettingsSetRequest {
     pub clear_fast_scan_last_digits: Option<bool>,
     #[structopt(long)]
     pub clear_memory_alignment: Option<bool>,
-    #[structopt(short = "pause", long)]
+    #[structopt(short = "p", long)]
     pub pause_while_scanning: Option<bool>,
-    #[structopt(short = "repeat_delay", long)]
+    #[structopt(short = "d", long)]
     pub repeat_scan_delay_ms: Option<u64>,
     #[structopt(long)]
     pub results_page_size_auto: Option<bool>,
     #[structopt(long)]
     pub results_page_size_max: Option<u32>,
-    #[structopt(short = "psize", long)]
+    #[structopt(short = "s", long)]
     pub results_page_size: Option<u32>,
-    #[structopt(short = "r_read_interval", long)]
+    #[structopt(short = "r", long)]
     pub results_read_interval_ms: Option<u64>,
-    #[structopt(short = "p_read_interval", long)]
+    #[structopt(short = "P", long)]
     pub project_read_interval_ms: Option<u64>,
-    #[structopt(short = "f_interval", long)]
+    #[structopt(short = "F", long)]
     pub freeze_interval_ms: Option<u64>,
-    #[structopt(short = "m_align", long)]
+    #[structopt(short = "m", long)]
     pub memory_alignment: Option<MemoryAlignment>,
-    #[structopt(short = "m_read", long)]
+    #[structopt(short = "M", long)]
     pub memory_read_mode: Option<MemoryReadMode>,
-    #[structopt(short = "f_tol", long)]
+    #[structopt(short = "T", long)]
     pub floating_point_tolerance: Option<FloatingPointTolerance>,
-    #[structopt(short = "st", long)]
+    #[structopt(long)]
+    pub value_overflow_policy: Option<ValueOverflowPolicy>,
+    #[structopt(short = "S", long)]
     pub is_single_threaded_scan: Option<bool>,
-    #[structopt(short = "dbg", long)]
+    #[structopt(long)]
+    pub scan_thread_count: Option<u32>,
+    #[structopt(long)]
+    pub vector_width: Option<ScanVectorWidth>,
+    #[structopt(short = "D", long)]
     pub debug_perform_validation_scan: Option<bool>,
 }
 
@@ -118,8 +118,8 @@ impl BaseSystemConversions {
 
         padded.extend(bytes);
 
-        // Swap endian if requested.
-        if is_big_endian {
+        // The padded bytes are big-endian, so they are only swapped for little-endian types.
+        if !is_big_endian {
             padded.reverse();
         }
 
@@ -143,3 +143,24 @@ impl BaseSystemConversions {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BaseSystemConversions;
+
+    #[test]
+    fn primitive_aligned_bytes_follow_the_requested_byte_order() {
+        assert_eq!(
+            BaseSystemConversions::convert_to_primitive_aligned_bytes::<i32>("1234", 16, false).unwrap(),
+            vec![0x34, 0x12, 0x00, 0x00]
+        );
+        assert_eq!(
+            BaseSystemConversions::convert_to_primitive_aligned_bytes::<i32>("1234", 16, true).unwrap(),
+            vec![0x00, 0x00, 0x12, 0x34]
+        );
+        assert_eq!(
+            BaseSystemConversions::convert_to_primitive_aligned_bytes::<u16>("0b101", 2, false).unwrap(),
+            vec![0x05, 0x00]
+        );
+    }
+}
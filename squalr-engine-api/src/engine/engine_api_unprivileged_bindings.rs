@@ -3,7 +3,10 @@ use crate::{
         privileged_command::PrivilegedCommand, privileged_command_response::PrivilegedCommandResponse, unprivileged_command::UnprivilegedCommand,
         unprivileged_command_response::UnprivilegedCommandResponse,
     },
-    engine::engine_unprivileged_state::EngineUnprivilegedState,
+    engine::{
+        engine_unprivileged_state::EngineUnprivilegedState,
+        protocol::{engine_handshake::EngineHandshake, engine_protocol_status::EngineProtocolStatus},
+    },
     events::engine_event::EngineEvent,
 };
 use crossbeam_channel::Receiver;
@@ -28,4 +31,10 @@ pub trait EngineApiUnprivilegedBindings: Send + Sync {
 
     /// Requests to listen to all engine events.
     fn subscribe_to_engine_events(&self) -> Result<Receiver<EngineEvent>, String>;
+
+    /// Gets the result of protocol negotiation with the engine.
+    /// By default, the engine is assumed to be built into this process, in which case it is always fully compatible.
+    fn get_protocol_status(&self) -> EngineProtocolStatus {
+        EngineHandshake::current().negotiate(&EngineHandshake::current())
+    }
 }
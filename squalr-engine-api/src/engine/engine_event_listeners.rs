@@ -0,0 +1,245 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{
+        Arc, RwLock, Weak,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+};
+
+/// A type-erased callback, which downcasts the event to the type it was registered for.
+type EngineEventCallback = Box<dyn Fn(&dyn Any) + Send + Sync>;
+
+/// A callback registered for one engine event type, which checks its filter before running.
+struct EngineEventListener {
+    listener_id: u64,
+    /// Cleared when the listener is unsubscribed, such that an event already being dispatched is not delivered after the fact.
+    is_active: AtomicBool,
+    callback: EngineEventCallback,
+}
+
+/// All listeners for engine events, keyed by the event type they listen for.
+#[derive(Default)]
+pub struct EngineEventListeners {
+    next_listener_id: AtomicU64,
+    listeners: RwLock<HashMap<TypeId, Vec<Arc<EngineEventListener>>>>,
+}
+
+impl EngineEventListeners {
+    /// Registers a listener that lives as long as these listeners do.
+    pub fn add_listener<E: Any>(
+        &self,
+        callback: impl Fn(&E) + Send + Sync + 'static,
+    ) {
+        self.register_listener::<E>(|_event| true, callback);
+    }
+
+    /// Registers a listener that is only invoked for events passing the filter, and is removed once every clone of the returned
+    /// subscription is dropped. The filter is evaluated on the dispatching thread, such that uninterested listeners never run.
+    pub fn add_filtered_listener<E: Any>(
+        self: &Arc<Self>,
+        filter: impl Fn(&E) -> bool + Send + Sync + 'static,
+        callback: impl Fn(&E) + Send + Sync + 'static,
+    ) -> EngineEventSubscription {
+        let listener = self.register_listener::<E>(filter, callback);
+
+        EngineEventSubscription {
+            _engine_event_subscription_handle: Arc::new(EngineEventSubscriptionHandle {
+                engine_event_listeners: Arc::downgrade(self),
+                event_type_id: TypeId::of::<E>(),
+                listener,
+            }),
+        }
+    }
+
+    /// Dispatches an engine event to all active listeners for its event type whose filter accepts it.
+    pub fn dispatch<E: Any>(
+        &self,
+        event: &E,
+    ) {
+        // Listeners are invoked outside of the lock, such that a callback may subscribe or unsubscribe without deadlocking.
+        let listeners = match self.listeners.read() {
+            Ok(listeners) => listeners.get(&TypeId::of::<E>()).cloned().unwrap_or_default(),
+            Err(error) => {
+                log::error!("Error dispatching engine event: {}", error);
+                return;
+            }
+        };
+
+        for listener in listeners {
+            if listener.is_active.load(Ordering::Acquire) {
+                (listener.callback)(event);
+            }
+        }
+    }
+
+    /// Gets the number of listeners registered for the given event type.
+    pub fn get_listener_count<E: Any>(&self) -> usize {
+        match self.listeners.read() {
+            Ok(listeners) => listeners
+                .get(&TypeId::of::<E>())
+                .map_or(0, |listeners| listeners.len()),
+            Err(_error) => 0,
+        }
+    }
+
+    fn register_listener<E: Any>(
+        &self,
+        filter: impl Fn(&E) -> bool + Send + Sync + 'static,
+        callback: impl Fn(&E) + Send + Sync + 'static,
+    ) -> Arc<EngineEventListener> {
+        let listener = Arc::new(EngineEventListener {
+            listener_id: self.next_listener_id.fetch_add(1, Ordering::Relaxed),
+            is_active: AtomicBool::new(true),
+            callback: Box::new(move |event| {
+                if let Some(event) = event.downcast_ref::<E>().filter(|event| filter(event)) {
+                    callback(event);
+                }
+            }),
+        });
+
+        match self.listeners.write() {
+            Ok(mut listeners) => {
+                listeners
+                    .entry(TypeId::of::<E>())
+                    .or_insert_with(Vec::new)
+                    .push(listener.clone());
+            }
+            Err(error) => {
+                log::error!("Error listening for engine event: {}", error);
+            }
+        }
+
+        listener
+    }
+
+    fn remove_listener(
+        &self,
+        event_type_id: TypeId,
+        listener_id: u64,
+    ) {
+        match self.listeners.write() {
+            Ok(mut listeners) => {
+                if let Some(listeners) = listeners.get_mut(&event_type_id) {
+                    listeners.retain(|listener| listener.listener_id != listener_id);
+                }
+            }
+            Err(error) => {
+                log::error!("Error removing engine event listener: {}", error);
+            }
+        }
+    }
+}
+
+/// Keeps a listener registered until the last clone of this subscription is dropped. Views store these alongside their state,
+/// such that listeners owned by views that are torn down, ie closed pop-outs, do not outlive them.
+#[derive(Clone)]
+#[must_use = "dropping the subscription stops delivery of its events"]
+pub struct EngineEventSubscription {
+    /// Held only to be dropped, which removes the listener.
+    _engine_event_subscription_handle: Arc<EngineEventSubscriptionHandle>,
+}
+
+struct EngineEventSubscriptionHandle {
+    engine_event_listeners: Weak<EngineEventListeners>,
+    event_type_id: TypeId,
+    listener: Arc<EngineEventListener>,
+}
+
+impl Drop for EngineEventSubscriptionHandle {
+    fn drop(&mut self) {
+        self.listener.is_active.store(false, Ordering::Release);
+
+        if let Some(engine_event_listeners) = self.engine_event_listeners.upgrade() {
+            engine_event_listeners.remove_listener(self.event_type_id, self.listener.listener_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EngineEventListeners;
+    use std::sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    struct TaskEvent {
+        task_id: String,
+    }
+
+    struct OtherEvent;
+
+    #[test]
+    fn filtered_out_events_never_invoke_the_callback() {
+        let engine_event_listeners = Arc::new(EngineEventListeners::default());
+        let received_task_ids = Arc::new(Mutex::new(Vec::new()));
+        let received_task_ids_clone = received_task_ids.clone();
+        let _subscription = engine_event_listeners.add_filtered_listener::<TaskEvent>(
+            |event| event.task_id == "scan",
+            move |event| {
+                received_task_ids_clone
+                    .lock()
+                    .unwrap()
+                    .push(event.task_id.clone())
+            },
+        );
+
+        engine_event_listeners.dispatch(&TaskEvent {
+            task_id: "pointer_scan".to_string(),
+        });
+        engine_event_listeners.dispatch(&TaskEvent { task_id: "scan".to_string() });
+        engine_event_listeners.dispatch(&OtherEvent);
+
+        assert_eq!(*received_task_ids.lock().unwrap(), vec!["scan".to_string()]);
+    }
+
+    #[test]
+    fn dropping_the_last_subscription_stops_delivery() {
+        let engine_event_listeners = Arc::new(EngineEventListeners::default());
+        let invocation_count = Arc::new(AtomicUsize::new(0));
+        let invocation_count_clone = invocation_count.clone();
+        let subscription = engine_event_listeners.add_filtered_listener::<OtherEvent>(
+            |_event| true,
+            move |_event| {
+                invocation_count_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+        let subscription_clone = subscription.clone();
+
+        engine_event_listeners.dispatch(&OtherEvent);
+        drop(subscription);
+
+        // A remaining clone keeps the listener registered.
+        engine_event_listeners.dispatch(&OtherEvent);
+        assert_eq!(invocation_count.load(Ordering::SeqCst), 2);
+
+        drop(subscription_clone);
+        engine_event_listeners.dispatch(&OtherEvent);
+
+        assert_eq!(invocation_count.load(Ordering::SeqCst), 2);
+        assert_eq!(engine_event_listeners.get_listener_count::<OtherEvent>(), 0);
+    }
+
+    #[test]
+    fn listeners_may_unsubscribe_while_being_dispatched() {
+        let engine_event_listeners = Arc::new(EngineEventListeners::default());
+        let subscription_slot = Arc::new(Mutex::new(None));
+        let subscription_slot_clone = subscription_slot.clone();
+        let invocation_count = Arc::new(AtomicUsize::new(0));
+        let invocation_count_clone = invocation_count.clone();
+        let subscription = engine_event_listeners.add_filtered_listener::<OtherEvent>(
+            |_event| true,
+            move |_event| {
+                invocation_count_clone.fetch_add(1, Ordering::SeqCst);
+                subscription_slot_clone.lock().unwrap().take();
+            },
+        );
+
+        *subscription_slot.lock().unwrap() = Some(subscription);
+        engine_event_listeners.dispatch(&OtherEvent);
+        engine_event_listeners.dispatch(&OtherEvent);
+
+        assert_eq!(invocation_count.load(Ordering::SeqCst), 1);
+    }
+}
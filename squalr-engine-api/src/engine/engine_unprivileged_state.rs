@@ -1,20 +1,27 @@
 use crate::commands::{privileged_command::PrivilegedCommand, privileged_command_response::PrivilegedCommandResponse};
 use crate::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
+use crate::engine::engine_event_listeners::{EngineEventListeners, EngineEventSubscription};
 use crate::engine::logging::log_dispatcher::LogDispatcher;
+use crate::engine::protocol::engine_capabilities::EngineCapabilities;
+use crate::engine::protocol::engine_protocol_status::EngineProtocolStatus;
+use crate::events::breakpoints::breakpoints_event::BreakpointsEvent;
+use crate::events::code_patches::code_patches_event::CodePatchesEvent;
 use crate::events::engine_event::EngineEvent;
 use crate::events::engine_event::EngineEventRequest;
+use crate::events::hotkeys::hotkeys_event::HotkeysEvent;
+use crate::events::memory_watch::memory_watch_event::MemoryWatchEvent;
+use crate::events::module_symbols::module_symbols_event::ModuleSymbolsEvent;
 use crate::events::process::process_event::ProcessEvent;
 use crate::events::project::project_event::ProjectEvent;
 use crate::events::project_items::project_items_event::ProjectItemsEvent;
 use crate::events::pointer_scan_results::pointer_scan_results_event::PointerScanResultsEvent;
+use crate::events::scan_benchmark::scan_benchmark_event::ScanBenchmarkEvent;
 use crate::events::scan_results::scan_results_event::ScanResultsEvent;
 use crate::events::trackable_task::trackable_task_event::TrackableTaskEvent;
+use crate::events::value_watch::value_watch_event::ValueWatchEvent;
+use crate::events::write_sequence::write_sequence_event::WriteSequenceEvent;
 use crate::structures::projects::project_manager::ProjectManager;
-use std::{
-    any::{Any, TypeId},
-    collections::HashMap,
-    sync::{Arc, RwLock},
-};
+use std::sync::{Arc, RwLock};
 
 /// Exposes the ability to send commands to the engine, and handle events from the engine.
 pub struct EngineUnprivilegedState {
@@ -22,7 +29,7 @@ pub struct EngineUnprivilegedState {
     engine_api_unprivileged_bindings: Arc<RwLock<dyn EngineApiUnprivilegedBindings>>,
 
     /// All event listeners that are listening for particular engine events.
-    event_listeners: Arc<RwLock<HashMap<TypeId, Vec<Box<dyn Fn(&dyn Any) + Send + Sync>>>>>,
+    event_listeners: Arc<EngineEventListeners>,
 
     // Routes logs to the file system, as well as any optional subscribers to log events, such as output in the GUI.
     file_system_logger: Arc<LogDispatcher>,
@@ -36,7 +43,7 @@ impl EngineUnprivilegedState {
         let project_manager = Arc::new(ProjectManager::new());
         let engine_unprivileged_state = Arc::new(EngineUnprivilegedState {
             engine_api_unprivileged_bindings,
-            event_listeners: Arc::new(RwLock::new(HashMap::new())),
+            event_listeners: Arc::new(EngineEventListeners::default()),
             file_system_logger: Arc::new(LogDispatcher::new()),
             project_manager,
         });
@@ -57,28 +64,49 @@ impl EngineUnprivilegedState {
         &self.file_system_logger
     }
 
-    /// Registers a listener for each time a particular engine event is fired.
+    /// Registers a listener for each time a particular engine event is fired. The listener is never removed, so listeners owned by
+    /// views that can be torn down should use `listen_for_engine_event_filtered` instead.
     pub fn listen_for_engine_event<E: EngineEventRequest + 'static>(
         &self,
         callback: impl Fn(&E) + Send + Sync + 'static,
     ) {
-        match self.event_listeners.write() {
-            Ok(mut event_listeners) => {
-                let callbacks = event_listeners
-                    .entry(TypeId::of::<E>())
-                    .or_insert_with(Vec::new);
-                callbacks.push(Box::new(move |event| {
-                    if let Some(event) = event.downcast_ref::<E>() {
-                        callback(event);
-                    }
-                }));
-            }
+        self.event_listeners.add_listener::<E>(callback);
+    }
+
+    /// Registers a listener for each time a particular engine event passing the filter is fired, ie progress for one task id.
+    /// The filter runs on the event dispatcher thread, such that listeners are not woken for events they do not care about.
+    /// The listener is removed once every clone of the returned subscription is dropped.
+    pub fn listen_for_engine_event_filtered<E: EngineEventRequest + 'static>(
+        &self,
+        filter: impl Fn(&E) -> bool + Send + Sync + 'static,
+        callback: impl Fn(&E) + Send + Sync + 'static,
+    ) -> EngineEventSubscription {
+        self.event_listeners
+            .add_filtered_listener::<E>(filter, callback)
+    }
+
+    /// Gets the result of protocol negotiation with the engine.
+    pub fn get_protocol_status(&self) -> EngineProtocolStatus {
+        match self.engine_api_unprivileged_bindings.read() {
+            Ok(engine_bindings) => engine_bindings.get_protocol_status(),
             Err(error) => {
-                log::error!("Error listening for engine event: {}", error);
+                log::error!("Failed to acquire unprivileged engine bindings read lock for protocol status: {}", error);
+                EngineProtocolStatus::Pending
             }
         }
     }
 
+    /// Determines whether the engine supports all of the given optional capabilities.
+    /// Optional features must check this before sending their commands, rather than assuming the engine supports them.
+    pub fn has_capabilities(
+        &self,
+        capabilities: EngineCapabilities,
+    ) -> bool {
+        self.get_protocol_status()
+            .get_capabilities()
+            .contains(capabilities)
+    }
+
     /// Gets the project manager for this session.
     pub fn get_project_manager(&self) -> &Arc<ProjectManager> {
         &self.project_manager
@@ -138,65 +166,91 @@ impl EngineUnprivilegedState {
 
     /// Deconstructs an engine event to extract the particular event structure being sent, and routes it to the proper event listeners.
     fn route_engine_event(
-        event_listeners: &Arc<RwLock<HashMap<TypeId, Vec<Box<dyn Fn(&dyn Any) + Send + Sync>>>>>,
+        event_listeners: &EngineEventListeners,
         engine_event: EngineEvent,
     ) {
         match engine_event {
             EngineEvent::Process(process_event) => match process_event {
                 ProcessEvent::ProcessChanged { process_changed_event } => {
-                    Self::dispatch_engine_event(&event_listeners, process_changed_event);
+                    event_listeners.dispatch(&process_changed_event);
                 }
             },
             EngineEvent::Project(project_event) => match project_event {
                 ProjectEvent::ProjectClosed { project_closed_event } => {
-                    Self::dispatch_engine_event(&event_listeners, project_closed_event);
+                    event_listeners.dispatch(&project_closed_event);
                 }
                 ProjectEvent::ProjectCreated { project_created_event } => {
-                    Self::dispatch_engine_event(&event_listeners, project_created_event);
+                    event_listeners.dispatch(&project_created_event);
                 }
                 ProjectEvent::ProjectDeleted { project_deleted_event } => {
-                    Self::dispatch_engine_event(&event_listeners, project_deleted_event);
+                    event_listeners.dispatch(&project_deleted_event);
                 }
             },
             EngineEvent::ProjectItems(project_items_event) => match project_items_event {
                 ProjectItemsEvent::ProjectItemsChanged { project_items_changed_event } => {
-                    Self::dispatch_engine_event(&event_listeners, project_items_changed_event);
+                    event_listeners.dispatch(&project_items_changed_event);
                 }
             },
             EngineEvent::ScanResults(process_event) => match process_event {
                 ScanResultsEvent::ScanResultsUpdated { scan_results_updated_event } => {
-                    Self::dispatch_engine_event(&event_listeners, scan_results_updated_event);
+                    event_listeners.dispatch(&scan_results_updated_event);
                 }
             },
             EngineEvent::PointerScanResults(pointer_scan_results_event) => match pointer_scan_results_event {
-                PointerScanResultsEvent::PointerScanResultsUpdated { pointer_scan_results_updated_event } => {
-                    Self::dispatch_engine_event(&event_listeners, pointer_scan_results_updated_event);
+                PointerScanResultsEvent::PointerScanResultsUpdated {
+                    pointer_scan_results_updated_event,
+                } => {
+                    event_listeners.dispatch(&pointer_scan_results_updated_event);
+                }
+            },
+            EngineEvent::ScanBenchmark(scan_benchmark_event) => match scan_benchmark_event {
+                ScanBenchmarkEvent::ScanBenchmarkFinished { scan_benchmark_finished_event } => {
+                    event_listeners.dispatch(&scan_benchmark_finished_event);
                 }
             },
             EngineEvent::TrackableTask(trackable_task_event) => match trackable_task_event {
                 TrackableTaskEvent::ProgressChanged { progress_changed_event } => {
-                    Self::dispatch_engine_event(&event_listeners, progress_changed_event);
+                    event_listeners.dispatch(&progress_changed_event);
                 }
             },
-        }
-    }
-
-    /// Dispatches a particular engine event to all listeners for its event type.
-    fn dispatch_engine_event<E: 'static + Any + Send + Sync>(
-        event_listeners: &Arc<RwLock<HashMap<TypeId, Vec<Box<dyn Fn(&dyn Any) + Send + Sync>>>>>,
-        event: E,
-    ) {
-        match event_listeners.read() {
-            Ok(event_listeners) => {
-                if let Some(callbacks) = event_listeners.get(&TypeId::of::<E>()) {
-                    for callback in callbacks {
-                        callback(&event);
-                    }
+            EngineEvent::MemoryWatch(memory_watch_event) => match memory_watch_event {
+                MemoryWatchEvent::MemoryWatchHits { memory_watch_hits_event } => {
+                    event_listeners.dispatch(&memory_watch_hits_event);
                 }
-            }
-            Err(error) => {
-                log::error!("Error dispatching engine event: {}", error);
-            }
+                MemoryWatchEvent::MemoryWatchStopped { memory_watch_stopped_event } => {
+                    event_listeners.dispatch(&memory_watch_stopped_event);
+                }
+            },
+            EngineEvent::WriteSequence(write_sequence_event) => match write_sequence_event {
+                WriteSequenceEvent::WriteSequenceFinished { write_sequence_finished_event } => {
+                    event_listeners.dispatch(&write_sequence_finished_event);
+                }
+            },
+            EngineEvent::ModuleSymbols(module_symbols_event) => match module_symbols_event {
+                ModuleSymbolsEvent::ModuleSymbolsLoaded { module_symbols_loaded_event } => {
+                    event_listeners.dispatch(&module_symbols_loaded_event);
+                }
+            },
+            EngineEvent::ValueWatch(value_watch_event) => match value_watch_event {
+                ValueWatchEvent::ValueWatchTriggered { value_watch_triggered_event } => {
+                    event_listeners.dispatch(&value_watch_triggered_event);
+                }
+            },
+            EngineEvent::Breakpoints(breakpoints_event) => match breakpoints_event {
+                BreakpointsEvent::BreakpointsChanged { breakpoints_changed_event } => {
+                    event_listeners.dispatch(&breakpoints_changed_event);
+                }
+            },
+            EngineEvent::CodePatches(code_patches_event) => match code_patches_event {
+                CodePatchesEvent::CodePatchesChanged { code_patches_changed_event } => {
+                    event_listeners.dispatch(&code_patches_changed_event);
+                }
+            },
+            EngineEvent::Hotkeys(hotkeys_event) => match hotkeys_event {
+                HotkeysEvent::HotkeyTriggered { hotkey_triggered_event } => {
+                    event_listeners.dispatch(&hotkey_triggered_event);
+                }
+            },
         }
     }
 }
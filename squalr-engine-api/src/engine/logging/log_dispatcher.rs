@@ -1,7 +1,10 @@
 use crate::{engine::logging::log_history_appender::LogHistoryAppender, structures::logging::log_event::LogEvent};
 use log::LevelFilter;
 use log4rs::{
-    append::{console::ConsoleAppender, file::FileAppender},
+    append::{
+        console::{ConsoleAppender, Target},
+        file::FileAppender,
+    },
     config::{Appender, Config, Root},
     encode::pattern::PatternEncoder,
 };
@@ -48,7 +51,13 @@ impl LogDispatcher {
             fs::rename(&log_file, &backup_file)?;
         }
 
+        // Tools that print machine readable output to stdout move the log to stderr, such that it does not corrupt their output.
+        let console_target = match std::env::var("SQUALR_LOG_TARGET") {
+            Ok(value) if value.trim().eq_ignore_ascii_case("stderr") => Target::Stderr,
+            _ => Target::Stdout,
+        };
         let stdout = ConsoleAppender::builder()
+            .target(console_target)
             .encoder(Box::new(PatternEncoder::new("{d(%Y-%m-%d %H:%M:%S)} - {l} - {t} - {m}\n")))
             .build();
 
@@ -1,4 +1,6 @@
 pub mod engine_api_priviliged_bindings;
 pub mod engine_api_unprivileged_bindings;
+pub mod engine_event_listeners;
 pub mod engine_unprivileged_state;
+pub mod protocol;
 mod logging;
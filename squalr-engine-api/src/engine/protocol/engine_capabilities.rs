@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use std::ops::BitOr;
+
+/// A bitset of optional commands and events supported by a peer. Hosts must check these before using optional features,
+/// since sending a command that the engine does not know about cannot be deserialized on the other end.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineCapabilities {
+    bits: u64,
+}
+
+impl EngineCapabilities {
+    pub const NONE: EngineCapabilities = EngineCapabilities::from_bits(0);
+
+    /// Pointer scans, and querying their results.
+    pub const POINTER_SCAN: EngineCapabilities = EngineCapabilities::from_bits(1 << 0);
+
+    /// Struct scans.
+    pub const STRUCT_SCAN: EngineCapabilities = EngineCapabilities::from_bits(1 << 1);
+
+    /// Counting how many element scan results a scan would keep, without performing the scan.
+    pub const ELEMENT_SCAN_PREVIEW: EngineCapabilities = EngineCapabilities::from_bits(1 << 2);
+
+    /// Querying the ledger of memory modifications made this session, and restoring them.
+    pub const MEMORY_LEDGER: EngineCapabilities = EngineCapabilities::from_bits(1 << 3);
+
+    /// Watching which instructions access an address, and the events reporting them.
+    pub const MEMORY_WATCH: EngineCapabilities = EngineCapabilities::from_bits(1 << 4);
+
+    /// Running timed sequences of writes and freeze toggles, and the event reporting when they finish.
+    pub const WRITE_SEQUENCE: EngineCapabilities = EngineCapabilities::from_bits(1 << 5);
+
+    /// Resolving addresses to module symbols, and the event reporting when a module's symbols finish loading.
+    pub const MODULE_SYMBOLS: EngineCapabilities = EngineCapabilities::from_bits(1 << 6);
+
+    /// Stride scans, which find arrays of structs by a field repeating at a fixed stride.
+    pub const STRIDE_SCAN: EngineCapabilities = EngineCapabilities::from_bits(1 << 7);
+
+    /// Notifying when watched values change, and the event reporting when a watch triggers.
+    pub const VALUE_WATCH: EngineCapabilities = EngineCapabilities::from_bits(1 << 8);
+
+    /// Resolving user entered address expressions against the opened process.
+    pub const ADDRESS_RESOLVE: EngineCapabilities = EngineCapabilities::from_bits(1 << 9);
+
+    /// Setting and removing software breakpoints, and the event reporting when they change.
+    pub const BREAKPOINTS: EngineCapabilities = EngineCapabilities::from_bits(1 << 10);
+
+    /// Assembling instructions, patching and restoring code, and the event reporting when code patches change.
+    pub const CODE_PATCHES: EngineCapabilities = EngineCapabilities::from_bits(1 << 11);
+
+    /// Element scans for an unknown initial value, which keep every element without comparing.
+    pub const UNKNOWN_INITIAL_VALUE_SCAN: EngineCapabilities = EngineCapabilities::from_bits(1 << 12);
+
+    /// Listing the scans that element scan results can be reverted to, and reverting to them.
+    pub const SCAN_HISTORY: EngineCapabilities = EngineCapabilities::from_bits(1 << 13);
+
+    /// Saving element scan results to a file, and comparing against the values saved in one.
+    pub const SCAN_SNAPSHOT_FILES: EngineCapabilities = EngineCapabilities::from_bits(1 << 14);
+
+    /// Registering chords that are detected regardless of which window has focus, and the event reporting when one is pressed.
+    /// Only Windows can detect chords this way, so this is not supported on other platforms.
+    pub const GLOBAL_HOTKEYS: EngineCapabilities = EngineCapabilities::from_bits(1 << 15);
+
+    /// Scan thread count and vector width settings, benchmarking scans with each of them, and the event reporting the results.
+    pub const SCAN_BENCHMARK: EngineCapabilities = EngineCapabilities::from_bits(1 << 16);
+
+    /// All capabilities supported by this build.
+    pub const CURRENT: EngineCapabilities = EngineCapabilities::from_bits(
+        Self::POINTER_SCAN.bits()
+            | Self::STRUCT_SCAN.bits()
+            | Self::ELEMENT_SCAN_PREVIEW.bits()
+            | Self::MEMORY_LEDGER.bits()
+            | Self::MEMORY_WATCH.bits()
+            | Self::WRITE_SEQUENCE.bits()
+            | Self::MODULE_SYMBOLS.bits()
+            | Self::STRIDE_SCAN.bits()
+            | Self::VALUE_WATCH.bits()
+            | Self::ADDRESS_RESOLVE.bits()
+            | Self::BREAKPOINTS.bits()
+            | Self::CODE_PATCHES.bits()
+            | Self::UNKNOWN_INITIAL_VALUE_SCAN.bits()
+            | Self::SCAN_HISTORY.bits()
+            | Self::SCAN_SNAPSHOT_FILES.bits()
+            | Self::SCAN_BENCHMARK.bits()
+            | if cfg!(windows) { Self::GLOBAL_HOTKEYS.bits() } else { 0 },
+    );
+
+    pub const fn from_bits(bits: u64) -> Self {
+        Self { bits }
+    }
+
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Determines whether all of the given capabilities are present.
+    pub const fn contains(
+        &self,
+        capabilities: EngineCapabilities,
+    ) -> bool {
+        self.bits & capabilities.bits == capabilities.bits
+    }
+
+    /// Gets the capabilities present in both sets.
+    pub const fn intersection(
+        &self,
+        capabilities: EngineCapabilities,
+    ) -> Self {
+        Self::from_bits(self.bits & capabilities.bits)
+    }
+}
+
+impl BitOr for EngineCapabilities {
+    type Output = EngineCapabilities;
+
+    fn bitor(
+        self,
+        other: EngineCapabilities,
+    ) -> EngineCapabilities {
+        EngineCapabilities::from_bits(self.bits | other.bits)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::engine::protocol::{
+    engine_capabilities::EngineCapabilities, engine_protocol_status::EngineProtocolStatus, engine_protocol_version::EngineProtocolVersion,
+};
+use serde::{Deserialize, Serialize};
+
+/// Exchanged by both peers when bindings connect, before any other traffic.
+///
+/// Unlike every other serialized structure, this one must never change shape across protocol versions, since it is the only
+/// means by which mismatched peers are able to detect each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineHandshake {
+    pub protocol_version: EngineProtocolVersion,
+    pub capabilities: EngineCapabilities,
+}
+
+impl EngineHandshake {
+    /// Creates the handshake describing this build.
+    pub fn current() -> Self {
+        Self {
+            protocol_version: EngineProtocolVersion::CURRENT,
+            capabilities: EngineCapabilities::CURRENT,
+        }
+    }
+
+    /// Negotiates against the handshake received from the remote peer.
+    pub fn negotiate(
+        &self,
+        remote_handshake: &EngineHandshake,
+    ) -> EngineProtocolStatus {
+        if !self
+            .protocol_version
+            .is_compatible_with(&remote_handshake.protocol_version)
+        {
+            return EngineProtocolStatus::Incompatible {
+                local_version: self.protocol_version,
+                remote_version: remote_handshake.protocol_version,
+            };
+        }
+
+        EngineProtocolStatus::Connected {
+            remote_version: remote_handshake.protocol_version,
+            capabilities: self.capabilities.intersection(remote_handshake.capabilities),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EngineHandshake;
+    use crate::engine::protocol::{
+        engine_capabilities::EngineCapabilities, engine_protocol_status::EngineProtocolStatus, engine_protocol_version::EngineProtocolVersion,
+    };
+
+    #[test]
+    fn negotiate_rejects_major_mismatch() {
+        let local_handshake = EngineHandshake::current();
+        let remote_handshake = EngineHandshake {
+            protocol_version: EngineProtocolVersion::new(EngineProtocolVersion::CURRENT.major + 1, 0),
+            capabilities: EngineCapabilities::CURRENT,
+        };
+
+        let protocol_status = local_handshake.negotiate(&remote_handshake);
+
+        assert_eq!(
+            protocol_status,
+            EngineProtocolStatus::Incompatible {
+                local_version: EngineProtocolVersion::CURRENT,
+                remote_version: remote_handshake.protocol_version,
+            }
+        );
+        assert_eq!(protocol_status.get_capabilities(), EngineCapabilities::NONE);
+    }
+
+    #[test]
+    fn negotiate_accepts_minor_mismatch_with_shared_capabilities() {
+        let local_handshake = EngineHandshake::current();
+        let remote_handshake = EngineHandshake {
+            protocol_version: EngineProtocolVersion::new(EngineProtocolVersion::CURRENT.major, EngineProtocolVersion::CURRENT.minor + 1),
+            capabilities: EngineCapabilities::POINTER_SCAN | EngineCapabilities::from_bits(1 << 63),
+        };
+
+        let capabilities = local_handshake.negotiate(&remote_handshake).get_capabilities();
+
+        assert!(capabilities.contains(EngineCapabilities::POINTER_SCAN));
+        assert!(!capabilities.contains(EngineCapabilities::ELEMENT_SCAN_PREVIEW));
+        assert!(!capabilities.contains(EngineCapabilities::from_bits(1 << 63)));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::engine::protocol::{engine_capabilities::EngineCapabilities, engine_protocol_version::EngineProtocolVersion};
+
+/// The result of protocol negotiation between a host and the engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EngineProtocolStatus {
+    /// The engine has not yet answered the handshake.
+    Pending,
+
+    /// The engine speaks a compatible protocol. Capabilities are those supported by both peers.
+    Connected {
+        remote_version: EngineProtocolVersion,
+        capabilities: EngineCapabilities,
+    },
+
+    /// The engine speaks an incompatible protocol, and no commands may be exchanged with it.
+    Incompatible {
+        local_version: EngineProtocolVersion,
+        remote_version: EngineProtocolVersion,
+    },
+}
+
+impl EngineProtocolStatus {
+    /// Gets the capabilities usable with the engine, which are empty unless negotiation succeeded.
+    pub fn get_capabilities(&self) -> EngineCapabilities {
+        match self {
+            EngineProtocolStatus::Connected { capabilities, .. } => *capabilities,
+            EngineProtocolStatus::Pending | EngineProtocolStatus::Incompatible { .. } => EngineCapabilities::NONE,
+        }
+    }
+
+    pub fn is_incompatible(&self) -> bool {
+        matches!(self, EngineProtocolStatus::Incompatible { .. })
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The version of the serialized command protocol spoken between a host (GUI, CLI) and the engine.
+///
+/// By convention, bump the major version whenever any serialized command, response, event, or structure changes shape, since
+/// peers deserialize positionally and cannot detect this on their own. Bump the minor version when only adding new optional
+/// commands or events, which must also be advertised with an `EngineCapabilities` flag so that older peers are never sent them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineProtocolVersion {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl EngineProtocolVersion {
+    pub const CURRENT: EngineProtocolVersion = EngineProtocolVersion { major: 4, minor: 0 };
+
+    pub const fn new(
+        major: u16,
+        minor: u16,
+    ) -> Self {
+        Self { major, minor }
+    }
+
+    /// Determines whether two peers can safely exchange commands. Minor versions only add capability-gated commands, so only the major version must match.
+    pub fn is_compatible_with(
+        &self,
+        other: &EngineProtocolVersion,
+    ) -> bool {
+        self.major == other.major
+    }
+}
+
+impl fmt::Display for EngineProtocolVersion {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "{}.{}", self.major, self.minor)
+    }
+}
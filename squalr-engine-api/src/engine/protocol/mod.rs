@@ -0,0 +1,4 @@
+pub mod engine_capabilities;
+pub mod engine_handshake;
+pub mod engine_protocol_status;
+pub mod engine_protocol_version;
@@ -0,0 +1,7 @@
+use crate::events::breakpoints::changed::breakpoints_changed_event::BreakpointsChangedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BreakpointsEvent {
+    BreakpointsChanged { breakpoints_changed_event: BreakpointsChangedEvent },
+}
@@ -0,0 +1,20 @@
+use crate::events::{
+    breakpoints::breakpoints_event::BreakpointsEvent,
+    engine_event::{EngineEvent, EngineEventRequest},
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired when a software breakpoint is set or removed, such that every view showing breakpoints stays in sync.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BreakpointsChangedEvent {
+    /// The addresses of all active breakpoints, in ascending order.
+    pub breakpoint_addresses: Vec<u64>,
+}
+
+impl EngineEventRequest for BreakpointsChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Breakpoints(BreakpointsEvent::BreakpointsChanged {
+            breakpoints_changed_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1 @@
+pub mod breakpoints_changed_event;
@@ -0,0 +1,2 @@
+pub mod breakpoints_event;
+pub mod changed;
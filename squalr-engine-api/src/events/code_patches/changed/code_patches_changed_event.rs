@@ -0,0 +1,21 @@
+use crate::events::{
+    code_patches::code_patches_event::CodePatchesEvent,
+    engine_event::{EngineEvent, EngineEventRequest},
+};
+use crate::structures::memory::code_patch::CodePatch;
+use serde::{Deserialize, Serialize};
+
+/// Fired when code is patched or restored, such that every view showing code patches stays in sync.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CodePatchesChangedEvent {
+    /// All code patches, in ascending order of address.
+    pub code_patches: Vec<CodePatch>,
+}
+
+impl EngineEventRequest for CodePatchesChangedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::CodePatches(CodePatchesEvent::CodePatchesChanged {
+            code_patches_changed_event: self.clone(),
+        })
+    }
+}
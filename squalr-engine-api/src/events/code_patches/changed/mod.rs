@@ -0,0 +1 @@
+pub mod code_patches_changed_event;
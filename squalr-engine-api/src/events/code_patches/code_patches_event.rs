@@ -0,0 +1,7 @@
+use crate::events::code_patches::changed::code_patches_changed_event::CodePatchesChangedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum CodePatchesEvent {
+    CodePatchesChanged { code_patches_changed_event: CodePatchesChangedEvent },
+}
@@ -0,0 +1,2 @@
+pub mod changed;
+pub mod code_patches_event;
@@ -1,9 +1,17 @@
+use crate::events::breakpoints::breakpoints_event::BreakpointsEvent;
+use crate::events::code_patches::code_patches_event::CodePatchesEvent;
+use crate::events::hotkeys::hotkeys_event::HotkeysEvent;
+use crate::events::memory_watch::memory_watch_event::MemoryWatchEvent;
+use crate::events::module_symbols::module_symbols_event::ModuleSymbolsEvent;
 use crate::events::process::process_event::ProcessEvent;
 use crate::events::project::project_event::ProjectEvent;
 use crate::events::project_items::project_items_event::ProjectItemsEvent;
 use crate::events::pointer_scan_results::pointer_scan_results_event::PointerScanResultsEvent;
+use crate::events::scan_benchmark::scan_benchmark_event::ScanBenchmarkEvent;
 use crate::events::scan_results::scan_results_event::ScanResultsEvent;
 use crate::events::trackable_task::trackable_task_event::TrackableTaskEvent;
+use crate::events::value_watch::value_watch_event::ValueWatchEvent;
+use crate::events::write_sequence::write_sequence_event::WriteSequenceEvent;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -14,6 +22,14 @@ pub enum EngineEvent {
     TrackableTask(TrackableTaskEvent),
     ScanResults(ScanResultsEvent),
     PointerScanResults(PointerScanResultsEvent),
+    ScanBenchmark(ScanBenchmarkEvent),
+    MemoryWatch(MemoryWatchEvent),
+    WriteSequence(WriteSequenceEvent),
+    ModuleSymbols(ModuleSymbolsEvent),
+    ValueWatch(ValueWatchEvent),
+    Breakpoints(BreakpointsEvent),
+    CodePatches(CodePatchesEvent),
+    Hotkeys(HotkeysEvent),
 }
 
 pub trait EngineEventRequest: Clone + Serialize + DeserializeOwned {
@@ -0,0 +1,7 @@
+use crate::events::hotkeys::triggered::hotkey_triggered_event::HotkeyTriggeredEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum HotkeysEvent {
+    HotkeyTriggered { hotkey_triggered_event: HotkeyTriggeredEvent },
+}
@@ -0,0 +1,2 @@
+pub mod hotkeys_event;
+pub mod triggered;
@@ -0,0 +1,21 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    hotkeys::hotkeys_event::HotkeysEvent,
+};
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use serde::{Deserialize, Serialize};
+
+/// Fired when a registered chord is pressed, regardless of which window has focus. The engine only knows the chord, so the host
+/// looks up what the chord is bound to.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HotkeyTriggeredEvent {
+    pub chord: HotkeyChord,
+}
+
+impl EngineEventRequest for HotkeyTriggeredEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::Hotkeys(HotkeysEvent::HotkeyTriggered {
+            hotkey_triggered_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    memory_watch::memory_watch_event::MemoryWatchEvent,
+};
+use crate::structures::memory::memory_watch_hit::MemoryWatchHit;
+use serde::{Deserialize, Serialize};
+
+/// Reports the accesses observed by a memory watch since its previous report. Hit counts are deltas, not running totals.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryWatchHitsEvent {
+    pub watch_id: u64,
+    pub hits: Vec<MemoryWatchHit>,
+}
+
+impl EngineEventRequest for MemoryWatchHitsEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::MemoryWatch(MemoryWatchEvent::MemoryWatchHits {
+            memory_watch_hits_event: self.clone(),
+        })
+    }
+}
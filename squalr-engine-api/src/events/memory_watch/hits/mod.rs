@@ -0,0 +1 @@
+pub mod memory_watch_hits_event;
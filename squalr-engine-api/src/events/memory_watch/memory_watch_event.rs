@@ -0,0 +1,9 @@
+use crate::events::memory_watch::hits::memory_watch_hits_event::MemoryWatchHitsEvent;
+use crate::events::memory_watch::stopped::memory_watch_stopped_event::MemoryWatchStoppedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MemoryWatchEvent {
+    MemoryWatchHits { memory_watch_hits_event: MemoryWatchHitsEvent },
+    MemoryWatchStopped { memory_watch_stopped_event: MemoryWatchStoppedEvent },
+}
@@ -0,0 +1,3 @@
+pub mod hits;
+pub mod memory_watch_event;
+pub mod stopped;
@@ -0,0 +1,20 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    memory_watch::memory_watch_event::MemoryWatchEvent,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired once a memory watch has detached, whether it was stopped on request, the process exited, or the debugger failed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemoryWatchStoppedEvent {
+    pub watch_id: u64,
+    pub reason: String,
+}
+
+impl EngineEventRequest for MemoryWatchStoppedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::MemoryWatch(MemoryWatchEvent::MemoryWatchStopped {
+            memory_watch_stopped_event: self.clone(),
+        })
+    }
+}
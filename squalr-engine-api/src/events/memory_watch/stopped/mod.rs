@@ -0,0 +1 @@
+pub mod memory_watch_stopped_event;
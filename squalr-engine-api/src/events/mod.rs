@@ -1,7 +1,15 @@
+pub mod breakpoints;
+pub mod code_patches;
 pub mod engine_event;
+pub mod hotkeys;
+pub mod memory_watch;
+pub mod module_symbols;
 pub mod process;
 pub mod project;
 pub mod project_items;
 pub mod pointer_scan_results;
+pub mod scan_benchmark;
 pub mod scan_results;
 pub mod trackable_task;
+pub mod value_watch;
+pub mod write_sequence;
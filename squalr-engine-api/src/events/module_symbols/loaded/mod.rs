@@ -0,0 +1 @@
+pub mod module_symbols_loaded_event;
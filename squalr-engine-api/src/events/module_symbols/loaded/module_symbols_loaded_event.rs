@@ -0,0 +1,19 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    module_symbols::module_symbols_event::ModuleSymbolsEvent,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired once the exports of a module have been parsed, such that addresses within it can now be resolved to symbols.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ModuleSymbolsLoadedEvent {
+    pub module_name: String,
+}
+
+impl EngineEventRequest for ModuleSymbolsLoadedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::ModuleSymbols(ModuleSymbolsEvent::ModuleSymbolsLoaded {
+            module_symbols_loaded_event: self.clone(),
+        })
+    }
+}
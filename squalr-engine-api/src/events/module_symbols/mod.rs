@@ -0,0 +1,2 @@
+pub mod loaded;
+pub mod module_symbols_event;
@@ -0,0 +1,7 @@
+use crate::events::module_symbols::loaded::module_symbols_loaded_event::ModuleSymbolsLoadedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ModuleSymbolsEvent {
+    ModuleSymbolsLoaded { module_symbols_loaded_event: ModuleSymbolsLoadedEvent },
+}
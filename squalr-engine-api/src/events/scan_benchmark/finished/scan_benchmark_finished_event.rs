@@ -0,0 +1,22 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    scan_benchmark::scan_benchmark_event::ScanBenchmarkEvent,
+};
+use crate::structures::scanning::benchmarks::scan_configuration_benchmark::ScanConfigurationBenchmark;
+use serde::{Deserialize, Serialize};
+
+/// Fired once a scan benchmark has stopped, with the configurations measured before it finished or was cancelled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanBenchmarkFinishedEvent {
+    pub task_id: String,
+    pub scan_configuration_benchmarks: Vec<ScanConfigurationBenchmark>,
+    pub was_cancelled: bool,
+}
+
+impl EngineEventRequest for ScanBenchmarkFinishedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::ScanBenchmark(ScanBenchmarkEvent::ScanBenchmarkFinished {
+            scan_benchmark_finished_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod finished;
+pub mod scan_benchmark_event;
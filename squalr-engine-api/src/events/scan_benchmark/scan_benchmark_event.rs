@@ -0,0 +1,9 @@
+use crate::events::scan_benchmark::finished::scan_benchmark_finished_event::ScanBenchmarkFinishedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ScanBenchmarkEvent {
+    ScanBenchmarkFinished {
+        scan_benchmark_finished_event: ScanBenchmarkFinishedEvent,
+    },
+}
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ScanResultsUpdatedEvent {
     pub is_new_scan: bool,
+
+    /// The scan generation of the results after this update. Query and refresh responses carry the same id.
+    pub scan_generation: u64,
 }
 
 impl EngineEventRequest for ScanResultsUpdatedEvent {
@@ -8,6 +8,9 @@ use serde::{Deserialize, Serialize};
 pub struct TrackableTaskProgressChangedEvent {
     pub task_id: String,
     pub progress: f32,
+    /// The memory held by the task, for tasks that track it.
+    #[serde(default)]
+    pub memory_usage_bytes: Option<u64>,
 }
 
 impl EngineEventRequest for TrackableTaskProgressChangedEvent {
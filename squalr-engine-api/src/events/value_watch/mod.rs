@@ -0,0 +1,2 @@
+pub mod triggered;
+pub mod value_watch_event;
@@ -0,0 +1 @@
+pub mod value_watch_triggered_event;
@@ -0,0 +1,26 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    value_watch::value_watch_event::ValueWatchEvent,
+};
+use crate::structures::data_values::data_value::DataValue;
+use serde::{Deserialize, Serialize};
+
+/// Fired when a watched value changes in a way that meets the condition of its watch.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValueWatchTriggeredEvent {
+    pub watch_id: u64,
+    pub label: String,
+    /// The address of the value, relative to the module if a module name is given.
+    pub address: u64,
+    pub module_name: String,
+    pub previous_value: DataValue,
+    pub current_value: DataValue,
+}
+
+impl EngineEventRequest for ValueWatchTriggeredEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::ValueWatch(ValueWatchEvent::ValueWatchTriggered {
+            value_watch_triggered_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,7 @@
+use crate::events::value_watch::triggered::value_watch_triggered_event::ValueWatchTriggeredEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ValueWatchEvent {
+    ValueWatchTriggered { value_watch_triggered_event: ValueWatchTriggeredEvent },
+}
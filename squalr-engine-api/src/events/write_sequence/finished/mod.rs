@@ -0,0 +1 @@
+pub mod write_sequence_finished_event;
@@ -0,0 +1,22 @@
+use crate::events::{
+    engine_event::{EngineEvent, EngineEventRequest},
+    write_sequence::write_sequence_event::WriteSequenceEvent,
+};
+use serde::{Deserialize, Serialize};
+
+/// Fired once a write sequence has stopped, whether it ran to the end, was cancelled, or was aborted by a failed step.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WriteSequenceFinishedEvent {
+    pub task_id: String,
+    pub completed_step_count: usize,
+    pub failed_step_indices: Vec<usize>,
+    pub was_cancelled: bool,
+}
+
+impl EngineEventRequest for WriteSequenceFinishedEvent {
+    fn to_engine_event(&self) -> EngineEvent {
+        EngineEvent::WriteSequence(WriteSequenceEvent::WriteSequenceFinished {
+            write_sequence_finished_event: self.clone(),
+        })
+    }
+}
@@ -0,0 +1,2 @@
+pub mod finished;
+pub mod write_sequence_event;
@@ -0,0 +1,9 @@
+use crate::events::write_sequence::finished::write_sequence_finished_event::WriteSequenceFinishedEvent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum WriteSequenceEvent {
+    WriteSequenceFinished {
+        write_sequence_finished_event: WriteSequenceFinishedEvent,
+    },
+}
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+/// Contains all software breakpoints set in the opened process, along with the original byte that each `int3` was patched over.
+/// Breakpoints only apply to the process they were set in, so they are discarded when a different process is bound.
+pub struct BreakpointListRegistry {
+    process_id: Option<u32>,
+    original_bytes: BTreeMap<u64, u8>,
+}
+
+impl BreakpointListRegistry {
+    pub fn new() -> Self {
+        Self {
+            process_id: None,
+            original_bytes: BTreeMap::new(),
+        }
+    }
+
+    /// Associates the registry with the given process, discarding all breakpoints if this differs from the previous process.
+    pub fn bind_process(
+        &mut self,
+        process_id: Option<u32>,
+    ) {
+        if self.process_id != process_id {
+            self.original_bytes.clear();
+            self.process_id = process_id;
+        }
+    }
+
+    /// Gets the addresses of all breakpoints, in ascending order.
+    pub fn get_breakpoint_addresses(&self) -> Vec<u64> {
+        self.original_bytes.keys().copied().collect()
+    }
+
+    pub fn has_breakpoint(
+        &self,
+        address: u64,
+    ) -> bool {
+        self.original_bytes.contains_key(&address)
+    }
+
+    /// Records a breakpoint and the original byte it was patched over.
+    pub fn add_breakpoint(
+        &mut self,
+        address: u64,
+        original_byte: u8,
+    ) {
+        self.original_bytes.insert(address, original_byte);
+    }
+
+    /// Forgets a breakpoint. Returns the original byte to restore, or `None` if no breakpoint was set at the address.
+    pub fn remove_breakpoint(
+        &mut self,
+        address: u64,
+    ) -> Option<u8> {
+        self.original_bytes.remove(&address)
+    }
+}
+
+impl Default for BreakpointListRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BreakpointListRegistry;
+
+    #[test]
+    fn breakpoints_keep_their_original_bytes_until_the_process_changes() {
+        let mut breakpoint_list_registry = BreakpointListRegistry::new();
+
+        breakpoint_list_registry.bind_process(Some(1));
+        breakpoint_list_registry.add_breakpoint(0x2000, 0x55);
+        breakpoint_list_registry.add_breakpoint(0x1000, 0x48);
+        breakpoint_list_registry.bind_process(Some(1));
+
+        assert_eq!(breakpoint_list_registry.get_breakpoint_addresses(), vec![0x1000, 0x2000]);
+        assert_eq!(breakpoint_list_registry.remove_breakpoint(0x2000), Some(0x55));
+        assert_eq!(breakpoint_list_registry.remove_breakpoint(0x2000), None);
+
+        breakpoint_list_registry.bind_process(Some(2));
+
+        assert!(!breakpoint_list_registry.has_breakpoint(0x1000));
+        assert!(breakpoint_list_registry.get_breakpoint_addresses().is_empty());
+    }
+}
@@ -0,0 +1 @@
+pub mod breakpoint_list_registry;
@@ -0,0 +1,182 @@
+use crate::structures::memory::code_patch::CodePatch;
+use std::collections::BTreeMap;
+
+/// Contains all code patches applied to the opened process, keyed by their address. Patches never overlap, since patching within
+/// a patch updates it in place. Patches only apply to the process they were made in, so they are discarded when a different
+/// process is bound.
+pub struct CodePatchListRegistry {
+    process_id: Option<u32>,
+    code_patches: BTreeMap<u64, CodePatch>,
+}
+
+impl CodePatchListRegistry {
+    pub fn new() -> Self {
+        Self {
+            process_id: None,
+            code_patches: BTreeMap::new(),
+        }
+    }
+
+    /// Associates the registry with the given process, discarding all patches if this differs from the previous process.
+    pub fn bind_process(
+        &mut self,
+        process_id: Option<u32>,
+    ) {
+        if self.process_id != process_id {
+            self.clear();
+            self.process_id = process_id;
+        }
+    }
+
+    /// Forgets all patches without writing their original code back, such as once the modification ledger has restored it.
+    pub fn clear(&mut self) {
+        self.code_patches.clear();
+    }
+
+    /// Gets all code patches, in ascending order of address.
+    pub fn get_code_patches(&self) -> Vec<CodePatch> {
+        self.code_patches.values().cloned().collect()
+    }
+
+    /// Gets the patch containing the given address, if any.
+    pub fn find_code_patch(
+        &self,
+        address: u64,
+    ) -> Option<&CodePatch> {
+        self.code_patches
+            .range(..=address)
+            .next_back()
+            .map(|(_, code_patch)| code_patch)
+            .filter(|code_patch| code_patch.contains_address(address))
+    }
+
+    /// Gets the first patch sharing any byte with the given range, if any.
+    pub fn find_overlapping_code_patch(
+        &self,
+        address: u64,
+        length: u64,
+    ) -> Option<&CodePatch> {
+        let end_address = address.saturating_add(length);
+
+        self.find_code_patch(address).or_else(|| {
+            self.code_patches
+                .range(address..end_address)
+                .next()
+                .map(|(_, code_patch)| code_patch)
+        })
+    }
+
+    /// Records a new patch. The caller is responsible for ensuring it does not overlap an existing patch.
+    pub fn add_code_patch(
+        &mut self,
+        code_patch: CodePatch,
+    ) {
+        self.code_patches.insert(code_patch.address, code_patch);
+    }
+
+    /// Replaces patched bytes within an existing patch, keeping its original bytes. Returns false without changes if the bytes do not
+    /// fall entirely within a single patch.
+    pub fn update_code_patch(
+        &mut self,
+        address: u64,
+        patched_bytes: &[u8],
+    ) -> bool {
+        let patch_address = match self.find_code_patch(address) {
+            Some(code_patch) if address.saturating_add(patched_bytes.len() as u64) <= code_patch.get_end_address() => code_patch.address,
+            _ => return false,
+        };
+
+        match self.code_patches.get_mut(&patch_address) {
+            Some(code_patch) => {
+                let offset = (address - patch_address) as usize;
+
+                code_patch.patched_bytes[offset..offset + patched_bytes.len()].copy_from_slice(patched_bytes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forgets the patch containing the given address. Returns the patch, such that its original bytes can be restored, or `None`
+    /// if no patch contains the address.
+    pub fn remove_code_patch(
+        &mut self,
+        address: u64,
+    ) -> Option<CodePatch> {
+        let patch_address = self.find_code_patch(address)?.address;
+
+        self.code_patches.remove(&patch_address)
+    }
+}
+
+impl Default for CodePatchListRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CodePatchListRegistry;
+    use crate::structures::memory::code_patch::CodePatch;
+
+    fn make_patch(
+        address: u64,
+        length: usize,
+    ) -> CodePatch {
+        CodePatch {
+            address,
+            original_bytes: vec![0xCC; length],
+            patched_bytes: vec![0x90; length],
+        }
+    }
+
+    #[test]
+    fn patches_are_found_by_any_address_they_contain() {
+        let mut code_patch_list_registry = CodePatchListRegistry::new();
+
+        code_patch_list_registry.bind_process(Some(1));
+        code_patch_list_registry.add_code_patch(make_patch(0x1010, 4));
+        code_patch_list_registry.add_code_patch(make_patch(0x1000, 2));
+
+        assert_eq!(
+            code_patch_list_registry
+                .find_code_patch(0x1013)
+                .map(|code_patch| code_patch.address),
+            Some(0x1010)
+        );
+        assert_eq!(code_patch_list_registry.find_code_patch(0x1014), None);
+        assert_eq!(code_patch_list_registry.find_code_patch(0x1002), None);
+        assert_eq!(
+            code_patch_list_registry
+                .find_overlapping_code_patch(0x1008, 0x10)
+                .map(|code_patch| code_patch.address),
+            Some(0x1010)
+        );
+        assert_eq!(code_patch_list_registry.find_overlapping_code_patch(0x1002, 0x0E), None);
+        assert_eq!(code_patch_list_registry.remove_code_patch(0x1001), Some(make_patch(0x1000, 2)));
+        assert_eq!(code_patch_list_registry.get_code_patches(), vec![make_patch(0x1010, 4)]);
+
+        code_patch_list_registry.bind_process(Some(2));
+
+        assert!(code_patch_list_registry.get_code_patches().is_empty());
+    }
+
+    #[test]
+    fn updating_a_patch_keeps_its_original_bytes() {
+        let mut code_patch_list_registry = CodePatchListRegistry::new();
+
+        code_patch_list_registry.add_code_patch(make_patch(0x1000, 4));
+
+        assert!(code_patch_list_registry.update_code_patch(0x1001, &[0xEB, 0x00]));
+        assert!(!code_patch_list_registry.update_code_patch(0x1003, &[0xEB, 0x00]));
+        assert_eq!(
+            code_patch_list_registry.get_code_patches(),
+            vec![CodePatch {
+                address: 0x1000,
+                original_bytes: vec![0xCC; 4],
+                patched_bytes: vec![0x90, 0xEB, 0x00, 0x90],
+            }]
+        );
+    }
+}
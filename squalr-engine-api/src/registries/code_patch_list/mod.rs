@@ -0,0 +1 @@
+pub mod code_patch_list_registry;
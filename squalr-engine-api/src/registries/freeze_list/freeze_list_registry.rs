@@ -1,20 +1,33 @@
+use crate::registries::freeze_list::frozen_value::FrozenValue;
 use crate::structures::memory::pointer::Pointer;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct FreezeListRegistry {
-    frozen_pointers: HashMap<Pointer, Vec<u8>>,
+    frozen_pointers: HashMap<Pointer, FrozenValue>,
+    unreadable_pointers: HashSet<Pointer>,
+    disabled_pointers: HashSet<Pointer>,
+    revision: u64,
 }
 
 /// Contains all indicies that the user has marked as frozen in the scan results list.
 /// Frozen refers to wriiting a specified value to an address repeatedly within a timer, 'freezing' it to the original value.
+///
+/// The freeze task holds the lock on this registry for the whole of each freeze pass, so every change below lands either entirely
+/// before or entirely after a pass. Changes are applied in the order the engine receives them, with the last one winning:
+/// - Freezing an address that is already frozen replaces the value it is frozen to.
+/// - Writing to a frozen address through `write_address` replaces the value it is frozen to, rather than being undone by the next pass.
+/// - Unfreezing an address stops the freeze task from writing to it, such that a write issued afterwards is never overwritten.
 impl FreezeListRegistry {
     pub fn new() -> Self {
         Self {
             frozen_pointers: HashMap::new(),
+            unreadable_pointers: HashSet::new(),
+            disabled_pointers: HashSet::new(),
+            revision: 0,
         }
     }
 
-    pub fn get_frozen_pointers(&self) -> &HashMap<Pointer, Vec<u8>> {
+    pub fn get_frozen_pointers(&self) -> &HashMap<Pointer, FrozenValue> {
         &self.frozen_pointers
     }
 
@@ -29,31 +42,170 @@ impl FreezeListRegistry {
         &self,
         pointer: &Pointer,
     ) -> Option<&Vec<u8>> {
-        if let Some(data_value) = self.frozen_pointers.get(pointer) {
-            Some(data_value)
-        } else {
-            None
-        }
+        self.frozen_pointers
+            .get(pointer)
+            .map(|frozen_value| frozen_value.get_value_bytes())
+    }
+
+    /// Gets the revision at which the value of a frozen address was last set, or none if the address is not frozen.
+    pub fn get_address_frozen_revision(
+        &self,
+        pointer: &Pointer,
+    ) -> Option<u64> {
+        self.frozen_pointers
+            .get(pointer)
+            .map(|frozen_value| frozen_value.get_revision())
     }
 
+    /// Freezes an address to the given value, replacing the value it was frozen to if it was already frozen.
     pub fn set_address_frozen(
         &mut self,
         pointer: Pointer,
         data_value: Vec<u8>,
     ) {
-        self.frozen_pointers.insert(pointer, data_value);
+        let revision = self.next_revision();
+
+        self.frozen_pointers
+            .insert(pointer, FrozenValue::new(data_value, revision));
     }
 
     pub fn set_address_unfrozen(
         &mut self,
         pointer: &Pointer,
     ) {
+        self.next_revision();
         self.frozen_pointers.remove(pointer);
+        self.unreadable_pointers.remove(pointer);
+    }
+
+    /// Unfreezes every address that is not relative to a module, ie once the process restarted and those addresses no longer refer
+    /// to the same memory. Returns the number of addresses unfrozen.
+    pub fn unfreeze_absolute_addresses(&mut self) -> u64 {
+        let absolute_pointers: Vec<Pointer> = self
+            .frozen_pointers
+            .keys()
+            .filter(|pointer| pointer.get_module_name().is_empty())
+            .cloned()
+            .collect();
+
+        for pointer in &absolute_pointers {
+            self.set_address_unfrozen(pointer);
+        }
+
+        absolute_pointers.len() as u64
+    }
+
+    /// Writes a value to an address with the given writer, refreezing the address at the written value if it is frozen. Holding the
+    /// registry for the duration of the write ensures that no freeze pass can restore the previous value afterwards.
+    pub fn write_address(
+        &mut self,
+        pointer: Pointer,
+        value_bytes: &[u8],
+        write_bytes: impl FnOnce(&[u8]) -> bool,
+    ) -> bool {
+        if !write_bytes(value_bytes) {
+            return false;
+        }
+
+        if self.is_address_frozen(&pointer) {
+            self.set_address_frozen(pointer, value_bytes.to_vec());
+        }
+
+        true
+    }
+
+    /// Gets whether a frozen address was found unreadable, in which case it is skipped by the freeze loop until it is readable again.
+    pub fn is_address_unreadable(
+        &self,
+        pointer: &Pointer,
+    ) -> bool {
+        self.unreadable_pointers.contains(pointer)
+    }
+
+    /// Marks a frozen address as unreadable or readable again. Addresses that are not frozen are ignored.
+    pub fn set_address_unreadable(
+        &mut self,
+        pointer: &Pointer,
+        is_unreadable: bool,
+    ) {
+        if is_unreadable && self.frozen_pointers.contains_key(pointer) {
+            self.unreadable_pointers.insert(pointer.clone());
+        } else {
+            self.unreadable_pointers.remove(pointer);
+        }
+    }
+
+    /// Gets whether an address belongs to a disabled project entry, in which case it is skipped by the freeze loop until it is enabled
+    /// again. The value it is frozen to is kept, such that enabling it resumes freezing at the same value.
+    pub fn is_address_disabled(
+        &self,
+        pointer: &Pointer,
+    ) -> bool {
+        self.disabled_pointers.contains(pointer)
+    }
+
+    /// Replaces the set of disabled addresses. Addresses do not need to be frozen to be disabled.
+    pub fn set_disabled_pointers(
+        &mut self,
+        disabled_pointers: HashSet<Pointer>,
+    ) {
+        self.disabled_pointers = disabled_pointers;
     }
 
     // JIRA: This function need sto be able to clear by source. We need to be be able to register by source.
     // We need to be able to also freeze complex types like pointers.
     pub fn clear(&mut self) {
+        self.next_revision();
         self.frozen_pointers.clear();
+        self.unreadable_pointers.clear();
+    }
+
+    fn next_revision(&mut self) -> u64 {
+        self.revision += 1;
+        self.revision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FreezeListRegistry;
+    use crate::structures::memory::pointer::Pointer;
+
+    #[test]
+    fn the_last_change_to_a_frozen_address_wins() {
+        let mut freeze_list_registry = FreezeListRegistry::new();
+        let pointer = Pointer::new(0x10, vec![], "game.exe".to_string());
+
+        freeze_list_registry.set_address_frozen(pointer.clone(), vec![1]);
+        let first_revision = freeze_list_registry.get_address_frozen_revision(&pointer);
+
+        freeze_list_registry.set_address_frozen(pointer.clone(), vec![2]);
+
+        assert_eq!(freeze_list_registry.get_address_frozen_bytes(&pointer), Some(&vec![2]));
+        assert!(freeze_list_registry.get_address_frozen_revision(&pointer) > first_revision);
+
+        assert!(freeze_list_registry.write_address(pointer.clone(), &[3], |_value_bytes| true));
+        assert_eq!(freeze_list_registry.get_address_frozen_bytes(&pointer), Some(&vec![3]));
+
+        // Failed writes leave the frozen value as it was.
+        assert!(!freeze_list_registry.write_address(pointer.clone(), &[4], |_value_bytes| false));
+        assert_eq!(freeze_list_registry.get_address_frozen_bytes(&pointer), Some(&vec![3]));
+    }
+
+    #[test]
+    fn writes_to_unfrozen_addresses_do_not_freeze_them() {
+        let mut freeze_list_registry = FreezeListRegistry::new();
+        let pointer = Pointer::new(0x20, vec![], String::new());
+        let mut written_bytes = vec![];
+
+        freeze_list_registry.set_address_frozen(pointer.clone(), vec![1]);
+        freeze_list_registry.set_address_unfrozen(&pointer);
+
+        assert!(freeze_list_registry.write_address(pointer.clone(), &[5], |value_bytes| {
+            written_bytes = value_bytes.to_vec();
+            true
+        }));
+        assert_eq!(written_bytes, vec![5]);
+        assert!(!freeze_list_registry.is_address_frozen(&pointer));
     }
 }
@@ -0,0 +1,24 @@
+/// The value that the freeze task writes to a frozen address on every pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrozenValue {
+    value_bytes: Vec<u8>,
+    revision: u64,
+}
+
+impl FrozenValue {
+    pub fn new(
+        value_bytes: Vec<u8>,
+        revision: u64,
+    ) -> Self {
+        Self { value_bytes, revision }
+    }
+
+    pub fn get_value_bytes(&self) -> &Vec<u8> {
+        &self.value_bytes
+    }
+
+    /// Gets the revision of the freeze list at which this value was last set. A higher revision was requested more recently.
+    pub fn get_revision(&self) -> u64 {
+        self.revision
+    }
+}
@@ -1 +1,2 @@
 pub mod freeze_list_registry;
+pub mod frozen_value;
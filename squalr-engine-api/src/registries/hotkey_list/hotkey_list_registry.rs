@@ -0,0 +1,111 @@
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+
+/// A registered chord, along with whether it was held as of the previous key change.
+struct HotkeyEntry {
+    chord: HotkeyChord,
+    is_down: bool,
+}
+
+/// Contains the chords that trigger an event when pressed, regardless of which window has focus. Chords only trigger when they
+/// are first pressed, such that holding a chord down does not repeat its action.
+pub struct HotkeyListRegistry {
+    hotkeys: Vec<HotkeyEntry>,
+}
+
+impl HotkeyListRegistry {
+    pub fn new() -> Self {
+        Self { hotkeys: vec![] }
+    }
+
+    /// Gets all registered chords, in the order they were registered.
+    pub fn get_chords(&self) -> Vec<HotkeyChord> {
+        self.hotkeys
+            .iter()
+            .map(|hotkey_entry| hotkey_entry.chord.clone())
+            .collect()
+    }
+
+    /// Replaces the registered chords. Chords that stay registered keep whether they are held, such that re-registering while a
+    /// chord is held does not trigger it again.
+    pub fn set_chords(
+        &mut self,
+        chords: Vec<HotkeyChord>,
+    ) {
+        let mut hotkeys = Vec::with_capacity(chords.len());
+
+        for chord in chords {
+            if hotkeys
+                .iter()
+                .any(|hotkey_entry: &HotkeyEntry| hotkey_entry.chord == chord)
+            {
+                continue;
+            }
+
+            let is_down = self
+                .hotkeys
+                .iter()
+                .any(|hotkey_entry| hotkey_entry.chord == chord && hotkey_entry.is_down);
+
+            hotkeys.push(HotkeyEntry { chord, is_down });
+        }
+
+        self.hotkeys = hotkeys;
+    }
+
+    /// Records whether a chord is held after a key change. Returns true if the chord was just pressed, ie it was not held before.
+    pub fn update_is_down(
+        &mut self,
+        chord: &HotkeyChord,
+        is_down: bool,
+    ) -> bool {
+        match self
+            .hotkeys
+            .iter_mut()
+            .find(|hotkey_entry| hotkey_entry.chord == *chord)
+        {
+            Some(hotkey_entry) => !std::mem::replace(&mut hotkey_entry.is_down, is_down) && is_down,
+            None => false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.hotkeys.clear();
+    }
+}
+
+impl Default for HotkeyListRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HotkeyListRegistry;
+    use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+
+    #[test]
+    fn chords_only_trigger_when_first_pressed() {
+        let mut hotkey_list_registry = HotkeyListRegistry::new();
+        let chord = "Ctrl+Numpad1"
+            .parse::<HotkeyChord>()
+            .expect("Chord should parse.");
+
+        hotkey_list_registry.set_chords(vec![chord.clone(), chord.clone()]);
+
+        assert_eq!(hotkey_list_registry.get_chords().len(), 1);
+        assert!(hotkey_list_registry.update_is_down(&chord, true));
+        assert!(!hotkey_list_registry.update_is_down(&chord, true));
+
+        // Registering again while the chord is held does not trigger it a second time.
+        hotkey_list_registry.set_chords(vec![chord.clone()]);
+
+        assert!(!hotkey_list_registry.update_is_down(&chord, true));
+        assert!(!hotkey_list_registry.update_is_down(&chord, false));
+        assert!(hotkey_list_registry.update_is_down(&chord, true));
+
+        hotkey_list_registry.clear();
+
+        assert!(!hotkey_list_registry.update_is_down(&chord, true));
+    }
+}
@@ -1,5 +1,9 @@
+pub mod breakpoint_list;
+pub mod code_patch_list;
 pub mod freeze_list;
+pub mod hotkey_list;
 pub mod project_item_types;
 pub mod registries;
 pub mod scan_rules;
 pub mod symbols;
+pub mod value_watch_list;
@@ -1,6 +1,8 @@
 use crate::registries::{
-    freeze_list::freeze_list_registry::FreezeListRegistry, project_item_types::project_item_type_registry::ProjectItemTypeRegistry,
-    scan_rules::element_scan_rule_registry::ElementScanRuleRegistry, symbols::symbol_registry::SymbolRegistry,
+    breakpoint_list::breakpoint_list_registry::BreakpointListRegistry, code_patch_list::code_patch_list_registry::CodePatchListRegistry,
+    freeze_list::freeze_list_registry::FreezeListRegistry, hotkey_list::hotkey_list_registry::HotkeyListRegistry,
+    project_item_types::project_item_type_registry::ProjectItemTypeRegistry, scan_rules::element_scan_rule_registry::ElementScanRuleRegistry,
+    symbols::symbol_registry::SymbolRegistry, value_watch_list::value_watch_list_registry::ValueWatchListRegistry,
 };
 use std::sync::{Arc, RwLock};
 
@@ -8,6 +10,18 @@ pub struct Registries {
     // The list of frozen scan results.
     freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
 
+    /// The list of values that notify the user when they change.
+    value_watch_list_registry: Arc<RwLock<ValueWatchListRegistry>>,
+
+    /// The list of chords that trigger an event when pressed, regardless of which window has focus.
+    hotkey_list_registry: Arc<RwLock<HotkeyListRegistry>>,
+
+    /// The list of software breakpoints set in the opened process.
+    breakpoint_list_registry: Arc<RwLock<BreakpointListRegistry>>,
+
+    /// The list of code patches applied to the opened process.
+    code_patch_list_registry: Arc<RwLock<CodePatchListRegistry>>,
+
     /// The registry for project item types.
     project_item_type_registry: Arc<RwLock<ProjectItemTypeRegistry>>,
 
@@ -21,12 +35,20 @@ pub struct Registries {
 impl Registries {
     pub fn new() -> Self {
         let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let value_watch_list_registry = Arc::new(RwLock::new(ValueWatchListRegistry::new()));
+        let hotkey_list_registry = Arc::new(RwLock::new(HotkeyListRegistry::new()));
+        let breakpoint_list_registry = Arc::new(RwLock::new(BreakpointListRegistry::new()));
+        let code_patch_list_registry = Arc::new(RwLock::new(CodePatchListRegistry::new()));
         let project_item_type_registry = Arc::new(RwLock::new(ProjectItemTypeRegistry::new()));
         let element_scan_rule_registry = Arc::new(RwLock::new(ElementScanRuleRegistry::new()));
         let symbol_registry = Arc::new(RwLock::new(SymbolRegistry::new()));
 
         Self {
             freeze_list_registry,
+            value_watch_list_registry,
+            hotkey_list_registry,
+            breakpoint_list_registry,
+            code_patch_list_registry,
             project_item_type_registry,
             element_scan_rule_registry,
             symbol_registry,
@@ -38,6 +60,26 @@ impl Registries {
         self.freeze_list_registry.clone()
     }
 
+    /// Gets the registry for the list of values that have been marked to notify on change.
+    pub fn get_value_watch_list_registry(&self) -> Arc<RwLock<ValueWatchListRegistry>> {
+        self.value_watch_list_registry.clone()
+    }
+
+    /// Gets the registry for the list of chords that trigger an event when pressed.
+    pub fn get_hotkey_list_registry(&self) -> Arc<RwLock<HotkeyListRegistry>> {
+        self.hotkey_list_registry.clone()
+    }
+
+    /// Gets the registry for the list of software breakpoints.
+    pub fn get_breakpoint_list_registry(&self) -> Arc<RwLock<BreakpointListRegistry>> {
+        self.breakpoint_list_registry.clone()
+    }
+
+    /// Gets the registry for the list of code patches.
+    pub fn get_code_patch_list_registry(&self) -> Arc<RwLock<CodePatchListRegistry>> {
+        self.code_patch_list_registry.clone()
+    }
+
     /// Gets the registry for project item types.
     pub fn get_project_item_type_registry(&self) -> Arc<RwLock<ProjectItemTypeRegistry>> {
         self.project_item_type_registry.clone()
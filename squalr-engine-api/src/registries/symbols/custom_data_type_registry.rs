@@ -0,0 +1,171 @@
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use crate::structures::data_types::custom_types::data_type_custom_struct::DataTypeCustomStruct;
+use serde_json::to_string_pretty;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Holds user-defined composite data types, which are registered at runtime and persisted beside the executable.
+/// This is process-wide rather than owned by a `SymbolRegistry`, such that every registry instance resolves the same custom types.
+/// The engine owns registration. Clients send register and unregister requests, then mirror the definitions the engine returns.
+pub struct CustomDataTypeRegistry {
+    custom_data_types: RwLock<Vec<Arc<DataTypeCustomStruct>>>,
+    definitions_file: PathBuf,
+}
+
+impl CustomDataTypeRegistry {
+    fn new() -> Self {
+        let definitions_file = Self::default_definitions_path();
+        let definitions: Vec<CustomDataTypeDefinition> = match fs::read_to_string(&definitions_file) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        };
+        let custom_data_types = definitions
+            .into_iter()
+            .filter_map(|definition| match DataTypeCustomStruct::new(definition) {
+                Ok(custom_data_type) => Some(Arc::new(custom_data_type)),
+                Err(error) => {
+                    log::warn!("Skipping invalid custom data type definition: {}", error);
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            custom_data_types: RwLock::new(custom_data_types),
+            definitions_file,
+        }
+    }
+
+    pub fn get_instance() -> &'static CustomDataTypeRegistry {
+        static INSTANCE: OnceLock<CustomDataTypeRegistry> = OnceLock::new();
+
+        INSTANCE.get_or_init(CustomDataTypeRegistry::new)
+    }
+
+    fn default_definitions_path() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_default()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("custom_data_types.json")
+    }
+
+    fn save_definitions(&self) {
+        let definitions = self.get_definitions();
+
+        match to_string_pretty(&definitions) {
+            Ok(json) => {
+                if let Err(error) = fs::write(&self.definitions_file, json) {
+                    log::error!("Failed to save custom data type definitions: {}", error);
+                }
+            }
+            Err(error) => log::error!("Failed to serialize custom data type definitions: {}", error),
+        }
+    }
+
+    pub fn get_custom_data_type(
+        &self,
+        data_type_id: &str,
+    ) -> Option<Arc<DataTypeCustomStruct>> {
+        match self.custom_data_types.read() {
+            Ok(custom_data_types) => custom_data_types
+                .iter()
+                .find(|custom_data_type| custom_data_type.get_definition().get_data_type_id() == data_type_id)
+                .cloned(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on custom data types: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Gets the data type ids of all registered custom data types, in registration order.
+    pub fn get_data_type_ids(&self) -> Vec<String> {
+        self.get_definitions()
+            .iter()
+            .map(|definition| definition.get_data_type_id())
+            .collect()
+    }
+
+    pub fn get_definitions(&self) -> Vec<CustomDataTypeDefinition> {
+        match self.custom_data_types.read() {
+            Ok(custom_data_types) => custom_data_types
+                .iter()
+                .map(|custom_data_type| custom_data_type.get_definition().clone())
+                .collect(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on custom data types: {}", error);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Registers a custom data type, replacing any existing type with the same id, and persists the updated definitions.
+    pub fn register(
+        &self,
+        definition: CustomDataTypeDefinition,
+    ) -> Result<(), String> {
+        let custom_data_type = Arc::new(DataTypeCustomStruct::new(definition)?);
+        let data_type_id = custom_data_type.get_definition().get_data_type_id();
+
+        match self.custom_data_types.write() {
+            Ok(mut custom_data_types) => {
+                match custom_data_types
+                    .iter_mut()
+                    .find(|existing| existing.get_definition().get_data_type_id() == data_type_id)
+                {
+                    Some(existing) => *existing = custom_data_type,
+                    None => custom_data_types.push(custom_data_type),
+                }
+            }
+            Err(error) => return Err(format!("Failed to acquire write lock on custom data types: {}", error)),
+        }
+
+        self.save_definitions();
+
+        Ok(())
+    }
+
+    /// Removes a custom data type by id, returning whether it was registered.
+    pub fn unregister(
+        &self,
+        data_type_id: &str,
+    ) -> bool {
+        let was_removed = match self.custom_data_types.write() {
+            Ok(mut custom_data_types) => {
+                let original_count = custom_data_types.len();
+
+                custom_data_types.retain(|custom_data_type| custom_data_type.get_definition().get_data_type_id() != data_type_id);
+                custom_data_types.len() != original_count
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on custom data types: {}", error);
+                false
+            }
+        };
+
+        if was_removed {
+            self.save_definitions();
+        }
+
+        was_removed
+    }
+
+    /// Replaces the registered types with definitions owned by the engine, without persisting them. This keeps a client in sync
+    /// when the engine runs in another process.
+    pub fn mirror_definitions(
+        &self,
+        definitions: Vec<CustomDataTypeDefinition>,
+    ) {
+        let custom_data_types = definitions
+            .into_iter()
+            .filter_map(|definition| DataTypeCustomStruct::new(definition).ok().map(Arc::new))
+            .collect();
+
+        match self.custom_data_types.write() {
+            Ok(mut existing_custom_data_types) => *existing_custom_data_types = custom_data_types,
+            Err(error) => log::error!("Failed to acquire write lock on custom data types: {}", error),
+        }
+    }
+}
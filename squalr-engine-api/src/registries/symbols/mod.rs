@@ -1 +1,2 @@
+pub mod custom_data_type_registry;
 pub mod symbol_registry;
@@ -1,3 +1,4 @@
+use crate::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
 use crate::structures::data_types::generics::vector_function::GetVectorFunction;
 use crate::structures::data_values::container_type::ContainerType;
 use crate::structures::scanning::comparisons::scan_function_vector::{VectorCompareFnDelta, VectorCompareFnImmediate, VectorCompareFnRelative};
@@ -11,7 +12,8 @@ use crate::structures::{
             bool8::data_type_bool8::DataTypeBool8, bool32::data_type_bool32::DataTypeBool32, f32::data_type_f32::DataTypeF32,
             f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be, i8::data_type_i8::DataTypeI8,
             i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32, i32be::data_type_i32be::DataTypeI32be,
-            i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::utf8::data_type_string_utf8::DataTypeStringUtf8,
+            i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::encoded::data_type_string_encoded::DataTypeStringEncoded,
+            string::encoded::text_encoding::TextEncoding, string::utf8::data_type_string_utf8::DataTypeStringUtf8,
             u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32,
             u32be::data_type_u32be::DataTypeU32be, u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
         },
@@ -42,7 +44,8 @@ pub struct SymbolRegistry {
 }
 
 impl SymbolRegistry {
-    // JIRA: Deprecate this. Needs to support mutability, mirroring from client to server for non-standalone builds, etc.
+    // JIRA: Deprecate this. Needs mirroring from client to server for non-standalone builds, etc.
+    // User-defined types are mutable, but live in the `CustomDataTypeRegistry` which each lookup falls back to.
     pub fn get_instance() -> &'static SymbolRegistry {
         static mut INSTANCE: Option<SymbolRegistry> = None;
         static ONCE: Once = Once::new();
@@ -77,6 +80,8 @@ impl SymbolRegistry {
     ) -> Option<Arc<SymbolicStructDefinition>> {
         if let Some(symbolic_struct_definition) = self.symbolic_struct_registry.get(symbolic_struct_ref_id.trim()) {
             Some(symbolic_struct_definition.clone())
+        } else if let Some(custom_data_type) = CustomDataTypeRegistry::get_instance().get_custom_data_type(symbolic_struct_ref_id.trim()) {
+            Some(Arc::new(custom_data_type.get_symbolic_struct_definition()))
         } else {
             log::warn!("Failed to find symbolic struct in registry: {}", symbolic_struct_ref_id);
             None
@@ -93,12 +98,41 @@ impl SymbolRegistry {
     ) -> Option<Arc<dyn DataType>> {
         if let Some(data_type) = self.data_type_registry.get(data_type_id.trim()) {
             Some(data_type.clone())
+        } else if let Some(custom_data_type) = CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_id.trim()) {
+            Some(custom_data_type)
         } else {
             log::warn!("Failed to find data type in registry: {}", data_type_id);
             None
         }
     }
 
+    /// Determines if the given data type is a user-defined composite type, rather than a built-in type.
+    pub fn is_custom_data_type(
+        &self,
+        data_type_ref: &DataTypeRef,
+    ) -> bool {
+        !self.data_type_registry.contains_key(data_type_ref.get_data_type_id())
+            && CustomDataTypeRegistry::get_instance()
+                .get_custom_data_type(data_type_ref.get_data_type_id())
+                .is_some()
+    }
+
+    /// Gets which fields of a composite value string are wildcards. Returns an empty list for types without fields.
+    pub fn get_field_wildcards(
+        &self,
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Vec<bool> {
+        if self.data_type_registry.contains_key(data_type_ref.get_data_type_id()) {
+            return Vec::new();
+        }
+
+        match CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_ref.get_data_type_id()) {
+            Some(custom_data_type) => custom_data_type.get_field_wildcards(anonymous_value_string),
+            None => Vec::new(),
+        }
+    }
+
     /// Determines if the `DataType` this struct represents is currently registered and available.
     pub fn is_valid(
         &self,
@@ -280,6 +314,7 @@ impl SymbolRegistry {
                 ScanCompareTypeImmediate::GreaterThanOrEqual => data_type.get_compare_greater_than_or_equal(scan_constraint),
                 ScanCompareTypeImmediate::LessThan => data_type.get_compare_less_than(scan_constraint),
                 ScanCompareTypeImmediate::LessThanOrEqual => data_type.get_compare_less_than_or_equal(scan_constraint),
+                ScanCompareTypeImmediate::Between => data_type.get_compare_between(scan_constraint),
             },
             None => None,
         }
@@ -292,7 +327,7 @@ impl SymbolRegistry {
     ) -> Option<ScalarCompareFnRelative> {
         match self.get_data_type(scan_constraint.get_data_value().get_data_type_id()) {
             Some(data_type) => match scan_compare_type {
-                ScanCompareTypeRelative::Changed => data_type.get_compare_changed(scan_constraint),
+                ScanCompareTypeRelative::Changed | ScanCompareTypeRelative::DiffersFromFirstScan => data_type.get_compare_changed(scan_constraint),
                 ScanCompareTypeRelative::Unchanged => data_type.get_compare_unchanged(scan_constraint),
                 ScanCompareTypeRelative::Increased => data_type.get_compare_increased(scan_constraint),
                 ScanCompareTypeRelative::Decreased => data_type.get_compare_decreased(scan_constraint),
@@ -395,6 +430,9 @@ impl SymbolRegistry {
             Arc::new(DataTypeF64 {}),
             Arc::new(DataTypeF64be {}),
             Arc::new(DataTypeStringUtf8 {}),
+            Arc::new(DataTypeStringEncoded::new(TextEncoding::ShiftJis)),
+            Arc::new(DataTypeStringEncoded::new(TextEncoding::Latin1)),
+            Arc::new(DataTypeStringEncoded::new(TextEncoding::Utf32)),
         ];
 
         for built_in_data_type in built_in_data_types.into_iter() {
@@ -0,0 +1 @@
+pub mod value_watch_list_registry;
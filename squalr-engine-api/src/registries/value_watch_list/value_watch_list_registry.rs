@@ -0,0 +1,170 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::pointer::Pointer;
+use crate::structures::memory::value_watch::ValueWatch;
+use crate::structures::memory::value_watch_condition::ValueWatchCondition;
+use std::collections::HashSet;
+
+/// A watch, along with the bytes of the value as of the previous refresh tick.
+struct ValueWatchEntry {
+    value_watch: ValueWatch,
+    /// The bytes read on the previous tick, or `None` if there is no baseline yet, ie the watch is new or the value was unreadable.
+    previous_value_bytes: Option<Vec<u8>>,
+}
+
+/// Contains all values that the user asked to be notified about when they change. Watches are keyed by the address and module of
+/// the value, such that watching the same value again replaces its condition rather than notifying twice.
+pub struct ValueWatchListRegistry {
+    value_watches: Vec<ValueWatchEntry>,
+    disabled_pointers: HashSet<Pointer>,
+    next_watch_id: u64,
+}
+
+impl ValueWatchListRegistry {
+    pub fn new() -> Self {
+        Self {
+            value_watches: vec![],
+            disabled_pointers: HashSet::new(),
+            next_watch_id: 1,
+        }
+    }
+
+    /// Gets all active watches, in the order they were added.
+    pub fn get_value_watches(&self) -> Vec<ValueWatch> {
+        self.value_watches
+            .iter()
+            .map(|value_watch_entry| value_watch_entry.value_watch.clone())
+            .collect()
+    }
+
+    /// Starts watching a value, replacing any existing watch on the same address and module. Returns the identifier of the watch.
+    pub fn add_watch(
+        &mut self,
+        label: String,
+        address: u64,
+        module_name: String,
+        data_type_ref: DataTypeRef,
+        condition: ValueWatchCondition,
+    ) -> u64 {
+        let watch_id = self.next_watch_id;
+
+        self.next_watch_id += 1;
+        self.value_watches
+            .retain(|value_watch_entry| value_watch_entry.value_watch.address != address || value_watch_entry.value_watch.module_name != module_name);
+        self.value_watches.push(ValueWatchEntry {
+            value_watch: ValueWatch {
+                watch_id,
+                label,
+                address,
+                module_name,
+                data_type_ref,
+                condition,
+            },
+            previous_value_bytes: None,
+        });
+
+        watch_id
+    }
+
+    /// Stops a watch. Returns whether a watch with the given identifier existed.
+    pub fn remove_watch(
+        &mut self,
+        watch_id: u64,
+    ) -> bool {
+        let watch_count = self.value_watches.len();
+
+        self.value_watches
+            .retain(|value_watch_entry| value_watch_entry.value_watch.watch_id != watch_id);
+
+        self.value_watches.len() != watch_count
+    }
+
+    /// Records the bytes read for a watch this tick. Returns the bytes of the previous tick if the value changed since then. Unreadable
+    /// values clear the baseline, such that a value becoming readable again is not reported as a change.
+    pub fn update_value_bytes(
+        &mut self,
+        watch_id: u64,
+        current_value_bytes: Option<Vec<u8>>,
+    ) -> Option<Vec<u8>> {
+        let value_watch_entry = self
+            .value_watches
+            .iter_mut()
+            .find(|value_watch_entry| value_watch_entry.value_watch.watch_id == watch_id)?;
+        let previous_value_bytes = std::mem::replace(&mut value_watch_entry.previous_value_bytes, current_value_bytes);
+
+        match (previous_value_bytes, value_watch_entry.previous_value_bytes.as_ref()) {
+            (Some(previous_value_bytes), Some(current_value_bytes)) if previous_value_bytes != *current_value_bytes => Some(previous_value_bytes),
+            _ => None,
+        }
+    }
+
+    /// Gets whether a watched value belongs to a disabled project entry, in which case it is not compared until it is enabled again.
+    pub fn is_address_disabled(
+        &self,
+        address: u64,
+        module_name: &str,
+    ) -> bool {
+        self.disabled_pointers
+            .contains(&Pointer::new(address, vec![], module_name.to_string()))
+    }
+
+    /// Replaces the set of disabled addresses. Watches on them are kept, but only compared again once they are enabled.
+    pub fn set_disabled_pointers(
+        &mut self,
+        disabled_pointers: HashSet<Pointer>,
+    ) {
+        self.disabled_pointers = disabled_pointers;
+    }
+
+    pub fn clear(&mut self) {
+        self.value_watches.clear();
+    }
+}
+
+impl Default for ValueWatchListRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueWatchListRegistry;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::value_watch_condition::ValueWatchCondition;
+
+    #[test]
+    fn watching_the_same_value_again_replaces_the_watch() {
+        let mut value_watch_list_registry = ValueWatchListRegistry::new();
+        let first_watch_id = value_watch_list_registry.add_watch(
+            "Health".to_string(),
+            0x10,
+            "game.exe".to_string(),
+            DataTypeRef::new("i32"),
+            ValueWatchCondition::AnyChange,
+        );
+        let second_watch_id = value_watch_list_registry.add_watch(
+            "Health".to_string(),
+            0x10,
+            "game.exe".to_string(),
+            DataTypeRef::new("i32"),
+            ValueWatchCondition::AnyChange,
+        );
+
+        assert_ne!(first_watch_id, second_watch_id);
+        assert_eq!(value_watch_list_registry.get_value_watches().len(), 1);
+        assert!(!value_watch_list_registry.remove_watch(first_watch_id));
+        assert!(value_watch_list_registry.remove_watch(second_watch_id));
+    }
+
+    #[test]
+    fn changes_are_only_reported_against_a_readable_baseline() {
+        let mut value_watch_list_registry = ValueWatchListRegistry::new();
+        let watch_id = value_watch_list_registry.add_watch(String::new(), 0x10, String::new(), DataTypeRef::new("u8"), ValueWatchCondition::AnyChange);
+
+        assert_eq!(value_watch_list_registry.update_value_bytes(watch_id, Some(vec![1])), None);
+        assert_eq!(value_watch_list_registry.update_value_bytes(watch_id, Some(vec![1])), None);
+        assert_eq!(value_watch_list_registry.update_value_bytes(watch_id, Some(vec![2])), Some(vec![1]));
+        assert_eq!(value_watch_list_registry.update_value_bytes(watch_id, None), None);
+        assert_eq!(value_watch_list_registry.update_value_bytes(watch_id, Some(vec![3])), None);
+    }
+}
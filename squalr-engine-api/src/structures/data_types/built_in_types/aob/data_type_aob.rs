@@ -2,6 +2,7 @@ use crate::structures::data_types::data_type_error::DataTypeError;
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
 use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::memory::byte_pattern::BytePattern;
 use crate::structures::memory::endian::Endian;
 use crate::structures::{data_types::data_type::DataType, data_values::data_value::DataValue};
 use serde::{Deserialize, Serialize};
@@ -12,6 +13,9 @@ pub struct DataTypeAob {}
 impl DataTypeAob {
     pub const DATA_TYPE_ID: &str = "aob";
 
+    /// The fewest bytes a scan pattern may have, as a single byte matches far too much of memory to narrow anything down.
+    pub const MINIMUM_SCAN_PATTERN_LENGTH: usize = 2;
+
     pub fn get_data_type_id() -> &'static str {
         Self::DATA_TYPE_ID
     }
@@ -20,70 +24,44 @@ impl DataTypeAob {
         Self::DATA_TYPE_ID
     }
 
-    fn parse_hex_bytes(value_string: &str) -> Result<Vec<u8>, DataTypeError> {
-        let trimmed = value_string.trim();
-        if trimmed.is_empty() {
-            return Err(DataTypeError::ParseError("AOB pattern cannot be empty.".to_string()));
+    /// Parses a pattern to scan for, where `??`, `?`, or a `?` in place of either digit of a byte are wildcards. Patterns must be at
+    /// least two bytes long, and must compare at least one bit, as a pattern of only wildcards matches everywhere.
+    pub fn parse_scan_pattern(anonymous_value_string: &AnonymousValueString) -> Result<BytePattern, DataTypeError> {
+        if !Self::is_hex_format(anonymous_value_string.get_anonymous_value_string_format()) {
+            return Err(DataTypeError::ParseError("Array of byte patterns must be entered as hex.".to_string()));
         }
 
-        let mut bytes = Vec::new();
-        let separators = |ch: char| ch.is_whitespace() || ch == ',';
-        let tokens: Vec<&str> = trimmed.split(separators).filter(|token| !token.is_empty()).collect();
-
-        if tokens.len() <= 1 {
-            let cleaned = trimmed
-                .replace(',', " ")
-                .split_whitespace()
-                .collect::<Vec<_>>()
-                .join("");
-            let mut cleaned = cleaned.as_str();
-            if cleaned.starts_with("0x") || cleaned.starts_with("0X") {
-                cleaned = &cleaned[2..];
-            }
-
-            if cleaned.len() % 2 != 0 {
-                return Err(DataTypeError::ParseError("AOB hex string length must be even.".to_string()));
-            }
+        let byte_pattern = BytePattern::parse_hex(anonymous_value_string.get_anonymous_value_string()).map_err(DataTypeError::ParseError)?;
 
-            for chunk in cleaned.as_bytes().chunks(2) {
-                let hex_pair = std::str::from_utf8(chunk).map_err(|_| DataTypeError::ParseError("Invalid UTF-8 in hex string.".to_string()))?;
-                let value = u8::from_str_radix(hex_pair, 16)
-                    .map_err(|error| DataTypeError::ParseError(format!("Failed to parse hex byte '{}': {}", hex_pair, error)))?;
-                bytes.push(value);
-            }
-
-            return Ok(bytes);
+        if byte_pattern.len() < Self::MINIMUM_SCAN_PATTERN_LENGTH {
+            return Err(DataTypeError::ParseError(format!(
+                "Array of byte patterns must be at least {} bytes long.",
+                Self::MINIMUM_SCAN_PATTERN_LENGTH
+            )));
         }
 
-        for token in tokens {
-            let mut token = token.trim();
-            if token.starts_with("0x") || token.starts_with("0X") {
-                token = &token[2..];
-            }
-
-            if token.is_empty() {
-                continue;
-            }
+        if byte_pattern.is_all_wildcards() {
+            return Err(DataTypeError::ParseError(
+                "Array of byte patterns must contain at least one byte that is not a wildcard.".to_string(),
+            ));
+        }
 
-            let token = if token.len() == 1 {
-                format!("0{}", token)
-            } else {
-                token.to_string()
-            };
-
-            if token.len() != 2 {
-                return Err(DataTypeError::ParseError(format!(
-                    "Invalid AOB token '{}'. Expected 1-2 hex digits.",
-                    token
-                )));
-            }
+        Ok(byte_pattern)
+    }
 
-            let value = u8::from_str_radix(&token, 16)
-                .map_err(|error| DataTypeError::ParseError(format!("Failed to parse hex byte '{}': {}", token, error)))?;
-            bytes.push(value);
-        }
+    /// Gets whether values in the given format are read as hex bytes, which covers every format but binary.
+    fn is_hex_format(anonymous_value_string_format: AnonymousValueStringFormat) -> bool {
+        matches!(
+            anonymous_value_string_format,
+            AnonymousValueStringFormat::Hexadecimal
+                | AnonymousValueStringFormat::String
+                | AnonymousValueStringFormat::Decimal
+                | AnonymousValueStringFormat::Address
+        )
+    }
 
-        Ok(bytes)
+    fn parse_hex_bytes(value_string: &str) -> Result<Vec<u8>, DataTypeError> {
+        BytePattern::parse_hex_bytes(value_string).map_err(DataTypeError::ParseError)
     }
 
     fn format_hex_bytes(value_bytes: &[u8]) -> String {
@@ -108,11 +86,13 @@ impl DataType for DataTypeAob {
         1
     }
 
+    /// Wildcards are accepted, as array of byte values are usually entered as scan patterns. Writing a value still requires every byte.
     fn validate_value_string(
         &self,
         anonymous_value_string: &AnonymousValueString,
     ) -> bool {
-        self.deanonymize_value_string(anonymous_value_string).is_ok()
+        Self::is_hex_format(anonymous_value_string.get_anonymous_value_string_format())
+            && BytePattern::parse_hex(anonymous_value_string.get_anonymous_value_string()).is_ok_and(|byte_pattern| !byte_pattern.is_empty())
     }
 
     fn deanonymize_value_string(
@@ -0,0 +1,155 @@
+use crate::structures::data_types::built_in_types::primitive_data_type_string::PrimitiveDataTypeString;
+use crate::structures::data_types::built_in_types::string::encoded::text_encoding::TextEncoding;
+use crate::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use crate::structures::data_types::data_type_error::DataTypeError;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::data_values::container_type::ContainerType;
+use crate::structures::memory::endian::Endian;
+use crate::structures::{data_types::data_type::DataType, data_values::data_value::DataValue};
+use serde::{Deserialize, Serialize};
+
+/// A string stored in a non UTF-8 text encoding. A single instance is registered per supported encoding.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DataTypeStringEncoded {
+    text_encoding: TextEncoding,
+}
+
+impl DataTypeStringEncoded {
+    pub const SHIFT_JIS_DATA_TYPE_ID: &str = "string_shift_jis";
+    pub const LATIN1_DATA_TYPE_ID: &str = "string_latin1";
+    pub const UTF32_DATA_TYPE_ID: &str = "string_utf32";
+
+    pub fn new(text_encoding: TextEncoding) -> Self {
+        Self { text_encoding }
+    }
+
+    pub fn get_text_encoding(&self) -> TextEncoding {
+        self.text_encoding
+    }
+
+    /// Gets whether the given data type id refers to a string, regardless of its encoding.
+    pub fn is_string_data_type_id(data_type_id: &str) -> bool {
+        data_type_id == DataTypeStringUtf8::DATA_TYPE_ID || TextEncoding::from_data_type_id(data_type_id).is_some()
+    }
+
+    pub fn get_value_from_primitive_string(
+        text_encoding: TextEncoding,
+        string: &str,
+    ) -> Option<DataValue> {
+        text_encoding
+            .encode(string)
+            .map(|string_bytes| DataValue::new(DataTypeRef::new(text_encoding.get_data_type_id()), string_bytes))
+    }
+}
+
+impl DataType for DataTypeStringEncoded {
+    fn get_data_type_id(&self) -> &str {
+        self.text_encoding.get_data_type_id()
+    }
+
+    fn get_icon_id(&self) -> &str {
+        "string"
+    }
+
+    fn get_unit_size_in_bytes(&self) -> u64 {
+        self.text_encoding.get_unit_size_in_bytes()
+    }
+
+    fn validate_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> bool {
+        self.deanonymize_value_string(anonymous_value_string).is_ok()
+    }
+
+    fn deanonymize_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<DataValue, DataTypeError> {
+        let data_type_ref = DataTypeRef::new(self.get_data_type_id());
+
+        // Text that the encoding cannot represent would otherwise produce a constraint that never matches.
+        let is_raw_bytes = matches!(
+            anonymous_value_string.get_anonymous_value_string_format(),
+            AnonymousValueStringFormat::Hexadecimal | AnonymousValueStringFormat::Binary
+        );
+
+        if !is_raw_bytes
+            && self
+                .text_encoding
+                .encode(anonymous_value_string.get_anonymous_value_string())
+                .is_none()
+        {
+            return Err(DataTypeError::ParseError(format!(
+                "'{}' cannot be represented in {}.",
+                anonymous_value_string.get_anonymous_value_string(),
+                self.text_encoding.get_name()
+            )));
+        }
+
+        let decoded_bytes = PrimitiveDataTypeString::deanonymize_string(anonymous_value_string, |value_string| {
+            self.text_encoding.encode(value_string).unwrap_or_default()
+        })?;
+
+        Ok(DataValue::new(data_type_ref, decoded_bytes))
+    }
+
+    fn anonymize_value_bytes(
+        &self,
+        value_bytes: &[u8],
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Result<AnonymousValueString, DataTypeError> {
+        let bytes = &value_bytes[..self.text_encoding.get_terminated_length(value_bytes)];
+
+        match anonymous_value_string_format {
+            AnonymousValueStringFormat::String => Ok(AnonymousValueString::new(
+                self.text_encoding.decode(bytes),
+                AnonymousValueStringFormat::String,
+                ContainerType::ArrayFixed(bytes.len() as u64),
+            )),
+            AnonymousValueStringFormat::Hexadecimal => {
+                let hex = bytes
+                    .iter()
+                    .map(|byte| format!("{:02X}", byte))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+
+                Ok(AnonymousValueString::new(
+                    hex,
+                    AnonymousValueStringFormat::Hexadecimal,
+                    ContainerType::ArrayFixed(bytes.len() as u64),
+                ))
+            }
+            _ => Err(DataTypeError::ParseError("Unsupported data value format".to_string())),
+        }
+    }
+
+    fn get_supported_anonymous_value_string_formats(&self) -> Vec<AnonymousValueStringFormat> {
+        PrimitiveDataTypeString::get_supported_anonymous_value_string_formats()
+    }
+
+    fn get_default_anonymous_value_string_format(&self) -> AnonymousValueStringFormat {
+        AnonymousValueStringFormat::String
+    }
+
+    fn is_floating_point(&self) -> bool {
+        false
+    }
+
+    fn is_signed(&self) -> bool {
+        false
+    }
+
+    fn get_endian(&self) -> Endian {
+        Endian::Little
+    }
+
+    fn get_default_value(
+        &self,
+        data_type_ref: DataTypeRef,
+    ) -> DataValue {
+        DataValue::new(data_type_ref.clone(), vec![])
+    }
+}
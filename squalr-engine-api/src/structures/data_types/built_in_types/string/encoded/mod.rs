@@ -0,0 +1,4 @@
+pub mod data_type_string_encoded;
+pub mod data_type_string_encoded_scalar_comparisons;
+pub mod data_type_string_encoded_vector_comparisons;
+pub mod text_encoding;
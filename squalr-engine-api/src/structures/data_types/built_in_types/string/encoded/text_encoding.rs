@@ -0,0 +1,159 @@
+use crate::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use serde::{Deserialize, Serialize};
+
+/// Text encodings commonly used by games for strings that are not UTF-8, each backing its own string data type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TextEncoding {
+    /// The double byte encoding used by most Japanese PC and console titles.
+    ShiftJis,
+
+    /// ISO-8859-1, where every byte maps directly to the code point of the same value.
+    Latin1,
+
+    /// Little endian UTF-32, where every code point occupies four bytes.
+    Utf32,
+}
+
+impl TextEncoding {
+    pub const ALL: [TextEncoding; 3] = [
+        TextEncoding::ShiftJis,
+        TextEncoding::Latin1,
+        TextEncoding::Utf32,
+    ];
+
+    pub fn get_data_type_id(&self) -> &'static str {
+        match self {
+            TextEncoding::ShiftJis => DataTypeStringEncoded::SHIFT_JIS_DATA_TYPE_ID,
+            TextEncoding::Latin1 => DataTypeStringEncoded::LATIN1_DATA_TYPE_ID,
+            TextEncoding::Utf32 => DataTypeStringEncoded::UTF32_DATA_TYPE_ID,
+        }
+    }
+
+    pub fn from_data_type_id(data_type_id: &str) -> Option<TextEncoding> {
+        Self::ALL
+            .into_iter()
+            .find(|text_encoding| text_encoding.get_data_type_id() == data_type_id)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TextEncoding::ShiftJis => "Shift-JIS",
+            TextEncoding::Latin1 => "Latin-1",
+            TextEncoding::Utf32 => "UTF-32",
+        }
+    }
+
+    /// Gets the size of a single code unit. Shift-JIS characters span one or two units.
+    pub fn get_unit_size_in_bytes(&self) -> u64 {
+        match self {
+            TextEncoding::ShiftJis | TextEncoding::Latin1 => 1,
+            TextEncoding::Utf32 => 4,
+        }
+    }
+
+    /// Encodes the given text, or returns `None` if it contains a character this encoding cannot represent.
+    pub fn encode(
+        &self,
+        text: &str,
+    ) -> Option<Vec<u8>> {
+        match self {
+            TextEncoding::ShiftJis => {
+                let (bytes, _, had_errors) = encoding_rs::SHIFT_JIS.encode(text);
+
+                if had_errors { None } else { Some(bytes.into_owned()) }
+            }
+            TextEncoding::Latin1 => text
+                .chars()
+                .map(|character| u8::try_from(u32::from(character)).ok())
+                .collect(),
+            TextEncoding::Utf32 => Some(
+                text.chars()
+                    .flat_map(|character| u32::from(character).to_le_bytes())
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Decodes the given bytes, replacing invalid sequences with U+FFFD rather than failing, as memory seldom holds clean text.
+    pub fn decode(
+        &self,
+        bytes: &[u8],
+    ) -> String {
+        match self {
+            TextEncoding::ShiftJis => encoding_rs::SHIFT_JIS
+                .decode_without_bom_handling(bytes)
+                .0
+                .into_owned(),
+            TextEncoding::Latin1 => bytes.iter().map(|byte| char::from(*byte)).collect(),
+            TextEncoding::Utf32 => bytes
+                .chunks(4)
+                .map(|chunk| {
+                    <[u8; 4]>::try_from(chunk)
+                        .ok()
+                        .and_then(|code_unit| char::from_u32(u32::from_le_bytes(code_unit)))
+                        .unwrap_or(char::REPLACEMENT_CHARACTER)
+                })
+                .collect(),
+        }
+    }
+
+    /// Gets the length of the text before the first null code unit, or the full length if the text is not terminated.
+    pub fn get_terminated_length(
+        &self,
+        bytes: &[u8],
+    ) -> usize {
+        let unit_size = self.get_unit_size_in_bytes() as usize;
+
+        bytes
+            .chunks_exact(unit_size)
+            .position(|code_unit| code_unit.iter().all(|byte| *byte == 0))
+            .map(|unit_index| unit_index * unit_size)
+            .unwrap_or(bytes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextEncoding;
+
+    #[test]
+    fn representative_strings_round_trip() {
+        let cases = [
+            (TextEncoding::ShiftJis, "ゲームオーバー HP 100"),
+            (TextEncoding::Latin1, "Café à la crème"),
+            (TextEncoding::Utf32, "Ω≈ 🎮 level"),
+        ];
+
+        for (text_encoding, text) in cases {
+            let bytes = text_encoding.encode(text).expect("Text should be encodable.");
+
+            assert_eq!(text_encoding.decode(&bytes), text, "{} did not round trip.", text_encoding.get_name());
+        }
+
+        assert_eq!(TextEncoding::ShiftJis.encode("ア").unwrap(), vec![0x83, 0x41]);
+        assert_eq!(TextEncoding::Utf32.encode("A").unwrap(), vec![0x41, 0, 0, 0]);
+    }
+
+    #[test]
+    fn unrepresentable_characters_fail_to_encode() {
+        assert_eq!(TextEncoding::Latin1.encode("ア"), None);
+        assert_eq!(TextEncoding::ShiftJis.encode("🎮"), None);
+    }
+
+    #[test]
+    fn invalid_sequences_decode_to_replacement_characters() {
+        // A lead byte missing its trail byte, and a code point beyond the Unicode range followed by a partial unit.
+        assert_eq!(TextEncoding::ShiftJis.decode(&[b'A', 0x83]), "A\u{FFFD}");
+        assert_eq!(TextEncoding::Utf32.decode(&[0x41, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x42]), "A\u{FFFD}\u{FFFD}");
+    }
+
+    #[test]
+    fn terminated_length_stops_at_the_first_null_unit() {
+        let mut bytes = TextEncoding::Utf32.encode("AB").unwrap();
+
+        bytes.extend([0, 0, 0, 0, b'C', 0, 0, 0]);
+
+        assert_eq!(TextEncoding::Utf32.get_terminated_length(&bytes), 8);
+        assert_eq!(TextEncoding::Latin1.get_terminated_length(b"abc"), 3);
+    }
+}
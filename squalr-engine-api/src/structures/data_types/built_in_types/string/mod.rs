@@ -1 +1,2 @@
+pub mod encoded;
 pub mod utf8;
@@ -2,6 +2,7 @@ use crate::structures::scanning::{
     comparisons::scan_function_scalar::{ScalarCompareFnDelta, ScalarCompareFnImmediate, ScalarCompareFnRelative},
     constraints::scan_constraint::ScanConstraint,
 };
+use std::sync::Arc;
 
 pub trait ScalarComparable {
     fn get_compare_equal(
@@ -34,6 +35,20 @@ pub trait ScalarComparable {
         scan_constraint: &ScanConstraint,
     ) -> Option<ScalarCompareFnImmediate>;
 
+    /// Matches values that are greater than or equal to the data value, and less than or equal to the upper bound value.
+    fn get_compare_between(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        let upper_bound_scan_constraint = scan_constraint.with_data_value(scan_constraint.get_upper_bound_data_value()?.clone());
+        let compare_lower_bound = self.get_compare_greater_than_or_equal(scan_constraint)?;
+        let compare_upper_bound = self.get_compare_less_than_or_equal(&upper_bound_scan_constraint)?;
+
+        Some(Arc::new(move |current_value_pointer| {
+            compare_lower_bound(current_value_pointer) && compare_upper_bound(current_value_pointer)
+        }))
+    }
+
     fn get_compare_changed(
         &self,
         scan_constraint: &ScanConstraint,
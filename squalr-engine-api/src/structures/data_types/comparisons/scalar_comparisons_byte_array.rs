@@ -13,6 +13,10 @@ pub struct ScalarComparisonsByteArray {}
 
 impl ScalarComparisonsByteArray {
     pub fn get_compare_equal(scan_constraint: &ScanConstraint) -> Option<ScalarCompareFnImmediate> {
+        if !scan_constraint.get_byte_mask().is_empty() {
+            return Self::get_compare_masked(scan_constraint, true);
+        }
+
         let immediate_values = scan_constraint.get_data_value();
         let immediate_values = immediate_values.get_value_bytes().clone();
         let len = immediate_values.len();
@@ -24,6 +28,10 @@ impl ScalarComparisonsByteArray {
     }
 
     pub fn get_compare_not_equal(scan_constraint: &ScanConstraint) -> Option<ScalarCompareFnImmediate> {
+        if !scan_constraint.get_byte_mask().is_empty() {
+            return Self::get_compare_masked(scan_constraint, false);
+        }
+
         let immediate_values = scan_constraint.get_data_value();
         let immediate_values = immediate_values.get_value_bytes().clone();
         let len = immediate_values.len();
@@ -34,6 +42,28 @@ impl ScalarComparisonsByteArray {
         }))
     }
 
+    /// Compares only the bits set in the byte mask of the constraint, returning whether they all match when `is_equal` is set, and
+    /// whether any of them differ otherwise.
+    fn get_compare_masked(
+        scan_constraint: &ScanConstraint,
+        is_equal: bool,
+    ) -> Option<ScalarCompareFnImmediate> {
+        let immediate_values = scan_constraint.get_data_value().get_value_bytes().clone();
+        let byte_mask = scan_constraint.get_byte_mask().to_vec();
+        let len = immediate_values.len();
+
+        Some(Arc::new(move |current_values_ptr| unsafe {
+            let current_values = std::slice::from_raw_parts(current_values_ptr, len);
+            let is_match = current_values
+                .iter()
+                .zip(&byte_mask)
+                .zip(&immediate_values)
+                .all(|((current_value, mask), immediate_value)| current_value & mask == *immediate_value);
+
+            is_match == is_equal
+        }))
+    }
+
     pub fn get_compare_greater_than(scan_constraint: &ScanConstraint) -> Option<ScalarCompareFnImmediate> {
         let immediate_values = scan_constraint.get_data_value();
         let immediate_values = immediate_values.get_value_bytes().clone();
@@ -1,3 +1,4 @@
+use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::scanning::comparisons::scan_function_scalar::{ScalarCompareFnDelta, ScalarCompareFnImmediate, ScalarCompareFnRelative};
 use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
 use num_traits::Float;
@@ -16,6 +17,17 @@ impl ScalarComparisonsFloat {
         let immediate_value_ptr = immediate_value.as_ptr();
         let immediate_value = unsafe { ptr::read_unaligned(immediate_value_ptr as *const PrimitiveType) };
 
+        if scan_constraint.get_floating_point_tolerance() == FloatingPointTolerance::ToleranceInteger {
+            let immediate_integer = immediate_value.trunc();
+
+            return Some(Arc::new(move |current_value_ptr| {
+                let current_value = unsafe { ptr::read_unaligned(current_value_ptr as *const PrimitiveType) };
+
+                // Equality is determined by the integer parts alone, ie 99.9 equals 99.
+                current_value.trunc() == immediate_integer
+            }));
+        }
+
         Some(Arc::new(move |current_value_ptr| {
             let current_value = unsafe { ptr::read_unaligned(current_value_ptr as *const PrimitiveType) };
 
@@ -32,6 +44,17 @@ impl ScalarComparisonsFloat {
         let immediate_value_ptr = immediate_value.as_ptr();
         let immediate_value = unsafe { ptr::read_unaligned(immediate_value_ptr as *const PrimitiveType) };
 
+        if scan_constraint.get_floating_point_tolerance() == FloatingPointTolerance::ToleranceInteger {
+            let immediate_integer = immediate_value.trunc();
+
+            return Some(Arc::new(move |current_value_ptr| {
+                let current_value = unsafe { ptr::read_unaligned(current_value_ptr as *const PrimitiveType) };
+
+                // Inequality is determined by the integer parts alone, ie 99.9 does not equal 100.
+                current_value.trunc() != immediate_integer
+            }));
+        }
+
         Some(Arc::new(move |current_value_ptr| {
             let current_value = unsafe { ptr::read_unaligned(current_value_ptr as *const PrimitiveType) };
 
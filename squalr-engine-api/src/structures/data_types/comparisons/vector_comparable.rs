@@ -6,6 +6,7 @@ use crate::structures::scanning::comparisons::scan_function_vector::{
     VectorCompareFnRelative16, VectorCompareFnRelative32, VectorCompareFnRelative64,
 };
 use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use std::sync::Arc;
 
 pub trait VectorComparable {
     fn get_vector_compare_equal_64(
@@ -98,6 +99,46 @@ pub trait VectorComparable {
         scan_constraint: &ScanConstraint,
     ) -> Option<VectorCompareFnImmediate16>;
 
+    /// Ands together the greater than or equal mask of the lower bound with the less than or equal mask of the upper bound.
+    fn get_vector_compare_between_64(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<VectorCompareFnImmediate64> {
+        let upper_bound_scan_constraint = scan_constraint.with_data_value(scan_constraint.get_upper_bound_data_value()?.clone());
+        let compare_lower_bound = self.get_vector_compare_greater_than_or_equal_64(scan_constraint)?;
+        let compare_upper_bound = self.get_vector_compare_less_than_or_equal_64(&upper_bound_scan_constraint)?;
+
+        Some(Arc::new(move |current_values_pointer| {
+            compare_lower_bound(current_values_pointer) & compare_upper_bound(current_values_pointer)
+        }))
+    }
+
+    fn get_vector_compare_between_32(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<VectorCompareFnImmediate32> {
+        let upper_bound_scan_constraint = scan_constraint.with_data_value(scan_constraint.get_upper_bound_data_value()?.clone());
+        let compare_lower_bound = self.get_vector_compare_greater_than_or_equal_32(scan_constraint)?;
+        let compare_upper_bound = self.get_vector_compare_less_than_or_equal_32(&upper_bound_scan_constraint)?;
+
+        Some(Arc::new(move |current_values_pointer| {
+            compare_lower_bound(current_values_pointer) & compare_upper_bound(current_values_pointer)
+        }))
+    }
+
+    fn get_vector_compare_between_16(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<VectorCompareFnImmediate16> {
+        let upper_bound_scan_constraint = scan_constraint.with_data_value(scan_constraint.get_upper_bound_data_value()?.clone());
+        let compare_lower_bound = self.get_vector_compare_greater_than_or_equal_16(scan_constraint)?;
+        let compare_upper_bound = self.get_vector_compare_less_than_or_equal_16(&upper_bound_scan_constraint)?;
+
+        Some(Arc::new(move |current_values_pointer| {
+            compare_lower_bound(current_values_pointer) & compare_upper_bound(current_values_pointer)
+        }))
+    }
+
     fn get_vector_compare_changed_64(
         &self,
         scan_constraint: &ScanConstraint,
@@ -320,6 +361,7 @@ pub trait VectorComparable {
             ScanCompareTypeImmediate::GreaterThanOrEqual => self.get_vector_compare_greater_than_or_equal_64(scan_constraint),
             ScanCompareTypeImmediate::LessThan => self.get_vector_compare_less_than_64(scan_constraint),
             ScanCompareTypeImmediate::LessThanOrEqual => self.get_vector_compare_less_than_or_equal_64(scan_constraint),
+            ScanCompareTypeImmediate::Between => self.get_vector_compare_between_64(scan_constraint),
         }
     }
 
@@ -335,6 +377,7 @@ pub trait VectorComparable {
             ScanCompareTypeImmediate::GreaterThanOrEqual => self.get_vector_compare_greater_than_or_equal_32(scan_constraint),
             ScanCompareTypeImmediate::LessThan => self.get_vector_compare_less_than_32(scan_constraint),
             ScanCompareTypeImmediate::LessThanOrEqual => self.get_vector_compare_less_than_or_equal_32(scan_constraint),
+            ScanCompareTypeImmediate::Between => self.get_vector_compare_between_32(scan_constraint),
         }
     }
 
@@ -350,6 +393,7 @@ pub trait VectorComparable {
             ScanCompareTypeImmediate::GreaterThanOrEqual => self.get_vector_compare_greater_than_or_equal_16(scan_constraint),
             ScanCompareTypeImmediate::LessThan => self.get_vector_compare_less_than_16(scan_constraint),
             ScanCompareTypeImmediate::LessThanOrEqual => self.get_vector_compare_less_than_or_equal_16(scan_constraint),
+            ScanCompareTypeImmediate::Between => self.get_vector_compare_between_16(scan_constraint),
         }
     }
 
@@ -359,7 +403,7 @@ pub trait VectorComparable {
         scan_constraint: &ScanConstraint,
     ) -> Option<VectorCompareFnRelative64> {
         match scan_compare_type {
-            ScanCompareTypeRelative::Changed => self.get_vector_compare_changed_64(scan_constraint),
+            ScanCompareTypeRelative::Changed | ScanCompareTypeRelative::DiffersFromFirstScan => self.get_vector_compare_changed_64(scan_constraint),
             ScanCompareTypeRelative::Unchanged => self.get_vector_compare_unchanged_64(scan_constraint),
             ScanCompareTypeRelative::Increased => self.get_vector_compare_increased_64(scan_constraint),
             ScanCompareTypeRelative::Decreased => self.get_vector_compare_decreased_64(scan_constraint),
@@ -372,7 +416,7 @@ pub trait VectorComparable {
         scan_constraint: &ScanConstraint,
     ) -> Option<VectorCompareFnRelative32> {
         match scan_compare_type {
-            ScanCompareTypeRelative::Changed => self.get_vector_compare_changed_32(scan_constraint),
+            ScanCompareTypeRelative::Changed | ScanCompareTypeRelative::DiffersFromFirstScan => self.get_vector_compare_changed_32(scan_constraint),
             ScanCompareTypeRelative::Unchanged => self.get_vector_compare_unchanged_32(scan_constraint),
             ScanCompareTypeRelative::Increased => self.get_vector_compare_increased_32(scan_constraint),
             ScanCompareTypeRelative::Decreased => self.get_vector_compare_decreased_32(scan_constraint),
@@ -385,7 +429,7 @@ pub trait VectorComparable {
         scan_constraint: &ScanConstraint,
     ) -> Option<VectorCompareFnRelative16> {
         match scan_compare_type {
-            ScanCompareTypeRelative::Changed => self.get_vector_compare_changed_16(scan_constraint),
+            ScanCompareTypeRelative::Changed | ScanCompareTypeRelative::DiffersFromFirstScan => self.get_vector_compare_changed_16(scan_constraint),
             ScanCompareTypeRelative::Unchanged => self.get_vector_compare_unchanged_16(scan_constraint),
             ScanCompareTypeRelative::Increased => self.get_vector_compare_increased_16(scan_constraint),
             ScanCompareTypeRelative::Decreased => self.get_vector_compare_decreased_16(scan_constraint),
@@ -1,3 +1,4 @@
+use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::data_types::generics::vector_generics::VectorGenerics;
 use crate::structures::scanning::comparisons::scan_function_vector::{VectorCompareFnDelta, VectorCompareFnImmediate, VectorCompareFnRelative};
 use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
@@ -52,11 +53,29 @@ impl VectorComparisonsFloatBigEndian {
     where
         LaneCount<N>: SupportedLaneCount,
         LaneCount<E>: SupportedLaneCount,
-        Simd<PrimitiveType, E>: SimdFloat + SimdPartialOrd + Sub<Output = Simd<PrimitiveType, E>>,
+        Simd<PrimitiveType, E>: SimdFloat + SimdPartialOrd + Sub<Output = Simd<PrimitiveType, E>> + Rem<Output = Simd<PrimitiveType, E>>,
     {
         let immediate_value = scan_constraint.get_data_value();
         let tolerance: Simd<PrimitiveType, E> = Simd::splat(scan_constraint.get_floating_point_tolerance().get_value());
         let immediate_value_ptr = immediate_value.as_ptr();
+
+        if scan_constraint.get_floating_point_tolerance() == FloatingPointTolerance::ToleranceInteger {
+            let one: Simd<PrimitiveType, E> = Simd::splat(PrimitiveType::one());
+            let immediate_integer: Simd<PrimitiveType, E> = Simd::splat(PrimitiveType::read_float_be(immediate_value_ptr).trunc());
+
+            return Some(Arc::new(move |current_values_ptr| {
+                let current_values: Simd<PrimitiveType, E> = ReadFloatBigEndian::read_float_vector_be(current_values_ptr);
+
+                // Equality is determined by the integer parts alone, ie 99.9 equals 99.
+                // Subtracting the remainder of a division by one truncates each value.
+                VectorGenerics::transmute_mask(
+                    current_values
+                        .sub(current_values.rem(one))
+                        .simd_eq(immediate_integer),
+                )
+            }));
+        }
+
         let immediate_value: Simd<PrimitiveType, E> = Simd::splat(ReadFloatBigEndian::read_float_be(immediate_value_ptr));
 
         Some(Arc::new(move |current_values_ptr| {
@@ -73,11 +92,29 @@ impl VectorComparisonsFloatBigEndian {
     where
         LaneCount<N>: SupportedLaneCount,
         LaneCount<E>: SupportedLaneCount,
-        Simd<PrimitiveType, E>: SimdFloat + SimdPartialOrd + Sub<Output = Simd<PrimitiveType, E>>,
+        Simd<PrimitiveType, E>: SimdFloat + SimdPartialOrd + Sub<Output = Simd<PrimitiveType, E>> + Rem<Output = Simd<PrimitiveType, E>>,
     {
         let immediate_value = scan_constraint.get_data_value();
         let tolerance: Simd<PrimitiveType, E> = Simd::splat(scan_constraint.get_floating_point_tolerance().get_value());
         let immediate_value_ptr = immediate_value.as_ptr();
+
+        if scan_constraint.get_floating_point_tolerance() == FloatingPointTolerance::ToleranceInteger {
+            let one: Simd<PrimitiveType, E> = Simd::splat(PrimitiveType::one());
+            let immediate_integer: Simd<PrimitiveType, E> = Simd::splat(PrimitiveType::read_float_be(immediate_value_ptr).trunc());
+
+            return Some(Arc::new(move |current_values_ptr| {
+                let current_values: Simd<PrimitiveType, E> = ReadFloatBigEndian::read_float_vector_be(current_values_ptr);
+
+                // Inequality is determined by the integer parts alone, ie 99.9 does not equal 100.
+                // Subtracting the remainder of a division by one truncates each value.
+                VectorGenerics::transmute_mask(
+                    current_values
+                        .sub(current_values.rem(one))
+                        .simd_ne(immediate_integer),
+                )
+            }));
+        }
+
         let immediate_value: Simd<PrimitiveType, E> = Simd::splat(ReadFloatBigEndian::read_float_be(immediate_value_ptr));
 
         Some(Arc::new(move |current_values_ptr| {
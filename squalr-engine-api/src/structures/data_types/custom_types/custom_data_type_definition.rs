@@ -0,0 +1,197 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+use crate::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A single named field within a user-defined composite data type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomDataTypeField {
+    name: String,
+    data_type_ref: DataTypeRef,
+}
+
+impl CustomDataTypeField {
+    pub fn new(
+        name: &str,
+        data_type_ref: DataTypeRef,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            data_type_ref,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_data_type_ref(&self) -> &DataTypeRef {
+        &self.data_type_ref
+    }
+
+    /// Gets whether this field only pads the struct, ie `u8 _reserved` or `u32 pad2`. Padding fields still take up space, but are
+    /// left out when the struct is broken down into project entries.
+    pub fn is_padding(&self) -> bool {
+        let name = self.name.to_ascii_lowercase();
+        let name_stem = name.trim_end_matches(|character: char| character.is_ascii_digit() || character == '_');
+
+        name.starts_with('_') || name_stem == "pad" || name_stem == "padding"
+    }
+}
+
+/// Defines a user-defined composite data type as an ordered list of built-in fields, laid out contiguously without padding.
+/// Definitions are written as `Name = type [field_name], ...`, where `type xN` repeats an unnamed field N times.
+/// For example, `Vector3 = f32 x3` or `Entity header = u32 id, u32 flags, f32 hp`. Explicit padding is written as a field named
+/// `pad`, `padding`, or starting with an underscore, ie `Entity = u32 id, u16 _unused, u16 pad`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomDataTypeDefinition {
+    name: String,
+    fields: Vec<CustomDataTypeField>,
+}
+
+impl CustomDataTypeDefinition {
+    const MAX_FIELD_COUNT: usize = 256;
+
+    pub fn new(
+        name: &str,
+        fields: Vec<CustomDataTypeField>,
+    ) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            fields,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_fields(&self) -> &[CustomDataTypeField] {
+        &self.fields
+    }
+
+    /// Gets the data type id that this definition registers under, derived from the name (ie `Entity header` => `entity_header`).
+    pub fn get_data_type_id(&self) -> String {
+        self.name
+            .trim()
+            .chars()
+            .map(|character| {
+                if character.is_ascii_alphanumeric() {
+                    character.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    /// Validates that the definition has a usable name and only contains fixed size built-in field types.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.get_data_type_id().trim_matches('_').is_empty() {
+            return Err("Custom data type name must contain at least one letter or digit.".to_string());
+        }
+
+        if self.fields.is_empty() {
+            return Err(format!("Custom data type '{}' must define at least one field.", self.name));
+        }
+
+        if self.fields.len() > Self::MAX_FIELD_COUNT {
+            return Err(format!(
+                "Custom data type '{}' exceeds the maximum of {} fields.",
+                self.name,
+                Self::MAX_FIELD_COUNT
+            ));
+        }
+
+        let built_in_data_types = SymbolRegistry::get_instance().get_data_type_registry();
+
+        if built_in_data_types.contains_key(&self.get_data_type_id()) {
+            return Err(format!("Custom data type '{}' conflicts with a built-in data type.", self.name));
+        }
+
+        for field in &self.fields {
+            let data_type_id = field.get_data_type_ref().get_data_type_id();
+
+            if data_type_id == DataTypeAob::DATA_TYPE_ID || DataTypeStringEncoded::is_string_data_type_id(data_type_id) {
+                return Err(format!(
+                    "Field '{}' uses variable sized type '{}', which cannot be a struct field.",
+                    field.get_name(),
+                    data_type_id
+                ));
+            }
+
+            if !built_in_data_types.contains_key(data_type_id) {
+                return Err(format!("Field '{}' uses unknown data type '{}'.", field.get_name(), data_type_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_repeat_count(token: &str) -> Option<usize> {
+        token
+            .strip_prefix('x')
+            .or_else(|| token.strip_prefix('X'))
+            .and_then(|count| count.parse::<usize>().ok())
+    }
+}
+
+impl FromStr for CustomDataTypeDefinition {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (name, field_list) = string
+            .split_once('=')
+            .ok_or_else(|| "Expected format: Name = type [field_name], ...".to_string())?;
+        let mut fields = Vec::new();
+
+        for field_string in field_list.split(',') {
+            let tokens: Vec<&str> = field_string.split_whitespace().collect();
+
+            match tokens.as_slice() {
+                [data_type_id] => {
+                    fields.push(CustomDataTypeField::new(&format!("field_{}", fields.len()), DataTypeRef::new(data_type_id)));
+                }
+                [data_type_id, name_or_repeat] => match Self::parse_repeat_count(name_or_repeat) {
+                    Some(repeat_count) => {
+                        if repeat_count == 0 || repeat_count > Self::MAX_FIELD_COUNT {
+                            return Err(format!("Invalid repeat count '{}'.", name_or_repeat));
+                        }
+
+                        for _ in 0..repeat_count {
+                            fields.push(CustomDataTypeField::new(&format!("field_{}", fields.len()), DataTypeRef::new(data_type_id)));
+                        }
+                    }
+                    None => fields.push(CustomDataTypeField::new(name_or_repeat, DataTypeRef::new(data_type_id))),
+                },
+                [] => return Err("Custom data type definitions cannot contain empty fields.".to_string()),
+                _ => return Err(format!("Unable to parse field '{}'.", field_string.trim())),
+            }
+        }
+
+        let definition = CustomDataTypeDefinition::new(name, fields);
+
+        definition.validate()?;
+
+        Ok(definition)
+    }
+}
+
+impl fmt::Display for CustomDataTypeDefinition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| format!("{} {}", field.get_data_type_ref(), field.get_name()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(formatter, "{} = {}", self.name, fields)
+    }
+}
@@ -0,0 +1,367 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use crate::structures::data_types::data_type::DataType;
+use crate::structures::data_types::data_type_error::DataTypeError;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::data_values::container_type::ContainerType;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::memory::endian::Endian;
+use crate::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
+use crate::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use crate::structures::structs::symbolic_struct_ref::SymbolicStructRef;
+use crate::structures::structs::valued_struct::ValuedStruct;
+use std::sync::Arc;
+
+/// A resolved field of a custom struct, with its byte offset from the start of the struct.
+#[derive(Clone, Debug)]
+pub struct CustomStructFieldLayout {
+    pub name: String,
+    pub offset: u64,
+    pub data_type: Arc<dyn DataType>,
+    pub is_padding: bool,
+}
+
+/// A dynamic data type wrapping a user-defined ordered field list. Values are displayed as tuples, ie `(1.0, 2.5, -3.0)`,
+/// and any field may be given as `?` to exclude it from comparisons.
+#[derive(Debug)]
+pub struct DataTypeCustomStruct {
+    data_type_id: String,
+    definition: CustomDataTypeDefinition,
+    field_layouts: Vec<CustomStructFieldLayout>,
+    size_in_bytes: u64,
+}
+
+impl DataTypeCustomStruct {
+    pub const ICON_ID: &str = "custom_struct";
+    pub const WILDCARD: &str = "?";
+
+    pub fn new(definition: CustomDataTypeDefinition) -> Result<Self, String> {
+        definition.validate()?;
+
+        let built_in_data_types = SymbolRegistry::get_instance().get_data_type_registry();
+        let mut field_layouts = Vec::with_capacity(definition.get_fields().len());
+        let mut offset = 0;
+
+        for field in definition.get_fields() {
+            let data_type = built_in_data_types
+                .get(field.get_data_type_ref().get_data_type_id())
+                .cloned()
+                .ok_or_else(|| format!("Unknown field data type: {}", field.get_data_type_ref()))?;
+            let field_size = data_type.get_unit_size_in_bytes();
+
+            field_layouts.push(CustomStructFieldLayout {
+                name: field.get_name().to_string(),
+                offset,
+                data_type,
+                is_padding: field.is_padding(),
+            });
+            offset += field_size;
+        }
+
+        Ok(Self {
+            data_type_id: definition.get_data_type_id(),
+            definition,
+            field_layouts,
+            size_in_bytes: offset,
+        })
+    }
+
+    pub fn get_definition(&self) -> &CustomDataTypeDefinition {
+        &self.definition
+    }
+
+    pub fn get_field_layouts(&self) -> &[CustomStructFieldLayout] {
+        &self.field_layouts
+    }
+
+    /// Gets which fields of the given value string are wildcards, in field order.
+    pub fn get_field_wildcards(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Vec<bool> {
+        Self::split_value_string(anonymous_value_string.get_anonymous_value_string())
+            .iter()
+            .map(|field_value| Self::is_wildcard(field_value))
+            .collect()
+    }
+
+    /// Creates a symbolic struct with one field per struct field, such that struct-typed project entries can be read and displayed.
+    pub fn get_symbolic_struct_definition(&self) -> SymbolicStructDefinition {
+        SymbolicStructDefinition::new(
+            self.data_type_id.clone(),
+            self.field_layouts
+                .iter()
+                .map(|field_layout| SymbolicFieldDefinition::new(field_layout.data_type.get_ref(), ContainerType::None))
+                .collect(),
+        )
+    }
+
+    /// Splits a struct value into its named fields for display in the struct viewer.
+    pub fn to_valued_struct(
+        &self,
+        value_bytes: &[u8],
+        is_read_only: bool,
+    ) -> ValuedStruct {
+        let fields = self
+            .field_layouts
+            .iter()
+            .map(|field_layout| {
+                let field_bytes = Self::get_field_bytes(value_bytes, field_layout);
+
+                DataValue::new(field_layout.data_type.get_ref(), field_bytes.to_vec()).to_named_valued_struct_field(field_layout.name.clone(), is_read_only)
+            })
+            .collect();
+
+        ValuedStruct::new(SymbolicStructRef::new(self.data_type_id.clone()), fields)
+    }
+
+    pub(crate) fn get_field_bytes<'a>(
+        value_bytes: &'a [u8],
+        field_layout: &CustomStructFieldLayout,
+    ) -> &'a [u8] {
+        let start = (field_layout.offset as usize).min(value_bytes.len());
+        let end = (start + field_layout.data_type.get_unit_size_in_bytes() as usize).min(value_bytes.len());
+
+        &value_bytes[start..end]
+    }
+
+    fn is_wildcard(field_value: &str) -> bool {
+        !field_value.is_empty() && field_value.chars().all(|character| character == '?')
+    }
+
+    fn split_value_string(value_string: &str) -> Vec<&str> {
+        let value_string = value_string.trim();
+        let value_string = value_string
+            .strip_prefix('(')
+            .and_then(|value_string| value_string.strip_suffix(')'))
+            .unwrap_or(value_string);
+
+        value_string.split(',').map(str::trim).collect()
+    }
+}
+
+impl DataType for DataTypeCustomStruct {
+    fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    fn get_icon_id(&self) -> &str {
+        Self::ICON_ID
+    }
+
+    fn get_unit_size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    fn validate_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> bool {
+        self.deanonymize_value_string(anonymous_value_string).is_ok()
+    }
+
+    fn deanonymize_value_string(
+        &self,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<DataValue, DataTypeError> {
+        let field_values = Self::split_value_string(anonymous_value_string.get_anonymous_value_string());
+
+        if field_values.len() != self.field_layouts.len() {
+            return Err(DataTypeError::ParseError(format!(
+                "Expected {} comma separated values for {}, got {}.",
+                self.field_layouts.len(),
+                self.definition.get_name(),
+                field_values.len()
+            )));
+        }
+
+        let mut value_bytes = Vec::with_capacity(self.size_in_bytes as usize);
+
+        for (field_value, field_layout) in field_values.iter().zip(self.field_layouts.iter()) {
+            if Self::is_wildcard(field_value) {
+                // Wildcard fields are masked out of comparisons, so their bytes are only placeholders.
+                value_bytes.resize(value_bytes.len() + field_layout.data_type.get_unit_size_in_bytes() as usize, 0);
+                continue;
+            }
+
+            let field_value_string = AnonymousValueString::new(
+                field_value.to_string(),
+                anonymous_value_string.get_anonymous_value_string_format(),
+                ContainerType::None,
+            );
+            let field_data_value = field_layout
+                .data_type
+                .deanonymize_value_string(&field_value_string)
+                .map_err(|error| DataTypeError::ParseError(format!("Field '{}': {}", field_layout.name, error)))?;
+
+            value_bytes.extend_from_slice(field_data_value.get_value_bytes());
+        }
+
+        Ok(DataValue::new(self.get_ref(), value_bytes))
+    }
+
+    fn anonymize_value_bytes(
+        &self,
+        value_bytes: &[u8],
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Result<AnonymousValueString, DataTypeError> {
+        if (value_bytes.len() as u64) < self.size_in_bytes {
+            return Err(DataTypeError::InvalidByteCount {
+                expected: self.size_in_bytes,
+                actual: value_bytes.len() as u64,
+            });
+        }
+
+        let mut field_strings = Vec::with_capacity(self.field_layouts.len());
+
+        for field_layout in &self.field_layouts {
+            let field_data_type = &field_layout.data_type;
+            let field_format = if field_data_type
+                .get_supported_anonymous_value_string_formats()
+                .contains(&anonymous_value_string_format)
+            {
+                anonymous_value_string_format
+            } else {
+                field_data_type.get_default_anonymous_value_string_format()
+            };
+            let field_string = field_data_type.anonymize_value_bytes(Self::get_field_bytes(value_bytes, field_layout), field_format)?;
+
+            field_strings.push(field_string.get_anonymous_value_string().to_string());
+        }
+
+        Ok(AnonymousValueString::new(
+            format!("({})", field_strings.join(", ")),
+            anonymous_value_string_format,
+            ContainerType::None,
+        ))
+    }
+
+    fn get_supported_anonymous_value_string_formats(&self) -> Vec<AnonymousValueStringFormat> {
+        vec![
+            AnonymousValueStringFormat::Decimal,
+            AnonymousValueStringFormat::Hexadecimal,
+        ]
+    }
+
+    fn get_default_anonymous_value_string_format(&self) -> AnonymousValueStringFormat {
+        AnonymousValueStringFormat::Decimal
+    }
+
+    fn get_endian(&self) -> Endian {
+        Endian::Little
+    }
+
+    fn is_floating_point(&self) -> bool {
+        self.field_layouts
+            .iter()
+            .any(|field_layout| field_layout.data_type.is_floating_point())
+    }
+
+    fn is_signed(&self) -> bool {
+        self.field_layouts
+            .iter()
+            .any(|field_layout| field_layout.data_type.is_signed())
+    }
+
+    fn get_default_value(
+        &self,
+        data_type_ref: DataTypeRef,
+    ) -> DataValue {
+        DataValue::new(data_type_ref, vec![0u8; self.size_in_bytes as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DataTypeCustomStruct;
+    use crate::structures::data_types::comparisons::scalar_comparable::ScalarComparable;
+    use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+    use crate::structures::data_types::data_type::DataType;
+    use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+    use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+
+    fn entity_header_bytes(
+        id: u32,
+        flags: u32,
+        hp: f32,
+    ) -> Vec<u8> {
+        [id.to_le_bytes(), flags.to_le_bytes(), hp.to_le_bytes()].concat()
+    }
+
+    #[test]
+    fn definitions_parse_repeated_and_named_fields() {
+        let vector3 = "Vector3 = f32 x3".parse::<CustomDataTypeDefinition>().unwrap();
+        let entity_header = "Entity header = u32 id, u32 flags, f32 hp"
+            .parse::<CustomDataTypeDefinition>()
+            .unwrap();
+
+        assert_eq!(vector3.get_data_type_id(), "vector3");
+        assert_eq!(vector3.get_fields().len(), 3);
+        assert_eq!(entity_header.get_data_type_id(), "entity_header");
+        assert_eq!(entity_header.get_fields()[2].get_name(), "hp");
+        assert!(!entity_header.get_fields()[2].is_padding());
+        assert!(
+            "Padded = u32 id, u8 _flags, u8 pad, u16 padding_2"
+                .parse::<CustomDataTypeDefinition>()
+                .unwrap()
+                .get_fields()
+                .iter()
+                .skip(1)
+                .all(|field| field.is_padding())
+        );
+        assert_eq!(
+            DataTypeCustomStruct::new(entity_header)
+                .unwrap()
+                .get_unit_size_in_bytes(),
+            12
+        );
+        assert!(
+            "Bad = u32 id, aob pattern"
+                .parse::<CustomDataTypeDefinition>()
+                .is_err()
+        );
+        assert!(
+            "Bad = u32 id, missing_type value"
+                .parse::<CustomDataTypeDefinition>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn struct_values_compare_field_by_field_honoring_wildcards() {
+        let definition = "Entity header = u32 id, u32 flags, f32 hp"
+            .parse::<CustomDataTypeDefinition>()
+            .unwrap();
+        let data_type = DataTypeCustomStruct::new(definition).unwrap();
+        let value_string = AnonymousValueString::new("(7, ?, 100)".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None);
+        let data_value = data_type.deanonymize_value_string(&value_string).unwrap();
+        let mut scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            data_value,
+            FloatingPointTolerance::default(),
+        );
+
+        scan_constraint.set_field_wildcards(data_type.get_field_wildcards(&value_string));
+
+        let compare_equal = data_type.get_compare_equal(&scan_constraint).unwrap();
+        let matching = entity_header_bytes(7, 0xDEAD, 100.0);
+        let mismatching = entity_header_bytes(8, 0, 100.0);
+
+        assert!(compare_equal(matching.as_ptr()));
+        assert!(!compare_equal(mismatching.as_ptr()));
+        assert_eq!(
+            data_type
+                .anonymize_value_bytes(&matching, AnonymousValueStringFormat::Decimal)
+                .unwrap()
+                .get_anonymous_value_string(),
+            "(7, 57005, 100)"
+        );
+    }
+}
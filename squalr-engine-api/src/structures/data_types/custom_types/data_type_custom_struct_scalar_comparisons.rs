@@ -0,0 +1,259 @@
+use crate::structures::data_types::comparisons::scalar_comparable::ScalarComparable;
+use crate::structures::data_types::custom_types::data_type_custom_struct::DataTypeCustomStruct;
+use crate::structures::data_types::data_type::DataType;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::scanning::comparisons::scan_function_scalar::{ScalarCompareFnDelta, ScalarCompareFnImmediate, ScalarCompareFnRelative};
+use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use std::sync::Arc;
+
+impl DataTypeCustomStruct {
+    /// Creates a constraint for each non-wildcard field, paired with the field offset and the field data type.
+    fn get_field_constraints(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Vec<(usize, Arc<dyn DataType>, ScanConstraint)> {
+        let value_bytes = scan_constraint.get_data_value().get_value_bytes();
+        let field_wildcards = scan_constraint.get_field_wildcards();
+
+        self.get_field_layouts()
+            .iter()
+            .enumerate()
+            .filter(|(field_index, _)| !field_wildcards.get(*field_index).copied().unwrap_or(false))
+            .map(|(_, field_layout)| {
+                let mut field_bytes = Self::get_field_bytes(value_bytes, field_layout).to_vec();
+
+                field_bytes.resize(field_layout.data_type.get_unit_size_in_bytes() as usize, 0);
+
+                let field_constraint = ScanConstraint::new(
+                    scan_constraint.get_scan_compare_type(),
+                    DataValue::new(field_layout.data_type.get_ref(), field_bytes),
+                    scan_constraint.get_floating_point_tolerance(),
+                );
+
+                (field_layout.offset as usize, field_layout.data_type.clone(), field_constraint)
+            })
+            .collect()
+    }
+
+    /// Combines per-field immediate comparisons, such that a struct matches only if every non-wildcard field matches.
+    fn get_compare_all_fields_immediate(
+        &self,
+        scan_constraint: &ScanConstraint,
+        get_field_compare: impl Fn(&Arc<dyn DataType>, &ScanConstraint) -> Option<ScalarCompareFnImmediate>,
+    ) -> Option<ScalarCompareFnImmediate> {
+        let field_compares = self
+            .get_field_constraints(scan_constraint)
+            .iter()
+            .map(|(offset, data_type, field_constraint)| get_field_compare(data_type, field_constraint).map(|compare| (*offset, compare)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Arc::new(move |current_value_ptr| {
+            field_compares
+                .iter()
+                .all(|(offset, compare)| compare(unsafe { current_value_ptr.add(*offset) }))
+        }))
+    }
+
+    /// Combines per-field relative or delta comparisons. Fields are either all required to match, or any single match is sufficient.
+    fn get_compare_fields_relative(
+        &self,
+        scan_constraint: &ScanConstraint,
+        require_all_fields: bool,
+        get_field_compare: impl Fn(&Arc<dyn DataType>, &ScanConstraint) -> Option<ScalarCompareFnRelative>,
+    ) -> Option<ScalarCompareFnRelative> {
+        let field_compares = self
+            .get_field_constraints(scan_constraint)
+            .iter()
+            .map(|(offset, data_type, field_constraint)| get_field_compare(data_type, field_constraint).map(|compare| (*offset, compare)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Arc::new(move |current_value_ptr, previous_value_ptr| {
+            let mut field_results = field_compares
+                .iter()
+                .map(|(offset, compare)| compare(unsafe { current_value_ptr.add(*offset) }, unsafe { previous_value_ptr.add(*offset) }));
+
+            if require_all_fields {
+                field_results.all(|is_match| is_match)
+            } else {
+                field_results.any(|is_match| is_match)
+            }
+        }))
+    }
+}
+
+impl ScalarComparable for DataTypeCustomStruct {
+    fn get_compare_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| data_type.get_compare_equal(field_constraint))
+    }
+
+    fn get_compare_not_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| data_type.get_compare_not_equal(field_constraint))
+    }
+
+    fn get_compare_greater_than(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| {
+            data_type.get_compare_greater_than(field_constraint)
+        })
+    }
+
+    fn get_compare_greater_than_or_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| {
+            data_type.get_compare_greater_than_or_equal(field_constraint)
+        })
+    }
+
+    fn get_compare_less_than(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| data_type.get_compare_less_than(field_constraint))
+    }
+
+    fn get_compare_less_than_or_equal(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnImmediate> {
+        self.get_compare_all_fields_immediate(scan_constraint, |data_type, field_constraint| {
+            data_type.get_compare_less_than_or_equal(field_constraint)
+        })
+    }
+
+    fn get_compare_changed(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        // A struct has changed if any of its fields changed, rather than all of them.
+        self.get_compare_fields_relative(scan_constraint, false, |data_type, field_constraint| {
+            data_type.get_compare_changed(field_constraint)
+        })
+    }
+
+    fn get_compare_unchanged(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_unchanged(field_constraint)
+        })
+    }
+
+    fn get_compare_increased(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_increased(field_constraint)
+        })
+    }
+
+    fn get_compare_decreased(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnRelative> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_decreased(field_constraint)
+        })
+    }
+
+    fn get_compare_increased_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_increased_by(field_constraint)
+        })
+    }
+
+    fn get_compare_decreased_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_decreased_by(field_constraint)
+        })
+    }
+
+    fn get_compare_multiplied_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_multiplied_by(field_constraint)
+        })
+    }
+
+    fn get_compare_divided_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_divided_by(field_constraint)
+        })
+    }
+
+    fn get_compare_modulo_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_modulo_by(field_constraint)
+        })
+    }
+
+    fn get_compare_shift_left_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_shift_left_by(field_constraint)
+        })
+    }
+
+    fn get_compare_shift_right_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_shift_right_by(field_constraint)
+        })
+    }
+
+    fn get_compare_logical_and_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_logical_and_by(field_constraint)
+        })
+    }
+
+    fn get_compare_logical_or_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_logical_or_by(field_constraint)
+        })
+    }
+
+    fn get_compare_logical_xor_by(
+        &self,
+        scan_constraint: &ScanConstraint,
+    ) -> Option<ScalarCompareFnDelta> {
+        self.get_compare_fields_relative(scan_constraint, true, |data_type, field_constraint| {
+            data_type.get_compare_logical_xor_by(field_constraint)
+        })
+    }
+}
@@ -0,0 +1,4 @@
+pub mod custom_data_type_definition;
+pub mod data_type_custom_struct;
+pub mod data_type_custom_struct_scalar_comparisons;
+pub mod data_type_custom_struct_vector_comparisons;
@@ -23,6 +23,10 @@ pub enum FloatingPointTolerance {
     /// Represents a tolerance of epsilon (ie essentially an exact match).
     #[serde(rename = "epsilon")]
     ToleranceEpsilon,
+    /// Represents comparing only the integer parts of values, ie 99.9 equals 99, as a game rounding a value for display would show it.
+    /// Comparisons that cannot truncate, such as delta scans, treat this as a tolerance of 0.5.
+    #[serde(rename = "integer")]
+    ToleranceInteger,
 }
 
 impl FloatingPointTolerance {
@@ -35,6 +39,7 @@ impl FloatingPointTolerance {
                 FloatingPointTolerance::Tolerance10E3 => PrimitiveType::from(0.001).unwrap_unchecked(),
                 FloatingPointTolerance::Tolerance10E4 => PrimitiveType::from(0.0001).unwrap_unchecked(),
                 FloatingPointTolerance::Tolerance10E5 => PrimitiveType::from(0.00001).unwrap_unchecked(),
+                FloatingPointTolerance::ToleranceInteger => PrimitiveType::from(0.5).unwrap_unchecked(),
             }
         }
     }
@@ -69,6 +74,7 @@ impl FromStr for FloatingPointTolerance {
             "0.0001" => Ok(FloatingPointTolerance::Tolerance10E4),
             "0.00001" => Ok(FloatingPointTolerance::Tolerance10E5),
             "epsilon" => Ok(FloatingPointTolerance::ToleranceEpsilon),
+            "integer" => Ok(FloatingPointTolerance::ToleranceInteger),
             _ => Err(format!("Invalid tolerance string: '{}'", string)),
         }
     }
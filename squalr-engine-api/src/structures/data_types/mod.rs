@@ -1,5 +1,6 @@
 pub mod built_in_types;
 pub mod comparisons;
+pub mod custom_types;
 pub mod data_type;
 pub mod data_type_error;
 pub mod data_type_ref;
@@ -0,0 +1,184 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+use crate::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use crate::structures::data_values::container_type::ContainerType;
+use crate::structures::data_values::data_value::DataValue;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Parses a comma or newline separated list of values typed into a single value box, ie `1017, 2044, 3999`. Each token is read in
+/// the format of the list, unless the token carries its own `0x` or `0b` prefix.
+pub struct AnonymousValueList {}
+
+impl AnonymousValueList {
+    const TOKEN_SEPARATORS: [char; 3] = [',', '\n', '\r'];
+
+    /// Splits a value string into its non-empty, trimmed tokens.
+    pub fn split_tokens(value_string: &str) -> Vec<&str> {
+        value_string
+            .split(Self::TOKEN_SEPARATORS)
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .collect()
+    }
+
+    /// Gets whether the value string holds more than one value. Arrays are a single value, even though they are also comma separated.
+    pub fn is_value_list(anonymous_value_string: &AnonymousValueString) -> bool {
+        anonymous_value_string.get_container_type() == ContainerType::None && Self::split_tokens(anonymous_value_string.get_anonymous_value_string()).len() > 1
+    }
+
+    /// Gets whether the data type can be scanned for a list of values. Text, byte arrays, and structs give commas their own meaning.
+    pub fn supports_value_lists(data_type_ref: &DataTypeRef) -> bool {
+        let data_type_id = data_type_ref.get_data_type_id();
+
+        !DataTypeStringEncoded::is_string_data_type_id(data_type_id)
+            && data_type_id != DataTypeAob::DATA_TYPE_ID
+            && !SymbolRegistry::get_instance().is_custom_data_type(data_type_ref)
+    }
+
+    /// Gets each token of the list as its own value string, applying any per token format prefix.
+    pub fn get_token_value_strings(anonymous_value_string: &AnonymousValueString) -> Vec<AnonymousValueString> {
+        let list_format = anonymous_value_string.get_anonymous_value_string_format();
+
+        Self::split_tokens(anonymous_value_string.get_anonymous_value_string())
+            .into_iter()
+            .map(|token| {
+                let (token, token_format) = Self::get_token_format(token, list_format);
+
+                AnonymousValueString::new(token.to_string(), token_format, ContainerType::None)
+            })
+            .collect()
+    }
+
+    /// Parses every token of the list against the data type, dropping duplicates. Fails with every token that could not be parsed,
+    /// rather than stopping at the first, such that they can all be pointed out at once.
+    pub fn deanonymize(
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<Vec<DataValue>, AnonymousValueListError> {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let mut data_values = vec![];
+        let mut seen_value_bytes = HashSet::new();
+        let mut failed_tokens = vec![];
+
+        for token_value_string in Self::get_token_value_strings(anonymous_value_string) {
+            match symbol_registry.deanonymize_value_string(data_type_ref, &token_value_string) {
+                Ok(data_value) => {
+                    if seen_value_bytes.insert(data_value.get_value_bytes().clone()) {
+                        data_values.push(data_value);
+                    }
+                }
+                Err(_) => failed_tokens.push(token_value_string.get_anonymous_value_string().to_string()),
+            }
+        }
+
+        if failed_tokens.is_empty() && !data_values.is_empty() {
+            Ok(data_values)
+        } else {
+            Err(AnonymousValueListError { failed_tokens })
+        }
+    }
+
+    fn get_token_format(
+        token: &str,
+        list_format: AnonymousValueStringFormat,
+    ) -> (&str, AnonymousValueStringFormat) {
+        if let Some(hexadecimal_token) = token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+            (hexadecimal_token, AnonymousValueStringFormat::Hexadecimal)
+        } else if let Some(binary_token) = token.strip_prefix("0b").or_else(|| token.strip_prefix("0B")) {
+            (binary_token, AnonymousValueStringFormat::Binary)
+        } else {
+            (token, list_format)
+        }
+    }
+}
+
+/// Lists the tokens of a value list that could not be parsed as the requested data type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnonymousValueListError {
+    pub failed_tokens: Vec<String>,
+}
+
+impl fmt::Display for AnonymousValueListError {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if self.failed_tokens.is_empty() {
+            write!(formatter, "The value list is empty")
+        } else {
+            write!(formatter, "Failed to parse values: {}", self.failed_tokens.join(", "))
+        }
+    }
+}
+
+impl std::error::Error for AnonymousValueListError {}
+
+#[cfg(test)]
+mod tests {
+    use super::AnonymousValueList;
+    use crate::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+    use crate::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+    use crate::structures::data_values::data_value::DataValue;
+
+    fn to_value_string(
+        value_string: &str,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> AnonymousValueString {
+        AnonymousValueString::new(value_string.to_string(), anonymous_value_string_format, ContainerType::None)
+    }
+
+    fn to_i32_values(data_values: &[DataValue]) -> Vec<i32> {
+        data_values
+            .iter()
+            .map(|data_value| i32::from_le_bytes(data_value.get_value_bytes()[..4].try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn commas_and_newlines_both_separate_values() {
+        assert_eq!(AnonymousValueList::split_tokens("1017, 2044,\r\n3999\n\n"), vec!["1017", "2044", "3999"]);
+        assert!(AnonymousValueList::is_value_list(&to_value_string("1, 2", AnonymousValueStringFormat::Decimal)));
+        assert!(!AnonymousValueList::is_value_list(&to_value_string("12,", AnonymousValueStringFormat::Decimal)));
+    }
+
+    #[test]
+    fn tokens_are_parsed_in_the_list_format_unless_prefixed() {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+        let value_list = to_value_string("10, 0x20, 0b11", AnonymousValueStringFormat::Hexadecimal);
+        let data_values = AnonymousValueList::deanonymize(&data_type_ref, &value_list).unwrap();
+
+        assert_eq!(to_i32_values(&data_values), vec![0x10, 0x20, 0b11]);
+    }
+
+    #[test]
+    fn duplicate_values_are_dropped() {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+        let value_list = to_value_string("5, 6, 5, 0x5", AnonymousValueStringFormat::Decimal);
+        let data_values = AnonymousValueList::deanonymize(&data_type_ref, &value_list).unwrap();
+
+        assert_eq!(to_i32_values(&data_values), vec![5, 6]);
+    }
+
+    #[test]
+    fn every_failed_token_is_reported() {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+        let value_list = to_value_string("1017, abc, 2044, 9999999999", AnonymousValueStringFormat::Decimal);
+        let error = AnonymousValueList::deanonymize(&data_type_ref, &value_list).unwrap_err();
+
+        assert_eq!(error.failed_tokens, vec!["abc".to_string(), "9999999999".to_string()]);
+    }
+
+    #[test]
+    fn byte_arrays_do_not_support_value_lists() {
+        assert!(!AnonymousValueList::supports_value_lists(&DataTypeRef::new(DataTypeAob::DATA_TYPE_ID)));
+        assert!(AnonymousValueList::supports_value_lists(&DataTypeRef::new(DataTypeI32::DATA_TYPE_ID)));
+    }
+}
@@ -1,4 +1,6 @@
+pub mod anonymous_value_list;
 pub mod anonymous_value_string;
 pub mod anonymous_value_string_format;
 pub mod container_type;
 pub mod data_value;
+pub mod value_nudge;
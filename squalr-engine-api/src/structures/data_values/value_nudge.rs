@@ -0,0 +1,248 @@
+use crate::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+use crate::structures::data_types::built_in_types::f32be::data_type_f32be::DataTypeF32be;
+use crate::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+use crate::structures::data_types::built_in_types::f64be::data_type_f64be::DataTypeF64be;
+use crate::structures::data_types::built_in_types::i8::data_type_i8::DataTypeI8;
+use crate::structures::data_types::built_in_types::i16::data_type_i16::DataTypeI16;
+use crate::structures::data_types::built_in_types::i16be::data_type_i16be::DataTypeI16be;
+use crate::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use crate::structures::data_types::built_in_types::i32be::data_type_i32be::DataTypeI32be;
+use crate::structures::data_types::built_in_types::i64::data_type_i64::DataTypeI64;
+use crate::structures::data_types::built_in_types::i64be::data_type_i64be::DataTypeI64be;
+use crate::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use crate::structures::data_types::built_in_types::u16::data_type_u16::DataTypeU16;
+use crate::structures::data_types::built_in_types::u16be::data_type_u16be::DataTypeU16be;
+use crate::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use crate::structures::data_types::built_in_types::u32be::data_type_u32be::DataTypeU32be;
+use crate::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use crate::structures::data_types::built_in_types::u64be::data_type_u64be::DataTypeU64be;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::settings::value_overflow_policy::ValueOverflowPolicy;
+use serde::{Deserialize, Serialize};
+
+/// The size of a nudge. Integers step by 1 or 10, while floating point values step by 0.1 or 1.0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValueNudgeStep {
+    Small,
+    Large,
+}
+
+/// Which kinds of numeric values a nudge applies to, used to label nudges for a selection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueNudgeKind {
+    Integer,
+    FloatingPoint,
+}
+
+/// A small relative change to a numeric value, such as +1 or -0.1, applied in the arithmetic of the value's own data type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueNudge {
+    step: ValueNudgeStep,
+    is_decrement: bool,
+}
+
+macro_rules! nudge_integer {
+    ($primitive:ty, $from_bytes:ident, $to_bytes:ident, $value_bytes:expr, $magnitude:expr, $is_decrement:expr, $overflow_policy:expr) => {{
+        let value = <$primitive>::$from_bytes($value_bytes.try_into().ok()?);
+        let magnitude = $magnitude as $primitive;
+        let nudged_value = match ($overflow_policy, $is_decrement) {
+            (ValueOverflowPolicy::Saturate, false) => value.saturating_add(magnitude),
+            (ValueOverflowPolicy::Saturate, true) => value.saturating_sub(magnitude),
+            (ValueOverflowPolicy::Wrap, false) => value.wrapping_add(magnitude),
+            (ValueOverflowPolicy::Wrap, true) => value.wrapping_sub(magnitude),
+        };
+
+        nudged_value.$to_bytes().to_vec()
+    }};
+}
+
+macro_rules! nudge_float {
+    ($primitive:ty, $from_bytes:ident, $to_bytes:ident, $value_bytes:expr, $magnitude:expr, $is_decrement:expr) => {{
+        let value = <$primitive>::$from_bytes($value_bytes.try_into().ok()?);
+        let magnitude = $magnitude as $primitive;
+        let nudged_value = if $is_decrement { value - magnitude } else { value + magnitude };
+
+        nudged_value.$to_bytes().to_vec()
+    }};
+}
+
+impl ValueNudge {
+    /// Every nudge, in the order they are shown: largest decrement first, largest increment last.
+    pub const ALL: [ValueNudge; 4] = [
+        ValueNudge::new(ValueNudgeStep::Large, true),
+        ValueNudge::new(ValueNudgeStep::Small, true),
+        ValueNudge::new(ValueNudgeStep::Small, false),
+        ValueNudge::new(ValueNudgeStep::Large, false),
+    ];
+
+    pub const fn new(
+        step: ValueNudgeStep,
+        is_decrement: bool,
+    ) -> Self {
+        Self { step, is_decrement }
+    }
+
+    pub fn get_step(&self) -> ValueNudgeStep {
+        self.step
+    }
+
+    pub fn is_decrement(&self) -> bool {
+        self.is_decrement
+    }
+
+    /// Gets the kind of nudge arithmetic used for a data type, or `None` if values of the type cannot be nudged.
+    pub fn get_kind(data_type_id: &str) -> Option<ValueNudgeKind> {
+        match data_type_id {
+            DataTypeU8::DATA_TYPE_ID
+            | DataTypeU16::DATA_TYPE_ID
+            | DataTypeU16be::DATA_TYPE_ID
+            | DataTypeU32::DATA_TYPE_ID
+            | DataTypeU32be::DATA_TYPE_ID
+            | DataTypeU64::DATA_TYPE_ID
+            | DataTypeU64be::DATA_TYPE_ID
+            | DataTypeI8::DATA_TYPE_ID
+            | DataTypeI16::DATA_TYPE_ID
+            | DataTypeI16be::DATA_TYPE_ID
+            | DataTypeI32::DATA_TYPE_ID
+            | DataTypeI32be::DATA_TYPE_ID
+            | DataTypeI64::DATA_TYPE_ID
+            | DataTypeI64be::DATA_TYPE_ID => Some(ValueNudgeKind::Integer),
+            DataTypeF32::DATA_TYPE_ID | DataTypeF32be::DATA_TYPE_ID | DataTypeF64::DATA_TYPE_ID | DataTypeF64be::DATA_TYPE_ID => {
+                Some(ValueNudgeKind::FloatingPoint)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the button label for this nudge, given which kinds of values it will apply to. When a selection mixes integers
+    /// and floating point values, both step sizes are shown.
+    pub fn get_label(
+        &self,
+        has_integers: bool,
+        has_floating_points: bool,
+    ) -> String {
+        let sign = if self.is_decrement { "-" } else { "+" };
+        let integer_label = format!("{}{}", sign, self.get_integer_magnitude());
+        let floating_point_label = format!("{}{:.1}", sign, self.get_floating_point_magnitude());
+
+        match (has_integers, has_floating_points) {
+            (true, true) => format!("{} / {}", integer_label, floating_point_label),
+            (false, true) => floating_point_label,
+            _ => integer_label,
+        }
+    }
+
+    /// Applies this nudge to a value, returning the nudged value. Returns `None` if the value is not a single numeric
+    /// element, ie it is a string, boolean, array, or custom type.
+    pub fn apply(
+        &self,
+        data_value: &DataValue,
+        overflow_policy: ValueOverflowPolicy,
+    ) -> Option<DataValue> {
+        let value_bytes = data_value.get_value_bytes().as_slice();
+        let integer_magnitude = self.get_integer_magnitude();
+        let floating_point_magnitude = self.get_floating_point_magnitude();
+        let is_decrement = self.is_decrement;
+
+        let nudged_bytes = match data_value.get_data_type_id() {
+            DataTypeU8::DATA_TYPE_ID => nudge_integer!(u8, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU16::DATA_TYPE_ID => nudge_integer!(u16, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU16be::DATA_TYPE_ID => nudge_integer!(u16, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU32::DATA_TYPE_ID => nudge_integer!(u32, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU32be::DATA_TYPE_ID => nudge_integer!(u32, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU64::DATA_TYPE_ID => nudge_integer!(u64, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeU64be::DATA_TYPE_ID => nudge_integer!(u64, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI8::DATA_TYPE_ID => nudge_integer!(i8, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI16::DATA_TYPE_ID => nudge_integer!(i16, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI16be::DATA_TYPE_ID => nudge_integer!(i16, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI32::DATA_TYPE_ID => nudge_integer!(i32, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI32be::DATA_TYPE_ID => nudge_integer!(i32, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI64::DATA_TYPE_ID => nudge_integer!(i64, from_le_bytes, to_le_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeI64be::DATA_TYPE_ID => nudge_integer!(i64, from_be_bytes, to_be_bytes, value_bytes, integer_magnitude, is_decrement, overflow_policy),
+            DataTypeF32::DATA_TYPE_ID => nudge_float!(f32, from_le_bytes, to_le_bytes, value_bytes, floating_point_magnitude, is_decrement),
+            DataTypeF32be::DATA_TYPE_ID => nudge_float!(f32, from_be_bytes, to_be_bytes, value_bytes, floating_point_magnitude, is_decrement),
+            DataTypeF64::DATA_TYPE_ID => nudge_float!(f64, from_le_bytes, to_le_bytes, value_bytes, floating_point_magnitude, is_decrement),
+            DataTypeF64be::DATA_TYPE_ID => nudge_float!(f64, from_be_bytes, to_be_bytes, value_bytes, floating_point_magnitude, is_decrement),
+            _ => return None,
+        };
+
+        Some(DataValue::new(data_value.get_data_type_ref().clone(), nudged_bytes))
+    }
+
+    fn get_integer_magnitude(&self) -> u8 {
+        match self.step {
+            ValueNudgeStep::Small => 1,
+            ValueNudgeStep::Large => 10,
+        }
+    }
+
+    fn get_floating_point_magnitude(&self) -> f64 {
+        match self.step {
+            ValueNudgeStep::Small => 0.1,
+            ValueNudgeStep::Large => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValueNudge, ValueNudgeStep};
+    use crate::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+    use crate::structures::data_types::built_in_types::i16be::data_type_i16be::DataTypeI16be;
+    use crate::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::data_values::data_value::DataValue;
+    use crate::structures::settings::value_overflow_policy::ValueOverflowPolicy;
+
+    const SMALL_INCREMENT: ValueNudge = ValueNudge::new(ValueNudgeStep::Small, false);
+    const LARGE_DECREMENT: ValueNudge = ValueNudge::new(ValueNudgeStep::Large, true);
+
+    #[test]
+    fn integers_saturate_or_wrap_at_their_bounds() {
+        let value = DataTypeU8::get_value_from_primitive(255);
+
+        assert_eq!(
+            SMALL_INCREMENT.apply(&value, ValueOverflowPolicy::Saturate),
+            Some(DataTypeU8::get_value_from_primitive(255))
+        );
+        assert_eq!(
+            SMALL_INCREMENT.apply(&value, ValueOverflowPolicy::Wrap),
+            Some(DataTypeU8::get_value_from_primitive(0))
+        );
+        assert_eq!(
+            LARGE_DECREMENT.apply(&DataTypeU8::get_value_from_primitive(4), ValueOverflowPolicy::Saturate),
+            Some(DataTypeU8::get_value_from_primitive(0))
+        );
+    }
+
+    #[test]
+    fn big_endian_values_keep_their_byte_order() {
+        let value = DataTypeI16be::get_value_from_primitive(-5);
+
+        assert_eq!(
+            LARGE_DECREMENT.apply(&value, ValueOverflowPolicy::Saturate),
+            Some(DataTypeI16be::get_value_from_primitive(-15))
+        );
+    }
+
+    #[test]
+    fn floating_point_values_nudge_by_tenths() {
+        let value = DataTypeF32::get_value_from_primitive(1.5);
+
+        assert_eq!(
+            SMALL_INCREMENT.apply(&value, ValueOverflowPolicy::Saturate),
+            Some(DataTypeF32::get_value_from_primitive(1.5 + 0.1))
+        );
+        assert_eq!(SMALL_INCREMENT.get_label(false, true), "+0.1");
+        assert_eq!(LARGE_DECREMENT.get_label(true, true), "-10 / -1.0");
+    }
+
+    #[test]
+    fn arrays_and_non_numeric_values_are_not_nudged() {
+        let array_value = DataValue::new(DataTypeRef::new(DataTypeU8::DATA_TYPE_ID), vec![1, 2]);
+        let string_value = DataValue::new(DataTypeRef::new("string_utf8"), vec![b'a']);
+
+        assert_eq!(SMALL_INCREMENT.apply(&array_value, ValueOverflowPolicy::Saturate), None);
+        assert_eq!(SMALL_INCREMENT.apply(&string_value, ValueOverflowPolicy::Saturate), None);
+    }
+}
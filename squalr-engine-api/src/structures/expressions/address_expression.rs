@@ -0,0 +1,752 @@
+use crate::structures::expressions::address_resolve_error::AddressResolveError;
+use crate::structures::memory::address_layout::AddressLayout;
+use crate::structures::memory::normalized_module::NormalizedModule;
+use crate::structures::memory::resolved_address::ResolvedAddress;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressOperator {
+    Add,
+    Subtract,
+    Multiply,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AddressExpressionNode {
+    Literal(u64),
+    /// A module name, ie `game.exe` or `"My Game.exe"`, resolved to the module base when the expression is resolved.
+    Module(String),
+    /// The size of a module, written as `size` after the module that it is added to, ie `game.exe+size` for the end of `game.exe`.
+    ModuleSize(String),
+    Negate(Box<AddressExpressionNode>),
+    /// A bracketed expression, ie `[game.exe+1A0]`, resolved to the pointer stored at the address it evaluates to.
+    Dereference(Box<AddressExpressionNode>),
+    Binary {
+        operator: AddressOperator,
+        left: Box<AddressExpressionNode>,
+        right: Box<AddressExpressionNode>,
+    },
+}
+
+/// A user entered address, ie `7FF6A000`, `game.exe+1A0`, `[game.exe+1A0]+18`, or `"My Game.exe" + 0x20 * 4`.
+/// Numbers are hexadecimal, with an optional `0x` prefix, unless they are all decimal digits followed by `d`, ie `256d`. This
+/// means `10d` is decimal, whereas `0x10d` is hexadecimal. Any other word is a module name, and names containing characters other
+/// than letters, digits, `_` and `.` must be quoted. Supports `+ - *`, unary minus, parentheses, and brackets to read the pointer
+/// at an address. Adding `size` to a module name, ie `game.exe+size`, gives the end of the module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressExpression {
+    root: AddressExpressionNode,
+}
+
+impl AddressExpression {
+    pub fn parse(expression_string: &str) -> Result<Self, AddressResolveError> {
+        let mut parser = AddressExpressionParser {
+            characters: expression_string.chars().collect(),
+            position: 0,
+        };
+
+        parser.skip_whitespace();
+
+        if parser.is_at_end() {
+            return Err(AddressResolveError::Empty);
+        }
+
+        let root = parser.parse_sum()?;
+
+        parser.skip_whitespace();
+
+        if !parser.is_at_end() {
+            return Err(AddressResolveError::syntax_error("Unexpected character", parser.position));
+        }
+
+        Ok(Self { root })
+    }
+
+    pub fn get_root(&self) -> &AddressExpressionNode {
+        &self.root
+    }
+
+    /// Evaluates this expression, looking up the base address and size of each module and the pointer stored at each dereferenced
+    /// address with the given resolvers. Intermediate values may be negative, but the final address must fit in 64 bits.
+    pub fn evaluate<F, R>(
+        &self,
+        resolve_module: F,
+        read_pointer: R,
+    ) -> Result<u64, AddressResolveError>
+    where
+        F: Fn(&str) -> Option<(u64, u64)>,
+        R: Fn(u64) -> Option<u64>,
+    {
+        let address = Self::evaluate_node(&self.root, &resolve_module, &read_pointer)?;
+
+        u64::try_from(address).map_err(|_| AddressResolveError::Overflow)
+    }
+
+    /// Resolves this expression against the modules and regions of a process, describing where the resulting address lies.
+    pub fn resolve(
+        &self,
+        address_layout: &impl AddressLayout,
+    ) -> Result<ResolvedAddress, AddressResolveError> {
+        let modules = address_layout.get_modules();
+        let address = self.evaluate(
+            |module_name| Self::find_module(modules, module_name).map(|module| (module.get_base_address(), module.get_region_size())),
+            |pointer_address| address_layout.read_pointer(pointer_address),
+        )?;
+        let (module_name, module_offset) = modules
+            .iter()
+            .find(|module| module.contains_address(address))
+            .map(|module| (module.get_module_name().to_string(), address - module.get_base_address()))
+            .unwrap_or_default();
+
+        Ok(ResolvedAddress {
+            address,
+            module_name,
+            module_offset,
+            protection: address_layout.get_protection(address),
+            is_readable: address_layout.is_readable(address),
+        })
+    }
+
+    /// Finds a module by name, ignoring case. Names without an extension also match, ie `game` finds `game.exe`.
+    fn find_module<'modules>(
+        modules: &'modules [NormalizedModule],
+        module_name: &str,
+    ) -> Option<&'modules NormalizedModule> {
+        modules
+            .iter()
+            .find(|module| module.get_module_name().eq_ignore_ascii_case(module_name))
+            .or_else(|| {
+                modules.iter().find(|module| {
+                    module
+                        .get_module_name()
+                        .split_once('.')
+                        .is_some_and(|(module_stem, _extension)| module_stem.eq_ignore_ascii_case(module_name))
+                })
+            })
+    }
+
+    fn evaluate_node<F, R>(
+        node: &AddressExpressionNode,
+        resolve_module: &F,
+        read_pointer: &R,
+    ) -> Result<i128, AddressResolveError>
+    where
+        F: Fn(&str) -> Option<(u64, u64)>,
+        R: Fn(u64) -> Option<u64>,
+    {
+        match node {
+            AddressExpressionNode::Literal(value) => Ok(*value as i128),
+            AddressExpressionNode::Module(module_name) => resolve_module(module_name)
+                .map(|(base_address, _module_size)| base_address as i128)
+                .ok_or_else(|| AddressResolveError::UnknownModule {
+                    module_name: module_name.clone(),
+                }),
+            AddressExpressionNode::ModuleSize(module_name) => resolve_module(module_name)
+                .map(|(_base_address, module_size)| module_size as i128)
+                .ok_or_else(|| AddressResolveError::UnknownModule {
+                    module_name: module_name.clone(),
+                }),
+            AddressExpressionNode::Negate(operand) => Self::evaluate_node(operand, resolve_module, read_pointer)?
+                .checked_neg()
+                .ok_or(AddressResolveError::Overflow),
+            AddressExpressionNode::Dereference(operand) => {
+                let pointer_address = Self::evaluate_node(operand, resolve_module, read_pointer)?;
+                let pointer_address = u64::try_from(pointer_address).map_err(|_| AddressResolveError::Overflow)?;
+
+                read_pointer(pointer_address)
+                    .map(|pointer_value| pointer_value as i128)
+                    .ok_or(AddressResolveError::UnreadablePointer { address: pointer_address })
+            }
+            AddressExpressionNode::Binary { operator, left, right } => {
+                let left = Self::evaluate_node(left, resolve_module, read_pointer)?;
+                let right = Self::evaluate_node(right, resolve_module, read_pointer)?;
+                let result = match operator {
+                    AddressOperator::Add => left.checked_add(right),
+                    AddressOperator::Subtract => left.checked_sub(right),
+                    AddressOperator::Multiply => left.checked_mul(right),
+                };
+
+                result.ok_or(AddressResolveError::Overflow)
+            }
+        }
+    }
+}
+
+impl FromStr for AddressExpression {
+    type Err = AddressResolveError;
+
+    fn from_str(expression_string: &str) -> Result<Self, Self::Err> {
+        Self::parse(expression_string)
+    }
+}
+
+/// A recursive descent parser, with one function per precedence level.
+struct AddressExpressionParser {
+    characters: Vec<char>,
+    position: usize,
+}
+
+impl AddressExpressionParser {
+    fn is_at_end(&self) -> bool {
+        self.position >= self.characters.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.characters.get(self.position).copied()
+    }
+
+    fn is_word_character(character: char) -> bool {
+        character.is_ascii_alphanumeric() || character == '_' || character == '.'
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    /// Skips the `size` keyword if it is the next word, such that `game.exe+size` means the size of `game.exe` rather than a module
+    /// named `size`. A quoted `"size"` is still a module name.
+    fn skip_size_keyword(&mut self) -> bool {
+        self.skip_whitespace();
+
+        let word_end = self.position + "size".len();
+        let is_size_keyword = self
+            .characters
+            .get(self.position..word_end)
+            .is_some_and(|word| word.iter().collect::<String>().eq_ignore_ascii_case("size"))
+            && !self
+                .characters
+                .get(word_end)
+                .copied()
+                .is_some_and(Self::is_word_character);
+
+        if is_size_keyword {
+            self.position = word_end;
+        }
+
+        is_size_keyword
+    }
+
+    fn parse_sum(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        let mut left = self.parse_product()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = match self.peek() {
+                Some('+') => AddressOperator::Add,
+                Some('-') => AddressOperator::Subtract,
+                _ => return Ok(left),
+            };
+
+            self.position += 1;
+
+            let right = match &left {
+                AddressExpressionNode::Module(module_name) if operator == AddressOperator::Add && self.skip_size_keyword() => {
+                    AddressExpressionNode::ModuleSize(module_name.clone())
+                }
+                _ => self.parse_product()?,
+            };
+
+            left = AddressExpressionNode::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        let mut left = self.parse_unary()?;
+
+        loop {
+            self.skip_whitespace();
+
+            if self.peek() != Some('*') {
+                return Ok(left);
+            }
+
+            self.position += 1;
+
+            let right = self.parse_unary()?;
+
+            left = AddressExpressionNode::Binary {
+                operator: AddressOperator::Multiply,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+
+            return Ok(AddressExpressionNode::Negate(Box::new(self.parse_unary()?)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('(') => {
+                let open_position = self.position;
+
+                self.position += 1;
+
+                let inner = self.parse_sum()?;
+
+                self.skip_whitespace();
+
+                if self.peek() != Some(')') {
+                    return Err(AddressResolveError::syntax_error("Unclosed '('", open_position));
+                }
+
+                self.position += 1;
+
+                Ok(inner)
+            }
+            Some('[') => {
+                let open_position = self.position;
+
+                self.position += 1;
+
+                let inner = self.parse_sum()?;
+
+                self.skip_whitespace();
+
+                if self.peek() != Some(']') {
+                    return Err(AddressResolveError::syntax_error("Unclosed '['", open_position));
+                }
+
+                self.position += 1;
+
+                Ok(AddressExpressionNode::Dereference(Box::new(inner)))
+            }
+            Some('"') => self.parse_quoted_module(),
+            Some(character) if Self::is_word_character(character) => self.parse_word(),
+            Some(_) => Err(AddressResolveError::syntax_error(
+                "Expected an address, module name, '(', or '['",
+                self.position,
+            )),
+            None => Err(AddressResolveError::syntax_error("Unexpected end of expression", self.position)),
+        }
+    }
+
+    fn parse_quoted_module(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        let open_position = self.position;
+
+        self.position += 1;
+
+        let name_start = self.position;
+
+        while self.peek().is_some_and(|character| character != '"') {
+            self.position += 1;
+        }
+
+        if self.is_at_end() {
+            return Err(AddressResolveError::syntax_error("Unclosed '\"'", open_position));
+        }
+
+        let module_name: String = self.characters[name_start..self.position].iter().collect();
+        let module_name = module_name.trim();
+
+        self.position += 1;
+
+        if module_name.is_empty() {
+            return Err(AddressResolveError::syntax_error("Empty module name", open_position));
+        }
+
+        Ok(AddressExpressionNode::Module(module_name.to_string()))
+    }
+
+    /// Parses a hexadecimal number, a decimal number suffixed with `d`, or a module name if the word is neither.
+    fn parse_word(&mut self) -> Result<AddressExpressionNode, AddressResolveError> {
+        let word_start = self.position;
+
+        while self.peek().is_some_and(Self::is_word_character) {
+            self.position += 1;
+        }
+
+        let word: String = self.characters[word_start..self.position].iter().collect();
+        let decimal_digits = word.strip_suffix('d').or_else(|| word.strip_suffix('D'));
+
+        if let Some(decimal_digits) = decimal_digits.filter(|decimal_digits| {
+            !decimal_digits.is_empty()
+                && decimal_digits
+                    .chars()
+                    .all(|character| character.is_ascii_digit())
+        }) {
+            return decimal_digits
+                .parse::<u64>()
+                .map(AddressExpressionNode::Literal)
+                .map_err(|_| AddressResolveError::Overflow);
+        }
+        let (hex_digits, is_prefixed) = match word.strip_prefix("0x").or_else(|| word.strip_prefix("0X")) {
+            Some(hex_digits) => (hex_digits, true),
+            None => (word.as_str(), false),
+        };
+        let is_hex = !hex_digits.is_empty()
+            && hex_digits
+                .chars()
+                .all(|character| character.is_ascii_hexdigit());
+
+        if is_hex {
+            return u64::from_str_radix(hex_digits, 16)
+                .map(AddressExpressionNode::Literal)
+                .map_err(|_| AddressResolveError::Overflow);
+        }
+
+        if is_prefixed {
+            return Err(AddressResolveError::syntax_error(&format!("Invalid hexadecimal number '{}'", word), word_start));
+        }
+
+        Ok(AddressExpressionNode::Module(word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddressExpression, AddressExpressionNode, AddressOperator};
+    use crate::structures::expressions::address_resolve_error::AddressResolveError;
+    use crate::structures::memory::address_layout::AddressLayout;
+    use crate::structures::memory::normalized_module::NormalizedModule;
+    use crate::structures::memory::resolved_address::AddressProtection;
+
+    const READ_EXECUTE: AddressProtection = AddressProtection {
+        is_readable: true,
+        is_writable: false,
+        is_executable: true,
+    };
+    const READ_WRITE: AddressProtection = AddressProtection {
+        is_readable: true,
+        is_writable: true,
+        is_executable: false,
+    };
+    const GUARD: AddressProtection = AddressProtection {
+        is_readable: false,
+        is_writable: false,
+        is_executable: false,
+    };
+
+    /// Two modules, with the code and data regions of the game, a guard page, and a heap region outside of any module. The game
+    /// data holds a pointer into the heap, which holds a pointer back into the game data.
+    struct MockAddressLayout {
+        modules: Vec<NormalizedModule>,
+        regions: Vec<(u64, u64, AddressProtection)>,
+        pointers: Vec<(u64, u64)>,
+    }
+
+    impl MockAddressLayout {
+        fn new() -> Self {
+            Self {
+                modules: vec![
+                    NormalizedModule::new("Game.exe", 0x1_4000_0000, 0x20000),
+                    NormalizedModule::new("kernel32.dll", 0x7FFA_0000_0000, 0x1000),
+                ],
+                regions: vec![
+                    (0x1_4000_0000, 0x10000, READ_EXECUTE),
+                    (0x1_4001_0000, 0xF000, READ_WRITE),
+                    (0x1_4001_F000, 0x1000, GUARD),
+                    (0x2000_0000, 0x8000, READ_WRITE),
+                ],
+                pointers: vec![(0x1_4001_0100, 0x2000_0040), (0x2000_0058, 0x1_4001_0200)],
+            }
+        }
+    }
+
+    impl AddressLayout for MockAddressLayout {
+        fn get_modules(&self) -> &[NormalizedModule] {
+            &self.modules
+        }
+
+        fn get_protection(
+            &self,
+            address: u64,
+        ) -> Option<AddressProtection> {
+            self.regions
+                .iter()
+                .find(|(base_address, region_size, _protection)| address >= *base_address && address < base_address + region_size)
+                .map(|(_base_address, _region_size, protection)| *protection)
+        }
+
+        fn is_readable(
+            &self,
+            address: u64,
+        ) -> bool {
+            self.get_protection(address)
+                .is_some_and(|protection| protection.is_readable)
+        }
+
+        fn read_pointer(
+            &self,
+            address: u64,
+        ) -> Option<u64> {
+            self.pointers
+                .iter()
+                .find(|(pointer_address, _pointer_value)| *pointer_address == address)
+                .map(|(_pointer_address, pointer_value)| *pointer_value)
+        }
+    }
+
+    fn evaluate(expression_string: &str) -> Result<u64, AddressResolveError> {
+        AddressExpression::parse(expression_string)?.evaluate(
+            |module_name| (module_name == "game.exe").then_some((0x1000, 0x800)),
+            |address| (address == 0x1018).then_some(0x5000),
+        )
+    }
+
+    #[test]
+    fn words_parse_as_hex_numbers_unless_they_are_module_names() {
+        assert_eq!(evaluate("7FF6A000"), Ok(0x7FF6A000));
+        assert_eq!(evaluate(" 0x7ff6a000 "), Ok(0x7FF6A000));
+        assert_eq!(evaluate("FFFFFFFFFFFFFFFF"), Ok(u64::MAX));
+        assert_eq!(
+            AddressExpression::parse("game.exe+1A0").unwrap().get_root(),
+            &AddressExpressionNode::Binary {
+                operator: AddressOperator::Add,
+                left: Box::new(AddressExpressionNode::Module("game.exe".to_string())),
+                right: Box::new(AddressExpressionNode::Literal(0x1A0)),
+            }
+        );
+        assert_eq!(
+            AddressExpression::parse("\" My Game-Win64.exe \"")
+                .unwrap()
+                .get_root(),
+            &AddressExpressionNode::Module("My Game-Win64.exe".to_string())
+        );
+        assert_eq!(
+            AddressExpression::parse("kernel32").unwrap().get_root(),
+            &AddressExpressionNode::Module("kernel32".to_string())
+        );
+    }
+
+    #[test]
+    fn words_ending_in_d_parse_as_decimal_numbers_when_their_digits_are_decimal() {
+        assert_eq!(evaluate("256d"), Ok(0x100));
+        assert_eq!(evaluate("game.exe+16D"), Ok(0x1010));
+        assert_eq!(evaluate("0x10d"), Ok(0x10D));
+        assert_eq!(evaluate("1Ad"), Ok(0x1AD));
+        assert_eq!(evaluate("18446744073709551615d"), Ok(u64::MAX));
+        assert_eq!(AddressExpression::parse("18446744073709551616d"), Err(AddressResolveError::Overflow));
+    }
+
+    #[test]
+    fn brackets_dereference_the_pointer_at_an_address() {
+        assert_eq!(
+            AddressExpression::parse("[game.exe+18]+4").unwrap().get_root(),
+            &AddressExpressionNode::Binary {
+                operator: AddressOperator::Add,
+                left: Box::new(AddressExpressionNode::Dereference(Box::new(AddressExpressionNode::Binary {
+                    operator: AddressOperator::Add,
+                    left: Box::new(AddressExpressionNode::Module("game.exe".to_string())),
+                    right: Box::new(AddressExpressionNode::Literal(0x18)),
+                }))),
+                right: Box::new(AddressExpressionNode::Literal(0x4)),
+            }
+        );
+        assert_eq!(evaluate("[game.exe+18]+4"), Ok(0x5004));
+        assert_eq!(evaluate("[ game.exe + 24d ] - 10"), Ok(0x4FF0));
+        assert_eq!(evaluate("[1018] * 2"), Ok(0xA000));
+        assert_eq!(evaluate("[game.exe+20]"), Err(AddressResolveError::UnreadablePointer { address: 0x1020 }));
+        assert_eq!(evaluate("[-1]"), Err(AddressResolveError::Overflow));
+    }
+
+    #[test]
+    fn nested_brackets_follow_each_pointer_in_turn() {
+        let address_layout = MockAddressLayout::new();
+        let resolved_address = AddressExpression::parse("[[game.exe+10100]+18]+8")
+            .unwrap()
+            .resolve(&address_layout)
+            .unwrap();
+
+        assert_eq!(resolved_address.address, 0x1_4001_0208);
+        assert_eq!(resolved_address.module_name, "Game.exe");
+        assert_eq!(resolved_address.module_offset, 0x10208);
+        assert_eq!(
+            AddressExpression::parse("[[game.exe+10100]+10]")
+                .unwrap()
+                .resolve(&address_layout),
+            Err(AddressResolveError::UnreadablePointer { address: 0x2000_0050 })
+        );
+        assert_eq!(
+            AddressExpression::parse("[[client.dll+10]+18]")
+                .unwrap()
+                .resolve(&address_layout),
+            Err(AddressResolveError::UnknownModule {
+                module_name: "client.dll".to_string()
+            })
+        );
+        assert_eq!(
+            AddressExpression::parse("[[game.exe+10100]+18"),
+            Err(AddressResolveError::syntax_error("Unclosed '['", 0))
+        );
+        assert_eq!(
+            AddressExpression::parse("[game.exe+(10]"),
+            Err(AddressResolveError::syntax_error("Unclosed '('", 10))
+        );
+        assert!(matches!(AddressExpression::parse("[]"), Err(AddressResolveError::Syntax { position: 1, .. })));
+        assert_eq!(
+            AddressExpression::parse("game.exe]"),
+            Err(AddressResolveError::syntax_error("Unexpected character", 8))
+        );
+    }
+
+    #[test]
+    fn evaluate_respects_precedence_and_allows_negative_intermediates() {
+        assert_eq!(evaluate("game.exe + 10 * 2"), Ok(0x1020));
+        assert_eq!(evaluate("(game.exe + 10) * 2"), Ok(0x2020));
+        assert_eq!(evaluate("-10 + game.exe"), Ok(0xFF0));
+        assert_eq!(evaluate("game.exe - -(4)"), Ok(0x1004));
+        assert_eq!(evaluate("10 - 20 + 20"), Ok(0x10));
+    }
+
+    #[test]
+    fn size_after_a_module_name_is_the_size_of_that_module() {
+        assert_eq!(
+            AddressExpression::parse("game.exe + SIZE").unwrap().get_root(),
+            &AddressExpressionNode::Binary {
+                operator: AddressOperator::Add,
+                left: Box::new(AddressExpressionNode::Module("game.exe".to_string())),
+                right: Box::new(AddressExpressionNode::ModuleSize("game.exe".to_string())),
+            }
+        );
+        assert_eq!(evaluate("game.exe+size"), Ok(0x1800));
+        assert_eq!(evaluate("game.exe+size-10"), Ok(0x17F0));
+        assert_eq!(
+            AddressExpression::parse("game.exe+sizes").unwrap().get_root(),
+            &AddressExpressionNode::Binary {
+                operator: AddressOperator::Add,
+                left: Box::new(AddressExpressionNode::Module("game.exe".to_string())),
+                right: Box::new(AddressExpressionNode::Module("sizes".to_string())),
+            }
+        );
+        assert_eq!(
+            evaluate("\"size\""),
+            Err(AddressResolveError::UnknownModule {
+                module_name: "size".to_string()
+            })
+        );
+
+        let address_layout = MockAddressLayout::new();
+        let module_end = AddressExpression::parse("GAME+size")
+            .unwrap()
+            .resolve(&address_layout)
+            .unwrap();
+
+        assert_eq!(module_end.address, 0x1_4002_0000);
+    }
+
+    #[test]
+    fn errors_identify_the_failing_part_of_the_expression() {
+        assert_eq!(AddressExpression::parse("   "), Err(AddressResolveError::Empty));
+        assert_eq!(
+            AddressExpression::parse("game.exe + (10"),
+            Err(AddressResolveError::syntax_error("Unclosed '('", 11))
+        );
+        assert_eq!(
+            AddressExpression::parse("\"game.exe"),
+            Err(AddressResolveError::syntax_error("Unclosed '\"'", 0))
+        );
+        assert_eq!(
+            AddressExpression::parse("\"\" + 4"),
+            Err(AddressResolveError::syntax_error("Empty module name", 0))
+        );
+        assert_eq!(
+            AddressExpression::parse("0x12G4"),
+            Err(AddressResolveError::syntax_error("Invalid hexadecimal number '0x12G4'", 0))
+        );
+        assert_eq!(
+            AddressExpression::parse("10 / 2"),
+            Err(AddressResolveError::syntax_error("Unexpected character", 3))
+        );
+        assert!(matches!(AddressExpression::parse("10 +"), Err(AddressResolveError::Syntax { position: 4, .. })));
+        assert!(matches!(
+            AddressExpression::parse("game.exe+$"),
+            Err(AddressResolveError::Syntax { position: 9, .. })
+        ));
+        assert_eq!(
+            evaluate("client.dll+4"),
+            Err(AddressResolveError::UnknownModule {
+                module_name: "client.dll".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn addresses_outside_64_bits_overflow() {
+        assert_eq!(AddressExpression::parse("10000000000000000"), Err(AddressResolveError::Overflow));
+        assert_eq!(evaluate("FFFFFFFFFFFFFFFF + 1"), Err(AddressResolveError::Overflow));
+        assert_eq!(evaluate("game.exe - 1001"), Err(AddressResolveError::Overflow));
+        assert_eq!(
+            evaluate("FFFFFFFFFFFFFFFF * FFFFFFFFFFFFFFFF * FFFFFFFFFFFFFFFF"),
+            Err(AddressResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn resolve_finds_the_owning_module_and_region_protection() {
+        let address_layout = MockAddressLayout::new();
+        let resolved_address = AddressExpression::parse("game.exe+10020")
+            .unwrap()
+            .resolve(&address_layout)
+            .unwrap();
+
+        assert_eq!(resolved_address.address, 0x1_4001_0020);
+        assert_eq!(resolved_address.module_name, "Game.exe");
+        assert_eq!(resolved_address.module_offset, 0x10020);
+        assert_eq!(resolved_address.protection, Some(READ_WRITE));
+        assert!(resolved_address.is_readable);
+        assert_eq!(resolved_address.get_display_address(), "Game.exe+10020");
+
+        // Module names match regardless of case, or without their extension.
+        let resolved_code_address = AddressExpression::parse("GAME + 8")
+            .unwrap()
+            .resolve(&address_layout)
+            .unwrap();
+
+        assert_eq!(resolved_code_address.address, 0x1_4000_0008);
+        assert_eq!(resolved_code_address.protection, Some(READ_EXECUTE));
+    }
+
+    #[test]
+    fn resolve_reports_addresses_outside_modules_and_unreadable_memory() {
+        let address_layout = MockAddressLayout::new();
+        let resolve = |expression_string: &str| {
+            AddressExpression::parse(expression_string)
+                .unwrap()
+                .resolve(&address_layout)
+        };
+
+        let heap_address = resolve("20000010").unwrap();
+
+        assert_eq!(heap_address.module_name, "");
+        assert_eq!(heap_address.module_offset, 0);
+        assert_eq!(heap_address.get_display_address(), "20000010");
+        assert!(heap_address.is_readable);
+
+        let guard_address = resolve("game.exe+1F000").unwrap();
+
+        assert_eq!(guard_address.protection, Some(GUARD));
+        assert!(!guard_address.is_readable);
+
+        // Addresses within a module are not necessarily mapped.
+        let unmapped_address = resolve("kernel32.dll+800").unwrap();
+
+        assert_eq!(unmapped_address.module_name, "kernel32.dll");
+        assert_eq!(unmapped_address.protection, None);
+        assert!(!unmapped_address.is_readable);
+        assert_eq!(
+            resolve("user32.dll+10"),
+            Err(AddressResolveError::UnknownModule {
+                module_name: "user32.dll".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why an address expression could not be resolved against the opened process.
+#[derive(Clone, Debug, Error, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AddressResolveError {
+    #[error("Enter an address, ie 7FF6A000 or game.exe+1A0")]
+    Empty,
+
+    #[error("{message} at position {position}")]
+    Syntax { message: String, position: usize },
+
+    #[error("No module named '{module_name}' is loaded")]
+    UnknownModule { module_name: String },
+
+    #[error("The address does not fit in 64 bits")]
+    Overflow,
+
+    #[error("Could not read a pointer at {address:X}")]
+    UnreadablePointer { address: u64 },
+
+    #[error("No process is opened")]
+    NoProcess,
+
+    /// Reported by hosts connected to an engine that predates address resolution, rather than sending it a command it cannot read.
+    #[error("The connected engine cannot resolve addresses")]
+    Unsupported,
+}
+
+impl AddressResolveError {
+    pub fn syntax_error(
+        message: &str,
+        position: usize,
+    ) -> Self {
+        AddressResolveError::Syntax {
+            message: message.to_string(),
+            position,
+        }
+    }
+}
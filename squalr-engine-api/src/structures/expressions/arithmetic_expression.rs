@@ -0,0 +1,464 @@
+use crate::structures::expressions::expression_error::ExpressionError;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    ShiftLeft,
+    ShiftRight,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpressionNode {
+    Number(f64),
+    /// A whole number literal, kept exact such that integer evaluation does not round values beyond the precision of a float.
+    Integer(i128),
+    /// A named value in square brackets, ie `[hp]` or `[7FF6A000]`, resolved when the expression is evaluated.
+    Reference(String),
+    Negate(Box<ExpressionNode>),
+    Binary {
+        operator: ArithmeticOperator,
+        left: Box<ExpressionNode>,
+        right: Box<ExpressionNode>,
+    },
+}
+
+/// An arithmetic expression over numbers and bracketed references, ie `[hp] / [hp_max] * 100`.
+/// Supports `+ - * / %`, unary minus, parentheses, and decimal or `0x` prefixed hex literals. Integer evaluation also supports `<< >>`,
+/// which bind more loosely than `+ -`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArithmeticExpression {
+    root: ExpressionNode,
+}
+
+impl ArithmeticExpression {
+    pub fn parse(expression_string: &str) -> Result<Self, ExpressionError> {
+        let mut parser = ExpressionParser {
+            characters: expression_string.chars().collect(),
+            position: 0,
+        };
+
+        parser.skip_whitespace();
+
+        if parser.is_at_end() {
+            return Err(ExpressionError::parse_error("Expression is empty", 0));
+        }
+
+        let root = parser.parse_shift()?;
+
+        parser.skip_whitespace();
+
+        if !parser.is_at_end() {
+            return Err(ExpressionError::parse_error("Unexpected character", parser.position));
+        }
+
+        Ok(Self { root })
+    }
+
+    pub fn get_root(&self) -> &ExpressionNode {
+        &self.root
+    }
+
+    /// Gets the distinct reference names used by this expression, in the order they first appear.
+    pub fn get_references(&self) -> Vec<&str> {
+        let mut references = Vec::new();
+
+        Self::collect_references(&self.root, &mut references);
+
+        references
+    }
+
+    /// Evaluates this expression, looking up each reference with the given resolver.
+    pub fn evaluate<F>(
+        &self,
+        resolve_reference: F,
+    ) -> Result<f64, ExpressionError>
+    where
+        F: Fn(&str) -> Option<f64>,
+    {
+        Self::evaluate_node(&self.root, &resolve_reference)
+    }
+
+    /// Evaluates this expression with exact integer arithmetic, looking up each reference with the given resolver. Division truncates
+    /// toward zero, and fractional literals or results too large for an `i128` are errors rather than being rounded.
+    pub fn evaluate_integer<F>(
+        &self,
+        resolve_reference: F,
+    ) -> Result<i128, ExpressionError>
+    where
+        F: Fn(&str) -> Option<i128>,
+    {
+        Self::evaluate_integer_node(&self.root, &resolve_reference)
+    }
+
+    fn collect_references<'expression>(
+        node: &'expression ExpressionNode,
+        references: &mut Vec<&'expression str>,
+    ) {
+        match node {
+            ExpressionNode::Number(_) | ExpressionNode::Integer(_) => {}
+            ExpressionNode::Reference(name) => {
+                if !references.contains(&name.as_str()) {
+                    references.push(name);
+                }
+            }
+            ExpressionNode::Negate(operand) => Self::collect_references(operand, references),
+            ExpressionNode::Binary { left, right, .. } => {
+                Self::collect_references(left, references);
+                Self::collect_references(right, references);
+            }
+        }
+    }
+
+    fn evaluate_node<F>(
+        node: &ExpressionNode,
+        resolve_reference: &F,
+    ) -> Result<f64, ExpressionError>
+    where
+        F: Fn(&str) -> Option<f64>,
+    {
+        match node {
+            ExpressionNode::Number(value) => Ok(*value),
+            ExpressionNode::Integer(value) => Ok(*value as f64),
+            ExpressionNode::Reference(name) => resolve_reference(name).ok_or_else(|| ExpressionError::UnresolvedReference { name: name.clone() }),
+            ExpressionNode::Negate(operand) => Ok(-Self::evaluate_node(operand, resolve_reference)?),
+            ExpressionNode::Binary { operator, left, right } => {
+                let left = Self::evaluate_node(left, resolve_reference)?;
+                let right = Self::evaluate_node(right, resolve_reference)?;
+
+                match operator {
+                    ArithmeticOperator::Add => Ok(left + right),
+                    ArithmeticOperator::Subtract => Ok(left - right),
+                    ArithmeticOperator::Multiply => Ok(left * right),
+                    ArithmeticOperator::Divide | ArithmeticOperator::Remainder if right == 0.0 => Err(ExpressionError::DivisionByZero),
+                    ArithmeticOperator::Divide => Ok(left / right),
+                    ArithmeticOperator::Remainder => Ok(left % right),
+                    ArithmeticOperator::ShiftLeft | ArithmeticOperator::ShiftRight => Err(ExpressionError::ShiftOfNonInteger),
+                }
+            }
+        }
+    }
+
+    fn evaluate_integer_node<F>(
+        node: &ExpressionNode,
+        resolve_reference: &F,
+    ) -> Result<i128, ExpressionError>
+    where
+        F: Fn(&str) -> Option<i128>,
+    {
+        match node {
+            ExpressionNode::Number(value) => Err(ExpressionError::NotAnInteger { value: *value }),
+            ExpressionNode::Integer(value) => Ok(*value),
+            ExpressionNode::Reference(name) => resolve_reference(name).ok_or_else(|| ExpressionError::UnresolvedReference { name: name.clone() }),
+            ExpressionNode::Negate(operand) => Self::evaluate_integer_node(operand, resolve_reference)?
+                .checked_neg()
+                .ok_or(ExpressionError::Overflow),
+            ExpressionNode::Binary { operator, left, right } => {
+                let left = Self::evaluate_integer_node(left, resolve_reference)?;
+                let right = Self::evaluate_integer_node(right, resolve_reference)?;
+
+                match operator {
+                    ArithmeticOperator::Add => left.checked_add(right).ok_or(ExpressionError::Overflow),
+                    ArithmeticOperator::Subtract => left.checked_sub(right).ok_or(ExpressionError::Overflow),
+                    ArithmeticOperator::Multiply => left.checked_mul(right).ok_or(ExpressionError::Overflow),
+                    ArithmeticOperator::Divide | ArithmeticOperator::Remainder if right == 0 => Err(ExpressionError::DivisionByZero),
+                    ArithmeticOperator::Divide => left.checked_div(right).ok_or(ExpressionError::Overflow),
+                    ArithmeticOperator::Remainder => left.checked_rem(right).ok_or(ExpressionError::Overflow),
+                    // Shifting left is multiplying by a power of two, which unlike a plain shift reports the bits that fall off the end.
+                    ArithmeticOperator::ShiftLeft => u32::try_from(right)
+                        .ok()
+                        .and_then(|shift| 2i128.checked_pow(shift))
+                        .and_then(|factor| left.checked_mul(factor))
+                        .ok_or(ExpressionError::Overflow),
+                    ArithmeticOperator::ShiftRight => u32::try_from(right)
+                        .ok()
+                        .map(|shift| left >> shift.min(i128::BITS - 1))
+                        .ok_or(ExpressionError::Overflow),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for ArithmeticExpression {
+    type Err = ExpressionError;
+
+    fn from_str(expression_string: &str) -> Result<Self, Self::Err> {
+        Self::parse(expression_string)
+    }
+}
+
+/// A recursive descent parser, with one function per precedence level.
+struct ExpressionParser {
+    characters: Vec<char>,
+    position: usize,
+}
+
+impl ExpressionParser {
+    fn is_at_end(&self) -> bool {
+        self.position >= self.characters.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.characters.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn parse_shift(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        let mut left = self.parse_sum()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = match (self.peek(), self.characters.get(self.position + 1).copied()) {
+                (Some('<'), Some('<')) => ArithmeticOperator::ShiftLeft,
+                (Some('>'), Some('>')) => ArithmeticOperator::ShiftRight,
+                _ => return Ok(left),
+            };
+
+            self.position += 2;
+
+            let right = self.parse_sum()?;
+
+            left = ExpressionNode::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    fn parse_sum(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        let mut left = self.parse_product()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = match self.peek() {
+                Some('+') => ArithmeticOperator::Add,
+                Some('-') => ArithmeticOperator::Subtract,
+                _ => return Ok(left),
+            };
+
+            self.position += 1;
+
+            let right = self.parse_product()?;
+
+            left = ExpressionNode::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    fn parse_product(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        let mut left = self.parse_unary()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = match self.peek() {
+                Some('*') => ArithmeticOperator::Multiply,
+                Some('/') => ArithmeticOperator::Divide,
+                Some('%') => ArithmeticOperator::Remainder,
+                _ => return Ok(left),
+            };
+
+            self.position += 1;
+
+            let right = self.parse_unary()?;
+
+            left = ExpressionNode::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+
+            return Ok(ExpressionNode::Negate(Box::new(self.parse_unary()?)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('(') => {
+                let open_position = self.position;
+
+                self.position += 1;
+
+                let inner = self.parse_shift()?;
+
+                self.skip_whitespace();
+
+                if self.peek() != Some(')') {
+                    return Err(ExpressionError::parse_error("Unclosed '('", open_position));
+                }
+
+                self.position += 1;
+
+                Ok(inner)
+            }
+            Some('[') => self.parse_reference(),
+            Some(character) if character.is_ascii_digit() || character == '.' => self.parse_number(),
+            Some(_) => Err(ExpressionError::parse_error("Expected a number, [reference], or '('", self.position)),
+            None => Err(ExpressionError::parse_error("Unexpected end of expression", self.position)),
+        }
+    }
+
+    fn parse_reference(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        let open_position = self.position;
+
+        self.position += 1;
+
+        let name_start = self.position;
+
+        while self.peek().is_some_and(|character| character != ']') {
+            self.position += 1;
+        }
+
+        if self.is_at_end() {
+            return Err(ExpressionError::parse_error("Unclosed '['", open_position));
+        }
+
+        let name: String = self.characters[name_start..self.position].iter().collect();
+        let name = name.trim();
+
+        self.position += 1;
+
+        if name.is_empty() {
+            return Err(ExpressionError::parse_error("Empty reference", open_position));
+        }
+
+        Ok(ExpressionNode::Reference(name.to_string()))
+    }
+
+    fn parse_number(&mut self) -> Result<ExpressionNode, ExpressionError> {
+        let number_start = self.position;
+
+        while self
+            .peek()
+            .is_some_and(|character| character.is_ascii_alphanumeric() || character == '.')
+        {
+            self.position += 1;
+        }
+
+        let number_string: String = self.characters[number_start..self.position].iter().collect();
+        let value = match number_string
+            .strip_prefix("0x")
+            .or_else(|| number_string.strip_prefix("0X"))
+        {
+            Some(hex_digits) => u64::from_str_radix(hex_digits, 16)
+                .map(|value| ExpressionNode::Integer(value as i128))
+                .ok(),
+            None if number_string
+                .chars()
+                .all(|character| character.is_ascii_digit()) =>
+            {
+                number_string.parse::<i128>().ok().map(ExpressionNode::Integer)
+            }
+            None => number_string.parse::<f64>().ok().map(ExpressionNode::Number),
+        };
+
+        value.ok_or_else(|| ExpressionError::parse_error(&format!("Invalid number '{}'", number_string), number_start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArithmeticExpression;
+    use crate::structures::expressions::expression_error::ExpressionError;
+
+    #[test]
+    fn evaluate_respects_precedence_and_resolves_references() {
+        let expression = ArithmeticExpression::parse("[hp] / [hp_max] * 100").expect("expression should parse");
+        let resolve_reference = |name: &str| match name {
+            "hp" => Some(25.0),
+            "hp_max" => Some(200.0),
+            _ => None,
+        };
+
+        assert_eq!(expression.get_references(), vec!["hp", "hp_max"]);
+        assert_eq!(expression.evaluate(resolve_reference), Ok(12.5));
+        assert_eq!(
+            ArithmeticExpression::parse("2 + 3 * -(4 - 0x2) % 5")
+                .unwrap()
+                .evaluate(|_| None),
+            Ok(1.0)
+        );
+    }
+
+    #[test]
+    fn errors_identify_the_failing_part_of_the_expression() {
+        assert!(matches!(
+            ArithmeticExpression::parse("[hp] / ([hp_max]"),
+            Err(ExpressionError::ParseError { position: 7, .. })
+        ));
+        assert!(matches!(
+            ArithmeticExpression::parse("[hp"),
+            Err(ExpressionError::ParseError { position: 0, .. })
+        ));
+        assert!(matches!(ArithmeticExpression::parse("1 +"), Err(ExpressionError::ParseError { .. })));
+        assert_eq!(
+            ArithmeticExpression::parse("[hp] + [mp]")
+                .unwrap()
+                .evaluate(|name| (name == "hp").then_some(1.0)),
+            Err(ExpressionError::UnresolvedReference { name: "mp".to_string() })
+        );
+        assert_eq!(
+            ArithmeticExpression::parse("1 / 0").unwrap().evaluate(|_| None),
+            Err(ExpressionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn integer_evaluation_is_exact_and_supports_shifts() {
+        let evaluate_integer = |expression_string: &str| {
+            ArithmeticExpression::parse(expression_string)
+                .expect("expression should parse")
+                .evaluate_integer(|_| None)
+        };
+
+        assert_eq!(evaluate_integer("100*4"), Ok(400));
+        assert_eq!(evaluate_integer("0x1F4+8"), Ok(508));
+        assert_eq!(evaluate_integer("1<<16"), Ok(65_536));
+        assert_eq!(evaluate_integer("0x8000 >> 4"), Ok(0x800));
+
+        // Shifts bind more loosely than sums, and products more tightly.
+        assert_eq!(evaluate_integer("1 + 1 << 2 * 2"), Ok(32));
+        assert_eq!(evaluate_integer("-(0x10 - 1) * 2"), Ok(-30));
+        assert_eq!(evaluate_integer("-7 / 2"), Ok(-3));
+
+        // Floats would round this to 2^53.
+        assert_eq!(evaluate_integer("9007199254740993"), Ok(9_007_199_254_740_993));
+        assert_eq!(evaluate_integer("1 << 127"), Err(ExpressionError::Overflow));
+        assert_eq!(evaluate_integer("2.5 * 2"), Err(ExpressionError::NotAnInteger { value: 2.5 }));
+        assert_eq!(evaluate_integer("1 % 0"), Err(ExpressionError::DivisionByZero));
+        assert_eq!(
+            ArithmeticExpression::parse("1 << 2")
+                .unwrap()
+                .evaluate(|_| None),
+            Err(ExpressionError::ShiftOfNonInteger)
+        );
+    }
+}
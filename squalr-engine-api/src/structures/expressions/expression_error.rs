@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, PartialEq)]
+pub enum ExpressionError {
+    #[error("{message} at position {position}")]
+    ParseError { message: String, position: usize },
+
+    #[error("Unresolved reference [{name}]")]
+    UnresolvedReference { name: String },
+
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    #[error("{value} is not a whole number")]
+    NotAnInteger { value: f64 },
+
+    #[error("Shifts are only supported for whole numbers")]
+    ShiftOfNonInteger,
+
+    #[error("Result is too large")]
+    Overflow,
+}
+
+impl ExpressionError {
+    pub fn parse_error(
+        message: &str,
+        position: usize,
+    ) -> Self {
+        ExpressionError::ParseError {
+            message: message.to_string(),
+            position,
+        }
+    }
+}
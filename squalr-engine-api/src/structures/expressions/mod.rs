@@ -0,0 +1,4 @@
+pub mod address_expression;
+pub mod address_resolve_error;
+pub mod arithmetic_expression;
+pub mod expression_error;
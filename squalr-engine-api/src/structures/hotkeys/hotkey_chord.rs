@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A key along with the modifier keys held with it, ie `Ctrl+Numpad1`. Chords are normalized when parsed, such that the same
+/// combination always compares equal and has the same string form, regardless of how it was typed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HotkeyChord {
+    is_ctrl_down: bool,
+    is_shift_down: bool,
+    is_alt_down: bool,
+    key_name: String,
+}
+
+impl HotkeyChord {
+    /// The names of the keys that can be bound besides letters, digits, F1 to F24, and Numpad0 to Numpad9, in their canonical casing.
+    pub const NAMED_KEYS: [&str; 14] = [
+        "NumpadAdd",
+        "NumpadSubtract",
+        "NumpadMultiply",
+        "NumpadDivide",
+        "NumpadDecimal",
+        "Up",
+        "Down",
+        "Left",
+        "Right",
+        "PageUp",
+        "PageDown",
+        "Home",
+        "End",
+        "Insert",
+    ];
+
+    /// Creates a chord, failing if the key cannot be bound.
+    pub fn new(
+        is_ctrl_down: bool,
+        is_shift_down: bool,
+        is_alt_down: bool,
+        key_name: &str,
+    ) -> Result<Self, String> {
+        let key_name =
+            Self::normalize_key_name(key_name.trim()).ok_or_else(|| format!("'{}' is not a key that can be bound, ie F5 or Numpad1.", key_name.trim()))?;
+
+        Ok(Self {
+            is_ctrl_down,
+            is_shift_down,
+            is_alt_down,
+            key_name,
+        })
+    }
+
+    pub fn get_is_ctrl_down(&self) -> bool {
+        self.is_ctrl_down
+    }
+
+    pub fn get_is_shift_down(&self) -> bool {
+        self.is_shift_down
+    }
+
+    pub fn get_is_alt_down(&self) -> bool {
+        self.is_alt_down
+    }
+
+    pub fn get_key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    /// Gets the canonical casing of a key name, ie `numpad1` becomes `Numpad1`, or `None` if the key cannot be bound.
+    fn normalize_key_name(key_name: &str) -> Option<String> {
+        if key_name.len() == 1
+            && key_name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric())
+        {
+            Some(key_name.to_ascii_uppercase())
+        } else if let Some(number) = Self::parse_numbered_key_name(key_name, "F").filter(|number| (1..=24).contains(number)) {
+            Some(format!("F{}", number))
+        } else if let Some(number) = Self::parse_numbered_key_name(key_name, "Numpad").filter(|number| *number <= 9) {
+            Some(format!("Numpad{}", number))
+        } else {
+            Self::NAMED_KEYS
+                .iter()
+                .find(|named_key| named_key.eq_ignore_ascii_case(key_name))
+                .map(|named_key| named_key.to_string())
+        }
+    }
+
+    /// Parses the number of a key such as `F5`. Leading zeros are rejected, such that `F05` is not a second spelling of `F5`.
+    fn parse_numbered_key_name(
+        key_name: &str,
+        prefix: &str,
+    ) -> Option<u32> {
+        let number = key_name
+            .get(..prefix.len())
+            .filter(|key_prefix| key_prefix.eq_ignore_ascii_case(prefix))
+            .map(|_| &key_name[prefix.len()..])?;
+
+        if number.is_empty() || (number.len() > 1 && number.starts_with('0')) || !number.chars().all(|character| character.is_ascii_digit()) {
+            return None;
+        }
+
+        number.parse::<u32>().ok()
+    }
+}
+
+impl FromStr for HotkeyChord {
+    type Err = String;
+
+    /// Parses modifiers and a key joined by `+`, ie `Ctrl+Shift+F5`. Modifiers may appear in any order.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut is_ctrl_down = false;
+        let mut is_shift_down = false;
+        let mut is_alt_down = false;
+        let mut key_name = None;
+
+        for part in string.split('+').map(str::trim) {
+            if part.eq_ignore_ascii_case("ctrl") || part.eq_ignore_ascii_case("control") {
+                is_ctrl_down = true;
+            } else if part.eq_ignore_ascii_case("shift") {
+                is_shift_down = true;
+            } else if part.eq_ignore_ascii_case("alt") {
+                is_alt_down = true;
+            } else if key_name.replace(part).is_some() {
+                return Err(format!("'{}' has more than one key. Expected format: [Ctrl+][Shift+][Alt+]<key>", string));
+            }
+        }
+
+        match key_name {
+            Some(key_name) => Self::new(is_ctrl_down, is_shift_down, is_alt_down, key_name),
+            None => Err(format!("'{}' has no key. Expected format: [Ctrl+][Shift+][Alt+]<key>", string)),
+        }
+    }
+}
+
+impl fmt::Display for HotkeyChord {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if self.is_ctrl_down {
+            write!(formatter, "Ctrl+")?;
+        }
+
+        if self.is_shift_down {
+            write!(formatter, "Shift+")?;
+        }
+
+        if self.is_alt_down {
+            write!(formatter, "Alt+")?;
+        }
+
+        write!(formatter, "{}", self.key_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HotkeyChord;
+
+    #[test]
+    fn chords_are_normalized_regardless_of_casing_and_modifier_order() {
+        let chord = "shift + ctrl+numpad1".parse::<HotkeyChord>();
+
+        assert_eq!(chord, "Ctrl+Shift+Numpad1".parse::<HotkeyChord>());
+        assert_eq!(chord.map(|chord| chord.to_string()), Ok("Ctrl+Shift+Numpad1".to_string()));
+    }
+
+    #[test]
+    fn chords_need_exactly_one_known_key() {
+        assert!("F5".parse::<HotkeyChord>().is_ok());
+        assert!("Ctrl+Alt".parse::<HotkeyChord>().is_err());
+        assert!("Ctrl+A+B".parse::<HotkeyChord>().is_err());
+        assert!("Ctrl+Hyper".parse::<HotkeyChord>().is_err());
+    }
+}
@@ -0,0 +1 @@
+pub mod hotkey_chord;
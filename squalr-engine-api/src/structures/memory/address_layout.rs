@@ -0,0 +1,24 @@
+use crate::structures::memory::normalized_module::NormalizedModule;
+use crate::structures::memory::resolved_address::AddressProtection;
+
+/// The loaded modules and mapped memory of a process, which address expressions are resolved against.
+pub trait AddressLayout {
+    fn get_modules(&self) -> &[NormalizedModule];
+
+    /// Gets the protection of the region containing the address, or none if the address is not mapped.
+    fn get_protection(
+        &self,
+        address: u64,
+    ) -> Option<AddressProtection>;
+
+    fn is_readable(
+        &self,
+        address: u64,
+    ) -> bool;
+
+    /// Reads a pointer sized to the process at the given address, or none if it cannot be read.
+    fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Option<u64>;
+}
@@ -0,0 +1,138 @@
+use crate::structures::memory::bitness::Bitness;
+use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+
+/// Usermode address bounds and pointer width for an opened process. This is the single source of truth for
+/// bitness-dependent assumptions, so that the memory queryer, pointer scanner, and range validation agree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AddressSpaceBounds {
+    min_usermode_address: u64,
+    max_usermode_address: u64,
+    maximum_address: u64,
+    pointer_size: usize,
+}
+
+impl AddressSpaceBounds {
+    /// Anything below this is not addressable by a normal 32-bit program.
+    pub const USERMODE_32_MIN_ADDRESS: u64 = 0x10000;
+
+    /// For 32-bit applications, the usermode memory is generally the first 2GB of process RAM.
+    /// JIRA: Large Address Aware support? This is incredibly rare, but would be more correct to support.
+    pub const USERMODE_32_MAX_ADDRESS: u64 = 0x7FFF_FFFF;
+
+    /// The usermode bounds of a 64-bit Windows process. These stand in for the native bounds where the host cannot be queried,
+    /// such as in the GUI, which may not run on the same machine as the engine.
+    pub const USERMODE_64_MIN_ADDRESS: u64 = 0x10000;
+    pub const USERMODE_64_MAX_ADDRESS: u64 = 0x7FFF_FFFE_FFFF;
+
+    /// Selects bounds for the given process. The native bounds are the OS-reported usermode range for the host, and
+    /// are only used when the target matches the host's pointer width.
+    pub fn for_process(
+        process_info: &OpenedProcessInfo,
+        native_min_usermode_address: u64,
+        native_max_usermode_address: u64,
+    ) -> Self {
+        Self::for_bitness(process_info.get_bitness(), native_min_usermode_address, native_max_usermode_address)
+    }
+
+    pub fn for_bitness(
+        bitness: Bitness,
+        native_min_usermode_address: u64,
+        native_max_usermode_address: u64,
+    ) -> Self {
+        match bitness {
+            Bitness::Bit32 => Self {
+                min_usermode_address: Self::USERMODE_32_MIN_ADDRESS,
+                max_usermode_address: Self::USERMODE_32_MAX_ADDRESS,
+                maximum_address: u32::MAX as u64,
+                pointer_size: 4,
+            },
+            Bitness::Bit64 => Self {
+                min_usermode_address: native_min_usermode_address,
+                max_usermode_address: native_max_usermode_address,
+                maximum_address: u64::MAX,
+                pointer_size: 8,
+            },
+        }
+    }
+
+    pub fn get_min_usermode_address(&self) -> u64 {
+        self.min_usermode_address
+    }
+
+    pub fn get_max_usermode_address(&self) -> u64 {
+        self.max_usermode_address
+    }
+
+    pub fn get_maximum_address(&self) -> u64 {
+        self.maximum_address
+    }
+
+    pub fn get_pointer_size(&self) -> usize {
+        self.pointer_size
+    }
+
+    /// Determines whether a value read from memory could be a pointer into usermode memory for this process.
+    pub fn is_valid_pointer(
+        &self,
+        value: u64,
+    ) -> bool {
+        value >= self.min_usermode_address && value <= self.max_usermode_address
+    }
+
+    /// Clamps a user-provided address range to what this process can actually address.
+    /// Returns `None` if the range does not overlap the address space at all.
+    pub fn clamp_range(
+        &self,
+        start_address: u64,
+        end_address: u64,
+    ) -> Option<(u64, u64)> {
+        let end_address = end_address.min(self.maximum_address);
+
+        if start_address >= end_address {
+            return None;
+        }
+
+        Some((start_address, end_address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AddressSpaceBounds;
+    use crate::structures::memory::bitness::Bitness;
+    use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    const NATIVE_MIN: u64 = 0x10000;
+    const NATIVE_MAX: u64 = 0x7FFF_FFFE_FFFF;
+
+    fn mock_process(bitness: Bitness) -> OpenedProcessInfo {
+        OpenedProcessInfo::new(1234, "target.exe".to_string(), 0, bitness, None)
+    }
+
+    #[test]
+    fn bounds_for_32_bit_process_ignore_native_bounds() {
+        let bounds = AddressSpaceBounds::for_process(&mock_process(Bitness::Bit32), NATIVE_MIN, NATIVE_MAX);
+
+        assert_eq!(bounds.get_min_usermode_address(), AddressSpaceBounds::USERMODE_32_MIN_ADDRESS);
+        assert_eq!(bounds.get_max_usermode_address(), AddressSpaceBounds::USERMODE_32_MAX_ADDRESS);
+        assert_eq!(bounds.get_maximum_address(), u32::MAX as u64);
+        assert_eq!(bounds.get_pointer_size(), 4);
+        assert!(bounds.is_valid_pointer(0x0040_0000));
+        assert!(!bounds.is_valid_pointer(0x1_0000_0000));
+        assert_eq!(bounds.clamp_range(0, u64::MAX), Some((0, u32::MAX as u64)));
+        assert_eq!(bounds.clamp_range(0x1_0000_0000, u64::MAX), None);
+    }
+
+    #[test]
+    fn bounds_for_64_bit_process_use_native_bounds() {
+        let bounds = AddressSpaceBounds::for_process(&mock_process(Bitness::Bit64), NATIVE_MIN, NATIVE_MAX);
+
+        assert_eq!(bounds.get_min_usermode_address(), NATIVE_MIN);
+        assert_eq!(bounds.get_max_usermode_address(), NATIVE_MAX);
+        assert_eq!(bounds.get_maximum_address(), u64::MAX);
+        assert_eq!(bounds.get_pointer_size(), 8);
+        assert!(bounds.is_valid_pointer(0x1_0000_0000));
+        assert!(!bounds.is_valid_pointer(NATIVE_MAX + 1));
+        assert_eq!(bounds.clamp_range(0x1000, u64::MAX), Some((0x1000, u64::MAX)));
+    }
+}
@@ -5,3 +5,15 @@ pub enum Bitness {
     Bit32,
     Bit64,
 }
+
+impl Bitness {
+    /// Gets the bitness of the running engine binary.
+    pub fn get_host_bitness() -> Bitness {
+        if cfg!(target_pointer_width = "64") { Bitness::Bit64 } else { Bitness::Bit32 }
+    }
+
+    /// Determines whether a target of this bitness runs under emulation relative to the host (ie WOW64).
+    pub fn is_narrower_than_host(&self) -> bool {
+        *self == Bitness::Bit32 && Self::get_host_bitness() == Bitness::Bit64
+    }
+}
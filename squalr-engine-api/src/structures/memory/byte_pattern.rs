@@ -0,0 +1,308 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A sequence of bytes to search for, where wildcard positions match any byte. Wildcards may also cover a single nibble, ie `4?`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BytePattern {
+    /// The bytes to match, with every wildcard bit cleared.
+    bytes: Vec<u8>,
+    /// The bits of each byte that must match, where a zero bit is a wildcard.
+    mask: Vec<u8>,
+}
+
+impl BytePattern {
+    /// Parses a hex byte pattern, ie `48 8B ?? 05`, `48,8B,??,05`, `488B??05`, or `0x488B??05`.
+    /// Either `?` or `??` may be used as a wildcard token, and single digit tokens are zero-extended when separated. Either digit of a
+    /// byte may be `?` to leave only that nibble as a wildcard, ie `4?` or `?F`.
+    /// Separated tokens may also be contiguous hex strings, such that multi-line hex dumps parse as one pattern.
+    pub fn parse_hex(pattern_string: &str) -> Result<Self, String> {
+        let trimmed = pattern_string.trim();
+
+        if trimmed.is_empty() {
+            return Err("Byte pattern cannot be empty.".to_string());
+        }
+
+        let separators = |character: char| character.is_whitespace() || character == ',';
+        let tokens: Vec<&str> = trimmed
+            .split(separators)
+            .filter(|token| !token.is_empty())
+            .collect();
+        let is_separated = tokens.len() > 1;
+        let mut byte_pattern = Self::default();
+
+        for token in tokens {
+            let token = Self::strip_hex_prefix(token);
+
+            if token.is_empty() {
+                continue;
+            }
+
+            if token == "?" {
+                byte_pattern.push(0, 0);
+                continue;
+            }
+
+            // A lone digit is only unambiguous between separators, as a contiguous hex string is read two digits at a time.
+            if is_separated && token.len() == 1 {
+                let (byte, mask) = Self::parse_token(&format!("0{}", token))?;
+
+                byte_pattern.push(byte, mask);
+                continue;
+            }
+
+            if token.len() % 2 != 0 {
+                return Err(format!("Invalid byte pattern token '{}'. Expected an even number of hex digits, or ??.", token));
+            }
+
+            for chunk in token.as_bytes().chunks(2) {
+                let hex_pair = std::str::from_utf8(chunk).map_err(|_| "Invalid UTF-8 in hex string.".to_string())?;
+
+                let (byte, mask) = Self::parse_token(hex_pair)?;
+
+                byte_pattern.push(byte, mask);
+            }
+        }
+
+        Ok(byte_pattern)
+    }
+
+    /// Parses hex text into exact bytes, using the same syntax as `parse_hex`. Wildcards are rejected, as there is no byte to write for them.
+    pub fn parse_hex_bytes(hex_string: &str) -> Result<Vec<u8>, String> {
+        let byte_pattern = Self::parse_hex(hex_string)?;
+
+        if byte_pattern.is_empty() {
+            return Err("No hex bytes were found.".to_string());
+        }
+
+        byte_pattern
+            .to_bytes()
+            .ok_or_else(|| "Wildcards cannot be used here, as every byte must have a value.".to_string())
+    }
+
+    /// Creates a pattern matching the exact bytes of the given text.
+    pub fn from_ascii(text: &str) -> Self {
+        Self::from_bytes(text.as_bytes())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+            mask: vec![0xFF; bytes.len()],
+        }
+    }
+
+    /// Gets the bytes to match, where every wildcard bit is zero.
+    pub fn get_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Gets the bits of each byte that must match, where a zero bit is a wildcard.
+    pub fn get_mask(&self) -> &[u8] {
+        &self.mask
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn has_wildcards(&self) -> bool {
+        self.mask.iter().any(|mask| *mask != 0xFF)
+    }
+
+    /// Determines whether every bit of this pattern is a wildcard, such that it would match anywhere.
+    pub fn is_all_wildcards(&self) -> bool {
+        self.mask.iter().all(|mask| *mask == 0)
+    }
+
+    /// Gets the exact bytes of this pattern, or `None` if the pattern contains wildcards.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        if self.has_wildcards() { None } else { Some(self.bytes.clone()) }
+    }
+
+    /// Determines whether this pattern matches the haystack at the given offset.
+    pub fn matches_at(
+        &self,
+        haystack: &[u8],
+        offset: usize,
+    ) -> bool {
+        match haystack.get(offset..offset.saturating_add(self.bytes.len())) {
+            Some(window) => !self.bytes.is_empty() && self.matches_window(window),
+            None => false,
+        }
+    }
+
+    /// Finds the offsets of all matches in the haystack, including overlapping matches.
+    pub fn find_all(
+        &self,
+        haystack: &[u8],
+    ) -> Vec<usize> {
+        if self.bytes.is_empty() {
+            return Vec::new();
+        }
+
+        haystack
+            .windows(self.bytes.len())
+            .enumerate()
+            .filter(|(_offset, window)| self.matches_window(window))
+            .map(|(offset, _window)| offset)
+            .collect()
+    }
+
+    fn matches_window(
+        &self,
+        window: &[u8],
+    ) -> bool {
+        self.bytes
+            .iter()
+            .zip(&self.mask)
+            .zip(window)
+            .all(|((pattern_byte, mask), byte)| byte & mask == *pattern_byte)
+    }
+
+    fn push(
+        &mut self,
+        byte: u8,
+        mask: u8,
+    ) {
+        self.bytes.push(byte & mask);
+        self.mask.push(mask);
+    }
+
+    fn strip_hex_prefix(token: &str) -> &str {
+        token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+            .unwrap_or(token)
+    }
+
+    /// Parses a one or two digit token into a byte and the mask of its digits that are not wildcards.
+    fn parse_token(token: &str) -> Result<(u8, u8), String> {
+        if token == "?" || token == "??" {
+            return Ok((0, 0));
+        }
+
+        let mut byte = 0u8;
+        let mut mask = 0u8;
+
+        for character in token.chars() {
+            let (nibble, nibble_mask) = match character {
+                '?' => (0, 0),
+                _ => match character.to_digit(16) {
+                    Some(digit) => (digit as u8, 0xF),
+                    None => return Err(format!("Failed to parse hex byte '{}': invalid digit '{}'", token, character)),
+                },
+            };
+
+            byte = (byte << 4) | nibble;
+            mask = (mask << 4) | nibble_mask;
+        }
+
+        Ok((byte, mask))
+    }
+}
+
+impl FromStr for BytePattern {
+    type Err = String;
+
+    fn from_str(pattern_string: &str) -> Result<Self, Self::Err> {
+        Self::parse_hex(pattern_string)
+    }
+}
+
+impl fmt::Display for BytePattern {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let format_nibble = |nibble: u8, nibble_mask: u8| match nibble_mask {
+            0 => "?".to_string(),
+            _ => format!("{:X}", nibble),
+        };
+        let tokens: Vec<String> = self
+            .bytes
+            .iter()
+            .zip(&self.mask)
+            .map(|(byte, mask)| format!("{}{}", format_nibble(byte >> 4, mask >> 4), format_nibble(byte & 0xF, mask & 0xF)))
+            .collect();
+
+        write!(formatter, "{}", tokens.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BytePattern;
+
+    #[test]
+    fn parse_hex_accepts_wildcards_in_spaced_and_contiguous_forms() {
+        let spaced = BytePattern::parse_hex("48 8B ?? 5").expect("spaced pattern should parse");
+        let contiguous = BytePattern::parse_hex("0x488B??05").expect("contiguous pattern should parse");
+
+        assert_eq!(spaced, contiguous);
+        assert_eq!(spaced.to_string(), "48 8B ?? 05");
+        assert!(spaced.has_wildcards());
+        assert_eq!(spaced.to_bytes(), None);
+        assert!(BytePattern::parse_hex("48 8BC").is_err());
+        assert!(BytePattern::parse_hex("48B").is_err());
+    }
+
+    #[test]
+    fn find_all_matches_wildcards_and_overlapping_matches() {
+        let haystack = [0x48, 0x8B, 0x01, 0x05, 0x48, 0x8B, 0xFF, 0x05, 0xAA, 0xAA, 0xAA];
+        let pattern = BytePattern::parse_hex("48 8B ?? 05").expect("pattern should parse");
+
+        assert_eq!(pattern.find_all(&haystack), vec![0, 4]);
+        assert!(pattern.matches_at(&haystack, 4));
+        assert!(!pattern.matches_at(&haystack, 9));
+        assert_eq!(BytePattern::from_bytes(&[0xAA, 0xAA]).find_all(&haystack), vec![8, 9]);
+        assert_eq!(BytePattern::from_ascii("H").find_all(&haystack), vec![0, 4]);
+    }
+
+    #[test]
+    fn nibble_wildcards_match_either_half_of_a_byte() {
+        let haystack = [0x40, 0x8B, 0x4F, 0x0B, 0x50, 0x8B];
+        let pattern = BytePattern::parse_hex("4? ?B").expect("nibble pattern should parse");
+
+        assert_eq!(pattern.to_string(), "4? ?B");
+        assert_eq!(pattern, BytePattern::parse_hex("4??B").expect("contiguous nibble pattern should parse"));
+        assert_eq!(pattern.get_bytes(), &[0x40, 0x0B]);
+        assert_eq!(pattern.get_mask(), &[0xF0, 0x0F]);
+        assert_eq!(pattern.find_all(&haystack), vec![0, 2]);
+        assert!(!pattern.is_all_wildcards());
+        assert!(
+            BytePattern::parse_hex("?? ? ??")
+                .expect("wildcards should parse")
+                .is_all_wildcards()
+        );
+        assert!(BytePattern::parse_hex("4G").is_err());
+    }
+
+    #[test]
+    fn parse_hex_bytes_tolerates_prefixes_and_line_breaks() {
+        let expected = vec![0x48, 0x8B, 0x05, 0x90, 0xC3];
+
+        assert_eq!(BytePattern::parse_hex_bytes("48 8B 05 90 C3"), Ok(expected.clone()));
+        assert_eq!(BytePattern::parse_hex_bytes("0x48, 0x8B, 0x05,\r\n0x90, 0xC3"), Ok(expected.clone()));
+        assert_eq!(BytePattern::parse_hex_bytes("488B05\n90C3\n"), Ok(expected.clone()));
+        assert_eq!(BytePattern::parse_hex_bytes("0x488b0590c3"), Ok(expected));
+    }
+
+    #[test]
+    fn parse_hex_bytes_rejects_malformed_input() {
+        assert!(BytePattern::parse_hex_bytes("").is_err());
+        assert!(BytePattern::parse_hex_bytes(" \n ").is_err());
+        assert!(BytePattern::parse_hex_bytes("0x").is_err());
+        assert!(BytePattern::parse_hex_bytes("48 ?? 05").is_err());
+        assert!(BytePattern::parse_hex_bytes("48 ? 05").is_err());
+        assert!(BytePattern::parse_hex_bytes("488").is_err());
+        assert!(BytePattern::parse_hex_bytes("48 8G").is_err());
+        assert!(BytePattern::parse_hex_bytes("48 -1").is_err());
+        assert!(BytePattern::parse_hex_bytes("hello").is_err());
+        assert!(BytePattern::parse_hex_bytes("48 é5").is_err());
+    }
+}
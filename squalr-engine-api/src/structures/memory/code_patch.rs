@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Bytes written over the code of the opened process, along with the code they replaced.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CodePatch {
+    pub address: u64,
+    pub original_bytes: Vec<u8>,
+    pub patched_bytes: Vec<u8>,
+}
+
+impl CodePatch {
+    /// Gets the address one past the last patched byte.
+    pub fn get_end_address(&self) -> u64 {
+        self.address.saturating_add(self.patched_bytes.len() as u64)
+    }
+
+    pub fn contains_address(
+        &self,
+        address: u64,
+    ) -> bool {
+        address >= self.address && address < self.get_end_address()
+    }
+}
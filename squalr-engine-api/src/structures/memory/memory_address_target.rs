@@ -0,0 +1,51 @@
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::memory::pointer::Pointer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// An address, which is relative to the module if one is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MemoryAddressTarget {
+    pub address: u64,
+    pub module_name: String,
+}
+
+impl MemoryAddressTarget {
+    pub fn new(
+        address: u64,
+        module_name: String,
+    ) -> Self {
+        Self { address, module_name }
+    }
+
+    /// Gets the pointer to this address, as keyed by the freeze list.
+    pub fn to_pointer(&self) -> Pointer {
+        Pointer::new(self.address, vec![], self.module_name.clone())
+    }
+}
+
+/// Parses targets in the form `<address>[@<module>]`, ie `0x1A0@game.exe`.
+impl FromStr for MemoryAddressTarget {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (address, module_name) = string.split_once('@').unwrap_or((string, ""));
+        let address = Conversions::parse_hex_or_int(address.trim()).map_err(|error| format!("Invalid address '{}': {}", address, error))?;
+
+        Ok(Self::new(address, module_name.trim().to_string()))
+    }
+}
+
+impl fmt::Display for MemoryAddressTarget {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if self.module_name.is_empty() {
+            write!(formatter, "0x{:X}", self.address)
+        } else {
+            write!(formatter, "0x{:X}@{}", self.address, self.module_name)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// The outcome of restoring the original bytes at a single address.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct MemoryRestoreResult {
+    pub address: u64,
+    pub byte_count: u64,
+    pub success: bool,
+}
@@ -0,0 +1,13 @@
+use crate::structures::memory::register_snapshot::RegisterSnapshot;
+use serde::{Deserialize, Serialize};
+
+/// An instruction observed accessing a watched address, along with how many times it did so since the previous report.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryWatchHit {
+    pub instruction_address: u64,
+    /// The most recent address accessed by this instruction within the watched range.
+    pub access_address: u64,
+    pub hit_count: u64,
+    /// The registers of the thread as of the most recent access by this instruction, if they could be read.
+    pub registers: Option<RegisterSnapshot>,
+}
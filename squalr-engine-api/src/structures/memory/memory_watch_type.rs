@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Which accesses to a watched range are reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoryWatchType {
+    /// Reads and writes are both reported.
+    #[default]
+    Access,
+    /// Only writes are reported.
+    Write,
+}
+
+impl MemoryWatchType {
+    /// Gets a short description of the reported accesses, ie for "Find out what {} this address".
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            MemoryWatchType::Access => "accesses",
+            MemoryWatchType::Write => "writes to",
+        }
+    }
+}
+
+impl FromStr for MemoryWatchType {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "access" | "a" => Ok(MemoryWatchType::Access),
+            "write" | "w" => Ok(MemoryWatchType::Write),
+            _ => Err(format!("Invalid memory watch type: '{}'. Expected 'access' or 'write'.", string)),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::conversions::conversions_from_primitives::Conversions;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A single step of a write sequence, resolved to the addresses it acts on. Addresses are relative to the module, if one is set.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MemoryWriteSequenceStep {
+    /// Writes the given bytes to an address. Frozen addresses are refrozen at the written bytes.
+    Write { address: u64, module_name: String, value_bytes: Vec<u8> },
+    /// Waits before running the next step.
+    Delay { duration_ms: u64 },
+    /// Freezes an address at its current value, or unfreezes it if it is already frozen.
+    ToggleFreeze {
+        address: u64,
+        module_name: String,
+        data_type_ref: DataTypeRef,
+    },
+}
+
+impl MemoryWriteSequenceStep {
+    /// Gets the address and module targeted by this step, if any.
+    pub fn get_target(&self) -> Option<(u64, &str)> {
+        match self {
+            MemoryWriteSequenceStep::Write { address, module_name, .. } => Some((*address, module_name)),
+            MemoryWriteSequenceStep::Delay { .. } => None,
+            MemoryWriteSequenceStep::ToggleFreeze { address, module_name, .. } => Some((*address, module_name)),
+        }
+    }
+
+    fn parse_target(target: &str) -> Result<(u64, String), String> {
+        let (address, module_name) = target.split_once('@').unwrap_or((target, ""));
+        let address = Conversions::parse_hex_or_int(address.trim()).map_err(|error| format!("Invalid address '{}': {}", address, error))?;
+
+        Ok((address, module_name.trim().to_string()))
+    }
+
+    fn parse_value_bytes(value_bytes: &str) -> Result<Vec<u8>, String> {
+        let value_bytes: String = value_bytes
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect();
+
+        if value_bytes.is_empty()
+            || !value_bytes.len().is_multiple_of(2)
+            || !value_bytes
+                .chars()
+                .all(|character| character.is_ascii_hexdigit())
+        {
+            return Err(format!("Expected an even number of hex digits, got '{}'.", value_bytes));
+        }
+
+        (0..value_bytes.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&value_bytes[index..index + 2], 16).map_err(|error| error.to_string()))
+            .collect()
+    }
+}
+
+/// Parses steps in the form `write:<address>[@<module>]:<hex bytes>`, `delay:<milliseconds>`, or
+/// `freeze:<address>[@<module>]:<data type>`, ie `write:0x1A0@game.exe:64000000`.
+impl FromStr for MemoryWriteSequenceStep {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (step_kind, arguments) = string
+            .split_once(':')
+            .ok_or_else(|| "Expected format: write:<address>[@<module>]:<hex bytes>, delay:<ms>, or freeze:<address>[@<module>]:<data type>".to_string())?;
+
+        match step_kind.trim() {
+            "delay" => {
+                let duration_ms = arguments
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|error| format!("Invalid delay '{}': {}", arguments, error))?;
+
+                Ok(MemoryWriteSequenceStep::Delay { duration_ms })
+            }
+            "write" => {
+                let (target, value_bytes) = arguments
+                    .rsplit_once(':')
+                    .ok_or_else(|| "Expected format: write:<address>[@<module>]:<hex bytes>".to_string())?;
+                let (address, module_name) = Self::parse_target(target)?;
+
+                Ok(MemoryWriteSequenceStep::Write {
+                    address,
+                    module_name,
+                    value_bytes: Self::parse_value_bytes(value_bytes)?,
+                })
+            }
+            "freeze" => {
+                let (target, data_type_id) = arguments
+                    .rsplit_once(':')
+                    .ok_or_else(|| "Expected format: freeze:<address>[@<module>]:<data type>".to_string())?;
+                let (address, module_name) = Self::parse_target(target)?;
+
+                Ok(MemoryWriteSequenceStep::ToggleFreeze {
+                    address,
+                    module_name,
+                    data_type_ref: DataTypeRef::new(data_type_id.trim()),
+                })
+            }
+            _ => Err(format!("Unknown write sequence step '{}'. Expected write, delay, or freeze.", step_kind)),
+        }
+    }
+}
+
+impl fmt::Display for MemoryWriteSequenceStep {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            MemoryWriteSequenceStep::Write {
+                address,
+                module_name,
+                value_bytes,
+            } => {
+                let value_bytes: String = value_bytes
+                    .iter()
+                    .map(|value_byte| format!("{:02X}", value_byte))
+                    .collect();
+
+                write!(formatter, "write:0x{:X}@{}:{}", address, module_name, value_bytes)
+            }
+            MemoryWriteSequenceStep::Delay { duration_ms } => write!(formatter, "delay:{}", duration_ms),
+            MemoryWriteSequenceStep::ToggleFreeze {
+                address,
+                module_name,
+                data_type_ref,
+            } => write!(formatter, "freeze:0x{:X}@{}:{}", address, module_name, data_type_ref),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryWriteSequenceStep;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+
+    #[test]
+    fn steps_round_trip_through_their_string_form() {
+        let steps = vec![
+            MemoryWriteSequenceStep::Write {
+                address: 0x1A0,
+                module_name: "game.exe".to_string(),
+                value_bytes: vec![0x64, 0x00, 0x00, 0x00],
+            },
+            MemoryWriteSequenceStep::Delay { duration_ms: 200 },
+            MemoryWriteSequenceStep::ToggleFreeze {
+                address: 0x7FF0_0000_1000,
+                module_name: String::new(),
+                data_type_ref: DataTypeRef::new("i32"),
+            },
+        ];
+
+        for step in steps {
+            assert_eq!(step.to_string().parse::<MemoryWriteSequenceStep>(), Ok(step));
+        }
+    }
+
+    #[test]
+    fn modules_are_optional_and_malformed_steps_are_rejected() {
+        assert_eq!(
+            "write:4096:FF01".parse::<MemoryWriteSequenceStep>(),
+            Ok(MemoryWriteSequenceStep::Write {
+                address: 4096,
+                module_name: String::new(),
+                value_bytes: vec![0xFF, 0x01],
+            })
+        );
+        assert!("write:0x10:ABC".parse::<MemoryWriteSequenceStep>().is_err());
+        assert!("delay:soon".parse::<MemoryWriteSequenceStep>().is_err());
+        assert!("poke:0x10:00".parse::<MemoryWriteSequenceStep>().is_err());
+    }
+}
@@ -1,6 +1,21 @@
+pub mod address_layout;
+pub mod address_space_bounds;
 pub mod bitness;
+pub mod byte_pattern;
+pub mod code_patch;
 pub mod endian;
+pub mod memory_address_target;
 pub mod memory_alignment;
+pub mod memory_restore_result;
+pub mod memory_watch_hit;
+pub mod memory_watch_type;
+pub mod memory_write_sequence_step;
 pub mod normalized_module;
 pub mod normalized_region;
 pub mod pointer;
+pub mod process_query_cache_stats;
+pub mod register_snapshot;
+pub mod region_interval_set;
+pub mod resolved_address;
+pub mod value_watch;
+pub mod value_watch_condition;
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Diagnostics for the engine-side cache of process modules and regions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessQueryCacheStats {
+    /// The current cache generation, incremented every time the cached snapshot is discarded.
+    pub generation: u64,
+    pub hits: u64,
+    pub misses: u64,
+    /// The number of times modules were enumerated from the OS.
+    pub module_enumerations: u64,
+    /// The number of times regions were enumerated from the OS.
+    pub region_enumerations: u64,
+}
+
+impl ProcessQueryCacheStats {
+    /// Gets the fraction of cache lookups that were served without enumerating, or 0 if there were no lookups.
+    pub fn get_hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+
+        if lookups == 0 { 0.0 } else { self.hits as f64 / lookups as f64 }
+    }
+}
+
+impl fmt::Display for ProcessQueryCacheStats {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            formatter,
+            "generation {}, {} hits, {} misses ({:.1}% hit rate), {} module enumerations, {} region enumerations",
+            self.generation,
+            self.hits,
+            self.misses,
+            self.get_hit_rate() * 100.0,
+            self.module_enumerations,
+            self.region_enumerations
+        )
+    }
+}
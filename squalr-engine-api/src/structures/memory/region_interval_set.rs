@@ -0,0 +1,80 @@
+/// A sorted set of disjoint address ranges, for quickly testing whether an address falls within mapped memory.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegionIntervalSet {
+    /// Sorted, non-overlapping and non-adjacent `[start, end)` ranges.
+    intervals: Vec<(u64, u64)>,
+}
+
+impl RegionIntervalSet {
+    /// Builds a set from `[start, end)` ranges in any order. Overlapping and adjacent ranges are merged, and empty ones dropped.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = (u64, u64)>) -> Self {
+        let mut ranges: Vec<(u64, u64)> = ranges
+            .into_iter()
+            .filter(|(start_address, end_address)| start_address < end_address)
+            .collect();
+        let mut intervals: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+
+        ranges.sort_unstable();
+
+        for (start_address, end_address) in ranges {
+            match intervals.last_mut() {
+                Some((_, last_end_address)) if start_address <= *last_end_address => {
+                    *last_end_address = (*last_end_address).max(end_address);
+                }
+                _ => intervals.push((start_address, end_address)),
+            }
+        }
+
+        Self { intervals }
+    }
+
+    pub fn contains(
+        &self,
+        address: u64,
+    ) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|(start_address, _)| *start_address <= address);
+
+        index > 0 && address < self.intervals[index - 1].1
+    }
+
+    pub fn get_intervals(&self) -> &[(u64, u64)] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegionIntervalSet;
+
+    #[test]
+    fn overlapping_and_adjacent_ranges_are_merged() {
+        let region_interval_set = RegionIntervalSet::from_ranges([
+            (0x3000, 0x4000),
+            (0x1000, 0x2000),
+            (0x2000, 0x2800),
+            (0x3800, 0x5000),
+            (0x9000, 0x9000),
+        ]);
+
+        assert_eq!(region_interval_set.get_intervals(), &[(0x1000, 0x2800), (0x3000, 0x5000)]);
+    }
+
+    #[test]
+    fn contains_excludes_range_ends_and_gaps() {
+        let region_interval_set = RegionIntervalSet::from_ranges([(0x1000, 0x2000), (0x3000, 0x4000)]);
+
+        assert!(!region_interval_set.contains(0xFFF));
+        assert!(region_interval_set.contains(0x1000));
+        assert!(region_interval_set.contains(0x1FFF));
+        assert!(!region_interval_set.contains(0x2000));
+        assert!(region_interval_set.contains(0x3ABC));
+        assert!(!region_interval_set.contains(0x4000));
+        assert!(!RegionIntervalSet::default().contains(0x1000));
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+/// The general purpose registers of a thread, captured as it performed a trapped access. Threads of 32-bit processes only fill the
+/// lower halves of the first eight registers, along with the instruction pointer and flags.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisterSnapshot {
+    pub is_32_bit: bool,
+    pub rax: u64,
+    pub rbx: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub rbp: u64,
+    pub rsp: u64,
+    pub r8: u64,
+    pub r9: u64,
+    pub r10: u64,
+    pub r11: u64,
+    pub r12: u64,
+    pub r13: u64,
+    pub r14: u64,
+    pub r15: u64,
+    pub rip: u64,
+    pub rflags: u64,
+}
+
+impl RegisterSnapshot {
+    /// Gets each register under the name it has for the bitness of the thread, in the order debuggers usually list them.
+    pub fn get_named_registers(&self) -> Vec<(&'static str, u64)> {
+        if self.is_32_bit {
+            return vec![
+                ("EAX", self.rax),
+                ("EBX", self.rbx),
+                ("ECX", self.rcx),
+                ("EDX", self.rdx),
+                ("ESI", self.rsi),
+                ("EDI", self.rdi),
+                ("EBP", self.rbp),
+                ("ESP", self.rsp),
+                ("EIP", self.rip),
+                ("EFLAGS", self.rflags),
+            ];
+        }
+
+        vec![
+            ("RAX", self.rax),
+            ("RBX", self.rbx),
+            ("RCX", self.rcx),
+            ("RDX", self.rdx),
+            ("RSI", self.rsi),
+            ("RDI", self.rdi),
+            ("RBP", self.rbp),
+            ("RSP", self.rsp),
+            ("R8", self.r8),
+            ("R9", self.r9),
+            ("R10", self.r10),
+            ("R11", self.r11),
+            ("R12", self.r12),
+            ("R13", self.r13),
+            ("R14", self.r14),
+            ("R15", self.r15),
+            ("RIP", self.rip),
+            ("RFLAGS", self.rflags),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegisterSnapshot;
+
+    #[test]
+    fn registers_are_named_for_the_bitness_of_the_thread() {
+        let mut register_snapshot = RegisterSnapshot {
+            rax: 0x10,
+            rip: 0x401000,
+            ..RegisterSnapshot::default()
+        };
+
+        assert_eq!(register_snapshot.get_named_registers().len(), 18);
+        assert_eq!(register_snapshot.get_named_registers()[0], ("RAX", 0x10));
+
+        register_snapshot.is_32_bit = true;
+
+        let named_registers = register_snapshot.get_named_registers();
+
+        assert_eq!(named_registers.len(), 10);
+        assert_eq!(named_registers[0], ("EAX", 0x10));
+        assert!(named_registers.contains(&("EIP", 0x401000)));
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The protection of the memory region containing an address.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressProtection {
+    pub is_readable: bool,
+    pub is_writable: bool,
+    pub is_executable: bool,
+}
+
+impl fmt::Display for AddressProtection {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            formatter,
+            "{}{}{}",
+            if self.is_readable { 'r' } else { '-' },
+            if self.is_writable { 'w' } else { '-' },
+            if self.is_executable { 'x' } else { '-' }
+        )
+    }
+}
+
+/// An address expression resolved against the opened process.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedAddress {
+    pub address: u64,
+    /// The module containing the address, or empty if the address is not within a module.
+    pub module_name: String,
+    /// Offset from the module base when `module_name` is set.
+    pub module_offset: u64,
+    /// The protection of the region containing the address, or none if the address is not mapped.
+    pub protection: Option<AddressProtection>,
+    /// Whether the address could be read at the time it was resolved.
+    pub is_readable: bool,
+}
+
+impl ResolvedAddress {
+    /// Gets the address as `module+offset` when it lies within a module, otherwise as an absolute hexadecimal address.
+    pub fn get_display_address(&self) -> String {
+        if self.module_name.is_empty() {
+            format!("{:X}", self.address)
+        } else {
+            format!("{}+{:X}", self.module_name, self.module_offset)
+        }
+    }
+}
+
+impl fmt::Display for ResolvedAddress {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        if self.module_name.is_empty() {
+            write!(formatter, "{:X}", self.address)?;
+        } else {
+            write!(formatter, "{} ({:X})", self.get_display_address(), self.address)?;
+        }
+
+        match self.protection {
+            Some(protection) if self.is_readable => write!(formatter, ", {}", protection),
+            Some(protection) => write!(formatter, ", {}, unreadable", protection),
+            None => write!(formatter, ", unmapped"),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::value_watch_condition::ValueWatchCondition;
+use serde::{Deserialize, Serialize};
+
+/// A value that the engine compares every refresh tick, reporting when it changes in a way that meets the condition.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueWatch {
+    pub watch_id: u64,
+    /// The name shown when the watch triggers, ie the description of a project entry.
+    pub label: String,
+    /// The address of the value, relative to the module if a module name is given.
+    pub address: u64,
+    pub module_name: String,
+    pub data_type_ref: DataTypeRef,
+    pub condition: ValueWatchCondition,
+}
@@ -0,0 +1,85 @@
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// The change to a watched value that triggers a notification.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum ValueWatchCondition {
+    /// Triggers whenever the bytes of the value change.
+    #[default]
+    AnyChange,
+    /// Triggers when the value moves from below the threshold to at or above it, or back.
+    CrossesThreshold { threshold: AnonymousValueString },
+    /// Triggers when the value changes to the given value.
+    Equals { value: AnonymousValueString },
+}
+
+impl ValueWatchCondition {
+    /// Gets a short description of this condition for display, ie `crosses 100`.
+    pub fn get_description(&self) -> String {
+        match self {
+            ValueWatchCondition::AnyChange => "any change".to_string(),
+            ValueWatchCondition::CrossesThreshold { threshold } => format!("crosses {}", threshold.get_anonymous_value_string()),
+            ValueWatchCondition::Equals { value } => format!("equals {}", value.get_anonymous_value_string()),
+        }
+    }
+}
+
+impl FromStr for ValueWatchCondition {
+    type Err = String;
+
+    /// Parses `any`, `crosses:<value>`, or `equals:<value>`, where the value is an anonymous value string, ie `100;dec;none`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.split_once(':') {
+            None if string.trim().eq_ignore_ascii_case("any") => Ok(ValueWatchCondition::AnyChange),
+            Some((condition, value)) if condition.trim().eq_ignore_ascii_case("crosses") => Ok(ValueWatchCondition::CrossesThreshold {
+                threshold: value.parse::<AnonymousValueString>()?,
+            }),
+            Some((condition, value)) if condition.trim().eq_ignore_ascii_case("equals") => Ok(ValueWatchCondition::Equals {
+                value: value.parse::<AnonymousValueString>()?,
+            }),
+            _ => Err("Expected format: any, crosses:<value>, or equals:<value>".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ValueWatchCondition {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ValueWatchCondition::AnyChange => write!(formatter, "any"),
+            ValueWatchCondition::CrossesThreshold { threshold } => write!(formatter, "crosses:{}", threshold),
+            ValueWatchCondition::Equals { value } => write!(formatter, "equals:{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueWatchCondition;
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+
+    #[test]
+    fn conditions_round_trip_through_strings() {
+        let conditions = [
+            ValueWatchCondition::AnyChange,
+            ValueWatchCondition::CrossesThreshold {
+                threshold: AnonymousValueString::new("100".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            },
+            ValueWatchCondition::Equals {
+                value: AnonymousValueString::new("0".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            },
+        ];
+
+        for condition in conditions {
+            assert_eq!(condition.to_string().parse::<ValueWatchCondition>(), Ok(condition));
+        }
+
+        assert!("above:5;dec;none".parse::<ValueWatchCondition>().is_err());
+    }
+}
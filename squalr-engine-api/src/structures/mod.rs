@@ -1,5 +1,7 @@
 pub mod data_types;
 pub mod data_values;
+pub mod expressions;
+pub mod hotkeys;
 pub mod logging;
 pub mod memory;
 pub mod processes;
@@ -1,2 +1,3 @@
 pub mod pointer_scan_result;
 pub mod pointer_scan_results;
+pub mod pointer_scan_results_file;
@@ -1,4 +1,6 @@
+use crate::conversions::conversions_from_primitives::Conversions;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct PointerScanResult {
@@ -45,4 +47,129 @@ impl PointerScanResult {
     pub fn is_module(&self) -> bool {
         self.is_module
     }
+
+    /// Follows this pointer path from the given base address, where the base address holds the first pointer. Each pointer read
+    /// along the path is added to the next offset. Returns the address the path leads to, or `None` if any pointer along it could
+    /// not be read, ie because it now lies in unmapped memory.
+    pub fn resolve(
+        &self,
+        base_address: u64,
+        mut read_pointer: impl FnMut(u64) -> Option<u64>,
+    ) -> Option<u64> {
+        self.offsets
+            .iter()
+            .try_fold(base_address, |pointer_address, offset| {
+                Some(read_pointer(pointer_address)?.wrapping_add(*offset))
+            })
+    }
+
+    /// Formats an offset as hex. Offsets are stored in two's complement, such that a negative offset is shown with a leading minus,
+    /// ie `-10` rather than `FFFFFFFFFFFFFFF0`.
+    pub fn format_offset(offset: u64) -> String {
+        let signed_offset = offset as i64;
+
+        if signed_offset < 0 {
+            format!("-{:X}", signed_offset.unsigned_abs())
+        } else {
+            format!("{:X}", offset)
+        }
+    }
+
+    /// Parses an offset in the form shown by `format_offset`, storing a negative offset in two's complement.
+    pub fn parse_offset(offset: &str) -> Result<u64, String> {
+        let offset = offset.trim();
+        let (is_negative, magnitude) = match offset.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, offset),
+        };
+        let magnitude = Conversions::parse_hex_address(magnitude).map_err(|error| format!("Invalid offset '{}': {}", offset, error))?;
+
+        Ok(if is_negative { magnitude.wrapping_neg() } else { magnitude })
+    }
+}
+
+/// Parses a pointer path in the form shown in the pointer scan results, ie `game.exe+1A0 -> [10, -8, 2C]` or `21BD0000 -> [10]`.
+/// The address of a module based path is not known until it is resolved against the module, and is left as zero.
+impl FromStr for PointerScanResult {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (base, offsets) = string
+            .split_once("->")
+            .ok_or_else(|| format!("Expected a pointer path in the form `base -> [offsets]`, got '{}'.", string))?;
+        let offsets = offsets
+            .trim()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(str::trim)
+            .filter(|offset| !offset.is_empty())
+            .map(Self::parse_offset)
+            .collect::<Result<Vec<u64>, String>>()?;
+
+        match base.trim().rsplit_once('+') {
+            Some((module_name, module_offset)) => {
+                let module_offset =
+                    Conversions::parse_hex_address(module_offset).map_err(|error| format!("Invalid module offset '{}': {}", module_offset, error))?;
+
+                Ok(Self::new(0, module_name.trim().to_string(), module_offset, offsets, true))
+            }
+            None => {
+                let base_address = Conversions::parse_hex_address(base).map_err(|error| format!("Invalid base address '{}': {}", base.trim(), error))?;
+
+                Ok(Self::new(base_address, String::new(), base_address, offsets, false))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerScanResult;
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    #[test]
+    fn pointer_paths_parse_from_the_results_list_format() {
+        let pointer_scan_result = PointerScanResult::from_str("game.exe+1A0 -> [10, 8, 2C]").unwrap();
+
+        assert!(pointer_scan_result.is_module());
+        assert_eq!(pointer_scan_result.get_module_name(), "game.exe");
+        assert_eq!(pointer_scan_result.get_module_offset(), 0x1A0);
+        assert_eq!(pointer_scan_result.get_offsets(), &vec![0x10, 0x8, 0x2C]);
+
+        let pointer_scan_result = PointerScanResult::from_str("0000000021BD0000 -> []").unwrap();
+
+        assert!(!pointer_scan_result.is_module());
+        assert_eq!(pointer_scan_result.get_base_address(), 0x21BD_0000);
+        assert!(pointer_scan_result.get_offsets().is_empty());
+        assert!(PointerScanResult::from_str("game.exe+1A0").is_err());
+        assert!(PointerScanResult::from_str("game.exe+1A0 -> [10, G]").is_err());
+    }
+
+    #[test]
+    fn negative_offsets_round_trip_through_the_results_list_format() {
+        let pointer_scan_result = PointerScanResult::from_str("game.exe+1A0 -> [-10, 8]").unwrap();
+
+        assert_eq!(pointer_scan_result.get_offsets(), &vec![(-0x10i64) as u64, 0x8]);
+        assert_eq!(PointerScanResult::format_offset(pointer_scan_result.get_offsets()[0]), "-10");
+        assert_eq!(PointerScanResult::format_offset(0x2C), "2C");
+        assert!(PointerScanResult::parse_offset("--10").is_err());
+    }
+
+    #[test]
+    fn resolve_follows_each_pointer_and_stops_at_unreadable_memory() {
+        let memory = HashMap::from([(0x1000u64, 0x2000u64), (0x2010, 0x3000)]);
+        let read_pointer = |pointer_address: u64| memory.get(&pointer_address).copied();
+        let pointer_scan_result = PointerScanResult::new(0x1000, "game.exe".to_string(), 0x1000, vec![0x10, 0x24], true);
+
+        assert_eq!(pointer_scan_result.resolve(0x1000, read_pointer), Some(0x3024));
+
+        // The first pointer no longer leads to the second, so the path breaks at an unmapped read.
+        assert_eq!(pointer_scan_result.resolve(0x2010, read_pointer), None);
+        assert_eq!(
+            PointerScanResult::new(0x4000, String::new(), 0x4000, vec![], false).resolve(0x4000, read_pointer),
+            Some(0x4000)
+        );
+    }
 }
@@ -5,11 +5,18 @@ use serde::{Deserialize, Serialize};
 pub struct PointerScanResults {
     results: Vec<PointerScanResult>,
     page_size: u64,
+    /// The depth the scan stopped at after reaching its memory cap, if it was cut short.
+    #[serde(default)]
+    memory_capped_depth: Option<u64>,
 }
 
 impl PointerScanResults {
     pub fn new(results: Vec<PointerScanResult>, page_size: u64) -> Self {
-        Self { results, page_size }
+        Self {
+            results,
+            page_size,
+            memory_capped_depth: None,
+        }
     }
 
     pub fn get_results(&self) -> &Vec<PointerScanResult> {
@@ -20,6 +27,14 @@ impl PointerScanResults {
         self.results = results;
     }
 
+    pub fn get_memory_capped_depth(&self) -> Option<u64> {
+        self.memory_capped_depth
+    }
+
+    pub fn set_memory_capped_depth(&mut self, memory_capped_depth: Option<u64>) {
+        self.memory_capped_depth = memory_capped_depth;
+    }
+
     pub fn get_page_size(&self) -> u64 {
         self.page_size
     }
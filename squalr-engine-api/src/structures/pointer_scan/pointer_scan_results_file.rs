@@ -0,0 +1,185 @@
+use crate::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+use crate::structures::pointer_scan::pointer_scan_results::PointerScanResults;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// A complete set of pointer scan results saved to disk (a `.ptr` file), along with what is needed to tell whether they can be
+/// loaded into another process, such that an expensive scan need not be repeated after restarting Squalr.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PointerScanResultsFile {
+    pub format_version: u32,
+    /// The pointer size, in bytes, of the process the results were found in.
+    pub pointer_size: u64,
+    /// The modules that static pointer paths are based on, which must be loaded for the results to be used again.
+    pub module_names: Vec<String>,
+    pub pointer_scan_results: PointerScanResults,
+}
+
+impl PointerScanResultsFile {
+    pub const CURRENT_FORMAT_VERSION: u32 = 1;
+    pub const FILE_EXTENSION: &'static str = "ptr";
+
+    pub fn new(
+        pointer_size: u64,
+        pointer_scan_results: PointerScanResults,
+    ) -> Self {
+        let module_names: BTreeSet<String> = pointer_scan_results
+            .get_results()
+            .iter()
+            .filter(|pointer_scan_result| pointer_scan_result.is_module())
+            .map(|pointer_scan_result| pointer_scan_result.get_module_name().to_string())
+            .collect();
+
+        Self {
+            format_version: Self::CURRENT_FORMAT_VERSION,
+            pointer_size,
+            module_names: module_names.into_iter().collect(),
+            pointer_scan_results,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(self).map_err(|error| format!("Failed to serialize pointer scan results: {}", error))
+    }
+
+    /// Reads saved results, rejecting files written by a newer format version rather than guessing at their contents.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let pointer_scan_results_file: Self =
+            serde_json::from_slice(bytes).map_err(|error| format!("The file does not contain valid pointer scan results: {}", error))?;
+
+        if pointer_scan_results_file.format_version > Self::CURRENT_FORMAT_VERSION {
+            return Err(format!(
+                "The file was saved in pointer scan format version {}, but only versions up to {} can be loaded.",
+                pointer_scan_results_file.format_version,
+                Self::CURRENT_FORMAT_VERSION
+            ));
+        }
+
+        Ok(pointer_scan_results_file)
+    }
+
+    /// Checks that these results can be used in a process with the given pointer size and modules, and rebases static paths onto
+    /// the current address of their module. Fails if the pointer size differs, or if any module the results rely on is not loaded.
+    pub fn into_pointer_scan_results(
+        self,
+        pointer_size: u64,
+        resolve_module_address: impl Fn(&str) -> Option<u64>,
+    ) -> Result<PointerScanResults, String> {
+        if self.pointer_size != pointer_size {
+            return Err(format!(
+                "The results were saved from a {}-bit process, but the opened process is {}-bit.",
+                self.pointer_size * 8,
+                pointer_size * 8
+            ));
+        }
+
+        let missing_module_names: Vec<&str> = self
+            .module_names
+            .iter()
+            .filter(|module_name| resolve_module_address(module_name).is_none())
+            .map(String::as_str)
+            .collect();
+
+        if !missing_module_names.is_empty() {
+            return Err(format!(
+                "The results rely on modules that are not loaded in the opened process: {}.",
+                missing_module_names.join(", ")
+            ));
+        }
+
+        let mut pointer_scan_results = self.pointer_scan_results;
+        let rebased_results = pointer_scan_results
+            .get_results()
+            .iter()
+            .map(|pointer_scan_result| {
+                let base_address = if pointer_scan_result.is_module() {
+                    resolve_module_address(pointer_scan_result.get_module_name())
+                        .unwrap_or_default()
+                        .wrapping_add(pointer_scan_result.get_module_offset())
+                } else {
+                    pointer_scan_result.get_base_address()
+                };
+
+                PointerScanResult::new(
+                    base_address,
+                    pointer_scan_result.get_module_name().to_string(),
+                    pointer_scan_result.get_module_offset(),
+                    pointer_scan_result.get_offsets().clone(),
+                    pointer_scan_result.is_module(),
+                )
+            })
+            .collect();
+
+        pointer_scan_results.set_results(rebased_results);
+
+        Ok(pointer_scan_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerScanResultsFile;
+    use crate::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+    use crate::structures::pointer_scan::pointer_scan_results::PointerScanResults;
+
+    fn make_pointer_scan_results_file() -> PointerScanResultsFile {
+        let pointer_scan_results = PointerScanResults::new(
+            vec![
+                PointerScanResult::new(0x40_0100, "game.exe".to_string(), 0x100, vec![0x10, 0x20], true),
+                PointerScanResult::new(0x7F_0040, "engine.dll".to_string(), 0x40, vec![0x8], true),
+                PointerScanResult::new(0x5000, String::new(), 0x5000, vec![0x20], false),
+            ],
+            512,
+        );
+
+        PointerScanResultsFile::new(8, pointer_scan_results)
+    }
+
+    #[test]
+    fn saved_results_load_back_rebased_onto_the_current_modules() {
+        let bytes = make_pointer_scan_results_file().to_bytes().unwrap();
+        let pointer_scan_results_file = PointerScanResultsFile::from_bytes(&bytes).unwrap();
+
+        assert_eq!(pointer_scan_results_file.module_names, vec!["engine.dll".to_string(), "game.exe".to_string()]);
+
+        let pointer_scan_results = pointer_scan_results_file
+            .into_pointer_scan_results(8, |module_name| match module_name {
+                "game.exe" => Some(0x1_0000),
+                "engine.dll" => Some(0x2_0000),
+                _ => None,
+            })
+            .unwrap();
+        let results = pointer_scan_results.get_results();
+
+        assert_eq!(pointer_scan_results.get_result_count(), 3);
+        assert_eq!(pointer_scan_results.get_page_size(), 512);
+        assert_eq!(results[0].get_base_address(), 0x1_0100);
+        assert_eq!(results[1].get_base_address(), 0x2_0040);
+        assert_eq!(results[2].get_base_address(), 0x5000);
+        assert_eq!(results[0].get_offsets(), &vec![0x10, 0x20]);
+    }
+
+    #[test]
+    fn results_from_another_pointer_width_or_module_set_are_rejected() {
+        let pointer_width_error = make_pointer_scan_results_file()
+            .into_pointer_scan_results(4, |_module_name| Some(0x1_0000))
+            .unwrap_err();
+
+        assert!(pointer_width_error.contains("64-bit"));
+        assert!(pointer_width_error.contains("32-bit"));
+
+        let module_error = make_pointer_scan_results_file()
+            .into_pointer_scan_results(8, |module_name| (module_name == "game.exe").then_some(0x1_0000))
+            .unwrap_err();
+
+        assert!(module_error.contains("engine.dll"));
+        assert!(!module_error.contains("game.exe"));
+
+        let mut newer_file = make_pointer_scan_results_file();
+
+        newer_file.format_version = PointerScanResultsFile::CURRENT_FORMAT_VERSION + 1;
+
+        assert!(PointerScanResultsFile::from_bytes(&newer_file.to_bytes().unwrap()).is_err());
+        assert!(PointerScanResultsFile::from_bytes(b"not a pointer scan").is_err());
+    }
+}
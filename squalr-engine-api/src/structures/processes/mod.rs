@@ -1,3 +1,6 @@
 pub mod opened_process_info;
+pub mod process_attach_conflict;
+pub mod process_attach_mode;
 pub mod process_icon;
 pub mod process_info;
+pub mod process_thumbnail;
@@ -10,6 +10,9 @@ pub struct OpenedProcessInfo {
     handle: u64,
     bitness: Bitness,
     icon: Option<ProcessIcon>,
+    /// Whether this instance attached without the attach lock, and must leave writing and freezing to the instance holding it.
+    #[serde(default)]
+    is_read_only: bool,
 }
 
 impl OpenedProcessInfo {
@@ -26,9 +29,20 @@ impl OpenedProcessInfo {
             handle,
             bitness,
             icon,
+            is_read_only: false,
         }
     }
 
+    /// Gets this process with its read-only flag replaced, ie when another instance takes over the attach lock.
+    pub fn with_read_only(
+        mut self,
+        is_read_only: bool,
+    ) -> Self {
+        self.is_read_only = is_read_only;
+
+        self
+    }
+
     pub fn get_process_id(&self) -> Pid {
         Pid::from_u32(self.process_id)
     }
@@ -52,4 +66,8 @@ impl OpenedProcessInfo {
     pub fn get_icon(&self) -> &Option<ProcessIcon> {
         &self.icon
     }
+
+    pub fn get_is_read_only(&self) -> bool {
+        self.is_read_only
+    }
 }
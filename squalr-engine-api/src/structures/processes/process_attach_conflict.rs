@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Describes another Squalr instance already holding the attach lock on a process that this instance tried to open.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcessAttachConflict {
+    /// The process that both instances tried to attach to.
+    process_id: u32,
+    /// The process id of the Squalr instance holding the attach lock.
+    holder_process_id: u32,
+}
+
+impl ProcessAttachConflict {
+    pub fn new(
+        process_id: u32,
+        holder_process_id: u32,
+    ) -> Self {
+        Self { process_id, holder_process_id }
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    pub fn get_holder_process_id(&self) -> u32 {
+        self.holder_process_id
+    }
+}
+
+impl fmt::Display for ProcessAttachConflict {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            formatter,
+            "Another Squalr instance (pid {}) is already attached to process {}",
+            self.holder_process_id, self.process_id
+        )
+    }
+}
@@ -0,0 +1,26 @@
+/// How to attach to a process that another Squalr instance may already be attached to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessAttachMode {
+    /// Takes the attach lock, failing with a conflict if another instance holds it.
+    Exclusive,
+    /// Attaches without the attach lock. Values can be read, but nothing is written, such that freezes never fight.
+    ReadOnly,
+    /// Asks the instance holding the attach lock to release it and stop freezing, taking the lock by force if it does not answer.
+    Takeover,
+}
+
+impl ProcessAttachMode {
+    /// Gets the attach mode requested by the read-only and takeover flags of an open request. Read-only wins if both are set.
+    pub fn from_flags(
+        read_only: bool,
+        takeover: bool,
+    ) -> Self {
+        if read_only {
+            ProcessAttachMode::ReadOnly
+        } else if takeover {
+            ProcessAttachMode::Takeover
+        } else {
+            ProcessAttachMode::Exclusive
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::structures::processes::process_icon::ProcessIcon;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of capturing a preview of the main window of a process.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub enum ProcessThumbnail {
+    /// The main window was captured, downscaled to fit the requested bounds. Stored as a plain RGBA image, like process icons.
+    Captured(ProcessIcon),
+    /// The main window exists, but is minimized and has nothing to render.
+    Minimized,
+    /// The process has no visible window, ie console, service, or headless processes.
+    NoWindow,
+    /// Window capture is not supported on this platform.
+    #[default]
+    Unsupported,
+}
+
+impl ProcessThumbnail {
+    pub fn get_image(&self) -> Option<&ProcessIcon> {
+        match self {
+            ProcessThumbnail::Captured(image) => Some(image),
+            _ => None,
+        }
+    }
+
+    /// Gets a short description of why no image is available, for display in place of the thumbnail.
+    pub fn get_unavailable_reason(&self) -> Option<&'static str> {
+        match self {
+            ProcessThumbnail::Captured(_) => None,
+            ProcessThumbnail::Minimized => Some("Window is minimized"),
+            ProcessThumbnail::NoWindow => Some("No window"),
+            ProcessThumbnail::Unsupported => Some("No window preview on this platform"),
+        }
+    }
+}
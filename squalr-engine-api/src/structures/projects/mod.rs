@@ -1,6 +1,10 @@
 pub mod project;
 pub mod project_info;
 pub mod project_items;
+pub mod project_items_add_summary;
 pub mod project_manager;
 pub mod project_manifest;
 pub mod project_ref;
+pub mod project_search;
+pub mod struct_dissections;
+pub mod write_sequences;
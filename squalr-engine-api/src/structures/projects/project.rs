@@ -1,13 +1,23 @@
 use crate::structures::{
+    hotkeys::hotkey_chord::HotkeyChord,
+    memory::memory_address_target::MemoryAddressTarget,
     processes::process_icon::ProcessIcon,
     projects::{
         project_info::ProjectInfo,
-        project_items::{project_item::ProjectItem, project_item_ref::ProjectItemRef},
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item::ProjectItem,
+            project_item_hotkey::ProjectItemHotkey,
+            project_item_ref::ProjectItemRef,
+        },
         project_manifest::ProjectManifest,
+        struct_dissections::struct_dissection::StructDissection,
+        write_sequences::write_sequence::WriteSequence,
     },
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Represents a full project in memory that can be serialized to the filesystem as distinct files, or exported as a single file.
 #[derive(Serialize, Deserialize)]
@@ -21,6 +31,10 @@ pub struct Project {
     /// The reference to the root project item, which is always a directory.
     #[serde(skip)]
     project_root_ref: ProjectItemRef,
+
+    /// The paths of items that were moved or removed since the last save, whose files are deleted when the project is saved.
+    #[serde(skip)]
+    removed_project_item_paths: Vec<PathBuf>,
 }
 
 impl Project {
@@ -33,11 +47,27 @@ impl Project {
         project_items: HashMap<ProjectItemRef, ProjectItem>,
         project_root_ref: ProjectItemRef,
     ) -> Self {
-        Self {
+        let mut project = Self {
             project_info,
             project_items,
             project_root_ref,
+            removed_project_item_paths: vec![],
+        };
+
+        for disabled_group_path in project
+            .project_info
+            .get_project_manifest()
+            .get_disabled_group_paths()
+            .clone()
+        {
+            let group_ref = ProjectItemRef::new(project.get_absolute_path(&disabled_group_path));
+
+            if let Some(group) = project.project_items.get_mut(&group_ref) {
+                group.set_is_enabled(false);
+            }
         }
+
+        project
     }
 
     pub fn get_name(&self) -> &str {
@@ -63,6 +93,24 @@ impl Project {
         self.project_info.set_project_icon(project_icon);
     }
 
+    /// Gets whether the project info or any project item has changes that have not been saved.
+    pub fn get_has_unsaved_changes(&self) -> bool {
+        self.project_info.get_has_unsaved_changes()
+            || self
+                .project_items
+                .values()
+                .any(|project_item| project_item.get_has_unsaved_changes())
+    }
+
+    /// Marks the project info and all project items as saved, such as after loading the project from disk.
+    pub fn clear_unsaved_changes(&mut self) {
+        self.project_info.set_has_unsaved_changes(false);
+
+        for project_item in self.project_items.values_mut() {
+            project_item.set_has_unsaved_changes(false);
+        }
+    }
+
     pub fn get_project_items(&self) -> &HashMap<ProjectItemRef, ProjectItem> {
         &self.project_items
     }
@@ -71,6 +119,10 @@ impl Project {
         &mut self.project_items
     }
 
+    pub fn get_project_root_ref(&self) -> &ProjectItemRef {
+        &self.project_root_ref
+    }
+
     pub fn get_project_root(&self) -> Option<&ProjectItem> {
         self.project_items.get(&self.project_root_ref)
     }
@@ -79,10 +131,825 @@ impl Project {
         self.project_items.get_mut(&self.project_root_ref)
     }
 
+    pub fn get_project_item(
+        &self,
+        project_item_ref: &ProjectItemRef,
+    ) -> Option<&ProjectItem> {
+        self.project_items.get(project_item_ref)
+    }
+
     pub fn get_project_item_mut(
         &mut self,
         project_item_ref: &ProjectItemRef,
     ) -> Option<&mut ProjectItem> {
         self.project_items.get_mut(project_item_ref)
     }
+
+    /// Gets whether a project item is enabled, which requires both the item and every group containing it to be enabled. Disabled
+    /// items are skipped by value refreshing, freezing, triggers, and hotkeys.
+    pub fn is_project_item_enabled(
+        &self,
+        project_item_ref: &ProjectItemRef,
+    ) -> bool {
+        project_item_ref
+            .get_project_item_path()
+            .ancestors()
+            .all(|path| match self.project_items.get(&ProjectItemRef::new(path.to_path_buf())) {
+                Some(project_item) => project_item.get_is_enabled(),
+                None => true,
+            })
+    }
+
+    /// Gets whether a project item is both activated and enabled, and should therefore be frozen or otherwise acted upon.
+    pub fn is_project_item_active(
+        &self,
+        project_item_ref: &ProjectItemRef,
+    ) -> bool {
+        match self.project_items.get(project_item_ref) {
+            Some(project_item) => project_item.get_is_activated() && self.is_project_item_enabled(project_item_ref),
+            None => false,
+        }
+    }
+
+    /// Enables or disables a project item, leaving its activation as is. Returns false if the item does not exist.
+    pub fn set_project_item_enabled(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        is_enabled: bool,
+    ) -> bool {
+        let relative_path = self.get_relative_path(project_item_ref.get_project_item_path());
+        let project_item = match self.project_items.get_mut(project_item_ref) {
+            Some(project_item) => project_item,
+            None => return false,
+        };
+
+        if project_item.get_is_enabled() == is_enabled {
+            return true;
+        }
+
+        project_item.set_is_enabled(is_enabled);
+
+        // Groups are saved as part of the manifest, as directories are not serialized.
+        if project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID {
+            self.project_info
+                .get_project_manifest_mut()
+                .set_group_enabled(relative_path, is_enabled);
+            self.project_info.set_has_unsaved_changes(true);
+        } else {
+            project_item.set_has_unsaved_changes(true);
+        }
+
+        true
+    }
+
+    /// Enables or disables a project item along with every item within it, such that a group can be toggled in one click.
+    /// Returns false if the item does not exist.
+    pub fn set_project_item_enabled_recursive(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        is_enabled: bool,
+    ) -> bool {
+        if !self.set_project_item_enabled(project_item_ref, is_enabled) {
+            return false;
+        }
+
+        for descendant_ref in self.get_descendant_refs(project_item_ref) {
+            self.set_project_item_enabled(&descendant_ref, is_enabled);
+        }
+
+        true
+    }
+
+    /// Gets the addresses of every disabled address entry, which the engine skips when freezing and comparing watched values. Addresses
+    /// that also have an enabled entry are left out, as that entry still expects them to be acted upon.
+    pub fn get_disabled_address_targets(&self) -> Vec<MemoryAddressTarget> {
+        let mut enabled_address_targets = HashSet::new();
+        let mut disabled_address_targets = HashSet::new();
+
+        for (project_item_ref, project_item) in &self.project_items {
+            if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
+                continue;
+            }
+
+            let address_target = MemoryAddressTarget::new(
+                ProjectItemTypeAddress::get_field_address(project_item),
+                ProjectItemTypeAddress::get_field_module(project_item),
+            );
+
+            if self.is_project_item_enabled(project_item_ref) {
+                enabled_address_targets.insert(address_target);
+            } else {
+                disabled_address_targets.insert(address_target);
+            }
+        }
+
+        disabled_address_targets
+            .into_iter()
+            .filter(|address_target| !enabled_address_targets.contains(address_target))
+            .collect()
+    }
+
+    /// Gets whether the given project item is a group, which are directories that hold other project items.
+    pub fn is_project_group(
+        &self,
+        project_item_ref: &ProjectItemRef,
+    ) -> bool {
+        self.project_items
+            .get(project_item_ref)
+            .is_some_and(|project_item| project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID)
+    }
+
+    /// Gets every project item within the given group, at any depth, excluding the group itself.
+    pub fn get_descendant_refs(
+        &self,
+        group_ref: &ProjectItemRef,
+    ) -> Vec<ProjectItemRef> {
+        self.project_items
+            .keys()
+            .filter(|project_item_ref| {
+                *project_item_ref != group_ref
+                    && project_item_ref
+                        .get_project_item_path()
+                        .starts_with(group_ref.get_project_item_path())
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Moves a project item, along with everything within it, into the given group. The item keeps its name unless the group
+    /// already holds an item of that name, in which case it is renamed as `add_project_item_with_unique_name` does.
+    /// Returns the new reference of the item, or None if the item or group do not exist, or if a group is moved into itself.
+    pub fn move_project_item(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        target_group_ref: &ProjectItemRef,
+    ) -> Option<ProjectItemRef> {
+        let source_path = project_item_ref.get_project_item_path().clone();
+        let target_group_path = target_group_ref.get_project_item_path();
+
+        if *project_item_ref == self.project_root_ref
+            || !self.project_items.contains_key(project_item_ref)
+            || !self.is_project_group(target_group_ref)
+            || target_group_path.starts_with(&source_path)
+        {
+            return None;
+        }
+
+        if source_path.parent() == Some(target_group_path.as_path()) {
+            return Some(project_item_ref.clone());
+        }
+
+        let is_group = self.is_project_group(project_item_ref);
+        let mut destination_ref = ProjectItemRef::new(target_group_path.join(source_path.file_name()?));
+
+        if self.project_items.contains_key(&destination_ref) {
+            let extension = if is_group { "" } else { Self::PROJECT_ITEM_EXTENSION };
+            let base_name = self.project_items.get(project_item_ref)?.get_field_name();
+            let (name, unique_ref) = self.get_unique_project_item_ref(target_group_path, extension, &base_name);
+
+            if let Some(project_item) = self.project_items.get_mut(project_item_ref) {
+                project_item.set_field_name(&name);
+            }
+
+            destination_ref = unique_ref;
+        }
+
+        let destination_path = destination_ref.get_project_item_path().clone();
+        let mut moved_project_item_refs = self.get_descendant_refs(project_item_ref);
+
+        moved_project_item_refs.push(project_item_ref.clone());
+
+        for moved_project_item_ref in moved_project_item_refs {
+            if let Some(mut project_item) = self.project_items.remove(&moved_project_item_ref) {
+                let relative_path = moved_project_item_ref
+                    .get_project_item_path()
+                    .strip_prefix(&source_path)
+                    .unwrap_or(Path::new(""));
+
+                // Joining an empty path would add a trailing separator, so the moved item itself takes the destination path as is.
+                let moved_path = if relative_path.as_os_str().is_empty() {
+                    destination_path.clone()
+                } else {
+                    destination_path.join(relative_path)
+                };
+
+                project_item.set_has_unsaved_changes(true);
+                self.project_items
+                    .insert(ProjectItemRef::new(moved_path), project_item);
+            }
+        }
+
+        // Groups are stored in the manifest by path, so disabled groups that were moved are updated to follow them.
+        let relative_source_path = self.get_relative_path(&source_path);
+        let relative_destination_path = self.get_relative_path(&destination_path);
+
+        self.project_info
+            .get_project_manifest_mut()
+            .move_group_paths(&relative_source_path, &relative_destination_path);
+        self.project_info.set_has_unsaved_changes(true);
+        self.removed_project_item_paths.push(source_path);
+
+        Some(destination_ref)
+    }
+
+    /// Removes a project item. Groups either take everything within them along, or first move their items into the group that
+    /// contains them. Returns false if the item does not exist, or is the project root.
+    pub fn remove_project_item(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        should_move_children_to_parent: bool,
+    ) -> bool {
+        if *project_item_ref == self.project_root_ref || !self.project_items.contains_key(project_item_ref) {
+            return false;
+        }
+
+        let removed_path = project_item_ref.get_project_item_path().clone();
+
+        if should_move_children_to_parent && let Some(parent_path) = removed_path.parent() {
+            let parent_ref = ProjectItemRef::new(parent_path.to_path_buf());
+            let child_refs: Vec<ProjectItemRef> = self
+                .get_descendant_refs(project_item_ref)
+                .into_iter()
+                .filter(|descendant_ref| descendant_ref.get_project_item_path().parent() == Some(removed_path.as_path()))
+                .collect();
+
+            for child_ref in child_refs {
+                self.move_project_item(&child_ref, &parent_ref);
+            }
+        }
+
+        for descendant_ref in self.get_descendant_refs(project_item_ref) {
+            self.project_items.remove(&descendant_ref);
+        }
+
+        self.project_items.remove(project_item_ref);
+
+        let relative_removed_path = self.get_relative_path(&removed_path);
+
+        self.project_info
+            .get_project_manifest_mut()
+            .remove_group_paths(&relative_removed_path);
+        self.project_info.set_has_unsaved_changes(true);
+        self.removed_project_item_paths.push(removed_path);
+
+        true
+    }
+
+    /// Takes the paths of items that were moved or removed since the last save, such that their files can be deleted.
+    pub fn take_removed_project_item_paths(&mut self) -> Vec<PathBuf> {
+        std::mem::take(&mut self.removed_project_item_paths)
+    }
+
+    /// Gets a path relative to the project directory, as stored in the manifest.
+    pub fn get_relative_path(
+        &self,
+        path: &Path,
+    ) -> PathBuf {
+        match self.project_info.get_project_directory() {
+            Some(project_directory) => path
+                .strip_prefix(&project_directory)
+                .map(|relative_path| relative_path.to_path_buf())
+                .unwrap_or_else(|_| path.to_path_buf()),
+            None => path.to_path_buf(),
+        }
+    }
+
+    /// Gets the absolute path of a path stored relative to the project directory.
+    pub fn get_absolute_path(
+        &self,
+        relative_path: &Path,
+    ) -> PathBuf {
+        match self.project_info.get_project_directory() {
+            Some(project_directory) => project_directory.join(relative_path),
+            None => relative_path.to_path_buf(),
+        }
+    }
+
+    /// Adds a write sequence, replacing any existing sequence of the same name.
+    pub fn set_write_sequence(
+        &mut self,
+        write_sequence: WriteSequence,
+    ) {
+        self.project_info
+            .get_project_manifest_mut()
+            .set_write_sequence(write_sequence);
+        self.project_info.set_has_unsaved_changes(true);
+    }
+
+    /// Removes the write sequence of the given name. Returns false if there is no such sequence.
+    pub fn remove_write_sequence(
+        &mut self,
+        name: &str,
+    ) -> bool {
+        let is_removed = self
+            .project_info
+            .get_project_manifest_mut()
+            .remove_write_sequence(name);
+
+        if is_removed {
+            self.project_info.set_has_unsaved_changes(true);
+        }
+
+        is_removed
+    }
+
+    /// Adds a struct dissection, replacing any existing dissection of the same name.
+    pub fn set_struct_dissection(
+        &mut self,
+        struct_dissection: StructDissection,
+    ) {
+        self.project_info
+            .get_project_manifest_mut()
+            .set_struct_dissection(struct_dissection);
+        self.project_info.set_has_unsaved_changes(true);
+    }
+
+    /// Removes the struct dissection of the given name. Returns false if there is no such dissection.
+    pub fn remove_struct_dissection(
+        &mut self,
+        name: &str,
+    ) -> bool {
+        let is_removed = self
+            .project_info
+            .get_project_manifest_mut()
+            .remove_struct_dissection(name);
+
+        if is_removed {
+            self.project_info.set_has_unsaved_changes(true);
+        }
+
+        is_removed
+    }
+
+    /// Gets the project item that the given chord is bound to, along with the hotkey bound to it.
+    pub fn find_hotkey(
+        &self,
+        chord: &HotkeyChord,
+    ) -> Option<(&ProjectItemRef, &ProjectItemHotkey)> {
+        self.project_items
+            .iter()
+            .find_map(|(project_item_ref, project_item)| {
+                project_item
+                    .get_hotkeys()
+                    .iter()
+                    .find(|hotkey| hotkey.get_chord() == chord)
+                    .map(|hotkey| (project_item_ref, hotkey))
+            })
+    }
+
+    /// Gets the chords of every hotkey in the project, in no particular order.
+    pub fn get_hotkey_chords(&self) -> Vec<HotkeyChord> {
+        self.project_items
+            .values()
+            .flat_map(|project_item| project_item.get_hotkeys())
+            .map(|hotkey| hotkey.get_chord().clone())
+            .collect()
+    }
+
+    /// Binds a hotkey to a project item. A chord can only be bound once per project, so binding a chord that another item uses
+    /// fails rather than triggering both. Binding a chord the item already uses replaces its action.
+    pub fn set_project_item_hotkey(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        hotkey: ProjectItemHotkey,
+    ) -> Result<(), String> {
+        if let Some((bound_project_item_ref, _hotkey)) = self.find_hotkey(hotkey.get_chord())
+            && bound_project_item_ref != project_item_ref
+        {
+            let bound_project_item_name = self
+                .project_items
+                .get(bound_project_item_ref)
+                .map(|project_item| project_item.get_field_name())
+                .unwrap_or_default();
+
+            return Err(format!("{} is already bound to '{}'.", hotkey.get_chord(), bound_project_item_name));
+        }
+
+        let project_item = self
+            .project_items
+            .get_mut(project_item_ref)
+            .ok_or_else(|| format!("The entry '{}' no longer exists.", project_item_ref.get_project_item_path().display()))?;
+
+        project_item.set_hotkey(hotkey);
+        project_item.set_has_unsaved_changes(true);
+
+        Ok(())
+    }
+
+    /// Unbinds the hotkey with the given chord from a project item. Returns false if the chord was not bound to the item.
+    pub fn remove_project_item_hotkey(
+        &mut self,
+        project_item_ref: &ProjectItemRef,
+        chord: &HotkeyChord,
+    ) -> bool {
+        match self.project_items.get_mut(project_item_ref) {
+            Some(project_item) => {
+                if !project_item.remove_hotkey(chord) {
+                    return false;
+                }
+
+                project_item.set_has_unsaved_changes(true);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts a project item, renaming it to its current name followed by the first free numeric suffix, ie `u32 2`.
+    pub fn add_project_item_with_unique_name(
+        &mut self,
+        project_item: ProjectItem,
+    ) -> ProjectItemRef {
+        let project_directory = self.project_info.get_project_directory().unwrap_or_default();
+
+        self.insert_project_item_with_unique_name(&project_directory, Self::PROJECT_ITEM_EXTENSION, project_item)
+    }
+
+    /// Inserts a project item into the given group, renaming it as `add_project_item_with_unique_name` does.
+    pub fn add_project_item_to_group_with_unique_name(
+        &mut self,
+        group_ref: &ProjectItemRef,
+        project_item: ProjectItem,
+    ) -> ProjectItemRef {
+        let group_path = group_ref.get_project_item_path().clone();
+
+        self.insert_project_item_with_unique_name(&group_path, Self::PROJECT_ITEM_EXTENSION, project_item)
+    }
+
+    /// Creates an empty group at the root of the project, named after the given name followed by the first free numeric suffix.
+    pub fn add_project_group_with_unique_name(
+        &mut self,
+        name: &str,
+    ) -> ProjectItemRef {
+        let project_directory = self.project_info.get_project_directory().unwrap_or_default();
+        let group = ProjectItemTypeDirectory::new_project_item(&ProjectItemRef::new(PathBuf::from(name)));
+
+        self.insert_project_item_with_unique_name(&project_directory, "", group)
+    }
+
+    /// Creates an empty group within the given group, renaming it as `add_project_group_with_unique_name` does.
+    pub fn add_project_group_to_group_with_unique_name(
+        &mut self,
+        parent_group_ref: &ProjectItemRef,
+        name: &str,
+    ) -> ProjectItemRef {
+        let parent_group_path = parent_group_ref.get_project_item_path().clone();
+        let group = ProjectItemTypeDirectory::new_project_item(&ProjectItemRef::new(PathBuf::from(name)));
+
+        self.insert_project_item_with_unique_name(&parent_group_path, "", group)
+    }
+
+    /// Gets whether an address entry already exists at the given address, which is relative to the module if one is given.
+    pub fn has_address_entry(
+        &self,
+        address: u64,
+        module: &str,
+    ) -> bool {
+        self.project_items.values().any(|project_item| {
+            project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID
+                && ProjectItemTypeAddress::get_field_address(project_item) == address
+                && ProjectItemTypeAddress::get_field_module(project_item) == module
+        })
+    }
+
+    fn insert_project_item_with_unique_name(
+        &mut self,
+        directory: &Path,
+        extension: &str,
+        mut project_item: ProjectItem,
+    ) -> ProjectItemRef {
+        let (name, project_item_ref) = self.get_unique_project_item_ref(directory, extension, &project_item.get_field_name());
+
+        project_item.set_field_name(&name);
+        self.project_items.insert(project_item_ref.clone(), project_item);
+
+        project_item_ref
+    }
+
+    /// Gets the given name followed by the first numeric suffix that is not taken in the directory, along with its reference.
+    fn get_unique_project_item_ref(
+        &self,
+        directory: &Path,
+        extension: &str,
+        base_name: &str,
+    ) -> (String, ProjectItemRef) {
+        (1..)
+            .map(|suffix| {
+                let name = format!("{} {}", base_name, suffix);
+                let project_item_path = directory.join(format!("{}{}", name, extension));
+
+                (name, ProjectItemRef::new(project_item_path))
+            })
+            .find(|(_name, project_item_ref)| !self.project_items.contains_key(project_item_ref))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Project;
+    use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+    use crate::structures::memory::memory_address_target::MemoryAddressTarget;
+    use crate::structures::projects::{
+        project_info::ProjectInfo,
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item::ProjectItem,
+            project_item_hotkey::{ProjectItemHotkey, ProjectItemHotkeyAction},
+            project_item_ref::ProjectItemRef,
+        },
+        project_manifest::ProjectManifest,
+    };
+    use crate::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn create_project(project_manifest: ProjectManifest) -> (Project, ProjectItemRef, ProjectItemRef) {
+        let project_directory = PathBuf::from("projects").join("test");
+        let project_root_ref = ProjectItemRef::new(project_directory.clone());
+        let group_ref = ProjectItemRef::new(project_directory.join("group"));
+        let address_ref = ProjectItemRef::new(project_directory.join("group").join("health.json"));
+        let mut project_items = HashMap::new();
+
+        project_items.insert(project_root_ref.clone(), ProjectItemTypeDirectory::new_project_item(&project_root_ref));
+        project_items.insert(group_ref.clone(), ProjectItemTypeDirectory::new_project_item(&group_ref));
+        project_items.insert(
+            address_ref.clone(),
+            ProjectItemTypeAddress::new_project_item("health", 0x1000, "", "", DataTypeU32::get_value_from_primitive(100)),
+        );
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, project_manifest);
+        let project = Project::new(project_info, project_items, project_root_ref);
+
+        (project, group_ref, address_ref)
+    }
+
+    #[test]
+    fn only_saved_state_changes_mark_the_project_unsaved() {
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        // Freshly created items are unsaved until they are written to disk for the first time.
+        assert!(project.get_has_unsaved_changes());
+
+        project.clear_unsaved_changes();
+
+        // Activation is not saved with the project, so toggling it leaves the project clean.
+        if let Some(project_item) = project.get_project_item_mut(&address_ref) {
+            project_item.toggle_activated();
+        }
+
+        assert!(!project.get_has_unsaved_changes());
+
+        project.set_project_item_enabled(&address_ref, false);
+
+        assert!(project.get_has_unsaved_changes());
+
+        project.clear_unsaved_changes();
+        project.set_project_item_enabled(&group_ref, false);
+
+        assert!(project.get_project_info().get_has_unsaved_changes());
+
+        project.clear_unsaved_changes();
+
+        // Setting an item to the state it is already in is not a change.
+        project.set_project_item_enabled(&group_ref, false);
+
+        assert!(!project.get_has_unsaved_changes());
+
+        let added_project_item_ref = project.add_project_item_with_unique_name(ProjectItemTypeAddress::new_project_item(
+            "mana",
+            0x2000,
+            "",
+            "",
+            DataTypeU32::get_value_from_primitive(50),
+        ));
+
+        assert!(project.get_has_unsaved_changes());
+        assert!(added_project_item_ref.get_project_item_path().starts_with(PathBuf::from("projects").join("test")));
+    }
+
+    #[test]
+    fn disabled_group_overrides_enabled_entries_without_changing_them() {
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        if let Some(project_item) = project.get_project_item_mut(&address_ref) {
+            project_item.toggle_activated();
+        }
+
+        assert!(project.is_project_item_active(&address_ref));
+        assert!(project.set_project_item_enabled(&group_ref, false));
+        assert!(!project.is_project_item_active(&address_ref));
+        assert!(
+            project
+                .get_project_item(&address_ref)
+                .map(|project_item| project_item.get_is_enabled() && project_item.get_is_activated())
+                .unwrap_or(false)
+        );
+
+        // Enabling the group only brings back entries that are individually enabled.
+        assert!(project.set_project_item_enabled(&address_ref, false));
+        assert!(project.set_project_item_enabled(&group_ref, true));
+        assert!(!project.is_project_item_enabled(&address_ref));
+        assert!(project.set_project_item_enabled(&address_ref, true));
+        assert!(project.is_project_item_active(&address_ref));
+    }
+
+    #[test]
+    fn only_addresses_without_an_enabled_entry_are_disabled() {
+        let (mut project, group_ref, _address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        assert!(project.get_disabled_address_targets().is_empty());
+        assert!(project.set_project_item_enabled(&group_ref, false));
+        assert_eq!(project.get_disabled_address_targets(), vec![MemoryAddressTarget::new(0x1000, String::new())]);
+
+        // Another enabled entry at the same address keeps it enabled.
+        project.add_project_item_with_unique_name(ProjectItemTypeAddress::new_project_item(
+            "health copy",
+            0x1000,
+            "",
+            "",
+            DataTypeU32::get_value_from_primitive(100),
+        ));
+
+        assert!(project.get_disabled_address_targets().is_empty());
+    }
+
+    #[test]
+    fn group_enablement_round_trips_through_the_manifest() {
+        let (mut project, group_ref, _address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        project.set_project_item_enabled(&group_ref, false);
+
+        assert_eq!(project.get_project_manifest().get_disabled_group_paths(), &vec![PathBuf::from("group")]);
+
+        let (reloaded_project, group_ref, address_ref) = create_project(project.get_project_manifest().clone());
+
+        assert!(!reloaded_project.is_project_item_enabled(&group_ref));
+        assert!(!reloaded_project.is_project_item_enabled(&address_ref));
+    }
+
+    #[test]
+    fn groups_hold_their_entries_and_address_entries_are_found_by_address() {
+        let (mut project, _group_ref, _address_ref) = create_project(ProjectManifest::new(vec![]));
+        let struct_group_ref = project.add_project_group_with_unique_name("Entity header");
+        let field_ref = project.add_project_item_to_group_with_unique_name(
+            &struct_group_ref,
+            ProjectItemTypeAddress::new_project_item("hp", 0x18, "game.exe", "", DataTypeU32::get_value_from_primitive(0)),
+        );
+
+        assert_eq!(struct_group_ref.get_file_or_directory_name(), "Entity header 1");
+        assert_eq!(
+            project.add_project_group_with_unique_name("Entity header"),
+            ProjectItemRef::new(PathBuf::from("projects").join("test").join("Entity header 2"))
+        );
+        assert_eq!(field_ref.get_project_item_path().parent(), Some(struct_group_ref.get_project_item_path().as_path()));
+        assert!(project.has_address_entry(0x18, "game.exe"));
+        assert!(project.has_address_entry(0x1000, ""));
+        assert!(!project.has_address_entry(0x18, ""));
+    }
+
+    #[test]
+    fn enabling_a_group_recursively_sets_every_entry_within_it() {
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        assert!(project.set_project_item_enabled(&address_ref, false));
+        assert!(project.set_project_item_enabled_recursive(&group_ref, false));
+        assert!(!project.get_project_item(&group_ref).unwrap().get_is_enabled());
+        assert!(project.set_project_item_enabled_recursive(&group_ref, true));
+        assert!(project.get_project_item(&group_ref).unwrap().get_is_enabled());
+        assert!(project.get_project_item(&address_ref).unwrap().get_is_enabled());
+    }
+
+    #[test]
+    fn moving_a_group_takes_its_entries_and_disabled_state_along() {
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+        let target_group_ref = project.add_project_group_with_unique_name("Player");
+
+        project.set_project_item_enabled(&group_ref, false);
+
+        // Groups cannot be moved into themselves.
+        assert_eq!(project.move_project_item(&group_ref, &group_ref), None);
+
+        let moved_group_ref = project
+            .move_project_item(&group_ref, &target_group_ref)
+            .expect("Group should move.");
+        let moved_address_ref = ProjectItemRef::new(moved_group_ref.get_project_item_path().join("health.json"));
+
+        assert!(project.get_project_item(&group_ref).is_none());
+        assert!(project.get_project_item(&address_ref).is_none());
+        assert!(project.get_project_item(&moved_address_ref).is_some());
+        assert!(!project.is_project_item_enabled(&moved_address_ref));
+        assert_eq!(
+            project.get_project_manifest().get_disabled_group_paths(),
+            &vec![PathBuf::from("Player 1").join("group")]
+        );
+        assert_eq!(project.take_removed_project_item_paths(), vec![group_ref.get_project_item_path().clone()]);
+
+        // Names taken in the target group are made unique, as when adding entries.
+        let nested_group_ref = project.add_project_group_to_group_with_unique_name(&moved_group_ref, "Stats");
+        let root_group_ref = project.add_project_group_with_unique_name("Stats");
+        let moved_root_group_ref = project
+            .move_project_item(&root_group_ref, &moved_group_ref)
+            .expect("Group should move.");
+
+        assert_eq!(nested_group_ref, ProjectItemRef::new(moved_group_ref.get_project_item_path().join("Stats 1")));
+        assert_eq!(
+            moved_root_group_ref,
+            ProjectItemRef::new(moved_group_ref.get_project_item_path().join("Stats 1 1"))
+        );
+        assert_eq!(
+            project
+                .get_project_item(&moved_root_group_ref)
+                .unwrap()
+                .get_field_name(),
+            "Stats 1 1"
+        );
+    }
+
+    #[test]
+    fn removing_a_group_deletes_or_keeps_its_entries() {
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+        let root_ref = project.get_project_root_ref().clone();
+
+        assert!(!project.remove_project_item(&root_ref, false));
+        assert!(project.remove_project_item(&group_ref, false));
+        assert!(project.get_project_item(&group_ref).is_none());
+        assert!(project.get_project_item(&address_ref).is_none());
+
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+        let kept_address_ref = ProjectItemRef::new(PathBuf::from("projects").join("test").join("health.json"));
+
+        assert!(project.remove_project_item(&group_ref, true));
+        assert!(project.get_project_item(&address_ref).is_none());
+        assert!(project.get_project_item(&kept_address_ref).is_some());
+
+        let (mut project, group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+
+        project.set_project_item_enabled(&group_ref, false);
+
+        assert!(project.remove_project_item(&group_ref, false));
+        assert!(project.get_project_item(&address_ref).is_none());
+        assert!(
+            project
+                .get_project_manifest()
+                .get_disabled_group_paths()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn chords_bound_to_another_entry_are_refused_and_follow_moved_entries() {
+        let (mut project, _group_ref, address_ref) = create_project(ProjectManifest::new(vec![]));
+        let mana_ref = project.add_project_item_with_unique_name(ProjectItemTypeAddress::new_project_item(
+            "mana",
+            0x2000,
+            "",
+            "",
+            DataTypeU32::get_value_from_primitive(50),
+        ));
+        let chord = "Ctrl+Numpad1"
+            .parse::<HotkeyChord>()
+            .expect("Chord should parse.");
+
+        assert!(
+            project
+                .set_project_item_hotkey(&address_ref, ProjectItemHotkey::new(chord.clone(), ProjectItemHotkeyAction::ToggleFreeze))
+                .is_ok()
+        );
+        assert!(
+            project
+                .set_project_item_hotkey(&mana_ref, ProjectItemHotkey::new(chord.clone(), ProjectItemHotkeyAction::ToggleFreeze))
+                .is_err()
+        );
+
+        let root_ref = project.get_project_root_ref().clone();
+        let moved_address_ref = project
+            .move_project_item(&address_ref, &root_ref)
+            .expect("Entry should move.");
+
+        // Hotkeys are stored on the entry itself, such that they follow it when it is moved.
+        assert_eq!(
+            project
+                .find_hotkey(&chord)
+                .map(|(project_item_ref, _hotkey)| project_item_ref.clone()),
+            Some(moved_address_ref.clone())
+        );
+        assert!(!project.remove_project_item_hotkey(&mana_ref, &chord));
+        assert!(project.remove_project_item_hotkey(&moved_address_ref, &chord));
+        assert!(project.get_hotkey_chords().is_empty());
+    }
+
+    #[test]
+    fn entries_saved_before_the_enabled_flag_load_as_enabled() {
+        let project_item = ProjectItemTypeAddress::new_project_item("health", 0x1000, "", "", DataTypeU32::get_value_from_primitive(100));
+        let mut project_item_json = serde_json::to_value(&project_item).unwrap_or_default();
+
+        if let Some(fields) = project_item_json.as_object_mut() {
+            fields.remove("is_enabled");
+        }
+
+        let project_item: ProjectItem = serde_json::from_value(project_item_json).expect("Project item should deserialize.");
+
+        assert!(project_item.get_is_enabled());
+    }
 }
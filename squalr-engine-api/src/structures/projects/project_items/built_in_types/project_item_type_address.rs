@@ -108,7 +108,7 @@ impl ProjectItemTypeAddress {
         project_item
     }
 
-    pub fn get_field_address(project_item: &mut ProjectItem) -> u64 {
+    pub fn get_field_address(project_item: &ProjectItem) -> u64 {
         if let Some(name_field) = project_item
             .get_properties()
             .get_fields()
@@ -142,17 +142,8 @@ impl ProjectItemTypeAddress {
             .set_field_data(Self::PROPERTY_ADDRESS, field_data, false);
     }
 
-    pub fn get_field_module(project_item: &mut ProjectItem) -> String {
-        if let Some(name_field) = project_item
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_MODULE)
-        {
-            name_field.get_display_string(true, 0)
-        } else {
-            String::new()
-        }
+    pub fn get_field_module(project_item: &ProjectItem) -> String {
+        Self::get_field_string(project_item, Self::PROPERTY_MODULE).unwrap_or_default()
     }
 
     pub fn set_field_module(
@@ -168,16 +159,7 @@ impl ProjectItemTypeAddress {
     }
 
     pub fn get_field_freeze_data_value_interpreter(project_item: &mut ProjectItem) -> String {
-        if let Some(name_field) = project_item
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_FREEZE_DISPLAY_VALUE)
-        {
-            name_field.get_display_string(true, 0)
-        } else {
-            String::new()
-        }
+        Self::get_field_string(project_item, Self::PROPERTY_FREEZE_DISPLAY_VALUE).unwrap_or_default()
     }
 
     pub fn set_field_freeze_data_value_interpreter(
@@ -192,17 +174,8 @@ impl ProjectItemTypeAddress {
             .set_field_data(Self::PROPERTY_FREEZE_DISPLAY_VALUE, field_data, true);
     }
 
-    pub fn get_field_symbolic_struct_definition_reference(project_item: &mut ProjectItem) -> Option<SymbolicStructRef> {
-        if let Some(name_field) = project_item
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE)
-        {
-            Some(SymbolicStructRef::new(name_field.get_display_string(true, 0)))
-        } else {
-            None
-        }
+    pub fn get_field_symbolic_struct_definition_reference(project_item: &ProjectItem) -> Option<SymbolicStructRef> {
+        Self::get_field_string(project_item, Self::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE).map(SymbolicStructRef::new)
     }
 
     pub fn set_field_symbolic_struct_definition_reference(
@@ -216,4 +189,18 @@ impl ProjectItemTypeAddress {
             .get_properties_mut()
             .set_field_data(Self::PROPERTY_SYMBOLIC_STRUCT_DEFINITION_REFERENCE, field_data, false);
     }
+
+    /// Reads a string property as text. Display strings of fields are their raw bytes, which is not what string properties hold.
+    fn get_field_string(
+        project_item: &ProjectItem,
+        property_name: &str,
+    ) -> Option<String> {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == property_name)
+            .and_then(|field| field.get_data_value())
+            .map(|data_value| String::from_utf8_lossy(data_value.get_value_bytes()).to_string())
+    }
 }
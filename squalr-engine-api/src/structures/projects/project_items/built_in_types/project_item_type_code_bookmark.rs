@@ -0,0 +1,57 @@
+use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
+use crate::registries::registries::Registries;
+use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+use crate::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use crate::structures::projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+/// A bookmarked code location, ie an instruction added from the disassembler. Unlike address entries, bookmarks carry no value.
+#[derive(Serialize, Deserialize)]
+pub struct ProjectItemTypeCodeBookmark {}
+
+impl ProjectItemType for ProjectItemTypeCodeBookmark {
+    fn get_project_item_type_id(&self) -> &str {
+        Self::PROJECT_ITEM_TYPE_ID
+    }
+
+    fn on_activated_changed(
+        &self,
+        _engine_bindings: &Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
+        _registries: &Registries,
+        _project_item_ref: &ProjectItemRef,
+    ) {
+    }
+
+    fn tick(
+        &self,
+        _engine_bindings: &dyn EngineApiPrivilegedBindings,
+        _opened_process: &Option<OpenedProcessInfo>,
+        _registries: &Registries,
+        _project_item_ref: &ProjectItemRef,
+    ) {
+    }
+}
+
+impl ProjectItemTypeCodeBookmark {
+    pub const PROJECT_ITEM_TYPE_ID: &str = "code_bookmark";
+
+    /// Creates a bookmark for the instruction at the given address. The address is module relative when a module is given.
+    /// The address and module are stored under the same properties as address entries.
+    pub fn new_project_item(
+        name: &str,
+        address: u64,
+        module: &str,
+        description: &str,
+    ) -> ProjectItem {
+        let project_item_type_ref = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let mut project_item = ProjectItem::new(project_item_type_ref, name);
+
+        project_item.set_field_description(description);
+        ProjectItemTypeAddress::set_field_module(&mut project_item, module);
+        ProjectItemTypeAddress::set_field_address(&mut project_item, address);
+
+        project_item
+    }
+}
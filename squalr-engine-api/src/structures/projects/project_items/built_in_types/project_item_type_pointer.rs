@@ -1,18 +1,22 @@
 use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use crate::registries::registries::Registries;
+use crate::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::data_value::DataValue;
+use crate::structures::pointer_scan::pointer_scan_result::PointerScanResult;
 use crate::structures::processes::opened_process_info::OpenedProcessInfo;
+use crate::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
 use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
-use crate::structures::projects::project_items::project_item_type::ProjectItemType;
+use crate::structures::projects::project_items::{project_item::ProjectItem, project_item_type::ProjectItemType, project_item_type_ref::ProjectItemTypeRef};
+use crate::structures::structs::valued_struct_field::ValuedStructFieldData;
 use serde::{Deserialize, Serialize};
 use std::sync::{Arc, RwLock};
 
+/// A pointer path to a value, ie a path found by a pointer scan. Only the path is stored, such that the address it leads to is
+/// resolved again on every read, and survives the target process being restarted.
 #[derive(Serialize, Deserialize)]
 pub struct ProjectItemTypePointer {}
 
-impl ProjectItemTypePointer {
-    pub const PROJECT_ITEM_TYPE_ID: &str = "pointer";
-}
-
 impl ProjectItemType for ProjectItemTypePointer {
     fn get_project_item_type_id(&self) -> &str {
         &Self::PROJECT_ITEM_TYPE_ID
@@ -33,14 +37,110 @@ impl ProjectItemType for ProjectItemTypePointer {
         _opened_process: &Option<OpenedProcessInfo>,
         _registries: &Registries,
         _project_item: &ProjectItemRef,
-    ) {}
+    ) {
+    }
 }
 
 impl ProjectItemTypePointer {
-    // JIRA: Make properties for these.
-    /*
-    module_name: String,
-    module_offset: u64,
-    pointer_offsets: Vec<i32>,
-    */
+    pub const PROJECT_ITEM_TYPE_ID: &str = "pointer";
+    pub const PROPERTY_POINTER_OFFSETS: &str = "pointer_offsets";
+    pub const DEFAULT_NAME: &str = "Pointer";
+
+    /// Creates a pointer entry from its base and offset chain. The base is module relative when a module is given, and is stored
+    /// under the same properties as address entries.
+    pub fn new_project_item(
+        name: &str,
+        address: u64,
+        module: &str,
+        pointer_offsets: &[u64],
+        description: &str,
+        data_type_ref: &DataTypeRef,
+    ) -> ProjectItem {
+        let project_item_type_ref = ProjectItemTypeRef::new(Self::PROJECT_ITEM_TYPE_ID.to_string());
+        let mut project_item = ProjectItem::new(project_item_type_ref, name);
+
+        project_item.set_field_description(description);
+        ProjectItemTypeAddress::set_field_module(&mut project_item, module);
+        ProjectItemTypeAddress::set_field_address(&mut project_item, address);
+        Self::set_field_pointer_offsets(&mut project_item, pointer_offsets);
+        ProjectItemTypeAddress::set_field_symbolic_struct_definition_reference(&mut project_item, data_type_ref.get_data_type_id());
+
+        project_item
+    }
+
+    /// Creates a pointer entry for a pointer scan result, based on its module and offset if it is static.
+    pub fn new_project_item_from_pointer_scan_result(
+        name: &str,
+        pointer_scan_result: &PointerScanResult,
+        data_type_ref: &DataTypeRef,
+    ) -> ProjectItem {
+        let (address, module) = if pointer_scan_result.is_module() {
+            (pointer_scan_result.get_module_offset(), pointer_scan_result.get_module_name())
+        } else {
+            (pointer_scan_result.get_base_address(), "")
+        };
+
+        Self::new_project_item(name, address, module, pointer_scan_result.get_offsets(), "", data_type_ref)
+    }
+
+    pub fn get_field_pointer_offsets(project_item: &ProjectItem) -> Vec<u64> {
+        project_item
+            .get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == Self::PROPERTY_POINTER_OFFSETS)
+            .and_then(|field| field.get_data_value())
+            .map(|data_value| {
+                data_value
+                    .get_value_bytes()
+                    .chunks_exact(size_of::<u64>())
+                    .map(|offset_bytes| u64::from_le_bytes(offset_bytes.try_into().unwrap_or([0u8; 8])))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Sets the offset chain, stored as an array of u64 values.
+    pub fn set_field_pointer_offsets(
+        project_item: &mut ProjectItem,
+        pointer_offsets: &[u64],
+    ) {
+        let offset_bytes = pointer_offsets
+            .iter()
+            .flat_map(|pointer_offset| pointer_offset.to_le_bytes())
+            .collect();
+        let pointer_offsets_data_value = DataValue::new(DataTypeRef::new(DataTypeU64::get_data_type_id()), offset_bytes);
+        let field_data = ValuedStructFieldData::Value(pointer_offsets_data_value);
+
+        project_item
+            .get_properties_mut()
+            .set_field_data(Self::PROPERTY_POINTER_OFFSETS, field_data, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectItemTypePointer;
+    use crate::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+    use crate::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+
+    #[test]
+    fn pointer_entries_keep_the_module_base_and_offset_chain_of_a_scan_result() {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::get_data_type_id());
+        let pointer_scan_result = PointerScanResult::new(0x7FF6_A000_01A0, "game.exe".to_string(), 0x1A0, vec![0x10, 0x8, 0x2C], true);
+        let project_item = ProjectItemTypePointer::new_project_item_from_pointer_scan_result("Health", &pointer_scan_result, &data_type_ref);
+
+        assert_eq!(ProjectItemTypeAddress::get_field_module(&project_item), "game.exe");
+        assert_eq!(ProjectItemTypeAddress::get_field_address(&project_item), 0x1A0);
+        assert_eq!(ProjectItemTypePointer::get_field_pointer_offsets(&project_item), vec![0x10, 0x8, 0x2C]);
+
+        let heap_pointer_scan_result = PointerScanResult::new(0x21BD_0000, String::new(), 0x21BD_0000, vec![], false);
+        let project_item = ProjectItemTypePointer::new_project_item_from_pointer_scan_result("Heap", &heap_pointer_scan_result, &data_type_ref);
+
+        assert_eq!(ProjectItemTypeAddress::get_field_module(&project_item), "");
+        assert_eq!(ProjectItemTypeAddress::get_field_address(&project_item), 0x21BD_0000);
+        assert!(ProjectItemTypePointer::get_field_pointer_offsets(&project_item).is_empty());
+    }
 }
@@ -1,5 +1,6 @@
 pub mod built_in_types;
 pub mod project_item;
+pub mod project_item_hotkey;
 pub mod project_item_ref;
 pub mod project_item_type;
 pub mod project_item_type_ref;
@@ -1,5 +1,7 @@
 use crate::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use crate::registries::registries::Registries;
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use crate::structures::projects::project_items::project_item_hotkey::ProjectItemHotkey;
 use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use crate::structures::{
     data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8,
@@ -18,6 +20,15 @@ pub struct ProjectItem {
     /// The container for all properties on this project item.
     properties: ValuedStruct,
 
+    /// A value indicating whether this item takes part in value refreshing, freezing, triggers, and hotkeys. This is independent of
+    /// activation, such that disabling an item and enabling it again restores whatever it was doing before.
+    #[serde(default = "ProjectItem::default_is_enabled")]
+    is_enabled: bool,
+
+    /// The global hotkeys bound to this item. Chords are unique across the project, which the project enforces when binding them.
+    #[serde(default)]
+    hotkeys: Vec<ProjectItemHotkey>,
+
     /// A value indicating whether this item has been activated / enabled.
     #[serde(skip)]
     is_activated: bool,
@@ -42,6 +53,8 @@ impl ProjectItem {
         let mut project_item = Self {
             item_type,
             properties: ValuedStruct::new_anonymous(vec![]),
+            is_enabled: true,
+            hotkeys: vec![],
             is_activated: false,
             has_unsaved_changes: true,
             current_data_value_interpreter: String::new(),
@@ -75,6 +88,48 @@ impl ProjectItem {
         self.has_unsaved_changes = has_unsaved_changes;
     }
 
+    pub fn get_is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    pub fn set_is_enabled(
+        &mut self,
+        is_enabled: bool,
+    ) {
+        self.is_enabled = is_enabled;
+    }
+
+    pub fn get_hotkeys(&self) -> &Vec<ProjectItemHotkey> {
+        &self.hotkeys
+    }
+
+    /// Binds a hotkey to this item, replacing any hotkey already bound to the same chord.
+    pub fn set_hotkey(
+        &mut self,
+        hotkey: ProjectItemHotkey,
+    ) {
+        match self
+            .hotkeys
+            .iter_mut()
+            .find(|existing_hotkey| existing_hotkey.get_chord() == hotkey.get_chord())
+        {
+            Some(existing_hotkey) => *existing_hotkey = hotkey,
+            None => self.hotkeys.push(hotkey),
+        }
+    }
+
+    /// Unbinds the hotkey with the given chord. Returns whether a hotkey was bound to it.
+    pub fn remove_hotkey(
+        &mut self,
+        chord: &HotkeyChord,
+    ) -> bool {
+        let hotkey_count = self.hotkeys.len();
+
+        self.hotkeys.retain(|hotkey| hotkey.get_chord() != chord);
+
+        self.hotkeys.len() != hotkey_count
+    }
+
     pub fn get_is_activated(&self) -> bool {
         self.is_activated
     }
@@ -99,21 +154,16 @@ impl ProjectItem {
         }
     }
 
+    fn default_is_enabled() -> bool {
+        true
+    }
+
     pub fn get_display_string(&self) -> &str {
         &self.current_data_value_interpreter
     }
 
     pub fn get_field_name(&self) -> String {
-        if let Some(name_field) = self
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_NAME)
-        {
-            name_field.get_display_string(true, 0)
-        } else {
-            String::new()
-        }
+        self.get_field_string(Self::PROPERTY_NAME).unwrap_or_default()
     }
 
     pub fn set_field_name(
@@ -128,16 +178,8 @@ impl ProjectItem {
     }
 
     pub fn get_field_icon_id(&self) -> String {
-        if let Some(icon_id_field) = self
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_ICON_ID)
-        {
-            icon_id_field.get_display_string(true, 0)
-        } else {
-            String::new()
-        }
+        self.get_field_string(Self::PROPERTY_ICON_ID)
+            .unwrap_or_default()
     }
 
     pub fn set_field_icon_id(
@@ -152,16 +194,8 @@ impl ProjectItem {
     }
 
     pub fn get_field_description(&self) -> String {
-        if let Some(icon_id_field) = self
-            .get_properties()
-            .get_fields()
-            .iter()
-            .find(|field| field.get_name() == Self::PROPERTY_DESCRIPTION)
-        {
-            icon_id_field.get_display_string(true, 0)
-        } else {
-            String::new()
-        }
+        self.get_field_string(Self::PROPERTY_DESCRIPTION)
+            .unwrap_or_default()
     }
 
     pub fn set_field_description(
@@ -174,4 +208,17 @@ impl ProjectItem {
         self.get_properties_mut()
             .set_field_data(Self::PROPERTY_DESCRIPTION, field_data, false);
     }
+
+    /// Reads a string property as text. Display strings of fields are their raw bytes, which is not what string properties hold.
+    fn get_field_string(
+        &self,
+        property_name: &str,
+    ) -> Option<String> {
+        self.get_properties()
+            .get_fields()
+            .iter()
+            .find(|field| field.get_name() == property_name)
+            .and_then(|field| field.get_data_value())
+            .map(|data_value| String::from_utf8_lossy(data_value.get_value_bytes()).to_string())
+    }
 }
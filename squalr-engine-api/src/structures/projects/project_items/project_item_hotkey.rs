@@ -0,0 +1,116 @@
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::hotkeys::hotkey_chord::HotkeyChord;
+use crate::structures::projects::write_sequences::write_sequence_step::WriteSequenceStep;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// What pressing the chord of a project item hotkey does to the item.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ProjectItemHotkeyAction {
+    /// Writes a value to the address entry, parsed as the data type of that entry.
+    SetValue { value: AnonymousValueString },
+    /// Freezes the address entry at its current value, or unfreezes it if it is already frozen.
+    ToggleFreeze,
+}
+
+impl ProjectItemHotkeyAction {
+    /// Gets the write sequence step that performs this action on the entry at the given path, relative to the project directory.
+    pub fn to_write_sequence_step(
+        &self,
+        project_item_path: PathBuf,
+    ) -> WriteSequenceStep {
+        match self {
+            ProjectItemHotkeyAction::SetValue { value } => WriteSequenceStep::Write {
+                project_item_path,
+                value: value.clone(),
+            },
+            ProjectItemHotkeyAction::ToggleFreeze => WriteSequenceStep::ToggleFreeze { project_item_path },
+        }
+    }
+
+    /// Gets a short description of this action for display, ie `set to 999`.
+    pub fn get_description(&self) -> String {
+        match self {
+            ProjectItemHotkeyAction::SetValue { value } => format!("set to {}", value.get_anonymous_value_string()),
+            ProjectItemHotkeyAction::ToggleFreeze => "toggle freeze".to_string(),
+        }
+    }
+}
+
+impl FromStr for ProjectItemHotkeyAction {
+    type Err = String;
+
+    /// Parses `freeze`, or `set:<value>`, where the value is an anonymous value string, ie `999;dec;none`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.split_once(':') {
+            None if string.trim().eq_ignore_ascii_case("freeze") => Ok(ProjectItemHotkeyAction::ToggleFreeze),
+            Some((action, value)) if action.trim().eq_ignore_ascii_case("set") => Ok(ProjectItemHotkeyAction::SetValue {
+                value: value.parse::<AnonymousValueString>()?,
+            }),
+            _ => Err("Expected format: freeze, or set:<value>".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ProjectItemHotkeyAction {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ProjectItemHotkeyAction::SetValue { value } => write!(formatter, "set:{}", value),
+            ProjectItemHotkeyAction::ToggleFreeze => write!(formatter, "freeze"),
+        }
+    }
+}
+
+/// A key chord bound to an action on a project item, ie `Ctrl+Numpad1` sets health to 999. Chords are global, such that they are
+/// triggered even while the game has focus.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProjectItemHotkey {
+    chord: HotkeyChord,
+    action: ProjectItemHotkeyAction,
+}
+
+impl ProjectItemHotkey {
+    pub fn new(
+        chord: HotkeyChord,
+        action: ProjectItemHotkeyAction,
+    ) -> Self {
+        Self { chord, action }
+    }
+
+    pub fn get_chord(&self) -> &HotkeyChord {
+        &self.chord
+    }
+
+    pub fn get_action(&self) -> &ProjectItemHotkeyAction {
+        &self.action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectItemHotkeyAction;
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+
+    #[test]
+    fn actions_round_trip_through_strings() {
+        let actions = [
+            ProjectItemHotkeyAction::ToggleFreeze,
+            ProjectItemHotkeyAction::SetValue {
+                value: AnonymousValueString::new("999".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            },
+        ];
+
+        for action in actions {
+            assert_eq!(action.to_string().parse::<ProjectItemHotkeyAction>(), Ok(action));
+        }
+
+        assert!("poke:5;dec;none".parse::<ProjectItemHotkeyAction>().is_err());
+    }
+}
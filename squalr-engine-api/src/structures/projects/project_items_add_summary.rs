@@ -0,0 +1,11 @@
+use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
+
+/// The outcome of adding a batch of items to a project, such that the user can be told what was skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProjectItemsAddSummary {
+    /// The group that the items were added to, if one was created.
+    pub group_ref: Option<ProjectItemRef>,
+    pub added_count: usize,
+    /// The number of items skipped because an entry at the same address already exists.
+    pub skipped_count: usize,
+}
@@ -1,3 +1,11 @@
+use crate::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_request::MemorySetDisabledAddressesRequest;
+use crate::commands::privileged_command_request::PrivilegedCommandRequest;
+use crate::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use crate::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use crate::structures::projects::project_items::project_item::ProjectItem;
+use crate::structures::projects::project_items_add_summary::ProjectItemsAddSummary;
+use crate::structures::projects::struct_dissections::struct_dissection::StructDissection;
+use crate::structures::projects::write_sequences::write_sequence::WriteSequence;
 use crate::structures::projects::{project::Project, project_info::ProjectInfo, project_manifest::ProjectManifest};
 use notify::{
     Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher,
@@ -45,6 +53,176 @@ impl ProjectManager {
         }
     }
 
+    /// Sends the addresses of the disabled entries of the opened project to the engine, such that its freeze and value watch loops
+    /// skip them. Must be called after enabling, disabling, moving, or removing entries, and once a project is opened or closed.
+    pub fn sync_disabled_addresses(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) {
+        let targets = match self.opened_project.read() {
+            Ok(opened_project) => opened_project
+                .as_ref()
+                .map(|project| project.get_disabled_address_targets())
+                .unwrap_or_default(),
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return;
+            }
+        };
+        let memory_set_disabled_addresses_request = MemorySetDisabledAddressesRequest { targets };
+
+        memory_set_disabled_addresses_request.send(engine_unprivileged_state, |memory_set_disabled_addresses_response| {
+            if !memory_set_disabled_addresses_response.success {
+                log::error!("Failed to update the disabled addresses of the engine.");
+            }
+        });
+    }
+
+    /// Adds items to the opened project, each uniquely named after its current name, and notifies listeners of the change.
+    /// Returns an error if there is no opened project.
+    pub fn add_project_items(
+        &self,
+        project_items: Vec<ProjectItem>,
+    ) -> Result<(), String> {
+        {
+            let mut opened_project = self
+                .opened_project
+                .write()
+                .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+            let project = opened_project
+                .as_mut()
+                .ok_or_else(|| "No project is opened.".to_string())?;
+
+            for project_item in project_items {
+                project.add_project_item_with_unique_name(project_item);
+            }
+        }
+
+        self.notify_project_items_changed();
+
+        Ok(())
+    }
+
+    /// Adds items to a new group at the root of the opened project, named after the given group name. Address entries at the same
+    /// address as an existing entry are skipped, and the group is only created if at least one item is added.
+    /// Returns an error if there is no opened project.
+    pub fn add_project_items_to_new_group(
+        &self,
+        group_name: &str,
+        project_items: Vec<ProjectItem>,
+    ) -> Result<ProjectItemsAddSummary, String> {
+        let mut summary = ProjectItemsAddSummary::default();
+
+        {
+            let mut opened_project = self
+                .opened_project
+                .write()
+                .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+            let project = opened_project
+                .as_mut()
+                .ok_or_else(|| "No project is opened.".to_string())?;
+
+            for project_item in project_items {
+                let is_duplicate = project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID
+                    && project.has_address_entry(
+                        ProjectItemTypeAddress::get_field_address(&project_item),
+                        &ProjectItemTypeAddress::get_field_module(&project_item),
+                    );
+
+                if is_duplicate {
+                    summary.skipped_count += 1;
+                    continue;
+                }
+
+                let group_ref = summary
+                    .group_ref
+                    .get_or_insert_with(|| project.add_project_group_with_unique_name(group_name))
+                    .clone();
+
+                project.add_project_item_to_group_with_unique_name(&group_ref, project_item);
+                summary.added_count += 1;
+            }
+        }
+
+        if summary.added_count > 0 {
+            self.notify_project_items_changed();
+        }
+
+        Ok(summary)
+    }
+
+    /// Adds a write sequence to the opened project, replacing any existing sequence of the same name.
+    /// Returns an error if there is no opened project.
+    pub fn set_write_sequence(
+        &self,
+        write_sequence: WriteSequence,
+    ) -> Result<(), String> {
+        let mut opened_project = self
+            .opened_project
+            .write()
+            .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+        let project = opened_project
+            .as_mut()
+            .ok_or_else(|| "No project is opened.".to_string())?;
+
+        project.set_write_sequence(write_sequence);
+
+        Ok(())
+    }
+
+    /// Removes the write sequence with the given name from the opened project, returning whether it existed.
+    /// Returns an error if there is no opened project.
+    pub fn remove_write_sequence(
+        &self,
+        name: &str,
+    ) -> Result<bool, String> {
+        let mut opened_project = self
+            .opened_project
+            .write()
+            .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+        let project = opened_project
+            .as_mut()
+            .ok_or_else(|| "No project is opened.".to_string())?;
+
+        Ok(project.remove_write_sequence(name))
+    }
+
+    /// Adds a struct dissection to the opened project, replacing any existing dissection of the same name.
+    /// Returns an error if there is no opened project.
+    pub fn set_struct_dissection(
+        &self,
+        struct_dissection: StructDissection,
+    ) -> Result<(), String> {
+        let mut opened_project = self
+            .opened_project
+            .write()
+            .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+        let project = opened_project
+            .as_mut()
+            .ok_or_else(|| "No project is opened.".to_string())?;
+
+        project.set_struct_dissection(struct_dissection);
+
+        Ok(())
+    }
+
+    /// Removes the struct dissection with the given name from the opened project, returning whether it existed.
+    /// Returns an error if there is no opened project.
+    pub fn remove_struct_dissection(
+        &self,
+        name: &str,
+    ) -> Result<bool, String> {
+        let mut opened_project = self
+            .opened_project
+            .write()
+            .map_err(|error| format!("Failed to acquire opened project lock: {}", error))?;
+        let project = opened_project
+            .as_mut()
+            .ok_or_else(|| "No project is opened.".to_string())?;
+
+        Ok(project.remove_struct_dissection(name))
+    }
+
     /// Gets a reference to the shared lock containing the currently opened project.
     /// Take caution not to directly set the project if the desire is to capture project events.
     /// To capture these, call `set_opened_project` and `close_opened_project` instead.
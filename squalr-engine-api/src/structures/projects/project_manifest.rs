@@ -1,18 +1,157 @@
+use crate::structures::projects::struct_dissections::struct_dissection::StructDissection;
+use crate::structures::projects::write_sequences::write_sequence::WriteSequence;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ProjectManifest {
     #[serde(rename = "sort_order")]
     project_item_sort_order: Vec<PathBuf>,
+
+    /// The groups that have been disabled, relative to the project directory. Groups are directories, which have no file of their
+    /// own to store this in.
+    #[serde(default, rename = "disabled_groups")]
+    disabled_group_paths: Vec<PathBuf>,
+
+    /// The named write sequences of this project, in the order they were created.
+    #[serde(default)]
+    write_sequences: Vec<WriteSequence>,
+
+    /// The named struct dissections of this project, in the order they were created.
+    #[serde(default)]
+    struct_dissections: Vec<StructDissection>,
 }
 
 impl ProjectManifest {
     pub fn new(project_item_sort_order: Vec<PathBuf>) -> Self {
-        Self { project_item_sort_order }
+        Self {
+            project_item_sort_order,
+            disabled_group_paths: vec![],
+            write_sequences: vec![],
+            struct_dissections: vec![],
+        }
     }
 
     pub fn get_project_item_sort_order(&self) -> &Vec<PathBuf> {
         &self.project_item_sort_order
     }
+
+    pub fn get_disabled_group_paths(&self) -> &Vec<PathBuf> {
+        &self.disabled_group_paths
+    }
+
+    pub fn set_group_enabled(
+        &mut self,
+        group_path: PathBuf,
+        is_enabled: bool,
+    ) {
+        self.disabled_group_paths
+            .retain(|disabled_group_path| *disabled_group_path != group_path);
+
+        if !is_enabled {
+            self.disabled_group_paths.push(group_path);
+        }
+    }
+
+    /// Updates disabled groups at or within the given path to the path it was moved to.
+    pub fn move_group_paths(
+        &mut self,
+        source_path: &Path,
+        destination_path: &Path,
+    ) {
+        for disabled_group_path in &mut self.disabled_group_paths {
+            if let Ok(relative_path) = disabled_group_path.strip_prefix(source_path) {
+                *disabled_group_path = destination_path.join(relative_path);
+            }
+        }
+    }
+
+    /// Forgets disabled groups at or within the given path, such as after the group containing them is removed.
+    pub fn remove_group_paths(
+        &mut self,
+        removed_path: &Path,
+    ) {
+        self.disabled_group_paths
+            .retain(|disabled_group_path| !disabled_group_path.starts_with(removed_path));
+    }
+
+    pub fn get_write_sequences(&self) -> &Vec<WriteSequence> {
+        &self.write_sequences
+    }
+
+    pub fn get_write_sequence(
+        &self,
+        name: &str,
+    ) -> Option<&WriteSequence> {
+        self.write_sequences
+            .iter()
+            .find(|write_sequence| write_sequence.get_name() == name)
+    }
+
+    /// Adds a write sequence, replacing any existing sequence of the same name in place.
+    pub fn set_write_sequence(
+        &mut self,
+        write_sequence: WriteSequence,
+    ) {
+        match self
+            .write_sequences
+            .iter_mut()
+            .find(|existing_write_sequence| existing_write_sequence.get_name() == write_sequence.get_name())
+        {
+            Some(existing_write_sequence) => *existing_write_sequence = write_sequence,
+            None => self.write_sequences.push(write_sequence),
+        }
+    }
+
+    pub fn remove_write_sequence(
+        &mut self,
+        name: &str,
+    ) -> bool {
+        let write_sequence_count = self.write_sequences.len();
+
+        self.write_sequences
+            .retain(|write_sequence| write_sequence.get_name() != name);
+
+        self.write_sequences.len() != write_sequence_count
+    }
+
+    pub fn get_struct_dissections(&self) -> &Vec<StructDissection> {
+        &self.struct_dissections
+    }
+
+    pub fn get_struct_dissection(
+        &self,
+        name: &str,
+    ) -> Option<&StructDissection> {
+        self.struct_dissections
+            .iter()
+            .find(|struct_dissection| struct_dissection.get_name() == name)
+    }
+
+    /// Adds a struct dissection, replacing any existing dissection of the same name in place.
+    pub fn set_struct_dissection(
+        &mut self,
+        struct_dissection: StructDissection,
+    ) {
+        match self
+            .struct_dissections
+            .iter_mut()
+            .find(|existing_struct_dissection| existing_struct_dissection.get_name() == struct_dissection.get_name())
+        {
+            Some(existing_struct_dissection) => *existing_struct_dissection = struct_dissection,
+            None => self.struct_dissections.push(struct_dissection),
+        }
+    }
+
+    pub fn remove_struct_dissection(
+        &mut self,
+        name: &str,
+    ) -> bool {
+        let struct_dissection_count = self.struct_dissections.len();
+
+        self.struct_dissections
+            .retain(|struct_dissection| struct_dissection.get_name() != name);
+
+        self.struct_dissections.len() != struct_dissection_count
+    }
 }
@@ -0,0 +1,268 @@
+use crate::structures::projects::{
+    project::Project,
+    project_items::{
+        built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+        project_item::ProjectItem,
+        project_item_ref::ProjectItemRef,
+    },
+};
+use std::path::Path;
+
+/// The text of a project item that matched a search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectSearchField {
+    Name,
+    Description,
+    Address,
+    DataType,
+    Group,
+}
+
+/// A project entry that matched a search, along with the text that matched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProjectSearchMatch {
+    project_item_ref: ProjectItemRef,
+    matched_fields: Vec<ProjectSearchField>,
+}
+
+impl ProjectSearchMatch {
+    pub fn get_project_item_ref(&self) -> &ProjectItemRef {
+        &self.project_item_ref
+    }
+
+    pub fn get_matched_fields(&self) -> &Vec<ProjectSearchField> {
+        &self.matched_fields
+    }
+}
+
+/// Finds project entries by their name, description, address, data type, or the names of the groups containing them.
+/// Matching is a case-insensitive substring search. Accents are significant, ie `épée` matches `ÉPÉE` but not `epee`.
+pub struct ProjectSearch;
+
+impl ProjectSearch {
+    /// Finds all entries matching the query, ordered by path. Groups themselves are not returned, and an empty query matches nothing.
+    pub fn find_matches(
+        project: &Project,
+        query: &str,
+    ) -> Vec<ProjectSearchMatch> {
+        let normalized_query = Self::normalize(query.trim());
+
+        if normalized_query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut search_matches: Vec<ProjectSearchMatch> = project
+            .get_project_items()
+            .iter()
+            .filter(|(project_item_ref, project_item)| {
+                !Self::is_group(project_item)
+                    && !project
+                        .get_relative_path(project_item_ref.get_project_item_path())
+                        .as_os_str()
+                        .is_empty()
+            })
+            .filter_map(|(project_item_ref, project_item)| {
+                let matched_fields = Self::get_matched_fields(project, project_item_ref, project_item, &normalized_query);
+
+                if matched_fields.is_empty() {
+                    None
+                } else {
+                    Some(ProjectSearchMatch {
+                        project_item_ref: project_item_ref.clone(),
+                        matched_fields,
+                    })
+                }
+            })
+            .collect();
+
+        search_matches.sort_by(|left, right| {
+            left.project_item_ref
+                .get_project_item_path()
+                .cmp(right.project_item_ref.get_project_item_path())
+        });
+
+        search_matches
+    }
+
+    /// Gets which text of a project item contains the query, which must already be normalized.
+    pub fn get_matched_fields(
+        project: &Project,
+        project_item_ref: &ProjectItemRef,
+        project_item: &ProjectItem,
+        normalized_query: &str,
+    ) -> Vec<ProjectSearchField> {
+        let contains_query = |text: &str| Self::normalize(text).contains(normalized_query);
+        let mut matched_fields = Vec::new();
+
+        if contains_query(&project_item.get_field_name()) {
+            matched_fields.push(ProjectSearchField::Name);
+        }
+
+        if contains_query(&project_item.get_field_description()) {
+            matched_fields.push(ProjectSearchField::Description);
+        }
+
+        if project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
+            if Self::get_address_texts(project_item)
+                .iter()
+                .any(|address_text| contains_query(address_text))
+            {
+                matched_fields.push(ProjectSearchField::Address);
+            }
+
+            if ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)
+                .is_some_and(|symbolic_struct_ref| contains_query(symbolic_struct_ref.get_symbolic_struct_namespace()))
+            {
+                matched_fields.push(ProjectSearchField::DataType);
+            }
+        }
+
+        if Self::get_group_names(project, project_item_ref.get_project_item_path())
+            .iter()
+            .any(|group_name| contains_query(group_name))
+        {
+            matched_fields.push(ProjectSearchField::Group);
+        }
+
+        matched_fields
+    }
+
+    /// Normalizes text for comparison. This is Unicode aware, such that non-ASCII letters compare case-insensitively too.
+    pub fn normalize(text: &str) -> String {
+        text.to_lowercase()
+    }
+
+    /// Gets the ways an address is commonly written, ie `game.exe+1A2B`, `game.exe+0x1A2B`, or `0x1A2B` for non-module addresses.
+    fn get_address_texts(project_item: &ProjectItem) -> Vec<String> {
+        let address = ProjectItemTypeAddress::get_field_address(project_item);
+        let module = ProjectItemTypeAddress::get_field_module(project_item);
+
+        if module.is_empty() {
+            vec![format!("0x{:X}", address)]
+        } else {
+            vec![
+                format!("{}+{:X}", module, address),
+                format!("{}+0x{:X}", module, address),
+            ]
+        }
+    }
+
+    /// Gets the names of all groups containing the given path, excluding the project root.
+    fn get_group_names(
+        project: &Project,
+        project_item_path: &Path,
+    ) -> Vec<String> {
+        project_item_path
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor_path| !project.get_relative_path(ancestor_path).as_os_str().is_empty())
+            .filter_map(|ancestor_path| project.get_project_item(&ProjectItemRef::new(ancestor_path.to_path_buf())))
+            .filter(|project_item| Self::is_group(project_item))
+            .map(|project_item| project_item.get_field_name())
+            .collect()
+    }
+
+    fn is_group(project_item: &ProjectItem) -> bool {
+        project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProjectSearch, ProjectSearchField};
+    use crate::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use crate::structures::projects::{
+        project::Project,
+        project_info::ProjectInfo,
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item_ref::ProjectItemRef,
+        },
+        project_manifest::ProjectManifest,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn create_project() -> Project {
+        let project_directory = PathBuf::from("projects").join("test");
+        let player_ref = ProjectItemRef::new(project_directory.join("Player"));
+        let mut project_items = HashMap::new();
+        let mut add_address = |project_item_ref: ProjectItemRef, name: &str, address: u64, module: &str, description: &str| {
+            project_items.insert(
+                project_item_ref,
+                ProjectItemTypeAddress::new_project_item(name, address, module, description, DataTypeU32::get_value_from_primitive(0)),
+            );
+        };
+
+        add_address(
+            ProjectItemRef::new(project_directory.join("Player").join("stamina.json")),
+            "Stamina",
+            0x1A2B,
+            "game.exe",
+            "Drains while sprinting",
+        );
+        add_address(
+            ProjectItemRef::new(project_directory.join("Player").join("sword.json")),
+            "Sword",
+            0x2000,
+            "",
+            "Épée de feu",
+        );
+        add_address(
+            ProjectItemRef::new(project_directory.join("endurance.json")),
+            "Endurance",
+            0x3000,
+            "",
+            "Выносливость героя",
+        );
+        project_items.insert(player_ref.clone(), ProjectItemTypeDirectory::new_project_item(&player_ref));
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, ProjectManifest::new(vec![]));
+
+        Project::new(project_info, project_items, ProjectItemRef::new(project_directory))
+    }
+
+    fn find_names(
+        project: &Project,
+        query: &str,
+    ) -> Vec<String> {
+        ProjectSearch::find_matches(project, query)
+            .iter()
+            .filter_map(|search_match| project.get_project_item(search_match.get_project_item_ref()))
+            .map(|project_item| project_item.get_field_name())
+            .collect()
+    }
+
+    #[test]
+    fn matching_ignores_case() {
+        let project = create_project();
+
+        assert_eq!(find_names(&project, "STAMINA"), vec!["Stamina"]);
+        assert_eq!(find_names(&project, "sprint"), vec!["Stamina"]);
+        assert!(find_names(&project, "  ").is_empty());
+    }
+
+    #[test]
+    fn matching_handles_accented_and_unicode_descriptions() {
+        let project = create_project();
+
+        assert_eq!(find_names(&project, "ÉPÉE"), vec!["Sword"]);
+        assert_eq!(find_names(&project, "épée"), vec!["Sword"]);
+        assert!(find_names(&project, "epee").is_empty());
+        assert_eq!(find_names(&project, "ВЫНОСЛИВОСТЬ"), vec!["Endurance"]);
+    }
+
+    #[test]
+    fn matching_covers_addresses_data_types_and_groups() {
+        let project = create_project();
+        let search_matches = ProjectSearch::find_matches(&project, "game.exe+1a2b");
+
+        assert_eq!(search_matches.len(), 1);
+        assert_eq!(search_matches[0].get_matched_fields(), &vec![ProjectSearchField::Address]);
+        assert_eq!(find_names(&project, "0x2000"), vec!["Sword"]);
+        assert_eq!(find_names(&project, "u32").len(), 3);
+
+        // Entries match by the name of their group, but the group itself is not a result.
+        assert_eq!(find_names(&project, "player"), vec!["Stamina", "Sword"]);
+    }
+}
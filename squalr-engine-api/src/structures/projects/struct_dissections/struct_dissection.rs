@@ -0,0 +1,153 @@
+use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use serde::{Deserialize, Serialize};
+
+/// A pointer field of a dissected struct, followed into the struct that it points to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructDissectionPointer {
+    field_name: String,
+    node: StructDissectionNode,
+}
+
+impl StructDissectionPointer {
+    pub fn new(
+        field_name: &str,
+        node: StructDissectionNode,
+    ) -> Self {
+        Self {
+            field_name: field_name.to_string(),
+            node,
+        }
+    }
+
+    pub fn get_field_name(&self) -> &str {
+        &self.field_name
+    }
+
+    pub fn get_node(&self) -> &StructDissectionNode {
+        &self.node
+    }
+}
+
+/// A struct within a dissection, along with the pointer fields of it that are followed. The definition of the struct is kept with
+/// it, such that a saved dissection can be loaded even where its custom data types were never created.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructDissectionNode {
+    definition: CustomDataTypeDefinition,
+
+    #[serde(default)]
+    followed_pointers: Vec<StructDissectionPointer>,
+}
+
+impl StructDissectionNode {
+    pub fn new(
+        definition: CustomDataTypeDefinition,
+        followed_pointers: Vec<StructDissectionPointer>,
+    ) -> Self {
+        Self { definition, followed_pointers }
+    }
+
+    pub fn get_definition(&self) -> &CustomDataTypeDefinition {
+        &self.definition
+    }
+
+    pub fn get_followed_pointers(&self) -> &Vec<StructDissectionPointer> {
+        &self.followed_pointers
+    }
+}
+
+/// A struct at a base address, and the structs reached by following its pointer fields, ie a player whose `inventory` pointer is
+/// dissected as an inventory. Dissections are saved with the project manifest.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructDissection {
+    name: String,
+
+    /// The address of the root struct, relative to the module if one is set.
+    address: u64,
+
+    #[serde(default)]
+    module: String,
+
+    root: StructDissectionNode,
+}
+
+impl StructDissection {
+    pub fn new(
+        name: String,
+        address: u64,
+        module: String,
+        root: StructDissectionNode,
+    ) -> Self {
+        Self { name, address, module, root }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn get_root(&self) -> &StructDissectionNode {
+        &self.root
+    }
+
+    /// Gets the definition of every struct in the dissection, once per data type, in the order they are first reached.
+    pub fn get_definitions(&self) -> Vec<CustomDataTypeDefinition> {
+        let mut definitions: Vec<CustomDataTypeDefinition> = vec![];
+        let mut nodes = vec![&self.root];
+
+        while let Some(node) = nodes.pop() {
+            if !definitions
+                .iter()
+                .any(|definition| definition.get_data_type_id() == node.definition.get_data_type_id())
+            {
+                definitions.push(node.definition.clone());
+            }
+
+            // Children are pushed in reverse, such that they are visited in field order.
+            nodes.extend(
+                node.followed_pointers
+                    .iter()
+                    .rev()
+                    .map(|followed_pointer| &followed_pointer.node),
+            );
+        }
+
+        definitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StructDissection, StructDissectionNode, StructDissectionPointer};
+    use crate::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+
+    #[test]
+    fn definitions_are_collected_once_per_data_type() {
+        let player = "Dissection player = u32 hp, u64 inventory, u64 next"
+            .parse::<CustomDataTypeDefinition>()
+            .expect("Definition should parse.");
+        let inventory = "Dissection inventory = u32 gold, u32 slots"
+            .parse::<CustomDataTypeDefinition>()
+            .expect("Definition should parse.");
+        let struct_dissection = StructDissection::new(
+            "Player".to_string(),
+            0x1A0,
+            "game.exe".to_string(),
+            StructDissectionNode::new(
+                player.clone(),
+                vec![
+                    StructDissectionPointer::new("inventory", StructDissectionNode::new(inventory.clone(), vec![])),
+                    StructDissectionPointer::new("next", StructDissectionNode::new(player.clone(), vec![])),
+                ],
+            ),
+        );
+
+        assert_eq!(struct_dissection.get_definitions(), vec![player, inventory]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod write_sequence;
+pub mod write_sequence_step;
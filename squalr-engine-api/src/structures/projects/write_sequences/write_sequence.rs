@@ -0,0 +1,278 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::memory_write_sequence_step::MemoryWriteSequenceStep;
+use crate::structures::projects::project::Project;
+use crate::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use crate::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use crate::structures::projects::write_sequences::write_sequence_step::WriteSequenceStep;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// A named, ordered list of timed writes and freeze toggles, ie write A, wait 200 ms, write B. Sequences are saved with the project
+/// manifest, and are run by the engine as a trackable task.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WriteSequence {
+    name: String,
+
+    steps: Vec<WriteSequenceStep>,
+
+    /// Whether the remaining steps still run after a failed write, rather than stopping the sequence.
+    #[serde(default)]
+    continue_on_error: bool,
+
+    /// The name of the key that runs this sequence, ie `F5`.
+    #[serde(default)]
+    hotkey: Option<String>,
+}
+
+impl WriteSequence {
+    pub fn new(
+        name: String,
+        steps: Vec<WriteSequenceStep>,
+        continue_on_error: bool,
+        hotkey: Option<String>,
+    ) -> Self {
+        Self {
+            name,
+            steps,
+            continue_on_error,
+            hotkey,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_steps(&self) -> &Vec<WriteSequenceStep> {
+        &self.steps
+    }
+
+    pub fn get_continue_on_error(&self) -> bool {
+        self.continue_on_error
+    }
+
+    pub fn get_hotkey(&self) -> Option<&str> {
+        self.hotkey.as_deref()
+    }
+
+    /// Resolves every step against the entries of the project, such that the engine can run the sequence without the project.
+    /// Fails with every step that could not be resolved, rather than stopping at the first.
+    pub fn resolve(
+        &self,
+        project: &Project,
+    ) -> Result<Vec<MemoryWriteSequenceStep>, Vec<WriteSequenceResolveError>> {
+        let mut resolved_steps = vec![];
+        let mut resolve_errors = vec![];
+
+        for (step_index, step) in self.steps.iter().enumerate() {
+            match Self::resolve_step(project, step) {
+                Ok(resolved_step) => resolved_steps.push(resolved_step),
+                Err(reason) => resolve_errors.push(WriteSequenceResolveError { step_index, reason }),
+            }
+        }
+
+        if resolve_errors.is_empty() { Ok(resolved_steps) } else { Err(resolve_errors) }
+    }
+
+    fn resolve_step(
+        project: &Project,
+        step: &WriteSequenceStep,
+    ) -> Result<MemoryWriteSequenceStep, String> {
+        match step {
+            WriteSequenceStep::Delay { duration_ms } => Ok(MemoryWriteSequenceStep::Delay { duration_ms: *duration_ms }),
+            WriteSequenceStep::Write { project_item_path, value } => {
+                let (address, module_name, data_type_ref) = Self::resolve_address_entry(project, project_item_path)?;
+                let data_value = SymbolRegistry::get_instance()
+                    .deanonymize_value_string(&data_type_ref, value)
+                    .map_err(|error| format!("'{}' is not a valid {}: {}", value.get_anonymous_value_string(), data_type_ref, error))?;
+
+                Ok(MemoryWriteSequenceStep::Write {
+                    address,
+                    module_name,
+                    value_bytes: data_value.get_value_bytes().clone(),
+                })
+            }
+            WriteSequenceStep::ToggleFreeze { project_item_path } => {
+                let (address, module_name, data_type_ref) = Self::resolve_address_entry(project, project_item_path)?;
+
+                Ok(MemoryWriteSequenceStep::ToggleFreeze {
+                    address,
+                    module_name,
+                    data_type_ref,
+                })
+            }
+        }
+    }
+
+    fn resolve_address_entry(
+        project: &Project,
+        project_item_path: &Path,
+    ) -> Result<(u64, String, DataTypeRef), String> {
+        let project_item_ref = ProjectItemRef::new(project.get_absolute_path(project_item_path));
+        let project_item = project
+            .get_project_item(&project_item_ref)
+            .ok_or_else(|| format!("The entry '{}' no longer exists.", project_item_path.display()))?;
+
+        if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
+            return Err(format!("The entry '{}' is not an address.", project_item_path.display()));
+        }
+
+        if !project.is_project_item_enabled(&project_item_ref) {
+            return Err(format!("The entry '{}' is disabled.", project_item_path.display()));
+        }
+
+        let data_type_ref = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)
+            .map(|symbolic_struct_ref| DataTypeRef::new(symbolic_struct_ref.get_symbolic_struct_namespace()))
+            .ok_or_else(|| format!("The entry '{}' has no data type.", project_item_path.display()))?;
+
+        Ok((
+            ProjectItemTypeAddress::get_field_address(project_item),
+            ProjectItemTypeAddress::get_field_module(project_item),
+            data_type_ref,
+        ))
+    }
+}
+
+/// Describes a step of a write sequence that could not be resolved against the project.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WriteSequenceResolveError {
+    pub step_index: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for WriteSequenceResolveError {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(formatter, "Step {}: {}", self.step_index + 1, self.reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WriteSequence;
+    use crate::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use crate::structures::data_values::container_type::ContainerType;
+    use crate::structures::memory::memory_write_sequence_step::MemoryWriteSequenceStep;
+    use crate::structures::projects::{
+        project::Project,
+        project_info::ProjectInfo,
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item_ref::ProjectItemRef,
+        },
+        project_manifest::ProjectManifest,
+        write_sequences::write_sequence_step::WriteSequenceStep,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn create_project() -> Project {
+        let project_directory = PathBuf::from("projects").join("test");
+        let group_ref = ProjectItemRef::new(project_directory.join("group"));
+        let health_ref = ProjectItemRef::new(project_directory.join("group").join("health.json"));
+        let mut project_items = HashMap::new();
+
+        project_items.insert(group_ref.clone(), ProjectItemTypeDirectory::new_project_item(&group_ref));
+        project_items.insert(
+            health_ref,
+            ProjectItemTypeAddress::new_project_item("health", 0x1000, "game.exe", "", DataTypeU32::get_value_from_primitive(100)),
+        );
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, ProjectManifest::new(vec![]));
+
+        Project::new(project_info, project_items, ProjectItemRef::new(project_directory))
+    }
+
+    fn create_write_step(
+        project_item_path: &str,
+        value: &str,
+    ) -> WriteSequenceStep {
+        WriteSequenceStep::Write {
+            project_item_path: PathBuf::from(project_item_path),
+            value: AnonymousValueString::new(value.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+        }
+    }
+
+    #[test]
+    fn steps_resolve_to_the_address_and_data_type_of_their_entries() {
+        let project = create_project();
+        let health_path = PathBuf::from("group").join("health.json");
+        let write_sequence = WriteSequence::new(
+            "Heal".to_string(),
+            vec![
+                create_write_step(&health_path.to_string_lossy(), "250"),
+                WriteSequenceStep::Delay { duration_ms: 200 },
+                WriteSequenceStep::ToggleFreeze {
+                    project_item_path: health_path.clone(),
+                },
+            ],
+            false,
+            None,
+        );
+
+        assert_eq!(
+            write_sequence.resolve(&project),
+            Ok(vec![
+                MemoryWriteSequenceStep::Write {
+                    address: 0x1000,
+                    module_name: "game.exe".to_string(),
+                    value_bytes: 250u32.to_le_bytes().to_vec(),
+                },
+                MemoryWriteSequenceStep::Delay { duration_ms: 200 },
+                MemoryWriteSequenceStep::ToggleFreeze {
+                    address: 0x1000,
+                    module_name: "game.exe".to_string(),
+                    data_type_ref: DataTypeRef::new(DataTypeU32::get_data_type_id()),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn every_unresolvable_step_is_reported() {
+        let mut project = create_project();
+        let health_path = PathBuf::from("group").join("health.json");
+        let write_sequence = WriteSequence::new(
+            "Broken".to_string(),
+            vec![
+                create_write_step("missing.json", "1"),
+                WriteSequenceStep::Delay { duration_ms: 10 },
+                create_write_step(&health_path.to_string_lossy(), "not a number"),
+                WriteSequenceStep::ToggleFreeze {
+                    project_item_path: PathBuf::from("group"),
+                },
+            ],
+            true,
+            None,
+        );
+        let resolve_errors = write_sequence.resolve(&project).unwrap_err();
+
+        assert_eq!(
+            resolve_errors
+                .iter()
+                .map(|resolve_error| resolve_error.step_index)
+                .collect::<Vec<_>>(),
+            vec![0, 2, 3]
+        );
+
+        // Entries in a disabled group are not acted upon, just as they are not frozen.
+        let group_ref = ProjectItemRef::new(project.get_absolute_path(&PathBuf::from("group")));
+        project.set_project_item_enabled(&group_ref, false);
+
+        let disabled_sequence = WriteSequence::new(
+            "Disabled".to_string(),
+            vec![create_write_step(&health_path.to_string_lossy(), "1")],
+            false,
+            None,
+        );
+
+        assert!(disabled_sequence.resolve(&project).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// A single step of a write sequence. Entries are referenced by their path relative to the project directory, such that sequences
+/// keep working when the project is moved or renamed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WriteSequenceStep {
+    /// Writes a value to an address entry, parsed as the data type of that entry.
+    Write { project_item_path: PathBuf, value: AnonymousValueString },
+    /// Waits before running the next step.
+    Delay { duration_ms: u64 },
+    /// Freezes an address entry at its current value, or unfreezes it if it is already frozen.
+    ToggleFreeze { project_item_path: PathBuf },
+}
+
+impl WriteSequenceStep {
+    pub fn get_project_item_path(&self) -> Option<&PathBuf> {
+        match self {
+            WriteSequenceStep::Write { project_item_path, .. } => Some(project_item_path),
+            WriteSequenceStep::Delay { .. } => None,
+            WriteSequenceStep::ToggleFreeze { project_item_path } => Some(project_item_path),
+        }
+    }
+}
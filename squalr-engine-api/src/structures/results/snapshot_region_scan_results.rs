@@ -17,6 +17,7 @@ use std::{cmp::Reverse, collections::BinaryHeap};
 ///
 /// For example, scanning for 0 across multiple data types could produce 1, 2, 4, and 8 byte integer matches on the same address.
 /// The solution is TBD
+#[derive(Clone)]
 pub struct SnapshotRegionScanResults {
     /// The collection of filters produced by a scan for a specific snapshot region.
     snapshot_region_filter_collections: Vec<SnapshotRegionFilterCollection>,
@@ -126,9 +127,17 @@ impl SnapshotRegionScanResults {
                             .ok()
                     })
                     .unwrap_or_default();
+                let first_scan_value = snapshot_region.get_first_scan_value(scan_result_address, data_type_ref);
+                let first_scan_display_values = first_scan_value
+                    .as_ref()
+                    .and_then(|data_value| {
+                        symbol_registry
+                            .anonymize_value_to_supported_formats(data_value)
+                            .ok()
+                    })
+                    .unwrap_or_default();
                 let icon_id = symbol_registry.get_icon_id(data_type_ref);
-
-                return Some(ScanResultValued::new(
+                let mut scan_result_valued = ScanResultValued::new(
                     scan_result_address,
                     data_type_ref.clone(),
                     icon_id,
@@ -137,7 +146,11 @@ impl SnapshotRegionScanResults {
                     previous_value,
                     previous_display_values,
                     ScanResultRef::new(global_scan_result_index),
-                ));
+                );
+
+                scan_result_valued.set_first_scan_value(first_scan_value, first_scan_display_values);
+
+                return Some(scan_result_valued);
             }
 
             // Decrease the index as we've skipped this entire filter's elements.
@@ -162,6 +175,16 @@ impl SnapshotRegionScanResults {
             .sum()
     }
 
+    /// Moves every filter of this snapshot region by the given wrapping delta.
+    pub fn relocate(
+        &mut self,
+        address_delta: u64,
+    ) {
+        for snapshot_region_filter_collection in &mut self.snapshot_region_filter_collections {
+            snapshot_region_filter_collection.relocate(address_delta);
+        }
+    }
+
     /// Gets the collections of snapshot filters contained by this snapshot region. Generally one collection per data type scanned.
     pub fn get_filter_collections(&self) -> &Vec<SnapshotRegionFilterCollection> {
         &self.snapshot_region_filter_collections
@@ -1,4 +1,5 @@
 pub mod scan_result;
 pub mod scan_result_base;
+pub mod scan_result_read_status;
 pub mod scan_result_ref;
 pub mod scan_result_valued;
@@ -4,6 +4,7 @@ use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use crate::structures::data_values::data_value::DataValue;
 use crate::structures::scan_results::scan_result_base::ScanResultBase;
+use crate::structures::scan_results::scan_result_read_status::ScanResultReadStatus;
 use crate::structures::scan_results::scan_result_valued::ScanResultValued;
 use crate::structures::structs::valued_struct::ValuedStruct;
 use crate::structures::{data_types::built_in_types::bool8::data_type_bool8::DataTypeBool8, data_values::anonymous_value_string::AnonymousValueString};
@@ -18,6 +19,8 @@ pub struct ScanResult {
     recently_read_value: Option<DataValue>,
     recently_read_display_values: Vec<AnonymousValueString>,
     is_frozen: bool,
+    #[serde(default)]
+    read_status: ScanResultReadStatus,
 }
 
 impl ScanResult {
@@ -42,6 +45,7 @@ impl ScanResult {
             recently_read_value,
             recently_read_display_values,
             is_frozen,
+            read_status: ScanResultReadStatus::Ok,
         }
     }
 
@@ -95,6 +99,12 @@ impl ScanResult {
         !self.module.is_empty()
     }
 
+    /// Gets whether the memory under this result changed since it was snapshotted, ie the process restarted and the address is not
+    /// within a module, such that its value may no longer be meaningful.
+    pub fn is_stale(&self) -> bool {
+        self.get_base_result().is_stale()
+    }
+
     pub fn get_module(&self) -> &str {
         &self.module
     }
@@ -156,6 +166,47 @@ impl ScanResult {
             .get_previous_display_value(anonymous_value_string_format)
     }
 
+    /// Gets the value this result held when the snapshot was first scanned, if it was retained.
+    pub fn get_first_scan_value(&self) -> &Option<DataValue> {
+        self.valued_result.get_first_scan_value()
+    }
+
+    pub fn get_first_scan_display_value(
+        &self,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Option<&AnonymousValueString> {
+        self.valued_result
+            .get_first_scan_display_value(anonymous_value_string_format)
+    }
+
+    /// Gets whether the value could be read when this result was last refreshed. Unreadable results have no recently read value.
+    pub fn get_read_status(&self) -> &ScanResultReadStatus {
+        &self.read_status
+    }
+
+    pub fn set_read_status(
+        &mut self,
+        read_status: ScanResultReadStatus,
+    ) {
+        self.read_status = read_status;
+    }
+
+    /// Gets the value shown for this result, preferring the recently read value over the snapshotted one. Results that could not
+    /// be read show a marker instead, rather than a stale value that looks unchanged.
+    pub fn get_display_value_string(
+        &self,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> &str {
+        if !self.read_status.is_readable() {
+            return ScanResultReadStatus::UNREADABLE_MARKER;
+        }
+
+        self.get_recently_read_display_value(anonymous_value_string_format)
+            .or_else(|| self.get_current_display_value(anonymous_value_string_format))
+            .map(|value| value.get_anonymous_value_string())
+            .unwrap_or("??")
+    }
+
     pub fn get_is_frozen(&self) -> bool {
         self.is_frozen
     }
@@ -166,6 +217,16 @@ impl ScanResult {
     ) {
         self.is_frozen = is_frozen;
     }
+
+    /// Replaces the recently read value without writing it, ie to show a pending write before the next read confirms it.
+    pub fn set_recently_read_value_client_only(
+        &mut self,
+        recently_read_value: Option<DataValue>,
+        recently_read_display_values: Vec<AnonymousValueString>,
+    ) {
+        self.recently_read_value = recently_read_value;
+        self.recently_read_display_values = recently_read_display_values;
+    }
 }
 
 impl fmt::Debug for ScanResult {
@@ -1,3 +1,4 @@
+use crate::structures::snapshots::snapshot_region_change::SnapshotRegionChangeKind;
 use crate::structures::{data_types::data_type_ref::DataTypeRef, scan_results::scan_result_ref::ScanResultRef};
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +10,19 @@ pub struct ScanResultBase {
     data_type_ref: DataTypeRef,
     icon_id: String,
     scan_result_ref: ScanResultRef,
+
+    /// The data type this result was scanned as, if it has since been reinterpreted as another type for display and editing.
+    #[serde(default)]
+    original_data_type_ref: Option<DataTypeRef>,
+
+    /// How the memory backing this result changed since it was snapshotted, if it has. Stale results are not frozen or written
+    /// to unless explicitly allowed, as their address may no longer hold the value that was scanned for.
+    #[serde(default)]
+    stale_change_kind: Option<SnapshotRegionChangeKind>,
+
+    /// How many times this result's value repeats at a fixed stride from its address, if it was found by a stride scan.
+    #[serde(default)]
+    stride_repeat_count: Option<u64>,
 }
 
 impl ScanResultBase {
@@ -23,6 +37,9 @@ impl ScanResultBase {
             data_type_ref,
             icon_id,
             scan_result_ref,
+            original_data_type_ref: None,
+            stale_change_kind: None,
+            stride_repeat_count: None,
         }
     }
 
@@ -41,4 +58,41 @@ impl ScanResultBase {
     pub fn get_scan_result_ref(&self) -> &ScanResultRef {
         &self.scan_result_ref
     }
+
+    pub fn get_original_data_type_ref(&self) -> Option<&DataTypeRef> {
+        self.original_data_type_ref.as_ref()
+    }
+
+    pub fn set_original_data_type_ref(
+        &mut self,
+        original_data_type_ref: Option<DataTypeRef>,
+    ) {
+        self.original_data_type_ref = original_data_type_ref;
+    }
+
+    pub fn get_stale_change_kind(&self) -> Option<SnapshotRegionChangeKind> {
+        self.stale_change_kind
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.stale_change_kind.is_some()
+    }
+
+    pub fn set_stale_change_kind(
+        &mut self,
+        stale_change_kind: Option<SnapshotRegionChangeKind>,
+    ) {
+        self.stale_change_kind = stale_change_kind;
+    }
+
+    pub fn get_stride_repeat_count(&self) -> Option<u64> {
+        self.stride_repeat_count
+    }
+
+    pub fn set_stride_repeat_count(
+        &mut self,
+        stride_repeat_count: Option<u64>,
+    ) {
+        self.stride_repeat_count = stride_repeat_count;
+    }
 }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Describes whether the value of a scan result could be read when it was last refreshed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanResultReadStatus {
+    /// The full value was read.
+    #[default]
+    Ok,
+
+    /// None of the value could be read, ie the memory was freed or protected.
+    Unreadable { error: String },
+
+    /// Only the leading bytes of the value could be read, ie the value straddles the end of a region.
+    PartiallyRead { read_byte_count: u64, error: String },
+}
+
+impl ScanResultReadStatus {
+    /// The text shown and copied in place of a value that could not be read.
+    pub const UNREADABLE_MARKER: &'static str = "<unreadable>";
+
+    /// Creates the status of a failed read, given how many leading bytes of the value could still be read.
+    pub fn from_failed_read(
+        read_byte_count: u64,
+        error: String,
+    ) -> Self {
+        if read_byte_count == 0 {
+            ScanResultReadStatus::Unreadable { error }
+        } else {
+            ScanResultReadStatus::PartiallyRead { read_byte_count, error }
+        }
+    }
+
+    pub fn is_readable(&self) -> bool {
+        *self == ScanResultReadStatus::Ok
+    }
+
+    /// Gets a short, user facing explanation of why the value could not be read, if it could not be.
+    pub fn get_description(
+        &self,
+        value_size_in_bytes: u64,
+    ) -> Option<String> {
+        match self {
+            ScanResultReadStatus::Ok => None,
+            ScanResultReadStatus::Unreadable { error } => Some(format!("The value could not be read: {}", error)),
+            ScanResultReadStatus::PartiallyRead { read_byte_count, error } => Some(format!(
+                "Only {} of {} bytes of the value could be read: {}",
+                read_byte_count, value_size_in_bytes, error
+            )),
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::structures::data_values::anonymous_value_string::AnonymousValueString
 use crate::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use crate::structures::scan_results::scan_result_base::ScanResultBase;
 use crate::structures::scan_results::scan_result_ref::ScanResultRef;
+use crate::structures::snapshots::snapshot_region_change::SnapshotRegionChangeKind;
 use crate::structures::{data_types::data_type_ref::DataTypeRef, data_values::data_value::DataValue};
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,10 @@ pub struct ScanResultValued {
     current_display_values: Vec<AnonymousValueString>,
     previous_value: Option<DataValue>,
     previous_display_values: Vec<AnonymousValueString>,
+    #[serde(default)]
+    first_scan_value: Option<DataValue>,
+    #[serde(default)]
+    first_scan_display_values: Vec<AnonymousValueString>,
 }
 
 impl ScanResultValued {
@@ -33,6 +38,8 @@ impl ScanResultValued {
             current_display_values,
             previous_value,
             previous_display_values,
+            first_scan_value: None,
+            first_scan_display_values: vec![],
         }
     }
 
@@ -40,6 +47,42 @@ impl ScanResultValued {
         &self.scan_result_base
     }
 
+    /// Records the data type this result was scanned as, after it has been reinterpreted as the data type it now holds.
+    pub fn set_original_data_type_ref(
+        &mut self,
+        original_data_type_ref: Option<DataTypeRef>,
+    ) {
+        self.scan_result_base
+            .set_original_data_type_ref(original_data_type_ref);
+    }
+
+    /// Attaches the value this result held when the snapshot was first scanned, if it was retained.
+    pub fn set_first_scan_value(
+        &mut self,
+        first_scan_value: Option<DataValue>,
+        first_scan_display_values: Vec<AnonymousValueString>,
+    ) {
+        self.first_scan_value = first_scan_value;
+        self.first_scan_display_values = first_scan_display_values;
+    }
+
+    /// Marks this result as backed by memory that changed since it was snapshotted.
+    pub fn set_stale_change_kind(
+        &mut self,
+        stale_change_kind: Option<SnapshotRegionChangeKind>,
+    ) {
+        self.scan_result_base.set_stale_change_kind(stale_change_kind);
+    }
+
+    /// Attaches how many times this result's value repeats at the stride of the stride scan that found it.
+    pub fn set_stride_repeat_count(
+        &mut self,
+        stride_repeat_count: Option<u64>,
+    ) {
+        self.scan_result_base
+            .set_stride_repeat_count(stride_repeat_count);
+    }
+
     pub fn get_address(&self) -> u64 {
         self.scan_result_base.get_address()
     }
@@ -93,4 +136,21 @@ impl ScanResultValued {
 
         None
     }
+
+    pub fn get_first_scan_value(&self) -> &Option<DataValue> {
+        &self.first_scan_value
+    }
+
+    pub fn get_first_scan_display_values(&self) -> &Vec<AnonymousValueString> {
+        &self.first_scan_display_values
+    }
+
+    pub fn get_first_scan_display_value(
+        &self,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) -> Option<&AnonymousValueString> {
+        self.first_scan_display_values
+            .iter()
+            .find(|first_scan_display_value| first_scan_display_value.get_anonymous_value_string_format() == anonymous_value_string_format)
+    }
 }
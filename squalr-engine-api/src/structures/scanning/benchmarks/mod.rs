@@ -0,0 +1 @@
+pub mod scan_configuration_benchmark;
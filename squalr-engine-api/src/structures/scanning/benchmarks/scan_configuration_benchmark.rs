@@ -0,0 +1,10 @@
+use crate::structures::settings::scan_vector_width::ScanVectorWidth;
+use serde::{Deserialize, Serialize};
+
+/// The speed of a scan with a given thread count and vector width, as measured against a synthetic snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScanConfigurationBenchmark {
+    pub scan_thread_count: u32,
+    pub vector_width: ScanVectorWidth,
+    pub elements_per_second: f64,
+}
@@ -23,10 +23,12 @@ impl FromStr for ScanCompareType {
             ">=" => Ok(ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThanOrEqual)),
             "<" => Ok(ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan)),
             "<=" => Ok(ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual)),
+            ".." => Ok(ScanCompareType::Immediate(ScanCompareTypeImmediate::Between)),
             "c" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::Changed)),
             "u" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged)),
             "+" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::Increased)),
             "-" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::Decreased)),
+            "f" => Ok(ScanCompareType::Relative(ScanCompareTypeRelative::DiffersFromFirstScan)),
             "+x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::IncreasedByX)),
             "-x" => Ok(ScanCompareType::Delta(ScanCompareTypeDelta::DecreasedByX)),
             _ => Err(ParseScanCompareTypeError),
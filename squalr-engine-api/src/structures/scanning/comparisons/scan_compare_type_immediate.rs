@@ -8,4 +8,6 @@ pub enum ScanCompareTypeImmediate {
     GreaterThanOrEqual,
     LessThan,
     LessThanOrEqual,
+    /// Inclusively between the data value and an upper bound value.
+    Between,
 }
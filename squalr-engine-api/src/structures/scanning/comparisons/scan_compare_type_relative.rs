@@ -6,4 +6,6 @@ pub enum ScanCompareTypeRelative {
     Unchanged,
     Increased,
     Decreased,
+    /// Changed since the first scan, rather than since the previous scan.
+    DiffersFromFirstScan,
 }
@@ -1,7 +1,10 @@
 use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use crate::structures::data_values::anonymous_value_list::AnonymousValueList;
 use crate::structures::data_values::anonymous_value_string::AnonymousValueString;
+use crate::structures::data_values::data_value::DataValue;
 use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
 use crate::structures::scanning::comparisons::scan_compare_type_delta::ScanCompareTypeDelta;
 use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
@@ -16,6 +19,12 @@ use std::str::FromStr;
 pub struct AnonymousScanConstraint {
     scan_compare_type: ScanCompareType,
     anonymous_value_string: Option<AnonymousValueString>,
+    /// The inclusive upper bound of a between scan, where the anonymous value string is the inclusive lower bound.
+    #[serde(default)]
+    anonymous_value_string_upper_bound: Option<AnonymousValueString>,
+    /// The tolerance used to compare floating point values, in place of the tolerance from the scan settings.
+    #[serde(default)]
+    floating_point_tolerance: Option<FloatingPointTolerance>,
 }
 
 impl AnonymousScanConstraint {
@@ -26,6 +35,21 @@ impl AnonymousScanConstraint {
         Self {
             scan_compare_type,
             anonymous_value_string,
+            anonymous_value_string_upper_bound: None,
+            floating_point_tolerance: None,
+        }
+    }
+
+    /// Creates a constraint that matches values inclusively between a lower and an upper bound.
+    pub fn new_between(
+        anonymous_value_string_lower_bound: AnonymousValueString,
+        anonymous_value_string_upper_bound: AnonymousValueString,
+    ) -> Self {
+        Self {
+            scan_compare_type: ScanCompareType::Immediate(ScanCompareTypeImmediate::Between),
+            anonymous_value_string: Some(anonymous_value_string_lower_bound),
+            anonymous_value_string_upper_bound: Some(anonymous_value_string_upper_bound),
+            floating_point_tolerance: None,
         }
     }
 
@@ -37,42 +61,169 @@ impl AnonymousScanConstraint {
         &self.anonymous_value_string
     }
 
+    pub fn get_anonymous_value_string_upper_bound(&self) -> &Option<AnonymousValueString> {
+        &self.anonymous_value_string_upper_bound
+    }
+
+    pub fn get_floating_point_tolerance(&self) -> Option<FloatingPointTolerance> {
+        self.floating_point_tolerance
+    }
+
+    /// Sets the tolerance used to compare floating point values, overriding the tolerance passed in when deanonymizing.
+    pub fn set_floating_point_tolerance(
+        &mut self,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) {
+        self.floating_point_tolerance = Some(floating_point_tolerance);
+    }
+
     pub fn deanonymize_constraint(
         &self,
         data_type_ref: &DataTypeRef,
         floating_point_tolerance: FloatingPointTolerance,
     ) -> Option<ScanConstraint> {
         let symbol_registry = SymbolRegistry::get_instance();
+        let floating_point_tolerance = self
+            .floating_point_tolerance
+            .unwrap_or(floating_point_tolerance);
+
+        if self.scan_compare_type == ScanCompareType::Immediate(ScanCompareTypeImmediate::Between) {
+            return self.deanonymize_between_constraint(data_type_ref, floating_point_tolerance);
+        }
 
         if let Some(anonymous_value_string) = &self.anonymous_value_string {
-            if !anonymous_value_string.get_anonymous_value_string().trim().is_empty() {
-                match symbol_registry.deanonymize_value_string(&data_type_ref, &anonymous_value_string) {
-                    Ok(data_value) => return Some(ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance)),
-                    Err(error) => {
-                        let default_format = symbol_registry.get_default_anonymous_value_string_format(data_type_ref);
-                        let mut fallback_value = anonymous_value_string.clone();
-
-                        if fallback_value.get_anonymous_value_string_format() != default_format {
-                            fallback_value.set_anonymous_value_string_format(default_format);
-
-                            if let Ok(data_value) = symbol_registry.deanonymize_value_string(&data_type_ref, &fallback_value) {
-                                return Some(ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance));
-                            }
-                        }
+            if data_type_ref.get_data_type_id() == DataTypeAob::DATA_TYPE_ID {
+                return self.deanonymize_byte_pattern_constraint(data_type_ref, anonymous_value_string, floating_point_tolerance);
+            }
+
+            if AnonymousValueList::is_value_list(anonymous_value_string) && AnonymousValueList::supports_value_lists(data_type_ref) {
+                return self.deanonymize_value_list_constraint(data_type_ref, anonymous_value_string, floating_point_tolerance);
+            }
+
+            if let Some((data_value, field_wildcards)) = Self::deanonymize_value(data_type_ref, anonymous_value_string) {
+                let mut scan_constraint = ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance);
+
+                scan_constraint.set_field_wildcards(field_wildcards);
+
+                return Some(scan_constraint);
+            }
+        }
+
+        if matches!(self.scan_compare_type, ScanCompareType::Relative(_))
+            && let Some(data_value) = symbol_registry.get_default_value(data_type_ref)
+        {
+            return Some(ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance));
+        }
+
+        None
+    }
+
+    /// Parses a single value, retrying in the default format of the data type if the given format fails. Returns the value along with
+    /// the fields that were left as wildcards, or None if the value is blank or cannot be parsed.
+    fn deanonymize_value(
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Option<(DataValue, Vec<bool>)> {
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        if anonymous_value_string
+            .get_anonymous_value_string()
+            .trim()
+            .is_empty()
+        {
+            return None;
+        }
 
-                        log::error!("Unable to parse value in anonymous constraint: {}", error);
+        match symbol_registry.deanonymize_value_string(data_type_ref, anonymous_value_string) {
+            Ok(data_value) => Some((data_value, symbol_registry.get_field_wildcards(data_type_ref, anonymous_value_string))),
+            Err(error) => {
+                let default_format = symbol_registry.get_default_anonymous_value_string_format(data_type_ref);
+                let mut fallback_value = anonymous_value_string.clone();
+
+                if fallback_value.get_anonymous_value_string_format() != default_format {
+                    fallback_value.set_anonymous_value_string_format(default_format);
+
+                    if let Ok(data_value) = symbol_registry.deanonymize_value_string(data_type_ref, &fallback_value) {
+                        return Some((data_value, symbol_registry.get_field_wildcards(data_type_ref, &fallback_value)));
                     }
                 }
+
+                log::error!("Unable to parse value in anonymous constraint: {}", error);
+
+                None
             }
         }
+    }
 
-        if matches!(self.scan_compare_type, ScanCompareType::Relative(_)) {
-            if let Some(data_value) = symbol_registry.get_default_value(data_type_ref) {
-                return Some(ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance));
+    /// Builds a constraint for an array of byte pattern, where wildcard bits are left out of the byte mask of the constraint.
+    fn deanonymize_byte_pattern_constraint(
+        &self,
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Option<ScanConstraint> {
+        let byte_pattern = match DataTypeAob::parse_scan_pattern(anonymous_value_string) {
+            Ok(byte_pattern) => byte_pattern,
+            Err(error) => {
+                log::error!("Unable to parse array of byte pattern in anonymous constraint: {}", error);
+
+                return None;
             }
+        };
+        let data_value = DataValue::new(data_type_ref.clone(), byte_pattern.get_bytes().to_vec());
+        let mut scan_constraint = ScanConstraint::new(self.scan_compare_type, data_value, floating_point_tolerance);
+
+        if byte_pattern.has_wildcards() {
+            scan_constraint.set_byte_mask(byte_pattern.get_mask().to_vec());
         }
 
-        None
+        Some(scan_constraint)
+    }
+
+    /// Builds a constraint that matches values inclusively between the lower and upper bound. Both bounds are required.
+    fn deanonymize_between_constraint(
+        &self,
+        data_type_ref: &DataTypeRef,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Option<ScanConstraint> {
+        let (Some(anonymous_value_string_lower_bound), Some(anonymous_value_string_upper_bound)) =
+            (&self.anonymous_value_string, &self.anonymous_value_string_upper_bound)
+        else {
+            log::error!("Between scans require both a lower and an upper bound.");
+            return None;
+        };
+        let (lower_bound_data_value, field_wildcards) = Self::deanonymize_value(data_type_ref, anonymous_value_string_lower_bound)?;
+        let (upper_bound_data_value, _field_wildcards) = Self::deanonymize_value(data_type_ref, anonymous_value_string_upper_bound)?;
+        let mut scan_constraint = ScanConstraint::new_between(self.scan_compare_type, lower_bound_data_value, upper_bound_data_value, floating_point_tolerance);
+
+        scan_constraint.set_field_wildcards(field_wildcards);
+
+        Some(scan_constraint)
+    }
+
+    /// Builds a constraint that matches any of the listed values. Only equality has a meaning across a list, ie equal to any of the
+    /// values, or not equal to all of them.
+    fn deanonymize_value_list_constraint(
+        &self,
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Option<ScanConstraint> {
+        match self.scan_compare_type {
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal) | ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual) => {}
+            _ => {
+                log::error!("Value lists can only be scanned for with equal or not equal comparisons.");
+                return None;
+            }
+        }
+
+        match AnonymousValueList::deanonymize(data_type_ref, anonymous_value_string) {
+            Ok(data_values) => ScanConstraint::new_any_of(self.scan_compare_type, data_values, floating_point_tolerance),
+            Err(error) => {
+                log::error!("Unable to parse value list in anonymous constraint: {}", error);
+                None
+            }
+        }
     }
 }
 
@@ -83,6 +234,7 @@ impl FromStr for AnonymousScanConstraint {
         // Prefixes are deliberately ordered in a manner to resolve ambiguity (ie + vs +{value}).
         let prefixes = [
             // Relative scans.
+            ("!=first", ScanCompareType::Relative(ScanCompareTypeRelative::DiffersFromFirstScan), false),
             ("!=", ScanCompareType::Relative(ScanCompareTypeRelative::Changed), false),
             ("==", ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged), false),
             ("+", ScanCompareType::Relative(ScanCompareTypeRelative::Increased), false),
@@ -101,6 +253,20 @@ impl FromStr for AnonymousScanConstraint {
 
         let string = string.trim();
 
+        // Ranges are written as lower..upper, and are checked first such that a negative lower bound is not read as a decrease.
+        if !string.starts_with(['=', '!', '<', '>', '+'])
+            && let Some((lower_bound, upper_bound)) = string.split_once("..")
+            && let (Ok(anonymous_value_string_lower_bound), Ok(anonymous_value_string_upper_bound)) = (
+                lower_bound.trim().parse::<AnonymousValueString>(),
+                upper_bound.trim().parse::<AnonymousValueString>(),
+            )
+        {
+            return Ok(AnonymousScanConstraint::new_between(
+                anonymous_value_string_lower_bound,
+                anonymous_value_string_upper_bound,
+            ));
+        }
+
         for (prefix, scan_compare_type, needs_value) in prefixes {
             if string.starts_with(prefix) {
                 let rest = &string[prefix.len()..].trim();
@@ -125,10 +291,7 @@ impl FromStr for AnonymousScanConstraint {
                     continue;
                 }
 
-                return Ok(AnonymousScanConstraint {
-                    scan_compare_type,
-                    anonymous_value_string,
-                });
+                return Ok(AnonymousScanConstraint::new(scan_compare_type, anonymous_value_string));
             }
         }
 
@@ -7,6 +7,15 @@ pub struct ScanConstraint {
     scan_compare_type: ScanCompareType,
     data_value: DataValue,
     floating_point_tolerance: FloatingPointTolerance,
+    /// Per-field wildcard flags for composite data types, where a wildcard field is excluded from comparisons.
+    field_wildcards: Vec<bool>,
+    /// Every value of a value list scan, including the data value. Equal matches any of these, and not equal matches none of them.
+    /// Empty for scans against a single value.
+    any_of_data_values: Vec<DataValue>,
+    /// The inclusive upper bound of a between scan, where the data value is the inclusive lower bound.
+    upper_bound_data_value: Option<DataValue>,
+    /// The bits of each byte of the data value that are compared, where a zero bit is a wildcard. Empty when every bit is compared.
+    byte_mask: Vec<u8>,
 }
 
 impl ScanConstraint {
@@ -19,6 +28,57 @@ impl ScanConstraint {
             scan_compare_type,
             data_value,
             floating_point_tolerance,
+            field_wildcards: Vec::new(),
+            any_of_data_values: Vec::new(),
+            upper_bound_data_value: None,
+            byte_mask: Vec::new(),
+        }
+    }
+
+    /// Creates a constraint that matches values inclusively between a lower and an upper bound.
+    pub fn new_between(
+        scan_compare_type: ScanCompareType,
+        lower_bound_data_value: DataValue,
+        upper_bound_data_value: DataValue,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Self {
+        let mut scan_constraint = Self::new(scan_compare_type, lower_bound_data_value, floating_point_tolerance);
+
+        scan_constraint.upper_bound_data_value = Some(upper_bound_data_value);
+
+        scan_constraint
+    }
+
+    /// Creates a constraint that compares against any of the given values. The first value doubles as the data value, which decides
+    /// the data type and size of the scan.
+    pub fn new_any_of(
+        scan_compare_type: ScanCompareType,
+        data_values: Vec<DataValue>,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Option<Self> {
+        let data_value = data_values.first()?.clone();
+        let mut scan_constraint = Self::new(scan_compare_type, data_value, floating_point_tolerance);
+
+        if data_values.len() > 1 {
+            scan_constraint.any_of_data_values = data_values;
+        }
+
+        Some(scan_constraint)
+    }
+
+    /// Creates a single value constraint with the same comparison and settings as this one, but against a different value.
+    pub fn with_data_value(
+        &self,
+        data_value: DataValue,
+    ) -> Self {
+        Self {
+            scan_compare_type: self.scan_compare_type,
+            data_value,
+            floating_point_tolerance: self.floating_point_tolerance,
+            field_wildcards: self.field_wildcards.clone(),
+            any_of_data_values: Vec::new(),
+            upper_bound_data_value: None,
+            byte_mask: self.byte_mask.clone(),
         }
     }
 
@@ -53,6 +113,14 @@ impl ScanConstraint {
         &mut self,
         data_type_ref: DataTypeRef,
     ) {
+        for any_of_data_value in &mut self.any_of_data_values {
+            any_of_data_value.set_data_type_in_place(data_type_ref.clone());
+        }
+
+        if let Some(upper_bound_data_value) = &mut self.upper_bound_data_value {
+            upper_bound_data_value.set_data_type_in_place(data_type_ref.clone());
+        }
+
         self.data_value.set_data_type_in_place(data_type_ref);
     }
 
@@ -66,4 +134,39 @@ impl ScanConstraint {
     ) {
         self.floating_point_tolerance = floating_point_tolerance
     }
+
+    pub fn get_field_wildcards(&self) -> &[bool] {
+        &self.field_wildcards
+    }
+
+    pub fn set_field_wildcards(
+        &mut self,
+        field_wildcards: Vec<bool>,
+    ) {
+        self.field_wildcards = field_wildcards
+    }
+
+    pub fn get_any_of_data_values(&self) -> &[DataValue] {
+        &self.any_of_data_values
+    }
+
+    pub fn get_upper_bound_data_value(&self) -> Option<&DataValue> {
+        self.upper_bound_data_value.as_ref()
+    }
+
+    pub fn get_byte_mask(&self) -> &[u8] {
+        &self.byte_mask
+    }
+
+    pub fn set_byte_mask(
+        &mut self,
+        byte_mask: Vec<u8>,
+    ) {
+        self.byte_mask = byte_mask
+    }
+
+    /// Gets whether this constraint compares against a list of values, rather than a single value.
+    pub fn is_any_of(&self) -> bool {
+        self.any_of_data_values.len() > 1
+    }
 }
@@ -3,11 +3,14 @@ use crate::structures::data_types::generics::vector_comparer::VectorComparer;
 use crate::structures::data_types::generics::vector_function::GetVectorFunction;
 use crate::structures::data_values::data_value::DataValue;
 use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
-use crate::structures::scanning::comparisons::scan_function_scalar::ScanFunctionScalar;
-use crate::structures::scanning::comparisons::scan_function_vector::ScanFunctionVector;
+use crate::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use crate::structures::scanning::comparisons::scan_function_scalar::{ScalarCompareFnImmediate, ScanFunctionScalar};
+use crate::structures::scanning::comparisons::scan_function_vector::{ScanFunctionVector, VectorCompareFnImmediate};
 use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
 use crate::{registries::symbols::symbol_registry::SymbolRegistry, structures::data_types::floating_point_tolerance::FloatingPointTolerance};
-use std::simd::{LaneCount, SupportedLaneCount};
+use std::collections::HashSet;
+use std::simd::{LaneCount, Simd, SupportedLaneCount};
+use std::sync::Arc;
 
 /// Represents a scan constraint that has finished being processed by rules.
 pub struct ScanConstraintFinalized {
@@ -48,9 +51,19 @@ impl GetVectorFunction<64> for LaneCount<64> {
 }
 
 impl ScanConstraintFinalized {
+    /// Value lists up to this size compare against each value in turn, which vectorizes. Larger lists are looked up in a hash set one
+    /// element at a time instead, as the cost of comparing against every value grows with the list while a lookup does not.
+    pub const ANY_OF_UNROLL_LIMIT: usize = 8;
+
     pub fn new(scan_constraint: ScanConstraint) -> Self {
         let symbol_registry = SymbolRegistry::get_instance();
-        let periodicity = Self::calculate_periodicity(symbol_registry, &scan_constraint.get_data_value(), &scan_constraint.get_scan_compare_type());
+        let periodicity = if scan_constraint.is_any_of() || scan_constraint.get_upper_bound_data_value().is_some() {
+            // Each value repeats differently, so there is no single period to take advantage of. Periodic scans also only support
+            // equality, whereas a range is an inequality on each of its bounds.
+            symbol_registry.get_unit_size_in_bytes(scan_constraint.get_data_value().get_data_type_ref())
+        } else {
+            Self::calculate_periodicity(symbol_registry, scan_constraint.get_data_value(), &scan_constraint.get_scan_compare_type())
+        };
         let data_type_unit_size_bytes = symbol_registry.get_unit_size_in_bytes(scan_constraint.get_data_value().get_data_type_ref());
         let value_size_bytes = scan_constraint.get_data_value().get_size_in_bytes();
         let unit_size_bytes = data_type_unit_size_bytes.max(value_size_bytes);
@@ -146,6 +159,10 @@ impl ScanConstraintFinalized {
     fn build_scan_function_scalar(scan_constraint: &ScanConstraint) -> Option<ScanFunctionScalar> {
         let symbol_registry = SymbolRegistry::get_instance();
 
+        if scan_constraint.is_any_of() {
+            return Self::build_any_of_scan_function_scalar(scan_constraint);
+        }
+
         match scan_constraint.get_scan_compare_type() {
             ScanCompareType::Immediate(scan_compare_type_immediate) => {
                 if let Some(compare_func) = symbol_registry.get_scalar_compare_func_immediate(&scan_compare_type_immediate, scan_constraint) {
@@ -173,6 +190,10 @@ impl ScanConstraintFinalized {
     {
         let symbol_registry = SymbolRegistry::get_instance();
 
+        if scan_constraint.is_any_of() {
+            return Self::build_any_of_scan_function_vector(scan_constraint);
+        }
+
         match scan_constraint.get_scan_compare_type() {
             ScanCompareType::Immediate(scan_compare_type_immediate) => {
                 if let Some(compare_func) = symbol_registry.get_vector_compare_func_immediate(&scan_compare_type_immediate, scan_constraint) {
@@ -193,4 +214,81 @@ impl ScanConstraintFinalized {
 
         None
     }
+
+    /// Gets whether a value list constraint matches when none of its values are equal, rather than when any of them are.
+    fn is_any_of_negated(scan_constraint: &ScanConstraint) -> Option<bool> {
+        match scan_constraint.get_scan_compare_type() {
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal) => Some(false),
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual) => Some(true),
+            _ => None,
+        }
+    }
+
+    fn build_any_of_scan_function_scalar(scan_constraint: &ScanConstraint) -> Option<ScanFunctionScalar> {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let is_negated = Self::is_any_of_negated(scan_constraint)?;
+        let any_of_data_values = scan_constraint.get_any_of_data_values();
+        let data_type_ref = scan_constraint.get_data_value().get_data_type_ref();
+
+        // Floating point values are equal within a tolerance, which rules out looking them up by their exact bytes.
+        if any_of_data_values.len() > Self::ANY_OF_UNROLL_LIMIT && !symbol_registry.is_floating_point(data_type_ref) {
+            let value_size_in_bytes = scan_constraint.get_data_value().get_size_in_bytes() as usize;
+            let value_set: HashSet<Box<[u8]>> = any_of_data_values
+                .iter()
+                .map(|data_value| data_value.get_value_bytes().clone().into_boxed_slice())
+                .collect();
+
+            return Some(ScanFunctionScalar::Immediate(Arc::new(move |current_value_pointer| {
+                let current_value_bytes = unsafe { std::slice::from_raw_parts(current_value_pointer, value_size_in_bytes) };
+
+                value_set.contains(current_value_bytes) != is_negated
+            })));
+        }
+
+        let compare_funcs = any_of_data_values
+            .iter()
+            .map(|data_value| {
+                symbol_registry.get_scalar_compare_func_immediate(&ScanCompareTypeImmediate::Equal, &scan_constraint.with_data_value(data_value.clone()))
+            })
+            .collect::<Option<Vec<ScalarCompareFnImmediate>>>()?;
+
+        Some(ScanFunctionScalar::Immediate(Arc::new(move |current_value_pointer| {
+            compare_funcs
+                .iter()
+                .any(|compare_func| compare_func(current_value_pointer))
+                != is_negated
+        })))
+    }
+
+    /// Ors together the equality masks of each value. Lists too large to unroll have no vector function, and are scanned with the
+    /// scalar hash set lookup instead.
+    fn build_any_of_scan_function_vector<const N: usize>(scan_constraint: &ScanConstraint) -> Option<ScanFunctionVector<N>>
+    where
+        LaneCount<N>: SupportedLaneCount + VectorComparer<N> + GetVectorFunction<N>,
+    {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let is_negated = Self::is_any_of_negated(scan_constraint)?;
+        let any_of_data_values = scan_constraint.get_any_of_data_values();
+
+        if any_of_data_values.len() > Self::ANY_OF_UNROLL_LIMIT {
+            return None;
+        }
+
+        let compare_funcs = any_of_data_values
+            .iter()
+            .map(|data_value| {
+                symbol_registry.get_vector_compare_func_immediate::<N>(&ScanCompareTypeImmediate::Equal, &scan_constraint.with_data_value(data_value.clone()))
+            })
+            .collect::<Option<Vec<VectorCompareFnImmediate<N>>>>()?;
+
+        Some(ScanFunctionVector::Immediate(Arc::new(move |current_values_pointer| {
+            let mut compare_result = Simd::<u8, N>::splat(0);
+
+            for compare_func in &compare_funcs {
+                compare_result |= compare_func(current_values_pointer);
+            }
+
+            if is_negated { !compare_result } else { compare_result }
+        })))
+    }
 }
@@ -31,6 +31,16 @@ impl SnapshotRegionFilter {
         self.filter_range.set_base_address(base_address);
     }
 
+    /// Moves this filter by the given wrapping delta, keeping its size.
+    pub fn relocate(
+        &mut self,
+        address_delta: u64,
+    ) {
+        let base_address = self.get_base_address().wrapping_add(address_delta);
+
+        self.filter_range.set_base_address(base_address);
+    }
+
     /// Sets the end address of this filter.
     pub fn set_end_address(
         &mut self,
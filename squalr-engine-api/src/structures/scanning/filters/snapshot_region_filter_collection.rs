@@ -1,8 +1,11 @@
 use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+use crate::structures::scanning::statistics::scan_strategy::ScanStrategy;
 use crate::structures::{data_types::data_type_ref::DataTypeRef, scanning::filters::snapshot_region_filter::SnapshotRegionFilter};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 /// A custom type that defines a set of filters (scan results) discovered by scanners.
+#[derive(Clone)]
 pub struct SnapshotRegionFilterCollection {
     /// The filters contained in this collection. This is kept as a vector of vectors for better parallelization.
     snapshot_region_filters: Vec<Vec<SnapshotRegionFilter>>,
@@ -20,6 +23,12 @@ pub struct SnapshotRegionFilterCollection {
 
     // The total number of results contained in this collection.
     number_of_results: u64,
+
+    // The strategy used by the scan that produced this collection.
+    scan_strategy: ScanStrategy,
+
+    // The statistics of the scan that produced this collection, which decide the strategy of the next scan over it.
+    scan_filter_statistics: ScanFilterStatistics,
 }
 
 impl SnapshotRegionFilterCollection {
@@ -59,9 +68,32 @@ impl SnapshotRegionFilterCollection {
             data_type_ref,
             unit_size_in_bytes: data_type_size,
             memory_alignment,
+            scan_strategy: ScanStrategy::default(),
+            scan_filter_statistics: ScanFilterStatistics::default(),
         }
     }
 
+    /// Attaches the strategy and statistics of the scan that produced this collection.
+    pub fn with_scan_statistics(
+        mut self,
+        scan_strategy: ScanStrategy,
+        scan_filter_statistics: ScanFilterStatistics,
+    ) -> Self {
+        self.scan_strategy = scan_strategy;
+        self.scan_filter_statistics = scan_filter_statistics;
+        self
+    }
+
+    /// Gets the strategy used by the scan that produced this collection.
+    pub fn get_scan_strategy(&self) -> ScanStrategy {
+        self.scan_strategy
+    }
+
+    /// Gets the statistics of the scan that produced this collection. These are empty for collections that were never scanned.
+    pub fn get_scan_filter_statistics(&self) -> &ScanFilterStatistics {
+        &self.scan_filter_statistics
+    }
+
     /// Gets the minimum address across all filters contained by this filter collection.
     /// This is O(1), as the filters are sorted upon creation of the filter collection.
     pub fn get_filter_minimum_address(&self) -> u64 {
@@ -86,6 +118,16 @@ impl SnapshotRegionFilterCollection {
         max_address
     }
 
+    /// Moves every filter in this collection by the given wrapping delta. Filters keep their order, as they all move together.
+    pub fn relocate(
+        &mut self,
+        address_delta: u64,
+    ) {
+        for snapshot_region_filter in self.snapshot_region_filters.iter_mut().flatten() {
+            snapshot_region_filter.relocate(address_delta);
+        }
+    }
+
     // Get the total number of results contained in this collection.
     pub fn get_number_of_results(&self) -> u64 {
         self.number_of_results
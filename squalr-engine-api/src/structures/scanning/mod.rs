@@ -1,6 +1,8 @@
+pub mod benchmarks;
 pub mod comparisons;
 pub mod constraints;
 pub mod filters;
 pub mod memory_read_mode;
 pub mod plans;
 pub mod rules;
+pub mod statistics;
@@ -1,8 +1,11 @@
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use crate::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
 use crate::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
 use crate::structures::scanning::memory_read_mode::MemoryReadMode;
+use crate::structures::settings::scan_vector_width::ScanVectorWidth;
 use std::collections::HashMap;
 
 /// Represents parameters that can be optimized by rules to efficiently execute an element scan.
@@ -13,6 +16,10 @@ pub struct ElementScanPlan {
     memory_read_mode: MemoryReadMode,
     is_single_thread_scan: bool,
 
+    /// The number of worker threads that scan regions, or zero for one per logical core.
+    scan_thread_count: u32,
+    vector_width: ScanVectorWidth,
+
     /// If this debug flag is provided, the scan will be performed twice. Once with a specialized scan, and once with the default scan.
     /// An assertion will be made that the default scan produced the exact same result as the specialized scan.
     debug_perform_validation_scan: bool,
@@ -33,10 +40,30 @@ impl ElementScanPlan {
             floating_point_tolerance,
             memory_read_mode,
             is_single_thread_scan,
+            scan_thread_count: 0,
+            vector_width: ScanVectorWidth::Auto,
             debug_perform_validation_scan,
         }
     }
 
+    /// Limits the scan to the given number of worker threads, or zero for one per logical core.
+    pub fn with_scan_thread_count(
+        mut self,
+        scan_thread_count: u32,
+    ) -> Self {
+        self.scan_thread_count = scan_thread_count;
+        self
+    }
+
+    /// Limits the SIMD vectors that the scan may use to the given width.
+    pub fn with_vector_width(
+        mut self,
+        vector_width: ScanVectorWidth,
+    ) -> Self {
+        self.vector_width = vector_width;
+        self
+    }
+
     pub fn get_scan_constraints_by_data_type(&self) -> &HashMap<DataTypeRef, Vec<ScanConstraintFinalized>> {
         &self.scan_constraints_by_data_type
     }
@@ -61,7 +88,30 @@ impl ElementScanPlan {
         self.is_single_thread_scan
     }
 
+    pub fn get_scan_thread_count(&self) -> u32 {
+        self.scan_thread_count
+    }
+
+    pub fn get_vector_width(&self) -> ScanVectorWidth {
+        self.vector_width
+    }
+
     pub fn get_debug_perform_validation_scan(&self) -> bool {
         self.debug_perform_validation_scan
     }
+
+    /// Gets whether this scan is for an unknown initial value, in which case no data type has constraints and every element is kept.
+    pub fn get_is_unknown_initial_value_scan(&self) -> bool {
+        self.scan_constraints_by_data_type
+            .values()
+            .all(|scan_constraints| scan_constraints.is_empty())
+    }
+
+    /// Gets whether this scan compares against the values of the first scan, which then stand in for the previous values.
+    pub fn get_compares_against_first_scan(&self) -> bool {
+        self.scan_constraints_by_data_type
+            .values()
+            .flatten()
+            .any(|scan_constraint| scan_constraint.get_scan_compare_type() == ScanCompareType::Relative(ScanCompareTypeRelative::DiffersFromFirstScan))
+    }
 }
@@ -8,6 +8,7 @@ use crate::structures::scanning::comparisons::scan_function_scalar::ScanFunction
 use crate::structures::scanning::comparisons::scan_function_vector::ScanFunctionVector;
 use crate::structures::scanning::constraints::scan_constraint::ScanConstraint;
 use crate::structures::scanning::plans::plan_types::planned_scan_type::PlannedScanType;
+use crate::structures::settings::scan_vector_width::ScanVectorWidth;
 use crate::structures::{data_types::data_type_ref::DataTypeRef, scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized};
 use std::simd::{LaneCount, SupportedLaneCount};
 
@@ -17,6 +18,7 @@ pub struct SnapshotFilterElementScanPlan<'lifetime> {
     memory_alignment: MemoryAlignment,
     floating_point_tolerance: FloatingPointTolerance,
     planned_scan_type: PlannedScanType,
+    vector_width: ScanVectorWidth,
 }
 
 impl<'lifetime> SnapshotFilterElementScanPlan<'lifetime> {
@@ -32,6 +34,7 @@ impl<'lifetime> SnapshotFilterElementScanPlan<'lifetime> {
             memory_alignment,
             floating_point_tolerance,
             planned_scan_type: PlannedScanType::Invalid(),
+            vector_width: ScanVectorWidth::Auto,
         }
     }
 
@@ -82,6 +85,17 @@ impl<'lifetime> SnapshotFilterElementScanPlan<'lifetime> {
         self.planned_scan_type = planned_scan_type;
     }
 
+    pub fn get_vector_width(&self) -> ScanVectorWidth {
+        self.vector_width
+    }
+
+    pub fn set_vector_width(
+        &mut self,
+        vector_width: ScanVectorWidth,
+    ) {
+        self.vector_width = vector_width;
+    }
+
     pub fn get_scan_function_scalar(&self) -> &Option<ScanFunctionScalar> {
         self.scan_constraint_finalized.get_scan_function_scalar()
     }
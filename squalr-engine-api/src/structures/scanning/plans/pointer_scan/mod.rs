@@ -1 +1,2 @@
 pub mod pointer_scan_parameters;
+pub mod pointer_validity_filters;
@@ -1,15 +1,20 @@
 use crate::structures::data_types::data_type_ref::DataTypeRef;
 use crate::structures::data_values::data_value::DataValue;
+use crate::structures::scanning::plans::pointer_scan::pointer_validity_filters::PointerValidityFilters;
 
 /// Represents the scan arguments for an element-wise scan.
 #[derive(Debug, Clone)]
 pub struct PointerScanParameters {
     target_address: DataValue,
     pointer_data_type_ref: DataTypeRef,
-    offset_size: u64,
+    min_offset: i64,
+    max_offset: i64,
+    max_offsets_per_level: Option<u64>,
     max_depth: u64,
     scan_statics: bool,
     scan_heaps: bool,
+    pointer_validity_filters: PointerValidityFilters,
+    max_memory_bytes: u64,
     is_single_thread_scan: bool,
 
     /// If this debug flag is provided, the scan will be performed twice. Once with a specialized scan, and once with the default scan.
@@ -18,23 +23,34 @@ pub struct PointerScanParameters {
 }
 
 impl PointerScanParameters {
+    /// The memory cap used when the request does not give one.
+    pub const DEFAULT_MAX_MEMORY_BYTES: u64 = 4_000_000_000;
+
     pub fn new(
         target_address: DataValue,
         pointer_data_type_ref: DataTypeRef,
-        offset_size: u64,
+        min_offset: i64,
+        max_offset: i64,
+        max_offsets_per_level: Option<u64>,
         max_depth: u64,
         scan_statics: bool,
         scan_heaps: bool,
+        pointer_validity_filters: PointerValidityFilters,
+        max_memory_bytes: u64,
         is_single_thread_scan: bool,
         debug_perform_validation_scan: bool,
     ) -> Self {
         Self {
             target_address,
             pointer_data_type_ref,
-            offset_size,
+            min_offset,
+            max_offset,
+            max_offsets_per_level,
             max_depth,
             scan_statics,
             scan_heaps,
+            pointer_validity_filters,
+            max_memory_bytes,
             is_single_thread_scan,
             debug_perform_validation_scan,
         }
@@ -48,8 +64,21 @@ impl PointerScanParameters {
         &self.pointer_data_type_ref
     }
 
-    pub fn get_offset_size(&self) -> u64 {
-        self.offset_size
+    /// Gets the smallest offset from a pointer value to the address it leads to. A negative offset reaches an address below the
+    /// pointer value.
+    pub fn get_min_offset(&self) -> i64 {
+        self.min_offset
+    }
+
+    /// Gets the largest offset from a pointer value to the address it leads to.
+    pub fn get_max_offset(&self) -> i64 {
+        self.max_offset
+    }
+
+    /// Gets the most distinct offsets followed from each address on a level, keeping the offsets closest to zero. `None` follows
+    /// every offset within the offset range.
+    pub fn get_max_offsets_per_level(&self) -> Option<u64> {
+        self.max_offsets_per_level
     }
 
     pub fn get_max_depth(&self) -> u64 {
@@ -64,6 +93,15 @@ impl PointerScanParameters {
         self.scan_heaps
     }
 
+    pub fn get_pointer_validity_filters(&self) -> &PointerValidityFilters {
+        &self.pointer_validity_filters
+    }
+
+    /// Gets the most memory the pointer map and its levels may use before the scan stops deepening.
+    pub fn get_max_memory_bytes(&self) -> u64 {
+        self.max_memory_bytes
+    }
+
     pub fn get_is_single_thread_scan(&self) -> bool {
         self.is_single_thread_scan
     }
@@ -0,0 +1,104 @@
+use crate::structures::memory::region_interval_set::RegionIntervalSet;
+use serde::{Deserialize, Serialize};
+
+/// Heuristics that discard values which cannot plausibly be pointers before they enter the pointer map. Filtering while the map is
+/// built keeps junk out of every level of the scan, rather than only the first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointerValidityFilters {
+    /// The alignment that pointer storage addresses must have. When unset, the pointer size of the target is used.
+    pointer_alignment: Option<u64>,
+
+    /// Whether pointer values must fall within mapped memory.
+    require_mapped_targets: bool,
+
+    /// Whether pointer values must themselves be aligned to the pointer alignment.
+    require_aligned_targets: bool,
+}
+
+impl Default for PointerValidityFilters {
+    fn default() -> Self {
+        Self {
+            pointer_alignment: None,
+            require_mapped_targets: true,
+            require_aligned_targets: false,
+        }
+    }
+}
+
+impl PointerValidityFilters {
+    pub fn new(
+        pointer_alignment: Option<u64>,
+        require_mapped_targets: bool,
+        require_aligned_targets: bool,
+    ) -> Self {
+        Self {
+            pointer_alignment,
+            require_mapped_targets,
+            require_aligned_targets,
+        }
+    }
+
+    pub fn get_pointer_alignment(&self) -> Option<u64> {
+        self.pointer_alignment
+    }
+
+    /// Gets the storage alignment to scan with for a target of the given pointer size. Alignments that are not a power of two are
+    /// rounded down to one, as no other value can evenly divide addresses.
+    pub fn resolve_pointer_alignment(
+        &self,
+        pointer_size: u64,
+    ) -> u64 {
+        let pointer_alignment = self.pointer_alignment.unwrap_or(pointer_size).max(1);
+
+        if pointer_alignment.is_power_of_two() { pointer_alignment } else { 1 }
+    }
+
+    pub fn get_require_mapped_targets(&self) -> bool {
+        self.require_mapped_targets
+    }
+
+    pub fn get_require_aligned_targets(&self) -> bool {
+        self.require_aligned_targets
+    }
+
+    /// Determines whether a pointer value passes the target heuristics. Storage alignment is not checked here, as scans only
+    /// visit aligned storage addresses to begin with.
+    pub fn is_valid_target(
+        &self,
+        pointer_value: u64,
+        pointer_alignment: u64,
+        mapped_regions: &RegionIntervalSet,
+    ) -> bool {
+        if self.require_aligned_targets && !pointer_value.is_multiple_of(pointer_alignment.max(1)) {
+            return false;
+        }
+
+        !self.require_mapped_targets || mapped_regions.contains(pointer_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerValidityFilters;
+    use crate::structures::memory::region_interval_set::RegionIntervalSet;
+
+    #[test]
+    fn alignment_defaults_to_the_pointer_size() {
+        assert_eq!(PointerValidityFilters::default().resolve_pointer_alignment(8), 8);
+        assert_eq!(PointerValidityFilters::default().resolve_pointer_alignment(4), 4);
+        assert_eq!(PointerValidityFilters::new(Some(4), true, false).resolve_pointer_alignment(8), 4);
+        assert_eq!(PointerValidityFilters::new(Some(6), true, false).resolve_pointer_alignment(8), 1);
+    }
+
+    #[test]
+    fn targets_are_filtered_by_mapping_and_alignment() {
+        let mapped_regions = RegionIntervalSet::from_ranges([(0x1000, 0x2000)]);
+        let permissive_filters = PointerValidityFilters::new(None, false, false);
+        let strict_filters = PointerValidityFilters::new(None, true, true);
+
+        assert!(permissive_filters.is_valid_target(0x5003, 8, &mapped_regions));
+        assert!(strict_filters.is_valid_target(0x1008, 8, &mapped_regions));
+        assert!(!strict_filters.is_valid_target(0x1004, 8, &mapped_regions));
+        assert!(!strict_filters.is_valid_target(0x5000, 8, &mapped_regions));
+    }
+}
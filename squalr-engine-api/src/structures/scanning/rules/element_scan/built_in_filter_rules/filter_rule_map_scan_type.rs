@@ -14,7 +14,7 @@ use crate::structures::scanning::{
     },
 };
 use crate::structures::snapshots::snapshot_region::SnapshotRegion;
-use crate::structures::data_types::built_in_types::string::utf8::data_type_string_utf8::DataTypeStringUtf8;
+use crate::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
 use crate::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
 
 pub struct RuleMapScanType {}
@@ -33,7 +33,7 @@ impl ElementScanFilterRule for RuleMapScanType {
         snapshot_region: &SnapshotRegion,
         _snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
         snapshot_region_filter: &SnapshotRegionFilter,
-        _scan_constraint_finalized: &ScanConstraintFinalized,
+        scan_constraint_finalized: &ScanConstraintFinalized,
         snapshot_filter_element_scan_plan: &mut SnapshotFilterElementScanPlan,
     ) {
         let is_valid_for_snapshot_region = if snapshot_region.has_current_values() {
@@ -51,6 +51,33 @@ impl ElementScanFilterRule for RuleMapScanType {
             return;
         }
 
+        // Custom struct types compare field-by-field, which has no vectorized or byte array equivalent.
+        if SymbolRegistry::get_instance().is_custom_data_type(snapshot_filter_element_scan_plan.get_data_type_ref()) {
+            snapshot_filter_element_scan_plan.set_planned_scan_type(PlannedScanType::Scalar(PlannedScanTypeScalar::ScalarIterative));
+
+            return;
+        }
+
+        // Patterns with wildcards compare only the bits under their mask, which the Boyer-Moore tables cannot account for. The masked
+        // scanner also finds matches that begin in this filter but end past it, so it is used regardless of the filter size.
+        if !scan_constraint_finalized
+            .get_scan_constraint()
+            .get_byte_mask()
+            .is_empty()
+            && snapshot_filter_element_scan_plan.get_compare_type() == ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal)
+        {
+            snapshot_filter_element_scan_plan.set_planned_scan_type(PlannedScanType::ByteArray(PlannedScanTypeByteArray::ByteArrayMasked));
+
+            return;
+        }
+
+        // Value lists too large to unroll into vector compares are looked up one element at a time.
+        if scan_constraint_finalized.get_scan_constraint().is_any_of() && scan_constraint_finalized.get_scan_function_vector::<16>().is_none() {
+            snapshot_filter_element_scan_plan.set_planned_scan_type(PlannedScanType::Scalar(PlannedScanTypeScalar::ScalarIterative));
+
+            return;
+        }
+
         let region_size = snapshot_region_filter.get_region_size();
 
         // Early check as to whether we are smaller than the smallest possible vector.
@@ -73,13 +100,19 @@ impl ElementScanFilterRule for RuleMapScanType {
         let memory_alignment_size = snapshot_filter_element_scan_plan.get_memory_alignment() as u64;
         let value_length_bytes = snapshot_filter_element_scan_plan.get_data_value().get_size_in_bytes();
         let data_type_id = data_type_ref.get_data_type_id();
-        let is_byte_array_type =
-            data_type_id == DataTypeStringUtf8::DATA_TYPE_ID || data_type_id == DataTypeAob::DATA_TYPE_ID || value_length_bytes > data_type_size_bytes;
+        let is_byte_array_type = DataTypeStringEncoded::is_string_data_type_id(data_type_id)
+            || data_type_id == DataTypeAob::DATA_TYPE_ID
+            || value_length_bytes > data_type_size_bytes;
 
-        // Decide whether to use a scalar or SIMD scan based on filter region size.
-        let vectorization_size = if VectorGenerics::plan_vector_scan::<64>(region_size, data_type_size_bytes, memory_alignment_size).is_valid() {
+        // Decide whether to use a scalar or SIMD scan based on filter region size, using no wider a vector than the settings allow.
+        let vector_width = snapshot_filter_element_scan_plan.get_vector_width();
+        let vectorization_size = if vector_width.allows_vector_size(64)
+            && VectorGenerics::plan_vector_scan::<64>(region_size, data_type_size_bytes, memory_alignment_size).is_valid()
+        {
             PlannedScanVectorizationSize::Vector64
-        } else if VectorGenerics::plan_vector_scan::<32>(region_size, data_type_size_bytes, memory_alignment_size).is_valid() {
+        } else if vector_width.allows_vector_size(32)
+            && VectorGenerics::plan_vector_scan::<32>(region_size, data_type_size_bytes, memory_alignment_size).is_valid()
+        {
             PlannedScanVectorizationSize::Vector32
         } else if VectorGenerics::plan_vector_scan::<16>(region_size, data_type_size_bytes, memory_alignment_size).is_valid() {
             PlannedScanVectorizationSize::Vector16
@@ -41,6 +41,11 @@ impl ElementScanParametersRule for RuleMapToPrimitiveType {
                 return;
             }
 
+            // Patterns with wildcards only compare some of their bits, which a primitive comparison has no way to express.
+            if !scan_constraint.get_byte_mask().is_empty() {
+                continue;
+            }
+
             let data_type_size = data_value.get_size_in_bytes();
             let data_type_default_size = symbol_registry.get_unit_size_in_bytes(data_type_ref);
 
@@ -0,0 +1,3 @@
+pub mod scan_filter_statistics;
+pub mod scan_statistics;
+pub mod scan_strategy;
@@ -0,0 +1,125 @@
+use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use serde::{Deserialize, Serialize};
+
+/// Describes the outcome of scanning a filter collection: how many elements matched, and how the run length encoder grouped
+/// the matches into runs. These are kept with the resulting collection, such that the next scan over the same snapshot can
+/// pick a scanner suited to the shape of the results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanFilterStatistics {
+    scanned_element_count: u64,
+    matched_element_count: u64,
+    run_count: u64,
+
+    /// The number of runs falling into each bucket of `RUN_LENGTH_BUCKET_LABELS`, by run length in elements.
+    run_length_histogram: [u64; 4],
+}
+
+impl ScanFilterStatistics {
+    pub const RUN_LENGTH_BUCKET_LABELS: [&'static str; 4] = ["1", "2-15", "16-255", "256+"];
+
+    pub fn new(scanned_element_count: u64) -> Self {
+        Self {
+            scanned_element_count,
+            ..Default::default()
+        }
+    }
+
+    /// Creates statistics from the filters produced by a scan. Each filter is a single run emitted by the run length encoder.
+    pub fn from_result_filters<'lifetime>(
+        scanned_element_count: u64,
+        result_filters: impl Iterator<Item = &'lifetime SnapshotRegionFilter>,
+        unit_size_in_bytes: u64,
+        memory_alignment: MemoryAlignment,
+    ) -> Self {
+        let mut scan_filter_statistics = Self::new(scanned_element_count);
+
+        for result_filter in result_filters {
+            scan_filter_statistics.record_run(result_filter.get_element_count(unit_size_in_bytes, memory_alignment));
+        }
+
+        scan_filter_statistics
+    }
+
+    /// Records a run of consecutive matching elements.
+    pub fn record_run(
+        &mut self,
+        run_length_in_elements: u64,
+    ) {
+        if run_length_in_elements == 0 {
+            return;
+        }
+
+        let bucket_index = match run_length_in_elements {
+            1 => 0,
+            2..=15 => 1,
+            16..=255 => 2,
+            _ => 3,
+        };
+
+        self.matched_element_count += run_length_in_elements;
+        self.run_count += 1;
+        self.run_length_histogram[bucket_index] += 1;
+    }
+
+    /// Adds the counts of another set of statistics to these, ie to total the statistics of every region in a snapshot.
+    pub fn merge(
+        &mut self,
+        other: &ScanFilterStatistics,
+    ) {
+        self.scanned_element_count += other.scanned_element_count;
+        self.matched_element_count += other.matched_element_count;
+        self.run_count += other.run_count;
+
+        for (bucket, other_bucket) in self
+            .run_length_histogram
+            .iter_mut()
+            .zip(other.run_length_histogram)
+        {
+            *bucket += other_bucket;
+        }
+    }
+
+    pub fn get_scanned_element_count(&self) -> u64 {
+        self.scanned_element_count
+    }
+
+    pub fn get_matched_element_count(&self) -> u64 {
+        self.matched_element_count
+    }
+
+    pub fn get_run_count(&self) -> u64 {
+        self.run_count
+    }
+
+    pub fn get_run_length_histogram(&self) -> &[u64; 4] {
+        &self.run_length_histogram
+    }
+
+    /// Gets the fraction of scanned elements that matched, or `None` if nothing was scanned.
+    pub fn get_matched_fraction(&self) -> Option<f64> {
+        if self.scanned_element_count == 0 {
+            return None;
+        }
+
+        Some(self.matched_element_count as f64 / self.scanned_element_count as f64)
+    }
+
+    /// Gets the mean run length in elements, or zero if nothing matched.
+    pub fn get_average_run_length(&self) -> f64 {
+        if self.run_count == 0 {
+            return 0.0;
+        }
+
+        self.matched_element_count as f64 / self.run_count as f64
+    }
+
+    /// Gets the fraction of runs that are a single isolated element, or zero if nothing matched.
+    pub fn get_single_element_run_fraction(&self) -> f64 {
+        if self.run_count == 0 {
+            return 0.0;
+        }
+
+        self.run_length_histogram[0] as f64 / self.run_count as f64
+    }
+}
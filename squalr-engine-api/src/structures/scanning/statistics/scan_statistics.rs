@@ -0,0 +1,59 @@
+use crate::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+use crate::structures::scanning::statistics::scan_strategy::ScanStrategy;
+use serde::{Deserialize, Serialize};
+
+/// Totals the statistics of every filter collection in a snapshot for the most recent scan, along with how many collections
+/// were scanned with each strategy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScanStatistics {
+    scan_filter_statistics: ScanFilterStatistics,
+    planned_collection_count: u64,
+    sparse_collection_count: u64,
+    dense_collection_count: u64,
+}
+
+impl ScanStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the statistics of a single filter collection, which was scanned using the given strategy.
+    pub fn record_collection(
+        &mut self,
+        scan_strategy: ScanStrategy,
+        scan_filter_statistics: &ScanFilterStatistics,
+    ) {
+        self.scan_filter_statistics.merge(scan_filter_statistics);
+
+        match scan_strategy {
+            ScanStrategy::Planned => self.planned_collection_count += 1,
+            ScanStrategy::Sparse => self.sparse_collection_count += 1,
+            ScanStrategy::Dense => self.dense_collection_count += 1,
+        }
+    }
+
+    pub fn get_scan_filter_statistics(&self) -> &ScanFilterStatistics {
+        &self.scan_filter_statistics
+    }
+
+    /// Gets the number of filter collections that were scanned using the given strategy.
+    pub fn get_collection_count(
+        &self,
+        scan_strategy: ScanStrategy,
+    ) -> u64 {
+        match scan_strategy {
+            ScanStrategy::Planned => self.planned_collection_count,
+            ScanStrategy::Sparse => self.sparse_collection_count,
+            ScanStrategy::Dense => self.dense_collection_count,
+        }
+    }
+
+    /// Gets the strategy used for the most filter collections, preferring the planned strategy on ties.
+    pub fn get_dominant_strategy(&self) -> ScanStrategy {
+        [ScanStrategy::Planned, ScanStrategy::Sparse, ScanStrategy::Dense]
+            .into_iter()
+            .rev()
+            .max_by_key(|scan_strategy| self.get_collection_count(*scan_strategy))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,140 @@
+use crate::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+use serde::{Deserialize, Serialize};
+
+/// How the filters of a collection are dispatched to scanners, chosen from the statistics of the scan that produced them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScanStrategy {
+    /// Leaves the choice of scanner to the scan rules, which pick one per filter from its size, data type, and alignment.
+    #[default]
+    Planned,
+
+    /// The previous pass left mostly isolated matches. Each filter is scanned on the thread that picked it up, and filters
+    /// holding a single element go straight to the single element scanner, as the overhead of fanning out across threads
+    /// and walking the iterative scanner outweighs the one comparison being made.
+    Sparse,
+
+    /// The previous pass matched most elements in long runs, which the full-vector scanners encode a vector at a time.
+    /// The vector plans chosen by the scan rules are kept, and filters continue to be scanned in parallel.
+    Dense,
+}
+
+impl ScanStrategy {
+    /// Below this fraction of matched elements, results are considered sparse.
+    pub const SPARSE_MATCHED_FRACTION: f64 = 0.01;
+
+    /// Above this fraction of single element runs, results are considered sparse regardless of how many elements matched.
+    pub const SPARSE_SINGLE_ELEMENT_RUN_FRACTION: f64 = 0.5;
+
+    /// Above this fraction of matched elements, results are considered dense if the runs are also long.
+    pub const DENSE_MATCHED_FRACTION: f64 = 0.5;
+
+    /// The average run length in elements needed for dense results, ie enough to fill the smallest vector of bytes.
+    pub const DENSE_AVERAGE_RUN_LENGTH: f64 = 16.0;
+
+    /// Below this many scanned elements, the statistics say too little about a region to act on.
+    pub const MINIMUM_SCANNED_ELEMENT_COUNT: u64 = 64;
+
+    /// Picks the strategy for scanning the filters that a previous scan produced, given the statistics of that scan.
+    pub fn select(scan_filter_statistics: &ScanFilterStatistics) -> Self {
+        if scan_filter_statistics.get_scanned_element_count() < Self::MINIMUM_SCANNED_ELEMENT_COUNT {
+            return ScanStrategy::Planned;
+        }
+
+        let matched_fraction = scan_filter_statistics.get_matched_fraction().unwrap_or(0.0);
+
+        if matched_fraction < Self::SPARSE_MATCHED_FRACTION
+            || scan_filter_statistics.get_single_element_run_fraction() > Self::SPARSE_SINGLE_ELEMENT_RUN_FRACTION
+        {
+            ScanStrategy::Sparse
+        } else if matched_fraction > Self::DENSE_MATCHED_FRACTION && scan_filter_statistics.get_average_run_length() >= Self::DENSE_AVERAGE_RUN_LENGTH {
+            ScanStrategy::Dense
+        } else {
+            ScanStrategy::Planned
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ScanStrategy::Planned => "Planned",
+            ScanStrategy::Sparse => "Sparse",
+            ScanStrategy::Dense => "Dense",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanStrategy;
+    use crate::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+
+    fn create_statistics(
+        scanned_element_count: u64,
+        run_lengths: &[u64],
+    ) -> ScanFilterStatistics {
+        let mut scan_filter_statistics = ScanFilterStatistics::new(scanned_element_count);
+
+        for run_length in run_lengths {
+            scan_filter_statistics.record_run(*run_length);
+        }
+
+        scan_filter_statistics
+    }
+
+    #[test]
+    fn few_matches_select_sparse() {
+        // 5 of 10,000 elements matched.
+        let scan_filter_statistics = create_statistics(10_000, &[1, 1, 3]);
+
+        assert_eq!(ScanStrategy::select(&scan_filter_statistics), ScanStrategy::Sparse);
+    }
+
+    #[test]
+    fn mostly_isolated_matches_select_sparse_even_when_many_matched() {
+        // Every other element matched, but only ever one at a time.
+        let scan_filter_statistics = create_statistics(200, &[1; 100]);
+
+        assert_eq!(scan_filter_statistics.get_matched_fraction(), Some(0.5));
+        assert_eq!(ScanStrategy::select(&scan_filter_statistics), ScanStrategy::Sparse);
+    }
+
+    #[test]
+    fn long_runs_covering_most_elements_select_dense() {
+        let scan_filter_statistics = create_statistics(10_000, &[4_096, 4_000, 64, 1]);
+
+        assert_eq!(ScanStrategy::select(&scan_filter_statistics), ScanStrategy::Dense);
+        assert_eq!(scan_filter_statistics.get_run_length_histogram(), &[1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn many_short_runs_covering_most_elements_stay_planned() {
+        // Over half matched, but in runs too short to fill a vector.
+        let scan_filter_statistics = create_statistics(1_000, &[4; 150]);
+
+        assert_eq!(ScanStrategy::select(&scan_filter_statistics), ScanStrategy::Planned);
+    }
+
+    #[test]
+    fn moderate_matches_stay_planned() {
+        let scan_filter_statistics = create_statistics(10_000, &[1_000, 500, 32]);
+
+        assert_eq!(ScanStrategy::select(&scan_filter_statistics), ScanStrategy::Planned);
+    }
+
+    #[test]
+    fn small_or_unscanned_regions_stay_planned() {
+        assert_eq!(ScanStrategy::select(&ScanFilterStatistics::default()), ScanStrategy::Planned);
+        assert_eq!(ScanStrategy::select(&create_statistics(32, &[])), ScanStrategy::Planned);
+    }
+
+    #[test]
+    fn merged_statistics_total_their_counts() {
+        let mut scan_filter_statistics = create_statistics(100, &[1, 20]);
+
+        scan_filter_statistics.merge(&create_statistics(300, &[300]));
+
+        assert_eq!(scan_filter_statistics.get_scanned_element_count(), 400);
+        assert_eq!(scan_filter_statistics.get_matched_element_count(), 321);
+        assert_eq!(scan_filter_statistics.get_run_count(), 3);
+        assert_eq!(scan_filter_statistics.get_run_length_histogram(), &[1, 0, 1, 1]);
+    }
+}
@@ -2,9 +2,15 @@ use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 use std::fmt;
 
-#[derive(Copy, Clone, Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct GeneralSettings {
     pub engine_request_delay_ms: u64,
+    /// The name of the last process that was successfully opened, offered to be reopened on startup.
+    #[serde(default)]
+    pub last_opened_process_name: Option<String>,
+    /// Whether to reopen the opened process automatically when it exits and a process with the same name starts again.
+    #[serde(default)]
+    pub auto_reattach: bool,
 }
 
 impl fmt::Debug for GeneralSettings {
@@ -21,6 +27,10 @@ impl fmt::Debug for GeneralSettings {
 
 impl Default for GeneralSettings {
     fn default() -> Self {
-        Self { engine_request_delay_ms: 0 }
+        Self {
+            engine_request_delay_ms: 0,
+            last_opened_process_name: None,
+            auto_reattach: false,
+        }
     }
 }
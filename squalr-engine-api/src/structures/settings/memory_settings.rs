@@ -36,6 +36,12 @@ pub struct MemorySettings {
     pub end_address: u64,
     #[serde(default)]
     pub only_query_usermode: bool,
+    /// How long cached module and region lists stay valid before they are enumerated again.
+    #[serde(default)]
+    pub query_cache_interval_ms: u64,
+    /// Whether the exports of the main module are parsed when attaching, rather than when an address within it is first shown.
+    #[serde(default)]
+    pub preload_main_module_symbols: bool,
 }
 
 impl fmt::Debug for MemorySettings {
@@ -50,6 +56,10 @@ impl fmt::Debug for MemorySettings {
     }
 }
 
+impl MemorySettings {
+    pub const DEFAULT_QUERY_CACHE_INTERVAL_MS: u64 = 5000;
+}
+
 impl Default for MemorySettings {
     fn default() -> Self {
         Self {
@@ -73,6 +83,10 @@ impl Default for MemorySettings {
             start_address: 0,
             end_address: u64::MAX,
             only_query_usermode: true,
+
+            query_cache_interval_ms: Self::DEFAULT_QUERY_CACHE_INTERVAL_MS,
+
+            preload_main_module_symbols: false,
         }
     }
 }
@@ -3,3 +3,5 @@ pub mod memory_settings;
 pub mod project_settings;
 pub mod scan_settings;
 pub mod scan_thread_priority;
+pub mod scan_vector_width;
+pub mod value_overflow_policy;
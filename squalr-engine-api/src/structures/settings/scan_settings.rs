@@ -1,5 +1,7 @@
 use crate::structures::memory::memory_alignment::MemoryAlignment;
 use crate::structures::settings::scan_thread_priority::ScanThreadPriority;
+use crate::structures::settings::scan_vector_width::ScanVectorWidth;
+use crate::structures::settings::value_overflow_policy::ValueOverflowPolicy;
 use crate::structures::{data_types::floating_point_tolerance::FloatingPointTolerance, scanning::memory_read_mode::MemoryReadMode};
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
@@ -24,7 +26,11 @@ pub struct ScanSettings {
     pub memory_alignment: Option<MemoryAlignment>,
     pub memory_read_mode: MemoryReadMode,
     pub floating_point_tolerance: FloatingPointTolerance,
+    pub value_overflow_policy: ValueOverflowPolicy,
     pub is_single_threaded_scan: bool,
+    /// The number of worker threads that scan regions, or zero for one per logical core.
+    pub scan_thread_count: u32,
+    pub vector_width: ScanVectorWidth,
     pub debug_perform_validation_scan: bool,
 }
 
@@ -60,7 +66,10 @@ impl Default for ScanSettings {
             floating_point_tolerance: FloatingPointTolerance::default(),
             // Reading interleaved avoids a dedicated full-pass value collection step which can stall the UI on large scans.
             memory_read_mode: MemoryReadMode::ReadInterleavedWithScan,
+            value_overflow_policy: ValueOverflowPolicy::default(),
             is_single_threaded_scan: false,
+            scan_thread_count: 0,
+            vector_width: ScanVectorWidth::Auto,
             debug_perform_validation_scan: false,
         }
     }
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The widest SIMD vector that element scans may use, in bytes. Auto uses the widest vector the hardware supports natively, while a
+/// fixed width is used even where the hardware is narrower, in which case each vector is split across several native registers.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ScanVectorWidth {
+    #[default]
+    Auto,
+    Bytes16,
+    Bytes32,
+    Bytes64,
+}
+
+impl ScanVectorWidth {
+    pub const ALL: [ScanVectorWidth; 4] = [
+        ScanVectorWidth::Auto,
+        ScanVectorWidth::Bytes16,
+        ScanVectorWidth::Bytes32,
+        ScanVectorWidth::Bytes64,
+    ];
+
+    /// Replaces Auto with the fixed width matching the given hardware vector size, in bytes. Fixed widths are kept as they are.
+    pub fn resolve_for_hardware(
+        &self,
+        hardware_vector_size_in_bytes: u64,
+    ) -> ScanVectorWidth {
+        match self {
+            ScanVectorWidth::Auto if hardware_vector_size_in_bytes >= 64 => ScanVectorWidth::Bytes64,
+            ScanVectorWidth::Auto if hardware_vector_size_in_bytes >= 32 => ScanVectorWidth::Bytes32,
+            ScanVectorWidth::Auto => ScanVectorWidth::Bytes16,
+            vector_width => *vector_width,
+        }
+    }
+
+    /// Determines whether scans may use vectors of the given size. Narrower vectors are always allowed, such that filters too
+    /// small for the chosen width are still vectorized where they can be. Auto allows every size until resolved for the hardware.
+    pub fn allows_vector_size(
+        &self,
+        vector_size_in_bytes: usize,
+    ) -> bool {
+        match self {
+            ScanVectorWidth::Auto => true,
+            ScanVectorWidth::Bytes16 => vector_size_in_bytes <= 16,
+            ScanVectorWidth::Bytes32 => vector_size_in_bytes <= 32,
+            ScanVectorWidth::Bytes64 => vector_size_in_bytes <= 64,
+        }
+    }
+}
+
+impl FromStr for ScanVectorWidth {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "auto" => Ok(ScanVectorWidth::Auto),
+            "16" | "bytes16" => Ok(ScanVectorWidth::Bytes16),
+            "32" | "bytes32" => Ok(ScanVectorWidth::Bytes32),
+            "64" | "bytes64" => Ok(ScanVectorWidth::Bytes64),
+            _ => Err(format!("Unknown vector width: {}", input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanVectorWidth;
+
+    #[test]
+    fn auto_resolves_to_the_hardware_width_and_fixed_widths_are_kept() {
+        assert_eq!(ScanVectorWidth::Auto.resolve_for_hardware(64), ScanVectorWidth::Bytes64);
+        assert_eq!(ScanVectorWidth::Auto.resolve_for_hardware(32), ScanVectorWidth::Bytes32);
+        assert_eq!(ScanVectorWidth::Auto.resolve_for_hardware(16), ScanVectorWidth::Bytes16);
+        assert_eq!(ScanVectorWidth::Auto.resolve_for_hardware(0), ScanVectorWidth::Bytes16);
+        assert_eq!(ScanVectorWidth::Bytes64.resolve_for_hardware(16), ScanVectorWidth::Bytes64);
+        assert_eq!(ScanVectorWidth::Bytes16.resolve_for_hardware(64), ScanVectorWidth::Bytes16);
+    }
+
+    #[test]
+    fn every_width_parses_to_itself() {
+        assert_eq!("auto".parse::<ScanVectorWidth>(), Ok(ScanVectorWidth::Auto));
+        assert_eq!("16".parse::<ScanVectorWidth>(), Ok(ScanVectorWidth::Bytes16));
+        assert_eq!("bytes32".parse::<ScanVectorWidth>(), Ok(ScanVectorWidth::Bytes32));
+        assert_eq!(" 64 ".parse::<ScanVectorWidth>(), Ok(ScanVectorWidth::Bytes64));
+        assert!("128".parse::<ScanVectorWidth>().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// How integer values behave when nudging them past the bounds of their data type.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ValueOverflowPolicy {
+    /// Values stop at the minimum or maximum of their data type.
+    #[default]
+    Saturate,
+    /// Values wrap around, ie nudging a u8 of 255 by +1 gives 0.
+    Wrap,
+}
+
+impl FromStr for ValueOverflowPolicy {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "saturate" | "clamp" => Ok(ValueOverflowPolicy::Saturate),
+            "wrap" | "wrapping" => Ok(ValueOverflowPolicy::Wrap),
+            _ => Err(format!("Unknown overflow policy: {}", input)),
+        }
+    }
+}
@@ -1,2 +1,7 @@
+pub mod scan_snapshot_file;
 pub mod snapshot;
 pub mod snapshot_region;
+pub mod snapshot_region_change;
+pub mod snapshot_region_first_scan_values;
+pub mod snapshot_relocation;
+pub mod snapshot_scan_history;
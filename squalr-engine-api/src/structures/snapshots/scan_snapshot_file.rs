@@ -0,0 +1,443 @@
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::memory_alignment::MemoryAlignment;
+use crate::structures::memory::normalized_module::NormalizedModule;
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use crate::structures::snapshots::snapshot::Snapshot;
+use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use serde::{Deserialize, Serialize};
+
+/// A module that saved values lie in, such that their addresses can be rebased onto wherever the module is loaded next.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanSnapshotFileModule {
+    pub module_name: String,
+    /// The size of the module when saved. A module of another size is most likely another build, whose layout cannot be trusted.
+    pub module_size: u64,
+}
+
+/// A saved scan result, located by its offset into the module it lies in, or by its absolute address if it lies in no module.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotFileEntry {
+    /// The index into the module layout of the module this value lies in, if any.
+    pub module_index: Option<usize>,
+    /// The offset from the base of the module, or the absolute address if the value lies in no module.
+    pub offset: u64,
+    pub value_bytes: Vec<u8>,
+}
+
+/// The scan results of a snapshot saved to disk along with their values, such that they can be compared against in another session,
+/// ie to find values that only change between play sessions. The process, module layout, and data type are saved with them, such
+/// that comparing against another process fails rather than comparing unrelated memory.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScanSnapshotFile {
+    pub format_version: u32,
+    pub process_name: String,
+    /// The modules that saved values lie in.
+    pub modules: Vec<ScanSnapshotFileModule>,
+    pub data_type_ref: DataTypeRef,
+    pub memory_alignment: MemoryAlignment,
+    pub unit_size_in_bytes: u64,
+    pub entries: Vec<ScanSnapshotFileEntry>,
+}
+
+impl ScanSnapshotFile {
+    pub const CURRENT_FORMAT_VERSION: u32 = 1;
+    pub const FILE_EXTENSION: &'static str = "snap";
+
+    /// The most results that can be saved. Every result is written out with its value, so results should be narrowed down first.
+    pub const MAX_ENTRY_COUNT: u64 = 1_000_000;
+
+    /// Loaded results closer together than this share a snapshot region, as long as they start within the same page.
+    const REGION_MERGE_GAP_BYTES: u64 = 256;
+    const PAGE_SIZE: u64 = 0x1000;
+
+    /// Captures every scan result of the snapshot along with its current value. Addresses within a module are saved relative to
+    /// it. Fails if there are no results, too many results, or results of more than one data type.
+    pub fn from_snapshot(
+        snapshot: &Snapshot,
+        process_name: &str,
+        modules: &[NormalizedModule],
+    ) -> Result<Self, String> {
+        let result_count = snapshot.get_number_of_results();
+
+        if result_count == 0 {
+            return Err("There are no scan results to save.".to_string());
+        }
+
+        if result_count > Self::MAX_ENTRY_COUNT {
+            return Err(format!(
+                "There are {} scan results, but at most {} can be saved. Narrow the results down before saving them.",
+                result_count,
+                Self::MAX_ENTRY_COUNT
+            ));
+        }
+
+        let mut file_module_indices: Vec<Option<usize>> = vec![None; modules.len()];
+        let mut file_modules = vec![];
+        let mut collection_layout: Option<(DataTypeRef, MemoryAlignment, u64)> = None;
+        let mut entries = Vec::with_capacity(result_count as usize);
+
+        for snapshot_region in snapshot.get_snapshot_regions() {
+            for snapshot_region_filter_collection in snapshot_region.get_scan_results().get_filter_collections() {
+                if snapshot_region_filter_collection.get_number_of_results() == 0 {
+                    continue;
+                }
+
+                let unit_size_in_bytes = snapshot_region_filter_collection.get_unit_size_in_bytes();
+                let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+                let layout = (
+                    snapshot_region_filter_collection.get_data_type_ref().clone(),
+                    memory_alignment,
+                    unit_size_in_bytes,
+                );
+
+                match &collection_layout {
+                    Some(collection_layout) if *collection_layout != layout => {
+                        return Err("Only scan results of a single data type and alignment can be saved.".to_string());
+                    }
+                    Some(_collection_layout) => {}
+                    None => collection_layout = Some(layout),
+                }
+
+                let alignment_in_bytes = (memory_alignment as u64).max(1);
+
+                for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                    let mut address = snapshot_region_filter.get_base_address();
+
+                    while address.saturating_add(unit_size_in_bytes) <= snapshot_region_filter.get_end_address() {
+                        let value_offset = address.saturating_sub(snapshot_region.get_base_address()) as usize;
+                        let value_bytes = snapshot_region
+                            .get_current_values()
+                            .get(value_offset..value_offset + unit_size_in_bytes as usize)
+                            .ok_or_else(|| "The values of the scan results have not been collected yet.".to_string())?
+                            .to_vec();
+                        let entry = match modules
+                            .iter()
+                            .position(|module| module.contains_address(address))
+                        {
+                            Some(process_module_index) => {
+                                let module = &modules[process_module_index];
+                                let module_index = *file_module_indices[process_module_index].get_or_insert_with(|| {
+                                    file_modules.push(ScanSnapshotFileModule {
+                                        module_name: module.get_module_name().to_string(),
+                                        module_size: module.get_region_size(),
+                                    });
+
+                                    file_modules.len() - 1
+                                });
+
+                                ScanSnapshotFileEntry {
+                                    module_index: Some(module_index),
+                                    offset: address - module.get_base_address(),
+                                    value_bytes,
+                                }
+                            }
+                            None => ScanSnapshotFileEntry {
+                                module_index: None,
+                                offset: address,
+                                value_bytes,
+                            },
+                        };
+
+                        entries.push(entry);
+                        address += alignment_in_bytes;
+                    }
+                }
+            }
+        }
+
+        let Some((data_type_ref, memory_alignment, unit_size_in_bytes)) = collection_layout else {
+            return Err("There are no scan results to save.".to_string());
+        };
+
+        Ok(Self {
+            format_version: Self::CURRENT_FORMAT_VERSION,
+            process_name: process_name.to_string(),
+            modules: file_modules,
+            data_type_ref,
+            memory_alignment,
+            unit_size_in_bytes,
+            entries,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        serde_json::to_vec(self).map_err(|error| format!("Failed to serialize the scan snapshot: {}", error))
+    }
+
+    /// Reads a saved snapshot, rejecting files written by a newer format version rather than guessing at their contents.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let scan_snapshot_file: Self = serde_json::from_slice(bytes).map_err(|error| format!("The file does not contain a valid scan snapshot: {}", error))?;
+
+        if scan_snapshot_file.format_version > Self::CURRENT_FORMAT_VERSION {
+            return Err(format!(
+                "The file was saved in scan snapshot format version {}, but only versions up to {} can be loaded.",
+                scan_snapshot_file.format_version,
+                Self::CURRENT_FORMAT_VERSION
+            ));
+        }
+
+        Ok(scan_snapshot_file)
+    }
+
+    /// Rebases the saved results onto the given process, laying them out as snapshot regions whose current values are the saved
+    /// values. Fails if the process name differs, or if a module that saved values lie in is not loaded or has changed size.
+    pub fn into_snapshot_regions(
+        self,
+        process_name: &str,
+        modules: &[NormalizedModule],
+    ) -> Result<Vec<SnapshotRegion>, String> {
+        if !self.process_name.eq_ignore_ascii_case(process_name) {
+            return Err(format!(
+                "The snapshot was saved from {}, but the opened process is {}.",
+                self.process_name, process_name
+            ));
+        }
+
+        let mut mismatched_modules = vec![];
+        let module_base_addresses: Vec<u64> = self
+            .modules
+            .iter()
+            .map(|file_module| {
+                match modules.iter().find(|module| {
+                    module
+                        .get_module_name()
+                        .eq_ignore_ascii_case(&file_module.module_name)
+                }) {
+                    Some(module) if module.get_region_size() == file_module.module_size => module.get_base_address(),
+                    Some(module) => {
+                        mismatched_modules.push(format!(
+                            "{} is {} bytes, but was {} bytes",
+                            file_module.module_name,
+                            module.get_region_size(),
+                            file_module.module_size
+                        ));
+                        0
+                    }
+                    None => {
+                        mismatched_modules.push(format!("{} is not loaded", file_module.module_name));
+                        0
+                    }
+                }
+            })
+            .collect();
+
+        if !mismatched_modules.is_empty() {
+            return Err(format!(
+                "The modules of the opened process do not match the snapshot: {}.",
+                mismatched_modules.join(", ")
+            ));
+        }
+
+        let data_type_ref = self.data_type_ref;
+        let memory_alignment = self.memory_alignment;
+        let unit_size_in_bytes = self.unit_size_in_bytes.max(1);
+        let mut addressed_values = Vec::with_capacity(self.entries.len());
+
+        for entry in self.entries {
+            let address = match entry.module_index {
+                Some(module_index) => match module_base_addresses.get(module_index) {
+                    Some(module_base_address) => module_base_address.wrapping_add(entry.offset),
+                    None => return Err("The scan snapshot refers to a module that is missing from its module layout.".to_string()),
+                },
+                None => entry.offset,
+            };
+
+            if entry.value_bytes.len() as u64 != unit_size_in_bytes {
+                return Err("The scan snapshot contains a value whose size does not match its data type.".to_string());
+            }
+
+            addressed_values.push((address, entry.value_bytes));
+        }
+
+        addressed_values.sort_by_key(|(address, _value_bytes)| *address);
+
+        // Filters span at least one alignment, as scans produce, such that each holds exactly one result.
+        let filter_size = unit_size_in_bytes.max(memory_alignment as u64);
+        let mut snapshot_regions = vec![];
+        let mut group_start_index = 0;
+
+        for index in 1..=addressed_values.len() {
+            let is_group_end = match addressed_values.get(index) {
+                Some((address, _value_bytes)) => {
+                    let group_base_address = addressed_values[group_start_index].0;
+                    let previous_end_address = addressed_values[index - 1].0.saturating_add(filter_size);
+
+                    address.saturating_sub(previous_end_address) > Self::REGION_MERGE_GAP_BYTES
+                        || address / Self::PAGE_SIZE != group_base_address / Self::PAGE_SIZE
+                }
+                None => true,
+            };
+
+            if is_group_end {
+                snapshot_regions.push(Self::create_snapshot_region(
+                    &addressed_values[group_start_index..index],
+                    filter_size,
+                    &data_type_ref,
+                    memory_alignment,
+                    unit_size_in_bytes,
+                ));
+                group_start_index = index;
+            }
+        }
+
+        Ok(snapshot_regions)
+    }
+
+    /// Creates a snapshot region holding a result at each of the given addresses, which must be sorted, with the given values.
+    fn create_snapshot_region(
+        addressed_values: &[(u64, Vec<u8>)],
+        filter_size: u64,
+        data_type_ref: &DataTypeRef,
+        memory_alignment: MemoryAlignment,
+        unit_size_in_bytes: u64,
+    ) -> SnapshotRegion {
+        let base_address = addressed_values
+            .first()
+            .map_or(0, |(address, _value_bytes)| *address);
+        let end_address = addressed_values
+            .iter()
+            .map(|(address, _value_bytes)| address.saturating_add(filter_size))
+            .max()
+            .unwrap_or(base_address);
+        let page_boundaries = (base_address / Self::PAGE_SIZE + 1..)
+            .map(|page_index| page_index * Self::PAGE_SIZE)
+            .take_while(|page_boundary| *page_boundary < end_address)
+            .collect();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(base_address, end_address - base_address), page_boundaries);
+        let mut current_values = vec![0u8; (end_address - base_address) as usize];
+
+        for (address, value_bytes) in addressed_values {
+            let value_offset = (address - base_address) as usize;
+
+            current_values[value_offset..value_offset + value_bytes.len()].copy_from_slice(value_bytes);
+        }
+
+        snapshot_region.current_values = current_values;
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![
+                addressed_values
+                    .iter()
+                    .map(|(address, _value_bytes)| SnapshotRegionFilter::new(*address, filter_size))
+                    .collect(),
+            ],
+            data_type_ref.clone(),
+            memory_alignment,
+            unit_size_in_bytes,
+        )]));
+
+        snapshot_region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanSnapshotFile;
+    use crate::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::memory_alignment::MemoryAlignment;
+    use crate::structures::memory::normalized_module::NormalizedModule;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+    use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+    use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+    use crate::structures::snapshots::snapshot::Snapshot;
+    use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+
+    /// Creates a region holding an i32 result with the given value at each of the given addresses, which must be sorted.
+    fn create_snapshot_region(results: &[(u64, i32)]) -> SnapshotRegion {
+        let base_address = results[0].0;
+        let end_address = results[results.len() - 1].0 + 4;
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(base_address, end_address - base_address), vec![]);
+        let mut current_values = vec![0u8; (end_address - base_address) as usize];
+
+        for (address, value) in results {
+            let value_offset = (address - base_address) as usize;
+
+            current_values[value_offset..value_offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+
+        snapshot_region.current_values = current_values;
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![
+                results
+                    .iter()
+                    .map(|(address, _value)| SnapshotRegionFilter::new(*address, 4))
+                    .collect(),
+            ],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        )]));
+
+        snapshot_region
+    }
+
+    /// Creates a snapshot with a result in the game module, and two nearby results outside of any module.
+    fn create_scan_snapshot_file() -> ScanSnapshotFile {
+        let mut snapshot = Snapshot::new();
+
+        snapshot.set_snapshot_regions(vec![
+            create_snapshot_region(&[(0x40_0010, 100)]),
+            create_snapshot_region(&[(0x9000_0000, 7), (0x9000_0008, -1)]),
+        ]);
+
+        ScanSnapshotFile::from_snapshot(&snapshot, "game.exe", &[NormalizedModule::new("game.exe", 0x40_0000, 0x2000)]).unwrap()
+    }
+
+    #[test]
+    fn saved_results_load_back_rebased_onto_the_current_modules() {
+        let bytes = create_scan_snapshot_file().to_bytes().unwrap();
+        let scan_snapshot_file = ScanSnapshotFile::from_bytes(&bytes).unwrap();
+
+        assert_eq!(scan_snapshot_file.entries.len(), 3);
+        assert_eq!(scan_snapshot_file.modules.len(), 1);
+        assert_eq!(scan_snapshot_file.entries[0].module_index, Some(0));
+        assert_eq!(scan_snapshot_file.entries[0].offset, 0x10);
+
+        let snapshot_regions = scan_snapshot_file
+            .into_snapshot_regions("GAME.EXE", &[NormalizedModule::new("game.exe", 0x50_0000, 0x2000)])
+            .unwrap();
+
+        // Nearby results within a page share a region, with the saved values as their current values.
+        assert_eq!(snapshot_regions.len(), 2);
+        assert_eq!(snapshot_regions[0].get_base_address(), 0x50_0010);
+        assert_eq!(snapshot_regions[0].get_current_values(), &100i32.to_le_bytes().to_vec());
+        assert_eq!(snapshot_regions[1].get_base_address(), 0x9000_0000);
+        assert_eq!(snapshot_regions[1].get_region_size(), 12);
+        assert_eq!(snapshot_regions[1].get_scan_results().get_number_of_results(), 2);
+        assert_eq!(snapshot_regions[1].get_current_values()[8..12], (-1i32).to_le_bytes());
+    }
+
+    #[test]
+    fn snapshots_from_another_process_or_module_layout_are_rejected() {
+        let process_error = create_scan_snapshot_file()
+            .into_snapshot_regions("other.exe", &[NormalizedModule::new("game.exe", 0x40_0000, 0x2000)])
+            .err()
+            .unwrap_or_default();
+
+        assert!(process_error.contains("other.exe"));
+
+        let module_size_error = create_scan_snapshot_file()
+            .into_snapshot_regions("game.exe", &[NormalizedModule::new("game.exe", 0x40_0000, 0x3000)])
+            .err()
+            .unwrap_or_default();
+
+        assert!(module_size_error.contains("game.exe"));
+
+        assert!(
+            create_scan_snapshot_file()
+                .into_snapshot_regions("game.exe", &[])
+                .is_err()
+        );
+
+        let mut newer_file = create_scan_snapshot_file();
+
+        newer_file.format_version = ScanSnapshotFile::CURRENT_FORMAT_VERSION + 1;
+
+        assert!(ScanSnapshotFile::from_bytes(&newer_file.to_bytes().unwrap()).is_err());
+        assert!(ScanSnapshotFile::from_bytes(b"not a scan snapshot").is_err());
+    }
+}
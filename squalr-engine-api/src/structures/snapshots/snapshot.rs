@@ -1,9 +1,49 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::memory::normalized_region::NormalizedRegion;
 use crate::structures::scan_results::scan_result_valued::ScanResultValued;
+use crate::structures::scanning::statistics::scan_statistics::ScanStatistics;
 use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use crate::structures::snapshots::snapshot_region_change::{SnapshotRegionChange, SnapshotRegionChangeKind};
+use crate::structures::snapshots::snapshot_relocation::SnapshotRelocation;
+use crate::structures::snapshots::snapshot_scan_history::{SnapshotScanHistoryEntry, SnapshotScanHistoryItem};
 use std::cmp;
+use std::collections::{HashMap, VecDeque};
 
 pub struct Snapshot {
     snapshot_regions: Vec<SnapshotRegion>,
+
+    /// The number of bytes read from the process the last time values were collected for this snapshot.
+    last_read_byte_count: u64,
+
+    /// The statistics of the most recent scan over this snapshot, if it has been scanned since it was created.
+    last_scan_statistics: Option<ScanStatistics>,
+
+    /// Data types that individual scan results have been reinterpreted as, by global scan result index. A filter collection holds
+    /// a single data type, so scans cannot compare these results as their new type. These are cleared whenever results change.
+    data_type_overrides: HashMap<u64, DataTypeRef>,
+
+    /// Addresses that individual scan results have been moved to, by global scan result index. Like data type overrides, these
+    /// only change how results are read back, and are cleared whenever results change.
+    address_overrides: HashMap<u64, u64>,
+
+    /// Identifies the current set of scan results. This only ever increases, such that responses describing older results can be
+    /// told apart from responses describing the latest ones.
+    scan_generation: u64,
+
+    /// Ranges whose backing memory changed since they were snapshotted, sorted by base address. These stay marked until the
+    /// affected ranges are revalidated or a new scan is started, even if the layout later appears to recover.
+    stale_region_changes: Vec<SnapshotRegionChange>,
+
+    /// How many times the value of each result repeats at a fixed stride, by address, if the results were found by a stride scan.
+    /// Keyed by address rather than index, as these survive revalidation, but are cleared whenever a scan replaces the results.
+    stride_repeat_counts: HashMap<u64, u64>,
+
+    /// The number of scans performed over this snapshot since it was created, or zero if it has not been scanned.
+    scan_number: u64,
+
+    /// The results of the most recent earlier scans, oldest first, such that this snapshot can be reverted to them.
+    scan_history: VecDeque<SnapshotScanHistoryEntry>,
 }
 
 /// Represents a snapshot of memory in an external process that contains current and previous values of memory pages.
@@ -11,9 +51,23 @@ impl Snapshot {
     /// Creates a new snapshot from the given collection of snapshot regions.
     /// This will automatically sort and remove invalid regions.
     pub fn new() -> Self {
-        Self { snapshot_regions: vec![] }
+        Self {
+            snapshot_regions: vec![],
+            last_read_byte_count: 0,
+            last_scan_statistics: None,
+            data_type_overrides: HashMap::new(),
+            address_overrides: HashMap::new(),
+            scan_generation: 0,
+            stale_region_changes: vec![],
+            stride_repeat_counts: HashMap::new(),
+            scan_number: 0,
+            scan_history: VecDeque::new(),
+        }
     }
 
+    /// The number of earlier scans retained in the scan history. Each retains the values under its results, so this is kept small.
+    const MAX_SCAN_HISTORY_ENTRY_COUNT: usize = 5;
+
     /// Assigns new snapshot regions to this snapshot.
     pub fn set_snapshot_regions(
         &mut self,
@@ -65,6 +119,304 @@ impl Snapshot {
             .sum()
     }
 
+    /// Gets the number of bytes read from the process the last time values were collected. After a scan has filtered the snapshot,
+    /// only the memory under the surviving filters is read, so this may be far smaller than the byte count of the snapshot.
+    pub fn get_last_read_byte_count(&self) -> u64 {
+        self.last_read_byte_count
+    }
+
+    pub fn set_last_read_byte_count(
+        &mut self,
+        last_read_byte_count: u64,
+    ) {
+        self.last_read_byte_count = last_read_byte_count;
+    }
+
+    pub fn get_last_scan_statistics(&self) -> Option<&ScanStatistics> {
+        self.last_scan_statistics.as_ref()
+    }
+
+    pub fn set_last_scan_statistics(
+        &mut self,
+        last_scan_statistics: Option<ScanStatistics>,
+    ) {
+        self.last_scan_statistics = last_scan_statistics;
+    }
+
+    pub fn get_scan_generation(&self) -> u64 {
+        self.scan_generation
+    }
+
+    /// Marks the scan results as replaced. Must be called whenever a scan changes which results exist, alongside clearing overrides.
+    pub fn advance_scan_generation(&mut self) -> u64 {
+        self.scan_generation = self.scan_generation.wrapping_add(1);
+        self.scan_generation
+    }
+
+    /// Gets the ranges under the scan results of this snapshot, split on page boundaries. Regions that have not been filtered by a
+    /// scan yet are included in full.
+    pub fn get_scanned_ranges(&self) -> Vec<NormalizedRegion> {
+        self.snapshot_regions
+            .iter()
+            .flat_map(|snapshot_region| snapshot_region.get_filter_read_ranges(0))
+            .collect()
+    }
+
+    /// Gets the ranges whose backing memory changed since they were snapshotted.
+    pub fn get_stale_region_changes(&self) -> &Vec<SnapshotRegionChange> {
+        &self.stale_region_changes
+    }
+
+    /// Marks the given ranges as stale, ignoring any already covered by an existing change. Returns the number of ranges newly marked.
+    pub fn mark_stale_regions(
+        &mut self,
+        region_changes: Vec<SnapshotRegionChange>,
+    ) -> u64 {
+        let mut newly_marked_count = 0;
+
+        for region_change in region_changes {
+            let region = region_change.get_region();
+            let is_already_marked = self.stale_region_changes.iter().any(|stale_region_change| {
+                let stale_region = stale_region_change.get_region();
+
+                stale_region.get_base_address() <= region.get_base_address() && stale_region.get_end_address() >= region.get_end_address()
+            });
+
+            if !is_already_marked {
+                self.stale_region_changes.push(region_change);
+                newly_marked_count += 1;
+            }
+        }
+
+        self.stale_region_changes
+            .sort_by_key(|stale_region_change| stale_region_change.get_region().get_base_address());
+
+        newly_marked_count
+    }
+
+    /// Removes and returns every stale range, ie once they are about to be revalidated.
+    pub fn take_stale_region_changes(&mut self) -> Vec<SnapshotRegionChange> {
+        std::mem::take(&mut self.stale_region_changes)
+    }
+
+    /// Gets how the memory backing the given address changed since it was snapshotted, if it has.
+    pub fn get_stale_change_kind(
+        &self,
+        address: u64,
+    ) -> Option<SnapshotRegionChangeKind> {
+        // Changes are sorted by base address, so only those starting at or before the address can contain it.
+        let candidate_count = self
+            .stale_region_changes
+            .partition_point(|stale_region_change| stale_region_change.get_region().get_base_address() <= address);
+
+        self.stale_region_changes[..candidate_count]
+            .iter()
+            .rev()
+            .find(|stale_region_change| address < stale_region_change.get_region().get_end_address())
+            .map(|stale_region_change| stale_region_change.get_change_kind())
+    }
+
+    /// Follows the results of a process that restarted into the new process. Regions within a module that was loaded again are
+    /// moved to its new base address, while every other region is marked stale, as its address no longer refers to the same
+    /// memory. Stale ranges of the exited process, overrides, and the scan history are discarded, as they describe the old layout.
+    /// Returns the number of regions that were moved, and the number that were marked stale.
+    pub fn relocate_for_restarted_process(
+        &mut self,
+        snapshot_relocations: &[SnapshotRelocation],
+    ) -> (u64, u64) {
+        let mut stale_region_changes = vec![];
+        let mut relocated_stride_repeat_counts = HashMap::new();
+        let mut relocated_region_count = 0;
+
+        for snapshot_region in &mut self.snapshot_regions {
+            let base_address = snapshot_region.get_base_address();
+            let end_address = snapshot_region.get_end_address();
+            let snapshot_relocation = snapshot_relocations
+                .iter()
+                .find(|snapshot_relocation| snapshot_relocation.contains_range(base_address, end_address));
+
+            match snapshot_relocation {
+                Some(snapshot_relocation) => {
+                    let address_delta = snapshot_relocation.get_address_delta();
+
+                    for (address, repeat_count) in &self.stride_repeat_counts {
+                        if *address >= base_address && *address < end_address {
+                            relocated_stride_repeat_counts.insert(address.wrapping_add(address_delta), *repeat_count);
+                        }
+                    }
+
+                    snapshot_region.relocate(address_delta);
+                    relocated_region_count += 1;
+                }
+                None => stale_region_changes.push(SnapshotRegionChange::new(
+                    NormalizedRegion::new(base_address, snapshot_region.get_region_size()),
+                    SnapshotRegionChangeKind::ProcessRestarted,
+                )),
+            }
+        }
+
+        let stale_region_count = stale_region_changes.len() as u64;
+
+        // Moved regions may now sort differently, which changes result indicies.
+        self.sort_regions();
+        self.clear_data_type_overrides();
+        self.stride_repeat_counts = relocated_stride_repeat_counts;
+        self.stale_region_changes.clear();
+        self.mark_stale_regions(stale_region_changes);
+        self.scan_history.clear();
+        self.advance_scan_generation();
+
+        (relocated_region_count, stale_region_count)
+    }
+
+    /// Reinterprets the scan result at the specified index as the given data type, until results next change. Returns false if no
+    /// scan result exists at the index.
+    pub fn set_scan_result_data_type(
+        &mut self,
+        global_scan_result_index: u64,
+        data_type_ref: DataTypeRef,
+    ) -> bool {
+        self.data_type_overrides.remove(&global_scan_result_index);
+
+        let scan_result = match self.get_scan_result(global_scan_result_index) {
+            Some(scan_result) => scan_result,
+            None => return false,
+        };
+
+        if scan_result.get_data_type_ref() != &data_type_ref {
+            self.data_type_overrides
+                .insert(global_scan_result_index, data_type_ref);
+        }
+
+        true
+    }
+
+    /// Moves the scan result at the specified index to the given address, until results next change. The result keeps its data
+    /// type, and reads its values at the new address. Returns false if no scan result exists at the index.
+    pub fn set_scan_result_address(
+        &mut self,
+        global_scan_result_index: u64,
+        address: u64,
+    ) -> bool {
+        self.address_overrides.remove(&global_scan_result_index);
+
+        let scan_result = match self.get_scan_result(global_scan_result_index) {
+            Some(scan_result) => scan_result,
+            None => return false,
+        };
+
+        if scan_result.get_address() != address {
+            self.address_overrides.insert(global_scan_result_index, address);
+        }
+
+        true
+    }
+
+    /// Discards every data type and address that scan results were reinterpreted as or moved to. Must be called whenever scan
+    /// result indicies change.
+    pub fn clear_data_type_overrides(&mut self) {
+        self.data_type_overrides.clear();
+        self.address_overrides.clear();
+    }
+
+    /// Attaches the repeat counts found by a stride scan to the results at their addresses, replacing any previous counts.
+    pub fn set_stride_repeat_counts(
+        &mut self,
+        stride_repeat_counts: HashMap<u64, u64>,
+    ) {
+        self.stride_repeat_counts = stride_repeat_counts;
+    }
+
+    /// Discards the repeat counts of the last stride scan. Must be called whenever any other scan replaces the results.
+    pub fn clear_stride_repeat_counts(&mut self) {
+        self.stride_repeat_counts.clear();
+    }
+
+    /// Retains the current results in the scan history, if this snapshot has been scanned, and counts the scan about to replace them.
+    /// Must be called before a scan replaces the results. The oldest entry is dropped once the history is full.
+    pub fn record_scan_history(&mut self) {
+        if self.scan_number > 0 {
+            let scan_history_item = SnapshotScanHistoryItem::new(self.scan_number, self.get_number_of_results());
+            let is_first_scan = self.scan_number == 1;
+            let snapshot_regions = self
+                .snapshot_regions
+                .iter()
+                .map(|snapshot_region| snapshot_region.clone_for_scan_history(is_first_scan))
+                .collect();
+
+            self.scan_history.push_back(SnapshotScanHistoryEntry::new(
+                scan_history_item,
+                snapshot_regions,
+                self.last_scan_statistics,
+                self.stride_repeat_counts.clone(),
+            ));
+
+            while self.scan_history.len() > Self::MAX_SCAN_HISTORY_ENTRY_COUNT {
+                self.scan_history.pop_front();
+            }
+        }
+
+        self.scan_number = self.scan_number.saturating_add(1);
+    }
+
+    /// Discards the scan history and restarts the scan count. Must be called whenever a new scan is started.
+    pub fn clear_scan_history(&mut self) {
+        self.scan_history.clear();
+        self.scan_number = 0;
+    }
+
+    /// Gets the scans that this snapshot can be reverted to, oldest first, followed by the current scan if there is one.
+    pub fn get_scan_history_items(&self) -> Vec<SnapshotScanHistoryItem> {
+        let mut scan_history_items: Vec<SnapshotScanHistoryItem> = self
+            .scan_history
+            .iter()
+            .map(|scan_history_entry| *scan_history_entry.get_scan_history_item())
+            .collect();
+
+        if self.scan_number > 0 {
+            scan_history_items.push(SnapshotScanHistoryItem::new(self.scan_number, self.get_number_of_results()));
+        }
+
+        scan_history_items
+    }
+
+    /// Restores the results of the given scan from the scan history, discarding the results of every scan after it. The values of
+    /// the restored results are those read by that scan, and serve as both their current and previous values until the next scan.
+    /// Returns false if the scan is not in the history.
+    pub fn revert_to_scan(
+        &mut self,
+        scan_number: u64,
+    ) -> bool {
+        let Some(entry_index) = self
+            .scan_history
+            .iter()
+            .position(|scan_history_entry| scan_history_entry.get_scan_history_item().get_scan_number() == scan_number)
+        else {
+            return false;
+        };
+
+        self.scan_history.truncate(entry_index + 1);
+
+        let Some(scan_history_entry) = self.scan_history.pop_back() else {
+            return false;
+        };
+        let (snapshot_regions, last_scan_statistics, stride_repeat_counts) = scan_history_entry.into_parts();
+
+        self.set_snapshot_regions(
+            snapshot_regions
+                .into_iter()
+                .map(SnapshotRegion::restore_from_scan_history)
+                .collect(),
+        );
+        self.last_scan_statistics = last_scan_statistics;
+        self.stride_repeat_counts = stride_repeat_counts;
+        self.scan_number = scan_number;
+        self.clear_data_type_overrides();
+        self.advance_scan_generation();
+
+        true
+    }
+
     /// Seeks to the scan result at the specified index. First this performs a linear scan to locate the snapshot region
     /// containing the index, followed by a binary search to find the exact filter, and finally the scan result.
     pub fn get_scan_result(
@@ -78,7 +430,29 @@ impl Snapshot {
             let number_of_region_results = snapshot_region_scan_results.get_number_of_results();
 
             if local_scan_result_index < number_of_region_results {
-                return snapshot_region_scan_results.get_scan_result(snapshot_region, global_scan_result_index, local_scan_result_index);
+                let scan_result = snapshot_region_scan_results.get_scan_result(snapshot_region, global_scan_result_index, local_scan_result_index)?;
+                let data_type_override = self.data_type_overrides.get(&global_scan_result_index);
+                let address_override = self.address_overrides.get(&global_scan_result_index);
+                let mut scan_result = match (data_type_override, address_override) {
+                    (None, None) => scan_result,
+                    (data_type_override, address_override) => {
+                        let address = address_override.copied().unwrap_or(scan_result.get_address());
+                        let data_type_ref = data_type_override
+                            .unwrap_or(scan_result.get_data_type_ref())
+                            .clone();
+
+                        self.reinterpret_scan_result(scan_result, address, &data_type_ref)
+                    }
+                };
+
+                scan_result.set_stale_change_kind(self.get_stale_change_kind(scan_result.get_address()));
+                scan_result.set_stride_repeat_count(
+                    self.stride_repeat_counts
+                        .get(&scan_result.get_address())
+                        .copied(),
+                );
+
+                return Some(scan_result);
             }
 
             local_scan_result_index = local_scan_result_index.saturating_sub(number_of_region_results);
@@ -94,4 +468,360 @@ impl Snapshot {
             .map(|snapshot_region| snapshot_region.get_scan_results().get_number_of_results())
             .sum()
     }
+
+    /// Rebuilds a scan result at another address or as another data type. Values are taken from the snapshot region holding the
+    /// address where the value still fits inside it. Otherwise the current value is left as a default of the data type, which
+    /// callers fill in by reading live memory.
+    fn reinterpret_scan_result(
+        &self,
+        scan_result: ScanResultValued,
+        address: u64,
+        data_type_ref: &DataTypeRef,
+    ) -> ScanResultValued {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let snapshot_region = self
+            .snapshot_regions
+            .iter()
+            .find(|snapshot_region| snapshot_region.get_base_address() <= address && address < snapshot_region.get_end_address());
+        let current_value = snapshot_region
+            .and_then(|snapshot_region| snapshot_region.get_current_value(address, data_type_ref))
+            .or_else(|| symbol_registry.get_default_value(data_type_ref));
+        let previous_value = snapshot_region.and_then(|snapshot_region| snapshot_region.get_previous_value(address, data_type_ref));
+        let first_scan_value = snapshot_region.and_then(|snapshot_region| snapshot_region.get_first_scan_value(address, data_type_ref));
+        let anonymize = |data_value: &Option<_>| {
+            data_value
+                .as_ref()
+                .and_then(|data_value| {
+                    symbol_registry
+                        .anonymize_value_to_supported_formats(data_value)
+                        .ok()
+                })
+                .unwrap_or_default()
+        };
+        let current_display_values = anonymize(&current_value);
+        let previous_display_values = anonymize(&previous_value);
+        let first_scan_display_values = anonymize(&first_scan_value);
+        let mut reinterpreted_scan_result = ScanResultValued::new(
+            address,
+            data_type_ref.clone(),
+            symbol_registry.get_icon_id(data_type_ref),
+            current_value,
+            current_display_values,
+            previous_value,
+            previous_display_values,
+            scan_result.get_base_result().get_scan_result_ref().clone(),
+        );
+
+        reinterpreted_scan_result.set_first_scan_value(first_scan_value, first_scan_display_values);
+
+        if scan_result.get_data_type_ref() != data_type_ref {
+            reinterpreted_scan_result.set_original_data_type_ref(Some(scan_result.get_data_type_ref().clone()));
+        }
+
+        reinterpreted_scan_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Snapshot;
+    use crate::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+    use crate::structures::data_types::built_in_types::f64::data_type_f64::DataTypeF64;
+    use crate::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::memory_alignment::MemoryAlignment;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+    use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+    use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+    use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+    use crate::structures::snapshots::snapshot_region_change::{SnapshotRegionChange, SnapshotRegionChangeKind};
+    use crate::structures::snapshots::snapshot_relocation::SnapshotRelocation;
+    use crate::structures::snapshots::snapshot_scan_history::SnapshotScanHistoryItem;
+
+    /// Creates a snapshot of two i32 results, where the first holds the bits of 1.5f32 and the second ends the region.
+    fn create_snapshot() -> Snapshot {
+        let bytes = [1.5f32.to_le_bytes(), 7i32.to_le_bytes()].concat();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, bytes.len() as u64), vec![]);
+        let mut snapshot = Snapshot::new();
+
+        snapshot_region.current_values = bytes.clone();
+        snapshot_region.previous_values = bytes;
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(0x1000, 8)]],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        )]));
+        snapshot.set_snapshot_regions(vec![snapshot_region]);
+
+        snapshot
+    }
+
+    #[test]
+    fn reinterpreted_results_read_as_their_new_data_type_until_cleared() {
+        let mut snapshot = create_snapshot();
+
+        assert!(snapshot.set_scan_result_data_type(0, DataTypeRef::new(DataTypeF32::DATA_TYPE_ID)));
+
+        let scan_result = snapshot.get_scan_result(0).unwrap();
+
+        assert_eq!(scan_result.get_data_type_ref().get_data_type_id(), DataTypeF32::DATA_TYPE_ID);
+        assert_eq!(
+            scan_result.get_base_result().get_original_data_type_ref(),
+            Some(&DataTypeRef::new(DataTypeI32::DATA_TYPE_ID))
+        );
+        assert_eq!(scan_result.get_current_value().as_ref().unwrap().get_value_bytes(), &1.5f32.to_le_bytes().to_vec());
+        assert!(snapshot.get_scan_result(1).unwrap().get_base_result().get_original_data_type_ref().is_none());
+
+        snapshot.clear_data_type_overrides();
+
+        assert_eq!(snapshot.get_scan_result(0).unwrap().get_data_type_ref().get_data_type_id(), DataTypeI32::DATA_TYPE_ID);
+    }
+
+    #[test]
+    fn wider_types_past_the_region_end_are_left_for_a_live_read() {
+        let mut snapshot = create_snapshot();
+
+        assert!(snapshot.set_scan_result_data_type(1, DataTypeRef::new(DataTypeF64::DATA_TYPE_ID)));
+        assert!(!snapshot.set_scan_result_data_type(2, DataTypeRef::new(DataTypeF64::DATA_TYPE_ID)));
+
+        let scan_result = snapshot.get_scan_result(1).unwrap();
+
+        // The snapshot holds only four bytes at this address, so the value is a blank of the new width and has no previous value.
+        assert_eq!(scan_result.get_current_value().as_ref().unwrap().get_size_in_bytes(), 8);
+        assert!(scan_result.get_previous_value().is_none());
+    }
+
+    #[test]
+    fn reinterpreting_as_the_scanned_type_removes_the_conversion() {
+        let mut snapshot = create_snapshot();
+
+        snapshot.set_scan_result_data_type(0, DataTypeRef::new(DataTypeF32::DATA_TYPE_ID));
+        snapshot.set_scan_result_data_type(0, DataTypeRef::new(DataTypeI32::DATA_TYPE_ID));
+
+        assert!(snapshot.get_scan_result(0).unwrap().get_base_result().get_original_data_type_ref().is_none());
+    }
+
+    #[test]
+    fn moved_results_read_their_values_at_the_new_address_until_cleared() {
+        let mut snapshot = create_snapshot();
+
+        assert!(snapshot.set_scan_result_address(0, 0x1004));
+        assert!(snapshot.set_scan_result_address(1, 0x9000));
+        assert!(!snapshot.set_scan_result_address(2, 0x1000));
+
+        let moved_scan_result = snapshot.get_scan_result(0).unwrap();
+
+        assert_eq!(moved_scan_result.get_address(), 0x1004);
+        assert_eq!(moved_scan_result.get_data_type_ref().get_data_type_id(), DataTypeI32::DATA_TYPE_ID);
+        assert!(
+            moved_scan_result
+                .get_base_result()
+                .get_original_data_type_ref()
+                .is_none()
+        );
+        assert_eq!(
+            moved_scan_result
+                .get_current_value()
+                .as_ref()
+                .unwrap()
+                .get_value_bytes(),
+            &7i32.to_le_bytes().to_vec()
+        );
+
+        // Addresses outside of the snapshot are left for a live read.
+        let unsnapshotted_scan_result = snapshot.get_scan_result(1).unwrap();
+
+        assert_eq!(unsnapshotted_scan_result.get_address(), 0x9000);
+        assert_eq!(
+            unsnapshotted_scan_result
+                .get_current_value()
+                .as_ref()
+                .unwrap()
+                .get_size_in_bytes(),
+            4
+        );
+        assert!(unsnapshotted_scan_result.get_previous_value().is_none());
+
+        // Moving a result back to its scanned address removes the override.
+        snapshot.set_scan_result_address(1, 0x1004);
+        snapshot.clear_data_type_overrides();
+
+        assert_eq!(snapshot.get_scan_result(0).unwrap().get_address(), 0x1000);
+        assert_eq!(snapshot.get_scan_result(1).unwrap().get_address(), 0x1004);
+    }
+
+    #[test]
+    fn results_in_stale_ranges_are_marked_until_taken() {
+        let mut snapshot = create_snapshot();
+
+        assert_eq!(
+            snapshot.mark_stale_regions(vec![SnapshotRegionChange::new(
+                NormalizedRegion::new(0x1004, 0x1000),
+                SnapshotRegionChangeKind::Shrunk,
+            )]),
+            1
+        );
+        assert_eq!(
+            snapshot.mark_stale_regions(vec![SnapshotRegionChange::new(
+                NormalizedRegion::new(0x1004, 0x10),
+                SnapshotRegionChangeKind::Shrunk,
+            )]),
+            0
+        );
+        assert!(!snapshot.get_scan_result(0).unwrap().get_base_result().is_stale());
+        assert_eq!(
+            snapshot.get_scan_result(1).unwrap().get_base_result().get_stale_change_kind(),
+            Some(SnapshotRegionChangeKind::Shrunk)
+        );
+
+        snapshot.take_stale_region_changes();
+
+        assert!(!snapshot.get_scan_result(1).unwrap().get_base_result().is_stale());
+    }
+
+    #[test]
+    fn restarted_processes_move_module_results_and_mark_the_rest_stale() {
+        let mut snapshot = create_snapshot();
+        let mut heap_region = SnapshotRegion::new(NormalizedRegion::new(0x9000, 4), vec![]);
+
+        heap_region.current_values = 3i32.to_le_bytes().to_vec();
+        heap_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(0x9000, 4)]],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        )]));
+        let module_region = snapshot.get_snapshot_regions_mut().remove(0);
+
+        snapshot.set_snapshot_regions(vec![module_region, heap_region]);
+        snapshot.set_stride_repeat_counts([(0x1004, 12)].into_iter().collect());
+
+        let scan_generation = snapshot.get_scan_generation();
+
+        // The module holding the first region was loaded above the heap region, so the results also swap places.
+        assert_eq!(
+            snapshot.relocate_for_restarted_process(&[SnapshotRelocation::new(0x1000, 0x1000, 0xA000)]),
+            (1, 1)
+        );
+        assert!(snapshot.get_scan_generation() > scan_generation);
+
+        let heap_scan_result = snapshot.get_scan_result(0).unwrap();
+
+        assert_eq!(heap_scan_result.get_address(), 0x9000);
+        assert_eq!(
+            heap_scan_result.get_base_result().get_stale_change_kind(),
+            Some(SnapshotRegionChangeKind::ProcessRestarted)
+        );
+
+        let relocated_scan_result = snapshot.get_scan_result(2).unwrap();
+
+        assert_eq!(relocated_scan_result.get_address(), 0xA004);
+        assert!(!relocated_scan_result.get_base_result().is_stale());
+        assert_eq!(relocated_scan_result.get_base_result().get_stride_repeat_count(), Some(12));
+        assert_eq!(
+            relocated_scan_result.get_current_value().as_ref().unwrap().get_value_bytes(),
+            &7i32.to_le_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn stride_repeat_counts_are_attached_by_address_until_cleared() {
+        let mut snapshot = create_snapshot();
+
+        snapshot.set_stride_repeat_counts([(0x1004, 12)].into_iter().collect());
+
+        assert_eq!(
+            snapshot
+                .get_scan_result(0)
+                .unwrap()
+                .get_base_result()
+                .get_stride_repeat_count(),
+            None
+        );
+        assert_eq!(
+            snapshot
+                .get_scan_result(1)
+                .unwrap()
+                .get_base_result()
+                .get_stride_repeat_count(),
+            Some(12)
+        );
+
+        snapshot.clear_stride_repeat_counts();
+
+        assert_eq!(snapshot.get_scan_result(1).unwrap().get_base_result().get_stride_repeat_count(), None);
+    }
+
+    #[test]
+    fn reverting_restores_earlier_results_and_drops_later_scans() {
+        let mut snapshot = create_snapshot();
+        let scan_numbers = |snapshot: &Snapshot| {
+            snapshot
+                .get_scan_history_items()
+                .iter()
+                .map(SnapshotScanHistoryItem::get_scan_number)
+                .collect::<Vec<u64>>()
+        };
+
+        // The first scan has no earlier results to retain.
+        snapshot.record_scan_history();
+
+        assert_eq!(snapshot.get_scan_history_items(), vec![SnapshotScanHistoryItem::new(1, 2)]);
+
+        // A second scan narrows the results down to the second value.
+        snapshot.record_scan_history();
+        snapshot.get_snapshot_regions_mut()[0].set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(0x1004, 4)]],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        )]));
+        snapshot.set_stride_repeat_counts([(0x1004, 12)].into_iter().collect());
+
+        assert_eq!(
+            snapshot.get_scan_history_items(),
+            vec![
+                SnapshotScanHistoryItem::new(1, 2),
+                SnapshotScanHistoryItem::new(2, 1)
+            ]
+        );
+        assert!(snapshot.revert_to_scan(1));
+        assert_eq!(snapshot.get_number_of_results(), 2);
+        assert_eq!(snapshot.get_scan_history_items(), vec![SnapshotScanHistoryItem::new(1, 2)]);
+        assert!(!snapshot.revert_to_scan(2));
+
+        let scan_result = snapshot.get_scan_result(0).unwrap();
+
+        assert_eq!(
+            scan_result
+                .get_current_value()
+                .as_ref()
+                .unwrap()
+                .get_value_bytes(),
+            &1.5f32.to_le_bytes().to_vec()
+        );
+        assert_eq!(scan_result.get_previous_value(), scan_result.get_current_value());
+        assert_eq!(
+            snapshot
+                .get_scan_result(1)
+                .unwrap()
+                .get_base_result()
+                .get_stride_repeat_count(),
+            None
+        );
+
+        // Only the most recent earlier scans are retained.
+        for _ in 0..7 {
+            snapshot.record_scan_history();
+        }
+
+        assert_eq!(scan_numbers(&snapshot), vec![3, 4, 5, 6, 7, 8]);
+        assert!(!snapshot.revert_to_scan(1));
+
+        snapshot.clear_scan_history();
+
+        assert!(snapshot.get_scan_history_items().is_empty());
+    }
 }
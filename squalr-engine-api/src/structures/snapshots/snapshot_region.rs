@@ -6,6 +6,8 @@ use crate::structures::memory::normalized_region::NormalizedRegion;
 use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
 use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
 use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use crate::structures::snapshots::snapshot_region_first_scan_values::SnapshotRegionFirstScanValues;
+use crate::structures::snapshots::snapshot_scan_history::SnapshotScanHistoryRegion;
 use std::collections::HashSet;
 
 /// Defines a contiguous region of memory within a snapshot.
@@ -28,6 +30,9 @@ pub struct SnapshotRegion {
 
     /// The current scan results on this snapshot region.
     scan_results: SnapshotRegionScanResults,
+
+    /// The values under the filters when this region was first scanned, if they have been captured.
+    first_scan_values: Option<SnapshotRegionFirstScanValues>,
 }
 
 impl SnapshotRegion {
@@ -42,9 +47,13 @@ impl SnapshotRegion {
             page_boundaries,
             page_boundary_tombstones: HashSet::new(),
             scan_results: SnapshotRegionScanResults::new(vec![]),
+            first_scan_values: None,
         }
     }
 
+    /// Filters separated by gaps up to this size share a run of first scan values, as each run costs about this much to track.
+    const FIRST_SCAN_VALUES_MERGE_GAP_BYTES: u64 = 32;
+
     /// Gets the most recent values collected from memory within this snapshot region bounds.
     pub fn get_current_values(&self) -> &Vec<u8> {
         &self.current_values
@@ -103,6 +112,46 @@ impl SnapshotRegion {
         }
     }
 
+    /// Gets the value collected from memory within this snapshot region bounds when it was first scanned.
+    pub fn get_first_scan_value(
+        &self,
+        element_address: u64,
+        data_type_ref: &DataTypeRef,
+    ) -> Option<DataValue> {
+        self.first_scan_values
+            .as_ref()
+            .and_then(|first_scan_values| first_scan_values.get_value(element_address, data_type_ref))
+    }
+
+    pub fn get_first_scan_values(&self) -> Option<&SnapshotRegionFirstScanValues> {
+        self.first_scan_values.as_ref()
+    }
+
+    /// Retains the current values under the filters as the first scan values, unless they were already captured by an earlier scan.
+    /// Must be called once the first scan has assigned its results, such that only the values of surviving results are kept.
+    pub fn capture_first_scan_values(&mut self) {
+        if self.first_scan_values.is_some() || !self.has_current_values() {
+            return;
+        }
+
+        let filter_ranges = self.get_merged_filter_ranges(Self::FIRST_SCAN_VALUES_MERGE_GAP_BYTES);
+
+        self.first_scan_values = Some(SnapshotRegionFirstScanValues::capture(
+            self.get_base_address(),
+            &self.current_values,
+            &filter_ranges,
+        ));
+    }
+
+    /// Gets the first scan values laid out over this region, such that they can stand in for the previous values of a scan that
+    /// compares against the first scan. Empty if no first scan values were captured.
+    pub fn get_first_scan_values_as_region_values(&self) -> Vec<u8> {
+        match &self.first_scan_values {
+            Some(first_scan_values) => first_scan_values.to_region_values(self.get_base_address(), self.get_region_size()),
+            None => vec![],
+        }
+    }
+
     /// Gets a pointer to the first current value element in the specified filter contained within this snapshot region.
     pub fn get_current_values_filter_pointer(
         &self,
@@ -131,6 +180,76 @@ impl SnapshotRegion {
         }
     }
 
+    /// Gets the address ranges that must be read to refresh the values under this region's filters. Filters of every data type are
+    /// coalesced, bridging gaps of up to `merge_gap_bytes` to avoid issuing many tiny reads, then split on page boundaries so that a
+    /// deallocated page only fails its own read. Regions that have not been filtered by a scan yet are read in full.
+    pub fn get_filter_read_ranges(
+        &self,
+        merge_gap_bytes: u64,
+    ) -> Vec<NormalizedRegion> {
+        if self.scan_results.get_filter_collections().is_empty() {
+            return vec![NormalizedRegion::new(self.get_base_address(), self.get_region_size())];
+        }
+
+        let merged_ranges = self.get_merged_filter_ranges(merge_gap_bytes);
+        let mut read_ranges = Vec::with_capacity(merged_ranges.len());
+        let mut page_boundary_index = 0;
+
+        for (mut start_address, end_address) in merged_ranges {
+            while page_boundary_index < self.page_boundaries.len() && self.page_boundaries[page_boundary_index] <= start_address {
+                page_boundary_index += 1;
+            }
+
+            while page_boundary_index < self.page_boundaries.len() && self.page_boundaries[page_boundary_index] < end_address {
+                let page_boundary = self.page_boundaries[page_boundary_index];
+
+                read_ranges.push(NormalizedRegion::new(start_address, page_boundary - start_address));
+                start_address = page_boundary;
+                page_boundary_index += 1;
+            }
+
+            read_ranges.push(NormalizedRegion::new(start_address, end_address - start_address));
+        }
+
+        read_ranges
+    }
+
+    /// Gets the address ranges covered by the filters of every data type, sorted and coalesced across gaps of up to `merge_gap_bytes`.
+    fn get_merged_filter_ranges(
+        &self,
+        merge_gap_bytes: u64,
+    ) -> Vec<(u64, u64)> {
+        let region_base_address = self.get_base_address();
+        let region_end_address = self.get_end_address();
+        let filter_collections = self.scan_results.get_filter_collections();
+        let mut filter_ranges: Vec<(u64, u64)> = filter_collections
+            .iter()
+            .flat_map(|filter_collection| filter_collection.iter())
+            .map(|filter| {
+                (
+                    filter.get_base_address().max(region_base_address),
+                    filter.get_end_address().min(region_end_address),
+                )
+            })
+            .filter(|(start_address, end_address)| start_address < end_address)
+            .collect();
+
+        filter_ranges.sort_unstable_by_key(|(start_address, _)| *start_address);
+
+        let mut merged_ranges: Vec<(u64, u64)> = Vec::with_capacity(filter_ranges.len());
+
+        for (start_address, end_address) in filter_ranges {
+            match merged_ranges.last_mut() {
+                Some(last_range) if start_address <= last_range.1.saturating_add(merge_gap_bytes) => {
+                    last_range.1 = last_range.1.max(end_address);
+                }
+                _ => merged_ranges.push((start_address, end_address)),
+            }
+        }
+
+        merged_ranges
+    }
+
     pub fn get_base_address(&self) -> u64 {
         self.normalized_region.get_base_address()
     }
@@ -145,6 +264,32 @@ impl SnapshotRegion {
         self.normalized_region.get_region_size()
     }
 
+    /// Moves this region, along with its page boundaries, filters, and first scan values, by the given wrapping delta. Values are
+    /// kept as they are, such that they can still be compared against once the moved memory is read again.
+    pub fn relocate(
+        &mut self,
+        address_delta: u64,
+    ) {
+        let base_address = self.get_base_address().wrapping_add(address_delta);
+
+        self.normalized_region.set_base_address(base_address);
+        self.page_boundaries = self
+            .page_boundaries
+            .iter()
+            .map(|page_boundary| page_boundary.wrapping_add(address_delta))
+            .collect();
+        self.page_boundary_tombstones = self
+            .page_boundary_tombstones
+            .iter()
+            .map(|page_boundary| page_boundary.wrapping_add(address_delta))
+            .collect();
+        self.scan_results.relocate(address_delta);
+
+        if let Some(first_scan_values) = self.first_scan_values.as_mut() {
+            first_scan_values.relocate(address_delta);
+        }
+    }
+
     pub fn has_current_values(&self) -> bool {
         !self.current_values.is_empty()
     }
@@ -184,6 +329,52 @@ impl SnapshotRegion {
         &self.scan_results
     }
 
+    /// Copies this region and its scan results, such that these results can be restored after a later scan replaces them. Only the
+    /// current values under the filters are kept, compacted the same way as first scan values. The first scan's own values are not
+    /// kept twice, as they are already retained as the first scan values. Previous values are not copied, as once restored, the
+    /// current values are what the next scan compares against.
+    pub fn clone_for_scan_history(
+        &self,
+        is_first_scan: bool,
+    ) -> SnapshotScanHistoryRegion {
+        let retained_values = if !self.has_current_values() || (is_first_scan && self.first_scan_values.is_some()) {
+            None
+        } else {
+            let filter_ranges = self.get_merged_filter_ranges(Self::FIRST_SCAN_VALUES_MERGE_GAP_BYTES);
+
+            Some(SnapshotRegionFirstScanValues::capture(
+                self.get_base_address(),
+                &self.current_values,
+                &filter_ranges,
+            ))
+        };
+        let snapshot_region = Self {
+            normalized_region: self.normalized_region.clone(),
+            current_values: vec![],
+            previous_values: vec![],
+            page_boundaries: self.page_boundaries.clone(),
+            page_boundary_tombstones: self.page_boundary_tombstones.clone(),
+            scan_results: self.scan_results.clone(),
+            first_scan_values: self.first_scan_values.clone(),
+        };
+
+        SnapshotScanHistoryRegion::new(snapshot_region, retained_values)
+    }
+
+    /// Restores a region retained by the scan history. Its retained values, or its first scan values if it was retained by the
+    /// first scan, serve as both its current and previous values until the next scan.
+    pub fn restore_from_scan_history(snapshot_scan_history_region: SnapshotScanHistoryRegion) -> Self {
+        let (mut snapshot_region, retained_values) = snapshot_scan_history_region.into_parts();
+
+        snapshot_region.current_values = match retained_values {
+            Some(retained_values) => retained_values.to_region_values(snapshot_region.get_base_address(), snapshot_region.get_region_size()),
+            None => snapshot_region.get_first_scan_values_as_region_values(),
+        };
+        snapshot_region.previous_values = snapshot_region.current_values.clone();
+
+        snapshot_region
+    }
+
     pub fn set_scan_results(
         &mut self,
         scan_results: SnapshotRegionScanResults,
@@ -192,6 +383,46 @@ impl SnapshotRegion {
 
         // Upon assigning new scan results, we want to cull memory outside of the bounds of the filters.
         self.resize_to_filters();
+
+        // First scan values are carried forward for the surviving filters only.
+        if self.first_scan_values.is_some() {
+            let filter_ranges = self.get_merged_filter_ranges(Self::FIRST_SCAN_VALUES_MERGE_GAP_BYTES);
+
+            if let Some(first_scan_values) = self.first_scan_values.as_mut() {
+                first_scan_values.retain_filter_ranges(&filter_ranges);
+            }
+        }
+    }
+
+    /// Removes the parts of every filter that lie within the given ranges, ie memory that no longer exists. Filters are clipped on
+    /// their element boundaries, such that elements partially within a discarded range are removed with it.
+    pub fn discard_filters_within(
+        &mut self,
+        discarded_ranges: &[NormalizedRegion],
+    ) {
+        let filter_collections = self
+            .scan_results
+            .get_filter_collections()
+            .iter()
+            .map(|filter_collection| {
+                let memory_alignment = filter_collection.get_memory_alignment();
+                let unit_size_in_bytes = filter_collection.get_unit_size_in_bytes();
+                let filters = filter_collection
+                    .iter()
+                    .flat_map(|filter| Self::clip_filter(filter, discarded_ranges, memory_alignment, unit_size_in_bytes))
+                    .collect();
+
+                SnapshotRegionFilterCollection::new(
+                    vec![filters],
+                    filter_collection.get_data_type_ref().clone(),
+                    memory_alignment,
+                    unit_size_in_bytes,
+                )
+                .with_scan_statistics(filter_collection.get_scan_strategy(), *filter_collection.get_scan_filter_statistics())
+            })
+            .collect();
+
+        self.set_scan_results(SnapshotRegionScanResults::new(filter_collections));
     }
 
     pub fn mark_unreadable(&mut self) {
@@ -199,9 +430,59 @@ impl SnapshotRegion {
         self.previous_values.clear();
         self.page_boundaries.clear();
         self.page_boundary_tombstones.clear();
+        self.first_scan_values = None;
         self.normalized_region.set_region_size(0);
     }
 
+    /// Splits a filter around the given ranges, keeping only whole elements outside of them on the original element grid.
+    fn clip_filter(
+        snapshot_region_filter: &SnapshotRegionFilter,
+        discarded_ranges: &[NormalizedRegion],
+        memory_alignment: MemoryAlignment,
+        unit_size_in_bytes: u64,
+    ) -> Vec<SnapshotRegionFilter> {
+        let alignment = (memory_alignment as u64).max(1);
+        let mut kept_ranges = vec![(snapshot_region_filter.get_base_address(), snapshot_region_filter.get_end_address())];
+
+        for discarded_range in discarded_ranges {
+            let discarded_start = discarded_range.get_base_address();
+            let discarded_end = discarded_range.get_end_address();
+
+            kept_ranges = kept_ranges
+                .into_iter()
+                .flat_map(|(start_address, end_address)| {
+                    if discarded_end <= start_address || discarded_start >= end_address {
+                        return vec![(start_address, end_address)];
+                    }
+
+                    let mut clipped_ranges = vec![];
+
+                    if discarded_start > start_address {
+                        let stop_address = start_address + (discarded_start - start_address) / alignment * alignment;
+
+                        clipped_ranges.push((start_address, stop_address));
+                    }
+
+                    if discarded_end < end_address {
+                        let resume_address = start_address + (discarded_end - start_address).div_ceil(alignment) * alignment;
+
+                        if resume_address < end_address {
+                            clipped_ranges.push((resume_address, end_address));
+                        }
+                    }
+
+                    clipped_ranges
+                })
+                .collect();
+        }
+
+        kept_ranges
+            .into_iter()
+            .filter(|(start_address, end_address)| end_address - start_address >= unit_size_in_bytes)
+            .map(|(start_address, end_address)| SnapshotRegionFilter::new(start_address, end_address - start_address))
+            .collect()
+    }
+
     /// Constrict this snapshot region based on the highest and lowest addresses in the contained scan result filters.
     /// JIRA: Shard large gaps into multiple regions?
     fn resize_to_filters(&mut self) {
@@ -214,6 +495,7 @@ impl SnapshotRegion {
             self.current_values = vec![];
             self.previous_values = vec![];
             self.page_boundaries = vec![];
+            self.first_scan_values = None;
             self.normalized_region.set_region_size(0);
             return;
         }
@@ -238,3 +520,219 @@ impl SnapshotRegion {
             .retain(|&boundary| boundary >= filter_lowest_address && boundary <= filter_highest_address);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotRegion;
+    use crate::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+    use crate::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use crate::structures::data_types::data_type_ref::DataTypeRef;
+    use crate::structures::memory::memory_alignment::MemoryAlignment;
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+    use crate::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+    use crate::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use crate::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+
+    fn create_filtered_region(
+        page_boundaries: Vec<u64>,
+        filters: &[(u64, u64)],
+    ) -> SnapshotRegion {
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x3000), page_boundaries);
+        let filters = filters
+            .iter()
+            .map(|(base_address, size)| SnapshotRegionFilter::new(*base_address, *size))
+            .collect();
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![filters],
+            DataTypeRef::new(DataTypeU8::DATA_TYPE_ID),
+            MemoryAlignment::Alignment1,
+            1,
+        )]));
+
+        snapshot_region
+    }
+
+    fn narrow_filters(
+        snapshot_region: &mut SnapshotRegion,
+        filters: &[(u64, u64)],
+    ) {
+        let filters = filters
+            .iter()
+            .map(|(base_address, size)| SnapshotRegionFilter::new(*base_address, *size))
+            .collect();
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![filters],
+            DataTypeRef::new(DataTypeU8::DATA_TYPE_ID),
+            MemoryAlignment::Alignment1,
+            1,
+        )]));
+    }
+
+    fn to_ranges(read_ranges: Vec<NormalizedRegion>) -> Vec<(u64, u64)> {
+        read_ranges
+            .iter()
+            .map(|read_range| (read_range.get_base_address(), read_range.get_end_address()))
+            .collect()
+    }
+
+    #[test]
+    fn unfiltered_regions_are_read_in_full() {
+        let snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x3000), vec![0x2000]);
+
+        assert_eq!(to_ranges(snapshot_region.get_filter_read_ranges(64)), vec![(0x1000, 0x4000)]);
+    }
+
+    #[test]
+    fn filters_merge_across_small_gaps_and_keep_region_edges() {
+        // The first and last filters sit on the region edges, and the middle pair is separated by a gap below the threshold.
+        let snapshot_region = create_filtered_region(vec![], &[(0x1000, 0x10), (0x1800, 0x8), (0x1820, 0x8), (0x3FF0, 0x10)]);
+
+        assert_eq!(
+            to_ranges(snapshot_region.get_filter_read_ranges(0x20)),
+            vec![(0x1000, 0x1010), (0x1800, 0x1828), (0x3FF0, 0x4000)]
+        );
+        assert_eq!(
+            to_ranges(snapshot_region.get_filter_read_ranges(0x10)),
+            vec![(0x1000, 0x1010), (0x1800, 0x1808), (0x1820, 0x1828), (0x3FF0, 0x4000)]
+        );
+    }
+
+    #[test]
+    fn merged_ranges_split_on_page_boundaries() {
+        let snapshot_region = create_filtered_region(vec![0x2000, 0x3000], &[(0x1000, 0x8), (0x1FF8, 0x10), (0x3FF8, 0x8)]);
+
+        assert_eq!(
+            to_ranges(snapshot_region.get_filter_read_ranges(0)),
+            vec![(0x1000, 0x1008), (0x1FF8, 0x2000), (0x2000, 0x2008), (0x3FF8, 0x4000)]
+        );
+    }
+
+    #[test]
+    fn discarded_ranges_clip_filters_on_element_boundaries() {
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x3000), vec![]);
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(0x1000, 0x100), SnapshotRegionFilter::new(0x3000, 0x10)]],
+            DataTypeRef::new(DataTypeU8::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        )]));
+        snapshot_region.discard_filters_within(&[NormalizedRegion::new(0x1042, 0x20), NormalizedRegion::new(0x2000, 0x2000)]);
+
+        let filters: Vec<(u64, u64)> = snapshot_region.get_scan_results().get_filter_collections()[0]
+            .iter()
+            .map(|filter| (filter.get_base_address(), filter.get_end_address()))
+            .collect();
+
+        // The element at 0x1040 straddles the first discarded range, so the kept filters resume on the next element at 0x1064.
+        assert_eq!(filters, vec![(0x1000, 0x1040), (0x1064, 0x1100)]);
+        assert_eq!(snapshot_region.get_scan_results().get_number_of_results(), 16 + 39);
+        assert_eq!((snapshot_region.get_base_address(), snapshot_region.get_end_address()), (0x1000, 0x1100));
+    }
+
+    #[test]
+    fn first_scan_values_survive_narrowing_and_compact_once_sparse() {
+        let original_values: Vec<u8> = (0..0x3000u32)
+            .map(|offset| (offset ^ (offset >> 8)) as u8)
+            .collect();
+        let first_scan_value = |snapshot_region: &SnapshotRegion, address: u64| {
+            snapshot_region
+                .get_first_scan_value(address, &DataTypeRef::new(DataTypeU8::DATA_TYPE_ID))
+                .map(|data_value| data_value.get_value_bytes()[0])
+        };
+        let original_value = |address: u64| Some(original_values[(address - 0x1000) as usize]);
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x3000), vec![]);
+
+        snapshot_region.current_values = original_values.clone();
+
+        // The first scan keeps most of the region, so the first scan values stay a single run spanning the filters.
+        narrow_filters(&mut snapshot_region, &[(0x1000, 0x1000), (0x2000, 0x1000)]);
+        snapshot_region.capture_first_scan_values();
+
+        let first_scan_values = snapshot_region.get_first_scan_values().unwrap();
+
+        assert!(!first_scan_values.is_compact());
+        assert_eq!(first_scan_values.get_byte_count(), 0x2000);
+        assert_eq!(first_scan_value(&snapshot_region, 0x1ABC), original_value(0x1ABC));
+
+        // Later reads replace the current values, which must not affect the first scan values.
+        snapshot_region
+            .current_values
+            .iter_mut()
+            .for_each(|value| *value = 0);
+        snapshot_region.capture_first_scan_values();
+
+        narrow_filters(&mut snapshot_region, &[(0x1100, 0x10), (0x1800, 0x10), (0x2F00, 0x4)]);
+
+        let first_scan_values = snapshot_region.get_first_scan_values().unwrap();
+
+        assert!(first_scan_values.is_compact());
+        assert_eq!(first_scan_values.get_byte_count(), 0x24);
+
+        for address in (0x1100..0x1110).chain(0x1800..0x1810).chain(0x2F00..0x2F04) {
+            assert_eq!(first_scan_value(&snapshot_region, address), original_value(address));
+        }
+
+        assert_eq!(first_scan_value(&snapshot_region, 0x1ABC), None);
+
+        narrow_filters(&mut snapshot_region, &[(0x1804, 0x4)]);
+
+        let first_scan_u32 = snapshot_region
+            .get_first_scan_value(0x1804, &DataTypeRef::new(DataTypeU32::DATA_TYPE_ID))
+            .unwrap();
+
+        assert_eq!(
+            snapshot_region
+                .get_first_scan_values()
+                .unwrap()
+                .get_byte_count(),
+            4
+        );
+        assert_eq!(first_scan_u32.get_value_bytes().as_slice(), &original_values[0x804..0x808]);
+        assert_eq!(first_scan_value(&snapshot_region, 0x1100), None);
+
+        // Laid out over the region, the retained values line up with the current values of the surviving filter.
+        assert_eq!(snapshot_region.get_first_scan_values_as_region_values(), original_values[0x804..0x808].to_vec());
+
+        narrow_filters(&mut snapshot_region, &[]);
+
+        assert!(snapshot_region.get_first_scan_values().is_none());
+    }
+
+    #[test]
+    fn scan_history_keeps_only_values_under_sparse_filters() {
+        let original_values: Vec<u8> = (0..0x3000u32).map(|offset| (offset % 251) as u8).collect();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0x1000, 0x3000), vec![]);
+
+        snapshot_region.current_values = original_values.clone();
+        narrow_filters(&mut snapshot_region, &[(0x1000, 0x1000), (0x2000, 0x1000)]);
+        snapshot_region.capture_first_scan_values();
+
+        // The first scan's values are already held as the first scan values, so they are not copied again.
+        let first_scan_history_region = snapshot_region.clone_for_scan_history(true);
+
+        assert_eq!(first_scan_history_region.get_retained_byte_count(), 0);
+        assert_eq!(
+            SnapshotRegion::restore_from_scan_history(first_scan_history_region).get_current_values(),
+            &original_values[..0x2000].to_vec()
+        );
+
+        narrow_filters(&mut snapshot_region, &[(0x1100, 0x10), (0x2F00, 0x4)]);
+
+        let history_region = snapshot_region.clone_for_scan_history(false);
+
+        assert_eq!(history_region.get_retained_byte_count(), 0x14);
+
+        let restored_region = SnapshotRegion::restore_from_scan_history(history_region);
+        let restored_value = |address: u64| restored_region.get_current_values()[(address - restored_region.get_base_address()) as usize];
+
+        for address in (0x1100..0x1110).chain(0x2F00..0x2F04) {
+            assert_eq!(restored_value(address), original_values[(address - 0x1000) as usize]);
+        }
+
+        assert_eq!(restored_region.get_current_values(), &restored_region.previous_values);
+        assert_eq!(restored_region.get_region_size(), snapshot_region.get_region_size());
+    }
+}
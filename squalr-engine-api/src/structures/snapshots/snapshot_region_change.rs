@@ -0,0 +1,232 @@
+use crate::structures::memory::normalized_region::NormalizedRegion;
+use serde::{Deserialize, Serialize};
+
+/// Describes how the memory backing part of a snapshot changed since it was snapshotted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapshotRegionChangeKind {
+    /// The backing region still exists, but no longer covers this range.
+    Shrunk,
+
+    /// No region backs any of the snapshotted range anymore.
+    Disappeared,
+
+    /// The range is still allocated, but its protection no longer matches the memory settings used to snapshot it.
+    ProtectionChanged,
+
+    /// The process was restarted, and the range lies outside of any module that could be followed into the new process.
+    ProcessRestarted,
+}
+
+impl SnapshotRegionChangeKind {
+    /// Gets a short, user facing explanation of this change.
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            SnapshotRegionChangeKind::Shrunk => "The memory region backing this result shrank after it was snapshotted.",
+            SnapshotRegionChangeKind::Disappeared => "The memory region backing this result was freed after it was snapshotted.",
+            SnapshotRegionChangeKind::ProtectionChanged => "The memory region backing this result changed protection after it was snapshotted.",
+            SnapshotRegionChangeKind::ProcessRestarted => "The process restarted after this result was snapshotted, and its address is not within a module.",
+        }
+    }
+}
+
+/// A range of a snapshot whose backing memory no longer matches the layout it was snapshotted with.
+#[derive(Clone)]
+pub struct SnapshotRegionChange {
+    region: NormalizedRegion,
+    change_kind: SnapshotRegionChangeKind,
+}
+
+impl SnapshotRegionChange {
+    pub fn new(
+        region: NormalizedRegion,
+        change_kind: SnapshotRegionChangeKind,
+    ) -> Self {
+        Self { region, change_kind }
+    }
+
+    pub fn get_region(&self) -> &NormalizedRegion {
+        &self.region
+    }
+
+    pub fn get_change_kind(&self) -> SnapshotRegionChangeKind {
+        self.change_kind
+    }
+
+    /// Compares snapshotted ranges against the live memory layout of the process. `live_regions` are all allocated regions
+    /// overlapping the snapshotted ranges regardless of protection, while `live_matching_regions` are only those that still match
+    /// the memory settings. Growing or splitting a region is harmless, as the snapshotted bytes are still backed by readable memory.
+    pub fn diff_regions(
+        snapshot_ranges: &[NormalizedRegion],
+        live_regions: &[NormalizedRegion],
+        live_matching_regions: &[NormalizedRegion],
+    ) -> Vec<SnapshotRegionChange> {
+        let mut region_changes = vec![];
+
+        for snapshot_range in snapshot_ranges {
+            let start_address = snapshot_range.get_base_address();
+            let end_address = snapshot_range.get_end_address();
+
+            if start_address >= end_address {
+                continue;
+            }
+
+            let unbacked_ranges = Self::subtract_regions(start_address, end_address, live_regions);
+            let unbacked_size: u64 = unbacked_ranges
+                .iter()
+                .map(|(unbacked_start, unbacked_end)| unbacked_end - unbacked_start)
+                .sum();
+
+            if unbacked_size == end_address - start_address {
+                region_changes.push(SnapshotRegionChange::new(
+                    snapshot_range.clone(),
+                    SnapshotRegionChangeKind::Disappeared,
+                ));
+                continue;
+            }
+
+            for (unbacked_start, unbacked_end) in unbacked_ranges {
+                region_changes.push(SnapshotRegionChange::new(
+                    NormalizedRegion::new(unbacked_start, unbacked_end - unbacked_start),
+                    SnapshotRegionChangeKind::Shrunk,
+                ));
+            }
+
+            // Of the bytes that are still backed, any that no longer match the settings had their protection changed.
+            for live_region in live_regions {
+                let backed_start = live_region.get_base_address().max(start_address);
+                let backed_end = live_region.get_end_address().min(end_address);
+
+                if backed_start >= backed_end {
+                    continue;
+                }
+
+                for (changed_start, changed_end) in Self::subtract_regions(backed_start, backed_end, live_matching_regions) {
+                    region_changes.push(SnapshotRegionChange::new(
+                        NormalizedRegion::new(changed_start, changed_end - changed_start),
+                        SnapshotRegionChangeKind::ProtectionChanged,
+                    ));
+                }
+            }
+        }
+
+        region_changes.sort_by_key(|region_change| region_change.region.get_base_address());
+        region_changes
+    }
+
+    /// Gets the parts of the given address range that are not covered by any of the given regions.
+    fn subtract_regions(
+        start_address: u64,
+        end_address: u64,
+        regions: &[NormalizedRegion],
+    ) -> Vec<(u64, u64)> {
+        let mut covered_ranges: Vec<(u64, u64)> = regions
+            .iter()
+            .map(|region| (region.get_base_address().max(start_address), region.get_end_address().min(end_address)))
+            .filter(|(covered_start, covered_end)| covered_start < covered_end)
+            .collect();
+        let mut uncovered_ranges = vec![];
+        let mut cursor = start_address;
+
+        covered_ranges.sort_unstable();
+
+        for (covered_start, covered_end) in covered_ranges {
+            if covered_start > cursor {
+                uncovered_ranges.push((cursor, covered_start));
+            }
+
+            cursor = cursor.max(covered_end);
+        }
+
+        if cursor < end_address {
+            uncovered_ranges.push((cursor, end_address));
+        }
+
+        uncovered_ranges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnapshotRegionChange, SnapshotRegionChangeKind};
+    use crate::structures::memory::normalized_region::NormalizedRegion;
+
+    fn to_regions(ranges: &[(u64, u64)]) -> Vec<NormalizedRegion> {
+        ranges
+            .iter()
+            .map(|(start_address, end_address)| NormalizedRegion::new(*start_address, end_address - start_address))
+            .collect()
+    }
+
+    fn to_changes(region_changes: Vec<SnapshotRegionChange>) -> Vec<(u64, u64, SnapshotRegionChangeKind)> {
+        region_changes
+            .iter()
+            .map(|region_change| {
+                (
+                    region_change.get_region().get_base_address(),
+                    region_change.get_region().get_end_address(),
+                    region_change.get_change_kind(),
+                )
+            })
+            .collect()
+    }
+
+    /// Diffs the snapshot ranges against a layout where every live region still matches the memory settings.
+    fn diff_unprotected(
+        snapshot_ranges: &[(u64, u64)],
+        live_ranges: &[(u64, u64)],
+    ) -> Vec<(u64, u64, SnapshotRegionChangeKind)> {
+        let live_regions = to_regions(live_ranges);
+
+        to_changes(SnapshotRegionChange::diff_regions(&to_regions(snapshot_ranges), &live_regions, &live_regions))
+    }
+
+    #[test]
+    fn unchanged_layouts_report_nothing() {
+        assert!(diff_unprotected(&[(0x1000, 0x3000)], &[(0x1000, 0x3000)]).is_empty());
+    }
+
+    #[test]
+    fn shrunk_regions_report_the_lost_tail() {
+        assert_eq!(
+            diff_unprotected(&[(0x1000, 0x3000)], &[(0x1000, 0x2000)]),
+            vec![(0x2000, 0x3000, SnapshotRegionChangeKind::Shrunk)]
+        );
+    }
+
+    #[test]
+    fn grown_regions_report_nothing() {
+        assert!(diff_unprotected(&[(0x1000, 0x3000)], &[(0x0000, 0x5000)]).is_empty());
+    }
+
+    #[test]
+    fn split_regions_report_only_the_freed_gap() {
+        assert!(diff_unprotected(&[(0x1000, 0x3000)], &[(0x1000, 0x2000), (0x2000, 0x3000)]).is_empty());
+        assert_eq!(
+            diff_unprotected(&[(0x1000, 0x4000)], &[(0x3000, 0x4000), (0x1000, 0x2000)]),
+            vec![(0x2000, 0x3000, SnapshotRegionChangeKind::Shrunk)]
+        );
+    }
+
+    #[test]
+    fn disappeared_regions_report_the_whole_range() {
+        assert_eq!(
+            diff_unprotected(&[(0x1000, 0x2000), (0x8000, 0x9000)], &[(0x8000, 0x9000)]),
+            vec![(0x1000, 0x2000, SnapshotRegionChangeKind::Disappeared)]
+        );
+    }
+
+    #[test]
+    fn reprotected_regions_report_protection_changes() {
+        let snapshot_regions = to_regions(&[(0x1000, 0x3000)]);
+        let live_regions = to_regions(&[(0x1000, 0x2000), (0x2000, 0x2800)]);
+        let live_matching_regions = to_regions(&[(0x1000, 0x2000)]);
+
+        assert_eq!(
+            to_changes(SnapshotRegionChange::diff_regions(&snapshot_regions, &live_regions, &live_matching_regions)),
+            vec![
+                (0x2000, 0x2800, SnapshotRegionChangeKind::ProtectionChanged),
+                (0x2800, 0x3000, SnapshotRegionChangeKind::Shrunk),
+            ]
+        );
+    }
+}
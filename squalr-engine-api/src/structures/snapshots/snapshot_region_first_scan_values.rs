@@ -0,0 +1,194 @@
+use crate::registries::symbols::symbol_registry::SymbolRegistry;
+use crate::structures::data_types::data_type_ref::DataTypeRef;
+use crate::structures::data_values::data_value::DataValue;
+
+/// The values a snapshot region held when it was first scanned, carried forward as later scans narrow its filters.
+/// While results are dense, the values are kept as a single run spanning the filters, which costs no more than the current values.
+/// Once the surviving filters cover little of that span, only the values under the filters are kept, such that the memory cost
+/// scales with the surviving results rather than with the original snapshot.
+#[derive(Clone, Debug, Default)]
+pub struct SnapshotRegionFirstScanValues {
+    /// Runs of first scan values, each paired with the address of its first byte, sorted by address and never overlapping.
+    value_runs: Vec<(u64, Vec<u8>)>,
+
+    /// Whether the values have been reduced to runs under the filters, rather than a single run spanning them.
+    is_compact: bool,
+}
+
+impl SnapshotRegionFirstScanValues {
+    /// Values are compacted once the filters cover less than one in this many bytes of the span between their bounds.
+    const COMPACTION_RATIO: u64 = 4;
+
+    /// Captures the first scan values under the given filter ranges, which must be sorted and non-overlapping.
+    pub fn capture(
+        base_address: u64,
+        values: &[u8],
+        filter_ranges: &[(u64, u64)],
+    ) -> Self {
+        // Sparse results are copied under the filters directly, rather than copying the whole region first.
+        if Self::should_compact(filter_ranges) {
+            return Self {
+                value_runs: Self::copy_ranges(&[(base_address, values)], filter_ranges),
+                is_compact: true,
+            };
+        }
+
+        let mut first_scan_values = Self {
+            value_runs: vec![(base_address, values.to_vec())],
+            is_compact: false,
+        };
+
+        first_scan_values.retain_filter_ranges(filter_ranges);
+
+        first_scan_values
+    }
+
+    /// Discards the values outside of the given filter ranges, which must be sorted and non-overlapping. Values are compacted
+    /// once the filters become sparse, and stay compact from then on.
+    pub fn retain_filter_ranges(
+        &mut self,
+        filter_ranges: &[(u64, u64)],
+    ) {
+        let (Some((lowest_address, _)), Some((_, highest_address))) = (filter_ranges.first(), filter_ranges.last()) else {
+            self.value_runs.clear();
+            return;
+        };
+
+        if !self.is_compact && !Self::should_compact(filter_ranges) {
+            // Dense values are trimmed to the filter bounds in place, the same way the region trims its current values.
+            if let [(run_base_address, run_values)] = self.value_runs.as_mut_slice() {
+                let start_offset = (lowest_address.saturating_sub(*run_base_address) as usize).min(run_values.len());
+
+                run_values.drain(..start_offset);
+                *run_base_address += start_offset as u64;
+                run_values.truncate(highest_address.saturating_sub(*run_base_address) as usize);
+            }
+
+            return;
+        }
+
+        let value_runs = std::mem::take(&mut self.value_runs);
+        let value_run_slices: Vec<(u64, &[u8])> = value_runs
+            .iter()
+            .map(|(run_base_address, run_values)| (*run_base_address, run_values.as_slice()))
+            .collect();
+
+        self.value_runs = Self::copy_ranges(&value_run_slices, filter_ranges);
+        self.is_compact = true;
+    }
+
+    /// Gets the first scan value of the given data type at the given address, if every byte of it was retained.
+    pub fn get_value(
+        &self,
+        element_address: u64,
+        data_type_ref: &DataTypeRef,
+    ) -> Option<DataValue> {
+        let data_type_size = SymbolRegistry::get_instance().get_unit_size_in_bytes(data_type_ref);
+        let run_index = self
+            .value_runs
+            .partition_point(|(run_base_address, _)| *run_base_address <= element_address)
+            .checked_sub(1)?;
+        let (run_base_address, run_values) = &self.value_runs[run_index];
+        let start = (element_address - run_base_address) as usize;
+        let end = start.checked_add(data_type_size as usize)?;
+        let value_bytes = run_values.get(start..end)?;
+        let mut data_value = SymbolRegistry::get_instance().get_default_value(data_type_ref)?;
+
+        data_value.copy_from_bytes(value_bytes);
+
+        Some(data_value)
+    }
+
+    /// Lays the retained values out over the given region, such that they line up with the region's current values. Bytes that
+    /// were not retained are left as zero, and are never read by a scan as they lie outside of every filter.
+    pub fn to_region_values(
+        &self,
+        base_address: u64,
+        region_size: u64,
+    ) -> Vec<u8> {
+        let end_address = base_address.saturating_add(region_size);
+        let mut region_values = vec![0u8; region_size as usize];
+
+        for (run_base_address, run_values) in &self.value_runs {
+            let start_address = (*run_base_address).max(base_address);
+            let stop_address = run_base_address
+                .saturating_add(run_values.len() as u64)
+                .min(end_address);
+
+            if start_address < stop_address {
+                let run_offset = (start_address - run_base_address) as usize;
+                let region_offset = (start_address - base_address) as usize;
+                let length = (stop_address - start_address) as usize;
+
+                region_values[region_offset..region_offset + length].copy_from_slice(&run_values[run_offset..run_offset + length]);
+            }
+        }
+
+        region_values
+    }
+
+    /// Moves every run of values by the given wrapping delta.
+    pub fn relocate(
+        &mut self,
+        address_delta: u64,
+    ) {
+        for (run_base_address, _) in &mut self.value_runs {
+            *run_base_address = run_base_address.wrapping_add(address_delta);
+        }
+    }
+
+    /// Gets the number of value bytes currently retained.
+    pub fn get_byte_count(&self) -> u64 {
+        self.value_runs
+            .iter()
+            .map(|(_, run_values)| run_values.len() as u64)
+            .sum()
+    }
+
+    pub fn is_compact(&self) -> bool {
+        self.is_compact
+    }
+
+    fn should_compact(filter_ranges: &[(u64, u64)]) -> bool {
+        let (Some((lowest_address, _)), Some((_, highest_address))) = (filter_ranges.first(), filter_ranges.last()) else {
+            return true;
+        };
+        let retained_byte_count: u64 = filter_ranges
+            .iter()
+            .map(|(start_address, end_address)| end_address.saturating_sub(*start_address))
+            .sum();
+
+        retained_byte_count.saturating_mul(Self::COMPACTION_RATIO) < highest_address.saturating_sub(*lowest_address)
+    }
+
+    /// Copies the parts of the given value runs that lie within the given filter ranges. Both must be sorted by address.
+    fn copy_ranges(
+        value_runs: &[(u64, &[u8])],
+        filter_ranges: &[(u64, u64)],
+    ) -> Vec<(u64, Vec<u8>)> {
+        let mut copied_runs = Vec::with_capacity(filter_ranges.len());
+
+        for (start_address, end_address) in filter_ranges {
+            let run_index =
+                value_runs.partition_point(|(run_base_address, run_values)| run_base_address.saturating_add(run_values.len() as u64) <= *start_address);
+
+            for (run_base_address, run_values) in &value_runs[run_index..] {
+                if run_base_address >= end_address {
+                    break;
+                }
+
+                let copy_start_address = (*start_address).max(*run_base_address);
+                let copy_end_address = (*end_address).min(run_base_address.saturating_add(run_values.len() as u64));
+
+                if copy_start_address < copy_end_address {
+                    let start = (copy_start_address - run_base_address) as usize;
+                    let end = (copy_end_address - run_base_address) as usize;
+
+                    copied_runs.push((copy_start_address, run_values[start..end].to_vec()));
+                }
+            }
+        }
+
+        copied_runs
+    }
+}
@@ -0,0 +1,106 @@
+use crate::structures::memory::normalized_module::NormalizedModule;
+
+/// Describes where a module of a process that exited was loaded again once the process restarted, such that snapshot ranges within
+/// the module can follow it to its new base address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotRelocation {
+    base_address: u64,
+    region_size: u64,
+    relocated_base_address: u64,
+}
+
+impl SnapshotRelocation {
+    pub fn new(
+        base_address: u64,
+        region_size: u64,
+        relocated_base_address: u64,
+    ) -> Self {
+        Self {
+            base_address,
+            region_size,
+            relocated_base_address,
+        }
+    }
+
+    /// Pairs the modules of the exited process with the modules of the restarted process by name. Modules that were not loaded
+    /// again, or that changed size, are left out, as offsets into them can no longer be trusted.
+    pub fn from_modules(
+        previous_modules: &[NormalizedModule],
+        current_modules: &[NormalizedModule],
+    ) -> Vec<SnapshotRelocation> {
+        previous_modules
+            .iter()
+            .filter_map(|previous_module| {
+                current_modules
+                    .iter()
+                    .find(|current_module| {
+                        current_module
+                            .get_module_name()
+                            .eq_ignore_ascii_case(previous_module.get_module_name())
+                            && current_module.get_region_size() == previous_module.get_region_size()
+                    })
+                    .map(|current_module| {
+                        SnapshotRelocation::new(
+                            previous_module.get_base_address(),
+                            previous_module.get_region_size(),
+                            current_module.get_base_address(),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Gets the wrapping delta to add to an address within the module to move it to the new base address.
+    pub fn get_address_delta(&self) -> u64 {
+        self.relocated_base_address.wrapping_sub(self.base_address)
+    }
+
+    /// Gets whether the given range lies entirely within the module as it was loaded before the restart.
+    pub fn contains_range(
+        &self,
+        start_address: u64,
+        end_address: u64,
+    ) -> bool {
+        start_address >= self.base_address && end_address <= self.base_address.saturating_add(self.region_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotRelocation;
+    use crate::structures::memory::normalized_module::NormalizedModule;
+
+    #[test]
+    fn modules_are_paired_by_name_and_size() {
+        let previous_modules = vec![
+            NormalizedModule::new("Game.exe", 0x140000000, 0x5000),
+            NormalizedModule::new("engine.dll", 0x7FF000000000, 0x2000),
+            NormalizedModule::new("patched.dll", 0x7FF100000000, 0x1000),
+            NormalizedModule::new("unloaded.dll", 0x7FF200000000, 0x1000),
+        ];
+        let current_modules = vec![
+            NormalizedModule::new("game.exe", 0x150000000, 0x5000),
+            NormalizedModule::new("engine.dll", 0x7FE000000000, 0x2000),
+            NormalizedModule::new("patched.dll", 0x7FF100000000, 0x3000),
+        ];
+
+        assert_eq!(
+            SnapshotRelocation::from_modules(&previous_modules, &current_modules),
+            vec![
+                SnapshotRelocation::new(0x140000000, 0x5000, 0x150000000),
+                SnapshotRelocation::new(0x7FF000000000, 0x2000, 0x7FE000000000),
+            ]
+        );
+    }
+
+    #[test]
+    fn deltas_move_addresses_up_or_down() {
+        let moved_up = SnapshotRelocation::new(0x1000, 0x1000, 0x5000);
+        let moved_down = SnapshotRelocation::new(0x5000, 0x1000, 0x1000);
+
+        assert_eq!(0x1010u64.wrapping_add(moved_up.get_address_delta()), 0x5010);
+        assert_eq!(0x5010u64.wrapping_add(moved_down.get_address_delta()), 0x1010);
+        assert!(moved_up.contains_range(0x1000, 0x2000));
+        assert!(!moved_up.contains_range(0x1800, 0x2800));
+    }
+}
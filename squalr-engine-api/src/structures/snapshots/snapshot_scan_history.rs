@@ -0,0 +1,99 @@
+use crate::structures::scanning::statistics::scan_statistics::ScanStatistics;
+use crate::structures::snapshots::snapshot_region::SnapshotRegion;
+use crate::structures::snapshots::snapshot_region_first_scan_values::SnapshotRegionFirstScanValues;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Summarizes the results of a scan in the history of a snapshot, such that a scan to revert to can be chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotScanHistoryItem {
+    /// The number of scans performed over the snapshot up to and including this one, starting from 1 for the first scan.
+    scan_number: u64,
+
+    /// The number of results this scan left behind.
+    result_count: u64,
+}
+
+impl SnapshotScanHistoryItem {
+    pub fn new(
+        scan_number: u64,
+        result_count: u64,
+    ) -> Self {
+        Self { scan_number, result_count }
+    }
+
+    pub fn get_scan_number(&self) -> u64 {
+        self.scan_number
+    }
+
+    pub fn get_result_count(&self) -> u64 {
+        self.result_count
+    }
+}
+
+/// The results of an earlier scan over a snapshot, retained along with the values under them such that the snapshot can be reverted
+/// to them after later scans replace them.
+pub struct SnapshotScanHistoryEntry {
+    scan_history_item: SnapshotScanHistoryItem,
+    snapshot_regions: Vec<SnapshotScanHistoryRegion>,
+    last_scan_statistics: Option<ScanStatistics>,
+    stride_repeat_counts: HashMap<u64, u64>,
+}
+
+impl SnapshotScanHistoryEntry {
+    pub fn new(
+        scan_history_item: SnapshotScanHistoryItem,
+        snapshot_regions: Vec<SnapshotScanHistoryRegion>,
+        last_scan_statistics: Option<ScanStatistics>,
+        stride_repeat_counts: HashMap<u64, u64>,
+    ) -> Self {
+        Self {
+            scan_history_item,
+            snapshot_regions,
+            last_scan_statistics,
+            stride_repeat_counts,
+        }
+    }
+
+    pub fn get_scan_history_item(&self) -> &SnapshotScanHistoryItem {
+        &self.scan_history_item
+    }
+
+    /// Consumes this entry, returning its snapshot regions, scan statistics, and stride repeat counts.
+    pub fn into_parts(self) -> (Vec<SnapshotScanHistoryRegion>, Option<ScanStatistics>, HashMap<u64, u64>) {
+        (self.snapshot_regions, self.last_scan_statistics, self.stride_repeat_counts)
+    }
+}
+
+/// A snapshot region retained in the scan history, holding only the values under its filters rather than its full current values.
+pub struct SnapshotScanHistoryRegion {
+    /// The region and its scan results, without current or previous values.
+    snapshot_region: SnapshotRegion,
+
+    /// The current values under the filters, or None if they are the first scan values of the region.
+    retained_values: Option<SnapshotRegionFirstScanValues>,
+}
+
+impl SnapshotScanHistoryRegion {
+    pub fn new(
+        snapshot_region: SnapshotRegion,
+        retained_values: Option<SnapshotRegionFirstScanValues>,
+    ) -> Self {
+        Self {
+            snapshot_region,
+            retained_values,
+        }
+    }
+
+    /// Gets the number of value bytes retained for this region, excluding its first scan values.
+    pub fn get_retained_byte_count(&self) -> u64 {
+        self.retained_values
+            .as_ref()
+            .map_or(0, SnapshotRegionFirstScanValues::get_byte_count)
+    }
+
+    /// Consumes this region, returning the region without values and its retained values.
+    pub fn into_parts(self) -> (SnapshotRegion, Option<SnapshotRegionFirstScanValues>) {
+        (self.snapshot_region, self.retained_values)
+    }
+}
@@ -7,6 +7,8 @@ use uuid::Uuid;
 pub struct TrackableTask {
     name: String,
     progress: Arc<Mutex<f32>>,
+    /// The memory held by the task, for tasks that track it.
+    memory_usage_bytes: Mutex<Option<u64>>,
     task_identifier: String,
     is_canceled: Arc<AtomicBool>,
     is_completed: Arc<AtomicBool>,
@@ -26,6 +28,7 @@ impl TrackableTask {
         let task = Arc::new(TrackableTask {
             name,
             progress: Arc::new(Mutex::new(0.0)),
+            memory_usage_bytes: Mutex::new(None),
             task_identifier,
             is_canceled: Arc::new(AtomicBool::new(false)),
             is_completed: Arc::new(AtomicBool::new(false)),
@@ -69,6 +72,23 @@ impl TrackableTask {
         }
     }
 
+    pub fn get_memory_usage_bytes(&self) -> Option<u64> {
+        match self.memory_usage_bytes.lock() {
+            Ok(memory_usage_bytes) => *memory_usage_bytes,
+            Err(_) => None,
+        }
+    }
+
+    /// Records the memory held by the task. This is reported alongside the next progress update, rather than on its own.
+    pub fn set_memory_usage_bytes(
+        &self,
+        memory_usage_bytes: u64,
+    ) {
+        if let Ok(mut memory_usage_bytes_guard) = self.memory_usage_bytes.lock() {
+            *memory_usage_bytes_guard = Some(memory_usage_bytes);
+        }
+    }
+
     pub fn subscribe_to_progress_updates(&self) -> Receiver<f32> {
         self.progress_receiver.clone()
     }
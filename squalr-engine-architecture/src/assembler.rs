@@ -0,0 +1,468 @@
+use iced_x86::{Code, Encoder, Instruction, MemoryOperand, Mnemonic, OpCodeOperandKind, Register};
+
+/// Alternate names for condition codes, mapped to the name iced uses, ie `jz` is encoded as `je`.
+const CONDITION_ALIASES: [(&str, &str); 14] = [
+    ("z", "e"),
+    ("nz", "ne"),
+    ("c", "b"),
+    ("nc", "ae"),
+    ("nae", "b"),
+    ("nb", "ae"),
+    ("na", "be"),
+    ("nbe", "a"),
+    ("nge", "l"),
+    ("nl", "ge"),
+    ("ng", "le"),
+    ("nle", "g"),
+    ("pe", "p"),
+    ("po", "np"),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct AssemblyMemoryOperand {
+    base: Register,
+    index: Register,
+    scale: u32,
+    displacement: i64,
+    segment: Register,
+    /// The size given by a `ptr` prefix, ie 4 for `dword ptr`. Required whenever no other operand implies the size.
+    size: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AssemblyOperand {
+    Register(Register),
+    Immediate(i64),
+    Memory(AssemblyMemoryOperand),
+}
+
+/// Assembles single x86-64 instructions written in Intel syntax, the inverse of the disassembler's formatting.
+pub struct Assembler {}
+
+impl Assembler {
+    /// Assembles an instruction, ie `mov dword ptr [rbx+0x10], 1`, as it would be encoded at the given address. Branch targets are
+    /// absolute, and RIP relative operands are relative to the next instruction, matching how the disassembler shows them. Of every
+    /// encoding the instruction has, the shortest is chosen.
+    pub fn assemble(
+        instruction_text: &str,
+        address: u64,
+    ) -> Result<Vec<u8>, String> {
+        let instruction_text = instruction_text.trim().to_ascii_lowercase();
+        let mut has_lock_prefix = false;
+        let mut remaining_text = instruction_text.as_str();
+
+        let mnemonic_text = loop {
+            let (token, rest) = remaining_text
+                .split_once(char::is_whitespace)
+                .unwrap_or((remaining_text, ""));
+
+            match token {
+                "lock" => has_lock_prefix = true,
+                "" => return Err("Enter an instruction to assemble.".to_string()),
+                _ => {
+                    remaining_text = rest.trim();
+                    break token;
+                }
+            }
+
+            remaining_text = rest.trim();
+        };
+        let mnemonic = Self::parse_mnemonic(mnemonic_text).ok_or_else(|| format!("Unknown instruction: '{}'.", mnemonic_text))?;
+        let operands = if remaining_text.is_empty() {
+            vec![]
+        } else {
+            remaining_text
+                .split(',')
+                .map(Self::parse_operand)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        let mut encodings: Vec<(Vec<u8>, usize)> = Code::values()
+            .filter(|code| {
+                let op_code = code.op_code();
+
+                code.mnemonic() == mnemonic && op_code.is_instruction() && op_code.mode64() && op_code.op_count() as usize == operands.len()
+            })
+            .filter_map(|code| Self::encode(code, &operands, has_lock_prefix, address))
+            .collect();
+
+        if encodings.is_empty() {
+            return Err(format!("'{}' is not a valid instruction.", instruction_text));
+        }
+
+        // Without a size on the memory operand, ie `inc [rax]`, every operand size encodes. Guessing one would patch the wrong width.
+        let has_unsized_memory = operands
+            .iter()
+            .any(|operand| matches!(operand, AssemblyOperand::Memory(memory_operand) if memory_operand.size.is_none()));
+
+        if has_unsized_memory
+            && encodings
+                .iter()
+                .any(|(_, memory_size)| *memory_size != encodings[0].1)
+        {
+            return Err("The operand size is ambiguous. Specify it with byte, word, dword or qword ptr.".to_string());
+        }
+
+        encodings.sort_by_key(|(bytes, _)| bytes.len());
+
+        Ok(encodings.swap_remove(0).0)
+    }
+
+    fn parse_mnemonic(mnemonic_text: &str) -> Option<Mnemonic> {
+        let mut mnemonic_text = mnemonic_text.to_string();
+
+        for condition_prefix in ["j", "set", "cmov"] {
+            if let Some(condition) = mnemonic_text.strip_prefix(condition_prefix)
+                && let Some((_, alias)) = CONDITION_ALIASES.iter().find(|(name, _)| *name == condition)
+            {
+                mnemonic_text = format!("{}{}", condition_prefix, alias);
+                break;
+            }
+        }
+
+        Mnemonic::values().find(|mnemonic| format!("{:?}", mnemonic).eq_ignore_ascii_case(&mnemonic_text))
+    }
+
+    fn parse_register(register_text: &str) -> Option<Register> {
+        if register_text.is_empty() || register_text == "none" {
+            return None;
+        }
+
+        Register::values().find(|register| format!("{:?}", register).eq_ignore_ascii_case(register_text))
+    }
+
+    /// Parses an integer as hexadecimal when prefixed with `0x` or suffixed with `h`, and as decimal otherwise.
+    fn parse_integer(integer_text: &str) -> Option<i64> {
+        let (is_negative, magnitude_text) = match integer_text.strip_prefix('-') {
+            Some(magnitude_text) => (true, magnitude_text.trim()),
+            None => (false, integer_text),
+        };
+        let magnitude = if let Some(hex_text) = magnitude_text.strip_prefix("0x") {
+            u64::from_str_radix(hex_text, 16).ok()?
+        } else if let Some(hex_text) = magnitude_text.strip_suffix('h') {
+            u64::from_str_radix(hex_text, 16).ok()?
+        } else {
+            magnitude_text.parse::<u64>().ok()?
+        };
+
+        Some(if is_negative { (magnitude as i64).wrapping_neg() } else { magnitude as i64 })
+    }
+
+    fn parse_operand(operand_text: &str) -> Result<AssemblyOperand, String> {
+        let operand_text = operand_text.trim();
+
+        if !operand_text.contains('[') {
+            if let Some(register) = Self::parse_register(operand_text) {
+                return Ok(AssemblyOperand::Register(register));
+            }
+
+            return Self::parse_integer(operand_text)
+                .map(AssemblyOperand::Immediate)
+                .ok_or_else(|| format!("Unknown operand: '{}'.", operand_text));
+        }
+
+        Self::parse_memory_operand(operand_text).map(AssemblyOperand::Memory)
+    }
+
+    /// Parses a memory operand, ie `dword ptr fs:[rax+rcx*4-0x10]`.
+    fn parse_memory_operand(operand_text: &str) -> Result<AssemblyMemoryOperand, String> {
+        let invalid_operand = || format!("Invalid memory operand: '{}'.", operand_text);
+        let (prefix_text, address_text) = operand_text.split_once('[').ok_or_else(invalid_operand)?;
+        let address_text = address_text.strip_suffix(']').ok_or_else(invalid_operand)?;
+        let mut memory_operand = AssemblyMemoryOperand {
+            base: Register::None,
+            index: Register::None,
+            scale: 1,
+            displacement: 0,
+            segment: Register::None,
+            size: None,
+        };
+
+        for prefix_token in prefix_text.split(|character: char| character.is_whitespace() || character == ':') {
+            match prefix_token {
+                "" | "ptr" => {}
+                "byte" => memory_operand.size = Some(1),
+                "word" => memory_operand.size = Some(2),
+                "dword" => memory_operand.size = Some(4),
+                "fword" => memory_operand.size = Some(6),
+                "qword" => memory_operand.size = Some(8),
+                "tbyte" | "tword" => memory_operand.size = Some(10),
+                "xmmword" | "oword" => memory_operand.size = Some(16),
+                "ymmword" => memory_operand.size = Some(32),
+                "zmmword" => memory_operand.size = Some(64),
+                _ => match Self::parse_register(prefix_token) {
+                    Some(segment) if segment.is_segment_register() => memory_operand.segment = segment,
+                    _ => return Err(invalid_operand()),
+                },
+            }
+        }
+
+        // Splitting before each sign keeps it attached to its term, ie `rax-0x10` becomes `rax` and `-0x10`.
+        let mut terms = vec![];
+        let mut term_start = 0;
+
+        for (character_index, character) in address_text.char_indices() {
+            if (character == '+' || character == '-') && character_index > term_start {
+                terms.push(&address_text[term_start..character_index]);
+                term_start = character_index;
+            }
+        }
+
+        terms.push(&address_text[term_start..]);
+
+        for term in terms {
+            let term = term.trim().trim_start_matches('+').trim();
+
+            if let Some(displacement) = Self::parse_integer(&term.replace(' ', "")) {
+                memory_operand.displacement = memory_operand.displacement.wrapping_add(displacement);
+            } else if let Some((left_text, right_text)) = term.split_once('*') {
+                let (register_text, scale_text) = match Self::parse_register(left_text.trim()) {
+                    Some(_) => (left_text.trim(), right_text.trim()),
+                    None => (right_text.trim(), left_text.trim()),
+                };
+                let index = Self::parse_register(register_text).ok_or_else(invalid_operand)?;
+                let scale = Self::parse_integer(scale_text)
+                    .filter(|scale| matches!(scale, 1 | 2 | 4 | 8))
+                    .ok_or_else(invalid_operand)?;
+
+                if memory_operand.index != Register::None {
+                    return Err(invalid_operand());
+                }
+
+                memory_operand.index = index;
+                memory_operand.scale = scale as u32;
+            } else {
+                let register = Self::parse_register(term).ok_or_else(invalid_operand)?;
+
+                if memory_operand.base == Register::None {
+                    memory_operand.base = register;
+                } else if memory_operand.index == Register::None {
+                    memory_operand.index = register;
+                } else {
+                    return Err(invalid_operand());
+                }
+            }
+        }
+
+        Ok(memory_operand)
+    }
+
+    /// Encodes the instruction with the given code, returning its bytes and memory operand size, or `None` if the operands do not fit
+    /// the code. RIP relative targets depend on the instruction length, so those are encoded once to find it, then again at their target.
+    fn encode(
+        code: Code,
+        operands: &[AssemblyOperand],
+        has_lock_prefix: bool,
+        address: u64,
+    ) -> Option<(Vec<u8>, usize)> {
+        let mut instruction_length = 0;
+
+        for _ in 0..2 {
+            let mut instruction = Self::create_instruction(code, operands, address.wrapping_add(instruction_length))?;
+
+            instruction.set_has_lock_prefix(has_lock_prefix);
+
+            let memory_size = instruction.memory_size().size();
+            let has_mismatched_size = operands.iter().any(|operand| match operand {
+                AssemblyOperand::Memory(memory_operand) => memory_operand.size.is_some_and(|size| size != memory_size),
+                _ => false,
+            });
+
+            if has_mismatched_size {
+                return None;
+            }
+
+            let mut encoder = Encoder::new(64);
+            let encoded_length = encoder.encode(&instruction, address).ok()? as u64;
+            let is_rip_relative = instruction.memory_base() == Register::RIP;
+
+            if !is_rip_relative || encoded_length == instruction_length {
+                return Some((encoder.take_buffer(), memory_size));
+            }
+
+            instruction_length = encoded_length;
+        }
+
+        None
+    }
+
+    fn create_instruction(
+        code: Code,
+        operands: &[AssemblyOperand],
+        next_instruction_address: u64,
+    ) -> Option<Instruction> {
+        let is_branch = matches!(
+            code.op_code().op_kind(0),
+            OpCodeOperandKind::br64_1 | OpCodeOperandKind::br64_4 | OpCodeOperandKind::br32_1 | OpCodeOperandKind::br32_4
+        );
+        let to_memory = |memory_operand: &AssemblyMemoryOperand| Self::to_memory_operand(memory_operand, next_instruction_address);
+
+        match operands {
+            [] => Some(Instruction::with(code)),
+            [AssemblyOperand::Immediate(target)] if is_branch => Instruction::with_branch(code, *target as u64).ok(),
+            [AssemblyOperand::Register(register)] => Instruction::with1(code, *register).ok(),
+            [AssemblyOperand::Immediate(immediate)] => match i32::try_from(*immediate) {
+                Ok(immediate) => Instruction::with1(code, immediate).ok(),
+                Err(_) => Instruction::with1(code, u32::try_from(*immediate).ok()?).ok(),
+            },
+            [AssemblyOperand::Memory(memory_operand)] => Instruction::with1(code, to_memory(memory_operand)?).ok(),
+            [
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Register(register_1),
+            ] => Instruction::with2(code, *register_0, *register_1).ok(),
+            [
+                AssemblyOperand::Register(register),
+                AssemblyOperand::Immediate(immediate),
+            ] => match i32::try_from(*immediate) {
+                Ok(immediate) => Instruction::with2(code, *register, immediate).ok(),
+                Err(_) => Instruction::with2(code, *register, *immediate).ok(),
+            },
+            [
+                AssemblyOperand::Register(register),
+                AssemblyOperand::Memory(memory_operand),
+            ] => Instruction::with2(code, *register, to_memory(memory_operand)?).ok(),
+            [
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Register(register),
+            ] => Instruction::with2(code, to_memory(memory_operand)?, *register).ok(),
+            [
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Immediate(immediate),
+            ] => match i32::try_from(*immediate) {
+                Ok(immediate) => Instruction::with2(code, to_memory(memory_operand)?, immediate).ok(),
+                Err(_) => Instruction::with2(code, to_memory(memory_operand)?, u32::try_from(*immediate).ok()?).ok(),
+            },
+            [
+                AssemblyOperand::Immediate(immediate),
+                AssemblyOperand::Register(register),
+            ] => Instruction::with2(code, i32::try_from(*immediate).ok()?, *register).ok(),
+            [
+                AssemblyOperand::Immediate(immediate_0),
+                AssemblyOperand::Immediate(immediate_1),
+            ] => Instruction::with2(code, i32::try_from(*immediate_0).ok()?, i32::try_from(*immediate_1).ok()?).ok(),
+            [
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Register(register_1),
+                AssemblyOperand::Register(register_2),
+            ] => Instruction::with3(code, *register_0, *register_1, *register_2).ok(),
+            [
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Register(register_1),
+                AssemblyOperand::Immediate(immediate),
+            ] => Instruction::with3(code, *register_0, *register_1, i32::try_from(*immediate).ok()?).ok(),
+            [
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Register(register_1),
+                AssemblyOperand::Memory(memory_operand),
+            ] => Instruction::with3(code, *register_0, *register_1, to_memory(memory_operand)?).ok(),
+            [
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Register(register_1),
+            ] => Instruction::with3(code, *register_0, to_memory(memory_operand)?, *register_1).ok(),
+            [
+                AssemblyOperand::Register(register),
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Immediate(immediate),
+            ] => Instruction::with3(code, *register, to_memory(memory_operand)?, i32::try_from(*immediate).ok()?).ok(),
+            [
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Register(register_0),
+                AssemblyOperand::Register(register_1),
+            ] => Instruction::with3(code, to_memory(memory_operand)?, *register_0, *register_1).ok(),
+            [
+                AssemblyOperand::Memory(memory_operand),
+                AssemblyOperand::Register(register),
+                AssemblyOperand::Immediate(immediate),
+            ] => Instruction::with3(code, to_memory(memory_operand)?, *register, i32::try_from(*immediate).ok()?).ok(),
+            _ => None,
+        }
+    }
+
+    /// Converts a parsed memory operand for the encoder. Absolute addresses beyond the reach of a 32-bit displacement are encoded
+    /// RIP relative when near enough to the instruction, and RIP relative displacements are converted to the absolute target the
+    /// encoder expects.
+    fn to_memory_operand(
+        memory_operand: &AssemblyMemoryOperand,
+        next_instruction_address: u64,
+    ) -> Option<MemoryOperand> {
+        let is_absolute = memory_operand.base == Register::None && memory_operand.index == Register::None;
+        let (base, displacement) = if memory_operand.base == Register::RIP {
+            (Register::RIP, next_instruction_address.wrapping_add(memory_operand.displacement as u64) as i64)
+        } else if is_absolute
+            && i32::try_from(memory_operand.displacement).is_err()
+            && i32::try_from(
+                memory_operand
+                    .displacement
+                    .wrapping_sub(next_instruction_address as i64),
+            )
+            .is_ok()
+        {
+            (Register::RIP, memory_operand.displacement)
+        } else {
+            (memory_operand.base, memory_operand.displacement)
+        };
+        let displacement_size = if base == Register::RIP || is_absolute {
+            8
+        } else if displacement == 0 {
+            0
+        } else if i8::try_from(displacement).is_ok() {
+            1
+        } else {
+            8
+        };
+
+        if memory_operand.index == Register::RSP {
+            return None;
+        }
+
+        Some(MemoryOperand::new(
+            base,
+            memory_operand.index,
+            if memory_operand.index == Register::None { 1 } else { memory_operand.scale },
+            displacement,
+            displacement_size,
+            false,
+            memory_operand.segment,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Assembler;
+
+    fn assemble(instruction_text: &str) -> Result<Vec<u8>, String> {
+        Assembler::assemble(instruction_text, 0x140001000)
+    }
+
+    #[test]
+    fn instructions_assemble_to_their_shortest_encoding() {
+        assert_eq!(assemble("nop"), Ok(vec![0x90]));
+        assert_eq!(assemble("ret"), Ok(vec![0xC3]));
+        assert_eq!(assemble("mov eax, 1"), Ok(vec![0xB8, 0x01, 0x00, 0x00, 0x00]));
+        assert_eq!(assemble("add rsp, 0x28"), Ok(vec![0x48, 0x83, 0xC4, 0x28]));
+        assert_eq!(assemble("xor eax, eax"), Ok(vec![0x31, 0xC0]));
+        assert_eq!(assemble("mov dword ptr [rbx+0x10], 100"), Ok(vec![0xC7, 0x43, 0x10, 0x64, 0x00, 0x00, 0x00]));
+        assert_eq!(assemble("mov eax, [rcx+rdx*4-8]"), Ok(vec![0x8B, 0x44, 0x91, 0xF8]));
+        assert_eq!(assemble("movss xmm0, dword ptr [rax]"), Ok(vec![0xF3, 0x0F, 0x10, 0x00]));
+        assert_eq!(assemble("lock inc dword ptr [rax]"), Ok(vec![0xF0, 0xFF, 0x00]));
+    }
+
+    #[test]
+    fn branches_and_rip_relative_operands_resolve_against_the_address() {
+        assert_eq!(assemble("jmp 0x140001010"), Ok(vec![0xEB, 0x0E]));
+        assert_eq!(assemble("jz 0x140001000"), Ok(vec![0x74, 0xFE]));
+        assert_eq!(assemble("call 0x140002000"), Ok(vec![0xE8, 0xFB, 0x0F, 0x00, 0x00]));
+        assert_eq!(assemble("mov eax, dword ptr [rip+0x10]"), Ok(vec![0x8B, 0x05, 0x10, 0x00, 0x00, 0x00]));
+        assert_eq!(assemble("mov eax, dword ptr [0x140002000]"), Ok(vec![0x8B, 0x05, 0xFA, 0x0F, 0x00, 0x00]));
+    }
+
+    #[test]
+    fn invalid_and_ambiguous_instructions_are_rejected() {
+        assert!(assemble("").is_err());
+        assert!(assemble("frobnicate eax").is_err());
+        assert!(assemble("mov eax, rbx").is_err());
+        assert!(assemble("inc [rax]").is_err());
+        assert!(assemble("mov byte ptr [rax], 0x1234").is_err());
+    }
+}
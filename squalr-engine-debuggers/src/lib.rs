@@ -1 +1 @@
-
+pub mod memory_watch;
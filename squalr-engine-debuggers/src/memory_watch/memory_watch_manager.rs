@@ -0,0 +1,138 @@
+use crate::memory_watch::page_trap::page_trap_session::PageTrapStopReason;
+use crate::memory_watch::page_trap::page_trap_watch::PageTrapWatch;
+use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Owns every running memory watch, such that watches can be stopped by id, and all of them torn down together when the process
+/// changes or Squalr exits. A watch left running would leave the target with trapped pages once Squalr is gone.
+pub struct MemoryWatchManager {
+    next_watch_id: AtomicU64,
+    watches: Mutex<HashMap<u64, PageTrapWatch>>,
+}
+
+impl MemoryWatchManager {
+    fn new() -> Self {
+        Self {
+            next_watch_id: AtomicU64::new(1),
+            watches: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get_instance() -> &'static MemoryWatchManager {
+        static INSTANCE: OnceLock<MemoryWatchManager> = OnceLock::new();
+
+        INSTANCE.get_or_init(|| {
+            Self::install_panic_hook();
+            MemoryWatchManager::new()
+        })
+    }
+
+    /// Starts a page trap watch on the given range, returning its id. Hits are reported as deltas, and the stop callback is invoked
+    /// once the watch ends for any reason other than failing to start.
+    pub fn start_page_trap_watch(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        size: u64,
+        watch_type: MemoryWatchType,
+        on_hits: impl Fn(u64, Vec<MemoryWatchHit>) + Send + 'static,
+        on_stopped: impl FnOnce(u64, PageTrapStopReason) + Send + 'static,
+    ) -> Result<u64, String> {
+        let watch_id = self.next_watch_id.fetch_add(1, Ordering::Relaxed);
+        let page_trap_watch = PageTrapWatch::start(
+            process_info.get_process_id_raw(),
+            process_info.get_handle(),
+            address,
+            size,
+            watch_type,
+            move |hits| on_hits(watch_id, hits),
+            move |stop_reason| on_stopped(watch_id, stop_reason),
+        )?;
+
+        match self.watches.lock() {
+            Ok(mut watches) => {
+                watches.retain(|_, page_trap_watch| !page_trap_watch.is_finished());
+                watches.insert(watch_id, page_trap_watch);
+            }
+            Err(error) => {
+                log::error!("Failed to acquire memory watch lock: {}", error);
+            }
+        }
+
+        Ok(watch_id)
+    }
+
+    /// Stops the watch with the given id, returning whether it was running.
+    pub fn stop_watch(
+        &self,
+        watch_id: u64,
+    ) -> bool {
+        let page_trap_watch = match self.watches.lock() {
+            Ok(mut watches) => watches.remove(&watch_id),
+            Err(error) => {
+                log::error!("Failed to acquire memory watch lock: {}", error);
+                None
+            }
+        };
+
+        match page_trap_watch {
+            Some(mut page_trap_watch) => {
+                let was_running = !page_trap_watch.is_finished();
+
+                page_trap_watch.stop();
+
+                was_running
+            }
+            None => false,
+        }
+    }
+
+    pub fn stop_all(&self) {
+        let page_trap_watches: Vec<PageTrapWatch> = match self.watches.lock() {
+            Ok(mut watches) => watches
+                .drain()
+                .map(|(_, page_trap_watch)| page_trap_watch)
+                .collect(),
+            Err(error) => {
+                log::error!("Failed to acquire memory watch lock: {}", error);
+                return;
+            }
+        };
+
+        for mut page_trap_watch in page_trap_watches {
+            page_trap_watch.stop();
+        }
+    }
+
+    /// Stops all watches when the main thread panics, since that takes the process down along with the debugger threads.
+    /// Panics elsewhere only end their own thread, and each debugger loop restores its pages when its own thread unwinds.
+    fn install_panic_hook() {
+        let previous_hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |panic_info| {
+            if std::thread::current().name() == Some("main") {
+                // The lock may be held by the panicking code itself, so this gives up rather than risk deadlocking.
+                let page_trap_watches: Vec<PageTrapWatch> = MemoryWatchManager::get_instance()
+                    .watches
+                    .try_lock()
+                    .map(|mut watches| {
+                        watches
+                            .drain()
+                            .map(|(_, page_trap_watch)| page_trap_watch)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                for mut page_trap_watch in page_trap_watches {
+                    page_trap_watch.stop();
+                }
+            }
+
+            previous_hook(panic_info);
+        }));
+    }
+}
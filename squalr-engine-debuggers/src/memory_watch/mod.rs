@@ -0,0 +1,2 @@
+pub mod memory_watch_manager;
+pub mod page_trap;
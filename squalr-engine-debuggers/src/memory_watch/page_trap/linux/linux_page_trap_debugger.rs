@@ -0,0 +1,852 @@
+use crate::memory_watch::page_trap::page_trap_session::{PageTrapSession, PageTrapStopReason};
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::ptr::null_mut;
+use std::thread;
+use std::time::Duration;
+
+/// The x86-64 syscall instruction, injected to run mprotect within the target.
+const SYSCALL_INSTRUCTION: [u8; 2] = [0x0F, 0x05];
+
+/// How many times a trapped instruction is stepped before giving up, such as when it keeps faulting on other trapped pages.
+const MAX_STEP_ATTEMPTS: usize = 8;
+
+#[derive(Clone, Copy, Default)]
+struct TracedThread {
+    /// Whether the thread is in a ptrace stop, and can be inspected or resumed.
+    is_stopped: bool,
+    /// A signal intercepted while the thread was stopped, delivered when it is resumed or detached.
+    pending_signal: i32,
+}
+
+/// Traps pages with ptrace on Linux. Every thread is seized, and the trapped pages are made inaccessible by injecting mprotect
+/// calls into a stopped thread. Accesses then raise SIGSEGV, which is intercepted before the target ever sees it. Write watches
+/// only revoke write access, such that reads proceed untrapped.
+pub struct LinuxPageTrapDebugger {
+    process_id: libc::pid_t,
+    page_size: u64,
+    watch_type: MemoryWatchType,
+    /// Each trapped page, along with the protection it had before being trapped.
+    trapped_pages: Vec<(u64, i32)>,
+    threads: HashMap<libc::pid_t, TracedThread>,
+    is_armed: bool,
+    is_detached: bool,
+}
+
+impl LinuxPageTrapDebugger {
+    pub fn run(session: &mut PageTrapSession) -> PageTrapStopReason {
+        let mut debugger = match Self::attach(session) {
+            Ok(debugger) => debugger,
+            Err(error) => return PageTrapStopReason::Failed(error),
+        };
+
+        session.report_armed(Ok(()));
+
+        let stop_reason = debugger.run_event_loop(session);
+
+        debugger.detach();
+
+        stop_reason
+    }
+
+    fn attach(session: &PageTrapSession) -> Result<Self, String> {
+        let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            page_size if page_size > 0 => page_size as u64,
+            _ => 4096,
+        };
+        let trapped_pages = Self::read_page_protections(session.process_id, &session.get_page_addresses(page_size))?;
+
+        if session.watch_type == MemoryWatchType::Write
+            && let Some((page_address, _)) = trapped_pages
+                .iter()
+                .find(|(_, protection)| protection & libc::PROT_WRITE == 0)
+        {
+            return Err(format!("Address 0x{:X} is not writable, so there are no writes to find.", page_address));
+        }
+
+        let mut debugger = Self {
+            process_id: session.process_id as libc::pid_t,
+            page_size,
+            watch_type: session.watch_type,
+            trapped_pages,
+            threads: HashMap::new(),
+            is_armed: false,
+            is_detached: false,
+        };
+
+        // Any failure from here on is cleaned up by the drop, which restores whatever was changed and detaches.
+        debugger.seize_threads()?;
+        debugger.stop_all_threads();
+
+        let injection_thread_id = debugger.get_injection_thread()?;
+
+        debugger.is_armed = true;
+
+        for (page_address, original_protection) in debugger.trapped_pages.clone() {
+            debugger.inject_mprotect(injection_thread_id, page_address, debugger.get_trapped_protection(original_protection))?;
+        }
+
+        for thread_id in debugger.threads.keys().copied().collect::<Vec<_>>() {
+            debugger.resume_thread(thread_id);
+        }
+
+        Ok(debugger)
+    }
+
+    fn run_event_loop(
+        &mut self,
+        session: &mut PageTrapSession,
+    ) -> PageTrapStopReason {
+        loop {
+            if session.is_stop_requested() {
+                return PageTrapStopReason::Requested;
+            }
+
+            let mut had_event = false;
+
+            // Waiting on -1 would also reap unrelated children of this process, so each traced thread is polled individually.
+            for thread_id in self.threads.keys().copied().collect::<Vec<_>>() {
+                let mut status = 0;
+                let result = unsafe { libc::waitpid(thread_id, &mut status, libc::WNOHANG | libc::__WALL) };
+
+                if result == 0 {
+                    continue;
+                }
+
+                had_event = true;
+
+                if result < 0 || libc::WIFEXITED(status) || libc::WIFSIGNALED(status) {
+                    self.threads.remove(&thread_id);
+                    continue;
+                }
+
+                if let Err(error) = self.handle_stop(session, thread_id, status) {
+                    return PageTrapStopReason::Failed(error);
+                }
+            }
+
+            if self.threads.is_empty() {
+                return PageTrapStopReason::ProcessExited;
+            }
+
+            session.flush_hits(false);
+
+            if !had_event {
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    fn handle_stop(
+        &mut self,
+        session: &mut PageTrapSession,
+        thread_id: libc::pid_t,
+        status: i32,
+    ) -> Result<(), String> {
+        let signal = libc::WSTOPSIG(status);
+        let event = (status >> 16) & 0xFF;
+
+        self.set_stopped(thread_id, true);
+
+        match event {
+            libc::PTRACE_EVENT_CLONE => {
+                if let Some(new_thread_id) = Self::get_event_message(thread_id) {
+                    // New threads start in a ptrace stop, which is reported (and resumed) like any other.
+                    self.threads.insert(new_thread_id, TracedThread::default());
+                }
+
+                self.resume_thread(thread_id);
+            }
+            libc::PTRACE_EVENT_FORK | libc::PTRACE_EVENT_VFORK => {
+                if let Some(child_process_id) = Self::get_event_message(thread_id) {
+                    self.release_forked_child(child_process_id, event == libc::PTRACE_EVENT_FORK);
+                }
+
+                self.resume_thread(thread_id);
+            }
+            libc::PTRACE_EVENT_STOP => {
+                if Self::is_group_stop_signal(signal) {
+                    // Listening keeps the thread stopped like the rest of the group, while still reporting when it continues.
+                    unsafe { libc::ptrace(libc::PTRACE_LISTEN, thread_id, null_mut::<c_void>(), null_mut::<c_void>()) };
+                    self.set_stopped(thread_id, false);
+                } else {
+                    self.resume_thread(thread_id);
+                }
+            }
+            _ if signal == libc::SIGSEGV => match self.get_trapped_fault_address(thread_id) {
+                Some(fault_address) => self.handle_trap(session, thread_id, fault_address)?,
+                None => {
+                    self.set_pending_signal(thread_id, signal);
+                    self.resume_thread(thread_id);
+                }
+            },
+            _ => {
+                self.set_pending_signal(thread_id, signal);
+                self.resume_thread(thread_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the faulting instruction, then lets it complete by stepping it with its page restored before trapping the page again.
+    /// Other threads keep running meanwhile, so their accesses to this page during the step are missed.
+    fn handle_trap(
+        &mut self,
+        session: &mut PageTrapSession,
+        thread_id: libc::pid_t,
+        fault_address: u64,
+    ) -> Result<(), String> {
+        let registers = Self::get_registers(thread_id)?;
+
+        session.record_hit(registers.rip, fault_address, Some(Self::get_register_snapshot(&registers)));
+
+        let mut restored_pages = vec![];
+        let mut next_fault_address = Some(fault_address);
+
+        for _ in 0..MAX_STEP_ATTEMPTS {
+            let Some(fault_address) = next_fault_address else {
+                break;
+            };
+            let Some(page_index) = self.find_trapped_page(fault_address) else {
+                break;
+            };
+
+            // An instruction may touch several trapped pages (ie a copy between them), in which case each is restored in turn.
+            if !restored_pages.contains(&page_index) {
+                let (page_address, original_protection) = self.trapped_pages[page_index];
+
+                self.inject_mprotect(thread_id, page_address, original_protection)?;
+                restored_pages.push(page_index);
+            }
+
+            next_fault_address = self.single_step(thread_id)?;
+        }
+
+        for page_index in restored_pages {
+            let (page_address, original_protection) = self.trapped_pages[page_index];
+
+            self.inject_mprotect(thread_id, page_address, self.get_trapped_protection(original_protection))?;
+        }
+
+        // A fault outside of the trapped pages belongs to the target, so it is delivered as usual.
+        if next_fault_address.is_some_and(|fault_address| self.find_trapped_page(fault_address).is_none()) {
+            self.set_pending_signal(thread_id, libc::SIGSEGV);
+        }
+
+        self.resume_thread(thread_id);
+
+        Ok(())
+    }
+
+    /// Steps a stopped thread by one instruction. Returns the fault address if the step raised SIGSEGV instead.
+    fn single_step(
+        &mut self,
+        thread_id: libc::pid_t,
+    ) -> Result<Option<u64>, String> {
+        loop {
+            if unsafe { libc::ptrace(libc::PTRACE_SINGLESTEP, thread_id, null_mut::<c_void>(), null_mut::<c_void>()) } < 0 {
+                return Err(format!("Failed to single step thread {}: {}", thread_id, std::io::Error::last_os_error()));
+            }
+
+            let status = self.wait_for_stop(thread_id)?;
+            let signal = libc::WSTOPSIG(status);
+            let event = (status >> 16) & 0xFF;
+
+            if event == 0 && signal == libc::SIGTRAP {
+                return Ok(None);
+            }
+
+            if event == 0 && signal == libc::SIGSEGV {
+                let fault_address = Self::get_fault_address(thread_id).unwrap_or(0);
+
+                return Ok(Some(fault_address));
+            }
+
+            // The step was preempted, such as by a pending interrupt or an asynchronous signal, so it is retried after noting the signal.
+            if event == 0 {
+                self.set_pending_signal(thread_id, signal);
+            }
+        }
+    }
+
+    /// Runs mprotect within the target from a stopped thread, by briefly replacing the instruction at its program counter with a syscall.
+    fn inject_mprotect(
+        &mut self,
+        thread_id: libc::pid_t,
+        page_address: u64,
+        protection: i32,
+    ) -> Result<(), String> {
+        let original_registers = Self::get_registers(thread_id)?;
+        let instruction_address = original_registers.rip;
+
+        unsafe { *libc::__errno_location() = 0 };
+
+        let original_word = unsafe { libc::ptrace(libc::PTRACE_PEEKTEXT, thread_id, instruction_address as *mut c_void, null_mut::<c_void>()) };
+
+        if original_word == -1 && std::io::Error::last_os_error().raw_os_error().unwrap_or(0) != 0 {
+            return Err(format!(
+                "Failed to read the instruction at 0x{:X}: {}",
+                instruction_address,
+                std::io::Error::last_os_error()
+            ));
+        }
+
+        let mut syscall_word_bytes = original_word.to_ne_bytes();
+
+        syscall_word_bytes[..SYSCALL_INSTRUCTION.len()].copy_from_slice(&SYSCALL_INSTRUCTION);
+
+        let syscall_word = libc::c_long::from_ne_bytes(syscall_word_bytes);
+        let mut syscall_registers = original_registers;
+
+        syscall_registers.rax = libc::SYS_mprotect as u64;
+        syscall_registers.rdi = page_address;
+        syscall_registers.rsi = self.page_size;
+        syscall_registers.rdx = protection as u64;
+        // Clearing the original syscall number keeps the kernel from restarting a syscall the thread was interrupted in.
+        syscall_registers.orig_rax = u64::MAX;
+
+        if unsafe {
+            libc::ptrace(
+                libc::PTRACE_POKETEXT,
+                thread_id,
+                instruction_address as *mut c_void,
+                syscall_word as *mut c_void,
+            )
+        } < 0
+        {
+            return Err(format!("Failed to inject into thread {}: {}", thread_id, std::io::Error::last_os_error()));
+        }
+
+        let result = Self::set_registers(thread_id, &syscall_registers)
+            .and_then(|_| self.single_step(thread_id))
+            .and_then(|_| Self::get_registers(thread_id));
+
+        // The original instruction and registers are restored even if the injection failed part way.
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_POKETEXT,
+                thread_id,
+                instruction_address as *mut c_void,
+                original_word as *mut c_void,
+            )
+        };
+        Self::set_registers(thread_id, &original_registers)?;
+
+        let syscall_result = result?.rax as i64;
+
+        if syscall_result < 0 {
+            return Err(format!(
+                "mprotect(0x{:X}) failed within the target: {}",
+                page_address,
+                std::io::Error::from_raw_os_error(-syscall_result as i32)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Seizes every thread, rescanning until no new threads appear. Threads created afterwards are traced automatically.
+    fn seize_threads(&mut self) -> Result<(), String> {
+        let options = libc::PTRACE_O_TRACECLONE | libc::PTRACE_O_TRACEFORK | libc::PTRACE_O_TRACEVFORK;
+
+        loop {
+            let task_entries = std::fs::read_dir(format!("/proc/{}/task", self.process_id))
+                .map_err(|error| format!("Failed to list the threads of process {}: {}", self.process_id, error))?;
+            let mut seized_new_thread = false;
+
+            for task_entry in task_entries.flatten() {
+                let Some(thread_id) = task_entry
+                    .file_name()
+                    .to_str()
+                    .and_then(|thread_id| thread_id.parse::<libc::pid_t>().ok())
+                else {
+                    continue;
+                };
+
+                if self.threads.contains_key(&thread_id) {
+                    continue;
+                }
+
+                if unsafe { libc::ptrace(libc::PTRACE_SEIZE, thread_id, null_mut::<c_void>(), options as *mut c_void) } < 0 {
+                    let error = std::io::Error::last_os_error();
+
+                    // Threads can exit between listing and seizing them.
+                    if error.raw_os_error() == Some(libc::ESRCH) {
+                        continue;
+                    }
+
+                    return Err(match error.raw_os_error() {
+                        Some(libc::EPERM) | Some(libc::EACCES) => format!(
+                            "Failed to attach to process {}: permission denied, this needs CAP_SYS_PTRACE or same-user (check /proc/sys/kernel/yama/ptrace_scope), and no other debugger attached.",
+                            self.process_id
+                        ),
+                        _ => format!("Failed to attach to thread {}: {}", thread_id, error),
+                    });
+                }
+
+                self.threads.insert(thread_id, TracedThread::default());
+                seized_new_thread = true;
+            }
+
+            if !seized_new_thread {
+                break;
+            }
+        }
+
+        if self.threads.is_empty() {
+            return Err(format!("Process {} has no threads to attach to.", self.process_id));
+        }
+
+        Ok(())
+    }
+
+    /// Interrupts every running thread and waits until each is in a ptrace stop.
+    fn stop_all_threads(&mut self) {
+        let mut threads_to_stop: Vec<libc::pid_t> = self
+            .threads
+            .iter()
+            .filter(|(_, traced_thread)| !traced_thread.is_stopped)
+            .map(|(thread_id, _)| *thread_id)
+            .collect();
+
+        for thread_id in &threads_to_stop {
+            unsafe { libc::ptrace(libc::PTRACE_INTERRUPT, *thread_id, null_mut::<c_void>(), null_mut::<c_void>()) };
+        }
+
+        while let Some(thread_id) = threads_to_stop.pop() {
+            let status = match self.wait_for_stop(thread_id) {
+                Ok(status) => status,
+                Err(_) => continue,
+            };
+            let signal = libc::WSTOPSIG(status);
+            let event = (status >> 16) & 0xFF;
+
+            match event {
+                libc::PTRACE_EVENT_CLONE => {
+                    if let Some(new_thread_id) = Self::get_event_message(thread_id) {
+                        self.threads.insert(new_thread_id, TracedThread::default());
+                        threads_to_stop.push(new_thread_id);
+                    }
+                }
+                libc::PTRACE_EVENT_FORK | libc::PTRACE_EVENT_VFORK => {
+                    if let Some(child_process_id) = Self::get_event_message(thread_id) {
+                        self.release_forked_child(child_process_id, event == libc::PTRACE_EVENT_FORK);
+                    }
+                }
+                libc::PTRACE_EVENT_STOP => {}
+                // A fault on a trapped page is simply retried once the page is restored, rather than being delivered.
+                _ if signal == libc::SIGSEGV && self.get_trapped_fault_address(thread_id).is_some() => {}
+                _ => self.set_pending_signal(thread_id, signal),
+            }
+        }
+    }
+
+    /// Waits for a thread to enter a ptrace stop, returning its wait status. Fails if the thread exited instead.
+    fn wait_for_stop(
+        &mut self,
+        thread_id: libc::pid_t,
+    ) -> Result<i32, String> {
+        let mut status = 0;
+
+        loop {
+            if unsafe { libc::waitpid(thread_id, &mut status, libc::__WALL) } < 0 {
+                let error = std::io::Error::last_os_error();
+
+                if error.raw_os_error() == Some(libc::EINTR) {
+                    continue;
+                }
+
+                self.threads.remove(&thread_id);
+
+                return Err(format!("Failed to wait on thread {}: {}", thread_id, error));
+            }
+
+            if libc::WIFSTOPPED(status) {
+                self.set_stopped(thread_id, true);
+
+                return Ok(status);
+            }
+
+            self.threads.remove(&thread_id);
+
+            return Err(format!("Thread {} exited.", thread_id));
+        }
+    }
+
+    /// Forked children inherit the trapped pages, but not the tracer, so the pages are restored before the child is let go.
+    /// A vfork child shares memory with the parent, so restoring its pages would untrap the parent too. It is released as is.
+    fn release_forked_child(
+        &mut self,
+        child_process_id: libc::pid_t,
+        restore_pages: bool,
+    ) {
+        let mut child_debugger = Self {
+            process_id: child_process_id,
+            page_size: self.page_size,
+            watch_type: self.watch_type,
+            trapped_pages: self.trapped_pages.clone(),
+            threads: HashMap::from([(child_process_id, TracedThread::default())]),
+            is_armed: self.is_armed && restore_pages,
+            is_detached: false,
+        };
+
+        // The child starts in a ptrace stop, which must be reported before it can be injected into.
+        if child_debugger.wait_for_stop(child_process_id).is_ok() {
+            child_debugger.detach();
+        }
+    }
+
+    /// Restores the trapped pages and detaches from every thread, delivering any intercepted signals.
+    fn detach(&mut self) {
+        if self.is_detached {
+            return;
+        }
+
+        self.is_detached = true;
+        self.stop_all_threads();
+
+        if self.is_armed {
+            match self.get_injection_thread() {
+                Ok(injection_thread_id) => {
+                    for (page_address, original_protection) in self.trapped_pages.clone() {
+                        if let Err(error) = self.inject_mprotect(injection_thread_id, page_address, original_protection) {
+                            log::error!("Failed to restore a trapped page: {}", error);
+                        }
+                    }
+                }
+                Err(error) => log::error!("Failed to restore trapped pages: {}", error),
+            }
+
+            self.is_armed = false;
+        }
+
+        for (thread_id, traced_thread) in self.threads.drain() {
+            unsafe {
+                libc::ptrace(
+                    libc::PTRACE_DETACH,
+                    thread_id,
+                    null_mut::<c_void>(),
+                    traced_thread.pending_signal as *mut c_void,
+                )
+            };
+        }
+    }
+
+    fn resume_thread(
+        &mut self,
+        thread_id: libc::pid_t,
+    ) {
+        if let Some(traced_thread) = self.threads.get_mut(&thread_id) {
+            let pending_signal = traced_thread.pending_signal;
+
+            traced_thread.pending_signal = 0;
+            traced_thread.is_stopped = false;
+
+            unsafe { libc::ptrace(libc::PTRACE_CONT, thread_id, null_mut::<c_void>(), pending_signal as *mut c_void) };
+        }
+    }
+
+    fn set_stopped(
+        &mut self,
+        thread_id: libc::pid_t,
+        is_stopped: bool,
+    ) {
+        if let Some(traced_thread) = self.threads.get_mut(&thread_id) {
+            traced_thread.is_stopped = is_stopped;
+        }
+    }
+
+    fn set_pending_signal(
+        &mut self,
+        thread_id: libc::pid_t,
+        signal: i32,
+    ) {
+        if let Some(traced_thread) = self.threads.get_mut(&thread_id) {
+            traced_thread.pending_signal = signal;
+        }
+    }
+
+    /// Gets a stopped thread to inject syscalls from, preferring the main thread.
+    fn get_injection_thread(&self) -> Result<libc::pid_t, String> {
+        if self
+            .threads
+            .get(&self.process_id)
+            .map(|traced_thread| traced_thread.is_stopped)
+            .unwrap_or(false)
+        {
+            return Ok(self.process_id);
+        }
+
+        self.threads
+            .iter()
+            .find(|(_, traced_thread)| traced_thread.is_stopped)
+            .map(|(thread_id, _)| *thread_id)
+            .ok_or_else(|| format!("No stopped thread of process {} is available.", self.process_id))
+    }
+
+    fn find_trapped_page(
+        &self,
+        address: u64,
+    ) -> Option<usize> {
+        self.trapped_pages
+            .iter()
+            .position(|(page_address, _)| address >= *page_address && address - *page_address < self.page_size)
+    }
+
+    /// Gets the fault address of a thread stopped by SIGSEGV, if it lies within a trapped page.
+    fn get_trapped_fault_address(
+        &self,
+        thread_id: libc::pid_t,
+    ) -> Option<u64> {
+        if !self.is_armed {
+            return None;
+        }
+
+        Self::get_fault_address(thread_id).filter(|fault_address| self.find_trapped_page(*fault_address).is_some())
+    }
+
+    fn get_fault_address(thread_id: libc::pid_t) -> Option<u64> {
+        let mut signal_info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+
+        if unsafe {
+            libc::ptrace(
+                libc::PTRACE_GETSIGINFO,
+                thread_id,
+                null_mut::<c_void>(),
+                &mut signal_info as *mut _ as *mut c_void,
+            )
+        } < 0
+        {
+            return None;
+        }
+
+        if signal_info.si_signo != libc::SIGSEGV {
+            return None;
+        }
+
+        Some(unsafe { signal_info.si_addr() } as u64)
+    }
+
+    fn get_event_message(thread_id: libc::pid_t) -> Option<libc::pid_t> {
+        let mut event_message: libc::c_ulong = 0;
+
+        if unsafe {
+            libc::ptrace(
+                libc::PTRACE_GETEVENTMSG,
+                thread_id,
+                null_mut::<c_void>(),
+                &mut event_message as *mut _ as *mut c_void,
+            )
+        } < 0
+        {
+            return None;
+        }
+
+        Some(event_message as libc::pid_t)
+    }
+
+    fn get_registers(thread_id: libc::pid_t) -> Result<libc::user_regs_struct, String> {
+        let mut registers: libc::user_regs_struct = unsafe { std::mem::zeroed() };
+
+        if unsafe { libc::ptrace(libc::PTRACE_GETREGS, thread_id, null_mut::<c_void>(), &mut registers as *mut _ as *mut c_void) } < 0 {
+            return Err(format!(
+                "Failed to read the registers of thread {}: {}",
+                thread_id,
+                std::io::Error::last_os_error()
+            ));
+        }
+
+        Ok(registers)
+    }
+
+    fn set_registers(
+        thread_id: libc::pid_t,
+        registers: &libc::user_regs_struct,
+    ) -> Result<(), String> {
+        if unsafe { libc::ptrace(libc::PTRACE_SETREGS, thread_id, null_mut::<c_void>(), registers as *const _ as *mut c_void) } < 0 {
+            return Err(format!(
+                "Failed to write the registers of thread {}: {}",
+                thread_id,
+                std::io::Error::last_os_error()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Gets the protection a page is given while trapped. Write watches keep the page readable, since only writes should fault.
+    fn get_trapped_protection(
+        &self,
+        original_protection: i32,
+    ) -> i32 {
+        match self.watch_type {
+            MemoryWatchType::Access => libc::PROT_NONE,
+            MemoryWatchType::Write => original_protection & !libc::PROT_WRITE,
+        }
+    }
+
+    fn get_register_snapshot(registers: &libc::user_regs_struct) -> RegisterSnapshot {
+        RegisterSnapshot {
+            is_32_bit: false,
+            rax: registers.rax,
+            rbx: registers.rbx,
+            rcx: registers.rcx,
+            rdx: registers.rdx,
+            rsi: registers.rsi,
+            rdi: registers.rdi,
+            rbp: registers.rbp,
+            rsp: registers.rsp,
+            r8: registers.r8,
+            r9: registers.r9,
+            r10: registers.r10,
+            r11: registers.r11,
+            r12: registers.r12,
+            r13: registers.r13,
+            r14: registers.r14,
+            r15: registers.r15,
+            rip: registers.rip,
+            rflags: registers.eflags,
+        }
+    }
+
+    fn is_group_stop_signal(signal: i32) -> bool {
+        matches!(signal, libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU)
+    }
+
+    /// Reads the protection of each page from /proc/pid/maps, such that it can be restored exactly.
+    fn read_page_protections(
+        process_id: u32,
+        page_addresses: &[u64],
+    ) -> Result<Vec<(u64, i32)>, String> {
+        let maps = std::fs::read_to_string(format!("/proc/{}/maps", process_id))
+            .map_err(|error| format!("Failed to read the memory map of process {}: {}", process_id, error))?;
+        let mappings: Vec<(u64, u64, &str)> = maps
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (start_address, end_address) = fields.next()?.split_once('-')?;
+
+                Some((
+                    u64::from_str_radix(start_address, 16).ok()?,
+                    u64::from_str_radix(end_address, 16).ok()?,
+                    fields.next()?,
+                ))
+            })
+            .collect();
+
+        page_addresses
+            .iter()
+            .map(|page_address| {
+                let (_, _, permissions) = mappings
+                    .iter()
+                    .find(|(start_address, end_address, _)| page_address >= start_address && page_address < end_address)
+                    .ok_or_else(|| format!("Address 0x{:X} is not mapped.", page_address))?;
+                let permissions = permissions.as_bytes();
+                let mut protection = libc::PROT_NONE;
+
+                if permissions.first() == Some(&b'r') {
+                    protection |= libc::PROT_READ;
+                }
+
+                if permissions.get(1) == Some(&b'w') {
+                    protection |= libc::PROT_WRITE;
+                }
+
+                if permissions.get(2) == Some(&b'x') {
+                    protection |= libc::PROT_EXEC;
+                }
+
+                if protection == libc::PROT_NONE {
+                    return Err(format!("Address 0x{:X} is already inaccessible, so it cannot be trapped.", page_address));
+                }
+
+                Ok((*page_address, protection))
+            })
+            .collect()
+    }
+}
+
+impl Drop for LinuxPageTrapDebugger {
+    fn drop(&mut self) {
+        // Covers attach failures and panics within the loop, which would otherwise leave the target with inaccessible pages.
+        self.detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory_watch::page_trap::page_trap_watch::PageTrapWatch;
+    use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[repr(C, align(4096))]
+    struct TrapTarget([u64; 512]);
+
+    static TRAP_TARGET: TrapTarget = TrapTarget([0; 512]);
+
+    #[test]
+    fn trapped_reads_are_reported_and_the_target_survives_detaching() {
+        let child_process_id = unsafe { libc::fork() };
+
+        if child_process_id == 0 {
+            loop {
+                unsafe { std::ptr::read_volatile(&TRAP_TARGET.0[8]) };
+                std::hint::spin_loop();
+            }
+        }
+
+        assert!(child_process_id > 0);
+
+        let reported_hits = Arc::new(Mutex::new(vec![]));
+        let reported_hits_clone = reported_hits.clone();
+        let watch_address = &TRAP_TARGET.0[8] as *const u64 as u64;
+        let page_trap_watch = PageTrapWatch::start(
+            child_process_id as u32,
+            child_process_id as u64,
+            watch_address,
+            8,
+            MemoryWatchType::Access,
+            move |hits| reported_hits_clone.lock().unwrap().extend(hits),
+            |_stop_reason| {},
+        );
+        let started_at = Instant::now();
+
+        while page_trap_watch.is_ok() && reported_hits.lock().unwrap().is_empty() && started_at.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let start_result = page_trap_watch.map(drop);
+
+        // Give the child a moment to fault if any page was left trapped.
+        std::thread::sleep(Duration::from_millis(100));
+
+        let mut status = 0;
+        let is_child_alive = unsafe { libc::waitpid(child_process_id, &mut status, libc::WNOHANG) } == 0;
+
+        unsafe {
+            libc::kill(child_process_id, libc::SIGKILL);
+            libc::waitpid(child_process_id, &mut status, 0);
+        }
+
+        assert_eq!(start_result, Ok(()));
+        assert!(is_child_alive);
+
+        let reported_hits = reported_hits.lock().unwrap();
+
+        assert!(!reported_hits.is_empty());
+        assert!(
+            reported_hits
+                .iter()
+                .all(|hit| hit.access_address == watch_address && hit.instruction_address != 0 && hit.hit_count > 0)
+        );
+        assert!(
+            reported_hits
+                .iter()
+                .all(|hit| hit.registers.as_ref().map(|registers| registers.rip) == Some(hit.instruction_address))
+        );
+    }
+}
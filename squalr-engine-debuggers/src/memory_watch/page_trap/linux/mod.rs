@@ -0,0 +1 @@
+pub mod linux_page_trap_debugger;
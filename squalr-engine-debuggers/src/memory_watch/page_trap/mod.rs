@@ -0,0 +1,9 @@
+pub mod page_trap_hit_collector;
+pub mod page_trap_session;
+pub mod page_trap_watch;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod linux;
+
+#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+mod windows;
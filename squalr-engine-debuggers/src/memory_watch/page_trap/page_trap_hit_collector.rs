@@ -0,0 +1,142 @@
+use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Aggregates trapped accesses by the instruction that performed them. A busy page traps thousands of times per second,
+/// so hits are counted here and reported as deltas at a fixed interval, rather than sending an event per access.
+struct PendingHit {
+    access_address: u64,
+    hit_count: u64,
+    registers: Option<RegisterSnapshot>,
+}
+
+pub struct PageTrapHitCollector {
+    watch_start: u64,
+    watch_end: u64,
+    /// Pending hits keyed by instruction address, along with the most recent address and registers of each instruction.
+    pending_hits: HashMap<u64, PendingHit>,
+    last_flush: Instant,
+}
+
+impl PageTrapHitCollector {
+    const FLUSH_INTERVAL_MS: u64 = 100;
+
+    pub fn new(
+        watch_address: u64,
+        watch_size: u64,
+    ) -> Self {
+        Self {
+            watch_start: watch_address,
+            watch_end: watch_address.saturating_add(watch_size.max(1)),
+            pending_hits: HashMap::new(),
+            last_flush: Instant::now(),
+        }
+    }
+
+    /// Records a trapped access. Traps fire for the entire page, so accesses outside of the watched range are ignored.
+    /// Returns whether the access was recorded.
+    pub fn record(
+        &mut self,
+        instruction_address: u64,
+        access_address: u64,
+        registers: Option<RegisterSnapshot>,
+    ) -> bool {
+        if access_address < self.watch_start || access_address >= self.watch_end {
+            return false;
+        }
+
+        let pending_hit = self
+            .pending_hits
+            .entry(instruction_address)
+            .or_insert(PendingHit {
+                access_address,
+                hit_count: 0,
+                registers: None,
+            });
+
+        pending_hit.access_address = access_address;
+        pending_hit.hit_count += 1;
+
+        if registers.is_some() {
+            pending_hit.registers = registers;
+        }
+
+        true
+    }
+
+    /// Takes the pending hits if the flush interval has elapsed, or unconditionally if forced.
+    pub fn take_hits(
+        &mut self,
+        force: bool,
+    ) -> Option<Vec<MemoryWatchHit>> {
+        if self.pending_hits.is_empty() || (!force && self.last_flush.elapsed() < Duration::from_millis(Self::FLUSH_INTERVAL_MS)) {
+            return None;
+        }
+
+        self.last_flush = Instant::now();
+
+        let mut hits: Vec<MemoryWatchHit> = self
+            .pending_hits
+            .drain()
+            .map(|(instruction_address, pending_hit)| MemoryWatchHit {
+                instruction_address,
+                access_address: pending_hit.access_address,
+                hit_count: pending_hit.hit_count,
+                registers: pending_hit.registers,
+            })
+            .collect();
+
+        hits.sort_by_key(|hit| hit.instruction_address);
+
+        Some(hits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PageTrapHitCollector;
+    use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+
+    #[test]
+    fn hits_are_filtered_to_the_watched_range_and_aggregated() {
+        let mut hit_collector = PageTrapHitCollector::new(0x1000, 4);
+
+        assert!(hit_collector.record(0x400010, 0x1000, None));
+        assert!(hit_collector.record(0x400010, 0x1003, None));
+        assert!(hit_collector.record(0x400000, 0x1002, None));
+        assert!(!hit_collector.record(0x400020, 0x1004, None));
+        assert!(!hit_collector.record(0x400020, 0x0FFF, None));
+
+        let hits = hit_collector.take_hits(true).unwrap_or_default();
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!((hits[0].instruction_address, hits[0].hit_count), (0x400000, 1));
+        assert_eq!((hits[1].instruction_address, hits[1].access_address, hits[1].hit_count), (0x400010, 0x1003, 2));
+
+        // Counts are deltas, so nothing is reported again until new hits arrive.
+        assert!(hit_collector.take_hits(true).is_none());
+    }
+
+    #[test]
+    fn hits_keep_the_most_recent_registers() {
+        let mut hit_collector = PageTrapHitCollector::new(0x1000, 4);
+        let first_registers = RegisterSnapshot {
+            rax: 1,
+            ..RegisterSnapshot::default()
+        };
+        let second_registers = RegisterSnapshot {
+            rax: 2,
+            ..RegisterSnapshot::default()
+        };
+
+        hit_collector.record(0x400010, 0x1000, Some(first_registers));
+        hit_collector.record(0x400010, 0x1000, Some(second_registers.clone()));
+        hit_collector.record(0x400010, 0x1000, None);
+
+        let hits = hit_collector.take_hits(true).unwrap_or_default();
+
+        assert_eq!(hits[0].hit_count, 3);
+        assert_eq!(hits[0].registers, Some(second_registers));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::memory_watch::page_trap::page_trap_hit_collector::PageTrapHitCollector;
+use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+
+/// Why a page trap debugger loop ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PageTrapStopReason {
+    Requested,
+    ProcessExited,
+    Failed(String),
+}
+
+impl fmt::Display for PageTrapStopReason {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Requested => write!(formatter, "Stopped."),
+            Self::ProcessExited => write!(formatter, "The process exited."),
+            Self::Failed(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+/// The state handed to a platform debugger loop, shared with the watch that started it.
+pub struct PageTrapSession {
+    pub process_id: u32,
+    pub process_handle: u64,
+    pub watch_address: u64,
+    pub watch_size: u64,
+    pub watch_type: MemoryWatchType,
+    stop_requested: Arc<AtomicBool>,
+    armed_sender: Option<Sender<Result<(), String>>>,
+    hit_collector: PageTrapHitCollector,
+    on_hits: Box<dyn FnMut(Vec<MemoryWatchHit>) + Send>,
+}
+
+impl PageTrapSession {
+    pub fn new(
+        process_id: u32,
+        process_handle: u64,
+        watch_address: u64,
+        watch_size: u64,
+        stop_requested: Arc<AtomicBool>,
+        armed_sender: Sender<Result<(), String>>,
+        on_hits: Box<dyn FnMut(Vec<MemoryWatchHit>) + Send>,
+    ) -> Self {
+        Self {
+            process_id,
+            process_handle,
+            watch_address,
+            watch_size,
+            watch_type: MemoryWatchType::default(),
+            stop_requested,
+            armed_sender: Some(armed_sender),
+            hit_collector: PageTrapHitCollector::new(watch_address, watch_size),
+            on_hits,
+        }
+    }
+
+    pub fn with_watch_type(
+        mut self,
+        watch_type: MemoryWatchType,
+    ) -> Self {
+        self.watch_type = watch_type;
+        self
+    }
+
+    /// Gets the base address of every page spanned by the watched range.
+    pub fn get_page_addresses(
+        &self,
+        page_size: u64,
+    ) -> Vec<u64> {
+        let first_page = self.watch_address - self.watch_address % page_size;
+        let last_byte = self.watch_address.saturating_add(self.watch_size.max(1) - 1);
+        let last_page = last_byte - last_byte % page_size;
+
+        (first_page..=last_page).step_by(page_size as usize).collect()
+    }
+
+    pub fn is_stop_requested(&self) -> bool {
+        self.stop_requested.load(Ordering::Acquire)
+    }
+
+    /// Reports whether the pages were trapped. The watch waits on this before returning, such that attach errors are returned
+    /// to the caller directly. Only the first report is delivered.
+    pub fn report_armed(
+        &mut self,
+        result: Result<(), String>,
+    ) {
+        if let Some(armed_sender) = self.armed_sender.take() {
+            let _ = armed_sender.send(result);
+        }
+    }
+
+    pub fn was_armed_reported(&self) -> bool {
+        self.armed_sender.is_none()
+    }
+
+    pub fn record_hit(
+        &mut self,
+        instruction_address: u64,
+        access_address: u64,
+        registers: Option<RegisterSnapshot>,
+    ) {
+        self.hit_collector
+            .record(instruction_address, access_address, registers);
+    }
+
+    /// Sends the pending hits if the report interval has elapsed, or unconditionally if forced.
+    pub fn flush_hits(
+        &mut self,
+        force: bool,
+    ) {
+        if let Some(hits) = self.hit_collector.take_hits(force) {
+            (self.on_hits)(hits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PageTrapSession;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::mpsc;
+
+    #[test]
+    fn page_addresses_cover_ranges_spanning_a_page_boundary() {
+        let (armed_sender, _armed_receiver) = mpsc::channel();
+        let create_session = |watch_address, watch_size| {
+            PageTrapSession::new(
+                1,
+                0,
+                watch_address,
+                watch_size,
+                Arc::new(AtomicBool::new(false)),
+                armed_sender.clone(),
+                Box::new(|_hits| {}),
+            )
+        };
+
+        assert_eq!(create_session(0x1FFE, 4).get_page_addresses(0x1000), vec![0x1000, 0x2000]);
+        assert_eq!(create_session(0x1000, 0x1000).get_page_addresses(0x1000), vec![0x1000]);
+        assert_eq!(create_session(0x1FFF, 0).get_page_addresses(0x1000), vec![0x1000]);
+    }
+}
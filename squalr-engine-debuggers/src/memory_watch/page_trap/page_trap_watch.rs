@@ -0,0 +1,143 @@
+use crate::memory_watch::page_trap::page_trap_session::{PageTrapSession, PageTrapStopReason};
+use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+use crate::memory_watch::page_trap::linux::linux_page_trap_debugger::LinuxPageTrapDebugger as PageTrapDebuggerImpl;
+#[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+use crate::memory_watch::page_trap::windows::windows_page_trap_debugger::WindowsPageTrapDebugger as PageTrapDebuggerImpl;
+
+/// Finds what accesses an address by revoking access to the pages containing it. Each access then faults into a debugger loop,
+/// which records the faulting instruction, restores access, single steps the instruction, and traps the page again.
+/// This works without hardware breakpoints, but every access to the entire page is trapped, which slows the target down heavily.
+pub struct PageTrapWatch {
+    stop_requested: Arc<AtomicBool>,
+    /// Disconnects once the debugger loop has detached, since the loop thread holds the only sender.
+    detached_receiver: Receiver<()>,
+    debugger_thread: Option<JoinHandle<()>>,
+}
+
+impl PageTrapWatch {
+    const ARM_TIMEOUT_MS: u64 = 5000;
+    const DETACH_TIMEOUT_MS: u64 = 2000;
+
+    /// Attaches to the process and traps the pages spanned by the given range. Returns once the pages are trapped, or with the
+    /// reason they could not be. Write watches still trap reads on platforms that cannot revoke write access alone, those hits are
+    /// just not reported. The stop callback is only invoked for watches that were started successfully.
+    pub fn start(
+        process_id: u32,
+        process_handle: u64,
+        address: u64,
+        size: u64,
+        watch_type: MemoryWatchType,
+        on_hits: impl FnMut(Vec<MemoryWatchHit>) + Send + 'static,
+        on_stopped: impl FnOnce(PageTrapStopReason) + Send + 'static,
+    ) -> Result<Self, String> {
+        if address.checked_add(size.max(1)).is_none() {
+            return Err(format!("The range at 0x{:X} overflows the address space.", address));
+        }
+
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let (armed_sender, armed_receiver) = mpsc::channel();
+        let (detached_sender, detached_receiver) = mpsc::channel::<()>();
+        let mut session = PageTrapSession::new(
+            process_id,
+            process_handle,
+            address,
+            size.max(1),
+            stop_requested.clone(),
+            armed_sender,
+            Box::new(on_hits),
+        )
+        .with_watch_type(watch_type);
+
+        // Debug events are only delivered to the thread that attached, so the whole loop lives on a dedicated thread.
+        let debugger_thread = thread::Builder::new()
+            .name(format!("page-trap-{}", process_id))
+            .spawn(move || {
+                let stop_reason = Self::run_debugger(&mut session);
+                let was_armed = session.was_armed_reported();
+
+                session.report_armed(Err(stop_reason.to_string()));
+                session.flush_hits(true);
+                drop(detached_sender);
+
+                if was_armed {
+                    on_stopped(stop_reason);
+                }
+            })
+            .map_err(|error| format!("Failed to spawn the page trap debugger thread: {}", error))?;
+
+        let mut page_trap_watch = Self {
+            stop_requested,
+            detached_receiver,
+            debugger_thread: Some(debugger_thread),
+        };
+
+        match armed_receiver.recv_timeout(Duration::from_millis(Self::ARM_TIMEOUT_MS)) {
+            Ok(Ok(())) => Ok(page_trap_watch),
+            Ok(Err(error)) => {
+                page_trap_watch.stop();
+                Err(error)
+            }
+            Err(_) => {
+                page_trap_watch.stop();
+                Err(format!("Timed out trapping the pages of process {}.", process_id))
+            }
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.debugger_thread
+            .as_ref()
+            .map(|debugger_thread| debugger_thread.is_finished())
+            .unwrap_or(true)
+    }
+
+    /// Requests that the debugger loop restore the trapped pages and detach, waiting briefly for it to do so.
+    pub fn stop(&mut self) {
+        self.stop_requested.store(true, Ordering::Release);
+
+        let Some(debugger_thread) = self.debugger_thread.take() else {
+            return;
+        };
+
+        // Stopping from within a callback on the debugger thread itself cannot wait, the loop exits once the callback returns.
+        if debugger_thread.thread().id() == thread::current().id() {
+            return;
+        }
+
+        match self
+            .detached_receiver
+            .recv_timeout(Duration::from_millis(Self::DETACH_TIMEOUT_MS))
+        {
+            Err(RecvTimeoutError::Timeout) => {
+                log::error!("Page trap debugger did not detach in time, the target process may still have trapped pages.");
+            }
+            _ => {
+                let _ = debugger_thread.join();
+            }
+        }
+    }
+
+    #[cfg(any(all(target_os = "linux", target_arch = "x86_64"), all(target_os = "windows", target_arch = "x86_64")))]
+    fn run_debugger(session: &mut PageTrapSession) -> PageTrapStopReason {
+        PageTrapDebuggerImpl::run(session)
+    }
+
+    #[cfg(not(any(all(target_os = "linux", target_arch = "x86_64"), all(target_os = "windows", target_arch = "x86_64"))))]
+    fn run_debugger(_session: &mut PageTrapSession) -> PageTrapStopReason {
+        PageTrapStopReason::Failed("Page trap watches are not supported on this platform.".to_string())
+    }
+}
+
+impl Drop for PageTrapWatch {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1 @@
+pub mod windows_page_trap_debugger;
@@ -0,0 +1,489 @@
+use crate::memory_watch::page_trap::page_trap_session::{PageTrapSession, PageTrapStopReason};
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::time::{Duration, Instant};
+use windows_sys::Win32::Foundation::{
+    CloseHandle, DBG_CONTINUE, DBG_EXCEPTION_NOT_HANDLED, HANDLE, NTSTATUS, STATUS_BREAKPOINT, STATUS_GUARD_PAGE_VIOLATION, STATUS_SINGLE_STEP,
+    STATUS_WX86_BREAKPOINT, STATUS_WX86_SINGLE_STEP,
+};
+use windows_sys::Win32::System::Diagnostics::Debug::{
+    CONTEXT, CONTEXT_CONTROL_AMD64, CONTEXT_INTEGER_AMD64, CREATE_PROCESS_DEBUG_EVENT, ContinueDebugEvent, DEBUG_EVENT, DebugActiveProcess,
+    DebugActiveProcessStop, DebugSetProcessKillOnExit, EXCEPTION_DEBUG_EVENT, EXCEPTION_RECORD, EXIT_PROCESS_DEBUG_EVENT, GetThreadContext,
+    LOAD_DLL_DEBUG_EVENT, SetThreadContext, WOW64_CONTEXT, WOW64_CONTEXT_CONTROL, WOW64_CONTEXT_INTEGER, WaitForDebugEvent, Wow64GetThreadContext,
+    Wow64SetThreadContext,
+};
+use windows_sys::Win32::System::Memory::{MEM_COMMIT, MEMORY_BASIC_INFORMATION, PAGE_GUARD, PAGE_NOACCESS, VirtualProtectEx, VirtualQueryEx};
+use windows_sys::Win32::System::Threading::{
+    IsWow64Process, OpenThread, ResumeThread, SuspendThread, THREAD_GET_CONTEXT, THREAD_SET_CONTEXT, THREAD_SUSPEND_RESUME,
+};
+
+/// Pages are always 4 KiB on x86-64 Windows.
+const PAGE_SIZE: u64 = 0x1000;
+
+/// The x86 trap flag, which raises a single step exception after the next instruction.
+const TRAP_FLAG: u32 = 0x100;
+
+/// The access type a guard page violation reports for writes. Reads report 0, and executes report 8.
+const ACCESS_TYPE_WRITE: usize = 1;
+
+const DEBUG_EVENT_TIMEOUT_MS: u32 = 50;
+const ATTACH_TIMEOUT_MS: u64 = 5000;
+const DETACH_TIMEOUT_MS: u64 = 1000;
+
+/// The x64 thread context must be 16 byte aligned, which the binding does not guarantee on its own.
+#[repr(C, align(16))]
+struct AlignedContext(CONTEXT);
+
+/// Traps pages with PAGE_GUARD on Windows. A guard page raises a one-shot exception on access, after which the access is retried and
+/// succeeds. The accessing thread is then single stepped, such that the guard can be set again once the access has completed.
+pub struct WindowsPageTrapDebugger {
+    process_id: u32,
+    process_handle: HANDLE,
+    is_wow64: bool,
+    watch_type: MemoryWatchType,
+    /// Each trapped page, along with the protection it had before being trapped.
+    trapped_pages: Vec<(u64, u32)>,
+    /// Threads stepping over a trapped access, along with the index of the page to guard again once the step completes.
+    stepping_threads: HashMap<u32, usize>,
+    /// Hits recorded while handling events, passed on to the session by the event loop.
+    recorded_hits: Vec<(u64, u64, Option<RegisterSnapshot>)>,
+    received_attach_breakpoint: bool,
+    received_wow64_attach_breakpoint: bool,
+    is_attached: bool,
+    is_armed: bool,
+}
+
+impl WindowsPageTrapDebugger {
+    pub fn run(session: &mut PageTrapSession) -> PageTrapStopReason {
+        let mut debugger = match Self::attach(session) {
+            Ok(debugger) => debugger,
+            Err(error) => return PageTrapStopReason::Failed(error),
+        };
+
+        let stop_reason = debugger.run_event_loop(session);
+
+        debugger.detach();
+
+        stop_reason
+    }
+
+    fn attach(session: &PageTrapSession) -> Result<Self, String> {
+        let process_handle = session.process_handle as HANDLE;
+        let trapped_pages = Self::query_page_protections(process_handle, &session.get_page_addresses(PAGE_SIZE))?;
+        let mut is_wow64 = 0;
+
+        unsafe {
+            IsWow64Process(process_handle, &mut is_wow64);
+
+            if DebugActiveProcess(session.process_id) == 0 {
+                return Err(format!(
+                    "Failed to attach to process {}: {}. Another debugger may already be attached.",
+                    session.process_id,
+                    std::io::Error::last_os_error()
+                ));
+            }
+
+            // Without this, exiting Squalr while attached would kill the target.
+            DebugSetProcessKillOnExit(0);
+        }
+
+        Ok(Self {
+            process_id: session.process_id,
+            process_handle,
+            is_wow64: is_wow64 != 0,
+            watch_type: session.watch_type,
+            trapped_pages,
+            stepping_threads: HashMap::new(),
+            recorded_hits: vec![],
+            received_attach_breakpoint: false,
+            received_wow64_attach_breakpoint: false,
+            is_attached: true,
+            is_armed: false,
+        })
+    }
+
+    fn run_event_loop(
+        &mut self,
+        session: &mut PageTrapSession,
+    ) -> PageTrapStopReason {
+        let attached_at = Instant::now();
+
+        loop {
+            if session.is_stop_requested() {
+                return PageTrapStopReason::Requested;
+            }
+
+            if !self.is_armed && attached_at.elapsed() > Duration::from_millis(ATTACH_TIMEOUT_MS) {
+                return PageTrapStopReason::Failed(format!("Timed out waiting for process {} to break in.", self.process_id));
+            }
+
+            let mut debug_event: DEBUG_EVENT = unsafe { std::mem::zeroed() };
+
+            if unsafe { WaitForDebugEvent(&mut debug_event, DEBUG_EVENT_TIMEOUT_MS) } == 0 {
+                session.flush_hits(false);
+                continue;
+            }
+
+            let (continue_status, mut stop_reason) = self.handle_debug_event(&debug_event);
+
+            // The attach breakpoint is raised once every thread is suspended, which makes it the place to guard the pages.
+            if self.received_attach_breakpoint && !self.is_armed {
+                match self.arm_pages() {
+                    Ok(()) => session.report_armed(Ok(())),
+                    Err(error) => stop_reason = Some(PageTrapStopReason::Failed(error)),
+                }
+            }
+
+            unsafe { ContinueDebugEvent(debug_event.dwProcessId, debug_event.dwThreadId, continue_status) };
+
+            for (instruction_address, access_address, registers) in self.recorded_hits.drain(..) {
+                session.record_hit(instruction_address, access_address, registers);
+            }
+
+            if let Some(stop_reason) = stop_reason {
+                return stop_reason;
+            }
+
+            session.flush_hits(false);
+        }
+    }
+
+    fn handle_debug_event(
+        &mut self,
+        debug_event: &DEBUG_EVENT,
+    ) -> (NTSTATUS, Option<PageTrapStopReason>) {
+        unsafe {
+            match debug_event.dwDebugEventCode {
+                CREATE_PROCESS_DEBUG_EVENT => {
+                    Self::close_handle(debug_event.u.CreateProcessInfo.hFile);
+                    (DBG_CONTINUE, None)
+                }
+                LOAD_DLL_DEBUG_EVENT => {
+                    Self::close_handle(debug_event.u.LoadDll.hFile);
+                    (DBG_CONTINUE, None)
+                }
+                EXIT_PROCESS_DEBUG_EVENT => (DBG_CONTINUE, Some(PageTrapStopReason::ProcessExited)),
+                EXCEPTION_DEBUG_EVENT => (self.handle_exception(debug_event.dwThreadId, &debug_event.u.Exception.ExceptionRecord), None),
+                _ => (DBG_CONTINUE, None),
+            }
+        }
+    }
+
+    fn handle_exception(
+        &mut self,
+        thread_id: u32,
+        exception_record: &EXCEPTION_RECORD,
+    ) -> NTSTATUS {
+        match exception_record.ExceptionCode {
+            STATUS_BREAKPOINT if !self.received_attach_breakpoint => {
+                self.received_attach_breakpoint = true;
+                DBG_CONTINUE
+            }
+            STATUS_WX86_BREAKPOINT if !self.received_wow64_attach_breakpoint => {
+                self.received_wow64_attach_breakpoint = true;
+                DBG_CONTINUE
+            }
+            STATUS_GUARD_PAGE_VIOLATION if exception_record.NumberParameters >= 2 => {
+                let access_address = exception_record.ExceptionInformation[1] as u64;
+                let Some(page_index) = self.find_trapped_page(access_address) else {
+                    // Guard pages belonging to the target (ie its stack) are left to the target.
+                    return DBG_EXCEPTION_NOT_HANDLED;
+                };
+
+                // Guard pages trap reads and writes alike, so write watches step over reads without reporting them.
+                let is_reported = self.watch_type == MemoryWatchType::Access || exception_record.ExceptionInformation[0] == ACCESS_TYPE_WRITE;
+                let mut registers = None;
+
+                // The fault already cleared the guard, so the access succeeds once continued. The step after it guards the page again.
+                if self.is_armed {
+                    match self.set_trap_flag(thread_id, true) {
+                        Some(thread_registers) => {
+                            registers = Some(thread_registers);
+                            self.stepping_threads.insert(thread_id, page_index);
+                        }
+                        None => {
+                            log::warn!(
+                                "Failed to step thread {}, the page at 0x{:X} is no longer trapped.",
+                                thread_id,
+                                self.trapped_pages[page_index].0
+                            );
+                        }
+                    }
+                }
+
+                if is_reported {
+                    self.recorded_hits
+                        .push((exception_record.ExceptionAddress as u64, access_address, registers));
+                }
+
+                DBG_CONTINUE
+            }
+            STATUS_SINGLE_STEP | STATUS_WX86_SINGLE_STEP => match self.stepping_threads.remove(&thread_id) {
+                Some(page_index) => {
+                    if self.is_armed {
+                        if let Err(error) = self.guard_page(page_index) {
+                            log::warn!("{}", error);
+                        }
+                    }
+
+                    DBG_CONTINUE
+                }
+                None => DBG_EXCEPTION_NOT_HANDLED,
+            },
+            _ => DBG_EXCEPTION_NOT_HANDLED,
+        }
+    }
+
+    fn arm_pages(&mut self) -> Result<(), String> {
+        self.is_armed = true;
+
+        for page_index in 0..self.trapped_pages.len() {
+            self.guard_page(page_index)?;
+        }
+
+        Ok(())
+    }
+
+    fn guard_page(
+        &self,
+        page_index: usize,
+    ) -> Result<(), String> {
+        let (page_address, original_protection) = self.trapped_pages[page_index];
+
+        self.protect_page(page_address, original_protection | PAGE_GUARD)
+    }
+
+    fn protect_page(
+        &self,
+        page_address: u64,
+        protection: u32,
+    ) -> Result<(), String> {
+        let mut old_protection = 0;
+
+        if unsafe {
+            VirtualProtectEx(
+                self.process_handle,
+                page_address as *const c_void,
+                PAGE_SIZE as usize,
+                protection,
+                &mut old_protection,
+            )
+        } == 0
+        {
+            return Err(format!(
+                "Failed to protect the page at 0x{:X}: {}",
+                page_address,
+                std::io::Error::last_os_error()
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sets or clears the trap flag of a thread, using the 32-bit context for threads of WoW64 processes. Returns the registers
+    /// the thread had beforehand, or None if its context could not be updated.
+    fn set_trap_flag(
+        &self,
+        thread_id: u32,
+        is_enabled: bool,
+    ) -> Option<RegisterSnapshot> {
+        unsafe {
+            let thread_handle = OpenThread(THREAD_GET_CONTEXT | THREAD_SET_CONTEXT | THREAD_SUSPEND_RESUME, 0, thread_id);
+
+            if thread_handle.is_null() {
+                return None;
+            }
+
+            SuspendThread(thread_handle);
+
+            let update_eflags = |eflags: &mut u32| {
+                if is_enabled {
+                    *eflags |= TRAP_FLAG;
+                } else {
+                    *eflags &= !TRAP_FLAG;
+                }
+            };
+            let registers = if self.is_wow64 {
+                let mut context: WOW64_CONTEXT = std::mem::zeroed();
+
+                context.ContextFlags = WOW64_CONTEXT_CONTROL | WOW64_CONTEXT_INTEGER;
+
+                if Wow64GetThreadContext(thread_handle, &mut context) != 0 {
+                    let registers = Self::get_wow64_register_snapshot(&context);
+
+                    update_eflags(&mut context.EFlags);
+                    (Wow64SetThreadContext(thread_handle, &context) != 0).then_some(registers)
+                } else {
+                    None
+                }
+            } else {
+                let mut context = AlignedContext(std::mem::zeroed());
+
+                context.0.ContextFlags = CONTEXT_CONTROL_AMD64 | CONTEXT_INTEGER_AMD64;
+
+                if GetThreadContext(thread_handle, &mut context.0) != 0 {
+                    let registers = Self::get_register_snapshot(&context.0);
+
+                    update_eflags(&mut context.0.EFlags);
+                    (SetThreadContext(thread_handle, &context.0) != 0).then_some(registers)
+                } else {
+                    None
+                }
+            };
+
+            ResumeThread(thread_handle);
+            CloseHandle(thread_handle);
+
+            registers
+        }
+    }
+
+    fn get_register_snapshot(context: &CONTEXT) -> RegisterSnapshot {
+        RegisterSnapshot {
+            is_32_bit: false,
+            rax: context.Rax,
+            rbx: context.Rbx,
+            rcx: context.Rcx,
+            rdx: context.Rdx,
+            rsi: context.Rsi,
+            rdi: context.Rdi,
+            rbp: context.Rbp,
+            rsp: context.Rsp,
+            r8: context.R8,
+            r9: context.R9,
+            r10: context.R10,
+            r11: context.R11,
+            r12: context.R12,
+            r13: context.R13,
+            r14: context.R14,
+            r15: context.R15,
+            rip: context.Rip,
+            rflags: context.EFlags as u64,
+        }
+    }
+
+    fn get_wow64_register_snapshot(context: &WOW64_CONTEXT) -> RegisterSnapshot {
+        RegisterSnapshot {
+            is_32_bit: true,
+            rax: context.Eax as u64,
+            rbx: context.Ebx as u64,
+            rcx: context.Ecx as u64,
+            rdx: context.Edx as u64,
+            rsi: context.Esi as u64,
+            rdi: context.Edi as u64,
+            rbp: context.Ebp as u64,
+            rsp: context.Esp as u64,
+            rip: context.Eip as u64,
+            rflags: context.EFlags as u64,
+            ..RegisterSnapshot::default()
+        }
+    }
+
+    /// Restores the trapped pages and detaches. Threads still stepping over an access must finish first, since a single step
+    /// exception raised after detaching would crash the target.
+    fn detach(&mut self) {
+        if !self.is_attached {
+            return;
+        }
+
+        self.is_attached = false;
+
+        if self.is_armed {
+            self.is_armed = false;
+
+            for (page_address, original_protection) in self.trapped_pages.clone() {
+                if let Err(error) = self.protect_page(page_address, original_protection) {
+                    log::error!("Failed to restore a trapped page: {}", error);
+                }
+            }
+
+            let detach_started_at = Instant::now();
+
+            while detach_started_at.elapsed() < Duration::from_millis(DETACH_TIMEOUT_MS) {
+                let mut debug_event: DEBUG_EVENT = unsafe { std::mem::zeroed() };
+
+                // Events already queued must still be continued, even once no thread is stepping.
+                if unsafe { WaitForDebugEvent(&mut debug_event, 10) } == 0 {
+                    if self.stepping_threads.is_empty() {
+                        break;
+                    }
+
+                    continue;
+                }
+
+                let (continue_status, stop_reason) = self.handle_debug_event(&debug_event);
+
+                unsafe { ContinueDebugEvent(debug_event.dwProcessId, debug_event.dwThreadId, continue_status) };
+
+                if stop_reason.is_some() {
+                    self.stepping_threads.clear();
+                    break;
+                }
+            }
+
+            for thread_id in self.stepping_threads.keys().copied().collect::<Vec<_>>() {
+                self.set_trap_flag(thread_id, false);
+            }
+
+            self.stepping_threads.clear();
+        }
+
+        unsafe { DebugActiveProcessStop(self.process_id) };
+    }
+
+    fn find_trapped_page(
+        &self,
+        address: u64,
+    ) -> Option<usize> {
+        self.trapped_pages
+            .iter()
+            .position(|(page_address, _)| address >= *page_address && address - *page_address < PAGE_SIZE)
+    }
+
+    /// Gets the protection of each page, such that it can be restored exactly. Pages that cannot be accessed are rejected, since
+    /// there would be no accesses to find.
+    fn query_page_protections(
+        process_handle: HANDLE,
+        page_addresses: &[u64],
+    ) -> Result<Vec<(u64, u32)>, String> {
+        page_addresses
+            .iter()
+            .map(|page_address| {
+                let mut memory_info: MEMORY_BASIC_INFORMATION = unsafe { std::mem::zeroed() };
+                let queried_size = unsafe {
+                    VirtualQueryEx(
+                        process_handle,
+                        *page_address as *const c_void,
+                        &mut memory_info,
+                        size_of::<MEMORY_BASIC_INFORMATION>(),
+                    )
+                };
+
+                if queried_size == 0 || memory_info.State != MEM_COMMIT {
+                    return Err(format!("Address 0x{:X} is not committed memory.", page_address));
+                }
+
+                if memory_info.Protect & (PAGE_NOACCESS | PAGE_GUARD) != 0 {
+                    return Err(format!("Address 0x{:X} is already inaccessible, so it cannot be trapped.", page_address));
+                }
+
+                Ok((*page_address, memory_info.Protect))
+            })
+            .collect()
+    }
+
+    fn close_handle(handle: HANDLE) {
+        if !handle.is_null() {
+            unsafe { CloseHandle(handle) };
+        }
+    }
+}
+
+impl Drop for WindowsPageTrapDebugger {
+    fn drop(&mut self) {
+        // Covers panics within the loop, which would otherwise leave guard pages behind that the target cannot handle.
+        self.detach();
+    }
+}
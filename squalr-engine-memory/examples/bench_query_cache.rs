@@ -0,0 +1,105 @@
+// Compares enumeration calls and time across a simulated GUI frame sequence, with and without the process query cache.
+// Uses a mocked enumerator with 50k regions, so no target process is required.
+// Run: cargo run --release -p squalr-engine-memory --example bench_query_cache
+
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_memory::memory_queryer::process_query_cache::{ProcessQueryCache, ProcessQueryEnumerator};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+const REGION_COUNT: u64 = 50_000;
+const MODULE_COUNT: u64 = 200;
+const FRAME_COUNT: u64 = 600;
+// Results list module display, memory viewer region list, settings coverage preview and a valid-pointer check, per frame.
+const MODULE_LOOKUPS_PER_FRAME: u64 = 3;
+const REGION_LOOKUPS_PER_FRAME: u64 = 2;
+// Emulates an interval refresh every ~5s at 60fps.
+const FRAMES_PER_INVALIDATION: u64 = 300;
+
+struct MockEnumerator {
+    enumeration_count: Arc<AtomicU64>,
+}
+
+impl ProcessQueryEnumerator for MockEnumerator {
+    fn enumerate_modules(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        self.enumeration_count.fetch_add(1, Ordering::Relaxed);
+        (0..MODULE_COUNT)
+            .map(|index| NormalizedModule::new(&format!("module_{}.dll", index), 0x7FF0_0000_0000 + index * 0x10_0000, 0x10_0000))
+            .collect()
+    }
+
+    fn enumerate_regions(
+        &self,
+        _process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedRegion> {
+        self.enumeration_count.fetch_add(1, Ordering::Relaxed);
+        (0..REGION_COUNT)
+            .map(|index| NormalizedRegion::new(0x1_0000 + index * 0x1000, 0x1000))
+            .collect()
+    }
+}
+
+fn main() {
+    let process = OpenedProcessInfo::new(1, "game.exe".to_string(), 1, Bitness::Bit64, None);
+
+    // Uncached: every consumer enumerates independently.
+    let uncached_count = Arc::new(AtomicU64::new(0));
+    let uncached = MockEnumerator {
+        enumeration_count: uncached_count.clone(),
+    };
+    let start = Instant::now();
+
+    for _ in 0..FRAME_COUNT {
+        for _ in 0..MODULE_LOOKUPS_PER_FRAME {
+            std::hint::black_box(uncached.enumerate_modules(&process));
+        }
+
+        for _ in 0..REGION_LOOKUPS_PER_FRAME {
+            std::hint::black_box(uncached.enumerate_regions(&process));
+        }
+    }
+
+    let uncached_elapsed = start.elapsed();
+
+    // Cached: consumers share snapshots until an invalidation.
+    let cached_count = Arc::new(AtomicU64::new(0));
+    let cache = ProcessQueryCache::new_with_interval(
+        Box::new(MockEnumerator {
+            enumeration_count: cached_count.clone(),
+        }),
+        Some(Duration::from_secs(3600)),
+    );
+    let start = Instant::now();
+
+    for frame in 0..FRAME_COUNT {
+        if frame > 0 && frame % FRAMES_PER_INVALIDATION == 0 {
+            cache.invalidate();
+        }
+
+        for _ in 0..MODULE_LOOKUPS_PER_FRAME {
+            std::hint::black_box(cache.get_modules(&process));
+        }
+
+        for _ in 0..REGION_LOOKUPS_PER_FRAME {
+            std::hint::black_box(cache.get_regions(&process));
+        }
+    }
+
+    let cached_elapsed = start.elapsed();
+
+    println!("frames={} regions={} modules={}", FRAME_COUNT, REGION_COUNT, MODULE_COUNT);
+    println!(
+        "uncached: enumerations={} elapsed={:?}",
+        uncached_count.load(Ordering::Relaxed),
+        uncached_elapsed
+    );
+    println!("cached:   enumerations={} elapsed={:?}", cached_count.load(Ordering::Relaxed), cached_elapsed);
+    println!("cache stats: {}", cache.get_stats());
+}
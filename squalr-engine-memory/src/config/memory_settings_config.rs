@@ -50,6 +50,12 @@ impl MemorySettingsConfig {
             changed = true;
         }
 
+        if config.query_cache_interval_ms == 0 {
+            // Settings files written before the query cache existed deserialize this as zero.
+            config.query_cache_interval_ms = MemorySettings::DEFAULT_QUERY_CACHE_INTERVAL_MS;
+            changed = true;
+        }
+
         if !config.only_query_usermode && config.start_address == 0 && config.end_address == u64::MAX {
             // Avoid scanning kernel address space by default.
             config.only_query_usermode = true;
@@ -374,4 +380,36 @@ impl MemorySettingsConfig {
 
         Self::save_config();
     }
+
+    pub fn get_query_cache_interval_ms() -> u64 {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.query_cache_interval_ms
+        } else {
+            MemorySettings::default().query_cache_interval_ms
+        }
+    }
+
+    pub fn set_query_cache_interval_ms(value: u64) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.query_cache_interval_ms = value;
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_preload_main_module_symbols() -> bool {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.preload_main_module_symbols
+        } else {
+            MemorySettings::default().preload_main_module_symbols
+        }
+    }
+
+    pub fn set_preload_main_module_symbols(value: bool) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.preload_main_module_symbols = value;
+        }
+
+        Self::save_config();
+    }
 }
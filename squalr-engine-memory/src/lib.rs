@@ -2,3 +2,4 @@ pub mod config;
 pub mod memory_queryer;
 pub mod memory_reader;
 pub mod memory_writer;
+pub mod module_symbols;
@@ -2,25 +2,120 @@ use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
 use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
 use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
 use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
-use core::ffi::c_void;
-use core::mem::size_of;
-use squalr_engine_api::structures::memory::bitness::Bitness;
+use crate::memory_reader::linux::linux_memory_access_error::LinuxMemoryAccessError;
+use squalr_engine_api::structures::memory::address_space_bounds::AddressSpaceBounds;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::path::Path;
+
+/// A single line of `/proc/<pid>/maps`, ie `7f1c2a400000-7f1c2a428000 r--p 00000000 08:01 1234 /usr/lib/libc.so.6`.
+#[derive(Debug, Clone)]
+struct ProcMapsEntry {
+    start_address: u64,
+    end_address: u64,
+    protection: MemoryProtectionEnum,
+    memory_type: MemoryTypeEnum,
+    inode: u64,
+    path: String,
+}
+
+impl ProcMapsEntry {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.split_whitespace();
+        let (start_address, end_address) = fields.next()?.split_once('-')?;
+        let permissions = fields.next()?.as_bytes();
+        let _offset = fields.next()?;
+        let _device = fields.next()?;
+        let inode = fields.next()?.parse::<u64>().ok()?;
+        // Paths may contain spaces, and deleted files are suffixed with " (deleted)".
+        let path = fields.collect::<Vec<_>>().join(" ");
+
+        if permissions.len() < 4 {
+            return None;
+        }
+
+        let is_shared = permissions[3] == b's';
+        let mut protection = MemoryProtectionEnum::NONE;
+
+        if permissions[0] == b'r' {
+            protection |= MemoryProtectionEnum::READ;
+        }
+
+        if permissions[1] == b'w' {
+            protection |= MemoryProtectionEnum::WRITE;
+
+            // Private writable file mappings are copied on first write, like copy-on-write pages on Windows.
+            if !is_shared && inode != 0 {
+                protection |= MemoryProtectionEnum::COPY_ON_WRITE;
+            }
+        }
+
+        if permissions[2] == b'x' {
+            protection |= MemoryProtectionEnum::EXECUTE;
+        }
+
+        // Private file mappings are loaded images (executables and shared libraries), shared mappings are mapped files or shared
+        // memory, and everything else (heap, stacks, anonymous mappings) is private to the process.
+        let memory_type = if is_shared {
+            MemoryTypeEnum::MAPPED
+        } else if inode != 0 {
+            MemoryTypeEnum::IMAGE
+        } else {
+            MemoryTypeEnum::PRIVATE
+        };
+
+        Some(Self {
+            start_address: u64::from_str_radix(start_address, 16).ok()?,
+            end_address: u64::from_str_radix(end_address, 16).ok()?,
+            protection,
+            memory_type,
+            inode,
+            path,
+        })
+    }
+
+    /// Whether this region can be read through process_vm_readv. Kernel provided pages such as [vvar] fault on access.
+    fn is_readable(&self) -> bool {
+        self.protection.contains(MemoryProtectionEnum::READ) && self.path != "[vvar]" && self.path != "[vvar_vclock]" && self.path != "[vsyscall]"
+    }
+}
 
 pub struct LinuxMemoryQueryer;
 
 impl LinuxMemoryQueryer {
+    /// Linux refuses to map pages below vm.mmap_min_addr, which defaults to 64KB.
+    const USERMODE_64_MIN_ADDRESS: u64 = 0x10000;
+    /// The top of the lower half of the x86-64 and AArch64 (48-bit) address spaces.
+    const USERMODE_64_MAX_ADDRESS: u64 = 0x7FFF_FFFF_FFFF;
+
     pub fn new() -> Self {
         LinuxMemoryQueryer
     }
 
-    fn get_protection_flags(
+    fn get_address_space_bounds(
         &self,
-        protection: &MemoryProtectionEnum,
-    ) -> u32 {
-        0
+        process_info: &OpenedProcessInfo,
+    ) -> AddressSpaceBounds {
+        AddressSpaceBounds::for_process(process_info, Self::USERMODE_64_MIN_ADDRESS, Self::USERMODE_64_MAX_ADDRESS)
+    }
+
+    fn read_proc_maps(process_info: &OpenedProcessInfo) -> Vec<ProcMapsEntry> {
+        let process_id = process_info.get_process_id_raw();
+
+        match std::fs::read_to_string(format!("/proc/{}/maps", process_id)) {
+            Ok(maps) => maps.lines().filter_map(ProcMapsEntry::parse).collect(),
+            Err(error) => {
+                let access_error = if error.kind() == std::io::ErrorKind::NotFound {
+                    LinuxMemoryAccessError::ProcessExited
+                } else {
+                    LinuxMemoryAccessError::from_errno(error.raw_os_error().unwrap_or(0))
+                };
+
+                access_error.report(process_id, "Reading /proc/<pid>/maps", 0, 0);
+                vec![]
+            }
+        }
     }
 }
 
@@ -35,14 +130,68 @@ impl IMemoryQueryer for LinuxMemoryQueryer {
         end_address: u64,
         region_bounds_handling: RegionBoundsHandling,
     ) -> Vec<NormalizedRegion> {
-        vec![]
+        let mut regions = vec![];
+
+        // Return empty regions if the start or end address is invalid.
+        if start_address >= end_address {
+            return regions;
+        }
+
+        for proc_maps_entry in Self::read_proc_maps(process_info) {
+            if !proc_maps_entry.is_readable() || !allowed_types.intersects(proc_maps_entry.memory_type) {
+                continue;
+            }
+
+            // Ensure at least one required protection flag is set.
+            if !required_protection.is_empty() && !proc_maps_entry.protection.intersects(required_protection) {
+                continue;
+            }
+
+            // Ensure no ignored protection flags are set.
+            if proc_maps_entry.protection.intersects(excluded_protection) {
+                continue;
+            }
+
+            let mut region = NormalizedRegion::new(proc_maps_entry.start_address, proc_maps_entry.end_address - proc_maps_entry.start_address);
+
+            // Skip regions entirely outside of the provided bounds.
+            if region.get_end_address() <= start_address || region.get_base_address() >= end_address {
+                continue;
+            }
+
+            // Handle regions that are partially in the provided bounds based on given bounds handling method.
+            if region.get_base_address() < start_address || region.get_end_address() > end_address {
+                match region_bounds_handling {
+                    RegionBoundsHandling::Exclude => continue,
+                    RegionBoundsHandling::Include => {}
+                    RegionBoundsHandling::Resize => {
+                        region.set_end_address(end_address.min(region.get_end_address()));
+                        region.set_base_address_retain_end_address(start_address.max(region.get_base_address()));
+                    }
+                }
+            }
+
+            regions.push(region);
+        }
+
+        regions
     }
 
     fn get_all_virtual_pages(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> Vec<NormalizedRegion> {
-        vec![]
+        let start_address = 0;
+        let end_address = self.get_maximum_address(process_info);
+        self.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::NONE,
+            MemoryProtectionEnum::NONE,
+            MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            start_address,
+            end_address,
+            RegionBoundsHandling::Exclude,
+        )
     }
 
     fn is_address_writable(
@@ -50,35 +199,91 @@ impl IMemoryQueryer for LinuxMemoryQueryer {
         process_info: &OpenedProcessInfo,
         address: u64,
     ) -> bool {
-        false
+        let virtual_pages_in_bounds = self.get_virtual_pages(
+            process_info,
+            MemoryProtectionEnum::WRITE,
+            MemoryProtectionEnum::NONE,
+            MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+            address,
+            address.saturating_add(1),
+            RegionBoundsHandling::Include,
+        );
+
+        !virtual_pages_in_bounds.is_empty()
     }
 
     fn get_maximum_address(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> u64 {
-        0
+        self.get_address_space_bounds(process_info)
+            .get_maximum_address()
     }
 
     fn get_min_usermode_address(
         &self,
-        _: &OpenedProcessInfo,
+        process_info: &OpenedProcessInfo,
     ) -> u64 {
-        0
+        self.get_address_space_bounds(process_info)
+            .get_min_usermode_address()
     }
 
     fn get_max_usermode_address(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> u64 {
-        0
+        self.get_address_space_bounds(process_info)
+            .get_max_usermode_address()
     }
 
+    /// Modules are the files with at least one executable mapping. Each module spans from its lowest to its highest mapping,
+    /// since the loader maps the segments of an ELF image (headers, code, data) as separate regions.
     fn get_modules(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> Vec<NormalizedModule> {
-        vec![]
+        let proc_maps_entries = Self::read_proc_maps(process_info);
+        let mut modules: Vec<(String, u64, u64, bool)> = vec![];
+
+        for proc_maps_entry in proc_maps_entries
+            .iter()
+            .filter(|proc_maps_entry| proc_maps_entry.inode != 0 && proc_maps_entry.path.starts_with('/'))
+        {
+            let is_executable = proc_maps_entry
+                .protection
+                .contains(MemoryProtectionEnum::EXECUTE);
+
+            match modules
+                .iter_mut()
+                .find(|(module_path, _, _, _)| *module_path == proc_maps_entry.path)
+            {
+                Some((_, base_address, end_address, has_executable_mapping)) => {
+                    *base_address = (*base_address).min(proc_maps_entry.start_address);
+                    *end_address = (*end_address).max(proc_maps_entry.end_address);
+                    *has_executable_mapping |= is_executable;
+                }
+                None => modules.push((
+                    proc_maps_entry.path.clone(),
+                    proc_maps_entry.start_address,
+                    proc_maps_entry.end_address,
+                    is_executable,
+                )),
+            }
+        }
+
+        modules
+            .into_iter()
+            .filter(|(_, _, _, has_executable_mapping)| *has_executable_mapping)
+            .map(|(module_path, base_address, end_address, _)| {
+                let module_name = Path::new(&module_path)
+                    .file_name()
+                    .and_then(|file_name| file_name.to_str())
+                    .unwrap_or("")
+                    .to_string();
+
+                NormalizedModule::new(&module_name, base_address, end_address - base_address)
+            })
+            .collect()
     }
 
     fn address_to_module(
@@ -86,6 +291,12 @@ impl IMemoryQueryer for LinuxMemoryQueryer {
         address: u64,
         modules: &Vec<NormalizedModule>,
     ) -> Option<(String, u64)> {
+        for module in modules {
+            if module.contains_address(address) {
+                return Some((module.get_module_name().to_string(), address - module.get_base_address()));
+            }
+        }
+
         None
     }
 
@@ -94,6 +305,95 @@ impl IMemoryQueryer for LinuxMemoryQueryer {
         modules: &Vec<NormalizedModule>,
         identifier: &str,
     ) -> u64 {
+        if !identifier.is_empty() {
+            for module in modules {
+                if module
+                    .get_module_name()
+                    .trim()
+                    .eq_ignore_ascii_case(identifier.trim())
+                {
+                    return module.get_base_address();
+                }
+            }
+        }
+
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{LinuxMemoryQueryer, ProcMapsEntry};
+    use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+    use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+    use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    fn open_own_process() -> OpenedProcessInfo {
+        let process_id = std::process::id();
+
+        OpenedProcessInfo::new(process_id, "test-harness".to_string(), process_id as u64, Bitness::Bit64, None)
+    }
+
+    #[test]
+    fn maps_lines_map_onto_protection_and_type() {
+        let image_data = ProcMapsEntry::parse("7f1c2a600000-7f1c2a608000 rw-p 001f5000 08:01 1234    /usr/lib/my lib.so (deleted)").unwrap();
+        let heap = ProcMapsEntry::parse("55d0c3a1c000-55d0c3a3d000 rw-p 00000000 00:00 0      [heap]").unwrap();
+        let shared_memory = ProcMapsEntry::parse("7f1c2a800000-7f1c2a900000 rw-s 00000000 00:05 88   /dev/shm/buffer").unwrap();
+
+        assert_eq!(image_data.start_address, 0x7f1c2a600000);
+        assert_eq!(image_data.end_address, 0x7f1c2a608000);
+        assert_eq!(image_data.path, "/usr/lib/my lib.so (deleted)");
+        assert_eq!(image_data.memory_type, MemoryTypeEnum::IMAGE);
+        assert!(
+            image_data
+                .protection
+                .contains(MemoryProtectionEnum::COPY_ON_WRITE)
+        );
+        assert_eq!(heap.memory_type, MemoryTypeEnum::PRIVATE);
+        assert!(!heap.protection.contains(MemoryProtectionEnum::COPY_ON_WRITE));
+        assert!(!heap.protection.contains(MemoryProtectionEnum::EXECUTE));
+        assert_eq!(shared_memory.memory_type, MemoryTypeEnum::MAPPED);
+        assert!(ProcMapsEntry::parse("not a maps line").is_none());
+    }
+
+    #[test]
+    fn own_process_regions_and_modules_are_enumerated() {
+        let process_info = open_own_process();
+        let memory_queryer = LinuxMemoryQueryer::new();
+        let readable_entry_count = std::fs::read_to_string("/proc/self/maps")
+            .unwrap()
+            .lines()
+            .filter_map(ProcMapsEntry::parse)
+            .filter(|proc_maps_entry| proc_maps_entry.is_readable())
+            .count();
+        let regions = memory_queryer.get_all_virtual_pages(&process_info);
+        let local_value = Box::new(0x5A5A_5A5Au32);
+        let local_value_address = &*local_value as *const u32 as u64;
+
+        // Allow a little slack, since other test threads may map or unmap memory between the two reads.
+        assert!(!regions.is_empty());
+        assert!(regions.len().abs_diff(readable_entry_count) <= 8);
+        assert!(
+            regions
+                .iter()
+                .any(|region| region.contains_address(local_value_address))
+        );
+        assert!(memory_queryer.is_address_writable(&process_info, local_value_address));
+
+        let executable_name = std::env::current_exe()
+            .unwrap()
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let modules = memory_queryer.get_modules(&process_info);
+
+        assert!(
+            modules
+                .iter()
+                .any(|module| module.get_module_name() == executable_name)
+        );
+    }
+}
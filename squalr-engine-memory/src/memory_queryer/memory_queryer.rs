@@ -2,9 +2,11 @@ use crate::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
 use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
 use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
 use crate::memory_queryer::page_retrieval_mode::PageRetrievalMode;
+use crate::memory_queryer::process_query_cache::ProcessQueryCache;
 use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
 use crate::{config::memory_settings_config::MemorySettingsConfig, memory_queryer::MemoryQueryerImpl};
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
+use squalr_engine_api::structures::memory::address_space_bounds::AddressSpaceBounds;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use std::{collections::HashSet, sync::Once};
@@ -29,6 +31,15 @@ impl MemoryQueryer {
         }
     }
 
+    /// Gets the bitness-dependent usermode bounds and pointer width for the given process.
+    pub fn get_address_space_bounds(process_info: &OpenedProcessInfo) -> AddressSpaceBounds {
+        AddressSpaceBounds::for_process(
+            process_info,
+            MemoryQueryer::get_instance().get_min_usermode_address(process_info),
+            MemoryQueryer::get_instance().get_max_usermode_address(process_info),
+        )
+    }
+
     pub fn get_memory_page_bounds(
         process_info: &OpenedProcessInfo,
         page_retrieval_mode: PageRetrievalMode,
@@ -64,6 +75,23 @@ impl MemoryQueryer {
         normalized_regions
     }
 
+    /// Queries the pages within the given address range that match the protection and type filters of the memory settings.
+    pub fn query_pages_by_address_range_from_settings(
+        process_info: &OpenedProcessInfo,
+        start_address: u64,
+        end_address: u64,
+    ) -> Vec<NormalizedRegion> {
+        MemoryQueryer::get_instance().get_virtual_pages(
+            process_info,
+            MemoryQueryer::get_required_protection_settings(),
+            MemoryQueryer::get_excluded_protection_settings(),
+            MemoryQueryer::get_allowed_type_settings(),
+            start_address,
+            end_address,
+            RegionBoundsHandling::Resize,
+        )
+    }
+
     fn query_pages_from_usermode_memory(process_info: &OpenedProcessInfo) -> Vec<NormalizedRegion> {
         let required_page_flags = MemoryProtectionEnum::empty();
         let excluded_page_flags = MemoryProtectionEnum::empty();
@@ -140,13 +168,32 @@ impl MemoryQueryer {
         let excluded_page_flags = MemoryQueryer::get_excluded_protection_settings();
         let allowed_type_flags = MemoryQueryer::get_allowed_type_settings();
 
+        let address_space_bounds = MemoryQueryer::get_address_space_bounds(process_info);
         let (start_address, end_address) = if MemorySettingsConfig::get_only_query_usermode() {
             (
-                MemoryQueryer::get_instance().get_min_usermode_address(process_info),
-                MemoryQueryer::get_instance().get_max_usermode_address(process_info),
+                address_space_bounds.get_min_usermode_address(),
+                address_space_bounds.get_max_usermode_address(),
             )
         } else {
-            (MemorySettingsConfig::get_start_address(), MemorySettingsConfig::get_end_address())
+            let custom_start_address = MemorySettingsConfig::get_start_address();
+            let custom_end_address = MemorySettingsConfig::get_end_address();
+
+            // A custom range written for a 64-bit target may lie entirely outside of a 32-bit target's address space.
+            match address_space_bounds.clamp_range(custom_start_address, custom_end_address) {
+                Some(range) => range,
+                None => {
+                    log::warn!(
+                        "Custom query range 0x{:X}-0x{:X} is outside of the address space of this {}-bit process. Falling back to usermode bounds.",
+                        custom_start_address,
+                        custom_end_address,
+                        address_space_bounds.get_pointer_size() * 8,
+                    );
+                    (
+                        address_space_bounds.get_min_usermode_address(),
+                        address_space_bounds.get_max_usermode_address(),
+                    )
+                }
+            }
         };
 
         log::debug!(
@@ -170,7 +217,7 @@ impl MemoryQueryer {
         );
 
         if MemorySettingsConfig::get_only_main_module_image() {
-            let modules = MemoryQueryer::get_instance().get_modules(process_info);
+            let modules = ProcessQueryCache::get_instance().get_modules(process_info);
             let main_module_name = modules
                 .iter()
                 .find(|module| module.get_module_name().eq_ignore_ascii_case(process_info.get_name()))
@@ -281,20 +328,19 @@ impl MemoryQueryer {
     }
 
     fn query_pages_from_modules(process_info: &OpenedProcessInfo) -> Vec<NormalizedRegion> {
-        // Note that we use into_base_region to extract the base region without copying, instead taking ownership
-        let module_regions = MemoryQueryer::get_instance()
+        let module_regions = ProcessQueryCache::get_instance()
             .get_modules(process_info)
-            .into_iter()
-            .map(|module| module.into_base_region())
+            .iter()
+            .map(|module| module.get_base_region().clone())
             .collect();
 
         module_regions
     }
 
     fn query_pages_from_non_modules(process_info: &OpenedProcessInfo) -> Vec<NormalizedRegion> {
-        let modules: HashSet<u64> = MemoryQueryer::get_instance()
+        let modules: HashSet<u64> = ProcessQueryCache::get_instance()
             .get_modules(process_info)
-            .into_iter()
+            .iter()
             .map(|module| module.get_base_address())
             .collect();
 
@@ -0,0 +1,331 @@
+use crate::config::memory_settings_config::MemorySettingsConfig;
+use crate::memory_queryer::memory_queryer::MemoryQueryer;
+use crate::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use crate::memory_queryer::page_retrieval_mode::PageRetrievalMode;
+use arc_swap::ArcSwapOption;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::memory::process_query_cache_stats::ProcessQueryCacheStats;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Enumerates the modules and regions of a process. The cache wraps one of these such that the enumeration can be mocked.
+pub trait ProcessQueryEnumerator: Send + Sync {
+    fn enumerate_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule>;
+
+    fn enumerate_regions(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedRegion>;
+}
+
+/// Enumerates modules and usermode regions by querying the OS.
+pub struct OsProcessQueryEnumerator;
+
+impl ProcessQueryEnumerator for OsProcessQueryEnumerator {
+    fn enumerate_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedModule> {
+        MemoryQueryer::get_instance().get_modules(process_info)
+    }
+
+    fn enumerate_regions(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Vec<NormalizedRegion> {
+        MemoryQueryer::get_memory_page_bounds(process_info, PageRetrievalMode::FromUserMode)
+    }
+}
+
+/// Immutable query results for a single opened process. Each list is enumerated on first use, such that consumers
+/// that only need modules never pay for a full region walk.
+struct ProcessQuerySnapshot {
+    process_id: u32,
+    process_handle: u64,
+    created_at: Instant,
+    modules: OnceLock<Arc<Vec<NormalizedModule>>>,
+    regions: OnceLock<Arc<Vec<NormalizedRegion>>>,
+}
+
+impl ProcessQuerySnapshot {
+    fn new(process_info: &OpenedProcessInfo) -> Self {
+        Self {
+            process_id: process_info.get_process_id_raw(),
+            process_handle: process_info.get_handle(),
+            created_at: Instant::now(),
+            modules: OnceLock::new(),
+            regions: OnceLock::new(),
+        }
+    }
+
+    fn is_valid_for(
+        &self,
+        process_info: &OpenedProcessInfo,
+        max_age: Duration,
+    ) -> bool {
+        self.process_id == process_info.get_process_id_raw() && self.process_handle == process_info.get_handle() && self.created_at.elapsed() < max_age
+    }
+}
+
+/// Caches the module and region lists of the opened process, such that every consumer shares one enumeration.
+/// Snapshots are swapped atomically, so readers never block on, or observe a partially built, replacement snapshot.
+/// A snapshot is discarded when the process changes, when it outlives the configured interval, or on `invalidate`.
+pub struct ProcessQueryCache {
+    enumerator: Box<dyn ProcessQueryEnumerator>,
+    fixed_interval: Option<Duration>,
+    snapshot: ArcSwapOption<ProcessQuerySnapshot>,
+    rebuild_lock: Mutex<()>,
+    generation: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    module_enumerations: AtomicU64,
+    region_enumerations: AtomicU64,
+}
+
+impl ProcessQueryCache {
+    /// Creates a cache that expires snapshots after the interval from the memory settings.
+    pub fn new(enumerator: Box<dyn ProcessQueryEnumerator>) -> Self {
+        Self::new_with_interval(enumerator, None)
+    }
+
+    /// Creates a cache with an optional fixed expiry interval that overrides the memory settings.
+    pub fn new_with_interval(
+        enumerator: Box<dyn ProcessQueryEnumerator>,
+        fixed_interval: Option<Duration>,
+    ) -> Self {
+        Self {
+            enumerator,
+            fixed_interval,
+            snapshot: ArcSwapOption::empty(),
+            rebuild_lock: Mutex::new(()),
+            generation: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            module_enumerations: AtomicU64::new(0),
+            region_enumerations: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get_instance() -> &'static ProcessQueryCache {
+        static INSTANCE: OnceLock<ProcessQueryCache> = OnceLock::new();
+
+        INSTANCE.get_or_init(|| ProcessQueryCache::new(Box::new(OsProcessQueryEnumerator)))
+    }
+
+    /// Gets the modules of the given process, enumerating them only if the cached snapshot does not have them.
+    pub fn get_modules(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Arc<Vec<NormalizedModule>> {
+        let snapshot = self.get_snapshot(process_info);
+
+        self.get_or_enumerate(&snapshot.modules, &self.module_enumerations, || self.enumerator.enumerate_modules(process_info))
+    }
+
+    /// Gets all usermode regions of the given process, enumerating them only if the cached snapshot does not have them.
+    pub fn get_regions(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Arc<Vec<NormalizedRegion>> {
+        let snapshot = self.get_snapshot(process_info);
+
+        self.get_or_enumerate(&snapshot.regions, &self.region_enumerations, || self.enumerator.enumerate_regions(process_info))
+    }
+
+    /// Discards the cached snapshot, such that the next lookup enumerates again. Readers holding the old lists keep them.
+    pub fn invalidate(&self) {
+        let _rebuild_guard = self
+            .rebuild_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if self.snapshot.swap(None).is_some() {
+            self.generation.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    pub fn get_stats(&self) -> ProcessQueryCacheStats {
+        ProcessQueryCacheStats {
+            generation: self.generation.load(Ordering::Acquire),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            module_enumerations: self.module_enumerations.load(Ordering::Relaxed),
+            region_enumerations: self.region_enumerations.load(Ordering::Relaxed),
+        }
+    }
+
+    fn get_max_age(&self) -> Duration {
+        match self.fixed_interval {
+            Some(fixed_interval) => fixed_interval,
+            None => Duration::from_millis(MemorySettingsConfig::get_query_cache_interval_ms()),
+        }
+    }
+
+    fn get_snapshot(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> Arc<ProcessQuerySnapshot> {
+        let max_age = self.get_max_age();
+
+        if let Some(snapshot) = self
+            .snapshot
+            .load_full()
+            .filter(|snapshot| snapshot.is_valid_for(process_info, max_age))
+        {
+            return snapshot;
+        }
+
+        // Serialize replacements, such that concurrent misses agree on a single new snapshot rather than each enumerating.
+        let _rebuild_guard = self
+            .rebuild_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let previous_snapshot = self.snapshot.load_full();
+
+        if let Some(snapshot) = previous_snapshot
+            .as_ref()
+            .filter(|snapshot| snapshot.is_valid_for(process_info, max_age))
+        {
+            return snapshot.clone();
+        }
+
+        let snapshot = Arc::new(ProcessQuerySnapshot::new(process_info));
+
+        self.snapshot.store(Some(snapshot.clone()));
+
+        if previous_snapshot.is_some() {
+            self.generation.fetch_add(1, Ordering::AcqRel);
+        }
+
+        snapshot
+    }
+
+    fn get_or_enumerate<T>(
+        &self,
+        cached_list: &OnceLock<Arc<Vec<T>>>,
+        enumeration_counter: &AtomicU64,
+        enumerate: impl FnOnce() -> Vec<T>,
+    ) -> Arc<Vec<T>> {
+        let mut did_enumerate = false;
+        let list = cached_list
+            .get_or_init(|| {
+                did_enumerate = true;
+                enumeration_counter.fetch_add(1, Ordering::Relaxed);
+                Arc::new(enumerate())
+            })
+            .clone();
+
+        if did_enumerate {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        }
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProcessQueryCache, ProcessQueryEnumerator};
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    const MOCK_REGION_COUNT: u64 = 50_000;
+
+    struct MockProcessQueryEnumerator {
+        region_enumerations: Arc<AtomicU64>,
+    }
+
+    impl ProcessQueryEnumerator for MockProcessQueryEnumerator {
+        fn enumerate_modules(
+            &self,
+            _process_info: &OpenedProcessInfo,
+        ) -> Vec<NormalizedModule> {
+            vec![NormalizedModule::new("game.exe", 0x10000, 0x1000)]
+        }
+
+        fn enumerate_regions(
+            &self,
+            _process_info: &OpenedProcessInfo,
+        ) -> Vec<NormalizedRegion> {
+            self.region_enumerations.fetch_add(1, Ordering::Relaxed);
+
+            (0..MOCK_REGION_COUNT)
+                .map(|index| NormalizedRegion::new(0x10000 + index * 0x1000, 0x1000))
+                .collect()
+        }
+    }
+
+    fn make_process(
+        process_id: u32,
+        handle: u64,
+    ) -> OpenedProcessInfo {
+        OpenedProcessInfo::new(process_id, "game.exe".to_string(), handle, Bitness::Bit64, None)
+    }
+
+    #[test]
+    fn gui_frame_sequence_enumerates_once_per_process_and_invalidation() {
+        let region_enumerations = Arc::new(AtomicU64::new(0));
+        let cache = ProcessQueryCache::new_with_interval(
+            Box::new(MockProcessQueryEnumerator {
+                region_enumerations: region_enumerations.clone(),
+            }),
+            Some(Duration::from_secs(60)),
+        );
+        let process = make_process(100, 1);
+
+        // Each frame, the results list, memory viewer and coverage preview all ask for modules and regions.
+        for _ in 0..60 {
+            assert_eq!(cache.get_modules(&process).len(), 1);
+            assert_eq!(cache.get_regions(&process).len() as u64, MOCK_REGION_COUNT);
+            assert_eq!(cache.get_regions(&process).len() as u64, MOCK_REGION_COUNT);
+        }
+
+        assert_eq!(region_enumerations.load(Ordering::Relaxed), 1);
+
+        let held_regions = cache.get_regions(&process);
+
+        cache.invalidate();
+        assert_eq!(held_regions.len() as u64, MOCK_REGION_COUNT);
+        cache.get_regions(&process);
+        cache.get_regions(&make_process(200, 2));
+
+        let stats = cache.get_stats();
+
+        assert_eq!(region_enumerations.load(Ordering::Relaxed), 3);
+        assert_eq!(stats.region_enumerations, 3);
+        assert_eq!(stats.module_enumerations, 1);
+        assert_eq!(stats.generation, 2);
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.hits, 179);
+    }
+
+    #[test]
+    fn expired_snapshots_are_enumerated_again() {
+        let region_enumerations = Arc::new(AtomicU64::new(0));
+        let cache = ProcessQueryCache::new_with_interval(
+            Box::new(MockProcessQueryEnumerator {
+                region_enumerations: region_enumerations.clone(),
+            }),
+            Some(Duration::ZERO),
+        );
+        let process = make_process(100, 1);
+
+        cache.get_regions(&process);
+        cache.get_regions(&process);
+
+        assert_eq!(region_enumerations.load(Ordering::Relaxed), 2);
+    }
+}
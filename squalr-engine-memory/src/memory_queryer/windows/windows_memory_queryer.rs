@@ -4,7 +4,7 @@ use crate::memory_queryer::memory_type_enum::MemoryTypeEnum;
 use crate::memory_queryer::region_bounds_handling::RegionBoundsHandling;
 use core::ffi::c_void;
 use core::mem::size_of;
-use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::address_space_bounds::AddressSpaceBounds;
 use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
 use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
@@ -25,6 +25,21 @@ impl WindowsMemoryQueryer {
         WindowsMemoryQueryer
     }
 
+    /// WOW64 targets get 32-bit bounds; native targets use the system-provided bounds instead of a hardcoded constant.
+    fn get_address_space_bounds(
+        &self,
+        process_info: &OpenedProcessInfo,
+    ) -> AddressSpaceBounds {
+        let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
+        unsafe { GetNativeSystemInfo(&mut system_info) };
+
+        AddressSpaceBounds::for_process(
+            process_info,
+            system_info.lpMinimumApplicationAddress as usize as u64,
+            system_info.lpMaximumApplicationAddress as usize as u64,
+        )
+    }
+
     fn get_protection_flags(
         &self,
         protection: &MemoryProtectionEnum,
@@ -227,41 +242,24 @@ impl IMemoryQueryer for WindowsMemoryQueryer {
         &self,
         process_info: &OpenedProcessInfo,
     ) -> u64 {
-        if process_info.get_bitness() == Bitness::Bit32 {
-            u32::MAX as u64
-        } else {
-            u64::MAX
-        }
+        self.get_address_space_bounds(process_info)
+            .get_maximum_address()
     }
 
     fn get_min_usermode_address(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> u64 {
-        if process_info.get_bitness() == Bitness::Bit32 {
-            // In Windows, anything below this is not addressable by a normal 32-bit program.
-            0x10000
-        } else {
-            let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
-            unsafe { GetNativeSystemInfo(&mut system_info) };
-            system_info.lpMinimumApplicationAddress as usize as u64
-        }
+        self.get_address_space_bounds(process_info)
+            .get_min_usermode_address()
     }
 
     fn get_max_usermode_address(
         &self,
         process_info: &OpenedProcessInfo,
     ) -> u64 {
-        if process_info.get_bitness() == Bitness::Bit32 {
-            // For 32-bit applications, the usermode memory is generally the first 2GB of process RAM.
-            // JIRA: Large Address Aware support? This is incredibly rare, but would be more correct to support.
-            0x7FFF_FFFF
-        } else {
-            // Use system-provided bounds instead of a hardcoded constant.
-            let mut system_info: SYSTEM_INFO = unsafe { std::mem::zeroed() };
-            unsafe { GetNativeSystemInfo(&mut system_info) };
-            system_info.lpMaximumApplicationAddress as usize as u64
-        }
+        self.get_address_space_bounds(process_info)
+            .get_max_usermode_address()
     }
 
     fn get_modules(
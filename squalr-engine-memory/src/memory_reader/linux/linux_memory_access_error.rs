@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+/// Classifies failed memory accesses on Linux by errno, such that the cause can be reported usefully.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LinuxMemoryAccessError {
+    /// EPERM or EACCES. Accessing another process requires CAP_SYS_PTRACE, or the same user with a permissive ptrace scope.
+    PermissionDenied,
+    /// ESRCH. The target process has exited.
+    ProcessExited,
+    /// EFAULT or EIO. The address is unmapped, or the page does not allow the access.
+    BadAddress,
+    /// Any other errno.
+    Other(i32),
+}
+
+impl LinuxMemoryAccessError {
+    pub fn from_errno(errno: i32) -> Self {
+        match errno {
+            libc::EPERM | libc::EACCES => Self::PermissionDenied,
+            libc::ESRCH => Self::ProcessExited,
+            libc::EFAULT | libc::EIO => Self::BadAddress,
+            errno => Self::Other(errno),
+        }
+    }
+
+    pub fn last_os_error() -> Self {
+        Self::from_errno(std::io::Error::last_os_error().raw_os_error().unwrap_or(0))
+    }
+
+    /// Logs this error. Bad addresses are expected while scanning, and are only logged at debug level. Other errors are logged
+    /// as errors the first time they occur for a process, since a scan would otherwise repeat the same error for every region.
+    pub fn report(
+        &self,
+        process_id: u32,
+        operation: &str,
+        address: u64,
+        size: usize,
+    ) {
+        static REPORTED_ERRORS: OnceLock<Mutex<HashSet<(u32, LinuxMemoryAccessError)>>> = OnceLock::new();
+
+        let is_first_report = *self != Self::BadAddress
+            && REPORTED_ERRORS
+                .get_or_init(|| Mutex::new(HashSet::new()))
+                .lock()
+                .map(|mut reported_errors| reported_errors.insert((process_id, *self)))
+                .unwrap_or(false);
+
+        if is_first_report {
+            log::error!(
+                "{} failed for process {} (addr=0x{:X}, size={}): {}",
+                operation,
+                process_id,
+                address,
+                size,
+                self
+            );
+        } else {
+            log::debug!(
+                "{} failed for process {} (addr=0x{:X}, size={}): {}",
+                operation,
+                process_id,
+                address,
+                size,
+                self
+            );
+        }
+    }
+}
+
+impl fmt::Display for LinuxMemoryAccessError {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::PermissionDenied => write!(
+                formatter,
+                "permission denied, this needs CAP_SYS_PTRACE or same-user (check /proc/sys/kernel/yama/ptrace_scope)"
+            ),
+            Self::ProcessExited => write!(formatter, "the process has exited"),
+            Self::BadAddress => write!(formatter, "the address is not mapped or not accessible"),
+            Self::Other(errno) => write!(formatter, "{}", std::io::Error::from_raw_os_error(*errno)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxMemoryAccessError;
+
+    #[test]
+    fn errno_values_are_classified() {
+        assert_eq!(LinuxMemoryAccessError::from_errno(libc::EPERM), LinuxMemoryAccessError::PermissionDenied);
+        assert_eq!(LinuxMemoryAccessError::from_errno(libc::ESRCH), LinuxMemoryAccessError::ProcessExited);
+        assert_eq!(LinuxMemoryAccessError::from_errno(libc::EFAULT), LinuxMemoryAccessError::BadAddress);
+        assert_eq!(LinuxMemoryAccessError::from_errno(libc::EINVAL), LinuxMemoryAccessError::Other(libc::EINVAL));
+        assert!(
+            LinuxMemoryAccessError::PermissionDenied
+                .to_string()
+                .contains("CAP_SYS_PTRACE or same-user")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::memory_reader::linux::linux_memory_access_error::LinuxMemoryAccessError;
 use crate::memory_reader::memory_reader_trait::IMemoryReader;
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
 use squalr_engine_api::structures::{data_values::data_value::DataValue, processes::opened_process_info::OpenedProcessInfo};
@@ -6,9 +7,106 @@ use std::os::raw::c_void;
 pub struct LinuxMemoryReader;
 
 impl LinuxMemoryReader {
+    /// The kernel rejects calls with more than IOV_MAX remote iovecs.
+    pub(crate) const MAX_IOVECS_PER_CALL: usize = 1024;
+
     pub fn new() -> Self {
         LinuxMemoryReader
     }
+
+    pub(crate) fn get_page_size() -> u64 {
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+
+        if page_size > 0 { page_size as u64 } else { 4096 }
+    }
+
+    /// Splits a remote range into page bounded iovecs, up to the per call limit. A transfer stops at the first page that faults,
+    /// so keeping each iovec within a page means the returned byte count tells exactly how far the transfer got.
+    pub(crate) fn build_remote_iovecs(
+        address: u64,
+        size: usize,
+    ) -> Vec<libc::iovec> {
+        let page_size = Self::get_page_size();
+        let mut remote_iovecs = Vec::new();
+        let mut current_address = address;
+        let mut remaining_size = size as u64;
+
+        while remaining_size > 0 && remote_iovecs.len() < Self::MAX_IOVECS_PER_CALL {
+            let bytes_to_page_end = page_size - (current_address % page_size);
+            let iovec_size = bytes_to_page_end.min(remaining_size);
+
+            remote_iovecs.push(libc::iovec {
+                iov_base: current_address as *mut c_void,
+                iov_len: iovec_size as usize,
+            });
+
+            current_address = match current_address.checked_add(iovec_size) {
+                Some(next_address) => next_address,
+                None => break,
+            };
+            remaining_size -= iovec_size;
+        }
+
+        remote_iovecs
+    }
+
+    /// Reads the full range with process_vm_readv, continuing after short transfers until no further progress can be made.
+    fn read_process_memory(
+        process_id: u32,
+        address: u64,
+        values: &mut [u8],
+    ) -> Result<(), LinuxMemoryAccessError> {
+        if address.checked_add(values.len() as u64).is_none() {
+            return Err(LinuxMemoryAccessError::BadAddress);
+        }
+
+        let mut bytes_read = 0usize;
+
+        while bytes_read < values.len() {
+            let remote_iovecs = Self::build_remote_iovecs(address + bytes_read as u64, values.len() - bytes_read);
+            let chunk_size = remote_iovecs
+                .iter()
+                .map(|remote_iovec| remote_iovec.iov_len)
+                .sum();
+            let local_iovec = libc::iovec {
+                iov_base: values[bytes_read..].as_mut_ptr() as *mut c_void,
+                iov_len: chunk_size,
+            };
+
+            let result = unsafe {
+                libc::process_vm_readv(
+                    process_id as libc::pid_t,
+                    &local_iovec,
+                    1,
+                    remote_iovecs.as_ptr(),
+                    remote_iovecs.len() as libc::c_ulong,
+                    0,
+                )
+            };
+
+            match result {
+                result if result < 0 => return Err(LinuxMemoryAccessError::last_os_error()),
+                0 => return Err(LinuxMemoryAccessError::BadAddress),
+                result => bytes_read += result as usize,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read_and_report(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        match Self::read_process_memory(process_info.get_process_id_raw(), address, values) {
+            Ok(()) => true,
+            Err(error) => {
+                error.report(process_info.get_process_id_raw(), "process_vm_readv", address, values.len());
+                false
+            }
+        }
+    }
 }
 
 impl IMemoryReader for LinuxMemoryReader {
@@ -18,7 +116,14 @@ impl IMemoryReader for LinuxMemoryReader {
         address: u64,
         data_value: &mut DataValue,
     ) -> bool {
-        false
+        let mut buffer = vec![0u8; data_value.get_size_in_bytes() as usize];
+        let success = Self::read_and_report(process_info, address, &mut buffer);
+
+        if success {
+            data_value.copy_from_bytes(&buffer);
+        }
+
+        success
     }
 
     fn read_struct(
@@ -27,7 +132,14 @@ impl IMemoryReader for LinuxMemoryReader {
         address: u64,
         valued_struct: &mut ValuedStruct,
     ) -> bool {
-        false
+        let mut buffer = vec![0u8; valued_struct.get_size_in_bytes() as usize];
+        let success = Self::read_and_report(process_info, address, &mut buffer);
+
+        if success {
+            valued_struct.copy_from_bytes(&buffer);
+        }
+
+        success
     }
 
     fn read_bytes(
@@ -36,6 +148,57 @@ impl IMemoryReader for LinuxMemoryReader {
         address: u64,
         values: &mut [u8],
     ) -> bool {
-        false
+        Self::read_and_report(process_info, address, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxMemoryReader;
+    use crate::memory_reader::memory_reader_trait::IMemoryReader;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    fn open_own_process() -> OpenedProcessInfo {
+        let process_id = std::process::id();
+
+        OpenedProcessInfo::new(process_id, "test-harness".to_string(), process_id as u64, Bitness::Bit64, None)
+    }
+
+    #[test]
+    fn reads_spanning_several_pages_match_local_memory() {
+        let page_size = LinuxMemoryReader::get_page_size() as usize;
+        let expected_bytes: Vec<u8> = (0..page_size * 3 + 123)
+            .map(|index| (index % 251) as u8)
+            .collect();
+        let mut read_bytes = vec![0u8; expected_bytes.len() - 7];
+
+        // Start at an unaligned address, such that the read is split on page boundaries.
+        let address = expected_bytes.as_ptr() as u64 + 7;
+
+        assert!(LinuxMemoryReader::new().read_bytes(&open_own_process(), address, &mut read_bytes));
+        assert_eq!(read_bytes, expected_bytes[7..]);
+    }
+
+    #[test]
+    fn reads_from_unmapped_memory_fail() {
+        let mut read_bytes = vec![0u8; 16];
+
+        assert!(!LinuxMemoryReader::new().read_bytes(&open_own_process(), 0x10, &mut read_bytes));
+    }
+
+    #[test]
+    fn remote_iovecs_are_page_bounded_and_capped() {
+        let page_size = LinuxMemoryReader::get_page_size();
+        let remote_iovecs = LinuxMemoryReader::build_remote_iovecs(page_size - 4, (page_size * 2) as usize);
+
+        assert_eq!(remote_iovecs.len(), 3);
+        assert_eq!(remote_iovecs[0].iov_len, 4);
+        assert_eq!(remote_iovecs[1].iov_len, page_size as usize);
+        assert_eq!(remote_iovecs[2].iov_len, page_size as usize - 4);
+
+        let remote_iovecs = LinuxMemoryReader::build_remote_iovecs(0, (page_size as usize) * (LinuxMemoryReader::MAX_IOVECS_PER_CALL + 8));
+
+        assert_eq!(remote_iovecs.len(), LinuxMemoryReader::MAX_IOVECS_PER_CALL);
     }
 }
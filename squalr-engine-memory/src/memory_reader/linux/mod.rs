@@ -1 +1,2 @@
+pub mod linux_memory_access_error;
 pub mod linux_memory_reader;
@@ -6,7 +6,7 @@ use std::sync::Once;
 mod android;
 
 #[cfg(any(target_os = "linux"))]
-mod linux;
+pub(crate) mod linux;
 
 #[cfg(any(target_os = "macos"))]
 mod macos;
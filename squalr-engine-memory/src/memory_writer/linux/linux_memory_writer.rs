@@ -1,3 +1,5 @@
+use crate::memory_reader::linux::linux_memory_access_error::LinuxMemoryAccessError;
+use crate::memory_reader::linux::linux_memory_reader::LinuxMemoryReader;
 use crate::memory_writer::memory_writer_trait::IMemoryWriter;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use std::os::raw::c_void;
@@ -11,11 +13,147 @@ impl LinuxMemoryWriter {
     }
 
     fn write_memory(
-        process_handle: u64,
+        process_id: u32,
         address: u64,
         data: &[u8],
     ) -> bool {
-        false
+        let bytes_written = match Self::write_process_memory(process_id, address, data) {
+            Ok(()) => return true,
+            Err((bytes_written, error)) => {
+                error.report(process_id, "process_vm_writev", address, data.len());
+
+                // Read-only pages (ie code) and restrictive ptrace policies deny the vm call, but ptrace writes may still be allowed.
+                if error != LinuxMemoryAccessError::PermissionDenied && error != LinuxMemoryAccessError::BadAddress {
+                    return false;
+                }
+
+                bytes_written
+            }
+        };
+        let remaining_address = address + bytes_written as u64;
+        let remaining_data = &data[bytes_written..];
+
+        match Self::write_with_ptrace(process_id, remaining_address, remaining_data) {
+            Ok(()) => true,
+            Err(error) => {
+                error.report(process_id, "ptrace(PTRACE_POKEDATA)", remaining_address, remaining_data.len());
+                false
+            }
+        }
+    }
+
+    /// Writes with process_vm_writev, continuing after short transfers. On failure, returns how many bytes were written.
+    fn write_process_memory(
+        process_id: u32,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), (usize, LinuxMemoryAccessError)> {
+        if address.checked_add(data.len() as u64).is_none() {
+            return Err((0, LinuxMemoryAccessError::BadAddress));
+        }
+
+        let mut bytes_written = 0usize;
+
+        while bytes_written < data.len() {
+            let remote_iovecs = LinuxMemoryReader::build_remote_iovecs(address + bytes_written as u64, data.len() - bytes_written);
+            let chunk_size = remote_iovecs
+                .iter()
+                .map(|remote_iovec| remote_iovec.iov_len)
+                .sum();
+            let local_iovec = libc::iovec {
+                iov_base: data[bytes_written..].as_ptr() as *mut c_void,
+                iov_len: chunk_size,
+            };
+
+            let result = unsafe {
+                libc::process_vm_writev(
+                    process_id as libc::pid_t,
+                    &local_iovec,
+                    1,
+                    remote_iovecs.as_ptr(),
+                    remote_iovecs.len() as libc::c_ulong,
+                    0,
+                )
+            };
+
+            match result {
+                result if result < 0 => return Err((bytes_written, LinuxMemoryAccessError::last_os_error())),
+                0 => return Err((bytes_written, LinuxMemoryAccessError::BadAddress)),
+                result => bytes_written += result as usize,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attaches to the process, writes word by word with PTRACE_POKEDATA (which ignores page protections), then detaches.
+    fn write_with_ptrace(
+        process_id: u32,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), LinuxMemoryAccessError> {
+        let pid = process_id as libc::pid_t;
+
+        unsafe {
+            if libc::ptrace(libc::PTRACE_ATTACH, pid, null_mut::<c_void>(), null_mut::<c_void>()) < 0 {
+                return Err(LinuxMemoryAccessError::last_os_error());
+            }
+
+            // The process must be stopped before its memory can be poked.
+            let mut status = 0;
+            let result = if libc::waitpid(pid, &mut status, libc::__WALL) < 0 {
+                Err(LinuxMemoryAccessError::last_os_error())
+            } else {
+                Self::poke_bytes(pid, address, data)
+            };
+
+            libc::ptrace(libc::PTRACE_DETACH, pid, null_mut::<c_void>(), null_mut::<c_void>());
+
+            result
+        }
+    }
+
+    /// Pokes the given bytes into a stopped tracee. Partially covered words are read first, such that neighbouring bytes are kept.
+    unsafe fn poke_bytes(
+        pid: libc::pid_t,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), LinuxMemoryAccessError> {
+        let word_size = size_of::<libc::c_long>() as u64;
+        let first_word_address = address - address % word_size;
+        let end_address = address + data.len() as u64;
+        let mut word_address = first_word_address;
+
+        while word_address < end_address {
+            // PEEKDATA returns the word itself, so errno is the only way to tell a failure apart from a word of -1.
+            unsafe { *libc::__errno_location() = 0 };
+
+            let word = unsafe { libc::ptrace(libc::PTRACE_PEEKDATA, pid, word_address as *mut c_void, null_mut::<c_void>()) };
+
+            if word == -1 && std::io::Error::last_os_error().raw_os_error().unwrap_or(0) != 0 {
+                return Err(LinuxMemoryAccessError::last_os_error());
+            }
+
+            let mut word_bytes = word.to_ne_bytes();
+
+            for (byte_index, word_byte) in word_bytes.iter_mut().enumerate() {
+                let byte_address = word_address + byte_index as u64;
+
+                if byte_address >= address && byte_address < end_address {
+                    *word_byte = data[(byte_address - address) as usize];
+                }
+            }
+
+            let new_word = libc::c_long::from_ne_bytes(word_bytes);
+
+            if unsafe { libc::ptrace(libc::PTRACE_POKEDATA, pid, word_address as *mut c_void, new_word as *mut c_void) } < 0 {
+                return Err(LinuxMemoryAccessError::last_os_error());
+            }
+
+            word_address += word_size;
+        }
+
+        Ok(())
     }
 }
 
@@ -26,6 +164,34 @@ impl IMemoryWriter for LinuxMemoryWriter {
         address: u64,
         values: &[u8],
     ) -> bool {
-        Self::write_memory(process_info.get_handle(), address, values)
+        Self::write_memory(process_info.get_process_id_raw(), address, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinuxMemoryWriter;
+    use crate::memory_reader::linux::linux_memory_reader::LinuxMemoryReader;
+    use crate::memory_reader::memory_reader_trait::IMemoryReader;
+    use crate::memory_writer::memory_writer_trait::IMemoryWriter;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+
+    #[test]
+    fn writes_round_trip_through_reads() {
+        let process_id = std::process::id();
+        let process_info = OpenedProcessInfo::new(process_id, "test-harness".to_string(), process_id as u64, Bitness::Bit64, None);
+        let page_size = LinuxMemoryReader::get_page_size() as usize;
+        let target_bytes = vec![0u8; page_size * 2 + 64];
+        let written_bytes: Vec<u8> = (0..page_size + 32)
+            .map(|index| (index % 199) as u8 + 1)
+            .collect();
+        let address = target_bytes.as_ptr() as u64 + page_size as u64 - 16;
+        let mut read_back_bytes = vec![0u8; written_bytes.len()];
+
+        assert!(LinuxMemoryWriter::new().write_bytes(&process_info, address, &written_bytes));
+        assert!(LinuxMemoryReader::new().read_bytes(&process_info, address, &mut read_back_bytes));
+        assert_eq!(read_back_bytes, written_bytes);
+        assert_eq!(std::hint::black_box(&target_bytes)[page_size - 17], 0);
     }
 }
@@ -1,6 +1,13 @@
 pub mod memory_writer_trait;
+pub mod write_ledger;
 
-use std::sync::Once;
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::memory_writer::memory_writer_trait::IMemoryWriter;
+use crate::memory_writer::write_ledger::WriteLedger;
+use squalr_engine_api::structures::memory::memory_restore_result::MemoryRestoreResult;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::sync::{Once, RwLock};
 
 #[cfg(any(target_os = "android"))]
 mod android;
@@ -43,4 +50,104 @@ impl MemoryWriter {
             INSTANCE.as_ref().unwrap_unchecked()
         }
     }
+
+    /// Gets the ledger of original bytes for all writes made through `write_bytes_with_ledger` this session.
+    pub fn get_write_ledger() -> &'static RwLock<WriteLedger> {
+        static mut LEDGER: Option<RwLock<WriteLedger>> = None;
+        static INIT: Once = Once::new();
+
+        unsafe {
+            INIT.call_once(|| {
+                LEDGER = Some(RwLock::new(WriteLedger::new()));
+            });
+
+            #[allow(static_mut_refs)]
+            LEDGER.as_ref().unwrap_unchecked()
+        }
+    }
+
+    /// Writes bytes to the process, first capturing the original bytes if any of the written bytes have not been captured yet.
+    /// All user-initiated writes (pokes, freezes, edits, patches) should go through this so they can be restored later.
+    pub fn write_bytes_with_ledger(
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        if process_info.get_is_read_only() {
+            log::warn!("Cannot write to 0x{:X}: the process is attached read-only.", address);
+
+            return false;
+        }
+
+        let is_range_recorded = match Self::get_write_ledger().write() {
+            Ok(mut write_ledger) => {
+                write_ledger.bind_process(Some(process_info.get_process_id_raw()));
+                write_ledger.is_range_recorded(address, values.len())
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write ledger lock: {}", error);
+                true
+            }
+        };
+
+        // Only pay for a read when this write touches bytes that have not already been captured.
+        if !is_range_recorded {
+            let mut original_bytes = vec![0u8; values.len()];
+
+            if MemoryReader::get_instance().read_bytes(process_info, address, &mut original_bytes)
+                && let Ok(mut write_ledger) = Self::get_write_ledger().write()
+            {
+                write_ledger.record(address, &original_bytes);
+            }
+        }
+
+        Self::get_instance().write_bytes(process_info, address, values)
+    }
+
+    /// Restores every recorded address to its original bytes, most recent first. Addresses that fail to restore are kept in
+    /// the ledger so that the restore can be retried.
+    pub fn restore_ledger(process_info: &OpenedProcessInfo) -> Vec<MemoryRestoreResult> {
+        // The original bytes stay in the ledger, such that they can still be restored once this instance may write again.
+        if process_info.get_is_read_only() {
+            log::warn!("Cannot restore original bytes: the process is attached read-only.");
+
+            return vec![];
+        }
+
+        let entries = match Self::get_write_ledger().write() {
+            Ok(mut write_ledger) => {
+                write_ledger.bind_process(Some(process_info.get_process_id_raw()));
+                write_ledger.take_entries_for_restore()
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write ledger lock: {}", error);
+                return vec![];
+            }
+        };
+
+        let mut restore_results = Vec::with_capacity(entries.len());
+        let mut failed_entries = vec![];
+
+        for entry in entries {
+            let success = Self::get_instance().write_bytes(process_info, entry.get_address(), entry.get_original_bytes());
+
+            restore_results.push(MemoryRestoreResult {
+                address: entry.get_address(),
+                byte_count: entry.get_original_bytes().len() as u64,
+                success,
+            });
+
+            if !success {
+                failed_entries.push(entry);
+            }
+        }
+
+        if !failed_entries.is_empty()
+            && let Ok(mut write_ledger) = Self::get_write_ledger().write()
+        {
+            write_ledger.reinsert(failed_entries);
+        }
+
+        restore_results
+    }
 }
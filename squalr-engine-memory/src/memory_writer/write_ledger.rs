@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+
+/// The original bytes of a write, captured immediately before the write was made.
+#[derive(Clone, Debug)]
+pub struct WriteLedgerEntry {
+    address: u64,
+    original_bytes: Vec<u8>,
+}
+
+impl WriteLedgerEntry {
+    pub fn get_address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn get_original_bytes(&self) -> &[u8] {
+        &self.original_bytes
+    }
+}
+
+/// Tracks the original bytes of every address written to during a session, such that all modifications can be undone.
+/// Each write that touches a byte not yet captured is recorded as its own entry. Restoring entries most recent first leaves
+/// every byte holding the value captured by the earliest write that covered it, even when writes partially overlap.
+pub struct WriteLedger {
+    process_id: Option<u32>,
+    entries: Vec<WriteLedgerEntry>,
+    recorded_ranges: BTreeMap<u64, u64>,
+    total_bytes: usize,
+    is_capacity_exceeded: bool,
+}
+
+impl WriteLedger {
+    /// The maximum number of original bytes retained before new addresses stop being recorded.
+    pub const MAX_LEDGER_BYTES: usize = 16 * 1024 * 1024;
+
+    pub fn new() -> Self {
+        Self {
+            process_id: None,
+            entries: vec![],
+            recorded_ranges: BTreeMap::new(),
+            total_bytes: 0,
+            is_capacity_exceeded: false,
+        }
+    }
+
+    /// Associates the ledger with the given process, discarding all entries if this differs from the previous process.
+    pub fn bind_process(
+        &mut self,
+        process_id: Option<u32>,
+    ) {
+        if self.process_id != process_id {
+            self.clear();
+            self.process_id = process_id;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recorded_ranges.clear();
+        self.total_bytes = 0;
+        self.is_capacity_exceeded = false;
+    }
+
+    /// Gets whether every byte of a write of the given length at the given address has already had its original value captured.
+    pub fn is_range_recorded(
+        &self,
+        address: u64,
+        length: usize,
+    ) -> bool {
+        let end_address = address.saturating_add(length as u64);
+
+        // Recorded ranges are kept merged, so only the last range starting at or before the address can cover the write.
+        match self.recorded_ranges.range(..=address).next_back() {
+            Some((_, range_end)) => *range_end >= end_address,
+            None => false,
+        }
+    }
+
+    /// Records the original bytes for a write. Writes that only touch already captured bytes are skipped, since restoring the
+    /// earlier entries recovers them. Returns false if the ledger is at capacity.
+    pub fn record(
+        &mut self,
+        address: u64,
+        original_bytes: &[u8],
+    ) -> bool {
+        if original_bytes.is_empty() || self.is_range_recorded(address, original_bytes.len()) {
+            return true;
+        }
+
+        if self.total_bytes.saturating_add(original_bytes.len()) > Self::MAX_LEDGER_BYTES {
+            if !self.is_capacity_exceeded {
+                log::warn!(
+                    "Modification ledger exceeded {} bytes. Further writes will not be restorable this session.",
+                    Self::MAX_LEDGER_BYTES
+                );
+            }
+
+            self.is_capacity_exceeded = true;
+
+            return false;
+        }
+
+        self.total_bytes += original_bytes.len();
+        self.insert_recorded_range(address, original_bytes.len());
+        self.entries.push(WriteLedgerEntry {
+            address,
+            original_bytes: original_bytes.to_vec(),
+        });
+
+        true
+    }
+
+    /// Removes and returns all entries in the order they should be restored (most recent first).
+    pub fn take_entries_for_restore(&mut self) -> Vec<WriteLedgerEntry> {
+        let mut entries = std::mem::take(&mut self.entries);
+
+        entries.reverse();
+        self.clear();
+
+        entries
+    }
+
+    /// Returns entries that failed to restore to the ledger, such that a later restore can retry them. The entries are expected
+    /// in the order they were taken for restore, and are placed ahead of anything recorded since, as they were captured earlier.
+    pub fn reinsert(
+        &mut self,
+        failed_entries: Vec<WriteLedgerEntry>,
+    ) {
+        let newer_entries = std::mem::take(&mut self.entries);
+
+        self.entries = failed_entries.into_iter().rev().collect();
+        self.entries.extend(newer_entries);
+        self.recorded_ranges.clear();
+        self.total_bytes = 0;
+
+        for index in 0..self.entries.len() {
+            let address = self.entries[index].address;
+            let length = self.entries[index].original_bytes.len();
+
+            self.total_bytes += length;
+            self.insert_recorded_range(address, length);
+        }
+    }
+
+    pub fn get_address_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn get_total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    pub fn get_is_capacity_exceeded(&self) -> bool {
+        self.is_capacity_exceeded
+    }
+
+    /// Adds the given range to the recorded ranges, merging it with any ranges it overlaps or touches.
+    fn insert_recorded_range(
+        &mut self,
+        address: u64,
+        length: usize,
+    ) {
+        let mut range_start = address;
+        let mut range_end = address.saturating_add(length as u64);
+
+        if let Some((&previous_start, &previous_end)) = self.recorded_ranges.range(..=range_start).next_back()
+            && previous_end >= range_start
+        {
+            range_start = previous_start;
+            range_end = range_end.max(previous_end);
+        }
+
+        let overlapping_starts: Vec<u64> = self
+            .recorded_ranges
+            .range(range_start..=range_end)
+            .map(|(start, _)| *start)
+            .collect();
+
+        for overlapping_start in overlapping_starts {
+            if let Some(overlapping_end) = self.recorded_ranges.remove(&overlapping_start) {
+                range_end = range_end.max(overlapping_end);
+            }
+        }
+
+        self.recorded_ranges.insert(range_start, range_end);
+    }
+}
+
+impl Default for WriteLedger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WriteLedger, WriteLedgerEntry};
+
+    /// Applies the entries in restore order to a simulated region of memory starting at the given base address.
+    fn restore_into_memory(
+        base_address: u64,
+        memory: &[u8],
+        entries: &[WriteLedgerEntry],
+    ) -> Vec<u8> {
+        let mut memory = memory.to_vec();
+
+        for entry in entries {
+            for (offset, byte) in entry.get_original_bytes().iter().enumerate() {
+                let address = entry.get_address() + offset as u64;
+
+                if address >= base_address && ((address - base_address) as usize) < memory.len() {
+                    memory[(address - base_address) as usize] = *byte;
+                }
+            }
+        }
+
+        memory
+    }
+
+    #[test]
+    fn repeated_writes_keep_first_original_and_restore_in_reverse() {
+        let mut ledger = WriteLedger::new();
+
+        ledger.bind_process(Some(100));
+        assert!(ledger.record(0x1000, &[1, 2, 3, 4]));
+        assert!(ledger.record(0x2000, &[9]));
+
+        // A later write to the same address sees the modified bytes as "original", which must not replace the first capture.
+        assert!(ledger.is_range_recorded(0x1000, 4));
+        assert!(ledger.record(0x1000, &[7, 7, 7, 7]));
+        assert_eq!(ledger.get_address_count(), 2);
+
+        assert!(!ledger.is_range_recorded(0x1000, 6));
+        assert!(ledger.record(0x1000, &[7, 7, 7, 7, 5, 6]));
+
+        assert_eq!(ledger.get_address_count(), 3);
+        assert_eq!(ledger.get_total_bytes(), 11);
+
+        let entries = ledger.take_entries_for_restore();
+
+        assert_eq!(entries[0].get_address(), 0x1000);
+        assert_eq!(entries[1].get_address(), 0x2000);
+        assert_eq!(entries[2].get_address(), 0x1000);
+        assert_eq!(restore_into_memory(0x1000, &[0; 6], &entries), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(ledger.get_address_count(), 0);
+    }
+
+    #[test]
+    fn overlapping_writes_restore_every_original_byte() {
+        let mut ledger = WriteLedger::new();
+
+        // 2 bytes at 0x1000, then 2 bytes at 0x1002, then 4 bytes spanning both, each capturing the bytes present at the time.
+        assert!(ledger.record(0x1000, &[1, 2]));
+        assert!(ledger.record(0x1002, &[3, 4]));
+        assert!(ledger.is_range_recorded(0x1000, 4));
+        assert!(ledger.record(0x1000, &[0xAA, 0xAA, 0xBB, 0xBB]));
+        assert!(!ledger.is_range_recorded(0x0FFF, 4));
+        assert!(ledger.record(0x0FFF, &[0, 0xCC, 0xCC, 0xCC]));
+
+        let entries = ledger.take_entries_for_restore();
+
+        assert_eq!(restore_into_memory(0x0FFF, &[0xFF; 5], &entries), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn failed_restores_are_retried_in_the_original_order() {
+        let mut ledger = WriteLedger::new();
+
+        ledger.record(0x1000, &[1, 2]);
+        ledger.record(0x1000, &[0xAA, 0xAA, 3]);
+
+        let entries = ledger.take_entries_for_restore();
+
+        ledger.record(0x2000, &[9]);
+        ledger.reinsert(entries);
+
+        assert_eq!(ledger.get_address_count(), 3);
+        assert_eq!(ledger.get_total_bytes(), 6);
+        assert!(ledger.is_range_recorded(0x1000, 3));
+
+        let entries = ledger.take_entries_for_restore();
+
+        assert_eq!(entries[0].get_address(), 0x2000);
+        assert_eq!(restore_into_memory(0x1000, &[0; 3], &entries), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn changing_process_clears_ledger() {
+        let mut ledger = WriteLedger::new();
+
+        ledger.bind_process(Some(100));
+        ledger.record(0x1000, &[1, 2, 3, 4]);
+        ledger.bind_process(Some(100));
+        assert_eq!(ledger.get_address_count(), 1);
+
+        ledger.bind_process(Some(200));
+        assert_eq!(ledger.get_address_count(), 0);
+        assert_eq!(ledger.get_total_bytes(), 0);
+    }
+
+    #[test]
+    fn recording_past_capacity_is_rejected() {
+        let mut ledger = WriteLedger::new();
+        let large_bytes = vec![0u8; WriteLedger::MAX_LEDGER_BYTES];
+
+        assert!(ledger.record(0x1000, &large_bytes));
+        assert!(!ledger.record(0x1000 + WriteLedger::MAX_LEDGER_BYTES as u64, &[1]));
+        assert!(ledger.get_is_capacity_exceeded());
+        assert_eq!(ledger.get_address_count(), 1);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod module_export_parser;
+pub mod module_symbol;
+pub mod module_symbol_cache;
@@ -0,0 +1,668 @@
+use crate::module_symbols::module_symbol::ModuleSymbol;
+use std::fmt;
+
+/// Reads the bytes of a module image as it is mapped into memory, by offset from the module base.
+pub trait ModuleImageReader {
+    fn read_bytes(
+        &self,
+        offset: u64,
+        bytes: &mut [u8],
+    ) -> bool;
+}
+
+/// The reasons an export table could not be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModuleExportParseError {
+    /// The parse was abandoned before it completed, ie because the process changed.
+    Cancelled,
+    /// A header or table was unreadable, or pointed outside of the module.
+    Malformed(&'static str),
+}
+
+impl fmt::Display for ModuleExportParseError {
+    fn fmt(
+        &self,
+        formatter: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ModuleExportParseError::Cancelled => write!(formatter, "parsing was cancelled"),
+            ModuleExportParseError::Malformed(reason) => write!(formatter, "malformed image: {}", reason),
+        }
+    }
+}
+
+/// Parses the exported symbols of PE and ELF images that are mapped into process memory. Only the export directory (PE) or the
+/// dynamic symbol table (ELF) is read, since section headers and debug information are generally not mapped.
+pub struct ModuleExportParser;
+
+impl ModuleExportParser {
+    /// The most symbols read from one module, which bounds the cost of parsing a corrupt or hostile image.
+    pub const MAX_SYMBOLS: usize = 1 << 18;
+
+    const MAX_SYMBOL_NAME_LENGTH: usize = 512;
+    const MAX_PROGRAM_HEADERS: u64 = 256;
+    const MAX_DYNAMIC_ENTRIES: u64 = 1024;
+    const SYMBOLS_PER_READ: usize = 256;
+
+    const PE_DOS_MAGIC: u16 = 0x5A4D;
+    const PE_SIGNATURE: u32 = 0x0000_4550;
+    const PE_OPTIONAL_HEADER_MAGIC_32: u16 = 0x10B;
+    const PE_OPTIONAL_HEADER_MAGIC_64: u16 = 0x20B;
+
+    const ELF_MAGIC: [u8; 4] = [0x7F, b'E', b'L', b'F'];
+    const ELF_PT_LOAD: u32 = 1;
+    const ELF_PT_DYNAMIC: u32 = 2;
+    const ELF_DT_NULL: u64 = 0;
+    const ELF_DT_HASH: u64 = 4;
+    const ELF_DT_STRTAB: u64 = 5;
+    const ELF_DT_SYMTAB: u64 = 6;
+    const ELF_DT_STRSZ: u64 = 10;
+    const ELF_DT_SYMENT: u64 = 11;
+    const ELF_DT_GNU_HASH: u64 = 0x6FFF_FEF5;
+    const ELF_STT_OBJECT: u8 = 1;
+    const ELF_STT_FUNC: u8 = 2;
+    const ELF_PAGE_SIZE: u64 = 0x1000;
+
+    /// Parses the exports of a mapped module. Images that are neither PE nor ELF have no exports. The cancellation check is polled
+    /// between batches of symbols, such that abandoning a large module does not wait for the parse to finish.
+    pub fn parse_exports(
+        image_reader: &dyn ModuleImageReader,
+        base_address: u64,
+        module_size: u64,
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Result<Vec<ModuleSymbol>, ModuleExportParseError> {
+        let mut magic = [0u8; 4];
+
+        if module_size < magic.len() as u64 || !image_reader.read_bytes(0, &mut magic) {
+            return Err(ModuleExportParseError::Malformed("the image header is unreadable"));
+        }
+
+        let module_image = ModuleImage {
+            image_reader,
+            module_size,
+            is_big_endian: false,
+        };
+
+        if magic == Self::ELF_MAGIC {
+            Self::parse_elf_exports(module_image, base_address, is_cancelled)
+        } else if u16::from_le_bytes([magic[0], magic[1]]) == Self::PE_DOS_MAGIC {
+            Self::parse_pe_exports(&module_image, is_cancelled)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn parse_pe_exports(
+        module_image: &ModuleImage,
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Result<Vec<ModuleSymbol>, ModuleExportParseError> {
+        let nt_headers_offset = module_image.read_u32(0x3C)? as u64;
+
+        if module_image.read_u32(nt_headers_offset)? != Self::PE_SIGNATURE {
+            return Err(ModuleExportParseError::Malformed("missing PE signature"));
+        }
+
+        // The optional header follows the 4 byte signature and the 20 byte file header.
+        let optional_header_offset = nt_headers_offset + 24;
+        let (rva_count_offset, data_directory_offset) = match module_image.read_u16(optional_header_offset)? {
+            Self::PE_OPTIONAL_HEADER_MAGIC_32 => (92, 96),
+            Self::PE_OPTIONAL_HEADER_MAGIC_64 => (108, 112),
+            _ => return Err(ModuleExportParseError::Malformed("unknown optional header magic")),
+        };
+
+        if module_image.read_u32(optional_header_offset + rva_count_offset)? == 0 {
+            return Ok(Vec::new());
+        }
+
+        let export_directory_rva = module_image.read_u32(optional_header_offset + data_directory_offset)? as u64;
+        let export_directory_size = module_image.read_u32(optional_header_offset + data_directory_offset + 4)? as u64;
+
+        if export_directory_rva == 0 || export_directory_size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let function_count = (module_image.read_u32(export_directory_rva + 20)? as usize).min(Self::MAX_SYMBOLS);
+        let name_count = (module_image.read_u32(export_directory_rva + 24)? as usize).min(Self::MAX_SYMBOLS);
+        let functions = module_image.read_vec(module_image.read_u32(export_directory_rva + 28)? as u64, function_count * 4)?;
+        let name_rvas = module_image.read_vec(module_image.read_u32(export_directory_rva + 32)? as u64, name_count * 4)?;
+        let name_ordinals = module_image.read_vec(module_image.read_u32(export_directory_rva + 36)? as u64, name_count * 2)?;
+        let export_directory_range = export_directory_rva..export_directory_rva + export_directory_size;
+        let mut symbols = Vec::with_capacity(name_count);
+
+        for name_index in 0..name_count {
+            if name_index % Self::SYMBOLS_PER_READ == 0 && is_cancelled() {
+                return Err(ModuleExportParseError::Cancelled);
+            }
+
+            let function_index = module_image.decode_u16(&name_ordinals[name_index * 2..]) as usize;
+
+            if function_index >= function_count {
+                continue;
+            }
+
+            let function_rva = module_image.decode_u32(&functions[function_index * 4..]) as u64;
+
+            // Forwarded exports point at a "module.function" string within the export directory rather than at code.
+            if function_rva == 0 || function_rva >= module_image.module_size || export_directory_range.contains(&function_rva) {
+                continue;
+            }
+
+            if let Some(name) = module_image.read_string(module_image.decode_u32(&name_rvas[name_index * 4..]) as u64, None) {
+                symbols.push(ModuleSymbol::new(name, function_rva));
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    fn parse_elf_exports(
+        mut module_image: ModuleImage,
+        base_address: u64,
+        is_cancelled: &dyn Fn() -> bool,
+    ) -> Result<Vec<ModuleSymbol>, ModuleExportParseError> {
+        let mut identity = [0u8; 2];
+
+        if !module_image.image_reader.read_bytes(4, &mut identity) {
+            return Err(ModuleExportParseError::Malformed("the ELF identity is unreadable"));
+        }
+
+        let is_64_bit = match identity[0] {
+            1 => false,
+            2 => true,
+            _ => return Err(ModuleExportParseError::Malformed("unknown ELF class")),
+        };
+
+        module_image.is_big_endian = match identity[1] {
+            1 => false,
+            2 => true,
+            _ => return Err(ModuleExportParseError::Malformed("unknown ELF data encoding")),
+        };
+
+        let (program_header_offset, program_header_size, program_header_count) = if is_64_bit {
+            (module_image.read_u64(0x20)?, module_image.read_u16(0x36)?, module_image.read_u16(0x38)?)
+        } else {
+            (module_image.read_u32(0x1C)? as u64, module_image.read_u16(0x2A)?, module_image.read_u16(0x2C)?)
+        };
+        let mut lowest_load_address = None;
+        let mut dynamic_address = None;
+
+        for program_header_index in 0..(program_header_count as u64).min(Self::MAX_PROGRAM_HEADERS) {
+            let program_header = program_header_offset + program_header_index * program_header_size as u64;
+            let virtual_address = if is_64_bit {
+                module_image.read_u64(program_header + 16)?
+            } else {
+                module_image.read_u32(program_header + 8)? as u64
+            };
+
+            match module_image.read_u32(program_header)? {
+                Self::ELF_PT_LOAD => {
+                    let page_address = virtual_address & !(Self::ELF_PAGE_SIZE - 1);
+
+                    lowest_load_address = Some(lowest_load_address.map_or(page_address, |lowest: u64| lowest.min(page_address)));
+                }
+                Self::ELF_PT_DYNAMIC => dynamic_address = Some(virtual_address),
+                _ => {}
+            }
+        }
+
+        let (Some(lowest_load_address), Some(dynamic_address)) = (lowest_load_address, dynamic_address) else {
+            return Ok(Vec::new());
+        };
+
+        // Symbol values are link-time addresses, which map onto the module base through the lowest loaded segment.
+        let address_to_offset = |address: u64| address.checked_sub(lowest_load_address);
+
+        // The dynamic linker relocates most dynamic entries to runtime addresses, but not on every platform.
+        let pointer_to_offset = |pointer: u64| {
+            if pointer >= base_address && pointer - base_address < module_image.module_size {
+                Some(pointer - base_address)
+            } else {
+                address_to_offset(pointer)
+            }
+        };
+
+        let dynamic_offset = address_to_offset(dynamic_address).ok_or(ModuleExportParseError::Malformed("the dynamic segment precedes the image"))?;
+        let dynamic_entry_size = if is_64_bit { 16 } else { 8 };
+        let mut hash_offset = None;
+        let mut gnu_hash_offset = None;
+        let mut string_table_offset = None;
+        let mut string_table_size = None;
+        let mut symbol_table_offset = None;
+        let mut symbol_entry_size = if is_64_bit { 24 } else { 16 };
+
+        for dynamic_index in 0..Self::MAX_DYNAMIC_ENTRIES {
+            let dynamic_entry = dynamic_offset + dynamic_index * dynamic_entry_size;
+            let tag = module_image.read_word(dynamic_entry, is_64_bit)?;
+            let value = module_image.read_word(dynamic_entry + dynamic_entry_size / 2, is_64_bit)?;
+
+            match tag {
+                Self::ELF_DT_NULL => break,
+                Self::ELF_DT_HASH => hash_offset = pointer_to_offset(value),
+                Self::ELF_DT_GNU_HASH => gnu_hash_offset = pointer_to_offset(value),
+                Self::ELF_DT_STRTAB => string_table_offset = pointer_to_offset(value),
+                Self::ELF_DT_STRSZ => string_table_size = Some(value),
+                Self::ELF_DT_SYMTAB => symbol_table_offset = pointer_to_offset(value),
+                Self::ELF_DT_SYMENT => symbol_entry_size = value.max(1),
+                _ => {}
+            }
+        }
+
+        let (Some(string_table_offset), Some(symbol_table_offset)) = (string_table_offset, symbol_table_offset) else {
+            return Ok(Vec::new());
+        };
+
+        if symbol_entry_size < if is_64_bit { 24 } else { 16 } {
+            return Err(ModuleExportParseError::Malformed("dynamic symbols are smaller than the ELF class allows"));
+        }
+
+        // The dynamic symbol table has no length of its own, so it is recovered from the hash tables.
+        let symbol_count = if let Some(hash_offset) = hash_offset {
+            module_image.read_u32(hash_offset + 4)? as u64
+        } else if let Some(gnu_hash_offset) = gnu_hash_offset {
+            Self::get_gnu_hash_symbol_count(&module_image, gnu_hash_offset, is_64_bit)?
+        } else if string_table_offset > symbol_table_offset {
+            (string_table_offset - symbol_table_offset) / symbol_entry_size
+        } else {
+            0
+        };
+        let symbol_count = (symbol_count as usize).min(Self::MAX_SYMBOLS);
+        let mut symbols = Vec::new();
+
+        for first_symbol_index in (0..symbol_count).step_by(Self::SYMBOLS_PER_READ) {
+            if is_cancelled() {
+                return Err(ModuleExportParseError::Cancelled);
+            }
+
+            let batch_symbol_count = Self::SYMBOLS_PER_READ.min(symbol_count - first_symbol_index);
+            let symbol_entries = module_image.read_vec(
+                symbol_table_offset + first_symbol_index as u64 * symbol_entry_size,
+                batch_symbol_count * symbol_entry_size as usize,
+            )?;
+
+            for symbol_entry in symbol_entries.chunks_exact(symbol_entry_size as usize) {
+                let (name_offset, symbol_info, section_index, value) = if is_64_bit {
+                    (
+                        module_image.decode_u32(symbol_entry),
+                        symbol_entry[4],
+                        module_image.decode_u16(&symbol_entry[6..]),
+                        module_image.decode_u64(&symbol_entry[8..]),
+                    )
+                } else {
+                    (
+                        module_image.decode_u32(symbol_entry),
+                        symbol_entry[12],
+                        module_image.decode_u16(&symbol_entry[14..]),
+                        module_image.decode_u32(&symbol_entry[4..]) as u64,
+                    )
+                };
+                let symbol_type = symbol_info & 0xF;
+
+                // Undefined symbols are imports, which belong to whichever module defines them.
+                if name_offset == 0 || section_index == 0 || value == 0 || (symbol_type != Self::ELF_STT_FUNC && symbol_type != Self::ELF_STT_OBJECT) {
+                    continue;
+                }
+
+                let Some(offset) = address_to_offset(value).filter(|offset| *offset < module_image.module_size) else {
+                    continue;
+                };
+
+                let name_limit = string_table_size.map(|string_table_size| string_table_offset + string_table_size);
+
+                if let Some(name) = module_image.read_string(string_table_offset + name_offset as u64, name_limit) {
+                    symbols.push(ModuleSymbol::new(name, offset));
+                }
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    /// Gets the number of dynamic symbols from a GNU hash table, which is one past the last symbol reachable from its buckets.
+    fn get_gnu_hash_symbol_count(
+        module_image: &ModuleImage,
+        gnu_hash_offset: u64,
+        is_64_bit: bool,
+    ) -> Result<u64, ModuleExportParseError> {
+        let bucket_count = module_image.read_u32(gnu_hash_offset)? as u64;
+        let first_hashed_symbol = module_image.read_u32(gnu_hash_offset + 4)? as u64;
+        let bloom_word_count = module_image.read_u32(gnu_hash_offset + 8)? as u64;
+        let buckets_offset = gnu_hash_offset + 16 + bloom_word_count * if is_64_bit { 8 } else { 4 };
+        let buckets = module_image.read_vec(buckets_offset, (bucket_count as usize).min(Self::MAX_SYMBOLS) * 4)?;
+        let last_bucket_symbol = buckets
+            .chunks_exact(4)
+            .map(|bucket| module_image.decode_u32(bucket) as u64)
+            .max()
+            .unwrap_or(0);
+
+        if last_bucket_symbol < first_hashed_symbol {
+            return Ok(first_hashed_symbol);
+        }
+
+        // Walk the chain of the last bucket until the entry marking the end of the chain.
+        let chains_offset = buckets_offset + bucket_count * 4;
+        let mut symbol_index = last_bucket_symbol;
+
+        while symbol_index < Self::MAX_SYMBOLS as u64 {
+            let chain_entry = module_image.read_u32(chains_offset + (symbol_index - first_hashed_symbol) * 4)?;
+
+            symbol_index += 1;
+
+            if chain_entry & 1 != 0 {
+                break;
+            }
+        }
+
+        Ok(symbol_index)
+    }
+}
+
+/// Bounds checked, endian aware reads over a mapped module image.
+struct ModuleImage<'a> {
+    image_reader: &'a dyn ModuleImageReader,
+    module_size: u64,
+    is_big_endian: bool,
+}
+
+impl ModuleImage<'_> {
+    fn read_vec(
+        &self,
+        offset: u64,
+        length: usize,
+    ) -> Result<Vec<u8>, ModuleExportParseError> {
+        let mut bytes = vec![0u8; length];
+
+        if length == 0 {
+            return Ok(bytes);
+        }
+
+        if offset.saturating_add(length as u64) > self.module_size || !self.image_reader.read_bytes(offset, &mut bytes) {
+            return Err(ModuleExportParseError::Malformed("a table lies outside of the image"));
+        }
+
+        Ok(bytes)
+    }
+
+    fn read_u16(
+        &self,
+        offset: u64,
+    ) -> Result<u16, ModuleExportParseError> {
+        Ok(self.decode_u16(&self.read_vec(offset, 2)?))
+    }
+
+    fn read_u32(
+        &self,
+        offset: u64,
+    ) -> Result<u32, ModuleExportParseError> {
+        Ok(self.decode_u32(&self.read_vec(offset, 4)?))
+    }
+
+    fn read_u64(
+        &self,
+        offset: u64,
+    ) -> Result<u64, ModuleExportParseError> {
+        Ok(self.decode_u64(&self.read_vec(offset, 8)?))
+    }
+
+    fn read_word(
+        &self,
+        offset: u64,
+        is_64_bit: bool,
+    ) -> Result<u64, ModuleExportParseError> {
+        if is_64_bit {
+            self.read_u64(offset)
+        } else {
+            Ok(self.read_u32(offset)? as u64)
+        }
+    }
+
+    /// Reads a null terminated string, stopping at the image end or limit. Names cut short by either are discarded.
+    fn read_string(
+        &self,
+        offset: u64,
+        limit: Option<u64>,
+    ) -> Option<String> {
+        let limit = limit.unwrap_or(self.module_size).min(self.module_size);
+        let mut name_bytes = Vec::new();
+        let mut chunk = [0u8; 32];
+
+        while name_bytes.len() < ModuleExportParser::MAX_SYMBOL_NAME_LENGTH {
+            let chunk_offset = offset.checked_add(name_bytes.len() as u64)?;
+            let chunk_length = (limit.checked_sub(chunk_offset)? as usize).min(chunk.len());
+
+            if chunk_length == 0
+                || !self
+                    .image_reader
+                    .read_bytes(chunk_offset, &mut chunk[..chunk_length])
+            {
+                return None;
+            }
+
+            match chunk[..chunk_length].iter().position(|byte| *byte == 0) {
+                Some(terminator_index) => {
+                    name_bytes.extend_from_slice(&chunk[..terminator_index]);
+
+                    return if name_bytes.is_empty() {
+                        None
+                    } else {
+                        Some(String::from_utf8_lossy(&name_bytes).into_owned())
+                    };
+                }
+                None => name_bytes.extend_from_slice(&chunk[..chunk_length]),
+            }
+        }
+
+        None
+    }
+
+    fn decode_u16(
+        &self,
+        bytes: &[u8],
+    ) -> u16 {
+        let bytes = [bytes[0], bytes[1]];
+
+        if self.is_big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+
+    fn decode_u32(
+        &self,
+        bytes: &[u8],
+    ) -> u32 {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+
+        if self.is_big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+
+    fn decode_u64(
+        &self,
+        bytes: &[u8],
+    ) -> u64 {
+        let mut word = [0u8; 8];
+
+        word.copy_from_slice(&bytes[..8]);
+
+        if self.is_big_endian {
+            u64::from_be_bytes(word)
+        } else {
+            u64::from_le_bytes(word)
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{ModuleExportParseError, ModuleExportParser, ModuleImageReader};
+    use crate::module_symbols::module_symbol::ModuleSymbol;
+
+    pub(crate) struct ByteImageReader<'a> {
+        pub(crate) image: &'a [u8],
+    }
+
+    impl ModuleImageReader for ByteImageReader<'_> {
+        fn read_bytes(
+            &self,
+            offset: u64,
+            bytes: &mut [u8],
+        ) -> bool {
+            let start = offset as usize;
+
+            match self.image.get(start..start + bytes.len()) {
+                Some(source) => {
+                    bytes.copy_from_slice(source);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    fn put(
+        image: &mut [u8],
+        offset: usize,
+        bytes: &[u8],
+    ) {
+        image[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    /// Builds a PE32+ image exporting `Beta` at 0x500 and `Alpha` at 0x520, plus a forwarded export.
+    pub(crate) fn create_pe_image() -> Vec<u8> {
+        let mut image = vec![0u8; 0x2000];
+
+        put(&mut image, 0x00, b"MZ");
+        put(&mut image, 0x3C, &0x80u32.to_le_bytes());
+        put(&mut image, 0x80, b"PE\0\0");
+        put(&mut image, 0x98, &0x20Bu16.to_le_bytes());
+        put(&mut image, 0x98 + 108, &16u32.to_le_bytes());
+        put(&mut image, 0x98 + 112, &0x1000u32.to_le_bytes());
+        put(&mut image, 0x98 + 116, &0x100u32.to_le_bytes());
+
+        // Export directory: function count, name count, and the function, name, and ordinal tables.
+        put(&mut image, 0x1000 + 20, &3u32.to_le_bytes());
+        put(&mut image, 0x1000 + 24, &3u32.to_le_bytes());
+        put(&mut image, 0x1000 + 28, &0x1040u32.to_le_bytes());
+        put(&mut image, 0x1000 + 32, &0x1050u32.to_le_bytes());
+        put(&mut image, 0x1000 + 36, &0x1060u32.to_le_bytes());
+
+        for (index, function_rva) in [0x500u32, 0x520, 0x10D0].iter().enumerate() {
+            put(&mut image, 0x1040 + index * 4, &function_rva.to_le_bytes());
+        }
+
+        for (index, name_rva) in [0x10A0u32, 0x10B0, 0x10C0].iter().enumerate() {
+            put(&mut image, 0x1050 + index * 4, &name_rva.to_le_bytes());
+        }
+
+        for (index, ordinal) in [1u16, 0, 2].iter().enumerate() {
+            put(&mut image, 0x1060 + index * 2, &ordinal.to_le_bytes());
+        }
+
+        put(&mut image, 0x10A0, b"Alpha\0");
+        put(&mut image, 0x10B0, b"Beta\0");
+        put(&mut image, 0x10C0, b"Forwarded\0");
+        put(&mut image, 0x10D0, b"OTHER.Function\0");
+
+        image
+    }
+
+    /// Builds a 64-bit ELF shared object defining `game_tick` at 0x800 and `player_health` at 0x900, plus an undefined import.
+    /// Dynamic entries are biased by the given amount, to mimic a dynamic linker that relocates them to runtime addresses.
+    pub(crate) fn create_elf_image(dynamic_pointer_bias: u64) -> Vec<u8> {
+        let mut image = vec![0u8; 0x1000];
+
+        put(&mut image, 0x00, &[0x7F, b'E', b'L', b'F', 2, 1]);
+        put(&mut image, 0x10, &3u16.to_le_bytes());
+        put(&mut image, 0x20, &0x40u64.to_le_bytes());
+        put(&mut image, 0x36, &56u16.to_le_bytes());
+        put(&mut image, 0x38, &2u16.to_le_bytes());
+
+        // A single loadable segment, and the dynamic segment within it.
+        put(&mut image, 0x40, &1u32.to_le_bytes());
+        put(&mut image, 0x40 + 40, &0x1000u64.to_le_bytes());
+        put(&mut image, 0x78, &2u32.to_le_bytes());
+        put(&mut image, 0x78 + 16, &0x200u64.to_le_bytes());
+        put(&mut image, 0x78 + 40, &0x50u64.to_le_bytes());
+
+        for (index, (tag, value)) in [(4u64, 0x300u64), (5, 0x400), (6, 0x500), (11, 24)]
+            .iter()
+            .enumerate()
+        {
+            let value = if *tag == 11 { *value } else { *value + dynamic_pointer_bias };
+
+            put(&mut image, 0x200 + index * 16, &tag.to_le_bytes());
+            put(&mut image, 0x200 + index * 16 + 8, &value.to_le_bytes());
+        }
+
+        put(&mut image, 0x304, &4u32.to_le_bytes());
+        put(&mut image, 0x400, b"\0game_tick\0player_health\0imported\0");
+
+        for (index, (name_offset, symbol_info, section_index, value)) in [
+            (1u32, 0x12u8, 1u16, 0x800u64),
+            (11, 0x11, 2, 0x900),
+            (25, 0x12, 0, 0),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let symbol_entry = 0x500 + (index + 1) * 24;
+
+            put(&mut image, symbol_entry, &name_offset.to_le_bytes());
+            put(&mut image, symbol_entry + 4, &[*symbol_info]);
+            put(&mut image, symbol_entry + 6, &section_index.to_le_bytes());
+            put(&mut image, symbol_entry + 8, &value.to_le_bytes());
+        }
+
+        image
+    }
+
+    fn parse(
+        image: &[u8],
+        base_address: u64,
+    ) -> Result<Vec<ModuleSymbol>, ModuleExportParseError> {
+        ModuleExportParser::parse_exports(&ByteImageReader { image }, base_address, image.len() as u64, &|| false)
+    }
+
+    #[test]
+    fn pe_exports_are_parsed_without_forwarders() {
+        let mut symbols = parse(&create_pe_image(), 0x1_4000_0000).unwrap();
+
+        symbols.sort_by_key(|symbol| symbol.get_offset());
+
+        assert_eq!(
+            symbols,
+            vec![
+                ModuleSymbol::new("Beta".to_string(), 0x500),
+                ModuleSymbol::new("Alpha".to_string(), 0x520)
+            ]
+        );
+    }
+
+    #[test]
+    fn elf_dynamic_symbols_are_parsed_whether_or_not_relocated() {
+        let base_address = 0x7F00_0000_0000;
+        let expected_symbols = vec![
+            ModuleSymbol::new("game_tick".to_string(), 0x800),
+            ModuleSymbol::new("player_health".to_string(), 0x900),
+        ];
+
+        assert_eq!(parse(&create_elf_image(0), base_address).unwrap(), expected_symbols);
+        assert_eq!(parse(&create_elf_image(base_address), base_address).unwrap(), expected_symbols);
+    }
+
+    #[test]
+    fn unknown_truncated_and_cancelled_images_are_handled() {
+        let pe_image = create_pe_image();
+
+        assert_eq!(parse(&[0u8; 0x100], 0x1000).unwrap(), Vec::new());
+        assert!(matches!(parse(&pe_image[..0x1010], 0x1000), Err(ModuleExportParseError::Malformed(_))));
+        assert_eq!(
+            ModuleExportParser::parse_exports(&ByteImageReader { image: &pe_image }, 0x1000, pe_image.len() as u64, &|| true),
+            Err(ModuleExportParseError::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+/// A symbol exported by a module, located by its offset from the module base.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleSymbol {
+    name: String,
+    offset: u64,
+}
+
+impl ModuleSymbol {
+    pub fn new(
+        name: String,
+        offset: u64,
+    ) -> Self {
+        Self { name, offset }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+/// The parsed exports of one loaded module instance, sorted by offset for lookups.
+#[derive(Debug)]
+pub struct ModuleSymbolTable {
+    module_name: String,
+    base_address: u64,
+    symbols: Vec<ModuleSymbol>,
+}
+
+impl ModuleSymbolTable {
+    pub fn new(
+        module_name: &str,
+        base_address: u64,
+        mut symbols: Vec<ModuleSymbol>,
+    ) -> Self {
+        symbols.sort_by(|left, right| {
+            left.offset
+                .cmp(&right.offset)
+                .then_with(|| left.name.cmp(&right.name))
+        });
+        symbols.dedup_by_key(|symbol| symbol.offset);
+
+        Self {
+            module_name: module_name.to_string(),
+            base_address,
+            symbols,
+        }
+    }
+
+    pub fn get_module_name(&self) -> &str {
+        &self.module_name
+    }
+
+    pub fn get_base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    pub fn get_symbols(&self) -> &Vec<ModuleSymbol> {
+        &self.symbols
+    }
+
+    /// Gets the closest symbol at or below the given offset from the module base.
+    pub fn find_symbol(
+        &self,
+        offset: u64,
+    ) -> Option<&ModuleSymbol> {
+        let symbol_index = self.symbols.partition_point(|symbol| symbol.offset <= offset);
+
+        symbol_index
+            .checked_sub(1)
+            .map(|symbol_index| &self.symbols[symbol_index])
+    }
+
+    /// Formats an address as `module!symbol` or `module!symbol+0x10`, if a symbol precedes it within this module.
+    pub fn format_address(
+        &self,
+        address: u64,
+    ) -> Option<String> {
+        let offset = address.checked_sub(self.base_address)?;
+        let symbol = self.find_symbol(offset)?;
+        let displacement = offset - symbol.offset;
+
+        if displacement == 0 {
+            Some(format!("{}!{}", self.module_name, symbol.name))
+        } else {
+            Some(format!("{}!{}+0x{:X}", self.module_name, symbol.name, displacement))
+        }
+    }
+}
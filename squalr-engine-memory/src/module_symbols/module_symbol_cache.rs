@@ -0,0 +1,475 @@
+use crate::memory_reader::MemoryReader;
+use crate::memory_reader::memory_reader_trait::IMemoryReader;
+use crate::module_symbols::module_export_parser::{ModuleExportParseError, ModuleExportParser, ModuleImageReader};
+use crate::module_symbols::module_symbol::ModuleSymbolTable;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use std::collections::{HashSet, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Reads the memory of a process. The cache wraps one of these such that module images can be mocked.
+pub trait ModuleImageSource: Send + Sync {
+    fn read_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        bytes: &mut [u8],
+    ) -> bool;
+}
+
+/// Reads module images from the memory of the opened process.
+pub struct OsModuleImageSource;
+
+impl ModuleImageSource for OsModuleImageSource {
+    fn read_bytes(
+        &self,
+        process_info: &OpenedProcessInfo,
+        address: u64,
+        bytes: &mut [u8],
+    ) -> bool {
+        MemoryReader::get_instance().read_bytes(process_info, address, bytes)
+    }
+}
+
+/// Adapts a process memory source to the module relative reads of the export parser.
+struct ProcessModuleImageReader<'a> {
+    image_source: &'a dyn ModuleImageSource,
+    process_info: &'a OpenedProcessInfo,
+    base_address: u64,
+}
+
+impl ModuleImageReader for ProcessModuleImageReader<'_> {
+    fn read_bytes(
+        &self,
+        offset: u64,
+        bytes: &mut [u8],
+    ) -> bool {
+        match self.base_address.checked_add(offset) {
+            Some(address) => self.image_source.read_bytes(self.process_info, address, bytes),
+            None => false,
+        }
+    }
+}
+
+/// A module waiting to be parsed, tagged with the cache generation it was requested in.
+struct ModuleSymbolJob {
+    generation: u64,
+    process_info: OpenedProcessInfo,
+    module_name: String,
+    base_address: u64,
+    module_size: u64,
+}
+
+#[derive(Default)]
+struct ModuleSymbolCacheState {
+    process_key: Option<(u32, u64)>,
+    /// Parsed modules, with the most recently used first.
+    symbol_tables: VecDeque<Arc<ModuleSymbolTable>>,
+    pending_modules: HashSet<(String, u64)>,
+}
+
+struct ModuleSymbolCacheShared {
+    image_source: Box<dyn ModuleImageSource>,
+    on_symbols_loaded: Box<dyn Fn(&str) + Send + Sync>,
+    capacity: usize,
+    generation: AtomicU64,
+    state: Mutex<ModuleSymbolCacheState>,
+}
+
+/// Lazily parses module exports, such that addresses can be shown as `module!symbol+0x10`. A module is only parsed once an address
+/// within it is resolved, and parsing happens on a small worker pool, such that resolving never blocks on a large module.
+/// Unresolved addresses return none until the parse completes, at which point the loaded callback fires so callers can resolve again.
+/// Only the most recently used modules are retained, and changing process abandons all pending parses.
+pub struct ModuleSymbolCache {
+    shared: Arc<ModuleSymbolCacheShared>,
+    job_sender: Sender<ModuleSymbolJob>,
+}
+
+impl ModuleSymbolCache {
+    pub const DEFAULT_WORKER_COUNT: usize = 2;
+    pub const DEFAULT_CAPACITY: usize = 64;
+
+    /// Creates a cache with the default worker count and capacity. The callback receives the name of each module once parsed.
+    pub fn new(
+        image_source: Box<dyn ModuleImageSource>,
+        on_symbols_loaded: Box<dyn Fn(&str) + Send + Sync>,
+    ) -> Self {
+        Self::new_with_limits(image_source, Self::DEFAULT_WORKER_COUNT, Self::DEFAULT_CAPACITY, on_symbols_loaded)
+    }
+
+    pub fn new_with_limits(
+        image_source: Box<dyn ModuleImageSource>,
+        worker_count: usize,
+        capacity: usize,
+        on_symbols_loaded: Box<dyn Fn(&str) + Send + Sync>,
+    ) -> Self {
+        let shared = Arc::new(ModuleSymbolCacheShared {
+            image_source,
+            on_symbols_loaded,
+            capacity: capacity.max(1),
+            generation: AtomicU64::new(0),
+            state: Mutex::new(ModuleSymbolCacheState::default()),
+        });
+        let (job_sender, job_receiver) = mpsc::channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        for worker_index in 0..worker_count.max(1) {
+            let shared = shared.clone();
+            let job_receiver = job_receiver.clone();
+            let spawn_result = thread::Builder::new()
+                .name(format!("module-symbols-{}", worker_index))
+                .spawn(move || Self::run_worker(shared, job_receiver));
+
+            if let Err(error) = spawn_result {
+                log::error!("Failed to start module symbol worker: {}", error);
+            }
+        }
+
+        Self { shared, job_sender }
+    }
+
+    /// Resolves an address to `module!symbol` or `module!symbol+0x10`. Returns none for addresses outside of every module, for
+    /// addresses preceding every export of their module, and while the containing module is still being parsed.
+    pub fn resolve_address(
+        &self,
+        process_info: &OpenedProcessInfo,
+        modules: &[NormalizedModule],
+        address: u64,
+    ) -> Option<String> {
+        let module = modules.iter().find(|module| module.contains_address(address))?;
+
+        self.get_or_schedule(process_info, module)?
+            .format_address(address)
+    }
+
+    /// Schedules a module to be parsed ahead of any address within it being resolved.
+    pub fn preload_module(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) {
+        let _ = self.get_or_schedule(process_info, module);
+    }
+
+    /// Discards all parsed modules, and abandons any parses that are queued or in progress.
+    pub fn cancel_all(&self) {
+        let mut state = self
+            .shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        self.shared.generation.fetch_add(1, Ordering::AcqRel);
+        *state = ModuleSymbolCacheState::default();
+    }
+
+    fn get_or_schedule(
+        &self,
+        process_info: &OpenedProcessInfo,
+        module: &NormalizedModule,
+    ) -> Option<Arc<ModuleSymbolTable>> {
+        let mut state = self
+            .shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let process_key = (process_info.get_process_id_raw(), process_info.get_handle());
+
+        // Parses for another process are of no use, so abandon them rather than let them occupy the workers.
+        if state.process_key != Some(process_key) {
+            self.shared.generation.fetch_add(1, Ordering::AcqRel);
+            *state = ModuleSymbolCacheState {
+                process_key: Some(process_key),
+                ..ModuleSymbolCacheState::default()
+            };
+        }
+
+        let module_name = module.get_module_name();
+        let base_address = module.get_base_address();
+
+        if let Some(table_index) = state
+            .symbol_tables
+            .iter()
+            .position(|symbol_table| symbol_table.get_base_address() == base_address && symbol_table.get_module_name() == module_name)
+        {
+            let symbol_table = state.symbol_tables.remove(table_index)?;
+
+            state.symbol_tables.push_front(symbol_table.clone());
+
+            return Some(symbol_table);
+        }
+
+        if state
+            .pending_modules
+            .insert((module_name.to_string(), base_address))
+        {
+            let module_symbol_job = ModuleSymbolJob {
+                generation: self.shared.generation.load(Ordering::Acquire),
+                process_info: process_info.clone(),
+                module_name: module_name.to_string(),
+                base_address,
+                module_size: module.get_region_size(),
+            };
+
+            if let Err(error) = self.job_sender.send(module_symbol_job) {
+                log::error!("Failed to schedule symbol loading for {}: {}", module_name, error);
+            }
+        }
+
+        None
+    }
+
+    fn run_worker(
+        shared: Arc<ModuleSymbolCacheShared>,
+        job_receiver: Arc<Mutex<Receiver<ModuleSymbolJob>>>,
+    ) {
+        loop {
+            let module_symbol_job = match job_receiver
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .recv()
+            {
+                Ok(module_symbol_job) => module_symbol_job,
+                // The cache was dropped.
+                Err(_) => return,
+            };
+
+            Self::parse_module(&shared, module_symbol_job);
+        }
+    }
+
+    fn parse_module(
+        shared: &ModuleSymbolCacheShared,
+        module_symbol_job: ModuleSymbolJob,
+    ) {
+        let is_cancelled = || shared.generation.load(Ordering::Acquire) != module_symbol_job.generation;
+
+        if is_cancelled() {
+            return;
+        }
+
+        let image_reader = ProcessModuleImageReader {
+            image_source: shared.image_source.as_ref(),
+            process_info: &module_symbol_job.process_info,
+            base_address: module_symbol_job.base_address,
+        };
+        let symbols = match ModuleExportParser::parse_exports(&image_reader, module_symbol_job.base_address, module_symbol_job.module_size, &is_cancelled) {
+            Ok(symbols) => symbols,
+            Err(ModuleExportParseError::Cancelled) => return,
+            Err(error) => {
+                // Keep an empty table, such that a module without readable exports is not parsed again on every lookup.
+                log::debug!("Could not read the exports of {}: {}", module_symbol_job.module_name, error);
+                Vec::new()
+            }
+        };
+        let symbol_table = Arc::new(ModuleSymbolTable::new(&module_symbol_job.module_name, module_symbol_job.base_address, symbols));
+
+        {
+            let mut state = shared
+                .state
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            // Cancellation resets the state under this lock, so a stale parse must not be inserted into the new state.
+            if is_cancelled() {
+                return;
+            }
+
+            state
+                .pending_modules
+                .remove(&(module_symbol_job.module_name.clone(), module_symbol_job.base_address));
+            state.symbol_tables.push_front(symbol_table);
+            state.symbol_tables.truncate(shared.capacity);
+        }
+
+        (shared.on_symbols_loaded)(&module_symbol_job.module_name);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ModuleImageSource, ModuleSymbolCache};
+    use crate::module_symbols::module_export_parser::tests::{create_elf_image, create_pe_image};
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use std::sync::mpsc::{self, Receiver};
+    use std::sync::{Arc, Condvar, Mutex};
+    use std::time::Duration;
+
+    const GAME_BASE: u64 = 0x1_4000_0000;
+    const LIBRARY_BASE: u64 = 0x7F00_0000_0000;
+    const UNTOUCHED_BASE: u64 = 0x7FF8_0000_0000;
+
+    /// Serves synthetic images at fixed addresses, recording every address read, and optionally holding reads until released.
+    struct MockModuleImageSource {
+        images: Vec<(u64, Vec<u8>)>,
+        read_addresses: Arc<Mutex<Vec<u64>>>,
+        read_gate: Arc<(Mutex<bool>, Condvar)>,
+    }
+
+    impl ModuleImageSource for MockModuleImageSource {
+        fn read_bytes(
+            &self,
+            _process_info: &OpenedProcessInfo,
+            address: u64,
+            bytes: &mut [u8],
+        ) -> bool {
+            let (is_open, condvar) = &*self.read_gate;
+            let _is_open = condvar
+                .wait_while(is_open.lock().unwrap(), |is_open| !*is_open)
+                .unwrap();
+
+            self.read_addresses.lock().unwrap().push(address);
+
+            match self
+                .images
+                .iter()
+                .find(|(base_address, image)| address >= *base_address && address + bytes.len() as u64 <= *base_address + image.len() as u64)
+            {
+                Some((base_address, image)) => {
+                    let start = (address - base_address) as usize;
+
+                    bytes.copy_from_slice(&image[start..start + bytes.len()]);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    struct TestContext {
+        cache: ModuleSymbolCache,
+        modules: Vec<NormalizedModule>,
+        process: OpenedProcessInfo,
+        read_addresses: Arc<Mutex<Vec<u64>>>,
+        read_gate: Arc<(Mutex<bool>, Condvar)>,
+        loaded_modules: Receiver<String>,
+    }
+
+    fn create_context(
+        capacity: usize,
+        is_read_gate_open: bool,
+    ) -> TestContext {
+        let read_addresses = Arc::new(Mutex::new(Vec::new()));
+        let read_gate = Arc::new((Mutex::new(is_read_gate_open), Condvar::new()));
+        let (loaded_sender, loaded_modules) = mpsc::channel();
+        let loaded_sender = Mutex::new(loaded_sender);
+        let image_source = MockModuleImageSource {
+            images: vec![
+                (GAME_BASE, create_pe_image()),
+                (LIBRARY_BASE, create_elf_image(LIBRARY_BASE)),
+                (UNTOUCHED_BASE, create_pe_image()),
+            ],
+            read_addresses: read_addresses.clone(),
+            read_gate: read_gate.clone(),
+        };
+        let cache = ModuleSymbolCache::new_with_limits(
+            Box::new(image_source),
+            2,
+            capacity,
+            Box::new(move |module_name| {
+                let _ = loaded_sender.lock().unwrap().send(module_name.to_string());
+            }),
+        );
+
+        TestContext {
+            cache,
+            modules: vec![
+                NormalizedModule::new("game.exe", GAME_BASE, 0x2000),
+                NormalizedModule::new("libgame.so", LIBRARY_BASE, 0x1000),
+                NormalizedModule::new("untouched.dll", UNTOUCHED_BASE, 0x2000),
+            ],
+            process: OpenedProcessInfo::new(100, "game.exe".to_string(), 1, Bitness::Bit64, None),
+            read_addresses,
+            read_gate,
+            loaded_modules,
+        }
+    }
+
+    impl TestContext {
+        fn resolve(
+            &self,
+            address: u64,
+        ) -> Option<String> {
+            self.cache
+                .resolve_address(&self.process, &self.modules, address)
+        }
+
+        fn wait_for_loaded_module(&self) -> Option<String> {
+            self.loaded_modules.recv_timeout(Duration::from_secs(5)).ok()
+        }
+
+        fn open_read_gate(&self) {
+            let (is_open, condvar) = &*self.read_gate;
+
+            *is_open.lock().unwrap() = true;
+            condvar.notify_all();
+        }
+    }
+
+    #[test]
+    fn lazy_resolution_never_parses_untouched_modules() {
+        let context = create_context(ModuleSymbolCache::DEFAULT_CAPACITY, true);
+
+        // The first lookup only schedules the parse, and shows the raw address until it completes.
+        assert_eq!(context.resolve(GAME_BASE + 0x524), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("game.exe"));
+        assert_eq!(context.resolve(GAME_BASE + 0x524).as_deref(), Some("game.exe!Alpha+0x4"));
+        assert_eq!(context.resolve(GAME_BASE + 0x500).as_deref(), Some("game.exe!Beta"));
+
+        assert_eq!(context.resolve(LIBRARY_BASE + 0x900), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("libgame.so"));
+        assert_eq!(context.resolve(LIBRARY_BASE + 0x900).as_deref(), Some("libgame.so!player_health"));
+
+        // Addresses outside of every module never schedule a parse.
+        assert_eq!(context.resolve(0x1000), None);
+
+        let read_addresses = context.read_addresses.lock().unwrap();
+
+        assert!(!read_addresses.is_empty());
+        assert!(
+            read_addresses
+                .iter()
+                .all(|address| !(UNTOUCHED_BASE..UNTOUCHED_BASE + 0x2000).contains(address))
+        );
+    }
+
+    #[test]
+    fn least_recently_used_modules_are_evicted() {
+        let context = create_context(1, true);
+
+        assert_eq!(context.resolve(GAME_BASE + 0x500), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("game.exe"));
+        assert_eq!(context.resolve(LIBRARY_BASE + 0x800), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("libgame.so"));
+        assert_eq!(context.resolve(LIBRARY_BASE + 0x800).as_deref(), Some("libgame.so!game_tick"));
+
+        // The game module was evicted, so it is parsed again.
+        assert_eq!(context.resolve(GAME_BASE + 0x500), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("game.exe"));
+        assert_eq!(context.resolve(GAME_BASE + 0x500).as_deref(), Some("game.exe!Beta"));
+    }
+
+    #[test]
+    fn cancelled_parses_are_discarded() {
+        let context = create_context(ModuleSymbolCache::DEFAULT_CAPACITY, false);
+
+        assert_eq!(context.resolve(GAME_BASE + 0x500), None);
+        context.cache.cancel_all();
+        context.open_read_gate();
+
+        assert!(
+            context
+                .loaded_modules
+                .recv_timeout(Duration::from_millis(200))
+                .is_err()
+        );
+        assert_eq!(context.resolve(GAME_BASE + 0x500), None);
+        assert_eq!(context.wait_for_loaded_module().as_deref(), Some("game.exe"));
+        assert_eq!(context.resolve(GAME_BASE + 0x500).as_deref(), Some("game.exe!Beta"));
+    }
+}
@@ -1 +1,2 @@
+pub mod process_attach_lock;
 pub mod process_manager;
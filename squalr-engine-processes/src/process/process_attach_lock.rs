@@ -0,0 +1,465 @@
+use crate::process_query::{process_query_options::ProcessQueryOptions, process_queryer::ProcessQuery};
+use squalr_engine_api::structures::processes::{process_attach_conflict::ProcessAttachConflict, process_attach_mode::ProcessAttachMode};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+use sysinfo::Pid;
+
+/// The contents of an attach lock file, identifying the Squalr instance that holds it.
+struct ProcessAttachLockRecord {
+    instance_id: String,
+    holder_process_id: u32,
+}
+
+impl ProcessAttachLockRecord {
+    fn parse(lock_file_contents: &str) -> Option<Self> {
+        let mut instance_id = None;
+        let mut holder_process_id = None;
+
+        for line in lock_file_contents.lines() {
+            match line.split_once('=') {
+                Some(("instance_id", value)) => instance_id = Some(value.trim().to_string()),
+                Some(("holder_process_id", value)) => holder_process_id = value.trim().parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            instance_id: instance_id?,
+            holder_process_id: holder_process_id?,
+        })
+    }
+
+    fn to_lock_file_contents(&self) -> String {
+        format!("instance_id={}\nholder_process_id={}\n", self.instance_id, self.holder_process_id)
+    }
+}
+
+/// Coordinates Squalr instances attaching to the same process, such that two instances never fight over the same frozen values.
+/// Each attached process has a lock file named after its process id in a shared directory, which is created atomically by the
+/// instance that takes it. The holder refreshes the modified time of the file as a heartbeat, and watches for a sibling takeover
+/// file, through which another instance asks it to release the lock and stop writing.
+pub struct ProcessAttachLock {
+    lock_directory: PathBuf,
+    instance_id: String,
+    takeover_timeout: Duration,
+    is_process_alive: fn(u32) -> bool,
+    held_process_id: Option<u32>,
+}
+
+impl ProcessAttachLock {
+    /// Locks whose heartbeat is older than this are considered abandoned, ie if their holder hangs or the file outlives a crash.
+    const STALE_HEARTBEAT_AGE: Duration = Duration::from_secs(15);
+    /// How often a takeover checks whether the holder has released the lock.
+    const TAKEOVER_POLL_INTERVAL: Duration = Duration::from_millis(50);
+    /// How long a takeover waits for the holder to release the lock before taking it by force.
+    const DEFAULT_TAKEOVER_TIMEOUT: Duration = Duration::from_secs(2);
+
+    pub fn new() -> Self {
+        Self::new_in_directory(
+            std::env::temp_dir().join("squalr").join("attach_locks"),
+            Self::DEFAULT_TAKEOVER_TIMEOUT,
+            Self::query_is_process_alive,
+        )
+    }
+
+    fn new_in_directory(
+        lock_directory: PathBuf,
+        takeover_timeout: Duration,
+        is_process_alive: fn(u32) -> bool,
+    ) -> Self {
+        // Instances are told apart by process id and a counter, such that engines sharing a process still contend with each other.
+        static NEXT_INSTANCE_INDEX: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            lock_directory,
+            instance_id: format!("{}-{}", std::process::id(), NEXT_INSTANCE_INDEX.fetch_add(1, Ordering::Relaxed)),
+            takeover_timeout,
+            is_process_alive,
+            held_process_id: None,
+        }
+    }
+
+    /// Attaches to the given process in the given mode, returning whether the attach is read-only. Any lock held on a previously
+    /// attached process is released once the new attach succeeds, and is kept if it fails with a conflict.
+    pub fn acquire(
+        &mut self,
+        process_id: u32,
+        attach_mode: ProcessAttachMode,
+    ) -> Result<bool, ProcessAttachConflict> {
+        if attach_mode == ProcessAttachMode::ReadOnly {
+            self.release();
+
+            return Ok(true);
+        }
+
+        if self.held_process_id == Some(process_id) {
+            return Ok(false);
+        }
+
+        // Failing to coordinate must not prevent attaching, so any unexpected file system error attaches without the lock.
+        if let Err(error) = fs::create_dir_all(&self.lock_directory) {
+            log::warn!(
+                "Failed to create attach lock directory, attaching without checking for other instances: {}",
+                error
+            );
+
+            return Ok(false);
+        }
+
+        let lock_path = self.get_lock_path(process_id);
+        let takeover_path = self.get_takeover_path(process_id);
+        let mut takeover_deadline = None;
+
+        loop {
+            match self.try_create_lock_file(&lock_path) {
+                Ok(()) => {
+                    // A takeover request left over in the directory was meant for the previous holder, not for this instance.
+                    let _ = fs::remove_file(&takeover_path);
+
+                    self.release();
+                    self.held_process_id = Some(process_id);
+
+                    return Ok(false);
+                }
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(error) => {
+                    log::warn!("Failed to create attach lock, attaching without checking for other instances: {}", error);
+
+                    return Ok(false);
+                }
+            }
+
+            let lock_record = Self::read_lock_record(&lock_path);
+            let conflict = ProcessAttachConflict::new(
+                process_id,
+                lock_record
+                    .as_ref()
+                    .map(|lock_record| lock_record.holder_process_id)
+                    .unwrap_or(0),
+            );
+            let should_remove_lock = if self.is_stale(&lock_path, lock_record.as_ref()) {
+                log::warn!("Reclaiming an abandoned attach lock on process {}.", process_id);
+
+                true
+            } else {
+                match attach_mode {
+                    ProcessAttachMode::Exclusive | ProcessAttachMode::ReadOnly => return Err(conflict),
+                    ProcessAttachMode::Takeover => match takeover_deadline {
+                        None => {
+                            if let Err(error) = fs::write(&takeover_path, &self.instance_id) {
+                                log::warn!("Failed to request takeover of the attach lock: {}", error);
+                            }
+
+                            takeover_deadline = Some(Instant::now() + self.takeover_timeout);
+
+                            false
+                        }
+                        Some(takeover_deadline) if Instant::now() >= takeover_deadline => {
+                            log::warn!(
+                                "Squalr instance (pid {}) did not release its attach lock on process {} in time, taking it by force.",
+                                conflict.get_holder_process_id(),
+                                process_id
+                            );
+
+                            true
+                        }
+                        Some(_) => false,
+                    },
+                }
+            };
+
+            if should_remove_lock {
+                match fs::remove_file(&lock_path) {
+                    Ok(()) => {}
+                    Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+                    Err(error) => {
+                        log::warn!("Failed to remove attach lock: {}", error);
+
+                        return Err(conflict);
+                    }
+                }
+            } else {
+                thread::sleep(Self::TAKEOVER_POLL_INTERVAL);
+            }
+        }
+    }
+
+    /// Refreshes the heartbeat of the held lock, and returns whether the lock was just lost to another instance, either because it
+    /// asked for a takeover or because it took the lock by force. Once lost, the caller must stop writing to the process.
+    pub fn poll_for_takeover(&mut self) -> bool {
+        let Some(process_id) = self.held_process_id else {
+            return false;
+        };
+        let lock_path = self.get_lock_path(process_id);
+        let is_lock_lost = !lock_path.exists() || Self::read_lock_record(&lock_path).is_some_and(|lock_record| lock_record.instance_id != self.instance_id);
+
+        if is_lock_lost {
+            self.held_process_id = None;
+
+            return true;
+        }
+
+        if self.get_takeover_path(process_id).exists() {
+            self.release();
+
+            return true;
+        }
+
+        if let Err(error) = OpenOptions::new()
+            .write(true)
+            .open(&lock_path)
+            .and_then(|lock_file| lock_file.set_modified(SystemTime::now()))
+        {
+            log::warn!("Failed to refresh attach lock heartbeat: {}", error);
+        }
+
+        false
+    }
+
+    /// Releases the held lock, if any. A lock that another instance has since taken is left alone.
+    pub fn release(&mut self) {
+        let Some(process_id) = self.held_process_id.take() else {
+            return;
+        };
+        let lock_path = self.get_lock_path(process_id);
+
+        if Self::read_lock_record(&lock_path).is_some_and(|lock_record| lock_record.instance_id == self.instance_id)
+            && let Err(error) = fs::remove_file(&lock_path)
+        {
+            log::warn!("Failed to release attach lock: {}", error);
+        }
+    }
+
+    pub fn get_held_process_id(&self) -> Option<u32> {
+        self.held_process_id
+    }
+
+    fn try_create_lock_file(
+        &self,
+        lock_path: &Path,
+    ) -> io::Result<()> {
+        let lock_record = ProcessAttachLockRecord {
+            instance_id: self.instance_id.clone(),
+            holder_process_id: std::process::id(),
+        };
+        let mut lock_file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(lock_path)?;
+
+        if let Err(error) = lock_file.write_all(lock_record.to_lock_file_contents().as_bytes()) {
+            drop(lock_file);
+            let _ = fs::remove_file(lock_path);
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Determines whether a lock was abandoned, either because its holder exited or because its heartbeat stopped. A lock that
+    /// cannot be parsed may still be being written, so only its heartbeat is considered.
+    fn is_stale(
+        &self,
+        lock_path: &Path,
+        lock_record: Option<&ProcessAttachLockRecord>,
+    ) -> bool {
+        if lock_record.is_some_and(|lock_record| !(self.is_process_alive)(lock_record.holder_process_id)) {
+            return true;
+        }
+
+        match fs::metadata(lock_path).and_then(|metadata| metadata.modified()) {
+            Ok(modified_time) => modified_time
+                .elapsed()
+                .is_ok_and(|heartbeat_age| heartbeat_age > Self::STALE_HEARTBEAT_AGE),
+            Err(_) => false,
+        }
+    }
+
+    fn read_lock_record(lock_path: &Path) -> Option<ProcessAttachLockRecord> {
+        ProcessAttachLockRecord::parse(&fs::read_to_string(lock_path).ok()?)
+    }
+
+    fn get_lock_path(
+        &self,
+        process_id: u32,
+    ) -> PathBuf {
+        self.lock_directory.join(format!("{}.lock", process_id))
+    }
+
+    fn get_takeover_path(
+        &self,
+        process_id: u32,
+    ) -> PathBuf {
+        self.lock_directory.join(format!("{}.takeover", process_id))
+    }
+
+    fn query_is_process_alive(process_id: u32) -> bool {
+        let process_query_options = ProcessQueryOptions {
+            required_process_id: Some(Pid::from_u32(process_id)),
+            search_name: None,
+            require_windowed: false,
+            match_case: false,
+            fetch_icons: false,
+            limit: Some(1),
+        };
+
+        !ProcessQuery::get_processes(process_query_options).is_empty()
+    }
+}
+
+impl Default for ProcessAttachLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ProcessAttachLock {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessAttachLock;
+    use squalr_engine_api::structures::processes::process_attach_mode::ProcessAttachMode;
+    use std::fs::{self, OpenOptions};
+    use std::path::{Path, PathBuf};
+    use std::thread;
+    use std::time::{Duration, SystemTime};
+
+    const TARGET_PROCESS_ID: u32 = 4242;
+    const EXITED_PROCESS_ID: u32 = 7;
+
+    fn create_test_directory(test_name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("squalr_attach_lock_test_{}_{}", test_name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&directory);
+
+        directory
+    }
+
+    fn create_engine(
+        lock_directory: &Path,
+        takeover_timeout: Duration,
+    ) -> ProcessAttachLock {
+        ProcessAttachLock::new_in_directory(lock_directory.to_path_buf(), takeover_timeout, |process_id| process_id != EXITED_PROCESS_ID)
+    }
+
+    #[test]
+    fn exclusive_attach_conflicts_until_the_holder_releases() {
+        let lock_directory = create_test_directory("exclusive");
+        let mut first_engine = create_engine(&lock_directory, Duration::from_secs(1));
+        let mut second_engine = create_engine(&lock_directory, Duration::from_secs(1));
+
+        assert_eq!(first_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+
+        let conflict = second_engine
+            .acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive)
+            .unwrap_err();
+
+        assert_eq!(conflict.get_process_id(), TARGET_PROCESS_ID);
+        assert_eq!(conflict.get_holder_process_id(), std::process::id());
+        assert!(!first_engine.poll_for_takeover());
+
+        first_engine.release();
+
+        assert_eq!(second_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+        assert_eq!(second_engine.get_held_process_id(), Some(TARGET_PROCESS_ID));
+    }
+
+    #[test]
+    fn read_only_attach_leaves_the_holder_undisturbed() {
+        let lock_directory = create_test_directory("read_only");
+        let mut first_engine = create_engine(&lock_directory, Duration::from_secs(1));
+        let mut second_engine = create_engine(&lock_directory, Duration::from_secs(1));
+
+        assert_eq!(first_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+        assert_eq!(second_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::ReadOnly), Ok(true));
+        assert_eq!(second_engine.get_held_process_id(), None);
+        assert!(!first_engine.poll_for_takeover());
+        assert_eq!(first_engine.get_held_process_id(), Some(TARGET_PROCESS_ID));
+    }
+
+    #[test]
+    fn takeover_is_granted_once_the_holder_sees_the_request() {
+        let lock_directory = create_test_directory("takeover");
+        let mut first_engine = create_engine(&lock_directory, Duration::from_secs(1));
+        let mut second_engine = create_engine(&lock_directory, Duration::from_secs(30));
+
+        assert_eq!(first_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+
+        // The holder polls the way the process manager does, and stops once it has handed over the lock.
+        let first_engine_thread = thread::spawn(move || {
+            while !first_engine.poll_for_takeover() {
+                thread::sleep(Duration::from_millis(10));
+            }
+
+            first_engine
+        });
+
+        assert_eq!(second_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Takeover), Ok(false));
+
+        let first_engine = first_engine_thread.join().unwrap();
+
+        assert_eq!(first_engine.get_held_process_id(), None);
+        assert_eq!(second_engine.get_held_process_id(), Some(TARGET_PROCESS_ID));
+        assert!(
+            !lock_directory
+                .join(format!("{}.takeover", TARGET_PROCESS_ID))
+                .exists()
+        );
+    }
+
+    #[test]
+    fn takeover_forces_the_lock_from_an_unresponsive_holder() {
+        let lock_directory = create_test_directory("forced_takeover");
+        let mut first_engine = create_engine(&lock_directory, Duration::from_secs(1));
+        let mut second_engine = create_engine(&lock_directory, Duration::from_millis(100));
+
+        assert_eq!(first_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+        assert_eq!(second_engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Takeover), Ok(false));
+
+        // The former holder learns that it lost the lock on its next poll, and releasing it afterwards leaves the new lock in place.
+        assert!(first_engine.poll_for_takeover());
+        first_engine.release();
+        drop(first_engine);
+
+        assert_eq!(second_engine.get_held_process_id(), Some(TARGET_PROCESS_ID));
+        assert!(!second_engine.poll_for_takeover());
+    }
+
+    #[test]
+    fn abandoned_locks_are_reclaimed() {
+        let lock_directory = create_test_directory("stale");
+        let lock_path = lock_directory.join(format!("{}.lock", TARGET_PROCESS_ID));
+        let mut engine = create_engine(&lock_directory, Duration::from_secs(1));
+
+        // A lock left behind by an instance that has since exited.
+        fs::create_dir_all(&lock_directory).unwrap();
+        fs::write(&lock_path, format!("instance_id=abandoned\nholder_process_id={}\n", EXITED_PROCESS_ID)).unwrap();
+
+        assert_eq!(engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+
+        engine.release();
+
+        // A lock whose holder is still running, but whose heartbeat stopped long ago.
+        fs::write(&lock_path, format!("instance_id=abandoned\nholder_process_id={}\n", std::process::id())).unwrap();
+        OpenOptions::new()
+            .write(true)
+            .open(&lock_path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(60))
+            .unwrap();
+
+        assert_eq!(engine.acquire(TARGET_PROCESS_ID, ProcessAttachMode::Exclusive), Ok(false));
+        assert_eq!(engine.get_held_process_id(), Some(TARGET_PROCESS_ID));
+    }
+}
@@ -1,34 +1,66 @@
+use crate::process::process_attach_lock::ProcessAttachLock;
 use crate::process_query::{process_query_options::ProcessQueryOptions, process_queryer::ProcessQuery};
 use squalr_engine_api::{
     events::{
         engine_event::{EngineEvent, EngineEventRequest},
         process::changed::process_changed_event::ProcessChangedEvent,
     },
-    structures::processes::opened_process_info::OpenedProcessInfo,
+    structures::processes::{opened_process_info::OpenedProcessInfo, process_attach_conflict::ProcessAttachConflict, process_attach_mode::ProcessAttachMode},
 };
 use std::{
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
     thread,
     time::Duration,
 };
 
 pub struct ProcessManager {
     opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+    /// The process that was last detached from because it exited, until another process is opened or the process is closed.
+    exited_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
     event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+    attach_lock: Arc<Mutex<ProcessAttachLock>>,
 }
 
 impl ProcessManager {
+    /// How often the attach lock heartbeat is refreshed, which is also how quickly a takeover request from another instance is honored.
+    const ATTACH_LOCK_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
     pub fn new(event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>) -> Self {
         let instance = Self {
             opened_process: Arc::new(RwLock::new(None)),
+            exited_process: Arc::new(RwLock::new(None)),
             event_emitter: event_emitter.clone(),
+            attach_lock: Arc::new(Mutex::new(ProcessAttachLock::new())),
         };
 
-        Self::listen_for_open_process_death(event_emitter, instance.opened_process.clone());
+        Self::listen_for_open_process_death(
+            event_emitter.clone(),
+            instance.opened_process.clone(),
+            instance.exited_process.clone(),
+            instance.attach_lock.clone(),
+        );
+        Self::listen_for_attach_takeover(event_emitter, instance.opened_process.clone(), instance.attach_lock.clone());
 
         instance
     }
 
+    /// Takes the attach lock on the given process in the given mode, returning whether the process must be opened read-only. Fails
+    /// with a conflict if another Squalr instance holds the lock and the mode does not take it over.
+    pub fn acquire_attach_lock(
+        &self,
+        process_id: u32,
+        attach_mode: ProcessAttachMode,
+    ) -> Result<bool, ProcessAttachConflict> {
+        match self.attach_lock.lock() {
+            Ok(mut attach_lock) => attach_lock.acquire(process_id, attach_mode),
+            Err(error) => {
+                log::error!("Failed to acquire attach lock: {}", error);
+
+                Ok(false)
+            }
+        }
+    }
+
     /// Sets the process to which we are currently attached.
     pub fn set_opened_process(
         &self,
@@ -44,6 +76,7 @@ impl ProcessManager {
 
             log::info!("Opened process: {}, pid: {}", process_info.get_name(), process_info.get_process_id());
             *process = Some(process_info.clone());
+            Self::clear_exited_process(&self.exited_process);
 
             (self.event_emitter)(
                 ProcessChangedEvent {
@@ -64,6 +97,8 @@ impl ProcessManager {
             }
 
             *process = None;
+            Self::release_attach_lock(&self.attach_lock);
+            Self::clear_exited_process(&self.exited_process);
 
             log::info!("Process closed.");
 
@@ -82,6 +117,17 @@ impl ProcessManager {
         }
     }
 
+    /// Gets the process that was last detached from because it exited, if no other process has been opened or closed since.
+    pub fn get_exited_process(&self) -> Option<OpenedProcessInfo> {
+        match self.exited_process.read() {
+            Ok(exited_process) => exited_process.clone(),
+            Err(error) => {
+                log::error!("Failed to access exited process: {}", error);
+                None
+            }
+        }
+    }
+
     /// Gets a reference to the shared lock containing the currently opened process.
     pub fn get_opened_process_ref(&self) -> Arc<RwLock<Option<OpenedProcessInfo>>> {
         self.opened_process.clone()
@@ -91,6 +137,8 @@ impl ProcessManager {
     fn listen_for_open_process_death(
         event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
         opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        exited_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        attach_lock: Arc<Mutex<ProcessAttachLock>>,
     ) {
         std::thread::spawn(move || {
             loop {
@@ -128,7 +176,12 @@ impl ProcessManager {
                             }
                         }
 
+                        if let Ok(mut exited_process) = exited_process.write() {
+                            *exited_process = opened_process.take();
+                        }
+
                         *opened_process = None;
+                        Self::release_attach_lock(&attach_lock);
                         log::info!("Process no longer open, detaching.");
                         (event_emitter)(ProcessChangedEvent { process_info: None }.to_engine_event());
                     }
@@ -136,4 +189,66 @@ impl ProcessManager {
             }
         });
     }
+
+    /// Keeps the attach lock heartbeat alive, and stops writing to the opened process once another instance takes the lock over.
+    /// The process stays attached read-only, such that values can still be inspected while the other instance freezes them.
+    fn listen_for_attach_takeover(
+        event_emitter: Arc<dyn Fn(EngineEvent) + Send + Sync>,
+        opened_process: Arc<RwLock<Option<OpenedProcessInfo>>>,
+        attach_lock: Arc<Mutex<ProcessAttachLock>>,
+    ) {
+        std::thread::spawn(move || {
+            loop {
+                thread::sleep(Self::ATTACH_LOCK_POLL_INTERVAL);
+
+                let lost_process_id = match attach_lock.lock() {
+                    Ok(mut attach_lock) => {
+                        let held_process_id = attach_lock.get_held_process_id();
+
+                        if attach_lock.poll_for_takeover() { held_process_id } else { None }
+                    }
+                    Err(_) => None,
+                };
+                let Some(lost_process_id) = lost_process_id else {
+                    continue;
+                };
+
+                if let Ok(mut opened_process) = opened_process.write() {
+                    let Some(opened_process_info) = opened_process
+                        .as_ref()
+                        .filter(|opened_process_info| opened_process_info.get_process_id_raw() == lost_process_id)
+                        .cloned()
+                    else {
+                        continue;
+                    };
+                    let opened_process_info = opened_process_info.with_read_only(true);
+
+                    log::warn!(
+                        "Another Squalr instance took over process {}. Freezes and writes are stopped, and the process stays attached read-only.",
+                        lost_process_id
+                    );
+                    *opened_process = Some(opened_process_info.clone());
+                    (event_emitter)(
+                        ProcessChangedEvent {
+                            process_info: Some(opened_process_info),
+                        }
+                        .to_engine_event(),
+                    );
+                }
+            }
+        });
+    }
+
+    fn clear_exited_process(exited_process: &RwLock<Option<OpenedProcessInfo>>) {
+        if let Ok(mut exited_process) = exited_process.write() {
+            *exited_process = None;
+        }
+    }
+
+    fn release_attach_lock(attach_lock: &Mutex<ProcessAttachLock>) {
+        match attach_lock.lock() {
+            Ok(mut attach_lock) => attach_lock.release(),
+            Err(error) => log::error!("Failed to release attach lock: {}", error),
+        }
+    }
 }
@@ -5,6 +5,7 @@ use crate::process_query::process_queryer::ProcessQueryer;
 use image::ImageReader;
 use once_cell::sync::Lazy;
 use regex::bytes::Regex;
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
 use squalr_engine_common::logging::log_level::LogLevel;
 use squalr_engine_common::logging::logger::Logger;
 use std::collections::HashMap;
@@ -15,6 +16,7 @@ use std::io::Cursor;
 use std::io::Read;
 use std::path::Path;
 use std::sync::RwLock;
+use sysinfo::Pid;
 use zip::ZipArchive;
 
 pub(crate) static PROCESS_MONITOR: Lazy<RwLock<AndroidProcessMonitor>> = Lazy::new(|| RwLock::new(AndroidProcessMonitor::new()));
@@ -276,4 +278,13 @@ impl ProcessQueryer for AndroidProcessQuery {
 
         results
     }
+
+    fn capture_thumbnail(
+        _process_id: &Pid,
+        _max_width: u32,
+        _max_height: u32,
+    ) -> ProcessThumbnail {
+        // Other apps cannot be captured without the media projection permission, so only the launcher icon is shown.
+        ProcessThumbnail::Unsupported
+    }
 }
@@ -3,6 +3,7 @@ use crate::process_query::process_queryer::ProcessQueryer;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_icon::ProcessIcon;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
 use std::sync::{Arc, RwLock};
 use sysinfo::{Pid, System};
 
@@ -44,4 +45,13 @@ impl ProcessQueryer for LinuxProcessQuery {
     fn get_processes(options: ProcessQueryOptions) -> Vec<ProcessInfo> {
         vec![]
     }
+
+    fn capture_thumbnail(
+        _process_id: &Pid,
+        _max_width: u32,
+        _max_height: u32,
+    ) -> ProcessThumbnail {
+        // Capturing requires a compositor specific screenshot API (X11 XGetImage, or a Wayland portal), so only the icon is shown.
+        ProcessThumbnail::Unsupported
+    }
 }
@@ -5,6 +5,7 @@ use squalr_engine_api::structures::memory::bitness::Bitness;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_icon::ProcessIcon;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
 use std::collections::HashMap;
 use std::sync::RwLock;
 use sysinfo::{Pid, ProcessesToUpdate, System};
@@ -122,4 +123,13 @@ impl ProcessQueryer for MacOsProcessQuery {
             .take(options.limit.unwrap_or(u64::MAX) as usize)
             .collect()
     }
+
+    fn capture_thumbnail(
+        _process_id: &Pid,
+        _max_width: u32,
+        _max_height: u32,
+    ) -> ProcessThumbnail {
+        // Requires CGWindowListCreateImage, which also needs the screen recording permission.
+        ProcessThumbnail::Unsupported
+    }
 }
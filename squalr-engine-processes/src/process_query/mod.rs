@@ -1,5 +1,6 @@
 pub mod process_query_options;
 pub mod process_queryer;
+pub mod process_thumbnail_scaler;
 
 #[cfg(any(target_os = "android"))]
 mod android;
@@ -1,5 +1,6 @@
 use crate::process_query::process_query_options::ProcessQueryOptions;
-use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo};
+use squalr_engine_api::structures::processes::{opened_process_info::OpenedProcessInfo, process_info::ProcessInfo, process_thumbnail::ProcessThumbnail};
+use sysinfo::Pid;
 
 pub(crate) trait ProcessQueryer {
     fn start_monitoring() -> Result<(), String>;
@@ -7,6 +8,11 @@ pub(crate) trait ProcessQueryer {
     fn open_process(process_info: &ProcessInfo) -> Result<OpenedProcessInfo, String>;
     fn close_process(handle: u64) -> Result<(), String>;
     fn get_processes(options: ProcessQueryOptions) -> Vec<ProcessInfo>;
+    fn capture_thumbnail(
+        process_id: &Pid,
+        max_width: u32,
+        max_height: u32,
+    ) -> ProcessThumbnail;
 }
 
 #[cfg(any(target_os = "android"))]
@@ -43,4 +49,14 @@ impl ProcessQuery {
     pub fn get_processes(process_query_options: ProcessQueryOptions) -> Vec<ProcessInfo> {
         ProcessQueryImpl::get_processes(process_query_options)
     }
+
+    /// Captures a downscaled preview of the main window of a process. This can take tens of milliseconds, so avoid calling it
+    /// from a UI thread.
+    pub fn capture_thumbnail(
+        process_id: &Pid,
+        max_width: u32,
+        max_height: u32,
+    ) -> ProcessThumbnail {
+        ProcessQueryImpl::capture_thumbnail(process_id, max_width, max_height)
+    }
 }
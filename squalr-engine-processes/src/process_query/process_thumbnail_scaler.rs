@@ -0,0 +1,72 @@
+use image::RgbaImage;
+use image::imageops;
+use squalr_engine_api::structures::processes::process_icon::ProcessIcon;
+
+/// Shrinks captured window images down to thumbnail size before they leave the engine, keeping responses small.
+pub struct ProcessThumbnailScaler {}
+
+impl ProcessThumbnailScaler {
+    /// Downscales an RGBA image to fit within the given bounds, preserving the aspect ratio. Images are never upscaled.
+    pub fn downscale(
+        bytes_rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+        max_width: u32,
+        max_height: u32,
+    ) -> Option<ProcessIcon> {
+        let image = RgbaImage::from_raw(width, height, bytes_rgba)?;
+        let (fitted_width, fitted_height) = Self::get_fitted_size(width, height, max_width, max_height);
+
+        if fitted_width == width && fitted_height == height {
+            return Some(ProcessIcon::new(image.into_raw(), width, height));
+        }
+
+        let thumbnail = imageops::thumbnail(&image, fitted_width, fitted_height);
+
+        Some(ProcessIcon::new(thumbnail.into_raw(), fitted_width, fitted_height))
+    }
+
+    /// Gets the largest size with the same aspect ratio that fits within the given bounds, without exceeding the original size.
+    pub fn get_fitted_size(
+        width: u32,
+        height: u32,
+        max_width: u32,
+        max_height: u32,
+    ) -> (u32, u32) {
+        if width == 0 || height == 0 {
+            return (0, 0);
+        }
+
+        let scale = (max_width as f64 / width as f64)
+            .min(max_height as f64 / height as f64)
+            .min(1.0);
+        let fitted_width = ((width as f64 * scale).round() as u32).max(1);
+        let fitted_height = ((height as f64 * scale).round() as u32).max(1);
+
+        (fitted_width, fitted_height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessThumbnailScaler;
+
+    #[test]
+    fn fitted_size_preserves_aspect_ratio() {
+        assert_eq!(ProcessThumbnailScaler::get_fitted_size(1920, 1080, 256, 160), (256, 144));
+        assert_eq!(ProcessThumbnailScaler::get_fitted_size(800, 1200, 256, 160), (107, 160));
+    }
+
+    #[test]
+    fn small_images_are_not_upscaled() {
+        assert_eq!(ProcessThumbnailScaler::get_fitted_size(100, 50, 256, 160), (100, 50));
+    }
+
+    #[test]
+    fn downscaled_images_match_their_reported_size() {
+        let thumbnail = ProcessThumbnailScaler::downscale(vec![255u8; 640 * 480 * 4], 640, 480, 256, 160).unwrap();
+
+        assert_eq!((thumbnail.get_width(), thumbnail.get_height()), (213, 160));
+        assert_eq!(thumbnail.get_bytes_rgba().len(), 213 * 160 * 4);
+    }
+}
@@ -1,5 +1,5 @@
 use windows_sys::Win32::{
-    Graphics::Gdi::{HDC, ReleaseDC},
+    Graphics::Gdi::{DeleteDC, DeleteObject, HBITMAP, HDC, ReleaseDC},
     UI::WindowsAndMessaging::{DestroyIcon, HICON},
 };
 
@@ -22,3 +22,23 @@ impl Drop for DcHandle {
         }
     }
 }
+
+pub struct MemoryDcHandle(pub HDC);
+
+impl Drop for MemoryDcHandle {
+    fn drop(&mut self) {
+        unsafe {
+            DeleteDC(self.0);
+        }
+    }
+}
+
+pub struct BitmapHandle(pub HBITMAP);
+
+impl Drop for BitmapHandle {
+    fn drop(&mut self) {
+        unsafe {
+            DeleteObject(self.0);
+        }
+    }
+}
@@ -1,26 +1,31 @@
 use crate::process_query::process_query_options::ProcessQueryOptions;
 use crate::process_query::process_queryer::ProcessQueryer;
-use crate::process_query::windows::windows_icon_handle::{DcHandle, IconHandle};
+use crate::process_query::process_thumbnail_scaler::ProcessThumbnailScaler;
+use crate::process_query::windows::windows_icon_handle::{BitmapHandle, DcHandle, IconHandle, MemoryDcHandle};
 use crate::process_query::windows::windows_process_monitor::WindowsProcessMonitor;
 use once_cell::sync::Lazy;
 use squalr_engine_api::structures::memory::bitness::Bitness;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::processes::process_icon::ProcessIcon;
 use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::{Mutex, RwLock};
 use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate};
-use windows_sys::Win32::Foundation::{BOOL, CloseHandle, GetLastError, HANDLE, HWND, LPARAM, ERROR_ACCESS_DENIED};
-use windows_sys::Win32::Graphics::Gdi::{BITMAPINFO, BITMAPINFOHEADER, DIB_RGB_COLORS, GetDC, GetDIBits};
+use windows_sys::Win32::Foundation::{BOOL, CloseHandle, GetLastError, HANDLE, HWND, LPARAM, ERROR_ACCESS_DENIED, RECT};
+use windows_sys::Win32::Graphics::Gdi::{
+    BI_RGB, BITMAPINFO, BITMAPINFOHEADER, CreateCompatibleBitmap, CreateCompatibleDC, DIB_RGB_COLORS, GetDC, GetDIBits, SelectObject,
+};
+use windows_sys::Win32::Storage::Xps::PrintWindow;
 use windows_sys::Win32::System::ProcessStatus::K32GetModuleFileNameExW;
 use windows_sys::Win32::System::Threading::{IsWow64Process, IsWow64Process2};
 use windows_sys::Win32::System::Threading::{
     OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_OPERATION, PROCESS_VM_READ, PROCESS_VM_WRITE,
 };
 use windows_sys::Win32::UI::Shell::ExtractIconW;
-use windows_sys::Win32::UI::WindowsAndMessaging::{EnumWindows, IsWindowVisible};
+use windows_sys::Win32::UI::WindowsAndMessaging::{EnumWindows, GW_OWNER, GetWindow, GetWindowRect, IsIconic, IsWindowVisible, PW_RENDERFULLCONTENT};
 use windows_sys::Win32::UI::WindowsAndMessaging::{GetIconInfo, ICONINFO};
 use windows_sys::Win32::UI::WindowsAndMessaging::{GetWindowThreadProcessId, HICON};
 
@@ -30,6 +35,115 @@ static PROCESS_CACHE: Lazy<RwLock<HashMap<Pid, ProcessInfo>>> = Lazy::new(|| RwL
 pub struct WindowsProcessQuery {}
 
 impl WindowsProcessQuery {
+    /// Finds the main window of a process, taken to be its largest visible top-level window that has no owner.
+    fn find_main_window(process_id: &Pid) -> Option<HWND> {
+        struct MainWindowFinder {
+            process_id: u32,
+            main_window: HWND,
+            main_window_area: i64,
+        }
+
+        unsafe extern "system" fn enum_window_callback(
+            hwnd: HWND,
+            lparam: LPARAM,
+        ) -> BOOL {
+            let finder = unsafe { &mut *(lparam as *mut MainWindowFinder) };
+            let mut process_id: u32 = 0;
+            unsafe { GetWindowThreadProcessId(hwnd, &mut process_id) };
+
+            if process_id != finder.process_id || unsafe { IsWindowVisible(hwnd) } == 0 || !unsafe { GetWindow(hwnd, GW_OWNER) }.is_null() {
+                // Continue enumeration.
+                return BOOL::from(true);
+            }
+
+            let mut window_rect = unsafe { std::mem::zeroed::<RECT>() };
+            unsafe { GetWindowRect(hwnd, &mut window_rect) };
+
+            let window_area = (window_rect.right - window_rect.left) as i64 * (window_rect.bottom - window_rect.top) as i64;
+
+            // Minimized windows report a tiny placeholder rectangle, so always keep the first candidate found.
+            if finder.main_window.is_null() || window_area > finder.main_window_area {
+                finder.main_window = hwnd;
+                finder.main_window_area = window_area;
+            }
+
+            // Continue enumeration.
+            BOOL::from(true)
+        }
+
+        let mut finder = MainWindowFinder {
+            process_id: process_id.as_u32(),
+            main_window: std::ptr::null_mut(),
+            main_window_area: 0,
+        };
+
+        unsafe {
+            EnumWindows(Some(enum_window_callback), &mut finder as *mut MainWindowFinder as LPARAM);
+        }
+
+        (!finder.main_window.is_null()).then_some(finder.main_window)
+    }
+
+    /// Renders a window into an offscreen bitmap, returning its RGBA pixels along with the width and height.
+    fn capture_window(window: HWND) -> Option<(Vec<u8>, u32, u32)> {
+        unsafe {
+            let mut window_rect = std::mem::zeroed::<RECT>();
+
+            if GetWindowRect(window, &mut window_rect) == 0 {
+                return None;
+            }
+
+            let width = window_rect.right - window_rect.left;
+            let height = window_rect.bottom - window_rect.top;
+
+            if width <= 0 || height <= 0 {
+                return None;
+            }
+
+            let screen_dc = DcHandle(GetDC(std::ptr::null_mut()));
+            let memory_dc = MemoryDcHandle(CreateCompatibleDC(screen_dc.0));
+            let bitmap = BitmapHandle(CreateCompatibleBitmap(screen_dc.0, width, height));
+
+            if memory_dc.0.is_null() || bitmap.0.is_null() {
+                return None;
+            }
+
+            let previous_object = SelectObject(memory_dc.0, bitmap.0);
+            // Render the full content, including hardware accelerated surfaces that a plain `PrintWindow` leaves black.
+            let is_printed = PrintWindow(window, memory_dc.0, PW_RENDERFULLCONTENT) != 0;
+
+            // The bitmap must be deselected before it can be read back, or deleted.
+            SelectObject(memory_dc.0, previous_object);
+
+            if !is_printed {
+                return None;
+            }
+
+            let mut bmi = std::mem::zeroed::<BITMAPINFO>();
+            bmi.bmiHeader.biSize = std::mem::size_of::<BITMAPINFOHEADER>() as u32;
+            bmi.bmiHeader.biWidth = width;
+            // A negative height requests top-down rows, so unlike icons no flip is needed.
+            bmi.bmiHeader.biHeight = -height;
+            bmi.bmiHeader.biPlanes = 1;
+            bmi.bmiHeader.biBitCount = 32;
+            bmi.bmiHeader.biCompression = BI_RGB;
+
+            let mut pixels = vec![0u8; width as usize * height as usize * 4];
+
+            if GetDIBits(memory_dc.0, bitmap.0, 0, height as u32, pixels.as_mut_ptr() as *mut _, &mut bmi, DIB_RGB_COLORS) == 0 {
+                return None;
+            }
+
+            // Convert BGRX to RGBA. Window captures carry no meaningful alpha, so treat every pixel as opaque.
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+                pixel[3] = 255;
+            }
+
+            Some((pixels, width as u32, height as u32))
+        }
+    }
+
     fn is_process_windowed(process_id: &Pid) -> bool {
         struct WindowFinder {
             process_id: u32,
@@ -379,4 +493,26 @@ impl ProcessQueryer for WindowsProcessQuery {
 
         filtered_processes
     }
+
+    fn capture_thumbnail(
+        process_id: &Pid,
+        max_width: u32,
+        max_height: u32,
+    ) -> ProcessThumbnail {
+        let main_window = match Self::find_main_window(process_id) {
+            Some(main_window) => main_window,
+            None => return ProcessThumbnail::NoWindow,
+        };
+
+        if unsafe { IsIconic(main_window) } != 0 {
+            return ProcessThumbnail::Minimized;
+        }
+
+        match Self::capture_window(main_window)
+            .and_then(|(bytes_rgba, width, height)| ProcessThumbnailScaler::downscale(bytes_rgba, width, height, max_width, max_height))
+        {
+            Some(thumbnail) => ProcessThumbnail::Captured(thumbnail),
+            None => ProcessThumbnail::NoWindow,
+        }
+    }
 }
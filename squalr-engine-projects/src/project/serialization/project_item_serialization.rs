@@ -1,5 +1,7 @@
 use crate::project::serialization::serializable_project_file::SerializableProjectFile;
-use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use squalr_engine_api::structures::projects::project_items::{
+    built_in_types::project_item_type_directory::ProjectItemTypeDirectory, project_item::ProjectItem,
+};
 use std::{
     fs::{self, File},
     path::Path,
@@ -12,16 +14,20 @@ impl SerializableProjectFile for ProjectItem {
         save_even_if_unchanged: bool,
     ) -> anyhow::Result<()> {
         if save_even_if_unchanged || self.get_has_unsaved_changes() {
-            if !project_item_path.exists() {
-                fs::create_dir(&project_item_path)?;
-            }
+            // Only serialize if this is an actual file. Directories have no serialization logic, beyond ensuring that they exist.
+            if self.get_item_type().get_project_item_type_id() == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID {
+                fs::create_dir_all(project_item_path)?;
+            } else {
+                if let Some(parent_directory) = project_item_path.parent() {
+                    fs::create_dir_all(parent_directory)?;
+                }
 
-            // Only serialize if this is an actual file. Directories have no serialization logic.
-            if project_item_path.is_file() {
                 let file = File::create(&project_item_path)?;
 
                 serde_json::to_writer_pretty(file, &self)?;
             }
+
+            self.set_has_unsaved_changes(false);
         }
 
         Ok(())
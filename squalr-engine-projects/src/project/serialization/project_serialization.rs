@@ -4,7 +4,7 @@ use squalr_engine_api::structures::projects::{
     project_info::ProjectInfo,
     project_items::{built_in_types::project_item_type_directory::ProjectItemTypeDirectory, project_item::ProjectItem, project_item_ref::ProjectItemRef},
 };
-use std::{collections::HashMap, path::Path};
+use std::{collections::HashMap, fs, io::ErrorKind, path::Path};
 
 impl SerializableProjectFile for Project {
     fn save_to_path(
@@ -16,16 +16,29 @@ impl SerializableProjectFile for Project {
         self.get_project_info_mut()
             .save_to_path(directory, save_even_if_unchanged)?;
 
-        // Save all project items.
+        // Delete the files of moved and removed items first, as moved items are written again below at their new paths.
+        for removed_project_item_path in self.take_removed_project_item_paths() {
+            let remove_result = if removed_project_item_path.is_dir() {
+                fs::remove_dir_all(&removed_project_item_path)
+            } else {
+                fs::remove_file(&removed_project_item_path)
+            };
+
+            if let Err(error) = remove_result
+                && error.kind() != ErrorKind::NotFound
+            {
+                log::error!("Failed to delete removed project item {:?}: {}", removed_project_item_path, error);
+            }
+        }
+
+        // Save all project items. Items that fail to save keep their unsaved changes, such that the project stays marked as unsaved.
         for project_item_pair in self.get_project_items_mut() {
             let project_item_ref = project_item_pair.0;
             let project_item = project_item_pair.1;
             let project_item_path = project_item_ref.get_project_item_path();
 
-            if project_item_path.is_file() {
-                if let Err(error) = project_item.save_to_path(project_item_path, save_even_if_unchanged) {
-                    log::error!("Failed to serialize project item: {}", error)
-                }
+            if let Err(error) = project_item.save_to_path(project_item_path, save_even_if_unchanged) {
+                log::error!("Failed to serialize project item: {}", error)
             }
         }
 
@@ -55,7 +68,9 @@ impl SerializableProjectFile for Project {
 
                     load_recursive(&entry_path, project_items)?;
                 } else if let Some(extension) = entry_path.extension() {
-                    if extension == Project::PROJECT_ITEM_EXTENSION {
+                    let is_project_file = entry_path.file_name().is_some_and(|file_name| file_name == Project::PROJECT_FILE);
+
+                    if extension == Project::PROJECT_ITEM_EXTENSION.trim_start_matches('.') && !is_project_file {
                         let item_ref = ProjectItemRef::new(entry_path.clone());
                         let project_item = ProjectItem::load_from_path(&entry_path)?;
 
@@ -71,6 +86,117 @@ impl SerializableProjectFile for Project {
 
         load_recursive(&project_directory_path, &mut project_items)?;
 
-        Ok(Project::new(project_info, project_items, project_root_ref))
+        let mut project = Project::new(project_info, project_items, project_root_ref);
+
+        // Everything was just read from disk, so nothing is unsaved, even though directories are created fresh.
+        project.clear_unsaved_changes();
+
+        Ok(project)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::project::serialization::serializable_project_file::SerializableProjectFile;
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::projects::{
+        project::Project,
+        project_info::ProjectInfo,
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item_ref::ProjectItemRef,
+        },
+        project_manifest::ProjectManifest,
+    };
+    use std::collections::HashMap;
+    use std::fs;
+
+    #[test]
+    fn saving_clears_unsaved_changes_and_loading_starts_clean() {
+        let project_directory = std::env::temp_dir().join(format!("squalr_project_serialization_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&project_directory);
+
+        fs::create_dir_all(&project_directory).expect("Project directory should be created.");
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, ProjectManifest::default());
+        let project_root_ref = ProjectItemRef::new(project_directory.clone());
+        let mut project_items = HashMap::new();
+
+        project_items.insert(project_root_ref.clone(), ProjectItemTypeDirectory::new_project_item(&project_root_ref));
+
+        let mut project = Project::new(project_info, project_items, project_root_ref);
+
+        project.add_project_item_with_unique_name(ProjectItemTypeAddress::new_project_item(
+            "health",
+            0x1000,
+            "",
+            "",
+            DataTypeU32::get_value_from_primitive(100),
+        ));
+
+        assert!(project.get_has_unsaved_changes());
+
+        project
+            .save_to_path(&project_directory, false)
+            .expect("Project should save.");
+
+        assert!(!project.get_has_unsaved_changes());
+
+        let loaded_project = Project::load_from_path(&project_directory).expect("Project should load.");
+
+        assert!(!loaded_project.get_has_unsaved_changes());
+        assert_eq!(loaded_project.get_project_items().len(), 2);
+
+        let _ = fs::remove_dir_all(&project_directory);
+    }
+
+    #[test]
+    fn moved_and_removed_items_are_only_found_at_their_new_paths_after_saving() {
+        let project_directory = std::env::temp_dir().join(format!("squalr_project_grouping_test_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&project_directory);
+
+        fs::create_dir_all(&project_directory).expect("Project directory should be created.");
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, ProjectManifest::default());
+        let project_root_ref = ProjectItemRef::new(project_directory.clone());
+        let mut project_items = HashMap::new();
+
+        project_items.insert(project_root_ref.clone(), ProjectItemTypeDirectory::new_project_item(&project_root_ref));
+
+        let mut project = Project::new(project_info, project_items, project_root_ref);
+        let health_ref = project.add_project_item_with_unique_name(ProjectItemTypeAddress::new_project_item(
+            "health",
+            0x1000,
+            "",
+            "",
+            DataTypeU32::get_value_from_primitive(100),
+        ));
+        let player_group_ref = project.add_project_group_with_unique_name("Player");
+        let unused_group_ref = project.add_project_group_with_unique_name("Unused");
+
+        project
+            .save_to_path(&project_directory, false)
+            .expect("Project should save.");
+
+        let moved_health_ref = project
+            .move_project_item(&health_ref, &player_group_ref)
+            .expect("Entry should move into the group.");
+
+        assert!(project.remove_project_item(&unused_group_ref, false));
+
+        project
+            .save_to_path(&project_directory, false)
+            .expect("Project should save.");
+
+        assert!(!health_ref.get_project_item_path().exists());
+        assert!(!unused_group_ref.get_project_item_path().exists());
+
+        let loaded_project = Project::load_from_path(&project_directory).expect("Project should load.");
+
+        assert!(loaded_project.get_project_item(&moved_health_ref).is_some());
+        assert!(loaded_project.get_project_item(&unused_group_ref).is_none());
+        assert_eq!(loaded_project.get_project_items().len(), 3);
+
+        let _ = fs::remove_dir_all(&project_directory);
     }
 }
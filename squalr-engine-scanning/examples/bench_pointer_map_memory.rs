@@ -0,0 +1,121 @@
+// Compares the memory used per million pointers by the pointer map and a deep scan level, between the previous representation (a
+// tree of per-value address lists, and levels that own the offsets of every path) and the flat sorted arrays used now. Allocations
+// are counted by a wrapping global allocator. Uses synthetic pointers, so no target process is required.
+// Run: cargo run --release -p squalr-engine-scanning --example bench_pointer_map_memory
+
+use squalr_engine_scanning::pointer_scans::structures::pointer_map::PointerMap;
+use squalr_engine_scanning::pointer_scans::structures::pointer_scan_level::{PointerScanLevel, PointerScanNode};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const POINTER_COUNT: u64 = 1_000_000;
+// Roughly how many pointers share each value, as with pointers into the same heap object.
+const POINTERS_PER_VALUE: u64 = 4;
+const LEVEL_DEPTH: usize = 3;
+
+struct CountingAllocator;
+
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(
+        &self,
+        layout: Layout,
+    ) -> *mut u8 {
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+    ) {
+        ALLOCATED_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+    let pointers = (0..POINTER_COUNT)
+        .map(|pointer_index| {
+            let value = 0x1000_0000 + (pointer_index / POINTERS_PER_VALUE).wrapping_mul(0x9E37) % 0x1000_0000 * 8;
+            let pointer_address = 0x4000_0000 + pointer_index * 8;
+
+            (value, pointer_address)
+        })
+        .collect::<Vec<_>>();
+
+    let tree_map_bytes = measure_bytes(|| {
+        let mut pointer_map: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+
+        for (value, pointer_address) in &pointers {
+            pointer_map.entry(*value).or_default().push(*pointer_address);
+        }
+
+        pointer_map
+    });
+    let flat_map_bytes = measure_bytes(|| {
+        let mut pointer_map = PointerMap::new();
+
+        for (value, pointer_address) in &pointers {
+            pointer_map.insert(*value, *pointer_address);
+        }
+
+        pointer_map.finalize();
+        pointer_map
+    });
+
+    let owned_offsets_level_bytes = measure_bytes(|| {
+        pointers
+            .iter()
+            .map(|(_value, pointer_address)| (*pointer_address, vec![0x10u64; LEVEL_DEPTH]))
+            .collect::<Vec<_>>()
+    });
+    let flat_level_bytes = measure_bytes(|| {
+        let mut pointer_scan_level = PointerScanLevel::new();
+
+        for (pointer_index, (_value, pointer_address)) in pointers.iter().enumerate() {
+            pointer_scan_level.push(PointerScanNode {
+                pointer_address: *pointer_address,
+                offset: 0x10,
+                parent_index: pointer_index as u32,
+            });
+        }
+
+        pointer_scan_level.finalize();
+        pointer_scan_level
+    });
+
+    let per_million = |bytes: usize| bytes as f64 * 1_000_000.0 / POINTER_COUNT as f64 / (1024.0 * 1024.0);
+
+    println!("Pointers: {} ({} per value)", POINTER_COUNT, POINTERS_PER_VALUE);
+    println!(
+        "Pointer map, per million pointers: before {:.1} MiB, after {:.1} MiB",
+        per_million(tree_map_bytes),
+        per_million(flat_map_bytes)
+    );
+    println!(
+        "Level at depth {}, per million pointers: before {:.1} MiB, after {:.1} MiB",
+        LEVEL_DEPTH,
+        per_million(owned_offsets_level_bytes),
+        per_million(flat_level_bytes)
+    );
+}
+
+/// Measures the bytes still allocated by the value that the given function builds.
+fn measure_bytes<T>(build: impl FnOnce() -> T) -> usize {
+    let allocated_bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let value = build();
+    let allocated_bytes = ALLOCATED_BYTES
+        .load(Ordering::Relaxed)
+        .saturating_sub(allocated_bytes_before);
+
+    drop(value);
+
+    allocated_bytes
+}
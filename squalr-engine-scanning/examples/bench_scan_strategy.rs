@@ -0,0 +1,142 @@
+// Compares rescanning sparse results with the planned and sparse scan strategies, on a skewed workload where roughly 0.2% of
+// values match. Uses synthetic snapshot regions, so no target process is required.
+// Run: cargo run --release -p squalr-engine-scanning --example bench_scan_strategy
+
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint::ScanConstraint;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::scanning::statistics::scan_strategy::ScanStrategy;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use squalr_engine_scanning::scanners::element_scan_dispatcher::ElementScanDispatcher;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const REGION_COUNT: u64 = 64;
+const REGION_SIZE: u64 = 1024 * 1024;
+// One in every ~512 values matches.
+const MATCH_PERIOD: u64 = 512;
+const ITERATION_COUNT: u32 = 20;
+const SEARCHED_VALUE: i32 = 1337;
+
+/// Creates a region of i32 values where matches are scattered pseudo-randomly, such that the results are isolated elements.
+fn create_snapshot_region(region_index: u64) -> SnapshotRegion {
+    let element_count = REGION_SIZE / 4;
+    let mut random_state = 0x9E37_79B9_7F4A_7C15u64 ^ region_index;
+    let bytes = (0..element_count)
+        .flat_map(|_| {
+            random_state = random_state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            let value = if (random_state >> 33).is_multiple_of(MATCH_PERIOD) {
+                SEARCHED_VALUE
+            } else {
+                0
+            };
+
+            value.to_le_bytes()
+        })
+        .collect::<Vec<u8>>();
+    let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(region_index * REGION_SIZE * 2, REGION_SIZE), vec![]);
+
+    snapshot_region.current_values = bytes.clone();
+    snapshot_region.previous_values = bytes;
+    snapshot_region
+}
+
+fn time_rescans(
+    snapshot_regions: &[SnapshotRegion],
+    snapshot_region_filter_collections: &[SnapshotRegionFilterCollection],
+    element_scan_plan: &ElementScanPlan,
+    scan_strategy: ScanStrategy,
+) -> (Duration, u64) {
+    let mut result_count = 0;
+    let start = Instant::now();
+
+    for _ in 0..ITERATION_COUNT {
+        result_count = snapshot_regions
+            .iter()
+            .zip(snapshot_region_filter_collections)
+            .map(|(snapshot_region, snapshot_region_filter_collection)| {
+                ElementScanDispatcher::dispatch_scan_with_strategy(snapshot_region, snapshot_region_filter_collection, element_scan_plan, scan_strategy)
+                    .get_number_of_results()
+            })
+            .sum();
+    }
+
+    (start.elapsed(), std::hint::black_box(result_count))
+}
+
+fn main() {
+    let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+    let scan_constraint = ScanConstraint::new(
+        ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+        DataValue::new(data_type_ref.clone(), SEARCHED_VALUE.to_le_bytes().to_vec()),
+        FloatingPointTolerance::default(),
+    );
+    let element_scan_plan = ElementScanPlan::new(
+        HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(scan_constraint)])]),
+        MemoryAlignment::Alignment4,
+        FloatingPointTolerance::default(),
+        MemoryReadMode::Skip,
+        false,
+        false,
+    );
+    let snapshot_regions = (0..REGION_COUNT)
+        .map(create_snapshot_region)
+        .collect::<Vec<_>>();
+
+    // The first scan over each region produces the sparse results, along with the statistics that select the next strategy.
+    let first_scan_collections = snapshot_regions
+        .iter()
+        .map(|snapshot_region| {
+            let initial_collection = SnapshotRegionFilterCollection::new(
+                vec![vec![SnapshotRegionFilter::new(
+                    snapshot_region.get_base_address(),
+                    REGION_SIZE,
+                )]],
+                data_type_ref.clone(),
+                MemoryAlignment::Alignment4,
+                4,
+            );
+
+            ElementScanDispatcher::dispatch_scan(snapshot_region, &initial_collection, &element_scan_plan)
+        })
+        .collect::<Vec<_>>();
+    let selected_strategy = ScanStrategy::select(first_scan_collections[0].get_scan_filter_statistics());
+    let first_scan_result_count: u64 = first_scan_collections
+        .iter()
+        .map(|collection| collection.get_number_of_results())
+        .sum();
+
+    println!(
+        "{} regions of {} KiB, {} results after the first scan, selected strategy: {}",
+        REGION_COUNT,
+        REGION_SIZE / 1024,
+        first_scan_result_count,
+        selected_strategy.get_name()
+    );
+
+    let (planned_elapsed, planned_result_count) = time_rescans(&snapshot_regions, &first_scan_collections, &element_scan_plan, ScanStrategy::Planned);
+    let (sparse_elapsed, sparse_result_count) = time_rescans(&snapshot_regions, &first_scan_collections, &element_scan_plan, ScanStrategy::Sparse);
+
+    assert_eq!(planned_result_count, sparse_result_count);
+
+    println!("Planned: {:?} per rescan", planned_elapsed / ITERATION_COUNT);
+    println!("Sparse:  {:?} per rescan", sparse_elapsed / ITERATION_COUNT);
+    println!(
+        "Speedup: {:.2}x",
+        planned_elapsed.as_secs_f64() / sparse_elapsed.as_secs_f64().max(f64::EPSILON)
+    );
+}
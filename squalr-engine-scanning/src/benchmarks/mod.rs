@@ -0,0 +1,2 @@
+pub mod scan_configuration_benchmark_task;
+pub mod synthetic_snapshot;
@@ -0,0 +1,133 @@
+use crate::benchmarks::synthetic_snapshot::SyntheticSnapshot;
+use crate::scan_settings_config::ScanSettingsConfig;
+use crate::scanners::element_scan_executor_task::ElementScanExecutorTask;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scanning::benchmarks::scan_configuration_benchmark::ScanConfigurationBenchmark;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::settings::scan_vector_width::ScanVectorWidth;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Instant;
+
+const TASK_NAME: &str = "Scan Benchmark";
+
+/// The seed of the synthetic snapshot, fixed such that every configuration scans the same bytes.
+const SNAPSHOT_SEED: u64 = 0x5155_414C;
+
+pub struct ScanConfigurationBenchmarkTask;
+
+/// Measures how quickly scans run with each combination of thread count and vector width, such that the fastest can be chosen
+/// for this machine. Scans run over a synthetic snapshot, so no process needs to be opened.
+impl ScanConfigurationBenchmarkTask {
+    /// Starts the benchmark on its own thread. The given function creates the plan of the scan to measure, which is then limited
+    /// to each configuration in turn. Results are passed to the finished callback in the order they were measured.
+    pub fn start_task(
+        snapshot_size_in_bytes: u64,
+        create_element_scan_plan: impl Fn() -> ElementScanPlan + Send + 'static,
+        on_finished: impl FnOnce(&TrackableTask, Vec<ScanConfigurationBenchmark>, bool) + Send + 'static,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            ElementScanExecutorTask::apply_thread_priority(ScanSettingsConfig::get_thread_priority());
+
+            let cancellation_token = task_clone.get_cancellation_token();
+            let configurations = Self::get_configurations(Self::get_logical_core_count());
+            let mut scan_configuration_benchmarks = Vec::with_capacity(configurations.len());
+            let mut was_cancelled = false;
+
+            for (configuration_index, (scan_thread_count, vector_width)) in configurations.iter().enumerate() {
+                if cancellation_token.load(Ordering::Acquire) {
+                    was_cancelled = true;
+                    break;
+                }
+
+                let element_scan_plan = create_element_scan_plan()
+                    .with_scan_thread_count(*scan_thread_count)
+                    .with_vector_width(*vector_width);
+
+                let elements_per_second = Self::measure_elements_per_second(&task_clone, snapshot_size_in_bytes, element_scan_plan);
+
+                // A scan cut short by cancellation would report a misleading speed, so it is left out of the results.
+                if cancellation_token.load(Ordering::Acquire) {
+                    was_cancelled = true;
+                    break;
+                }
+
+                scan_configuration_benchmarks.push(ScanConfigurationBenchmark {
+                    scan_thread_count: *scan_thread_count,
+                    vector_width: *vector_width,
+                    elements_per_second,
+                });
+
+                task_clone.set_progress((configuration_index + 1) as f32 / configurations.len() as f32 * 100.0);
+            }
+
+            task_clone.complete();
+            on_finished(&task_clone, scan_configuration_benchmarks, was_cancelled);
+        });
+
+        task
+    }
+
+    /// Gets every configuration to measure, ie one thread, half of the logical cores, and all of them, each with every vector width.
+    pub fn get_configurations(logical_core_count: u32) -> Vec<(u32, ScanVectorWidth)> {
+        let mut scan_thread_counts = vec![1, (logical_core_count / 2).max(1), logical_core_count.max(1)];
+
+        scan_thread_counts.dedup();
+
+        scan_thread_counts
+            .into_iter()
+            .flat_map(|scan_thread_count| {
+                ScanVectorWidth::ALL
+                    .into_iter()
+                    .map(move |vector_width| (scan_thread_count, vector_width))
+            })
+            .collect()
+    }
+
+    fn get_logical_core_count() -> u32 {
+        thread::available_parallelism()
+            .map(|logical_core_count| logical_core_count.get() as u32)
+            .unwrap_or(1)
+    }
+
+    /// Scans a fresh snapshot with the given plan, timing only the scan itself rather than the creation of the snapshot. The scan
+    /// runs as part of the benchmark task, such that cancelling the benchmark also stops the scan in progress.
+    fn measure_elements_per_second(
+        trackable_task: &Arc<TrackableTask>,
+        snapshot_size_in_bytes: u64,
+        element_scan_plan: ElementScanPlan,
+    ) -> f64 {
+        let element_count = snapshot_size_in_bytes as f64 / element_scan_plan.get_memory_alignment() as u64 as f64;
+        let process_info = OpenedProcessInfo::new(0, "benchmark".to_string(), 0, Bitness::Bit64, None);
+        let snapshot = Arc::new(RwLock::new(SyntheticSnapshot::create(snapshot_size_in_bytes, SNAPSHOT_SEED)));
+        let start_time = Instant::now();
+
+        ElementScanExecutorTask::scan_task(trackable_task, process_info, snapshot, element_scan_plan, false);
+
+        element_count / start_time.elapsed().as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanConfigurationBenchmarkTask;
+    use squalr_engine_api::structures::settings::scan_vector_width::ScanVectorWidth;
+
+    #[test]
+    fn thread_counts_are_measured_once_with_every_vector_width() {
+        let configurations = ScanConfigurationBenchmarkTask::get_configurations(2);
+
+        assert_eq!(configurations.len(), 2 * ScanVectorWidth::ALL.len());
+        assert!(
+            configurations
+                .iter()
+                .all(|(scan_thread_count, _vector_width)| *scan_thread_count == 1 || *scan_thread_count == 2)
+        );
+    }
+}
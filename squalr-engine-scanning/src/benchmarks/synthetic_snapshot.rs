@@ -0,0 +1,129 @@
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+
+/// Builds snapshots filled with values resembling game memory, entirely in-process, such that scans can be measured without
+/// attaching to a process. The same seed and size always produce the same bytes, so result counts are stable across runs.
+pub struct SyntheticSnapshot {}
+
+impl SyntheticSnapshot {
+    /// Text planted throughout the snapshot for string scans to find.
+    pub const MARKER_STRING: &'static str = "SqualrBench";
+
+    /// Bytes planted throughout the snapshot for array of byte scans to find.
+    pub const MARKER_BYTES: [u8; 8] = [0xDE, 0xAD, 0xBE, 0xEF, 0x13, 0x37, 0xC0, 0xDE];
+
+    const BASE_ADDRESS: u64 = 0x1000_0000;
+    const REGION_SIZE: u64 = 1024 * 1024;
+
+    /// Regions are spaced apart, such that they are not contiguous, as is the case for most pages of a real process.
+    const REGION_STRIDE: u64 = Self::REGION_SIZE * 2;
+    const MARKER_INTERVAL: usize = 64 * 1024;
+
+    /// Creates a snapshot of the given size. Previous values hold the first sample, and current values hold a second sample
+    /// in which roughly one in a hundred 4 byte elements has changed.
+    pub fn create(
+        size_in_bytes: u64,
+        seed: u64,
+    ) -> Snapshot {
+        let mut random = SplitMix64::new(seed);
+        let mut snapshot_regions = Vec::new();
+        let mut remaining_size = size_in_bytes - size_in_bytes % 4;
+        let mut base_address = Self::BASE_ADDRESS;
+
+        while remaining_size > 0 {
+            let region_size = remaining_size.min(Self::REGION_SIZE);
+            let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(base_address, region_size), vec![]);
+
+            snapshot_region.previous_values = Self::create_values(&mut random, region_size as usize);
+            snapshot_region.current_values = Self::create_changed_values(&mut random, &snapshot_region.previous_values);
+
+            Self::plant_markers(&mut snapshot_region.previous_values);
+            Self::plant_markers(&mut snapshot_region.current_values);
+
+            snapshot_regions.push(snapshot_region);
+            remaining_size -= region_size;
+            base_address += Self::REGION_STRIDE;
+        }
+
+        let mut snapshot = Snapshot::new();
+
+        snapshot.set_snapshot_regions(snapshot_regions);
+        snapshot
+    }
+
+    /// Fills values as 4 byte elements, most of which are zero, with many small counters and some noise.
+    fn create_values(
+        random: &mut SplitMix64,
+        size_in_bytes: usize,
+    ) -> Vec<u8> {
+        (0..size_in_bytes / 4)
+            .flat_map(|_| {
+                let random_value = random.next();
+                let element = match random_value % 100 {
+                    0..60 => 0,
+                    60..90 => ((random_value >> 32) % 1000) as u32,
+                    _ => (random_value >> 32) as u32,
+                };
+
+                element.to_le_bytes()
+            })
+            .collect()
+    }
+
+    fn create_changed_values(
+        random: &mut SplitMix64,
+        previous_values: &[u8],
+    ) -> Vec<u8> {
+        let mut current_values = previous_values.to_vec();
+
+        for element in current_values.chunks_exact_mut(4) {
+            if random.next().is_multiple_of(100) {
+                let value = u32::from_le_bytes([element[0], element[1], element[2], element[3]]);
+
+                element.copy_from_slice(&value.wrapping_add(1).to_le_bytes());
+            }
+        }
+
+        current_values
+    }
+
+    /// Plants the marker string and bytes at fixed intervals, identically in both samples.
+    fn plant_markers(values: &mut [u8]) {
+        let marker_string = Self::MARKER_STRING.as_bytes();
+
+        for offset in (0..values.len()).step_by(Self::MARKER_INTERVAL) {
+            let marker_bytes_offset = offset + Self::MARKER_INTERVAL / 2;
+
+            if let Some(window) = values.get_mut(offset..offset + marker_string.len()) {
+                window.copy_from_slice(marker_string);
+            }
+
+            if let Some(window) = values.get_mut(marker_bytes_offset..marker_bytes_offset + Self::MARKER_BYTES.len()) {
+                window.copy_from_slice(&Self::MARKER_BYTES);
+            }
+        }
+    }
+}
+
+/// A small, seedable generator, such that benchmark snapshots do not depend on an external source of randomness.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut value = self.state;
+
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        value ^ (value >> 31)
+    }
+}
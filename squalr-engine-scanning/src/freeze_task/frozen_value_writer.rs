@@ -0,0 +1,47 @@
+use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::RwLock;
+
+pub struct FrozenValueWriter;
+
+/// Writes values requested by the user to the opened process. Writing to a frozen address changes the value it is frozen to, rather than
+/// being a one-shot write that the next freeze pass would undo.
+impl FrozenValueWriter {
+    /// Gets the pointer that an absolute address is frozen under, which is relative to its module if the address belongs to one.
+    pub fn get_pointer(
+        address: u64,
+        modules: &Vec<NormalizedModule>,
+    ) -> Pointer {
+        match MemoryQueryer::get_instance().address_to_module(address, modules) {
+            Some((module_name, module_offset)) => Pointer::new(module_offset, vec![], module_name),
+            None => Pointer::new(address, vec![], String::default()),
+        }
+    }
+
+    /// Writes bytes to the absolute address of the given pointer, recording the write in the write ledger.
+    pub fn write_bytes(
+        process_info: &OpenedProcessInfo,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+        pointer: Pointer,
+        address: u64,
+        value_bytes: &[u8],
+    ) -> bool {
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                return false;
+            }
+        };
+
+        freeze_list_registry_guard.write_address(pointer, value_bytes, |value_bytes| {
+            MemoryWriter::write_bytes_with_ledger(process_info, address, value_bytes)
+        })
+    }
+}
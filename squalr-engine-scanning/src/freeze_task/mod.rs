@@ -1 +1,2 @@
+pub mod frozen_value_writer;
 pub mod snapshot_scan_result_freeze_task;
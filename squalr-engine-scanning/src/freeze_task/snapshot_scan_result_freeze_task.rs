@@ -1,11 +1,23 @@
 use crate::scan_settings_config::ScanSettingsConfig;
+use squalr_engine_api::events::value_watch::triggered::value_watch_triggered_event::ValueWatchTriggeredEvent;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_watch_list::value_watch_list_registry::ValueWatchListRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_api::structures::memory::value_watch_condition::ValueWatchCondition;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::scan_constraint::ScanConstraint;
 use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
 use squalr_engine_memory::memory_writer::MemoryWriter;
-use squalr_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::sync::atomic::Ordering;
@@ -16,11 +28,14 @@ const TASK_NAME: &'static str = "Scan Result Freezer";
 
 pub struct SnapshotScanResultFreezeTask;
 
-/// Implementation of a task that freezes all scan results selected by the user.
+/// Implementation of a task that freezes all scan results selected by the user. Each tick also compares the values the user is
+/// watching, after freezing such that a frozen value is seen as it will remain.
 impl SnapshotScanResultFreezeTask {
     pub fn start_task(
         process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
         freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
+        value_watch_list_registry: Arc<RwLock<ValueWatchListRegistry>>,
+        on_value_watch_triggered: impl Fn(ValueWatchTriggeredEvent) + Send + 'static,
     ) -> Arc<TrackableTask> {
         let task = TrackableTask::create(TASK_NAME.to_string(), None);
         let task_clone = task.clone();
@@ -31,6 +46,7 @@ impl SnapshotScanResultFreezeTask {
                     break;
                 }
                 Self::collect_values_task(&process_info, &freeze_list_registry);
+                Self::compare_watched_values_task(&process_info, &value_watch_list_registry, &on_value_watch_triggered);
 
                 thread::sleep(Duration::from_millis(ScanSettingsConfig::get_results_read_interval_ms()));
             }
@@ -54,9 +70,10 @@ impl SnapshotScanResultFreezeTask {
             }
         };
 
+        // A read-only attach leaves freezing to the Squalr instance holding the attach lock.
         let process_info = match process_info_lock.as_ref() {
-            Some(process_info) => process_info,
-            None => return,
+            Some(process_info) if !process_info.get_is_read_only() => process_info,
+            _ => return,
         };
 
         let freeze_list_registry_guard = match freeze_list_registry.write() {
@@ -68,13 +85,387 @@ impl SnapshotScanResultFreezeTask {
             }
         };
 
-        let modules = MemoryQueryer::get_instance().get_modules(&process_info);
+        let modules = ProcessQueryCache::get_instance().get_modules(process_info);
+
+        Self::run_freeze_pass(&freeze_list_registry_guard, |pointer, value_bytes| {
+            let module_address = MemoryQueryer::get_instance().resolve_module(&modules, pointer.get_module_name());
+            let _success = MemoryWriter::write_bytes_with_ledger(process_info, module_address.saturating_add(pointer.get_address()), value_bytes);
+        });
+    }
+
+    /// Writes each frozen value with the given writer. Callers must hold the freeze list lock for the whole pass, such that a request
+    /// changing the freeze list is applied either entirely before the pass or entirely after it, and never has its value overwritten.
+    pub fn run_freeze_pass(
+        freeze_list_registry: &FreezeListRegistry,
+        mut write_bytes: impl FnMut(&Pointer, &[u8]),
+    ) {
+        for (pointer, frozen_value) in freeze_list_registry.get_frozen_pointers() {
+            // Unreadable addresses are skipped until a refresh reads them again, rather than failing to write every pass.
+            if freeze_list_registry.is_address_unreadable(pointer) {
+                continue;
+            }
 
-        for pointer in freeze_list_registry_guard.get_frozen_pointers().keys() {
-            if let Some(value_bytes) = freeze_list_registry_guard.get_address_frozen_bytes(pointer) {
-                let module_address = MemoryQueryer::get_instance().resolve_module(&modules, pointer.get_module_name());
-                let _success = MemoryWriter::get_instance().write_bytes(process_info, module_address.saturating_add(pointer.get_address()), value_bytes);
+            // Disabled project entries keep their frozen value, but are not written until they are enabled again.
+            if freeze_list_registry.is_address_disabled(pointer) {
+                continue;
             }
+
+            write_bytes(pointer, frozen_value.get_value_bytes());
         }
     }
+
+    fn compare_watched_values_task(
+        process_info: &Arc<RwLock<Option<OpenedProcessInfo>>>,
+        value_watch_list_registry: &Arc<RwLock<ValueWatchListRegistry>>,
+        on_value_watch_triggered: &impl Fn(ValueWatchTriggeredEvent),
+    ) {
+        let process_info_lock = match process_info.read() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on process info for value watches: {}", error);
+
+                return;
+            }
+        };
+
+        let process_info = match process_info_lock.as_ref() {
+            Some(process_info) => process_info,
+            None => return,
+        };
+
+        let mut value_watch_list_registry_guard = match value_watch_list_registry.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueWatchListRegistry: {}", error);
+
+                return;
+            }
+        };
+
+        let value_watches = value_watch_list_registry_guard.get_value_watches();
+
+        if value_watches.is_empty() {
+            return;
+        }
+
+        let symbol_registry = SymbolRegistry::get_instance();
+        let modules = ProcessQueryCache::get_instance().get_modules(process_info);
+        let mut triggered_events = vec![];
+
+        for value_watch in value_watches {
+            // Disabled project entries are not compared, and lose their baseline such that changes made while disabled are not reported.
+            if value_watch_list_registry_guard.is_address_disabled(value_watch.address, &value_watch.module_name) {
+                value_watch_list_registry_guard.update_value_bytes(value_watch.watch_id, None);
+                continue;
+            }
+
+            let module_address = MemoryQueryer::get_instance().resolve_module(&modules, &value_watch.module_name);
+            let mut value_bytes = vec![0u8; symbol_registry.get_unit_size_in_bytes(&value_watch.data_type_ref) as usize];
+            let current_value_bytes = (!value_bytes.is_empty()
+                && MemoryReader::get_instance().read_bytes(process_info, module_address.saturating_add(value_watch.address), &mut value_bytes))
+            .then_some(value_bytes);
+
+            let Some(previous_value_bytes) = value_watch_list_registry_guard.update_value_bytes(value_watch.watch_id, current_value_bytes.clone()) else {
+                continue;
+            };
+            let Some(current_value_bytes) = current_value_bytes else {
+                continue;
+            };
+
+            if is_value_watch_triggered(&value_watch.condition, &value_watch.data_type_ref, &previous_value_bytes, &current_value_bytes) {
+                triggered_events.push(ValueWatchTriggeredEvent {
+                    watch_id: value_watch.watch_id,
+                    label: value_watch.label,
+                    address: value_watch.address,
+                    module_name: value_watch.module_name,
+                    previous_value: DataValue::new(value_watch.data_type_ref.clone(), previous_value_bytes),
+                    current_value: DataValue::new(value_watch.data_type_ref, current_value_bytes),
+                });
+            }
+        }
+
+        // Events are emitted outside of the lock, such that listeners may add or remove watches in response.
+        drop(value_watch_list_registry_guard);
+
+        for triggered_event in triggered_events {
+            on_value_watch_triggered(triggered_event);
+        }
+    }
+}
+
+/// Determines whether a watched value changing from the previous bytes to the current bytes meets the condition of its watch.
+/// Both byte arrays must hold one value of the data type.
+pub fn is_value_watch_triggered(
+    condition: &ValueWatchCondition,
+    data_type_ref: &DataTypeRef,
+    previous_value_bytes: &[u8],
+    current_value_bytes: &[u8],
+) -> bool {
+    let (scan_compare_type_immediate, condition_value) = match condition {
+        ValueWatchCondition::AnyChange => return previous_value_bytes != current_value_bytes,
+        ValueWatchCondition::CrossesThreshold { threshold } => (ScanCompareTypeImmediate::GreaterThanOrEqual, threshold),
+        ValueWatchCondition::Equals { value } => (ScanCompareTypeImmediate::Equal, value),
+    };
+    let symbol_registry = SymbolRegistry::get_instance();
+    let Ok(condition_data_value) = symbol_registry.deanonymize_value_string(data_type_ref, condition_value) else {
+        return false;
+    };
+    let scan_constraint = ScanConstraint::new(
+        ScanCompareType::Immediate(scan_compare_type_immediate),
+        condition_data_value,
+        ScanSettingsConfig::get_floating_point_tolerance(),
+    );
+    let Some(compare_func) = symbol_registry.get_scalar_compare_func_immediate(&scan_compare_type_immediate, &scan_constraint) else {
+        return false;
+    };
+    let previous_matches = compare_func(previous_value_bytes.as_ptr());
+    let current_matches = compare_func(current_value_bytes.as_ptr());
+
+    match condition {
+        ValueWatchCondition::CrossesThreshold { .. } => previous_matches != current_matches,
+        _ => current_matches && !previous_matches,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnapshotScanResultFreezeTask, is_value_watch_triggered};
+    use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::memory::pointer::Pointer;
+    use squalr_engine_api::structures::memory::value_watch_condition::ValueWatchCondition;
+    use std::collections::{HashMap, HashSet};
+    use std::sync::mpsc;
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::thread;
+    use std::time::Duration;
+
+    /// Process memory keyed by the frozen pointer that addresses it, holding a single value per pointer.
+    type FakeMemory = Mutex<HashMap<Pointer, Vec<u8>>>;
+
+    fn run_freeze_pass(
+        memory: &FakeMemory,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+    ) {
+        let freeze_list_registry_guard = freeze_list_registry.read().unwrap();
+
+        SnapshotScanResultFreezeTask::run_freeze_pass(&freeze_list_registry_guard, |pointer, value_bytes| {
+            memory
+                .lock()
+                .unwrap()
+                .insert(pointer.clone(), value_bytes.to_vec());
+        });
+    }
+
+    fn write_value(
+        memory: &FakeMemory,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+        pointer: &Pointer,
+        value: u32,
+    ) {
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .write_address(pointer.clone(), &value.to_le_bytes(), |value_bytes| {
+                memory
+                    .lock()
+                    .unwrap()
+                    .insert(pointer.clone(), value_bytes.to_vec());
+                true
+            });
+    }
+
+    fn read_value(
+        memory: &FakeMemory,
+        pointer: &Pointer,
+    ) -> u32 {
+        u32::from_le_bytes(memory.lock().unwrap()[pointer].as_slice().try_into().unwrap())
+    }
+
+    #[test]
+    fn freezing_a_frozen_address_again_replaces_its_value() {
+        let memory = FakeMemory::default();
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let pointer = Pointer::new(0x10, vec![], "game.exe".to_string());
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+        run_freeze_pass(&memory, &freeze_list_registry);
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 200u32.to_le_bytes().to_vec());
+
+        // The game changing the value in between passes does not matter, as the newest frozen value is what gets enforced.
+        memory
+            .lock()
+            .unwrap()
+            .insert(pointer.clone(), 5u32.to_le_bytes().to_vec());
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 200);
+    }
+
+    #[test]
+    fn writes_and_unfreezes_are_never_undone_by_later_passes() {
+        let memory = FakeMemory::default();
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let pointer = Pointer::new(0x20, vec![], String::new());
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        // Changing the value of a frozen address changes the value it stays frozen to.
+        write_value(&memory, &freeze_list_registry, &pointer, 250);
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 250);
+        assert!(freeze_list_registry.read().unwrap().is_address_frozen(&pointer));
+
+        // Unfreezing and then writing leaves the written value in place.
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_unfrozen(&pointer);
+        write_value(&memory, &freeze_list_registry, &pointer, 7);
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 7);
+    }
+
+    #[test]
+    fn writes_requested_during_a_pass_are_applied_after_it() {
+        let memory = Arc::new(FakeMemory::default());
+        let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let pointer = Pointer::new(0x30, vec![], String::new());
+        let (pass_started_sender, pass_started_receiver) = mpsc::channel();
+        let (finish_pass_sender, finish_pass_receiver) = mpsc::channel::<()>();
+        let (write_finished_sender, write_finished_receiver) = mpsc::channel();
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+
+        // Hold a freeze pass open midway through writing, until the test allows it to finish.
+        let freeze_pass_thread = {
+            let memory = memory.clone();
+            let freeze_list_registry = freeze_list_registry.clone();
+
+            thread::spawn(move || {
+                let freeze_list_registry_guard = freeze_list_registry.read().unwrap();
+
+                SnapshotScanResultFreezeTask::run_freeze_pass(&freeze_list_registry_guard, |pointer, value_bytes| {
+                    pass_started_sender.send(()).unwrap();
+                    finish_pass_receiver.recv().unwrap();
+                    memory
+                        .lock()
+                        .unwrap()
+                        .insert(pointer.clone(), value_bytes.to_vec());
+                });
+            })
+        };
+
+        pass_started_receiver.recv().unwrap();
+
+        let write_thread = {
+            let memory = memory.clone();
+            let freeze_list_registry = freeze_list_registry.clone();
+            let pointer = pointer.clone();
+
+            thread::spawn(move || {
+                write_value(&memory, &freeze_list_registry, &pointer, 250);
+                write_finished_sender.send(()).unwrap();
+            })
+        };
+
+        // The write waits for the pass, rather than landing in the middle of it and then being overwritten by the old value.
+        assert!(
+            write_finished_receiver
+                .recv_timeout(Duration::from_millis(50))
+                .is_err()
+        );
+
+        finish_pass_sender.send(()).unwrap();
+        freeze_pass_thread.join().unwrap();
+        write_thread.join().unwrap();
+
+        assert_eq!(read_value(&memory, &pointer), 250);
+
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 250);
+    }
+
+    #[test]
+    fn disabled_addresses_keep_their_frozen_value_until_enabled() {
+        let memory = FakeMemory::default();
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let pointer = Pointer::new(0x40, vec![], "game.exe".to_string());
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_disabled_pointers(HashSet::from([pointer.clone()]));
+        memory
+            .lock()
+            .unwrap()
+            .insert(pointer.clone(), 5u32.to_le_bytes().to_vec());
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 5);
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_disabled_pointers(HashSet::new());
+        run_freeze_pass(&memory, &freeze_list_registry);
+
+        assert_eq!(read_value(&memory, &pointer), 100);
+    }
+
+    fn decimal(value: &str) -> AnonymousValueString {
+        AnonymousValueString::new(value.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None)
+    }
+
+    #[test]
+    fn thresholds_trigger_when_crossed_in_either_direction() {
+        let data_type_ref = DataTypeRef::new("i32");
+        let condition = ValueWatchCondition::CrossesThreshold { threshold: decimal("100") };
+        let is_triggered = |previous: i32, current: i32| is_value_watch_triggered(&condition, &data_type_ref, &previous.to_le_bytes(), &current.to_le_bytes());
+
+        assert!(is_triggered(99, 100));
+        assert!(is_triggered(150, -5));
+        assert!(!is_triggered(100, 150));
+        assert!(!is_triggered(10, 20));
+    }
+
+    #[test]
+    fn equals_triggers_only_when_the_value_is_reached() {
+        let data_type_ref = DataTypeRef::new("i32");
+        let condition = ValueWatchCondition::Equals { value: decimal("0") };
+        let is_triggered = |previous: i32, current: i32| is_value_watch_triggered(&condition, &data_type_ref, &previous.to_le_bytes(), &current.to_le_bytes());
+
+        assert!(is_triggered(5, 0));
+        assert!(!is_triggered(0, 5));
+        assert!(!is_triggered(5, 4));
+        assert!(is_value_watch_triggered(
+            &ValueWatchCondition::AnyChange,
+            &data_type_ref,
+            &[5, 0, 0, 0],
+            &[4, 0, 0, 0]
+        ));
+    }
 }
@@ -1,7 +1,11 @@
 #![feature(get_mut_unchecked)]
 #![feature(portable_simd)]
 
+pub mod benchmarks;
 pub mod freeze_task;
 pub mod pointer_scans;
+pub mod scan_results;
 pub mod scan_settings_config;
 pub mod scanners;
+pub mod snapshot_monitor;
+pub mod write_sequences;
@@ -1,2 +1,3 @@
+pub mod pointer_rescan_executor_task;
 pub mod pointer_scan_executor_task;
 pub mod structures;
@@ -0,0 +1,205 @@
+use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_results::PointerScanResults;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+pub struct PointerRescanExecutorTask {}
+
+const TASK_NAME: &str = "Pointer Rescan Executor";
+const PROGRESS_INTERVAL_RESULTS: usize = 4096;
+
+/// Implementation of a task that filters existing pointer scan results against a new target address, such that paths found before
+/// restarting the target process can be validated without scanning again.
+impl PointerRescanExecutorTask {
+    pub fn start_task(
+        process_info: OpenedProcessInfo,
+        pointer_scan_results: PointerScanResults,
+        target_address: u64,
+        pointer_data_type_ref: DataTypeRef,
+        results_sink: Arc<RwLock<PointerScanResults>>,
+        with_logging: bool,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            Self::rescan_task(
+                &task_clone,
+                process_info,
+                pointer_scan_results,
+                target_address,
+                pointer_data_type_ref,
+                results_sink,
+                with_logging,
+            );
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn rescan_task(
+        trackable_task: &Arc<TrackableTask>,
+        process_info: OpenedProcessInfo,
+        mut pointer_scan_results: PointerScanResults,
+        target_address: u64,
+        pointer_data_type_ref: DataTypeRef,
+        results_sink: Arc<RwLock<PointerScanResults>>,
+        with_logging: bool,
+    ) {
+        if with_logging {
+            log::info!(
+                "Rescanning {} pointer path(s) against 0x{:X}...",
+                pointer_scan_results.get_result_count(),
+                target_address
+            );
+        }
+
+        // A 32-bit target can only hold 4-byte pointers, regardless of the requested pointer data type.
+        let pointer_size = if MemoryQueryer::get_address_space_bounds(&process_info).get_pointer_size() == 4
+            || pointer_data_type_ref.get_data_type_id() == DataTypeU32::get_data_type_id()
+        {
+            4usize
+        } else {
+            8usize
+        };
+        let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+        let kept_results = Self::filter_pointer_paths(
+            trackable_task,
+            pointer_scan_results.get_results(),
+            target_address,
+            |module_name| match MemoryQueryer::get_instance().resolve_module(&modules, module_name) {
+                0 => None,
+                module_address => Some(module_address),
+            },
+            |pointer_address| {
+                let mut pointer_bytes = [0u8; 8];
+
+                if !MemoryReader::get_instance().read_bytes(&process_info, pointer_address, &mut pointer_bytes[..pointer_size]) {
+                    return None;
+                }
+
+                Some(u64::from_le_bytes(pointer_bytes))
+            },
+        );
+
+        // A cancelled rescan keeps the results it started with, rather than the paths it happened to validate before stopping.
+        if trackable_task.get_cancellation_token().load(Ordering::SeqCst) {
+            return;
+        }
+
+        if with_logging {
+            log::info!(
+                "Pointer rescan kept {} of {} pointer path(s).",
+                kept_results.len(),
+                pointer_scan_results.get_result_count()
+            );
+        }
+
+        pointer_scan_results.set_results(kept_results);
+
+        if let Ok(mut sink) = results_sink.write() {
+            *sink = pointer_scan_results;
+        }
+    }
+
+    /// Resolves each pointer path again and keeps the paths that lead to the target address. Module based paths are rebased onto the
+    /// current address of their module, and are dropped if that module is no longer loaded. Paths that pass through memory that can
+    /// no longer be read are dropped as well.
+    fn filter_pointer_paths(
+        trackable_task: &TrackableTask,
+        pointer_scan_results: &[PointerScanResult],
+        target_address: u64,
+        resolve_module_address: impl Fn(&str) -> Option<u64>,
+        mut read_pointer: impl FnMut(u64) -> Option<u64>,
+    ) -> Vec<PointerScanResult> {
+        let mut kept_results = Vec::new();
+
+        for (result_index, pointer_scan_result) in pointer_scan_results.iter().enumerate() {
+            if result_index % PROGRESS_INTERVAL_RESULTS == 0 {
+                if trackable_task.get_cancellation_token().load(Ordering::SeqCst) {
+                    break;
+                }
+
+                trackable_task.set_progress(result_index as f32 / pointer_scan_results.len() as f32 * 100.0);
+            }
+
+            let base_address = if pointer_scan_result.is_module() {
+                match resolve_module_address(pointer_scan_result.get_module_name()) {
+                    Some(module_address) => module_address.wrapping_add(pointer_scan_result.get_module_offset()),
+                    None => continue,
+                }
+            } else {
+                pointer_scan_result.get_base_address()
+            };
+
+            if pointer_scan_result.resolve(base_address, &mut read_pointer) == Some(target_address) {
+                kept_results.push(PointerScanResult::new(
+                    base_address,
+                    pointer_scan_result.get_module_name().to_string(),
+                    pointer_scan_result.get_module_offset(),
+                    pointer_scan_result.get_offsets().clone(),
+                    pointer_scan_result.is_module(),
+                ));
+            }
+        }
+
+        kept_results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerRescanExecutorTask;
+    use squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+    use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+    use std::collections::HashMap;
+
+    #[test]
+    fn rescans_keep_paths_that_still_reach_the_new_target() {
+        const MODULE_ADDRESS: u64 = 0x40_0000;
+        const NEW_TARGET_ADDRESS: u64 = 0x9020;
+
+        // After a restart, the module moved to a new address, and its pointer leads to a newly allocated object.
+        let memory = HashMap::from([
+            (MODULE_ADDRESS + 0x100, 0x8000u64),
+            (0x8010, 0x9000),
+            (MODULE_ADDRESS + 0x200, 0x8800),
+            (0x5000, 0x9000),
+        ]);
+        let pointer_scan_results = vec![
+            PointerScanResult::new(0x1_0100, "game.exe".to_string(), 0x100, vec![0x10, 0x20], true),
+            // Leads to a different address now.
+            PointerScanResult::new(0x1_0100, "game.exe".to_string(), 0x100, vec![0x10, 0x28], true),
+            // Passes through an unmapped page, as the first pointer no longer leads to a readable object.
+            PointerScanResult::new(0x1_0200, "game.exe".to_string(), 0x200, vec![0x10, 0x20], true),
+            // Belongs to a module that is no longer loaded.
+            PointerScanResult::new(0x2_0100, "plugin.dll".to_string(), 0x100, vec![0x10, 0x20], true),
+            PointerScanResult::new(0x5000, String::new(), 0x5000, vec![0x20], false),
+        ];
+        let trackable_task = TrackableTask::create("Test".to_string(), None);
+        let kept_results = PointerRescanExecutorTask::filter_pointer_paths(
+            &trackable_task,
+            &pointer_scan_results,
+            NEW_TARGET_ADDRESS,
+            |module_name| (module_name == "game.exe").then_some(MODULE_ADDRESS),
+            |pointer_address| memory.get(&pointer_address).copied(),
+        );
+
+        assert_eq!(kept_results.len(), 2);
+        assert_eq!(kept_results[0].get_base_address(), MODULE_ADDRESS + 0x100);
+        assert_eq!(kept_results[0].get_module_offset(), 0x100);
+        assert_eq!(kept_results[1].get_base_address(), 0x5000);
+    }
+}
@@ -1,13 +1,20 @@
+use crate::pointer_scans::structures::pointer_map::PointerMap;
+use crate::pointer_scans::structures::pointer_offset_bounds::PointerOffsetBounds;
+use crate::pointer_scans::structures::pointer_scan_level::{PointerScanLevel, PointerScanNode};
 use crate::scanners::value_collector_task::ValueCollectorTask;
 use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use squalr_engine_api::structures::memory::address_space_bounds::AddressSpaceBounds;
+use squalr_engine_api::structures::memory::region_interval_set::RegionIntervalSet;
 use squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_results::PointerScanResults;
 use squalr_engine_api::structures::scanning::plans::pointer_scan::pointer_scan_parameters::PointerScanParameters;
+use squalr_engine_api::structures::scanning::plans::pointer_scan::pointer_validity_filters::PointerValidityFilters;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use std::collections::{BTreeMap, HashSet};
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
 use std::sync::{Arc, RwLock};
 use std::thread;
 
@@ -15,6 +22,7 @@ pub struct PointerScanExecutorTask {}
 
 const TASK_NAME: &'static str = "Pointer Scan Executor";
 const MAX_RESULTS: usize = 250_000;
+const PROGRESS_INTERVAL_NODES: usize = 4096;
 
 impl PointerScanExecutorTask {
     pub fn start_task(
@@ -22,7 +30,7 @@ impl PointerScanExecutorTask {
         statics_snapshot: Arc<RwLock<Snapshot>>,
         heaps_snapshot: Arc<RwLock<Snapshot>>,
         pointer_scan_parameters: PointerScanParameters,
-        results_sink: Arc<RwLock<Vec<PointerScanResult>>>,
+        results_sink: Arc<RwLock<PointerScanResults>>,
         with_logging: bool,
     ) -> Arc<TrackableTask> {
         let task = TrackableTask::create(TASK_NAME.to_string(), None);
@@ -51,7 +59,7 @@ impl PointerScanExecutorTask {
         statics_snapshot: Arc<RwLock<Snapshot>>,
         heaps_snapshot: Arc<RwLock<Snapshot>>,
         pointer_scan_parameters: PointerScanParameters,
-        results_sink: Arc<RwLock<Vec<PointerScanResult>>>,
+        results_sink: Arc<RwLock<PointerScanResults>>,
         with_logging: bool,
     ) {
         if with_logging {
@@ -61,7 +69,11 @@ impl PointerScanExecutorTask {
         ValueCollectorTask::start_task(process_info.clone(), statics_snapshot.clone(), with_logging).wait_for_completion();
         ValueCollectorTask::start_task(process_info.clone(), heaps_snapshot.clone(), with_logging).wait_for_completion();
 
-        let pointer_size = if pointer_scan_parameters.get_pointer_data_type_ref().get_data_type_id() == DataTypeU32::get_data_type_id() {
+        // A 32-bit target can only hold 4-byte pointers, regardless of the requested pointer data type.
+        let address_space_bounds = MemoryQueryer::get_address_space_bounds(&process_info);
+        let pointer_size = if address_space_bounds.get_pointer_size() == 4
+            || pointer_scan_parameters.get_pointer_data_type_ref().get_data_type_id() == DataTypeU32::get_data_type_id()
+        {
             4usize
         } else {
             8usize
@@ -77,153 +89,458 @@ impl PointerScanExecutorTask {
             _ => 0,
         };
 
-        let max_offset = pointer_scan_parameters.get_offset_size();
+        let pointer_offset_bounds = PointerOffsetBounds::new(
+            pointer_scan_parameters.get_min_offset(),
+            pointer_scan_parameters.get_max_offset(),
+            pointer_scan_parameters.get_max_offsets_per_level(),
+        );
         let max_depth = pointer_scan_parameters.get_max_depth().max(1);
-
-        let mut pointer_map: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
-        let min_user_addr = 0u64;
-        let max_user_addr = MemoryQueryer::get_instance().get_max_usermode_address(&process_info);
+        let max_memory_bytes = pointer_scan_parameters.get_max_memory_bytes();
+
+        let pointer_candidate_filter = PointerCandidateFilter {
+            pointer_size,
+            pointer_alignment: pointer_scan_parameters
+                .get_pointer_validity_filters()
+                .resolve_pointer_alignment(pointer_size as u64),
+            pointer_validity_filters: *pointer_scan_parameters.get_pointer_validity_filters(),
+            address_space_bounds,
+            mapped_regions: build_mapped_regions(&[&statics_snapshot, &heaps_snapshot]),
+        };
+        let mut pointer_map = PointerMap::new();
+        let mut is_pointer_map_complete = true;
 
         if pointer_scan_parameters.get_scan_statics() {
-            collect_pointer_values(&statics_snapshot, pointer_size, min_user_addr, max_user_addr, &mut pointer_map);
+            is_pointer_map_complete &= collect_pointer_values(&statics_snapshot, &pointer_candidate_filter, &mut pointer_map, max_memory_bytes);
         }
 
-        if pointer_scan_parameters.get_scan_heaps() {
-            collect_pointer_values(&heaps_snapshot, pointer_size, min_user_addr, max_user_addr, &mut pointer_map);
+        if pointer_scan_parameters.get_scan_heaps() && is_pointer_map_complete {
+            is_pointer_map_complete &= collect_pointer_values(&heaps_snapshot, &pointer_candidate_filter, &mut pointer_map, max_memory_bytes);
         }
 
-        let modules = MemoryQueryer::get_instance().get_modules(&process_info);
-        let mut results: Vec<PointerScanResult> = Vec::new();
-        let mut visited: HashSet<(u64, usize)> = HashSet::new();
+        pointer_map.finalize();
+        trackable_task.set_memory_usage_bytes(pointer_map.get_size_in_bytes());
+
+        if with_logging {
+            log::info!(
+                "Pointer map built with {} candidate pointer(s), using {} byte(s).",
+                pointer_map.len(),
+                pointer_map.get_size_in_bytes()
+            );
+        }
 
-        let mut frontier: Vec<(u64, Vec<u64>)> = vec![(target_address, Vec::new())];
+        let pointer_path_search = if is_pointer_map_complete {
+            let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+
+            Self::search_pointer_paths(
+                trackable_task,
+                &pointer_map,
+                target_address,
+                pointer_offset_bounds,
+                max_depth,
+                max_memory_bytes,
+                |pointer_address| MemoryQueryer::get_instance().address_to_module(pointer_address, &modules),
+            )
+        } else {
+            // Paths found through a partial map would silently miss pointers, so no search is attempted.
+            PointerPathSearch {
+                results: Vec::new(),
+                memory_capped_depth: Some(0),
+            }
+        };
+
+        if with_logging && let Some(memory_capped_depth) = pointer_path_search.memory_capped_depth {
+            log::warn!(
+                "Pointer scan reached its memory cap of {} byte(s) at depth {}, keeping the results found so far.",
+                max_memory_bytes,
+                memory_capped_depth
+            );
+        }
+
+        if let Ok(mut sink) = results_sink.write() {
+            sink.set_results(pointer_path_search.results);
+            sink.set_memory_capped_depth(pointer_path_search.memory_capped_depth);
+        }
+    }
+
+    /// Searches the pointer map for paths to the target address, one level of depth at a time. Each level holds the pointers
+    /// reaching the previous one within the offset bounds, and a result is recorded for every pointer found. Once the pointer map and levels would use more
+    /// than the memory cap, the search stops deepening and keeps the results found so far.
+    fn search_pointer_paths(
+        trackable_task: &TrackableTask,
+        pointer_map: &PointerMap,
+        target_address: u64,
+        pointer_offset_bounds: PointerOffsetBounds,
+        max_depth: u64,
+        max_memory_bytes: u64,
+        address_to_module: impl Fn(u64) -> Option<(String, u64)>,
+    ) -> PointerPathSearch {
+        let mut levels = vec![PointerScanLevel::new_root(target_address)];
+        let mut levels_size_in_bytes = levels[0].get_size_in_bytes();
+        let mut results: Vec<PointerScanResult> = Vec::new();
+        let mut memory_capped_depth = None;
+        let mut offsets = Vec::new();
 
-        for depth in 0..max_depth {
+        'deepen: for depth in 0..max_depth {
             if trackable_task.get_cancellation_token().load(std::sync::atomic::Ordering::SeqCst) {
                 break;
             }
 
-            let mut next_frontier = Vec::new();
-
-            for (target, offsets) in frontier.iter() {
-                let start = target.saturating_sub(max_offset);
-                let end = target.saturating_add(max_offset);
-
-                for (value, pointer_addresses) in pointer_map.range(start..=end) {
-                    let offset = target.saturating_sub(*value);
-                    for pointer_address in pointer_addresses {
-                        let mut new_offsets = offsets.clone();
-                        new_offsets.insert(0, offset);
-
-                        if results.len() < MAX_RESULTS {
-                            let mut module_name = String::new();
-                            let mut module_offset = *pointer_address;
-                            let mut is_module = false;
-
-                            if let Some((found_module_name, offset_addr)) =
-                                MemoryQueryer::get_instance().address_to_module(*pointer_address, &modules)
-                            {
-                                module_name = found_module_name;
-                                module_offset = offset_addr;
-                                is_module = true;
-                            }
-
-                            results.push(PointerScanResult::new(
-                                *pointer_address,
-                                module_name,
-                                module_offset,
-                                new_offsets.clone(),
-                                is_module,
-                            ));
-                        }
-
-                        if results.len() >= MAX_RESULTS {
-                            break;
-                        }
-
-                        let key = (*pointer_address, depth as usize + 1);
-                        if visited.insert(key) {
-                            next_frontier.push((*pointer_address, new_offsets));
-                        }
-                    }
+            let level_index = depth as usize;
+            let frontier = &levels[level_index];
+            let mut next_level = PointerScanLevel::new();
 
+            for (node_index, node) in frontier.get_nodes().iter().enumerate() {
+                for pointer_map_entry in pointer_offset_bounds.get_pointers_to(pointer_map, node.pointer_address) {
                     if results.len() >= MAX_RESULTS {
-                        break;
+                        break 'deepen;
                     }
+
+                    // Negative offsets are kept in two's complement, such that resolving the path wraps back below the value.
+                    let offset = node.pointer_address.wrapping_sub(pointer_map_entry.value);
+
+                    offsets.clear();
+                    offsets.push(offset);
+                    collect_offsets(&levels, level_index, node_index, &mut offsets);
+
+                    let (module_name, module_offset, is_module) = match address_to_module(pointer_map_entry.pointer_address) {
+                        Some((module_name, module_offset)) => (module_name, module_offset, true),
+                        None => (String::new(), pointer_map_entry.pointer_address, false),
+                    };
+
+                    results.push(PointerScanResult::new(
+                        pointer_map_entry.pointer_address,
+                        module_name,
+                        module_offset,
+                        offsets.clone(),
+                        is_module,
+                    ));
+
+                    next_level.push(PointerScanNode {
+                        pointer_address: pointer_map_entry.pointer_address,
+                        offset,
+                        parent_index: node_index as u32,
+                    });
+                }
+
+                let memory_usage_bytes = pointer_map.get_size_in_bytes() + levels_size_in_bytes + next_level.get_size_in_bytes();
+
+                if memory_usage_bytes > max_memory_bytes {
+                    trackable_task.set_memory_usage_bytes(memory_usage_bytes);
+                    memory_capped_depth = Some(depth + 1);
+                    break 'deepen;
                 }
 
-                if results.len() >= MAX_RESULTS {
-                    break;
+                // To reduce performance impact, only periodically send progress updates.
+                if node_index % PROGRESS_INTERVAL_NODES == PROGRESS_INTERVAL_NODES - 1 {
+                    let level_progress = (node_index + 1) as f32 / frontier.len() as f32;
+
+                    trackable_task.set_memory_usage_bytes(memory_usage_bytes);
+                    trackable_task.set_progress((depth as f32 + level_progress) / max_depth as f32 * 100.0);
                 }
             }
 
-            let progress = ((depth + 1) as f32 / max_depth as f32) * 100.0;
-            trackable_task.set_progress(progress);
+            next_level.finalize();
 
-            if next_frontier.is_empty() {
+            if next_level.is_empty() {
                 break;
             }
 
-            frontier = next_frontier;
+            levels_size_in_bytes += next_level.get_size_in_bytes();
+            levels.push(next_level);
+
+            trackable_task.set_memory_usage_bytes(pointer_map.get_size_in_bytes() + levels_size_in_bytes);
+            trackable_task.set_progress((depth + 1) as f32 / max_depth as f32 * 100.0);
         }
 
-        if let Ok(mut sink) = results_sink.write() {
-            *sink = results;
+        PointerPathSearch { results, memory_capped_depth }
+    }
+}
+
+/// The results of searching the pointer map, along with the depth the search stopped at if it reached the memory cap.
+struct PointerPathSearch {
+    results: Vec<PointerScanResult>,
+    memory_capped_depth: Option<u64>,
+}
+
+/// Appends the offsets leading from a node to the target address, walking from the node on the given level back to the root.
+fn collect_offsets(
+    levels: &[PointerScanLevel],
+    level_index: usize,
+    node_index: usize,
+    offsets: &mut Vec<u64>,
+) {
+    let mut node_index = node_index;
+
+    // The root level only holds the target address, which has no offset of its own.
+    for level in levels[1..=level_index].iter().rev() {
+        let node = &level.get_nodes()[node_index];
+
+        offsets.push(node.offset);
+        node_index = node.parent_index as usize;
+    }
+}
+
+/// Decides which values read from memory are kept as pointer candidates when building the pointer map.
+struct PointerCandidateFilter {
+    pointer_size: usize,
+    pointer_alignment: u64,
+    pointer_validity_filters: PointerValidityFilters,
+    address_space_bounds: AddressSpaceBounds,
+    mapped_regions: RegionIntervalSet,
+}
+
+/// Collects the address ranges of every snapshot, such that pointer values can be checked against mapped memory.
+fn build_mapped_regions(snapshots: &[&Arc<RwLock<Snapshot>>]) -> RegionIntervalSet {
+    let mut ranges = Vec::new();
+
+    for snapshot in snapshots {
+        match snapshot.read() {
+            Ok(snapshot) => ranges.extend(
+                snapshot
+                    .get_snapshot_regions()
+                    .iter()
+                    .map(|region| (region.get_base_address(), region.get_end_address())),
+            ),
+            Err(error) => log::error!("Failed to acquire snapshot read lock: {}", error),
         }
     }
+
+    RegionIntervalSet::from_ranges(ranges)
 }
 
 fn collect_pointer_values(
     snapshot: &Arc<RwLock<Snapshot>>,
-    pointer_size: usize,
-    min_addr: u64,
-    max_addr: u64,
-    pointer_map: &mut BTreeMap<u64, Vec<u64>>,
-) {
+    pointer_candidate_filter: &PointerCandidateFilter,
+    pointer_map: &mut PointerMap,
+    max_memory_bytes: u64,
+) -> bool {
     let snapshot = match snapshot.read() {
         Ok(snapshot) => snapshot,
         Err(error) => {
             log::error!("Failed to acquire snapshot read lock: {}", error);
-            return;
+            return true;
         }
     };
 
-    for region in snapshot.get_snapshot_regions() {
-        let base_address = region.get_base_address();
-        let bytes = region.get_current_values();
-        if bytes.len() < pointer_size {
-            continue;
-        }
+    snapshot.get_snapshot_regions().iter().all(|region| {
+        collect_region_pointer_values(
+            region.get_base_address(),
+            region.get_current_values(),
+            pointer_candidate_filter,
+            pointer_map,
+            max_memory_bytes,
+        )
+    })
+}
+
+/// Adds every pointer candidate stored in the given bytes to the pointer map. Only storage addresses on the pointer alignment are
+/// visited, and values failing the validity filters are skipped. Returns false if the pointer map grew past the memory cap, in
+/// which case the remaining bytes are not visited.
+fn collect_region_pointer_values(
+    base_address: u64,
+    bytes: &[u8],
+    pointer_candidate_filter: &PointerCandidateFilter,
+    pointer_map: &mut PointerMap,
+    max_memory_bytes: u64,
+) -> bool {
+    let pointer_size = pointer_candidate_filter.pointer_size;
+    let pointer_alignment = pointer_candidate_filter.pointer_alignment;
+
+    if bytes.len() < pointer_size {
+        return true;
+    }
 
-        let mut offset = 0usize;
-        while offset + pointer_size <= bytes.len() {
-            let value = if pointer_size == 4 {
-                let raw = u32::from_le_bytes([
-                    bytes[offset],
-                    bytes[offset + 1],
-                    bytes[offset + 2],
-                    bytes[offset + 3],
-                ]) as u64;
-                raw
-            } else {
-                u64::from_le_bytes([
-                    bytes[offset],
-                    bytes[offset + 1],
-                    bytes[offset + 2],
-                    bytes[offset + 3],
-                    bytes[offset + 4],
-                    bytes[offset + 5],
-                    bytes[offset + 6],
-                    bytes[offset + 7],
-                ])
-            };
-
-            if value >= min_addr && value <= max_addr {
-                let pointer_address = base_address.saturating_add(offset as u64);
-                pointer_map.entry(value).or_insert_with(Vec::new).push(pointer_address);
+    let misalignment = base_address % pointer_alignment;
+    let mut offset = if misalignment == 0 { 0 } else { (pointer_alignment - misalignment) as usize };
+
+    while offset + pointer_size <= bytes.len() {
+        let value = if pointer_size == 4 {
+            u32::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]) as u64
+        } else {
+            u64::from_le_bytes([
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+                bytes[offset + 4],
+                bytes[offset + 5],
+                bytes[offset + 6],
+                bytes[offset + 7],
+            ])
+        };
+
+        if pointer_candidate_filter
+            .address_space_bounds
+            .is_valid_pointer(value)
+            && pointer_candidate_filter
+                .pointer_validity_filters
+                .is_valid_target(value, pointer_alignment, &pointer_candidate_filter.mapped_regions)
+        {
+            let pointer_address = base_address.saturating_add(offset as u64);
+            pointer_map.insert(value, pointer_address);
+
+            if pointer_map.get_size_in_bytes() > max_memory_bytes {
+                return false;
             }
+        }
+
+        offset += pointer_alignment as usize;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PointerCandidateFilter, PointerScanExecutorTask, collect_region_pointer_values};
+    use crate::pointer_scans::structures::pointer_map::PointerMap;
+    use crate::pointer_scans::structures::pointer_offset_bounds::PointerOffsetBounds;
+    use squalr_engine_api::structures::memory::address_space_bounds::AddressSpaceBounds;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::memory::region_interval_set::RegionIntervalSet;
+    use squalr_engine_api::structures::scanning::plans::pointer_scan::pointer_validity_filters::PointerValidityFilters;
+    use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+
+    const BASE_ADDRESS: u64 = 0x10000;
+    const REGION_SIZE: u64 = 0x1000;
+
+    /// Builds a synthetic region holding, in order, a pointer into the region, an unaligned pointer into the region, a pointer
+    /// into unmapped memory, and then the same pointer stored at an offset that is only 4-aligned.
+    fn build_address_space() -> Vec<u8> {
+        let mut bytes = vec![0u8; REGION_SIZE as usize];
+        let mut write = |offset: usize, value: u64| bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+
+        write(0x00, BASE_ADDRESS + 0x100);
+        write(0x08, BASE_ADDRESS + 0x103);
+        write(0x10, 0x7000_0000);
+        write(0x1C, BASE_ADDRESS + 0x200);
+
+        bytes
+    }
+
+    fn build_pointer_candidate_filter(
+        pointer_alignment: Option<u64>,
+        require_mapped_targets: bool,
+        require_aligned_targets: bool,
+    ) -> PointerCandidateFilter {
+        let pointer_validity_filters = PointerValidityFilters::new(pointer_alignment, require_mapped_targets, require_aligned_targets);
+
+        PointerCandidateFilter {
+            pointer_size: 8,
+            pointer_alignment: pointer_validity_filters.resolve_pointer_alignment(8),
+            pointer_validity_filters,
+            address_space_bounds: AddressSpaceBounds::for_bitness(Bitness::Bit64, 0x10000, 0x7FFF_FFFE_FFFF),
+            mapped_regions: RegionIntervalSet::from_ranges([(BASE_ADDRESS, BASE_ADDRESS + REGION_SIZE)]),
+        }
+    }
+
+    fn count_candidates(
+        pointer_alignment: Option<u64>,
+        require_mapped_targets: bool,
+        require_aligned_targets: bool,
+    ) -> usize {
+        let pointer_candidate_filter = build_pointer_candidate_filter(pointer_alignment, require_mapped_targets, require_aligned_targets);
+        let mut pointer_map = PointerMap::new();
+
+        collect_region_pointer_values(BASE_ADDRESS, &build_address_space(), &pointer_candidate_filter, &mut pointer_map, u64::MAX);
+
+        pointer_map.len()
+    }
+
+    /// Builds a pointer map over a ring of pointers, where each slot points to the next one and the last slot points back to the
+    /// first. Starting from the first slot, every depth of the search finds exactly one more pointer.
+    fn build_pointer_ring(slot_count: u64) -> PointerMap {
+        let mut pointer_map = PointerMap::new();
+
+        for slot_index in 0..slot_count {
+            let next_slot_index = (slot_index + 1) % slot_count;
 
-            offset += pointer_size;
+            pointer_map.insert(BASE_ADDRESS + next_slot_index * 8, BASE_ADDRESS + slot_index * 8);
         }
+
+        pointer_map.finalize();
+        pointer_map
+    }
+
+    #[test]
+    fn each_filter_reduces_the_candidate_count() {
+        let unfiltered_count = count_candidates(Some(4), false, false);
+        let aligned_storage_count = count_candidates(None, false, false);
+        let mapped_target_count = count_candidates(None, true, false);
+        let aligned_target_count = count_candidates(None, true, true);
+
+        assert_eq!(unfiltered_count, 4);
+        assert_eq!(aligned_storage_count, 3);
+        assert_eq!(mapped_target_count, 2);
+        assert_eq!(aligned_target_count, 1);
+    }
+
+    #[test]
+    fn pointer_map_stops_growing_at_the_memory_cap() {
+        let pointer_candidate_filter = build_pointer_candidate_filter(Some(4), false, false);
+        let mut pointer_map = PointerMap::new();
+
+        assert!(!collect_region_pointer_values(
+            BASE_ADDRESS,
+            &build_address_space(),
+            &pointer_candidate_filter,
+            &mut pointer_map,
+            0
+        ));
+        assert_eq!(pointer_map.len(), 1);
+    }
+
+    #[test]
+    fn search_without_a_memory_cap_reaches_the_max_depth() {
+        let pointer_map = build_pointer_ring(64);
+        let trackable_task = TrackableTask::create("Test".to_string(), None);
+        let pointer_path_search = PointerScanExecutorTask::search_pointer_paths(
+            &trackable_task,
+            &pointer_map,
+            BASE_ADDRESS,
+            PointerOffsetBounds::new(0, 0, None),
+            64,
+            u64::MAX,
+            |_pointer_address| None,
+        );
+
+        assert_eq!(pointer_path_search.memory_capped_depth, None);
+        assert_eq!(pointer_path_search.results.len(), 64);
+        assert_eq!(pointer_path_search.results[63].get_offsets().len(), 64);
+    }
+
+    #[test]
+    fn search_finalizes_early_once_the_memory_cap_is_reached() {
+        let pointer_map = build_pointer_ring(64);
+        let trackable_task = TrackableTask::create("Test".to_string(), None);
+        let max_memory_bytes = pointer_map.get_size_in_bytes() + 1024;
+        let pointer_path_search = PointerScanExecutorTask::search_pointer_paths(
+            &trackable_task,
+            &pointer_map,
+            BASE_ADDRESS,
+            PointerOffsetBounds::new(0, 0, None),
+            64,
+            max_memory_bytes,
+            |_pointer_address| None,
+        );
+        let memory_capped_depth = pointer_path_search
+            .memory_capped_depth
+            .expect("the search should stop at the memory cap");
+
+        assert!(memory_capped_depth > 1 && memory_capped_depth < 64);
+        assert!(pointer_path_search.results.len() as u64 >= memory_capped_depth - 1);
+        assert!(pointer_path_search.results.len() < 64);
+        assert!(
+            pointer_path_search
+                .results
+                .iter()
+                .all(|result| result.get_offsets().len() as u64 <= memory_capped_depth)
+        );
+        assert!(trackable_task.get_memory_usage_bytes().unwrap_or(0) > max_memory_bytes);
     }
 }
@@ -1 +1,3 @@
+pub mod pointer_map;
+pub mod pointer_offset_bounds;
 pub mod pointer_scan_level;
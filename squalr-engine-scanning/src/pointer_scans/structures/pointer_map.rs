@@ -0,0 +1,109 @@
+use std::mem::size_of;
+
+/// A pointer found in memory, being the address storing it and the value it points to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PointerMapEntry {
+    pub value: u64,
+    pub pointer_address: u64,
+}
+
+/// Maps pointer values to the addresses storing them. Entries are kept in one flat array sorted by value, rather than as a tree of
+/// per-value lists, such that each pointer costs 16 bytes with no per-entry allocation.
+#[derive(Default)]
+pub struct PointerMap {
+    entries: Vec<PointerMapEntry>,
+}
+
+impl PointerMap {
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Adds a pointer to the map. Entries are only searchable once the map is finalized.
+    pub fn insert(
+        &mut self,
+        value: u64,
+        pointer_address: u64,
+    ) {
+        self.entries.push(PointerMapEntry { value, pointer_address });
+    }
+
+    /// Sorts the entries such that they can be searched, releasing any capacity left over from building the map.
+    pub fn finalize(&mut self) {
+        self.entries
+            .sort_unstable_by_key(|entry| (entry.value, entry.pointer_address));
+        self.entries.shrink_to_fit();
+    }
+
+    /// Gets every entry whose value falls within the given inclusive range, ordered by value. The map must be finalized first.
+    pub fn range(
+        &self,
+        start_value: u64,
+        end_value: u64,
+    ) -> &[PointerMapEntry] {
+        let start_index = self.entries.partition_point(|entry| entry.value < start_value);
+        let end_index = self.entries.partition_point(|entry| entry.value <= end_value);
+
+        &self.entries[start_index..end_index.max(start_index)]
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the memory held by the entries of this map, including any capacity not yet used.
+    pub fn get_size_in_bytes(&self) -> u64 {
+        (self.entries.capacity() * size_of::<PointerMapEntry>()) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PointerMap, PointerMapEntry};
+
+    #[test]
+    fn range_finds_every_pointer_with_a_value_in_bounds() {
+        let mut pointer_map = PointerMap::new();
+
+        for (value, pointer_address) in [
+            (0x300, 0x10),
+            (0x100, 0x20),
+            (0x200, 0x30),
+            (0x100, 0x08),
+            (0x400, 0x40),
+        ] {
+            pointer_map.insert(value, pointer_address);
+        }
+
+        pointer_map.finalize();
+
+        assert_eq!(
+            pointer_map.range(0x100, 0x300),
+            &[
+                PointerMapEntry {
+                    value: 0x100,
+                    pointer_address: 0x08
+                },
+                PointerMapEntry {
+                    value: 0x100,
+                    pointer_address: 0x20
+                },
+                PointerMapEntry {
+                    value: 0x200,
+                    pointer_address: 0x30
+                },
+                PointerMapEntry {
+                    value: 0x300,
+                    pointer_address: 0x10
+                },
+            ]
+        );
+        assert!(pointer_map.range(0x101, 0x1FF).is_empty());
+        assert!(pointer_map.range(0x500, u64::MAX).is_empty());
+        assert_eq!(pointer_map.get_size_in_bytes(), 5 * 16);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::pointer_scans::structures::pointer_map::{PointerMap, PointerMapEntry};
+
+/// Limits which pointers are followed from an address during a pointer scan. A pointer is followed if its value plus an offset
+/// within the bounds reaches the address, where a negative offset reaches an address below the pointer value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PointerOffsetBounds {
+    min_offset: i64,
+    max_offset: i64,
+    max_offsets_per_level: Option<u64>,
+}
+
+impl PointerOffsetBounds {
+    pub fn new(
+        min_offset: i64,
+        max_offset: i64,
+        max_offsets_per_level: Option<u64>,
+    ) -> Self {
+        Self {
+            min_offset,
+            max_offset,
+            max_offsets_per_level,
+        }
+    }
+
+    /// Gets every pointer that reaches the given address through an offset within the bounds, ordered by value. If more distinct
+    /// offsets are found than are allowed per level, only those closest to zero are kept, preferring positive offsets on a tie.
+    pub fn get_pointers_to<'a>(
+        &self,
+        pointer_map: &'a PointerMap,
+        address: u64,
+    ) -> &'a [PointerMapEntry] {
+        // The value range is computed in a wider type, such that offsets reaching past either end of the address space clamp to it.
+        let start_value = (address as i128 - self.max_offset as i128).clamp(0, u64::MAX as i128) as u64;
+        let end_value = (address as i128 - self.min_offset as i128).clamp(0, u64::MAX as i128) as u64;
+
+        if start_value > end_value || self.min_offset > self.max_offset {
+            return &[];
+        }
+
+        let entries = pointer_map.range(start_value, end_value);
+
+        match self.max_offsets_per_level {
+            Some(max_offsets_per_level) => Self::get_closest_offsets(entries, address, max_offsets_per_level),
+            None => entries,
+        }
+    }
+
+    /// Narrows entries sorted by value to those with the given number of distinct values closest to the address. The closest
+    /// values always form one contiguous run around the address, so the run is grown one distinct value at a time from either side.
+    fn get_closest_offsets(
+        entries: &[PointerMapEntry],
+        address: u64,
+        max_offsets_per_level: u64,
+    ) -> &[PointerMapEntry] {
+        let split_index = entries.partition_point(|entry| entry.value <= address);
+        let mut start_index = split_index;
+        let mut end_index = split_index;
+
+        for _ in 0..max_offsets_per_level {
+            let below_distance = start_index
+                .checked_sub(1)
+                .map(|index| address - entries[index].value);
+            let above_distance = entries.get(end_index).map(|entry| entry.value - address);
+
+            match (below_distance, above_distance) {
+                (Some(below_distance), Some(above_distance)) if above_distance < below_distance => {
+                    end_index = Self::skip_value_forward(entries, end_index);
+                }
+                (Some(_), _) => {
+                    start_index = Self::skip_value_backward(entries, start_index);
+                }
+                (None, Some(_)) => {
+                    end_index = Self::skip_value_forward(entries, end_index);
+                }
+                (None, None) => break,
+            }
+        }
+
+        &entries[start_index..end_index]
+    }
+
+    fn skip_value_forward(
+        entries: &[PointerMapEntry],
+        index: usize,
+    ) -> usize {
+        let value = entries[index].value;
+
+        index + entries[index..].partition_point(|entry| entry.value == value)
+    }
+
+    fn skip_value_backward(
+        entries: &[PointerMapEntry],
+        index: usize,
+    ) -> usize {
+        let value = entries[index - 1].value;
+
+        entries[..index].partition_point(|entry| entry.value < value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PointerOffsetBounds;
+    use crate::pointer_scans::structures::pointer_map::PointerMap;
+
+    const ADDRESS: u64 = 0x1000;
+
+    /// Builds a pointer map holding two pointers to each of a few values around the address.
+    fn build_pointer_map() -> PointerMap {
+        let mut pointer_map = PointerMap::new();
+
+        for (index, value) in [
+            ADDRESS - 0x20,
+            ADDRESS - 0x8,
+            ADDRESS,
+            ADDRESS + 0x10,
+            ADDRESS + 0x40,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            pointer_map.insert(value, 0x100 + index as u64 * 0x10);
+            pointer_map.insert(value, 0x108 + index as u64 * 0x10);
+        }
+
+        pointer_map.finalize();
+        pointer_map
+    }
+
+    fn get_offsets(
+        pointer_offset_bounds: PointerOffsetBounds,
+        pointer_map: &PointerMap,
+    ) -> Vec<i64> {
+        let mut offsets: Vec<i64> = pointer_offset_bounds
+            .get_pointers_to(pointer_map, ADDRESS)
+            .iter()
+            .map(|entry| ADDRESS.wrapping_sub(entry.value) as i64)
+            .collect();
+
+        offsets.dedup();
+        offsets
+    }
+
+    #[test]
+    fn negative_offsets_reach_pointers_above_the_address() {
+        let pointer_map = build_pointer_map();
+
+        assert_eq!(get_offsets(PointerOffsetBounds::new(0, 0x100, None), &pointer_map), vec![0x20, 0x8, 0]);
+        assert_eq!(
+            get_offsets(PointerOffsetBounds::new(-0x10, 0x100, None), &pointer_map),
+            vec![0x20, 0x8, 0, -0x10]
+        );
+        assert_eq!(get_offsets(PointerOffsetBounds::new(-0x40, -0x10, None), &pointer_map), vec![-0x10, -0x40]);
+        assert!(get_offsets(PointerOffsetBounds::new(0x10, -0x10, None), &pointer_map).is_empty());
+    }
+
+    #[test]
+    fn offsets_per_level_keep_the_offsets_closest_to_zero() {
+        let pointer_map = build_pointer_map();
+        let pointer_offset_bounds = PointerOffsetBounds::new(-0x100, 0x100, Some(3));
+
+        assert_eq!(get_offsets(pointer_offset_bounds, &pointer_map), vec![0x8, 0, -0x10]);
+        assert_eq!(
+            pointer_offset_bounds
+                .get_pointers_to(&pointer_map, ADDRESS)
+                .len(),
+            6
+        );
+        assert_eq!(get_offsets(PointerOffsetBounds::new(-0x100, 0x100, Some(0)), &pointer_map), Vec::<i64>::new());
+        assert_eq!(get_offsets(PointerOffsetBounds::new(-0x100, 0x100, Some(99)), &pointer_map).len(), 5);
+    }
+
+    #[test]
+    fn offsets_clamp_to_the_ends_of_the_address_space() {
+        let mut pointer_map = PointerMap::new();
+
+        pointer_map.insert(0, 0x100);
+        pointer_map.insert(u64::MAX, 0x108);
+        pointer_map.finalize();
+
+        let pointer_offset_bounds = PointerOffsetBounds::new(-0x100, 0x100, None);
+
+        assert_eq!(pointer_offset_bounds.get_pointers_to(&pointer_map, 0x10)[0].value, 0);
+        assert_eq!(pointer_offset_bounds.get_pointers_to(&pointer_map, u64::MAX - 0x10)[0].value, u64::MAX);
+        assert_eq!(
+            PointerOffsetBounds::new(i64::MIN, i64::MAX, None)
+                .get_pointers_to(&pointer_map, 0x10)
+                .len(),
+            1
+        );
+    }
+}
@@ -1,35 +1,67 @@
-use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use std::mem::size_of;
 
+/// A pointer found at some depth of a pointer scan. Rather than owning the offsets leading to the target, each node refers to the
+/// node it points into on the previous level, such that deep levels do not copy the offsets of every path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PointerScanNode {
+    pub pointer_address: u64,
+    /// The offset from the value of this pointer to the node it points into.
+    pub offset: u64,
+    /// The index of the node this pointer points into, on the previous level.
+    pub parent_index: u32,
+}
+
+/// The pointers found at one depth of a pointer scan, stored as a flat array.
+#[derive(Default)]
 pub struct PointerScanLevel {
-    static_regions: Vec<SnapshotRegionFilter>,
-    heap_regions: Vec<SnapshotRegionFilter>,
+    nodes: Vec<PointerScanNode>,
 }
 
 impl PointerScanLevel {
-    pub fn new(
-        static_regions: Vec<SnapshotRegionFilter>,
-        heap_regions: Vec<SnapshotRegionFilter>,
-    ) -> Self {
-        Self { static_regions, heap_regions }
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Creates the level that a pointer scan starts from, holding only the target address.
+    pub fn new_root(target_address: u64) -> Self {
+        Self {
+            nodes: vec![PointerScanNode {
+                pointer_address: target_address,
+                offset: 0,
+                parent_index: 0,
+            }],
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        node: PointerScanNode,
+    ) {
+        self.nodes.push(node);
+    }
+
+    pub fn get_nodes(&self) -> &[PointerScanNode] {
+        &self.nodes
     }
 
-    /// Takes ownership of the resulting static region filters from this pointer scan level. Note that once this is called, the regions are emptied from this struct.
-    pub fn take_static_regions(&mut self) -> Vec<SnapshotRegionFilter> {
-        std::mem::take(&mut self.static_regions)
+    pub fn len(&self) -> usize {
+        self.nodes.len()
     }
 
-    /// Gets the resulting static region filters from this pointer scan level.
-    pub fn get_static_regions(&mut self) -> Vec<SnapshotRegionFilter> {
-        std::mem::take(&mut self.static_regions)
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
     }
 
-    /// Takes ownership of the resulting static region filters from this pointer scan level. Note that once this is called, the regions are emptied from this struct.
-    pub fn take_heap_regions(&mut self) -> Vec<SnapshotRegionFilter> {
-        std::mem::take(&mut self.heap_regions)
+    /// Sorts the nodes by address, keeping only the first node found for each address, and releases any unused capacity. Must
+    /// be called before any nodes of the next level refer to this one.
+    pub fn finalize(&mut self) {
+        self.nodes.sort_by_key(|node| node.pointer_address);
+        self.nodes.dedup_by_key(|node| node.pointer_address);
+        self.nodes.shrink_to_fit();
     }
 
-    /// Gets the resulting static region filters from this pointer scan level.
-    pub fn get_heap_regions(&mut self) -> Vec<SnapshotRegionFilter> {
-        std::mem::take(&mut self.heap_regions)
+    /// Gets the memory held by the nodes of this level, including any capacity not yet used.
+    pub fn get_size_in_bytes(&self) -> u64 {
+        (self.nodes.capacity() * size_of::<PointerScanNode>()) as u64
     }
 }
@@ -0,0 +1,2 @@
+pub mod scan_result_memory_reader;
+pub mod scan_result_refresher;
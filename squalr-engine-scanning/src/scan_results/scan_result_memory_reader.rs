@@ -0,0 +1,53 @@
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::scan_results::scan_result_read_status::ScanResultReadStatus;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+
+/// Reads the values of scan results, abstracted such that read failures can be verified without a process.
+pub trait ScanResultMemoryReader {
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> ScanResultReadStatus;
+}
+
+/// Reads scan result values from the opened process.
+pub struct ProcessScanResultMemoryReader {
+    process_info: OpenedProcessInfo,
+}
+
+impl ProcessScanResultMemoryReader {
+    /// Values longer than this are reported as unreadable rather than probed byte by byte for the readable portion.
+    const MAX_PROBED_BYTES: usize = 64;
+
+    pub fn new(process_info: OpenedProcessInfo) -> Self {
+        Self { process_info }
+    }
+}
+
+impl ScanResultMemoryReader for ProcessScanResultMemoryReader {
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> ScanResultReadStatus {
+        let memory_reader = MemoryReader::get_instance();
+
+        if memory_reader.read_bytes(&self.process_info, address, values) {
+            return ScanResultReadStatus::Ok;
+        }
+
+        // Capture the OS error before probing, since the probing reads overwrite it.
+        let error = std::io::Error::last_os_error().to_string();
+        let read_byte_count = if values.len() <= Self::MAX_PROBED_BYTES {
+            (0..values.len())
+                .take_while(|offset| memory_reader.read_bytes(&self.process_info, address.saturating_add(*offset as u64), &mut [0u8; 1]))
+                .count()
+        } else {
+            0
+        };
+
+        ScanResultReadStatus::from_failed_read(read_byte_count as u64, error)
+    }
+}
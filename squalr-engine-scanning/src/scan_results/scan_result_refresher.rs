@@ -0,0 +1,237 @@
+use crate::scan_results::scan_result_memory_reader::ScanResultMemoryReader;
+use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use squalr_engine_api::structures::scan_results::scan_result_read_status::ScanResultReadStatus;
+use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use std::sync::{Arc, RwLock};
+
+pub struct ScanResultRefresher;
+
+/// Wraps snapshotted scan results with their current value, module, freeze state, and read status, as shown in the results list.
+impl ScanResultRefresher {
+    /// Builds the full scan result for a snapshotted result. Values are read with the given reader if a process is opened.
+    /// Frozen results that cannot be read are excluded from the freeze loop until a later refresh reads them successfully.
+    pub fn refresh_scan_result(
+        scan_result_valued: ScanResultValued,
+        memory_reader: Option<&dyn ScanResultMemoryReader>,
+        modules: &Vec<NormalizedModule>,
+        freeze_list_registry: &Arc<RwLock<FreezeListRegistry>>,
+    ) -> ScanResult {
+        let address = scan_result_valued.get_address();
+        let mut recently_read_value = None;
+        let mut read_status = ScanResultReadStatus::Ok;
+        let mut module_name = String::default();
+        let mut module_offset = address;
+
+        if let (Some(memory_reader), Some(mut data_value)) = (memory_reader, scan_result_valued.get_current_value().clone()) {
+            let mut value_bytes = vec![0u8; data_value.get_size_in_bytes() as usize];
+
+            read_status = memory_reader.read_bytes(address, &mut value_bytes);
+
+            if read_status.is_readable() {
+                data_value.copy_from_bytes(&value_bytes);
+                recently_read_value = Some(data_value);
+            }
+        }
+
+        // Check whether this scan result belongs to a module (ie check if the address is static).
+        if let Some((found_module_name, found_module_offset)) = MemoryQueryer::get_instance().address_to_module(address, modules) {
+            module_name = found_module_name;
+            module_offset = found_module_offset;
+        }
+
+        let pointer = Pointer::new(module_offset, vec![], module_name.clone());
+        let is_frozen = match freeze_list_registry.write() {
+            Ok(mut freeze_list_registry) => {
+                let is_frozen = freeze_list_registry.is_address_frozen(&pointer);
+
+                if is_frozen {
+                    freeze_list_registry.set_address_unreadable(&pointer, !read_status.is_readable());
+                }
+
+                is_frozen
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                false
+            }
+        };
+
+        let recently_read_display_values = recently_read_value
+            .as_ref()
+            .and_then(|data_value| {
+                SymbolRegistry::get_instance()
+                    .anonymize_value_to_supported_formats(data_value)
+                    .ok()
+            })
+            .unwrap_or_default();
+        let mut scan_result = ScanResult::new(
+            scan_result_valued,
+            module_name,
+            module_offset,
+            recently_read_value,
+            recently_read_display_values,
+            is_frozen,
+        );
+
+        scan_result.set_read_status(read_status);
+
+        scan_result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultRefresher;
+    use crate::scan_results::scan_result_memory_reader::ScanResultMemoryReader;
+    use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::memory::pointer::Pointer;
+    use squalr_engine_api::structures::scan_results::scan_result_read_status::ScanResultReadStatus;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+    use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+    use std::collections::HashMap;
+    use std::sync::{Arc, RwLock};
+
+    /// Reads a fixed value from every address, except for addresses configured to fail.
+    struct FakeMemoryReader {
+        value: u32,
+        failed_reads: HashMap<u64, ScanResultReadStatus>,
+    }
+
+    impl ScanResultMemoryReader for FakeMemoryReader {
+        fn read_bytes(
+            &self,
+            address: u64,
+            values: &mut [u8],
+        ) -> ScanResultReadStatus {
+            if let Some(read_status) = self.failed_reads.get(&address) {
+                return read_status.clone();
+            }
+
+            values.copy_from_slice(&self.value.to_le_bytes()[..values.len()]);
+
+            ScanResultReadStatus::Ok
+        }
+    }
+
+    fn create_scan_result_valued(
+        address: u64,
+        snapshotted_value: u32,
+    ) -> ScanResultValued {
+        ScanResultValued::new(
+            address,
+            DataTypeRef::new(DataTypeU32::get_data_type_id()),
+            String::new(),
+            Some(DataTypeU32::get_value_from_primitive(snapshotted_value)),
+            vec![],
+            None,
+            vec![],
+            ScanResultRef::new(address),
+        )
+    }
+
+    #[test]
+    fn failed_reads_carry_their_status_instead_of_the_snapshotted_value() {
+        let memory_reader = FakeMemoryReader {
+            value: 250,
+            failed_reads: HashMap::from([
+                (
+                    0x2000,
+                    ScanResultReadStatus::Unreadable {
+                        error: "Access is denied.".to_string(),
+                    },
+                ),
+                (0x3000, ScanResultReadStatus::from_failed_read(2, "Partial copy.".to_string())),
+            ]),
+        };
+        let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let scan_results: Vec<_> = [0x1000, 0x2000, 0x3000]
+            .into_iter()
+            .map(|address| {
+                ScanResultRefresher::refresh_scan_result(create_scan_result_valued(address, 100), Some(&memory_reader), &vec![], &freeze_list_registry)
+            })
+            .collect();
+
+        assert_eq!(scan_results[0].get_read_status(), &ScanResultReadStatus::Ok);
+        assert_eq!(scan_results[0].get_display_value_string(AnonymousValueStringFormat::Decimal), "250");
+
+        assert_eq!(
+            scan_results[1].get_read_status(),
+            &ScanResultReadStatus::Unreadable {
+                error: "Access is denied.".to_string()
+            }
+        );
+        assert!(scan_results[1].get_recently_read_value().is_none());
+        assert_eq!(
+            scan_results[1].get_display_value_string(AnonymousValueStringFormat::Decimal),
+            ScanResultReadStatus::UNREADABLE_MARKER
+        );
+
+        assert_eq!(
+            scan_results[2].get_read_status(),
+            &ScanResultReadStatus::PartiallyRead {
+                read_byte_count: 2,
+                error: "Partial copy.".to_string()
+            }
+        );
+        assert_eq!(
+            scan_results[2].get_display_value_string(AnonymousValueStringFormat::Decimal),
+            ScanResultReadStatus::UNREADABLE_MARKER
+        );
+    }
+
+    #[test]
+    fn frozen_results_are_excluded_from_freezing_until_a_later_refresh_reads_them() {
+        let address = 0x2000;
+        let pointer = Pointer::new(address, vec![], String::new());
+        let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let mut memory_reader = FakeMemoryReader {
+            value: 250,
+            failed_reads: HashMap::from([(
+                address,
+                ScanResultReadStatus::Unreadable {
+                    error: "Access is denied.".to_string(),
+                },
+            )]),
+        };
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+
+        let scan_result =
+            ScanResultRefresher::refresh_scan_result(create_scan_result_valued(address, 100), Some(&memory_reader), &vec![], &freeze_list_registry);
+
+        assert!(scan_result.get_is_frozen());
+        assert!(!scan_result.get_read_status().is_readable());
+        assert!(
+            freeze_list_registry
+                .read()
+                .unwrap()
+                .is_address_unreadable(&pointer)
+        );
+
+        memory_reader.failed_reads.clear();
+
+        let scan_result =
+            ScanResultRefresher::refresh_scan_result(create_scan_result_valued(address, 100), Some(&memory_reader), &vec![], &freeze_list_registry);
+
+        assert_eq!(scan_result.get_read_status(), &ScanResultReadStatus::Ok);
+        assert!(
+            !freeze_list_registry
+                .read()
+                .unwrap()
+                .is_address_unreadable(&pointer)
+        );
+    }
+}
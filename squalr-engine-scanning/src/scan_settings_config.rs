@@ -4,6 +4,8 @@ use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
 use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
 use squalr_engine_api::structures::settings::scan_settings::ScanSettings;
 use squalr_engine_api::structures::settings::scan_thread_priority::ScanThreadPriority;
+use squalr_engine_api::structures::settings::scan_vector_width::ScanVectorWidth;
+use squalr_engine_api::structures::settings::value_overflow_policy::ValueOverflowPolicy;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Once;
@@ -327,6 +329,22 @@ impl ScanSettingsConfig {
         Self::save_config();
     }
 
+    pub fn get_value_overflow_policy() -> ValueOverflowPolicy {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.value_overflow_policy
+        } else {
+            ScanSettings::default().value_overflow_policy
+        }
+    }
+
+    pub fn set_value_overflow_policy(value: ValueOverflowPolicy) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.value_overflow_policy = value;
+        }
+
+        Self::save_config();
+    }
+
     pub fn get_is_single_threaded_scan() -> bool {
         if let Ok(config) = Self::get_instance().config.read() {
             config.is_single_threaded_scan
@@ -343,6 +361,38 @@ impl ScanSettingsConfig {
         Self::save_config();
     }
 
+    pub fn get_scan_thread_count() -> u32 {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.scan_thread_count
+        } else {
+            ScanSettings::default().scan_thread_count
+        }
+    }
+
+    pub fn set_scan_thread_count(value: u32) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.scan_thread_count = value;
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_vector_width() -> ScanVectorWidth {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.vector_width
+        } else {
+            ScanSettings::default().vector_width
+        }
+    }
+
+    pub fn set_vector_width(value: ScanVectorWidth) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.vector_width = value;
+        }
+
+        Self::save_config();
+    }
+
     pub fn get_debug_perform_validation_scan() -> bool {
         if let Ok(config) = Self::get_instance().config.read() {
             config.debug_perform_validation_scan
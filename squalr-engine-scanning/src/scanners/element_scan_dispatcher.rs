@@ -1,4 +1,5 @@
 use crate::scanners::scalar::scanner_scalar_byte_array_booyer_moore::ScannerScalarByteArrayBooyerMoore;
+use crate::scanners::scalar::scanner_scalar_byte_array_masked::ScannerScalarByteArrayMasked;
 use crate::scanners::scalar::scanner_scalar_iterative::ScannerScalarIterative;
 use crate::scanners::scalar::scanner_scalar_single_element::ScannerScalarSingleElement;
 use crate::scanners::scanner_null::ScannerNull;
@@ -18,7 +19,10 @@ use squalr_engine_api::structures::scanning::plans::plan_types::planned_scan_typ
 use squalr_engine_api::structures::scanning::plans::plan_types::planned_scan_type_scalar::PlannedScanTypeScalar;
 use squalr_engine_api::structures::scanning::plans::plan_types::planned_scan_type_vector::PlannedScanTypeVector;
 use squalr_engine_api::structures::scanning::plans::plan_types::planned_scan_vectorization_size::PlannedScanVectorizationSize;
+use squalr_engine_api::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+use squalr_engine_api::structures::scanning::statistics::scan_strategy::ScanStrategy;
 use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+use squalr_engine_architecture::vectors::Vectors;
 use std::cmp;
 
 pub struct ElementScanDispatcher {}
@@ -26,11 +30,25 @@ pub struct ElementScanDispatcher {}
 /// Implements a scan dispatcher, which picks the best scanner based on the scan constraints and the region being scanned.
 /// Choosing the best scanner is critical to maintaining high performance scans.
 impl ElementScanDispatcher {
-    /// Performs a scan over a provided filter collection, returning a new filter collection with the results.
+    /// Performs a scan over a provided filter collection, returning a new filter collection with the results. The scanners are
+    /// picked using a strategy selected from the statistics of the scan that produced the provided collection.
     pub fn dispatch_scan(
         snapshot_region: &SnapshotRegion,
         snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
         element_scan_plan: &ElementScanPlan,
+    ) -> SnapshotRegionFilterCollection {
+        let scan_strategy = ScanStrategy::select(snapshot_region_filter_collection.get_scan_filter_statistics());
+
+        Self::dispatch_scan_with_strategy(snapshot_region, snapshot_region_filter_collection, element_scan_plan, scan_strategy)
+    }
+
+    /// Performs a scan over a provided filter collection using the given strategy, returning a new filter collection with the
+    /// results. The statistics of this scan are attached to the returned collection.
+    pub fn dispatch_scan_with_strategy(
+        snapshot_region: &SnapshotRegion,
+        snapshot_region_filter_collection: &SnapshotRegionFilterCollection,
+        element_scan_plan: &ElementScanPlan,
+        scan_strategy: ScanStrategy,
     ) -> SnapshotRegionFilterCollection {
         let unit_size_in_bytes = element_scan_plan
             .get_scan_constraints_by_data_type()
@@ -48,6 +66,7 @@ impl ElementScanDispatcher {
                         snapshot_region_filter,
                         snapshot_region,
                         element_scan_plan,
+                        scan_strategy,
                     )
                 })
                 .collect()
@@ -60,17 +79,26 @@ impl ElementScanDispatcher {
                         snapshot_region_filter,
                         snapshot_region,
                         element_scan_plan,
+                        scan_strategy,
                     )
                 })
                 .collect()
         };
 
-        SnapshotRegionFilterCollection::new(
+        let result_snapshot_region_filter_collection = SnapshotRegionFilterCollection::new(
             result_snapshot_region_filters,
             snapshot_region_filter_collection.get_data_type_ref().clone(),
             snapshot_region_filter_collection.get_memory_alignment(),
             unit_size_in_bytes,
-        )
+        );
+        let scan_filter_statistics = ScanFilterStatistics::from_result_filters(
+            snapshot_region_filter_collection.get_number_of_results(),
+            result_snapshot_region_filter_collection.iter(),
+            result_snapshot_region_filter_collection.get_unit_size_in_bytes(),
+            result_snapshot_region_filter_collection.get_memory_alignment(),
+        );
+
+        result_snapshot_region_filter_collection.with_scan_statistics(scan_strategy, scan_filter_statistics)
     }
 
     // This method orchestrates multiple scan parameters to combine when scanning a single snapshot region.
@@ -81,6 +109,7 @@ impl ElementScanDispatcher {
         snapshot_region_filter: &SnapshotRegionFilter,
         snapshot_region: &SnapshotRegion,
         element_scan_plan: &ElementScanPlan,
+        scan_strategy: ScanStrategy,
     ) -> Option<Vec<SnapshotRegionFilter>> {
         let scan_constraints = match element_scan_plan
             .get_scan_constraints_by_data_type()
@@ -91,6 +120,9 @@ impl ElementScanDispatcher {
         };
         let mut scan_result_filters = vec![snapshot_region_filter.clone()];
 
+        // Sparse results leave filters too small to be worth splitting across threads, so keep each one on this thread.
+        let is_single_thread_scan = element_scan_plan.get_is_single_thread_scan() || scan_strategy == ScanStrategy::Sparse;
+
         // Helper function to map the given element scan parameters to optimized mapped parameters for the given filter.
         let process_constraint = |snapshot_region_filter: &SnapshotRegionFilter, scan_constraint_finalized: &ScanConstraintFinalized| {
             let mut snapshot_filter_element_scan_plan = SnapshotFilterElementScanPlan::new(
@@ -99,6 +131,12 @@ impl ElementScanDispatcher {
                 element_scan_plan.get_floating_point_tolerance(),
             );
 
+            snapshot_filter_element_scan_plan.set_vector_width(
+                element_scan_plan
+                    .get_vector_width()
+                    .resolve_for_hardware(Vectors::get_hardware_vector_size()),
+            );
+
             // Apply all scan rules to the mapped parameters.
             for (_id, scan_filter_rule) in ElementScanRuleRegistry::get_instance()
                 .get_scan_filter_rule_registry()
@@ -113,12 +151,14 @@ impl ElementScanDispatcher {
                 );
             }
 
+            Self::apply_scan_strategy(scan_strategy, snapshot_region_filter, &mut snapshot_filter_element_scan_plan);
+
             Self::dispatch_scan_for_snapshot_filter(snapshot_region_filter, &snapshot_filter_element_scan_plan, snapshot_region, element_scan_plan)
         };
 
         // Perform each scan sequentially over the current result filters.
         for scan_constraint in scan_constraints {
-            scan_result_filters = if element_scan_plan.get_is_single_thread_scan() {
+            scan_result_filters = if is_single_thread_scan {
                 scan_result_filters
                     .iter()
                     .flat_map(|snapshot_region_filter| process_constraint(snapshot_region_filter, scan_constraint))
@@ -137,6 +177,29 @@ impl ElementScanDispatcher {
         if scan_result_filters.is_empty() { None } else { Some(scan_result_filters) }
     }
 
+    /// Adjusts the scanner planned by the scan rules to suit the strategy chosen for the filter collection.
+    fn apply_scan_strategy(
+        scan_strategy: ScanStrategy,
+        snapshot_region_filter: &SnapshotRegionFilter,
+        snapshot_filter_element_scan_plan: &mut SnapshotFilterElementScanPlan,
+    ) {
+        if scan_strategy != ScanStrategy::Sparse {
+            return;
+        }
+
+        // A filter spanning exactly one element needs one comparison, which the single element scanner makes without
+        // setting up a run length encoder.
+        let is_single_element = snapshot_region_filter.get_region_size() == snapshot_filter_element_scan_plan.get_unit_size_in_bytes();
+        let is_planned_scalar_iterative = matches!(
+            snapshot_filter_element_scan_plan.get_planned_scan_type(),
+            PlannedScanType::Scalar(PlannedScanTypeScalar::ScalarIterative)
+        );
+
+        if is_single_element && is_planned_scalar_iterative {
+            snapshot_filter_element_scan_plan.set_planned_scan_type(PlannedScanType::Scalar(PlannedScanTypeScalar::SingleElement));
+        }
+    }
+
     fn dispatch_scan_for_snapshot_filter(
         snapshot_region_filter: &SnapshotRegionFilter,
         snapshot_filter_element_scan_plan: &SnapshotFilterElementScanPlan,
@@ -205,6 +268,7 @@ impl ElementScanDispatcher {
             },
             PlannedScanType::ByteArray(scan_parameters_byte_array) => match scan_parameters_byte_array {
                 PlannedScanTypeByteArray::ByteArrayBooyerMoore => &ScannerScalarByteArrayBooyerMoore {},
+                PlannedScanTypeByteArray::ByteArrayMasked => &ScannerScalarByteArrayMasked {},
             },
         }
     }
@@ -255,3 +319,561 @@ impl ElementScanDispatcher {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ElementScanDispatcher;
+    use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+    use squalr_engine_api::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+    use squalr_engine_api::structures::data_types::built_in_types::f32::data_type_f32::DataTypeF32;
+    use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+    use squalr_engine_api::structures::data_types::built_in_types::string::encoded::text_encoding::TextEncoding;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::data_values::data_value::DataValue;
+    use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+    use squalr_engine_api::structures::scanning::constraints::scan_constraint::ScanConstraint;
+    use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+    use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+    use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+    use squalr_engine_api::structures::scanning::statistics::scan_strategy::ScanStrategy;
+    use squalr_engine_api::structures::settings::scan_vector_width::ScanVectorWidth;
+    use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+    use std::collections::HashMap;
+
+    const ELEMENT_COUNT: usize = 4_096;
+
+    /// Creates a region of i32 values, where every 512th element is 7 and the rest are 0.
+    fn create_sparse_snapshot_region() -> SnapshotRegion {
+        let bytes = (0..ELEMENT_COUNT)
+            .flat_map(|index| if index % 512 == 0 { 7i32 } else { 0i32 }.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, bytes.len() as u64), vec![]);
+
+        snapshot_region.current_values = bytes.clone();
+        snapshot_region.previous_values = bytes;
+        snapshot_region
+    }
+
+    fn create_equal_to_seven_scan_plan() -> ElementScanPlan {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+        let scan_constraint = ScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            DataValue::new(data_type_ref.clone(), 7i32.to_le_bytes().to_vec()),
+            FloatingPointTolerance::default(),
+        );
+
+        ElementScanPlan::new(
+            HashMap::from([(data_type_ref, vec![ScanConstraintFinalized::new(scan_constraint)])]),
+            MemoryAlignment::Alignment4,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            false,
+            false,
+        )
+    }
+
+    fn get_filter_ranges(snapshot_region_filter_collection: &SnapshotRegionFilterCollection) -> Vec<(u64, u64)> {
+        snapshot_region_filter_collection
+            .iter()
+            .map(|snapshot_region_filter| (snapshot_region_filter.get_base_address(), snapshot_region_filter.get_region_size()))
+            .collect()
+    }
+
+    /// Scans synthetic i32 values for a pasted list of values, returning the address of every matched element.
+    fn scan_for_value_list(
+        scan_compare_type_immediate: ScanCompareTypeImmediate,
+        values: &[i32],
+        element_values: &[i32],
+    ) -> Vec<u64> {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+        let bytes = element_values
+            .iter()
+            .flat_map(|element_value| element_value.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, bytes.len() as u64), vec![]);
+
+        snapshot_region.current_values = bytes.clone();
+        snapshot_region.previous_values = bytes;
+
+        let value_list = values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",\n");
+        let anonymous_scan_constraint = AnonymousScanConstraint::new(
+            ScanCompareType::Immediate(scan_compare_type_immediate),
+            Some(AnonymousValueString::new(value_list, AnonymousValueStringFormat::Decimal, ContainerType::None)),
+        );
+        let scan_constraint = anonymous_scan_constraint
+            .deanonymize_constraint(&data_type_ref, FloatingPointTolerance::default())
+            .expect("Value list should parse.");
+
+        assert_eq!(scan_constraint.is_any_of(), values.len() > 1);
+
+        let element_scan_plan = ElementScanPlan::new(
+            HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(scan_constraint)])]),
+            MemoryAlignment::Alignment4,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            false,
+            false,
+        );
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                0,
+                snapshot_region.get_region_size(),
+            )]],
+            data_type_ref,
+            MemoryAlignment::Alignment4,
+            4,
+        );
+        let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+
+        get_filter_ranges(&result_collection)
+            .into_iter()
+            .flat_map(|(base_address, region_size)| (base_address..base_address + region_size).step_by(4))
+            .collect()
+    }
+
+    fn get_expected_addresses(
+        element_values: &[i32],
+        is_match: impl Fn(i32) -> bool,
+    ) -> Vec<u64> {
+        element_values
+            .iter()
+            .enumerate()
+            .filter(|(_index, element_value)| is_match(**element_value))
+            .map(|(index, _element_value)| index as u64 * 4)
+            .collect()
+    }
+
+    /// Scans synthetic 4 byte elements for values between two bounds, returning the address of every matched element.
+    fn scan_for_range(
+        data_type_ref: DataTypeRef,
+        element_bytes: Vec<u8>,
+        lower_bound: &str,
+        upper_bound: &str,
+    ) -> Vec<u64> {
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, element_bytes.len() as u64), vec![]);
+
+        snapshot_region.current_values = element_bytes.clone();
+        snapshot_region.previous_values = element_bytes;
+
+        let anonymous_scan_constraint = AnonymousScanConstraint::new_between(
+            AnonymousValueString::new(lower_bound.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            AnonymousValueString::new(upper_bound.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+        );
+        let scan_constraint = anonymous_scan_constraint
+            .deanonymize_constraint(&data_type_ref, FloatingPointTolerance::default())
+            .expect("Range should parse.");
+        let element_scan_plan = ElementScanPlan::new(
+            HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(scan_constraint)])]),
+            MemoryAlignment::Alignment4,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            false,
+            false,
+        );
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                0,
+                snapshot_region.get_region_size(),
+            )]],
+            data_type_ref,
+            MemoryAlignment::Alignment4,
+            4,
+        );
+        let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+
+        get_filter_ranges(&result_collection)
+            .into_iter()
+            .flat_map(|(base_address, region_size)| (base_address..base_address + region_size).step_by(4))
+            .collect()
+    }
+
+    #[test]
+    fn every_vector_width_finds_the_same_results() {
+        let snapshot_region = create_sparse_snapshot_region();
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                0,
+                snapshot_region.get_region_size(),
+            )]],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        );
+        let expected_ranges = (0..ELEMENT_COUNT as u64)
+            .step_by(512)
+            .map(|index| (index * 4, 4))
+            .collect::<Vec<(u64, u64)>>();
+
+        for vector_width in ScanVectorWidth::ALL {
+            let element_scan_plan = create_equal_to_seven_scan_plan().with_vector_width(vector_width);
+            let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+
+            assert_eq!(
+                get_filter_ranges(&result_collection),
+                expected_ranges,
+                "Mismatched results for {:?}.",
+                vector_width
+            );
+        }
+    }
+
+    #[test]
+    fn integer_ranges_include_both_bounds() {
+        let data_type_ref = DataTypeRef::new(DataTypeI32::DATA_TYPE_ID);
+
+        // A large region is scanned with vectors, while a region smaller than a vector is scanned one element at a time.
+        for element_count in [ELEMENT_COUNT as i32, 3] {
+            let element_values = (0..element_count)
+                .map(|index| (index % 21) - 10)
+                .collect::<Vec<i32>>();
+            let element_bytes = element_values
+                .iter()
+                .flat_map(|element_value| element_value.to_le_bytes())
+                .collect::<Vec<u8>>();
+            let matched_addresses = scan_for_range(data_type_ref.clone(), element_bytes.clone(), "-9", "5");
+            let expected_addresses = get_expected_addresses(&element_values, |element_value| (-9..=5).contains(&element_value));
+
+            assert!(!expected_addresses.is_empty());
+            assert_eq!(matched_addresses, expected_addresses, "Mismatched results for {} elements.", element_count);
+
+            // Equal bounds match exactly that value.
+            let matched_addresses = scan_for_range(data_type_ref.clone(), element_bytes, "-10", "-10");
+
+            assert_eq!(matched_addresses, get_expected_addresses(&element_values, |element_value| element_value == -10));
+        }
+    }
+
+    #[test]
+    fn float_ranges_include_both_bounds() {
+        let data_type_ref = DataTypeRef::new(DataTypeF32::DATA_TYPE_ID);
+        let element_values = (0..ELEMENT_COUNT)
+            .map(|index| (index % 64) as f32 * 0.25)
+            .collect::<Vec<f32>>();
+        let element_bytes = element_values
+            .iter()
+            .flat_map(|element_value| element_value.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let matched_addresses = scan_for_range(data_type_ref, element_bytes, "1.5", "2.5");
+        let expected_addresses = element_values
+            .iter()
+            .enumerate()
+            .filter(|(_index, element_value)| (1.5..=2.5).contains(*element_value))
+            .map(|(index, _element_value)| index as u64 * 4)
+            .collect::<Vec<u64>>();
+
+        // Each cycle of 64 elements holds 1.5, 1.75, 2.0, 2.25, and 2.5, but neither 1.25 nor 2.75.
+        assert_eq!(expected_addresses.len(), ELEMENT_COUNT / 64 * 5);
+        assert_eq!(matched_addresses, expected_addresses);
+    }
+
+    /// Scans synthetic f32 values for a value at the tolerance of the constraint, returning the address of every matched element.
+    fn scan_for_float_value(
+        element_values: &[f32],
+        value: &str,
+        floating_point_tolerance: FloatingPointTolerance,
+    ) -> Vec<u64> {
+        let data_type_ref = DataTypeRef::new(DataTypeF32::DATA_TYPE_ID);
+        let bytes = element_values
+            .iter()
+            .flat_map(|element_value| element_value.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, bytes.len() as u64), vec![]);
+
+        snapshot_region.current_values = bytes.clone();
+        snapshot_region.previous_values = bytes;
+
+        let mut anonymous_scan_constraint = AnonymousScanConstraint::new(
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+            Some(AnonymousValueString::new(
+                value.to_string(),
+                AnonymousValueStringFormat::Decimal,
+                ContainerType::None,
+            )),
+        );
+
+        anonymous_scan_constraint.set_floating_point_tolerance(floating_point_tolerance);
+
+        // The tolerance of the constraint takes the place of the tolerance from the scan settings.
+        let scan_constraint = anonymous_scan_constraint
+            .deanonymize_constraint(&data_type_ref, FloatingPointTolerance::default())
+            .expect("Value should parse.");
+
+        assert_eq!(scan_constraint.get_floating_point_tolerance(), floating_point_tolerance);
+
+        let element_scan_plan = ElementScanPlan::new(
+            HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(scan_constraint)])]),
+            MemoryAlignment::Alignment4,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            false,
+            false,
+        );
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                0,
+                snapshot_region.get_region_size(),
+            )]],
+            data_type_ref,
+            MemoryAlignment::Alignment4,
+            4,
+        );
+        let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+
+        get_filter_ranges(&result_collection)
+            .into_iter()
+            .flat_map(|(base_address, region_size)| (base_address..base_address + region_size).step_by(4))
+            .collect()
+    }
+
+    #[test]
+    fn float_equality_uses_the_tolerance_of_the_constraint() {
+        // A large region is scanned with vectors, while a region smaller than a vector is scanned one element at a time.
+        for element_count in [ELEMENT_COUNT, 3] {
+            let element_values = (0..element_count)
+                .map(|index| [99.99999f32, 100.0, 99.7, 100.5][index % 4])
+                .collect::<Vec<f32>>();
+            let get_expected_float_addresses = |is_match: fn(usize) -> bool| {
+                (0..element_count)
+                    .filter(|index| is_match(index % 4))
+                    .map(|index| index as u64 * 4)
+                    .collect::<Vec<u64>>()
+            };
+
+            // A stored 99.99999 is 100 within 0.001, but not exactly.
+            assert_eq!(
+                scan_for_float_value(&element_values, "100", FloatingPointTolerance::Tolerance10E3),
+                get_expected_float_addresses(|cycle_index| cycle_index <= 1),
+                "Mismatched results for {} elements.",
+                element_count
+            );
+            assert_eq!(
+                scan_for_float_value(&element_values, "100", FloatingPointTolerance::ToleranceEpsilon),
+                get_expected_float_addresses(|cycle_index| cycle_index == 1)
+            );
+
+            // Rounded values compare their integer parts, as a game displaying a rounded value would show them.
+            assert_eq!(
+                scan_for_float_value(&element_values, "100", FloatingPointTolerance::ToleranceInteger),
+                get_expected_float_addresses(|cycle_index| cycle_index == 1 || cycle_index == 3)
+            );
+            assert_eq!(
+                scan_for_float_value(&element_values, "99", FloatingPointTolerance::ToleranceInteger),
+                get_expected_float_addresses(|cycle_index| cycle_index == 0 || cycle_index == 2)
+            );
+        }
+    }
+
+    #[test]
+    fn value_lists_of_every_size_match_any_listed_value() {
+        let element_values = (0..ELEMENT_COUNT as i32)
+            .map(|index| (index * 37) % 5_000)
+            .collect::<Vec<i32>>();
+
+        // A single value is an ordinary scan, small lists unroll into vector compares, and large lists use a hash set lookup.
+        for value_count in [1, 4, 1_000] {
+            let values = (0..value_count)
+                .map(|value_index| value_index * 5 + 3)
+                .collect::<Vec<i32>>();
+            let matched_addresses = scan_for_value_list(ScanCompareTypeImmediate::Equal, &values, &element_values);
+            let expected_addresses = get_expected_addresses(&element_values, |element_value| values.contains(&element_value));
+
+            assert!(!expected_addresses.is_empty());
+            assert_eq!(
+                matched_addresses, expected_addresses,
+                "Mismatched results for a list of {} values.",
+                value_count
+            );
+        }
+    }
+
+    #[test]
+    fn not_equal_value_lists_match_none_of_the_listed_values() {
+        let element_values = (0..ELEMENT_COUNT as i32)
+            .map(|index| index % 16)
+            .collect::<Vec<i32>>();
+
+        for value_count in [4, 1_000] {
+            let values = (0..value_count)
+                .map(|value_index| value_index * 3)
+                .collect::<Vec<i32>>();
+            let matched_addresses = scan_for_value_list(ScanCompareTypeImmediate::NotEqual, &values, &element_values);
+            let expected_addresses = get_expected_addresses(&element_values, |element_value| !values.contains(&element_value));
+
+            assert_eq!(
+                matched_addresses, expected_addresses,
+                "Mismatched results for a list of {} values.",
+                value_count
+            );
+        }
+    }
+
+    #[test]
+    fn sparse_results_are_rescanned_with_the_sparse_strategy_and_the_same_results() {
+        let snapshot_region = create_sparse_snapshot_region();
+        let element_scan_plan = create_equal_to_seven_scan_plan();
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![SnapshotRegionFilter::new(
+                0,
+                snapshot_region.get_region_size(),
+            )]],
+            DataTypeRef::new(DataTypeI32::DATA_TYPE_ID),
+            MemoryAlignment::Alignment4,
+            4,
+        );
+
+        let first_scan_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+        let first_scan_statistics = first_scan_collection.get_scan_filter_statistics();
+
+        assert_eq!(first_scan_collection.get_scan_strategy(), ScanStrategy::Planned);
+        assert_eq!(first_scan_statistics.get_scanned_element_count(), ELEMENT_COUNT as u64);
+        assert_eq!(first_scan_statistics.get_matched_element_count(), 8);
+        assert_eq!(first_scan_statistics.get_run_length_histogram(), &[8, 0, 0, 0]);
+
+        let sparse_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &first_scan_collection, &element_scan_plan);
+        let planned_collection =
+            ElementScanDispatcher::dispatch_scan_with_strategy(&snapshot_region, &first_scan_collection, &element_scan_plan, ScanStrategy::Planned);
+
+        assert_eq!(sparse_collection.get_scan_strategy(), ScanStrategy::Sparse);
+        assert_eq!(get_filter_ranges(&sparse_collection), get_filter_ranges(&planned_collection));
+        assert_eq!(get_filter_ranges(&sparse_collection).len(), 8);
+    }
+
+    #[test]
+    fn encoded_strings_are_found_and_displayed_as_the_searched_text() {
+        let cases = [
+            (TextEncoding::ShiftJis, "セーブデータ"),
+            (TextEncoding::Latin1, "Señor Café"),
+            (TextEncoding::Utf32, "Ünïcødé ★"),
+        ];
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        for (text_encoding, text) in cases {
+            let data_type_ref = DataTypeRef::new(text_encoding.get_data_type_id());
+            let memory_alignment = MemoryAlignment::Alignment1;
+            let search_value = symbol_registry
+                .deanonymize_value_string(
+                    &data_type_ref,
+                    &AnonymousValueString::new(text.to_string(), AnonymousValueStringFormat::String, ContainerType::None),
+                )
+                .expect("Text should be representable in its encoding.");
+            let text_offset = 96;
+
+            // Surround the text with unrelated bytes, and terminate it with a null code unit as a game would.
+            let mut bytes = vec![0xAAu8; text_offset];
+            bytes.extend(search_value.get_value_bytes());
+            bytes.extend(vec![0u8; text_encoding.get_unit_size_in_bytes() as usize]);
+            bytes.extend(vec![0xAAu8; 64]);
+
+            let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, bytes.len() as u64), vec![]);
+            snapshot_region.current_values = bytes.clone();
+            snapshot_region.previous_values = bytes;
+
+            let element_scan_plan = ElementScanPlan::new(
+                HashMap::from([(
+                    data_type_ref.clone(),
+                    vec![ScanConstraintFinalized::new(ScanConstraint::new(
+                        ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+                        search_value.clone(),
+                        FloatingPointTolerance::default(),
+                    ))],
+                )]),
+                memory_alignment,
+                FloatingPointTolerance::default(),
+                MemoryReadMode::Skip,
+                false,
+                false,
+            );
+            let initial_collection = SnapshotRegionFilterCollection::new(
+                vec![vec![SnapshotRegionFilter::new(
+                    0,
+                    snapshot_region.get_region_size(),
+                )]],
+                data_type_ref.clone(),
+                memory_alignment,
+                text_encoding.get_unit_size_in_bytes(),
+            );
+
+            let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+            let result_ranges = get_filter_ranges(&result_collection);
+
+            assert_eq!(result_ranges.len(), 1, "{} text was not found exactly once.", text_encoding.get_name());
+            assert_eq!(result_ranges[0].0, text_offset as u64);
+
+            // Display reads past the match, and should stop at the terminator.
+            let found_bytes = snapshot_region.current_values[text_offset..text_offset + 64].to_vec();
+            let displayed_value = symbol_registry
+                .anonymize_value(&DataValue::new(data_type_ref, found_bytes), AnonymousValueStringFormat::String)
+                .expect("Found text should be displayable.");
+
+            assert_eq!(displayed_value.get_anonymous_value_string(), text);
+        }
+    }
+
+    #[test]
+    fn aob_patterns_match_wildcards_across_filter_ends() {
+        let data_type_ref = DataTypeRef::new(DataTypeAob::DATA_TYPE_ID);
+        let bytes = vec![
+            0x48, 0x8B, 0x11, 0x05, 0x00, 0x00, 0x4F, 0x8B, 0x22, 0x05, 0x00, 0x00, 0x00, 0x48, 0x8B, 0x33, 0x06, 0x00, 0x00, 0x00, 0x00, 0x41, 0x8B, 0x44,
+        ];
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(0, bytes.len() as u64), vec![]);
+
+        snapshot_region.current_values = bytes.clone();
+        snapshot_region.previous_values = bytes;
+
+        let create_pattern_constraint = |pattern: &str| {
+            AnonymousScanConstraint::new(
+                ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+                Some(AnonymousValueString::new(
+                    pattern.to_string(),
+                    AnonymousValueStringFormat::Hexadecimal,
+                    ContainerType::None,
+                )),
+            )
+            .deanonymize_constraint(&data_type_ref, FloatingPointTolerance::default())
+        };
+        let scan_constraint = create_pattern_constraint("4? 8B ?? 05").expect("Pattern should parse.");
+
+        assert_eq!(scan_constraint.get_byte_mask(), &[0xF0, 0xFF, 0x00, 0xFF]);
+
+        let element_scan_plan = ElementScanPlan::new(
+            HashMap::from([(data_type_ref.clone(), vec![ScanConstraintFinalized::new(scan_constraint)])]),
+            MemoryAlignment::Alignment1,
+            FloatingPointTolerance::default(),
+            MemoryReadMode::Skip,
+            false,
+            false,
+        );
+
+        // The second match begins in the first filter and ends in the second, and the last candidate runs off the end of the region.
+        let initial_collection = SnapshotRegionFilterCollection::new(
+            vec![vec![
+                SnapshotRegionFilter::new(0, 8),
+                SnapshotRegionFilter::new(8, 16),
+            ]],
+            data_type_ref.clone(),
+            MemoryAlignment::Alignment1,
+            1,
+        );
+        let result_collection = ElementScanDispatcher::dispatch_scan(&snapshot_region, &initial_collection, &element_scan_plan);
+
+        assert_eq!(get_filter_ranges(&result_collection), vec![(0, 4), (6, 4)]);
+
+        // Patterns too short, or made entirely of wildcards, would match nearly everywhere.
+        assert!(create_pattern_constraint("48").is_none());
+        assert!(create_pattern_constraint("?? ? ??").is_none());
+    }
+}
@@ -3,16 +3,19 @@ use crate::scanners::snapshot_region_memory_reader::SnapshotRegionMemoryReader;
 use crate::scanners::value_collector_task::ValueCollectorTask;
 use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator};
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
 use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
 use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
 use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
 use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::scanning::statistics::scan_statistics::ScanStatistics;
+use squalr_engine_api::structures::scanning::statistics::scan_strategy::ScanStrategy;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
 use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
 use crate::scan_settings_config::ScanSettingsConfig;
 use squalr_engine_api::structures::settings::scan_thread_priority::ScanThreadPriority;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -43,7 +46,7 @@ impl ElementScanExecutorTask {
         task
     }
 
-    fn scan_task(
+    pub(crate) fn scan_task(
         trackable_task: &Arc<TrackableTask>,
         process_info: OpenedProcessInfo,
         snapshot: Arc<RwLock<Snapshot>>,
@@ -52,6 +55,18 @@ impl ElementScanExecutorTask {
     ) {
         let total_start_time = Instant::now();
 
+        // Retain the results this scan is about to replace, before any values are collected, such that they keep the values they
+        // were found with and can be reverted to.
+        match snapshot.write() {
+            Ok(mut snapshot_guard) => snapshot_guard.record_scan_history(),
+            Err(error) => {
+                if with_logging {
+                    log::error!("Failed to acquire write lock on snapshot to record scan history: {}", error);
+                }
+                return;
+            }
+        }
+
         // If the parameter is set, first collect values before the scan.
         // This is slower overall than interleaving the reads, but better for capturing values that may soon change.
         if element_scan_plan.get_memory_read_mode() == MemoryReadMode::ReadBeforeScan {
@@ -82,6 +97,7 @@ impl ElementScanExecutorTask {
 
         let start_time = Instant::now();
         let processed_region_count = Arc::new(AtomicUsize::new(0));
+        let read_byte_count = AtomicU64::new(0);
         let cancellation_token = trackable_task.get_cancellation_token();
 
         // Create a function that processes every snapshot region, from which we will grab the existing snapshot filters (previous results) to perform our next scan.
@@ -95,13 +111,27 @@ impl ElementScanExecutorTask {
 
             // Attempt to read new (or initial) memory values. Ignore failures as they usually indicate deallocated pages. // JIRA: Remove failures somehow.
             if element_scan_plan.get_memory_read_mode() == MemoryReadMode::ReadInterleavedWithScan {
-                if snapshot_region.read_all_memory_chunked(&process_info).is_err() {
-                    snapshot_region.mark_unreadable();
-                    processed_region_count.fetch_add(1, Ordering::SeqCst);
-                    return;
+                match snapshot_region.read_filtered_memory_chunked(&process_info) {
+                    Ok(bytes_read) => {
+                        read_byte_count.fetch_add(bytes_read, Ordering::Relaxed);
+                    }
+                    Err(_) => {
+                        snapshot_region.mark_unreadable();
+                        processed_region_count.fetch_add(1, Ordering::SeqCst);
+                        return;
+                    }
                 }
             }
 
+            // Scans against the first scan compare the current values with the first scan values in place of the previous values.
+            let previous_values = if element_scan_plan.get_compares_against_first_scan() {
+                let first_scan_values = snapshot_region.get_first_scan_values_as_region_values();
+
+                Some(std::mem::replace(&mut snapshot_region.previous_values, first_scan_values))
+            } else {
+                None
+            };
+
             /*
             // JIRA: Fixme? Early exit gains?
             if !element_scan_plan.is_valid_for_snapshot_region(snapshot_region) {
@@ -109,27 +139,37 @@ impl ElementScanExecutorTask {
                 return;
             }*/
 
-            // Create a function to dispatch our element scan to the best scanner implementation for the current region.
-            let element_scan_dispatcher = |snapshot_region_filter_collection| {
-                ElementScanDispatcher::dispatch_scan(snapshot_region, snapshot_region_filter_collection, &element_scan_plan)
-            };
+            // Unknown initial value scans have nothing to compare, so every element of the initialized results is kept as is.
+            if !element_scan_plan.get_is_unknown_initial_value_scan() {
+                // Create a function to dispatch our element scan to the best scanner implementation for the current region.
+                let element_scan_dispatcher = |snapshot_region_filter_collection| {
+                    ElementScanDispatcher::dispatch_scan(snapshot_region, snapshot_region_filter_collection, &element_scan_plan)
+                };
 
-            // Again, select the parallel or sequential iterator to iterate over each data type in the scan. Generally there is only 1, but multi-type scans are supported.
-            let scan_results_collection = snapshot_region.get_scan_results().get_filter_collections();
-            let single_thread_scan = element_scan_plan.get_is_single_thread_scan() || scan_results_collection.len() == 1;
-            let scan_results = SnapshotRegionScanResults::new(if single_thread_scan {
-                scan_results_collection
-                    .iter()
-                    .map(element_scan_dispatcher)
-                    .collect()
-            } else {
-                scan_results_collection
-                    .par_iter()
-                    .map(element_scan_dispatcher)
-                    .collect()
-            });
+                // Again, select the parallel or sequential iterator to iterate over each data type in the scan. Generally there is only 1, but multi-type scans are supported.
+                let scan_results_collection = snapshot_region.get_scan_results().get_filter_collections();
+                let single_thread_scan = element_scan_plan.get_is_single_thread_scan() || scan_results_collection.len() == 1;
+                let scan_results = SnapshotRegionScanResults::new(if single_thread_scan {
+                    scan_results_collection
+                        .iter()
+                        .map(element_scan_dispatcher)
+                        .collect()
+                } else {
+                    scan_results_collection
+                        .par_iter()
+                        .map(element_scan_dispatcher)
+                        .collect()
+                });
+
+                if let Some(previous_values) = previous_values {
+                    snapshot_region.previous_values = previous_values;
+                }
 
-            snapshot_region.set_scan_results(scan_results);
+                snapshot_region.set_scan_results(scan_results);
+            }
+
+            // The values read for the first scan are kept for the results that survived it.
+            snapshot_region.capture_first_scan_values();
 
             let processed = processed_region_count.fetch_add(1, Ordering::SeqCst);
 
@@ -149,7 +189,9 @@ impl ElementScanExecutorTask {
         if single_thread_scan {
             snapshot_regions.iter_mut().for_each(snapshot_iterator);
         } else {
-            snapshot_regions.par_iter_mut().for_each(snapshot_iterator);
+            Self::run_on_scan_threads(element_scan_plan.get_scan_thread_count(), || {
+                snapshot_regions.par_iter_mut().for_each(snapshot_iterator);
+            });
         };
 
         // Finalize: write the scanned regions back into the snapshot.
@@ -157,6 +199,17 @@ impl ElementScanExecutorTask {
             .iter()
             .map(|region| region.get_scan_results().get_number_of_results())
             .sum();
+        let mut scan_statistics = ScanStatistics::new();
+
+        for snapshot_region_filter_collection in snapshot_regions
+            .iter()
+            .flat_map(|region| region.get_scan_results().get_filter_collections())
+        {
+            scan_statistics.record_collection(
+                snapshot_region_filter_collection.get_scan_strategy(),
+                snapshot_region_filter_collection.get_scan_filter_statistics(),
+            );
+        }
         {
             let mut snapshot_guard = match snapshot.write() {
                 Ok(guard) => guard,
@@ -169,6 +222,16 @@ impl ElementScanExecutorTask {
             };
 
             snapshot_guard.set_snapshot_regions(snapshot_regions);
+            snapshot_guard.set_last_scan_statistics(Some(scan_statistics));
+
+            // Results are renumbered by the scan, and are compared as the scanned type again regardless.
+            snapshot_guard.clear_data_type_overrides();
+            snapshot_guard.clear_stride_repeat_counts();
+            snapshot_guard.advance_scan_generation();
+
+            if element_scan_plan.get_memory_read_mode() == MemoryReadMode::ReadInterleavedWithScan {
+                snapshot_guard.set_last_read_byte_count(read_byte_count.load(Ordering::Relaxed));
+            }
         }
 
         if with_logging {
@@ -181,6 +244,13 @@ impl ElementScanExecutorTask {
 
             log::info!("Results: {} bytes", StorageSizeConversions::value_to_metric_size(byte_count as u128));
             log::info!("Result count: {}", result_count);
+            log::info!(
+                "Scan strategy: {} ({} planned, {} sparse, {} dense)",
+                scan_statistics.get_dominant_strategy().get_name(),
+                scan_statistics.get_collection_count(ScanStrategy::Planned),
+                scan_statistics.get_collection_count(ScanStrategy::Sparse),
+                scan_statistics.get_collection_count(ScanStrategy::Dense),
+            );
             log::info!("Scan complete in: {:?}", duration);
             log::info!("Total scan time: {:?}", total_duration);
 
@@ -192,7 +262,95 @@ impl ElementScanExecutorTask {
         }
     }
 
-    fn apply_thread_priority(priority: ScanThreadPriority) {
+    /// Evaluates the scan plan against the existing results without modifying the snapshot, returning how many results would remain.
+    /// Values are freshly read into temporary buffers (unless reads are skipped), so the snapshot's values and filters are left untouched.
+    pub fn preview_result_count(
+        process_info: &OpenedProcessInfo,
+        snapshot: &Arc<RwLock<Snapshot>>,
+        element_scan_plan: &ElementScanPlan,
+    ) -> u64 {
+        let snapshot_guard = match snapshot.read() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot for scan preview: {}", error);
+                return 0;
+            }
+        };
+
+        let preview_region_result_count = |snapshot_region: &SnapshotRegion| -> u64 {
+            // Regions without results cannot gain any on a subsequent scan.
+            if snapshot_region.get_scan_results().get_number_of_results() == 0 {
+                return 0;
+            }
+
+            let mut preview_region = SnapshotRegion::new(
+                NormalizedRegion::new(snapshot_region.get_base_address(), snapshot_region.get_region_size()),
+                snapshot_region.page_boundaries.clone(),
+            );
+
+            preview_region.current_values = snapshot_region.current_values.clone();
+
+            if element_scan_plan.get_memory_read_mode() == MemoryReadMode::Skip {
+                preview_region.previous_values = snapshot_region.previous_values.clone();
+            } else if preview_region.read_all_memory_chunked(process_info).is_err() {
+                return 0;
+            }
+
+            if element_scan_plan.get_compares_against_first_scan() {
+                preview_region.previous_values = snapshot_region.get_first_scan_values_as_region_values();
+            }
+
+            snapshot_region
+                .get_scan_results()
+                .get_filter_collections()
+                .iter()
+                .map(|snapshot_region_filter_collection| {
+                    ElementScanDispatcher::dispatch_scan(&preview_region, snapshot_region_filter_collection, element_scan_plan).get_number_of_results()
+                })
+                .sum()
+        };
+
+        let snapshot_regions = snapshot_guard.get_snapshot_regions();
+
+        if element_scan_plan.get_is_single_thread_scan() {
+            snapshot_regions.iter().map(preview_region_result_count).sum()
+        } else {
+            snapshot_regions
+                .par_iter()
+                .map(preview_region_result_count)
+                .sum()
+        }
+    }
+
+    /// Runs parallel work on a pool of the given number of threads, or on the global pool of one thread per logical core if zero.
+    /// Parallel iterators nested within the work, ie those of the dispatcher, stay on the same pool.
+    fn run_on_scan_threads<F>(
+        scan_thread_count: u32,
+        work: F,
+    ) where
+        F: FnOnce() + Send,
+    {
+        if scan_thread_count == 0 {
+            return work();
+        }
+
+        match rayon::ThreadPoolBuilder::new()
+            .num_threads(scan_thread_count as usize)
+            .build()
+        {
+            Ok(thread_pool) => thread_pool.install(work),
+            Err(error) => {
+                log::warn!(
+                    "Failed to create {} scan threads, scanning on the default threads instead: {}",
+                    scan_thread_count,
+                    error
+                );
+                work();
+            }
+        }
+    }
+
+    pub(crate) fn apply_thread_priority(priority: ScanThreadPriority) {
         #[cfg(windows)]
         unsafe {
             use windows_sys::Win32::System::Threading::{
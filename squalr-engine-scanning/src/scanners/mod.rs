@@ -4,6 +4,7 @@ pub mod scalar;
 pub mod scanner_null;
 pub mod snapshot_region_memory_reader;
 pub mod snapshot_scanner;
+pub mod stride_scan_executor_task;
 pub mod structures;
 pub mod value_collector_task;
 pub mod vector;
@@ -73,12 +73,14 @@ impl Scanner for ScannerScalarByteArrayBooyerMoore {
                 if is_mismatch {
                     match_found = false;
 
-                    let bad_char_shift = boyer_moore_table.get_mismatch_shift(current_byte);
-                    let good_suffix_shift = boyer_moore_table.get_good_suffix_shift(inverse_pattern_index + 1);
+                    // The mismatch table holds the distance from the last occurrence of a byte to the end of the pattern, so it is
+                    // indexed by the byte under the end of the window wherever the mismatch occurred. Shifting by the mismatched
+                    // byte itself can step over a match when the pattern repeats bytes, such as the zero padding of UTF-32 text.
+                    let window_end_byte = unsafe { *current_value_pointer.add((scan_index + pattern_length - 1) as usize) };
 
-                    // Unlike classic Booyer-Moore we don't take the max of the shifts. Instead we prioritize a set good shift over the bad shift.
-                    // This reduces some skipping, but allows us to safely handle overlapping results.
-                    shift_value = (if good_suffix_shift > 0 { good_suffix_shift } else { bad_char_shift }).max(memory_alignment_size);
+                    shift_value = boyer_moore_table
+                        .get_mismatch_shift(window_end_byte)
+                        .max(memory_alignment_size);
                     break;
                 }
             }
@@ -0,0 +1,77 @@
+use crate::scanners::snapshot_scanner::Scanner;
+use crate::scanners::structures::snapshot_region_filter_run_length_encoder::SnapshotRegionFilterRunLengthEncoder;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use squalr_engine_api::structures::scanning::plans::element_scan::snapshot_filter_element_scan_plan::SnapshotFilterElementScanPlan;
+use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+
+pub struct ScannerScalarByteArrayMasked {}
+
+impl ScannerScalarByteArrayMasked {}
+
+/// Implements a scalar (ie CPU bound, non-SIMD) array of bytes region scanning algorithm for patterns containing wildcards. Only the
+/// bits set in the byte mask of the pattern are compared, checking the bytes that are not wildcards in order so that most candidates
+/// are rejected by their first byte. Matches may begin anywhere in the filter, and are read through to the end of the snapshot region.
+impl Scanner for ScannerScalarByteArrayMasked {
+    fn get_scanner_name(&self) -> &'static str {
+        "Byte Array (Masked)"
+    }
+
+    /// Performs a sequential iteration over a region of memory, performing the masked comparison at each aligned address. A run-length
+    /// encoding algorithm is used to generate new sub-regions as the scan progresses.
+    fn scan_region(
+        &self,
+        snapshot_region: &SnapshotRegion,
+        snapshot_region_filter: &SnapshotRegionFilter,
+        snapshot_filter_element_scan_plan: &SnapshotFilterElementScanPlan,
+    ) -> Vec<SnapshotRegionFilter> {
+        if snapshot_filter_element_scan_plan.get_compare_type() != ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal) {
+            log::error!("Unsupported scan constraint. Only equality is supported for masked array of byte scans.");
+            return vec![];
+        }
+
+        let scan_constraint = snapshot_filter_element_scan_plan.get_scan_constraint();
+        let scan_pattern = scan_constraint.get_data_value().get_value_bytes();
+        let byte_mask = scan_constraint.get_byte_mask();
+        let pattern_length = scan_pattern.len() as u64;
+
+        // Wildcard bytes never fail a comparison, so only the remaining bytes are kept, along with their offset into the pattern.
+        let compared_bytes: Vec<(usize, u8, u8)> = scan_pattern
+            .iter()
+            .enumerate()
+            .map(|(pattern_index, pattern_byte)| (pattern_index, *pattern_byte, byte_mask.get(pattern_index).copied().unwrap_or(0xFF)))
+            .filter(|(_pattern_index, _pattern_byte, mask)| *mask != 0)
+            .collect();
+
+        let current_values = snapshot_region.get_current_values();
+        let base_address = snapshot_region_filter.get_base_address();
+        let filter_offset = base_address.saturating_sub(snapshot_region.get_base_address());
+        let region_size = snapshot_region_filter.get_region_size();
+        let memory_alignment_size = (snapshot_filter_element_scan_plan.get_memory_alignment() as u64).max(1);
+        let data_type_size_padding = pattern_length.saturating_sub(memory_alignment_size);
+        let mut run_length_encoder = SnapshotRegionFilterRunLengthEncoder::new(base_address);
+        let mut scan_index: u64 = 0;
+
+        while scan_index < region_size {
+            let match_start = (filter_offset + scan_index) as usize;
+            let is_match = match current_values.get(match_start..match_start.saturating_add(pattern_length as usize)) {
+                Some(candidate_bytes) => compared_bytes
+                    .iter()
+                    .all(|(pattern_index, pattern_byte, mask)| candidate_bytes[*pattern_index] & mask == *pattern_byte),
+                None => false,
+            };
+
+            if is_match {
+                run_length_encoder.encode_range(memory_alignment_size);
+            } else {
+                run_length_encoder.finalize_current_encode_with_padding(memory_alignment_size, data_type_size_padding);
+            }
+
+            scan_index += memory_alignment_size;
+        }
+
+        run_length_encoder.finalize_current_encode_with_padding(0, data_type_size_padding);
+        run_length_encoder.take_result_regions()
+    }
+}
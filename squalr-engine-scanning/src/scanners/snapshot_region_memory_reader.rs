@@ -4,6 +4,9 @@ use squalr_engine_memory::memory_reader::MemoryReader;
 use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
 use crate::scan_settings_config::ScanSettingsConfig;
 
+/// Gaps between filters up to this size are read along with them, since a separate read call costs more than the wasted bytes.
+const FILTER_READ_MERGE_GAP_BYTES: u64 = 4096;
+
 pub trait SnapshotRegionMemoryReader {
     fn read_all_memory(
         &mut self,
@@ -13,6 +16,16 @@ pub trait SnapshotRegionMemoryReader {
         &mut self,
         process_info: &OpenedProcessInfo,
     ) -> Result<(), String>;
+    fn read_filtered_memory_chunked(
+        &mut self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<u64, String>;
+}
+
+fn get_read_chunk_size() -> usize {
+    (ScanSettingsConfig::get_scan_buffer_kb() as usize)
+        .saturating_mul(1024)
+        .clamp(1024, 16 * 1024 * 1024)
 }
 
 impl SnapshotRegionMemoryReader for SnapshotRegion {
@@ -94,12 +107,7 @@ impl SnapshotRegionMemoryReader for SnapshotRegion {
         &mut self,
         process_info: &OpenedProcessInfo,
     ) -> Result<(), String> {
-        let mut chunk_size = (ScanSettingsConfig::get_scan_buffer_kb() as usize).saturating_mul(1024);
-        if chunk_size < 1024 {
-            chunk_size = 1024;
-        } else if chunk_size > 16 * 1024 * 1024 {
-            chunk_size = 16 * 1024 * 1024;
-        }
+        let chunk_size = get_read_chunk_size();
         let region_size = self.get_region_size() as usize;
         let base_address = self.get_base_address();
 
@@ -189,4 +197,124 @@ impl SnapshotRegionMemoryReader for SnapshotRegion {
 
         Ok(())
     }
+
+    /// Reads only the memory under this region's filters, updating the current and previous value arrays. The bytes are written at
+    /// their offsets within the region, such that the value arrays stay aligned with the region base address. Bytes outside of the
+    /// filters are left stale, which is safe since scans never look beyond their filters. Returns the number of bytes read.
+    fn read_filtered_memory_chunked(
+        &mut self,
+        process_info: &OpenedProcessInfo,
+    ) -> Result<u64, String> {
+        let region_size = self.get_region_size();
+        let read_ranges = self.get_filter_read_ranges(FILTER_READ_MERGE_GAP_BYTES);
+        let read_range_byte_count: u64 = read_ranges
+            .iter()
+            .map(|read_range| read_range.get_region_size())
+            .sum();
+
+        // Nothing would be saved by a partial read, so take the simpler path that also handles the initial read.
+        if read_range_byte_count >= region_size {
+            return self
+                .read_all_memory_chunked(process_info)
+                .map(|_| region_size);
+        }
+
+        let chunk_size = get_read_chunk_size() as u64;
+        let base_address = self.get_base_address();
+
+        std::mem::swap(&mut self.current_values, &mut self.previous_values);
+
+        // The recycled array is the right size unless this is the first read, in which case gaps are simply left zeroed.
+        self.current_values.resize(region_size as usize, 0);
+
+        let mut total_chunks = 0;
+        let mut read_failures = Vec::new();
+        let mut bytes_read = 0u64;
+
+        for read_range in read_ranges {
+            let mut chunk_address = read_range.get_base_address();
+            let end_address = read_range.get_end_address();
+
+            while chunk_address < end_address {
+                let chunk_length = chunk_size.min(end_address - chunk_address);
+                let start_offset = chunk_address.saturating_sub(base_address) as usize;
+                let chunk = &mut self.current_values[start_offset..start_offset + chunk_length as usize];
+
+                total_chunks += 1;
+
+                if MemoryReader::get_instance().read_bytes(process_info, chunk_address, chunk) {
+                    bytes_read += chunk_length;
+                } else {
+                    read_failures.push(chunk_address);
+                }
+
+                chunk_address += chunk_length;
+            }
+        }
+
+        let failure_count = read_failures.len();
+        self.page_boundary_tombstones.extend(read_failures);
+
+        if total_chunks > 0 && failure_count >= total_chunks {
+            return Err("Failed to read memory region".to_string());
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::SnapshotRegionMemoryReader;
+    use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+    use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+    use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+    use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+    use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+    use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+    use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
+
+    #[test]
+    fn partial_reads_keep_current_and_previous_values_aligned() {
+        let process_id = std::process::id();
+        let process_info = OpenedProcessInfo::new(process_id, "test-harness".to_string(), process_id as u64, Bitness::Bit64, None);
+        let mut memory: Vec<u8> = (0..0x10000).map(|index| (index % 251) as u8).collect();
+        let base_address = memory.as_ptr() as u64;
+        let mut snapshot_region = SnapshotRegion::new(NormalizedRegion::new(base_address, memory.len() as u64), vec![]);
+
+        snapshot_region.read_all_memory_chunked(&process_info).unwrap();
+
+        // Filter both region edges, plus a pair of filters close enough to be read together.
+        let filters = [(0x0, 0x10), (0x8000, 0x8), (0x8100, 0x8), (0xFFF0, 0x10)];
+
+        snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+            vec![
+                filters
+                    .iter()
+                    .map(|(offset, size)| SnapshotRegionFilter::new(base_address + offset, *size))
+                    .collect(),
+            ],
+            DataTypeRef::new(DataTypeU8::DATA_TYPE_ID),
+            MemoryAlignment::Alignment1,
+            1,
+        )]));
+
+        let original_memory = memory.clone();
+
+        memory.iter_mut().for_each(|byte| *byte = byte.wrapping_add(1));
+
+        let bytes_read = snapshot_region.read_filtered_memory_chunked(&process_info).unwrap();
+
+        assert_eq!(bytes_read, 0x10 + 0x108 + 0x10);
+
+        for (offset, size) in filters {
+            let range = offset as usize..(offset + size) as usize;
+
+            assert_eq!(snapshot_region.get_current_values()[range.clone()], memory[range.clone()]);
+            assert_eq!(snapshot_region.get_previous_values()[range.clone()], original_memory[range]);
+        }
+    }
 }
@@ -0,0 +1,271 @@
+use crate::scanners::element_scan_executor_task::ElementScanExecutorTask;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::results::snapshot_region_scan_results::SnapshotRegionScanResults;
+use squalr_engine_api::structures::scanning::comparisons::scan_function_scalar::ScanFunctionScalar;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter::SnapshotRegionFilter;
+use squalr_engine_api::structures::scanning::filters::snapshot_region_filter_collection::SnapshotRegionFilterCollection;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Instant;
+
+pub struct StrideScanExecutorTask {}
+
+const TASK_NAME: &str = "Stride Scan Executor";
+
+/// The most repeats counted per array. This bounds verification of values that fill large ranges of memory, ie zeroed pages.
+pub const MAXIMUM_STRIDE_REPEAT_COUNT: u64 = 65536;
+
+/// Implementation of a task that finds arrays of structs, where a field holds the same value at a fixed stride. Candidates are found
+/// with an element scan over the snapshot, after which only the first element of each sufficiently long run is kept.
+impl StrideScanExecutorTask {
+    pub fn start_task(
+        process_info: OpenedProcessInfo,
+        snapshot: Arc<RwLock<Snapshot>>,
+        element_scan_plan: ElementScanPlan,
+        stride: u64,
+        minimum_repeat_count: u64,
+        with_logging: bool,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            Self::scan_task(
+                &task_clone,
+                process_info,
+                snapshot,
+                element_scan_plan,
+                stride,
+                minimum_repeat_count,
+                with_logging,
+            );
+
+            task_clone.complete();
+        });
+
+        task
+    }
+
+    fn scan_task(
+        trackable_task: &Arc<TrackableTask>,
+        process_info: OpenedProcessInfo,
+        snapshot: Arc<RwLock<Snapshot>>,
+        element_scan_plan: ElementScanPlan,
+        stride: u64,
+        minimum_repeat_count: u64,
+        with_logging: bool,
+    ) {
+        let start_time = Instant::now();
+        let memory_alignment = element_scan_plan.get_memory_alignment();
+
+        // Stride scans compare a single value of a single type, so the first constraint decides what each repeat must match.
+        let (data_type_ref, unit_size_in_bytes, compare_func) = match element_scan_plan
+            .get_scan_constraints_by_data_type()
+            .iter()
+            .find_map(|(data_type_ref, scan_constraints)| {
+                let scan_constraint = scan_constraints.first()?;
+
+                match scan_constraint.get_scan_function_scalar() {
+                    Some(ScanFunctionScalar::Immediate(compare_func)) => {
+                        Some((data_type_ref.clone(), scan_constraint.get_unit_size_in_bytes(), compare_func.clone()))
+                    }
+                    _ => None,
+                }
+            }) {
+            Some(stride_scan_constraint) => stride_scan_constraint,
+            None => {
+                log::error!("Stride scans require an immediate comparison against a value.");
+                return;
+            }
+        };
+
+        // Remember which memory the element scan is about to compare, such that repeats within it that are not candidates are known
+        // to not match without reading them again. Only repeats outside of it, ie past the end of a region, are read from the process.
+        let scanned_ranges = match snapshot.read() {
+            Ok(snapshot_guard) => snapshot_guard.get_scanned_ranges(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+                return;
+            }
+        };
+
+        ElementScanExecutorTask::scan_task(trackable_task, process_info.clone(), snapshot.clone(), element_scan_plan, with_logging);
+
+        let cancellation_token = trackable_task.get_cancellation_token();
+
+        if cancellation_token.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let (candidate_addresses, scan_generation) = match snapshot.read() {
+            Ok(snapshot_guard) => (Self::collect_candidate_addresses(&snapshot_guard), snapshot_guard.get_scan_generation()),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+                return;
+            }
+        };
+
+        if with_logging {
+            log::info!("Verifying {} stride scan candidates...", candidate_addresses.len());
+        }
+
+        let is_scanned_address = |address: u64| {
+            let range_index = scanned_ranges.partition_point(|scanned_range| scanned_range.get_base_address() <= address);
+
+            address.is_multiple_of((memory_alignment as u64).max(1))
+                && range_index
+                    .checked_sub(1)
+                    .is_some_and(|range_index| address.saturating_add(unit_size_in_bytes) <= scanned_ranges[range_index].get_end_address())
+        };
+        let is_match = |address: u64| {
+            if candidate_addresses.binary_search(&address).is_ok() {
+                return true;
+            }
+
+            if is_scanned_address(address) {
+                return false;
+            }
+
+            let mut value_bytes = vec![0u8; unit_size_in_bytes as usize];
+
+            MemoryReader::get_instance().read_bytes(&process_info, address, &mut value_bytes) && compare_func(value_bytes.as_ptr())
+        };
+        let stride_repeat_counts: HashMap<u64, u64> = candidate_addresses
+            .par_iter()
+            .filter(|_candidate_address| !cancellation_token.load(Ordering::Relaxed))
+            .filter_map(|candidate_address| {
+                let repeat_count = count_stride_repeats(*candidate_address, stride, is_match)?;
+
+                (repeat_count >= minimum_repeat_count).then_some((*candidate_address, repeat_count))
+            })
+            .collect();
+
+        if cancellation_token.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let mut snapshot_guard = match snapshot.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot to finalize: {}", error);
+                return;
+            }
+        };
+
+        if snapshot_guard.get_scan_generation() != scan_generation {
+            log::warn!("Scan results changed while verifying the stride scan; discarding its results.");
+            return;
+        }
+
+        // Narrow each region down to the array base addresses within it.
+        let mut array_base_addresses: Vec<u64> = stride_repeat_counts.keys().copied().collect();
+
+        array_base_addresses.sort_unstable();
+
+        for snapshot_region in snapshot_guard.get_snapshot_regions_mut() {
+            let region_base_address = snapshot_region.get_base_address();
+            let region_end_address = snapshot_region.get_end_address();
+            let first_index = array_base_addresses.partition_point(|address| *address < region_base_address);
+            let end_index = array_base_addresses.partition_point(|address| *address < region_end_address);
+            let snapshot_region_filters = array_base_addresses[first_index..end_index]
+                .iter()
+                .map(|address| SnapshotRegionFilter::new(*address, unit_size_in_bytes))
+                .collect();
+
+            snapshot_region.set_scan_results(SnapshotRegionScanResults::new(vec![SnapshotRegionFilterCollection::new(
+                vec![snapshot_region_filters],
+                data_type_ref.clone(),
+                memory_alignment,
+                unit_size_in_bytes,
+            )]));
+        }
+
+        snapshot_guard.discard_empty_regions();
+        snapshot_guard.clear_data_type_overrides();
+        snapshot_guard.set_stride_repeat_counts(stride_repeat_counts);
+        snapshot_guard.advance_scan_generation();
+
+        if with_logging {
+            log::info!("Stride scan found {} arrays in: {:?}", array_base_addresses.len(), start_time.elapsed());
+        }
+    }
+
+    /// Gets the addresses of every scan result in the snapshot, sorted ascending.
+    fn collect_candidate_addresses(snapshot: &Snapshot) -> Vec<u64> {
+        let mut candidate_addresses = vec![];
+
+        for snapshot_region_filter_collection in snapshot
+            .get_snapshot_regions()
+            .iter()
+            .flat_map(|snapshot_region| snapshot_region.get_scan_results().get_filter_collections())
+        {
+            let memory_alignment = snapshot_region_filter_collection.get_memory_alignment();
+            let unit_size_in_bytes = snapshot_region_filter_collection.get_unit_size_in_bytes();
+
+            for snapshot_region_filter in snapshot_region_filter_collection.iter() {
+                let element_count = snapshot_region_filter.get_element_count(unit_size_in_bytes, memory_alignment);
+
+                candidate_addresses
+                    .extend((0..element_count).map(|element_index| snapshot_region_filter.get_base_address() + element_index * memory_alignment as u64));
+            }
+        }
+
+        candidate_addresses.sort_unstable();
+        candidate_addresses.dedup();
+        candidate_addresses
+    }
+}
+
+/// Counts how many consecutive elements, spaced by the stride, hold a matching value starting at the given address, including itself.
+/// Returns `None` if the element one stride before also matches, since the address is then within an array rather than its base.
+pub fn count_stride_repeats(
+    base_address: u64,
+    stride: u64,
+    is_match: impl Fn(u64) -> bool,
+) -> Option<u64> {
+    if base_address.checked_sub(stride).is_some_and(&is_match) {
+        return None;
+    }
+
+    let mut repeat_count = 1;
+
+    while repeat_count < MAXIMUM_STRIDE_REPEAT_COUNT {
+        match base_address.checked_add(repeat_count * stride) {
+            Some(address) if is_match(address) => repeat_count += 1,
+            _ => break,
+        }
+    }
+
+    Some(repeat_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAXIMUM_STRIDE_REPEAT_COUNT, count_stride_repeats};
+    use std::collections::HashSet;
+
+    #[test]
+    fn only_the_first_element_of_a_run_is_a_base() {
+        let matches: HashSet<u64> = [0x1000, 0x1150, 0x12A0, 0x13F0, 0x2000].into_iter().collect();
+        let is_match = |address| matches.contains(&address);
+
+        assert_eq!(count_stride_repeats(0x1000, 0x150, is_match), Some(4));
+        assert_eq!(count_stride_repeats(0x1150, 0x150, is_match), None);
+        assert_eq!(count_stride_repeats(0x13F0, 0x150, is_match), None);
+        assert_eq!(count_stride_repeats(0x2000, 0x150, is_match), Some(1));
+    }
+
+    #[test]
+    fn runs_stop_at_the_ends_of_the_address_space() {
+        assert_eq!(count_stride_repeats(0x10, 0x20, |_address| true), Some(MAXIMUM_STRIDE_REPEAT_COUNT));
+        assert_eq!(count_stride_repeats(u64::MAX - 0x10, 0x20, |address| address >= u64::MAX - 0x10), Some(1));
+    }
+}
@@ -10,7 +10,7 @@ use crate::scan_settings_config::ScanSettingsConfig;
 use squalr_engine_api::structures::settings::scan_thread_priority::ScanThreadPriority;
 use std::sync::Arc;
 use std::sync::RwLock;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::time::Instant;
 use std::time::Duration;
 
@@ -72,6 +72,7 @@ impl ValueCollectorTask {
 
         let start_time = Instant::now();
         let processed_region_count = Arc::new(AtomicUsize::new(0));
+        let read_byte_count = AtomicU64::new(0);
 
         if with_logging && total_region_count == 0 {
             log::warn!(
@@ -89,8 +90,12 @@ impl ValueCollectorTask {
 
             // Attempt to read new (or initial) memory values. Ignore failed regions, as these are generally just deallocated pages.
             // JIRA: We probably want some way of tombstoning deallocated pages.
-            if snapshot_region.read_all_memory_chunked(&process_info).is_err() {
-                snapshot_region.mark_unreadable();
+            // Once a scan has filtered the region, only the memory under the surviving filters is read.
+            match snapshot_region.read_filtered_memory_chunked(&process_info) {
+                Ok(bytes_read) => {
+                    read_byte_count.fetch_add(bytes_read, Ordering::Relaxed);
+                }
+                Err(_) => snapshot_region.mark_unreadable(),
             }
 
             // Report progress periodically (not every time for performance)
@@ -115,6 +120,7 @@ impl ValueCollectorTask {
             .filter(|region| region.get_region_size() == 0)
             .count();
         let final_byte_count: u64 = snapshot_regions.iter().map(|r| r.get_region_size()).sum();
+        let read_byte_count = read_byte_count.load(Ordering::Relaxed);
 
         // Write the regions back into the snapshot.
         {
@@ -129,6 +135,7 @@ impl ValueCollectorTask {
             };
 
             snapshot_guard.set_snapshot_regions(snapshot_regions);
+            snapshot_guard.set_last_read_byte_count(read_byte_count);
         }
 
         if with_logging {
@@ -137,7 +144,9 @@ impl ValueCollectorTask {
 
             log::info!("Values collected in: {:?}", duration);
             log::info!(
-                "{} bytes read ({})",
+                "{} bytes read ({}) from a snapshot of {} bytes ({})",
+                read_byte_count,
+                StorageSizeConversions::value_to_metric_size(read_byte_count as u128),
                 byte_count,
                 StorageSizeConversions::value_to_metric_size(byte_count as u128)
             );
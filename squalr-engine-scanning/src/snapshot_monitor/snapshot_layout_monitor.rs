@@ -0,0 +1,108 @@
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_api::structures::snapshots::snapshot_region_change::SnapshotRegionChange;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use std::sync::{Arc, RwLock};
+
+pub struct SnapshotLayoutMonitor;
+
+/// Detects when the memory backing a snapshot is freed or re-protected by the process between scans, such that results over that
+/// memory can be flagged as stale rather than shown with unreadable values.
+impl SnapshotLayoutMonitor {
+    /// Checks the memory layout under every scan result of the snapshot, marking any changed ranges as stale.
+    /// Returns the number of ranges that were newly marked.
+    pub fn check_snapshot(
+        process_info: &OpenedProcessInfo,
+        snapshot: &Arc<RwLock<Snapshot>>,
+    ) -> u64 {
+        let scanned_ranges = match snapshot.read() {
+            Ok(snapshot) => snapshot.get_scanned_ranges(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot to check its layout: {}", error);
+
+                return 0;
+            }
+        };
+
+        Self::check_ranges(process_info, snapshot, scanned_ranges)
+    }
+
+    /// Checks the memory layout under only the scanned ranges that contain any of the given addresses, ie the visible results.
+    pub fn check_snapshot_addresses(
+        process_info: &OpenedProcessInfo,
+        snapshot: &Arc<RwLock<Snapshot>>,
+        addresses: &[u64],
+    ) -> u64 {
+        let scanned_ranges = match snapshot.read() {
+            Ok(snapshot) => snapshot
+                .get_scanned_ranges()
+                .into_iter()
+                .filter(|scanned_range| {
+                    addresses
+                        .iter()
+                        .any(|address| *address >= scanned_range.get_base_address() && *address < scanned_range.get_end_address())
+                })
+                .collect(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot to check its layout: {}", error);
+
+                return 0;
+            }
+        };
+
+        Self::check_ranges(process_info, snapshot, scanned_ranges)
+    }
+
+    /// Re-queries the memory layout under the given ranges and returns any ranges whose backing memory changed.
+    pub fn find_region_changes(
+        process_info: &OpenedProcessInfo,
+        scanned_ranges: &[NormalizedRegion],
+    ) -> Vec<SnapshotRegionChange> {
+        let start_address = scanned_ranges.iter().map(|range| range.get_base_address()).min();
+        let end_address = scanned_ranges.iter().map(|range| range.get_end_address()).max();
+        let (Some(start_address), Some(end_address)) = (start_address, end_address) else {
+            return vec![];
+        };
+        let live_regions = MemoryQueryer::query_pages_by_address_range(process_info, start_address, end_address);
+        let mut live_matching_regions = MemoryQueryer::query_pages_by_address_range_from_settings(process_info, start_address, end_address);
+
+        // Snapshots fall back to relaxed protection filters when the settings match nothing, in which case every region would
+        // otherwise appear re-protected.
+        if live_matching_regions.is_empty() {
+            live_matching_regions = live_regions.clone();
+        }
+
+        SnapshotRegionChange::diff_regions(scanned_ranges, &live_regions, &live_matching_regions)
+    }
+
+    fn check_ranges(
+        process_info: &OpenedProcessInfo,
+        snapshot: &Arc<RwLock<Snapshot>>,
+        scanned_ranges: Vec<NormalizedRegion>,
+    ) -> u64 {
+        let region_changes = Self::find_region_changes(process_info, &scanned_ranges);
+
+        if region_changes.is_empty() {
+            return 0;
+        }
+
+        let newly_marked_count = match snapshot.write() {
+            Ok(mut snapshot) => snapshot.mark_stale_regions(region_changes),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot to mark stale regions: {}", error);
+
+                return 0;
+            }
+        };
+
+        if newly_marked_count > 0 {
+            log::warn!(
+                "{} snapshot range(s) were freed or re-protected since they were snapshotted. Results over them are marked stale until the snapshot is revalidated.",
+                newly_marked_count
+            );
+        }
+
+        newly_marked_count
+    }
+}
@@ -0,0 +1,2 @@
+pub mod write_sequence_memory_access;
+pub mod write_sequence_task;
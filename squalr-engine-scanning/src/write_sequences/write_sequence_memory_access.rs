@@ -0,0 +1,78 @@
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::Arc;
+
+/// The memory operations performed by a write sequence, abstracted such that the ordering of steps can be verified without a process.
+pub trait WriteSequenceMemoryAccess: Send + Sync {
+    /// Resolves an address relative to a module, or none if the module is not loaded.
+    fn resolve_address(
+        &self,
+        address: u64,
+        module_name: &str,
+    ) -> Option<u64>;
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool;
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool;
+}
+
+/// Accesses the memory of the opened process. Writes are recorded in the write ledger, such that they can be restored later.
+pub struct ProcessWriteSequenceMemoryAccess {
+    process_info: OpenedProcessInfo,
+    modules: Arc<Vec<NormalizedModule>>,
+}
+
+impl ProcessWriteSequenceMemoryAccess {
+    pub fn new(process_info: OpenedProcessInfo) -> Self {
+        let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+
+        Self { process_info, modules }
+    }
+}
+
+impl WriteSequenceMemoryAccess for ProcessWriteSequenceMemoryAccess {
+    fn resolve_address(
+        &self,
+        address: u64,
+        module_name: &str,
+    ) -> Option<u64> {
+        if module_name.is_empty() {
+            return Some(address);
+        }
+
+        match MemoryQueryer::get_instance().resolve_module(&self.modules, module_name) {
+            0 => None,
+            module_address => Some(module_address.saturating_add(address)),
+        }
+    }
+
+    fn read_bytes(
+        &self,
+        address: u64,
+        values: &mut [u8],
+    ) -> bool {
+        MemoryReader::get_instance().read_bytes(&self.process_info, address, values)
+    }
+
+    fn write_bytes(
+        &self,
+        address: u64,
+        values: &[u8],
+    ) -> bool {
+        MemoryWriter::write_bytes_with_ledger(&self.process_info, address, values)
+    }
+}
@@ -0,0 +1,554 @@
+use crate::write_sequences::write_sequence_memory_access::WriteSequenceMemoryAccess;
+use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::memory_write_sequence_step::MemoryWriteSequenceStep;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const TASK_NAME: &str = "Write Sequence";
+
+/// How often a delay checks whether the sequence was cancelled. Delays sleep toward a fixed deadline, so this does not add drift.
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Describes how far a write sequence got before it stopped.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WriteSequenceOutcome {
+    /// The number of steps that were run, including any that failed.
+    pub completed_step_count: usize,
+    pub failed_step_indices: Vec<usize>,
+    pub was_cancelled: bool,
+}
+
+pub struct WriteSequenceTask;
+
+/// Runs a write sequence on its own thread, such that delays are measured by the engine rather than by the round trip to the host.
+///
+/// Steps that touch memory share the freeze list lock with the freeze task, which holds that lock for the whole of each freeze pass.
+/// A write therefore takes the same lock, writes, and refreezes a frozen address at the written bytes before releasing it. The freeze
+/// task can then only run entirely before the write, which the write overwrites, or entirely after it, which writes the new value
+/// again. It can never restore the old value afterwards. The freeze task takes the opened process lock before the freeze list lock,
+/// so the sequence never holds the opened process lock at all, and never holds any lock while waiting on a delay.
+impl WriteSequenceTask {
+    pub fn start_task(
+        steps: Vec<MemoryWriteSequenceStep>,
+        continue_on_error: bool,
+        memory_access: Arc<dyn WriteSequenceMemoryAccess>,
+        freeze_list_registry: Arc<RwLock<FreezeListRegistry>>,
+        on_finished: impl FnOnce(&TrackableTask, WriteSequenceOutcome) + Send + 'static,
+    ) -> Arc<TrackableTask> {
+        let task = TrackableTask::create(TASK_NAME.to_string(), None);
+        let task_clone = task.clone();
+
+        thread::spawn(move || {
+            let outcome = Self::run_steps(&steps, continue_on_error, memory_access.as_ref(), &freeze_list_registry, &task_clone);
+
+            task_clone.complete();
+            on_finished(&task_clone, outcome);
+        });
+
+        task
+    }
+
+    /// Gets the indices of every step whose address cannot currently be resolved and read. A sequence should not be started unless
+    /// this is empty, such that it does not stop halfway through on an address that was already gone before it began.
+    pub fn find_unresolved_steps(
+        steps: &[MemoryWriteSequenceStep],
+        memory_access: &dyn WriteSequenceMemoryAccess,
+    ) -> Vec<usize> {
+        steps
+            .iter()
+            .enumerate()
+            .filter(|(_step_index, step)| {
+                let read_size = match step {
+                    MemoryWriteSequenceStep::Write { value_bytes, .. } => Some(value_bytes.len()),
+                    MemoryWriteSequenceStep::Delay { .. } => return false,
+                    MemoryWriteSequenceStep::ToggleFreeze { data_type_ref, .. } => Self::get_value_size(data_type_ref),
+                };
+                let resolved_address = step
+                    .get_target()
+                    .and_then(|(address, module_name)| memory_access.resolve_address(address, module_name));
+
+                match (resolved_address, read_size) {
+                    (Some(resolved_address), Some(read_size)) if read_size > 0 => !memory_access.read_bytes(resolved_address, &mut vec![0u8; read_size]),
+                    _ => true,
+                }
+            })
+            .map(|(step_index, _step)| step_index)
+            .collect()
+    }
+
+    /// Runs every step in order, reporting progress on the task after each. Stops at the first failed step unless told to continue.
+    pub fn run_steps(
+        steps: &[MemoryWriteSequenceStep],
+        continue_on_error: bool,
+        memory_access: &dyn WriteSequenceMemoryAccess,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+        task: &TrackableTask,
+    ) -> WriteSequenceOutcome {
+        let mut outcome = WriteSequenceOutcome::default();
+        let cancellation_token = task.get_cancellation_token();
+
+        for (step_index, step) in steps.iter().enumerate() {
+            if cancellation_token.load(Ordering::Acquire) {
+                outcome.was_cancelled = true;
+                break;
+            }
+
+            let is_success = match step {
+                MemoryWriteSequenceStep::Write {
+                    address,
+                    module_name,
+                    value_bytes,
+                } => Self::run_write(*address, module_name, value_bytes, memory_access, freeze_list_registry),
+                MemoryWriteSequenceStep::Delay { duration_ms } => {
+                    if !Self::run_delay(Duration::from_millis(*duration_ms), task) {
+                        outcome.was_cancelled = true;
+                        break;
+                    }
+
+                    true
+                }
+                MemoryWriteSequenceStep::ToggleFreeze {
+                    address,
+                    module_name,
+                    data_type_ref,
+                } => Self::run_toggle_freeze(*address, module_name, data_type_ref, memory_access, freeze_list_registry),
+            };
+
+            outcome.completed_step_count += 1;
+            task.set_progress(outcome.completed_step_count as f32 / steps.len() as f32 * 100.0);
+
+            if !is_success {
+                log::warn!("Write sequence step {} failed.", step_index + 1);
+                outcome.failed_step_indices.push(step_index);
+
+                if !continue_on_error {
+                    break;
+                }
+            }
+        }
+
+        outcome
+    }
+
+    fn run_write(
+        address: u64,
+        module_name: &str,
+        value_bytes: &[u8],
+        memory_access: &dyn WriteSequenceMemoryAccess,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+    ) -> bool {
+        let resolved_address = match memory_access.resolve_address(address, module_name) {
+            Some(resolved_address) => resolved_address,
+            None => return false,
+        };
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                return false;
+            }
+        };
+        let pointer = Pointer::new(address, vec![], module_name.to_string());
+
+        // Refreeze at the new value before releasing the lock, otherwise the next freeze pass would immediately undo this write.
+        freeze_list_registry_guard.write_address(pointer, value_bytes, |value_bytes| memory_access.write_bytes(resolved_address, value_bytes))
+    }
+
+    fn run_toggle_freeze(
+        address: u64,
+        module_name: &str,
+        data_type_ref: &DataTypeRef,
+        memory_access: &dyn WriteSequenceMemoryAccess,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+    ) -> bool {
+        let mut freeze_list_registry_guard = match freeze_list_registry.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                return false;
+            }
+        };
+        let pointer = Pointer::new(address, vec![], module_name.to_string());
+
+        if freeze_list_registry_guard.is_address_frozen(&pointer) {
+            freeze_list_registry_guard.set_address_unfrozen(&pointer);
+
+            return true;
+        }
+
+        let (resolved_address, value_size) = match (memory_access.resolve_address(address, module_name), Self::get_value_size(data_type_ref)) {
+            (Some(resolved_address), Some(value_size)) => (resolved_address, value_size),
+            _ => return false,
+        };
+        let mut value_bytes = vec![0u8; value_size];
+
+        if !memory_access.read_bytes(resolved_address, &mut value_bytes) {
+            return false;
+        }
+
+        freeze_list_registry_guard.set_address_frozen(pointer, value_bytes);
+
+        true
+    }
+
+    /// Waits for the given duration, returning false if the sequence was cancelled in the meantime.
+    fn run_delay(
+        duration: Duration,
+        task: &TrackableTask,
+    ) -> bool {
+        let deadline = Instant::now() + duration;
+        let cancellation_token = task.get_cancellation_token();
+
+        loop {
+            if cancellation_token.load(Ordering::Acquire) {
+                return false;
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            if remaining.is_zero() {
+                return true;
+            }
+
+            thread::sleep(remaining.min(CANCELLATION_POLL_INTERVAL));
+        }
+    }
+
+    fn get_value_size(data_type_ref: &DataTypeRef) -> Option<usize> {
+        SymbolRegistry::get_instance()
+            .get_default_value(data_type_ref)
+            .map(|data_value| data_value.get_value_bytes().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WriteSequenceOutcome, WriteSequenceTask};
+    use crate::freeze_task::snapshot_scan_result_freeze_task::SnapshotScanResultFreezeTask;
+    use crate::write_sequences::write_sequence_memory_access::WriteSequenceMemoryAccess;
+    use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::memory::memory_write_sequence_step::MemoryWriteSequenceStep;
+    use squalr_engine_api::structures::memory::pointer::Pointer;
+    use squalr_engine_api::structures::tasks::trackable_task::TrackableTask;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex, RwLock, mpsc};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    const MODULE_NAME: &str = "game.exe";
+    const MODULE_BASE: u64 = 0x40_0000;
+
+    /// A fake process with a single loaded module, where writes to unmapped addresses fail.
+    #[derive(Default)]
+    struct FakeMemoryAccess {
+        memory: Mutex<HashMap<u64, u8>>,
+        unwritable_addresses: Vec<u64>,
+    }
+
+    impl FakeMemoryAccess {
+        fn with_u32(
+            self,
+            address: u64,
+            value: u32,
+        ) -> Self {
+            self.write_bytes(MODULE_BASE + address, &value.to_le_bytes());
+            self
+        }
+
+        fn read_u32(
+            &self,
+            address: u64,
+        ) -> u32 {
+            let mut value_bytes = [0u8; 4];
+
+            self.read_bytes(MODULE_BASE + address, &mut value_bytes);
+
+            u32::from_le_bytes(value_bytes)
+        }
+    }
+
+    impl WriteSequenceMemoryAccess for FakeMemoryAccess {
+        fn resolve_address(
+            &self,
+            address: u64,
+            module_name: &str,
+        ) -> Option<u64> {
+            match module_name {
+                "" => Some(address),
+                MODULE_NAME => Some(MODULE_BASE + address),
+                _ => None,
+            }
+        }
+
+        fn read_bytes(
+            &self,
+            address: u64,
+            values: &mut [u8],
+        ) -> bool {
+            let memory = self.memory.lock().unwrap();
+
+            for (offset, value) in values.iter_mut().enumerate() {
+                match memory.get(&(address + offset as u64)) {
+                    Some(byte) => *value = *byte,
+                    None => return false,
+                }
+            }
+
+            true
+        }
+
+        fn write_bytes(
+            &self,
+            address: u64,
+            values: &[u8],
+        ) -> bool {
+            if self.unwritable_addresses.contains(&address) {
+                return false;
+            }
+
+            let mut memory = self.memory.lock().unwrap();
+
+            for (offset, value) in values.iter().enumerate() {
+                memory.insert(address + offset as u64, *value);
+            }
+
+            true
+        }
+    }
+
+    fn create_write_step(
+        address: u64,
+        value: u32,
+    ) -> MemoryWriteSequenceStep {
+        MemoryWriteSequenceStep::Write {
+            address,
+            module_name: MODULE_NAME.to_string(),
+            value_bytes: value.to_le_bytes().to_vec(),
+        }
+    }
+
+    fn create_toggle_freeze_step(address: u64) -> MemoryWriteSequenceStep {
+        MemoryWriteSequenceStep::ToggleFreeze {
+            address,
+            module_name: MODULE_NAME.to_string(),
+            data_type_ref: DataTypeRef::new(DataTypeU32::get_data_type_id()),
+        }
+    }
+
+    fn run(
+        steps: &[MemoryWriteSequenceStep],
+        continue_on_error: bool,
+        memory_access: &FakeMemoryAccess,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+    ) -> WriteSequenceOutcome {
+        let task = TrackableTask::create("Test".to_string(), None);
+
+        WriteSequenceTask::run_steps(steps, continue_on_error, memory_access, freeze_list_registry, &task)
+    }
+
+    /// Performs one pass of the freeze task against the fake process, taking the freeze list lock the same way.
+    fn run_freeze_pass(
+        memory_access: &FakeMemoryAccess,
+        freeze_list_registry: &RwLock<FreezeListRegistry>,
+    ) {
+        let freeze_list_registry_guard = freeze_list_registry.read().unwrap();
+
+        SnapshotScanResultFreezeTask::run_freeze_pass(&freeze_list_registry_guard, |pointer, value_bytes| {
+            if let Some(address) = memory_access.resolve_address(pointer.get_address(), pointer.get_module_name()) {
+                memory_access.write_bytes(address, value_bytes);
+            }
+        });
+    }
+
+    #[test]
+    fn writes_to_frozen_entries_refreeze_them_at_the_written_value() {
+        let memory_access = FakeMemoryAccess::default().with_u32(0x10, 100);
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let pointer = Pointer::new(0x10, vec![], MODULE_NAME.to_string());
+
+        freeze_list_registry
+            .write()
+            .unwrap()
+            .set_address_frozen(pointer.clone(), 100u32.to_le_bytes().to_vec());
+
+        let outcome = run(&[create_write_step(0x10, 250)], false, &memory_access, &freeze_list_registry);
+
+        assert!(outcome.failed_step_indices.is_empty());
+
+        // The next freeze pass keeps the written value, rather than reverting to the value that was frozen before.
+        run_freeze_pass(&memory_access, &freeze_list_registry);
+
+        assert_eq!(memory_access.read_u32(0x10), 250);
+        assert_eq!(
+            freeze_list_registry
+                .read()
+                .unwrap()
+                .get_address_frozen_bytes(&pointer),
+            Some(&250u32.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn toggling_freeze_captures_the_current_value_and_then_releases_it() {
+        let memory_access = FakeMemoryAccess::default().with_u32(0x20, 7);
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let pointer = Pointer::new(0x20, vec![], MODULE_NAME.to_string());
+        let steps = [
+            create_write_step(0x20, 8),
+            create_toggle_freeze_step(0x20),
+            create_write_step(0x20, 9),
+        ];
+
+        run(&steps, false, &memory_access, &freeze_list_registry);
+
+        assert_eq!(
+            freeze_list_registry
+                .read()
+                .unwrap()
+                .get_address_frozen_bytes(&pointer),
+            Some(&9u32.to_le_bytes().to_vec())
+        );
+
+        run(&[create_toggle_freeze_step(0x20)], false, &memory_access, &freeze_list_registry);
+
+        assert!(!freeze_list_registry.read().unwrap().is_address_frozen(&pointer));
+    }
+
+    #[test]
+    fn failed_writes_abort_unless_continuing_on_error() {
+        let freeze_list_registry = RwLock::new(FreezeListRegistry::new());
+        let steps = [
+            create_write_step(0x30, 1),
+            create_write_step(0x34, 2),
+            create_write_step(0x38, 3),
+        ];
+        let create_memory_access = || FakeMemoryAccess {
+            unwritable_addresses: vec![MODULE_BASE + 0x34],
+            ..FakeMemoryAccess::default()
+        };
+
+        let memory_access = create_memory_access();
+        let outcome = run(&steps, false, &memory_access, &freeze_list_registry);
+
+        assert_eq!(
+            outcome,
+            WriteSequenceOutcome {
+                completed_step_count: 2,
+                failed_step_indices: vec![1],
+                was_cancelled: false,
+            }
+        );
+        assert_eq!(memory_access.read_u32(0x38), 0);
+
+        let memory_access = create_memory_access();
+        let outcome = run(&steps, true, &memory_access, &freeze_list_registry);
+
+        assert_eq!(outcome.completed_step_count, 3);
+        assert_eq!(outcome.failed_step_indices, vec![1]);
+        assert_eq!(memory_access.read_u32(0x38), 3);
+    }
+
+    #[test]
+    fn unresolved_steps_are_found_before_starting() {
+        let memory_access = FakeMemoryAccess::default().with_u32(0x40, 1);
+        let steps = [
+            create_write_step(0x40, 2),
+            MemoryWriteSequenceStep::Delay { duration_ms: 5 },
+            MemoryWriteSequenceStep::Write {
+                address: 0x40,
+                module_name: "unloaded.dll".to_string(),
+                value_bytes: vec![0],
+            },
+            create_toggle_freeze_step(0x9000),
+        ];
+
+        assert_eq!(WriteSequenceTask::find_unresolved_steps(&steps, &memory_access), vec![2, 3]);
+    }
+
+    #[test]
+    fn cancelling_interrupts_a_delay() {
+        let memory_access = Arc::new(FakeMemoryAccess::default());
+        let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let (outcome_sender, outcome_receiver) = mpsc::channel();
+        let task = WriteSequenceTask::start_task(
+            vec![
+                MemoryWriteSequenceStep::Delay { duration_ms: 60_000 },
+                create_write_step(0x50, 1),
+            ],
+            false,
+            memory_access.clone(),
+            freeze_list_registry,
+            move |_task, outcome| outcome_sender.send(outcome).unwrap(),
+        );
+        let cancel_time = Instant::now();
+
+        task.cancel();
+
+        let outcome = outcome_receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        assert!(cancel_time.elapsed() < Duration::from_secs(5));
+        assert!(outcome.was_cancelled);
+        assert_eq!(outcome.completed_step_count, 0);
+        assert_eq!(memory_access.read_u32(0x50), 0);
+    }
+
+    #[test]
+    fn sequences_and_the_freeze_loop_never_deadlock_on_a_shared_entry() {
+        let memory_access = Arc::new(FakeMemoryAccess::default().with_u32(0x60, 0));
+        let freeze_list_registry = Arc::new(RwLock::new(FreezeListRegistry::new()));
+        let is_freezing = Arc::new(AtomicBool::new(true));
+        let steps: Vec<MemoryWriteSequenceStep> = (0..=200)
+            .flat_map(|index| {
+                [
+                    create_toggle_freeze_step(0x60),
+                    create_write_step(0x60, index),
+                    MemoryWriteSequenceStep::Delay { duration_ms: 0 },
+                ]
+            })
+            .collect();
+
+        // Stand in for the freeze task, hammering the same entry for as long as the sequence runs.
+        let freeze_thread = {
+            let memory_access = memory_access.clone();
+            let freeze_list_registry = freeze_list_registry.clone();
+            let is_freezing = is_freezing.clone();
+
+            thread::spawn(move || {
+                while is_freezing.load(Ordering::Acquire) {
+                    run_freeze_pass(&memory_access, &freeze_list_registry);
+                    thread::yield_now();
+                }
+            })
+        };
+
+        let (outcome_sender, outcome_receiver) = mpsc::channel();
+        let _task = WriteSequenceTask::start_task(steps, false, memory_access.clone(), freeze_list_registry.clone(), move |_task, outcome| {
+            outcome_sender.send(outcome).unwrap()
+        });
+        let outcome = outcome_receiver.recv_timeout(Duration::from_secs(10));
+
+        is_freezing.store(false, Ordering::Release);
+        freeze_thread.join().unwrap();
+
+        let outcome = outcome.expect("The write sequence deadlocked with the freeze loop.");
+
+        assert_eq!(outcome.completed_step_count, 603);
+        assert!(outcome.failed_step_indices.is_empty());
+
+        // The last toggle froze the entry, so the final write refroze it at the final value.
+        run_freeze_pass(&memory_access, &freeze_list_registry);
+
+        assert_eq!(memory_access.read_u32(0x60), 200);
+    }
+}
@@ -45,6 +45,8 @@ fn main() {
             process_id: Some(pid),
             search_name: None,
             match_case: false,
+            read_only: false,
+            takeover: false,
         };
         req.send(&engine_unprivileged_state, move |resp| {
             tx.send(resp.opened_process_info.is_some()).ok();
@@ -93,6 +95,7 @@ fn main() {
     let req = ElementScanRequest {
         scan_constraints: vec![constraint],
         data_type_refs: vec![DataTypeRef::new(DataTypeI32::get_data_type_id())],
+        is_unknown_initial_scan: false,
     };
     req.send(&engine_unprivileged_state, move |resp| {
         tx.send(resp.trackable_task_handle.is_some()).ok();
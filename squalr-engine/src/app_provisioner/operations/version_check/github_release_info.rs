@@ -5,6 +5,7 @@ pub struct GitHubReleaseInfo {
     pub tag_name: String,
     pub name: Option<String>,
     pub body: Option<String>,
+    pub html_url: Option<String>,
     pub draft: Option<bool>,
     pub prerelease: Option<bool>,
     pub created_at: Option<String>,
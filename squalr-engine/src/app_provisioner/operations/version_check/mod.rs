@@ -1,3 +1,6 @@
 pub mod github_release_info;
+pub mod release_version;
+pub mod update_check_result;
+pub mod update_checker;
 pub mod version_checker_status;
 pub mod version_checker_task;
@@ -0,0 +1,70 @@
+use anyhow::{Context, Result};
+use semver::Version;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Parses and compares release versions. Release tags are semver with an optional `v` prefix, ie `v1.2.0` or `v1.3.0-beta.2`.
+pub struct ReleaseVersion {}
+
+impl ReleaseVersion {
+    /// Gets the version this build of Squalr was compiled as.
+    pub fn get_current_version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    pub fn parse(version_text: &str) -> Result<Version> {
+        let version_text = version_text.trim();
+        let version_text = version_text
+            .strip_prefix('v')
+            .or_else(|| version_text.strip_prefix('V'))
+            .unwrap_or(version_text);
+
+        Version::from_str(version_text).with_context(|| format!("Invalid release version: {}", version_text))
+    }
+
+    /// Gets whether the release tag names a newer version than the current one. Pre-releases order before the release they
+    /// precede, so `1.3.0-beta` is newer than `1.2.0` but older than `1.3.0`. Build metadata is ignored, as semver specifies.
+    pub fn is_newer(
+        release_tag: &str,
+        current_version: &str,
+    ) -> Result<bool> {
+        let release_version = Self::parse(release_tag)?;
+        let current_version = Self::parse(current_version)?;
+
+        Ok(release_version.cmp_precedence(&current_version) == Ordering::Greater)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReleaseVersion;
+
+    #[test]
+    fn tags_parse_with_or_without_a_prefix() {
+        assert_eq!(ReleaseVersion::parse("v1.2.3").unwrap(), ReleaseVersion::parse("1.2.3").unwrap());
+        assert_eq!(ReleaseVersion::parse(" V0.4.0 ").unwrap().minor, 4);
+        assert!(ReleaseVersion::parse("latest").is_err());
+    }
+
+    #[test]
+    fn newer_releases_are_detected() {
+        assert!(ReleaseVersion::is_newer("v1.2.1", "1.2.0").unwrap());
+        assert!(ReleaseVersion::is_newer("v2.0.0", "1.9.9").unwrap());
+        assert!(!ReleaseVersion::is_newer("v1.2.0", "1.2.0").unwrap());
+        assert!(!ReleaseVersion::is_newer("v1.1.9", "1.2.0").unwrap());
+    }
+
+    #[test]
+    fn pre_releases_order_before_their_release() {
+        assert!(ReleaseVersion::is_newer("v1.3.0-beta.1", "1.2.0").unwrap());
+        assert!(!ReleaseVersion::is_newer("v1.3.0-beta.1", "1.3.0").unwrap());
+        assert!(ReleaseVersion::is_newer("v1.3.0", "1.3.0-beta.1").unwrap());
+        assert!(ReleaseVersion::is_newer("v1.3.0-beta.2", "1.3.0-beta.1").unwrap());
+        assert!(ReleaseVersion::is_newer("v1.3.0-rc.1", "1.3.0-beta.9").unwrap());
+    }
+
+    #[test]
+    fn build_metadata_is_ignored() {
+        assert!(!ReleaseVersion::is_newer("v1.2.0+linux", "1.2.0").unwrap());
+    }
+}
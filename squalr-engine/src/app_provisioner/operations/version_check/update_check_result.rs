@@ -0,0 +1,8 @@
+use crate::app_provisioner::operations::version_check::github_release_info::GitHubReleaseInfo;
+
+/// The outcome of comparing the latest published release against the running version.
+#[derive(Clone)]
+pub enum UpdateCheckResult {
+    UpToDate,
+    UpdateAvailable(GitHubReleaseInfo),
+}
@@ -0,0 +1,181 @@
+use crate::app_provisioner::app_provisioner_config::AppProvisionerConfig;
+use crate::app_provisioner::operations::version_check::github_release_info::GitHubReleaseInfo;
+use crate::app_provisioner::operations::version_check::release_version::ReleaseVersion;
+use crate::app_provisioner::operations::version_check::update_check_result::UpdateCheckResult;
+use anyhow::{Context, Result};
+use std::time::Duration;
+use ureq::{
+    config::Config,
+    tls::{TlsConfig, TlsProvider},
+};
+
+/// Fetches the raw JSON describing the latest published release.
+pub trait ReleaseSource {
+    fn fetch_latest_release_json(&self) -> Result<String>;
+}
+
+/// Fetches the latest release from the GitHub releases API, giving up after a short timeout so that being offline is quiet.
+pub struct GitHubReleaseSource {
+    timeout: Duration,
+}
+
+impl GitHubReleaseSource {
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+}
+
+impl Default for GitHubReleaseSource {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_TIMEOUT)
+    }
+}
+
+impl ReleaseSource for GitHubReleaseSource {
+    fn fetch_latest_release_json(&self) -> Result<String> {
+        let tls_config = TlsConfig::builder().provider(TlsProvider::NativeTls).build();
+        let config = Config::builder()
+            .tls_config(tls_config)
+            .timeout_global(Some(self.timeout))
+            .build();
+        let agent = config.new_agent();
+        let response = agent
+            .get(AppProvisionerConfig::get_latest_version_url())
+            .header("User-Agent", "squalr-rust-updater")
+            .header("Accept", "application/vnd.github+json")
+            .call()
+            .context("Failed to send GitHub latest release request")?;
+
+        response
+            .into_body()
+            .read_to_string()
+            .context("Failed to read GitHub release response body")
+    }
+}
+
+/// Checks whether a newer release than the running version has been published. This only reports the release, and never downloads it.
+pub struct UpdateChecker {}
+
+impl UpdateChecker {
+    pub fn check(
+        release_source: &dyn ReleaseSource,
+        current_version: &str,
+    ) -> Result<UpdateCheckResult> {
+        let release_json = release_source.fetch_latest_release_json()?;
+
+        Self::evaluate_release_json(&release_json, current_version)
+    }
+
+    /// Compares a GitHub release response against the current version. Drafts are never offered, since they are not published yet.
+    pub fn evaluate_release_json(
+        release_json: &str,
+        current_version: &str,
+    ) -> Result<UpdateCheckResult> {
+        let release: GitHubReleaseInfo = serde_json::from_str(release_json).context("Failed to parse GitHub release JSON")?;
+
+        if release.draft.unwrap_or(false) {
+            return Ok(UpdateCheckResult::UpToDate);
+        }
+
+        if ReleaseVersion::is_newer(&release.tag_name, current_version)? {
+            Ok(UpdateCheckResult::UpdateAvailable(release))
+        } else {
+            Ok(UpdateCheckResult::UpToDate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReleaseSource, UpdateChecker};
+    use crate::app_provisioner::operations::version_check::update_check_result::UpdateCheckResult;
+    use anyhow::{Result, anyhow};
+
+    const RELEASE_JSON: &str = r###"{
+        "tag_name": "v1.4.0",
+        "name": "Squalr 1.4.0",
+        "body": "## Changes\n- Faster scans",
+        "html_url": "https://github.com/zcanann/Squalr-Rust/releases/tag/v1.4.0",
+        "draft": false,
+        "prerelease": false,
+        "published_at": "2026-01-01T00:00:00Z",
+        "assets": [{ "name": "squalr.zip", "browser_download_url": "https://example.com/squalr.zip" }],
+        "author": { "login": "zcanann" }
+    }"###;
+
+    struct CannedReleaseSource {
+        release_json: Option<&'static str>,
+    }
+
+    impl ReleaseSource for CannedReleaseSource {
+        fn fetch_latest_release_json(&self) -> Result<String> {
+            self.release_json
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Network unreachable"))
+        }
+    }
+
+    #[test]
+    fn newer_releases_are_offered_with_their_notes() {
+        let release_source = CannedReleaseSource {
+            release_json: Some(RELEASE_JSON),
+        };
+
+        match UpdateChecker::check(&release_source, "1.3.2").unwrap() {
+            UpdateCheckResult::UpdateAvailable(release) => {
+                assert_eq!(release.tag_name, "v1.4.0");
+                assert_eq!(release.body.as_deref(), Some("## Changes\n- Faster scans"));
+                assert_eq!(release.html_url.as_deref(), Some("https://github.com/zcanann/Squalr-Rust/releases/tag/v1.4.0"));
+            }
+            UpdateCheckResult::UpToDate => panic!("Expected an update to be available."),
+        }
+    }
+
+    #[test]
+    fn current_and_older_releases_are_up_to_date() {
+        let release_source = CannedReleaseSource {
+            release_json: Some(RELEASE_JSON),
+        };
+
+        assert!(matches!(UpdateChecker::check(&release_source, "1.4.0").unwrap(), UpdateCheckResult::UpToDate));
+        assert!(matches!(
+            UpdateChecker::check(&release_source, "1.5.0-beta.1").unwrap(),
+            UpdateCheckResult::UpToDate
+        ));
+    }
+
+    #[test]
+    fn pre_release_builds_are_offered_the_final_release() {
+        let release_source = CannedReleaseSource {
+            release_json: Some(RELEASE_JSON),
+        };
+
+        assert!(matches!(
+            UpdateChecker::check(&release_source, "1.4.0-rc.2").unwrap(),
+            UpdateCheckResult::UpdateAvailable(_)
+        ));
+    }
+
+    #[test]
+    fn drafts_are_never_offered() {
+        let draft_json = RELEASE_JSON.replace("\"draft\": false", "\"draft\": true");
+
+        assert!(matches!(
+            UpdateChecker::evaluate_release_json(&draft_json, "1.0.0").unwrap(),
+            UpdateCheckResult::UpToDate
+        ));
+    }
+
+    #[test]
+    fn offline_and_malformed_responses_are_errors() {
+        let offline_source = CannedReleaseSource { release_json: None };
+        let malformed_source = CannedReleaseSource {
+            release_json: Some("{ \"message\": \"API rate limit exceeded\" }"),
+        };
+
+        assert!(UpdateChecker::check(&offline_source, "1.0.0").is_err());
+        assert!(UpdateChecker::check(&malformed_source, "1.0.0").is_err());
+    }
+}
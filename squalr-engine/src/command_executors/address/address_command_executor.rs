@@ -0,0 +1,24 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    address::address_command::AddressCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for AddressCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            AddressCommand::Resolve { address_resolve_request } => address_resolve_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod address_command_executor;
+pub mod resolve;
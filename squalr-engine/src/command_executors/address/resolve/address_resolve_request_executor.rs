@@ -0,0 +1,127 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::address::resolve::address_resolve_request::AddressResolveRequest;
+use squalr_engine_api::commands::address::resolve::address_resolve_response::AddressResolveResponse;
+use squalr_engine_api::structures::expressions::address_expression::AddressExpression;
+use squalr_engine_api::structures::expressions::address_resolve_error::AddressResolveError;
+use squalr_engine_api::structures::memory::address_layout::AddressLayout;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::memory::resolved_address::AddressProtection;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_memory::memory_queryer::memory_protection_enum::MemoryProtectionEnum;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_type_enum::MemoryTypeEnum;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_queryer::region_bounds_handling::RegionBoundsHandling;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for AddressResolveRequest {
+    type ResponseType = AddressResolveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        // Parse first, such that syntax errors are reported even without an opened process.
+        let address_expression = match AddressExpression::parse(&self.expression) {
+            Ok(address_expression) => address_expression,
+            Err(error) => return AddressResolveResponse { resolved_address: Err(error) },
+        };
+
+        let Some(opened_process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return AddressResolveResponse {
+                resolved_address: Err(AddressResolveError::NoProcess),
+            };
+        };
+
+        let address_layout = OpenedProcessAddressLayout {
+            modules: ProcessQueryCache::get_instance().get_modules(&opened_process_info),
+            opened_process_info,
+        };
+
+        AddressResolveResponse {
+            resolved_address: address_expression.resolve(&address_layout),
+        }
+    }
+}
+
+/// The modules and memory of the opened process, with protection queried live for the resolved address, and pointers read live
+/// for each dereference.
+struct OpenedProcessAddressLayout {
+    opened_process_info: OpenedProcessInfo,
+    modules: Arc<Vec<NormalizedModule>>,
+}
+
+impl OpenedProcessAddressLayout {
+    fn has_page_with_protection(
+        &self,
+        address: u64,
+        required_protection: MemoryProtectionEnum,
+    ) -> bool {
+        // Use an inclusive 1-byte range. `get_virtual_pages` treats `start >= end` as empty.
+        !MemoryQueryer::get_instance()
+            .get_virtual_pages(
+                &self.opened_process_info,
+                required_protection,
+                MemoryProtectionEnum::NONE,
+                MemoryTypeEnum::PRIVATE | MemoryTypeEnum::IMAGE | MemoryTypeEnum::MAPPED,
+                address,
+                address.saturating_add(1),
+                RegionBoundsHandling::Include,
+            )
+            .is_empty()
+    }
+}
+
+impl AddressLayout for OpenedProcessAddressLayout {
+    fn get_modules(&self) -> &[NormalizedModule] {
+        &self.modules
+    }
+
+    fn get_protection(
+        &self,
+        address: u64,
+    ) -> Option<AddressProtection> {
+        if !self.has_page_with_protection(address, MemoryProtectionEnum::NONE) {
+            return None;
+        }
+
+        // Not every platform can filter pages by read access, so readability comes from actually reading the address.
+        Some(AddressProtection {
+            is_readable: self.is_readable(address),
+            is_writable: self.has_page_with_protection(address, MemoryProtectionEnum::WRITE),
+            is_executable: self.has_page_with_protection(address, MemoryProtectionEnum::EXECUTE),
+        })
+    }
+
+    fn is_readable(
+        &self,
+        address: u64,
+    ) -> bool {
+        let mut byte = [0u8; 1];
+
+        MemoryReader::get_instance().read_bytes(&self.opened_process_info, address, &mut byte)
+    }
+
+    fn read_pointer(
+        &self,
+        address: u64,
+    ) -> Option<u64> {
+        let mut pointer_bytes = [0u8; 8];
+        let pointer_size = match self.opened_process_info.get_bitness() {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        };
+
+        MemoryReader::get_instance()
+            .read_bytes(&self.opened_process_info, address, &mut pointer_bytes[..pointer_size])
+            .then(|| u64::from_le_bytes(pointer_bytes))
+    }
+}
@@ -0,0 +1 @@
+pub mod address_resolve_request_executor;
@@ -0,0 +1,30 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    breakpoints::breakpoints_command::BreakpointsCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for BreakpointsCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            BreakpointsCommand::Set { breakpoint_set_request } => breakpoint_set_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            BreakpointsCommand::Remove { breakpoint_remove_request } => breakpoint_remove_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            BreakpointsCommand::List { breakpoints_list_request } => breakpoints_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::breakpoints::list::breakpoints_list_request::BreakpointsListRequest;
+use squalr_engine_api::commands::breakpoints::list::breakpoints_list_response::BreakpointsListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for BreakpointsListRequest {
+    type ResponseType = BreakpointsListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_id = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|process_info| process_info.get_process_id_raw());
+
+        match engine_privileged_state.get_breakpoint_list_registry().write() {
+            Ok(mut breakpoint_list_registry) => {
+                // Breakpoints set in a process that has since been closed are discarded, rather than listed against the new one.
+                breakpoint_list_registry.bind_process(process_id);
+
+                BreakpointsListResponse {
+                    breakpoint_addresses: breakpoint_list_registry.get_breakpoint_addresses(),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on BreakpointListRegistry: {}", error);
+                BreakpointsListResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod breakpoints_list_request_executor;
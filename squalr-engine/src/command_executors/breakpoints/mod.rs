@@ -0,0 +1,4 @@
+pub mod breakpoints_command_executor;
+pub mod list;
+pub mod remove;
+pub mod set;
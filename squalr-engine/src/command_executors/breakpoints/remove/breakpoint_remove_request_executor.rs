@@ -0,0 +1,73 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::breakpoints::remove::breakpoint_remove_request::BreakpointRemoveRequest;
+use squalr_engine_api::commands::breakpoints::remove::breakpoint_remove_response::BreakpointRemoveResponse;
+use squalr_engine_api::events::breakpoints::changed::breakpoints_changed_event::BreakpointsChangedEvent;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use squalr_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for BreakpointRemoveRequest {
+    type ResponseType = BreakpointRemoveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                return BreakpointRemoveResponse {
+                    breakpoint_addresses: vec![],
+                    error_message: Some("No process is opened to remove a breakpoint from.".to_string()),
+                };
+            }
+        };
+        let breakpoint_list_registry = engine_privileged_state.get_breakpoint_list_registry();
+        let mut breakpoint_list_registry = match breakpoint_list_registry.write() {
+            Ok(breakpoint_list_registry) => breakpoint_list_registry,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on BreakpointListRegistry: {}", error);
+
+                return BreakpointRemoveResponse {
+                    breakpoint_addresses: vec![],
+                    error_message: Some(error.to_string()),
+                };
+            }
+        };
+
+        breakpoint_list_registry.bind_process(Some(process_info.get_process_id_raw()));
+
+        let error_message = match breakpoint_list_registry.remove_breakpoint(self.address) {
+            None => Some(format!("No breakpoint is set at {:X}.", self.address)),
+            Some(original_byte) => {
+                // The breakpoint stays recorded if the original byte cannot be written back, such that removing it can be retried.
+                if process_info.get_is_read_only() || !MemoryWriter::get_instance().write_bytes(&process_info, self.address, &[original_byte]) {
+                    breakpoint_list_registry.add_breakpoint(self.address, original_byte);
+
+                    Some(format!("Could not restore the original byte at {:X}.", self.address))
+                } else {
+                    None
+                }
+            }
+        };
+        let breakpoint_addresses = breakpoint_list_registry.get_breakpoint_addresses();
+
+        drop(breakpoint_list_registry);
+
+        match &error_message {
+            Some(error_message) => log::error!("{}", error_message),
+            None => engine_privileged_state.emit_event(BreakpointsChangedEvent {
+                breakpoint_addresses: breakpoint_addresses.clone(),
+            }),
+        }
+
+        BreakpointRemoveResponse {
+            breakpoint_addresses,
+            error_message,
+        }
+    }
+}
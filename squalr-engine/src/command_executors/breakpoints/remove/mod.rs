@@ -0,0 +1 @@
+pub mod breakpoint_remove_request_executor;
@@ -0,0 +1,87 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::breakpoints::set::breakpoint_set_request::BreakpointSetRequest;
+use squalr_engine_api::commands::breakpoints::set::breakpoint_set_response::BreakpointSetResponse;
+use squalr_engine_api::events::breakpoints::changed::breakpoints_changed_event::BreakpointsChangedEvent;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use squalr_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use std::sync::Arc;
+
+/// The x86 `int3` instruction, which raises a breakpoint exception when executed.
+const INT3_OPCODE: u8 = 0xCC;
+
+impl PrivilegedCommandRequestExecutor for BreakpointSetRequest {
+    type ResponseType = BreakpointSetResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                return BreakpointSetResponse {
+                    breakpoint_addresses: vec![],
+                    error_message: Some("No process is opened to set a breakpoint in.".to_string()),
+                };
+            }
+        };
+        let breakpoint_list_registry = engine_privileged_state.get_breakpoint_list_registry();
+        let mut breakpoint_list_registry = match breakpoint_list_registry.write() {
+            Ok(breakpoint_list_registry) => breakpoint_list_registry,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on BreakpointListRegistry: {}", error);
+
+                return BreakpointSetResponse {
+                    breakpoint_addresses: vec![],
+                    error_message: Some(error.to_string()),
+                };
+            }
+        };
+
+        breakpoint_list_registry.bind_process(Some(process_info.get_process_id_raw()));
+
+        // Patching over an existing breakpoint would record the `int3` as the original byte, and lose the real one.
+        if breakpoint_list_registry.has_breakpoint(self.address) {
+            return BreakpointSetResponse {
+                breakpoint_addresses: breakpoint_list_registry.get_breakpoint_addresses(),
+                error_message: None,
+            };
+        }
+
+        let error_message = if process_info.get_is_read_only() {
+            Some("Cannot set a breakpoint: the process is attached read-only.".to_string())
+        } else {
+            let mut original_byte = [0u8];
+
+            if !MemoryReader::get_instance().read_bytes(&process_info, self.address, &mut original_byte) {
+                Some(format!("Could not read the instruction at {:X}.", self.address))
+            } else if !MemoryWriter::get_instance().write_bytes(&process_info, self.address, &[INT3_OPCODE]) {
+                Some(format!("Could not write a breakpoint at {:X}. The page may be protected.", self.address))
+            } else {
+                breakpoint_list_registry.add_breakpoint(self.address, original_byte[0]);
+                None
+            }
+        };
+        let breakpoint_addresses = breakpoint_list_registry.get_breakpoint_addresses();
+
+        drop(breakpoint_list_registry);
+
+        match &error_message {
+            Some(error_message) => log::error!("{}", error_message),
+            None => engine_privileged_state.emit_event(BreakpointsChangedEvent {
+                breakpoint_addresses: breakpoint_addresses.clone(),
+            }),
+        }
+
+        BreakpointSetResponse {
+            breakpoint_addresses,
+            error_message,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod breakpoint_set_request_executor;
@@ -0,0 +1,23 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::code::assemble::code_assemble_request::CodeAssembleRequest;
+use squalr_engine_api::commands::code::assemble::code_assemble_response::CodeAssembleResponse;
+use squalr_engine_architecture::assembler::Assembler;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for CodeAssembleRequest {
+    type ResponseType = CodeAssembleResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match Assembler::assemble(&self.instruction, self.address) {
+            Ok(bytes) => CodeAssembleResponse { bytes, error_message: None },
+            Err(error_message) => CodeAssembleResponse {
+                bytes: vec![],
+                error_message: Some(error_message),
+            },
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod code_assemble_request_executor;
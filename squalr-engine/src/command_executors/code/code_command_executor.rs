@@ -0,0 +1,33 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    code::code_command::CodeCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for CodeCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            CodeCommand::Assemble { code_assemble_request } => code_assemble_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            CodeCommand::Patch { code_patch_request } => code_patch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            CodeCommand::Restore { code_restore_request } => code_restore_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            CodeCommand::List { code_patches_list_request } => code_patches_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
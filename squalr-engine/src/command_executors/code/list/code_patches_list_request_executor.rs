@@ -0,0 +1,34 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::code::list::code_patches_list_request::CodePatchesListRequest;
+use squalr_engine_api::commands::code::list::code_patches_list_response::CodePatchesListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for CodePatchesListRequest {
+    type ResponseType = CodePatchesListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_id = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|process_info| process_info.get_process_id_raw());
+
+        match engine_privileged_state.get_code_patch_list_registry().write() {
+            Ok(mut code_patch_list_registry) => {
+                // Patches made in a process that has since been closed are discarded, rather than listed against the new one.
+                code_patch_list_registry.bind_process(process_id);
+
+                CodePatchesListResponse {
+                    code_patches: code_patch_list_registry.get_code_patches(),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on CodePatchListRegistry: {}", error);
+                CodePatchesListResponse::default()
+            }
+        }
+    }
+}
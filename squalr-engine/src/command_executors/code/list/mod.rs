@@ -0,0 +1 @@
+pub mod code_patches_list_request_executor;
@@ -0,0 +1,5 @@
+pub mod assemble;
+pub mod code_command_executor;
+pub mod list;
+pub mod patch;
+pub mod restore;
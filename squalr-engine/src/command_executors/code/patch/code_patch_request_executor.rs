@@ -0,0 +1,111 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::code::patch::code_patch_request::CodePatchRequest;
+use squalr_engine_api::commands::code::patch::code_patch_response::CodePatchResponse;
+use squalr_engine_api::events::code_patches::changed::code_patches_changed_event::CodePatchesChangedEvent;
+use squalr_engine_api::structures::memory::code_patch::CodePatch;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for CodePatchRequest {
+    type ResponseType = CodePatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                return CodePatchResponse {
+                    code_patches: vec![],
+                    error_message: Some("No process is opened to patch.".to_string()),
+                };
+            }
+        };
+        let end_address = self.address.saturating_add(self.bytes.len() as u64);
+
+        // The original byte under a breakpoint is only known to the breakpoint list, so patching over one would lose it.
+        let patched_breakpoint_address = match engine_privileged_state.get_breakpoint_list_registry().read() {
+            Ok(breakpoint_list_registry) => breakpoint_list_registry
+                .get_breakpoint_addresses()
+                .into_iter()
+                .find(|breakpoint_address| *breakpoint_address >= self.address && *breakpoint_address < end_address),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on BreakpointListRegistry: {}", error);
+                None
+            }
+        };
+        let code_patch_list_registry = engine_privileged_state.get_code_patch_list_registry();
+        let mut code_patch_list_registry = match code_patch_list_registry.write() {
+            Ok(code_patch_list_registry) => code_patch_list_registry,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on CodePatchListRegistry: {}", error);
+
+                return CodePatchResponse {
+                    code_patches: vec![],
+                    error_message: Some(error.to_string()),
+                };
+            }
+        };
+
+        code_patch_list_registry.bind_process(Some(process_info.get_process_id_raw()));
+
+        let overlapping_code_patch = code_patch_list_registry
+            .find_overlapping_code_patch(self.address, self.bytes.len() as u64)
+            .cloned();
+        let error_message = if self.bytes.is_empty() {
+            Some("No bytes were given to patch.".to_string())
+        } else if process_info.get_is_read_only() {
+            Some("Cannot patch code: the process is attached read-only.".to_string())
+        } else if let Some(breakpoint_address) = patched_breakpoint_address {
+            Some(format!("Remove the breakpoint at {:X} before patching over it.", breakpoint_address))
+        } else {
+            match overlapping_code_patch {
+                // Patching within a patch keeps the code from before the first patch as the original, such that restoring undoes both.
+                Some(code_patch) if code_patch.address <= self.address && end_address <= code_patch.get_end_address() => {
+                    if !MemoryWriter::write_bytes_with_ledger(&process_info, self.address, &self.bytes) {
+                        Some(format!("Could not write a patch at {:X}. The page may be protected.", self.address))
+                    } else {
+                        code_patch_list_registry.update_code_patch(self.address, &self.bytes);
+                        None
+                    }
+                }
+                Some(code_patch) => Some(format!("Restore the patch at {:X} before patching over it.", code_patch.address)),
+                None => {
+                    let mut original_bytes = vec![0u8; self.bytes.len()];
+
+                    if !MemoryReader::get_instance().read_bytes(&process_info, self.address, &mut original_bytes) {
+                        Some(format!("Could not read the code at {:X}.", self.address))
+                    } else if !MemoryWriter::write_bytes_with_ledger(&process_info, self.address, &self.bytes) {
+                        Some(format!("Could not write a patch at {:X}. The page may be protected.", self.address))
+                    } else {
+                        code_patch_list_registry.add_code_patch(CodePatch {
+                            address: self.address,
+                            original_bytes,
+                            patched_bytes: self.bytes.clone(),
+                        });
+                        None
+                    }
+                }
+            }
+        };
+        let code_patches = code_patch_list_registry.get_code_patches();
+
+        drop(code_patch_list_registry);
+
+        match &error_message {
+            Some(error_message) => log::error!("{}", error_message),
+            None => engine_privileged_state.emit_event(CodePatchesChangedEvent {
+                code_patches: code_patches.clone(),
+            }),
+        }
+
+        CodePatchResponse { code_patches, error_message }
+    }
+}
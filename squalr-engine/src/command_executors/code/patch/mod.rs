@@ -0,0 +1 @@
+pub mod code_patch_request_executor;
@@ -0,0 +1,86 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::code::restore::code_restore_request::CodeRestoreRequest;
+use squalr_engine_api::commands::code::restore::code_restore_response::CodeRestoreResponse;
+use squalr_engine_api::events::code_patches::changed::code_patches_changed_event::CodePatchesChangedEvent;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for CodeRestoreRequest {
+    type ResponseType = CodeRestoreResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                return CodeRestoreResponse {
+                    code_patches: vec![],
+                    error_message: Some("No process is opened to restore code in.".to_string()),
+                };
+            }
+        };
+        let breakpoint_addresses = match engine_privileged_state.get_breakpoint_list_registry().read() {
+            Ok(breakpoint_list_registry) => breakpoint_list_registry.get_breakpoint_addresses(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on BreakpointListRegistry: {}", error);
+                vec![]
+            }
+        };
+        let code_patch_list_registry = engine_privileged_state.get_code_patch_list_registry();
+        let mut code_patch_list_registry = match code_patch_list_registry.write() {
+            Ok(code_patch_list_registry) => code_patch_list_registry,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on CodePatchListRegistry: {}", error);
+
+                return CodeRestoreResponse {
+                    code_patches: vec![],
+                    error_message: Some(error.to_string()),
+                };
+            }
+        };
+
+        code_patch_list_registry.bind_process(Some(process_info.get_process_id_raw()));
+
+        let error_message = match code_patch_list_registry.remove_code_patch(self.address) {
+            None => Some(format!("No code patch contains {:X}.", self.address)),
+            Some(code_patch) => {
+                // Writing the original code over a breakpoint would drop its `int3` while the breakpoint list still expects it.
+                let breakpoint_address = breakpoint_addresses
+                    .into_iter()
+                    .find(|breakpoint_address| code_patch.contains_address(*breakpoint_address));
+                let error_message = if let Some(breakpoint_address) = breakpoint_address {
+                    Some(format!("Remove the breakpoint at {:X} before restoring the code under it.", breakpoint_address))
+                } else if !MemoryWriter::write_bytes_with_ledger(&process_info, code_patch.address, &code_patch.original_bytes) {
+                    Some(format!("Could not restore the code at {:X}. The page may be protected.", code_patch.address))
+                } else {
+                    None
+                };
+
+                // The patch stays recorded if the original code cannot be written back, such that restoring it can be retried.
+                if error_message.is_some() {
+                    code_patch_list_registry.add_code_patch(code_patch);
+                }
+
+                error_message
+            }
+        };
+        let code_patches = code_patch_list_registry.get_code_patches();
+
+        drop(code_patch_list_registry);
+
+        match &error_message {
+            Some(error_message) => log::error!("{}", error_message),
+            None => engine_privileged_state.emit_event(CodePatchesChangedEvent {
+                code_patches: code_patches.clone(),
+            }),
+        }
+
+        CodeRestoreResponse { code_patches, error_message }
+    }
+}
@@ -0,0 +1 @@
+pub mod code_restore_request_executor;
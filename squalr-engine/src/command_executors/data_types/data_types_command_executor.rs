@@ -0,0 +1,27 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    data_types::data_types_command::DataTypesCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for DataTypesCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            DataTypesCommand::Register { data_types_register_request } => data_types_register_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            DataTypesCommand::Unregister { data_types_unregister_request } => data_types_unregister_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
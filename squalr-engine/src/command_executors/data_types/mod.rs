@@ -0,0 +1,3 @@
+pub mod data_types_command_executor;
+pub mod register;
+pub mod unregister;
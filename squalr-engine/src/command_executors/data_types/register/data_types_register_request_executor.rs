@@ -0,0 +1,35 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::data_types::register::data_types_register_request::DataTypesRegisterRequest;
+use squalr_engine_api::commands::data_types::register::data_types_register_response::DataTypesRegisterResponse;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DataTypesRegisterRequest {
+    type ResponseType = DataTypesRegisterResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let custom_data_type_registry = CustomDataTypeRegistry::get_instance();
+        let mut registered_count = 0;
+        let mut error_messages = vec![];
+
+        for definition in &self.definitions {
+            match custom_data_type_registry.register(definition.clone()) {
+                Ok(()) => registered_count += 1,
+                Err(error) => {
+                    log::warn!("Unable to register custom data type '{}': {}", definition.get_name(), error);
+                    error_messages.push(error);
+                }
+            }
+        }
+
+        DataTypesRegisterResponse {
+            registered_count,
+            error_messages,
+            definitions: custom_data_type_registry.get_definitions(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod data_types_register_request_executor;
@@ -0,0 +1,23 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::data_types::unregister::data_types_unregister_request::DataTypesUnregisterRequest;
+use squalr_engine_api::commands::data_types::unregister::data_types_unregister_response::DataTypesUnregisterResponse;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for DataTypesUnregisterRequest {
+    type ResponseType = DataTypesUnregisterResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let custom_data_type_registry = CustomDataTypeRegistry::get_instance();
+        let was_unregistered = custom_data_type_registry.unregister(&self.data_type_id);
+
+        DataTypesUnregisterResponse {
+            was_unregistered,
+            definitions: custom_data_type_registry.get_definitions(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod data_types_unregister_request_executor;
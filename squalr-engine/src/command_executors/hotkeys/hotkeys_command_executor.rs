@@ -0,0 +1,24 @@
+use crate::{
+    command_executors::{privileged_command_executor::PrivilegedCommandExecutor, privileged_request_executor::PrivilegedCommandRequestExecutor},
+    engine_privileged_state::EnginePrivilegedState,
+};
+use squalr_engine_api::commands::{
+    hotkeys::hotkeys_command::HotkeysCommand,
+    privileged_command_response::{PrivilegedCommandResponse, TypedPrivilegedCommandResponse},
+};
+use std::sync::Arc;
+
+impl PrivilegedCommandExecutor for HotkeysCommand {
+    type ResponseType = PrivilegedCommandResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandExecutor>::ResponseType {
+        match self {
+            HotkeysCommand::Register { hotkeys_register_request } => hotkeys_register_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+        }
+    }
+}
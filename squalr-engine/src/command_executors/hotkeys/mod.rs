@@ -0,0 +1,2 @@
+pub mod hotkeys_command_executor;
+pub mod register;
@@ -0,0 +1,30 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::hotkeys;
+use squalr_engine_api::commands::hotkeys::register::hotkeys_register_request::HotkeysRegisterRequest;
+use squalr_engine_api::commands::hotkeys::register::hotkeys_register_response::HotkeysRegisterResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for HotkeysRegisterRequest {
+    type ResponseType = HotkeysRegisterResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_hotkey_list_registry().write() {
+            Ok(mut hotkey_list_registry) => {
+                hotkey_list_registry.set_chords(self.chords.clone());
+
+                HotkeysRegisterResponse {
+                    is_supported: hotkeys::is_supported(),
+                    registered_chord_count: hotkey_list_registry.get_chords().len() as u64,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on HotkeyListRegistry: {}", error);
+                HotkeysRegisterResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod hotkeys_register_request_executor;
@@ -0,0 +1,38 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::ledger::memory_ledger_request::MemoryLedgerRequest;
+use squalr_engine_api::commands::memory::ledger::memory_ledger_response::MemoryLedgerResponse;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryLedgerRequest {
+    type ResponseType = MemoryLedgerResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_id = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+            .map(|process_info| process_info.get_process_id_raw());
+
+        match MemoryWriter::get_write_ledger().write() {
+            Ok(mut write_ledger) => {
+                // Drops stale entries if the process changed since the last recorded write.
+                write_ledger.bind_process(process_id);
+
+                MemoryLedgerResponse {
+                    address_count: write_ledger.get_address_count() as u64,
+                    byte_count: write_ledger.get_total_bytes() as u64,
+                    is_capacity_exceeded: write_ledger.get_is_capacity_exceeded(),
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write ledger lock: {}", error);
+
+                MemoryLedgerResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_ledger_request_executor;
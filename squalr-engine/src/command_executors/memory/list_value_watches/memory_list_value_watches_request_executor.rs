@@ -0,0 +1,24 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::list_value_watches::memory_list_value_watches_request::MemoryListValueWatchesRequest;
+use squalr_engine_api::commands::memory::list_value_watches::memory_list_value_watches_response::MemoryListValueWatchesResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryListValueWatchesRequest {
+    type ResponseType = MemoryListValueWatchesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        match engine_privileged_state.get_value_watch_list_registry().read() {
+            Ok(value_watch_list_registry) => MemoryListValueWatchesResponse {
+                value_watches: value_watch_list_registry.get_value_watches(),
+            },
+            Err(error) => {
+                log::error!("Failed to acquire read lock on ValueWatchListRegistry: {}", error);
+                MemoryListValueWatchesResponse::default()
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_list_value_watches_request_executor;
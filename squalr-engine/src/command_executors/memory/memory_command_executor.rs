@@ -22,6 +22,48 @@ impl PrivilegedCommandExecutor for MemoryCommand {
             MemoryCommand::Regions { memory_regions_request } => memory_regions_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            MemoryCommand::Ledger { memory_ledger_request } => memory_ledger_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Restore { memory_restore_request } => memory_restore_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Search { memory_search_request } => memory_search_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::RefreshCache { memory_refresh_cache_request } => memory_refresh_cache_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Watch { memory_watch_request } => memory_watch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::Unwatch { memory_unwatch_request } => memory_unwatch_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::WriteSequence { memory_write_sequence_request } => memory_write_sequence_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::ResolveSymbols {
+                memory_resolve_symbols_request,
+            } => memory_resolve_symbols_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::WatchValue { memory_watch_value_request } => memory_watch_value_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::UnwatchValue { memory_unwatch_value_request } => memory_unwatch_value_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::ListValueWatches {
+                memory_list_value_watches_request,
+            } => memory_list_value_watches_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            MemoryCommand::SetDisabledAddresses {
+                memory_set_disabled_addresses_request,
+            } => memory_set_disabled_addresses_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
@@ -6,6 +6,7 @@ use squalr_engine_api::structures::structs::symbolic_struct_ref::SymbolicStructR
 use squalr_engine_api::structures::structs::valued_struct::ValuedStruct;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
 use squalr_engine_memory::memory_reader::MemoryReader;
 use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
 use std::sync::Arc;
@@ -33,9 +34,9 @@ impl PrivilegedCommandRequestExecutor for MemoryReadRequest {
                     .get_process_manager()
                     .get_opened_process()
                 {
-                    MemoryQueryer::get_instance().get_modules(&opened_process_info)
+                    ProcessQueryCache::get_instance().get_modules(&opened_process_info)
                 } else {
-                    vec![]
+                    Arc::default()
                 };
                 let module_address = MemoryQueryer::get_instance().resolve_module(&modules, &self.module_name);
                 let success = MemoryReader::get_instance().read_struct(&process_info, module_address.saturating_add(self.address), &mut out_valued_struct);
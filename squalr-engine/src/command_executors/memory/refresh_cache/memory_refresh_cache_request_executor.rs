@@ -0,0 +1,25 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::refresh_cache::memory_refresh_cache_request::MemoryRefreshCacheRequest;
+use squalr_engine_api::commands::memory::refresh_cache::memory_refresh_cache_response::MemoryRefreshCacheResponse;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryRefreshCacheRequest {
+    type ResponseType = MemoryRefreshCacheResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_query_cache = ProcessQueryCache::get_instance();
+
+        if !self.stats_only {
+            process_query_cache.invalidate();
+        }
+
+        MemoryRefreshCacheResponse {
+            cache_stats: process_query_cache.get_stats(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_refresh_cache_request_executor;
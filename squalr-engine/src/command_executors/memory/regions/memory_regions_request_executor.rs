@@ -2,9 +2,7 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::regions::memory_regions_request::MemoryRegionsRequest;
 use squalr_engine_api::commands::memory::regions::memory_regions_response::{MemoryRegionInfo, MemoryRegionsResponse};
-use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
-use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use squalr_engine_memory::memory_queryer::page_retrieval_mode::PageRetrievalMode;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for MemoryRegionsRequest {
@@ -19,10 +17,10 @@ impl PrivilegedCommandRequestExecutor for MemoryRegionsRequest {
         if let Some(opened_process_info) = engine_privileged_state.get_process_manager().get_opened_process() {
             // Memory Viewer wants a broad region list (CE-style). Using the scan settings can hide the
             // region containing the requested address, making "View Memory Region" appear broken.
-            let pages = MemoryQueryer::get_memory_page_bounds(&opened_process_info, PageRetrievalMode::FromUserMode);
-            let modules = MemoryQueryer::get_instance().get_modules(&opened_process_info);
+            let pages = ProcessQueryCache::get_instance().get_regions(&opened_process_info);
+            let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
             regions = pages
-                .into_iter()
+                .iter()
                 .map(|region| {
                     let base_address = region.get_base_address();
                     let mut module_name = String::new();
@@ -0,0 +1,34 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::resolve_symbols::memory_resolve_symbols_request::MemoryResolveSymbolsRequest;
+use squalr_engine_api::commands::memory::resolve_symbols::memory_resolve_symbols_response::MemoryResolveSymbolsResponse;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryResolveSymbolsRequest {
+    type ResponseType = MemoryResolveSymbolsResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(opened_process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryResolveSymbolsResponse {
+                symbols: vec![None; self.addresses.len()],
+            };
+        };
+
+        let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+        let module_symbol_cache = engine_privileged_state.get_module_symbol_cache();
+        let symbols = self
+            .addresses
+            .iter()
+            .map(|address| module_symbol_cache.resolve_address(&opened_process_info, &modules, *address))
+            .collect();
+
+        MemoryResolveSymbolsResponse { symbols }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_resolve_symbols_request_executor;
@@ -0,0 +1,68 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::restore::memory_restore_request::MemoryRestoreRequest;
+use squalr_engine_api::commands::memory::restore::memory_restore_response::MemoryRestoreResponse;
+use squalr_engine_api::events::code_patches::changed::code_patches_changed_event::CodePatchesChangedEvent;
+use squalr_engine_memory::memory_writer::MemoryWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryRestoreRequest {
+    type ResponseType = MemoryRestoreResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No process is opened to restore modifications for.");
+                return MemoryRestoreResponse::default();
+            }
+        };
+
+        // Frozen values would immediately overwrite the restored bytes, so freezes are released first.
+        match engine_privileged_state.get_freeze_list_registry().write() {
+            Ok(mut freeze_list_registry) => freeze_list_registry.clear(),
+            Err(error) => log::error!("Failed to acquire freeze list registry lock while restoring modifications: {}", error),
+        }
+
+        let restore_results = MemoryWriter::restore_ledger(&process_info);
+
+        // Code patches are written through the ledger, so restoring it already put their original code back. Any that failed to
+        // restore stay in the ledger, where a later restore retries them.
+        let code_patches_cleared = match engine_privileged_state.get_code_patch_list_registry().write() {
+            Ok(mut code_patch_list_registry) => {
+                let had_code_patches = !code_patch_list_registry.get_code_patches().is_empty();
+
+                code_patch_list_registry.clear();
+                had_code_patches
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on CodePatchListRegistry while restoring modifications: {}", error);
+                false
+            }
+        };
+
+        if code_patches_cleared {
+            engine_privileged_state.emit_event(CodePatchesChangedEvent { code_patches: vec![] });
+        }
+
+        let failed_count = restore_results.iter().filter(|result| !result.success).count();
+
+        for restore_result in restore_results.iter().filter(|result| !result.success) {
+            log::warn!("Failed to restore {} byte(s) at 0x{:X}.", restore_result.byte_count, restore_result.address);
+        }
+
+        log::info!(
+            "Restored {} of {} modified address(es).",
+            restore_results.len() - failed_count,
+            restore_results.len()
+        );
+
+        MemoryRestoreResponse { restore_results }
+    }
+}
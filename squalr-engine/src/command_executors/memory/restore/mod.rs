@@ -0,0 +1 @@
+pub mod memory_restore_request_executor;
@@ -0,0 +1,102 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
+use squalr_engine_api::commands::memory::search::memory_search_request::MemorySearchRequest;
+use squalr_engine_api::commands::memory::search::memory_search_response::{MemorySearchMatch, MemorySearchResponse};
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use std::sync::Arc;
+
+/// Regions are read in chunks of this size, such that huge regions do not require huge allocations.
+const SEARCH_CHUNK_SIZE: u64 = 1024 * 1024;
+
+impl PrivilegedCommandRequestExecutor for MemorySearchRequest {
+    type ResponseType = MemorySearchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let mut memory_search_response = MemorySearchResponse::default();
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("No opened process available.");
+                return memory_search_response;
+            }
+        };
+
+        if self.pattern.is_empty() {
+            return memory_search_response;
+        }
+
+        // Search the same broad region list shown by the memory viewer, rather than the scan settings, so that every match can be browsed.
+        let pages = ProcessQueryCache::get_instance().get_regions(&opened_process_info);
+        let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+        let pattern_length = self.pattern.len() as u64;
+        let mut chunk_bytes = Vec::new();
+
+        for page in pages.iter() {
+            let region_base = page.get_base_address();
+            let region_end = region_base.saturating_add(page.get_region_size());
+            let mut region_info = None;
+            let mut chunk_base = region_base;
+
+            while chunk_base < region_end {
+                // Overlap chunks by the pattern length, such that matches spanning a chunk boundary are still found.
+                let chunk_end = chunk_base.saturating_add(SEARCH_CHUNK_SIZE).min(region_end);
+                let read_end = chunk_end.saturating_add(pattern_length - 1).min(region_end);
+
+                chunk_bytes.resize((read_end - chunk_base) as usize, 0);
+
+                if MemoryReader::get_instance().read_bytes(&opened_process_info, chunk_base, &mut chunk_bytes) {
+                    for offset in self.pattern.find_all(&chunk_bytes) {
+                        let address = chunk_base.saturating_add(offset as u64);
+
+                        if address >= chunk_end {
+                            continue;
+                        }
+
+                        if memory_search_response.matches.len() as u64 >= self.max_results {
+                            memory_search_response.is_truncated = true;
+
+                            return memory_search_response;
+                        }
+
+                        let region = region_info
+                            .get_or_insert_with(|| {
+                                let mut region_info = MemoryRegionInfo {
+                                    base_address: region_base,
+                                    region_size: page.get_region_size(),
+                                    ..MemoryRegionInfo::default()
+                                };
+
+                                if let Some(module) = modules
+                                    .iter()
+                                    .find(|module| module.contains_address(region_base))
+                                {
+                                    region_info.module_name = module.get_module_name().to_string();
+                                    region_info.module_offset = region_base.saturating_sub(module.get_base_address());
+                                }
+
+                                region_info
+                            })
+                            .clone();
+
+                        memory_search_response
+                            .matches
+                            .push(MemorySearchMatch { address, region });
+                    }
+                }
+
+                chunk_base = chunk_end;
+            }
+        }
+
+        memory_search_response
+    }
+}
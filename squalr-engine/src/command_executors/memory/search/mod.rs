@@ -0,0 +1 @@
+pub mod memory_search_request_executor;
@@ -0,0 +1,38 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_request::MemorySetDisabledAddressesRequest;
+use squalr_engine_api::commands::memory::set_disabled_addresses::memory_set_disabled_addresses_response::MemorySetDisabledAddressesResponse;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemorySetDisabledAddressesRequest {
+    type ResponseType = MemorySetDisabledAddressesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let disabled_pointers: HashSet<Pointer> = self.targets.iter().map(|target| target.to_pointer()).collect();
+
+        match engine_privileged_state.get_freeze_list_registry().write() {
+            Ok(mut freeze_list_registry) => freeze_list_registry.set_disabled_pointers(disabled_pointers.clone()),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on FreezeListRegistry: {}", error);
+
+                return MemorySetDisabledAddressesResponse { success: false };
+            }
+        }
+
+        match engine_privileged_state.get_value_watch_list_registry().write() {
+            Ok(mut value_watch_list_registry) => value_watch_list_registry.set_disabled_pointers(disabled_pointers),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueWatchListRegistry: {}", error);
+
+                return MemorySetDisabledAddressesResponse { success: false };
+            }
+        }
+
+        MemorySetDisabledAddressesResponse { success: true }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_set_disabled_addresses_request_executor;
@@ -0,0 +1,19 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::unwatch::memory_unwatch_request::MemoryUnwatchRequest;
+use squalr_engine_api::commands::memory::unwatch::memory_unwatch_response::MemoryUnwatchResponse;
+use squalr_engine_debuggers::memory_watch::memory_watch_manager::MemoryWatchManager;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryUnwatchRequest {
+    type ResponseType = MemoryUnwatchResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let success = MemoryWatchManager::get_instance().stop_watch(self.watch_id);
+
+        MemoryUnwatchResponse { success }
+    }
+}
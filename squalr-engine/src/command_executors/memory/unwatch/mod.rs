@@ -0,0 +1 @@
+pub mod memory_unwatch_request_executor;
@@ -0,0 +1,24 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::unwatch_value::memory_unwatch_value_request::MemoryUnwatchValueRequest;
+use squalr_engine_api::commands::memory::unwatch_value::memory_unwatch_value_response::MemoryUnwatchValueResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryUnwatchValueRequest {
+    type ResponseType = MemoryUnwatchValueResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let success = match engine_privileged_state.get_value_watch_list_registry().write() {
+            Ok(mut value_watch_list_registry) => value_watch_list_registry.remove_watch(self.watch_id),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueWatchListRegistry: {}", error);
+                false
+            }
+        };
+
+        MemoryUnwatchValueResponse { success }
+    }
+}
@@ -0,0 +1 @@
+pub mod memory_unwatch_value_request_executor;
@@ -0,0 +1,69 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::watch::memory_watch_request::MemoryWatchRequest;
+use squalr_engine_api::commands::memory::watch::memory_watch_response::MemoryWatchResponse;
+use squalr_engine_api::events::memory_watch::hits::memory_watch_hits_event::MemoryWatchHitsEvent;
+use squalr_engine_api::events::memory_watch::stopped::memory_watch_stopped_event::MemoryWatchStoppedEvent;
+use squalr_engine_debuggers::memory_watch::memory_watch_manager::MemoryWatchManager;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryWatchRequest {
+    type ResponseType = MemoryWatchResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return MemoryWatchResponse {
+                watch_id: None,
+                error_message: Some("No process is opened to watch.".to_string()),
+            };
+        };
+
+        let address = if self.module_name.is_empty() {
+            self.address
+        } else {
+            let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+
+            MemoryQueryer::get_instance()
+                .resolve_module(&modules, &self.module_name)
+                .saturating_add(self.address)
+        };
+        let hits_engine_state = engine_privileged_state.clone();
+        let stopped_engine_state = engine_privileged_state.clone();
+
+        match MemoryWatchManager::get_instance().start_page_trap_watch(
+            &process_info,
+            address,
+            self.size,
+            self.watch_type,
+            move |watch_id, hits| hits_engine_state.emit_event(MemoryWatchHitsEvent { watch_id, hits }),
+            move |watch_id, stop_reason| {
+                stopped_engine_state.emit_event(MemoryWatchStoppedEvent {
+                    watch_id,
+                    reason: stop_reason.to_string(),
+                })
+            },
+        ) {
+            Ok(watch_id) => MemoryWatchResponse {
+                watch_id: Some(watch_id),
+                error_message: None,
+            },
+            Err(error) => {
+                log::error!("Failed to watch 0x{:X}: {}", address, error);
+
+                MemoryWatchResponse {
+                    watch_id: None,
+                    error_message: Some(error),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::watch_value::memory_watch_value_request::MemoryWatchValueRequest;
+use squalr_engine_api::commands::memory::watch_value::memory_watch_value_response::MemoryWatchValueResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::memory::value_watch_condition::ValueWatchCondition;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for MemoryWatchValueRequest {
+    type ResponseType = MemoryWatchValueResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        if symbol_registry.get_unit_size_in_bytes(&self.data_type_ref) == 0 {
+            return MemoryWatchValueResponse {
+                watch_id: None,
+                error_message: Some(format!("Unknown data type: {}", self.data_type_ref)),
+            };
+        }
+
+        // Reject values that cannot be compared now, rather than silently never triggering later.
+        let condition_value = match &self.condition {
+            ValueWatchCondition::AnyChange => None,
+            ValueWatchCondition::CrossesThreshold { threshold } => Some(threshold),
+            ValueWatchCondition::Equals { value } => Some(value),
+        };
+
+        if let Some(condition_value) = condition_value
+            && let Err(error) = symbol_registry.deanonymize_value_string(&self.data_type_ref, condition_value)
+        {
+            return MemoryWatchValueResponse {
+                watch_id: None,
+                error_message: Some(format!("Invalid value for the watch condition: {}", error)),
+            };
+        }
+
+        match engine_privileged_state.get_value_watch_list_registry().write() {
+            Ok(mut value_watch_list_registry) => {
+                let watch_id = value_watch_list_registry.add_watch(
+                    self.label.clone(),
+                    self.address,
+                    self.module_name.clone(),
+                    self.data_type_ref.clone(),
+                    self.condition.clone(),
+                );
+
+                MemoryWatchValueResponse {
+                    watch_id: Some(watch_id),
+                    error_message: None,
+                }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire write lock on ValueWatchListRegistry: {}", error);
+
+                MemoryWatchValueResponse {
+                    watch_id: None,
+                    error_message: Some(error.to_string()),
+                }
+            }
+        }
+    }
+}
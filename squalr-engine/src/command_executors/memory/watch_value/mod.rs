@@ -0,0 +1 @@
+pub mod memory_watch_value_request_executor;
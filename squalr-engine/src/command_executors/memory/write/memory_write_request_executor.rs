@@ -2,10 +2,11 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::commands::memory::write::memory_write_response::MemoryWriteResponse;
+use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use squalr_engine_memory::memory_writer::MemoryWriter;
-use squalr_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_scanning::freeze_task::frozen_value_writer::FrozenValueWriter;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
@@ -24,16 +25,25 @@ impl PrivilegedCommandRequestExecutor for MemoryWriteRequest {
                     .get_process_manager()
                     .get_opened_process()
                 {
-                    MemoryQueryer::get_instance().get_modules(&opened_process_info)
+                    ProcessQueryCache::get_instance().get_modules(&opened_process_info)
                 } else {
-                    vec![]
+                    Arc::default()
                 };
                 let module_address = MemoryQueryer::get_instance().resolve_module(&modules, &self.module_name);
-                let success = MemoryWriter::get_instance().write_bytes(&process_info, module_address.saturating_add(self.address), &self.value);
+                let pointer = Pointer::new(self.address, vec![], self.module_name.clone());
+                let success = FrozenValueWriter::write_bytes(
+                    &process_info,
+                    &engine_privileged_state.get_freeze_list_registry(),
+                    pointer,
+                    module_address.saturating_add(self.address),
+                    &self.value,
+                );
 
                 MemoryWriteResponse { success }
             } else {
-                let success = MemoryWriter::get_instance().write_bytes(&process_info, self.address, &self.value);
+                let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+                let pointer = FrozenValueWriter::get_pointer(self.address, &modules);
+                let success = FrozenValueWriter::write_bytes(&process_info, &engine_privileged_state.get_freeze_list_registry(), pointer, self.address, &self.value);
 
                 MemoryWriteResponse { success }
             }
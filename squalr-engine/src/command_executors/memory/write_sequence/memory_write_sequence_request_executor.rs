@@ -0,0 +1,86 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::memory::write_sequence::memory_write_sequence_request::MemoryWriteSequenceRequest;
+use squalr_engine_api::commands::memory::write_sequence::memory_write_sequence_response::MemoryWriteSequenceResponse;
+use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
+use squalr_engine_api::events::write_sequence::finished::write_sequence_finished_event::WriteSequenceFinishedEvent;
+use squalr_engine_scanning::write_sequences::write_sequence_memory_access::ProcessWriteSequenceMemoryAccess;
+use squalr_engine_scanning::write_sequences::write_sequence_task::WriteSequenceTask;
+use std::sync::Arc;
+use std::thread;
+
+impl PrivilegedCommandRequestExecutor for MemoryWriteSequenceRequest {
+    type ResponseType = MemoryWriteSequenceResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No process is opened to run the write sequence against.");
+            return MemoryWriteSequenceResponse::default();
+        };
+
+        let memory_access = Arc::new(ProcessWriteSequenceMemoryAccess::new(process_info));
+        let unresolved_step_indices = WriteSequenceTask::find_unresolved_steps(&self.steps, memory_access.as_ref());
+
+        if !unresolved_step_indices.is_empty() {
+            log::error!("Write sequence was not started, as some of its addresses could not be read.");
+
+            return MemoryWriteSequenceResponse {
+                trackable_task_handle: None,
+                unresolved_step_indices,
+            };
+        }
+
+        let finished_engine_state = engine_privileged_state.clone();
+        let task = WriteSequenceTask::start_task(
+            self.steps.clone(),
+            self.continue_on_error,
+            memory_access,
+            engine_privileged_state.get_freeze_list_registry(),
+            move |task, outcome| {
+                finished_engine_state.emit_event(WriteSequenceFinishedEvent {
+                    task_id: task.get_task_identifier(),
+                    completed_step_count: outcome.completed_step_count,
+                    failed_step_indices: outcome.failed_step_indices,
+                    was_cancelled: outcome.was_cancelled,
+                });
+            },
+        );
+        let task_handle = task.get_task_handle();
+        let task_id = task.get_task_identifier();
+        let progress_receiver = task.subscribe_to_progress_updates();
+        let progress_engine_state = engine_privileged_state.clone();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            while let Ok(progress) = progress_receiver.recv() {
+                progress_engine_state.emit_event(TrackableTaskProgressChangedEvent {
+                    task_id: task_id.clone(),
+                    progress,
+                    memory_usage_bytes: None,
+                });
+            }
+        });
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+        });
+
+        MemoryWriteSequenceResponse {
+            trackable_task_handle: Some(task_handle),
+            unresolved_step_indices: vec![],
+        }
+    }
+}
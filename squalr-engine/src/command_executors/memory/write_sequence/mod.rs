@@ -0,0 +1 @@
+pub mod memory_write_sequence_request_executor;
@@ -0,0 +1 @@
+pub mod pointer_scan_results_add_to_project_request_executor;
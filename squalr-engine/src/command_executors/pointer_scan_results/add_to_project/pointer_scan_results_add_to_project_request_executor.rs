@@ -0,0 +1,30 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_request::PointerScanResultsAddToProjectRequest;
+use squalr_engine_api::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_response::PointerScanResultsAddToProjectResponse;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_pointer::ProjectItemTypePointer;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PointerScanResultsAddToProjectRequest {
+    type ResponseType = PointerScanResultsAddToProjectResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        // Entries store the path rather than the address it currently leads to, such that they resolve again after a restart.
+        let project_items = self
+            .pointer_scan_results
+            .iter()
+            .map(|pointer_scan_result| {
+                ProjectItemTypePointer::new_project_item_from_pointer_scan_result(
+                    ProjectItemTypePointer::DEFAULT_NAME,
+                    pointer_scan_result,
+                    &self.data_type_ref,
+                )
+            })
+            .collect();
+
+        PointerScanResultsAddToProjectResponse { project_items }
+    }
+}
@@ -0,0 +1 @@
+pub mod pointer_scan_results_load_request_executor;
@@ -0,0 +1,72 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::pointer_scan_results::load::pointer_scan_results_load_request::PointerScanResultsLoadRequest;
+use squalr_engine_api::commands::pointer_scan_results::load::pointer_scan_results_load_response::PointerScanResultsLoadResponse;
+use squalr_engine_api::events::pointer_scan_results::updated::pointer_scan_results_updated_event::PointerScanResultsUpdatedEvent;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_results_file::PointerScanResultsFile;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::fs;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PointerScanResultsLoadRequest {
+    type ResponseType = PointerScanResultsLoadResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let loaded_result_count = load_pointer_scan_results(engine_privileged_state, self);
+
+        if let Err(error) = &loaded_result_count {
+            log::error!("{}", error);
+        }
+
+        PointerScanResultsLoadResponse { loaded_result_count }
+    }
+}
+
+fn load_pointer_scan_results(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    pointer_scan_results_load_request: &PointerScanResultsLoadRequest,
+) -> Result<u64, String> {
+    let bytes = fs::read(&pointer_scan_results_load_request.file_path).map_err(|error| {
+        format!(
+            "Failed to read pointer scan results from {}: {}",
+            pointer_scan_results_load_request.file_path.display(),
+            error
+        )
+    })?;
+    let pointer_scan_results_file = PointerScanResultsFile::from_bytes(&bytes)?;
+
+    // Results are checked against the opened process, as paths from another bitness or module set would silently resolve nowhere.
+    let process_info = engine_privileged_state
+        .get_process_manager()
+        .get_opened_process()
+        .ok_or_else(|| "Attach to a process before loading pointer scan results.".to_string())?;
+    let pointer_size = MemoryQueryer::get_address_space_bounds(&process_info).get_pointer_size() as u64;
+    let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+    let pointer_scan_results = pointer_scan_results_file.into_pointer_scan_results(pointer_size, |module_name| {
+        match MemoryQueryer::get_instance().resolve_module(&modules, module_name) {
+            0 => None,
+            module_address => Some(module_address),
+        }
+    })?;
+    let result_count = pointer_scan_results.get_result_count();
+
+    match engine_privileged_state.get_pointer_scan_results().write() {
+        Ok(mut current_pointer_scan_results) => *current_pointer_scan_results = pointer_scan_results,
+        Err(error) => return Err(format!("Failed to acquire write lock on pointer scan results: {}", error)),
+    }
+
+    log::info!(
+        "Loaded {} pointer scan result(s) from {}.",
+        result_count,
+        pointer_scan_results_load_request.file_path.display()
+    );
+
+    engine_privileged_state.emit_event(PointerScanResultsUpdatedEvent {});
+
+    Ok(result_count)
+}
@@ -1,2 +1,5 @@
+pub mod add_to_project;
+pub mod load;
 pub mod pointer_scan_results_command_executor;
 pub mod query;
+pub mod save;
@@ -16,6 +16,17 @@ impl PrivilegedCommandExecutor for PointerScanResultsCommand {
             PointerScanResultsCommand::Query { results_query_request } => results_query_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            PointerScanResultsCommand::AddToProject {
+                results_add_to_project_request,
+            } => results_add_to_project_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PointerScanResultsCommand::Save { results_save_request } => results_save_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            PointerScanResultsCommand::Load { results_load_request } => results_load_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
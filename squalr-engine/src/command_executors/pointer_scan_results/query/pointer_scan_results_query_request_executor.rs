@@ -15,11 +15,13 @@ impl PrivilegedCommandRequestExecutor for PointerScanResultsQueryRequest {
         let mut last_page_index = 0;
         let mut result_count = 0;
         let mut page_size = 512u64;
+        let mut memory_capped_depth = None;
 
         if let Ok(pointer_scan_results) = engine_privileged_state.get_pointer_scan_results().read() {
             result_count = pointer_scan_results.get_result_count();
             last_page_index = pointer_scan_results.get_last_page_index();
             page_size = pointer_scan_results.get_page_size();
+            memory_capped_depth = pointer_scan_results.get_memory_capped_depth();
             results = pointer_scan_results.query_page(self.page_index.clamp(0, last_page_index));
         }
 
@@ -29,6 +31,7 @@ impl PrivilegedCommandRequestExecutor for PointerScanResultsQueryRequest {
             last_page_index,
             page_size,
             result_count,
+            memory_capped_depth,
         }
     }
 }
@@ -0,0 +1 @@
+pub mod pointer_scan_results_save_request_executor;
@@ -0,0 +1,65 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::pointer_scan_results::save::pointer_scan_results_save_request::PointerScanResultsSaveRequest;
+use squalr_engine_api::commands::pointer_scan_results::save::pointer_scan_results_save_response::PointerScanResultsSaveResponse;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_results_file::PointerScanResultsFile;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use std::fs;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for PointerScanResultsSaveRequest {
+    type ResponseType = PointerScanResultsSaveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let saved_result_count = save_pointer_scan_results(engine_privileged_state, self);
+
+        if let Err(error) = &saved_result_count {
+            log::error!("{}", error);
+        }
+
+        PointerScanResultsSaveResponse { saved_result_count }
+    }
+}
+
+fn save_pointer_scan_results(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    pointer_scan_results_save_request: &PointerScanResultsSaveRequest,
+) -> Result<u64, String> {
+    // The pointer size is recorded such that the results are not later loaded into a process of another bitness.
+    let process_info = engine_privileged_state
+        .get_process_manager()
+        .get_opened_process()
+        .ok_or_else(|| "Attach to the process the results were found in before saving them.".to_string())?;
+    let pointer_size = MemoryQueryer::get_address_space_bounds(&process_info).get_pointer_size() as u64;
+    let pointer_scan_results = engine_privileged_state
+        .get_pointer_scan_results()
+        .read()
+        .map_err(|error| format!("Failed to acquire read lock on pointer scan results: {}", error))?
+        .clone();
+
+    if pointer_scan_results.get_result_count() == 0 {
+        return Err("There are no pointer scan results to save.".to_string());
+    }
+
+    let result_count = pointer_scan_results.get_result_count();
+    let bytes = PointerScanResultsFile::new(pointer_size, pointer_scan_results).to_bytes()?;
+
+    fs::write(&pointer_scan_results_save_request.file_path, bytes).map_err(|error| {
+        format!(
+            "Failed to write pointer scan results to {}: {}",
+            pointer_scan_results_save_request.file_path.display(),
+            error
+        )
+    })?;
+
+    log::info!(
+        "Saved {} pointer scan result(s) to {}.",
+        result_count,
+        pointer_scan_results_save_request.file_path.display()
+    );
+
+    Ok(result_count)
+}
@@ -25,6 +25,11 @@ impl ExecutableCommandPrivileged for PrivilegedCommand {
             PrivilegedCommand::Scan(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::Settings(command) => command.execute(engine_privileged_state),
             PrivilegedCommand::TrackableTasks(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Address(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Breakpoints(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Code(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::Hotkeys(command) => command.execute(engine_privileged_state),
+            PrivilegedCommand::DataTypes(command) => command.execute(engine_privileged_state),
         }
     }
 }
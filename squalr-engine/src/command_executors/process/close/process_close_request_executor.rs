@@ -1,5 +1,7 @@
 use crate::{command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor, engine_privileged_state::EnginePrivilegedState};
 use squalr_engine_api::commands::process::close::{process_close_request::ProcessCloseRequest, process_close_response::ProcessCloseResponse};
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_writer::MemoryWriter;
 use squalr_engine_processes::process_query::process_queryer::ProcessQuery;
 use std::sync::Arc;
 
@@ -25,6 +27,15 @@ impl PrivilegedCommandRequestExecutor for ProcessCloseRequest {
                     engine_privileged_state
                         .get_process_manager()
                         .clear_opened_process();
+                    ProcessQueryCache::get_instance().invalidate();
+
+                    // The modification ledger only applies to the process it was recorded against.
+                    match MemoryWriter::get_write_ledger().write() {
+                        Ok(mut write_ledger) => write_ledger.bind_process(None),
+                        Err(error) => log::error!("Failed to acquire write ledger lock: {}", error),
+                    }
+
+                    engine_privileged_state.get_module_symbol_cache().cancel_all();
                 }
                 Err(error) => {
                     log::error!("Failed to close process handle {}: {}", process_info.get_handle(), error);
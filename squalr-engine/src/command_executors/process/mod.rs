@@ -2,3 +2,4 @@ pub mod close;
 pub mod list;
 pub mod open;
 pub mod process_command_executor;
+pub mod thumbnail;
@@ -3,8 +3,12 @@ use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
 use squalr_engine_api::commands::process::open::process_open_response::ProcessOpenResponse;
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
+use squalr_engine_api::structures::processes::process_attach_mode::ProcessAttachMode;
+use squalr_engine_memory::config::memory_settings_config::MemorySettingsConfig;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::page_retrieval_mode::PageRetrievalMode;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_writer::MemoryWriter;
 use squalr_engine_processes::process_query::process_query_options::ProcessQueryOptions;
 use squalr_engine_processes::process_query::process_queryer::ProcessQuery;
 use std::sync::Arc;
@@ -19,7 +23,10 @@ impl PrivilegedCommandRequestExecutor for ProcessOpenRequest {
     ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
         if self.process_id.is_none() && self.search_name.is_none() {
             log::error!("Error: Neither PID nor search name provided. Cannot open process.");
-            return ProcessOpenResponse { opened_process_info: None };
+            return ProcessOpenResponse {
+                opened_process_info: None,
+                attach_conflict: None,
+            };
         }
 
         log::info!("Opening process...");
@@ -38,9 +45,71 @@ impl PrivilegedCommandRequestExecutor for ProcessOpenRequest {
         if let Some(process_info) = processes.first() {
             match ProcessQuery::open_process(&process_info) {
                 Ok(opened_process_info) => {
+                    // Another Squalr instance attached to the same process would fight this one over every frozen value, so the
+                    // attach lock decides which instance may write, and the process is left unopened if that has to be asked.
+                    let attach_mode = ProcessAttachMode::from_flags(self.read_only, self.takeover);
+                    let is_read_only = match engine_privileged_state
+                        .get_process_manager()
+                        .acquire_attach_lock(opened_process_info.get_process_id_raw(), attach_mode)
+                    {
+                        Ok(is_read_only) => is_read_only,
+                        Err(attach_conflict) => {
+                            log::warn!("{}. Open it read-only or take it over.", attach_conflict);
+
+                            if opened_process_info.get_handle() != 0 {
+                                let _ = ProcessQuery::close_process(opened_process_info.get_handle());
+                            }
+
+                            return ProcessOpenResponse {
+                                opened_process_info: None,
+                                attach_conflict: Some(attach_conflict),
+                            };
+                        }
+                    };
+                    let opened_process_info = opened_process_info.with_read_only(is_read_only);
+
+                    if is_read_only {
+                        log::info!("Attached read-only. Values will not be written or frozen.");
+                    }
+
                     engine_privileged_state
                         .get_process_manager()
                         .set_opened_process(opened_process_info.clone());
+                    ProcessQueryCache::get_instance().invalidate();
+
+                    // The modification ledger only applies to the process it was recorded against.
+                    match MemoryWriter::get_write_ledger().write() {
+                        Ok(mut write_ledger) => write_ledger.bind_process(Some(opened_process_info.get_process_id_raw())),
+                        Err(error) => log::error!("Failed to acquire write ledger lock: {}", error),
+                    }
+
+                    engine_privileged_state.get_module_symbol_cache().cancel_all();
+
+                    if MemorySettingsConfig::get_preload_main_module_symbols() {
+                        let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+
+                        match modules.iter().find(|module| {
+                            module
+                                .get_module_name()
+                                .eq_ignore_ascii_case(opened_process_info.get_name())
+                        }) {
+                            Some(main_module) => engine_privileged_state
+                                .get_module_symbol_cache()
+                                .preload_module(&opened_process_info, main_module),
+                            None => log::warn!("Main module not found for '{}'; symbols will load on demand.", opened_process_info.get_name()),
+                        }
+                    }
+
+                    if opened_process_info.get_bitness().is_narrower_than_host() {
+                        let address_space_bounds = MemoryQueryer::get_address_space_bounds(&opened_process_info);
+
+                        log::info!(
+                            "Attached to a 32-bit process. Using {}-byte pointers and usermode range 0x{:X}-0x{:X}.",
+                            address_space_bounds.get_pointer_size(),
+                            address_space_bounds.get_min_usermode_address(),
+                            address_space_bounds.get_max_usermode_address()
+                        );
+                    }
 
                     // Best-effort sanity check: some protected processes can be opened with limited rights but still
                     // deny VirtualQueryEx/ReadProcessMemory, which leads to "0 bytes read" and empty scans.
@@ -69,6 +138,7 @@ impl PrivilegedCommandRequestExecutor for ProcessOpenRequest {
 
                     return ProcessOpenResponse {
                         opened_process_info: Some(opened_process_info),
+                        attach_conflict: None,
                     };
                 }
                 Err(error) => {
@@ -79,6 +149,9 @@ impl PrivilegedCommandRequestExecutor for ProcessOpenRequest {
             log::error!("No matching process found.");
         }
 
-        ProcessOpenResponse { opened_process_info: None }
+        ProcessOpenResponse {
+            opened_process_info: None,
+            attach_conflict: None,
+        }
     }
 }
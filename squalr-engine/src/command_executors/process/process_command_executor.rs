@@ -22,6 +22,9 @@ impl PrivilegedCommandExecutor for ProcessCommand {
             ProcessCommand::Close { process_close_request } => process_close_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ProcessCommand::Thumbnail { process_thumbnail_request } => process_thumbnail_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
@@ -0,0 +1 @@
+pub mod process_thumbnail_request_executor;
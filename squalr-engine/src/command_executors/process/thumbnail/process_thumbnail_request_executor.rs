@@ -0,0 +1,23 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::process::thumbnail::process_thumbnail_request::ProcessThumbnailRequest;
+use squalr_engine_api::commands::process::thumbnail::process_thumbnail_response::ProcessThumbnailResponse;
+use squalr_engine_processes::process_query::process_queryer::ProcessQuery;
+use std::sync::Arc;
+use sysinfo::Pid;
+
+impl PrivilegedCommandRequestExecutor for ProcessThumbnailRequest {
+    type ResponseType = ProcessThumbnailResponse;
+
+    fn execute(
+        &self,
+        _engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let thumbnail = ProcessQuery::capture_thumbnail(&Pid::from_u32(self.process_id), self.max_width, self.max_height);
+
+        ProcessThumbnailResponse {
+            process_id: self.process_id,
+            thumbnail,
+        }
+    }
+}
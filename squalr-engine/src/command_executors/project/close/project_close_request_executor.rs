@@ -16,10 +16,12 @@ impl UnprivilegedCommandRequestExecutor for ProjectCloseRequest {
 
         if let Ok(mut opened_project) = opened_project.write() {
             *opened_project = None;
-
-            ProjectCloseResponse { success: true }
         } else {
-            ProjectCloseResponse { success: false }
+            return ProjectCloseResponse { success: false };
         }
+
+        project_manager.sync_disabled_addresses(engine_unprivileged_state);
+
+        ProjectCloseResponse { success: true }
     }
 }
@@ -49,6 +49,9 @@ impl UnprivilegedCommandRequestExecutor for ProjectOpenRequest {
         match Project::load_from_path(&project_directory_path) {
             Ok(project) => {
                 *opened_project = Some(project);
+                drop(opened_project);
+                project_manager.sync_disabled_addresses(engine_unprivileged_state);
+
                 ProjectOpenResponse { success: true }
             }
             Err(error) => {
@@ -0,0 +1 @@
+pub mod project_items_create_group_request_executor;
@@ -0,0 +1,53 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::create_group::project_items_create_group_request::ProjectItemsCreateGroupRequest;
+use squalr_engine_api::commands::project_items::create_group::project_items_create_group_response::ProjectItemsCreateGroupResponse;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsCreateGroupRequest {
+    type ResponseType = ProjectItemsCreateGroupResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let group_ref = {
+            let opened_project = project_manager.get_opened_project();
+            let mut opened_project = match opened_project.write() {
+                Ok(opened_project) => opened_project,
+                Err(error) => {
+                    log::error!("Failed to acquire opened project: {}", error);
+                    return ProjectItemsCreateGroupResponse::default();
+                }
+            };
+            let opened_project = match opened_project.as_mut() {
+                Some(opened_project) => opened_project,
+                None => {
+                    log::error!("Unable to create project group, no opened project.");
+                    return ProjectItemsCreateGroupResponse::default();
+                }
+            };
+            let parent_group_ref = match &self.parent_group_path {
+                Some(parent_group_path) => ProjectItemRef::new(PathBuf::from(parent_group_path)),
+                None => opened_project.get_project_root_ref().clone(),
+            };
+
+            if !opened_project.is_project_group(&parent_group_ref) {
+                log::error!("Unable to create project group, {:?} is not a group.", parent_group_ref.get_project_item_path());
+                return ProjectItemsCreateGroupResponse::default();
+            }
+
+            opened_project.add_project_group_to_group_with_unique_name(&parent_group_ref, &self.group_name)
+        };
+
+        project_manager.notify_project_items_changed();
+
+        ProjectItemsCreateGroupResponse {
+            success: true,
+            group_path: Some(group_ref.get_project_item_path().to_string_lossy().to_string()),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod project_items_delete_request_executor;
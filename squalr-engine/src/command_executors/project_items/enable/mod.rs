@@ -0,0 +1 @@
+pub mod project_items_enable_request_executor;
@@ -0,0 +1,57 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::enable::project_items_enable_request::ProjectItemsEnableRequest;
+use squalr_engine_api::commands::project_items::enable::project_items_enable_response::ProjectItemsEnableResponse;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsEnableRequest {
+    type ResponseType = ProjectItemsEnableResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let mut success = true;
+
+        {
+            let opened_project = project_manager.get_opened_project();
+            let mut opened_project = match opened_project.write() {
+                Ok(opened_project) => opened_project,
+                Err(error) => {
+                    log::error!("Failed to acquire opened project: {}", error);
+                    return ProjectItemsEnableResponse { success: false };
+                }
+            };
+            let opened_project = match opened_project.as_mut() {
+                Some(opened_project) => opened_project,
+                None => {
+                    log::error!("Unable to enable project items, no opened project.");
+                    return ProjectItemsEnableResponse { success: false };
+                }
+            };
+
+            for project_item_path in &self.project_item_paths {
+                let project_item_ref = ProjectItemRef::new(PathBuf::from(project_item_path));
+
+                let is_found = if self.is_recursive {
+                    opened_project.set_project_item_enabled_recursive(&project_item_ref, self.is_enabled)
+                } else {
+                    opened_project.set_project_item_enabled(&project_item_ref, self.is_enabled)
+                };
+
+                if !is_found {
+                    log::error!("Failed to find project item: {}", project_item_path);
+                    success = false;
+                }
+            }
+        }
+
+        project_manager.notify_project_items_changed();
+        project_manager.sync_disabled_addresses(engine_unprivileged_state);
+
+        ProjectItemsEnableResponse { success }
+    }
+}
@@ -1,3 +1,9 @@
 pub mod activate;
+pub mod create_group;
+pub mod delete;
+pub mod enable;
 pub mod list;
+pub mod move_to_group;
 pub mod project_items_executor;
+pub mod remove_hotkey;
+pub mod set_hotkey;
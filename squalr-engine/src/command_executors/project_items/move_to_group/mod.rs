@@ -0,0 +1 @@
+pub mod project_items_move_to_group_request_executor;
@@ -0,0 +1,62 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::move_to_group::project_items_move_to_group_request::ProjectItemsMoveToGroupRequest;
+use squalr_engine_api::commands::project_items::move_to_group::project_items_move_to_group_response::ProjectItemsMoveToGroupResponse;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsMoveToGroupRequest {
+    type ResponseType = ProjectItemsMoveToGroupResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let mut success = true;
+
+        {
+            let opened_project = project_manager.get_opened_project();
+            let mut opened_project = match opened_project.write() {
+                Ok(opened_project) => opened_project,
+                Err(error) => {
+                    log::error!("Failed to acquire opened project: {}", error);
+                    return ProjectItemsMoveToGroupResponse { success: false };
+                }
+            };
+            let opened_project = match opened_project.as_mut() {
+                Some(opened_project) => opened_project,
+                None => {
+                    log::error!("Unable to move project items, no opened project.");
+                    return ProjectItemsMoveToGroupResponse { success: false };
+                }
+            };
+            let target_group_ref = match &self.target_group_path {
+                Some(target_group_path) => ProjectItemRef::new(PathBuf::from(target_group_path)),
+                None => opened_project.get_project_root_ref().clone(),
+            };
+
+            for project_item_path in &self.project_item_paths {
+                let project_item_ref = ProjectItemRef::new(PathBuf::from(project_item_path));
+
+                if opened_project
+                    .move_project_item(&project_item_ref, &target_group_ref)
+                    .is_none()
+                {
+                    log::error!(
+                        "Failed to move project item {} into {:?}.",
+                        project_item_path,
+                        target_group_ref.get_project_item_path()
+                    );
+                    success = false;
+                }
+            }
+        }
+
+        project_manager.notify_project_items_changed();
+        project_manager.sync_disabled_addresses(engine_unprivileged_state);
+
+        ProjectItemsMoveToGroupResponse { success }
+    }
+}
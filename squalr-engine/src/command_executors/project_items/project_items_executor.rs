@@ -18,9 +18,35 @@ impl UnprivilegedCommandExecutor for ProjectItemsCommand {
             } => project_items_activate_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectItemsCommand::CreateGroup {
+                project_items_create_group_request,
+            } => project_items_create_group_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::Delete { project_items_delete_request } => project_items_delete_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::Enable { project_items_enable_request } => project_items_enable_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
             ProjectItemsCommand::List { project_items_list_request } => project_items_list_request
                 .execute(engine_unprivileged_state)
                 .to_engine_response(),
+            ProjectItemsCommand::MoveToGroup {
+                project_items_move_to_group_request,
+            } => project_items_move_to_group_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::RemoveHotkey {
+                project_items_remove_hotkey_request,
+            } => project_items_remove_hotkey_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
+            ProjectItemsCommand::SetHotkey {
+                project_items_set_hotkey_request,
+            } => project_items_set_hotkey_request
+                .execute(engine_unprivileged_state)
+                .to_engine_response(),
         }
     }
 }
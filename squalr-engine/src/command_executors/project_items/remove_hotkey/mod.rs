@@ -0,0 +1 @@
+pub mod project_items_remove_hotkey_request_executor;
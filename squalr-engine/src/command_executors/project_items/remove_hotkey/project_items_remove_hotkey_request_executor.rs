@@ -0,0 +1,47 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::remove_hotkey::project_items_remove_hotkey_request::ProjectItemsRemoveHotkeyRequest;
+use squalr_engine_api::commands::project_items::remove_hotkey::project_items_remove_hotkey_response::ProjectItemsRemoveHotkeyResponse;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsRemoveHotkeyRequest {
+    type ResponseType = ProjectItemsRemoveHotkeyResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+
+        let success = {
+            let opened_project = project_manager.get_opened_project();
+            let mut opened_project = match opened_project.write() {
+                Ok(opened_project) => opened_project,
+                Err(error) => {
+                    log::error!("Failed to acquire opened project: {}", error);
+                    return ProjectItemsRemoveHotkeyResponse { success: false };
+                }
+            };
+            let opened_project = match opened_project.as_mut() {
+                Some(opened_project) => opened_project,
+                None => {
+                    log::error!("Unable to remove project item hotkey, no opened project.");
+                    return ProjectItemsRemoveHotkeyResponse { success: false };
+                }
+            };
+            let project_item_ref = ProjectItemRef::new(PathBuf::from(&self.project_item_path));
+
+            opened_project.remove_project_item_hotkey(&project_item_ref, &self.chord)
+        };
+
+        if success {
+            project_manager.notify_project_items_changed();
+        } else {
+            log::error!("The hotkey {} is not bound to project item: {}", self.chord, self.project_item_path);
+        }
+
+        ProjectItemsRemoveHotkeyResponse { success }
+    }
+}
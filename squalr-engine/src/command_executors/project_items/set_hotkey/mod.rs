@@ -0,0 +1 @@
+pub mod project_items_set_hotkey_request_executor;
@@ -0,0 +1,97 @@
+use crate::command_executors::unprivileged_request_executor::UnprivilegedCommandRequestExecutor;
+use squalr_engine_api::commands::project_items::set_hotkey::project_items_set_hotkey_request::ProjectItemsSetHotkeyRequest;
+use squalr_engine_api::commands::project_items::set_hotkey::project_items_set_hotkey_response::ProjectItemsSetHotkeyResponse;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::project_item_hotkey::{ProjectItemHotkey, ProjectItemHotkeyAction};
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+impl UnprivilegedCommandRequestExecutor for ProjectItemsSetHotkeyRequest {
+    type ResponseType = ProjectItemsSetHotkeyResponse;
+
+    fn execute(
+        &self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> <Self as UnprivilegedCommandRequestExecutor>::ResponseType {
+        let project_manager = engine_unprivileged_state.get_project_manager();
+
+        let result = {
+            let opened_project = project_manager.get_opened_project();
+            let mut opened_project = match opened_project.write() {
+                Ok(opened_project) => opened_project,
+                Err(error) => {
+                    log::error!("Failed to acquire opened project: {}", error);
+                    return ProjectItemsSetHotkeyResponse {
+                        success: false,
+                        error_message: Some("Failed to acquire the opened project.".to_string()),
+                    };
+                }
+            };
+            let opened_project = match opened_project.as_mut() {
+                Some(opened_project) => opened_project,
+                None => {
+                    log::error!("Unable to set project item hotkey, no opened project.");
+                    return ProjectItemsSetHotkeyResponse {
+                        success: false,
+                        error_message: Some("No project is open.".to_string()),
+                    };
+                }
+            };
+            let project_item_ref = ProjectItemRef::new(PathBuf::from(&self.project_item_path));
+
+            // Reject values that cannot be written now, rather than silently failing each time the chord is pressed.
+            validate_action(opened_project, &project_item_ref, &self.action)
+                .and_then(|_| opened_project.set_project_item_hotkey(&project_item_ref, ProjectItemHotkey::new(self.chord.clone(), self.action.clone())))
+        };
+
+        match result {
+            Ok(()) => {
+                project_manager.notify_project_items_changed();
+
+                ProjectItemsSetHotkeyResponse {
+                    success: true,
+                    error_message: None,
+                }
+            }
+            Err(error) => ProjectItemsSetHotkeyResponse {
+                success: false,
+                error_message: Some(error),
+            },
+        }
+    }
+}
+
+/// Checks that the entry is an address, and that a value to set is valid for the data type of the entry.
+fn validate_action(
+    project: &Project,
+    project_item_ref: &ProjectItemRef,
+    action: &ProjectItemHotkeyAction,
+) -> Result<(), String> {
+    let project_item = project
+        .get_project_item(project_item_ref)
+        .ok_or_else(|| format!("The entry '{}' no longer exists.", project_item_ref.get_project_item_path().display()))?;
+
+    if project_item.get_item_type().get_project_item_type_id() != ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID {
+        return Err(format!(
+            "Hotkeys can only be bound to address entries, and '{}' is not one.",
+            project_item.get_field_name()
+        ));
+    }
+
+    if let ProjectItemHotkeyAction::SetValue { value } = action {
+        let data_type_ref = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)
+            .map(|symbolic_struct_ref| DataTypeRef::new(symbolic_struct_ref.get_symbolic_struct_namespace()))
+            .ok_or_else(|| format!("The entry '{}' has no data type.", project_item.get_field_name()))?;
+
+        SymbolRegistry::get_instance()
+            .deanonymize_value_string(&data_type_ref, value)
+            .map_err(|error| format!("'{}' is not a valid {}: {}", value.get_anonymous_value_string(), data_type_ref, error))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1 @@
+pub mod scan_benchmark_request_executor;
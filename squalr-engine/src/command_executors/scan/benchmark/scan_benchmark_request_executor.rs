@@ -0,0 +1,102 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::element_scan::element_scan_request_executor::create_scan_constraints_by_data_type;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::benchmark::scan_benchmark_request::ScanBenchmarkRequest;
+use squalr_engine_api::commands::scan::benchmark::scan_benchmark_response::ScanBenchmarkResponse;
+use squalr_engine_api::events::scan_benchmark::finished::scan_benchmark_finished_event::ScanBenchmarkFinishedEvent;
+use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::scanning::memory_read_mode::MemoryReadMode;
+use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
+use squalr_engine_scanning::benchmarks::scan_configuration_benchmark_task::ScanConfigurationBenchmarkTask;
+use std::sync::Arc;
+use std::thread;
+
+impl PrivilegedCommandRequestExecutor for ScanBenchmarkRequest {
+    type ResponseType = ScanBenchmarkResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        if self.size_mb == 0 {
+            log::error!("Scan benchmark was not started, as the snapshot size must be at least 1 MB.");
+            return ScanBenchmarkResponse::default();
+        }
+
+        let finished_engine_state = engine_privileged_state.clone();
+        let task = ScanConfigurationBenchmarkTask::start_task(
+            self.size_mb * 1024 * 1024,
+            create_benchmark_scan_plan,
+            move |task, scan_configuration_benchmarks, was_cancelled| {
+                finished_engine_state.emit_event(ScanBenchmarkFinishedEvent {
+                    task_id: task.get_task_identifier(),
+                    scan_configuration_benchmarks,
+                    was_cancelled,
+                });
+            },
+        );
+        let task_handle = task.get_task_handle();
+        let task_id = task.get_task_identifier();
+        let progress_receiver = task.subscribe_to_progress_updates();
+        let progress_engine_state = engine_privileged_state.clone();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            while let Ok(progress) = progress_receiver.recv() {
+                progress_engine_state.emit_event(TrackableTaskProgressChangedEvent {
+                    task_id: task_id.clone(),
+                    progress,
+                    memory_usage_bytes: None,
+                });
+            }
+        });
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+        });
+
+        ScanBenchmarkResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
+
+/// Creates the plan of an exact value scan for a 4 byte integer, which is the most common scan, and which every vector width can filter.
+/// Every setting that affects the scan is pinned, such that the user's scan settings do not skew the measurement.
+fn create_benchmark_scan_plan() -> ElementScanPlan {
+    let floating_point_tolerance = FloatingPointTolerance::default();
+    let scan_constraints = [AnonymousScanConstraint::new(
+        ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+        Some(AnonymousValueString::new(
+            "100".to_string(),
+            AnonymousValueStringFormat::Decimal,
+            ContainerType::None,
+        )),
+    )];
+
+    ElementScanPlan::new(
+        create_scan_constraints_by_data_type(&scan_constraints, &[DataTypeRef::new(DataTypeI32::DATA_TYPE_ID)], floating_point_tolerance),
+        MemoryAlignment::Alignment4,
+        floating_point_tolerance,
+        MemoryReadMode::Skip,
+        false,
+        false,
+    )
+}
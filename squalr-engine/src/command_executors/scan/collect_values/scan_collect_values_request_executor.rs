@@ -32,7 +32,17 @@ impl PrivilegedCommandRequestExecutor for ScanCollectValuesRequest {
                 engine_privileged_state
                     .get_trackable_task_manager()
                     .unregister_task(&task.get_task_identifier());
-                engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+                let scan_generation = engine_privileged_state
+                    .get_snapshot()
+                    .read()
+                    .map(|snapshot| snapshot.get_scan_generation())
+                    .unwrap_or_default();
+
+                engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                    is_new_scan: false,
+                    scan_generation,
+                });
             });
 
             ScanCollectValuesResponse {
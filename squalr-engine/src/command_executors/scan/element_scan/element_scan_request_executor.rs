@@ -5,11 +5,15 @@ use squalr_engine_api::commands::scan::element_scan::element_scan_response::Elem
 use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
 use squalr_engine_api::structures::memory::memory_alignment::MemoryAlignment;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
 use squalr_engine_api::structures::scanning::constraints::scan_constraint_finalized::ScanConstraintFinalized;
 use squalr_engine_api::structures::scanning::plans::element_scan::element_scan_plan::ElementScanPlan;
 use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
 use squalr_engine_scanning::scanners::element_scan_executor_task::ElementScanExecutorTask;
+use squalr_engine_scanning::snapshot_monitor::snapshot_layout_monitor::SnapshotLayoutMonitor;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::thread;
@@ -40,96 +44,21 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
                 thread::sleep(std::time::Duration::from_millis(repeat_delay_ms));
             }
 
-            let fast_scan_enabled = ScanSettingsConfig::get_fast_scan_enabled();
-            let fast_scan_alignment = ScanSettingsConfig::get_fast_scan_alignment();
-            let fast_scan_last_digits = ScanSettingsConfig::get_fast_scan_last_digits();
-            let explicit_alignment = ScanSettingsConfig::get_memory_alignment();
-            let symbol_registry = SymbolRegistry::get_instance();
-            let alignment = match explicit_alignment {
-                Some(alignment) => alignment,
+            let element_scan_plan = if self.is_unknown_initial_scan {
+                create_unknown_initial_value_scan_plan(&self.data_type_refs)
+            } else {
+                create_element_scan_plan(&self.scan_constraints, &self.data_type_refs)
+            };
+            let element_scan_plan = match element_scan_plan {
+                Some(element_scan_plan) => element_scan_plan,
                 None => {
-                    if fast_scan_enabled {
-                        if let Some(fast_alignment) = fast_scan_alignment {
-                            fast_alignment
-                        } else if fast_scan_last_digits.is_some() {
-                            MemoryAlignment::Alignment16
-                        } else {
-                            let mut size: Option<i32> = None;
-                            let mut mixed_sizes = false;
-                            for data_type_ref in &self.data_type_refs {
-                                if let Some(unit_size) = symbol_registry.get_unit_size_in_bytes(data_type_ref).try_into().ok() {
-                                    if let Some(existing) = size {
-                                        if existing != unit_size {
-                                            mixed_sizes = true;
-                                            break;
-                                        }
-                                    } else {
-                                        size = Some(unit_size);
-                                    }
-                                }
-                            }
-
-                            if mixed_sizes {
-                                MemoryAlignment::Alignment1
-                            } else {
-                                size.map(MemoryAlignment::from).unwrap_or(MemoryAlignment::Alignment1)
-                            }
-                        }
-                    } else {
-                        MemoryAlignment::Alignment1
-                    }
+                    log::error!("No valid scan constraints after parsing; aborting scan.");
+                    return ElementScanResponse { trackable_task_handle: None };
                 }
             };
-            let floating_point_tolerance = ScanSettingsConfig::get_floating_point_tolerance();
-            let memory_read_mode = ScanSettingsConfig::get_memory_read_mode();
-            let is_single_thread_scan = ScanSettingsConfig::get_is_single_threaded_scan();
-            let debug_perform_validation_scan = ScanSettingsConfig::get_debug_perform_validation_scan();
-
-            // Deanonymize all scan constraints against all data types.
-            // For example, an immediate comparison of >= 23 could end up being a byte, float, etc.
-            let scan_constraints_by_data_type: HashMap<_, _> = self
-                .data_type_refs
-                .iter()
-                .map(|data_type_ref| {
-                    // Deanonymize the initial anonymous scan constraints against the current data type.
-                    let scan_constraints = self
-                        .scan_constraints
-                        .iter()
-                        .filter_map(|anonymous_scan_constraint| anonymous_scan_constraint.deanonymize_constraint(data_type_ref, floating_point_tolerance))
-                        .collect();
-
-                    // Optimize the scan constraints by running them through each parameter rule sequentially.
-                    let scan_constraints_finalized: Vec<ScanConstraintFinalized> = ElementScanRuleRegistry::get_instance()
-                        .get_scan_parameters_rule_registry()
-                        .iter()
-                        .fold(scan_constraints, |mut scan_constraint, (_id, scan_parameter_rule)| {
-                            scan_parameter_rule.map_parameters(&mut scan_constraint);
-                            scan_constraint
-                        })
-                        .into_iter()
-                        .map(|scan_constraint| ScanConstraintFinalized::new(scan_constraint))
-                        .collect();
-
-                    (data_type_ref.clone(), scan_constraints_finalized)
-                })
-                .collect();
 
-            if scan_constraints_by_data_type
-                .values()
-                .all(|constraints| constraints.is_empty())
-            {
-                log::error!("No valid scan constraints after parsing; aborting scan.");
-                return ElementScanResponse { trackable_task_handle: None };
-            }
-
-            let element_scan_plan = ElementScanPlan::new(
-                scan_constraints_by_data_type,
-                alignment,
-                floating_point_tolerance,
-                memory_read_mode,
-                is_single_thread_scan,
-                debug_perform_validation_scan,
-            );
+            // Flag results whose memory was freed or re-protected since the last scan, as their values can no longer be trusted.
+            SnapshotLayoutMonitor::check_snapshot(&process_info, &snapshot);
 
             // Start the task to perform the scan.
             let task = ElementScanExecutorTask::start_task(process_info, snapshot, element_scan_plan, true);
@@ -145,7 +74,17 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
                 engine_privileged_state
                     .get_trackable_task_manager()
                     .unregister_task(&task.get_task_identifier());
-                engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+
+                let scan_generation = engine_privileged_state
+                    .get_snapshot()
+                    .read()
+                    .map(|snapshot| snapshot.get_scan_generation())
+                    .unwrap_or_default();
+
+                engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                    is_new_scan: false,
+                    scan_generation,
+                });
             });
 
             ElementScanResponse {
@@ -157,3 +96,143 @@ impl PrivilegedCommandRequestExecutor for ElementScanRequest {
         }
     }
 }
+
+/// Builds an element scan plan from anonymous scan constraints, resolving alignment from the scan settings.
+/// Returns `None` if no constraint is valid for any of the requested data types.
+pub fn create_element_scan_plan(
+    scan_constraints: &[AnonymousScanConstraint],
+    data_type_refs: &[DataTypeRef],
+) -> Option<ElementScanPlan> {
+    let floating_point_tolerance = ScanSettingsConfig::get_floating_point_tolerance();
+    let scan_constraints_by_data_type = create_scan_constraints_by_data_type(scan_constraints, data_type_refs, floating_point_tolerance);
+
+    if scan_constraints_by_data_type
+        .values()
+        .all(|constraints| constraints.is_empty())
+    {
+        return None;
+    }
+
+    Some(create_element_scan_plan_with_settings(
+        scan_constraints_by_data_type,
+        data_type_refs,
+        floating_point_tolerance,
+    ))
+}
+
+/// Builds an element scan plan that keeps every element of the requested data types, for a first scan of an unknown initial value.
+/// Returns `None` if no data type was requested.
+pub fn create_unknown_initial_value_scan_plan(data_type_refs: &[DataTypeRef]) -> Option<ElementScanPlan> {
+    if data_type_refs.is_empty() {
+        return None;
+    }
+
+    let floating_point_tolerance = ScanSettingsConfig::get_floating_point_tolerance();
+    let scan_constraints_by_data_type = data_type_refs
+        .iter()
+        .map(|data_type_ref| (data_type_ref.clone(), Vec::new()))
+        .collect();
+
+    Some(create_element_scan_plan_with_settings(
+        scan_constraints_by_data_type,
+        data_type_refs,
+        floating_point_tolerance,
+    ))
+}
+
+/// Wraps finalized scan constraints in an element scan plan, resolving alignment and the remaining parameters from the scan settings.
+fn create_element_scan_plan_with_settings(
+    scan_constraints_by_data_type: HashMap<DataTypeRef, Vec<ScanConstraintFinalized>>,
+    data_type_refs: &[DataTypeRef],
+    floating_point_tolerance: FloatingPointTolerance,
+) -> ElementScanPlan {
+    let fast_scan_enabled = ScanSettingsConfig::get_fast_scan_enabled();
+    let fast_scan_alignment = ScanSettingsConfig::get_fast_scan_alignment();
+    let fast_scan_last_digits = ScanSettingsConfig::get_fast_scan_last_digits();
+    let explicit_alignment = ScanSettingsConfig::get_memory_alignment();
+    let symbol_registry = SymbolRegistry::get_instance();
+    let alignment = match explicit_alignment {
+        Some(alignment) => alignment,
+        None => {
+            if fast_scan_enabled {
+                if let Some(fast_alignment) = fast_scan_alignment {
+                    fast_alignment
+                } else if fast_scan_last_digits.is_some() {
+                    MemoryAlignment::Alignment16
+                } else {
+                    let mut size: Option<i32> = None;
+                    let mut mixed_sizes = false;
+                    for data_type_ref in data_type_refs {
+                        if let Ok(unit_size) = symbol_registry.get_unit_size_in_bytes(data_type_ref).try_into() {
+                            if let Some(existing) = size {
+                                if existing != unit_size {
+                                    mixed_sizes = true;
+                                    break;
+                                }
+                            } else {
+                                size = Some(unit_size);
+                            }
+                        }
+                    }
+
+                    if mixed_sizes {
+                        MemoryAlignment::Alignment1
+                    } else {
+                        size.map(MemoryAlignment::from)
+                            .unwrap_or(MemoryAlignment::Alignment1)
+                    }
+                }
+            } else {
+                MemoryAlignment::Alignment1
+            }
+        }
+    };
+    let memory_read_mode = ScanSettingsConfig::get_memory_read_mode();
+    let is_single_thread_scan = ScanSettingsConfig::get_is_single_threaded_scan();
+    let debug_perform_validation_scan = ScanSettingsConfig::get_debug_perform_validation_scan();
+
+    ElementScanPlan::new(
+        scan_constraints_by_data_type,
+        alignment,
+        floating_point_tolerance,
+        memory_read_mode,
+        is_single_thread_scan,
+        debug_perform_validation_scan,
+    )
+    .with_scan_thread_count(ScanSettingsConfig::get_scan_thread_count())
+    .with_vector_width(ScanSettingsConfig::get_vector_width())
+}
+
+/// Deanonymizes the scan constraints against every requested data type, then optimizes them with the scan parameter rules.
+pub fn create_scan_constraints_by_data_type(
+    scan_constraints: &[AnonymousScanConstraint],
+    data_type_refs: &[DataTypeRef],
+    floating_point_tolerance: FloatingPointTolerance,
+) -> HashMap<DataTypeRef, Vec<ScanConstraintFinalized>> {
+    // Deanonymize all scan constraints against all data types.
+    // For example, an immediate comparison of >= 23 could end up being a byte, float, etc.
+    data_type_refs
+        .iter()
+        .map(|data_type_ref| {
+            // Deanonymize the initial anonymous scan constraints against the current data type.
+            let scan_constraints = scan_constraints
+                .iter()
+                .filter_map(|anonymous_scan_constraint| anonymous_scan_constraint.deanonymize_constraint(data_type_ref, floating_point_tolerance))
+                .collect();
+
+            // Optimize the scan constraints by running them through each parameter rule sequentially.
+            let scan_constraints_finalized: Vec<ScanConstraintFinalized> = ElementScanRuleRegistry::get_instance()
+                .get_scan_parameters_rule_registry()
+                .iter()
+                .fold(scan_constraints, |mut scan_constraint, (_id, scan_parameter_rule)| {
+                    scan_parameter_rule.map_parameters(&mut scan_constraint);
+                    scan_constraint
+                })
+                .into_iter()
+                .map(ScanConstraintFinalized::new)
+                .collect();
+
+            (data_type_ref.clone(), scan_constraints_finalized)
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::element_scan::element_scan_request_executor::create_element_scan_plan;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::element_scan_preview::element_scan_preview_request::ElementScanPreviewRequest;
+use squalr_engine_api::commands::scan::element_scan_preview::element_scan_preview_response::ElementScanPreviewResponse;
+use squalr_engine_scanning::scanners::element_scan_executor_task::ElementScanExecutorTask;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ElementScanPreviewRequest {
+    type ResponseType = ElementScanPreviewResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let current_result_count = snapshot
+            .read()
+            .map(|guard| guard.get_number_of_results())
+            .unwrap_or(0);
+        let unavailable_response = ElementScanPreviewResponse {
+            current_result_count,
+            preview_result_count: None,
+        };
+
+        // With no results, the next scan is a first scan over all memory, which is too costly to preview.
+        if current_result_count == 0 || current_result_count > ElementScanPreviewRequest::MAX_PREVIEW_RESULT_COUNT {
+            return unavailable_response;
+        }
+
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            return unavailable_response;
+        };
+
+        let Some(element_scan_plan) = create_element_scan_plan(&self.scan_constraints, &self.data_type_refs) else {
+            return unavailable_response;
+        };
+
+        let preview_result_count = ElementScanExecutorTask::preview_result_count(&process_info, &snapshot, &element_scan_plan);
+
+        ElementScanPreviewResponse {
+            current_result_count,
+            preview_result_count: Some(preview_result_count),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod element_scan_preview_request_executor;
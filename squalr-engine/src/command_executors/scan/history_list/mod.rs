@@ -0,0 +1 @@
+pub mod scan_history_list_request_executor;
@@ -0,0 +1,28 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::history_list::scan_history_list_request::ScanHistoryListRequest;
+use squalr_engine_api::commands::scan::history_list::scan_history_list_response::ScanHistoryListResponse;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanHistoryListRequest {
+    type ResponseType = ScanHistoryListResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot = match snapshot.read() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on snapshot: {}", error);
+
+                return ScanHistoryListResponse::default();
+            }
+        };
+
+        ScanHistoryListResponse {
+            scan_history_items: snapshot.get_scan_history_items(),
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod scan_history_revert_request_executor;
@@ -0,0 +1,42 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::history_revert::scan_history_revert_request::ScanHistoryRevertRequest;
+use squalr_engine_api::commands::scan::history_revert::scan_history_revert_response::ScanHistoryRevertResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanHistoryRevertRequest {
+    type ResponseType = ScanHistoryRevertResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let mut snapshot = match snapshot.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanHistoryRevertResponse::default();
+            }
+        };
+
+        if !snapshot.revert_to_scan(self.scan_number) {
+            log::error!("Cannot revert to scan {}, as it is not in the scan history.", self.scan_number);
+
+            return ScanHistoryRevertResponse::default();
+        }
+
+        let result_count = snapshot.get_number_of_results();
+
+        log::info!("Reverted to scan {} with {} result(s).", self.scan_number, result_count);
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+            is_new_scan: false,
+            scan_generation: snapshot.get_scan_generation(),
+        });
+
+        ScanHistoryRevertResponse { success: true, result_count }
+    }
+}
@@ -1,7 +1,16 @@
+pub mod benchmark;
 pub mod collect_values;
 pub mod element_scan;
+pub mod element_scan_preview;
+pub mod history_list;
+pub mod history_revert;
 pub mod new;
 pub mod pointer_scan;
+pub mod pointer_scan_rescan;
 pub mod reset;
+pub mod revalidate;
 pub mod scan_command_executor;
+pub mod snapshot_compare;
+pub mod snapshot_save;
+pub mod stride_scan;
 pub mod struct_scan;
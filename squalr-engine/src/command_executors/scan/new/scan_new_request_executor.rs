@@ -50,6 +50,14 @@ impl PrivilegedCommandRequestExecutor for ScanNewRequest {
         // Best-effort to clear the freeze list.
         freeze_list_registry_guard.clear();
 
+        // Statistics from scans of the previous snapshot say nothing about the new one.
+        snapshot.set_last_scan_statistics(None);
+        snapshot.clear_data_type_overrides();
+        snapshot.clear_stride_repeat_counts();
+        snapshot.clear_scan_history();
+        snapshot.take_stale_region_changes();
+        snapshot.advance_scan_generation();
+
         // Query all memory pages for the process from the OS.
         let mut memory_pages = MemoryQueryer::get_memory_page_bounds(&opened_process_info, PageRetrievalMode::FromSettings);
         if let Some(last_digit) = ScanSettingsConfig::get_fast_scan_last_digits() {
@@ -95,7 +103,10 @@ impl PrivilegedCommandRequestExecutor for ScanNewRequest {
 
         if memory_pages.is_empty() {
             snapshot.set_snapshot_regions(vec![]);
-            engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: true });
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                is_new_scan: true,
+                scan_generation: snapshot.get_scan_generation(),
+            });
             return ScanNewResponse {};
         }
 
@@ -129,7 +140,10 @@ impl PrivilegedCommandRequestExecutor for ScanNewRequest {
             // Update snapshot with new merged regions.
             snapshot.set_snapshot_regions(merged_snapshot_regions);
 
-            engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: true });
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                is_new_scan: true,
+                scan_generation: snapshot.get_scan_generation(),
+            });
         }
 
         ScanNewResponse {}
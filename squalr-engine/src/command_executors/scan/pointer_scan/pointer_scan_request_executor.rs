@@ -6,6 +6,7 @@ use squalr_engine_api::events::pointer_scan_results::updated::pointer_scan_resul
 use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
 use squalr_engine_api::structures::pointer_scan::pointer_scan_results::PointerScanResults;
 use squalr_engine_api::structures::scanning::plans::pointer_scan::pointer_scan_parameters::PointerScanParameters;
+use squalr_engine_api::structures::scanning::plans::pointer_scan::pointer_validity_filters::PointerValidityFilters;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
 use squalr_engine_api::structures::snapshots::snapshot_region::SnapshotRegion;
 use squalr_engine_scanning::pointer_scans::pointer_scan_executor_task::PointerScanExecutorTask;
@@ -47,10 +48,16 @@ impl PrivilegedCommandRequestExecutor for PointerScanRequest {
         let scan_parameters = PointerScanParameters::new(
             target_address,
             self.pointer_data_type_ref.clone(),
-            self.offset_size,
+            self.min_offset.unwrap_or(0),
+            self.max_offset
+                .unwrap_or(self.offset_size.min(i64::MAX as u64) as i64),
+            self.max_offsets_per_level,
             self.max_depth,
             self.scan_statics,
             self.scan_heaps,
+            PointerValidityFilters::new(self.pointer_alignment, !self.allow_unmapped_targets, self.require_aligned_targets),
+            self.max_memory_bytes
+                .unwrap_or(PointerScanParameters::DEFAULT_MAX_MEMORY_BYTES),
             ScanSettingsConfig::get_is_single_threaded_scan(),
             ScanSettingsConfig::get_debug_perform_validation_scan(),
         );
@@ -61,8 +68,8 @@ impl PrivilegedCommandRequestExecutor for PointerScanRequest {
 
         let statics_snapshot = Arc::new(RwLock::new(build_snapshot(&process_info, PageRetrievalMode::FromModules)));
         let heaps_snapshot = Arc::new(RwLock::new(build_snapshot(&process_info, PageRetrievalMode::FromNonModules)));
-        let results_sink: Arc<RwLock<Vec<squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult>>> =
-            Arc::new(RwLock::new(Vec::new()));
+        let page_size = ScanSettingsConfig::get_results_page_size() as u64;
+        let results_sink = Arc::new(RwLock::new(PointerScanResults::new(Vec::new(), page_size.max(1))));
 
         let task = PointerScanExecutorTask::start_task(
             process_info,
@@ -81,9 +88,14 @@ impl PrivilegedCommandRequestExecutor for PointerScanRequest {
 
         let task_id = task.get_task_identifier();
         let progress_engine_state = engine_privileged_state.clone();
+        let progress_task = task.clone();
         thread::spawn(move || {
             while let Ok(progress) = progress_receiver.recv() {
-                progress_engine_state.emit_event(TrackableTaskProgressChangedEvent { task_id: task_id.clone(), progress });
+                progress_engine_state.emit_event(TrackableTaskProgressChangedEvent {
+                    task_id: task_id.clone(),
+                    progress,
+                    memory_usage_bytes: progress_task.get_memory_usage_bytes(),
+                });
             }
         });
 
@@ -91,11 +103,10 @@ impl PrivilegedCommandRequestExecutor for PointerScanRequest {
             task.wait_for_completion();
             engine_privileged_state.get_trackable_task_manager().unregister_task(&task.get_task_identifier());
 
-            if let Ok(results_guard) = results_sink.read() {
-                let page_size = ScanSettingsConfig::get_results_page_size() as u64;
-                if let Ok(mut pointer_scan_results) = engine_privileged_state.get_pointer_scan_results().write() {
-                    *pointer_scan_results = PointerScanResults::new(results_guard.clone(), page_size.max(1));
-                }
+            if let Ok(results_guard) = results_sink.read()
+                && let Ok(mut pointer_scan_results) = engine_privileged_state.get_pointer_scan_results().write()
+            {
+                *pointer_scan_results = results_guard.clone();
             }
 
             engine_privileged_state.emit_event(PointerScanResultsUpdatedEvent {});
@@ -0,0 +1 @@
+pub mod pointer_scan_rescan_request_executor;
@@ -0,0 +1,110 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::pointer_scan_rescan::pointer_scan_rescan_request::PointerScanRescanRequest;
+use squalr_engine_api::commands::scan::pointer_scan_rescan::pointer_scan_rescan_response::PointerScanRescanResponse;
+use squalr_engine_api::events::pointer_scan_results::updated::pointer_scan_results_updated_event::PointerScanResultsUpdatedEvent;
+use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
+use squalr_engine_scanning::pointer_scans::pointer_rescan_executor_task::PointerRescanExecutorTask;
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+impl PrivilegedCommandRequestExecutor for PointerScanRescanRequest {
+    type ResponseType = PointerScanRescanResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let Some(process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        else {
+            log::error!("No opened process");
+            return PointerScanRescanResponse::default();
+        };
+
+        let target_address = {
+            let symbol_registry = engine_privileged_state.get_symbol_registry();
+            let symbol_registry_guard = match symbol_registry.read() {
+                Ok(registry) => registry,
+                Err(error) => {
+                    log::error!("Failed to acquire read lock on SymbolRegistry: {}", error);
+                    return PointerScanRescanResponse::default();
+                }
+            };
+
+            match symbol_registry_guard.deanonymize_value_string(&self.pointer_data_type_ref, &self.target_address) {
+                Ok(data_value) => {
+                    let mut address_bytes = [0u8; 8];
+                    let value_bytes = data_value.get_value_bytes();
+                    let byte_count = value_bytes.len().min(address_bytes.len());
+
+                    address_bytes[..byte_count].copy_from_slice(&value_bytes[..byte_count]);
+                    u64::from_le_bytes(address_bytes)
+                }
+                Err(error) => {
+                    log::error!("Failed to deanonimize pointer rescan target address: {}", error);
+                    return PointerScanRescanResponse::default();
+                }
+            }
+        };
+
+        let pointer_scan_results = match engine_privileged_state.get_pointer_scan_results().read() {
+            Ok(pointer_scan_results) => pointer_scan_results.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire read lock on pointer scan results: {}", error);
+                return PointerScanRescanResponse::default();
+            }
+        };
+
+        // The results stay in place until the rescan completes, such that a cancelled rescan leaves them untouched.
+        let results_sink = Arc::new(RwLock::new(pointer_scan_results.clone()));
+        let task = PointerRescanExecutorTask::start_task(
+            process_info,
+            pointer_scan_results,
+            target_address,
+            self.pointer_data_type_ref.clone(),
+            results_sink.clone(),
+            true,
+        );
+
+        let task_handle = task.get_task_handle();
+        let engine_privileged_state = engine_privileged_state.clone();
+        let progress_receiver = task.subscribe_to_progress_updates();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        let task_id = task.get_task_identifier();
+        let progress_engine_state = engine_privileged_state.clone();
+        thread::spawn(move || {
+            while let Ok(progress) = progress_receiver.recv() {
+                progress_engine_state.emit_event(TrackableTaskProgressChangedEvent {
+                    task_id: task_id.clone(),
+                    progress,
+                    memory_usage_bytes: None,
+                });
+            }
+        });
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+
+            if let Ok(results_guard) = results_sink.read()
+                && let Ok(mut pointer_scan_results) = engine_privileged_state.get_pointer_scan_results().write()
+            {
+                *pointer_scan_results = results_guard.clone();
+            }
+
+            engine_privileged_state.emit_event(PointerScanResultsUpdatedEvent {});
+        });
+
+        PointerScanRescanResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
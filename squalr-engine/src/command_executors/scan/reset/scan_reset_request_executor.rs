@@ -30,7 +30,16 @@ impl PrivilegedCommandRequestExecutor for ScanResetRequest {
 
                 // Clears snapshot regions to reset the scan.
                 snapshot.set_snapshot_regions(vec![]);
-                engine_privileged_state.emit_event(ScanResultsUpdatedEvent { is_new_scan: false });
+                snapshot.set_last_scan_statistics(None);
+                snapshot.clear_data_type_overrides();
+                snapshot.clear_stride_repeat_counts();
+                snapshot.clear_scan_history();
+                snapshot.take_stale_region_changes();
+                snapshot.advance_scan_generation();
+                engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                    is_new_scan: false,
+                    scan_generation: snapshot.get_scan_generation(),
+                });
 
                 log::info!("Cleared scan data.");
 
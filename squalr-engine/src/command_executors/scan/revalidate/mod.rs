@@ -0,0 +1 @@
+pub mod scan_revalidate_request_executor;
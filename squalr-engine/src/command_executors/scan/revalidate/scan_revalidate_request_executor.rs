@@ -0,0 +1,123 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::revalidate::scan_revalidate_request::ScanRevalidateRequest;
+use squalr_engine_api::commands::scan::revalidate::scan_revalidate_response::ScanRevalidateResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::memory::normalized_region::NormalizedRegion;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_scanning::snapshot_monitor::snapshot_layout_monitor::SnapshotLayoutMonitor;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanRevalidateRequest {
+    type ResponseType = ScanRevalidateResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => {
+                log::error!("Cannot revalidate snapshot, no opened process.");
+
+                return ScanRevalidateResponse::default();
+            }
+        };
+        let snapshot = engine_privileged_state.get_snapshot();
+        let mut snapshot = match snapshot.write() {
+            Ok(guard) => guard,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on snapshot: {}", error);
+
+                return ScanRevalidateResponse::default();
+            }
+        };
+        let stale_ranges: Vec<NormalizedRegion> = snapshot
+            .take_stale_region_changes()
+            .into_iter()
+            .map(|stale_region_change| stale_region_change.get_region().clone())
+            .collect();
+
+        if stale_ranges.is_empty() {
+            return ScanRevalidateResponse::default();
+        }
+
+        // Whatever is still freed or re-protected cannot be re-snapshotted, so results over it are discarded.
+        let discarded_ranges: Vec<NormalizedRegion> = SnapshotLayoutMonitor::find_region_changes(&opened_process_info, &stale_ranges)
+            .into_iter()
+            .map(|region_change| region_change.get_region().clone())
+            .collect();
+        let result_count_before = snapshot.get_number_of_results();
+
+        for snapshot_region in snapshot.get_snapshot_regions_mut() {
+            let region_base_address = snapshot_region.get_base_address();
+            let region_end_address = snapshot_region.get_end_address();
+            let overlaps_region = |range: &NormalizedRegion| range.get_base_address() < region_end_address && range.get_end_address() > region_base_address;
+
+            if discarded_ranges.iter().any(overlaps_region) {
+                snapshot_region.discard_filters_within(&discarded_ranges);
+            }
+
+            // Re-read the stale ranges that are still backed, such that relative scans compare against their new contents.
+            let region_base_address = snapshot_region.get_base_address();
+            let region_end_address = snapshot_region.get_end_address();
+
+            for stale_range in &stale_ranges {
+                let read_start_address = stale_range.get_base_address().max(region_base_address);
+                let read_end_address = stale_range.get_end_address().min(region_end_address);
+
+                if read_start_address >= read_end_address || !snapshot_region.has_current_values() {
+                    continue;
+                }
+
+                let start_offset = (read_start_address - region_base_address) as usize;
+                let end_offset = (read_end_address - region_base_address) as usize;
+
+                if end_offset > snapshot_region.current_values.len() {
+                    continue;
+                }
+
+                let values = &mut snapshot_region.current_values[start_offset..end_offset];
+
+                if !MemoryReader::get_instance().read_bytes(&opened_process_info, read_start_address, values) {
+                    continue;
+                }
+
+                if end_offset <= snapshot_region.previous_values.len() {
+                    let current_values = snapshot_region.current_values[start_offset..end_offset].to_vec();
+
+                    snapshot_region.previous_values[start_offset..end_offset].copy_from_slice(&current_values);
+                }
+            }
+        }
+
+        snapshot.discard_empty_regions();
+
+        let discarded_result_count = result_count_before.saturating_sub(snapshot.get_number_of_results());
+
+        if discarded_result_count > 0 {
+            snapshot.clear_data_type_overrides();
+            snapshot.advance_scan_generation();
+        }
+
+        log::info!(
+            "Revalidated {} stale snapshot range(s), discarding {} result(s) over memory that no longer exists.",
+            stale_ranges.len(),
+            discarded_result_count
+        );
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+            is_new_scan: false,
+            scan_generation: snapshot.get_scan_generation(),
+        });
+
+        ScanRevalidateResponse {
+            revalidated_range_count: stale_ranges.len() as u64,
+            discarded_result_count,
+        }
+    }
+}
@@ -19,18 +19,45 @@ impl PrivilegedCommandExecutor for ScanCommand {
             ScanCommand::New { scan_new_request } => scan_new_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::Revalidate { scan_revalidate_request } => scan_revalidate_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::HistoryList { scan_history_list_request } => scan_history_list_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::HistoryRevert { scan_history_revert_request } => scan_history_revert_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::SnapshotSave { scan_snapshot_save_request } => scan_snapshot_save_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::SnapshotCompare { scan_snapshot_compare_request } => scan_snapshot_compare_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanCommand::CollectValues { scan_value_collector_request } => scan_value_collector_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
             ScanCommand::ElementScan { element_scan_request } => element_scan_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::ElementScanPreview { element_scan_preview_request } => element_scan_preview_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanCommand::PointerScan { pointer_scan_request } => pointer_scan_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::PointerScanRescan { pointer_scan_rescan_request } => pointer_scan_rescan_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanCommand::StructScan { struct_scan_request } => struct_scan_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanCommand::StrideScan { stride_scan_request } => stride_scan_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanCommand::Benchmark { scan_benchmark_request } => scan_benchmark_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
         }
     }
 }
@@ -0,0 +1 @@
+pub mod scan_snapshot_compare_request_executor;
@@ -0,0 +1,126 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::element_scan::element_scan_request::ElementScanRequest;
+use squalr_engine_api::commands::scan::snapshot_compare::scan_snapshot_compare_request::ScanSnapshotCompareRequest;
+use squalr_engine_api::commands::scan::snapshot_compare::scan_snapshot_compare_response::ScanSnapshotCompareResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_relative::ScanCompareTypeRelative;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_api::structures::snapshots::scan_snapshot_file::ScanSnapshotFile;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::fs;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanSnapshotCompareRequest {
+    type ResponseType = ScanSnapshotCompareResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let (result_count, data_type_ref) = match load_scan_snapshot(engine_privileged_state, self) {
+            Ok(loaded_scan_snapshot) => loaded_scan_snapshot,
+            Err(error) => {
+                log::error!("{}", error);
+
+                return ScanSnapshotCompareResponse {
+                    compared_result_count: Err(error),
+                    trackable_task_handle: None,
+                };
+            }
+        };
+
+        // The saved values are now the current values of the results. As a scan reads new values in behind them, they become the
+        // previous values, such that a relative scan compares the values of this session against the saved ones.
+        let scan_compare_type_relative = if self.keep_matching_values {
+            ScanCompareTypeRelative::Unchanged
+        } else {
+            ScanCompareTypeRelative::Changed
+        };
+        let element_scan_response = ElementScanRequest {
+            scan_constraints: vec![AnonymousScanConstraint::new(
+                ScanCompareType::Relative(scan_compare_type_relative),
+                None,
+            )],
+            data_type_refs: vec![data_type_ref],
+            is_unknown_initial_scan: false,
+        }
+        .execute(engine_privileged_state);
+
+        if element_scan_response.trackable_task_handle.is_none() {
+            let error = "Failed to start comparing the scan snapshot against the current values.".to_string();
+            let scan_generation = engine_privileged_state
+                .get_snapshot()
+                .read()
+                .map(|snapshot| snapshot.get_scan_generation())
+                .unwrap_or_default();
+
+            log::error!("{}", error);
+
+            // The saved results were loaded regardless, so they are reported such that they can still be scanned by hand.
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                is_new_scan: false,
+                scan_generation,
+            });
+
+            return ScanSnapshotCompareResponse {
+                compared_result_count: Err(error),
+                trackable_task_handle: None,
+            };
+        }
+
+        ScanSnapshotCompareResponse {
+            compared_result_count: Ok(result_count),
+            trackable_task_handle: element_scan_response.trackable_task_handle,
+        }
+    }
+}
+
+/// Replaces the scan results of the snapshot with those saved to the requested file, returning how many were loaded along with
+/// their data type. The results being replaced are kept in the scan history.
+fn load_scan_snapshot(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    scan_snapshot_compare_request: &ScanSnapshotCompareRequest,
+) -> Result<(u64, DataTypeRef), String> {
+    let bytes = fs::read(&scan_snapshot_compare_request.file_path).map_err(|error| {
+        format!(
+            "Failed to read scan snapshot from {}: {}",
+            scan_snapshot_compare_request.file_path.display(),
+            error
+        )
+    })?;
+    let scan_snapshot_file = ScanSnapshotFile::from_bytes(&bytes)?;
+    let data_type_ref = scan_snapshot_file.data_type_ref.clone();
+
+    // Results are checked against the opened process, as values saved from another process or build would compare unrelated memory.
+    let process_info = engine_privileged_state
+        .get_process_manager()
+        .get_opened_process()
+        .ok_or_else(|| "Attach to a process before comparing against a scan snapshot.".to_string())?;
+    let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+    let snapshot_regions = scan_snapshot_file.into_snapshot_regions(process_info.get_name(), &modules)?;
+    let snapshot = engine_privileged_state.get_snapshot();
+    let mut snapshot = snapshot
+        .write()
+        .map_err(|error| format!("Failed to acquire write lock on snapshot: {}", error))?;
+
+    snapshot.record_scan_history();
+    snapshot.set_snapshot_regions(snapshot_regions);
+    snapshot.set_last_scan_statistics(None);
+    snapshot.clear_data_type_overrides();
+    snapshot.clear_stride_repeat_counts();
+    snapshot.take_stale_region_changes();
+    snapshot.advance_scan_generation();
+
+    let result_count = snapshot.get_number_of_results();
+
+    log::info!(
+        "Loaded {} scan result(s) from {}.",
+        result_count,
+        scan_snapshot_compare_request.file_path.display()
+    );
+
+    Ok((result_count, data_type_ref))
+}
@@ -0,0 +1 @@
+pub mod scan_snapshot_save_request_executor;
@@ -0,0 +1,54 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::snapshot_save::scan_snapshot_save_request::ScanSnapshotSaveRequest;
+use squalr_engine_api::commands::scan::snapshot_save::scan_snapshot_save_response::ScanSnapshotSaveResponse;
+use squalr_engine_api::structures::snapshots::scan_snapshot_file::ScanSnapshotFile;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use std::fs;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanSnapshotSaveRequest {
+    type ResponseType = ScanSnapshotSaveResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let saved_result_count = save_scan_snapshot(engine_privileged_state, self);
+
+        if let Err(error) = &saved_result_count {
+            log::error!("{}", error);
+        }
+
+        ScanSnapshotSaveResponse { saved_result_count }
+    }
+}
+
+fn save_scan_snapshot(
+    engine_privileged_state: &Arc<EnginePrivilegedState>,
+    scan_snapshot_save_request: &ScanSnapshotSaveRequest,
+) -> Result<u64, String> {
+    // The process and its modules are recorded such that the results can be rebased onto, or rejected by, the next session.
+    let process_info = engine_privileged_state
+        .get_process_manager()
+        .get_opened_process()
+        .ok_or_else(|| "Attach to the process the results were found in before saving them.".to_string())?;
+    let modules = ProcessQueryCache::get_instance().get_modules(&process_info);
+    let scan_snapshot_file = {
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot = snapshot
+            .read()
+            .map_err(|error| format!("Failed to acquire read lock on snapshot: {}", error))?;
+
+        ScanSnapshotFile::from_snapshot(&snapshot, process_info.get_name(), &modules)?
+    };
+    let result_count = scan_snapshot_file.entries.len() as u64;
+    let bytes = scan_snapshot_file.to_bytes()?;
+
+    fs::write(&scan_snapshot_save_request.file_path, bytes)
+        .map_err(|error| format!("Failed to write scan snapshot to {}: {}", scan_snapshot_save_request.file_path.display(), error))?;
+
+    log::info!("Saved {} scan result(s) to {}.", result_count, scan_snapshot_save_request.file_path.display());
+
+    Ok(result_count)
+}
@@ -0,0 +1 @@
+pub mod stride_scan_request_executor;
@@ -0,0 +1,109 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::command_executors::scan::element_scan::element_scan_request_executor::create_element_scan_plan;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan::stride_scan::stride_scan_request::StrideScanRequest;
+use squalr_engine_api::commands::scan::stride_scan::stride_scan_response::StrideScanResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+use squalr_engine_scanning::scanners::stride_scan_executor_task::StrideScanExecutorTask;
+use squalr_engine_scanning::snapshot_monitor::snapshot_layout_monitor::SnapshotLayoutMonitor;
+use std::sync::Arc;
+use std::thread;
+
+impl PrivilegedCommandRequestExecutor for StrideScanRequest {
+    type ResponseType = StrideScanResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(process_info) => process_info,
+            None => {
+                log::error!("No opened process");
+                return StrideScanResponse::default();
+            }
+        };
+
+        let snapshot = engine_privileged_state.get_snapshot();
+        let region_count = snapshot
+            .read()
+            .map(|guard| guard.get_region_count())
+            .unwrap_or(0);
+
+        if region_count == 0 {
+            log::error!("Snapshot is empty. Run a New Scan (build snapshot) before scanning.");
+            return StrideScanResponse::default();
+        }
+
+        if self.minimum_repeat_count < 2 {
+            log::error!("A stride scan must require at least 2 repeats, otherwise every match is an array.");
+            return StrideScanResponse::default();
+        }
+
+        let scan_constraint = AnonymousScanConstraint::new(ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal), Some(self.scan_value.clone()));
+        let element_scan_plan = match create_element_scan_plan(&[scan_constraint], std::slice::from_ref(&self.data_type_ref)) {
+            Some(element_scan_plan) => element_scan_plan,
+            None => {
+                log::error!("No valid scan constraints after parsing; aborting stride scan.");
+                return StrideScanResponse::default();
+            }
+        };
+
+        // Elements of an array cannot overlap, so a stride shorter than the value would compare parts of the same value.
+        let unit_size_in_bytes = element_scan_plan
+            .get_scan_constraints_by_data_type()
+            .values()
+            .flatten()
+            .map(|scan_constraint| scan_constraint.get_unit_size_in_bytes())
+            .max()
+            .unwrap_or(1);
+
+        if self.stride < unit_size_in_bytes {
+            log::error!(
+                "The stride of {} bytes is smaller than the {} bytes of the value, aborting stride scan.",
+                self.stride,
+                unit_size_in_bytes
+            );
+            return StrideScanResponse::default();
+        }
+
+        // Flag results whose memory was freed or re-protected since the last scan, as their values can no longer be trusted.
+        SnapshotLayoutMonitor::check_snapshot(&process_info, &snapshot);
+
+        let task = StrideScanExecutorTask::start_task(process_info, snapshot, element_scan_plan, self.stride, self.minimum_repeat_count, true);
+        let task_handle = task.get_task_handle();
+        let engine_privileged_state = engine_privileged_state.clone();
+
+        engine_privileged_state
+            .get_trackable_task_manager()
+            .register_task(task.clone());
+
+        thread::spawn(move || {
+            task.wait_for_completion();
+            engine_privileged_state
+                .get_trackable_task_manager()
+                .unregister_task(&task.get_task_identifier());
+
+            let scan_generation = engine_privileged_state
+                .get_snapshot()
+                .read()
+                .map(|snapshot| snapshot.get_scan_generation())
+                .unwrap_or_default();
+
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                is_new_scan: false,
+                scan_generation,
+            });
+        });
+
+        StrideScanResponse {
+            trackable_task_handle: Some(task_handle),
+        }
+    }
+}
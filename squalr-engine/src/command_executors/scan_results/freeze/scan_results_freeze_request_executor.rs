@@ -3,11 +3,10 @@ use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_response::ScanResultsFreezeResponse;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
-use squalr_engine_api::structures::memory::pointer::Pointer;
-use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
-use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
 use squalr_engine_memory::memory_reader::MemoryReader;
 use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_scanning::freeze_task::frozen_value_writer::FrozenValueWriter;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanResultsFreezeRequest {
@@ -42,9 +41,9 @@ impl PrivilegedCommandRequestExecutor for ScanResultsFreezeRequest {
             .get_process_manager()
             .get_opened_process()
         {
-            MemoryQueryer::get_instance().get_modules(&opened_process_info)
+            ProcessQueryCache::get_instance().get_modules(&opened_process_info)
         } else {
-            vec![]
+            Arc::default()
         };
 
         let mut failed_freeze_toggle_scan_result_refs = Vec::new();
@@ -54,24 +53,18 @@ impl PrivilegedCommandRequestExecutor for ScanResultsFreezeRequest {
 
             if let Some(scan_result) = snapshot_guard.get_scan_result(scan_result_index) {
                 let address = scan_result.get_address();
-                let mut module_name = String::default();
-                let mut module_offset = scan_result.get_address();
+                let pointer = FrozenValueWriter::get_pointer(address, &modules);
 
-                // Check whether this scan result belongs to a module (ie check if the address is static).
-                if let Some((found_module_name, address)) = MemoryQueryer::get_instance().address_to_module(module_offset, &modules) {
-                    module_name = found_module_name;
-                    module_offset = address;
-                }
-
-                let pointer = Pointer::new(module_offset, vec![], module_name);
-
-                if self.is_frozen {
+                if self.is_frozen && scan_result.get_base_result().is_stale() && !self.allow_stale {
+                    log::warn!("Skipped freezing stale scan result at 0x{:X}. Revalidate the snapshot, or confirm to freeze it anyway.", address);
+                } else if self.is_frozen {
                     if let Some(opened_process_info) = engine_privileged_state
                         .get_process_manager()
                         .get_opened_process()
                     {
                         let data_type_ref = scan_result.get_data_type_ref();
 
+                        // Freezing a result that is already frozen refreezes it at its current value, which replaces the old frozen value.
                         if let Some(mut data_value) = symbol_registry.get_default_value(data_type_ref) {
                             if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
                                 freeze_list_registry_guard.set_address_frozen(pointer, data_value.get_value_bytes().to_vec());
@@ -7,6 +7,7 @@ use squalr_engine_api::structures::memory::pointer::Pointer;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
 use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
 use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
 use squalr_engine_memory::memory_reader::MemoryReader;
 use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
 use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
@@ -31,9 +32,9 @@ impl PrivilegedCommandRequestExecutor for ScanResultsListRequest {
             .get_process_manager()
             .get_opened_process()
         {
-            MemoryQueryer::get_instance().get_modules(&opened_process_info)
+            ProcessQueryCache::get_instance().get_modules(&opened_process_info)
         } else {
-            vec![]
+            Arc::default()
         };
 
         if let Ok(snapshot) = engine_privileged_state.get_snapshot().read() {
@@ -5,4 +5,6 @@ pub mod list;
 pub mod query;
 pub mod refresh;
 pub mod scan_results_command_executor;
+pub mod set_data_type;
 pub mod set_property;
+pub mod set_values;
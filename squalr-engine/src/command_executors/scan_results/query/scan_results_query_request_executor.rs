@@ -2,13 +2,9 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan_results::query::scan_results_query_request::ScanResultsQueryRequest;
 use squalr_engine_api::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
-use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
-use squalr_engine_api::structures::memory::pointer::Pointer;
-use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
-use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
-use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use squalr_engine_memory::memory_reader::MemoryReader;
-use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_scanning::scan_results::scan_result_memory_reader::{ProcessScanResultMemoryReader, ScanResultMemoryReader};
+use squalr_engine_scanning::scan_results::scan_result_refresher::ScanResultRefresher;
 use squalr_engine_scanning::scan_settings_config::ScanSettingsConfig;
 use std::sync::Arc;
 
@@ -19,7 +15,6 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
-        let symbol_registry = SymbolRegistry::get_instance();
         let configured_page_size_max = ScanSettingsConfig::get_results_page_size_max().max(1) as u64;
         let configured_page_size_auto = ScanSettingsConfig::get_results_page_size_auto();
         let requested_page_size = self.page_size.unwrap_or(configured_page_size_max as u32).max(1) as u64;
@@ -34,21 +29,29 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
         let mut last_page_index = 0;
         let mut result_count = 0;
         let mut total_size_in_bytes = 0;
+        let mut last_read_size_in_bytes = 0;
+        let mut last_scan_statistics = None;
+        let mut scan_generation = 0;
 
-        // Collect modules if possible so that we can resolve whether individual addresses are static later.
-        let modules = if let Some(opened_process_info) = engine_privileged_state
+        let opened_process_info = engine_privileged_state
             .get_process_manager()
-            .get_opened_process()
-        {
-            MemoryQueryer::get_instance().get_modules(&opened_process_info)
-        } else {
-            vec![]
+            .get_opened_process();
+
+        // Collect modules if possible so that we can resolve whether individual addresses are static later.
+        let modules = match &opened_process_info {
+            Some(opened_process_info) => ProcessQueryCache::get_instance().get_modules(opened_process_info),
+            None => Arc::default(),
         };
+        let memory_reader = opened_process_info.map(ProcessScanResultMemoryReader::new);
+        let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
 
         if let Ok(snapshot) = engine_privileged_state.get_snapshot().read() {
             result_count = snapshot.get_number_of_results();
             last_page_index = result_count.saturating_sub(1) / results_page_size;
             total_size_in_bytes = snapshot.get_byte_count();
+            last_read_size_in_bytes = snapshot.get_last_read_byte_count();
+            last_scan_statistics = snapshot.get_last_scan_statistics().copied();
+            scan_generation = snapshot.get_scan_generation();
             page_index = self.page_index.clamp(0, last_page_index);
 
             // Get the range of indicies for the elements of this page.
@@ -62,52 +65,14 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
                     None => break,
                     Some(scan_result_base) => scan_result_base,
                 };
-                let mut recently_read_value = None;
-                let mut module_name = String::default();
-                let address = scan_result_base.get_address();
-                let mut module_offset = scan_result_base.get_address();
-
-                // Best-effort attempt to read the values for this scan result.
-                if let Some(opened_process_info) = engine_privileged_state
-                    .get_process_manager()
-                    .get_opened_process()
-                {
-                    if let Some(mut data_value) = scan_result_base.get_current_value().clone() {
-                        if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
-                            recently_read_value = Some(data_value);
-                        }
-                    }
-                }
-
-                // Check whether this scan result belongs to a module (ie check if the address is static).
-                if let Some((found_module_name, address)) = MemoryQueryer::get_instance().address_to_module(address, &modules) {
-                    module_name = found_module_name;
-                    module_offset = address;
-                }
-
-                let pointer = Pointer::new(module_offset, vec![], module_name.clone());
-                let is_frozen = if let Ok(freeze_list_registry) = engine_privileged_state.get_freeze_list_registry().read() {
-                    freeze_list_registry.is_address_frozen(&pointer)
-                } else {
-                    false
-                };
-
-                let recently_read_display_values = recently_read_value
-                    .as_ref()
-                    .and_then(|data_value| {
-                        symbol_registry
-                            .anonymize_value_to_supported_formats(data_value)
-                            .ok()
-                    })
-                    .unwrap_or_default();
 
-                scan_results_list.push(ScanResult::new(
+                scan_results_list.push(ScanResultRefresher::refresh_scan_result(
                     scan_result_base,
-                    module_name,
-                    module_offset,
-                    recently_read_value,
-                    recently_read_display_values,
-                    is_frozen,
+                    memory_reader
+                        .as_ref()
+                        .map(|memory_reader| memory_reader as &dyn ScanResultMemoryReader),
+                    &modules,
+                    &freeze_list_registry,
                 ));
             }
         }
@@ -119,6 +84,9 @@ impl PrivilegedCommandRequestExecutor for ScanResultsQueryRequest {
             last_page_index,
             result_count,
             total_size_in_bytes,
+            last_read_size_in_bytes,
+            last_scan_statistics,
+            scan_generation,
         }
     }
 }
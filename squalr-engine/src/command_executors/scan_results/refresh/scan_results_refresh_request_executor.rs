@@ -2,13 +2,10 @@ use crate::command_executors::privileged_request_executor::PrivilegedCommandRequ
 use crate::engine_privileged_state::EnginePrivilegedState;
 use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
 use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
-use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
-use squalr_engine_api::structures::memory::pointer::Pointer;
-use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
-use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
-use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
-use squalr_engine_memory::memory_reader::MemoryReader;
-use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_scanning::scan_results::scan_result_memory_reader::{ProcessScanResultMemoryReader, ScanResultMemoryReader};
+use squalr_engine_scanning::scan_results::scan_result_refresher::ScanResultRefresher;
+use squalr_engine_scanning::snapshot_monitor::snapshot_layout_monitor::SnapshotLayoutMonitor;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
@@ -18,8 +15,26 @@ impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
         &self,
         engine_privileged_state: &Arc<EnginePrivilegedState>,
     ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
-        let symbol_registry = SymbolRegistry::get_instance();
         let snapshot = engine_privileged_state.get_snapshot();
+
+        // Check whether the memory under the refreshed results changed since it was snapshotted, such that they are marked stale.
+        if let Some(opened_process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            let addresses: Vec<u64> = match snapshot.read() {
+                Ok(snapshot) => self
+                    .scan_result_refs
+                    .iter()
+                    .filter_map(|scan_result_ref| snapshot.get_scan_result(scan_result_ref.get_scan_result_global_index()))
+                    .map(|scan_result| scan_result.get_address())
+                    .collect(),
+                Err(_) => vec![],
+            };
+
+            SnapshotLayoutMonitor::check_snapshot_addresses(&opened_process_info, &snapshot, &addresses);
+        }
+
         let snapshot_guard = match snapshot.read() {
             Ok(snapshot) => snapshot,
             Err(error) => {
@@ -28,73 +43,38 @@ impl PrivilegedCommandRequestExecutor for ScanResultsRefreshRequest {
                 return ScanResultsRefreshResponse::default();
             }
         };
-        let mut scan_results_list = vec![];
+        let opened_process_info = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process();
 
         // Collect modules if possible so that we can resolve whether individual addresses are static later.
-        let modules = if let Some(opened_process_info) = engine_privileged_state
-            .get_process_manager()
-            .get_opened_process()
-        {
-            MemoryQueryer::get_instance().get_modules(&opened_process_info)
-        } else {
-            vec![]
+        let modules = match &opened_process_info {
+            Some(opened_process_info) => ProcessQueryCache::get_instance().get_modules(opened_process_info),
+            None => Arc::default(),
         };
+        let memory_reader = opened_process_info.map(ProcessScanResultMemoryReader::new);
+        let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
 
-        // Wrap each ScanResultBase with a full ScanResult that includes current values and module information.
-        for scan_result_ref in self.scan_result_refs.clone().into_iter() {
-            if let Some(scan_result) = snapshot_guard.get_scan_result(scan_result_ref.get_scan_result_global_index()) {
-                let mut recently_read_value = None;
-                let mut module_name = String::default();
-                let address = scan_result.get_address();
-                let mut module_offset = address;
-
-                // Best-effort attempt to read the values for this scan result.
-                if let Some(opened_process_info) = engine_privileged_state
-                    .get_process_manager()
-                    .get_opened_process()
-                {
-                    if let Some(mut data_value) = scan_result.get_current_value().clone() {
-                        if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
-                            recently_read_value = Some(data_value);
-                        }
-                    }
-                }
-
-                // Check whether this scan result belongs to a module (ie check if the address is static).
-                if let Some((found_module_name, address)) = MemoryQueryer::get_instance().address_to_module(address, &modules) {
-                    module_name = found_module_name;
-                    module_offset = address;
-                }
-
-                let pointer = Pointer::new(module_offset, vec![], module_name.clone());
-                let is_frozen = if let Ok(freeze_list_registry) = engine_privileged_state.get_freeze_list_registry().read() {
-                    freeze_list_registry.is_address_frozen(&pointer)
-                } else {
-                    false
-                };
-
-                let recently_read_display_values = recently_read_value
-                    .as_ref()
-                    .and_then(|data_value| {
-                        symbol_registry
-                            .anonymize_value_to_supported_formats(data_value)
-                            .ok()
-                    })
-                    .unwrap_or_default();
-
-                scan_results_list.push(ScanResult::new(
+        // Wrap each ScanResultBase with a full ScanResult that includes current values, module information, and read status.
+        let scan_results_list = self
+            .scan_result_refs
+            .iter()
+            .filter_map(|scan_result_ref| snapshot_guard.get_scan_result(scan_result_ref.get_scan_result_global_index()))
+            .map(|scan_result| {
+                ScanResultRefresher::refresh_scan_result(
                     scan_result,
-                    module_name,
-                    module_offset,
-                    recently_read_value,
-                    recently_read_display_values,
-                    is_frozen,
-                ));
-            }
-        }
+                    memory_reader
+                        .as_ref()
+                        .map(|memory_reader| memory_reader as &dyn ScanResultMemoryReader),
+                    &modules,
+                    &freeze_list_registry,
+                )
+            })
+            .collect();
 
         ScanResultsRefreshResponse {
             scan_results: scan_results_list,
+            scan_generation: snapshot_guard.get_scan_generation(),
         }
     }
 }
@@ -33,6 +33,14 @@ impl PrivilegedCommandExecutor for ScanResultsCommand {
             ScanResultsCommand::SetProperty { results_set_property_request } => results_set_property_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
+            ScanResultsCommand::SetValues { results_set_values_request } => results_set_values_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
+            ScanResultsCommand::SetDataType {
+                results_set_data_type_request,
+            } => results_set_data_type_request
+                .execute(engine_privileged_state)
+                .to_engine_response(),
             ScanResultsCommand::Delete { results_delete_request } => results_delete_request
                 .execute(engine_privileged_state)
                 .to_engine_response(),
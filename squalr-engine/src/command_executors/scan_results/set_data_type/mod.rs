@@ -0,0 +1 @@
+pub mod scan_results_set_data_type_request_executor;
@@ -0,0 +1,107 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan_results::set_data_type::scan_results_set_data_type_request::ScanResultsSetDataTypeRequest;
+use squalr_engine_api::commands::scan_results::set_data_type::scan_results_set_data_type_response::ScanResultsSetDataTypeResponse;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::memory::pointer::Pointer;
+use squalr_engine_memory::memory_queryer::memory_queryer::MemoryQueryer;
+use squalr_engine_memory::memory_queryer::memory_queryer_trait::IMemoryQueryer;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_memory::memory_reader::MemoryReader;
+use squalr_engine_memory::memory_reader::memory_reader_trait::IMemoryReader;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanResultsSetDataTypeRequest {
+    type ResponseType = ScanResultsSetDataTypeResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let all_failed = || ScanResultsSetDataTypeResponse {
+            converted_count: 0,
+            failed_scan_result_refs: self.scan_result_refs.clone(),
+        };
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        if !symbol_registry.is_valid(&self.data_type_ref) {
+            log::error!("Cannot convert scan results to unknown data type {}.", self.data_type_ref);
+
+            return all_failed();
+        }
+
+        let snapshot = engine_privileged_state.get_snapshot();
+        let mut snapshot_guard = match snapshot.write() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire write lock on Snapshot: {}", error);
+
+                return all_failed();
+            }
+        };
+        let mut converted_scan_results = Vec::new();
+        let mut failed_scan_result_refs = Vec::new();
+
+        for scan_result_ref in &self.scan_result_refs {
+            let scan_result_index = scan_result_ref.get_scan_result_global_index();
+
+            match snapshot_guard.set_scan_result_data_type(scan_result_index, self.data_type_ref.clone()) {
+                true => converted_scan_results.extend(snapshot_guard.get_scan_result(scan_result_index)),
+                false => failed_scan_result_refs.push(scan_result_ref.clone()),
+            }
+        }
+
+        let scan_generation = snapshot_guard.get_scan_generation();
+
+        drop(snapshot_guard);
+
+        // Frozen addresses keep writing the bytes captured when they were frozen, so capture them again at the new width.
+        if let Some(opened_process_info) = engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+
+            if let Ok(mut freeze_list_registry_guard) = engine_privileged_state.get_freeze_list_registry().write() {
+                for scan_result in &converted_scan_results {
+                    let address = scan_result.get_address();
+                    let pointer = match MemoryQueryer::get_instance().address_to_module(address, &modules) {
+                        Some((module_name, module_offset)) => Pointer::new(module_offset, vec![], module_name),
+                        None => Pointer::new(address, vec![], String::default()),
+                    };
+
+                    if !freeze_list_registry_guard.is_address_frozen(&pointer) {
+                        continue;
+                    }
+
+                    let Some(mut data_value) = symbol_registry.get_default_value(scan_result.get_data_type_ref()) else {
+                        continue;
+                    };
+
+                    if MemoryReader::get_instance().read(&opened_process_info, address, &mut data_value) {
+                        freeze_list_registry_guard.set_address_frozen(pointer, data_value.get_value_bytes().to_vec());
+                    }
+                }
+            }
+        }
+
+        if !converted_scan_results.is_empty() {
+            log::warn!(
+                "Showing {} scan result(s) as {}. Scans compare each result set as a single data type, so the next scan will use the original type for these results.",
+                converted_scan_results.len(),
+                self.data_type_ref
+            );
+
+            engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+                is_new_scan: false,
+                scan_generation,
+            });
+        }
+
+        ScanResultsSetDataTypeResponse {
+            converted_count: converted_scan_results.len() as u64,
+            failed_scan_result_refs,
+        }
+    }
+}
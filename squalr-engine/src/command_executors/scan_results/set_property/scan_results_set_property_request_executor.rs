@@ -5,10 +5,11 @@ use squalr_engine_api::commands::scan_results::set_property::scan_results_set_pr
 use squalr_engine_api::commands::scan_results::set_property::scan_results_set_property_response::ScanResultsSetPropertyResponse;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
 use squalr_engine_api::structures::data_types::built_in_types::bool32::data_type_bool32::DataTypeBool32;
+use squalr_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
 use squalr_engine_api::structures::data_types::data_type::DataType;
 use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
-use squalr_engine_memory::memory_writer::MemoryWriter;
-use squalr_engine_memory::memory_writer::memory_writer_trait::IMemoryWriter;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_scanning::freeze_task::frozen_value_writer::FrozenValueWriter;
 use std::sync::Arc;
 
 impl PrivilegedCommandRequestExecutor for ScanResultsSetPropertyRequest {
@@ -31,8 +32,15 @@ impl PrivilegedCommandRequestExecutor for ScanResultsSetPropertyRequest {
 
         match self.field_namespace.as_str() {
             ScanResult::PROPERTY_NAME_VALUE => {
+                let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
+
                 for scan_result_ref in &self.scan_result_refs {
                     if let Some(scan_result) = snapshot_guard.get_scan_result(scan_result_ref.get_scan_result_global_index()) {
+                        if scan_result.get_base_result().is_stale() && !self.allow_stale {
+                            log::warn!("Skipped writing to stale scan result at 0x{:X}.", scan_result.get_address());
+                            continue;
+                        }
+
                         if let Ok(data_value) = symbol_registry.deanonymize_value_string(scan_result.get_data_type_ref(), &self.anonymous_value_string) {
                             let value_bytes = data_value.get_value_bytes();
                             let address = scan_result.get_address();
@@ -40,8 +48,11 @@ impl PrivilegedCommandRequestExecutor for ScanResultsSetPropertyRequest {
                                 .get_process_manager()
                                 .get_opened_process()
                             {
-                                // Best-effort attempt to write the property bytes.
-                                let _ = MemoryWriter::get_instance().write_bytes(&opened_process_info, address, &value_bytes);
+                                let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+                                let pointer = FrozenValueWriter::get_pointer(address, &modules);
+
+                                // Best-effort attempt to write the property bytes. Frozen results stay frozen, at the new value.
+                                let _ = FrozenValueWriter::write_bytes(&opened_process_info, &freeze_list_registry, pointer, address, value_bytes);
                             }
                         }
                     }
@@ -56,12 +67,52 @@ impl PrivilegedCommandRequestExecutor for ScanResultsSetPropertyRequest {
                     let scan_results_freeze_request = ScanResultsFreezeRequest {
                         scan_result_refs: self.scan_result_refs.clone(),
                         is_frozen,
+                        allow_stale: self.allow_stale,
                     };
 
                     scan_results_freeze_request.execute(engine_privileged_state);
                 }
             }
-            ScanResult::PROPERTY_NAME_ADDRESS | ScanResult::PROPERTY_NAME_MODULE | ScanResult::PROPERTY_NAME_MODULE_OFFSET => {
+            ScanResult::PROPERTY_NAME_ADDRESS => {
+                let data_type = DataTypeU64 {};
+                let address = match data_type.deanonymize_value_string(&self.anonymous_value_string) {
+                    Ok(data_value) => u64::from_le_bytes(
+                        data_value
+                            .get_value_bytes()
+                            .as_slice()
+                            .try_into()
+                            .unwrap_or([0u8; 8]),
+                    ),
+                    Err(error) => {
+                        log::error!("Failed to parse scan result address: {}", error);
+
+                        return ScanResultsSetPropertyResponse::default();
+                    }
+                };
+
+                // Moving results only changes how they are read back, but still requires exclusive access to the snapshot.
+                drop(snapshot_guard);
+
+                let mut snapshot_guard = match snapshot.write() {
+                    Ok(snapshot) => snapshot,
+                    Err(error) => {
+                        log::error!("Failed to acquire write lock on Snapshot: {}", error);
+
+                        return ScanResultsSetPropertyResponse::default();
+                    }
+                };
+
+                for scan_result_ref in &self.scan_result_refs {
+                    if !snapshot_guard.set_scan_result_address(scan_result_ref.get_scan_result_global_index(), address) {
+                        log::warn!(
+                            "Cannot move scan result {} to 0x{:X}, as it no longer exists.",
+                            scan_result_ref.get_scan_result_global_index(),
+                            address
+                        );
+                    }
+                }
+            }
+            ScanResult::PROPERTY_NAME_MODULE | ScanResult::PROPERTY_NAME_MODULE_OFFSET => {
                 log::warn!("Cannot set read-only property {}", self.field_namespace);
             }
             _ => {
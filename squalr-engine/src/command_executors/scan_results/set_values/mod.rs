@@ -0,0 +1 @@
+pub mod scan_results_set_values_request_executor;
@@ -0,0 +1,88 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use squalr_engine_api::commands::scan_results::set_values::scan_results_set_values_request::ScanResultsSetValuesRequest;
+use squalr_engine_api::commands::scan_results::set_values::scan_results_set_values_response::ScanResultsSetValuesResponse;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_scanning::freeze_task::frozen_value_writer::FrozenValueWriter;
+use std::sync::Arc;
+
+impl PrivilegedCommandRequestExecutor for ScanResultsSetValuesRequest {
+    type ResponseType = ScanResultsSetValuesResponse;
+
+    fn execute(
+        &self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) -> <Self as PrivilegedCommandRequestExecutor>::ResponseType {
+        let all_failed = || ScanResultsSetValuesResponse {
+            failed_scan_result_refs: self.scan_result_refs.clone(),
+        };
+
+        if self.scan_result_refs.len() != self.anonymous_value_strings.len() {
+            log::error!(
+                "Mismatched scan result values: {} refs were given {} values.",
+                self.scan_result_refs.len(),
+                self.anonymous_value_strings.len()
+            );
+
+            return all_failed();
+        }
+
+        let opened_process_info = match engine_privileged_state
+            .get_process_manager()
+            .get_opened_process()
+        {
+            Some(opened_process_info) => opened_process_info,
+            None => return all_failed(),
+        };
+        let snapshot = engine_privileged_state.get_snapshot();
+        let snapshot_guard = match snapshot.read() {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                log::error!("Failed to acquire read lock on Snapshot: {}", error);
+
+                return all_failed();
+            }
+        };
+        let symbol_registry = SymbolRegistry::get_instance();
+        let freeze_list_registry = engine_privileged_state.get_freeze_list_registry();
+        let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+        let mut failed_scan_result_refs = Vec::new();
+
+        for (scan_result_ref, anonymous_value_string) in self.scan_result_refs.iter().zip(&self.anonymous_value_strings) {
+            let scan_result = match snapshot_guard.get_scan_result(scan_result_ref.get_scan_result_global_index()) {
+                Some(scan_result) => scan_result,
+                None => {
+                    failed_scan_result_refs.push(scan_result_ref.clone());
+                    continue;
+                }
+            };
+
+            if scan_result.get_base_result().is_stale() && !self.allow_stale {
+                log::warn!("Skipped writing to stale scan result at 0x{:X}.", scan_result.get_address());
+                failed_scan_result_refs.push(scan_result_ref.clone());
+                continue;
+            }
+
+            // Each value is read as the data type of its own scan result, such that a selection mixing types writes correctly.
+            let is_written = match symbol_registry.deanonymize_value_string(scan_result.get_data_type_ref(), anonymous_value_string) {
+                Ok(data_value) => {
+                    let address = scan_result.get_address();
+                    let pointer = FrozenValueWriter::get_pointer(address, &modules);
+
+                    FrozenValueWriter::write_bytes(&opened_process_info, &freeze_list_registry, pointer, address, data_value.get_value_bytes())
+                }
+                Err(error) => {
+                    log::warn!("Failed to write value {} to scan result: {}", anonymous_value_string, error);
+                    false
+                }
+            };
+
+            if !is_written {
+                failed_scan_result_refs.push(scan_result_ref.clone());
+            }
+        }
+
+        ScanResultsSetValuesResponse { failed_scan_result_refs }
+    }
+}
@@ -16,6 +16,14 @@ impl PrivilegedCommandRequestExecutor for GeneralSettingsSetRequest {
             GeneralSettingsConfig::set_engine_request_delay_ms(engine_request_delay_ms);
         }
 
+        if let Some(last_opened_process_name) = &self.last_opened_process_name {
+            GeneralSettingsConfig::set_last_opened_process_name(last_opened_process_name.clone());
+        }
+
+        if let Some(auto_reattach) = self.auto_reattach {
+            GeneralSettingsConfig::set_auto_reattach(auto_reattach);
+        }
+
         GeneralSettingsSetResponse {}
     }
 }
@@ -76,6 +76,14 @@ impl PrivilegedCommandRequestExecutor for MemorySettingsSetRequest {
             MemorySettingsConfig::set_only_query_usermode(only_query_usermode);
         }
 
+        if let Some(query_cache_interval_ms) = self.query_cache_interval_ms {
+            MemorySettingsConfig::set_query_cache_interval_ms(query_cache_interval_ms);
+        }
+
+        if let Some(preload_main_module_symbols) = self.preload_main_module_symbols {
+            MemorySettingsConfig::set_preload_main_module_symbols(preload_main_module_symbols);
+        }
+
         MemorySettingsSetResponse {}
     }
 }
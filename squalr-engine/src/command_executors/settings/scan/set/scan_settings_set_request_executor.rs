@@ -94,10 +94,22 @@ impl PrivilegedCommandRequestExecutor for ScanSettingsSetRequest {
             ScanSettingsConfig::set_floating_point_tolerance(floating_point_tolerance);
         }
 
+        if let Some(value_overflow_policy) = self.value_overflow_policy {
+            ScanSettingsConfig::set_value_overflow_policy(value_overflow_policy);
+        }
+
         if let Some(is_single_threaded_scan) = self.is_single_threaded_scan {
             ScanSettingsConfig::set_is_single_threaded_scan(is_single_threaded_scan);
         }
 
+        if let Some(scan_thread_count) = self.scan_thread_count {
+            ScanSettingsConfig::set_scan_thread_count(scan_thread_count);
+        }
+
+        if let Some(vector_width) = self.vector_width {
+            ScanSettingsConfig::set_vector_width(vector_width);
+        }
+
         if let Some(debug_perform_validation_scan) = self.debug_perform_validation_scan {
             ScanSettingsConfig::set_debug_perform_validation_scan(debug_perform_validation_scan);
         }
@@ -8,7 +8,10 @@ use crossbeam_channel::Sender;
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
 use squalr_engine_api::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
+use squalr_engine_api::engine::protocol::engine_handshake::EngineHandshake;
+use squalr_engine_api::engine::protocol::engine_protocol_status::EngineProtocolStatus;
 use squalr_engine_api::events::engine_event::EngineEvent;
+use squalr_engine_debuggers::memory_watch::memory_watch_manager::MemoryWatchManager;
 use std::collections::HashMap;
 use std::sync::Mutex;
 use std::sync::{Arc, RwLock};
@@ -95,6 +98,7 @@ impl InterprocessEngineApiPrivilegedBindings {
         if let Ok(mut ipc_connection) = self.ipc_connection.write() {
             match InterprocessPipeBidirectional::create() {
                 Ok(new_connection) => {
+                    Self::answer_handshake(&new_connection)?;
                     *ipc_connection = Some(new_connection);
                     self.listen_for_host_requests(&engine_privileged_state);
                     Ok(())
@@ -106,6 +110,23 @@ impl InterprocessEngineApiPrivilegedBindings {
         }
     }
 
+    /// Answers the host's handshake with our own. The answer is sent even when the protocols are incompatible, such that the
+    /// host is able to report which versions are involved.
+    fn answer_handshake(ipc_connection: &InterprocessPipeBidirectional) -> Result<(), String> {
+        let (host_handshake, request_id) = ipc_connection.receive::<EngineHandshake>()?;
+        let local_handshake = EngineHandshake::current();
+
+        ipc_connection.send(local_handshake, request_id)?;
+
+        match local_handshake.negotiate(&host_handshake) {
+            EngineProtocolStatus::Incompatible { local_version, remote_version } => Err(format!(
+                "Host protocol {} is incompatible with protocol {}, refusing host requests.",
+                remote_version, local_version
+            )),
+            EngineProtocolStatus::Pending | EngineProtocolStatus::Connected { .. } => Ok(()),
+        }
+    }
+
     pub fn dispatch_response(
         ipc_connection: Arc<RwLock<Option<InterprocessPipeBidirectional>>>,
         engine_egress: EngineEgress,
@@ -143,6 +164,8 @@ impl InterprocessEngineApiPrivilegedBindings {
                                 }
                             },
                             Err(_error) => {
+                                // Exiting skips destructors, so any watches must detach from the target first.
+                                MemoryWatchManager::get_instance().stop_all();
                                 std::process::exit(1);
                             }
                         }
@@ -9,6 +9,8 @@ use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
 use squalr_engine_api::commands::unprivileged_command_response::UnprivilegedCommandResponse;
 use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_handshake::EngineHandshake;
+use squalr_engine_api::engine::protocol::engine_protocol_status::EngineProtocolStatus;
 use squalr_engine_api::events::engine_event::EngineEvent;
 use std::collections::HashMap;
 use std::io;
@@ -32,6 +34,9 @@ pub struct InterprocessEngineApiUnprivilegedBindings {
 
     /// The list of subscribers to which we send engine events, after having received them from the engine.
     event_senders: Arc<RwLock<Vec<Sender<EngineEvent>>>>,
+
+    /// The result of the handshake with the shell process.
+    protocol_status: Arc<RwLock<EngineProtocolStatus>>,
 }
 
 impl EngineApiUnprivilegedBindings for InterprocessEngineApiUnprivilegedBindings {
@@ -41,6 +46,18 @@ impl EngineApiUnprivilegedBindings for InterprocessEngineApiUnprivilegedBindings
         privileged_command: PrivilegedCommand,
         callback: Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>,
     ) -> Result<(), String> {
+        // Commands from a mismatched protocol would be deserialized as garbage by the shell, so never send them.
+        match self.get_protocol_status() {
+            EngineProtocolStatus::Connected { .. } => {}
+            EngineProtocolStatus::Pending => return Err("The privileged shell has not completed its handshake.".to_string()),
+            EngineProtocolStatus::Incompatible { local_version, remote_version } => {
+                return Err(format!(
+                    "The privileged shell speaks protocol {}, which is incompatible with protocol {}.",
+                    remote_version, local_version
+                ));
+            }
+        }
+
         let request_id = Uuid::new_v4();
 
         if let Ok(mut request_handles) = self.request_handles.lock() {
@@ -82,6 +99,13 @@ impl EngineApiUnprivilegedBindings for InterprocessEngineApiUnprivilegedBindings
 
         Ok(receiver)
     }
+
+    fn get_protocol_status(&self) -> EngineProtocolStatus {
+        match self.protocol_status.read() {
+            Ok(protocol_status) => *protocol_status,
+            Err(_error) => EngineProtocolStatus::Pending,
+        }
+    }
 }
 
 impl InterprocessEngineApiUnprivilegedBindings {
@@ -91,6 +115,7 @@ impl InterprocessEngineApiUnprivilegedBindings {
             ipc_connection: Arc::new(RwLock::new(None)),
             request_handles: Arc::new(Mutex::new(HashMap::new())),
             event_senders: Arc::new(RwLock::new(vec![])),
+            protocol_status: Arc::new(RwLock::new(EngineProtocolStatus::Pending)),
         };
 
         instance.initialize();
@@ -103,9 +128,10 @@ impl InterprocessEngineApiUnprivilegedBindings {
         let ipc_connection = self.ipc_connection.clone();
         let request_handles = self.request_handles.clone();
         let event_senders = self.event_senders.clone();
+        let protocol_status = self.protocol_status.clone();
 
         thread::spawn(move || {
-            if let Err(error) = Self::spawn_privileged_cli(privileged_shell_process) {
+            if let Err(error) = Self::spawn_privileged_cli(privileged_shell_process.clone()) {
                 log::error!("Failed to spawn privileged cli: {}", error);
             }
 
@@ -113,10 +139,62 @@ impl InterprocessEngineApiUnprivilegedBindings {
                 log::error!("Failed to bind to inter process pipe: {}", error);
             }
 
+            match Self::perform_handshake(&ipc_connection) {
+                Ok(negotiated_protocol_status) => {
+                    if let Ok(mut protocol_status) = protocol_status.write() {
+                        *protocol_status = negotiated_protocol_status;
+                    }
+
+                    if let EngineProtocolStatus::Incompatible { local_version, remote_version } = negotiated_protocol_status {
+                        log::error!(
+                            "Privileged shell protocol {} is incompatible with protocol {}. Reinstall so that both match.",
+                            remote_version,
+                            local_version
+                        );
+                        Self::terminate_privileged_cli(&privileged_shell_process);
+
+                        return;
+                    }
+                }
+                Err(error) => {
+                    log::error!("Failed to perform handshake with privileged shell: {}", error);
+                    return;
+                }
+            }
+
             Self::listen_for_shell_responses(request_handles, event_senders, ipc_connection);
         });
     }
 
+    /// Exchanges handshakes with the shell. This must be the first traffic over the pipe, since it is the only exchange that is
+    /// guaranteed to be understood by a shell built from a different version.
+    fn perform_handshake(ipc_connection: &Arc<RwLock<Option<InterprocessPipeBidirectional>>>) -> Result<EngineProtocolStatus, String> {
+        let ipc_connection = ipc_connection
+            .read()
+            .map_err(|error| format!("Failed to acquire read lock on bidirectional interprocess connection: {}", error))?;
+        let ipc_connection = ipc_connection
+            .as_ref()
+            .ok_or_else(|| "No interprocess connection is bound.".to_string())?;
+        let local_handshake = EngineHandshake::current();
+
+        ipc_connection.send(local_handshake, Uuid::nil())?;
+
+        let (remote_handshake, _request_id) = ipc_connection.receive::<EngineHandshake>()?;
+
+        Ok(local_handshake.negotiate(&remote_handshake))
+    }
+
+    fn terminate_privileged_cli(privileged_shell_process: &Arc<RwLock<Option<Child>>>) {
+        let child = match privileged_shell_process.write() {
+            Ok(mut privileged_shell_process) => privileged_shell_process.take(),
+            Err(_error) => None,
+        };
+
+        if let Some(Err(error)) = child.map(|mut child| child.kill()) {
+            log::error!("Failed to terminate privileged shell: {}", error);
+        }
+    }
+
     fn handle_engine_response(
         request_handles: &Arc<Mutex<HashMap<Uuid, Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync>>>>,
         engine_response: PrivilegedCommandResponse,
@@ -2,7 +2,7 @@ use crate::engine_bindings::executable_command_privileged::ExecutableCommandPriv
 use crate::engine_bindings::executable_command_unprivileged::ExecutableCommandUnprivleged;
 use crate::engine_privileged_state::EnginePrivilegedState;
 use crate::general_settings_config::GeneralSettingsConfig;
-use crossbeam_channel::Receiver;
+use crossbeam_channel::{Receiver, Sender};
 use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
 use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
 use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
@@ -11,10 +11,21 @@ use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnpriv
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
 use squalr_engine_api::events::engine_event::EngineEvent;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// A privileged command waiting on the debug request delay, along with when it should run.
+type DelayedPrivilegedCommand = (Instant, PrivilegedCommand, Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>);
 
 pub struct StandaloneEngineApiUnprivilegedBindings {
     // The instance of the engine privileged state. Since this is an intra-process implementation, we invoke commands using this state directly.
     engine_privileged_state: Option<Arc<EnginePrivilegedState>>,
+
+    // Commands delayed by the debug request delay, which are run one at a time such that they execute in the order they were dispatched.
+    delayed_command_sender: Option<Sender<DelayedPrivilegedCommand>>,
+
+    // The number of delayed commands that have not yet executed.
+    pending_delayed_command_count: Arc<AtomicUsize>,
 }
 
 impl StandaloneEngineApiUnprivilegedBindings {
@@ -27,8 +38,36 @@ impl StandaloneEngineApiUnprivilegedBindings {
 
             None
         };
+        let pending_delayed_command_count = Arc::new(AtomicUsize::new(0));
+        let delayed_command_sender = engine_privileged_state
+            .as_ref()
+            .map(|engine_privileged_state| Self::start_delayed_command_thread(engine_privileged_state.clone(), pending_delayed_command_count.clone()));
+
+        Self {
+            engine_privileged_state,
+            delayed_command_sender,
+            pending_delayed_command_count,
+        }
+    }
+
+    fn start_delayed_command_thread(
+        engine_privileged_state: Arc<EnginePrivilegedState>,
+        pending_delayed_command_count: Arc<AtomicUsize>,
+    ) -> Sender<DelayedPrivilegedCommand> {
+        let (delayed_command_sender, delayed_command_receiver) = crossbeam_channel::unbounded::<DelayedPrivilegedCommand>();
 
-        Self { engine_privileged_state }
+        std::thread::spawn(move || {
+            for (execute_at, privileged_command, callback) in delayed_command_receiver {
+                std::thread::sleep(execute_at.saturating_duration_since(Instant::now()));
+
+                let response = privileged_command.execute(&engine_privileged_state);
+
+                pending_delayed_command_count.fetch_sub(1, Ordering::AcqRel);
+                callback(response);
+            }
+        });
+
+        delayed_command_sender
     }
 }
 
@@ -41,18 +80,25 @@ impl EngineApiUnprivilegedBindings for StandaloneEngineApiUnprivilegedBindings {
     ) -> Result<(), String> {
         let engine_request_delay = GeneralSettingsConfig::get_engine_request_delay_ms();
 
-        if let Some(engine_privileged_state) = &self.engine_privileged_state {
-            // Execute the request either immediately, or on an artificial delay if a debug request delay is set.
-            if engine_request_delay <= 0 {
+        if let (Some(engine_privileged_state), Some(delayed_command_sender)) = (&self.engine_privileged_state, &self.delayed_command_sender) {
+            // Execute the request either immediately, or on an artificial delay if a debug request delay is set. A request dispatched
+            // while delayed requests are still pending waits behind them, such that requests always execute in the order they were
+            // dispatched. Otherwise, ie unfreezing and then writing a value could execute as writing and then unfreezing.
+            if engine_request_delay <= 0 && self.pending_delayed_command_count.load(Ordering::Acquire) == 0 {
                 callback(privileged_command.execute(&engine_privileged_state));
             } else {
-                let engine_privileged_state = engine_privileged_state.clone();
+                let execute_at = Instant::now() + Duration::from_millis(engine_request_delay);
+
+                self.pending_delayed_command_count.fetch_add(1, Ordering::AcqRel);
+
+                if delayed_command_sender
+                    .send((execute_at, privileged_command, callback))
+                    .is_err()
+                {
+                    self.pending_delayed_command_count.fetch_sub(1, Ordering::AcqRel);
 
-                std::thread::spawn(move || {
-                    std::thread::sleep(std::time::Duration::from_millis(engine_request_delay as u64));
-                    let response = privileged_command.execute(&engine_privileged_state);
-                    callback(response);
-                });
+                    return Err("Failed to queue delayed engine command.".to_string());
+                }
             }
 
             Ok(())
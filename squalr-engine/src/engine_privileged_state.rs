@@ -1,17 +1,27 @@
 use crate::engine_bindings::interprocess::interprocess_engine_api_privileged_bindings::InterprocessEngineApiPrivilegedBindings;
 use crate::engine_bindings::standalone::standalone_engine_api_privileged_bindings::StandalonePrivilegedEngine;
 use crate::engine_mode::EngineMode;
+use crate::tasks::hotkey_listen_task::HotkeyListenTask;
+use crate::tasks::process_reattach_task::ProcessReattachTask;
 use crate::tasks::trackable_task_manager::TrackableTaskManager;
 use crossbeam_channel::Receiver;
 use squalr_engine_api::engine::engine_api_priviliged_bindings::EngineApiPrivilegedBindings;
 use squalr_engine_api::events::engine_event::{EngineEvent, EngineEventRequest};
+use squalr_engine_api::events::module_symbols::loaded::module_symbols_loaded_event::ModuleSymbolsLoadedEvent;
+use squalr_engine_api::events::process::process_event::ProcessEvent;
+use squalr_engine_api::registries::breakpoint_list::breakpoint_list_registry::BreakpointListRegistry;
+use squalr_engine_api::registries::code_patch_list::code_patch_list_registry::CodePatchListRegistry;
 use squalr_engine_api::registries::freeze_list::freeze_list_registry::FreezeListRegistry;
+use squalr_engine_api::registries::hotkey_list::hotkey_list_registry::HotkeyListRegistry;
 use squalr_engine_api::registries::project_item_types::project_item_type_registry::ProjectItemTypeRegistry;
 use squalr_engine_api::registries::registries::Registries;
 use squalr_engine_api::registries::scan_rules::element_scan_rule_registry::ElementScanRuleRegistry;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::registries::value_watch_list::value_watch_list_registry::ValueWatchListRegistry;
 use squalr_engine_api::structures::pointer_scan::pointer_scan_results::PointerScanResults;
 use squalr_engine_api::structures::snapshots::snapshot::Snapshot;
+use squalr_engine_debuggers::memory_watch::memory_watch_manager::MemoryWatchManager;
+use squalr_engine_memory::module_symbols::module_symbol_cache::{ModuleSymbolCache, OsModuleImageSource};
 use squalr_engine_processes::process::process_manager::ProcessManager;
 use squalr_engine_processes::process_query::process_queryer::ProcessQuery;
 use squalr_engine_scanning::freeze_task::snapshot_scan_result_freeze_task::SnapshotScanResultFreezeTask;
@@ -32,6 +42,9 @@ pub struct EnginePrivilegedState {
     /// Stores the most recent pointer scan results for paging.
     pointer_scan_results: Arc<RwLock<PointerScanResults>>,
 
+    /// The lazily parsed exports of the modules in the opened process.
+    module_symbol_cache: Arc<ModuleSymbolCache>,
+
     /// Defines functionality that can be invoked by the engine for the GUI or CLI to handle.
     engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>,
 
@@ -66,14 +79,34 @@ impl EnginePrivilegedState {
         let snapshot = Arc::new(RwLock::new(Snapshot::new()));
         let pointer_scan_results = Arc::new(RwLock::new(PointerScanResults::default()));
         let registries = Arc::new(Registries::new());
+        let symbols_loaded_emitter = event_emitter.clone();
+        let module_symbol_cache = Arc::new(ModuleSymbolCache::new(
+            Box::new(OsModuleImageSource),
+            Box::new(move |module_name| {
+                symbols_loaded_emitter(
+                    ModuleSymbolsLoadedEvent {
+                        module_name: module_name.to_string(),
+                    }
+                    .to_engine_event(),
+                )
+            }),
+        ));
 
-        SnapshotScanResultFreezeTask::start_task(process_manager.get_opened_process_ref(), registries.get_freeze_list_registry().clone());
+        let value_watch_emitter = event_emitter.clone();
+
+        SnapshotScanResultFreezeTask::start_task(
+            process_manager.get_opened_process_ref(),
+            registries.get_freeze_list_registry().clone(),
+            registries.get_value_watch_list_registry().clone(),
+            move |value_watch_triggered_event| value_watch_emitter(value_watch_triggered_event.to_engine_event()),
+        );
 
         let engine_privileged_state = Arc::new(EnginePrivilegedState {
             process_manager,
             task_manager,
             snapshot,
             pointer_scan_results,
+            module_symbol_cache,
             engine_bindings,
             registries,
         });
@@ -113,6 +146,9 @@ impl EnginePrivilegedState {
             log::error!("Failed to monitor system processes: {}", error);
         }
 
+        ProcessReattachTask::start_task(Arc::downgrade(&engine_privileged_state));
+        HotkeyListenTask::start_task(Arc::downgrade(&engine_privileged_state));
+
         engine_privileged_state
     }
 
@@ -134,6 +170,11 @@ impl EnginePrivilegedState {
         self.pointer_scan_results.clone()
     }
 
+    /// Gets the cache of module exports, used to show addresses as symbols.
+    pub fn get_module_symbol_cache(&self) -> Arc<ModuleSymbolCache> {
+        self.module_symbol_cache.clone()
+    }
+
     /// Gets all engine registries.
     pub fn get_registries(&self) -> Arc<Registries> {
         self.registries.clone()
@@ -144,6 +185,26 @@ impl EnginePrivilegedState {
         self.registries.get_freeze_list_registry()
     }
 
+    /// Gets the registry for the list of values that have been marked to notify on change.
+    pub fn get_value_watch_list_registry(&self) -> Arc<RwLock<ValueWatchListRegistry>> {
+        self.registries.get_value_watch_list_registry()
+    }
+
+    /// Gets the registry for the list of chords that fire an event when pressed, regardless of which window has focus.
+    pub fn get_hotkey_list_registry(&self) -> Arc<RwLock<HotkeyListRegistry>> {
+        self.registries.get_hotkey_list_registry()
+    }
+
+    /// Gets the registry for the list of software breakpoints set in the opened process.
+    pub fn get_breakpoint_list_registry(&self) -> Arc<RwLock<BreakpointListRegistry>> {
+        self.registries.get_breakpoint_list_registry()
+    }
+
+    /// Gets the registry for the list of code patches applied to the opened process.
+    pub fn get_code_patch_list_registry(&self) -> Arc<RwLock<CodePatchListRegistry>> {
+        self.registries.get_code_patch_list_registry()
+    }
+
     /// Gets the registry for symbols.
     pub fn get_symbol_registry(&self) -> Arc<RwLock<SymbolRegistry>> {
         self.registries.get_symbol_registry()
@@ -193,6 +254,11 @@ impl EnginePrivilegedState {
     fn create_event_emitter(engine_bindings: Arc<RwLock<dyn EngineApiPrivilegedBindings>>) -> Arc<dyn Fn(EngineEvent) + Send + Sync> {
         let engine_bindings = engine_bindings.clone();
         Arc::new(move |event: EngineEvent| {
+            // Watches are bound to the process they trap pages in, so they are torn down rather than left attached.
+            if let EngineEvent::Process(ProcessEvent::ProcessChanged { .. }) = &event {
+                MemoryWatchManager::get_instance().stop_all();
+            }
+
             if let Ok(bindings) = engine_bindings.read() {
                 if let Err(error) = bindings.emit_event(event) {
                     log::error!("Error dispatching engine event: {}", error);
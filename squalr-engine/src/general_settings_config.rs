@@ -78,4 +78,36 @@ impl GeneralSettingsConfig {
 
         Self::save_config();
     }
+
+    pub fn get_last_opened_process_name() -> Option<String> {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.last_opened_process_name.clone()
+        } else {
+            GeneralSettings::default().last_opened_process_name
+        }
+    }
+
+    pub fn set_last_opened_process_name(value: String) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.last_opened_process_name = Some(value);
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_auto_reattach() -> bool {
+        if let Ok(config) = Self::get_instance().config.read() {
+            config.auto_reattach
+        } else {
+            GeneralSettings::default().auto_reattach
+        }
+    }
+
+    pub fn set_auto_reattach(value: bool) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.auto_reattach = value;
+        }
+
+        Self::save_config();
+    }
 }
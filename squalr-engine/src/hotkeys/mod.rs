@@ -0,0 +1,35 @@
+#[cfg(windows)]
+pub mod windows_hotkey_hooks;
+
+use squalr_engine_api::structures::hotkeys::hotkey_chord::HotkeyChord;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether the listener for keys pressed in any window started successfully.
+static IS_LISTENING: AtomicBool = AtomicBool::new(false);
+
+/// Gets whether chords are detected regardless of which window has focus, which requires both platform support and the listener
+/// to have started.
+pub fn is_supported() -> bool {
+    IS_LISTENING.load(Ordering::Acquire)
+}
+
+/// Starts listening for keys regardless of which window has focus. Each time a key is pressed or released, the callback is given a
+/// function telling whether a chord is held with exactly its modifiers, such that `Ctrl+1` does not also fire while `Ctrl+Shift+1`
+/// is held. Only one listener runs per process. Returns an error on platforms without support, or if the listener could not be
+/// started.
+pub fn start_listening(on_keys_changed: impl Fn(&dyn Fn(&HotkeyChord) -> bool) + Send + 'static) -> Result<(), String> {
+    #[cfg(windows)]
+    let listen_result = windows_hotkey_hooks::start_keyboard_hook(on_keys_changed);
+
+    #[cfg(not(windows))]
+    let listen_result = {
+        let _ = on_keys_changed;
+        Err("Global hotkeys are not supported on this platform.".to_string())
+    };
+
+    if listen_result.is_ok() {
+        IS_LISTENING.store(true, Ordering::Release);
+    }
+
+    listen_result
+}
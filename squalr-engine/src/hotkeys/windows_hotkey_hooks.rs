@@ -0,0 +1,160 @@
+use squalr_engine_api::structures::hotkeys::hotkey_chord::HotkeyChord;
+use std::collections::HashSet;
+use std::ptr::{null, null_mut};
+use std::sync::OnceLock;
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use windows_sys::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
+use windows_sys::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows_sys::Win32::UI::Input::KeyboardAndMouse::{
+    VIRTUAL_KEY, VK_ADD, VK_DECIMAL, VK_DIVIDE, VK_DOWN, VK_END, VK_F1, VK_HOME, VK_INSERT, VK_LCONTROL, VK_LEFT, VK_LMENU, VK_LSHIFT, VK_MULTIPLY, VK_NEXT,
+    VK_NUMPAD0, VK_PRIOR, VK_RCONTROL, VK_RIGHT, VK_RMENU, VK_RSHIFT, VK_SUBTRACT, VK_UP,
+};
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, GetMessageW, HC_ACTION, KBDLLHOOKSTRUCT, MSG, SetWindowsHookExW, UnhookWindowsHookEx, WH_KEYBOARD_LL, WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN,
+    WM_SYSKEYUP,
+};
+
+/// A key being pressed or released, as seen by the keyboard hook.
+struct KeyEvent {
+    virtual_key: VIRTUAL_KEY,
+    is_down: bool,
+}
+
+/// Forwards key events out of the hook procedure, which cannot capture state, to the thread tracking which keys are held.
+static KEY_EVENT_SENDER: OnceLock<Sender<KeyEvent>> = OnceLock::new();
+
+/// Installs a low level keyboard hook, calling back each time a key is pressed or released with a function telling whether a chord
+/// is held. The hook passes every key on, such that the game still receives them. Only one hook can be installed per process.
+pub fn start_keyboard_hook(on_keys_changed: impl Fn(&dyn Fn(&HotkeyChord) -> bool) + Send + 'static) -> Result<(), String> {
+    let (key_event_sender, key_event_receiver) = mpsc::channel();
+
+    KEY_EVENT_SENDER
+        .set(key_event_sender)
+        .map_err(|_key_event_sender| "The keyboard hook is already installed.".to_string())?;
+
+    let (hook_result_sender, hook_result_receiver) = mpsc::channel();
+
+    // Low level hooks are called on the thread that installed them, which must pump messages for as long as the hook is installed.
+    thread::spawn(move || {
+        let hook = unsafe { SetWindowsHookExW(WH_KEYBOARD_LL, Some(keyboard_hook_procedure), GetModuleHandleW(null()), 0) };
+
+        if hook.is_null() {
+            let _ = hook_result_sender.send(Err(format!("Failed to install the keyboard hook: {}", std::io::Error::last_os_error())));
+            return;
+        }
+
+        let _ = hook_result_sender.send(Ok(()));
+        let mut message: MSG = unsafe { std::mem::zeroed() };
+
+        while unsafe { GetMessageW(&mut message, null_mut(), 0, 0) } > 0 {}
+
+        unsafe { UnhookWindowsHookEx(hook) };
+    });
+
+    hook_result_receiver
+        .recv()
+        .map_err(|error| format!("The keyboard hook thread stopped before installing the hook: {}", error))??;
+
+    // Chords are matched away from the hook procedure, which must return quickly or Windows removes the hook.
+    thread::spawn(move || {
+        let mut held_keys = HashSet::new();
+
+        for key_event in key_event_receiver {
+            let is_changed = if key_event.is_down {
+                held_keys.insert(key_event.virtual_key)
+            } else {
+                held_keys.remove(&key_event.virtual_key)
+            };
+
+            // Holding a key repeats its key down, which changes nothing.
+            if is_changed {
+                on_keys_changed(&|chord| is_chord_down(chord, &held_keys));
+            }
+        }
+    });
+
+    Ok(())
+}
+
+unsafe extern "system" fn keyboard_hook_procedure(
+    code: i32,
+    w_param: WPARAM,
+    l_param: LPARAM,
+) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        let keyboard_hook_struct = unsafe { &*(l_param as *const KBDLLHOOKSTRUCT) };
+        let is_down = match w_param as u32 {
+            WM_KEYDOWN | WM_SYSKEYDOWN => Some(true),
+            WM_KEYUP | WM_SYSKEYUP => Some(false),
+            _ => None,
+        };
+
+        if let (Some(is_down), Some(key_event_sender)) = (is_down, KEY_EVENT_SENDER.get()) {
+            let _ = key_event_sender.send(KeyEvent {
+                virtual_key: keyboard_hook_struct.vkCode as VIRTUAL_KEY,
+                is_down,
+            });
+        }
+    }
+
+    // Keys are always passed on, such that the game still receives them.
+    unsafe { CallNextHookEx(null_mut(), code, w_param, l_param) }
+}
+
+/// Gets whether a chord is held with exactly its modifiers among the held keys. Low level hooks report which side a modifier is on,
+/// whereas chords do not care, so either side counts.
+fn is_chord_down(
+    chord: &HotkeyChord,
+    held_keys: &HashSet<VIRTUAL_KEY>,
+) -> bool {
+    let virtual_key = match get_virtual_key(chord.get_key_name()) {
+        Some(virtual_key) => virtual_key,
+        None => return false,
+    };
+    let is_either_held = |left_virtual_key, right_virtual_key| held_keys.contains(&left_virtual_key) || held_keys.contains(&right_virtual_key);
+
+    held_keys.contains(&virtual_key)
+        && is_either_held(VK_LCONTROL, VK_RCONTROL) == chord.get_is_ctrl_down()
+        && is_either_held(VK_LSHIFT, VK_RSHIFT) == chord.get_is_shift_down()
+        && is_either_held(VK_LMENU, VK_RMENU) == chord.get_is_alt_down()
+}
+
+/// Maps the canonical key names of chords to virtual keys. Letters and digits share their virtual key with their ASCII code.
+fn get_virtual_key(key_name: &str) -> Option<VIRTUAL_KEY> {
+    if key_name.len() == 1 {
+        return key_name.bytes().next().map(|key_byte| key_byte as VIRTUAL_KEY);
+    }
+
+    if let Some(number) = key_name
+        .strip_prefix("Numpad")
+        .and_then(|number| number.parse::<VIRTUAL_KEY>().ok())
+    {
+        return Some(VK_NUMPAD0 + number);
+    }
+
+    if let Some(number) = key_name
+        .strip_prefix('F')
+        .and_then(|number| number.parse::<VIRTUAL_KEY>().ok())
+    {
+        return Some(VK_F1 + number - 1);
+    }
+
+    match key_name {
+        "NumpadAdd" => Some(VK_ADD),
+        "NumpadSubtract" => Some(VK_SUBTRACT),
+        "NumpadMultiply" => Some(VK_MULTIPLY),
+        "NumpadDivide" => Some(VK_DIVIDE),
+        "NumpadDecimal" => Some(VK_DECIMAL),
+        "Up" => Some(VK_UP),
+        "Down" => Some(VK_DOWN),
+        "Left" => Some(VK_LEFT),
+        "Right" => Some(VK_RIGHT),
+        "PageUp" => Some(VK_PRIOR),
+        "PageDown" => Some(VK_NEXT),
+        "Home" => Some(VK_HOME),
+        "End" => Some(VK_END),
+        "Insert" => Some(VK_INSERT),
+        _ => None,
+    }
+}
@@ -4,6 +4,7 @@ pub mod engine_bindings;
 pub mod engine_mode;
 mod engine_privileged_state;
 pub mod general_settings_config;
+pub mod hotkeys;
 pub mod privileges;
 pub mod squalr_engine;
 pub mod tasks;
@@ -10,6 +10,7 @@ use squalr_engine_api::dependency_injection::dependency_container::DependencyCon
 use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
 use squalr_engine_architecture::vectors::Vectors;
+use squalr_engine_debuggers::memory_watch::memory_watch_manager::MemoryWatchManager;
 use std::sync::{Arc, RwLock};
 
 /// Orchestrates commands and responses to and from the engine.
@@ -93,3 +94,12 @@ impl SqualrEngine {
         &self.dependency_container
     }
 }
+
+impl Drop for SqualrEngine {
+    fn drop(&mut self) {
+        // Watches leave pages trapped within the target, which must be restored before the debugger threads end with this process.
+        if self.engine_privileged_state.is_some() {
+            MemoryWatchManager::get_instance().stop_all();
+        }
+    }
+}
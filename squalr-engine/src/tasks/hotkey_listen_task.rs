@@ -0,0 +1,45 @@
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::hotkeys;
+use squalr_engine_api::events::hotkeys::triggered::hotkey_triggered_event::HotkeyTriggeredEvent;
+use squalr_engine_api::structures::hotkeys::hotkey_chord::HotkeyChord;
+use std::sync::{Arc, Weak};
+
+/// Listens for the registered chords, and fires an event when one is pressed, regardless of which window has focus. Keys are
+/// observed as they are pressed and released rather than polled, and are passed on such that they still reach the game.
+pub struct HotkeyListenTask;
+
+impl HotkeyListenTask {
+    pub fn start_task(engine_privileged_state: Weak<EnginePrivilegedState>) {
+        let listen_result = hotkeys::start_listening(move |is_chord_down| {
+            if let Some(engine_privileged_state) = engine_privileged_state.upgrade() {
+                Self::on_keys_changed(&engine_privileged_state, is_chord_down);
+            }
+        });
+
+        if let Err(error) = listen_result {
+            log::warn!("Global hotkeys are unavailable: {}", error);
+        }
+    }
+
+    fn on_keys_changed(
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        is_chord_down: &dyn Fn(&HotkeyChord) -> bool,
+    ) {
+        let triggered_chords = match engine_privileged_state.get_hotkey_list_registry().write() {
+            Ok(mut hotkey_list_registry) => hotkey_list_registry
+                .get_chords()
+                .into_iter()
+                .filter(|chord| hotkey_list_registry.update_is_down(chord, is_chord_down(chord)))
+                .collect::<Vec<_>>(),
+            Err(error) => {
+                log::error!("Failed to acquire write lock on HotkeyListRegistry: {}", error);
+                return;
+            }
+        };
+
+        // Events are emitted once the registry lock is released, such that listeners may register chords in response.
+        for chord in triggered_chords {
+            engine_privileged_state.emit_event(HotkeyTriggeredEvent { chord });
+        }
+    }
+}
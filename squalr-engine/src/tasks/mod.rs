@@ -1 +1,3 @@
+pub mod hotkey_listen_task;
+pub mod process_reattach_task;
 pub mod trackable_task_manager;
@@ -0,0 +1,170 @@
+use crate::command_executors::privileged_request_executor::PrivilegedCommandRequestExecutor;
+use crate::engine_privileged_state::EnginePrivilegedState;
+use crate::general_settings_config::GeneralSettingsConfig;
+use squalr_engine_api::commands::process::open::process_open_request::ProcessOpenRequest;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use squalr_engine_api::structures::memory::normalized_module::NormalizedModule;
+use squalr_engine_api::structures::processes::opened_process_info::OpenedProcessInfo;
+use squalr_engine_api::structures::snapshots::snapshot_relocation::SnapshotRelocation;
+use squalr_engine_memory::memory_queryer::process_query_cache::ProcessQueryCache;
+use squalr_engine_processes::process_query::process_query_options::ProcessQueryOptions;
+use squalr_engine_processes::process_query::process_queryer::ProcessQuery;
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+/// Reopens the opened process once it exits and a process with the same name starts again, if auto-reattach is enabled. Scan
+/// results within modules follow the modules to where they were loaded in the new process, while other results are marked stale.
+pub struct ProcessReattachTask {
+    /// The modules of the opened process, captured while it runs, as they can no longer be enumerated once it exits.
+    attached_modules: Option<(u32, Arc<Vec<NormalizedModule>>)>,
+
+    /// The last process that reopening was attempted on, such that a process that cannot be opened is not retried every poll.
+    last_attempted_process_id: Option<u32>,
+}
+
+impl ProcessReattachTask {
+    /// How often the opened process modules are captured, and how often a restarted process is looked for once it exits.
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn start_task(engine_privileged_state: Weak<EnginePrivilegedState>) {
+        thread::spawn(move || {
+            let mut process_reattach_task = ProcessReattachTask {
+                attached_modules: None,
+                last_attempted_process_id: None,
+            };
+
+            loop {
+                thread::sleep(Self::POLL_INTERVAL);
+
+                let engine_privileged_state = match engine_privileged_state.upgrade() {
+                    Some(engine_privileged_state) => engine_privileged_state,
+                    None => return,
+                };
+
+                process_reattach_task.poll(&engine_privileged_state);
+            }
+        });
+    }
+
+    fn poll(
+        &mut self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+    ) {
+        if !GeneralSettingsConfig::get_auto_reattach() {
+            self.attached_modules = None;
+            return;
+        }
+
+        let process_manager = engine_privileged_state.get_process_manager();
+
+        if let Some(opened_process_info) = process_manager.get_opened_process() {
+            let modules = ProcessQueryCache::get_instance().get_modules(&opened_process_info);
+
+            self.attached_modules = Some((opened_process_info.get_process_id_raw(), modules));
+            self.last_attempted_process_id = None;
+
+            return;
+        }
+
+        // Only processes that exited are reopened. Closing the process on purpose clears the exited process.
+        let exited_process_info = match process_manager.get_exited_process() {
+            Some(exited_process_info) => exited_process_info,
+            None => return,
+        };
+        let process_query_options = ProcessQueryOptions {
+            required_process_id: None,
+            search_name: Some(exited_process_info.get_name().to_string()),
+            require_windowed: false,
+            match_case: true,
+            fetch_icons: false,
+            limit: None,
+        };
+        let restarted_process_id = ProcessQuery::get_processes(process_query_options)
+            .iter()
+            .find(|process_info| {
+                process_info.get_name() == exited_process_info.get_name() && process_info.get_process_id_raw() != exited_process_info.get_process_id_raw()
+            })
+            .map(|process_info| process_info.get_process_id_raw());
+        let restarted_process_id = match restarted_process_id {
+            Some(restarted_process_id) if self.last_attempted_process_id != Some(restarted_process_id) => restarted_process_id,
+            _ => return,
+        };
+
+        self.last_attempted_process_id = Some(restarted_process_id);
+
+        log::info!("'{}' restarted as pid {}, reattaching.", exited_process_info.get_name(), restarted_process_id);
+
+        // Opening fires the process changed event as usual, and keeps the read-only mode the process was attached with.
+        let process_open_request = ProcessOpenRequest {
+            process_id: Some(restarted_process_id),
+            search_name: None,
+            match_case: false,
+            read_only: exited_process_info.get_is_read_only(),
+            takeover: false,
+        };
+        let reopened_process_info = match process_open_request
+            .execute(engine_privileged_state)
+            .opened_process_info
+        {
+            Some(reopened_process_info) => reopened_process_info,
+            None => {
+                log::warn!("Failed to reattach to '{}'. Open it manually to continue.", exited_process_info.get_name());
+                return;
+            }
+        };
+
+        self.relocate_scan_results(engine_privileged_state, &exited_process_info, &reopened_process_info);
+    }
+
+    /// Moves scan results within modules of the exited process to where those modules were loaded in the reopened process, and
+    /// unfreezes absolute addresses, which would otherwise be written into unrelated memory of the new process.
+    fn relocate_scan_results(
+        &mut self,
+        engine_privileged_state: &Arc<EnginePrivilegedState>,
+        exited_process_info: &OpenedProcessInfo,
+        reopened_process_info: &OpenedProcessInfo,
+    ) {
+        let previous_modules = match self.attached_modules.take() {
+            Some((process_id, modules)) if process_id == exited_process_info.get_process_id_raw() => modules,
+            _ => Arc::default(),
+        };
+        let current_modules = ProcessQueryCache::get_instance().get_modules(reopened_process_info);
+        let snapshot_relocations = SnapshotRelocation::from_modules(&previous_modules, &current_modules);
+        let snapshot = engine_privileged_state.get_snapshot();
+        let scan_generation = match snapshot.write() {
+            Ok(mut snapshot) => {
+                let (relocated_region_count, stale_region_count) = snapshot.relocate_for_restarted_process(&snapshot_relocations);
+
+                log::info!(
+                    "Moved {} snapshot region(s) into {} reloaded module(s). {} region(s) outside of modules are marked stale.",
+                    relocated_region_count,
+                    snapshot_relocations.len(),
+                    stale_region_count
+                );
+
+                snapshot.get_scan_generation()
+            }
+            Err(error) => {
+                log::error!("Failed to acquire snapshot write lock to relocate scan results: {}", error);
+                return;
+            }
+        };
+
+        if let Ok(mut freeze_list_registry) = engine_privileged_state.get_freeze_list_registry().write() {
+            let unfrozen_count = freeze_list_registry.unfreeze_absolute_addresses();
+
+            if unfrozen_count > 0 {
+                log::warn!(
+                    "Unfroze {} address(es) outside of modules, as they do not carry over to the restarted process.",
+                    unfrozen_count
+                );
+            }
+        }
+
+        engine_privileged_state.emit_event(ScanResultsUpdatedEvent {
+            is_new_scan: false,
+            scan_generation,
+        });
+    }
+}
@@ -1,12 +1,28 @@
+use crate::models::appearance::appearance_settings::AppearanceSettings;
+use crate::models::crash_recovery::crash_context::CrashContext;
+use crate::models::crash_recovery::crash_recovery::CrashRecovery;
+use crate::models::crash_recovery::emergency_save::EmergencySave;
 use crate::models::docking::docking_manager::DockingManager;
 use crate::models::docking::settings::dockable_window_settings::DockableWindowSettings;
+use crate::models::taskbar_progress::taskbar_progress::TaskbarProgress;
+use crate::ui::frame_profiler::FrameProfiler;
+use crate::views::element_scanner::scanner::element_scanner_view_state::ElementScannerViewState;
+use crate::views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData;
 use crate::views::main_window::main_window_view::MainWindowView;
+use crate::views::main_window::view_data::crash_recovery_view_data::CrashRecoveryViewData;
+use crate::views::main_window::view_data::unsaved_changes_view_data::UnsavedChangesViewData;
+use crate::views::main_window::view_data::update_checker_view_data::UpdateCheckerViewData;
+use crate::views::main_window::view_data::workspace_view_data::WorkspaceViewData;
 use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::{app_context::AppContext, ui::theme::Theme};
-use eframe::egui::{CentralPanel, Context, Frame, ScrollArea, TextEdit, Visuals};
+use eframe::egui::{CentralPanel, Context, Frame, ScrollArea, TextEdit, ViewportCommand, Visuals};
 use epaint::{CornerRadius, Rgba, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::protocol::engine_protocol_status::EngineProtocolStatus;
 use squalr_engine_api::{dependency_injection::dependency_container::DependencyContainer, engine::engine_unprivileged_state::EngineUnprivilegedState};
+use std::cell::RefCell;
 use std::sync::RwLock;
+use std::time::{Duration, Instant};
 use std::{rc::Rc, sync::Arc};
 
 #[derive(Clone)]
@@ -15,9 +31,16 @@ pub struct App {
     main_window_view: MainWindowView,
     corner_radius: CornerRadius,
     last_panic: Option<String>,
+    element_scanner_view_data: Dependency<ElementScannerViewData>,
+    unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+    workspace_view_data: Dependency<WorkspaceViewData>,
+    taskbar_progress: Rc<RefCell<TaskbarProgress>>,
+    last_emergency_save_time: Instant,
 }
 
 impl App {
+    const EMERGENCY_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
     pub fn new(
         context: &Context,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
@@ -25,19 +48,140 @@ impl App {
         app_title: String,
     ) -> Self {
         let theme = Arc::new(Theme::new(context));
+
+        // Restore the user's UI scale, which is applied relative to the OS scale factor.
+        context.set_zoom_factor(AppearanceSettings::get_ui_scale());
+
         // Create built in docked windows.
         let main_dock_root = DockableWindowSettings::get_dock_layout_settings();
         let docking_manager = Arc::new(RwLock::new(DockingManager::new(main_dock_root)));
         let app_context = Arc::new(AppContext::new(context.clone(), theme, docking_manager, engine_unprivileged_state));
         let corner_radius = CornerRadius::same(8);
         let main_window_view = MainWindowView::new(app_context.clone(), Rc::new(app_title), corner_radius);
+        let element_scanner_view_data = app_context
+            .dependency_container
+            .get_dependency::<ElementScannerViewData>();
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .get_dependency::<UnsavedChangesViewData>();
+        let workspace_view_data = app_context
+            .dependency_container
+            .get_dependency::<WorkspaceViewData>();
+        let crash_recovery_view_data = app_context
+            .dependency_container
+            .get_dependency::<CrashRecoveryViewData>();
+
+        CrashRecoveryViewData::show_pending_crash(crash_recovery_view_data, CrashRecovery::take_pending_crash(CrashRecovery::get_crash_directory()));
+
+        let update_checker_view_data = app_context
+            .dependency_container
+            .get_dependency::<UpdateCheckerViewData>();
+
+        UpdateCheckerViewData::check_for_updates_on_startup(update_checker_view_data, context.clone());
 
         Self {
             app_context,
             main_window_view,
             corner_radius,
             last_panic: None,
+            element_scanner_view_data,
+            unsaved_changes_view_data,
+            workspace_view_data,
+            taskbar_progress: Rc::new(RefCell::new(TaskbarProgress::new())),
+            last_emergency_save_time: Instant::now(),
+        }
+    }
+
+    /// Refreshes the copy of unsaved project changes that is written out if Squalr crashes. The panic hook cannot read the project
+    /// itself, since the panicking code may be holding its lock, so it relies on this copy instead.
+    fn update_emergency_save(&mut self) {
+        if self.last_emergency_save_time.elapsed() < Self::EMERGENCY_SAVE_INTERVAL {
+            return;
         }
+
+        self.last_emergency_save_time = Instant::now();
+
+        let opened_project = self
+            .app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+
+        // Never stall a frame waiting on the project. The next interval tries again.
+        let emergency_save = match opened_project.try_read() {
+            Ok(opened_project) => opened_project.as_ref().and_then(EmergencySave::capture),
+            Err(_) => return,
+        };
+
+        CrashContext::set_emergency_save(emergency_save.as_ref());
+    }
+
+    /// Holds back closing the window while the opened project has unsaved changes, asking whether to save them instead. Once
+    /// the window is allowed to close, the active workspace is saved.
+    fn intercept_close_request(
+        &self,
+        context: &Context,
+    ) {
+        if !context.input(|input| input.viewport().close_requested()) {
+            return;
+        }
+
+        if UnsavedChangesViewData::intercept_close_request(self.unsaved_changes_view_data.clone(), self.app_context.clone()) {
+            context.send_viewport_cmd(ViewportCommand::CancelClose);
+        } else {
+            WorkspaceViewData::save_active_workspace(self.workspace_view_data.clone(), self.app_context.clone());
+        }
+    }
+
+    /// Mirrors scan progress onto the taskbar, such that it stays visible while another window is focused.
+    fn update_taskbar_progress(
+        &self,
+        context: &Context,
+        frame: &eframe::Frame,
+    ) {
+        let scan_progress = self
+            .element_scanner_view_data
+            .read("App taskbar scan progress")
+            .and_then(|element_scanner_view_data| {
+                (element_scanner_view_data.view_state == ElementScannerViewState::ScanInProgress).then_some(element_scanner_view_data.scan_progress)
+            });
+
+        if scan_progress.is_some() {
+            // Unfocused windows are not repainted on input, so keep polling for as long as the scan runs.
+            context.request_repaint_after(Duration::from_millis(250));
+        }
+
+        self.taskbar_progress
+            .borrow_mut()
+            .update(context, frame, scan_progress);
+    }
+
+    /// Renders a blocking error in place of the main window. Nothing can be done with an engine that speaks an incompatible
+    /// protocol, so rather than letting every command fail individually, explain the mismatch up front.
+    fn show_incompatible_engine(
+        context: &Context,
+        protocol_status: EngineProtocolStatus,
+    ) {
+        let EngineProtocolStatus::Incompatible { local_version, remote_version } = protocol_status else {
+            return;
+        };
+
+        CentralPanel::default().show(context, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.heading("Squalr engine version mismatch");
+                ui.label(format!(
+                    "This GUI speaks engine protocol {}, but the privileged engine speaks protocol {}.",
+                    local_version, remote_version
+                ));
+                ui.label("Reinstall Squalr so that the GUI and the engine come from the same release.");
+
+                ui.separator();
+
+                if ui.button("Exit").clicked() {
+                    ui.ctx().send_viewport_cmd(ViewportCommand::Close);
+                }
+            });
+        });
     }
 }
 
@@ -52,8 +196,31 @@ impl eframe::App for App {
     fn update(
         &mut self,
         context: &Context,
-        _frame: &mut eframe::Frame,
+        frame: &mut eframe::Frame,
     ) {
+        match self.app_context.engine_unprivileged_state.get_protocol_status() {
+            EngineProtocolStatus::Connected { .. } => {}
+            // The handshake completes off the UI thread, so poll until it resolves in case nothing else triggers a repaint.
+            EngineProtocolStatus::Pending => context.request_repaint_after(Duration::from_millis(250)),
+            protocol_status @ EngineProtocolStatus::Incompatible { .. } => {
+                Self::show_incompatible_engine(context, protocol_status);
+                return;
+            }
+        }
+
+        let frame_profiler = FrameProfiler::get_instance();
+
+        frame_profiler.begin_frame();
+
+        {
+            let _profiler_scope = frame_profiler.scope("Repaint scheduling");
+
+            self.update_taskbar_progress(context, frame);
+        }
+
+        self.update_emergency_save();
+        self.intercept_close_request(context);
+
         let main_window_view = self.main_window_view.clone();
         let app_frame = Frame::new()
             .corner_radius(self.corner_radius)
@@ -70,6 +237,8 @@ impl eframe::App for App {
                     user_interface.add(main_window_view);
                 });
 
+            let _profiler_scope = frame_profiler.scope("Memory viewer popout");
+
             MemoryViewerView::show_popout_window(self.app_context.clone());
         }));
 
@@ -101,6 +270,8 @@ impl eframe::App for App {
             self.last_panic = Some(report);
         }
 
+        frame_profiler.end_frame();
+
         if let Some(report) = self.last_panic.clone() {
             // Keep the app alive and show a diagnostic overlay.
             CentralPanel::default().show(context, |ui| {
@@ -132,4 +303,12 @@ impl eframe::App for App {
             });
         }
     }
+
+    fn on_exit(
+        &mut self,
+        _gl: Option<&eframe::glow::Context>,
+    ) {
+        // Stop reading memory before the engine is torn down, rather than leaving the scheduler to race it.
+        self.app_context.refresh_scheduler.shutdown();
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{models::docking::docking_manager::DockingManager, ui::theme::Theme};
+use crate::models::command_palette::command_registry::CommandRegistry;
+use crate::models::docking::{docking_command::DockingCommand, docking_command_queue::DockingCommandQueue, docking_manager::DockingManager};
+use crate::models::refresh_scheduler::refresh_scheduler::RefreshScheduler;
+use crate::ui::theme::Theme;
 use eframe::egui::Context;
 use squalr_engine_api::{dependency_injection::dependency_container::DependencyContainer, engine::engine_unprivileged_state::EngineUnprivilegedState};
 use std::sync::{Arc, RwLock};
@@ -9,7 +12,13 @@ pub struct AppContext {
     pub context: Context,
     pub theme: Arc<Theme>,
     pub docking_manager: Arc<RwLock<DockingManager>>,
+    /// Changes to the docking layout, applied once per frame before layout. Views should prefer this over writing to the docking manager.
+    pub docking_commands: Arc<DockingCommandQueue>,
     pub engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    /// Actions that views register at construction, such that they can be searched and run from the command palette.
+    pub command_registry: Arc<CommandRegistry>,
+    /// Runs the periodic memory reads of every view, at per-view intervals that can all be paused at once.
+    pub refresh_scheduler: Arc<RefreshScheduler>,
 
     /// Allows for registering and listening for dependencies.
     pub dependency_container: Arc<DependencyContainer>,
@@ -23,13 +32,28 @@ impl AppContext {
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) -> Self {
         let dependency_container = Arc::new(DependencyContainer::new());
+        let docking_commands = Arc::new(DockingCommandQueue::new());
+        let command_registry = Arc::new(CommandRegistry::new());
+        let refresh_scheduler = Arc::new(RefreshScheduler::new(context.clone(), engine_unprivileged_state.clone()));
 
         Self {
             context,
             theme,
             docking_manager,
+            docking_commands,
             engine_unprivileged_state,
+            command_registry,
+            refresh_scheduler,
             dependency_container,
         }
     }
+
+    /// Queues a change to the docking layout, and requests a repaint such that it is applied on the next frame.
+    pub fn queue_docking_command(
+        &self,
+        docking_command: DockingCommand,
+    ) {
+        self.docking_commands.push(docking_command);
+        self.context.request_repaint();
+    }
 }
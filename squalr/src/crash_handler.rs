@@ -1,3 +1,14 @@
+use crate::models::crash_recovery::crash_context::CrashContext;
+use crate::models::crash_recovery::crash_recovery::{CrashMarker, CrashRecovery};
+use crate::ui::ui_trace;
+use std::backtrace::Backtrace;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 #[cfg(windows)]
 mod windows {
     use rustc_demangle::demangle;
@@ -49,7 +60,16 @@ mod windows {
 
     unsafe extern "system" fn unhandled_exception_filter(exception_info: *const EXCEPTION_POINTERS) -> i32 {
         // Must not panic from inside the exception filter.
-        let _ = unsafe { write_crash_report(exception_info) };
+        if let Ok(Some(crash_log_path)) = unsafe { write_crash_report(exception_info) } {
+            // Only unhandled exceptions end the process, so unlike the vectored handler, this is where a restart should offer recovery.
+            super::write_crash_marker(
+                super::CrashRecovery::get_crash_directory(),
+                &crash_log_path,
+                super::get_timestamp_ms(),
+                super::CrashContext::get_active_window_id(),
+            );
+        }
+
         EXCEPTION_EXECUTE_HANDLER
     }
 
@@ -62,7 +82,8 @@ mod windows {
         let tid = unsafe { GetCurrentThreadId() };
 
         let base = format!("squalr_crash_{timestamp_ms}_pid{pid}_tid{tid}");
-        let dir = std::env::temp_dir();
+        let dir = super::CrashRecovery::get_crash_directory();
+        let _ = std::fs::create_dir_all(dir);
         (dir.join(format!("{base}.log")), dir.join(format!("{base}.dmp")))
     }
 
@@ -116,14 +137,14 @@ mod windows {
         Ok(())
     }
 
-    unsafe fn write_crash_report(exception_info: *const EXCEPTION_POINTERS) -> std::io::Result<()> {
+    unsafe fn write_crash_report(exception_info: *const EXCEPTION_POINTERS) -> std::io::Result<Option<std::path::PathBuf>> {
         if exception_info.is_null() {
-            return Ok(());
+            return Ok(None);
         }
 
         let exception_record = unsafe { (*exception_info).ExceptionRecord };
         if exception_record.is_null() {
-            return Ok(());
+            return Ok(None);
         }
 
         let exception_code = unsafe { (*exception_record).ExceptionCode };
@@ -145,7 +166,7 @@ mod windows {
         let process = unsafe { GetCurrentProcess() };
         if process == std::ptr::null_mut() {
             let _ = writeln!(file, "GetCurrentProcess failed.");
-            return Ok(());
+            return Ok(Some(crash_log_path));
         }
 
         let _ = unsafe { write_minidump(&crash_dump_path, exception_info) };
@@ -155,7 +176,7 @@ mod windows {
         unsafe { SymSetOptions(SYMOPT_UNDNAME | SYMOPT_DEFERRED_LOADS | SYMOPT_LOAD_LINES) };
         if unsafe { SymInitialize(process, std::ptr::null(), 1) } == 0 {
             let _ = writeln!(file, "SymInitialize failed.");
-            return Ok(());
+            return Ok(Some(crash_log_path));
         }
 
         // Resolve symbol name.
@@ -168,7 +189,7 @@ mod windows {
             Err(_) => {
                 let _ = writeln!(file, "Failed to compute SYMBOL_INFO layout.");
                 let _ = file.flush();
-                return Ok(());
+                return Ok(Some(crash_log_path));
             }
         };
 
@@ -176,7 +197,7 @@ mod windows {
         if symbol_buf.is_null() {
             let _ = writeln!(file, "Failed to allocate SYMBOL_INFO buffer.");
             let _ = file.flush();
-            return Ok(());
+            return Ok(Some(crash_log_path));
         }
 
         let symbol = symbol_buf as *mut SYMBOL_INFO;
@@ -260,7 +281,7 @@ mod windows {
         }
 
         let _ = file.flush();
-        Ok(())
+        Ok(Some(crash_log_path))
     }
 
     // Compile-time assertions for windows-sys types.
@@ -270,10 +291,148 @@ mod windows {
     }
 }
 
-#[cfg(windows)]
+static IN_PANIC_HOOK: AtomicBool = AtomicBool::new(false);
+
+/// Installs the panic hook, and on Windows the structured exception handlers, writing crash files to the default directory.
 pub fn install() {
+    install_panic_hook();
+
+    #[cfg(windows)]
     windows::install();
 }
 
-#[cfg(not(windows))]
-pub fn install() {}
+/// Replaces any previous hook, since the crash report supersedes it, but still chains to it such that panics reach stderr.
+fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |panic_info| {
+        // A second panic while a report is being written would interleave the files, so only the first one is reported.
+        if IN_PANIC_HOOK
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            let _ = write_panic_report(CrashRecovery::get_crash_directory(), panic_info);
+            IN_PANIC_HOOK.store(false, Ordering::SeqCst);
+        }
+
+        previous_hook(panic_info);
+    }));
+}
+
+fn get_timestamp_ms() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0)
+}
+
+/// Writes the crash report, then the emergency save and crash marker. This must not take any lock that the panicking code may
+/// hold, so it only reads lock-free state from `CrashContext` and the non-blocking `ui_trace` history.
+fn write_panic_report(
+    crash_directory: &Path,
+    panic_info: &dyn fmt::Display,
+) -> std::io::Result<PathBuf> {
+    let crashed_at_ms = get_timestamp_ms();
+    let report_path = crash_directory.join(format!("squalr_crash_{}_pid{}.log", crashed_at_ms, std::process::id()));
+    let active_window_id = CrashContext::get_active_window_id();
+    let backtrace = Backtrace::force_capture();
+    let recent_trace_records = ui_trace::get_recent_records();
+
+    fs::create_dir_all(crash_directory)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&report_path)?;
+
+    let _ = writeln!(file, "================ Squalr panic ================");
+    let _ = writeln!(file, "Version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(file, "Thread: {}", std::thread::current().name().unwrap_or("<unnamed>"));
+    let _ = writeln!(file, "Active window: {}", active_window_id.as_deref().unwrap_or("<none>"));
+    let _ = writeln!(file, "{}", panic_info);
+    let _ = writeln!(file);
+    let _ = writeln!(file, "Backtrace:");
+    let _ = writeln!(file, "{}", backtrace);
+    let _ = writeln!(file, "Recent UI trace ({} records):", recent_trace_records.len());
+
+    for trace_record in &recent_trace_records {
+        let _ = writeln!(file, "  {}", trace_record);
+    }
+
+    let _ = file.flush();
+
+    write_crash_marker(crash_directory, &report_path, crashed_at_ms, active_window_id);
+
+    Ok(report_path)
+}
+
+/// Writes the pre-captured emergency save, followed by the marker that causes the next launch to offer restoring from it.
+fn write_crash_marker(
+    crash_directory: &Path,
+    report_path: &Path,
+    crashed_at_ms: u128,
+    active_window_id: Option<String>,
+) {
+    match CrashContext::get_emergency_save_json() {
+        Some(emergency_save_json) => {
+            let _ = fs::write(CrashRecovery::get_emergency_save_path(crash_directory), emergency_save_json.as_bytes());
+        }
+        None => CrashRecovery::clear_emergency_save(crash_directory),
+    }
+
+    let crash_marker = CrashMarker {
+        report_path: report_path.to_path_buf(),
+        crashed_at_ms,
+        active_window_id,
+    };
+
+    if let Ok(crash_marker_json) = serde_json::to_string_pretty(&crash_marker) {
+        let _ = fs::write(CrashRecovery::get_crash_marker_path(crash_directory), crash_marker_json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::write_panic_report;
+    use crate::models::crash_recovery::crash_context::CrashContext;
+    use crate::models::crash_recovery::crash_recovery::CrashRecovery;
+    use crate::models::crash_recovery::emergency_save::{EmergencyProjectFile, EmergencySave};
+    use crate::ui::ui_trace;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Writes the report straight into a directory of its own, rather than installing the process wide panic hook, such that
+    /// panics in other tests are neither reported here nor redirected.
+    #[test]
+    fn panic_report_writes_report_and_emergency_save() {
+        let crash_directory = std::env::temp_dir().join(format!("squalr_crash_handler_test_{}", std::process::id()));
+        let emergency_save = EmergencySave {
+            project_name: "Test Project".to_string(),
+            project_directory: Some(PathBuf::from("projects/test_project")),
+            project_files: vec![EmergencyProjectFile {
+                path: PathBuf::from("projects/test_project/health.json"),
+                contents: "{}".to_string(),
+            }],
+        };
+
+        let _ = fs::remove_dir_all(&crash_directory);
+        CrashContext::set_emergency_save(Some(&emergency_save));
+        CrashContext::set_active_window_id(Some("window_test"));
+        ui_trace::trace("crash_handler_test.before_panic");
+
+        let report_path = write_panic_report(&crash_directory, &"Controlled crash handler test panic").expect("The report should be written.");
+        let pending_crash = CrashRecovery::take_pending_crash(&crash_directory).expect("The report should leave a crash marker behind.");
+        let report = fs::read_to_string(&pending_crash.crash_marker.report_path).expect("The marker should point at the report.");
+
+        assert_eq!(pending_crash.crash_marker.report_path, report_path);
+        assert!(report.contains("Controlled crash handler test panic"));
+        assert!(report.contains(env!("CARGO_PKG_VERSION")));
+        assert!(report.contains("Active window: window_test"));
+        assert!(report.contains("crash_handler_test.before_panic"));
+        assert_eq!(pending_crash.crash_marker.active_window_id.as_deref(), Some("window_test"));
+        assert_eq!(pending_crash.emergency_save, Some(emergency_save));
+        assert!(!CrashRecovery::get_crash_marker_path(&crash_directory).exists());
+
+        let _ = fs::remove_dir_all(&crash_directory);
+    }
+}
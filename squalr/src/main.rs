@@ -10,31 +10,24 @@ pub mod views;
 use app::App;
 use eframe::NativeOptions;
 use eframe::egui::{IconData, ViewportBuilder};
+use models::crash_recovery::crash_recovery::CrashRecovery;
+use models::demo_target::demo_target::DemoTarget;
 use squalr_engine::engine_mode::EngineMode;
 use squalr_engine::squalr_engine::SqualrEngine;
-use std::io::Write;
 
 static ICON_APP: &[u8] = include_bytes!("../images/app/app_icon.png");
 static APP_NAME: &str = "Squalr";
 
 pub fn main() {
-    crash_handler::install();
+    // Squalr launches a copy of itself as the target of its demo, which runs without a window or an engine.
+    if std::env::args().any(|argument| argument == DemoTarget::LAUNCH_ARGUMENT) {
+        DemoTarget::run();
+        return;
+    }
 
-    // In release builds we run without a console window. Capture panics to a log file so crashes
-    // remain diagnosable for end users.
-    let panic_log_path = std::env::temp_dir().join("squalr_panic.log");
-    std::panic::set_hook(Box::new(move |panic_info| {
-        let backtrace = std::backtrace::Backtrace::force_capture();
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&panic_log_path)
-        {
-            let _ = writeln!(file, "================ Squalr panic ================");
-            let _ = writeln!(file, "{}", panic_info);
-            let _ = writeln!(file, "{backtrace}");
-        }
-    }));
+    // In release builds we run without a console window, so panics are written to a crash report beside the executable, and the
+    // next launch offers to restore whatever was unsaved.
+    crash_handler::install();
 
     // Create a standalone engine (same process for gui and engine).
     let mut squalr_engine = match SqualrEngine::new(EngineMode::Standalone) {
@@ -85,7 +78,11 @@ pub fn main() {
             }
         }),
     ) {
-        Ok(_) => {}
+        Ok(_) => {
+            // Any panic this session was survived, since the event loop exited normally.
+            CrashRecovery::clear_crash_marker(CrashRecovery::get_crash_directory());
+            CrashRecovery::clear_emergency_save(CrashRecovery::get_crash_directory());
+        }
         Err(error) => {
             panic!("Fatal error in Squalr event loop: {}", error);
         }
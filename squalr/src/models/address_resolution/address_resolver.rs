@@ -0,0 +1,38 @@
+use squalr_engine_api::commands::address::resolve::address_resolve_request::AddressResolveRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::structures::expressions::address_resolve_error::AddressResolveError;
+use squalr_engine_api::structures::memory::resolved_address::ResolvedAddress;
+use std::sync::Arc;
+
+/// The outcome of resolving an address input, shown inline beside the input once it is committed.
+pub type AddressResolution = Result<ResolvedAddress, AddressResolveError>;
+
+/// Resolves the addresses typed into address inputs with the engine, such that every input accepts the same expressions as the CLI.
+pub struct AddressResolver {}
+
+impl AddressResolver {
+    /// Resolves the given expression against the opened process. The callback runs immediately if the engine cannot resolve
+    /// addresses, so callers must not hold a lock that the callback takes.
+    pub fn resolve<F>(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        expression: &str,
+        callback: F,
+    ) where
+        F: FnOnce(AddressResolution) + Clone + Send + Sync + 'static,
+    {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::ADDRESS_RESOLVE) {
+            callback(Err(AddressResolveError::Unsupported));
+            return;
+        }
+
+        let address_resolve_request = AddressResolveRequest {
+            expression: expression.to_string(),
+        };
+
+        address_resolve_request.send(engine_unprivileged_state, move |address_resolve_response| {
+            callback(address_resolve_response.resolved_address)
+        });
+    }
+}
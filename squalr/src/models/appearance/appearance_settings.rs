@@ -0,0 +1,181 @@
+use crate::models::appearance::row_density::RowDensity;
+use crate::models::appearance::value_color_rules::ValueColorRules;
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AppearanceSettingsConfig {
+    /// Multiplier applied on top of the OS scale factor, where 1.0 matches the OS default.
+    pub ui_scale: f32,
+    pub row_density: RowDensity,
+    /// The most constraint rows the element scanner allows at once.
+    pub max_scan_constraints: u32,
+    /// The most cells a single copy from the scan results may hold. Larger copies are refused in favor of exporting to a file.
+    pub max_copied_cells: u64,
+    /// Conditional formatting applied to the value column.
+    pub value_color_rules: ValueColorRules,
+}
+
+impl Default for AppearanceSettingsConfig {
+    fn default() -> Self {
+        Self {
+            ui_scale: AppearanceSettings::DEFAULT_UI_SCALE,
+            row_density: RowDensity::default(),
+            max_scan_constraints: AppearanceSettings::DEFAULT_MAX_SCAN_CONSTRAINTS,
+            max_copied_cells: AppearanceSettings::DEFAULT_MAX_COPIED_CELLS,
+            value_color_rules: ValueColorRules::default(),
+        }
+    }
+}
+
+/// GUI-only appearance preferences, persisted beside the executable. These are not engine settings, since they have no meaning to a privileged shell.
+pub struct AppearanceSettings {
+    config: RwLock<AppearanceSettingsConfig>,
+    config_file: PathBuf,
+}
+
+impl AppearanceSettings {
+    pub const DEFAULT_UI_SCALE: f32 = 1.0;
+    pub const MINIMUM_UI_SCALE: f32 = 0.75;
+    pub const MAXIMUM_UI_SCALE: f32 = 2.0;
+    pub const DEFAULT_MAX_SCAN_CONSTRAINTS: u32 = 10;
+    pub const MINIMUM_MAX_SCAN_CONSTRAINTS: u32 = 1;
+    pub const MAXIMUM_MAX_SCAN_CONSTRAINTS: u32 = 32;
+    pub const DEFAULT_MAX_COPIED_CELLS: u64 = 5_000_000;
+    pub const MINIMUM_MAX_COPIED_CELLS: u64 = 1_000_000;
+    pub const MAXIMUM_MAX_COPIED_CELLS: u64 = 50_000_000;
+
+    fn new() -> Self {
+        let config_file = Self::default_config_path();
+        let config = match fs::read_to_string(&config_file) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => AppearanceSettingsConfig::default(),
+        };
+
+        Self {
+            config: RwLock::new(config),
+            config_file,
+        }
+    }
+
+    fn get_instance() -> &'static AppearanceSettings {
+        static INSTANCE: OnceLock<AppearanceSettings> = OnceLock::new();
+
+        INSTANCE.get_or_init(AppearanceSettings::new)
+    }
+
+    fn default_config_path() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_default()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("appearance_settings.json")
+    }
+
+    fn save_config() {
+        let config = match Self::get_instance().config.read() {
+            Ok(config) => config.clone(),
+            Err(_) => return,
+        };
+
+        match to_string_pretty(&config) {
+            Ok(json) => {
+                if let Err(error) = fs::write(&Self::get_instance().config_file, json) {
+                    log::error!("Failed to save appearance settings: {}", error);
+                }
+            }
+            Err(error) => log::error!("Failed to serialize appearance settings: {}", error),
+        }
+    }
+
+    pub fn clamp_ui_scale(ui_scale: f32) -> f32 {
+        if ui_scale.is_finite() {
+            ui_scale.clamp(Self::MINIMUM_UI_SCALE, Self::MAXIMUM_UI_SCALE)
+        } else {
+            Self::DEFAULT_UI_SCALE
+        }
+    }
+
+    pub fn get_ui_scale() -> f32 {
+        match Self::get_instance().config.read() {
+            Ok(config) => Self::clamp_ui_scale(config.ui_scale),
+            Err(_) => Self::DEFAULT_UI_SCALE,
+        }
+    }
+
+    pub fn set_ui_scale(ui_scale: f32) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.ui_scale = Self::clamp_ui_scale(ui_scale);
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_row_density() -> RowDensity {
+        match Self::get_instance().config.read() {
+            Ok(config) => config.row_density,
+            Err(_) => RowDensity::default(),
+        }
+    }
+
+    pub fn set_row_density(row_density: RowDensity) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.row_density = row_density;
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_max_scan_constraints() -> u32 {
+        match Self::get_instance().config.read() {
+            Ok(config) => config
+                .max_scan_constraints
+                .clamp(Self::MINIMUM_MAX_SCAN_CONSTRAINTS, Self::MAXIMUM_MAX_SCAN_CONSTRAINTS),
+            Err(_) => Self::DEFAULT_MAX_SCAN_CONSTRAINTS,
+        }
+    }
+
+    pub fn set_max_scan_constraints(max_scan_constraints: u32) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.max_scan_constraints = max_scan_constraints.clamp(Self::MINIMUM_MAX_SCAN_CONSTRAINTS, Self::MAXIMUM_MAX_SCAN_CONSTRAINTS);
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_max_copied_cells() -> u64 {
+        match Self::get_instance().config.read() {
+            Ok(config) => config
+                .max_copied_cells
+                .clamp(Self::MINIMUM_MAX_COPIED_CELLS, Self::MAXIMUM_MAX_COPIED_CELLS),
+            Err(_) => Self::DEFAULT_MAX_COPIED_CELLS,
+        }
+    }
+
+    pub fn set_max_copied_cells(max_copied_cells: u64) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.max_copied_cells = max_copied_cells.clamp(Self::MINIMUM_MAX_COPIED_CELLS, Self::MAXIMUM_MAX_COPIED_CELLS);
+        }
+
+        Self::save_config();
+    }
+
+    pub fn get_value_color_rules() -> ValueColorRules {
+        match Self::get_instance().config.read() {
+            Ok(config) => config.value_color_rules.clone(),
+            Err(_) => ValueColorRules::default(),
+        }
+    }
+
+    pub fn set_value_color_rules(value_color_rules: ValueColorRules) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.value_color_rules = value_color_rules;
+        }
+
+        Self::save_config();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod appearance_settings;
+pub mod row_density;
+pub mod value_color_rules;
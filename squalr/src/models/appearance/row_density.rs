@@ -0,0 +1,102 @@
+use crate::ui::fonts::font_set::FontSet;
+use epaint::FontId;
+use serde::{Deserialize, Serialize};
+
+/// How tightly list rows are packed in the results, pointer scanner, memory region, and project lists.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RowDensity {
+    #[default]
+    Comfortable,
+    Compact,
+    Dense,
+}
+
+impl RowDensity {
+    pub const ALL: [RowDensity; 3] = [RowDensity::Comfortable, RowDensity::Compact, RowDensity::Dense];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            RowDensity::Comfortable => "Comfortable",
+            RowDensity::Compact => "Compact",
+            RowDensity::Dense => "Dense",
+        }
+    }
+
+    pub fn get_row_height(&self) -> f32 {
+        match self {
+            RowDensity::Comfortable => 32.0,
+            RowDensity::Compact => 22.0,
+            RowDensity::Dense => 18.0,
+        }
+    }
+
+    pub fn get_icon_size(&self) -> f32 {
+        match self {
+            RowDensity::Comfortable | RowDensity::Compact => 16.0,
+            RowDensity::Dense => 12.0,
+        }
+    }
+
+    /// Picks the font for row text from the given set. Dense rows are too short for the normal font, so they drop to the small one.
+    pub fn get_font(
+        &self,
+        font_set: &FontSet,
+    ) -> FontId {
+        match self {
+            RowDensity::Comfortable | RowDensity::Compact => font_set.font_normal.clone(),
+            RowDensity::Dense => font_set.font_small.clone(),
+        }
+    }
+
+    /// Gets the number of whole rows that fit within the given height, which is never less than one.
+    pub fn get_rows_fitting(
+        &self,
+        viewport_height: f32,
+    ) -> u32 {
+        (viewport_height / self.get_row_height()).floor().max(1.0) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RowDensity;
+    use crate::ui::theme::Theme;
+    use eframe::egui::{Context, RawInput};
+
+    #[test]
+    fn rows_fit_viewport_without_clipping_text() {
+        let context = Context::default();
+        let theme = Theme::new(&context);
+
+        // Fonts are only loaded once a frame has run.
+        let _ = context.run(RawInput::default(), |_context| {});
+
+        for row_density in RowDensity::ALL {
+            for viewport_height in [100.0, 480.0, 1080.0] {
+                let rows_fitting = row_density.get_rows_fitting(viewport_height);
+
+                assert!(rows_fitting as f32 * row_density.get_row_height() <= viewport_height);
+                assert!((rows_fitting + 1) as f32 * row_density.get_row_height() > viewport_height);
+            }
+
+            assert!(row_density.get_icon_size() <= row_density.get_row_height());
+
+            for font_set in [
+                &theme.font_library.font_noto_sans,
+                &theme.font_library.font_ubuntu_mono_bold,
+            ] {
+                let font_id = row_density.get_font(font_set);
+                let glyph_height = context.fonts(|fonts| fonts.row_height(&font_id));
+
+                assert!(
+                    glyph_height <= row_density.get_row_height(),
+                    "{} rows are {}px, but {:?} needs {}px",
+                    row_density.get_label(),
+                    row_density.get_row_height(),
+                    font_id,
+                    glyph_height
+                );
+            }
+        }
+    }
+}
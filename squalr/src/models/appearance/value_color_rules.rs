@@ -0,0 +1,276 @@
+use crate::ui::theme::Theme;
+use epaint::Color32;
+use serde::{Deserialize, Serialize};
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+
+/// The families of data types that value color rules are defined for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueColorRuleFamily {
+    Integer,
+    Float,
+}
+
+impl ValueColorRuleFamily {
+    pub const ALL: [ValueColorRuleFamily; 2] = [ValueColorRuleFamily::Integer, ValueColorRuleFamily::Float];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ValueColorRuleFamily::Integer => "Integer",
+            ValueColorRuleFamily::Float => "Float",
+        }
+    }
+}
+
+/// The colors a value can be shown in. These map onto theme colors rather than arbitrary ones, such that they stay legible.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ValueColor {
+    #[default]
+    Red,
+    Yellow,
+    Green,
+    Blue,
+}
+
+impl ValueColor {
+    pub const ALL: [ValueColor; 4] = [
+        ValueColor::Red,
+        ValueColor::Yellow,
+        ValueColor::Green,
+        ValueColor::Blue,
+    ];
+
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ValueColor::Red => "Red",
+            ValueColor::Yellow => "Yellow",
+            ValueColor::Green => "Green",
+            ValueColor::Blue => "Blue",
+        }
+    }
+
+    pub fn get_color(
+        &self,
+        theme: &Theme,
+    ) -> Color32 {
+        match self {
+            ValueColor::Red => theme.error_red,
+            ValueColor::Yellow => theme.background_control_warning,
+            ValueColor::Green => theme.background_control_success,
+            ValueColor::Blue => theme.binary_blue,
+        }
+    }
+}
+
+/// Colors values within a range, where the minimum is inclusive and the maximum is exclusive. A missing bound is unbounded, such that
+/// a rule without either bound acts as an "else" rule.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ValueColorRule {
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub color: ValueColor,
+}
+
+impl ValueColorRule {
+    pub fn new(
+        minimum: Option<f64>,
+        maximum: Option<f64>,
+        color: ValueColor,
+    ) -> Self {
+        Self { minimum, maximum, color }
+    }
+
+    /// Gets whether the value lies within this rule. NaN lies within no rule, not even one without bounds.
+    pub fn contains(
+        &self,
+        value: f64,
+    ) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+
+        self.minimum.is_none_or(|minimum| value >= minimum) && self.maximum.is_none_or(|maximum| value < maximum)
+    }
+}
+
+/// Conditional formatting for the value column, as an ordered list of threshold rules per data type family. The first rule containing
+/// a value decides its color, such that overlapping rules resolve in favor of the earlier one.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ValueColorRules {
+    pub integer_rules: Vec<ValueColorRule>,
+    pub float_rules: Vec<ValueColorRule>,
+}
+
+impl ValueColorRules {
+    /// The most rules kept per family, which bounds the cost of evaluating every visible value each frame.
+    pub const MAX_RULES_PER_FAMILY: usize = 16;
+
+    pub fn get_rules(
+        &self,
+        value_color_rule_family: ValueColorRuleFamily,
+    ) -> &Vec<ValueColorRule> {
+        match value_color_rule_family {
+            ValueColorRuleFamily::Integer => &self.integer_rules,
+            ValueColorRuleFamily::Float => &self.float_rules,
+        }
+    }
+
+    pub fn set_rules(
+        &mut self,
+        value_color_rule_family: ValueColorRuleFamily,
+        mut value_color_rules: Vec<ValueColorRule>,
+    ) {
+        value_color_rules.truncate(Self::MAX_RULES_PER_FAMILY);
+
+        match value_color_rule_family {
+            ValueColorRuleFamily::Integer => self.integer_rules = value_color_rules,
+            ValueColorRuleFamily::Float => self.float_rules = value_color_rules,
+        }
+    }
+
+    /// Gets the color of a value, if any rule of its family contains it. Values that are not numeric, or could not be read, are never colored.
+    pub fn evaluate(
+        &self,
+        data_value: &DataValue,
+    ) -> Option<ValueColor> {
+        let (value_color_rule_family, value) = Self::read_numeric_value(data_value)?;
+
+        self.get_rules(value_color_rule_family)
+            .iter()
+            .take(Self::MAX_RULES_PER_FAMILY)
+            .find(|value_color_rule| value_color_rule.contains(value))
+            .map(|value_color_rule| value_color_rule.color)
+    }
+
+    /// Reads the typed value from its bytes. Integers beyond 2^53 lose precision, which is acceptable for coarse thresholds.
+    fn read_numeric_value(data_value: &DataValue) -> Option<(ValueColorRuleFamily, f64)> {
+        let value_bytes = data_value.get_value_bytes();
+
+        macro_rules! read_le {
+            ($primitive_type:ty) => {
+                <[u8; size_of::<$primitive_type>()]>::try_from(value_bytes.as_slice())
+                    .ok()
+                    .map(|bytes| <$primitive_type>::from_le_bytes(bytes) as f64)
+            };
+        }
+
+        macro_rules! read_be {
+            ($primitive_type:ty) => {
+                <[u8; size_of::<$primitive_type>()]>::try_from(value_bytes.as_slice())
+                    .ok()
+                    .map(|bytes| <$primitive_type>::from_be_bytes(bytes) as f64)
+            };
+        }
+
+        let (value_color_rule_family, value) = match data_value.get_data_type_id() {
+            "u8" => (ValueColorRuleFamily::Integer, read_le!(u8)),
+            "i8" => (ValueColorRuleFamily::Integer, read_le!(i8)),
+            "u16" => (ValueColorRuleFamily::Integer, read_le!(u16)),
+            "i16" => (ValueColorRuleFamily::Integer, read_le!(i16)),
+            "u32" => (ValueColorRuleFamily::Integer, read_le!(u32)),
+            "i32" => (ValueColorRuleFamily::Integer, read_le!(i32)),
+            "u64" => (ValueColorRuleFamily::Integer, read_le!(u64)),
+            "i64" => (ValueColorRuleFamily::Integer, read_le!(i64)),
+            "u16be" => (ValueColorRuleFamily::Integer, read_be!(u16)),
+            "i16be" => (ValueColorRuleFamily::Integer, read_be!(i16)),
+            "u32be" => (ValueColorRuleFamily::Integer, read_be!(u32)),
+            "i32be" => (ValueColorRuleFamily::Integer, read_be!(i32)),
+            "u64be" => (ValueColorRuleFamily::Integer, read_be!(u64)),
+            "i64be" => (ValueColorRuleFamily::Integer, read_be!(i64)),
+            "f32" => (ValueColorRuleFamily::Float, read_le!(f32)),
+            "f64" => (ValueColorRuleFamily::Float, read_le!(f64)),
+            "f32be" => (ValueColorRuleFamily::Float, read_be!(f32)),
+            "f64be" => (ValueColorRuleFamily::Float, read_be!(f64)),
+            _ => return None,
+        };
+
+        value.map(|value| (value_color_rule_family, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValueColor, ValueColorRule, ValueColorRuleFamily, ValueColorRules};
+    use squalr_engine_api::structures::data_types::built_in_types::{
+        f32::data_type_f32::DataTypeF32, f64be::data_type_f64be::DataTypeF64be, i32::data_type_i32::DataTypeI32,
+        string::utf8::data_type_string_utf8::DataTypeStringUtf8, u8::data_type_u8::DataTypeU8,
+    };
+
+    fn create_traffic_light_rules() -> Vec<ValueColorRule> {
+        vec![
+            ValueColorRule::new(None, Some(10.0), ValueColor::Red),
+            ValueColorRule::new(Some(10.0), Some(50.0), ValueColor::Yellow),
+            ValueColorRule::new(None, None, ValueColor::Green),
+        ]
+    }
+
+    #[test]
+    fn first_containing_rule_wins() {
+        let mut value_color_rules = ValueColorRules::default();
+
+        value_color_rules.set_rules(ValueColorRuleFamily::Integer, create_traffic_light_rules());
+
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(-5)), Some(ValueColor::Red));
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(10)), Some(ValueColor::Yellow));
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(49)), Some(ValueColor::Yellow));
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(50)), Some(ValueColor::Green));
+        assert_eq!(value_color_rules.evaluate(&DataTypeU8::get_value_from_primitive(255)), Some(ValueColor::Green));
+
+        // Integer rules do not apply to floats, nor to non-numeric values.
+        assert_eq!(value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(5.0)), None);
+        assert_eq!(value_color_rules.evaluate(&DataTypeStringUtf8::get_value_from_primitive_string("5")), None);
+    }
+
+    #[test]
+    fn overlapping_rules_resolve_in_order() {
+        let mut value_color_rules = ValueColorRules::default();
+
+        value_color_rules.set_rules(
+            ValueColorRuleFamily::Integer,
+            vec![
+                ValueColorRule::new(Some(0.0), Some(100.0), ValueColor::Blue),
+                ValueColorRule::new(Some(50.0), Some(150.0), ValueColor::Red),
+            ],
+        );
+
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(75)), Some(ValueColor::Blue));
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(125)), Some(ValueColor::Red));
+        assert_eq!(value_color_rules.evaluate(&DataTypeI32::get_value_from_primitive(150)), None);
+
+        // Rules beyond the cap are dropped rather than evaluated.
+        value_color_rules.set_rules(
+            ValueColorRuleFamily::Integer,
+            vec![ValueColorRule::new(None, None, ValueColor::Green); ValueColorRules::MAX_RULES_PER_FAMILY + 4],
+        );
+
+        assert_eq!(value_color_rules.integer_rules.len(), ValueColorRules::MAX_RULES_PER_FAMILY);
+    }
+
+    #[test]
+    fn float_edge_cases_are_handled() {
+        let mut value_color_rules = ValueColorRules::default();
+
+        value_color_rules.set_rules(ValueColorRuleFamily::Float, create_traffic_light_rules());
+
+        assert_eq!(value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(f32::NAN)), None);
+        assert_eq!(
+            value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(f32::NEG_INFINITY)),
+            Some(ValueColor::Red)
+        );
+        assert_eq!(
+            value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(f32::INFINITY)),
+            Some(ValueColor::Green)
+        );
+        assert_eq!(value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(9.999)), Some(ValueColor::Red));
+        assert_eq!(
+            value_color_rules.evaluate(&DataTypeF64be::get_value_from_primitive(10.0)),
+            Some(ValueColor::Yellow)
+        );
+
+        // Negative zero compares equal to zero, so it falls within a range starting at zero.
+        value_color_rules.set_rules(ValueColorRuleFamily::Float, vec![ValueColorRule::new(Some(0.0), None, ValueColor::Blue)]);
+
+        assert_eq!(value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(-0.0)), Some(ValueColor::Blue));
+        assert_eq!(value_color_rules.evaluate(&DataTypeF32::get_value_from_primitive(-f32::MIN_POSITIVE)), None);
+    }
+}
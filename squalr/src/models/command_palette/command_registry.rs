@@ -0,0 +1,176 @@
+use crate::models::command_palette::fuzzy_matcher::FuzzyMatcher;
+use crate::models::command_palette::palette_command::PaletteCommand;
+use std::sync::RwLock;
+
+/// A command matching a palette query, along with whether it can currently run.
+#[derive(Clone)]
+pub struct CommandSearchResult {
+    pub command: PaletteCommand,
+    pub disabled_reason: Option<String>,
+}
+
+/// The commands shown in the command palette. Views register their actions when they are constructed, and the palette searches
+/// and runs them.
+pub struct CommandRegistry {
+    commands: RwLock<Vec<PaletteCommand>>,
+}
+
+impl CommandRegistry {
+    /// Matches on the category are accepted, but rank below equally good matches on the label alone.
+    const CATEGORY_MATCH_PENALTY: i32 = 4;
+
+    pub fn new() -> Self {
+        Self {
+            commands: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Registers a command, replacing any command registered with the same id.
+    pub fn register(
+        &self,
+        command: PaletteCommand,
+    ) {
+        match self.commands.write() {
+            Ok(mut commands) => match commands
+                .iter_mut()
+                .find(|registered_command| registered_command.command_id == command.command_id)
+            {
+                Some(registered_command) => *registered_command = command,
+                None => commands.push(command),
+            },
+            Err(error) => log::error!("Failed to acquire command registry to register a command: {}", error),
+        }
+    }
+
+    /// Gets the commands matching the query, best matches first. Commands that match equally well keep the order in which they
+    /// were registered. An empty query lists every command.
+    pub fn search(
+        &self,
+        query: &str,
+    ) -> Vec<CommandSearchResult> {
+        let commands = self.get_commands();
+        let mut scored_commands = commands
+            .into_iter()
+            .filter_map(|command| {
+                let label_score = FuzzyMatcher::score(query, &command.label);
+                let category_score = FuzzyMatcher::score(query, &format!("{} {}", command.category, command.label))
+                    .map(|category_score| category_score - Self::CATEGORY_MATCH_PENALTY);
+                let score = label_score.max(category_score)?;
+
+                Some((score, command))
+            })
+            .collect::<Vec<_>>();
+
+        scored_commands.sort_by(|left, right| right.0.cmp(&left.0));
+        scored_commands
+            .into_iter()
+            .map(|(_score, command)| CommandSearchResult {
+                disabled_reason: command.get_disabled_reason(),
+                command,
+            })
+            .collect()
+    }
+
+    /// Runs the command with the given id. Whether the command is enabled is checked again right before it runs, as the state it
+    /// depends on may have changed since it was listed.
+    pub fn invoke(
+        &self,
+        command_id: &str,
+    ) -> Result<(), String> {
+        // Commands are cloned out of the registry before running, such that a command may register further commands.
+        let command = self
+            .get_commands()
+            .into_iter()
+            .find(|command| command.command_id == command_id)
+            .ok_or_else(|| format!("No command is registered as '{}'.", command_id))?;
+
+        command.invoke()
+    }
+
+    fn get_commands(&self) -> Vec<PaletteCommand> {
+        match self.commands.read() {
+            Ok(commands) => commands.clone(),
+            Err(error) => {
+                log::error!("Failed to acquire command registry to read commands: {}", error);
+
+                Vec::new()
+            }
+        }
+    }
+}
+
+impl Default for CommandRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandRegistry;
+    use crate::models::command_palette::palette_command::PaletteCommand;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+    #[test]
+    fn searches_rank_label_matches_first_and_keep_registration_order_for_ties() {
+        let command_registry = CommandRegistry::new();
+
+        for (command_id, category, label) in [
+            ("new_scan", "Scan", "New Scan"),
+            ("unfreeze", "Results", "Unfreeze Selected Results"),
+            ("show_settings", "Windows", "Show Settings"),
+            ("show_scanner", "Windows", "Show Element Scanner"),
+        ] {
+            command_registry.register(PaletteCommand::new(command_id, category, label, || {}));
+        }
+
+        let get_command_ids = |query: &str| {
+            command_registry
+                .search(query)
+                .into_iter()
+                .map(|search_result| search_result.command.command_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(get_command_ids("ns")[0], "new_scan");
+        assert_eq!(get_command_ids("scanner")[0], "show_scanner");
+        assert_eq!(get_command_ids("windows"), vec!["show_settings", "show_scanner"]);
+        assert_eq!(get_command_ids("").len(), 4);
+        assert!(get_command_ids("zzz").is_empty());
+    }
+
+    #[test]
+    fn disabled_commands_are_listed_with_their_reason_but_never_run() {
+        let command_registry = CommandRegistry::new();
+        let is_scanning = Arc::new(AtomicBool::new(true));
+        let is_scanning_clone = is_scanning.clone();
+        let run_count = Arc::new(AtomicU32::new(0));
+        let run_count_clone = run_count.clone();
+
+        command_registry.register(
+            PaletteCommand::new("new_scan", "Scan", "New Scan", move || {
+                run_count_clone.fetch_add(1, Ordering::SeqCst);
+            })
+            .with_disabled_reason(move || {
+                is_scanning_clone
+                    .load(Ordering::SeqCst)
+                    .then(|| "A scan is in progress.".to_string())
+            }),
+        );
+
+        let search_results = command_registry.search("new scan");
+
+        assert_eq!(search_results.len(), 1);
+        assert_eq!(search_results[0].disabled_reason.as_deref(), Some("A scan is in progress."));
+        assert_eq!(command_registry.invoke("new_scan"), Err("A scan is in progress.".to_string()));
+        assert_eq!(run_count.load(Ordering::SeqCst), 0);
+
+        // The state is checked again when invoking, rather than trusting what was listed.
+        is_scanning.store(false, Ordering::SeqCst);
+
+        assert_eq!(command_registry.invoke("new_scan"), Ok(()));
+        assert_eq!(run_count.load(Ordering::SeqCst), 1);
+        assert!(command_registry.invoke("missing").is_err());
+    }
+}
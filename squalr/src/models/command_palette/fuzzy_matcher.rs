@@ -0,0 +1,146 @@
+/// Scores how well a query typed into the command palette matches a piece of text. Every character of the query must appear in
+/// the text in order, ignoring case and spaces in the query. Characters at the start of a word, and runs of consecutive characters,
+/// score higher, such that "ns" ranks "New Scan" above "Unfreeze Selection".
+pub struct FuzzyMatcher {}
+
+impl FuzzyMatcher {
+    const MATCH_SCORE: i32 = 1;
+    const WORD_START_BONUS: i32 = 8;
+    const CONSECUTIVE_BONUS: i32 = 6;
+    const GAP_PENALTY: i32 = 1;
+    /// Unmatched text before the first match is penalized up to this many characters, such that long prefixes are not punished
+    /// without bound.
+    const MAXIMUM_LEADING_PENALTY: i32 = 3;
+
+    /// Gets the score of the best way to match the query against the text, or `None` if the text does not contain every character
+    /// of the query in order. An empty query matches everything with a score of zero.
+    pub fn score(
+        query: &str,
+        text: &str,
+    ) -> Option<i32> {
+        let query_characters = query
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect::<Vec<_>>();
+        let text_characters = text.chars().collect::<Vec<_>>();
+
+        if query_characters.is_empty() {
+            return Some(0);
+        }
+
+        if query_characters.len() > text_characters.len() {
+            return None;
+        }
+
+        let lowercase_text_characters = text_characters
+            .iter()
+            .map(|character| character.to_lowercase().next().unwrap_or(*character))
+            .collect::<Vec<_>>();
+        let character_scores = (0..text_characters.len())
+            .map(|text_index| {
+                if Self::is_word_start(&text_characters, text_index) {
+                    Self::MATCH_SCORE + Self::WORD_START_BONUS
+                } else {
+                    Self::MATCH_SCORE
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // The best score with the current query character placed at each text position, built one query character at a time.
+        let mut best_scores = (0..text_characters.len())
+            .map(|text_index| {
+                (lowercase_text_characters[text_index] == query_characters[0])
+                    .then(|| character_scores[text_index] - (text_index as i32).min(Self::MAXIMUM_LEADING_PENALTY))
+            })
+            .collect::<Vec<_>>();
+
+        for query_character in &query_characters[1..] {
+            let mut next_best_scores = vec![None; text_characters.len()];
+            // The best score of placing the previous query character before the previous text position, adjusted such that
+            // subtracting the gap penalty for the current position gives the score after skipping the characters between.
+            let mut best_score_after_gap: Option<i32> = None;
+
+            for text_index in 1..text_characters.len() {
+                if text_index >= 2
+                    && let Some(previous_score) = best_scores[text_index - 2]
+                {
+                    let adjusted_score = previous_score + Self::GAP_PENALTY * (text_index as i32 - 1);
+
+                    best_score_after_gap = Some(best_score_after_gap.map_or(adjusted_score, |best_score| best_score.max(adjusted_score)));
+                }
+
+                if lowercase_text_characters[text_index] != *query_character {
+                    continue;
+                }
+
+                let consecutive_score = best_scores[text_index - 1].map(|previous_score| previous_score + Self::CONSECUTIVE_BONUS);
+                let gap_score = best_score_after_gap.map(|best_score| best_score - Self::GAP_PENALTY * text_index as i32);
+                let previous_score = match (consecutive_score, gap_score) {
+                    (Some(consecutive_score), Some(gap_score)) => Some(consecutive_score.max(gap_score)),
+                    (consecutive_score, gap_score) => consecutive_score.or(gap_score),
+                };
+
+                next_best_scores[text_index] = previous_score.map(|previous_score| previous_score + character_scores[text_index]);
+            }
+
+            best_scores = next_best_scores;
+        }
+
+        best_scores.into_iter().flatten().max()
+    }
+
+    /// Gets whether the character at the given index starts a word, either after a separator or as an uppercase letter following a
+    /// lowercase one.
+    fn is_word_start(
+        text_characters: &[char],
+        text_index: usize,
+    ) -> bool {
+        let Some(previous_character) = text_index
+            .checked_sub(1)
+            .map(|previous_index| text_characters[previous_index])
+        else {
+            return true;
+        };
+        let character = text_characters[text_index];
+
+        !previous_character.is_alphanumeric() || (previous_character.is_lowercase() && character.is_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FuzzyMatcher;
+
+    fn rank<'a>(
+        query: &str,
+        texts: &[&'a str],
+    ) -> Vec<&'a str> {
+        let mut scored_texts = texts
+            .iter()
+            .filter_map(|text| FuzzyMatcher::score(query, text).map(|score| (score, *text)))
+            .collect::<Vec<_>>();
+
+        scored_texts.sort_by(|left, right| right.0.cmp(&left.0));
+        scored_texts.into_iter().map(|(_score, text)| text).collect()
+    }
+
+    #[test]
+    fn word_starts_and_consecutive_characters_rank_first() {
+        assert_eq!(rank("ns", &["Unfreeze Selection", "New Scan"]), vec!["New Scan", "Unfreeze Selection"]);
+        assert_eq!(rank("mv", &["Remove Value", "Show Memory Viewer"]), vec!["Show Memory Viewer", "Remove Value"]);
+        assert_eq!(
+            rank("freeze", &["Unfreeze Selected Results", "Freeze Selected Results"]),
+            vec!["Freeze Selected Results", "Unfreeze Selected Results"]
+        );
+        assert_eq!(rank("scan", &["Select Cancel", "Start Scan"]), vec!["Start Scan", "Select Cancel"]);
+    }
+
+    #[test]
+    fn queries_match_in_order_ignoring_case_and_spaces() {
+        assert!(FuzzyMatcher::score("NEW scan", "New Scan").is_some());
+        assert!(FuzzyMatcher::score("nacs", "New Scan").is_none());
+        assert!(FuzzyMatcher::score("scans", "Scan").is_none());
+        assert_eq!(FuzzyMatcher::score("", "New Scan"), Some(0));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod command_registry;
+pub mod fuzzy_matcher;
+pub mod palette_command;
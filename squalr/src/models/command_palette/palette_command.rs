@@ -0,0 +1,73 @@
+use eframe::egui::KeyboardShortcut;
+use std::sync::Arc;
+
+/// An action that can be run from the command palette, registered by the view that owns the action.
+///
+/// Commands must not capture state that can go stale, such as a copy of a selection. Instead, they capture dependencies and run
+/// through the same requests and deferred frame actions as the buttons they mirror, such that they act on the state at the time
+/// they are run.
+#[derive(Clone)]
+pub struct PaletteCommand {
+    pub command_id: String,
+    pub category: String,
+    pub label: String,
+    /// The shortcut that runs this action in the view that owns it, shown beside the command.
+    pub shortcut: Option<KeyboardShortcut>,
+    disabled_reason: Option<Arc<dyn Fn() -> Option<String> + Send + Sync>>,
+    invoke: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl PaletteCommand {
+    pub fn new(
+        command_id: impl Into<String>,
+        category: impl Into<String>,
+        label: impl Into<String>,
+        invoke: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            command_id: command_id.into(),
+            category: category.into(),
+            label: label.into(),
+            shortcut: None,
+            disabled_reason: None,
+            invoke: Arc::new(invoke),
+        }
+    }
+
+    pub fn with_shortcut(
+        mut self,
+        shortcut: KeyboardShortcut,
+    ) -> Self {
+        self.shortcut = Some(shortcut);
+
+        self
+    }
+
+    /// Disables the command whenever the given callback returns a reason, which is shown in place of running it.
+    pub fn with_disabled_reason(
+        mut self,
+        disabled_reason: impl Fn() -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.disabled_reason = Some(Arc::new(disabled_reason));
+
+        self
+    }
+
+    /// Gets why the command cannot currently run, or `None` if it is enabled.
+    pub fn get_disabled_reason(&self) -> Option<String> {
+        self.disabled_reason
+            .as_ref()
+            .and_then(|disabled_reason| disabled_reason())
+    }
+
+    /// Runs the command, unless it is disabled, in which case the reason is returned instead.
+    pub fn invoke(&self) -> Result<(), String> {
+        if let Some(disabled_reason) = self.get_disabled_reason() {
+            return Err(disabled_reason);
+        }
+
+        (self.invoke)();
+
+        Ok(())
+    }
+}
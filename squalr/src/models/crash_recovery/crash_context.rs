@@ -0,0 +1,49 @@
+use crate::models::crash_recovery::emergency_save::EmergencySave;
+use arc_swap::ArcSwapOption;
+use std::sync::Arc;
+
+static ACTIVE_WINDOW_ID: ArcSwapOption<String> = ArcSwapOption::const_empty();
+static EMERGENCY_SAVE_JSON: ArcSwapOption<String> = ArcSwapOption::const_empty();
+
+/// State captured while the GUI runs, for the panic hook to read. A panic can happen while any lock or `Dependency` write guard
+/// is held, so everything here is stored lock-free, and the emergency save is serialized up front rather than in the hook.
+pub struct CrashContext {}
+
+impl CrashContext {
+    /// Records the window being drawn, or `None` once drawing it has finished.
+    pub fn set_active_window_id(window_id: Option<&str>) {
+        let is_unchanged = match (ACTIVE_WINDOW_ID.load().as_deref(), window_id) {
+            (Some(active_window_id), Some(window_id)) => active_window_id == window_id,
+            (None, None) => true,
+            _ => false,
+        };
+
+        // Windows are drawn every frame, so skip the allocation when nothing changed.
+        if !is_unchanged {
+            ACTIVE_WINDOW_ID.store(window_id.map(|window_id| Arc::new(window_id.to_string())));
+        }
+    }
+
+    pub fn get_active_window_id() -> Option<String> {
+        ACTIVE_WINDOW_ID
+            .load()
+            .as_deref()
+            .map(|window_id| window_id.to_string())
+    }
+
+    pub fn set_emergency_save(emergency_save: Option<&EmergencySave>) {
+        let emergency_save_json = emergency_save.and_then(|emergency_save| match serde_json::to_string(emergency_save) {
+            Ok(emergency_save_json) => Some(Arc::new(emergency_save_json)),
+            Err(error) => {
+                log::error!("Failed to serialize emergency save: {}", error);
+                None
+            }
+        });
+
+        EMERGENCY_SAVE_JSON.store(emergency_save_json);
+    }
+
+    pub fn get_emergency_save_json() -> Option<Arc<String>> {
+        EMERGENCY_SAVE_JSON.load_full()
+    }
+}
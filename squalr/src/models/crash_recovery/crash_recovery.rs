@@ -0,0 +1,83 @@
+use crate::models::crash_recovery::emergency_save::EmergencySave;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+static CRASH_DIRECTORY: OnceLock<PathBuf> = OnceLock::new();
+
+/// Written last when Squalr crashes, such that its presence on the next launch means the report and emergency save are complete.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CrashMarker {
+    pub report_path: PathBuf,
+    pub crashed_at_ms: u128,
+    pub active_window_id: Option<String>,
+}
+
+/// A crash found on launch, which the user has not yet chosen to restore from or dismiss.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingCrash {
+    pub crash_marker: CrashMarker,
+    pub emergency_save: Option<EmergencySave>,
+}
+
+/// Locates and manages the crash reports, crash marker, and emergency save, which live in a folder beside the executable.
+pub struct CrashRecovery {}
+
+impl CrashRecovery {
+    const CRASH_MARKER_FILE: &'static str = "crash_marker.json";
+    const EMERGENCY_SAVE_FILE: &'static str = "emergency_save.json";
+
+    pub fn get_crash_directory() -> &'static Path {
+        CRASH_DIRECTORY
+            .get_or_init(|| {
+                std::env::current_exe()
+                    .unwrap_or_default()
+                    .parent()
+                    .unwrap_or(Path::new(""))
+                    .join("crash_reports")
+            })
+            .as_path()
+    }
+
+    pub fn get_crash_marker_path(crash_directory: &Path) -> PathBuf {
+        crash_directory.join(Self::CRASH_MARKER_FILE)
+    }
+
+    pub fn get_emergency_save_path(crash_directory: &Path) -> PathBuf {
+        crash_directory.join(Self::EMERGENCY_SAVE_FILE)
+    }
+
+    /// Reads the crash left behind by the previous session, if any. The marker is removed, such that the same crash is only
+    /// offered once, but the emergency save is kept until it is restored or dismissed.
+    pub fn take_pending_crash(crash_directory: &Path) -> Option<PendingCrash> {
+        let crash_marker_path = Self::get_crash_marker_path(crash_directory);
+        let crash_marker_json = fs::read_to_string(&crash_marker_path).ok()?;
+
+        if let Err(error) = fs::remove_file(&crash_marker_path) {
+            log::error!("Failed to remove crash marker: {}", error);
+        }
+
+        let crash_marker = match serde_json::from_str::<CrashMarker>(&crash_marker_json) {
+            Ok(crash_marker) => crash_marker,
+            Err(error) => {
+                log::error!("Failed to read crash marker: {}", error);
+                return None;
+            }
+        };
+        let emergency_save = fs::read_to_string(Self::get_emergency_save_path(crash_directory))
+            .ok()
+            .and_then(|emergency_save_json| serde_json::from_str::<EmergencySave>(&emergency_save_json).ok());
+
+        Some(PendingCrash { crash_marker, emergency_save })
+    }
+
+    /// Removes the marker for a crash that the session survived, such as a trapped UI panic, once Squalr exits normally.
+    pub fn clear_crash_marker(crash_directory: &Path) {
+        let _ = fs::remove_file(Self::get_crash_marker_path(crash_directory));
+    }
+
+    pub fn clear_emergency_save(crash_directory: &Path) {
+        let _ = fs::remove_file(Self::get_emergency_save_path(crash_directory));
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_directory::ProjectItemTypeDirectory;
+use std::fs;
+use std::path::PathBuf;
+
+/// A project file that had unsaved changes at the time of a crash, along with the contents it would have been saved with.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EmergencyProjectFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// The unsaved parts of the opened project, captured ahead of time such that they can be written out by a panic hook without
+/// touching any locks. Restoring writes each file to where the project would have saved it.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EmergencySave {
+    pub project_name: String,
+    pub project_directory: Option<PathBuf>,
+    pub project_files: Vec<EmergencyProjectFile>,
+}
+
+impl EmergencySave {
+    /// Captures the project info and items with unsaved changes. Returns `None` if there is nothing unsaved to capture.
+    pub fn capture(project: &Project) -> Option<Self> {
+        let mut project_files = Vec::new();
+        let project_info = project.get_project_info();
+        let project_info_contents = match project_info.get_has_unsaved_changes() {
+            true => serde_json::to_string_pretty(project_info).ok(),
+            false => None,
+        };
+
+        if let Some(contents) = project_info_contents {
+            project_files.push(EmergencyProjectFile {
+                path: project_info.get_project_file_path().clone(),
+                contents,
+            });
+        }
+
+        for (project_item_ref, project_item) in project.get_project_items() {
+            // Directories have no serialized form, as they are recreated from the folder structure.
+            if !project_item.get_has_unsaved_changes()
+                || project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeDirectory::PROJECT_ITEM_TYPE_ID
+            {
+                continue;
+            }
+
+            if let Ok(contents) = serde_json::to_string_pretty(project_item) {
+                project_files.push(EmergencyProjectFile {
+                    path: project_item_ref.get_project_item_path().clone(),
+                    contents,
+                });
+            }
+        }
+
+        if project_files.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            project_name: project.get_name().to_string(),
+            project_directory: project_info.get_project_directory(),
+            project_files,
+        })
+    }
+
+    /// Writes every captured file back to its project path, overwriting what was last saved. Returns the number of files written.
+    pub fn restore_project_files(&self) -> Result<usize, String> {
+        for project_file in &self.project_files {
+            if let Some(parent_directory) = project_file.path.parent() {
+                fs::create_dir_all(parent_directory).map_err(|error| format!("Failed to create {:?}: {}", parent_directory, error))?;
+            }
+
+            fs::write(&project_file.path, &project_file.contents).map_err(|error| format!("Failed to write {:?}: {}", project_file.path, error))?;
+        }
+
+        Ok(self.project_files.len())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod crash_context;
+pub mod crash_recovery;
+pub mod emergency_save;
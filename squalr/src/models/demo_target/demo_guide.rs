@@ -0,0 +1,142 @@
+/// Steps are declared in the order they are done, such that earlier steps compare as less than later ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DemoGuideStep {
+    FirstScan,
+    ChangeValue,
+    NextScan,
+    Freeze,
+    Complete,
+}
+
+impl DemoGuideStep {
+    pub const ALL: [DemoGuideStep; 4] = [
+        DemoGuideStep::FirstScan,
+        DemoGuideStep::ChangeValue,
+        DemoGuideStep::NextScan,
+        DemoGuideStep::Freeze,
+    ];
+
+    pub fn get_title(&self) -> &'static str {
+        match self {
+            DemoGuideStep::FirstScan => "First scan",
+            DemoGuideStep::ChangeValue => "Change the value",
+            DemoGuideStep::NextScan => "Next scan",
+            DemoGuideStep::Freeze => "Freeze",
+            DemoGuideStep::Complete => "Done",
+        }
+    }
+}
+
+/// Tracks progress through the demo walkthrough: first scan, change the value, next scan, then freeze. Steps only move forward,
+/// each one completing when the user has done what it asks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DemoGuide {
+    step: DemoGuideStep,
+
+    /// The value held before the demo target was last asked to change it during the freeze step.
+    value_before_change: Option<i32>,
+
+    /// Whether the demo target has reported a different value since it was asked to change it during the freeze step.
+    has_value_moved: bool,
+}
+
+impl DemoGuide {
+    pub fn new() -> Self {
+        Self {
+            step: DemoGuideStep::FirstScan,
+            value_before_change: None,
+            has_value_moved: false,
+        }
+    }
+
+    pub fn get_step(&self) -> DemoGuideStep {
+        self.step
+    }
+
+    pub fn on_scan_completed(&mut self) {
+        self.step = match self.step {
+            DemoGuideStep::FirstScan => DemoGuideStep::ChangeValue,
+            DemoGuideStep::NextScan => DemoGuideStep::Freeze,
+            step => step,
+        };
+    }
+
+    pub fn on_value_change_requested(
+        &mut self,
+        current_value: Option<i32>,
+    ) {
+        match self.step {
+            DemoGuideStep::ChangeValue => self.step = DemoGuideStep::NextScan,
+            DemoGuideStep::Freeze => {
+                self.value_before_change = current_value;
+                self.has_value_moved = false;
+            }
+            _ => {}
+        }
+    }
+
+    /// A frozen value is briefly changed by the demo target, then written back by Squalr. Seeing the value return to what it was
+    /// before the change completes the freeze step.
+    pub fn on_value_reported(
+        &mut self,
+        value: i32,
+    ) {
+        if self.step != DemoGuideStep::Freeze {
+            return;
+        }
+
+        let Some(value_before_change) = self.value_before_change else {
+            return;
+        };
+
+        if value != value_before_change {
+            self.has_value_moved = true;
+        } else if self.has_value_moved {
+            self.step = DemoGuideStep::Complete;
+        }
+    }
+}
+
+impl Default for DemoGuide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DemoGuide, DemoGuideStep};
+
+    #[test]
+    fn steps_advance_in_order_and_freeze_completes_when_the_value_reverts() {
+        let mut demo_guide = DemoGuide::new();
+
+        // Changing the value before scanning does not skip ahead.
+        demo_guide.on_value_change_requested(Some(100));
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::FirstScan);
+
+        demo_guide.on_scan_completed();
+        demo_guide.on_value_change_requested(Some(100));
+        demo_guide.on_value_reported(101);
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::NextScan);
+
+        demo_guide.on_scan_completed();
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::Freeze);
+
+        // Without a freeze, the value stays changed.
+        demo_guide.on_value_change_requested(Some(101));
+        demo_guide.on_value_reported(102);
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::Freeze);
+
+        // With a freeze, the change is written back over.
+        demo_guide.on_value_change_requested(Some(102));
+        demo_guide.on_value_reported(102);
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::Freeze);
+        demo_guide.on_value_reported(103);
+        demo_guide.on_value_reported(102);
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::Complete);
+
+        demo_guide.on_scan_completed();
+        assert_eq!(demo_guide.get_step(), DemoGuideStep::Complete);
+    }
+}
@@ -0,0 +1,91 @@
+use std::io::{BufRead, Write};
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// A small process for trying Squalr without a game. Squalr launches a copy of itself with the launch argument, which holds a
+/// single integer for the user to find, change, and freeze. Squalr tells it to change the value over stdin, and it reports
+/// the value over stdout each time it changes, including when Squalr writes to it.
+pub struct DemoTarget {
+    /// Boxed such that the value lives on the heap at a stable address, as most game state does.
+    value: Box<AtomicI32>,
+    last_reported_value: Mutex<Option<i32>>,
+}
+
+impl DemoTarget {
+    pub const LAUNCH_ARGUMENT: &'static str = "--demo-target";
+    pub const INCREASE_COMMAND: &'static str = "increase";
+    const VALUE_REPORT_PREFIX: &'static str = "value ";
+
+    /// How often the value is checked for writes made by Squalr, such as a freeze.
+    const POLL_INTERVAL_MS: u64 = 50;
+
+    pub fn new(initial_value: i32) -> Self {
+        Self {
+            value: Box::new(AtomicI32::new(initial_value)),
+            last_reported_value: Mutex::new(None),
+        }
+    }
+
+    /// Runs the demo target until stdin closes, which happens when Squalr exits or closes the demo.
+    pub fn run() {
+        // Vary the starting value between launches, such that it is found by scanning rather than remembered.
+        let demo_target = Arc::new(Self::new(1000 + (std::process::id() % 9000) as i32));
+        let demo_target_clone = demo_target.clone();
+
+        demo_target.report_value();
+
+        thread::spawn(move || {
+            loop {
+                demo_target_clone.report_value();
+                thread::sleep(Duration::from_millis(Self::POLL_INTERVAL_MS));
+            }
+        });
+
+        for line in std::io::stdin().lock().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+
+            if line.trim() == Self::INCREASE_COMMAND {
+                demo_target.increase_value();
+            }
+        }
+    }
+
+    pub fn get_value(&self) -> i32 {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    pub fn increase_value(&self) {
+        self.value.fetch_add(1, Ordering::SeqCst);
+        self.report_value();
+    }
+
+    /// Parses a line written by a demo target, returning the value it reports, if any.
+    pub fn parse_value_report(line: &str) -> Option<i32> {
+        line.trim()
+            .strip_prefix(Self::VALUE_REPORT_PREFIX)?
+            .parse()
+            .ok()
+    }
+
+    /// Writes the current value to stdout, unless it is unchanged since it was last written.
+    fn report_value(&self) {
+        let Ok(mut last_reported_value) = self.last_reported_value.lock() else {
+            return;
+        };
+        let value = self.get_value();
+
+        if *last_reported_value == Some(value) {
+            return;
+        }
+
+        let mut stdout = std::io::stdout().lock();
+
+        if writeln!(stdout, "{}{}", Self::VALUE_REPORT_PREFIX, value).is_ok() && stdout.flush().is_ok() {
+            *last_reported_value = Some(value);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::demo_target::demo_target::DemoTarget;
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+
+/// A running demo target, launched by Squalr. The process is killed when this is dropped.
+pub struct DemoTargetProcess {
+    child: Mutex<Child>,
+    stdin: Mutex<ChildStdin>,
+    process_id: u32,
+}
+
+impl DemoTargetProcess {
+    /// Launches a copy of the running executable as a demo target.
+    pub fn create_command() -> std::io::Result<Command> {
+        let mut command = Command::new(std::env::current_exe()?);
+
+        command.arg(DemoTarget::LAUNCH_ARGUMENT);
+
+        Ok(command)
+    }
+
+    /// Spawns the given command, which must run a demo target. Each value the demo target reports is passed to the callback,
+    /// from a background thread.
+    pub fn spawn(
+        mut command: Command,
+        on_value_reported: impl Fn(i32) + Send + 'static,
+    ) -> std::io::Result<Self> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let (Some(stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+            let _ = child.kill();
+
+            return Err(std::io::Error::other("Demo target was started without piped stdio."));
+        };
+        let process_id = child.id();
+
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+
+                if let Some(value) = DemoTarget::parse_value_report(&line) {
+                    on_value_reported(value);
+                }
+            }
+        });
+
+        Ok(Self {
+            child: Mutex::new(child),
+            stdin: Mutex::new(stdin),
+            process_id,
+        })
+    }
+
+    pub fn get_process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    /// Tells the demo target to increase its value by one. The new value is reported back once it has changed.
+    pub fn increase_value(&self) -> std::io::Result<()> {
+        let mut stdin = self
+            .stdin
+            .lock()
+            .map_err(|error| std::io::Error::other(error.to_string()))?;
+
+        writeln!(stdin, "{}", DemoTarget::INCREASE_COMMAND)?;
+        stdin.flush()
+    }
+}
+
+impl Drop for DemoTargetProcess {
+    fn drop(&mut self) {
+        if let Ok(mut child) = self.child.lock() {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
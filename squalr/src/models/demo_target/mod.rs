@@ -0,0 +1,3 @@
+pub mod demo_guide;
+pub mod demo_target;
+pub mod demo_target_process;
@@ -0,0 +1,17 @@
+use crate::models::docking::hierarchy::dock_node::DockNode;
+
+/// A change to the docking layout requested by a view. Commands are queued and applied once per frame before layout,
+/// such that views never hold the docking manager's write lock mid-frame.
+#[derive(Clone, Debug)]
+pub enum DockingCommand {
+    /// Shows or hides a window. A window that is shown also becomes the active tab among its siblings.
+    ShowWindow { window_id: String, is_visible: bool },
+    /// Flips whether a window is shown.
+    ToggleWindow { window_id: String },
+    /// Makes a window the active tab among its siblings, without changing whether it is shown.
+    FocusWindow { window_id: String },
+    /// Sets or clears short text shown beside the title of a window, ie a count of new entries.
+    SetBadge { window_id: String, badge: Option<String> },
+    /// Replaces the whole layout, ie to reset to the default layout.
+    SetRoot { root_node: DockNode },
+}
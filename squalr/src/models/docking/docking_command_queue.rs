@@ -0,0 +1,168 @@
+use crate::models::docking::docking_command::DockingCommand;
+use crate::models::docking::docking_manager::DockingManager;
+use crossbeam_channel::{Receiver, Sender, unbounded};
+use std::sync::RwLock;
+
+/// A lock-free queue of docking commands. Any thread may push commands, and the dock root drains them into the docking
+/// manager once per frame, in the order they were pushed.
+pub struct DockingCommandQueue {
+    sender: Sender<DockingCommand>,
+    receiver: Receiver<DockingCommand>,
+}
+
+impl DockingCommandQueue {
+    pub fn new() -> Self {
+        let (sender, receiver) = unbounded();
+
+        Self { sender, receiver }
+    }
+
+    pub fn push(
+        &self,
+        docking_command: DockingCommand,
+    ) {
+        // The queue owns its receiver, so sending can only fail once the queue itself is gone.
+        let _ = self.sender.send(docking_command);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.receiver.is_empty()
+    }
+
+    /// Takes every queued command, passing each to the callback in order. Commands pushed while draining are taken as well.
+    pub fn drain(
+        &self,
+        mut callback: impl FnMut(DockingCommand),
+    ) -> usize {
+        let mut drained_count = 0;
+
+        while let Ok(docking_command) = self.receiver.try_recv() {
+            callback(docking_command);
+            drained_count += 1;
+        }
+
+        drained_count
+    }
+
+    /// Applies every queued command to the docking manager. Returns whether any command was applied.
+    pub fn apply_pending(
+        &self,
+        docking_manager: &mut DockingManager,
+    ) -> bool {
+        self.drain(|docking_command| docking_manager.apply_command(docking_command)) > 0
+    }
+
+    /// Applies a command right away rather than on the next frame, for code that reads the resulting layout immediately.
+    /// Queued commands are applied first, so that commands still take effect in the order they were issued.
+    pub fn apply_now(
+        &self,
+        docking_manager: &RwLock<DockingManager>,
+        docking_command: DockingCommand,
+    ) -> bool {
+        match docking_manager.write() {
+            Ok(mut docking_manager) => {
+                self.apply_pending(&mut docking_manager);
+                docking_manager.apply_command(docking_command);
+
+                true
+            }
+            Err(error) => {
+                log::error!("Failed to acquire docking manager to apply a docking command: {}", error);
+
+                false
+            }
+        }
+    }
+}
+
+impl Default for DockingCommandQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DockingCommandQueue;
+    use crate::models::docking::docking_command::DockingCommand;
+    use crate::models::docking::docking_manager::DockingManager;
+    use crate::models::docking::settings::dockable_window_settings::DockSettingsConfig;
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    #[test]
+    fn commands_flooded_from_many_threads_apply_once_in_order() {
+        const THREAD_COUNT: usize = 8;
+        const COMMANDS_PER_THREAD: usize = 500;
+
+        let docking_command_queue = Arc::new(DockingCommandQueue::new());
+        let barrier = Arc::new(Barrier::new(THREAD_COUNT + 1));
+        let producers = (0..THREAD_COUNT)
+            .map(|thread_index| {
+                let docking_command_queue = docking_command_queue.clone();
+                let barrier = barrier.clone();
+
+                thread::spawn(move || {
+                    barrier.wait();
+
+                    for command_index in 0..COMMANDS_PER_THREAD {
+                        docking_command_queue.push(DockingCommand::SetBadge {
+                            window_id: thread_index.to_string(),
+                            badge: Some(command_index.to_string()),
+                        });
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Drain repeatedly while the producers are still pushing, as the dock root would across several frames.
+        barrier.wait();
+
+        let mut next_command_indices = vec![0; THREAD_COUNT];
+        let mut apply_command = |docking_command: DockingCommand| match docking_command {
+            DockingCommand::SetBadge { window_id, badge: Some(badge) } => {
+                let thread_index = window_id.parse::<usize>().unwrap();
+
+                assert_eq!(badge.parse::<usize>().unwrap(), next_command_indices[thread_index]);
+                next_command_indices[thread_index] += 1;
+            }
+            other => panic!("Unexpected docking command: {:?}", other),
+        };
+        let mut applied_count = 0;
+
+        while producers.iter().any(|producer| !producer.is_finished()) {
+            applied_count += docking_command_queue.drain(&mut apply_command);
+        }
+
+        for producer in producers {
+            producer.join().unwrap();
+        }
+
+        applied_count += docking_command_queue.drain(&mut apply_command);
+
+        assert_eq!(applied_count, THREAD_COUNT * COMMANDS_PER_THREAD);
+        assert_eq!(next_command_indices, vec![COMMANDS_PER_THREAD; THREAD_COUNT]);
+        assert!(docking_command_queue.is_empty());
+    }
+
+    #[test]
+    fn applying_now_applies_queued_commands_first() {
+        let docking_command_queue = DockingCommandQueue::new();
+        let docking_manager = std::sync::RwLock::new(DockingManager::new(DockSettingsConfig::get_default_layout()));
+
+        docking_command_queue.push(DockingCommand::SetBadge {
+            window_id: "window".to_string(),
+            badge: Some("queued".to_string()),
+        });
+        docking_command_queue.apply_now(
+            &docking_manager,
+            DockingCommand::SetBadge {
+                window_id: "window".to_string(),
+                badge: Some("now".to_string()),
+            },
+        );
+
+        assert!(docking_command_queue.is_empty());
+        assert_eq!(docking_manager.read().unwrap().get_window_badge("window"), Some("now"));
+    }
+}
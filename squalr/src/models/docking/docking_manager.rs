@@ -1,11 +1,15 @@
+use crate::models::docking::docking_command::DockingCommand;
 use crate::models::docking::hierarchy::dock_layout::DockLayout;
 use crate::models::docking::hierarchy::dock_node::DockNode;
 use crate::models::docking::hierarchy::types::dock_reparent_direction::DockReparentDirection;
 use crate::models::docking::hierarchy::types::dock_splitter_drag_direction::DockSplitterDragDirection;
+use std::collections::HashMap;
 
 /// Handles a `DockLayout`, which contains a root `DockNode` and manages its layout.
 pub struct DockingManager {
     pub main_window_layout: DockLayout,
+    /// Short text shown beside window titles, by window id. These are not saved with the layout.
+    window_badges: HashMap<String, String>,
 }
 
 /// Contains various helper functions to manage an underlying docking hierarchy and its layout.
@@ -13,6 +17,7 @@ impl DockingManager {
     pub fn new(root_node: DockNode) -> Self {
         Self {
             main_window_layout: DockLayout::new(root_node),
+            window_badges: HashMap::new(),
         }
     }
 
@@ -114,6 +119,55 @@ impl DockingManager {
         false
     }
 
+    /// Sets or clears the short text shown beside the title of a window.
+    pub fn set_window_badge(
+        &mut self,
+        window_id: &str,
+        badge: Option<String>,
+    ) {
+        match badge {
+            Some(badge) => {
+                self.window_badges.insert(window_id.to_string(), badge);
+            }
+            None => {
+                self.window_badges.remove(window_id);
+            }
+        }
+    }
+
+    pub fn get_window_badge(
+        &self,
+        window_id: &str,
+    ) -> Option<&str> {
+        self.window_badges.get(window_id).map(String::as_str)
+    }
+
+    /// Applies a docking command queued by a view.
+    pub fn apply_command(
+        &mut self,
+        docking_command: DockingCommand,
+    ) {
+        match docking_command {
+            DockingCommand::ShowWindow { window_id, is_visible } => {
+                self.set_window_visible(&window_id, is_visible);
+            }
+            DockingCommand::ToggleWindow { window_id } => {
+                if let Some(is_visible) = self.get_node_by_id(&window_id).map(|node| node.is_visible()) {
+                    self.set_window_visible(&window_id, !is_visible);
+                }
+            }
+            DockingCommand::FocusWindow { window_id } => {
+                self.select_tab_by_window_id(&window_id);
+            }
+            DockingCommand::SetBadge { window_id, badge } => {
+                self.set_window_badge(&window_id, badge);
+            }
+            DockingCommand::SetRoot { root_node } => {
+                self.set_root(root_node);
+            }
+        }
+    }
+
     /// Given a `window_id`, this method determines which sibling tab is active, if any.
     pub fn get_active_tab(
         &self,
@@ -1,4 +1,6 @@
 pub mod builder;
+pub mod docking_command;
+pub mod docking_command_queue;
 pub mod docking_manager;
 pub mod hierarchy;
 pub mod settings;
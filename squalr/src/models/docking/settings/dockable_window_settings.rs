@@ -6,6 +6,8 @@ use crate::views::disassembler::disassembler_view::DisassemblerView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+use crate::views::memory_accessors::memory_accessors_view::MemoryAccessorsView;
+#[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::output::output_view::OutputView;
@@ -18,6 +20,8 @@ use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
 #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+#[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+use crate::views::watch_expressions::watch_expressions_view::WatchExpressionsView;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string_pretty;
 use std::fs;
@@ -66,6 +70,8 @@ impl DockSettingsConfig {
                     .push_tab(DockBuilder::window(DisassemblerView::WINDOW_ID))
                     .push_tab(DockBuilder::window(MemoryViewerView::WINDOW_ID))
                     .push_tab(DockBuilder::window(PointerScannerView::WINDOW_ID))
+                    .push_tab(DockBuilder::window(WatchExpressionsView::WINDOW_ID))
+                    .push_tab(DockBuilder::window(MemoryAccessorsView::WINDOW_ID))
                     .push_tab(DockBuilder::window(SettingsView::WINDOW_ID)),
             )
             .build();
@@ -108,6 +114,8 @@ impl DockSettingsConfig {
             SettingsView::WINDOW_ID,
             DisassemblerView::WINDOW_ID,
             MemoryViewerView::WINDOW_ID,
+            WatchExpressionsView::WINDOW_ID,
+            MemoryAccessorsView::WINDOW_ID,
         ];
 
         // Prefer inserting into the scanner/settings tab group so features show up where users expect (right-side tools).
@@ -1,4 +1,13 @@
+pub mod address_resolution;
+pub mod appearance;
 pub mod audio_player;
+pub mod command_palette;
+pub mod crash_recovery;
+pub mod demo_target;
 pub mod docking;
+pub mod refresh_scheduler;
 pub mod tab_menu;
+pub mod taskbar_progress;
 pub mod toolbar;
+pub mod update_check;
+pub mod workspace;
@@ -0,0 +1,5 @@
+pub mod refresh_batch;
+pub mod refresh_job;
+pub mod refresh_job_handle;
+pub mod refresh_job_queue;
+pub mod refresh_scheduler;
@@ -0,0 +1,271 @@
+use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use squalr_engine_api::commands::memory::read::memory_read_response::MemoryReadResponse;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_request::ScanResultsRefreshRequest;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+type ScanResultsRefreshCallback = Box<dyn FnOnce(Vec<ScanResult>, u64) + Send>;
+type MemoryReadCallback = Box<dyn FnOnce(MemoryReadResponse) + Send>;
+
+/// The engine reads queued by the refresh jobs that fell due in the same tick. Reads of the same kind are merged into a single
+/// request, and each job is handed back only the part of the response it asked for. Memory reads cover arbitrary ranges, so each
+/// is sent as its own request.
+pub struct RefreshBatch {
+    scan_results_refreshes: Vec<(Vec<ScanResultRef>, ScanResultsRefreshCallback)>,
+    memory_reads: Vec<(MemoryReadRequest, MemoryReadCallback)>,
+}
+
+impl RefreshBatch {
+    pub fn new() -> Self {
+        Self {
+            scan_results_refreshes: Vec::new(),
+            memory_reads: Vec::new(),
+        }
+    }
+
+    /// Queues reading the current values of the given scan results. The callback receives the refreshed results it asked for,
+    /// along with the scan generation they were read from.
+    pub fn refresh_scan_results(
+        &mut self,
+        scan_result_refs: Vec<ScanResultRef>,
+        callback: impl FnOnce(Vec<ScanResult>, u64) + Send + 'static,
+    ) {
+        self.scan_results_refreshes
+            .push((scan_result_refs, Box::new(callback)));
+    }
+
+    /// Queues reading a range of memory. The callback receives the response as is, including whether the read succeeded.
+    pub fn read_memory(
+        &mut self,
+        memory_read_request: MemoryReadRequest,
+        callback: impl FnOnce(MemoryReadResponse) + Send + 'static,
+    ) {
+        self.memory_reads
+            .push((memory_read_request, Box::new(callback)));
+    }
+
+    /// Sends the queued reads, calling back once every response has been handed out. Completes right away if nothing was queued.
+    pub fn send(
+        mut self,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        on_complete: impl FnOnce() + Send + 'static,
+    ) {
+        let request_count = self.memory_reads.len() + usize::from(!self.scan_results_refreshes.is_empty());
+
+        if request_count == 0 {
+            on_complete();
+            return;
+        }
+
+        let pending_completion = Arc::new(Mutex::new((request_count, Some(on_complete))));
+
+        for (memory_read_request, callback) in std::mem::take(&mut self.memory_reads) {
+            // Requests may call back more than once in principle, so the callback is taken by whichever call comes first.
+            let pending_callback = Arc::new(Mutex::new(Some(callback)));
+            let pending_completion = pending_completion.clone();
+
+            memory_read_request.send(engine_unprivileged_state, move |memory_read_response| {
+                let pending_callback = match pending_callback.lock() {
+                    Ok(mut pending_callback) => pending_callback.take(),
+                    Err(error) => {
+                        log::error!("Failed to acquire pending memory read callback: {}", error);
+                        return;
+                    }
+                };
+
+                if let Some(callback) = pending_callback {
+                    callback(memory_read_response);
+                    Self::complete_request(&pending_completion);
+                }
+            });
+        }
+
+        if self.scan_results_refreshes.is_empty() {
+            return;
+        }
+
+        let scan_results_refresh_request = ScanResultsRefreshRequest {
+            scan_result_refs: self.get_merged_scan_result_refs(),
+        };
+        // As above, the batch is taken by whichever call comes first.
+        let pending_batch = Arc::new(Mutex::new(Some(self)));
+
+        scan_results_refresh_request.send(engine_unprivileged_state, move |scan_results_refresh_response| {
+            let pending_batch = match pending_batch.lock() {
+                Ok(mut pending_batch) => pending_batch.take(),
+                Err(error) => {
+                    log::error!("Failed to acquire pending refresh batch: {}", error);
+                    return;
+                }
+            };
+
+            if let Some(refresh_batch) = pending_batch {
+                refresh_batch.dispatch_scan_results(scan_results_refresh_response.scan_results, scan_results_refresh_response.scan_generation);
+                Self::complete_request(&pending_completion);
+            }
+        });
+    }
+
+    /// Counts down the requests still awaiting a response, completing the batch once the last of them has been handed out.
+    fn complete_request<F>(pending_completion: &Mutex<(usize, Option<F>)>)
+    where
+        F: FnOnce(),
+    {
+        let on_complete = match pending_completion.lock() {
+            Ok(mut pending_completion) => {
+                pending_completion.0 = pending_completion.0.saturating_sub(1);
+
+                if pending_completion.0 == 0 { pending_completion.1.take() } else { None }
+            }
+            Err(error) => {
+                log::error!("Failed to acquire pending refresh batch completion: {}", error);
+                None
+            }
+        };
+
+        if let Some(on_complete) = on_complete {
+            on_complete();
+        }
+    }
+
+    /// Gets every queued scan result, in the order first asked for, with results asked for by several jobs only read once.
+    fn get_merged_scan_result_refs(&self) -> Vec<ScanResultRef> {
+        let mut seen_global_indices = HashSet::new();
+
+        self.scan_results_refreshes
+            .iter()
+            .flat_map(|(scan_result_refs, _callback)| scan_result_refs)
+            .filter(|scan_result_ref| seen_global_indices.insert(scan_result_ref.get_scan_result_global_index()))
+            .cloned()
+            .collect()
+    }
+
+    /// Hands each job the refreshed results it asked for, in the order it asked for them.
+    fn dispatch_scan_results(
+        self,
+        scan_results: Vec<ScanResult>,
+        scan_generation: u64,
+    ) {
+        for (scan_result_refs, callback) in self.scan_results_refreshes {
+            let requested_scan_results = scan_result_refs
+                .iter()
+                .filter_map(|scan_result_ref| {
+                    scan_results
+                        .iter()
+                        .find(|scan_result| {
+                            scan_result
+                                .get_base_result()
+                                .get_scan_result_ref()
+                                .get_scan_result_global_index()
+                                == scan_result_ref.get_scan_result_global_index()
+                        })
+                        .cloned()
+                })
+                .collect();
+
+            callback(requested_scan_results, scan_generation);
+        }
+    }
+}
+
+impl Default for RefreshBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshBatch;
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+    use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    fn make_scan_result(global_index: u64) -> ScanResult {
+        let valued = ScanResultValued::new(
+            0x1000 + global_index * 4,
+            DataTypeRef::new(DataTypeU32::get_data_type_id()),
+            String::new(),
+            Some(DataTypeU32::get_value_from_primitive(global_index as u32)),
+            vec![],
+            None,
+            vec![],
+            ScanResultRef::new(global_index),
+        );
+
+        ScanResult::new(valued, String::new(), 0, None, vec![], false)
+    }
+
+    fn get_global_indices(scan_results: &[ScanResult]) -> Vec<u64> {
+        scan_results
+            .iter()
+            .map(|scan_result| {
+                scan_result
+                    .get_base_result()
+                    .get_scan_result_ref()
+                    .get_scan_result_global_index()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn jobs_due_in_the_same_tick_share_one_read_and_get_back_their_own_results() {
+        let results_page_indices = Arc::new(Mutex::new(Vec::new()));
+        let graph_indices = Arc::new(Mutex::new(Vec::new()));
+        let results_page_indices_clone = results_page_indices.clone();
+        let graph_indices_clone = graph_indices.clone();
+        let mut refresh_batch = RefreshBatch::new();
+
+        refresh_batch.refresh_scan_results(
+            vec![
+                ScanResultRef::new(0),
+                ScanResultRef::new(1),
+                ScanResultRef::new(2),
+            ],
+            move |scan_results, _| {
+                *results_page_indices_clone.lock().unwrap() = get_global_indices(&scan_results);
+            },
+        );
+        refresh_batch.refresh_scan_results(vec![ScanResultRef::new(7), ScanResultRef::new(1)], move |scan_results, scan_generation| {
+            assert_eq!(scan_generation, 3);
+            *graph_indices_clone.lock().unwrap() = get_global_indices(&scan_results);
+        });
+
+        let merged_global_indices: Vec<u64> = refresh_batch
+            .get_merged_scan_result_refs()
+            .iter()
+            .map(|scan_result_ref| scan_result_ref.get_scan_result_global_index())
+            .collect();
+
+        assert_eq!(merged_global_indices, vec![0, 1, 2, 7]);
+
+        // The engine drops results that no longer exist, ie result 2 here.
+        refresh_batch.dispatch_scan_results(vec![make_scan_result(0), make_scan_result(1), make_scan_result(7)], 3);
+
+        assert_eq!(*results_page_indices.lock().unwrap(), vec![0, 1]);
+        assert_eq!(*graph_indices.lock().unwrap(), vec![7, 1]);
+    }
+
+    #[test]
+    fn batches_complete_once_the_last_request_calls_back() {
+        let is_complete = Arc::new(AtomicBool::new(false));
+        let is_complete_clone = is_complete.clone();
+        let pending_completion = Mutex::new((2, Some(move || is_complete_clone.store(true, Ordering::SeqCst))));
+
+        RefreshBatch::complete_request(&pending_completion);
+
+        assert!(!is_complete.load(Ordering::SeqCst));
+
+        RefreshBatch::complete_request(&pending_completion);
+
+        assert!(is_complete.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::models::refresh_scheduler::refresh_batch::RefreshBatch;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Runs one refresh of a view. Engine reads are queued on the batch rather than sent directly, such that they are sent along with
+/// the reads of every other job due in the same tick.
+pub type RefreshJobCallback = Arc<dyn Fn(&mut RefreshBatch) + Send + Sync>;
+
+/// A periodic refresh registered by a view.
+pub struct RefreshJob {
+    pub job_id: u64,
+    /// The name shown in the refresh scheduler panel, ie `Scan results`.
+    pub name: String,
+    pub interval: Duration,
+    pub is_enabled: bool,
+    pub next_due_time: Instant,
+    pub callback: RefreshJobCallback,
+}
+
+/// The adjustable settings of a refresh job, as listed in the refresh scheduler panel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshJobSummary {
+    pub job_id: u64,
+    pub name: String,
+    pub interval: Duration,
+    pub is_enabled: bool,
+}
@@ -0,0 +1,74 @@
+use crate::models::refresh_scheduler::refresh_job_queue::RefreshJobQueue;
+use std::sync::{Arc, Mutex, Weak};
+
+/// Keeps a refresh job registered until the last clone of this handle is dropped. Views store these alongside their state, such
+/// that jobs owned by views that are torn down do not keep reading on their behalf.
+#[derive(Clone)]
+#[must_use = "dropping the handle unregisters the refresh job"]
+pub struct RefreshJobHandle {
+    /// Dropping the last clone removes the job.
+    refresh_job_handle_inner: Arc<RefreshJobHandleInner>,
+}
+
+impl RefreshJobHandle {
+    pub fn new(
+        refresh_job_queue: &Arc<Mutex<RefreshJobQueue>>,
+        job_id: u64,
+    ) -> Self {
+        Self {
+            refresh_job_handle_inner: Arc::new(RefreshJobHandleInner {
+                refresh_job_queue: Arc::downgrade(refresh_job_queue),
+                job_id,
+            }),
+        }
+    }
+
+    /// Gets the id of the job, through which the owning view adjusts it on the scheduler.
+    pub fn get_job_id(&self) -> u64 {
+        self.refresh_job_handle_inner.job_id
+    }
+}
+
+struct RefreshJobHandleInner {
+    refresh_job_queue: Weak<Mutex<RefreshJobQueue>>,
+    job_id: u64,
+}
+
+impl Drop for RefreshJobHandleInner {
+    fn drop(&mut self) {
+        if let Some(refresh_job_queue) = self.refresh_job_queue.upgrade() {
+            match refresh_job_queue.lock() {
+                Ok(mut refresh_job_queue) => refresh_job_queue.remove_job(self.job_id),
+                Err(error) => log::error!("Error removing refresh job: {}", error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshJobHandle;
+    use crate::models::refresh_scheduler::refresh_job_queue::RefreshJobQueue;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn dropping_the_last_handle_removes_the_job() {
+        let refresh_job_queue = Arc::new(Mutex::new(RefreshJobQueue::new()));
+        let job_id = refresh_job_queue
+            .lock()
+            .unwrap()
+            .add_job("Graphs", Duration::from_millis(100), true, Arc::new(|_refresh_batch| {}), Instant::now());
+        let refresh_job_handle = RefreshJobHandle::new(&refresh_job_queue, job_id);
+        let refresh_job_handle_clone = refresh_job_handle.clone();
+
+        drop(refresh_job_handle);
+
+        // A remaining clone keeps the job registered.
+        assert_eq!(refresh_job_queue.lock().unwrap().get_job_summaries().len(), 1);
+
+        drop(refresh_job_handle_clone);
+
+        assert!(refresh_job_queue.lock().unwrap().get_job_summaries().is_empty());
+    }
+}
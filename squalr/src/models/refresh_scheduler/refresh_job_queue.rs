@@ -0,0 +1,239 @@
+use crate::models::refresh_scheduler::refresh_job::{RefreshJob, RefreshJobCallback, RefreshJobSummary};
+use std::time::{Duration, Instant};
+
+/// The registered refresh jobs, and when each of them next falls due. Scheduling is kept apart from the scheduler thread, such
+/// that it can be driven by any clock.
+pub struct RefreshJobQueue {
+    jobs: Vec<RefreshJob>,
+    next_job_id: u64,
+    is_paused: bool,
+}
+
+impl RefreshJobQueue {
+    /// Intervals are clamped to at least this long, such that a job can never keep the scheduler spinning.
+    pub const MINIMUM_INTERVAL: Duration = Duration::from_millis(16);
+
+    pub fn new() -> Self {
+        Self {
+            jobs: Vec::new(),
+            next_job_id: 0,
+            is_paused: false,
+        }
+    }
+
+    /// Adds a job, which first falls due right away. Returns the id of the job.
+    pub fn add_job(
+        &mut self,
+        name: &str,
+        interval: Duration,
+        is_enabled: bool,
+        callback: RefreshJobCallback,
+        now: Instant,
+    ) -> u64 {
+        let job_id = self.next_job_id;
+
+        self.next_job_id += 1;
+        self.jobs.push(RefreshJob {
+            job_id,
+            name: name.to_string(),
+            interval: interval.max(Self::MINIMUM_INTERVAL),
+            is_enabled,
+            next_due_time: now,
+            callback,
+        });
+
+        job_id
+    }
+
+    pub fn remove_job(
+        &mut self,
+        job_id: u64,
+    ) {
+        self.jobs.retain(|job| job.job_id != job_id);
+    }
+
+    pub fn set_job_enabled(
+        &mut self,
+        job_id: u64,
+        is_enabled: bool,
+    ) {
+        if let Some(job) = self.jobs.iter_mut().find(|job| job.job_id == job_id) {
+            job.is_enabled = is_enabled;
+        }
+    }
+
+    /// Changes how often a job runs. The job next falls due one new interval from now, or sooner if it was already due sooner.
+    pub fn set_job_interval(
+        &mut self,
+        job_id: u64,
+        interval: Duration,
+        now: Instant,
+    ) {
+        if let Some(job) = self.jobs.iter_mut().find(|job| job.job_id == job_id) {
+            job.interval = interval.max(Self::MINIMUM_INTERVAL);
+            job.next_due_time = job.next_due_time.min(now + job.interval);
+        }
+    }
+
+    pub fn set_paused(
+        &mut self,
+        is_paused: bool,
+    ) {
+        self.is_paused = is_paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Takes the callbacks of the enabled jobs that have fallen due, scheduling each job to run again one interval after it was due.
+    /// A job that fell behind by a whole interval, ie while paused, runs once and then resumes from now, rather than catching up.
+    pub fn take_due_callbacks(
+        &mut self,
+        now: Instant,
+    ) -> Vec<RefreshJobCallback> {
+        if self.is_paused {
+            return Vec::new();
+        }
+
+        self.jobs
+            .iter_mut()
+            .filter(|job| job.is_enabled && job.next_due_time <= now)
+            .map(|job| {
+                job.next_due_time += job.interval;
+
+                if job.next_due_time <= now {
+                    job.next_due_time = now + job.interval;
+                }
+
+                job.callback.clone()
+            })
+            .collect()
+    }
+
+    /// Gets when the next enabled job falls due, or none if nothing runs until the queue changes.
+    pub fn get_next_due_time(&self) -> Option<Instant> {
+        if self.is_paused {
+            return None;
+        }
+
+        self.jobs
+            .iter()
+            .filter(|job| job.is_enabled)
+            .map(|job| job.next_due_time)
+            .min()
+    }
+
+    pub fn get_job_summaries(&self) -> Vec<RefreshJobSummary> {
+        self.jobs
+            .iter()
+            .map(|job| RefreshJobSummary {
+                job_id: job.job_id,
+                name: job.name.clone(),
+                interval: job.interval,
+                is_enabled: job.is_enabled,
+            })
+            .collect()
+    }
+}
+
+impl Default for RefreshJobQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshJobQueue;
+    use crate::models::refresh_scheduler::refresh_job::RefreshJobCallback;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    fn no_op_callback() -> RefreshJobCallback {
+        Arc::new(|_refresh_batch| {})
+    }
+
+    #[test]
+    fn jobs_fall_due_once_per_interval_without_drifting() {
+        let start_time = Instant::now();
+        let interval = Duration::from_millis(100);
+        let mut refresh_job_queue = RefreshJobQueue::new();
+
+        refresh_job_queue.add_job("Graphs", interval, true, no_op_callback(), start_time);
+
+        assert_eq!(refresh_job_queue.take_due_callbacks(start_time).len(), 1);
+        assert!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_millis(99))
+                .is_empty()
+        );
+
+        // Running late does not push back the next run, such that the cadence holds over many ticks.
+        assert_eq!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_millis(130))
+                .len(),
+            1
+        );
+        assert_eq!(refresh_job_queue.get_next_due_time(), Some(start_time + Duration::from_millis(200)));
+
+        // Falling several intervals behind runs the job once, then resumes a full interval later.
+        let late_time = start_time + Duration::from_millis(550);
+
+        assert_eq!(refresh_job_queue.take_due_callbacks(late_time).len(), 1);
+        assert!(refresh_job_queue.take_due_callbacks(late_time).is_empty());
+        assert_eq!(refresh_job_queue.get_next_due_time(), Some(late_time + interval));
+
+        // Intervals are clamped, such that a zero interval cannot spin the scheduler.
+        let job_id = refresh_job_queue.add_job("Results", Duration::ZERO, true, no_op_callback(), start_time);
+
+        refresh_job_queue.set_job_interval(job_id, Duration::ZERO, start_time);
+
+        assert_eq!(refresh_job_queue.get_job_summaries()[1].interval, RefreshJobQueue::MINIMUM_INTERVAL);
+    }
+
+    #[test]
+    fn paused_and_disabled_jobs_do_not_run_and_resume_once() {
+        let start_time = Instant::now();
+        let mut refresh_job_queue = RefreshJobQueue::new();
+        let graphs_job_id = refresh_job_queue.add_job("Graphs", Duration::from_millis(100), true, no_op_callback(), start_time);
+        let results_job_id = refresh_job_queue.add_job("Results", Duration::from_millis(100), false, no_op_callback(), start_time);
+
+        refresh_job_queue.set_paused(true);
+
+        assert!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_secs(5))
+                .is_empty()
+        );
+        assert_eq!(refresh_job_queue.get_next_due_time(), None);
+
+        refresh_job_queue.set_paused(false);
+
+        // Only the enabled job runs after resuming, and only once for everything missed while paused.
+        assert_eq!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_secs(5))
+                .len(),
+            1
+        );
+        assert!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_secs(5))
+                .is_empty()
+        );
+
+        refresh_job_queue.set_job_enabled(results_job_id, true);
+        refresh_job_queue.remove_job(graphs_job_id);
+
+        assert_eq!(
+            refresh_job_queue
+                .take_due_callbacks(start_time + Duration::from_secs(5))
+                .len(),
+            1
+        );
+        assert_eq!(refresh_job_queue.get_job_summaries().len(), 1);
+        assert_eq!(refresh_job_queue.get_job_summaries()[0].job_id, results_job_id);
+    }
+}
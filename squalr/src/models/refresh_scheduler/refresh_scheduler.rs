@@ -0,0 +1,246 @@
+use crate::models::refresh_scheduler::refresh_batch::RefreshBatch;
+use crate::models::refresh_scheduler::refresh_job::RefreshJobSummary;
+use crate::models::refresh_scheduler::refresh_job_handle::RefreshJobHandle;
+use crate::models::refresh_scheduler::refresh_job_queue::RefreshJobQueue;
+use eframe::egui::Context;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Runs the periodic refreshes of every view on a single thread, such that views read memory at their own cadence without each
+/// spawning a polling thread. Reads from jobs that fall due together are sent as one batch, and every read can be paused at once.
+pub struct RefreshScheduler {
+    refresh_job_queue: Arc<Mutex<RefreshJobQueue>>,
+    /// Wakes the scheduler thread when the queue changes, such that a new or resumed job does not wait out the previous sleep.
+    wake_signal: Arc<Condvar>,
+    is_shut_down: Arc<AtomicBool>,
+    worker_thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl RefreshScheduler {
+    /// Starts the scheduler thread. Batches are sent through the engine, and a repaint is requested once their results are in.
+    pub fn new(
+        context: Context,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) -> Self {
+        Self::start(move |refresh_batch| {
+            let context = context.clone();
+
+            refresh_batch.send(&engine_unprivileged_state, move || context.request_repaint());
+        })
+    }
+
+    /// Registers a job that runs its callback once per interval while enabled. The job first runs right away, and is removed once
+    /// every clone of the returned handle is dropped.
+    pub fn register_job(
+        &self,
+        name: &str,
+        interval: Duration,
+        is_enabled: bool,
+        callback: impl Fn(&mut RefreshBatch) + Send + Sync + 'static,
+    ) -> RefreshJobHandle {
+        let job_id = match self.refresh_job_queue.lock() {
+            Ok(mut refresh_job_queue) => refresh_job_queue.add_job(name, interval, is_enabled, Arc::new(callback), Instant::now()),
+            Err(error) => {
+                log::error!("Error registering refresh job: {}", error);
+                u64::MAX
+            }
+        };
+
+        self.wake_signal.notify_all();
+
+        RefreshJobHandle::new(&self.refresh_job_queue, job_id)
+    }
+
+    /// Pauses or resumes every job. Jobs that fell due while paused run once on resuming.
+    pub fn set_paused(
+        &self,
+        is_paused: bool,
+    ) {
+        self.update_queue(|refresh_job_queue| refresh_job_queue.set_paused(is_paused));
+    }
+
+    pub fn is_paused(&self) -> bool {
+        match self.refresh_job_queue.lock() {
+            Ok(refresh_job_queue) => refresh_job_queue.is_paused(),
+            Err(_error) => false,
+        }
+    }
+
+    pub fn set_job_enabled(
+        &self,
+        job_id: u64,
+        is_enabled: bool,
+    ) {
+        self.update_queue(|refresh_job_queue| refresh_job_queue.set_job_enabled(job_id, is_enabled));
+    }
+
+    pub fn set_job_interval(
+        &self,
+        job_id: u64,
+        interval: Duration,
+    ) {
+        self.update_queue(|refresh_job_queue| refresh_job_queue.set_job_interval(job_id, interval, Instant::now()));
+    }
+
+    pub fn get_job_summaries(&self) -> Vec<RefreshJobSummary> {
+        match self.refresh_job_queue.lock() {
+            Ok(refresh_job_queue) => refresh_job_queue.get_job_summaries(),
+            Err(_error) => Vec::new(),
+        }
+    }
+
+    /// Stops the scheduler thread and waits for it to finish its current tick. Batches already sent may still complete afterwards.
+    pub fn shutdown(&self) {
+        self.is_shut_down.store(true, Ordering::SeqCst);
+        self.wake_signal.notify_all();
+
+        let worker_thread = match self.worker_thread.lock() {
+            Ok(mut worker_thread) => worker_thread.take(),
+            Err(_error) => None,
+        };
+
+        if let Some(worker_thread) = worker_thread {
+            // A job that shuts down the scheduler from its own callback cannot wait for itself.
+            if worker_thread.thread().id() != thread::current().id() {
+                let _ = worker_thread.join();
+            }
+        }
+    }
+
+    fn start(dispatch_batch: impl Fn(RefreshBatch) + Send + 'static) -> Self {
+        let refresh_job_queue = Arc::new(Mutex::new(RefreshJobQueue::new()));
+        let wake_signal = Arc::new(Condvar::new());
+        let is_shut_down = Arc::new(AtomicBool::new(false));
+        let refresh_job_queue_clone = refresh_job_queue.clone();
+        let wake_signal_clone = wake_signal.clone();
+        let is_shut_down_clone = is_shut_down.clone();
+        let worker_thread = thread::spawn(move || {
+            Self::run_worker(&refresh_job_queue_clone, &wake_signal_clone, &is_shut_down_clone, dispatch_batch);
+        });
+
+        Self {
+            refresh_job_queue,
+            wake_signal,
+            is_shut_down,
+            worker_thread: Mutex::new(Some(worker_thread)),
+        }
+    }
+
+    fn run_worker(
+        refresh_job_queue: &Mutex<RefreshJobQueue>,
+        wake_signal: &Condvar,
+        is_shut_down: &AtomicBool,
+        dispatch_batch: impl Fn(RefreshBatch),
+    ) {
+        loop {
+            let due_callbacks = {
+                let mut refresh_job_queue_guard = match refresh_job_queue.lock() {
+                    Ok(refresh_job_queue_guard) => refresh_job_queue_guard,
+                    Err(error) => {
+                        log::error!("Error running refresh scheduler: {}", error);
+                        return;
+                    }
+                };
+
+                loop {
+                    if is_shut_down.load(Ordering::SeqCst) {
+                        return;
+                    }
+
+                    let now = Instant::now();
+                    let due_callbacks = refresh_job_queue_guard.take_due_callbacks(now);
+
+                    if !due_callbacks.is_empty() {
+                        break due_callbacks;
+                    }
+
+                    refresh_job_queue_guard = match refresh_job_queue_guard.get_next_due_time() {
+                        Some(next_due_time) => match wake_signal.wait_timeout(refresh_job_queue_guard, next_due_time.saturating_duration_since(now)) {
+                            Ok((refresh_job_queue_guard, _wait_timeout_result)) => refresh_job_queue_guard,
+                            Err(_error) => return,
+                        },
+                        None => match wake_signal.wait(refresh_job_queue_guard) {
+                            Ok(refresh_job_queue_guard) => refresh_job_queue_guard,
+                            Err(_error) => return,
+                        },
+                    };
+                }
+            };
+
+            // Callbacks run outside of the lock, such that they may register or remove jobs.
+            let mut refresh_batch = RefreshBatch::new();
+
+            for due_callback in due_callbacks {
+                due_callback(&mut refresh_batch);
+            }
+
+            dispatch_batch(refresh_batch);
+        }
+    }
+
+    fn update_queue(
+        &self,
+        update: impl FnOnce(&mut RefreshJobQueue),
+    ) {
+        match self.refresh_job_queue.lock() {
+            Ok(mut refresh_job_queue) => update(&mut refresh_job_queue),
+            Err(error) => {
+                log::error!("Error updating refresh scheduler: {}", error);
+                return;
+            }
+        }
+
+        self.wake_signal.notify_all();
+    }
+}
+
+impl Drop for RefreshScheduler {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshScheduler;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn jobs_run_on_their_interval_until_paused() {
+        let dispatch_count = Arc::new(AtomicUsize::new(0));
+        let run_count = Arc::new(AtomicUsize::new(0));
+        let dispatch_count_clone = dispatch_count.clone();
+        let run_count_clone = run_count.clone();
+        let refresh_scheduler = RefreshScheduler::start(move |_refresh_batch| {
+            dispatch_count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        let _refresh_job_handle = refresh_scheduler.register_job("Graphs", Duration::from_millis(20), true, move |_refresh_batch| {
+            run_count_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        thread::sleep(Duration::from_millis(200));
+
+        let run_count_before_pause = run_count.load(Ordering::SeqCst);
+
+        assert!(run_count_before_pause >= 3);
+        // Every tick sends a batch, though the latest may not have been sent yet.
+        assert!(dispatch_count.load(Ordering::SeqCst) + 1 >= run_count_before_pause);
+
+        refresh_scheduler.set_paused(true);
+
+        // A tick already underway when pausing may still finish.
+        let run_count_at_pause = run_count.load(Ordering::SeqCst);
+
+        thread::sleep(Duration::from_millis(100));
+
+        assert!(run_count.load(Ordering::SeqCst) <= run_count_at_pause + 1);
+
+        refresh_scheduler.shutdown();
+    }
+}
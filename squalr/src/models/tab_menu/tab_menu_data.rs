@@ -1,9 +1,9 @@
 use smallvec::SmallVec;
-use std::{rc::Rc, sync::atomic::AtomicI32};
+use std::sync::{Arc, atomic::AtomicI32};
 
 #[derive(Clone)]
 pub struct TabMenuData {
     pub headers: SmallVec<[String; 16]>,
-    // Atomic for mutability.
-    pub active_tab_index: Rc<AtomicI32>,
+    // Atomic for mutability, and shared such that commands run outside of the view can switch tabs.
+    pub active_tab_index: Arc<AtomicI32>,
 }
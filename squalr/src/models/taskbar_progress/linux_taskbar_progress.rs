@@ -0,0 +1,43 @@
+use crate::models::taskbar_progress::taskbar_progress_trait::ITaskbarProgress;
+use eframe::egui::{Context, UserAttentionType, ViewportCommand};
+
+/// Linux has no portable taskbar progress API, so instead this raises the urgency hint while work is running unfocused,
+/// which docks and window lists render as a badge or highlight.
+pub struct LinuxTaskbarProgress {
+    is_requesting_attention: bool,
+}
+
+impl LinuxTaskbarProgress {
+    pub fn new() -> Self {
+        Self {
+            is_requesting_attention: false,
+        }
+    }
+}
+
+impl ITaskbarProgress for LinuxTaskbarProgress {
+    fn set_progress(
+        &mut self,
+        context: &Context,
+        _frame: &eframe::Frame,
+        _progress: f32,
+    ) {
+        let is_focused = context.input(|input| input.viewport().focused.unwrap_or(true));
+
+        if !is_focused && !self.is_requesting_attention {
+            context.send_viewport_cmd(ViewportCommand::RequestUserAttention(UserAttentionType::Informational));
+            self.is_requesting_attention = true;
+        }
+    }
+
+    fn clear(
+        &mut self,
+        context: &Context,
+        _frame: &eframe::Frame,
+    ) {
+        if self.is_requesting_attention {
+            context.send_viewport_cmd(ViewportCommand::RequestUserAttention(UserAttentionType::Reset));
+            self.is_requesting_attention = false;
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod taskbar_progress;
+pub mod taskbar_progress_trait;
+
+#[cfg(target_os = "linux")]
+mod linux_taskbar_progress;
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+mod no_op_taskbar_progress;
+
+#[cfg(target_os = "windows")]
+mod windows_taskbar_progress;
@@ -0,0 +1,22 @@
+use crate::models::taskbar_progress::taskbar_progress_trait::ITaskbarProgress;
+use eframe::egui::Context;
+
+/// Used on platforms without a supported taskbar or dock progress API.
+pub struct NoOpTaskbarProgress;
+
+impl ITaskbarProgress for NoOpTaskbarProgress {
+    fn set_progress(
+        &mut self,
+        _context: &Context,
+        _frame: &eframe::Frame,
+        _progress: f32,
+    ) {
+    }
+
+    fn clear(
+        &mut self,
+        _context: &Context,
+        _frame: &eframe::Frame,
+    ) {
+    }
+}
@@ -0,0 +1,61 @@
+use crate::models::taskbar_progress::taskbar_progress_trait::ITaskbarProgress;
+use eframe::egui::Context;
+
+/// Forwards progress to the platform taskbar integration, only when the displayed percentage actually changes.
+pub struct TaskbarProgress {
+    taskbar_progress_impl: Box<dyn ITaskbarProgress>,
+    displayed_percent: Option<u32>,
+}
+
+impl TaskbarProgress {
+    pub fn new() -> Self {
+        Self {
+            taskbar_progress_impl: Self::create_taskbar_progress_impl(),
+            displayed_percent: None,
+        }
+    }
+
+    /// Shows the given progress, or clears the indicator when no work is in progress.
+    pub fn update(
+        &mut self,
+        context: &Context,
+        frame: &eframe::Frame,
+        progress: Option<f32>,
+    ) {
+        let percent = progress.map(|progress| (progress.clamp(0.0, 1.0) * 100.0).round() as u32);
+
+        if percent == self.displayed_percent {
+            return;
+        }
+
+        match progress {
+            Some(progress) => self
+                .taskbar_progress_impl
+                .set_progress(context, frame, progress),
+            None => self.taskbar_progress_impl.clear(context, frame),
+        }
+
+        self.displayed_percent = percent;
+    }
+
+    #[cfg(target_os = "windows")]
+    fn create_taskbar_progress_impl() -> Box<dyn ITaskbarProgress> {
+        Box::new(crate::models::taskbar_progress::windows_taskbar_progress::WindowsTaskbarProgress::new())
+    }
+
+    #[cfg(target_os = "linux")]
+    fn create_taskbar_progress_impl() -> Box<dyn ITaskbarProgress> {
+        Box::new(crate::models::taskbar_progress::linux_taskbar_progress::LinuxTaskbarProgress::new())
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    fn create_taskbar_progress_impl() -> Box<dyn ITaskbarProgress> {
+        Box::new(crate::models::taskbar_progress::no_op_taskbar_progress::NoOpTaskbarProgress)
+    }
+}
+
+impl Default for TaskbarProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
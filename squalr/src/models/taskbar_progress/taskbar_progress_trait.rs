@@ -0,0 +1,19 @@
+use eframe::egui::Context;
+
+/// Reflects long running progress outside of the window, such that it remains visible while the window is unfocused.
+pub trait ITaskbarProgress {
+    /// Shows the given progress, in the range 0 to 1.
+    fn set_progress(
+        &mut self,
+        context: &Context,
+        frame: &eframe::Frame,
+        progress: f32,
+    );
+
+    /// Removes any progress indicator previously shown.
+    fn clear(
+        &mut self,
+        context: &Context,
+        frame: &eframe::Frame,
+    );
+}
@@ -0,0 +1,135 @@
+use crate::models::taskbar_progress::taskbar_progress_trait::ITaskbarProgress;
+use eframe::egui::Context;
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use std::ffi::c_void;
+use std::ptr::null_mut;
+use windows_sys::Win32::Foundation::HWND;
+use windows_sys::Win32::System::Com::{CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED, CoCreateInstance, CoInitializeEx};
+use windows_sys::core::{GUID, HRESULT};
+
+const CLSID_TASKBAR_LIST: GUID = GUID::from_u128(0x56fdf344_fd6d_11d0_958a_006097c9a090);
+const IID_ITASKBAR_LIST3: GUID = GUID::from_u128(0xea1afb91_9e28_4b86_90e9_9e9f8a5eefaf);
+const TBPF_NOPROGRESS: i32 = 0x0;
+const TBPF_NORMAL: i32 = 0x2;
+const PROGRESS_RESOLUTION: u64 = 1000;
+
+/// The leading entries of the ITaskbarList3 vtable, in declaration order. Unused methods are only kept as placeholders.
+#[repr(C)]
+struct TaskbarList3Vtable {
+    query_interface: usize,
+    add_ref: usize,
+    release: unsafe extern "system" fn(*mut c_void) -> u32,
+    hr_init: unsafe extern "system" fn(*mut c_void) -> HRESULT,
+    add_tab: usize,
+    delete_tab: usize,
+    activate_tab: usize,
+    set_active_alt: usize,
+    mark_fullscreen_window: usize,
+    set_progress_value: unsafe extern "system" fn(*mut c_void, HWND, u64, u64) -> HRESULT,
+    set_progress_state: unsafe extern "system" fn(*mut c_void, HWND, i32) -> HRESULT,
+}
+
+/// Shows progress on the taskbar button through ITaskbarList3. The COM object is created lazily on the UI thread.
+pub struct WindowsTaskbarProgress {
+    taskbar_list: *mut c_void,
+    did_attempt_create: bool,
+}
+
+impl WindowsTaskbarProgress {
+    pub fn new() -> Self {
+        Self {
+            taskbar_list: null_mut(),
+            did_attempt_create: false,
+        }
+    }
+
+    fn get_taskbar_list(&mut self) -> Option<*mut c_void> {
+        if !self.did_attempt_create {
+            self.did_attempt_create = true;
+
+            unsafe {
+                // The UI thread is usually already initialized by the windowing backend, in which case this is a harmless no-op.
+                let _ = CoInitializeEx(null_mut(), COINIT_APARTMENTTHREADED as u32);
+
+                let mut taskbar_list: *mut c_void = null_mut();
+                let result = CoCreateInstance(&CLSID_TASKBAR_LIST, null_mut(), CLSCTX_INPROC_SERVER, &IID_ITASKBAR_LIST3, &mut taskbar_list);
+
+                if result < 0 || taskbar_list.is_null() {
+                    log::warn!("Taskbar progress is unavailable (HRESULT 0x{:08X}).", result);
+                } else if (Self::get_vtable(taskbar_list).hr_init)(taskbar_list) < 0 {
+                    (Self::get_vtable(taskbar_list).release)(taskbar_list);
+                } else {
+                    self.taskbar_list = taskbar_list;
+                }
+            }
+        }
+
+        if self.taskbar_list.is_null() { None } else { Some(self.taskbar_list) }
+    }
+
+    unsafe fn get_vtable<'a>(taskbar_list: *mut c_void) -> &'a TaskbarList3Vtable {
+        unsafe { &**(taskbar_list as *const *const TaskbarList3Vtable) }
+    }
+
+    fn get_window_handle(frame: &eframe::Frame) -> Option<HWND> {
+        match frame
+            .window_handle()
+            .map(|window_handle| window_handle.as_raw())
+        {
+            Ok(RawWindowHandle::Win32(win32_window_handle)) => Some(win32_window_handle.hwnd.get() as HWND),
+            _ => None,
+        }
+    }
+}
+
+impl ITaskbarProgress for WindowsTaskbarProgress {
+    fn set_progress(
+        &mut self,
+        _context: &Context,
+        frame: &eframe::Frame,
+        progress: f32,
+    ) {
+        let window_handle = match Self::get_window_handle(frame) {
+            Some(window_handle) => window_handle,
+            None => return,
+        };
+
+        if let Some(taskbar_list) = self.get_taskbar_list() {
+            let completed = (progress.clamp(0.0, 1.0) as f64 * PROGRESS_RESOLUTION as f64) as u64;
+
+            unsafe {
+                let vtable = Self::get_vtable(taskbar_list);
+
+                (vtable.set_progress_state)(taskbar_list, window_handle, TBPF_NORMAL);
+                (vtable.set_progress_value)(taskbar_list, window_handle, completed, PROGRESS_RESOLUTION);
+            }
+        }
+    }
+
+    fn clear(
+        &mut self,
+        _context: &Context,
+        frame: &eframe::Frame,
+    ) {
+        let window_handle = match Self::get_window_handle(frame) {
+            Some(window_handle) => window_handle,
+            None => return,
+        };
+
+        if let Some(taskbar_list) = self.get_taskbar_list() {
+            unsafe {
+                (Self::get_vtable(taskbar_list).set_progress_state)(taskbar_list, window_handle, TBPF_NOPROGRESS);
+            }
+        }
+    }
+}
+
+impl Drop for WindowsTaskbarProgress {
+    fn drop(&mut self) {
+        if !self.taskbar_list.is_null() {
+            unsafe {
+                (Self::get_vtable(self.taskbar_list).release)(self.taskbar_list);
+            }
+        }
+    }
+}
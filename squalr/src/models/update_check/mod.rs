@@ -0,0 +1,2 @@
+pub mod release_notes_line;
+pub mod update_check_settings;
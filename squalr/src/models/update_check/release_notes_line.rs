@@ -0,0 +1,74 @@
+/// A line of release notes, classified from the subset of markdown that GitHub release bodies typically use.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReleaseNotesLine {
+    Heading(String),
+    Bullet { text: String, indent_level: usize },
+    Paragraph(String),
+    Blank,
+}
+
+impl ReleaseNotesLine {
+    /// Splits a release body into lines, stripping markdown markers that would otherwise render literally, ie headings, bullets,
+    /// and bold or inline code markers.
+    pub fn parse_release_notes(release_body: &str) -> Vec<ReleaseNotesLine> {
+        release_body.lines().map(Self::parse_line).collect()
+    }
+
+    fn parse_line(line: &str) -> ReleaseNotesLine {
+        let trimmed_line = line.trim_start();
+
+        if trimmed_line.trim().is_empty() {
+            return ReleaseNotesLine::Blank;
+        }
+
+        if trimmed_line.starts_with('#') {
+            let heading = trimmed_line.trim_start_matches('#');
+
+            return ReleaseNotesLine::Heading(Self::strip_inline_markers(heading.trim()));
+        }
+
+        for bullet_marker in ["- ", "* ", "+ "] {
+            if let Some(text) = trimmed_line.strip_prefix(bullet_marker) {
+                let indent_width = line.len() - trimmed_line.len();
+
+                return ReleaseNotesLine::Bullet {
+                    text: Self::strip_inline_markers(text.trim()),
+                    indent_level: indent_width / 2,
+                };
+            }
+        }
+
+        ReleaseNotesLine::Paragraph(Self::strip_inline_markers(trimmed_line.trim_end()))
+    }
+
+    fn strip_inline_markers(text: &str) -> String {
+        text.replace("**", "").replace("__", "").replace('`', "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReleaseNotesLine;
+
+    #[test]
+    fn release_bodies_are_classified_by_line() {
+        let release_notes = ReleaseNotesLine::parse_release_notes("## What's new\r\n- **Faster** scans\n  * Nested `detail`\n\nThanks to everyone!");
+
+        assert_eq!(
+            release_notes,
+            vec![
+                ReleaseNotesLine::Heading("What's new".to_string()),
+                ReleaseNotesLine::Bullet {
+                    text: "Faster scans".to_string(),
+                    indent_level: 0,
+                },
+                ReleaseNotesLine::Bullet {
+                    text: "Nested detail".to_string(),
+                    indent_level: 1,
+                },
+                ReleaseNotesLine::Blank,
+                ReleaseNotesLine::Paragraph("Thanks to everyone!".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use serde_json::to_string_pretty;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UpdateCheckSettingsConfig {
+    pub is_update_check_enabled: bool,
+    /// When the last automatic update check ran, in seconds since the unix epoch.
+    pub last_update_check_unix_seconds: Option<u64>,
+}
+
+impl Default for UpdateCheckSettingsConfig {
+    fn default() -> Self {
+        Self {
+            is_update_check_enabled: true,
+            last_update_check_unix_seconds: None,
+        }
+    }
+}
+
+/// GUI-only update check preferences, persisted beside the executable.
+pub struct UpdateCheckSettings {
+    config: RwLock<UpdateCheckSettingsConfig>,
+    config_file: PathBuf,
+}
+
+impl UpdateCheckSettings {
+    /// Automatic checks run at most once per interval, such that restarting Squalr repeatedly does not hammer the releases API.
+    pub const CHECK_INTERVAL_SECONDS: u64 = 24 * 60 * 60;
+
+    fn new() -> Self {
+        let config_file = Self::default_config_path();
+        let config = match fs::read_to_string(&config_file) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => UpdateCheckSettingsConfig::default(),
+        };
+
+        Self {
+            config: RwLock::new(config),
+            config_file,
+        }
+    }
+
+    fn get_instance() -> &'static UpdateCheckSettings {
+        static INSTANCE: OnceLock<UpdateCheckSettings> = OnceLock::new();
+
+        INSTANCE.get_or_init(UpdateCheckSettings::new)
+    }
+
+    fn default_config_path() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_default()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("update_check_settings.json")
+    }
+
+    fn save_config() {
+        let config = match Self::get_instance().config.read() {
+            Ok(config) => *config,
+            Err(_) => return,
+        };
+
+        match to_string_pretty(&config) {
+            Ok(json) => {
+                if let Err(error) = fs::write(&Self::get_instance().config_file, json) {
+                    log::error!("Failed to save update check settings: {}", error);
+                }
+            }
+            Err(error) => log::error!("Failed to serialize update check settings: {}", error),
+        }
+    }
+
+    fn get_unix_seconds_now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0)
+    }
+
+    pub fn get_is_update_check_enabled() -> bool {
+        match Self::get_instance().config.read() {
+            Ok(config) => config.is_update_check_enabled,
+            Err(_) => true,
+        }
+    }
+
+    pub fn set_is_update_check_enabled(is_update_check_enabled: bool) {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.is_update_check_enabled = is_update_check_enabled;
+        }
+
+        Self::save_config();
+    }
+
+    /// Gets whether an automatic check should run now, ie the check is enabled and has not already run within the last day.
+    pub fn is_automatic_check_due() -> bool {
+        match Self::get_instance().config.read() {
+            Ok(config) => config.is_update_check_enabled && Self::is_check_due(config.last_update_check_unix_seconds, Self::get_unix_seconds_now()),
+            Err(_) => false,
+        }
+    }
+
+    pub fn record_update_check() {
+        if let Ok(mut config) = Self::get_instance().config.write() {
+            config.last_update_check_unix_seconds = Some(Self::get_unix_seconds_now());
+        }
+
+        Self::save_config();
+    }
+
+    /// Gets whether enough time has passed since the last check. A last check in the future means the clock moved backwards, which
+    /// is treated as due rather than suppressing checks until the clock catches up.
+    pub fn is_check_due(
+        last_update_check_unix_seconds: Option<u64>,
+        now_unix_seconds: u64,
+    ) -> bool {
+        match last_update_check_unix_seconds {
+            Some(last_update_check_unix_seconds) if last_update_check_unix_seconds <= now_unix_seconds => {
+                now_unix_seconds - last_update_check_unix_seconds >= Self::CHECK_INTERVAL_SECONDS
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateCheckSettings;
+
+    #[test]
+    fn checks_run_at_most_once_per_day() {
+        let last_check = 1_000_000;
+
+        assert!(UpdateCheckSettings::is_check_due(None, last_check));
+        assert!(!UpdateCheckSettings::is_check_due(Some(last_check), last_check + 60));
+        assert!(!UpdateCheckSettings::is_check_due(
+            Some(last_check),
+            last_check + UpdateCheckSettings::CHECK_INTERVAL_SECONDS - 1
+        ));
+        assert!(UpdateCheckSettings::is_check_due(
+            Some(last_check),
+            last_check + UpdateCheckSettings::CHECK_INTERVAL_SECONDS
+        ));
+    }
+
+    #[test]
+    fn clocks_moving_backwards_do_not_suppress_checks() {
+        assert!(UpdateCheckSettings::is_check_due(Some(2_000_000), 1_000_000));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod workspace;
+pub mod workspace_component;
+pub mod workspace_contents;
+pub mod workspace_manifest;
+pub mod workspace_scan_constraints;
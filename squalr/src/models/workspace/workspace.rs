@@ -0,0 +1,393 @@
+use crate::models::workspace::workspace_component::WorkspaceComponent;
+use crate::models::workspace::workspace_contents::WorkspaceContents;
+use crate::models::workspace::workspace_manifest::{WorkspaceManifest, WorkspaceManifestComponent};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The result of loading a workspace. Components that could not be read are left out of the contents, with a warning saying why.
+pub struct WorkspaceLoadResult {
+    pub contents: WorkspaceContents,
+    pub warnings: Vec<String>,
+}
+
+/// A directory bundling the working context for one target executable: a manifest, plus one file per component.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Workspace {
+    directory: PathBuf,
+    manifest: WorkspaceManifest,
+}
+
+impl Workspace {
+    /// Gets the folder beside the executable in which workspaces are created by default.
+    pub fn get_workspaces_directory() -> PathBuf {
+        std::env::current_exe()
+            .unwrap_or_default()
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join("workspaces")
+    }
+
+    /// Turns a workspace name into a folder name, replacing characters that are not valid in paths on every platform.
+    pub fn create_directory_name(name: &str) -> String {
+        let directory_name = name
+            .trim()
+            .chars()
+            .map(|character| match character {
+                '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+                character if character.is_control() => '_',
+                character => character,
+            })
+            .collect::<String>();
+
+        match directory_name.trim_matches('.').is_empty() {
+            true => "workspace".to_string(),
+            false => directory_name,
+        }
+    }
+
+    pub fn get_directory(&self) -> &Path {
+        &self.directory
+    }
+
+    pub fn get_manifest(&self) -> &WorkspaceManifest {
+        &self.manifest
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    pub fn get_target_executable(&self) -> &str {
+        &self.manifest.target_executable
+    }
+
+    /// Reads the manifest of the workspace in the given directory. Only a missing or unreadable manifest fails, as components
+    /// are not read until `load_contents`.
+    pub fn open(directory: &Path) -> Result<Self, String> {
+        let manifest_path = directory.join(WorkspaceManifest::FILE_NAME);
+        let manifest_json = fs::read_to_string(&manifest_path).map_err(|error| format!("Failed to read {:?}: {}", manifest_path, error))?;
+        let manifest = serde_json::from_str::<WorkspaceManifest>(&manifest_json).map_err(|error| format!("Failed to parse {:?}: {}", manifest_path, error))?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Writes the given contents to a workspace directory, replacing any workspace already there. The manifest is written last,
+    /// such that a save interrupted partway through still leaves the previous manifest describing readable files.
+    pub fn save(
+        directory: &Path,
+        name: &str,
+        target_executable: &str,
+        contents: &WorkspaceContents,
+    ) -> Result<Self, String> {
+        fs::create_dir_all(directory).map_err(|error| format!("Failed to create {:?}: {}", directory, error))?;
+
+        let mut manifest = WorkspaceManifest::new(name.to_string(), target_executable.to_string());
+
+        Self::save_component(directory, &mut manifest, WorkspaceComponent::Project, contents.project.as_ref())?;
+        Self::save_component(
+            directory,
+            &mut manifest,
+            WorkspaceComponent::StructDefinitions,
+            contents.struct_definitions.as_ref(),
+        )?;
+        Self::save_component(directory, &mut manifest, WorkspaceComponent::Layout, contents.layout.as_ref())?;
+        Self::save_component(
+            directory,
+            &mut manifest,
+            WorkspaceComponent::ScanConstraints,
+            contents.scan_constraints.as_ref(),
+        )?;
+
+        let manifest_path = directory.join(WorkspaceManifest::FILE_NAME);
+        let manifest_json = serde_json::to_string_pretty(&manifest).map_err(|error| format!("Failed to serialize workspace manifest: {}", error))?;
+
+        fs::write(&manifest_path, manifest_json).map_err(|error| format!("Failed to write {:?}: {}", manifest_path, error))?;
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Reads every component listed in the manifest. Components that are missing, unreadable, unknown, or written by a newer
+    /// version of Squalr are skipped with a warning, such that a partially upgradable workspace still loads what it can.
+    pub fn load_contents(&self) -> WorkspaceLoadResult {
+        let mut contents = WorkspaceContents::default();
+        let mut warnings = Vec::new();
+
+        if self.manifest.manifest_version > WorkspaceManifest::CURRENT_MANIFEST_VERSION {
+            warnings.push(format!(
+                "Workspace {} was saved by a newer version of Squalr. Only the parts this version understands were loaded.",
+                self.manifest.name
+            ));
+        }
+
+        for manifest_component in &self.manifest.components {
+            let Some(component) = WorkspaceComponent::from_component_id(&manifest_component.component_id) else {
+                warnings.push(format!("Skipped unknown workspace component {}.", manifest_component.component_id));
+                continue;
+            };
+
+            if manifest_component.version > component.get_current_version() {
+                warnings.push(format!(
+                    "Skipped the {}, which was saved in a newer format (version {}, this version of Squalr reads up to {}).",
+                    component.get_display_name(),
+                    manifest_component.version,
+                    component.get_current_version()
+                ));
+                continue;
+            }
+
+            let load_result = match component {
+                WorkspaceComponent::Project => self
+                    .load_component(manifest_component)
+                    .map(|project| contents.project = Some(project)),
+                WorkspaceComponent::StructDefinitions => self
+                    .load_component(manifest_component)
+                    .map(|struct_definitions| contents.struct_definitions = Some(struct_definitions)),
+                WorkspaceComponent::Layout => self
+                    .load_component(manifest_component)
+                    .map(|layout| contents.layout = Some(layout)),
+                WorkspaceComponent::ScanConstraints => self
+                    .load_component(manifest_component)
+                    .map(|scan_constraints| contents.scan_constraints = Some(scan_constraints)),
+            };
+
+            if let Err(error) = load_result {
+                warnings.push(format!("Skipped the {}: {}", component.get_display_name(), error));
+            }
+        }
+
+        WorkspaceLoadResult { contents, warnings }
+    }
+
+    /// Finds every workspace directly within the given directory, sorted by name. Folders without a readable manifest are ignored.
+    pub fn find_all(workspaces_directory: &Path) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(workspaces_directory) else {
+            return Vec::new();
+        };
+        let mut workspaces = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.join(WorkspaceManifest::FILE_NAME).is_file())
+            .filter_map(|path| match Self::open(&path) {
+                Ok(workspace) => Some(workspace),
+                Err(error) => {
+                    log::warn!("Ignoring workspace: {}", error);
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        workspaces.sort_by(|left, right| {
+            left.get_name()
+                .to_lowercase()
+                .cmp(&right.get_name().to_lowercase())
+        });
+
+        workspaces
+    }
+
+    /// Finds the workspaces that belong to the given executable.
+    pub fn find_for_target(
+        workspaces_directory: &Path,
+        executable_name: &str,
+    ) -> Vec<Self> {
+        Self::find_all(workspaces_directory)
+            .into_iter()
+            .filter(|workspace| workspace.manifest.matches_target(executable_name))
+            .collect()
+    }
+
+    fn save_component<T: Serialize>(
+        directory: &Path,
+        manifest: &mut WorkspaceManifest,
+        component: WorkspaceComponent,
+        value: Option<&T>,
+    ) -> Result<(), String> {
+        let component_path = directory.join(component.get_file_name());
+        let Some(value) = value else {
+            // Remove what an earlier save left behind, such that the folder matches the manifest.
+            let _ = fs::remove_file(&component_path);
+
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(value).map_err(|error| format!("Failed to serialize the {}: {}", component.get_display_name(), error))?;
+
+        fs::write(&component_path, json).map_err(|error| format!("Failed to write {:?}: {}", component_path, error))?;
+
+        manifest.components.push(WorkspaceManifestComponent {
+            component_id: component.get_component_id().to_string(),
+            version: component.get_current_version(),
+            file_name: component.get_file_name().to_string(),
+        });
+
+        Ok(())
+    }
+
+    fn load_component<T: DeserializeOwned>(
+        &self,
+        manifest_component: &WorkspaceManifestComponent,
+    ) -> Result<T, String> {
+        // Only the file name is taken from the manifest, such that a hand edited manifest cannot point outside the workspace.
+        let file_name = Path::new(&manifest_component.file_name)
+            .file_name()
+            .ok_or_else(|| format!("{:?} is not a file name.", manifest_component.file_name))?;
+        let component_path = self.directory.join(file_name);
+        let json = fs::read_to_string(&component_path).map_err(|error| format!("Failed to read {:?}: {}", component_path, error))?;
+
+        serde_json::from_str(&json).map_err(|error| format!("Failed to parse {:?}: {}", component_path, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Workspace;
+    use crate::models::docking::settings::dockable_window_settings::DockSettingsConfig;
+    use crate::models::workspace::workspace_component::WorkspaceComponent;
+    use crate::models::workspace::workspace_contents::{WorkspaceContents, WorkspaceProjectReference};
+    use crate::models::workspace::workspace_manifest::{WorkspaceManifest, WorkspaceManifestComponent};
+    use crate::models::workspace::workspace_scan_constraints::WorkspaceScanConstraints;
+    use squalr_engine_api::structures::data_types::custom_types::custom_data_type_definition::{CustomDataTypeDefinition, CustomDataTypeField};
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type::ScanCompareType;
+    use squalr_engine_api::structures::scanning::comparisons::scan_compare_type_immediate::ScanCompareTypeImmediate;
+    use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn create_test_directory(test_name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("squalr_workspace_test_{}_{}", test_name, std::process::id()));
+
+        let _ = fs::remove_dir_all(&directory);
+
+        directory
+    }
+
+    fn create_test_contents() -> WorkspaceContents {
+        WorkspaceContents {
+            project: Some(WorkspaceProjectReference {
+                project_directory: PathBuf::from("projects/Example Game"),
+            }),
+            struct_definitions: Some(vec![CustomDataTypeDefinition::new(
+                "Player",
+                vec![
+                    CustomDataTypeField::new("health", DataTypeRef::new("i32")),
+                    CustomDataTypeField::new("speed", DataTypeRef::new("f32")),
+                ],
+            )]),
+            layout: Some(DockSettingsConfig::default()),
+            scan_constraints: Some(WorkspaceScanConstraints {
+                data_type_ref: DataTypeRef::new("u16"),
+                display_format: AnonymousValueStringFormat::Hexadecimal,
+                constraints: vec![AnonymousScanConstraint::new(
+                    ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+                    Some(AnonymousValueString::new(
+                        "1F4".to_string(),
+                        AnonymousValueStringFormat::Hexadecimal,
+                        ContainerType::None,
+                    )),
+                )],
+            }),
+        }
+    }
+
+    #[test]
+    fn saved_workspaces_load_every_component() {
+        let directory = create_test_directory("round_trip");
+        let workspace = Workspace::save(&directory, "Example Game", "game.exe", &create_test_contents()).expect("The workspace should save.");
+        let reopened_workspace = Workspace::open(&directory).expect("The workspace should reopen.");
+        let load_result = reopened_workspace.load_contents();
+
+        assert_eq!(reopened_workspace, workspace);
+        assert_eq!(reopened_workspace.get_manifest().components.len(), WorkspaceComponent::ALL.len());
+        assert!(load_result.warnings.is_empty(), "{:?}", load_result.warnings);
+        assert_eq!(
+            load_result
+                .contents
+                .project
+                .map(|project| project.project_directory),
+            Some(PathBuf::from("projects/Example Game"))
+        );
+        assert_eq!(load_result.contents.struct_definitions, create_test_contents().struct_definitions);
+        assert!(load_result.contents.layout.is_some());
+
+        let scan_constraints = load_result
+            .contents
+            .scan_constraints
+            .expect("The scan constraints should load.");
+
+        assert_eq!(scan_constraints.data_type_ref, DataTypeRef::new("u16"));
+        assert_eq!(scan_constraints.constraints.len(), 1);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn unreadable_and_newer_components_are_skipped_with_warnings() {
+        let directory = create_test_directory("partial_upgrade");
+        let workspace = Workspace::save(&directory, "Example Game", "game.exe", &create_test_contents()).expect("The workspace should save.");
+        let mut manifest = workspace.get_manifest().clone();
+
+        // Simulate a newer build: the layout format moved on, and a component this build has never heard of was added.
+        for component in manifest.components.iter_mut() {
+            if component.component_id == WorkspaceComponent::Layout.get_component_id() {
+                component.version = 99;
+            }
+        }
+
+        manifest.components.push(WorkspaceManifestComponent {
+            component_id: "memory_viewer_bookmarks".to_string(),
+            version: 1,
+            file_name: "memory_viewer_bookmarks.json".to_string(),
+        });
+        manifest.manifest_version = WorkspaceManifest::CURRENT_MANIFEST_VERSION + 1;
+
+        fs::write(directory.join(WorkspaceManifest::FILE_NAME), serde_json::to_string_pretty(&manifest).unwrap()).unwrap();
+        fs::write(directory.join(WorkspaceComponent::ScanConstraints.get_file_name()), "{ not json").unwrap();
+
+        let load_result = Workspace::open(&directory)
+            .expect("A newer manifest should still open.")
+            .load_contents();
+
+        assert_eq!(load_result.warnings.len(), 4, "{:?}", load_result.warnings);
+        assert!(load_result.contents.project.is_some());
+        assert!(load_result.contents.struct_definitions.is_some());
+        assert!(load_result.contents.layout.is_none());
+        assert!(load_result.contents.scan_constraints.is_none());
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn workspaces_are_found_by_target_executable() {
+        let directory = create_test_directory("find_for_target");
+        let contents = WorkspaceContents::default();
+
+        Workspace::save(&directory.join("b"), "Speedrun", "Game.exe", &contents).unwrap();
+        Workspace::save(&directory.join("a"), "Any%", "game.exe", &contents).unwrap();
+        Workspace::save(&directory.join("c"), "Other", "other.exe", &contents).unwrap();
+        fs::create_dir_all(directory.join("not_a_workspace")).unwrap();
+
+        let workspaces = Workspace::find_for_target(&directory, "GAME.EXE");
+        let workspace_names = workspaces
+            .iter()
+            .map(|workspace| workspace.get_name())
+            .collect::<Vec<_>>();
+
+        assert_eq!(workspace_names, vec!["Any%", "Speedrun"]);
+        assert_eq!(Workspace::find_all(&directory).len(), 3);
+        assert_eq!(Workspace::create_directory_name("Any% / 100%?"), "Any% _ 100%_");
+        assert_eq!(Workspace::create_directory_name(" .. "), "workspace");
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
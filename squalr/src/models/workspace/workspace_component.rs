@@ -0,0 +1,66 @@
+/// A part of a workspace, each stored in its own file using the format that part is already persisted in elsewhere.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WorkspaceComponent {
+    /// The directory of the project holding the workspace's address entries and their names.
+    Project,
+    /// Custom struct definitions, in the same format as the custom data types file.
+    StructDefinitions,
+    /// The docked window layout, in the same format as the docking settings file.
+    Layout,
+    /// The data type and constraints of the element scanner.
+    ScanConstraints,
+}
+
+impl WorkspaceComponent {
+    pub const ALL: [WorkspaceComponent; 4] = [
+        WorkspaceComponent::Project,
+        WorkspaceComponent::StructDefinitions,
+        WorkspaceComponent::Layout,
+        WorkspaceComponent::ScanConstraints,
+    ];
+
+    /// Gets the id under which this component is recorded in the manifest.
+    pub fn get_component_id(&self) -> &'static str {
+        match self {
+            WorkspaceComponent::Project => "project",
+            WorkspaceComponent::StructDefinitions => "struct_definitions",
+            WorkspaceComponent::Layout => "layout",
+            WorkspaceComponent::ScanConstraints => "scan_constraints",
+        }
+    }
+
+    pub fn from_component_id(component_id: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|component| component.get_component_id() == component_id)
+    }
+
+    pub fn get_file_name(&self) -> &'static str {
+        match self {
+            WorkspaceComponent::Project => "project.json",
+            WorkspaceComponent::StructDefinitions => "custom_data_types.json",
+            WorkspaceComponent::Layout => "docking_settings.json",
+            WorkspaceComponent::ScanConstraints => "scan_constraints.json",
+        }
+    }
+
+    /// Gets the version of this component written by this build. Components saved with a newer version are skipped on load,
+    /// as this build cannot know what changed.
+    pub fn get_current_version(&self) -> u32 {
+        match self {
+            WorkspaceComponent::Project => 1,
+            WorkspaceComponent::StructDefinitions => 1,
+            WorkspaceComponent::Layout => 1,
+            WorkspaceComponent::ScanConstraints => 1,
+        }
+    }
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            WorkspaceComponent::Project => "project",
+            WorkspaceComponent::StructDefinitions => "struct definitions",
+            WorkspaceComponent::Layout => "window layout",
+            WorkspaceComponent::ScanConstraints => "scan constraints",
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use crate::models::docking::settings::dockable_window_settings::DockSettingsConfig;
+use crate::models::workspace::workspace_scan_constraints::WorkspaceScanConstraints;
+use serde::{Deserialize, Serialize};
+use squalr_engine_api::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use std::path::PathBuf;
+
+/// Points at the project a workspace uses. The project keeps its own files, such that it can still be opened on its own.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WorkspaceProjectReference {
+    pub project_directory: PathBuf,
+}
+
+/// The working context bundled into a workspace. Each part is optional, both because there may be nothing to save (ie no
+/// project is open), and because a part that cannot be read is skipped on load rather than failing the whole workspace.
+#[derive(Default)]
+pub struct WorkspaceContents {
+    pub project: Option<WorkspaceProjectReference>,
+    pub struct_definitions: Option<Vec<CustomDataTypeDefinition>>,
+    pub layout: Option<DockSettingsConfig>,
+    pub scan_constraints: Option<WorkspaceScanConstraints>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// A component file listed in a workspace manifest, along with the version of the format it was written in.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WorkspaceManifestComponent {
+    /// The component id. Kept as text rather than an enum, such that components added by newer builds are skipped rather than
+    /// making the whole manifest unreadable.
+    pub component_id: String,
+    pub version: u32,
+    pub file_name: String,
+}
+
+/// Describes a workspace directory: which executable it belongs to, and which component files it holds.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct WorkspaceManifest {
+    pub manifest_version: u32,
+    pub name: String,
+    /// The executable name this workspace is loaded for when attaching, ie `game.exe`.
+    pub target_executable: String,
+    #[serde(default)]
+    pub components: Vec<WorkspaceManifestComponent>,
+}
+
+impl WorkspaceManifest {
+    pub const CURRENT_MANIFEST_VERSION: u32 = 1;
+    pub const FILE_NAME: &'static str = "workspace.json";
+
+    pub fn new(
+        name: String,
+        target_executable: String,
+    ) -> Self {
+        Self {
+            manifest_version: Self::CURRENT_MANIFEST_VERSION,
+            name,
+            target_executable,
+            components: Vec::new(),
+        }
+    }
+
+    /// Gets whether this workspace belongs to the given executable. Executable names are compared without regard to case, as
+    /// Windows does not distinguish them.
+    pub fn matches_target(
+        &self,
+        executable_name: &str,
+    ) -> bool {
+        self.target_executable.eq_ignore_ascii_case(executable_name)
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+
+/// The element scanner inputs last used against a target, such that the next session can pick up the same scan.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WorkspaceScanConstraints {
+    pub data_type_ref: DataTypeRef,
+    pub display_format: AnonymousValueStringFormat,
+    /// One constraint per row in the scanner, including rows whose value has not been filled in.
+    pub constraints: Vec<AnonymousScanConstraint>,
+}
@@ -4,10 +4,12 @@ use squalr_engine_api::structures::data_types::built_in_types::{
     aob::data_type_aob::DataTypeAob, bool8::data_type_bool8::DataTypeBool8, bool32::data_type_bool32::DataTypeBool32, f32::data_type_f32::DataTypeF32,
     f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be, i8::data_type_i8::DataTypeI8,
     i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32, i32be::data_type_i32be::DataTypeI32be,
-    i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::utf8::data_type_string_utf8::DataTypeStringUtf8,
+    i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::encoded::data_type_string_encoded::DataTypeStringEncoded,
+    string::utf8::data_type_string_utf8::DataTypeStringUtf8,
     u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32,
     u32be::data_type_u32be::DataTypeU32be, u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
 };
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
 
 pub struct DataTypeToIconConverter {}
 
@@ -47,9 +49,15 @@ impl DataTypeToIconConverter {
             DataTypeF64be::DATA_TYPE_ID => icon_library
                 .icon_handle_data_type_orange_blocks_reverse_8
                 .clone(),
-            DataTypeStringUtf8::DATA_TYPE_ID => icon_library.icon_handle_data_type_string.clone(),
+            DataTypeStringUtf8::DATA_TYPE_ID
+            | DataTypeStringEncoded::SHIFT_JIS_DATA_TYPE_ID
+            | DataTypeStringEncoded::LATIN1_DATA_TYPE_ID
+            | DataTypeStringEncoded::UTF32_DATA_TYPE_ID => icon_library.icon_handle_data_type_string.clone(),
             DataTypeAob::DATA_TYPE_ID => icon_library.icon_handle_data_type_purple_blocks_array.clone(),
-            _ => icon_library.icon_handle_data_type_unknown.clone(),
+            _ => match CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_id) {
+                Some(_) => icon_library.icon_handle_data_type_purple_blocks_array.clone(),
+                None => icon_library.icon_handle_data_type_unknown.clone(),
+            },
         }
     }
 }
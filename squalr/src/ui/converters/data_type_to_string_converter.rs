@@ -2,10 +2,12 @@ use squalr_engine_api::structures::data_types::built_in_types::{
     aob::data_type_aob::DataTypeAob, bool8::data_type_bool8::DataTypeBool8, bool32::data_type_bool32::DataTypeBool32, f32::data_type_f32::DataTypeF32,
     f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be, i8::data_type_i8::DataTypeI8,
     i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32, i32be::data_type_i32be::DataTypeI32be,
-    i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::utf8::data_type_string_utf8::DataTypeStringUtf8,
+    i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::encoded::data_type_string_encoded::DataTypeStringEncoded,
+    string::utf8::data_type_string_utf8::DataTypeStringUtf8,
     u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32,
     u32be::data_type_u32be::DataTypeU32be, u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
 };
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
 
 pub struct DataTypeToStringConverter {}
 
@@ -33,8 +35,19 @@ impl DataTypeToStringConverter {
             DataTypeF64::DATA_TYPE_ID => "Double",
             DataTypeF64be::DATA_TYPE_ID => "Double (BE)",
             DataTypeStringUtf8::DATA_TYPE_ID => "String",
+            DataTypeStringEncoded::SHIFT_JIS_DATA_TYPE_ID => "String (Shift-JIS)",
+            DataTypeStringEncoded::LATIN1_DATA_TYPE_ID => "String (Latin-1)",
+            DataTypeStringEncoded::UTF32_DATA_TYPE_ID => "String (UTF-32)",
             DataTypeAob::DATA_TYPE_ID => "Array of Bytes",
             _ => "Unknown",
         }
     }
+
+    /// Converts a data type to a display string, including the names of user-defined struct types.
+    pub fn convert_data_type_to_display_string(data_type_id: &str) -> String {
+        match CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_id) {
+            Some(custom_data_type) => custom_data_type.get_definition().get_name().to_string(),
+            None => Self::convert_data_type_to_string(data_type_id).to_string(),
+        }
+    }
 }
@@ -23,6 +23,10 @@ impl ScanCompareTypeToIconConverter {
             ScanCompareTypeImmediate::LessThanOrEqual => icon_library
                 .icon_handle_scan_immediate_less_than_or_equal
                 .clone(),
+            // There is no dedicated range icon, and a range is bounded inclusively from below like greater than or equal.
+            ScanCompareTypeImmediate::Between => icon_library
+                .icon_handle_scan_immediate_greater_than_or_equal
+                .clone(),
         }
     }
 
@@ -53,6 +57,7 @@ impl ScanCompareTypeToIconConverter {
             ScanCompareTypeRelative::Unchanged => icon_library.icon_handle_scan_relative_unchanged.clone(),
             ScanCompareTypeRelative::Increased => icon_library.icon_handle_scan_relative_increased.clone(),
             ScanCompareTypeRelative::Decreased => icon_library.icon_handle_scan_relative_decreased.clone(),
+            ScanCompareTypeRelative::DiffersFromFirstScan => icon_library.icon_handle_scan_relative_changed.clone(),
         }
     }
 
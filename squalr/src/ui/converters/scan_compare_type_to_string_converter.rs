@@ -14,6 +14,7 @@ impl ScanCompareTypeToStringConverter {
             ScanCompareTypeImmediate::GreaterThanOrEqual => "Greater than or equal to",
             ScanCompareTypeImmediate::LessThan => "Less than",
             ScanCompareTypeImmediate::LessThanOrEqual => "Less than or equal to",
+            ScanCompareTypeImmediate::Between => "Between",
         }
     }
 
@@ -38,6 +39,7 @@ impl ScanCompareTypeToStringConverter {
             ScanCompareTypeRelative::Unchanged => "Unchanged",
             ScanCompareTypeRelative::Increased => "Increased",
             ScanCompareTypeRelative::Decreased => "Decreased",
+            ScanCompareTypeRelative::DiffersFromFirstScan => "Differs from first scan",
         }
     }
 
@@ -0,0 +1,368 @@
+use crate::ui::text_table::TextTable;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static FRAME_PROFILER: FrameProfiler = FrameProfiler::new();
+
+/// Times how long each docked view and key phase of a frame takes, keeping a rolling window of recent frames from which the
+/// debug HUD shows per-scope mean and p95 costs.
+///
+/// Whether a frame is profiled is decided once, when it begins. Scopes opened during an unprofiled frame only read that flag,
+/// such that leaving the HUD closed costs next to nothing.
+pub struct FrameProfiler {
+    is_enabled: AtomicBool,
+    is_frame_profiled: AtomicBool,
+    state: Mutex<FrameProfilerState>,
+}
+
+struct FrameProfilerState {
+    frame_index: u64,
+    frame_start_time: Option<Instant>,
+    scopes: Vec<FrameScopeSamples>,
+}
+
+struct FrameScopeSamples {
+    scope_name: String,
+    /// The time spent in this scope so far in the current frame, summed over every time the scope was entered.
+    current_frame_cost: Option<Duration>,
+    samples: VecDeque<Duration>,
+    last_sampled_frame_index: u64,
+}
+
+/// The cost of a single scope over the rolling window, counting only the frames in which the scope was entered.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameScopeStatistics {
+    pub scope_name: String,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub sample_count: usize,
+}
+
+/// Records the time from its creation until it is dropped, such that early returns from a view still close the scope.
+#[must_use = "the scope is recorded when dropped, so it must be bound to a variable"]
+pub struct FrameProfilerScope<'a> {
+    profiler: &'a FrameProfiler,
+    scope: Option<(String, Instant)>,
+}
+
+impl FrameProfiler {
+    /// The number of frames over which statistics are aggregated.
+    pub const ROLLING_WINDOW_FRAME_COUNT: usize = 120;
+
+    /// The name of the scope spanning the whole frame.
+    pub const FRAME_SCOPE_NAME: &'static str = "Frame";
+
+    pub const fn new() -> Self {
+        Self {
+            is_enabled: AtomicBool::new(false),
+            is_frame_profiled: AtomicBool::new(false),
+            state: Mutex::new(FrameProfilerState {
+                frame_index: 0,
+                frame_start_time: None,
+                scopes: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn get_instance() -> &'static FrameProfiler {
+        &FRAME_PROFILER
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled.load(Ordering::Relaxed)
+    }
+
+    /// Turns profiling on or off, starting with the next frame. Turning it on discards statistics from the last time it ran.
+    pub fn set_enabled(
+        &self,
+        is_enabled: bool,
+    ) {
+        if self.is_enabled.swap(is_enabled, Ordering::Relaxed) == is_enabled || !is_enabled {
+            return;
+        }
+
+        if let Ok(mut state) = self.state.lock() {
+            state.scopes.clear();
+        }
+    }
+
+    pub fn begin_frame(&self) {
+        let is_frame_profiled = self.is_enabled();
+
+        self.is_frame_profiled
+            .store(is_frame_profiled, Ordering::Relaxed);
+
+        if !is_frame_profiled {
+            return;
+        }
+
+        if let Ok(mut state) = self.state.lock() {
+            state.frame_index += 1;
+            state.frame_start_time = Some(Instant::now());
+
+            for scope in state.scopes.iter_mut() {
+                scope.current_frame_cost = None;
+            }
+        }
+    }
+
+    /// Folds the scopes recorded since `begin_frame` into the rolling window. Scopes that have not been entered for a whole
+    /// window, such as closed views, are dropped.
+    pub fn end_frame(&self) {
+        if !self.is_frame_profiled.swap(false, Ordering::Relaxed) {
+            return;
+        }
+
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        if let Some(frame_start_time) = state.frame_start_time.take() {
+            Self::add_cost(&mut state, Self::FRAME_SCOPE_NAME, frame_start_time.elapsed());
+        }
+
+        let frame_index = state.frame_index;
+
+        for scope in state.scopes.iter_mut() {
+            if let Some(current_frame_cost) = scope.current_frame_cost.take() {
+                if scope.samples.len() >= Self::ROLLING_WINDOW_FRAME_COUNT {
+                    scope.samples.pop_front();
+                }
+
+                scope.samples.push_back(current_frame_cost);
+                scope.last_sampled_frame_index = frame_index;
+            }
+        }
+
+        state
+            .scopes
+            .retain(|scope| frame_index - scope.last_sampled_frame_index < Self::ROLLING_WINDOW_FRAME_COUNT as u64);
+    }
+
+    /// Opens a scope that is timed until the returned guard is dropped. Does nothing unless the current frame is profiled.
+    pub fn scope(
+        &self,
+        scope_name: &str,
+    ) -> FrameProfilerScope<'_> {
+        let scope = if self.is_frame_profiled.load(Ordering::Relaxed) {
+            Some((scope_name.to_string(), Instant::now()))
+        } else {
+            None
+        };
+
+        FrameProfilerScope { profiler: self, scope }
+    }
+
+    /// Gets the statistics of every scope in the rolling window, costliest first.
+    pub fn get_statistics(&self) -> Vec<FrameScopeStatistics> {
+        let Ok(state) = self.state.lock() else {
+            return Vec::new();
+        };
+        let mut statistics = state
+            .scopes
+            .iter()
+            .filter(|scope| !scope.samples.is_empty())
+            .map(|scope| {
+                let mut samples = scope.samples.iter().copied().collect::<Vec<_>>();
+                let sample_count = samples.len();
+                let mean = samples.iter().sum::<Duration>() / sample_count as u32;
+
+                samples.sort_unstable();
+
+                // Nearest-rank percentile, such that a single slow frame in a short window still shows up.
+                let p95_rank = (sample_count * 95).div_ceil(100).max(1);
+
+                FrameScopeStatistics {
+                    scope_name: scope.scope_name.clone(),
+                    mean,
+                    p95: samples[p95_rank - 1],
+                    sample_count,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        statistics.sort_by(|left, right| right.mean.cmp(&left.mean));
+
+        statistics
+    }
+
+    /// Formats the current statistics as an aligned text table, for logging or pasting into an issue.
+    pub fn format_report(&self) -> String {
+        let mut text_table = TextTable::new(vec![
+            "Scope".to_string(),
+            "Mean (ms)".to_string(),
+            "P95 (ms)".to_string(),
+            "Frames".to_string(),
+        ]);
+
+        for statistics in self.get_statistics() {
+            text_table.add_row(vec![
+                statistics.scope_name,
+                Self::format_milliseconds(statistics.mean),
+                Self::format_milliseconds(statistics.p95),
+                statistics.sample_count.to_string(),
+            ]);
+        }
+
+        text_table.format()
+    }
+
+    pub fn format_milliseconds(duration: Duration) -> String {
+        format!("{:.2}", duration.as_secs_f64() * 1000.0)
+    }
+
+    fn record(
+        &self,
+        scope_name: String,
+        elapsed: Duration,
+    ) {
+        // The frame may have ended while this scope was open, ie for a scope held across frames by mistake. Its time is dropped.
+        if !self.is_frame_profiled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        if let Ok(mut state) = self.state.lock() {
+            Self::add_cost(&mut state, &scope_name, elapsed);
+        }
+    }
+
+    fn add_cost(
+        state: &mut FrameProfilerState,
+        scope_name: &str,
+        elapsed: Duration,
+    ) {
+        let frame_index = state.frame_index;
+        let scope = match state
+            .scopes
+            .iter()
+            .position(|scope| scope.scope_name == scope_name)
+        {
+            Some(scope_position) => &mut state.scopes[scope_position],
+            None => {
+                state.scopes.push(FrameScopeSamples {
+                    scope_name: scope_name.to_string(),
+                    current_frame_cost: None,
+                    samples: VecDeque::with_capacity(Self::ROLLING_WINDOW_FRAME_COUNT),
+                    last_sampled_frame_index: frame_index,
+                });
+
+                state.scopes.last_mut().expect("A scope was just pushed.")
+            }
+        };
+
+        scope.current_frame_cost = Some(scope.current_frame_cost.unwrap_or_default() + elapsed);
+    }
+}
+
+impl Default for FrameProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for FrameProfilerScope<'_> {
+    fn drop(&mut self) {
+        if let Some((scope_name, start_time)) = self.scope.take() {
+            self.profiler.record(scope_name, start_time.elapsed());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameProfiler;
+    use std::thread;
+    use std::time::Duration;
+
+    // Sleeps overshoot by however long the scheduler takes to wake the thread, so only an upper bound needs slack.
+    const TOLERANCE: Duration = Duration::from_millis(25);
+
+    fn show_fake_view(
+        profiler: &FrameProfiler,
+        view_name: &str,
+        cost: Duration,
+        should_return_early: bool,
+    ) {
+        let _profiler_scope = profiler.scope(view_name);
+
+        thread::sleep(cost / 2);
+
+        if should_return_early {
+            return;
+        }
+
+        thread::sleep(cost / 2);
+    }
+
+    fn assert_cost_within_tolerance(
+        actual: Duration,
+        expected: Duration,
+    ) {
+        assert!(actual >= expected, "{:?} is below the expected {:?}", actual, expected);
+        assert!(actual <= expected + TOLERANCE, "{:?} exceeds the expected {:?}", actual, expected);
+    }
+
+    #[test]
+    fn views_are_attributed_the_time_spent_in_their_scopes() {
+        let profiler = FrameProfiler::new();
+        let fast_view_cost = Duration::from_millis(2);
+        let slow_view_cost = Duration::from_millis(12);
+        let frame_count = 8;
+
+        profiler.set_enabled(true);
+
+        for _ in 0..frame_count {
+            profiler.begin_frame();
+            show_fake_view(&profiler, "Fast view", fast_view_cost, false);
+            show_fake_view(&profiler, "Slow view", slow_view_cost, false);
+            // Returning early only runs the first half of the view, which the scope must still close over.
+            show_fake_view(&profiler, "Early return view", slow_view_cost, true);
+            profiler.end_frame();
+        }
+
+        let statistics = profiler.get_statistics();
+        let find_statistics = |scope_name: &str| {
+            statistics
+                .iter()
+                .find(|statistics| statistics.scope_name == scope_name)
+                .unwrap_or_else(|| panic!("No statistics were recorded for {}.", scope_name))
+        };
+
+        let fast_view_statistics = find_statistics("Fast view");
+        let slow_view_statistics = find_statistics("Slow view");
+        let early_return_view_statistics = find_statistics("Early return view");
+        let frame_statistics = find_statistics(FrameProfiler::FRAME_SCOPE_NAME);
+
+        assert_eq!(slow_view_statistics.sample_count, frame_count);
+        assert_cost_within_tolerance(fast_view_statistics.mean, fast_view_cost);
+        assert_cost_within_tolerance(slow_view_statistics.mean, slow_view_cost);
+        assert_cost_within_tolerance(early_return_view_statistics.mean, slow_view_cost / 2);
+        assert!(slow_view_statistics.p95 >= slow_view_cost);
+        assert!(frame_statistics.mean >= fast_view_cost + slow_view_cost + slow_view_cost / 2);
+
+        // The frame itself spans every view, so it comes first.
+        assert_eq!(statistics[0].scope_name, FrameProfiler::FRAME_SCOPE_NAME);
+        assert!(profiler.format_report().contains("Slow view"));
+    }
+
+    #[test]
+    fn scopes_record_nothing_while_profiling_is_off() {
+        let profiler = FrameProfiler::new();
+
+        profiler.begin_frame();
+        show_fake_view(&profiler, "Fast view", Duration::from_millis(1), false);
+        profiler.end_frame();
+
+        assert!(profiler.get_statistics().is_empty());
+
+        // Enabling mid-frame waits for the next frame, rather than recording a partial one.
+        profiler.begin_frame();
+        profiler.set_enabled(true);
+        show_fake_view(&profiler, "Fast view", Duration::from_millis(1), false);
+        profiler.end_frame();
+
+        assert!(profiler.get_statistics().is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+use eframe::egui::{Event, Key, Response, Ui};
+use std::ops::RangeInclusive;
+
+/// Tracks a contiguous range of selected lines in a read only, line based view, ie the disassembler or the output log. Lines
+/// are selected whole, by clicking, dragging across rows, or shift clicking to extend from the last clicked line.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct LineSelection {
+    anchor_index: Option<usize>,
+    cursor_index: Option<usize>,
+    is_dragging: bool,
+}
+
+impl LineSelection {
+    const COLUMN_SEPARATOR: &'static str = "  ";
+
+    pub fn get_range(&self) -> Option<RangeInclusive<usize>> {
+        let anchor_index = self.anchor_index.or(self.cursor_index)?;
+        let cursor_index = self.cursor_index.unwrap_or(anchor_index);
+
+        Some(anchor_index.min(cursor_index)..=anchor_index.max(cursor_index))
+    }
+
+    pub fn is_selected(
+        &self,
+        line_index: usize,
+    ) -> bool {
+        self.get_range()
+            .is_some_and(|selected_range| selected_range.contains(&line_index))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.get_range().is_none()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Selects a single line, making it the anchor for any later extension.
+    pub fn select_line(
+        &mut self,
+        line_index: usize,
+    ) {
+        self.anchor_index = Some(line_index);
+        self.cursor_index = Some(line_index);
+    }
+
+    /// Extends the selection from the anchor to the given line, or selects just that line if nothing was selected.
+    pub fn extend_to(
+        &mut self,
+        line_index: usize,
+    ) {
+        if self.anchor_index.is_none() {
+            self.anchor_index = Some(line_index);
+        }
+
+        self.cursor_index = Some(line_index);
+    }
+
+    pub fn select_all(
+        &mut self,
+        line_count: usize,
+    ) {
+        if line_count == 0 {
+            self.clear();
+            return;
+        }
+
+        self.anchor_index = Some(0);
+        self.cursor_index = Some(line_count - 1);
+    }
+
+    /// Drops the selection if the lines it covered no longer exist, ie after the view reloads with fewer lines.
+    pub fn clamp_to_line_count(
+        &mut self,
+        line_count: usize,
+    ) {
+        if self
+            .get_range()
+            .is_some_and(|selected_range| *selected_range.end() >= line_count)
+        {
+            self.clear();
+        }
+    }
+
+    /// Updates the selection from this frame's interaction with a row. Rows must sense clicks and drags. Dragging is tracked by
+    /// the pointer position rather than row hover, as the row that started a drag keeps the hover until the button is released.
+    pub fn handle_row_interaction(
+        &mut self,
+        user_interface: &Ui,
+        row_response: &Response,
+        line_index: usize,
+    ) {
+        let (is_primary_pressed, is_primary_down, is_shift_down, pointer_position) = user_interface.input(|input| {
+            (
+                input.pointer.primary_pressed(),
+                input.pointer.primary_down(),
+                input.modifiers.shift,
+                input.pointer.interact_pos(),
+            )
+        });
+
+        if is_primary_pressed && row_response.hovered() {
+            if is_shift_down {
+                self.extend_to(line_index);
+            } else {
+                self.select_line(line_index);
+            }
+
+            self.is_dragging = true;
+        } else if self.is_dragging
+            && is_primary_down
+            && pointer_position.is_some_and(|pointer_position| row_response.rect.y_range().contains(pointer_position.y))
+        {
+            self.cursor_index = Some(line_index);
+        }
+
+        if !is_primary_down {
+            self.is_dragging = false;
+        }
+
+        // Right clicking outside of the selection retargets it, such that the context menu acts on the clicked line.
+        if row_response.secondary_clicked() && !self.is_selected(line_index) {
+            self.select_line(line_index);
+        }
+    }
+
+    /// Gets whether the copy shortcut was pressed this frame. Some platforms only surface it as a copy event, others only as a key.
+    pub fn is_copy_requested(user_interface: &Ui) -> bool {
+        if user_interface.ctx().wants_keyboard_input() {
+            return false;
+        }
+
+        user_interface.input(|input| input.events.iter().any(|event| matches!(event, Event::Copy)) || (input.modifiers.command && input.key_pressed(Key::C)))
+    }
+
+    /// Formats the selected lines as plain text, padding each column to line up as it does on screen. Returns `None` when nothing
+    /// is selected.
+    pub fn format_selected_lines(
+        &self,
+        line_count: usize,
+        get_columns: impl Fn(usize) -> Vec<String>,
+    ) -> Option<String> {
+        let selected_range = self.get_range()?;
+        let rows = selected_range
+            .filter(|line_index| *line_index < line_count)
+            .map(get_columns)
+            .collect::<Vec<_>>();
+
+        if rows.is_empty() {
+            return None;
+        }
+
+        Some(Self::format_columns(&rows))
+    }
+
+    /// Lays out rows of cells such that every column but the last is padded to its widest cell.
+    pub fn format_columns(rows: &[Vec<String>]) -> String {
+        let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let column_widths = (0..column_count)
+            .map(|column_index| {
+                rows.iter()
+                    .filter_map(|row| row.get(column_index))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        rows.iter()
+            .map(|row| {
+                let mut line = String::new();
+
+                for (column_index, cell) in row.iter().enumerate() {
+                    if column_index > 0 {
+                        line.push_str(Self::COLUMN_SEPARATOR);
+                    }
+
+                    line.push_str(cell);
+
+                    if column_index + 1 < row.len() {
+                        let padding = column_widths[column_index].saturating_sub(cell.chars().count());
+                        line.push_str(&" ".repeat(padding));
+                    }
+                }
+
+                line.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineSelection;
+
+    fn to_cells(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn extending_selects_from_the_anchor_in_either_direction() {
+        let mut line_selection = LineSelection::default();
+
+        line_selection.select_line(5);
+        line_selection.extend_to(2);
+        assert_eq!(line_selection.get_range(), Some(2..=5));
+
+        line_selection.extend_to(8);
+        assert_eq!(line_selection.get_range(), Some(5..=8));
+        assert!(!line_selection.is_selected(4));
+        assert!(line_selection.is_selected(8));
+    }
+
+    #[test]
+    fn extending_without_an_anchor_selects_one_line() {
+        let mut line_selection = LineSelection::default();
+
+        line_selection.extend_to(3);
+        assert_eq!(line_selection.get_range(), Some(3..=3));
+    }
+
+    #[test]
+    fn shrinking_below_the_selection_clears_it() {
+        let mut line_selection = LineSelection::default();
+
+        line_selection.select_all(10);
+        line_selection.clamp_to_line_count(10);
+        assert_eq!(line_selection.get_range(), Some(0..=9));
+
+        line_selection.clamp_to_line_count(4);
+        assert!(line_selection.is_empty());
+    }
+
+    #[test]
+    fn selected_lines_are_copied_in_column_layout() {
+        let rows = [
+            to_cells(&["game.exe+1000", "48 89 5C 24 08", "mov [rsp+08],rbx"]),
+            to_cells(&["game.exe+1005", "C3", "ret"]),
+            to_cells(&["game.exe+1006", "90", "nop"]),
+        ];
+        let mut line_selection = LineSelection::default();
+
+        line_selection.select_line(1);
+        line_selection.extend_to(0);
+
+        assert_eq!(
+            line_selection.format_selected_lines(rows.len(), |line_index| rows[line_index].clone()),
+            Some(
+                [
+                    "game.exe+1000  48 89 5C 24 08  mov [rsp+08],rbx",
+                    "game.exe+1005  C3              ret",
+                ]
+                .join("\n")
+            )
+        );
+    }
+
+    #[test]
+    fn nothing_is_copied_without_a_selection() {
+        let line_selection = LineSelection::default();
+
+        assert_eq!(line_selection.format_selected_lines(3, |_line_index| to_cells(&["a"])), None);
+    }
+}
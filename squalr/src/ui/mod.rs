@@ -1,7 +1,11 @@
 pub mod converters;
 pub mod draw;
 pub mod fonts;
+pub mod frame_profiler;
 pub mod icon_library;
+pub mod line_selection;
+pub mod text_table;
 pub mod theme;
+pub mod toolbar_layout;
 pub mod ui_trace;
 pub mod widgets;
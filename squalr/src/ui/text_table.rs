@@ -0,0 +1,181 @@
+/// Lays out rows of cells as an aligned, monospace-friendly block of text, ie for pasting a page of results into a chat or
+/// issue where tab separated values would not line up.
+pub struct TextTable {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    max_column_width: usize,
+}
+
+impl TextTable {
+    pub const DEFAULT_MAX_COLUMN_WIDTH: usize = 48;
+    const COLUMN_SEPARATOR: &'static str = " │ ";
+    const HEADER_SEPARATOR_LINE: char = '─';
+    const HEADER_SEPARATOR_JUNCTION: &'static str = "─┼─";
+    const ELLIPSIS: char = '…';
+
+    pub fn new(headers: Vec<String>) -> Self {
+        Self {
+            headers,
+            rows: Vec::new(),
+            max_column_width: Self::DEFAULT_MAX_COLUMN_WIDTH,
+        }
+    }
+
+    /// Caps how wide any column may grow. Longer cells are cut short and end in an ellipsis.
+    pub fn with_max_column_width(
+        mut self,
+        max_column_width: usize,
+    ) -> Self {
+        self.max_column_width = max_column_width.max(1);
+        self
+    }
+
+    /// Adds a row of cells. Missing cells are left blank, and cells beyond the header count are dropped.
+    pub fn add_row(
+        &mut self,
+        cells: Vec<String>,
+    ) {
+        self.rows.push(cells);
+    }
+
+    pub fn format(&self) -> String {
+        let column_count = self.headers.len();
+
+        if column_count == 0 {
+            return String::new();
+        }
+
+        let header_cells = self
+            .headers
+            .iter()
+            .map(|header| self.fit_cell(header))
+            .collect::<Vec<_>>();
+        let row_cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                (0..column_count)
+                    .map(|column_index| self.fit_cell(row.get(column_index).map(String::as_str).unwrap_or_default()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let column_widths = (0..column_count)
+            .map(|column_index| {
+                std::iter::once(&header_cells)
+                    .chain(row_cells.iter())
+                    .map(|cells| cells[column_index].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let mut lines = Vec::with_capacity(row_cells.len() + 2);
+
+        lines.push(Self::format_line(&header_cells, &column_widths));
+        lines.push(
+            column_widths
+                .iter()
+                .map(|column_width| Self::HEADER_SEPARATOR_LINE.to_string().repeat(*column_width))
+                .collect::<Vec<_>>()
+                .join(Self::HEADER_SEPARATOR_JUNCTION),
+        );
+
+        for cells in &row_cells {
+            lines.push(Self::format_line(cells, &column_widths));
+        }
+
+        lines.join("\n")
+    }
+
+    fn fit_cell(
+        &self,
+        cell: &str,
+    ) -> String {
+        // Newlines and tabs would break the alignment of every row below them.
+        let cell = cell.replace(['\n', '\r', '\t'], " ");
+
+        if cell.chars().count() <= self.max_column_width {
+            return cell;
+        }
+
+        let mut fitted_cell = cell.chars().take(self.max_column_width - 1).collect::<String>();
+        fitted_cell.push(Self::ELLIPSIS);
+
+        fitted_cell
+    }
+
+    fn format_line(
+        cells: &[String],
+        column_widths: &[usize],
+    ) -> String {
+        let line = cells
+            .iter()
+            .zip(column_widths)
+            .map(|(cell, column_width)| format!("{:<width$}", cell, width = column_width))
+            .collect::<Vec<_>>()
+            .join(Self::COLUMN_SEPARATOR);
+
+        line.trim_end().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextTable;
+
+    fn create_table(headers: &[&str]) -> TextTable {
+        TextTable::new(headers.iter().map(|header| header.to_string()).collect())
+    }
+
+    fn to_cells(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn columns_are_padded_to_the_longest_cell() {
+        let mut text_table = create_table(&["Address", "Value", "Previous"]);
+
+        text_table.add_row(to_cells(&["00401000", "100", "99"]));
+        text_table.add_row(to_cells(&["game.exe+1F4", "-1", "12345678"]));
+
+        assert_eq!(
+            text_table.format(),
+            [
+                "Address      │ Value │ Previous",
+                "─────────────┼───────┼─────────",
+                "00401000     │ 100   │ 99",
+                "game.exe+1F4 │ -1    │ 12345678",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn long_cells_are_ellipsized_at_the_column_cap() {
+        let mut text_table = create_table(&["Base", "Offsets"]).with_max_column_width(8);
+
+        text_table.add_row(to_cells(&["00007FF6A0B01000", "[10, 20]"]));
+        text_table.add_row(to_cells(&["short"]));
+
+        assert_eq!(
+            text_table.format(),
+            [
+                "Base     │ Offsets",
+                "─────────┼─────────",
+                "00007FF… │ [10, 20]",
+                "short    │",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn line_breaks_within_cells_do_not_break_rows() {
+        let mut text_table = create_table(&["Value"]);
+
+        text_table.add_row(to_cells(&["a\nb"]));
+
+        assert_eq!(text_table.format(), ["Value", "─────", "a b"].join("\n"));
+    }
+}
@@ -0,0 +1,164 @@
+use eframe::egui::Ui;
+use epaint::{FontId, Rect, pos2, vec2};
+
+/// A fixed-width item placed by a `ToolbarLayout`.
+#[derive(Clone, Copy)]
+pub struct ToolbarItem {
+    pub width: f32,
+    pub spacing_before: f32,
+}
+
+impl ToolbarItem {
+    pub fn new(width: f32) -> Self {
+        Self {
+            width,
+            spacing_before: ToolbarLayout::ITEM_SPACING,
+        }
+    }
+
+    /// Places this item flush against the previous item, ie for grouped navigation buttons.
+    pub fn attached(width: f32) -> Self {
+        Self { width, spacing_before: 0.0 }
+    }
+}
+
+/// Computes toolbar item rects from the current style and font metrics, so that toolbars laid out with absolute rects stay
+/// aligned when the UI scale or fonts change, rather than assuming a hard-coded item height.
+#[derive(Clone, Copy)]
+pub struct ToolbarLayout {
+    item_height: f32,
+}
+
+impl ToolbarLayout {
+    pub const ITEM_SPACING: f32 = 8.0;
+    pub const PADDING: f32 = 4.0;
+    const MINIMUM_ITEM_HEIGHT: f32 = 28.0;
+
+    pub fn new(
+        user_interface: &Ui,
+        font_id: &FontId,
+    ) -> Self {
+        let row_height = user_interface.fonts(|fonts| fonts.row_height(font_id));
+        let spacing = user_interface.spacing();
+        let item_height = (row_height + spacing.button_padding.y * 2.0)
+            .max(spacing.interact_size.y)
+            .max(Self::MINIMUM_ITEM_HEIGHT)
+            .ceil();
+
+        Self { item_height }
+    }
+
+    pub fn get_item_height(&self) -> f32 {
+        self.item_height
+    }
+
+    /// Gets the height of a toolbar holding the given number of item rows, including padding above, between, and below rows.
+    pub fn get_toolbar_height(
+        &self,
+        row_count: usize,
+    ) -> f32 {
+        self.item_height * row_count as f32 + Self::PADDING * (row_count as f32 + 1.0)
+    }
+
+    /// Lays out items left to right, vertically centered within the given row.
+    pub fn layout_row(
+        &self,
+        row_rect: Rect,
+        items: &[ToolbarItem],
+    ) -> Vec<Rect> {
+        let top = row_rect.center().y - self.item_height * 0.5;
+        let mut left = row_rect.min.x;
+
+        items
+            .iter()
+            .map(|item| {
+                left += item.spacing_before;
+
+                let item_rect = Rect::from_min_size(pos2(left, top), vec2(item.width, self.item_height));
+
+                left = item_rect.max.x;
+
+                item_rect
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ToolbarLayout;
+    use crate::ui::theme::Theme;
+    use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
+    use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
+    use eframe::egui::{CentralPanel, Context, RawInput};
+    use epaint::{FontId, Rect, pos2, vec2};
+
+    fn assert_no_overlaps(
+        toolbar_name: &str,
+        toolbar_rect: Rect,
+        item_rects: &[Rect],
+    ) {
+        for (index, item_rect) in item_rects.iter().enumerate() {
+            assert!(
+                item_rect.top() >= toolbar_rect.top() && item_rect.bottom() <= toolbar_rect.bottom(),
+                "{} item {} at {:?} does not fit within toolbar {:?}",
+                toolbar_name,
+                index,
+                item_rect,
+                toolbar_rect
+            );
+
+            for (other_index, other_rect) in item_rects.iter().enumerate().skip(index + 1) {
+                assert!(
+                    !item_rect.intersect(*other_rect).is_positive(),
+                    "{} item {} at {:?} overlaps item {} at {:?}",
+                    toolbar_name,
+                    index,
+                    item_rect,
+                    other_index,
+                    other_rect
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn toolbars_do_not_overlap_at_double_scale() {
+        let context = Context::default();
+        let theme = Theme::new(&context);
+        let font_normal = theme.font_library.font_noto_sans.font_normal.clone();
+
+        context.set_pixels_per_point(2.0);
+
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(pos2(0.0, 0.0), vec2(800.0, 600.0))),
+            ..RawInput::default()
+        };
+
+        let _ = context.run(input, |context| {
+            CentralPanel::default().show(context, |user_interface| {
+                // Also lay out with doubled text, which is what previously overflowed the fixed 28px item height.
+                for font_id in [
+                    font_normal.clone(),
+                    FontId::new(font_normal.size * 2.0, font_normal.family.clone()),
+                ] {
+                    let toolbar_layout = ToolbarLayout::new(user_interface, &font_id);
+                    let row_height = user_interface.fonts(|fonts| fonts.row_height(&font_id));
+                    let toolbar_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(800.0, toolbar_layout.get_toolbar_height(1)));
+
+                    assert!(toolbar_layout.get_item_height() >= row_height);
+                    assert_no_overlaps(
+                        "Memory viewer toolbar",
+                        toolbar_rect,
+                        &MemoryViewerView::layout_toolbar_items(&toolbar_layout, toolbar_rect),
+                    );
+                    assert_no_overlaps(
+                        "Pointer scanner footer",
+                        toolbar_rect,
+                        &PointerScannerView::layout_footer_items(&toolbar_layout, toolbar_rect),
+                    );
+                }
+            });
+        });
+    }
+}
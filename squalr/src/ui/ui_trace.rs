@@ -1,11 +1,17 @@
+use std::collections::VecDeque;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 static TRACE_PATH: OnceLock<PathBuf> = OnceLock::new();
 
+/// The most recent trace records, kept regardless of whether file tracing is enabled, such that crash reports can include them.
+static RECENT_RECORDS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+pub const MAX_RECENT_RECORDS: usize = 200;
+
 fn trace_path() -> &'static Path {
     TRACE_PATH
         .get_or_init(|| std::env::temp_dir().join("squalr_ui_trace.log"))
@@ -17,22 +23,41 @@ pub fn is_enabled() -> bool {
 }
 
 pub fn trace(message: impl AsRef<str>) {
-    if !is_enabled() {
-        return;
-    }
-
     let timestamp_ms = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_millis())
         .unwrap_or(0);
+    let record = format!("[{}] {}", timestamp_ms, message.as_ref());
+
+    if is_enabled() {
+        write_record_to_file(&record);
+    }
 
+    if let Ok(mut recent_records) = RECENT_RECORDS.lock() {
+        if recent_records.len() >= MAX_RECENT_RECORDS {
+            recent_records.pop_front();
+        }
+
+        recent_records.push_back(record);
+    }
+}
+
+fn write_record_to_file(record: &str) {
     if let Ok(mut file) = OpenOptions::new()
         .create(true)
         .append(true)
         .open(trace_path())
     {
-        let _ = writeln!(file, "[{}] {}", timestamp_ms, message.as_ref());
+        let _ = writeln!(file, "{}", record);
         let _ = file.flush();
     }
 }
 
+/// Gets the most recent trace records, oldest first. This never blocks, such that it is safe to call from a panic hook, and
+/// instead returns nothing if the records are being written at that moment.
+pub fn get_recent_records() -> Vec<String> {
+    match RECENT_RECORDS.try_lock() {
+        Ok(recent_records) => recent_records.iter().cloned().collect(),
+        Err(_) => Vec::new(),
+    }
+}
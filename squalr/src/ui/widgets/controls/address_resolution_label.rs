@@ -0,0 +1,33 @@
+use crate::models::address_resolution::address_resolver::AddressResolution;
+use crate::ui::theme::Theme;
+use eframe::egui::{Label, Response, RichText, Ui, Widget};
+
+/// Inline feedback for a committed address input. Shows a green check with where the address resolved to, or the reason it
+/// could not be resolved in red.
+pub struct AddressResolutionLabel<'lifetime> {
+    theme: &'lifetime Theme,
+    address_resolution: &'lifetime AddressResolution,
+}
+
+impl<'lifetime> AddressResolutionLabel<'lifetime> {
+    pub fn new(
+        theme: &'lifetime Theme,
+        address_resolution: &'lifetime AddressResolution,
+    ) -> Self {
+        Self { theme, address_resolution }
+    }
+}
+
+impl<'lifetime> Widget for AddressResolutionLabel<'lifetime> {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let (text, color) = match self.address_resolution {
+            Ok(resolved_address) => (format!("✔ {}", resolved_address), self.theme.hexadecimal_green),
+            Err(error) => (format!("✖ {}", error), self.theme.error_red),
+        };
+
+        user_interface.add(Label::new(RichText::new(text).color(color)).truncate())
+    }
+}
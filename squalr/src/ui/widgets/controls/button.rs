@@ -1,6 +1,6 @@
 use crate::ui::theme::Theme;
 use crate::ui::widgets::controls::state_layer::StateLayer;
-use eframe::egui::{Color32, Response, Sense, Ui, Widget};
+use eframe::egui::{Color32, Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
 use epaint::CornerRadius;
 
 #[derive(Default)]
@@ -29,7 +29,7 @@ impl<'lifetime> Button<'lifetime> {
         button.hover_tint = theme.hover_tint;
         button.pressed_tint = theme.pressed_tint;
         button.border_color = theme.background_control_primary_dark;
-        button.border_color_focused = None;
+        button.border_color_focused = Some(theme.focused_border);
 
         button
     }
@@ -155,6 +155,9 @@ impl<'lifetime> Widget for Button<'lifetime> {
         }
         .paint(user_interface);
 
+        // Buttons are drawn as icons, so the tooltip doubles as the label announced to screen readers.
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, !self.disabled, self.tooltip_text));
+
         // Tooltip.
         if !self.tooltip_text.is_empty() {
             response = response.on_hover_text(self.tooltip_text);
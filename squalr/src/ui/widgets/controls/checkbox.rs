@@ -1,6 +1,6 @@
 use crate::ui::widgets::controls::state_layer::StateLayer;
 use crate::ui::{theme::Theme, widgets::controls::check_state::CheckState};
-use eframe::egui::{Color32, Response, Sense, Ui, Widget};
+use eframe::egui::{Color32, Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
 use epaint::{CornerRadius, Rect, StrokeKind, TextureHandle, Vec2, pos2};
 
 pub struct Checkbox<'lifetime> {
@@ -152,6 +152,9 @@ impl<'lifetime> Widget for Checkbox<'lifetime> {
             }
         }
 
+        // Mixed states are announced as unchecked, as accesskit has no tri-state checkbox through egui.
+        response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, !self.disabled, self.check_state == CheckState::True, self.tooltip_text));
+
         // Tooltip.
         if !self.tooltip_text.is_empty() {
             response = response.on_hover_text(self.tooltip_text);
@@ -1,6 +1,6 @@
 use crate::app_context::AppContext;
 use crate::ui::widgets::controls::state_layer::StateLayer;
-use eframe::egui::{Align, Area, Frame, Id, Key, Layout, Order, Response, Sense, Ui, Widget};
+use eframe::egui::{Align, Area, Frame, Id, Key, Layout, Order, Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
 use epaint::{Color32, CornerRadius, Margin, Rect, TextureHandle, Vec2, pos2, vec2};
 use std::sync::Arc;
 
@@ -86,6 +86,8 @@ impl<'lifetime, F: FnOnce(&mut Ui, &mut bool)> Widget for ComboBoxView<'lifetime
         let desired_size = vec2(self.width, self.height);
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(desired_size, Sense::click());
 
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, true, self.label));
+
         // Precompute positions.
         let icon_size_vec = vec2(self.icon_size, self.icon_size);
         let icon_y = allocated_size_rectangle.center().y - icon_size_vec.y * 0.5;
@@ -0,0 +1,329 @@
+use crate::ui::converters::data_type_to_string_converter::DataTypeToStringConverter;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::{
+    aob::data_type_aob::DataTypeAob, bool8::data_type_bool8::DataTypeBool8, bool32::data_type_bool32::DataTypeBool32, f32::data_type_f32::DataTypeF32,
+    f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be, i8::data_type_i8::DataTypeI8,
+    i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32, i32be::data_type_i32be::DataTypeI32be,
+    i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, string::encoded::data_type_string_encoded::DataTypeStringEncoded,
+    string::encoded::text_encoding::TextEncoding, string::utf8::data_type_string_utf8::DataTypeStringUtf8, u8::data_type_u8::DataTypeU8,
+    u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32, u32be::data_type_u32be::DataTypeU32be,
+    u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
+};
+use squalr_engine_api::structures::memory::endian::Endian;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+/// The groups that data types are presented in, in presentation order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DataTypeCategory {
+    Integers,
+    Floats,
+    Strings,
+    Bytes,
+    Custom,
+}
+
+impl DataTypeCategory {
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            DataTypeCategory::Integers => "Integers",
+            DataTypeCategory::Floats => "Floats",
+            DataTypeCategory::Strings => "Strings",
+            DataTypeCategory::Bytes => "Bytes",
+            DataTypeCategory::Custom => "Custom",
+        }
+    }
+}
+
+/// A single selectable data type, as presented to the user.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataTypeCatalogEntry {
+    data_type_id: String,
+    display_name: String,
+    category: DataTypeCategory,
+    aliases: &'static [&'static str],
+    sort_key: DataTypeSortKey,
+}
+
+impl DataTypeCatalogEntry {
+    pub fn get_data_type_id(&self) -> &str {
+        &self.data_type_id
+    }
+
+    pub fn get_display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    pub fn get_category(&self) -> DataTypeCategory {
+        self.category
+    }
+
+    pub fn get_aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Determines if this entry matches a search, by display name, data type id, or any alias. Matching is case insensitive.
+    pub fn matches_search(
+        &self,
+        search_text: &str,
+    ) -> bool {
+        let search_text = search_text.trim().to_lowercase();
+
+        search_text.is_empty()
+            || self.display_name.to_lowercase().contains(&search_text)
+            || self.data_type_id.to_lowercase().contains(&search_text)
+            || self.aliases.iter().any(|alias| alias.contains(&search_text))
+    }
+}
+
+/// Orders entries within a category by size, then endianness, then booleans after plain integers, then signedness. Strings have no
+/// meaningful size ordering, so they use a fixed rank instead. Names and ids only break any remaining ties, such as between custom types.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct DataTypeSortKey {
+    category: DataTypeCategory,
+    rank: usize,
+    unit_size_in_bytes: u64,
+    endian: Endian,
+    is_boolean: bool,
+    is_signed: bool,
+    display_name: String,
+    data_type_id: String,
+}
+
+/// A stable presentation of every registered data type, shared by all data type selectors. The registries themselves are unordered
+/// (and custom types can be added at any time), so this defines the order independently of how or when types were registered.
+pub struct DataTypeCatalog {}
+
+impl DataTypeCatalog {
+    /// Gets every selectable data type, grouped by category in a fixed order.
+    pub fn get_entries() -> Vec<DataTypeCatalogEntry> {
+        let built_in_data_type_ids = SymbolRegistry::get_instance()
+            .get_data_type_registry()
+            .keys()
+            .cloned();
+        let custom_data_type_ids = CustomDataTypeRegistry::get_instance().get_data_type_ids();
+
+        Self::build_entries(built_in_data_type_ids.chain(custom_data_type_ids))
+    }
+
+    /// Builds ordered entries from a list of data type ids. Repeated ids are a registration bug, so they are reported and shown once.
+    pub fn build_entries(data_type_ids: impl IntoIterator<Item = String>) -> Vec<DataTypeCatalogEntry> {
+        let mut seen_data_type_ids = HashSet::new();
+        let mut entries = vec![];
+
+        for data_type_id in data_type_ids {
+            if !seen_data_type_ids.insert(data_type_id.clone()) {
+                Self::report_duplicate_data_type_id(&data_type_id);
+                continue;
+            }
+
+            entries.push(Self::create_entry(data_type_id));
+        }
+
+        entries.sort_by(|left, right| left.sort_key.cmp(&right.sort_key));
+        entries
+    }
+
+    fn create_entry(data_type_id: String) -> DataTypeCatalogEntry {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let display_name = DataTypeToStringConverter::convert_data_type_to_display_string(&data_type_id);
+        let is_custom = !symbol_registry
+            .get_data_type_registry()
+            .contains_key(&data_type_id);
+        let data_type = symbol_registry.get_data_type_registry().get(&data_type_id);
+        let category = match data_type {
+            _ if is_custom => DataTypeCategory::Custom,
+            _ if data_type_id == DataTypeAob::DATA_TYPE_ID => DataTypeCategory::Bytes,
+            _ if DataTypeStringEncoded::is_string_data_type_id(&data_type_id) => DataTypeCategory::Strings,
+            Some(data_type) if data_type.is_floating_point() => DataTypeCategory::Floats,
+            _ => DataTypeCategory::Integers,
+        };
+        let sort_key = match (category, data_type) {
+            (DataTypeCategory::Integers, Some(data_type)) | (DataTypeCategory::Floats, Some(data_type)) => DataTypeSortKey {
+                category,
+                rank: 0,
+                unit_size_in_bytes: data_type.get_unit_size_in_bytes(),
+                endian: data_type.get_endian(),
+                is_boolean: data_type_id == DataTypeBool8::DATA_TYPE_ID || data_type_id == DataTypeBool32::DATA_TYPE_ID,
+                is_signed: data_type.is_signed(),
+                display_name: String::new(),
+                data_type_id: data_type_id.clone(),
+            },
+            _ => DataTypeSortKey {
+                category,
+                rank: Self::get_string_rank(&data_type_id),
+                unit_size_in_bytes: 0,
+                endian: Endian::Little,
+                is_boolean: false,
+                is_signed: false,
+                display_name: display_name.to_lowercase(),
+                data_type_id: data_type_id.clone(),
+            },
+        };
+
+        DataTypeCatalogEntry {
+            aliases: Self::get_aliases(&data_type_id),
+            data_type_id,
+            display_name,
+            category,
+            sort_key,
+        }
+    }
+
+    /// Ranks UTF-8 first, as the most common encoding, followed by the other encodings in their declared order.
+    fn get_string_rank(data_type_id: &str) -> usize {
+        if data_type_id == DataTypeStringUtf8::DATA_TYPE_ID {
+            return 0;
+        }
+
+        TextEncoding::ALL
+            .iter()
+            .position(|text_encoding| text_encoding.get_data_type_id() == data_type_id)
+            .map(|position| position + 1)
+            .unwrap_or(0)
+    }
+
+    /// Gets alternate names that users search for, such as Windows type names (`dword`) and C type names (`int`). All lowercase.
+    fn get_aliases(data_type_id: &str) -> &'static [&'static str] {
+        match data_type_id {
+            DataTypeU8::DATA_TYPE_ID => &["u8", "uint8", "byte", "unsigned char"],
+            DataTypeI8::DATA_TYPE_ID => &["i8", "int8", "sbyte", "char", "signed char"],
+            DataTypeBool8::DATA_TYPE_ID => &["bool", "boolean"],
+            DataTypeU16::DATA_TYPE_ID => &["u16", "uint16", "word", "ushort", "unsigned short"],
+            DataTypeI16::DATA_TYPE_ID => &["i16", "int16", "short"],
+            DataTypeU16be::DATA_TYPE_ID => &["u16", "uint16", "word", "ushort", "big endian"],
+            DataTypeI16be::DATA_TYPE_ID => &["i16", "int16", "short", "big endian"],
+            DataTypeU32::DATA_TYPE_ID => &["u32", "uint32", "dword", "uint", "unsigned int"],
+            DataTypeI32::DATA_TYPE_ID => &["i32", "int32", "int", "integer", "long"],
+            DataTypeBool32::DATA_TYPE_ID => &["bool", "boolean", "bool32", "win32 bool"],
+            DataTypeU32be::DATA_TYPE_ID => &["u32", "uint32", "dword", "uint", "big endian"],
+            DataTypeI32be::DATA_TYPE_ID => &["i32", "int32", "int", "integer", "big endian"],
+            DataTypeU64::DATA_TYPE_ID => &["u64", "uint64", "qword", "ulong", "unsigned long long"],
+            DataTypeI64::DATA_TYPE_ID => &["i64", "int64", "long long", "int64_t"],
+            DataTypeU64be::DATA_TYPE_ID => &["u64", "uint64", "qword", "ulong", "big endian"],
+            DataTypeI64be::DATA_TYPE_ID => &["i64", "int64", "long long", "big endian"],
+            DataTypeF32::DATA_TYPE_ID => &["f32", "float", "single", "real"],
+            DataTypeF32be::DATA_TYPE_ID => &["f32", "float", "single", "real", "big endian"],
+            DataTypeF64::DATA_TYPE_ID => &["f64", "double", "real"],
+            DataTypeF64be::DATA_TYPE_ID => &["f64", "double", "real", "big endian"],
+            DataTypeStringUtf8::DATA_TYPE_ID => &["string", "text", "str", "utf8", "utf-8"],
+            DataTypeStringEncoded::SHIFT_JIS_DATA_TYPE_ID => &["string", "text", "sjis", "shift jis", "japanese"],
+            DataTypeStringEncoded::LATIN1_DATA_TYPE_ID => &["string", "text", "ascii", "latin1", "iso-8859-1"],
+            DataTypeStringEncoded::UTF32_DATA_TYPE_ID => &["string", "text", "utf32", "utf-32", "wide"],
+            DataTypeAob::DATA_TYPE_ID => &["aob", "bytes", "byte array", "signature", "pattern"],
+            _ => &[],
+        }
+    }
+
+    /// Logs a duplicate registration once per id, rather than once per frame that a selector is open.
+    fn report_duplicate_data_type_id(data_type_id: &str) {
+        static REPORTED_DATA_TYPE_IDS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+        let reported_data_type_ids = REPORTED_DATA_TYPE_IDS.get_or_init(|| Mutex::new(HashSet::new()));
+
+        if let Ok(mut reported_data_type_ids) = reported_data_type_ids.lock()
+            && reported_data_type_ids.insert(data_type_id.to_string())
+        {
+            log::warn!(
+                "Data type '{}' is registered more than once. Only the first registration is shown.",
+                data_type_id
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DataTypeCatalog, DataTypeCatalogEntry, DataTypeCategory};
+    use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+
+    fn get_built_in_entries() -> Vec<DataTypeCatalogEntry> {
+        let built_in_data_type_ids = SymbolRegistry::get_instance()
+            .get_data_type_registry()
+            .keys()
+            .cloned()
+            .collect::<Vec<String>>();
+
+        DataTypeCatalog::build_entries(built_in_data_type_ids)
+    }
+
+    #[test]
+    fn built_in_types_have_a_pinned_order() {
+        let data_type_ids = get_built_in_entries()
+            .iter()
+            .map(|entry| entry.get_data_type_id().to_string())
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            data_type_ids,
+            vec![
+                "u8",
+                "i8",
+                "bool8",
+                "u16",
+                "i16",
+                "u16be",
+                "i16be",
+                "u32",
+                "i32",
+                "bool32",
+                "u32be",
+                "i32be",
+                "u64",
+                "i64",
+                "u64be",
+                "i64be",
+                "f32",
+                "f32be",
+                "f64",
+                "f64be",
+                "string_utf8",
+                "string_shift_jis",
+                "string_latin1",
+                "string_utf32",
+                "aob",
+            ]
+        );
+    }
+
+    #[test]
+    fn order_does_not_depend_on_registration_order() {
+        let entries = get_built_in_entries();
+        let reversed_entries = DataTypeCatalog::build_entries(
+            entries
+                .iter()
+                .rev()
+                .map(|entry| entry.get_data_type_id().to_string()),
+        );
+
+        assert_eq!(entries, reversed_entries);
+    }
+
+    #[test]
+    fn duplicate_registrations_are_shown_once() {
+        let entries = DataTypeCatalog::build_entries(["u32", "f32", "u32"].map(str::to_string));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].get_data_type_id(), "u32");
+        assert_eq!(entries[0].get_category(), DataTypeCategory::Integers);
+        assert_eq!(entries[1].get_category(), DataTypeCategory::Floats);
+    }
+
+    #[test]
+    fn search_matches_aliases() {
+        let entries = get_built_in_entries();
+        let dword_matches = entries
+            .iter()
+            .filter(|entry| entry.matches_search("DWORD"))
+            .map(|entry| entry.get_data_type_id())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(dword_matches, vec!["u32", "u32be"]);
+        assert!(
+            entries
+                .iter()
+                .any(|entry| entry.get_data_type_id() == "f64" && entry.matches_search("doub"))
+        );
+    }
+}
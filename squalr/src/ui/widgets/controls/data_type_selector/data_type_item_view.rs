@@ -2,7 +2,7 @@ use crate::{
     app_context::AppContext,
     ui::{draw::icon_draw::IconDraw, widgets::controls::state_layer::StateLayer},
 };
-use eframe::egui::{Align2, Rect, Response, Sense, TextureHandle, Ui, Widget, pos2, vec2};
+use eframe::egui::{Align2, Rect, Response, Sense, TextureHandle, Ui, Widget, WidgetInfo, WidgetType, pos2, vec2};
 use epaint::CornerRadius;
 use std::sync::Arc;
 
@@ -52,6 +52,8 @@ impl<'a> Widget for DataTypeItemView<'a> {
         let row_height = 32.0;
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(vec2(self.combo_box_width, row_height), Sense::click());
 
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, self.label));
+
         // Background and state overlay.
         StateLayer {
             bounds_min: allocated_size_rectangle.min,
@@ -65,7 +67,7 @@ impl<'a> Widget for DataTypeItemView<'a> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
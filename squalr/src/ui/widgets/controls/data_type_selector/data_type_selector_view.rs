@@ -1,19 +1,10 @@
 use crate::ui::converters::data_type_to_string_converter::DataTypeToStringConverter;
 use crate::ui::widgets::controls::combo_box::combo_box_view::ComboBoxView;
+use crate::ui::widgets::controls::data_type_selector::data_type_catalog::DataTypeCatalog;
 use crate::ui::widgets::controls::data_type_selector::data_type_item_view::DataTypeItemView;
 use crate::{app_context::AppContext, ui::converters::data_type_to_icon_converter::DataTypeToIconConverter};
-use eframe::egui::{Id, Response, Ui, Widget};
-use squalr_engine_api::structures::data_types::{
-    built_in_types::{
-        aob::data_type_aob::DataTypeAob, f32::data_type_f32::DataTypeF32, f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64,
-        f64be::data_type_f64be::DataTypeF64be, i8::data_type_i8::DataTypeI8, i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be,
-        i32::data_type_i32::DataTypeI32, i32be::data_type_i32be::DataTypeI32be, i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be,
-        u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32,
-        u32be::data_type_u32be::DataTypeU32be, u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
-        string::utf8::data_type_string_utf8::DataTypeStringUtf8,
-    },
-    data_type_ref::DataTypeRef,
-};
+use eframe::egui::{Id, Response, RichText, TextEdit, Ui, Widget};
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use std::sync::Arc;
 
 /// A widget that allows selecting from a set of data types.
@@ -82,53 +73,82 @@ impl<'lifetime> Widget for DataTypeSelectorView<'lifetime> {
         let element_width = width;
         let data_type_id = self.active_data_type.get_data_type_id();
         let icon = DataTypeToIconConverter::convert_data_type_to_icon(data_type_id, icon_library);
+        let data_type_label = DataTypeToStringConverter::convert_data_type_to_display_string(data_type_id);
+        let search_id = Id::new(("data_type_selector_search", self.menu_id));
 
         let combo_box = ComboBoxView::new(
             self.app_context.clone(),
-            DataTypeToStringConverter::convert_data_type_to_string(data_type_id),
+            &data_type_label,
             self.menu_id,
             Some(icon),
             |popup_user_interface: &mut Ui, should_close: &mut bool| {
+                let catalog_entries = DataTypeCatalog::get_entries();
+
                 popup_user_interface.vertical(|user_interface| {
-                    let mut add_item = |user_interface: &mut Ui, data_type_id: &str| {
+                    let mut search_text = user_interface
+                        .memory(|memory| memory.data.get_temp::<String>(search_id))
+                        .unwrap_or_default();
+
+                    user_interface.add(
+                        TextEdit::singleline(&mut search_text)
+                            .hint_text("Search types (ie dword, float)...")
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .desired_width(element_width),
+                    );
+
+                    let mut previous_category = None;
+                    let mut selected_data_type_id = None;
+
+                    for catalog_entry in catalog_entries
+                        .iter()
+                        .filter(|catalog_entry| catalog_entry.matches_search(&search_text))
+                    {
+                        let category = catalog_entry.get_category();
+
+                        if previous_category != Some(category) {
+                            if previous_category.is_some() {
+                                user_interface.separator();
+                            }
+
+                            user_interface.label(
+                                RichText::new(category.get_display_name())
+                                    .font(theme.font_library.font_noto_sans.font_small.clone())
+                                    .color(theme.foreground_preview),
+                            );
+                            previous_category = Some(category);
+                        }
+
+                        let data_type_id = catalog_entry.get_data_type_id();
+
                         if user_interface
                             .add(DataTypeItemView::new(
                                 self.app_context.clone(),
-                                DataTypeToStringConverter::convert_data_type_to_string(data_type_id),
+                                catalog_entry.get_display_name(),
                                 Some(DataTypeToIconConverter::convert_data_type_to_icon(data_type_id, icon_library)),
                                 element_width,
                             ))
                             .clicked()
                         {
-                            *self.active_data_type = DataTypeRef::new(data_type_id);
-                            *should_close = true;
+                            selected_data_type_id = Some(data_type_id);
                         }
-                    };
-
-                    // CE-style primary types.
-                    add_item(user_interface, DataTypeU8::get_data_type_id());
-                    add_item(user_interface, DataTypeI8::get_data_type_id());
-                    add_item(user_interface, DataTypeU16::get_data_type_id());
-                    add_item(user_interface, DataTypeI16::get_data_type_id());
-                    add_item(user_interface, DataTypeU32::get_data_type_id());
-                    add_item(user_interface, DataTypeI32::get_data_type_id());
-                    add_item(user_interface, DataTypeU64::get_data_type_id());
-                    add_item(user_interface, DataTypeI64::get_data_type_id());
-                    add_item(user_interface, DataTypeF32::get_data_type_id());
-                    add_item(user_interface, DataTypeF64::get_data_type_id());
-                    user_interface.separator();
-                    add_item(user_interface, DataTypeStringUtf8::get_data_type_id());
-                    add_item(user_interface, DataTypeAob::get_data_type_id());
-                    user_interface.separator();
-                    // Big-endian variants (advanced).
-                    add_item(user_interface, DataTypeU16be::get_data_type_id());
-                    add_item(user_interface, DataTypeI16be::get_data_type_id());
-                    add_item(user_interface, DataTypeU32be::get_data_type_id());
-                    add_item(user_interface, DataTypeI32be::get_data_type_id());
-                    add_item(user_interface, DataTypeU64be::get_data_type_id());
-                    add_item(user_interface, DataTypeI64be::get_data_type_id());
-                    add_item(user_interface, DataTypeF32be::get_data_type_id());
-                    add_item(user_interface, DataTypeF64be::get_data_type_id());
+                    }
+
+                    if previous_category.is_none() {
+                        user_interface.label(
+                            RichText::new("No matching types.")
+                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                .color(theme.foreground_preview),
+                        );
+                    }
+
+                    // Start the next opening with the full list.
+                    if let Some(selected_data_type_id) = selected_data_type_id {
+                        *self.active_data_type = DataTypeRef::new(selected_data_type_id);
+                        *should_close = true;
+                        search_text.clear();
+                    }
+
+                    user_interface.memory_mut(|memory| memory.data.insert_temp(search_id, search_text));
                 });
             },
         )
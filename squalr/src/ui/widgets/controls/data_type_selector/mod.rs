@@ -1,2 +1,3 @@
+pub mod data_type_catalog;
 pub mod data_type_item_view;
 pub mod data_type_selector_view;
@@ -1,12 +1,17 @@
 use crate::ui::widgets::controls::state_layer::StateLayer;
 use crate::{app_context::AppContext, ui::widgets::controls::data_value_box::data_value_box_convert_item_view::DataValueBoxConvertItemView};
-use eframe::egui::{Align, Area, Frame, Id, Key, Layout, Order, Response, Sense, TextEdit, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Area, Event, Frame, Id, Key, Layout, Order, Response, Sense, TextEdit, Ui, UiBuilder, Widget, WidgetInfo, WidgetType};
 use epaint::{Color32, CornerRadius, Margin, Rect, Stroke, StrokeKind, Vec2, pos2, vec2};
 use squalr_engine_api::{
     registries::symbols::symbol_registry::SymbolRegistry,
     structures::{
         data_types::data_type_ref::DataTypeRef,
-        data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat},
+        data_values::{
+            anonymous_value_list::{AnonymousValueList, AnonymousValueListError},
+            anonymous_value_string::AnonymousValueString,
+            anonymous_value_string_format::AnonymousValueStringFormat,
+            container_type::ContainerType,
+        },
     },
 };
 use std::sync::Arc;
@@ -17,6 +22,7 @@ pub struct DataValueBoxView<'lifetime> {
     validation_data_type: &'lifetime DataTypeRef,
     is_read_only: bool,
     is_value_owned: bool,
+    allow_value_lists: bool,
     preview_text: &'lifetime str,
     id: &'lifetime str,
     width: f32,
@@ -30,6 +36,7 @@ pub struct DataValueBoxView<'lifetime> {
 
 impl<'lifetime> DataValueBoxView<'lifetime> {
     const MIN_POPUP_WIDTH: f32 = 212.0;
+    const VALUE_LIST_CHIP_PADDING: f32 = 6.0;
 
     pub fn new(
         app_context: Arc<AppContext>,
@@ -46,6 +53,7 @@ impl<'lifetime> DataValueBoxView<'lifetime> {
             validation_data_type,
             is_read_only,
             is_value_owned,
+            allow_value_lists: false,
             preview_text,
             id,
             width: 212.0,
@@ -83,6 +91,31 @@ impl<'lifetime> DataValueBoxView<'lifetime> {
         self.height = height;
         self
     }
+
+    /// Allows a comma or newline separated list of values to be entered, such as for scanning for any of several values.
+    pub fn allow_value_lists(
+        mut self,
+        allow_value_lists: bool,
+    ) -> Self {
+        self.allow_value_lists = allow_value_lists;
+        self
+    }
+
+    /// Gets whether the value box currently accepts a list of values for its data type.
+    fn is_value_list_supported(&self) -> bool {
+        self.allow_value_lists
+            && self.anonymous_value_string.get_container_type() == ContainerType::None
+            && AnonymousValueList::supports_value_lists(self.validation_data_type)
+    }
+
+    /// Parses the entered list of values, if more than one value has been entered, returning the count of distinct values.
+    fn get_value_list_summary(&self) -> Option<Result<usize, AnonymousValueListError>> {
+        if !self.is_value_list_supported() || !AnonymousValueList::is_value_list(self.anonymous_value_string) {
+            return None;
+        }
+
+        Some(AnonymousValueList::deanonymize(self.validation_data_type, self.anonymous_value_string).map(|data_values| data_values.len()))
+    }
 }
 
 impl<'lifetime> Widget for DataValueBoxView<'lifetime> {
@@ -93,7 +126,11 @@ impl<'lifetime> Widget for DataValueBoxView<'lifetime> {
         let theme = &self.app_context.theme;
         let down_arrow = &theme.icon_library.icon_handle_navigation_down_arrow_small;
         let symbol_registry = SymbolRegistry::get_instance();
-        let is_valid = symbol_registry.validate_value_string(&self.validation_data_type, &self.anonymous_value_string);
+        let value_list_summary = self.get_value_list_summary();
+        let is_valid = match &value_list_summary {
+            Some(value_list_result) => value_list_result.is_ok(),
+            None => symbol_registry.validate_value_string(self.validation_data_type, self.anonymous_value_string),
+        };
         let text_color = match is_valid {
             true => match self.anonymous_value_string.get_anonymous_value_string_format() {
                 AnonymousValueStringFormat::Bool => theme.foreground,
@@ -110,6 +147,11 @@ impl<'lifetime> Widget for DataValueBoxView<'lifetime> {
 
         let desired_size = vec2(self.width, self.height);
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(desired_size, Sense::hover());
+        let response = match &value_list_summary {
+            Some(Ok(value_count)) => response.on_hover_text(format!("Matches any of {} values.", value_count)),
+            Some(Err(error)) => response.on_hover_text(error.to_string()),
+            None => response,
+        };
         let icon_size_vec = vec2(self.icon_size, self.icon_size);
 
         // Divider bar before right arrow.
@@ -122,12 +164,92 @@ impl<'lifetime> Widget for DataValueBoxView<'lifetime> {
             pos2(allocated_size_rectangle.max.x, allocated_size_rectangle.max.y),
         );
 
+        // Define editable region (between left label and dropdown divider).
+        let text_edit_rectangle = Rect::from_min_max(
+            pos2(allocated_size_rectangle.min.x, allocated_size_rectangle.min.y),
+            pos2(divider_x, allocated_size_rectangle.max.y),
+        );
+
+        let mut text_edit_rectangle_inner = text_edit_rectangle.shrink2(vec2(4.0, 4.0));
+
+        // Summarize entered value lists with a chip, leaving the remaining space for the text itself.
+        if let Some(value_list_result) = &value_list_summary {
+            let (chip_text, chip_color) = match value_list_result {
+                Ok(value_count) => (format!("{} values", value_count), theme.selected_background),
+                Err(error) => (format!("{} invalid", error.failed_tokens.len().max(1)), theme.background_control_danger),
+            };
+            let chip_galley = user_interface
+                .ctx()
+                .fonts(|fonts| fonts.layout_no_wrap(chip_text, theme.font_library.font_noto_sans.font_small.clone(), theme.foreground));
+            let chip_rectangle = Rect::from_min_size(
+                pos2(
+                    text_edit_rectangle_inner.min.x,
+                    text_edit_rectangle_inner.center().y - chip_galley.size().y * 0.5 - 2.0,
+                ),
+                vec2(chip_galley.size().x + Self::VALUE_LIST_CHIP_PADDING * 2.0, chip_galley.size().y + 4.0),
+            );
+
+            user_interface
+                .painter()
+                .rect_filled(chip_rectangle, CornerRadius::same(4), chip_color);
+            user_interface.painter().galley(
+                pos2(chip_rectangle.min.x + Self::VALUE_LIST_CHIP_PADDING, chip_rectangle.min.y + 2.0),
+                chip_galley,
+                theme.foreground,
+            );
+
+            text_edit_rectangle_inner.min.x = (chip_rectangle.max.x + 4.0).min(text_edit_rectangle_inner.max.x);
+        }
+
+        let mut text_value = self
+            .anonymous_value_string
+            .get_anonymous_value_string()
+            .to_string();
+        let mut text_edit_user_interface = user_interface.new_child(
+            UiBuilder::new()
+                .max_rect(text_edit_rectangle_inner)
+                .layout(Layout::right_to_left(Align::Center)),
+        );
+
+        let text_edit_id = user_interface.make_persistent_id(format!("{}_text_edit", self.id));
+
+        // Single line edits flatten pasted newlines into spaces, so rewrite pasted lines into a comma separated list first.
+        if self.is_value_list_supported() && user_interface.memory(|memory| memory.has_focus(text_edit_id)) {
+            user_interface.input_mut(|input_state| {
+                for event in input_state.events.iter_mut() {
+                    if let Event::Paste(pasted_text) = event
+                        && pasted_text.contains(['\n', '\r'])
+                    {
+                        *pasted_text = AnonymousValueList::split_tokens(pasted_text).join(", ");
+                    }
+                }
+            });
+        }
+
+        let font_id = if text_value.len() > 0 {
+            theme.font_library.font_ubuntu_mono_bold.font_normal.clone()
+        } else {
+            theme.font_library.font_noto_sans.font_normal.clone()
+        };
+        let text_edit_response = text_edit_user_interface.add(
+            TextEdit::singleline(&mut text_value)
+                .id(text_edit_id)
+                .vertical_align(eframe::egui::Align::Center)
+                .font(font_id.clone())
+                .text_color(text_color)
+                .hint_text(self.preview_text)
+                .frame(false),
+        );
+
+        // The dropdown is interacted with after the text edit, such that tabbing reaches the value before its format.
         let button_response = user_interface.interact(
             dropdown_background_rectangle,
             user_interface.make_persistent_id(format!("{}_button", self.id)),
             Sense::click(),
         );
 
+        button_response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, true, "Value format"));
+
         // Arrow position.
         let right_arrow_pos = pos2(
             allocated_size_rectangle.max.x - self.icon_padding - self.icon_size,
@@ -155,43 +277,18 @@ impl<'lifetime> Widget for DataValueBoxView<'lifetime> {
         }
         .paint(user_interface);
 
-        // Define editable region (between left label and dropdown divider).
-        let text_edit_rectangle = Rect::from_min_max(
-            pos2(allocated_size_rectangle.min.x, allocated_size_rectangle.min.y),
-            pos2(divider_x, allocated_size_rectangle.max.y),
-        );
-
-        let text_edit_rectangle_inner = text_edit_rectangle.shrink2(vec2(4.0, 4.0));
-
-        let mut text_value = self
-            .anonymous_value_string
-            .get_anonymous_value_string()
-            .to_string();
-        let mut text_edit_user_interface = user_interface.new_child(
-            UiBuilder::new()
-                .max_rect(text_edit_rectangle_inner)
-                .layout(Layout::right_to_left(Align::Center)),
-        );
-
-        let font_id = if text_value.len() > 0 {
-            theme.font_library.font_ubuntu_mono_bold.font_normal.clone()
+        // The text edit is frameless, so outline the whole box while its value has keyboard focus.
+        let border_stroke = if text_edit_response.has_focus() {
+            Stroke::new(self.border_width.max(StateLayer::FOCUS_RING_WIDTH), theme.focused_border)
         } else {
-            theme.font_library.font_noto_sans.font_normal.clone()
+            Stroke::new(self.border_width, theme.submenu_border)
         };
-        let text_edit_response = text_edit_user_interface.add(
-            TextEdit::singleline(&mut text_value)
-                .vertical_align(eframe::egui::Align::Center)
-                .font(font_id.clone())
-                .text_color(text_color)
-                .hint_text(self.preview_text)
-                .frame(false),
-        );
 
-        if self.border_width > 0.0 {
+        if border_stroke.width > 0.0 {
             user_interface.painter().rect_stroke(
                 allocated_size_rectangle,
                 CornerRadius::same(self.corner_radius),
-                Stroke::new(self.border_width, theme.submenu_border),
+                border_stroke,
                 StrokeKind::Inside,
             );
         }
@@ -1,3 +1,4 @@
+pub mod address_resolution_label;
 pub mod button;
 pub mod check_state;
 pub mod checkbox;
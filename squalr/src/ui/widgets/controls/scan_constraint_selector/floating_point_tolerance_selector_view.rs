@@ -0,0 +1,90 @@
+use crate::app_context::AppContext;
+use crate::ui::widgets::controls::combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView};
+use eframe::egui::{Response, Ui, Widget};
+use squalr_engine_api::structures::data_types::floating_point_tolerance::FloatingPointTolerance;
+use std::sync::Arc;
+
+/// A widget that allows selecting how closely a floating point value must match the scan value.
+pub struct FloatingPointToleranceSelectorView<'lifetime> {
+    app_context: Arc<AppContext>,
+    active_floating_point_tolerance: &'lifetime mut FloatingPointTolerance,
+    menu_id: &'lifetime str,
+    width: f32,
+    height: f32,
+}
+
+impl<'lifetime> FloatingPointToleranceSelectorView<'lifetime> {
+    /// The tolerances offered in the dropdown, from the strictest to the loosest.
+    const SELECTABLE_TOLERANCES: [FloatingPointTolerance; 5] = [
+        FloatingPointTolerance::ToleranceEpsilon,
+        FloatingPointTolerance::Tolerance10E3,
+        FloatingPointTolerance::Tolerance10E2,
+        FloatingPointTolerance::Tolerance10E1,
+        FloatingPointTolerance::ToleranceInteger,
+    ];
+
+    pub fn new(
+        app_context: Arc<AppContext>,
+        active_floating_point_tolerance: &'lifetime mut FloatingPointTolerance,
+        menu_id: &'lifetime str,
+    ) -> Self {
+        Self {
+            app_context,
+            active_floating_point_tolerance,
+            menu_id,
+            width: 136.0,
+            height: 28.0,
+        }
+    }
+
+    fn get_label(floating_point_tolerance: &FloatingPointTolerance) -> &'static str {
+        match floating_point_tolerance {
+            FloatingPointTolerance::ToleranceEpsilon => "Exact",
+            FloatingPointTolerance::Tolerance10E1 => "Within 0.1",
+            FloatingPointTolerance::Tolerance10E2 => "Within 0.01",
+            FloatingPointTolerance::Tolerance10E3 => "Within 0.001",
+            FloatingPointTolerance::Tolerance10E4 => "Within 0.0001",
+            FloatingPointTolerance::Tolerance10E5 => "Within 0.00001",
+            FloatingPointTolerance::ToleranceInteger => "Rounded to integer",
+        }
+    }
+}
+
+impl<'lifetime> Widget for FloatingPointToleranceSelectorView<'lifetime> {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let width = self.width;
+        let active_floating_point_tolerance = self.active_floating_point_tolerance;
+
+        let combo_box = ComboBoxView::new(
+            self.app_context.clone(),
+            Self::get_label(active_floating_point_tolerance),
+            self.menu_id,
+            None,
+            |popup_user_interface: &mut Ui, should_close: &mut bool| {
+                popup_user_interface.vertical(|user_interface| {
+                    for floating_point_tolerance in Self::SELECTABLE_TOLERANCES {
+                        if user_interface
+                            .add(ComboBoxItemView::new(
+                                self.app_context.clone(),
+                                Self::get_label(&floating_point_tolerance),
+                                None,
+                                width,
+                            ))
+                            .clicked()
+                        {
+                            *active_floating_point_tolerance = floating_point_tolerance;
+                            *should_close = true;
+                        }
+                    }
+                });
+            },
+        )
+        .width(width)
+        .height(self.height);
+
+        user_interface.add(combo_box)
+    }
+}
@@ -1,2 +1,3 @@
+pub mod floating_point_tolerance_selector_view;
 pub mod scan_compare_type_item_view;
 pub mod scan_compare_type_selector_view;
@@ -14,12 +14,16 @@ use std::sync::Arc;
 pub struct ScanCompareTypeSelectorView<'lifetime> {
     app_context: Arc<AppContext>,
     active_scan_compare_type: &'lifetime mut ScanCompareType,
+    /// Set for a first scan, in which case an unknown initial value is offered in place of comparisons against previous values.
+    is_unknown_initial_value: Option<&'lifetime mut bool>,
     menu_id: &'lifetime str,
     width: f32,
     height: f32,
 }
 
 impl<'lifetime> ScanCompareTypeSelectorView<'lifetime> {
+    const UNKNOWN_INITIAL_VALUE_LABEL: &'static str = "Unknown initial value";
+
     pub fn new(
         app_context: Arc<AppContext>,
         active_scan_compare_type: &'lifetime mut ScanCompareType,
@@ -28,6 +32,7 @@ impl<'lifetime> ScanCompareTypeSelectorView<'lifetime> {
         Self {
             app_context,
             active_scan_compare_type,
+            is_unknown_initial_value: None,
             menu_id: item_id,
             width: 232.0,
             height: 28.0,
@@ -50,6 +55,15 @@ impl<'lifetime> ScanCompareTypeSelectorView<'lifetime> {
         self
     }
 
+    /// Presents the comparisons available to a first scan, where selecting an unknown initial value sets the given flag.
+    pub fn first_scan(
+        mut self,
+        is_unknown_initial_value: &'lifetime mut bool,
+    ) -> Self {
+        self.is_unknown_initial_value = Some(is_unknown_initial_value);
+        self
+    }
+
     pub fn close(
         &self,
         user_interface: &mut Ui,
@@ -61,6 +75,23 @@ impl<'lifetime> ScanCompareTypeSelectorView<'lifetime> {
         });
     }
 
+    fn select_scan_compare_type(
+        &mut self,
+        scan_compare_type: ScanCompareType,
+    ) {
+        *self.active_scan_compare_type = scan_compare_type;
+
+        if let Some(is_unknown_initial_value) = self.is_unknown_initial_value.as_deref_mut() {
+            *is_unknown_initial_value = false;
+        }
+    }
+
+    fn select_unknown_initial_value(&mut self) {
+        if let Some(is_unknown_initial_value) = self.is_unknown_initial_value.as_deref_mut() {
+            *is_unknown_initial_value = true;
+        }
+    }
+
     fn create_header(
         &self,
         user_interface: &mut Ui,
@@ -97,7 +128,7 @@ impl<'lifetime> ScanCompareTypeSelectorView<'lifetime> {
 
 impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
     fn ui(
-        self,
+        mut self,
         user_interface: &mut Ui,
     ) -> Response {
         let app_context = self.app_context.clone();
@@ -108,84 +139,134 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
         let element_width_left = 160.0;
         let element_width_right = 204.0;
         let total_row_width = element_width_left + element_width_right;
-        let selected_icon = ScanCompareTypeToIconConverter::convert_scan_compare_type_to_icon(&self.active_scan_compare_type, icon_library);
+        let is_first_scan = self.is_unknown_initial_value.is_some();
+        let (selected_label, selected_icon) = if self.is_unknown_initial_value.as_deref() == Some(&true) {
+            (Self::UNKNOWN_INITIAL_VALUE_LABEL, icon_library.icon_handle_scan_collect_values.clone())
+        } else {
+            (
+                ScanCompareTypeToStringConverter::convert_scan_compare_type_to_string(self.active_scan_compare_type),
+                ScanCompareTypeToIconConverter::convert_scan_compare_type_to_icon(self.active_scan_compare_type, icon_library),
+            )
+        };
 
         let combo_box = ComboBoxView::new(
             self.app_context.clone(),
-            ScanCompareTypeToStringConverter::convert_scan_compare_type_to_string(&self.active_scan_compare_type),
+            selected_label,
             self.menu_id,
             Some(selected_icon),
             |popup_user_interface: &mut Ui, should_close: &mut bool| {
                 popup_user_interface.vertical(|user_interface| {
-                    self.create_header(user_interface, "Relative", total_row_width);
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Changed),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
-                                    &ScanCompareTypeRelative::Changed,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Relative(ScanCompareTypeRelative::Changed);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Unchanged),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
-                                    &ScanCompareTypeRelative::Unchanged,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged);
-                            *should_close = true;
-                        };
-                    });
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Increased),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
-                                    &ScanCompareTypeRelative::Increased,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Relative(ScanCompareTypeRelative::Increased);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Decreased),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
-                                    &ScanCompareTypeRelative::Decreased,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Relative(ScanCompareTypeRelative::Decreased);
-                            *should_close = true;
-                        };
-                    });
+                    if !is_first_scan {
+                        self.create_header(user_interface, "Relative", total_row_width);
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Changed),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
+                                        &ScanCompareTypeRelative::Changed,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Relative(ScanCompareTypeRelative::Changed));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Unchanged),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
+                                        &ScanCompareTypeRelative::Unchanged,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Relative(ScanCompareTypeRelative::Unchanged));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Increased),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
+                                        &ScanCompareTypeRelative::Increased,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Relative(ScanCompareTypeRelative::Increased));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(&ScanCompareTypeRelative::Decreased),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
+                                        &ScanCompareTypeRelative::Decreased,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Relative(ScanCompareTypeRelative::Decreased));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_relative_to_string(
+                                        &ScanCompareTypeRelative::DiffersFromFirstScan,
+                                    ),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_relative_to_icon(
+                                        &ScanCompareTypeRelative::DiffersFromFirstScan,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Relative(ScanCompareTypeRelative::DiffersFromFirstScan));
+                                *should_close = true;
+                            };
+                        });
+                    }
+
+                    if is_first_scan {
+                        // A first scan has no previous values to compare against, so it offers collecting every value instead.
+                        self.create_header(user_interface, "First scan", total_row_width);
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    Self::UNKNOWN_INITIAL_VALUE_LABEL,
+                                    Some(icon_library.icon_handle_scan_collect_values.clone()),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_unknown_initial_value();
+                                *should_close = true;
+                            };
+                        });
+                    }
 
                     self.create_header(user_interface, "Immediate", total_row_width);
 
@@ -202,7 +283,7 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal));
                             *should_close = true;
                         };
 
@@ -218,7 +299,7 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual));
                             *should_close = true;
                         };
                     });
@@ -235,7 +316,7 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThan);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThan));
                             *should_close = true;
                         };
 
@@ -251,7 +332,7 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThanOrEqual);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThanOrEqual));
                             *should_close = true;
                         };
                     });
@@ -268,7 +349,7 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan));
                             *should_close = true;
                         };
 
@@ -284,186 +365,205 @@ impl<'lifetime> Widget for ScanCompareTypeSelectorView<'lifetime> {
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual);
-                            *should_close = true;
-                        };
-                    });
-
-                    self.create_header(user_interface, "Delta", total_row_width);
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::IncreasedByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::IncreasedByX,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::IncreasedByX);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::DecreasedByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::DecreasedByX,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::DecreasedByX);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual));
                             *should_close = true;
                         };
                     });
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::MultipliedByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::MultipliedByX,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::MultipliedByX);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::DividedByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::DividedByX,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::DividedByX);
-                            *should_close = true;
-                        };
-                    });
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ModuloByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::ModuloByX,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::ModuloByX);
-                            *should_close = true;
-                        };
-                    });
-
-                    self.create_header(user_interface, "Binary", total_row_width);
-
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ShiftLeftByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::ShiftLeftByX,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::ShiftLeftByX);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ShiftRightByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::ShiftRightByX,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::ShiftRightByX);
-                            *should_close = true;
-                        };
-                    });
-
                     user_interface.horizontal(|user_interface| {
                         if user_interface
                             .add(ScanCompareTypeItemView::new(
                                 self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalAndByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::LogicalAndByX,
+                                ScanCompareTypeToStringConverter::convert_scan_compare_type_immediate_to_string(&ScanCompareTypeImmediate::Between),
+                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_immediate_to_icon(
+                                    &ScanCompareTypeImmediate::Between,
                                     icon_library,
                                 )),
                                 element_width_left,
                             ))
                             .clicked()
                         {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::LogicalAndByX);
-                            *should_close = true;
-                        };
-
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalOrByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::LogicalOrByX,
-                                    icon_library,
-                                )),
-                                element_width_right,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::LogicalOrByX);
+                            self.select_scan_compare_type(ScanCompareType::Immediate(ScanCompareTypeImmediate::Between));
                             *should_close = true;
                         };
                     });
 
-                    user_interface.horizontal(|user_interface| {
-                        if user_interface
-                            .add(ScanCompareTypeItemView::new(
-                                self.app_context.clone(),
-                                ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalXorByX),
-                                Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
-                                    &ScanCompareTypeDelta::LogicalXorByX,
-                                    icon_library,
-                                )),
-                                element_width_left,
-                            ))
-                            .clicked()
-                        {
-                            *self.active_scan_compare_type = ScanCompareType::Delta(ScanCompareTypeDelta::LogicalXorByX);
-                            *should_close = true;
-                        };
-                    });
+                    if !is_first_scan {
+                        self.create_header(user_interface, "Delta", total_row_width);
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::IncreasedByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::IncreasedByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::IncreasedByX));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::DecreasedByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::DecreasedByX,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::DecreasedByX));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::MultipliedByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::MultipliedByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::MultipliedByX));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::DividedByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::DividedByX,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::DividedByX));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ModuloByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::ModuloByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::ModuloByX));
+                                *should_close = true;
+                            };
+                        });
+
+                        self.create_header(user_interface, "Binary", total_row_width);
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ShiftLeftByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::ShiftLeftByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::ShiftLeftByX));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::ShiftRightByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::ShiftRightByX,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::ShiftRightByX));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalAndByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::LogicalAndByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalAndByX));
+                                *should_close = true;
+                            };
+
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalOrByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::LogicalOrByX,
+                                        icon_library,
+                                    )),
+                                    element_width_right,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalOrByX));
+                                *should_close = true;
+                            };
+                        });
+
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(ScanCompareTypeItemView::new(
+                                    self.app_context.clone(),
+                                    ScanCompareTypeToStringConverter::convert_scan_compare_type_delta_to_string(&ScanCompareTypeDelta::LogicalXorByX),
+                                    Some(ScanCompareTypeToIconConverter::convert_scan_compare_type_delta_to_icon(
+                                        &ScanCompareTypeDelta::LogicalXorByX,
+                                        icon_library,
+                                    )),
+                                    element_width_left,
+                                ))
+                                .clicked()
+                            {
+                                self.select_scan_compare_type(ScanCompareType::Delta(ScanCompareTypeDelta::LogicalXorByX));
+                                *should_close = true;
+                            };
+                        });
+                    }
                 });
             },
         )
@@ -21,6 +21,9 @@ pub struct StateLayer {
 }
 
 impl StateLayer {
+    /// The width of the outline drawn around focused controls, such that keyboard focus stays visible on borderless controls.
+    pub const FOCUS_RING_WIDTH: f32 = 2.0;
+
     pub fn paint(
         self,
         user_interface: &mut Ui,
@@ -48,14 +51,19 @@ impl StateLayer {
             .painter()
             .rect_filled(bounds_rect, self.corner_radius, fill);
 
-        // Draw the border.
-        if self.border_width > 0.0 {
-            let border_color = if self.has_focus { self.border_color_focused } else { self.border_color };
-
+        // Draw the border, or the focus ring in its place if this control has keyboard focus.
+        if self.enabled && self.has_focus {
+            user_interface.painter().rect_stroke(
+                bounds_rect,
+                self.corner_radius,
+                Stroke::new(self.border_width.max(Self::FOCUS_RING_WIDTH), self.border_color_focused),
+                StrokeKind::Inside,
+            );
+        } else if self.border_width > 0.0 {
             user_interface.painter().rect_stroke(
                 bounds_rect,
                 self.corner_radius,
-                Stroke::new(self.border_width, border_color),
+                Stroke::new(self.border_width, self.border_color),
                 StrokeKind::Inside,
             );
         }
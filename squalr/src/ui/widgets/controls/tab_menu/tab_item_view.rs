@@ -1,6 +1,6 @@
 use crate::app_context::AppContext;
 use crate::ui::widgets::controls::state_layer::StateLayer;
-use eframe::egui::{Response, Sense, Ui, Widget};
+use eframe::egui::{Response, Sense, Ui, Widget, WidgetInfo, WidgetType};
 use epaint::{CornerRadius, Stroke, StrokeKind, pos2, vec2};
 use std::sync::Arc;
 
@@ -54,6 +54,8 @@ impl<'lifetime> Widget for TabItemView<'lifetime> {
             (self.height.max(0.0)).max(text_size.y + 2.0 * padding_vertical),
         );
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(desired, Sense::click());
+
+        response.widget_info(|| WidgetInfo::selected(WidgetType::SelectableLabel, true, self.is_selected, self.header));
         let corner_radius = CornerRadius { nw: 4, ne: 4, sw: 0, se: 0 };
 
         user_interface.painter().rect(
@@ -86,7 +88,7 @@ impl<'lifetime> Widget for TabItemView<'lifetime> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_primary_dark,
-            border_color_focused: theme.background_control_primary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
@@ -69,7 +69,7 @@ impl<'lifetime> Widget for ToolbarHeaderItemView<'lifetime> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_primary_light,
-            border_color_focused: theme.background_control_primary_light,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
@@ -67,7 +67,7 @@ impl<'a> Widget for ToolbarMenuItemView<'a> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
@@ -1,3 +1,4 @@
+use crate::ui::frame_profiler::FrameProfiler;
 use crate::{app_context::AppContext, ui::widgets::docking::dock_root_view_data::DockRootViewData};
 use eframe::egui::{Response, Sense, Ui, UiBuilder, Widget};
 use epaint::{CornerRadius, Rect, pos2, vec2};
@@ -25,6 +26,7 @@ impl Widget for DockRootView {
     ) -> Response {
         let (available_size_rect, response) = user_interface.allocate_exact_size(user_interface.available_size(), Sense::empty());
         let theme = &self.app_context.theme;
+        let frame_profiler = FrameProfiler::get_instance();
         let docking_manager = &self.app_context.docking_manager;
         let windows = match self.dock_view_data.windows.read() {
             Ok(windows) => windows,
@@ -39,7 +41,18 @@ impl Widget for DockRootView {
             .painter()
             .rect_filled(available_size_rect, CornerRadius::ZERO, theme.background_panel);
 
+        // Commands queued by views since the last frame are applied here, before anything below reads the layout.
         if let Ok(mut docking_manager) = docking_manager.write() {
+            {
+                let _profiler_scope = frame_profiler.scope("Docking command drain");
+
+                self.app_context
+                    .docking_commands
+                    .apply_pending(&mut docking_manager);
+            }
+
+            let _profiler_scope = frame_profiler.scope("Docking layout");
+
             docking_manager.prepare_for_presentation();
             docking_manager
                 .get_main_window_layout_mut()
@@ -74,6 +87,7 @@ impl Widget for DockRootView {
             if let Some(window_rect) = window_rect {
                 let builder = UiBuilder::new().max_rect(window_rect);
                 let mut child_user_interface = user_interface.new_child(builder);
+                let _profiler_scope = frame_profiler.scope(window_identifier);
 
                 window.ui(&mut child_user_interface);
             }
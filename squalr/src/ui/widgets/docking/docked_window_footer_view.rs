@@ -1,5 +1,6 @@
 use crate::{
     app_context::AppContext,
+    models::docking::docking_command::DockingCommand,
     ui::widgets::{controls::button::Button, docking::dock_root_view_data::DockRootViewData},
 };
 use eframe::egui::{Align, Align2, Layout, Response, Sense, Ui, UiBuilder, Widget};
@@ -84,10 +85,15 @@ impl Widget for DockedWindowFooterView {
             if response.rect.is_positive() {
                 for window in windows.iter() {
                     if window.get_identifier() == sibling_id {
+                        let tab_title = match docking_manager.get_window_badge(&sibling_id) {
+                            Some(badge) => format!("{} ({})", window.get_title(), badge),
+                            None => window.get_title().to_string(),
+                        };
+
                         child_user_interface.painter().text(
                             response.rect.center(),
                             Align2::CENTER_CENTER,
-                            window.get_title(),
+                            tab_title,
                             theme.font_library.font_noto_sans.font_header.clone(),
                             theme.foreground,
                         );
@@ -103,12 +109,8 @@ impl Widget for DockedWindowFooterView {
         }
 
         if let Some(selected_tab_id) = selected_tab_id {
-            // Free read lock so that we can acquire write lock.
-            drop(docking_manager);
-
-            if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                docking_manager.select_tab_by_window_id(&selected_tab_id);
-            }
+            self.app_context
+                .queue_docking_command(DockingCommand::FocusWindow { window_id: selected_tab_id });
         }
 
         response
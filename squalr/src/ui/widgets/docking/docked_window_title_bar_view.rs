@@ -1,4 +1,5 @@
 use crate::app_context::AppContext;
+use crate::models::docking::docking_command::DockingCommand;
 use crate::ui::draw::icon_draw::IconDraw;
 use crate::ui::widgets::controls::button::Button;
 use eframe::egui::{Align, Id, Layout, Rect, Response, RichText, Sense, Ui, UiBuilder, Widget, pos2};
@@ -71,6 +72,22 @@ impl Widget for DockedWindowTitleBarView {
                 .font(theme.font_library.font_noto_sans.font_window_title.clone()),
         );
 
+        let badge = match docking_manager.read() {
+            Ok(docking_manager) => docking_manager
+                .get_window_badge(&self.identifier)
+                .map(str::to_string),
+            Err(_) => None,
+        };
+
+        if let Some(badge) = badge {
+            child_user_interface.add_space(6.0);
+            child_user_interface.label(
+                RichText::new(badge)
+                    .color(theme.foreground_preview)
+                    .font(theme.font_library.font_noto_sans.font_small.clone()),
+            );
+        }
+
         child_user_interface.add_space(child_user_interface.available_width());
 
         // Buttons aligned right-to-left.
@@ -82,12 +99,11 @@ impl Widget for DockedWindowTitleBarView {
             IconDraw::draw(ui, close.rect, &theme.icon_library.icon_handle_close);
 
             if close.clicked() {
-                if let Ok(mut docking_manager) = docking_manager.try_write() {
-                    // Hide the window and immediately validate tab selection so we don't end up with
-                    // an invisible active tab (blank content area).
-                    docking_manager.set_window_visible(&self.identifier, false);
-                    docking_manager.prepare_for_presentation();
-                }
+                // Tab selection is validated when the command is applied, so an invisible tab is never left active.
+                self.app_context.queue_docking_command(DockingCommand::ShowWindow {
+                    window_id: self.identifier.to_string(),
+                    is_visible: false,
+                });
             }
         });
 
@@ -1,3 +1,4 @@
+use crate::models::crash_recovery::crash_context::CrashContext;
 use crate::models::docking::hierarchy::types::dock_splitter_drag_direction::DockSplitterDragDirection;
 use crate::ui::widgets::docking::dock_root_view_data::DockRootViewData;
 use crate::ui::widgets::docking::docked_window_footer_view::DockedWindowFooterView;
@@ -174,7 +175,10 @@ impl<W: Widget> Widget for DockedWindowView<W> {
                         .layout(Layout::left_to_right(Align::Min)),
                 );
 
+                // Panics inside a window are reported against it, which narrows down the view at fault.
+                CrashContext::set_active_window_id(Some(&self.identifier));
                 content_user_interface.add(self.widget);
+                CrashContext::set_active_window_id(None);
 
                 // Finally render the footer if one exists. Currently only shown if there are multiple tabs stacked.
                 if has_footer {
@@ -1,9 +1,15 @@
 use crate::app_context::AppContext;
+use crate::ui::line_selection::LineSelection;
+use crate::ui::widgets::controls::address_resolution_label::AddressResolutionLabel;
 use crate::ui::widgets::controls::button::Button;
 use crate::views::disassembler::view_data::disassembler_view_data::DisassemblerViewData;
-use eframe::egui::{Align, Color32, Direction, Layout, Response, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, Widget, vec2};
+use crate::views::main_window::view_data::add_to_project_view_data::{AddToProjectEntryKind, AddToProjectViewData};
+use eframe::egui::{Align, Color32, Direction, Key, Layout, Response, RichText, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, Widget, Window, vec2};
 use epaint::{CornerRadius, Rect, Stroke, StrokeKind, pos2};
 use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -14,15 +20,97 @@ pub struct DisassemblerView {
 
 impl DisassemblerView {
     pub const WINDOW_ID: &'static str = "window_disassembler";
+    pub const BREAKPOINT_GUTTER_WIDTH: f32 = 16.0;
+    pub const BREAKPOINT_GUTTER_TOOLTIP: &'static str =
+        "Toggle a software breakpoint. Hits are not yet caught by a debugger, so executing one raises an exception in the process.";
 
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let disassembler_view_data = DisassemblerViewData::register(&app_context);
 
+        DisassemblerViewData::listen_for_symbols_loaded(disassembler_view_data.clone(), app_context.engine_unprivileged_state.clone());
+        DisassemblerViewData::listen_for_breakpoints_changed(disassembler_view_data.clone(), app_context.engine_unprivileged_state.clone());
+        DisassemblerViewData::listen_for_code_patches_changed(disassembler_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
         Self {
             app_context,
             disassembler_view_data,
         }
     }
+
+    /// Shows the dialog that assembles an instruction over the one it was opened on.
+    fn show_assemble_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let is_assemble_dialog_open = self
+            .disassembler_view_data
+            .read("Disassembler assemble dialog state")
+            .map(|disassembler_view_data| disassembler_view_data.assemble_dialog.is_some())
+            .unwrap_or(false);
+
+        if !is_assemble_dialog_open {
+            return;
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_assemble = false;
+        let mut should_close = false;
+
+        Window::new("Assemble")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut disassembler_view_data = match self
+                    .disassembler_view_data
+                    .write("Disassembler assemble dialog")
+                {
+                    Some(disassembler_view_data) => disassembler_view_data,
+                    None => return,
+                };
+                let Some(assemble_dialog) = disassembler_view_data.assemble_dialog.as_mut() else {
+                    return;
+                };
+
+                user_interface.label(format!(
+                    "Replaces the {} byte instruction at {:X}. Shorter instructions are padded with nop.",
+                    assemble_dialog.length, assemble_dialog.address
+                ));
+
+                let response = user_interface.add(
+                    TextEdit::singleline(&mut assemble_dialog.instruction_input)
+                        .hint_text("mov eax, 1")
+                        .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                        .desired_width(320.0),
+                );
+
+                if response.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                    should_assemble = true;
+                }
+
+                if let Some(error_message) = &assemble_dialog.error_message {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+
+                    if user_interface
+                        .add_enabled(!assemble_dialog.is_assembling, eframe::egui::Button::new("Assemble"))
+                        .clicked()
+                    {
+                        should_assemble = true;
+                    }
+                });
+            });
+
+        if should_close {
+            DisassemblerViewData::close_assemble_dialog(self.disassembler_view_data.clone());
+        } else if should_assemble {
+            DisassemblerViewData::assemble_and_patch(self.disassembler_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+    }
 }
 
 impl Widget for DisassemblerView {
@@ -32,6 +120,13 @@ impl Widget for DisassemblerView {
     ) -> Response {
         let theme = &self.app_context.theme;
         let mut should_refresh = false;
+        let mut should_copy_selected_lines = false;
+        let mut should_copy_instruction_bytes = false;
+        let mut toggle_breakpoint_address: Option<u64> = None;
+        let mut replace_with_nops_line: Option<(u64, usize)> = None;
+        let mut assemble_line: Option<(u64, usize, String)> = None;
+        let mut restore_code_address: Option<u64> = None;
+        let mut add_to_project_entry: Option<(AddToProjectEntryKind, u64, String, DataTypeRef)> = None;
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
@@ -52,14 +147,23 @@ impl Widget for DisassemblerView {
                 };
 
                 let address_box = Rect::from_min_size(pos2(toolbar_rect.min.x + 8.0, toolbar_rect.min.y + 4.0), vec2(180.0, 28.0));
-                let _address_response = toolbar_ui.put(
+                let address_response = toolbar_ui.put(
                     address_box,
                     TextEdit::singleline(&mut disassembler_view_data.address_input)
+                        .hint_text("game.exe+1A0")
                         .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
                         .text_color(theme.hexadecimal_green)
                         .background_color(theme.background_primary),
                 );
 
+                if address_response.changed() {
+                    disassembler_view_data.address_resolution = None;
+                }
+
+                if address_response.lost_focus() && toolbar_ui.input(|input_state| input_state.key_pressed(Key::Enter)) {
+                    should_refresh = true;
+                }
+
                 toolbar_ui.painter().rect_stroke(
                     address_box,
                     CornerRadius::ZERO,
@@ -89,6 +193,11 @@ impl Widget for DisassemblerView {
 
                 toolbar_ui.label("Disassemble");
 
+                if let Some(address_resolution) = &disassembler_view_data.address_resolution {
+                    toolbar_ui.add_space(8.0);
+                    toolbar_ui.add(AddressResolutionLabel::new(theme, address_resolution));
+                }
+
                 drop(disassembler_view_data);
 
                 user_interface.add_space(4.0);
@@ -104,9 +213,19 @@ impl Widget for DisassemblerView {
                     let is_loading = disassembler_view_data.is_loading;
                     let error_message = disassembler_view_data.error_message.clone();
                     let lines = disassembler_view_data.lines.clone();
-                    let module_name_present = disassembler_view_data.module_name.is_some();
+                    let module_name = disassembler_view_data.module_name.clone();
+                    let module_base = disassembler_view_data.module_base;
+                    let module_name_present = module_name.is_some();
                     let highlight_address = disassembler_view_data.highlight_address;
                     let highlight_pending = disassembler_view_data.highlight_pending;
+                    let breakpoint_addresses = disassembler_view_data.breakpoint_addresses.clone();
+                    let patched_line_addresses: HashSet<u64> = lines
+                        .iter()
+                        .map(|line| line.address)
+                        .filter(|address| disassembler_view_data.is_patched(*address))
+                        .collect();
+                    let original_line_selection = disassembler_view_data.line_selection.clone();
+                    let mut line_selection = original_line_selection.clone();
 
                     drop(disassembler_view_data);
 
@@ -123,15 +242,15 @@ impl Widget for DisassemblerView {
                         return;
                     }
 
+                    // Errors, ie a breakpoint that could not be written, are shown above the lines rather than replacing them.
                     if let Some(error_message) = &error_message {
                         user_interface.allocate_ui_with_layout(
                             vec2(user_interface.available_width(), 32.0),
                             Layout::centered_and_justified(Direction::LeftToRight),
                             |ui| {
-                                ui.label(error_message);
+                                ui.label(eframe::egui::RichText::new(error_message).color(theme.error_red));
                             },
                         );
-                        return;
                     }
 
                     let address_width = 180.0;
@@ -148,27 +267,50 @@ impl Widget for DisassemblerView {
                         return;
                     }
 
-                    for line in &lines {
+                    for (line_index, line) in lines.iter().enumerate() {
                         let is_highlighted = highlight_address == Some(line.address);
-                        let row_response = user_interface
+                        let is_selected = line_selection.is_selected(line_index);
+                        let has_breakpoint = breakpoint_addresses.contains(&line.address);
+                        let is_patched = patched_line_addresses.contains(&line.address);
+                        let row_inner_response = user_interface
                             .allocate_ui_with_layout(
                                 vec2(user_interface.available_width(), 20.0),
                                 Layout::left_to_right(Align::Min),
                                 |ui| {
                                     let row_rect = ui.available_rect_before_wrap();
+
+                                    // The patch tint is painted first, then the selection, such that the current address highlight stays
+                                    // visible over both.
+                                    if is_patched {
+                                        ui.painter()
+                                            .rect_filled(row_rect, 0.0, theme.background_control_warning_dark);
+                                    }
+                                    if is_selected {
+                                        ui.painter().rect_filled(row_rect, 0.0, theme.background_control_primary_dark);
+                                    }
                                     if is_highlighted {
                                         ui.painter().rect_filled(row_rect, 0.0, theme.selected_background);
                                     }
 
+                                    let (gutter_rect, _) = ui.allocate_exact_size(vec2(Self::BREAKPOINT_GUTTER_WIDTH, 20.0), Sense::hover());
+
+                                    if has_breakpoint {
+                                        ui.painter()
+                                            .circle_filled(gutter_rect.center(), 4.0, theme.error_red);
+                                    }
+
                                     let address_resp = ui.add_sized(
                                         vec2(address_width, 20.0),
                                         eframe::egui::Label::new(
-                                            eframe::egui::RichText::new(&line.display_address)
+                                            eframe::egui::RichText::new(line.get_display_address())
                                                 .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
                                                 .color(theme.hexadecimal_green),
-                                        ),
+                                        )
+                                        .selectable(false),
                                     );
-                                    if module_name_present {
+                                    if line.symbol.is_some() {
+                                        let _address_resp = address_resp.on_hover_text(format!("{}\n0x{:016X}", line.display_address, line.address));
+                                    } else if module_name_present {
                                         let _address_resp = address_resp.on_hover_text(format!("0x{:016X}", line.address));
                                     }
 
@@ -178,17 +320,40 @@ impl Widget for DisassemblerView {
                                             eframe::egui::RichText::new(&line.bytes)
                                                 .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
                                                 .color(theme.foreground),
-                                        ),
+                                        )
+                                        .selectable(false),
                                     );
 
-                                    ui.label(
-                                        eframe::egui::RichText::new(&line.instruction)
-                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
-                                            .color(theme.foreground),
+                                    ui.add(
+                                        eframe::egui::Label::new(
+                                            eframe::egui::RichText::new(line.get_display_instruction())
+                                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                                .color(theme.foreground),
+                                        )
+                                        .selectable(false),
                                     );
+
+                                    gutter_rect
                                 },
+                            );
+                        let row_rect = row_inner_response.response.rect;
+                        let row_response =
+                            user_interface.interact(row_rect, user_interface.id().with(("disassembler_line", line_index)), Sense::click_and_drag());
+
+                        line_selection.handle_row_interaction(user_interface, &row_response, line_index);
+
+                        // The gutter is interacted with after the row, such that clicking it toggles a breakpoint instead of selecting the line.
+                        let gutter_response = user_interface
+                            .interact(
+                                row_inner_response.inner,
+                                user_interface.id().with(("disassembler_breakpoint_gutter", line_index)),
+                                Sense::click(),
                             )
-                            .response;
+                            .on_hover_text(Self::BREAKPOINT_GUTTER_TOOLTIP);
+
+                        if gutter_response.clicked() {
+                            toggle_breakpoint_address = Some(line.address);
+                        }
 
                         if highlight_pending && is_highlighted {
                             row_response.scroll_to_me(Some(Align::Center));
@@ -196,6 +361,15 @@ impl Widget for DisassemblerView {
                         }
 
                         row_response.context_menu(|ui| {
+                            if ui.button("Copy selected lines").clicked() {
+                                should_copy_selected_lines = true;
+                                ui.close();
+                            }
+                            if ui.button("Copy instruction bytes").clicked() {
+                                should_copy_instruction_bytes = true;
+                                ui.close();
+                            }
+                            ui.separator();
                             if ui.button("Copy address").clicked() {
                                 ui.ctx().copy_text(line.display_address.clone());
                                 ui.close();
@@ -212,6 +386,48 @@ impl Widget for DisassemblerView {
                                 ui.ctx().copy_text(line.instruction.clone());
                                 ui.close();
                             }
+                            ui.separator();
+                            if ui.button("Replace with NOPs").clicked() {
+                                replace_with_nops_line = Some((line.address, line.length));
+                                ui.close();
+                            }
+                            if ui.button("Assemble...").clicked() {
+                                assemble_line = Some((line.address, line.length, line.instruction.clone()));
+                                ui.close();
+                            }
+                            if is_patched && ui.button("Restore original code").clicked() {
+                                restore_code_address = Some(line.address);
+                                ui.close();
+                            }
+                            ui.separator();
+                            if ui.button("Add this address to project...").clicked() {
+                                let (address, module) = match (&module_name, module_base) {
+                                    (Some(module_name), Some(module_base)) => (line.address.saturating_sub(module_base), module_name.clone()),
+                                    _ => (line.address, String::new()),
+                                };
+
+                                add_to_project_entry = Some((
+                                    AddToProjectEntryKind::CodeBookmark,
+                                    address,
+                                    module,
+                                    DataTypeRef::new(DataTypeU8::get_data_type_id()),
+                                ));
+                                ui.close();
+                            }
+                            // Only operands with a static address, ie RIP relative globals, can be added as address entries.
+                            if let Some(memory_operand) = &line.memory_operand {
+                                let add_operand_response = ui.button(format!("Add memory operand {:X} to project...", memory_operand.address));
+
+                                if add_operand_response.clicked() {
+                                    add_to_project_entry = Some((
+                                        AddToProjectEntryKind::Address,
+                                        memory_operand.address,
+                                        String::new(),
+                                        memory_operand.data_type.clone(),
+                                    ));
+                                    ui.close();
+                                }
+                            }
                         });
                     }
 
@@ -220,14 +436,81 @@ impl Widget for DisassemblerView {
                             data.highlight_pending = false;
                         }
                     }
+
+                    if user_interface.ui_contains_pointer() && LineSelection::is_copy_requested(user_interface) {
+                        should_copy_selected_lines = true;
+                    }
+
+                    if line_selection != original_line_selection
+                        && let Some(mut data) = self.disassembler_view_data.write("Disassembler update line selection")
+                    {
+                        data.line_selection = line_selection;
+                    }
                 });
             })
             .response;
 
+        if should_copy_selected_lines || should_copy_instruction_bytes {
+            let copy_text = self
+                .disassembler_view_data
+                .read("Disassembler copy selection")
+                .and_then(|disassembler_view_data| {
+                    if should_copy_instruction_bytes {
+                        disassembler_view_data.get_selected_instruction_bytes()
+                    } else {
+                        disassembler_view_data.get_selected_lines_text()
+                    }
+                });
+
+            if let Some(copy_text) = copy_text {
+                user_interface.ctx().copy_text(copy_text);
+            }
+        }
+
+        if let Some(toggle_breakpoint_address) = toggle_breakpoint_address {
+            DisassemblerViewData::toggle_breakpoint(
+                self.disassembler_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                toggle_breakpoint_address,
+            );
+        }
+
+        if let Some((address, length)) = replace_with_nops_line {
+            DisassemblerViewData::replace_with_nops(
+                self.disassembler_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                address,
+                length,
+            );
+        }
+
+        if let Some((address, length, instruction)) = assemble_line {
+            DisassemblerViewData::open_assemble_dialog(self.disassembler_view_data.clone(), address, length, instruction);
+        }
+
+        if let Some(restore_code_address) = restore_code_address {
+            DisassemblerViewData::restore_code_patch(
+                self.disassembler_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                restore_code_address,
+            );
+        }
+
+        self.show_assemble_dialog(user_interface);
+
         if should_refresh {
             DisassemblerViewData::refresh(self.disassembler_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
         }
 
+        if let Some((entry_kind, address, module, data_type)) = add_to_project_entry {
+            let add_to_project_view_data = self
+                .app_context
+                .dependency_container
+                .get_dependency::<AddToProjectViewData>();
+
+            AddToProjectViewData::open_dialog(add_to_project_view_data, entry_kind, address, &module, data_type);
+        }
+
         response
     }
 }
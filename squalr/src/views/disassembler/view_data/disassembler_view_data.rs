@@ -1,54 +1,172 @@
 use crate::app_context::AppContext;
-use iced_x86::{Decoder, DecoderOptions, Formatter, IntelFormatter};
+use crate::models::address_resolution::address_resolver::{AddressResolution, AddressResolver};
+use crate::ui::line_selection::LineSelection;
+use iced_x86::{Decoder, DecoderOptions, Formatter, Instruction, IntelFormatter, MemorySize, OpKind, Register};
+use squalr_engine_api::commands::breakpoints::list::breakpoints_list_request::BreakpointsListRequest;
+use squalr_engine_api::commands::breakpoints::remove::breakpoint_remove_request::BreakpointRemoveRequest;
+use squalr_engine_api::commands::breakpoints::set::breakpoint_set_request::BreakpointSetRequest;
+use squalr_engine_api::commands::code::assemble::code_assemble_request::CodeAssembleRequest;
+use squalr_engine_api::commands::code::list::code_patches_list_request::CodePatchesListRequest;
+use squalr_engine_api::commands::code::patch::code_patch_request::CodePatchRequest;
+use squalr_engine_api::commands::code::restore::code_restore_request::CodeRestoreRequest;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
-use squalr_engine_api::commands::memory::regions::memory_regions_request::MemoryRegionsRequest;
-use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::commands::memory::resolve_symbols::memory_resolve_symbols_request::MemoryResolveSymbolsRequest;
 use squalr_engine_api::dependency_injection::dependency::Dependency;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
-use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::events::breakpoints::changed::breakpoints_changed_event::BreakpointsChangedEvent;
+use squalr_engine_api::events::code_patches::changed::code_patches_changed_event::CodePatchesChangedEvent;
+use squalr_engine_api::events::module_symbols::loaded::module_symbols_loaded_event::ModuleSymbolsLoadedEvent;
+use squalr_engine_api::structures::data_types::built_in_types::{
+    f32::data_type_f32::DataTypeF32, f64::data_type_f64::DataTypeF64, i8::data_type_i8::DataTypeI8, i16::data_type_i16::DataTypeI16,
+    i32::data_type_i32::DataTypeI32, i64::data_type_i64::DataTypeI64, u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16,
+    u32::data_type_u32::DataTypeU32, u64::data_type_u64::DataTypeU64,
+};
 use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::memory::code_patch::CodePatch;
 use squalr_engine_api::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
 use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// The single byte x86 `nop` instruction, used to blank out code and to pad assembled instructions to the length they replace.
+const NOP_OPCODE: u8 = 0x90;
+
+/// A memory operand whose address is known without executing the instruction, ie an absolute or RIP relative operand.
+#[derive(Clone)]
+pub struct DisassemblerMemoryOperand {
+    pub address: u64,
+    pub data_type: DataTypeRef,
+}
+
 #[derive(Clone, Default)]
 pub struct DisassemblerLine {
     pub address: u64,
+    /// The length of the instruction in bytes, which is how many bytes replacing it may write.
+    pub length: usize,
     pub display_address: String,
     pub bytes: String,
     pub instruction: String,
+    pub memory_operand: Option<DisassemblerMemoryOperand>,
+    /// The branch target or static memory operand of the instruction, if any.
+    pub reference_address: Option<u64>,
+    /// The module symbol of this line's address, once the containing module's symbols have loaded.
+    pub symbol: Option<String>,
+    /// The module symbol of the reference address, once the containing module's symbols have loaded.
+    pub reference_symbol: Option<String>,
+}
+
+impl DisassemblerLine {
+    /// Gets the address as shown, preferring the symbol over the module offset or raw address.
+    pub fn get_display_address(&self) -> &str {
+        self.symbol.as_deref().unwrap_or(&self.display_address)
+    }
+
+    /// Gets the instruction as shown, annotated with the symbol of the address it references.
+    pub fn get_display_instruction(&self) -> String {
+        match &self.reference_symbol {
+            Some(reference_symbol) => format!("{}  ; {}", self.instruction, reference_symbol),
+            None => self.instruction.clone(),
+        }
+    }
+
+    /// Gets the cells of this line as they are laid out on screen.
+    pub fn get_columns(&self) -> Vec<String> {
+        vec![
+            self.get_display_address().to_string(),
+            self.bytes.trim().to_string(),
+            self.get_display_instruction(),
+        ]
+    }
+}
+
+/// The state of the dialog that assembles an instruction over an existing one.
+#[derive(Clone, Default)]
+pub struct DisassemblerAssembleDialog {
+    pub address: u64,
+    /// The length of the instruction being replaced. Shorter instructions are padded with `nop`, and longer ones are rejected.
+    pub length: usize,
+    pub instruction_input: String,
+    pub error_message: Option<String>,
+    pub is_assembling: bool,
 }
 
 #[derive(Clone)]
 pub struct DisassemblerViewData {
     pub address_input: String,
+    /// The outcome of resolving the committed address input, shown beside it.
+    pub address_resolution: Option<AddressResolution>,
     pub base_address: u64,
     pub module_name: Option<String>,
     pub module_base: Option<u64>,
     pub highlight_address: Option<u64>,
     pub highlight_pending: bool,
     pub lines: Vec<DisassemblerLine>,
+    pub line_selection: LineSelection,
     pub is_loading: bool,
     pub error_message: Option<String>,
     pub read_size: usize,
+    /// The addresses of all software breakpoints set in the opened process, as last reported by the engine.
+    pub breakpoint_addresses: HashSet<u64>,
+    /// All code patches applied to the opened process, as last reported by the engine. These are kept apart from the lines, such
+    /// that patched lines stay tinted across refreshes.
+    pub code_patches: Vec<CodePatch>,
+    pub assemble_dialog: Option<DisassemblerAssembleDialog>,
 }
 
 impl DisassemblerViewData {
     pub fn new() -> Self {
         Self {
             address_input: String::new(),
+            address_resolution: None,
             base_address: 0,
             module_name: None,
             module_base: None,
             highlight_address: None,
             highlight_pending: false,
             lines: Vec::new(),
+            line_selection: LineSelection::default(),
             is_loading: false,
             error_message: None,
             read_size: 0x200,
+            breakpoint_addresses: HashSet::new(),
+            code_patches: Vec::new(),
+            assemble_dialog: None,
+        }
+    }
+
+    /// Gets whether the byte at the given address has been patched.
+    pub fn is_patched(
+        &self,
+        address: u64,
+    ) -> bool {
+        self.code_patches
+            .iter()
+            .any(|code_patch| code_patch.contains_address(address))
+    }
+
+    /// Formats the selected lines as plain text, in the same column layout as the view.
+    pub fn get_selected_lines_text(&self) -> Option<String> {
+        self.line_selection
+            .format_selected_lines(self.lines.len(), |line_index| self.lines[line_index].get_columns())
+    }
+
+    /// Gets the hex bytes of the selected instructions as a single space separated sequence, ie for an array of bytes scan.
+    pub fn get_selected_instruction_bytes(&self) -> Option<String> {
+        let selected_range = self.line_selection.get_range()?;
+        let instruction_bytes = selected_range
+            .filter_map(|line_index| self.lines.get(line_index))
+            .map(|line| line.bytes.trim())
+            .filter(|bytes| !bytes.is_empty())
+            .collect::<Vec<_>>();
+
+        if instruction_bytes.is_empty() {
+            return None;
         }
+
+        Some(instruction_bytes.join(" "))
     }
 
     pub fn register(app_context: &Arc<AppContext>) -> Dependency<Self> {
@@ -72,9 +190,435 @@ impl DisassemblerViewData {
         Self::refresh(disassembler_view_data, engine_unprivileged_state);
     }
 
+    /// Resolves the shown lines again whenever the engine finishes loading the symbols of a module.
+    pub fn listen_for_symbols_loaded(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<ModuleSymbolsLoadedEvent>(move |_module_symbols_loaded_event| {
+            Self::resolve_symbols(disassembler_view_data.clone(), engine_unprivileged_state_clone.clone());
+        });
+    }
+
+    /// Keeps the shown breakpoints in sync when they are set or removed from any view.
+    pub fn listen_for_breakpoints_changed(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        engine_unprivileged_state.listen_for_engine_event::<BreakpointsChangedEvent>(move |breakpoints_changed_event| {
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data breakpoints changed") {
+                disassembler_view_data.breakpoint_addresses = breakpoints_changed_event
+                    .breakpoint_addresses
+                    .iter()
+                    .copied()
+                    .collect();
+            }
+        });
+    }
+
+    /// Fetches the breakpoints set in the opened process, such that lines loaded after a process change show the right breakpoints.
+    pub fn refresh_breakpoints(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::BREAKPOINTS) {
+            return;
+        }
+
+        let breakpoints_list_request = BreakpointsListRequest {};
+
+        breakpoints_list_request.send(&engine_unprivileged_state, move |breakpoints_list_response| {
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data breakpoints list response") {
+                disassembler_view_data.apply_breakpoint_response(breakpoints_list_response.breakpoint_addresses, None);
+            }
+        });
+    }
+
+    /// Sets a software breakpoint at the address, or removes the one already there. The shown breakpoints are only updated from the
+    /// engine's response, such that a breakpoint that could not be written, ie on a protected page, never shows as set.
+    pub fn toggle_breakpoint(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+    ) {
+        let is_breakpoint_set = match disassembler_view_data.write("Disassembler view data toggle breakpoint") {
+            Some(mut disassembler_view_data) => {
+                if !engine_unprivileged_state.has_capabilities(EngineCapabilities::BREAKPOINTS) {
+                    disassembler_view_data.error_message = Some("The engine does not support breakpoints.".to_string());
+                    return;
+                }
+
+                disassembler_view_data.error_message = None;
+                disassembler_view_data.breakpoint_addresses.contains(&address)
+            }
+            None => return,
+        };
+
+        if is_breakpoint_set {
+            let breakpoint_remove_request = BreakpointRemoveRequest { address };
+
+            breakpoint_remove_request.send(&engine_unprivileged_state, move |breakpoint_remove_response| {
+                if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data breakpoint remove response") {
+                    disassembler_view_data.apply_breakpoint_response(breakpoint_remove_response.breakpoint_addresses, breakpoint_remove_response.error_message);
+                }
+            });
+        } else {
+            let breakpoint_set_request = BreakpointSetRequest { address };
+
+            breakpoint_set_request.send(&engine_unprivileged_state, move |breakpoint_set_response| {
+                if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data breakpoint set response") {
+                    disassembler_view_data.apply_breakpoint_response(breakpoint_set_response.breakpoint_addresses, breakpoint_set_response.error_message);
+                }
+            });
+        }
+    }
+
+    fn apply_breakpoint_response(
+        &mut self,
+        breakpoint_addresses: Vec<u64>,
+        error_message: Option<String>,
+    ) {
+        self.breakpoint_addresses = breakpoint_addresses.into_iter().collect();
+
+        if error_message.is_some() {
+            self.error_message = error_message;
+        }
+    }
+
+    /// Keeps the shown code patches in sync when code is patched or restored from any view.
+    pub fn listen_for_code_patches_changed(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        engine_unprivileged_state.listen_for_engine_event::<CodePatchesChangedEvent>(move |code_patches_changed_event| {
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data code patches changed") {
+                disassembler_view_data.code_patches = code_patches_changed_event.code_patches.clone();
+            }
+        });
+    }
+
+    /// Fetches the code patches applied to the opened process, such that lines loaded after a process change are tinted correctly.
+    pub fn refresh_code_patches(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::CODE_PATCHES) {
+            return;
+        }
+
+        let code_patches_list_request = CodePatchesListRequest {};
+
+        code_patches_list_request.send(&engine_unprivileged_state, move |code_patches_list_response| {
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data code patches list response") {
+                disassembler_view_data.apply_code_patch_response(code_patches_list_response.code_patches, None);
+            }
+        });
+    }
+
+    /// Overwrites the instruction at the address with `nop`, keeping its length such that the following instructions are untouched.
+    pub fn replace_with_nops(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        length: usize,
+    ) {
+        Self::patch_code(disassembler_view_data, engine_unprivileged_state, address, vec![NOP_OPCODE; length]);
+    }
+
+    /// Opens the assemble dialog for the instruction at the address, starting from its current text.
+    pub fn open_assemble_dialog(
+        disassembler_view_data: Dependency<Self>,
+        address: u64,
+        length: usize,
+        instruction: String,
+    ) {
+        if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data open assemble dialog") {
+            disassembler_view_data.assemble_dialog = Some(DisassemblerAssembleDialog {
+                address,
+                length,
+                instruction_input: instruction,
+                ..DisassemblerAssembleDialog::default()
+            });
+        }
+    }
+
+    pub fn close_assemble_dialog(disassembler_view_data: Dependency<Self>) {
+        if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data close assemble dialog") {
+            disassembler_view_data.assemble_dialog = None;
+        }
+    }
+
+    /// Assembles the instruction typed into the dialog and patches it over the original, padded with `nop` to the original length.
+    /// Failures are shown in the dialog, which stays open such that the instruction can be corrected.
+    pub fn assemble_and_patch(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (address, length, instruction) = match disassembler_view_data.write("Disassembler view data assemble") {
+            Some(mut disassembler_view_data) => match disassembler_view_data.assemble_dialog.as_mut() {
+                Some(assemble_dialog) => {
+                    if !engine_unprivileged_state.has_capabilities(EngineCapabilities::CODE_PATCHES) {
+                        assemble_dialog.error_message = Some("The engine does not support patching code.".to_string());
+                        return;
+                    }
+
+                    assemble_dialog.error_message = None;
+                    assemble_dialog.is_assembling = true;
+
+                    (
+                        assemble_dialog.address,
+                        assemble_dialog.length,
+                        assemble_dialog.instruction_input.trim().to_string(),
+                    )
+                }
+                None => return,
+            },
+            None => return,
+        };
+        let code_assemble_request = CodeAssembleRequest { address, instruction };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        code_assemble_request.send(&engine_unprivileged_state, move |code_assemble_response| {
+            let padded_bytes = match code_assemble_response.error_message {
+                Some(error_message) => Err(error_message),
+                None => Self::pad_with_nops(code_assemble_response.bytes, length),
+            };
+            let padded_bytes = match padded_bytes {
+                Ok(padded_bytes) => padded_bytes,
+                Err(error_message) => {
+                    if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data assemble response")
+                        && let Some(assemble_dialog) = disassembler_view_data.assemble_dialog.as_mut()
+                    {
+                        assemble_dialog.error_message = Some(error_message);
+                        assemble_dialog.is_assembling = false;
+                    }
+
+                    return;
+                }
+            };
+            let code_patch_request = CodePatchRequest { address, bytes: padded_bytes };
+            let engine_unprivileged_state_for_reload = engine_unprivileged_state_clone.clone();
+
+            code_patch_request.send(&engine_unprivileged_state_clone, move |code_patch_response| {
+                let is_patched = code_patch_response.error_message.is_none();
+
+                if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data assemble patch response") {
+                    match code_patch_response.error_message {
+                        Some(error_message) => {
+                            if let Some(assemble_dialog) = disassembler_view_data.assemble_dialog.as_mut() {
+                                assemble_dialog.error_message = Some(error_message);
+                                assemble_dialog.is_assembling = false;
+                            }
+                        }
+                        None => disassembler_view_data.assemble_dialog = None,
+                    }
+
+                    disassembler_view_data.apply_code_patch_response(code_patch_response.code_patches, None);
+                }
+
+                if is_patched {
+                    Self::reload(disassembler_view_data, engine_unprivileged_state_for_reload, address);
+                }
+            });
+        });
+    }
+
+    /// Writes the original code back over the patch containing the address.
+    pub fn restore_code_patch(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+    ) {
+        if !Self::prepare_code_patch_request(&disassembler_view_data, &engine_unprivileged_state) {
+            return;
+        }
+
+        let code_restore_request = CodeRestoreRequest { address };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        code_restore_request.send(&engine_unprivileged_state, move |code_restore_response| {
+            let is_restored = code_restore_response.error_message.is_none();
+
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data code restore response") {
+                disassembler_view_data.apply_code_patch_response(code_restore_response.code_patches, code_restore_response.error_message);
+            }
+
+            // Failures leave the code as it was, so the disassembly is only reloaded on success, keeping the error visible.
+            if is_restored {
+                Self::reload(disassembler_view_data, engine_unprivileged_state_clone, address);
+            }
+        });
+    }
+
+    fn patch_code(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        bytes: Vec<u8>,
+    ) {
+        if !Self::prepare_code_patch_request(&disassembler_view_data, &engine_unprivileged_state) {
+            return;
+        }
+
+        let code_patch_request = CodePatchRequest { address, bytes };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        code_patch_request.send(&engine_unprivileged_state, move |code_patch_response| {
+            let is_patched = code_patch_response.error_message.is_none();
+
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data code patch response") {
+                disassembler_view_data.apply_code_patch_response(code_patch_response.code_patches, code_patch_response.error_message);
+            }
+
+            // Failures leave the code as it was, so the disassembly is only reloaded on success, keeping the error visible.
+            if is_patched {
+                Self::reload(disassembler_view_data, engine_unprivileged_state_clone, address);
+            }
+        });
+    }
+
+    /// Clears the previous error before a patch or restore, returning false if the engine cannot patch code.
+    fn prepare_code_patch_request(
+        disassembler_view_data: &Dependency<Self>,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> bool {
+        match disassembler_view_data.write("Disassembler view data prepare code patch") {
+            Some(mut disassembler_view_data) => {
+                if !engine_unprivileged_state.has_capabilities(EngineCapabilities::CODE_PATCHES) {
+                    disassembler_view_data.error_message = Some("The engine does not support patching code.".to_string());
+                    return false;
+                }
+
+                disassembler_view_data.error_message = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Pads assembled bytes with `nop` to the length of the instruction they replace. Longer bytes would overwrite the start of the
+    /// following instruction, so those are rejected instead.
+    fn pad_with_nops(
+        mut bytes: Vec<u8>,
+        length: usize,
+    ) -> Result<Vec<u8>, String> {
+        if bytes.len() > length {
+            return Err(format!(
+                "The assembled instruction is {} bytes, which is longer than the {} bytes of the instruction it replaces.",
+                bytes.len(),
+                length
+            ));
+        }
+
+        bytes.resize(length, NOP_OPCODE);
+
+        Ok(bytes)
+    }
+
+    fn apply_code_patch_response(
+        &mut self,
+        code_patches: Vec<CodePatch>,
+        error_message: Option<String>,
+    ) {
+        self.code_patches = code_patches;
+
+        if error_message.is_some() {
+            self.error_message = error_message;
+        }
+    }
+
+    /// Upgrades the raw addresses of the shown lines to module symbols in place. Addresses in modules whose symbols are still
+    /// loading stay as they are, and are resolved again once the engine reports the module as loaded.
+    pub fn resolve_symbols(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::MODULE_SYMBOLS) {
+            return;
+        }
+
+        let addresses = match disassembler_view_data.read("Disassembler view data resolve symbols") {
+            Some(disassembler_view_data) => {
+                let line_addresses = disassembler_view_data
+                    .lines
+                    .iter()
+                    .filter(|line| line.symbol.is_none())
+                    .map(|line| line.address);
+                let reference_addresses = disassembler_view_data
+                    .lines
+                    .iter()
+                    .filter(|line| line.reference_symbol.is_none())
+                    .filter_map(|line| line.reference_address);
+
+                line_addresses.chain(reference_addresses).collect::<Vec<_>>()
+            }
+            None => return,
+        };
+
+        if addresses.is_empty() {
+            return;
+        }
+
+        let memory_resolve_symbols_request = MemoryResolveSymbolsRequest { addresses: addresses.clone() };
+
+        memory_resolve_symbols_request.send(&engine_unprivileged_state, move |memory_resolve_symbols_response| {
+            let symbols: HashMap<u64, String> = addresses
+                .into_iter()
+                .zip(memory_resolve_symbols_response.symbols)
+                .filter_map(|(address, symbol)| symbol.map(|symbol| (address, symbol)))
+                .collect();
+
+            if symbols.is_empty() {
+                return;
+            }
+
+            // The lines may have been replaced while resolving, so symbols are matched by address rather than by position.
+            if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data apply symbols") {
+                Self::apply_symbols(&mut disassembler_view_data.lines, &symbols);
+            }
+        });
+    }
+
+    fn apply_symbols(
+        lines: &mut [DisassemblerLine],
+        symbols: &HashMap<u64, String>,
+    ) {
+        for line in lines.iter_mut() {
+            if let Some(symbol) = symbols.get(&line.address) {
+                line.symbol = Some(symbol.clone());
+            }
+
+            if let Some(reference_symbol) = line
+                .reference_address
+                .and_then(|reference_address| symbols.get(&reference_address))
+            {
+                line.reference_symbol = Some(reference_symbol.clone());
+            }
+        }
+    }
+
     pub fn refresh(
         disassembler_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        Self::refresh_with_highlight(disassembler_view_data, engine_unprivileged_state, None);
+    }
+
+    /// Disassembles the same address again after its code changed, keeping the given address highlighted rather than the first line.
+    fn reload(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        highlight_address: u64,
+    ) {
+        Self::refresh_with_highlight(disassembler_view_data, engine_unprivileged_state, Some(highlight_address));
+    }
+
+    fn refresh_with_highlight(
+        disassembler_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        highlight_address: Option<u64>,
     ) {
         let (address_input, read_size) = {
             let mut guard = match disassembler_view_data.write("Disassembler view data refresh") {
@@ -87,88 +631,51 @@ impl DisassemblerViewData {
             guard.module_name = None;
             guard.module_base = None;
             guard.lines.clear();
+            guard.line_selection.clear();
 
             (guard.address_input.trim().to_string(), guard.read_size)
         };
 
-        let module_parse = address_input.split_once('+').map(|(module, offset)| (module.trim().to_string(), offset.trim().to_string()));
-        let parsed_address = if module_parse.is_some() {
-            None
-        } else {
-            match Conversions::parse_hex_address(address_input.trim()) {
-                Ok(address) => Some(address),
-                Err(_) => {
-                    if let Some(mut disassembler_view_data) = disassembler_view_data.write("Disassembler view data invalid address") {
-                        disassembler_view_data.error_message = Some("Invalid address".to_string());
-                        disassembler_view_data.is_loading = false;
-                    }
-                    return;
-                }
-            }
-        };
-
-        let memory_regions_request = MemoryRegionsRequest {};
         let disassembler_view_data_clone = disassembler_view_data.clone();
         let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
 
-        memory_regions_request.send(&engine_unprivileged_state, move |memory_regions_response| {
-            let mut resolved_address = parsed_address.unwrap_or(0);
-            let mut module_name: Option<String> = None;
-            let mut module_base: Option<u64> = None;
-
-            if let Some((module, offset_string)) = module_parse.clone() {
-                let offset = match Conversions::parse_hex_address(&offset_string) {
-                    Ok(offset) => offset,
-                    Err(_) => {
-                        if let Some(mut disassembler_view_data) = disassembler_view_data_clone.write("Disassembler invalid module offset") {
-                            disassembler_view_data.is_loading = false;
-                            disassembler_view_data.error_message = Some("Invalid module offset".to_string());
-                        }
-                        return;
-                    }
-                };
+        AddressResolver::resolve(&engine_unprivileged_state, &address_input, move |address_resolution| {
+            let resolved_address = address_resolution.as_ref().ok().cloned();
 
-                if let Some(region) = memory_regions_response
-                    .regions
-                    .iter()
-                    .find(|region| region.module_name.eq_ignore_ascii_case(&module))
-                {
-                    let base = region.base_address.saturating_sub(region.module_offset);
-                    resolved_address = base.saturating_add(offset);
-                    module_name = Some(region.module_name.clone());
-                    module_base = Some(base);
-                } else {
-                    if let Some(mut disassembler_view_data) = disassembler_view_data_clone.write("Disassembler module not found") {
-                        disassembler_view_data.is_loading = false;
-                        disassembler_view_data.error_message = Some("Module not found".to_string());
-                    }
-                    return;
-                }
-            } else if let Some(address) = parsed_address {
-                if let Some(region) = memory_regions_response
-                    .regions
-                    .iter()
-                    .find(|region| address >= region.base_address && address < region.base_address.saturating_add(region.region_size))
-                {
-                    if !region.module_name.is_empty() {
-                        let base = region.base_address.saturating_sub(region.module_offset);
-                        module_name = Some(region.module_name.clone());
-                        module_base = Some(base);
-                    }
+            if let Some(mut disassembler_view_data) = disassembler_view_data_clone.write("Disassembler address resolved") {
+                disassembler_view_data.address_resolution = Some(address_resolution);
+
+                if resolved_address.is_none() {
+                    disassembler_view_data.is_loading = false;
                 }
             }
 
+            let Some(resolved_address) = resolved_address else {
+                return;
+            };
+
+            let (module_name, module_base) = if resolved_address.module_name.is_empty() {
+                (None, None)
+            } else {
+                (
+                    Some(resolved_address.module_name.clone()),
+                    Some(resolved_address.address - resolved_address.module_offset),
+                )
+            };
+
             let symbolic_struct_definition = SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
                 DataTypeRef::new(DataTypeU8::get_data_type_id()),
                 ContainerType::ArrayFixed(read_size as u64),
             )]);
 
             let memory_read_request = MemoryReadRequest {
-                address: resolved_address,
+                address: resolved_address.address,
                 module_name: String::new(),
                 symbolic_struct_definition,
             };
 
+            let engine_unprivileged_state_for_symbols = engine_unprivileged_state_clone.clone();
+
             memory_read_request.send(&engine_unprivileged_state_clone, move |memory_read_response| {
                 let bytes = memory_read_response.valued_struct.get_bytes();
                 let base_address = memory_read_response.address;
@@ -178,7 +685,7 @@ impl DisassemblerViewData {
                     disassembler_view_data.base_address = base_address;
                     disassembler_view_data.module_name = module_name.clone();
                     disassembler_view_data.module_base = module_base;
-                    disassembler_view_data.highlight_address = Some(base_address);
+                    disassembler_view_data.highlight_address = Some(highlight_address.unwrap_or(base_address));
                     disassembler_view_data.highlight_pending = true;
 
                     if !memory_read_response.success || bytes.is_empty() {
@@ -188,6 +695,7 @@ impl DisassemblerViewData {
                             display_address: format!("{:016X}", base_address),
                             bytes: "??".to_string(),
                             instruction: "db ??".to_string(),
+                            ..DisassemblerLine::default()
                         }];
                         return;
                     }
@@ -200,11 +708,16 @@ impl DisassemblerViewData {
                             display_address: format!("{:016X}", base_address),
                             bytes: "??".to_string(),
                             instruction: "db ??".to_string(),
+                            ..DisassemblerLine::default()
                         }];
                     } else {
                         disassembler_view_data.lines = decoded;
                     }
                 }
+
+                Self::refresh_breakpoints(disassembler_view_data_clone.clone(), engine_unprivileged_state_for_symbols.clone());
+                Self::refresh_code_patches(disassembler_view_data_clone.clone(), engine_unprivileged_state_for_symbols.clone());
+                Self::resolve_symbols(disassembler_view_data_clone, engine_unprivileged_state_for_symbols);
             });
         });
     }
@@ -250,14 +763,188 @@ impl DisassemblerViewData {
                 format!("{:016X}", instruction.ip())
             };
 
+            let memory_operand = Self::get_memory_operand(&instruction);
+            let reference_address = match instruction.op0_kind() {
+                OpKind::NearBranch16 | OpKind::NearBranch32 | OpKind::NearBranch64 => Some(instruction.near_branch_target()),
+                _ => memory_operand
+                    .as_ref()
+                    .map(|memory_operand| memory_operand.address),
+            };
+
             lines.push(DisassemblerLine {
                 address: instruction.ip(),
+                length,
                 display_address,
                 bytes: bytes_string,
                 instruction: instr_string,
+                memory_operand,
+                reference_address,
+                ..DisassemblerLine::default()
             });
         }
 
         lines
     }
+
+    /// Gets the memory operand of the instruction, if its address does not depend on register values.
+    fn get_memory_operand(instruction: &Instruction) -> Option<DisassemblerMemoryOperand> {
+        let has_memory_operand = (0..instruction.op_count()).any(|operand| instruction.op_kind(operand) == OpKind::Memory);
+        let is_static_address = matches!(instruction.memory_base(), Register::None | Register::RIP | Register::EIP)
+            && instruction.memory_index() == Register::None;
+
+        if !has_memory_operand || !is_static_address {
+            return None;
+        }
+
+        Some(DisassemblerMemoryOperand {
+            // The displacement of RIP relative operands is already resolved against the next instruction pointer by the decoder.
+            address: instruction.memory_displacement64(),
+            data_type: Self::get_memory_operand_data_type(instruction.memory_size()),
+        })
+    }
+
+    fn get_memory_operand_data_type(memory_size: MemorySize) -> DataTypeRef {
+        let data_type_id = match memory_size {
+            MemorySize::Int8 => DataTypeI8::get_data_type_id(),
+            MemorySize::Int16 => DataTypeI16::get_data_type_id(),
+            MemorySize::Int32 => DataTypeI32::get_data_type_id(),
+            MemorySize::Int64 => DataTypeI64::get_data_type_id(),
+            MemorySize::Float32 => DataTypeF32::get_data_type_id(),
+            MemorySize::Float64 => DataTypeF64::get_data_type_id(),
+            _ => match memory_size.size() {
+                2 => DataTypeU16::get_data_type_id(),
+                4 => DataTypeU32::get_data_type_id(),
+                8 => DataTypeU64::get_data_type_id(),
+                _ => DataTypeU8::get_data_type_id(),
+            },
+        };
+
+        DataTypeRef::new(data_type_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DisassemblerViewData;
+    use squalr_engine_api::structures::memory::code_patch::CodePatch;
+    use std::collections::HashMap;
+
+    #[test]
+    fn rip_relative_operands_resolve_to_typed_addresses() {
+        // mov eax, [rip+0x10] ; mov rax, [rsp] ; movss xmm0, [0x2000]
+        let bytes = [
+            0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0x48, 0x8B, 0x04, 0x24, 0xF3, 0x0F, 0x10, 0x04, 0x25, 0x00, 0x20, 0x00, 0x00,
+        ];
+        let lines = DisassemblerViewData::decode_instructions(&bytes, 0x1000, None, None);
+
+        assert_eq!(lines.len(), 3);
+
+        let rip_relative_operand = lines[0].memory_operand.as_ref().expect("RIP relative operand");
+
+        assert_eq!(rip_relative_operand.address, 0x1016);
+        assert_eq!(rip_relative_operand.data_type.get_data_type_id(), "u32");
+        assert!(lines[1].memory_operand.is_none());
+
+        let absolute_operand = lines[2].memory_operand.as_ref().expect("Absolute operand");
+
+        assert_eq!(absolute_operand.address, 0x2000);
+        assert_eq!(absolute_operand.data_type.get_data_type_id(), "f32");
+    }
+
+    #[test]
+    fn selected_lines_copy_as_text_and_as_instruction_bytes() {
+        // nop ; push rbx ; ret
+        let bytes = [0x90, 0x53, 0xC3];
+        let mut disassembler_view_data = DisassemblerViewData::new();
+
+        disassembler_view_data.lines = DisassemblerViewData::decode_instructions(&bytes, 0x1000, Some("game.exe"), Some(0x1000));
+        assert_eq!(disassembler_view_data.get_selected_lines_text(), None);
+
+        disassembler_view_data.line_selection.select_line(2);
+        disassembler_view_data.line_selection.extend_to(1);
+
+        assert_eq!(
+            disassembler_view_data.get_selected_lines_text(),
+            Some(["game.exe+1  53  push rbx", "game.exe+2  C3  ret"].join("\n"))
+        );
+        assert_eq!(disassembler_view_data.get_selected_instruction_bytes(), Some("53 C3".to_string()));
+    }
+
+    #[test]
+    fn resolved_symbols_upgrade_lines_in_place() {
+        // call 0x2000 ; nop
+        let bytes = [0xE8, 0xFB, 0x0F, 0x00, 0x00, 0x90];
+        let mut lines = DisassemblerViewData::decode_instructions(&bytes, 0x1000, Some("game.exe"), Some(0x1000));
+
+        assert_eq!(lines[0].reference_address, Some(0x2000));
+        assert_eq!(lines[1].reference_address, None);
+        assert_eq!(lines[0].get_columns()[0], "game.exe+0");
+
+        // Only the call target's module has loaded so far, so the line addresses stay as module offsets.
+        let mut symbols = HashMap::from([(0x2000, "kernel32.dll!Sleep".to_string())]);
+
+        DisassemblerViewData::apply_symbols(&mut lines, &symbols);
+
+        assert_eq!(lines[0].get_columns()[0], "game.exe+0");
+        assert!(lines[0].get_columns()[2].ends_with("  ; kernel32.dll!Sleep"));
+
+        symbols.insert(0x1005, "game.exe!main+0x5".to_string());
+        DisassemblerViewData::apply_symbols(&mut lines, &symbols);
+
+        assert_eq!(lines[1].get_columns(), vec!["game.exe!main+0x5", "90", "nop"]);
+    }
+
+    #[test]
+    fn breakpoints_only_show_once_the_engine_reports_them() {
+        let mut disassembler_view_data = DisassemblerViewData::new();
+
+        disassembler_view_data.apply_breakpoint_response(vec![0x1000], None);
+        assert!(disassembler_view_data.breakpoint_addresses.contains(&0x1000));
+
+        // A breakpoint on a protected page fails to write, so the engine reports the breakpoints without it.
+        disassembler_view_data.apply_breakpoint_response(
+            vec![0x1000],
+            Some("Could not write a breakpoint at 2000. The page may be protected.".to_string()),
+        );
+
+        assert!(!disassembler_view_data.breakpoint_addresses.contains(&0x2000));
+        assert_eq!(disassembler_view_data.breakpoint_addresses.len(), 1);
+        assert!(disassembler_view_data.error_message.is_some());
+    }
+
+    #[test]
+    fn assembled_instructions_are_padded_to_the_replaced_length() {
+        // mov eax, 1 ; ret
+        let bytes = [0xB8, 0x01, 0x00, 0x00, 0x00, 0xC3];
+        let lines = DisassemblerViewData::decode_instructions(&bytes, 0x1000, None, None);
+
+        assert_eq!(lines.iter().map(|line| line.length).collect::<Vec<_>>(), vec![5, 1]);
+
+        // xor eax, eax
+        assert_eq!(
+            DisassemblerViewData::pad_with_nops(vec![0x31, 0xC0], lines[0].length),
+            Ok(vec![0x31, 0xC0, 0x90, 0x90, 0x90])
+        );
+        assert!(DisassemblerViewData::pad_with_nops(vec![0x31, 0xC0], lines[1].length).is_err());
+    }
+
+    #[test]
+    fn patched_lines_are_tinted_until_restored() {
+        let mut disassembler_view_data = DisassemblerViewData::new();
+        let code_patch = CodePatch {
+            address: 0x1000,
+            original_bytes: vec![0xB8, 0x01, 0x00, 0x00, 0x00],
+            patched_bytes: vec![0x90; 5],
+        };
+
+        disassembler_view_data.apply_code_patch_response(vec![code_patch], None);
+
+        assert!(disassembler_view_data.is_patched(0x1000));
+        assert!(disassembler_view_data.is_patched(0x1004));
+        assert!(!disassembler_view_data.is_patched(0x1005));
+
+        disassembler_view_data.apply_code_patch_response(vec![], None);
+
+        assert!(!disassembler_view_data.is_patched(0x1000));
+    }
 }
@@ -1,10 +1,12 @@
 use crate::{
     app_context::AppContext,
+    models::appearance::{row_density::RowDensity, value_color_rules::ValueColorRules},
+    ui::converters::data_type_to_string_converter::DataTypeToStringConverter,
     ui::widgets::controls::{checkbox::Checkbox, state_layer::StateLayer},
     ui::ui_trace,
     views::element_scanner::results::view_data::element_scanner_result_frame_action::ElementScannerResultFrameAction,
 };
-use eframe::egui::{Align2, Rect, Response, Sense, Ui, Widget, pos2, vec2};
+use eframe::egui::{Align2, Rect, Response, Sense, Ui, Widget, WidgetInfo, WidgetType, pos2, vec2};
 use epaint::{Color32, CornerRadius, Stroke, StrokeKind};
 use squalr_engine_api::structures::{data_values::anonymous_value_string_format::AnonymousValueStringFormat, scan_results::scan_result::ScanResult};
 use std::sync::Arc;
@@ -13,12 +15,21 @@ pub struct ElementScannerResultEntryView<'lifetime> {
     app_context: Arc<AppContext>,
     scan_result: &'lifetime ScanResult,
     active_display_format: AnonymousValueStringFormat,
+    /// The display format chosen for this result alone, which takes precedence over the active display format.
+    display_format_override: Option<AnonymousValueStringFormat>,
     index: usize,
     is_selected: bool,
+    row_density: RowDensity,
+    value_color_rules: &'lifetime ValueColorRules,
     element_sanner_result_frame_action: &'lifetime mut ElementScannerResultFrameAction,
     address_splitter_position_x: f32,
     value_splitter_position_x: f32,
     previous_value_splitter_position_x: f32,
+    /// Where the first scan value column starts, if it is shown.
+    first_scan_value_position_x: Option<f32>,
+    flash_strength: f32,
+    /// How strongly the value cell is highlighted, as the value recently changed between refreshes.
+    value_change_strength: f32,
 }
 
 impl<'lifetime> ElementScannerResultEntryView<'lifetime> {
@@ -26,28 +37,45 @@ impl<'lifetime> ElementScannerResultEntryView<'lifetime> {
         app_context: Arc<AppContext>,
         scan_result: &'lifetime ScanResult,
         active_display_format: AnonymousValueStringFormat,
+        display_format_override: Option<AnonymousValueStringFormat>,
         index: usize,
         is_selected: bool,
+        row_density: RowDensity,
+        value_color_rules: &'lifetime ValueColorRules,
         element_sanner_result_frame_action: &'lifetime mut ElementScannerResultFrameAction,
         address_splitter_position_x: f32,
         value_splitter_position_x: f32,
         previous_value_splitter_position_x: f32,
+        first_scan_value_position_x: Option<f32>,
+        flash_strength: f32,
+        value_change_strength: f32,
     ) -> Self {
         Self {
             app_context,
             scan_result,
             active_display_format,
+            display_format_override,
             index,
             is_selected,
+            row_density,
+            value_color_rules,
             element_sanner_result_frame_action,
             address_splitter_position_x,
             value_splitter_position_x,
             previous_value_splitter_position_x,
+            first_scan_value_position_x,
+            flash_strength,
+            value_change_strength,
         }
     }
 
     pub fn get_height(&self) -> f32 {
-        32.0
+        self.row_density.get_row_height()
+    }
+
+    fn get_display_format(&self) -> AnonymousValueStringFormat {
+        self.display_format_override
+            .unwrap_or(self.active_display_format)
     }
 }
 
@@ -59,6 +87,7 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
         let theme = &self.app_context.theme;
         let text_left_padding = 8.0;
         let row_height = self.get_height();
+        let row_font = self.row_density.get_font(&theme.font_library.font_ubuntu_mono_bold);
 
         // `available_size().x` can be 0 in some nested layout contexts (notably in headless test frames),
         // which would make the row non-interactive even though we still paint text into it. Prefer
@@ -81,6 +110,33 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
             );
         }
 
+        // Briefly flash rows whose watched value just triggered a notification.
+        if self.flash_strength > 0.0 {
+            user_interface.painter().rect_filled(
+                allocated_size_rectangle,
+                CornerRadius::ZERO,
+                theme
+                    .background_control_warning
+                    .gamma_multiply(0.5 * self.flash_strength),
+            );
+        }
+
+        // Highlight values that just changed, fading out over time.
+        if self.value_change_strength > 0.0 {
+            let value_cell_rectangle = Rect::from_min_max(
+                pos2(self.value_splitter_position_x, allocated_size_rectangle.min.y),
+                pos2(self.previous_value_splitter_position_x, allocated_size_rectangle.max.y),
+            );
+
+            user_interface.painter().rect_filled(
+                value_cell_rectangle,
+                CornerRadius::ZERO,
+                theme
+                    .background_control_danger
+                    .gamma_multiply(0.6 * self.value_change_strength),
+            );
+        }
+
         // Background and state overlay.
         StateLayer {
             bounds_min: allocated_size_rectangle.min,
@@ -94,7 +150,7 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
@@ -113,25 +169,38 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
                 self.index,
                 self.scan_result.get_address()
             ));
-            *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(self.index as i32));
+            // Right-clicking a selected row keeps the selection, such that the context menu applies to all of it.
+            if !self.is_selected {
+                *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(self.index as i32));
+            }
         }
 
         if response.clicked() {
+            let modifiers = user_interface.input(|input| input.modifiers);
+
             ui_trace::trace(format!(
-                "results_entry.clicked index={} shift={} address=0x{:X}",
+                "results_entry.clicked index={} shift={} command={} address=0x{:X}",
                 self.index,
-                user_interface.input(|input| input.modifiers.shift),
+                modifiers.shift,
+                modifiers.command,
                 self.scan_result.get_address()
             ));
-            if user_interface.input(|input| input.modifiers.shift) {
-                *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionEnd(Some(self.index as i32));
+            if modifiers.shift {
+                *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::ExtendSelection(self.index as i32);
+            } else if modifiers.command {
+                *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::ToggleSelection(self.index as i32);
             } else {
                 *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(self.index as i32));
             }
         }
 
         if user_interface
-            .place(checkbox_rectangle, Checkbox::new_from_theme(theme).with_check_state_bool(is_frozen))
+            .place(
+                checkbox_rectangle,
+                Checkbox::new_from_theme(theme)
+                    .with_check_state_bool(is_frozen)
+                    .with_tooltip_text("Freeze value."),
+            )
             .clicked()
         {
             ui_trace::trace(format!(
@@ -149,6 +218,15 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
                 .rect_filled(checkbox_rectangle, CornerRadius::ZERO, theme.pressed_tint);
         }
 
+        // Frozen results that cannot be read are skipped by the freeze loop, which is flagged on the checkbox.
+        let is_freeze_paused = is_frozen && !self.scan_result.get_read_status().is_readable();
+
+        if is_freeze_paused {
+            user_interface
+                .painter()
+                .rect_stroke(checkbox_rectangle, CornerRadius::ZERO, Stroke::new(1.0, theme.error_red), StrokeKind::Outside);
+        }
+
         if self.scan_result.get_is_frozen() {
             let icon = &theme.icon_library.icon_handle_common_check_mark;
             let texture_size = icon.size_vec2();
@@ -164,7 +242,7 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
 
         // Address.
         let row_center_y = allocated_size_rectangle.center().y;
-        let icon_size = vec2(16.0, 16.0);
+        let icon_size = vec2(self.row_density.get_icon_size(), self.row_density.get_icon_size());
         let data_type_ref = self.scan_result.get_data_type_ref();
         let icon_handle = crate::ui::converters::data_type_to_icon_converter::DataTypeToIconConverter::convert_data_type_to_icon(
             data_type_ref.get_data_type_id(),
@@ -191,40 +269,78 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
         user_interface.painter().text(
             address_text_position,
             Align2::LEFT_CENTER,
-            address_string,
-            theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+            &address_string,
+            row_font.clone(),
             theme.hexadecimal_green,
         );
 
+        // Stale badge, for results over memory that was freed or re-protected since it was snapshotted.
+        let stale_change_kind = self.scan_result.get_base_result().get_stale_change_kind();
+
+        if stale_change_kind.is_some() {
+            let badge_font = theme.font_library.font_noto_sans.font_small.clone();
+            let badge_galley = user_interface
+                .painter()
+                .layout_no_wrap("STALE".to_string(), badge_font, theme.foreground);
+            let badge_padding = vec2(4.0, 1.0);
+            let badge_size = badge_galley.size() + badge_padding * 2.0;
+            let badge_rectangle = Rect::from_min_size(
+                pos2(self.value_splitter_position_x - badge_size.x - 6.0, row_center_y - badge_size.y * 0.5),
+                badge_size,
+            );
+
+            user_interface
+                .painter()
+                .rect_filled(badge_rectangle, CornerRadius::same(2), theme.background_control_warning_dark);
+            user_interface
+                .painter()
+                .galley(badge_rectangle.min + badge_padding, badge_galley, theme.foreground);
+        }
+
         // Value.
         let current_value_text_position = pos2(self.value_splitter_position_x + text_left_padding, row_center_y);
-        let current_value_string = match self
+        let read_status = self.scan_result.get_read_status();
+        let current_value_string = self
             .scan_result
-            .get_recently_read_display_value(self.active_display_format)
-        {
-            Some(recently_read_value) => recently_read_value.get_anonymous_value_string(),
-            None => match self
-                .scan_result
-                .get_current_display_value(self.active_display_format)
-            {
-                Some(current_value) => current_value.get_anonymous_value_string(),
-                None => "??",
-            },
+            .get_display_value_string(self.get_display_format());
+        // Conditional formatting applies to the typed value that was read, so unreadable and stale values keep their own color.
+        let current_value_color = if !read_status.is_readable() {
+            theme.error_red
+        } else if self.scan_result.is_stale() {
+            theme.foreground_preview
+        } else {
+            self.scan_result
+                .get_recently_read_value()
+                .as_ref()
+                .or(self.scan_result.get_current_value().as_ref())
+                .and_then(|data_value| self.value_color_rules.evaluate(data_value))
+                .map(|value_color| value_color.get_color(theme))
+                .unwrap_or(theme.foreground)
         };
 
+        // Rows are painted rather than built from labels, so announce the address and value of the row to screen readers.
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::SelectableLabel,
+                true,
+                self.is_selected,
+                format!("Address {}, value {}", address_string, current_value_string),
+            )
+        });
+
         user_interface.painter().text(
             current_value_text_position,
             Align2::LEFT_CENTER,
             current_value_string,
-            theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
-            theme.foreground,
+            row_font.clone(),
+            current_value_color,
         );
 
         // Previous value.
         let previous_value_text_position = pos2(self.previous_value_splitter_position_x + text_left_padding, row_center_y);
         let previous_value_string = match self
             .scan_result
-            .get_previous_display_value(self.active_display_format)
+            .get_previous_display_value(self.get_display_format())
         {
             Some(previous_value) => previous_value.get_anonymous_value_string(),
             None => "??",
@@ -234,10 +350,72 @@ impl<'a> Widget for ElementScannerResultEntryView<'a> {
             previous_value_text_position,
             Align2::LEFT_CENTER,
             previous_value_string,
-            theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+            row_font.clone(),
             theme.foreground,
         );
 
-        response
+        // First scan value.
+        let first_scan_value_string = self
+            .scan_result
+            .get_first_scan_display_value(self.get_display_format())
+            .map(|first_scan_value| first_scan_value.get_anonymous_value_string());
+
+        if let Some(first_scan_value_position_x) = self.first_scan_value_position_x {
+            user_interface.painter().text(
+                pos2(first_scan_value_position_x + text_left_padding, row_center_y),
+                Align2::LEFT_CENTER,
+                first_scan_value_string.unwrap_or("??"),
+                row_font.clone(),
+                theme.foreground,
+            );
+        }
+
+        // Stride repeat count, for the results of a stride scan.
+        if let Some(stride_repeat_count) = self.scan_result.get_base_result().get_stride_repeat_count() {
+            user_interface.painter().text(
+                pos2(allocated_size_rectangle.max.x - text_left_padding, row_center_y),
+                Align2::RIGHT_CENTER,
+                stride_repeat_count.to_string(),
+                row_font.clone(),
+                theme.foreground,
+            );
+        }
+
+        let read_status_description = read_status.get_description(
+            self.scan_result
+                .get_current_value()
+                .as_ref()
+                .map(|current_value| current_value.get_size_in_bytes())
+                .unwrap_or(0),
+        );
+        let response = match first_scan_value_string {
+            Some(first_scan_value_string) => response.on_hover_text(format!("First scan value: {}", first_scan_value_string)),
+            None => response,
+        };
+        let response = match read_status_description {
+            Some(read_status_description) if is_freeze_paused => {
+                response.on_hover_text(format!("{} Freezing is paused until the value can be read again.", read_status_description))
+            }
+            Some(read_status_description) => response.on_hover_text(read_status_description),
+            None => response,
+        };
+
+        let response = match stale_change_kind {
+            Some(stale_change_kind) => response.on_hover_text(format!(
+                "{} Its value may no longer be meaningful, so it is not frozen or written to without confirmation. Revalidate the snapshot to refresh it.",
+                stale_change_kind.get_description()
+            )),
+            None => response,
+        };
+
+        // Results shown as a different type than they were scanned as are only converted for display.
+        match self.scan_result.get_base_result().get_original_data_type_ref() {
+            Some(original_data_type_ref) => response.on_hover_text(format!(
+                "Shown as {}. The next scan compares this result as {}.",
+                DataTypeToStringConverter::convert_data_type_to_display_string(data_type_ref.get_data_type_id()),
+                DataTypeToStringConverter::convert_data_type_to_display_string(original_data_type_ref.get_data_type_id())
+            )),
+            None => response,
+        }
     }
 }
@@ -10,16 +10,20 @@ use crate::{
         },
         scanner::view_data::element_scanner_view_data::ElementScannerViewData,
     },
+    views::main_window::view_data::value_watches_view_data::ValueWatchesViewData,
 };
-use eframe::egui::{Align, Layout, Response, RichText, Sense, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Align2, Layout, Popup, PopupCloseBehavior, Response, RichText, Sense, TextEdit, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, Rect, Stroke, StrokeKind, pos2, vec2};
 use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::structures::data_values::value_nudge::ValueNudge;
 use std::sync::Arc;
 
 pub struct ElementScannerResultsActionBarView<'lifetime> {
     app_context: Arc<AppContext>,
     element_scanner_results_view_data: Dependency<ElementScannerResultsViewData>,
     element_scanner_view_data: Dependency<ElementScannerViewData>,
+    value_watches_view_data: Dependency<ValueWatchesViewData>,
     selection_freeze_checkstate: CheckState,
     element_sanner_result_frame_action: &'lifetime mut ElementScannerResultFrameAction,
     address_splitter_position_x: f32,
@@ -30,6 +34,8 @@ pub struct ElementScannerResultsActionBarView<'lifetime> {
 impl<'lifetime> ElementScannerResultsActionBarView<'lifetime> {
     pub const FOOTER_HEIGHT: f32 = 32.0;
 
+    const VALUE_FILTER_WIDTH: f32 = 120.0;
+
     pub fn new(
         app_context: Arc<AppContext>,
         selection_freeze_checkstate: CheckState,
@@ -44,11 +50,15 @@ impl<'lifetime> ElementScannerResultsActionBarView<'lifetime> {
         let element_scanner_view_data = app_context
             .dependency_container
             .get_dependency::<ElementScannerViewData>();
+        let value_watches_view_data = app_context
+            .dependency_container
+            .get_dependency::<ValueWatchesViewData>();
 
         Self {
             app_context,
             element_scanner_results_view_data,
             element_scanner_view_data,
+            value_watches_view_data,
             selection_freeze_checkstate,
             element_sanner_result_frame_action,
             address_splitter_position_x,
@@ -89,10 +99,22 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
         };
 
         let result_count = element_scanner_results_view_data.result_count;
+        let is_infinite_scroll = element_scanner_results_view_data.is_infinite_scroll;
+        let mut should_toggle_infinite_scroll = false;
+        let mut should_refresh_value_watches = false;
+        let mut value_watch_to_stop = None;
         let current_page_count = element_scanner_results_view_data.current_scan_results.len() as u64;
         let page_size = element_scanner_results_view_data.last_page_size.max(1);
         let (show_start, show_end) = if result_count == 0 || current_page_count == 0 {
             (0, 0)
+        } else if is_infinite_scroll {
+            let visible_row_range = &element_scanner_results_view_data.visible_row_range;
+
+            if visible_row_range.is_empty() {
+                (0, 0)
+            } else {
+                (visible_row_range.start.saturating_add(1), visible_row_range.end.min(result_count))
+            }
         } else {
             let start = element_scanner_results_view_data
                 .current_page_index
@@ -104,7 +126,10 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
                 .min(result_count);
             (start, end)
         };
-        let stats_text = format!("Found: {} | Showing {}~{}", result_count, show_start, show_end);
+        let stats_text = match &element_scanner_results_view_data.filtered_indices {
+            Some(filtered_indices) => format!("Found: {} | {} of {} shown", result_count, filtered_indices.len(), current_page_count),
+            None => format!("Found: {} | Showing {}~{}", result_count, show_start, show_end),
+        };
         let stats_text_width = user_interface.fonts(|fonts| {
             fonts
                 .layout_no_wrap(
@@ -115,7 +140,9 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
                 .size()
                 .x
         });
-        let right_min_width = (stats_text_width + 24.0).max(140.0);
+        // The value filter narrows the rows of a page, so it is only offered in paged mode.
+        let value_filter_width = if is_infinite_scroll { 0.0 } else { Self::VALUE_FILTER_WIDTH + 8.0 };
+        let right_min_width = (stats_text_width + value_filter_width + 24.0).max(140.0);
         let left_max_x = (allocated_size_rectangle.max.x - right_min_width).max(allocated_size_rectangle.min.x + 120.0);
         let left_rect = Rect::from_min_max(allocated_size_rectangle.min, pos2(left_max_x, allocated_size_rectangle.max.y));
         let right_rect = Rect::from_min_max(pos2(left_max_x, allocated_size_rectangle.min.y), allocated_size_rectangle.max);
@@ -147,7 +174,11 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
         left_ui.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
             user_interface.add_space(8.0);
             if user_interface
-                .add(Checkbox::new_from_theme(theme).with_check_state(self.selection_freeze_checkstate))
+                .add(
+                    Checkbox::new_from_theme(theme)
+                        .with_check_state(self.selection_freeze_checkstate)
+                        .with_tooltip_text("Freeze selected results."),
+                )
                 .clicked()
             {
                 match self.selection_freeze_checkstate {
@@ -223,6 +254,185 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
                 *self.element_sanner_result_frame_action =
                     ElementScannerResultFrameAction::CommitValueToSelection(element_scanner_results_view_data.current_display_string.clone());
             }
+
+            // Nudges step by whole numbers for integers and by tenths for floating point values, so label them by the selection.
+            let (has_integers, has_floating_points) = element_scanner_results_view_data.get_selection_nudge_kinds();
+            let is_nudge_disabled = !has_integers && !has_floating_points;
+
+            for value_nudge in ValueNudge::ALL {
+                let nudge_label = value_nudge.get_label(has_integers, has_floating_points);
+                let nudge_label_width = user_interface.fonts(|fonts| {
+                    fonts
+                        .layout_no_wrap(
+                            nudge_label.clone(),
+                            theme.font_library.font_noto_sans.font_small.clone(),
+                            theme.foreground,
+                        )
+                        .size()
+                        .x
+                });
+                let nudge_response = user_interface.add_sized(
+                    vec2((nudge_label_width + 12.0).max(button_size.x), button_size.y),
+                    Button::new_from_theme(theme)
+                        .background_color(Color32::TRANSPARENT)
+                        .disabled(is_nudge_disabled)
+                        .with_tooltip_text(if value_nudge.is_decrement() {
+                            "Decrease selected values."
+                        } else {
+                            "Increase selected values."
+                        }),
+                );
+
+                user_interface.painter().text(
+                    nudge_response.rect.center(),
+                    Align2::CENTER_CENTER,
+                    nudge_label,
+                    theme.font_library.font_noto_sans.font_small.clone(),
+                    if is_nudge_disabled { theme.foreground_preview } else { theme.foreground },
+                );
+
+                if nudge_response.clicked() && !is_nudge_disabled {
+                    *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::NudgeSelection(value_nudge);
+                }
+            }
+
+            // Freezes or unfreezes the whole page rather than the selection, unfreezing only once everything on it is frozen.
+            let is_page_frozen = element_scanner_results_view_data.is_page_frozen();
+            let freeze_page_label = if is_page_frozen { "Unfreeze all" } else { "Freeze all" };
+            let freeze_page_label_width = user_interface.fonts(|fonts| {
+                fonts
+                    .layout_no_wrap(
+                        freeze_page_label.to_string(),
+                        theme.font_library.font_noto_sans.font_small.clone(),
+                        theme.foreground,
+                    )
+                    .size()
+                    .x
+            });
+            let is_freeze_page_disabled = current_page_count == 0 || element_scanner_results_view_data.is_freezing_entries;
+            let freeze_page_response = user_interface.add_sized(
+                vec2(freeze_page_label_width + 12.0, button_size.y),
+                Button::new_from_theme(theme)
+                    .background_color(Color32::TRANSPARENT)
+                    .disabled(is_freeze_page_disabled)
+                    .with_tooltip_text(if is_page_frozen {
+                        "Unfreeze every result on this page."
+                    } else {
+                        "Freeze every result on this page."
+                    }),
+            );
+
+            user_interface.painter().text(
+                freeze_page_response.rect.center(),
+                Align2::CENTER_CENTER,
+                freeze_page_label,
+                theme.font_library.font_noto_sans.font_small.clone(),
+                if is_freeze_page_disabled { theme.foreground_preview } else { theme.foreground },
+            );
+
+            if freeze_page_response.clicked() && !is_freeze_page_disabled {
+                *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::ToggleFreezePage(!is_page_frozen);
+            }
+
+            let infinite_scroll_response = user_interface.add_sized(
+                button_size,
+                Button::new_from_theme(theme)
+                    .background_color(if is_infinite_scroll {
+                        theme.background_control_primary
+                    } else {
+                        Color32::TRANSPARENT
+                    })
+                    .with_tooltip_text(if is_infinite_scroll {
+                        "Switch to paged results."
+                    } else {
+                        "Switch to infinite scroll results."
+                    }),
+            );
+
+            IconDraw::draw(user_interface, infinite_scroll_response.rect, &theme.icon_library.icon_handle_navigation_down_arrows);
+
+            if infinite_scroll_response.clicked() {
+                should_toggle_infinite_scroll = true;
+            }
+
+            if element_scanner_results_view_data.has_stale_scan_results() {
+                let revalidate_response = user_interface.add_sized(
+                    button_size,
+                    Button::new_from_theme(theme)
+                        .background_color(theme.background_control_warning_dark)
+                        .disabled(element_scanner_results_view_data.is_revalidating_snapshot)
+                        .with_tooltip_text("Revalidate snapshot. Some results are over memory that changed since it was snapshotted."),
+                );
+
+                IconDraw::draw(user_interface, revalidate_response.rect, &theme.icon_library.icon_handle_navigation_refresh);
+
+                if revalidate_response.clicked() && !element_scanner_results_view_data.is_revalidating_snapshot {
+                    *self.element_sanner_result_frame_action = ElementScannerResultFrameAction::RevalidateSnapshot;
+                }
+            }
+
+            // Overflow menu.
+            let more_actions_button = user_interface.add_sized(
+                button_size,
+                Button::new_from_theme(theme)
+                    .background_color(Color32::TRANSPARENT)
+                    .with_tooltip_text("More actions."),
+            );
+            IconDraw::draw(user_interface, more_actions_button.rect, &theme.icon_library.icon_handle_common_properties);
+
+            // The watches are listed again each time the menu opens, as they are only known to the engine.
+            if more_actions_button.clicked() {
+                should_refresh_value_watches = true;
+            }
+
+            Popup::menu(&more_actions_button)
+                .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                .show(|user_interface| {
+                    user_interface.label(RichText::new("Watches").color(theme.foreground));
+                    user_interface.separator();
+
+                    if !self
+                        .app_context
+                        .engine_unprivileged_state
+                        .has_capabilities(EngineCapabilities::VALUE_WATCH)
+                    {
+                        user_interface.label("Change notifications are not supported by the connected engine.");
+                        return;
+                    }
+
+                    let value_watches = match self
+                        .value_watches_view_data
+                        .read("Element scanner results action bar watches")
+                    {
+                        Some(value_watches_view_data) => value_watches_view_data.value_watches.clone(),
+                        None => return,
+                    };
+
+                    if value_watches.is_empty() {
+                        user_interface.label("No values are being watched. Use Notify on change from the context menu of a result or project entry.");
+                    }
+
+                    for value_watch in value_watches {
+                        user_interface.horizontal(|user_interface| {
+                            let address_text = if value_watch.module_name.is_empty() {
+                                format!("{:X}", value_watch.address)
+                            } else {
+                                format!("{}+{:X}", value_watch.module_name, value_watch.address)
+                            };
+                            let watch_text = if value_watch.label.is_empty() {
+                                address_text
+                            } else {
+                                format!("{} ({})", value_watch.label, address_text)
+                            };
+
+                            user_interface.label(format!("{}, on {}", watch_text, value_watch.condition.get_description()));
+
+                            if user_interface.button("Stop").clicked() {
+                                value_watch_to_stop = Some(value_watch.watch_id);
+                            }
+                        });
+                    }
+                });
         });
 
         right_ui.add_space(8.0);
@@ -237,6 +447,50 @@ impl<'lifetime> Widget for ElementScannerResultsActionBarView<'lifetime> {
         );
         right_ui.add_space(8.0);
 
+        if !is_infinite_scroll {
+            let mut value_filter = element_scanner_results_view_data.value_filter.clone();
+            let value_filter_response = right_ui
+                .add(
+                    TextEdit::singleline(&mut value_filter)
+                        .hint_text("Filter values...")
+                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                        .text_color(theme.foreground)
+                        .background_color(theme.background_primary)
+                        .desired_width(Self::VALUE_FILTER_WIDTH),
+                )
+                .on_hover_text("Only show rows of this page whose value contains this text.");
+            let active_display_format = element_scanner_view_data.active_display_format;
+
+            // Values are matched as they are shown, so switching the display format matches them again.
+            if value_filter_response.changed() || element_scanner_results_view_data.value_filter_display_format != active_display_format {
+                element_scanner_results_view_data.set_value_filter(value_filter, active_display_format);
+            }
+        }
+
+        // Drop the guards before switching modes, as switching requeries results.
+        drop(element_scanner_results_view_data);
+        drop(element_scanner_view_data);
+
+        if should_refresh_value_watches {
+            ValueWatchesViewData::refresh_value_watches(self.value_watches_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if let Some(watch_id) = value_watch_to_stop {
+            ValueWatchesViewData::stop_watch(
+                self.value_watches_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                watch_id,
+            );
+        }
+
+        if should_toggle_infinite_scroll {
+            ElementScannerResultsViewData::set_infinite_scroll(
+                self.element_scanner_results_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                !is_infinite_scroll,
+            );
+        }
+
         response
     }
 }
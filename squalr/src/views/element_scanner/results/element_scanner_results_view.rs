@@ -1,6 +1,12 @@
 use crate::{
     app_context::AppContext,
-    ui::{draw::icon_draw::IconDraw, ui_trace, widgets::controls::check_state::CheckState},
+    models::{appearance::appearance_settings::AppearanceSettings, command_palette::palette_command::PaletteCommand, docking::docking_command::DockingCommand},
+    ui::{
+        converters::data_type_to_string_converter::DataTypeToStringConverter,
+        draw::icon_draw::IconDraw,
+        ui_trace,
+        widgets::controls::{address_resolution_label::AddressResolutionLabel, check_state::CheckState},
+    },
     views::{
         disassembler::view_data::disassembler_view_data::DisassemblerViewData,
         element_scanner::{
@@ -9,20 +15,50 @@ use crate::{
                 element_scanner_results_action_bar_view::ElementScannerResultsActionBarView,
                 view_data::{
                     element_scanner_result_frame_action::ElementScannerResultFrameAction, element_scanner_results_view_data::ElementScannerResultsViewData,
+                    scan_result_freeze_undo::ScanResultFreezeUndo, scan_result_row_navigation::ScanResultRowNavigation,
+                    scan_results_exporter::ScanResultsExportFormat, scan_results_sort::ScanResultsSortColumn, stale_results_policy::StaleResultsPolicy,
                 },
             },
             scanner::{element_scanner_view_state::ElementScannerViewState, view_data::element_scanner_view_data::ElementScannerViewData},
         },
+        memory_accessors::{memory_accessors_view::MemoryAccessorsView, view_data::memory_accessors_view_data::MemoryAccessorsViewData},
         memory_viewer::view_data::memory_viewer_view_data::MemoryViewerViewData,
         pointer_scanner::view_data::pointer_scanner_view_data::PointerScannerViewData,
+        main_window::view_data::{toasts_view_data::ToastsViewData, value_watches_view_data::ValueWatchesViewData},
         struct_viewer::view_data::struct_viewer_view_data::StructViewerViewData,
     },
 };
-use eframe::egui::{Align, Align2, CursorIcon, Direction, Layout, Response, ScrollArea, Sense, Spinner, Ui, Widget, Window};
-use epaint::{Margin, Rect, Vec2, pos2, vec2};
-use squalr_engine_api::{dependency_injection::dependency::Dependency, structures::scan_results::scan_result::ScanResult};
+use eframe::egui::{
+    Align, Align2, Area, Button, CursorIcon, Direction, Frame, Id, KeyboardShortcut, Layout, Modifiers, Order, ProgressBar, Response, RichText, ScrollArea,
+    Sense, Spinner, TextEdit, Ui, Widget, Window,
+};
+use epaint::{CornerRadius, Margin, Rect, Stroke, Vec2, pos2, vec2};
+use squalr_engine_api::{
+    dependency_injection::dependency::Dependency,
+    structures::{
+        data_types::{
+            built_in_types::{
+                f32::data_type_f32::DataTypeF32, f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be,
+                i8::data_type_i8::DataTypeI8, i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32,
+                i32be::data_type_i32be::DataTypeI32be, i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be, u8::data_type_u8::DataTypeU8,
+                u16::data_type_u16::DataTypeU16, u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32, u32be::data_type_u32be::DataTypeU32be,
+                u64::data_type_u64::DataTypeU64, u64be::data_type_u64be::DataTypeU64be,
+            },
+            data_type_ref::DataTypeRef,
+        },
+        data_values::{
+            anonymous_value_string_format::AnonymousValueStringFormat,
+            value_nudge::{ValueNudge, ValueNudgeStep},
+        },
+        memory::memory_watch_type::MemoryWatchType,
+        scan_results::scan_result::ScanResult,
+    },
+    registries::symbols::symbol_registry::SymbolRegistry,
+};
 use std::collections::HashSet;
+use std::ops::Range;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Clone)]
 pub struct ElementScannerResultsView {
@@ -33,11 +69,49 @@ pub struct ElementScannerResultsView {
     memory_viewer_view_data: Dependency<MemoryViewerViewData>,
     disassembler_view_data: Dependency<DisassemblerViewData>,
     pointer_scanner_view_data: Dependency<PointerScannerViewData>,
+    memory_accessors_view_data: Dependency<MemoryAccessorsViewData>,
+    value_watches_view_data: Dependency<ValueWatchesViewData>,
+    toasts_view_data: Dependency<ToastsViewData>,
 }
 
 impl ElementScannerResultsView {
     pub const WINDOW_ID: &'static str = "window_element_scanner_results";
 
+    /// The display formats that a single result can be shown in, regardless of the active display format.
+    const DISPLAY_FORMAT_OVERRIDES: [(&'static str, AnonymousValueStringFormat); 3] = [
+        ("Decimal", AnonymousValueStringFormat::Decimal),
+        ("Hexadecimal", AnonymousValueStringFormat::Hexadecimal),
+        ("Binary", AnonymousValueStringFormat::Binary),
+    ];
+    /// The segments of the display format toggle in the value column header, along with the name of each format for tooltips.
+    const DISPLAY_FORMAT_TOGGLES: [(&'static str, &'static str, AnonymousValueStringFormat); 3] = [
+        ("Dec", "decimal", AnonymousValueStringFormat::Decimal),
+        ("Hex", "hexadecimal", AnonymousValueStringFormat::Hexadecimal),
+        ("Bin", "binary", AnonymousValueStringFormat::Binary),
+    ];
+    /// Fixed width types that selected results can be shown as. Strings and byte arrays are left out, as their length cannot
+    /// be known from the address alone.
+    const CONVERTIBLE_DATA_TYPE_IDS: [&'static str; 18] = [
+        DataTypeU8::DATA_TYPE_ID,
+        DataTypeI8::DATA_TYPE_ID,
+        DataTypeU16::DATA_TYPE_ID,
+        DataTypeI16::DATA_TYPE_ID,
+        DataTypeU32::DATA_TYPE_ID,
+        DataTypeI32::DATA_TYPE_ID,
+        DataTypeU64::DATA_TYPE_ID,
+        DataTypeI64::DATA_TYPE_ID,
+        DataTypeF32::DATA_TYPE_ID,
+        DataTypeF64::DATA_TYPE_ID,
+        DataTypeU16be::DATA_TYPE_ID,
+        DataTypeI16be::DATA_TYPE_ID,
+        DataTypeU32be::DATA_TYPE_ID,
+        DataTypeI32be::DATA_TYPE_ID,
+        DataTypeU64be::DATA_TYPE_ID,
+        DataTypeI64be::DATA_TYPE_ID,
+        DataTypeF32be::DATA_TYPE_ID,
+        DataTypeF64be::DATA_TYPE_ID,
+    ];
+
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let element_scanner_view_data = app_context
             .dependency_container
@@ -57,6 +131,17 @@ impl ElementScannerResultsView {
         let pointer_scanner_view_data = app_context
             .dependency_container
             .get_dependency::<PointerScannerViewData>();
+        let memory_accessors_view_data = app_context
+            .dependency_container
+            .get_dependency::<MemoryAccessorsViewData>();
+        let value_watches_view_data = app_context
+            .dependency_container
+            .get_dependency::<ValueWatchesViewData>();
+        let toasts_view_data = app_context
+            .dependency_container
+            .get_dependency::<ToastsViewData>();
+
+        Self::register_commands(&app_context, &element_scanner_results_view_data);
 
         Self {
             app_context,
@@ -66,9 +151,421 @@ impl ElementScannerResultsView {
             memory_viewer_view_data,
             disassembler_view_data,
             pointer_scanner_view_data,
+            memory_accessors_view_data,
+            value_watches_view_data,
+            toasts_view_data,
+        }
+    }
+
+    /// Registers the selection actions with the command palette. Freezing goes through the same deferred frame action as the
+    /// context menu, such that it acts on the selection at the time it runs and offers the same undo toast.
+    fn register_commands(
+        app_context: &Arc<AppContext>,
+        element_scanner_results_view_data: &Dependency<ElementScannerResultsViewData>,
+    ) {
+        for (command_id, label, is_frozen) in [
+            ("element_scanner_results.freeze_selection", "Freeze Selected Results", true),
+            ("element_scanner_results.unfreeze_selection", "Unfreeze Selected Results", false),
+        ] {
+            let element_scanner_results_view_data_for_invoke = element_scanner_results_view_data.clone();
+            let element_scanner_results_view_data_for_reason = element_scanner_results_view_data.clone();
+
+            app_context.command_registry.register(
+                PaletteCommand::new(command_id, "Results", label, move || {
+                    if let Some(mut view_data) = element_scanner_results_view_data_for_invoke.write("Element scanner results freeze command") {
+                        view_data.pending_frame_action = ElementScannerResultFrameAction::ToggleFreezeSelection(is_frozen);
+                    }
+                })
+                .with_disabled_reason(move || {
+                    element_scanner_results_view_data_for_reason
+                        .read("Element scanner results freeze command disabled reason")
+                        .filter(|view_data| !view_data.selection.is_empty())
+                        .is_none()
+                        .then(|| "No scan results are selected.".to_string())
+                }),
+            );
+        }
+
+        let element_scanner_results_view_data = element_scanner_results_view_data.clone();
+
+        app_context.command_registry.register(
+            PaletteCommand::new("element_scanner_results.select_all", "Results", "Select All Results", move || {
+                ElementScannerResultsViewData::select_all(element_scanner_results_view_data.clone())
+            })
+            .with_shortcut(KeyboardShortcut::new(Modifiers::COMMAND, eframe::egui::Key::A)),
+        );
+    }
+
+    /// Gets the address and module that a value watch on this result is keyed by, which matches how the result is frozen.
+    fn get_value_watch_target(scan_result: &ScanResult) -> (u64, String) {
+        if scan_result.is_module() {
+            (scan_result.get_module_offset(), scan_result.get_module().to_string())
+        } else {
+            (scan_result.get_address(), String::new())
+        }
+    }
+
+    /// Reports a freeze batch in a toast, with a button to put every result back to the state it held before the batch.
+    fn show_freeze_undo_toast(
+        &self,
+        freeze_undo: Option<ScanResultFreezeUndo>,
+    ) {
+        let Some(freeze_undo) = freeze_undo else {
+            return;
+        };
+        let element_scanner_results_view_data = self.element_scanner_results_view_data.clone();
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        ToastsViewData::show_with_action(self.toasts_view_data.clone(), freeze_undo.get_summary(), "Undo", move || {
+            ElementScannerResultsViewData::undo_freeze(element_scanner_results_view_data.clone(), engine_unprivileged_state.clone(), &freeze_undo);
+        });
+    }
+
+    /// Asks how a held freeze or write should treat stale results, then applies it as decided.
+    fn show_stale_results_confirmation_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let stale_result_count = match self
+            .element_scanner_results_view_data
+            .read("Element scanner stale results confirmation read")
+        {
+            Some(view_data) => match &view_data.stale_results_confirmation {
+                Some(stale_results_confirmation) => stale_results_confirmation.stale_result_count,
+                None => return,
+            },
+            None => return,
+        };
+        let mut chosen_policy = None;
+        let mut should_cancel = false;
+
+        Window::new("Stale results")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |ui| {
+                ui.label(format!(
+                    "{} of the targeted results are over memory that was freed, shrank, or changed protection since it was snapshotted.",
+                    stale_result_count
+                ));
+
+                ui.horizontal(|ui| {
+                    if ui.button("Include stale results").clicked() {
+                        chosen_policy = Some(StaleResultsPolicy::Include);
+                    }
+                    if ui.button("Skip stale results").clicked() {
+                        chosen_policy = Some(StaleResultsPolicy::Skip);
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_cancel = true;
+                    }
+                });
+            });
+
+        if !should_cancel && chosen_policy.is_none() {
+            return;
+        }
+
+        let Some(stale_results_confirmation) = ElementScannerResultsViewData::take_stale_results_confirmation(self.element_scanner_results_view_data.clone())
+        else {
+            return;
+        };
+        let Some(stale_results_policy) = chosen_policy else {
+            return;
+        };
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        match stale_results_confirmation.frame_action {
+            ElementScannerResultFrameAction::FreezeIndex(index, is_frozen) => {
+                ElementScannerResultsViewData::set_scan_result_frozen(
+                    self.element_scanner_results_view_data.clone(),
+                    engine_unprivileged_state,
+                    index,
+                    is_frozen,
+                    stale_results_policy,
+                );
+            }
+            ElementScannerResultFrameAction::ToggleFreezeSelection(is_frozen) => {
+                let freeze_undo = ElementScannerResultsViewData::toggle_selected_scan_results_frozen(
+                    self.element_scanner_results_view_data.clone(),
+                    engine_unprivileged_state,
+                    is_frozen,
+                    stale_results_policy,
+                );
+
+                self.show_freeze_undo_toast(freeze_undo);
+            }
+            ElementScannerResultFrameAction::ToggleFreezePage(is_frozen) => {
+                let freeze_undo = ElementScannerResultsViewData::toggle_page_scan_results_frozen(
+                    self.element_scanner_results_view_data.clone(),
+                    engine_unprivileged_state,
+                    is_frozen,
+                    stale_results_policy,
+                );
+
+                self.show_freeze_undo_toast(freeze_undo);
+            }
+            ElementScannerResultFrameAction::CommitValueToSelection(edit_value) => {
+                ElementScannerResultsViewData::set_selected_scan_results_value(
+                    self.element_scanner_results_view_data.clone(),
+                    engine_unprivileged_state,
+                    ScanResult::PROPERTY_NAME_VALUE,
+                    edit_value,
+                    stale_results_policy,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Places copied rows on the clipboard once they are built, and shows the progress of a large copy, or the outcome of the
+    /// last one, in the bottom right of the window.
+    fn show_copy_toast(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        if let Some(copied_text) = ElementScannerResultsViewData::take_pending_clipboard_text(self.element_scanner_results_view_data.clone()) {
+            user_interface.ctx().copy_text(copied_text);
+        }
+
+        let (copy_progress, copy_status) = match self
+            .element_scanner_results_view_data
+            .read("Element scanner copy toast read")
+        {
+            Some(view_data) => (view_data.copy_progress, view_data.copy_status.clone()),
+            None => return,
+        };
+
+        if copy_progress.is_none() && copy_status.is_none() {
+            return;
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_cancel_copy = false;
+
+        Area::new(Id::new("element_scanner_results_copy_toast"))
+            .order(Order::Foreground)
+            .anchor(Align2::RIGHT_BOTTOM, vec2(-16.0, -40.0))
+            .show(user_interface.ctx(), |user_interface| {
+                Frame::new()
+                    .fill(theme.background_panel)
+                    .stroke(Stroke::new(1.0, theme.submenu_border))
+                    .corner_radius(CornerRadius::same(4))
+                    .inner_margin(Margin::same(8))
+                    .show(user_interface, |user_interface| {
+                        user_interface.set_max_width(320.0);
+
+                        if let Some((built_row_count, row_count)) = copy_progress {
+                            user_interface.label(RichText::new("Copying rows...").color(theme.foreground));
+                            user_interface.add(
+                                ProgressBar::new(built_row_count as f32 / row_count.max(1) as f32)
+                                    .text(format!("{} / {}", built_row_count, row_count))
+                                    .desired_width(300.0),
+                            );
+
+                            if user_interface.button("Cancel").clicked() {
+                                should_cancel_copy = true;
+                            }
+                        } else if let Some((copy_status, _reported_at)) = &copy_status {
+                            user_interface.label(RichText::new(copy_status).color(theme.foreground));
+                        }
+                    });
+            });
+
+        if should_cancel_copy {
+            ElementScannerResultsViewData::cancel_copy(self.element_scanner_results_view_data.clone());
+        }
+
+        // Progress is written from the copy thread, and the outcome is dropped once shown for long enough, so keep repainting.
+        user_interface
+            .ctx()
+            .request_repaint_after(Duration::from_millis(100));
+    }
+
+    /// Asks for the address to move a scan result to, showing why the address cannot be resolved if it is invalid.
+    fn show_change_address_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let is_change_address_dialog_shown = self
+            .element_scanner_results_view_data
+            .read("Element scanner change address dialog read")
+            .map(|view_data| view_data.address_edit.is_some())
+            .unwrap_or(false);
+
+        if !is_change_address_dialog_shown {
+            return;
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_apply = false;
+        let mut should_close = false;
+
+        Window::new("Change address")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |ui| {
+                let mut view_data = match self
+                    .element_scanner_results_view_data
+                    .write("Element scanner change address dialog write")
+                {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let Some(address_edit) = view_data.address_edit.as_mut() else {
+                    return;
+                };
+
+                ui.add_enabled_ui(!address_edit.is_applying, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Address");
+
+                        let address_response = ui.add(
+                            TextEdit::singleline(&mut address_edit.address_string)
+                                .hint_text("7FF6A000 or game.exe+1A0")
+                                .desired_width(240.0),
+                        );
+
+                        if address_response.changed() {
+                            address_edit.address_resolution = None;
+                        }
+
+                        should_apply = address_response.lost_focus() && ui.input(|input| input.key_pressed(eframe::egui::Key::Enter));
+                    });
+                });
+
+                if let Some(address_resolution) = &address_edit.address_resolution {
+                    ui.add(AddressResolutionLabel::new(theme, address_resolution));
+                }
+
+                ui.horizontal(|ui| {
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                    if ui
+                        .add_enabled(!address_edit.is_applying && !address_edit.address_string.trim().is_empty(), Button::new("OK"))
+                        .clicked()
+                    {
+                        should_apply = true;
+                    }
+                    if address_edit.is_applying {
+                        ui.add(Spinner::new());
+                    }
+                });
+            });
+
+        if should_close {
+            ElementScannerResultsViewData::hide_change_address_dialog(self.element_scanner_results_view_data.clone());
+        } else if should_apply {
+            ElementScannerResultsViewData::apply_address_edit(
+                self.element_scanner_results_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+            );
+        }
+    }
+
+    /// Asks where and in which format to export every scan result, then shows the progress of the export.
+    fn show_export_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let is_export_dialog_shown = self
+            .element_scanner_results_view_data
+            .read("Element scanner export dialog read")
+            .map(|view_data| view_data.show_export_dialog)
+            .unwrap_or(false);
+
+        if !is_export_dialog_shown {
+            return;
+        }
+
+        let mut should_export = false;
+        let mut should_cancel_export = false;
+        let mut should_close = false;
+
+        Window::new("Export results")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |ui| {
+                let mut view_data = match self
+                    .element_scanner_results_view_data
+                    .write("Element scanner export dialog write")
+                {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let is_exporting = view_data.export_progress.is_some();
+
+                ui.add_enabled_ui(!is_exporting, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("File");
+                        ui.add(TextEdit::singleline(&mut view_data.export_path).desired_width(280.0));
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Format");
+
+                        for export_format in ScanResultsExportFormat::ALL {
+                            if ui
+                                .radio(view_data.export_format == export_format, export_format.get_display_name())
+                                .clicked()
+                            {
+                                view_data.set_export_format(export_format);
+                            }
+                        }
+                    });
+                });
+
+                if let Some((exported_count, result_count)) = view_data.export_progress {
+                    ui.add(
+                        ProgressBar::new(exported_count as f32 / result_count.max(1) as f32)
+                            .text(format!("{} / {}", exported_count, result_count))
+                            .desired_width(320.0),
+                    );
+
+                    // Progress is written from the export thread, so keep repainting to show it.
+                    ui.ctx().request_repaint_after(Duration::from_millis(100));
+                } else if let Some(export_status) = &view_data.export_status {
+                    ui.label(export_status.as_str());
+                }
+
+                ui.horizontal(|ui| {
+                    if is_exporting {
+                        if ui.button("Cancel").clicked() {
+                            should_cancel_export = true;
+                        }
+                    } else {
+                        if ui.button("Close").clicked() {
+                            should_close = true;
+                        }
+                        if ui
+                            .add_enabled(!view_data.export_path.trim().is_empty(), Button::new("Export"))
+                            .clicked()
+                        {
+                            should_export = true;
+                        }
+                    }
+                });
+            });
+
+        if should_cancel_export {
+            ElementScannerResultsViewData::cancel_export(self.element_scanner_results_view_data.clone());
+        } else if should_close {
+            ElementScannerResultsViewData::hide_export_dialog(self.element_scanner_results_view_data.clone());
+        } else if should_export {
+            let active_display_format = self
+                .element_scanner_view_data
+                .read("Element scanner export display format")
+                .map(|view_data| view_data.active_display_format)
+                .unwrap_or_default();
+
+            ElementScannerResultsViewData::export_scan_results(
+                self.element_scanner_results_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                active_display_format,
+            );
         }
     }
 }
+
 impl Widget for ElementScannerResultsView {
     fn ui(
         self,
@@ -78,11 +575,15 @@ impl Widget for ElementScannerResultsView {
         const BAR_THICKNESS: f32 = 4.0;
         const MINIMUM_COLUMN_PIXEL_WIDTH: f32 = 80.0;
         const MINIMUM_SPLITTER_PIXEL_GAP: f32 = 40.0;
-        const AUTO_PAGE_SIZE_ROW_HEIGHT: f32 = 32.0;
         const AUTO_PAGE_SIZE_ROW_BUFFER: u32 = 4;
         const AUTO_PAGE_SIZE_MAX: u32 = 1_000_000;
+        const SCAN_PROGRESS_BAR_HEIGHT: f32 = 3.0;
+        const FIRST_SCAN_VALUE_COLUMN_WIDTH: f32 = 120.0;
 
         let theme = &self.app_context.theme;
+        let row_density = AppearanceSettings::get_row_density();
+        let value_color_rules = AppearanceSettings::get_value_color_rules();
+        let row_height = row_density.get_row_height();
         let mut new_value_splitter_ratio: Option<f32> = None;
         let mut new_previous_value_splitter_ratio: Option<f32> = None;
 
@@ -94,14 +595,33 @@ impl Widget for ElementScannerResultsView {
             .unwrap_or(ElementScannerResultFrameAction::None);
 
         let mut should_open_change_value_dialog = false;
+        let mut change_address_index: Option<usize> = None;
         let mut should_select_all = false;
+        let mut should_navigate_previous_page = false;
+        let mut should_navigate_next_page = false;
         let mut should_copy_selected_addresses = false;
+        let mut should_copy_page_as_formatted_text = false;
+        let mut should_open_export_dialog = false;
         let mut should_copy_selected_rows = false;
+        let mut should_toggle_freeze_selection = false;
+        let mut should_toggle_sort_column: Option<ScanResultsSortColumn> = None;
+        let mut should_toggle_first_scan_value_column = false;
+        let mut new_active_display_format: Option<AnonymousValueStringFormat> = None;
         let mut copy_text: Option<String> = None;
         let mut paste_selection_range: Option<(i32, i32)> = None;
         let mut browse_memory_address: Option<u64> = None;
         let mut disassemble_address: Option<u64> = None;
         let mut pointer_scan_address: Option<u64> = None;
+        let mut page_trap_watch_target: Option<(u64, u64, MemoryWatchType)> = None;
+        let mut value_watch_target: Option<(u64, String, DataTypeRef)> = None;
+        let value_flash_strengths = self
+            .value_watches_view_data
+            .read("Element scanner results value flashes")
+            .map(|view_data| view_data.get_flash_strengths(Instant::now()))
+            .unwrap_or_default();
+        let value_change_time = Instant::now();
+        let mut has_value_change_highlights = false;
+        let mut visible_row_range: Option<Range<u64>> = None;
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |mut user_interface| {
@@ -116,13 +636,32 @@ impl Widget for ElementScannerResultsView {
                     response
                 };
 
-                let (mut value_splitter_ratio, mut previous_value_splitter_ratio) = match self
+                let (
+                    mut value_splitter_ratio,
+                    mut previous_value_splitter_ratio,
+                    is_infinite_scroll,
+                    is_first_scan_value_column_shown,
+                    sort,
+                    has_stride_repeat_counts,
+                ) = match self
                     .element_scanner_results_view_data
                     .read("Element scanner results view")
                 {
                     Some(element_scanner_results_view_data) => (
                         element_scanner_results_view_data.value_splitter_ratio,
                         element_scanner_results_view_data.previous_value_splitter_ratio,
+                        element_scanner_results_view_data.is_infinite_scroll,
+                        element_scanner_results_view_data.is_first_scan_value_column_shown,
+                        element_scanner_results_view_data.sort,
+                        element_scanner_results_view_data
+                            .current_scan_results
+                            .iter()
+                            .any(|scan_result| {
+                                scan_result
+                                    .get_base_result()
+                                    .get_stride_repeat_count()
+                                    .is_some()
+                            }),
                     ),
                     None => return,
                 };
@@ -137,6 +676,27 @@ impl Widget for ElementScannerResultsView {
                     .painter()
                     .rect_filled(separator_rect, 0.0, theme.background_control);
 
+                // Paint scan progress over the top edge of the header rather than allocating space for it, such that rows never shift.
+                let scan_progress = self
+                    .element_scanner_view_data
+                    .read("Element scanner results view scan progress")
+                    .and_then(|element_scanner_view_data| {
+                        (element_scanner_view_data.view_state == ElementScannerViewState::ScanInProgress).then_some(element_scanner_view_data.scan_progress)
+                    });
+
+                if let Some(scan_progress) = scan_progress {
+                    let progress_track_rectangle = Rect::from_min_size(header_rectangle.min, vec2(header_rectangle.width(), SCAN_PROGRESS_BAR_HEIGHT));
+                    let progress_fill_rectangle =
+                        progress_track_rectangle.with_max_x(progress_track_rectangle.min.x + progress_track_rectangle.width() * scan_progress.clamp(0.0, 1.0));
+
+                    user_interface
+                        .painter()
+                        .rect_filled(progress_track_rectangle, 0.0, theme.background_control);
+                    user_interface
+                        .painter()
+                        .rect_filled(progress_fill_rectangle, 0.0, theme.background_control_primary);
+                }
+
                 let footer_height = ElementScannerResultsActionBarView::FOOTER_HEIGHT;
                 let content_clip_rectangle = user_interface
                     .available_rect_before_wrap()
@@ -154,18 +714,26 @@ impl Widget for ElementScannerResultsView {
                     return;
                 }
 
-                if content_height > 0.0 {
-                    let rows_fit = (content_height / AUTO_PAGE_SIZE_ROW_HEIGHT).floor().max(1.0) as u32;
+                // Infinite scroll fetches fixed size pages on demand, so the page size only follows the view height in paged mode.
+                if content_height > 0.0 && !is_infinite_scroll {
+                    let rows_fit = row_density.get_rows_fitting(content_height);
                     let desired_page_size = rows_fit
                         .saturating_add(AUTO_PAGE_SIZE_ROW_BUFFER)
                         .min(AUTO_PAGE_SIZE_MAX)
                         .max(1);
 
-                    ElementScannerResultsViewData::set_page_size_override(
+                    let is_requery_pending = ElementScannerResultsViewData::set_page_size_override(
                         self.element_scanner_results_view_data.clone(),
                         self.app_context.engine_unprivileged_state.clone(),
                         Some(desired_page_size),
                     );
+
+                    // Wake up once the resize settles, since no further input may arrive to trigger the deferred requery.
+                    if is_requery_pending {
+                        user_interface
+                            .ctx()
+                            .request_repaint_after(Duration::from_millis(ElementScannerResultsViewData::PAGE_SIZE_REQUERY_DEBOUNCE_MS));
+                    }
                 }
 
                 if value_splitter_ratio <= 0.0 || previous_value_splitter_ratio <= 0.0 || previous_value_splitter_ratio <= value_splitter_ratio {
@@ -211,40 +779,152 @@ impl Widget for ElementScannerResultsView {
                     &self.app_context.theme.icon_library.icon_handle_results_freeze,
                 );
 
-                // Address column header.
+                // Sortable column headers. Clicking a header sorts the current page by its column, which only applies in paged mode.
                 let text_left_padding = 8.0;
-                let address_header_x = faux_address_splitter_position_x + text_left_padding;
-                let address_header_position = pos2(address_header_x, header_rectangle.center().y);
-
-                user_interface.painter().text(
-                    address_header_position,
-                    Align2::LEFT_CENTER,
-                    "Address",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
+                let repeat_count_header_width = if has_stride_repeat_counts { MINIMUM_COLUMN_PIXEL_WIDTH } else { 0.0 };
+                let first_scan_value_column_width = if is_first_scan_value_column_shown {
+                    FIRST_SCAN_VALUE_COLUMN_WIDTH
+                } else {
+                    0.0
+                };
+                let first_scan_value_position_x =
+                    is_first_scan_value_column_shown.then_some(header_rectangle.max.x - repeat_count_header_width - first_scan_value_column_width);
+                let sortable_headers = [
+                    (
+                        ScanResultsSortColumn::Address,
+                        "Address",
+                        faux_address_splitter_position_x,
+                        value_splitter_position_x,
+                    ),
+                    (
+                        ScanResultsSortColumn::Value,
+                        "Value",
+                        value_splitter_position_x,
+                        previous_value_splitter_position_x,
+                    ),
+                    (
+                        ScanResultsSortColumn::PreviousValue,
+                        "Previous Value",
+                        previous_value_splitter_position_x,
+                        header_rectangle.max.x - repeat_count_header_width - first_scan_value_column_width,
+                    ),
+                ];
+
+                let mut value_header_text_max_x = value_splitter_position_x;
+
+                for (column, header_text, header_min_x, header_max_x) in sortable_headers {
+                    let header_label_position = pos2(header_min_x + text_left_padding, header_rectangle.center().y);
+                    let header_text_rectangle = user_interface.painter().text(
+                        header_label_position,
+                        Align2::LEFT_CENTER,
+                        header_text,
+                        theme.font_library.font_noto_sans.font_header.clone(),
+                        theme.foreground,
+                    );
 
-                // Value column header.
-                let value_label_position = pos2(value_splitter_position_x + text_left_padding, header_rectangle.center().y);
+                    if column == ScanResultsSortColumn::Value {
+                        value_header_text_max_x = header_text_rectangle.max.x;
+                    }
 
-                user_interface.painter().text(
-                    value_label_position,
-                    Align2::LEFT_CENTER,
-                    "Value",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
+                    if is_infinite_scroll {
+                        continue;
+                    }
 
-                // Previous value column header.
-                let previous_value_label_position = pos2(previous_value_splitter_position_x + text_left_padding, header_rectangle.center().y);
+                    if let Some(sort) = sort.filter(|sort| sort.column == column) {
+                        let sort_arrow = if sort.is_descending {
+                            &theme.icon_library.icon_handle_navigation_down_arrow_small
+                        } else {
+                            &theme.icon_library.icon_handle_navigation_up_arrow_small
+                        };
+                        let sort_arrow_center = pos2(header_text_rectangle.max.x + 10.0, header_rectangle.center().y);
 
-                user_interface.painter().text(
-                    previous_value_label_position,
-                    Align2::LEFT_CENTER,
-                    "Previous Value",
-                    theme.font_library.font_noto_sans.font_header.clone(),
-                    theme.foreground,
-                );
+                        IconDraw::draw_sized(user_interface, sort_arrow_center, vec2(12.0, 12.0), sort_arrow);
+                    }
+
+                    // Leave the splitters at either edge draggable.
+                    let header_click_rectangle = Rect::from_min_max(
+                        pos2(header_min_x + BAR_THICKNESS, header_rectangle.min.y),
+                        pos2((header_max_x - BAR_THICKNESS).max(header_min_x + BAR_THICKNESS), header_rectangle.max.y),
+                    );
+                    let header_response = user_interface
+                        .interact(header_click_rectangle, user_interface.id().with(("sort_header", header_text)), Sense::click())
+                        .on_hover_cursor(CursorIcon::PointingHand)
+                        .on_hover_text("Sort this page by this column. Click again to reverse the order, and a third time to restore snapshot order.");
+
+                    if header_response.clicked() {
+                        should_toggle_sort_column = Some(column);
+                    }
+                }
+
+                // Display format toggle, placed after the value header and its sort arrow. The toggle is added after the sortable headers,
+                // such that clicking a segment does not also sort the column. It is hidden if the value column is too narrow to fit it.
+                let (active_display_format, supported_formats, selected_data_type_id) = self
+                    .element_scanner_view_data
+                    .read("Element scanner results view display format toggle")
+                    .map(|element_scanner_view_data| {
+                        (
+                            element_scanner_view_data.active_display_format,
+                            SymbolRegistry::get_instance().get_supported_anonymous_value_string_formats(&element_scanner_view_data.selected_data_type),
+                            element_scanner_view_data
+                                .selected_data_type
+                                .get_data_type_id()
+                                .to_string(),
+                        )
+                    })
+                    .unwrap_or_default();
+                let display_format_segment_size = vec2(32.0, 20.0);
+                let display_format_toggle_min_x = value_header_text_max_x + 24.0;
+                let display_format_toggle_max_x = display_format_toggle_min_x + display_format_segment_size.x * Self::DISPLAY_FORMAT_TOGGLES.len() as f32;
+
+                if display_format_toggle_max_x <= previous_value_splitter_position_x - BAR_THICKNESS {
+                    for (segment_index, (segment_text, display_format_name, display_format)) in Self::DISPLAY_FORMAT_TOGGLES.into_iter().enumerate() {
+                        let is_supported = supported_formats.contains(&display_format);
+                        let segment_rectangle = Rect::from_center_size(
+                            pos2(
+                                display_format_toggle_min_x + display_format_segment_size.x * (segment_index as f32 + 0.5),
+                                header_rectangle.center().y,
+                            ),
+                            display_format_segment_size,
+                        );
+                        let segment_response = user_interface
+                            .put(segment_rectangle, |user_interface: &mut Ui| {
+                                user_interface.add_enabled(
+                                    is_supported,
+                                    Button::selectable(active_display_format == display_format, RichText::new(segment_text).small()),
+                                )
+                            })
+                            .on_hover_text(format!("Show values in {}.", display_format_name))
+                            .on_disabled_hover_text(format!("The {} data type cannot be shown in {}.", selected_data_type_id, display_format_name));
+
+                        if segment_response.clicked() && active_display_format != display_format {
+                            new_active_display_format = Some(display_format);
+                        }
+                    }
+                }
+
+                // First scan value column header, not sortable as the column is not part of the sort order.
+                if let Some(first_scan_value_position_x) = first_scan_value_position_x {
+                    user_interface.painter().text(
+                        pos2(first_scan_value_position_x + text_left_padding, header_rectangle.center().y),
+                        Align2::LEFT_CENTER,
+                        "First Scan",
+                        theme.font_library.font_noto_sans.font_header.clone(),
+                        theme.foreground,
+                    );
+                }
+
+                // Repeat count column header, only shown for the results of a stride scan. Counts are right aligned in each row.
+                if has_stride_repeat_counts {
+                    let repeat_count_label_position = pos2(header_rectangle.max.x - text_left_padding, header_rectangle.center().y);
+
+                    user_interface.painter().text(
+                        repeat_count_label_position,
+                        Align2::RIGHT_CENTER,
+                        "Repeats",
+                        theme.font_library.font_noto_sans.font_header.clone(),
+                        theme.foreground,
+                    );
+                }
 
                 // Assume all false.
                 let mut selection_freeze_checkstate = CheckState::False;
@@ -254,7 +934,7 @@ impl Widget for ElementScannerResultsView {
                     .id_salt("element_scanner_result_entries")
                     .max_height(content_height)
                     .auto_shrink([false, false])
-                    .show(&mut user_interface, |user_interface| {
+                    .show_viewport(user_interface, |user_interface, viewport| {
                         let element_scanner_results_view_data = match self
                             .element_scanner_results_view_data
                             .read("Element scanner results view element scanner results view data")
@@ -269,14 +949,16 @@ impl Widget for ElementScannerResultsView {
                             Some(element_scanner_view_data) => element_scanner_view_data,
                             None => return,
                         };
+                        let has_stale_scan_results = element_scanner_results_view_data.has_stale_scan_results();
 
                         user_interface.spacing_mut().menu_margin = Margin::ZERO;
                         user_interface.spacing_mut().window_margin = Margin::ZERO;
                         user_interface.spacing_mut().menu_spacing = 0.0;
                         user_interface.spacing_mut().item_spacing = Vec2::ZERO;
 
+                        // Keep showing the previous rows while a requery is in flight, rather than flashing a spinner.
                         if element_scanner_view_data.view_state == ElementScannerViewState::ScanInProgress
-                            || element_scanner_results_view_data.is_querying_scan_results
+                            || (element_scanner_results_view_data.is_querying_scan_results && element_scanner_results_view_data.current_scan_results.is_empty())
                         {
                             user_interface.allocate_ui_with_layout(
                                 vec2(user_interface.available_width(), 32.0),
@@ -300,6 +982,82 @@ impl Widget for ElementScannerResultsView {
                             should_copy_selected_rows = true;
                         }
 
+                        // Ctrl+Z and Ctrl+Y undo and redo value writes, unless a text field is taking the keys for its own undo.
+                        if input.modifiers.ctrl && !element_scanner_results_view_data.show_change_value_dialog && !user_interface.ctx().wants_keyboard_input() {
+                            if input.key_pressed(eframe::egui::Key::Y) || (input.modifiers.shift && input.key_pressed(eframe::egui::Key::Z)) {
+                                element_sanner_result_frame_action = ElementScannerResultFrameAction::RedoValueWrite;
+                            } else if input.key_pressed(eframe::egui::Key::Z) {
+                                element_sanner_result_frame_action = ElementScannerResultFrameAction::UndoValueWrite;
+                            }
+                        }
+
+                        // Arrow keys, Home, and End move the selection, and extend it with Shift held. Like mouse clicks, moves are
+                        // applied through the frame action after this frame, as the view data is locked for reading here.
+                        let mut keyboard_scroll_target_row: Option<usize> = None;
+
+                        if !element_scanner_results_view_data.show_change_value_dialog && !user_interface.ctx().wants_keyboard_input() {
+                            let viewport_row_count = (viewport.height() / row_height).floor().max(1.0) as i64;
+                            let row_navigation = if input.key_pressed(eframe::egui::Key::ArrowUp) {
+                                Some(ScanResultRowNavigation::Offset(-1))
+                            } else if input.key_pressed(eframe::egui::Key::ArrowDown) {
+                                Some(ScanResultRowNavigation::Offset(1))
+                            } else if input.key_pressed(eframe::egui::Key::Home) {
+                                Some(ScanResultRowNavigation::First)
+                            } else if input.key_pressed(eframe::egui::Key::End) {
+                                Some(ScanResultRowNavigation::Last)
+                            } else if is_infinite_scroll && input.key_pressed(eframe::egui::Key::PageUp) {
+                                Some(ScanResultRowNavigation::Offset(-viewport_row_count))
+                            } else if is_infinite_scroll && input.key_pressed(eframe::egui::Key::PageDown) {
+                                Some(ScanResultRowNavigation::Offset(viewport_row_count))
+                            } else {
+                                None
+                            };
+                            // Navigation moves between rows as listed, which skips rows hidden by the value filter.
+                            let target_row = row_navigation
+                                .and_then(|row_navigation| {
+                                    row_navigation.get_target_row(
+                                        element_scanner_results_view_data
+                                            .selection
+                                            .get_cursor()
+                                            .and_then(|cursor| element_scanner_results_view_data.get_row_position(cursor)),
+                                        element_scanner_results_view_data.get_row_count(),
+                                    )
+                                })
+                                .and_then(|target_row_position| element_scanner_results_view_data.get_row_index(target_row_position));
+
+                            if let Some(target_row) = target_row {
+                                element_sanner_result_frame_action = if input.modifiers.shift {
+                                    ElementScannerResultFrameAction::ExtendSelection(target_row as i32)
+                                } else {
+                                    ElementScannerResultFrameAction::SetSelectionStart(Some(target_row as i32))
+                                };
+                                keyboard_scroll_target_row = Some(target_row);
+                            }
+
+                            // In paged mode, PageUp and PageDown turn the page, which clears the selection.
+                            if !is_infinite_scroll {
+                                should_navigate_previous_page = input.key_pressed(eframe::egui::Key::PageUp);
+                                should_navigate_next_page = input.key_pressed(eframe::egui::Key::PageDown);
+                            }
+
+                            if input.key_pressed(eframe::egui::Key::Enter) && !element_scanner_results_view_data.selection.is_empty() {
+                                should_open_change_value_dialog = true;
+                            }
+
+                            if input.key_pressed(eframe::egui::Key::Space) && !element_scanner_results_view_data.selection.is_empty() {
+                                should_toggle_freeze_selection = true;
+                            }
+                        }
+
+                        // Plus and minus nudge the selection, unless a text field such as the value box is taking the keys.
+                        if !element_scanner_results_view_data.show_change_value_dialog && !user_interface.ctx().wants_keyboard_input() && !input.modifiers.ctrl {
+                            if input.key_pressed(eframe::egui::Key::Plus) || input.key_pressed(eframe::egui::Key::Equals) {
+                                element_sanner_result_frame_action = ElementScannerResultFrameAction::NudgeSelection(ValueNudge::new(ValueNudgeStep::Small, false));
+                            } else if input.key_pressed(eframe::egui::Key::Minus) {
+                                element_sanner_result_frame_action = ElementScannerResultFrameAction::NudgeSelection(ValueNudge::new(ValueNudgeStep::Small, true));
+                            }
+                        }
+
                         if !element_scanner_results_view_data.show_change_value_dialog {
                             if let Some(paste_text) = input.events.iter().find_map(|event| match event {
                                 eframe::egui::Event::Paste(text) => Some(text.clone()),
@@ -331,7 +1089,7 @@ impl Widget for ElementScannerResultsView {
                                 }
 
                                 let mut matched_indices: Vec<i32> = Vec::new();
-                                for (index, scan_result) in element_scanner_results_view_data.current_scan_results.iter().enumerate() {
+                                for (index, scan_result) in element_scanner_results_view_data.iter_loaded_scan_results() {
                                     let local_index = index as i32;
                                     if scan_result.is_module() {
                                         let module = scan_result.get_module().to_ascii_lowercase();
@@ -351,26 +1109,89 @@ impl Widget for ElementScannerResultsView {
                             }
                         }
 
+                        // In infinite scroll mode, the scroll area spans every result, and only the rows within the viewport are drawn.
+                        let row_range = if is_infinite_scroll {
+                            let result_count = element_scanner_results_view_data.result_count;
+                            let first_visible_row = ((viewport.min.y / row_height).floor().max(0.0) as u64).min(result_count);
+                            let last_visible_row = ((viewport.max.y / row_height).ceil().max(0.0) as u64).clamp(first_visible_row, result_count);
+
+                            user_interface.set_min_height(result_count as f32 * row_height);
+
+                            // Rows outside of the viewport are not drawn, so keyboard moves past its edges scroll to the row directly.
+                            if let Some(keyboard_scroll_target_row) = keyboard_scroll_target_row
+                                && !(first_visible_row as usize..last_visible_row as usize).contains(&keyboard_scroll_target_row)
+                            {
+                                let rows_origin = user_interface.cursor().min;
+                                let target_row_rectangle = Rect::from_min_size(
+                                    pos2(rows_origin.x, rows_origin.y + keyboard_scroll_target_row as f32 * row_height),
+                                    vec2(user_interface.available_width(), row_height),
+                                );
+
+                                user_interface.scroll_to_rect(target_row_rectangle, None);
+                            }
+
+                            user_interface.add_space(first_visible_row as f32 * row_height);
+                            visible_row_range = Some(first_visible_row..last_visible_row);
+
+                            first_visible_row as usize..last_visible_row as usize
+                        } else {
+                            0..element_scanner_results_view_data.get_row_count()
+                        };
+
                         user_interface.with_layout(Layout::top_down(Align::Min), |user_interface| {
                             // Draw rows, capture min/max Y.
-                            for index in 0..element_scanner_results_view_data.current_scan_results.len() {
-                                let is_selected = {
-                                    match (
-                                        element_scanner_results_view_data.selection_index_start,
-                                        element_scanner_results_view_data.selection_index_end,
-                                    ) {
-                                        (Some(start), Some(end)) => {
-                                            let (min_index, max_index) = if start <= end { (start, end) } else { (end, start) };
-                                            index as i32 >= min_index && index as i32 <= max_index
+                            for row_position in row_range {
+                                let Some(index) = element_scanner_results_view_data.get_row_index(row_position) else {
+                                    continue;
+                                };
+                                let is_selected = element_scanner_results_view_data.selection.contains(index);
+
+                                let scan_result = match element_scanner_results_view_data.get_scan_result(index) {
+                                    Some(scan_result) => scan_result,
+                                    None => {
+                                        // The page holding this row is still being fetched, so draw a placeholder in its place.
+                                        let (row_rectangle, row_response) = user_interface
+                                            .allocate_exact_size(vec2(user_interface.available_width().max(1.0), row_height), Sense::click());
+                                        let skeleton_height = row_height * 0.4;
+                                        let skeleton_y = row_rectangle.center().y - skeleton_height * 0.5;
+
+                                        if is_selected {
+                                            user_interface
+                                                .painter()
+                                                .rect_filled(row_rectangle, 0.0, theme.selected_background);
+                                        }
+
+                                        for (skeleton_min_x, skeleton_max_x) in [
+                                            (faux_address_splitter_position_x, value_splitter_position_x),
+                                            (value_splitter_position_x, previous_value_splitter_position_x),
+                                            (previous_value_splitter_position_x, row_rectangle.max.x),
+                                        ] {
+                                            let skeleton_rectangle = Rect::from_min_max(
+                                                pos2(skeleton_min_x + 8.0, skeleton_y),
+                                                pos2((skeleton_max_x - 16.0).max(skeleton_min_x + 8.0), skeleton_y + skeleton_height),
+                                            );
+
+                                            user_interface
+                                                .painter()
+                                                .rect_filled(skeleton_rectangle, 2.0, theme.background_control);
+                                        }
+
+                                        if row_response.clicked() {
+                                            let modifiers = user_interface.input(|input| input.modifiers);
+
+                                            element_sanner_result_frame_action = if modifiers.shift {
+                                                ElementScannerResultFrameAction::ExtendSelection(index as i32)
+                                            } else if modifiers.command {
+                                                ElementScannerResultFrameAction::ToggleSelection(index as i32)
+                                            } else {
+                                                ElementScannerResultFrameAction::SetSelectionStart(Some(index as i32))
+                                            };
                                         }
-                                        (Some(start), None) => index as i32 == start,
-                                        (None, Some(end)) => index as i32 == end,
-                                        (None, None) => false,
+
+                                        continue;
                                     }
                                 };
 
-                                let scan_result = &element_scanner_results_view_data.current_scan_results[index];
-
                                 // Update the cumulative check state based on whether this scan result is frozen.
                                 if is_selected {
                                     match selection_freeze_checkstate {
@@ -388,16 +1209,38 @@ impl Widget for ElementScannerResultsView {
                                     }
                                 }
 
+                                let global_index = scan_result
+                                    .get_base_result()
+                                    .get_scan_result_ref()
+                                    .get_scan_result_global_index();
+                                let display_format_override = element_scanner_results_view_data
+                                    .display_format_overrides
+                                    .get(&global_index)
+                                    .copied();
+                                let display_format = display_format_override.unwrap_or(element_scanner_view_data.active_display_format);
+                                let value_change_strength = element_scanner_results_view_data.get_value_change_strength(global_index, value_change_time);
+
+                                has_value_change_highlights |= value_change_strength > 0.0;
+
                                 let entry_widget = ElementScannerResultEntryView::new(
                                     self.app_context.clone(),
                                     &scan_result,
                                     element_scanner_view_data.active_display_format,
+                                    display_format_override,
                                     index,
                                     is_selected,
+                                    row_density,
+                                    &value_color_rules,
                                     &mut element_sanner_result_frame_action,
                                     faux_address_splitter_position_x,
                                     value_splitter_position_x,
                                     previous_value_splitter_position_x,
+                                    first_scan_value_position_x,
+                                    value_flash_strengths
+                                        .get(&Self::get_value_watch_target(scan_result))
+                                        .copied()
+                                        .unwrap_or(0.0),
+                                    value_change_strength,
                                 );
                                 let row_response = user_interface.add(entry_widget);
 
@@ -407,9 +1250,16 @@ impl Widget for ElementScannerResultsView {
 
                                 rows_max_y = Some(row_response.rect.max.y);
 
+                                if keyboard_scroll_target_row == Some(index) {
+                                    row_response.scroll_to_me(None);
+                                }
+
                                 // Primary click should immediately select the row, matching CE-style behavior.
                                 // (Selection mutation itself is deferred through frame action.)
-                                if row_response.clicked() || row_response.clicked_by(eframe::egui::PointerButton::Primary) {
+                                // Shift-clicks and Ctrl-clicks extend or toggle the selection instead, which the entry view has already handled.
+                                if (row_response.clicked() || row_response.clicked_by(eframe::egui::PointerButton::Primary))
+                                    && !user_interface.input(|input| input.modifiers.shift || input.modifiers.command)
+                                {
                                     element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(index as i32));
                                 }
 
@@ -425,13 +1275,23 @@ impl Widget for ElementScannerResultsView {
                                                 || input.pointer.button_released(eframe::egui::PointerButton::Secondary)
                                         }));
 
-                                if secondary_clicked {
+                                if secondary_clicked && !is_selected {
                                     element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(index as i32));
                                 }
 
                                 if row_response.double_clicked() {
                                     element_sanner_result_frame_action = ElementScannerResultFrameAction::SetSelectionStart(Some(index as i32));
-                                    should_open_change_value_dialog = true;
+
+                                    // Double-clicking the address cell edits the address, and anywhere else edits the value.
+                                    let is_address_cell = row_response
+                                        .interact_pointer_pos()
+                                        .is_some_and(|pointer_position| pointer_position.x < value_splitter_position_x);
+
+                                    if is_address_cell {
+                                        change_address_index = Some(index);
+                                    } else {
+                                        should_open_change_value_dialog = true;
+                                    }
                                 }
 
                                 row_response.context_menu(|ui| {
@@ -454,18 +1314,14 @@ impl Widget for ElementScannerResultsView {
                                     }
 
                                     if ui.button("Copy value").clicked() {
-                                        let current_value_string = scan_result
-                                            .get_recently_read_display_value(element_scanner_view_data.active_display_format)
-                                            .or_else(|| scan_result.get_current_display_value(element_scanner_view_data.active_display_format))
-                                            .map(|value| value.get_anonymous_value_string().to_string())
-                                            .unwrap_or_else(|| "??".to_string());
+                                        let current_value_string = scan_result.get_display_value_string(display_format).to_string();
                                         copy_text = Some(current_value_string);
                                         ui.close();
                                     }
 
                                     if ui.button("Copy previous value").clicked() {
                                         let previous_value_string = scan_result
-                                            .get_previous_display_value(element_scanner_view_data.active_display_format)
+                                            .get_previous_display_value(display_format)
                                             .map(|value| value.get_anonymous_value_string().to_string())
                                             .unwrap_or_else(|| "??".to_string());
                                         copy_text = Some(previous_value_string);
@@ -482,12 +1338,75 @@ impl Widget for ElementScannerResultsView {
                                         ui.close();
                                     }
 
+                                    if ui.button("Copy page as formatted text").clicked() {
+                                        should_copy_page_as_formatted_text = true;
+                                        ui.close();
+                                    }
+
+                                    if ui.button("Export results...").clicked() {
+                                        should_open_export_dialog = true;
+                                        ui.close();
+                                    }
+
+                                    ui.menu_button("Display as…", |ui| {
+                                        let supported_formats = SymbolRegistry::get_instance()
+                                            .get_supported_anonymous_value_string_formats(scan_result.get_data_type_ref());
+
+                                        for (display_format_name, display_format_option) in Self::DISPLAY_FORMAT_OVERRIDES {
+                                            let is_supported = supported_formats.contains(&display_format_option);
+                                            let is_checked = display_format == display_format_option;
+
+                                            if ui
+                                                .add_enabled(is_supported, Button::selectable(is_checked, display_format_name))
+                                                .clicked()
+                                            {
+                                                element_sanner_result_frame_action =
+                                                    ElementScannerResultFrameAction::SetDisplayFormatOverride(global_index, Some(display_format_option));
+                                                ui.close();
+                                            }
+                                        }
+
+                                        ui.separator();
+
+                                        if ui
+                                            .add_enabled(display_format_override.is_some(), Button::new("Use the active display format"))
+                                            .clicked()
+                                        {
+                                            element_sanner_result_frame_action = ElementScannerResultFrameAction::SetDisplayFormatOverride(global_index, None);
+                                            ui.close();
+                                        }
+                                    });
+
+                                    if ui
+                                        .add(Button::selectable(is_first_scan_value_column_shown, "Show first scan values"))
+                                        .clicked()
+                                    {
+                                        should_toggle_first_scan_value_column = true;
+                                        ui.close();
+                                    }
+
                                     ui.separator();
 
                                     if ui.button("Change value of selected addresses").clicked() {
                                         should_open_change_value_dialog = true;
                                         ui.close();
                                     }
+                                    if ui.button("Change address...").clicked() {
+                                        change_address_index = Some(index);
+                                        ui.close();
+                                    }
+                                    ui.menu_button("Change type of selected results to", |ui| {
+                                        for data_type_id in Self::CONVERTIBLE_DATA_TYPE_IDS {
+                                            if ui
+                                                .button(DataTypeToStringConverter::convert_data_type_to_string(data_type_id))
+                                                .clicked()
+                                            {
+                                                element_sanner_result_frame_action =
+                                                    ElementScannerResultFrameAction::ChangeSelectionDataType(DataTypeRef::new(data_type_id));
+                                                ui.close();
+                                            }
+                                        }
+                                    });
                                     if ui.button("Freeze selected addresses").clicked() {
                                         element_sanner_result_frame_action = ElementScannerResultFrameAction::ToggleFreezeSelection(true);
                                         ui.close();
@@ -496,6 +1415,10 @@ impl Widget for ElementScannerResultsView {
                                         element_sanner_result_frame_action = ElementScannerResultFrameAction::ToggleFreezeSelection(false);
                                         ui.close();
                                     }
+                                    if has_stale_scan_results && ui.button("Revalidate snapshot").clicked() {
+                                        element_sanner_result_frame_action = ElementScannerResultFrameAction::RevalidateSnapshot;
+                                        ui.close();
+                                    }
                                     if ui.button("Add selected addresses to the addresslist").clicked() {
                                         element_sanner_result_frame_action = ElementScannerResultFrameAction::AddSelection;
                                         ui.close();
@@ -516,11 +1439,46 @@ impl Widget for ElementScannerResultsView {
                                         pointer_scan_address = Some(scan_result.get_address());
                                         ui.close();
                                     }
+                                    for watch_type in [MemoryWatchType::Write, MemoryWatchType::Access] {
+                                        if ui
+                                            .button(format!("Find out what {} this address", watch_type.get_description()))
+                                            .on_hover_text("Traps accesses to the page holding this address. The target slows down heavily while watched.")
+                                            .clicked()
+                                        {
+                                            let watch_size = scan_result
+                                                .get_current_value()
+                                                .as_ref()
+                                                .or(scan_result.get_recently_read_value().as_ref())
+                                                .map(|data_value| data_value.get_size_in_bytes())
+                                                .unwrap_or(1)
+                                                .max(1);
+
+                                            page_trap_watch_target = Some((scan_result.get_address(), watch_size, watch_type));
+                                            ui.close();
+                                        }
+                                    }
+                                    if ui
+                                        .button("Notify on change...")
+                                        .on_hover_text("Shows a notification whenever this value changes, optionally only past a threshold or to a value.")
+                                        .clicked()
+                                    {
+                                        let (address, module_name) = Self::get_value_watch_target(scan_result);
+
+                                        value_watch_target = Some((address, module_name, scan_result.get_data_type_ref().clone()));
+                                        ui.close();
+                                    }
                                 });
                             }
                         });
                     });
 
+                // Space toggles freezing once the rows are drawn, as only then is the freeze state of the selection known. A partly
+                // frozen selection is frozen entirely.
+                if should_toggle_freeze_selection {
+                    element_sanner_result_frame_action =
+                        ElementScannerResultFrameAction::ToggleFreezeSelection(selection_freeze_checkstate != CheckState::True);
+                }
+
                 // Draw the footer.
                 user_interface.add(ElementScannerResultsActionBarView::new(
                     self.app_context.clone(),
@@ -563,7 +1521,7 @@ impl Widget for ElementScannerResultsView {
                     let mut new_previous_value_splitter_position_x = previous_value_splitter_position_x + drag_delta.x;
 
                     let minimum_previous_value_splitter_position_x = value_splitter_position_x + MINIMUM_SPLITTER_PIXEL_GAP;
-                    let maximum_previous_value_splitter_position_x = content_min_x + content_width - MINIMUM_COLUMN_PIXEL_WIDTH;
+                    let maximum_previous_value_splitter_position_x = content_min_x + content_width - MINIMUM_COLUMN_PIXEL_WIDTH - first_scan_value_column_width;
 
                     new_previous_value_splitter_position_x =
                         new_previous_value_splitter_position_x.clamp(minimum_previous_value_splitter_position_x, maximum_previous_value_splitter_position_x);
@@ -575,10 +1533,53 @@ impl Widget for ElementScannerResultsView {
             })
             .response;
 
+        if let Some(visible_row_range) = visible_row_range {
+            let has_pending_pages = ElementScannerResultsViewData::set_visible_row_range(
+                self.element_scanner_results_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                visible_row_range,
+            );
+
+            // Keep repainting while placeholders are shown, since fetched pages arrive without any input.
+            if has_pending_pages {
+                user_interface.ctx().request_repaint_after(Duration::from_millis(50));
+            }
+        }
+
         if should_select_all {
             ElementScannerResultsViewData::select_all(self.element_scanner_results_view_data.clone());
         }
 
+        if let Some(column) = should_toggle_sort_column {
+            ElementScannerResultsViewData::toggle_sort(self.element_scanner_results_view_data.clone(), column);
+        }
+
+        if let Some(display_format) = new_active_display_format {
+            ElementScannerViewData::set_active_display_format(self.element_scanner_view_data.clone(), display_format);
+        }
+
+        if should_toggle_first_scan_value_column
+            && let Some(mut view_data) = self
+                .element_scanner_results_view_data
+                .write("Element scanner results toggle first scan value column")
+        {
+            view_data.is_first_scan_value_column_shown = !view_data.is_first_scan_value_column_shown;
+        }
+
+        if should_navigate_previous_page {
+            ElementScannerResultsViewData::navigate_previous_page(
+                self.element_scanner_results_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+            );
+        } else if should_navigate_next_page {
+            ElementScannerResultsViewData::navigate_next_page(self.element_scanner_results_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        // Keep animating until every changed value has faded out.
+        if has_value_change_highlights {
+            user_interface.ctx().request_repaint();
+        }
+
         if let Some(text) = copy_text.take() {
             if !text.is_empty() {
                 user_interface.ctx().copy_text(text);
@@ -590,17 +1591,29 @@ impl Widget for ElementScannerResultsView {
                 .map(|view_data| view_data.active_display_format)
                 .unwrap_or_default();
 
-            let text = ElementScannerResultsViewData::copy_selected_rows_tsv(self.element_scanner_results_view_data.clone(), active_display_format);
+            ElementScannerResultsViewData::copy_selected_rows_tsv(self.element_scanner_results_view_data.clone(), active_display_format);
+        } else if should_copy_selected_addresses {
+            let text = ElementScannerResultsViewData::copy_selected_addresses(self.element_scanner_results_view_data.clone());
             if !text.is_empty() {
                 user_interface.ctx().copy_text(text);
             }
-        } else if should_copy_selected_addresses {
-            let text = ElementScannerResultsViewData::copy_selected_addresses(self.element_scanner_results_view_data.clone());
+        } else if should_copy_page_as_formatted_text {
+            let active_display_format = self
+                .element_scanner_view_data
+                .read("Element scanner copy page display format")
+                .map(|view_data| view_data.active_display_format)
+                .unwrap_or_default();
+
+            let text = ElementScannerResultsViewData::copy_page_as_formatted_text(self.element_scanner_results_view_data.clone(), active_display_format);
             if !text.is_empty() {
                 user_interface.ctx().copy_text(text);
             }
         }
 
+        if should_open_export_dialog {
+            ElementScannerResultsViewData::show_export_dialog(self.element_scanner_results_view_data.clone());
+        }
+
         if let Some((start, end)) = paste_selection_range.take() {
             let applied = ElementScannerResultsViewData::set_scan_result_selection_start(
                 self.element_scanner_results_view_data.clone(),
@@ -615,14 +1628,10 @@ impl Widget for ElementScannerResultsView {
                 return response;
             }
             if end != start {
-                let applied = ElementScannerResultsViewData::set_scan_result_selection_end(
-                    self.element_scanner_results_view_data.clone(),
-                    self.struct_viewer_view_data.clone(),
-                    Some(end),
-                );
+                let applied = ElementScannerResultsViewData::extend_scan_result_selection(self.element_scanner_results_view_data.clone(), end);
                 if !applied {
                     if let Some(mut view_data) = self.element_scanner_results_view_data.try_write("Element scanner stash pending paste selection end") {
-                        view_data.pending_frame_action = ElementScannerResultFrameAction::SetSelectionEnd(Some(end));
+                        view_data.pending_frame_action = ElementScannerResultFrameAction::ExtendSelection(end);
                     }
                     user_interface.ctx().request_repaint();
                     return response;
@@ -664,16 +1673,23 @@ impl Widget for ElementScannerResultsView {
                         return response;
                     }
                 }
-                ElementScannerResultFrameAction::SetSelectionEnd(index) => {
-                    let applied = ElementScannerResultsViewData::set_scan_result_selection_end(
-                        self.element_scanner_results_view_data.clone(),
-                        self.struct_viewer_view_data.clone(),
-                        index,
-                    );
+                ElementScannerResultFrameAction::ToggleSelection(index) => {
+                    let applied = ElementScannerResultsViewData::toggle_scan_result_selection(self.element_scanner_results_view_data.clone(), index);
                     if !applied {
-                        ui_trace::trace(format!("results_view.stash_pending SetSelectionEnd({:?})", index));
+                        ui_trace::trace(format!("results_view.stash_pending ToggleSelection({})", index));
+                        if let Some(mut view_data) = self.element_scanner_results_view_data.try_write("Element scanner stash pending selection toggle") {
+                            view_data.pending_frame_action = ElementScannerResultFrameAction::ToggleSelection(index);
+                        }
+                        user_interface.ctx().request_repaint();
+                        return response;
+                    }
+                }
+                ElementScannerResultFrameAction::ExtendSelection(index) => {
+                    let applied = ElementScannerResultsViewData::extend_scan_result_selection(self.element_scanner_results_view_data.clone(), index);
+                    if !applied {
+                        ui_trace::trace(format!("results_view.stash_pending ExtendSelection({})", index));
                         if let Some(mut view_data) = self.element_scanner_results_view_data.try_write("Element scanner stash pending selection end") {
-                            view_data.pending_frame_action = ElementScannerResultFrameAction::SetSelectionEnd(index);
+                            view_data.pending_frame_action = ElementScannerResultFrameAction::ExtendSelection(index);
                         }
                         user_interface.ctx().request_repaint();
                         return response;
@@ -685,14 +1701,28 @@ impl Widget for ElementScannerResultsView {
                         self.app_context.engine_unprivileged_state.clone(),
                         index,
                         is_frozen,
+                        StaleResultsPolicy::Confirm,
                     );
                 }
                 ElementScannerResultFrameAction::ToggleFreezeSelection(is_frozen) => {
-                    ElementScannerResultsViewData::toggle_selected_scan_results_frozen(
+                    let freeze_undo = ElementScannerResultsViewData::toggle_selected_scan_results_frozen(
                         self.element_scanner_results_view_data.clone(),
                         self.app_context.engine_unprivileged_state.clone(),
                         is_frozen,
+                        StaleResultsPolicy::Confirm,
                     );
+
+                    self.show_freeze_undo_toast(freeze_undo);
+                }
+                ElementScannerResultFrameAction::ToggleFreezePage(is_frozen) => {
+                    let freeze_undo = ElementScannerResultsViewData::toggle_page_scan_results_frozen(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                        is_frozen,
+                        StaleResultsPolicy::Confirm,
+                    );
+
+                    self.show_freeze_undo_toast(freeze_undo);
                 }
                 ElementScannerResultFrameAction::AddSelection => {
                     ElementScannerResultsViewData::add_scan_results_to_project(
@@ -712,6 +1742,46 @@ impl Widget for ElementScannerResultsView {
                         self.app_context.engine_unprivileged_state.clone(),
                         ScanResult::PROPERTY_NAME_VALUE,
                         edit_value,
+                        StaleResultsPolicy::Confirm,
+                    );
+                }
+                ElementScannerResultFrameAction::NudgeSelection(value_nudge) => {
+                    ElementScannerResultsViewData::nudge_selected_scan_results(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                        value_nudge,
+                    );
+                }
+                ElementScannerResultFrameAction::ChangeSelectionDataType(data_type_ref) => {
+                    ElementScannerResultsViewData::set_selected_scan_results_data_type(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                        data_type_ref,
+                    );
+                }
+                ElementScannerResultFrameAction::SetDisplayFormatOverride(global_index, display_format_override) => {
+                    ElementScannerResultsViewData::set_display_format_override(
+                        self.element_scanner_results_view_data.clone(),
+                        global_index,
+                        display_format_override,
+                    );
+                }
+                ElementScannerResultFrameAction::RevalidateSnapshot => {
+                    ElementScannerResultsViewData::revalidate_snapshot(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                    );
+                }
+                ElementScannerResultFrameAction::UndoValueWrite => {
+                    ElementScannerResultsViewData::undo_scan_result_write(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
+                    );
+                }
+                ElementScannerResultFrameAction::RedoValueWrite => {
+                    ElementScannerResultsViewData::redo_scan_result_write(
+                        self.element_scanner_results_view_data.clone(),
+                        self.app_context.engine_unprivileged_state.clone(),
                     );
                 }
             }
@@ -739,6 +1809,10 @@ impl Widget for ElementScannerResultsView {
             ElementScannerResultsViewData::show_change_value_dialog(self.element_scanner_results_view_data.clone(), seed_value);
         }
 
+        if let Some(index) = change_address_index {
+            ElementScannerResultsViewData::show_change_address_dialog(self.element_scanner_results_view_data.clone(), index);
+        }
+
         if let Some(address) = browse_memory_address {
             MemoryViewerViewData::set_target_address(
                 self.memory_viewer_view_data.clone(),
@@ -755,9 +1829,10 @@ impl Widget for ElementScannerResultsView {
                 address,
             );
 
-            if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                docking_manager.set_window_visible(crate::views::disassembler::disassembler_view::DisassemblerView::WINDOW_ID, true);
-            }
+            self.app_context.queue_docking_command(DockingCommand::ShowWindow {
+                window_id: crate::views::disassembler::disassembler_view::DisassemblerView::WINDOW_ID.to_string(),
+                is_visible: true,
+            });
         }
 
         if let Some(address) = pointer_scan_address {
@@ -768,9 +1843,36 @@ impl Widget for ElementScannerResultsView {
                 view_data.target_address = format!("{:X}", address);
             }
 
-            if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                docking_manager.set_window_visible(crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView::WINDOW_ID, true);
-            }
+            self.app_context.queue_docking_command(DockingCommand::ShowWindow {
+                window_id: crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView::WINDOW_ID.to_string(),
+                is_visible: true,
+            });
+        }
+
+        if let Some((address, size, watch_type)) = page_trap_watch_target {
+            MemoryAccessorsViewData::start_page_trap_watch(
+                self.memory_accessors_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                address,
+                size,
+                watch_type,
+            );
+
+            self.app_context.queue_docking_command(DockingCommand::ShowWindow {
+                window_id: MemoryAccessorsView::WINDOW_ID.to_string(),
+                is_visible: true,
+            });
+        }
+
+        if let Some((address, module_name, data_type_ref)) = value_watch_target {
+            ValueWatchesViewData::open_dialog(
+                self.value_watches_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                String::new(),
+                address,
+                module_name,
+                data_type_ref,
+            );
         }
 
         let mut should_commit_change_value = None;
@@ -834,9 +1936,15 @@ impl Widget for ElementScannerResultsView {
                 self.app_context.engine_unprivileged_state.clone(),
                 ScanResult::PROPERTY_NAME_VALUE,
                 change_value,
+                StaleResultsPolicy::Confirm,
             );
         }
 
+        self.show_change_address_dialog(user_interface);
+        self.show_stale_results_confirmation_dialog(user_interface);
+        self.show_export_dialog(user_interface);
+        self.show_copy_toast(user_interface);
+
         response
     }
 }
@@ -852,6 +1960,8 @@ mod tests {
     use crate::views::element_scanner::results::view_data::element_scanner_result_frame_action::ElementScannerResultFrameAction;
     use crate::views::element_scanner::results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData;
     use crate::views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData;
+    use crate::views::main_window::view_data::value_watches_view_data::ValueWatchesViewData;
+    use crate::views::memory_accessors::view_data::memory_accessors_view_data::MemoryAccessorsViewData;
     use crate::views::memory_viewer::view_data::memory_viewer_view_data::MemoryViewerViewData;
     use crate::views::pointer_scanner::view_data::pointer_scanner_view_data::PointerScannerViewData;
     use crate::views::struct_viewer::view_data::struct_viewer_view_data::StructViewerViewData;
@@ -951,6 +2061,8 @@ mod tests {
             app_context.dependency_container.register(MemoryViewerViewData::new());
             app_context.dependency_container.register(DisassemblerViewData::new());
             app_context.dependency_container.register(PointerScannerViewData::new());
+            app_context.dependency_container.register(MemoryAccessorsViewData::new());
+            app_context.dependency_container.register(ValueWatchesViewData::new());
 
             let mut results = ElementScannerResultsViewData::new();
             results.current_scan_results = Arc::new(vec![make_string_scan_result(0x21BD0034, "note")]);
@@ -1033,6 +2145,8 @@ mod tests {
         app_context.dependency_container.register(MemoryViewerViewData::new());
         app_context.dependency_container.register(DisassemblerViewData::new());
         app_context.dependency_container.register(PointerScannerViewData::new());
+        app_context.dependency_container.register(MemoryAccessorsViewData::new());
+        app_context.dependency_container.register(ValueWatchesViewData::new());
 
         let mut results = ElementScannerResultsViewData::new();
         results.current_scan_results = Arc::new(vec![make_string_scan_result(0x21BD0034, "note")]);
@@ -1069,7 +2183,7 @@ mod tests {
         let data = dep
             .read("Assert selection after primary click")
             .expect("read results view data");
-        assert_eq!(data.selection_index_start, Some(0), "primary click did not select the first row");
+        assert!(data.selection.contains(0), "primary click did not select the first row");
     }
 
     #[test]
@@ -1090,6 +2204,8 @@ mod tests {
         app_context.dependency_container.register(MemoryViewerViewData::new());
         app_context.dependency_container.register(DisassemblerViewData::new());
         app_context.dependency_container.register(PointerScannerViewData::new());
+        app_context.dependency_container.register(MemoryAccessorsViewData::new());
+        app_context.dependency_container.register(ValueWatchesViewData::new());
 
         let mut results = ElementScannerResultsViewData::new();
         results.current_scan_results = Arc::new(vec![make_string_scan_result(0x21BD0034, "note")]);
@@ -1126,6 +2242,6 @@ mod tests {
         let data = dep
             .read("Assert selection after secondary click")
             .expect("read results view data");
-        assert_eq!(data.selection_index_start, Some(0), "secondary click did not select the first row");
+        assert!(data.selection.contains(0), "secondary click did not select the first row");
     }
 }
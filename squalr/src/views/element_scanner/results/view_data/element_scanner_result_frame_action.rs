@@ -1,13 +1,25 @@
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::value_nudge::ValueNudge;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum ElementScannerResultFrameAction {
     None,
     SetSelectionStart(Option<i32>),
-    SetSelectionEnd(Option<i32>),
+    ToggleSelection(i32),
+    ExtendSelection(i32),
     FreezeIndex(i32, bool),
     ToggleFreezeSelection(bool),
+    ToggleFreezePage(bool),
     AddSelection,
     DeleteSelection,
     CommitValueToSelection(AnonymousValueString),
+    NudgeSelection(ValueNudge),
+    ChangeSelectionDataType(DataTypeRef),
+    /// Shows the result with the given global index in a display format of its own, or in the active one again if `None`.
+    SetDisplayFormatOverride(u64, Option<AnonymousValueStringFormat>),
+    RevalidateSnapshot,
+    UndoValueWrite,
+    RedoValueWrite,
 }
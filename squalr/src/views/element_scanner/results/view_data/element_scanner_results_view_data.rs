@@ -1,15 +1,24 @@
 use arc_swap::Guard;
+use squalr_engine_api::commands::scan::revalidate::scan_revalidate_request::ScanRevalidateRequest;
 use squalr_engine_api::commands::scan_results::add_to_project::scan_results_add_to_project_request::ScanResultsAddToProjectRequest;
 use squalr_engine_api::commands::scan_results::delete::scan_results_delete_request::ScanResultsDeleteRequest;
 use squalr_engine_api::commands::scan_results::freeze::scan_results_freeze_request::ScanResultsFreezeRequest;
+use squalr_engine_api::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
+use squalr_engine_api::commands::scan_results::set_data_type::scan_results_set_data_type_request::ScanResultsSetDataTypeRequest;
+use squalr_engine_api::commands::scan_results::set_values::scan_results_set_values_request::ScanResultsSetValuesRequest;
+use squalr_engine_api::commands::settings::scan::list::scan_settings_list_request::ScanSettingsListRequest;
 use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
 use squalr_engine_api::dependency_injection::dependency::Dependency;
 use squalr_engine_api::dependency_injection::write_guard::WriteGuard;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
-use squalr_engine_api::structures::scan_results::scan_result_base::ScanResultBase;
+use squalr_engine_api::structures::data_values::value_nudge::{ValueNudge, ValueNudgeKind};
 use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use squalr_engine_api::structures::scanning::statistics::scan_statistics::ScanStatistics;
+use squalr_engine_api::structures::settings::value_overflow_policy::ValueOverflowPolicy;
 use squalr_engine_api::{
     commands::{
         privileged_command_request::PrivilegedCommandRequest,
@@ -21,14 +30,38 @@ use squalr_engine_api::{
     events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent,
     structures::{data_values::anonymous_value_string::AnonymousValueString, scan_results::scan_result::ScanResult},
 };
-use std::ops::RangeInclusive;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
+use crate::models::address_resolution::address_resolver::AddressResolver;
+use crate::models::refresh_scheduler::refresh_batch::RefreshBatch;
+use crate::models::refresh_scheduler::refresh_job_handle::RefreshJobHandle;
+use crate::models::refresh_scheduler::refresh_scheduler::RefreshScheduler;
+use crate::views::struct_viewer::view_data::focused_struct_address::FocusedStructAddress;
 use crate::views::struct_viewer::view_data::struct_viewer_view_data::StructViewerViewData;
 use crate::ui::converters::data_type_to_string_converter::DataTypeToStringConverter;
+use crate::models::appearance::appearance_settings::AppearanceSettings;
+use crate::ui::text_table::TextTable;
 use crate::views::element_scanner::results::view_data::element_scanner_result_frame_action::ElementScannerResultFrameAction;
+use crate::views::element_scanner::results::view_data::scan_result_address_edit::ScanResultAddressEdit;
+use crate::views::element_scanner::results::view_data::scan_result_freeze_undo::ScanResultFreezeUndo;
+use crate::views::element_scanner::results::view_data::scan_result_selection::ScanResultSelection;
+use crate::views::element_scanner::results::view_data::scan_result_write_history::{ScanResultValueWrite, ScanResultWriteHistory};
+use crate::views::element_scanner::results::view_data::scan_results_exporter::{ScanResultsExportFormat, ScanResultsExporter};
+use crate::views::element_scanner::results::view_data::scan_results_page_cache::ScanResultsPageCache;
+use crate::views::element_scanner::results::view_data::scan_results_sort::{ScanResultsSort, ScanResultsSortColumn};
+use crate::views::element_scanner::results::view_data::scan_results_tsv_builder::ScanResultsTsvBuilder;
+use crate::views::element_scanner::results::view_data::stale_results_policy::{StaleResultsConfirmation, StaleResultsPolicy};
 
 #[derive(Clone)]
 pub struct ElementScannerResultsViewData {
@@ -36,70 +69,268 @@ pub struct ElementScannerResultsViewData {
     pub value_splitter_ratio: f32,
     pub previous_value_splitter_ratio: f32,
     pub current_scan_results: Arc<Vec<ScanResult>>,
+    /// How the rows of the current page are sorted, or none to show them in snapshot order. Not applied in infinite scroll mode,
+    /// where rows are fetched by their position in the snapshot.
+    pub sort: Option<ScanResultsSort>,
+    /// Hides rows of the current page whose current value, as shown, does not contain this text. Not applied in infinite scroll mode,
+    /// where the rows shown are not all loaded.
+    pub value_filter: String,
+    /// The rows of the current page that match the value filter, in page order, or none while no filter is active. Rows keep their
+    /// index into `current_scan_results`, such that the selection and the actions over it target the same results as unfiltered.
+    pub filtered_indices: Option<Vec<usize>>,
+    /// The active display format that the value filter was last matched against.
+    pub value_filter_display_format: AnonymousValueStringFormat,
+    /// Incremented each time fresh values arrive, such that dependent views only recompute on new data.
+    pub results_generation: u64,
+    /// The newest scan generation reported by the engine. Responses describing older results can arrive after the results were
+    /// replaced, in which case they are discarded rather than shown over newer data.
+    pub latest_scan_generation: u64,
     pub current_page_index: u64,
     pub cached_last_page_index: u64,
     pub last_page_size: u64,
     pub page_size_override: Option<u32>,
     pub last_queried_page_size_override: Option<u32>,
     pub last_page_size_override_change: Option<Instant>,
-    pub selection_index_start: Option<i32>,
-    pub selection_index_end: Option<i32>,
+    /// Selected rows, which need not be contiguous. These are rows within the current page, or global rows (ie scan result refs)
+    /// in infinite scroll mode.
+    pub selection: ScanResultSelection,
     pub result_count: u64,
+    /// Whether results are shown as one continuous list, with pages fetched on demand, rather than one page at a time.
+    pub is_infinite_scroll: bool,
+    /// Whether the value each result held at the first scan is shown in its own column, rather than only when hovering the row.
+    pub is_first_scan_value_column_shown: bool,
+    /// Recently queried pages, shared by paged mode (for prefetching) and infinite scroll mode.
+    pub page_cache: ScanResultsPageCache,
+    /// The global rows currently visible in infinite scroll mode.
+    pub visible_row_range: Range<u64>,
     pub stats_string: String,
+    /// The statistics of the most recent scan, shown when hovering the stats.
+    pub last_scan_statistics: Option<ScanStatistics>,
     pub current_display_string: AnonymousValueString,
+    /// Display formats chosen for individual results, keyed by scan result global index, which take precedence over the active
+    /// display format. Global indices stay stable as pages refresh, but refer to different results after the next scan.
+    pub display_format_overrides: HashMap<u64, AnonymousValueStringFormat>,
+    /// When the value of each result on the current page last changed between refreshes, keyed by scan result global index, such
+    /// that changed values can be highlighted.
+    pub value_changed_at: HashMap<u64, Instant>,
     pub is_querying_scan_results: bool,
     pub is_refreshing_scan_results: bool,
     pub is_setting_properties: bool,
     pub is_freezing_entries: bool,
     pub show_change_value_dialog: bool,
     pub change_value_string: AnonymousValueString,
+    /// The result being moved to another address in the change address dialog, if it is open.
+    pub address_edit: Option<ScanResultAddressEdit>,
+    /// Values written from this view, such that they can be undone until the next scan.
+    pub write_history: ScanResultWriteHistory,
+    /// A freeze or write over stale results, shown as a confirmation dialog until the user decides how to treat them.
+    pub stale_results_confirmation: Option<StaleResultsConfirmation>,
+    pub is_revalidating_snapshot: bool,
+    pub show_export_dialog: bool,
+    pub export_path: String,
+    pub export_format: ScanResultsExportFormat,
+    /// The number of results written and the total to write, while an export runs in the background.
+    pub export_progress: Option<(u64, u64)>,
+    /// Set to stop the running export after the page it is writing.
+    pub export_cancellation_token: Option<Arc<AtomicBool>>,
+    /// Describes how the last export finished, shown in the export dialog.
+    pub export_status: Option<String>,
+    /// The rows built so far by a copy running in the background, and the rows it copies.
+    pub copy_progress: Option<(u64, u64)>,
+    pub copy_cancellation_token: Option<Arc<AtomicBool>>,
+    /// Copied text waiting to be placed on the clipboard, which only the UI thread can do. Shared, such that cloning the view data
+    /// for a write does not copy it.
+    pub pending_clipboard_text: Option<Arc<String>>,
+    /// The outcome of the last copy, and when it was reported.
+    pub copy_status: Option<(String, Instant)>,
     pub pending_frame_action: ElementScannerResultFrameAction,
+    /// Keeps the listener requerying results on each scan registered for as long as this view data lives.
+    pub scan_results_subscription: Option<EngineEventSubscription>,
+    /// Keeps the job refreshing the values of the current page registered for as long as this view data lives.
+    pub refresh_job_handle: Option<RefreshJobHandle>,
 }
 
 impl ElementScannerResultsViewData {
     pub const DEFAULT_VALUE_SPLITTER_RATIO: f32 = 0.35;
     pub const DEFAULT_PREVIOUS_VALUE_SPLITTER_RATIO: f32 = 0.70;
+    /// Copies of more rows than this are built on a background thread, as building them would stall the frame.
+    pub const BACKGROUND_COPY_ROW_THRESHOLD: usize = 10_000;
+    /// How many rows a background copy builds between progress updates and cancellation checks.
+    const COPY_PROGRESS_INTERVAL_ROWS: u64 = 4096;
+    /// How long a value stays highlighted after it changes.
+    pub const VALUE_CHANGE_HIGHLIGHT_DURATION: Duration = Duration::from_secs(1);
+    /// How long the outcome of a copy is shown.
+    pub const COPY_STATUS_DURATION: Duration = Duration::from_secs(4);
     const AUTO_REFRESH_INTERVAL_MS: u64 = 750;
+    /// How long an export waits on the engine for a page of results before giving up.
+    const EXPORT_PAGE_TIMEOUT_MS: u64 = 30000;
     const AUTO_REFRESH_MAX_RESULTS_PER_PAGE: usize = 512;
-    const PAGE_SIZE_REQUERY_DEBOUNCE_MS: u64 = 200;
+    /// How long the page size must stay unchanged before requerying, such that a resize gesture only queries once it ends.
+    pub const PAGE_SIZE_REQUERY_DEBOUNCE_MS: u64 = 300;
+    /// Page size changes of this many rows or fewer are ignored, to avoid requerying on small layout jitter.
+    const PAGE_SIZE_HYSTERESIS_ROWS: u32 = 2;
+    /// The page size requested in infinite scroll mode. The engine may clamp this, in which case its page size is used instead.
+    pub const INFINITE_SCROLL_PAGE_SIZE: u32 = 256;
 
     pub fn new() -> Self {
         Self {
             value_splitter_ratio: Self::DEFAULT_VALUE_SPLITTER_RATIO,
             previous_value_splitter_ratio: Self::DEFAULT_PREVIOUS_VALUE_SPLITTER_RATIO,
             current_scan_results: Arc::new(Vec::new()),
+            sort: None,
+            value_filter: String::new(),
+            filtered_indices: None,
+            value_filter_display_format: AnonymousValueStringFormat::Decimal,
+            results_generation: 0,
+            latest_scan_generation: 0,
             current_page_index: 0,
             cached_last_page_index: 0,
             last_page_size: 1,
             page_size_override: None,
             last_queried_page_size_override: None,
             last_page_size_override_change: None,
-            selection_index_start: None,
-            selection_index_end: None,
+            selection: ScanResultSelection::default(),
             result_count: 0,
+            is_infinite_scroll: false,
+            is_first_scan_value_column_shown: false,
+            page_cache: ScanResultsPageCache::new(Self::INFINITE_SCROLL_PAGE_SIZE as u64, ScanResultsPageCache::DEFAULT_CAPACITY),
+            visible_row_range: 0..0,
             stats_string: String::new(),
+            last_scan_statistics: None,
             current_display_string: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            display_format_overrides: HashMap::new(),
+            value_changed_at: HashMap::new(),
             is_querying_scan_results: false,
             is_refreshing_scan_results: false,
             is_setting_properties: false,
             is_freezing_entries: false,
             show_change_value_dialog: false,
             change_value_string: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            address_edit: None,
+            write_history: ScanResultWriteHistory::default(),
+            stale_results_confirmation: None,
+            is_revalidating_snapshot: false,
+            show_export_dialog: false,
+            export_path: format!("scan_results.{}", ScanResultsExportFormat::default().get_file_extension()),
+            export_format: ScanResultsExportFormat::default(),
+            export_progress: None,
+            export_cancellation_token: None,
+            export_status: None,
+            copy_progress: None,
+            copy_cancellation_token: None,
+            pending_clipboard_text: None,
+            copy_status: None,
             pending_frame_action: ElementScannerResultFrameAction::None,
+            scan_results_subscription: None,
+            refresh_job_handle: None,
         }
     }
 
     pub fn select_all(element_scanner_results_view_data: Dependency<Self>) {
         if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner select all") {
-            if element_scanner_results_view_data.current_scan_results.is_empty() {
-                element_scanner_results_view_data.selection_index_start = None;
-                element_scanner_results_view_data.selection_index_end = None;
-                return;
+            let row_count = element_scanner_results_view_data.get_row_count();
+
+            element_scanner_results_view_data.selection.select_all(row_count);
+
+            // Rows were selected by their position in the list, which differs from their index while a value filter is active.
+            if let Some(filtered_indices) = element_scanner_results_view_data.filtered_indices.clone() {
+                element_scanner_results_view_data
+                    .selection
+                    .remap(|row| filtered_indices.get(row).copied());
             }
+        }
+    }
+
+    /// Gets the number of rows shown, which is every result in infinite scroll mode, or the rows of the current page matching the
+    /// value filter otherwise.
+    pub fn get_row_count(&self) -> usize {
+        if self.is_infinite_scroll {
+            self.result_count.min(i32::MAX as u64) as usize
+        } else if let Some(filtered_indices) = &self.filtered_indices {
+            filtered_indices.len()
+        } else {
+            self.current_scan_results.len()
+        }
+    }
+
+    /// Gets the selection index of the row at the given position in the list. These only differ while a value filter hides rows.
+    pub fn get_row_index(
+        &self,
+        row_position: usize,
+    ) -> Option<usize> {
+        match &self.filtered_indices {
+            Some(filtered_indices) => filtered_indices.get(row_position).copied(),
+            None => Some(row_position),
+        }
+    }
+
+    /// Gets the position in the list of the row with the given selection index, or none if the value filter hides it.
+    pub fn get_row_position(
+        &self,
+        index: usize,
+    ) -> Option<usize> {
+        match &self.filtered_indices {
+            Some(filtered_indices) => filtered_indices.binary_search(&index).ok(),
+            None => Some(index),
+        }
+    }
+
+    /// Shows only the rows of the current page whose value, in the format it is shown in, contains the given text. An empty filter
+    /// shows every row again, without requerying the engine.
+    pub fn set_value_filter(
+        &mut self,
+        value_filter: String,
+        active_display_format: AnonymousValueStringFormat,
+    ) {
+        self.value_filter = value_filter;
+        self.value_filter_display_format = active_display_format;
+        self.apply_value_filter();
+    }
+
+    /// Matches the rows of the current page against the value filter.
+    fn apply_value_filter(&mut self) {
+        let value_filter = self.value_filter.trim().to_lowercase();
+
+        if value_filter.is_empty() || self.is_infinite_scroll {
+            self.filtered_indices = None;
+
+            return;
+        }
+
+        let filtered_indices = self
+            .current_scan_results
+            .iter()
+            .enumerate()
+            .filter(|(_index, scan_result)| {
+                scan_result
+                    .get_display_value_string(self.get_display_format(scan_result, self.value_filter_display_format))
+                    .to_lowercase()
+                    .contains(&value_filter)
+            })
+            .map(|(index, _scan_result)| index)
+            .collect::<Vec<_>>();
 
-                element_scanner_results_view_data.selection_index_start = Some(0);
-                element_scanner_results_view_data.selection_index_end =
-                    Some(element_scanner_results_view_data.current_scan_results.len().saturating_sub(1) as i32);
+        self.filtered_indices = Some(filtered_indices);
+        self.deselect_hidden_rows();
+    }
+
+    /// Deselects rows hidden by the value filter, such that actions over the selection never reach rows that cannot be seen.
+    fn deselect_hidden_rows(&mut self) {
+        let Some(filtered_indices) = &self.filtered_indices else {
+            return;
+        };
+
+        if self
+            .selection
+            .iter_indices()
+            .any(|index| filtered_indices.binary_search(&index).is_err())
+        {
+            self.selection.remap(|index| {
+                filtered_indices
+                    .binary_search(&index)
+                    .ok()
+                    .map(|_position| index)
+            });
         }
     }
 
@@ -109,12 +340,10 @@ impl ElementScannerResultsViewData {
             None => return String::new(),
         };
 
-        let Some(range) = Self::get_selected_results_range(&element_scanner_results_view_data) else {
-            return String::new();
-        };
-
-        range
-            .filter_map(|index| element_scanner_results_view_data.current_scan_results.get(index))
+        element_scanner_results_view_data
+            .selection
+            .iter_indices()
+            .filter_map(|index| element_scanner_results_view_data.get_scan_result(index))
             .map(|scan_result| {
                 let address = scan_result.get_address();
                 if scan_result.is_module() {
@@ -129,250 +358,1110 @@ impl ElementScannerResultsViewData {
             .join("\n")
     }
 
+    /// Copies the selected rows as tab separated text, which is placed in the pending clipboard text once built. Large copies are
+    /// built on a background thread, and copies past the configured cell limit are refused in favor of exporting to a file.
     pub fn copy_selected_rows_tsv(
         element_scanner_results_view_data: Dependency<Self>,
         active_display_format: AnonymousValueStringFormat,
-    ) -> String {
-        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner copy selected rows") {
-            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-            None => return String::new(),
+    ) {
+        let mut view_data = match element_scanner_results_view_data.write("Element scanner copy selected rows") {
+            Some(view_data) => view_data,
+            None => return,
         };
 
-        let Some(range) = Self::get_selected_results_range(&element_scanner_results_view_data) else {
-            return String::new();
-        };
+        if view_data.copy_progress.is_some() {
+            return;
+        }
 
-        range
-            .filter_map(|index| element_scanner_results_view_data.current_scan_results.get(index))
-            .map(|scan_result| {
-                let address = scan_result.get_address();
-                let address_string = if scan_result.is_module() {
-                    format!("{}+{:X}", scan_result.get_module(), scan_result.get_module_offset())
-                } else if address <= u32::MAX as u64 {
-                    format!("{:08X}", address)
-                } else {
-                    format!("{:016X}", address)
-                };
+        let row_count = view_data.selection.get_selected_count();
+        let cell_count = ScanResultsTsvBuilder::get_cell_count(row_count as u64);
+        let max_copied_cells = AppearanceSettings::get_max_copied_cells();
+
+        if cell_count > max_copied_cells {
+            view_data.copy_status = Some((
+                format!(
+                    "Copying {} rows would copy {} cells, past the limit of {}. Export the results to a file instead.",
+                    row_count, cell_count, max_copied_cells
+                ),
+                Instant::now(),
+            ));
+            return;
+        }
 
-                let current_value_string = scan_result
-                    .get_recently_read_display_value(active_display_format)
-                    .or_else(|| scan_result.get_current_display_value(active_display_format))
-                    .map(|value| value.get_anonymous_value_string())
-                    .unwrap_or("??");
+        if row_count <= Self::BACKGROUND_COPY_ROW_THRESHOLD {
+            let copied_text = Self::build_selected_rows_tsv(&view_data, active_display_format, |_built_row_count| true);
 
-                let previous_value_string = scan_result
-                    .get_previous_display_value(active_display_format)
-                    .map(|value| value.get_anonymous_value_string())
-                    .unwrap_or("??");
+            view_data.pending_clipboard_text = copied_text
+                .filter(|copied_text| !copied_text.is_empty())
+                .map(Arc::new);
+            return;
+        }
 
-                let type_string = DataTypeToStringConverter::convert_data_type_to_string(scan_result.get_data_type_ref().get_data_type_id());
+        let cancellation_token = Arc::new(AtomicBool::new(false));
 
-                format!(
-                    "{}\t{}\t{}\t{}",
-                    address_string, current_value_string, previous_value_string, type_string
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
+        view_data.copy_progress = Some((0, row_count as u64));
+        view_data.copy_cancellation_token = Some(cancellation_token.clone());
+        view_data.copy_status = None;
+        drop(view_data);
+
+        thread::spawn(move || {
+            // Build from a snapshot, such that the UI keeps updating the view data in the meantime.
+            let Some(view_data_snapshot) = element_scanner_results_view_data.read("Element scanner copy selected rows snapshot") else {
+                return;
+            };
+            let view_data_snapshot = Guard::into_inner(view_data_snapshot);
+            let copied_text = Self::build_selected_rows_tsv(&view_data_snapshot, active_display_format, |built_row_count| {
+                if cancellation_token.load(Ordering::Acquire) {
+                    return false;
+                }
+
+                if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner copy progress") {
+                    view_data.copy_progress = Some((built_row_count, row_count as u64));
+                }
+
+                true
+            });
+
+            if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner copy selected rows finished") {
+                let copy_status = match &copied_text {
+                    Some(_) => format!("Copied {} rows.", row_count),
+                    None => "Copy cancelled.".to_string(),
+                };
+
+                view_data.copy_progress = None;
+                view_data.copy_cancellation_token = None;
+                view_data.copy_status = Some((copy_status, Instant::now()));
+                view_data.pending_clipboard_text = copied_text.map(Arc::new);
+            }
+        });
     }
 
-    pub fn show_change_value_dialog(
-        element_scanner_results_view_data: Dependency<Self>,
-        seed_value: AnonymousValueString,
-    ) {
-        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner show change value dialog") {
-            element_scanner_results_view_data.change_value_string = seed_value;
-            element_scanner_results_view_data.show_change_value_dialog = true;
+    pub fn cancel_copy(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(view_data) = element_scanner_results_view_data.read("Element scanner cancel copy")
+            && let Some(cancellation_token) = &view_data.copy_cancellation_token
+        {
+            cancellation_token.store(true, Ordering::Release);
         }
     }
 
-    pub fn hide_change_value_dialog(element_scanner_results_view_data: Dependency<Self>) {
-        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner hide change value dialog") {
-            element_scanner_results_view_data.show_change_value_dialog = false;
+    /// Takes the copied text waiting to be placed on the clipboard, dropping the outcome of the last copy once it has been shown
+    /// for long enough.
+    pub fn take_pending_clipboard_text(element_scanner_results_view_data: Dependency<Self>) -> Option<String> {
+        let is_copy_status_expired = |view_data: &Self| {
+            view_data
+                .copy_status
+                .as_ref()
+                .is_some_and(|(_copy_status, reported_at)| reported_at.elapsed() >= Self::COPY_STATUS_DURATION)
+        };
+        let has_changes = element_scanner_results_view_data
+            .read("Element scanner pending clipboard text")
+            .is_some_and(|view_data| view_data.pending_clipboard_text.is_some() || is_copy_status_expired(&view_data));
+
+        // Most frames have nothing to take, so avoid taking the write lock for them.
+        if !has_changes {
+            return None;
         }
-    }
 
-    pub fn poll_scan_results(
-        element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-    ) {
-        static POLL_STARTED: OnceLock<()> = OnceLock::new();
-        if POLL_STARTED.set(()).is_err() {
-            return;
+        let mut view_data = element_scanner_results_view_data.write("Element scanner take pending clipboard text")?;
+
+        if is_copy_status_expired(&view_data) {
+            view_data.copy_status = None;
         }
 
-        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        view_data
+            .pending_clipboard_text
+            .take()
+            .map(Arc::unwrap_or_clone)
+    }
 
-        // Requery all scan results if they update.
+    /// Builds the selected rows as tab separated text. Every so often, `should_continue` is called with the number of rows
+    /// built so far, and the copy is abandoned if it returns false.
+    fn build_selected_rows_tsv(
+        element_scanner_results_view_data: &Self,
+        active_display_format: AnonymousValueStringFormat,
+        mut should_continue: impl FnMut(u64) -> bool,
+    ) -> Option<String> {
+        let mut tsv_builder = ScanResultsTsvBuilder::with_row_capacity(element_scanner_results_view_data.selection.get_selected_count());
+
+        for (row_index, index) in element_scanner_results_view_data
+            .selection
+            .iter_indices()
+            .enumerate()
         {
-            engine_unprivileged_state.listen_for_engine_event::<ScanResultsUpdatedEvent>(move |scan_results_updated_event| {
-                let element_scanner_results_view_data = element_scanner_results_view_data_clone.clone();
-                let engine_unprivileged_state = engine_unprivileged_state_clone.clone();
-                let play_sound = !scan_results_updated_event.is_new_scan;
+            if (row_index as u64).is_multiple_of(Self::COPY_PROGRESS_INTERVAL_ROWS) && !should_continue(row_index as u64) {
+                return None;
+            }
 
-                Self::query_scan_results(element_scanner_results_view_data, engine_unprivileged_state, play_sound);
-            });
+            if let Some(scan_result) = element_scanner_results_view_data.get_scan_result(index) {
+                let display_format = element_scanner_results_view_data.get_display_format(scan_result, active_display_format);
+
+                tsv_builder.push_row(&Self::get_scan_result_cells(scan_result, display_format));
+            }
         }
 
-        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        Some(tsv_builder.finish())
+    }
 
-        // Refresh scan values periodically (throttled).
-        //
-        // NOTE: This is disabled by default because background writers can block UI interactions
-        // (e.g., clicking results) and cause Windows "App Hang" symptoms when the UI thread waits
-        // on a contended dependency writer lock. Re-enable only for debugging:
-        //   set SQUALR_ENABLE_SCAN_RESULT_AUTO_REFRESH=1
-        if std::env::var_os("SQUALR_ENABLE_SCAN_RESULT_AUTO_REFRESH").is_some() {
-            thread::spawn(move || {
-                loop {
-                    let should_refresh = element_scanner_results_view_data_clone
-                        .read("Element scanner results auto refresh guard")
-                        .map(|view_data| {
-                            !view_data.is_querying_scan_results
-                                && !view_data.is_refreshing_scan_results
-                                && !view_data.current_scan_results.is_empty()
-                                && view_data.current_scan_results.len() <= Self::AUTO_REFRESH_MAX_RESULTS_PER_PAGE
-                        })
-                        .unwrap_or(false);
+    /// Copies the rows of the current page as an aligned text table. In infinite scroll mode, the rows on screen stand in for the page.
+    pub fn copy_page_as_formatted_text(
+        element_scanner_results_view_data: Dependency<Self>,
+        active_display_format: AnonymousValueStringFormat,
+    ) -> String {
+        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner copy page as formatted text") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return String::new(),
+        };
 
-                    if should_refresh {
-                        let element_scanner_results_view_data = element_scanner_results_view_data_clone.clone();
-                        let engine_unprivileged_state = engine_unprivileged_state_clone.clone();
-                        Self::refresh_scan_results(element_scanner_results_view_data, engine_unprivileged_state);
-                    }
+        let page_indices = if element_scanner_results_view_data.is_infinite_scroll {
+            let visible_row_range = &element_scanner_results_view_data.visible_row_range;
 
-                    thread::sleep(Duration::from_millis(Self::AUTO_REFRESH_INTERVAL_MS));
-                }
-            });
+            (visible_row_range.start as usize..visible_row_range.end as usize).collect::<Vec<_>>()
+        } else {
+            (0..element_scanner_results_view_data.get_row_count())
+                .filter_map(|row_position| element_scanner_results_view_data.get_row_index(row_position))
+                .collect()
+        };
+
+        if page_indices.is_empty() {
+            return String::new();
+        }
+
+        let mut text_table = TextTable::new(
+            ScanResultsTsvBuilder::COLUMNS
+                .iter()
+                .map(|column| column.header.to_string())
+                .collect(),
+        );
+
+        for scan_result in page_indices
+            .into_iter()
+            .filter_map(|index| element_scanner_results_view_data.get_scan_result(index))
+        {
+            let display_format = element_scanner_results_view_data.get_display_format(scan_result, active_display_format);
+
+            text_table.add_row(Self::get_scan_result_cells(scan_result, display_format).to_vec());
         }
+
+        text_table.format()
     }
 
-    pub fn navigate_first_page(
+    /// Gets the format that the values of a scan result are shown in, which is its own display format if one was chosen, or the
+    /// active display format otherwise.
+    pub fn get_display_format(
+        &self,
+        scan_result: &ScanResult,
+        active_display_format: AnonymousValueStringFormat,
+    ) -> AnonymousValueStringFormat {
+        let global_index = scan_result
+            .get_base_result()
+            .get_scan_result_ref()
+            .get_scan_result_global_index();
+
+        self.display_format_overrides
+            .get(&global_index)
+            .copied()
+            .unwrap_or(active_display_format)
+    }
+
+    /// Shows a scan result in a display format of its own, or in the active display format again if `None`.
+    pub fn set_display_format_override(
         element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        global_index: u64,
+        display_format_override: Option<AnonymousValueStringFormat>,
     ) {
-        let new_page_index = 0;
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner set display format override") {
+            match display_format_override {
+                Some(display_format) => {
+                    element_scanner_results_view_data
+                        .display_format_overrides
+                        .insert(global_index, display_format);
+                }
+                None => {
+                    element_scanner_results_view_data
+                        .display_format_overrides
+                        .remove(&global_index);
+                }
+            }
 
-        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+            element_scanner_results_view_data.apply_value_filter();
+        }
     }
 
-    pub fn navigate_last_page(
-        element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-    ) {
-        let cached_last_page_index = match element_scanner_results_view_data.read("Element scanner results navigation last") {
-            Some(element_scanner_results_view_data) => element_scanner_results_view_data.cached_last_page_index,
-            None => return,
+    /// Gets the address, current value, previous value, and type of a scan result, as shown in the results list.
+    fn get_scan_result_cells(
+        scan_result: &ScanResult,
+        display_format: AnonymousValueStringFormat,
+    ) -> [String; 4] {
+        let address = scan_result.get_address();
+        let address_string = if scan_result.is_module() {
+            format!("{}+{:X}", scan_result.get_module(), scan_result.get_module_offset())
+        } else if address <= u32::MAX as u64 {
+            format!("{:08X}", address)
+        } else {
+            format!("{:016X}", address)
         };
-        let cached_last_page_index = cached_last_page_index;
-        let new_page_index = cached_last_page_index;
 
-        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+        let current_value_string = scan_result.get_display_value_string(display_format);
+
+        let previous_value_string = scan_result
+            .get_previous_display_value(display_format)
+            .map(|value| value.get_anonymous_value_string())
+            .unwrap_or("??");
+
+        let type_string = DataTypeToStringConverter::convert_data_type_to_display_string(scan_result.get_data_type_ref().get_data_type_id());
+
+        [
+            address_string,
+            current_value_string.to_string(),
+            previous_value_string.to_string(),
+            type_string,
+        ]
     }
 
-    pub fn navigate_previous_page(
+    pub fn show_change_value_dialog(
         element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        seed_value: AnonymousValueString,
     ) {
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
-        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner results navigation previous") {
-            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-            None => return,
-        };
-        let new_page_index = Self::load_current_page_index(&element_scanner_results_view_data).saturating_sub(1);
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner show change value dialog") {
+            element_scanner_results_view_data.change_value_string = seed_value;
+            element_scanner_results_view_data.show_change_value_dialog = true;
+        }
+    }
 
-        drop(element_scanner_results_view_data);
+    pub fn hide_change_value_dialog(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner hide change value dialog") {
+            element_scanner_results_view_data.show_change_value_dialog = false;
+        }
+    }
 
-        Self::set_page_index(element_scanner_results_view_data_clone, engine_unprivileged_state, new_page_index);
+    /// Opens the change address dialog for the scan result at the given selection index, if it is loaded.
+    pub fn show_change_address_dialog(
+        element_scanner_results_view_data: Dependency<Self>,
+        index: usize,
+    ) {
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner show change address dialog") {
+            element_scanner_results_view_data.address_edit = element_scanner_results_view_data
+                .get_scan_result(index)
+                .map(ScanResultAddressEdit::new);
+        }
     }
 
-    pub fn navigate_next_page(
+    pub fn hide_change_address_dialog(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner hide change address dialog") {
+            element_scanner_results_view_data.address_edit = None;
+        }
+    }
+
+    /// Resolves the address entered in the change address dialog, then moves the edited result there and reads it again. The
+    /// dialog stays open with the reason if the address cannot be resolved.
+    pub fn apply_address_edit(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) {
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
-        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner results navigation next") {
-            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+        let (scan_result_ref, address_string) = match element_scanner_results_view_data.write("Element scanner apply address edit") {
+            Some(mut element_scanner_results_view_data) => match element_scanner_results_view_data.address_edit.as_mut() {
+                Some(address_edit) if !address_edit.is_applying => {
+                    address_edit.is_applying = true;
+                    address_edit.address_resolution = None;
+
+                    (address_edit.scan_result_ref.clone(), address_edit.address_string.clone())
+                }
+                _ => return,
+            },
             None => return,
         };
-        let new_page_index = Self::load_current_page_index(&element_scanner_results_view_data).saturating_add(1);
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
 
-        drop(element_scanner_results_view_data);
+        AddressResolver::resolve(&engine_unprivileged_state, &address_string, move |address_resolution| {
+            let address = match address_resolution {
+                Ok(resolved_address) => resolved_address.address,
+                Err(error) => {
+                    if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner address edit resolved")
+                        && let Some(address_edit) = element_scanner_results_view_data.address_edit.as_mut()
+                    {
+                        address_edit.address_resolution = Some(Err(error));
+                        address_edit.is_applying = false;
+                    }
 
-        Self::set_page_index(element_scanner_results_view_data_clone, engine_unprivileged_state, new_page_index);
+                    return;
+                }
+            };
+
+            Self::move_scan_result(element_scanner_results_view_data, engine_unprivileged_state_clone, scan_result_ref, address);
+        });
     }
 
-    pub fn set_page_size_override(
+    /// Moves a scan result to the given address, then reads only that result again to show its value at the new address.
+    fn move_scan_result(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-        page_size_override: Option<u32>,
+        scan_result_ref: ScanResultRef,
+        address: u64,
     ) {
-        let now = Instant::now();
-        let mut should_query = false;
+        let scan_results_set_property_request = ScanResultsSetPropertyRequest {
+            scan_result_refs: vec![scan_result_ref.clone()],
+            anonymous_value_string: AnonymousValueString::new(format!("{:X}", address), AnonymousValueStringFormat::Hexadecimal, ContainerType::None),
+            field_namespace: ScanResult::PROPERTY_NAME_ADDRESS.to_string(),
+            allow_stale: false,
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
 
-        if let Some(mut view_data) = element_scanner_results_view_data.write("Set page size override") {
-            let normalized_override = page_size_override.map(|value| value.max(1));
-            if view_data.page_size_override != normalized_override {
-                view_data.page_size_override = normalized_override;
-                view_data.last_page_size_override_change = Some(now);
+        scan_results_set_property_request.send(&engine_unprivileged_state, move |_scan_results_set_property_response| {
+            if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner move scan result response") {
+                element_scanner_results_view_data.address_edit = None;
             }
 
-            if view_data.is_querying_scan_results {
-                return;
-            }
+            let scan_results_refresh_request = ScanResultsRefreshRequest {
+                scan_result_refs: vec![scan_result_ref],
+            };
+
+            scan_results_refresh_request.send(&engine_unprivileged_state_clone, move |scan_results_refresh_response| {
+                let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Element scanner refresh moved result") {
+                    Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+                    None => return,
+                };
 
-            let debounce_elapsed = view_data
-                .last_page_size_override_change
-                .map(|changed_at| now.duration_since(changed_at) >= Duration::from_millis(Self::PAGE_SIZE_REQUERY_DEBOUNCE_MS))
-                .unwrap_or(true);
+                if !element_scanner_results_view_data.observe_scan_generation(scan_results_refresh_response.scan_generation) {
+                    return;
+                }
+
+                element_scanner_results_view_data.replace_scan_results(scan_results_refresh_response.scan_results);
+            });
+        });
+    }
+
+    pub fn show_export_dialog(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner show export dialog") {
+            element_scanner_results_view_data.show_export_dialog = true;
 
-            if debounce_elapsed && view_data.last_queried_page_size_override != view_data.page_size_override {
-                view_data.last_queried_page_size_override = view_data.page_size_override;
-                should_query = true;
+            if element_scanner_results_view_data.export_progress.is_none() {
+                element_scanner_results_view_data.export_status = None;
             }
         }
+    }
 
-        if should_query {
-            Self::query_scan_results(element_scanner_results_view_data, engine_unprivileged_state, false);
+    pub fn hide_export_dialog(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner hide export dialog") {
+            element_scanner_results_view_data.show_export_dialog = false;
         }
     }
 
-    pub fn set_selected_scan_results_value(
+    /// Sets the export format, changing the extension of the export path to match.
+    pub fn set_export_format(
+        &mut self,
+        export_format: ScanResultsExportFormat,
+    ) {
+        self.export_format = export_format;
+        self.export_path = Path::new(&self.export_path)
+            .with_extension(export_format.get_file_extension())
+            .to_string_lossy()
+            .into_owned();
+    }
+
+    /// Exports every scan result, rather than only the current page, to the export path. Pages are queried and written one at a time
+    /// on a background thread, reporting progress through the export progress of this view data.
+    pub fn export_scan_results(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-        field_namespace: &str,
-        anonymous_value_string: AnonymousValueString,
+        active_display_format: AnonymousValueStringFormat,
     ) {
-        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data.clone());
+        let mut view_data = match element_scanner_results_view_data.write("Element scanner export scan results") {
+            Some(view_data) => view_data,
+            None => return,
+        };
 
-        if scan_result_refs.is_empty() {
+        if view_data.export_progress.is_some() {
             return;
         }
 
-        let scan_results_set_property_request = ScanResultsSetPropertyRequest {
-            scan_result_refs,
-            field_namespace: field_namespace.to_string(),
-            anonymous_value_string,
-        };
+        let export_path = PathBuf::from(view_data.export_path.trim());
+        let export_format = view_data.export_format;
+        let cancellation_token = Arc::new(AtomicBool::new(false));
 
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
-        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Set selected scan results") {
-            element_scanner_results_view_data.is_setting_properties = true;
-        }
-        Self::schedule_flag_timeout(element_scanner_results_view_data.clone(), FlagType::SettingProperties, 5000);
+        view_data.export_progress = Some((0, view_data.result_count));
+        view_data.export_cancellation_token = Some(cancellation_token.clone());
+        view_data.export_status = None;
+        drop(view_data);
 
-        scan_results_set_property_request.send(&engine_unprivileged_state, move |_scan_results_set_property_response| {
-            let mut element_scanner_results_view_data = match element_scanner_results_view_data_clone.write("Set selected scan results response") {
-                Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-                None => return,
+        thread::spawn(move || {
+            let export_result = Self::write_export(
+                &element_scanner_results_view_data,
+                &engine_unprivileged_state,
+                &export_path,
+                export_format,
+                active_display_format,
+                &cancellation_token,
+            );
+
+            // Leave no partial file behind, as it could be mistaken for a complete export.
+            if export_result.is_err() {
+                let _ = fs::remove_file(&export_path);
+            }
+
+            if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner export scan results finished") {
+                view_data.export_progress = None;
+                view_data.export_cancellation_token = None;
+                view_data.export_status = Some(match export_result {
+                    Ok(exported_count) => format!("Exported {} results to {}.", exported_count, export_path.display()),
+                    Err(error) => error,
+                });
+            }
+        });
+    }
+
+    pub fn cancel_export(element_scanner_results_view_data: Dependency<Self>) {
+        if let Some(view_data) = element_scanner_results_view_data.read("Element scanner cancel export")
+            && let Some(cancellation_token) = &view_data.export_cancellation_token
+        {
+            cancellation_token.store(true, Ordering::Release);
+        }
+    }
+
+    fn write_export(
+        element_scanner_results_view_data: &Dependency<Self>,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        export_path: &Path,
+        export_format: ScanResultsExportFormat,
+        active_display_format: AnonymousValueStringFormat,
+        cancellation_token: &AtomicBool,
+    ) -> Result<u64, String> {
+        let file = File::create(export_path).map_err(|error| format!("Failed to create {}: {}", export_path.display(), error))?;
+        let write_error = |error: io::Error| format!("Failed to write {}: {}", export_path.display(), error);
+        let mut exporter = ScanResultsExporter::new(BufWriter::new(file), export_format).map_err(write_error)?;
+        let mut export_scan_generation = None;
+        let mut page_index = 0;
+
+        loop {
+            if cancellation_token.load(Ordering::Acquire) {
+                return Err("Export cancelled.".to_string());
+            }
+
+            let scan_results_query_response = Self::query_export_page(engine_unprivileged_state, page_index)
+                .ok_or_else(|| "The engine stopped responding during the export.".to_string())?;
+
+            // Pages from different scans cannot be mixed, as the results would no longer describe a single scan.
+            if *export_scan_generation.get_or_insert(scan_results_query_response.scan_generation) != scan_results_query_response.scan_generation {
+                return Err("The scan results changed during the export. Export again once the scan finishes.".to_string());
+            }
+
+            for scan_result in &scan_results_query_response.scan_results {
+                exporter
+                    .write_row(&Self::get_scan_result_cells(scan_result, active_display_format), scan_result.get_is_frozen())
+                    .map_err(write_error)?;
+            }
+
+            if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner export progress") {
+                view_data.export_progress = Some((exporter.get_row_count(), scan_results_query_response.result_count));
+            }
+
+            if scan_results_query_response.scan_results.is_empty() || page_index >= scan_results_query_response.last_page_index {
+                break;
+            }
+
+            page_index += 1;
+        }
+
+        let exported_count = exporter.get_row_count();
+
+        exporter.finish().map_err(write_error)?;
+
+        Ok(exported_count)
+    }
+
+    /// Queries a page of scan results as large as the engine allows, waiting for the response.
+    fn query_export_page(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        page_index: u64,
+    ) -> Option<ScanResultsQueryResponse> {
+        let (response_sender, response_receiver) = crossbeam_channel::bounded(1);
+        let scan_results_query_request = ScanResultsQueryRequest {
+            page_index,
+            page_size: Some(u32::MAX),
+        };
+
+        scan_results_query_request.send(engine_unprivileged_state, move |scan_results_query_response| {
+            let _ = response_sender.send(scan_results_query_response);
+        });
+
+        response_receiver
+            .recv_timeout(Duration::from_millis(Self::EXPORT_PAGE_TIMEOUT_MS))
+            .ok()
+    }
+
+    pub fn poll_scan_results(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        static POLL_STARTED: OnceLock<()> = OnceLock::new();
+        if POLL_STARTED.set(()).is_err() {
+            return;
+        }
+
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+        let element_scanner_results_view_data_filter = element_scanner_results_view_data.clone();
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+
+        // Requery all scan results if they update, ignoring updates for scans older than the results already shown.
+        let scan_results_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<ScanResultsUpdatedEvent>(
+            move |scan_results_updated_event| {
+                element_scanner_results_view_data_filter
+                    .read("Element scanner results updated filter")
+                    .is_some_and(|view_data| scan_results_updated_event.scan_generation >= view_data.latest_scan_generation)
+            },
+            move |scan_results_updated_event| {
+                Self::on_scan_results_updated(
+                    element_scanner_results_view_data_clone.clone(),
+                    engine_unprivileged_state_clone.clone(),
+                    scan_results_updated_event,
+                );
+            },
+        );
+
+        if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner results subscribe") {
+            view_data.scan_results_subscription = Some(scan_results_subscription);
+        }
+    }
+
+    /// Registers the job that periodically reads the current values of the results on the current page. Registering again, ie from
+    /// another view over the same results, keeps the existing job.
+    pub fn register_refresh_job(
+        element_scanner_results_view_data: Dependency<Self>,
+        refresh_scheduler: &RefreshScheduler,
+    ) {
+        if element_scanner_results_view_data
+            .read("Element scanner results refresh job check")
+            .is_none_or(|view_data| view_data.refresh_job_handle.is_some())
+        {
+            return;
+        }
+
+        // Disabled by default, as the write made for each refresh contends with the UI thread for the view data lock, which can stall
+        // interactions such as clicking results. It can be enabled from the refresh rates menu, or by setting
+        // SQUALR_ENABLE_SCAN_RESULT_AUTO_REFRESH=1.
+        let is_enabled = std::env::var_os("SQUALR_ENABLE_SCAN_RESULT_AUTO_REFRESH").is_some();
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let refresh_job_handle = refresh_scheduler.register_job(
+            "Scan results",
+            Duration::from_millis(Self::AUTO_REFRESH_INTERVAL_MS),
+            is_enabled,
+            move |refresh_batch| {
+                Self::refresh_scan_results(element_scanner_results_view_data_clone.clone(), refresh_batch);
+            },
+        );
+
+        if let Some(mut view_data) = element_scanner_results_view_data.write("Element scanner results register refresh job") {
+            view_data.refresh_job_handle = Some(refresh_job_handle);
+        }
+    }
+
+    fn on_scan_results_updated(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        scan_results_updated_event: &ScanResultsUpdatedEvent,
+    ) {
+        let play_sound = !scan_results_updated_event.is_new_scan;
+
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Element scanner results updated") {
+            element_scanner_results_view_data.observe_scan_generation(scan_results_updated_event.scan_generation);
+            element_scanner_results_view_data.page_cache.clear();
+
+            // Writes made to the previous results cannot be undone, as their refs now point at different results.
+            if scan_results_updated_event.is_new_scan {
+                element_scanner_results_view_data.write_history.clear();
+                element_scanner_results_view_data
+                    .display_format_overrides
+                    .clear();
+                element_scanner_results_view_data.value_changed_at.clear();
+            }
+
+            // A query still in flight was sent before this update. Its response may be discarded as stale, so query again now
+            // rather than waiting on it.
+            element_scanner_results_view_data.is_querying_scan_results = false;
+        }
+
+        Self::query_scan_results(element_scanner_results_view_data, engine_unprivileged_state, play_sound);
+    }
+
+    /// Records a scan generation reported by the engine, returning false if it is older than the latest one seen. When the
+    /// generation advances, cached pages and the selection refer to results that no longer exist, so both are discarded.
+    fn observe_scan_generation(
+        &mut self,
+        scan_generation: u64,
+    ) -> bool {
+        if scan_generation < self.latest_scan_generation {
+            return false;
+        }
+
+        if scan_generation > self.latest_scan_generation {
+            self.latest_scan_generation = scan_generation;
+            self.page_cache.clear();
+            self.selection.clear();
+        }
+
+        true
+    }
+
+    pub fn navigate_first_page(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let new_page_index = 0;
+
+        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+    }
+
+    pub fn navigate_last_page(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let cached_last_page_index = match element_scanner_results_view_data.read("Element scanner results navigation last") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data.cached_last_page_index,
+            None => return,
+        };
+        let cached_last_page_index = cached_last_page_index;
+        let new_page_index = cached_last_page_index;
+
+        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+    }
+
+    pub fn navigate_previous_page(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner results navigation previous") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+        let new_page_index = Self::load_current_page_index(&element_scanner_results_view_data).saturating_sub(1);
+
+        drop(element_scanner_results_view_data);
+
+        Self::set_page_index(element_scanner_results_view_data_clone, engine_unprivileged_state, new_page_index);
+    }
+
+    pub fn navigate_next_page(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Element scanner results navigation next") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+        let new_page_index = Self::load_current_page_index(&element_scanner_results_view_data).saturating_add(1);
+
+        drop(element_scanner_results_view_data);
+
+        Self::set_page_index(element_scanner_results_view_data_clone, engine_unprivileged_state, new_page_index);
+    }
+
+    /// Applies a page size derived from the view height, returning whether a requery is still pending.
+    /// This is expected to be called every frame, since the requery is only issued once the size has settled.
+    pub fn set_page_size_override(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        page_size_override: Option<u32>,
+    ) -> bool {
+        Self::set_page_size_override_at(element_scanner_results_view_data, engine_unprivileged_state, page_size_override, Instant::now())
+    }
+
+    fn set_page_size_override_at(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        page_size_override: Option<u32>,
+        now: Instant,
+    ) -> bool {
+        let mut view_data = match element_scanner_results_view_data.write("Set page size override") {
+            Some(view_data) => view_data,
+            None => return false,
+        };
+        let normalized_override = page_size_override.map(|value| value.max(1));
+
+        if Self::is_page_size_change_significant(view_data.page_size_override, normalized_override) {
+            view_data.page_size_override = normalized_override;
+            view_data.last_page_size_override_change = Some(now);
+        }
+
+        if view_data.last_queried_page_size_override == view_data.page_size_override {
+            return false;
+        }
+
+        let debounce_elapsed = view_data
+            .last_page_size_override_change
+            .map(|changed_at| now.duration_since(changed_at) >= Duration::from_millis(Self::PAGE_SIZE_REQUERY_DEBOUNCE_MS))
+            .unwrap_or(true);
+
+        if view_data.is_querying_scan_results || !debounce_elapsed {
+            return true;
+        }
+
+        view_data.last_queried_page_size_override = view_data.page_size_override;
+
+        // Drop the write-guard before querying, as the response callback may run synchronously.
+        drop(view_data);
+
+        Self::query_scan_results(element_scanner_results_view_data, engine_unprivileged_state, false);
+
+        false
+    }
+
+    fn is_page_size_change_significant(
+        current_page_size: Option<u32>,
+        new_page_size: Option<u32>,
+    ) -> bool {
+        match (current_page_size, new_page_size) {
+            (Some(current_page_size), Some(new_page_size)) => current_page_size.abs_diff(new_page_size) > Self::PAGE_SIZE_HYSTERESIS_ROWS,
+            (current_page_size, new_page_size) => current_page_size != new_page_size,
+        }
+    }
+
+    pub fn set_selected_scan_results_value(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        field_namespace: &str,
+        anonymous_value_string: AnonymousValueString,
+        stale_results_policy: StaleResultsPolicy,
+    ) {
+        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data.clone());
+
+        if scan_result_refs.is_empty() {
+            return;
+        }
+
+        if stale_results_policy == StaleResultsPolicy::Confirm
+            && Self::request_stale_results_confirmation(
+                element_scanner_results_view_data.clone(),
+                ElementScannerResultFrameAction::CommitValueToSelection(anonymous_value_string.clone()),
+                None,
+            )
+        {
+            return;
+        }
+
+        Self::record_value_write(element_scanner_results_view_data.clone(), field_namespace, &anonymous_value_string);
+        Self::send_set_property_request(
+            element_scanner_results_view_data,
+            engine_unprivileged_state,
+            field_namespace,
+            scan_result_refs,
+            anonymous_value_string,
+            stale_results_policy == StaleResultsPolicy::Include,
+        );
+    }
+
+    /// Restores the values held before the most recent write, moving the write onto the redo stack. Results that no longer
+    /// exist are skipped.
+    pub fn undo_scan_result_write(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (value_write, result_count) = match element_scanner_results_view_data.write("Element scanner results view data: undo value write") {
+            Some(mut element_scanner_results_view_data) => match element_scanner_results_view_data.write_history.take_undo() {
+                Some(value_write) => {
+                    element_scanner_results_view_data
+                        .write_history
+                        .push_redo(value_write.clone());
+                    (value_write, element_scanner_results_view_data.result_count)
+                }
+                None => return,
+            },
+            None => return,
+        };
+        let previous_value_groups =
+            value_write.group_previous_values(|scan_result_ref| scan_result_ref.get_scan_result_global_index() < result_count);
+
+        if previous_value_groups.is_empty() {
+            log::info!("Nothing to undo, as the written scan results no longer exist.");
+            return;
+        }
+
+        // Undoing restores memory the user already chose to write, so stale results are skipped rather than asked about.
+        for (previous_value, scan_result_refs) in previous_value_groups {
+            Self::send_set_property_request(
+                element_scanner_results_view_data.clone(),
+                engine_unprivileged_state.clone(),
+                &value_write.field_namespace,
+                scan_result_refs,
+                previous_value,
+                false,
+            );
+        }
+    }
+
+    /// Writes the most recently undone value again, moving the write back onto the undo stack.
+    pub fn redo_scan_result_write(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (value_write, result_count) = match element_scanner_results_view_data.write("Element scanner results view data: redo value write") {
+            Some(mut element_scanner_results_view_data) => match element_scanner_results_view_data.write_history.take_redo() {
+                Some(value_write) => {
+                    element_scanner_results_view_data
+                        .write_history
+                        .push_undo(value_write.clone());
+                    (value_write, element_scanner_results_view_data.result_count)
+                }
+                None => return,
+            },
+            None => return,
+        };
+        let scan_result_refs = value_write
+            .scan_result_refs
+            .iter()
+            .filter(|scan_result_ref| scan_result_ref.get_scan_result_global_index() < result_count)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if scan_result_refs.is_empty() {
+            log::info!("Nothing to redo, as the written scan results no longer exist.");
+            return;
+        }
+
+        Self::send_set_property_request(
+            element_scanner_results_view_data,
+            engine_unprivileged_state,
+            &value_write.field_namespace,
+            scan_result_refs,
+            value_write.new_value,
+            false,
+        );
+    }
+
+    /// Records the values held by the selection before a value write, such that the write can be undone. Only loaded rows have
+    /// a known value to restore, so rows that were never loaded in infinite scroll mode are left out.
+    fn record_value_write(
+        element_scanner_results_view_data: Dependency<Self>,
+        field_namespace: &str,
+        new_value: &AnonymousValueString,
+    ) {
+        // Only the value field is read back with each result, so writes to other fields have nothing to restore.
+        if field_namespace != ScanResult::PROPERTY_NAME_VALUE {
+            return;
+        }
+
+        let symbol_registry = SymbolRegistry::get_instance();
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Element scanner results view data: record value write") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+        let mut scan_result_refs = Vec::new();
+        let mut previous_values = Vec::new();
+
+        for (_index, scan_result) in element_scanner_results_view_data
+            .iter_loaded_scan_results()
+            .filter(|(index, _scan_result)| element_scanner_results_view_data.selection.contains(*index))
+        {
+            let previous_value = match scan_result
+                .get_recently_read_value()
+                .as_ref()
+                .or(scan_result.get_current_value().as_ref())
+            {
+                Some(previous_value) => previous_value,
+                None => continue,
+            };
+
+            match symbol_registry.anonymize_value(previous_value, AnonymousValueStringFormat::Decimal) {
+                Ok(previous_value) => {
+                    scan_result_refs.push(scan_result.get_base_result().get_scan_result_ref().clone());
+                    previous_values.push(previous_value);
+                }
+                Err(error) => log::warn!("Failed to record the previous value of a scan result: {}", error),
+            }
+        }
+
+        if scan_result_refs.is_empty() {
+            return;
+        }
+
+        element_scanner_results_view_data
+            .write_history
+            .record(ScanResultValueWrite {
+                field_namespace: field_namespace.to_string(),
+                scan_result_refs,
+                previous_values,
+                new_value: new_value.clone(),
+            });
+    }
+
+    fn send_set_property_request(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        field_namespace: &str,
+        scan_result_refs: Vec<ScanResultRef>,
+        anonymous_value_string: AnonymousValueString,
+        allow_stale: bool,
+    ) {
+        let scan_results_set_property_request = ScanResultsSetPropertyRequest {
+            scan_result_refs,
+            field_namespace: field_namespace.to_string(),
+            anonymous_value_string,
+            allow_stale,
+        };
+
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data.write("Set selected scan results") {
+            element_scanner_results_view_data.is_setting_properties = true;
+        }
+        Self::schedule_flag_timeout(element_scanner_results_view_data.clone(), FlagType::SettingProperties, 5000);
+
+        scan_results_set_property_request.send(&engine_unprivileged_state, move |_scan_results_set_property_response| {
+            let mut element_scanner_results_view_data = match element_scanner_results_view_data_clone.write("Set selected scan results response") {
+                Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+                None => return,
             };
 
             element_scanner_results_view_data.is_setting_properties = false;
         });
     }
 
+    /// Gets whether the loaded rows of the selection hold integers and whether they hold floating point values, such that
+    /// nudges can be labelled with the step sizes they will apply.
+    pub fn get_selection_nudge_kinds(&self) -> (bool, bool) {
+        if self.selection.is_empty() {
+            return (false, false);
+        }
+
+        let mut has_integers = false;
+        let mut has_floating_points = false;
+
+        for (_index, scan_result) in self
+            .iter_loaded_scan_results()
+            .filter(|(index, _scan_result)| self.selection.contains(*index))
+        {
+            match ValueNudge::get_kind(scan_result.get_data_type_ref().get_data_type_id()) {
+                Some(ValueNudgeKind::Integer) => has_integers = true,
+                Some(ValueNudgeKind::FloatingPoint) => has_floating_points = true,
+                None => {}
+            }
+        }
+
+        (has_integers, has_floating_points)
+    }
+
+    /// Nudges the value of each selected scan result, using the arithmetic of its own data type and the overflow policy from
+    /// the scan settings. New values are shown right away, then reverted for any scan result that the engine fails to write.
+    pub fn nudge_selected_scan_results(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        value_nudge: ValueNudge,
+    ) {
+        let scan_settings_list_request = ScanSettingsListRequest {};
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        scan_settings_list_request.send(&engine_unprivileged_state, move |scan_settings_list_response| {
+            let value_overflow_policy = scan_settings_list_response
+                .scan_settings
+                .map(|scan_settings| scan_settings.value_overflow_policy)
+                .unwrap_or_default();
+
+            Self::write_nudged_scan_results(element_scanner_results_view_data, engine_unprivileged_state_clone, value_nudge, value_overflow_policy);
+        });
+    }
+
+    fn write_nudged_scan_results(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        value_nudge: ValueNudge,
+        value_overflow_policy: ValueOverflowPolicy,
+    ) {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let mut scan_result_refs = Vec::new();
+        let mut anonymous_value_strings = Vec::new();
+        let mut previous_values = HashMap::new();
+        let mut view_data = match element_scanner_results_view_data.write("Element scanner results view data: nudge selected scan results") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        // Only loaded rows have a value to nudge from, which in infinite scroll mode may be a subset of the selection.
+        let selected_indices = view_data
+            .iter_loaded_scan_results()
+            .map(|(index, _scan_result)| index)
+            .filter(|index| view_data.selection.contains(*index))
+            .collect::<Vec<_>>();
+
+        for index in selected_indices {
+            let Some(scan_result) = view_data.get_scan_result_mut(index) else {
+                continue;
+            };
+            let current_value = match scan_result
+                .get_recently_read_value()
+                .as_ref()
+                .or(scan_result.get_current_value().as_ref())
+            {
+                Some(current_value) => current_value,
+                None => continue,
+            };
+            let Some(nudged_value) = value_nudge.apply(current_value, value_overflow_policy) else {
+                continue;
+            };
+            let anonymous_value_string = match symbol_registry.anonymize_value(&nudged_value, AnonymousValueStringFormat::Decimal) {
+                Ok(anonymous_value_string) => anonymous_value_string,
+                Err(error) => {
+                    log::warn!("Failed to nudge scan result value: {}", error);
+                    continue;
+                }
+            };
+            let nudged_display_values = symbol_registry
+                .anonymize_value_to_supported_formats(&nudged_value)
+                .unwrap_or_default();
+            let scan_result_ref = scan_result.get_base_result().get_scan_result_ref().clone();
+
+            previous_values.insert(
+                scan_result_ref.get_scan_result_global_index(),
+                (
+                    scan_result.get_recently_read_value().clone(),
+                    scan_result.get_recently_read_display_values().clone(),
+                ),
+            );
+            scan_result.set_recently_read_value_client_only(Some(nudged_value), nudged_display_values);
+            scan_result_refs.push(scan_result_ref);
+            anonymous_value_strings.push(anonymous_value_string);
+        }
+
+        if scan_result_refs.is_empty() {
+            return;
+        }
+
+        view_data.is_setting_properties = true;
+
+        // Drop the write guard before sending the request, as the callback may run synchronously and take the same lock.
+        drop(view_data);
+
+        Self::schedule_flag_timeout(element_scanner_results_view_data.clone(), FlagType::SettingProperties, 5000);
+
+        // Nudges are small repeated steps, so stale results are skipped rather than asked about on every step.
+        let scan_results_set_values_request = ScanResultsSetValuesRequest {
+            scan_result_refs,
+            anonymous_value_strings,
+            allow_stale: false,
+        };
+
+        scan_results_set_values_request.send(&engine_unprivileged_state, move |scan_results_set_values_response| {
+            let mut view_data = match element_scanner_results_view_data.write("Element scanner results view data: nudge selected scan results response") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            for failed_scan_result_ref in scan_results_set_values_response.failed_scan_result_refs {
+                let global_index = failed_scan_result_ref.get_scan_result_global_index();
+                let Some((previous_value, previous_display_values)) = previous_values.remove(&global_index) else {
+                    continue;
+                };
+
+                if let Some(local_index) = Self::find_local_index_by_global_index(&view_data, global_index) {
+                    if let Some(scan_result) = view_data.get_scan_result_mut(local_index) {
+                        scan_result.set_recently_read_value_client_only(previous_value, previous_display_values);
+                    }
+                } else {
+                    log::warn!("Failed to find scan result to revert client side nudge (global index: {})", global_index);
+                }
+            }
+
+            view_data.is_setting_properties = false;
+        });
+    }
+
     fn load_current_page_index(element_scanner_results_view_data: &Guard<Arc<ElementScannerResultsViewData>>) -> u64 {
         element_scanner_results_view_data
             .current_page_index
@@ -403,9 +1492,21 @@ impl ElementScannerResultsViewData {
             Some(element_scanner_results_view_data) => element_scanner_results_view_data,
             None => return,
         };
-        let page_index = Self::load_current_page_index_write(&element_scanner_results_view_data);
-        let page_size = element_scanner_results_view_data.page_size_override;
+        let (page_index, page_size) = if element_scanner_results_view_data.is_infinite_scroll {
+            let (first_visible_page_index, _) = element_scanner_results_view_data
+                .page_cache
+                .locate(element_scanner_results_view_data.visible_row_range.start);
+
+            (first_visible_page_index, Some(Self::INFINITE_SCROLL_PAGE_SIZE))
+        } else {
+            (
+                Self::load_current_page_index_write(&element_scanner_results_view_data),
+                element_scanner_results_view_data.page_size_override,
+            )
+        };
         let scan_results_query_request = ScanResultsQueryRequest { page_index, page_size };
+        let cache_generation = element_scanner_results_view_data.page_cache.get_generation();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
 
         element_scanner_results_view_data.is_querying_scan_results = true;
         Self::schedule_flag_timeout(element_scanner_results_view_data_clone.clone(), FlagType::QueryingResults, 5000);
@@ -416,16 +1517,41 @@ impl ElementScannerResultsViewData {
         scan_results_query_request.send(&engine_unprivileged_state, move |scan_results_query_response| {
             // let audio_player = &self.audio_player;
             let byte_size_in_metric = StorageSizeConversions::value_to_metric_size(scan_results_query_response.total_size_in_bytes as u128);
+            let last_read_size_in_metric = StorageSizeConversions::value_to_metric_size(scan_results_query_response.last_read_size_in_bytes as u128);
             let result_count = scan_results_query_response.result_count;
+            let page_index = scan_results_query_response.page_index;
+            let last_page_index = scan_results_query_response.last_page_index;
+            let mut prefetch_page_index = None;
 
             if let Some(mut element_scanner_results_view_data) = element_scanner_results_view_data_clone.write("Query scan results response") {
+                if !element_scanner_results_view_data.observe_scan_generation(scan_results_query_response.scan_generation) {
+                    return;
+                }
+
+                let scan_results = Arc::new(scan_results_query_response.scan_results);
+
                 element_scanner_results_view_data.is_querying_scan_results = false;
-                element_scanner_results_view_data.current_page_index = scan_results_query_response.page_index;
-                element_scanner_results_view_data.cached_last_page_index = scan_results_query_response.last_page_index;
+                element_scanner_results_view_data.current_page_index = page_index;
+                element_scanner_results_view_data.cached_last_page_index = last_page_index;
                 element_scanner_results_view_data.last_page_size = scan_results_query_response.page_size.max(1);
                 element_scanner_results_view_data.result_count = result_count;
-                element_scanner_results_view_data.stats_string = format!("{} (Count: {})", byte_size_in_metric, result_count);
-                element_scanner_results_view_data.current_scan_results = Arc::new(scan_results_query_response.scan_results);
+                element_scanner_results_view_data.stats_string = format!(
+                    "{} (Count: {}, Last read: {})",
+                    byte_size_in_metric, result_count, last_read_size_in_metric
+                );
+                element_scanner_results_view_data.last_scan_statistics = scan_results_query_response.last_scan_statistics;
+                element_scanner_results_view_data.set_current_scan_results(scan_results.clone());
+                element_scanner_results_view_data.results_generation = element_scanner_results_view_data.results_generation.wrapping_add(1);
+                element_scanner_results_view_data.store_queried_page(cache_generation, page_index, scan_results_query_response.page_size, scan_results);
+
+                // Prefetch the next page in paged mode, such that navigating forward shows it immediately.
+                if !element_scanner_results_view_data.is_infinite_scroll && page_index < last_page_index {
+                    prefetch_page_index = Some(page_index + 1);
+                }
+            }
+
+            if let Some(prefetch_page_index) = prefetch_page_index {
+                Self::fetch_pages(element_scanner_results_view_data_clone.clone(), engine_unprivileged_state_clone, vec![prefetch_page_index]);
             }
 
             if play_sound {
@@ -438,17 +1564,23 @@ impl ElementScannerResultsViewData {
         });
     }
 
-    /// Fetches up-to-date values and module information for the current scan results, then updates the UI.
+    /// Queues reading up-to-date values and module information for the current scan results, then updates the UI once they arrive.
+    /// Pages too large to refresh cheaply are skipped.
     fn refresh_scan_results(
         element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        refresh_batch: &mut RefreshBatch,
     ) {
         if element_scanner_results_view_data
             .read("Refresh scan results")
             .map(|element_scanner_results_view_data| {
-                element_scanner_results_view_data.is_querying_scan_results || element_scanner_results_view_data.is_refreshing_scan_results
+                element_scanner_results_view_data.is_querying_scan_results
+                    || element_scanner_results_view_data.is_refreshing_scan_results
+                    || element_scanner_results_view_data
+                        .current_scan_results
+                        .is_empty()
+                    || element_scanner_results_view_data.current_scan_results.len() > Self::AUTO_REFRESH_MAX_RESULTS_PER_PAGE
             })
-            .unwrap_or(false)
+            .unwrap_or(true)
         {
             return;
         }
@@ -458,125 +1590,563 @@ impl ElementScannerResultsViewData {
             Some(element_scanner_results_view_data) => element_scanner_results_view_data,
             None => return,
         };
-        let engine_unprivileged_state = &engine_unprivileged_state;
 
         element_scanner_results_view_data.is_refreshing_scan_results = true;
         Self::schedule_flag_timeout(element_scanner_results_view_data_clone.clone(), FlagType::RefreshingResults, 5000);
 
-        // Fire a request to get all scan result data needed for display.
-        let scan_results_refresh_request = ScanResultsRefreshRequest {
-            scan_result_refs: element_scanner_results_view_data
-                .current_scan_results
-                .iter()
-                .map(|scan_result| scan_result.get_base_result().get_scan_result_ref().clone())
-                .collect(),
+        let scan_result_refs = element_scanner_results_view_data
+            .current_scan_results
+            .iter()
+            .map(|scan_result| scan_result.get_base_result().get_scan_result_ref().clone())
+            .collect();
+
+        // Drop to commit the write.
+        drop(element_scanner_results_view_data);
+
+        refresh_batch.refresh_scan_results(scan_result_refs, move |scan_results, scan_generation| {
+            let mut element_scanner_results_view_data = match element_scanner_results_view_data_clone.write("Refresh scan results response") {
+                Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+                None => return,
+            };
+
+            element_scanner_results_view_data.is_refreshing_scan_results = false;
+
+            if !element_scanner_results_view_data.observe_scan_generation(scan_generation) {
+                return;
+            }
+
+            element_scanner_results_view_data.record_value_changes(&scan_results, Instant::now());
+
+            // Update UI with refreshed, full scan result values.
+            element_scanner_results_view_data.set_current_scan_results(Arc::new(scan_results));
+            element_scanner_results_view_data.results_generation = element_scanner_results_view_data.results_generation.wrapping_add(1);
+        });
+    }
+
+    /// Replaces the loaded results with freshly read copies of some of them, leaving every other row as it was.
+    fn replace_scan_results(
+        &mut self,
+        scan_results: Vec<ScanResult>,
+    ) {
+        for scan_result in scan_results {
+            let global_index = scan_result
+                .get_base_result()
+                .get_scan_result_ref()
+                .get_scan_result_global_index();
+
+            if let Some(local_index) = Self::find_local_index_by_global_index(self, global_index)
+                && let Some(loaded_scan_result) = self.get_scan_result_mut(local_index)
+            {
+                *loaded_scan_result = scan_result;
+            }
+        }
+
+        // The replaced results may sort or filter differently.
+        self.set_current_scan_results(self.current_scan_results.clone());
+        self.results_generation = self.results_generation.wrapping_add(1);
+    }
+
+    /// Sorts the current page by the given column, moving from ascending to descending to snapshot order with each call.
+    pub fn toggle_sort(
+        element_scanner_results_view_data: Dependency<Self>,
+        column: ScanResultsSortColumn,
+    ) {
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Element scanner results toggle sort") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
         };
+        element_scanner_results_view_data.sort = ScanResultsSort::get_next(element_scanner_results_view_data.sort, column);
+
+        if element_scanner_results_view_data.is_infinite_scroll {
+            return;
+        }
+
+        let mut scan_results = element_scanner_results_view_data.current_scan_results.as_ref().clone();
+
+        match element_scanner_results_view_data.sort {
+            Some(sort) => sort.sort(&mut scan_results),
+            // Pages hold contiguous results, so snapshot order is the order of their global indices.
+            None => scan_results.sort_by_key(|scan_result| {
+                scan_result
+                    .get_base_result()
+                    .get_scan_result_ref()
+                    .get_scan_result_global_index()
+            }),
+        }
+
+        element_scanner_results_view_data.reorder_current_scan_results(scan_results);
+    }
+
+    /// Shows the given results as the current page, ordered by the active sort.
+    fn set_current_scan_results(
+        &mut self,
+        scan_results: Arc<Vec<ScanResult>>,
+    ) {
+        match self.sort.filter(|_| !self.is_infinite_scroll) {
+            Some(sort) => {
+                let mut scan_results = Arc::unwrap_or_clone(scan_results);
+
+                sort.sort(&mut scan_results);
+                self.reorder_current_scan_results(scan_results);
+            }
+            None => {
+                self.current_scan_results = scan_results;
+                self.apply_value_filter();
+            }
+        }
+    }
+
+    /// Replaces the current page with the given results, which may be in a different order. Selected rows follow their results to
+    /// their new positions, such that freezing or deleting the selection still targets the same results.
+    fn reorder_current_scan_results(
+        &mut self,
+        scan_results: Vec<ScanResult>,
+    ) {
+        let get_global_index = |scan_result: &ScanResult| {
+            scan_result
+                .get_base_result()
+                .get_scan_result_ref()
+                .get_scan_result_global_index()
+        };
+        let previous_global_indices = self
+            .current_scan_results
+            .iter()
+            .map(get_global_index)
+            .collect::<Vec<_>>();
+        let local_indices_by_global_index = scan_results
+            .iter()
+            .enumerate()
+            .map(|(local_index, scan_result)| (get_global_index(scan_result), local_index))
+            .collect::<HashMap<_, _>>();
+
+        self.selection.remap(|local_index| {
+            previous_global_indices
+                .get(local_index)
+                .and_then(|global_index| local_indices_by_global_index.get(global_index).copied())
+        });
+        self.current_scan_results = Arc::new(scan_results);
+        self.apply_value_filter();
+    }
+
+    /// Records which of the current results read a different value in the refreshed results. Results no longer shown, and changes
+    /// that are no longer highlighted, are forgotten, such that only the current page is ever tracked.
+    fn record_value_changes(
+        &mut self,
+        refreshed_scan_results: &[ScanResult],
+        now: Instant,
+    ) {
+        let previous_display_values = self
+            .current_scan_results
+            .iter()
+            .map(|scan_result| {
+                (
+                    scan_result
+                        .get_base_result()
+                        .get_scan_result_ref()
+                        .get_scan_result_global_index(),
+                    scan_result.get_recently_read_display_values(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let mut value_changed_at = HashMap::with_capacity(self.value_changed_at.len());
+
+        for refreshed_scan_result in refreshed_scan_results {
+            let global_index = refreshed_scan_result
+                .get_base_result()
+                .get_scan_result_ref()
+                .get_scan_result_global_index();
+            let refreshed_display_values = refreshed_scan_result.get_recently_read_display_values();
+
+            // Results read for the first time, or no longer readable, have nothing to compare against.
+            let has_changed = previous_display_values
+                .get(&global_index)
+                .is_some_and(|previous_display_values| {
+                    !previous_display_values.is_empty() && !refreshed_display_values.is_empty() && *previous_display_values != refreshed_display_values
+                });
+
+            if has_changed {
+                value_changed_at.insert(global_index, now);
+            } else if let Some(changed_at) = self.value_changed_at.get(&global_index)
+                && now.saturating_duration_since(*changed_at) < Self::VALUE_CHANGE_HIGHLIGHT_DURATION
+            {
+                value_changed_at.insert(global_index, *changed_at);
+            }
+        }
+
+        self.value_changed_at = value_changed_at;
+    }
+
+    /// Gets how strongly the value of a result should be highlighted, from 1 when it just changed down to 0 once the highlight
+    /// has faded out.
+    pub fn get_value_change_strength(
+        &self,
+        global_index: u64,
+        now: Instant,
+    ) -> f32 {
+        match self.value_changed_at.get(&global_index) {
+            Some(changed_at) => {
+                let elapsed_ratio =
+                    now.saturating_duration_since(*changed_at).as_secs_f32() / Self::VALUE_CHANGE_HIGHLIGHT_DURATION.as_secs_f32();
+
+                1.0 - elapsed_ratio.min(1.0)
+            }
+            None => 0.0,
+        }
+    }
+
+    fn set_page_index(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        new_page_index: u64,
+    ) {
+        if element_scanner_results_view_data
+            .read("Set page index")
+            .map(|element_scanner_results_view_data| element_scanner_results_view_data.is_querying_scan_results)
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Set page index") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+        let new_page_index = new_page_index.clamp(0, element_scanner_results_view_data.cached_last_page_index);
+
+        // If the new index is the same as the current one, do nothing.
+        if new_page_index == element_scanner_results_view_data.current_page_index {
+            return;
+        }
+
+        element_scanner_results_view_data.current_page_index = new_page_index;
+        element_scanner_results_view_data.value_changed_at.clear();
+
+        // Show a cached page right away. It is still requeried below, since its values may be out of date.
+        let cached_page = element_scanner_results_view_data
+            .page_cache
+            .get_page(new_page_index)
+            .filter(|_| element_scanner_results_view_data.page_cache.get_page_size() == element_scanner_results_view_data.last_page_size)
+            .cloned();
+
+        if let Some(cached_page) = cached_page {
+            element_scanner_results_view_data.page_cache.touch(new_page_index);
+            element_scanner_results_view_data.set_current_scan_results(cached_page);
+            element_scanner_results_view_data.results_generation = element_scanner_results_view_data.results_generation.wrapping_add(1);
+        }
+
+        // Clear out our selected items.
+        element_scanner_results_view_data.selection.clear();
+
+        // Drop to commit the write.
+        drop(element_scanner_results_view_data);
+
+        // Refresh scan results with the new page index. // JIRA: Should happen in the loop technically, but we need to make the MVVM bindings deadlock resistant.
+        Self::query_scan_results(element_scanner_results_view_data_clone, engine_unprivileged_state, false);
+    }
+
+    pub fn set_page_index_string(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        new_page_index_text: &str,
+    ) {
+        // Extract numeric part from new_page_index_text and parse it to u64, defaulting to 0.
+        let new_page_index = new_page_index_text
+            .chars()
+            .take_while(|char| char.is_digit(10))
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap_or(0);
+
+        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+    }
+
+    /// Switches between paged and infinite scroll mode. Selection is cleared, since its indices mean different things in each mode.
+    pub fn set_infinite_scroll(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_infinite_scroll: bool,
+    ) {
+        let mut view_data = match element_scanner_results_view_data.write("Set infinite scroll") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+
+        if view_data.is_infinite_scroll == is_infinite_scroll {
+            return;
+        }
+
+        view_data.is_infinite_scroll = is_infinite_scroll;
+        view_data.current_page_index = 0;
+        view_data.visible_row_range = 0..0;
+        view_data.selection.clear();
+        view_data.page_cache.clear();
+        view_data.value_changed_at.clear();
+        view_data.apply_value_filter();
+
+        // Drop the write-guard before querying, as the response callback may run synchronously.
+        drop(view_data);
+
+        Self::query_scan_results(element_scanner_results_view_data, engine_unprivileged_state, false);
+    }
+
+    /// Records the rows visible in infinite scroll mode, and fetches any pages needed to show them (plus one page either side).
+    /// Returns whether any fetch is still in flight, such that the caller can keep repainting until placeholders are filled in.
+    pub fn set_visible_row_range(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        visible_row_range: Range<u64>,
+    ) -> bool {
+        let pages_to_fetch = match element_scanner_results_view_data.write("Set visible row range") {
+            Some(mut view_data) => {
+                let visible_page_range = view_data.page_cache.get_page_range(visible_row_range.clone());
+
+                for page_index in visible_page_range.clone() {
+                    view_data.page_cache.touch(page_index);
+                }
+
+                view_data.visible_row_range = visible_row_range;
+
+                let last_page_index = view_data.result_count.saturating_sub(1) / view_data.page_cache.get_page_size();
+                let prefetch_page_range = visible_page_range.start.saturating_sub(1)..visible_page_range.end.saturating_add(1).min(last_page_index + 1);
+
+                view_data.page_cache.get_missing_pages(prefetch_page_range)
+            }
+            None => return false,
+        };
+
+        Self::fetch_pages(element_scanner_results_view_data.clone(), engine_unprivileged_state, pages_to_fetch);
+
+        element_scanner_results_view_data
+            .read("Set visible row range pending pages")
+            .map(|view_data| view_data.page_cache.has_pending_pages())
+            .unwrap_or(false)
+    }
+
+    /// Fetches the given pages into the page cache in the background, skipping pages that are already cached or in flight.
+    fn fetch_pages(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        page_indices: Vec<u64>,
+    ) {
+        let mut view_data = match element_scanner_results_view_data.write("Fetch scan result pages") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let missing_page_indices = view_data.page_cache.get_missing_pages(page_indices);
+        let page_size = view_data.page_cache.get_page_size().min(u32::MAX as u64) as u32;
+        let cache_generation = view_data.page_cache.get_generation();
+
+        for page_index in &missing_page_indices {
+            view_data.page_cache.mark_pending(*page_index);
+        }
+
+        // Drop the write-guard before sending, as the response callbacks may run synchronously.
+        drop(view_data);
+
+        for page_index in missing_page_indices {
+            let element_scanner_results_view_data = element_scanner_results_view_data.clone();
+            let scan_results_query_request = ScanResultsQueryRequest {
+                page_index,
+                page_size: Some(page_size),
+            };
+
+            scan_results_query_request.send(&engine_unprivileged_state, move |scan_results_query_response| {
+                let mut view_data = match element_scanner_results_view_data.write("Fetch scan result pages response") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
 
-        // Drop to commit the write.
-        drop(element_scanner_results_view_data);
+                // The engine clamps out of range pages, so the requested page may not be the one returned.
+                view_data.page_cache.clear_pending(page_index);
 
-        scan_results_refresh_request.send(engine_unprivileged_state, move |scan_results_refresh_response| {
-            let mut element_scanner_results_view_data = match element_scanner_results_view_data_clone.write("Refresh scan results response") {
-                Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-                None => return,
-            };
+                if !view_data.observe_scan_generation(scan_results_query_response.scan_generation) {
+                    return;
+                }
 
-            // Update UI with refreshed, full scan result values.
-            element_scanner_results_view_data.is_refreshing_scan_results = false;
-            element_scanner_results_view_data.current_scan_results = Arc::new(scan_results_refresh_response.scan_results);
-        });
+                view_data.store_queried_page(
+                    cache_generation,
+                    scan_results_query_response.page_index,
+                    scan_results_query_response.page_size,
+                    Arc::new(scan_results_query_response.scan_results),
+                );
+            });
+        }
     }
 
-    fn set_page_index(
-        element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-        new_page_index: u64,
+    /// Stores a queried page in the page cache, unless results were updated since it was requested.
+    /// The engine may use a different page size than requested, in which case the cache adopts the engine's page size.
+    fn store_queried_page(
+        &mut self,
+        cache_generation: u64,
+        page_index: u64,
+        page_size: u64,
+        scan_results: Arc<Vec<ScanResult>>,
     ) {
-        if element_scanner_results_view_data
-            .read("Set page index")
-            .map(|element_scanner_results_view_data| element_scanner_results_view_data.is_querying_scan_results)
-            .unwrap_or(false)
-        {
+        if cache_generation != self.page_cache.get_generation() {
             return;
         }
 
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
-        let mut element_scanner_results_view_data = match element_scanner_results_view_data.write("Set page index") {
-            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-            None => return,
-        };
-        let new_page_index = new_page_index.clamp(0, element_scanner_results_view_data.cached_last_page_index);
-
-        // If the new index is the same as the current one, do nothing.
-        if new_page_index == element_scanner_results_view_data.current_page_index {
-            return;
-        }
+        self.page_cache.set_page_size(page_size);
 
-        element_scanner_results_view_data.current_page_index = new_page_index;
+        let cache_generation = self.page_cache.get_generation();
 
-        // Clear out our selected items.
-        element_scanner_results_view_data.selection_index_start = None;
-        element_scanner_results_view_data.selection_index_end = None;
+        self.page_cache
+            .insert_page(page_index, page_size, cache_generation, scan_results);
+    }
 
-        // Drop to commit the write.
-        drop(element_scanner_results_view_data);
+    /// Gets the scan result at a selection index, if it is loaded.
+    pub fn get_scan_result(
+        &self,
+        index: usize,
+    ) -> Option<&ScanResult> {
+        if self.is_infinite_scroll {
+            self.page_cache.get_scan_result(index as u64)
+        } else {
+            self.current_scan_results.get(index)
+        }
+    }
 
-        // Refresh scan results with the new page index. // JIRA: Should happen in the loop technically, but we need to make the MVVM bindings deadlock resistant.
-        Self::query_scan_results(element_scanner_results_view_data_clone, engine_unprivileged_state, false);
+    fn get_scan_result_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut ScanResult> {
+        if self.is_infinite_scroll {
+            self.page_cache.get_scan_result_mut(index as u64)
+        } else {
+            Arc::make_mut(&mut self.current_scan_results).get_mut(index)
+        }
     }
 
-    pub fn set_page_index_string(
-        element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-        new_page_index_text: &str,
-    ) {
-        // Extract numeric part from new_page_index_text and parse it to u64, defaulting to 0.
-        let new_page_index = new_page_index_text
-            .chars()
-            .take_while(|char| char.is_digit(10))
-            .collect::<String>()
-            .parse::<u64>()
-            .unwrap_or(0);
+    /// Gets the scan result ref at a selection index. In infinite scroll mode, global rows are scan result refs,
+    /// such that selections spanning pages that were never loaded still resolve.
+    fn get_scan_result_ref(
+        &self,
+        index: usize,
+    ) -> Option<ScanResultRef> {
+        if self.is_infinite_scroll {
+            ((index as u64) < self.result_count).then(|| ScanResultRef::new(index as u64))
+        } else {
+            self.current_scan_results
+                .get(index)
+                .map(|scan_result| scan_result.get_base_result().get_scan_result_ref().clone())
+        }
+    }
 
-        Self::set_page_index(element_scanner_results_view_data, engine_unprivileged_state, new_page_index);
+    /// Iterates the loaded scan results along with their selection index.
+    pub fn iter_loaded_scan_results(&self) -> Box<dyn Iterator<Item = (usize, &ScanResult)> + '_> {
+        if self.is_infinite_scroll {
+            Box::new(
+                self.page_cache
+                    .iter_scan_results()
+                    .map(|(row_index, scan_result)| (row_index as usize, scan_result)),
+            )
+        } else {
+            Box::new(self.current_scan_results.iter().enumerate())
+        }
     }
 
+    /// Selects only the given row, or clears the selection if none is given.
     pub fn set_scan_result_selection_start(
         element_scanner_results_view_data: Dependency<Self>,
-        _struct_viewer_view_data: Dependency<StructViewerViewData>,
+        struct_viewer_view_data: Dependency<StructViewerViewData>,
         scan_result_collection_start_index: Option<i32>,
     ) -> bool {
         let mut element_scanner_results_view_data = match element_scanner_results_view_data.try_write("Set scan result selection start") {
             Some(element_scanner_results_view_data) => element_scanner_results_view_data,
             None => return false,
         };
+        let scan_result_collection_start_index = scan_result_collection_start_index.and_then(|index| usize::try_from(index).ok());
+
+        match scan_result_collection_start_index {
+            Some(index) => element_scanner_results_view_data.selection.select(index),
+            None => element_scanner_results_view_data.selection.clear(),
+        }
+
+        let selected_scan_result = scan_result_collection_start_index
+            .and_then(|index| element_scanner_results_view_data.get_scan_result(index).cloned());
+
+        drop(element_scanner_results_view_data);
+
+        if let Some(selected_scan_result) = selected_scan_result {
+            Self::focus_custom_struct_scan_result(struct_viewer_view_data, &selected_scan_result);
+        }
+
+        true
+    }
+
+    /// Selects or deselects the given row (Ctrl+Click), leaving the rest of the selection as it is.
+    pub fn toggle_scan_result_selection(
+        element_scanner_results_view_data: Dependency<Self>,
+        scan_result_collection_index: i32,
+    ) -> bool {
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.try_write("Toggle scan result selection") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return false,
+        };
 
-        element_scanner_results_view_data.selection_index_start = scan_result_collection_start_index;
-        element_scanner_results_view_data.selection_index_end = None;
+        element_scanner_results_view_data
+            .selection
+            .toggle(scan_result_collection_index.max(0) as usize);
 
         true
     }
 
-    pub fn set_scan_result_selection_end(
+    /// Selects every row from the last clicked row to the given row (Shift+Click), keeping any rows toggled before it.
+    pub fn extend_scan_result_selection(
         element_scanner_results_view_data: Dependency<Self>,
-        _struct_viewer_view_data: Dependency<StructViewerViewData>,
-        scan_result_collection_end_index: Option<i32>,
+        scan_result_collection_end_index: i32,
     ) -> bool {
-        let mut element_scanner_results_view_data = match element_scanner_results_view_data.try_write("Set scan result selection end") {
+        let mut element_scanner_results_view_data = match element_scanner_results_view_data.try_write("Extend scan result selection") {
             Some(element_scanner_results_view_data) => element_scanner_results_view_data,
             None => return false,
         };
 
-        element_scanner_results_view_data.selection_index_end = scan_result_collection_end_index;
+        element_scanner_results_view_data
+            .selection
+            .extend_to(scan_result_collection_end_index.max(0) as usize);
+
+        // Rows between the anchor and the given row may be hidden by the value filter.
+        element_scanner_results_view_data.deselect_hidden_rows();
 
         true
     }
 
+    /// Shows the fields of a custom struct typed scan result in the struct viewer. Built-in types have a single value, and are left alone.
+    fn focus_custom_struct_scan_result(
+        struct_viewer_view_data: Dependency<StructViewerViewData>,
+        scan_result: &ScanResult,
+    ) {
+        let custom_data_type = match CustomDataTypeRegistry::get_instance().get_custom_data_type(scan_result.get_data_type_ref().get_data_type_id()) {
+            Some(custom_data_type) => custom_data_type,
+            None => return,
+        };
+        let current_value = match scan_result.get_current_value() {
+            Some(current_value) => current_value,
+            None => return,
+        };
+        let valued_struct = custom_data_type.to_valued_struct(current_value.get_value_bytes(), true);
+        let focused_struct_address = if scan_result.is_module() {
+            FocusedStructAddress::new(scan_result.get_data_type_ref().clone(), scan_result.get_module_offset(), scan_result.get_module())
+        } else {
+            FocusedStructAddress::new(scan_result.get_data_type_ref().clone(), scan_result.get_address(), "")
+        };
+
+        StructViewerViewData::focus_valued_struct_at_address(
+            struct_viewer_view_data,
+            valued_struct,
+            focused_struct_address,
+            Box::new(|_valued_struct_field| {}),
+        );
+    }
+
     pub fn add_scan_results_to_project(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) {
-        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data);
+        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data.clone());
+
+        Self::add_selected_scan_results_to_opened_project(element_scanner_results_view_data, &engine_unprivileged_state);
 
         if !scan_result_refs.is_empty() {
             let engine_unprivileged_state = &engine_unprivileged_state;
@@ -586,6 +2156,51 @@ impl ElementScannerResultsViewData {
         }
     }
 
+    /// Adds the selected results to the opened project as address entries. Each entry references its data type as a symbolic struct,
+    /// such that custom struct typed results resolve to their full field list when the entry is read.
+    fn add_selected_scan_results_to_opened_project(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) {
+        let selected_scan_results: Vec<ScanResult> = match element_scanner_results_view_data.read("Collect selected scan results for project") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data
+                .selection
+                .iter_indices()
+                .filter_map(|index| element_scanner_results_view_data.get_scan_result(index).cloned())
+                .collect(),
+            None => return,
+        };
+
+        let project_items = selected_scan_results
+            .iter()
+            .filter_map(|scan_result| {
+                let data_type_ref = scan_result.get_data_type_ref();
+                let freeze_value = match SymbolRegistry::get_instance().get_default_value(data_type_ref) {
+                    Some(freeze_value) => freeze_value,
+                    None => {
+                        log::error!("Error adding scan result, unable to get default value. The data type may no longer be registered.");
+                        return None;
+                    }
+                };
+                let (address, module) = if scan_result.get_module().is_empty() {
+                    (scan_result.get_address(), "")
+                } else {
+                    (scan_result.get_module_offset(), scan_result.get_module())
+                };
+                let base_name = DataTypeToStringConverter::convert_data_type_to_display_string(data_type_ref.get_data_type_id());
+
+                Some(ProjectItemTypeAddress::new_project_item(&base_name, address, module, "", freeze_value))
+            })
+            .collect();
+
+        if let Err(error) = engine_unprivileged_state
+            .get_project_manager()
+            .add_project_items(project_items)
+        {
+            log::warn!("Unable to add scan results: {}", error);
+        }
+    }
+
     pub fn delete_selected_scan_results(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
@@ -600,12 +2215,56 @@ impl ElementScannerResultsViewData {
         }
     }
 
+    /// Shows the selected results as a different data type. The engine re-reads them at the new width, but the next scan still
+    /// compares them as the scanned type, since a result set holds a single data type.
+    pub fn set_selected_scan_results_data_type(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        data_type_ref: DataTypeRef,
+    ) {
+        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data);
+
+        if scan_result_refs.is_empty() {
+            return;
+        }
+
+        let engine_unprivileged_state = &engine_unprivileged_state;
+        let scan_results_set_data_type_request = ScanResultsSetDataTypeRequest {
+            scan_result_refs,
+            data_type_ref,
+        };
+
+        scan_results_set_data_type_request.send(engine_unprivileged_state, |scan_results_set_data_type_response| {
+            if !scan_results_set_data_type_response
+                .failed_scan_result_refs
+                .is_empty()
+            {
+                log::warn!(
+                    "Failed to change the data type of {} scan result(s).",
+                    scan_results_set_data_type_response.failed_scan_result_refs.len()
+                );
+            }
+        });
+    }
+
     pub fn set_scan_result_frozen(
         element_scanner_results_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
         local_scan_result_index: i32,
         is_frozen: bool,
+        stale_results_policy: StaleResultsPolicy,
     ) {
+        if is_frozen
+            && stale_results_policy == StaleResultsPolicy::Confirm
+            && Self::request_stale_results_confirmation(
+                element_scanner_results_view_data.clone(),
+                ElementScannerResultFrameAction::FreezeIndex(local_scan_result_index, is_frozen),
+                Some(vec![local_scan_result_index.max(0) as usize]),
+            )
+        {
+            return;
+        }
+
         let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
         let local_scan_result_indices_vec = (local_scan_result_index..=local_scan_result_index).collect::<Vec<_>>();
         let scan_result_refs = Self::collect_scan_result_refs_by_indicies(element_scanner_results_view_data.clone(), &&local_scan_result_indices_vec);
@@ -619,9 +2278,7 @@ impl ElementScannerResultsViewData {
             return;
         }
 
-        if let Some(scan_result) =
-            Arc::make_mut(&mut element_scanner_results_view_data.current_scan_results).get_mut(local_scan_result_index as usize)
-        {
+        if let Some(scan_result) = element_scanner_results_view_data.get_scan_result_mut(local_scan_result_index as usize) {
             scan_result.set_is_frozen_client_only(is_frozen);
         } else {
             log::warn!("Failed to find scan result to apply client side freeze at index: {}", local_scan_result_index)
@@ -632,20 +2289,268 @@ impl ElementScannerResultsViewData {
             return;
         }
 
-        element_scanner_results_view_data.is_freezing_entries = true;
+        element_scanner_results_view_data.is_freezing_entries = true;
+
+        // Drop the write guard before sending the request. The request may complete quickly and invoke the callback
+        // synchronously, which would otherwise deadlock when it tries to acquire this same lock to update the UI.
+        drop(element_scanner_results_view_data);
+
+        Self::schedule_flag_timeout(element_scanner_results_view_data_clone.clone(), FlagType::FreezingEntries, 5000);
+
+        let engine_unprivileged_state = &engine_unprivileged_state;
+        let scan_results_freeze_request = ScanResultsFreezeRequest {
+            scan_result_refs,
+            is_frozen,
+            allow_stale: stale_results_policy == StaleResultsPolicy::Include,
+        };
+
+        scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
+            let mut element_scanner_results_view_data =
+                match element_scanner_results_view_data_clone.write("Element scanner results view data: set scan result frozen response") {
+                    Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+                    None => return,
+                };
+
+            // Revert failures by mapping global -> local, and revert to previous state.
+            for failed_scan_result_ref in scan_results_freeze_response.failed_freeze_toggle_scan_result_refs {
+                let global_index = failed_scan_result_ref.get_scan_result_global_index();
+
+                if let Some(local_index) = Self::find_local_index_by_global_index(&element_scanner_results_view_data, global_index) {
+                        if let Some(scan_result) = element_scanner_results_view_data.get_scan_result_mut(local_index) {
+                            scan_result.set_is_frozen_client_only(!is_frozen);
+                        }
+                } else {
+                    log::warn!("Failed to find scan result to revert client side freeze (global index: {})", global_index);
+                }
+            }
+
+            element_scanner_results_view_data.is_freezing_entries = false;
+        });
+    }
+
+    /// Freezes or unfreezes the selected results, returning what is needed to undo the batch if it was applied.
+    pub fn toggle_selected_scan_results_frozen(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_frozen: bool,
+        stale_results_policy: StaleResultsPolicy,
+    ) -> Option<ScanResultFreezeUndo> {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data.clone());
+
+        if scan_result_refs.is_empty() {
+            return None;
+        }
+
+        if is_frozen
+            && stale_results_policy == StaleResultsPolicy::Confirm
+            && Self::request_stale_results_confirmation(
+                element_scanner_results_view_data.clone(),
+                ElementScannerResultFrameAction::ToggleFreezeSelection(is_frozen),
+                None,
+            )
+        {
+            return None;
+        }
+
+        let mut element_scanner_results_view_data =
+            match element_scanner_results_view_data.write("Element scanner results view data: set selected scan results frozen") {
+                Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+                None => return None,
+            };
+
+        if element_scanner_results_view_data.is_freezing_entries {
+            return None;
+        }
+
+        let mut previous_frozen_states = Vec::with_capacity(scan_result_refs.len());
+
+        Self::for_each_selected_scan_result(&mut element_scanner_results_view_data, |scan_result| {
+            previous_frozen_states.push((scan_result.get_base_result().get_scan_result_ref().clone(), scan_result.get_is_frozen()));
+            scan_result.set_is_frozen_client_only(is_frozen);
+        });
+
+        element_scanner_results_view_data.is_freezing_entries = true;
+
+        // Drop the write guard before sending the request. The request may complete quickly and invoke the callback
+        // synchronously, which would otherwise deadlock when it tries to acquire this same lock to update the UI.
+        drop(element_scanner_results_view_data);
+
+        Self::send_freeze_request(
+            element_scanner_results_view_data_clone,
+            engine_unprivileged_state,
+            scan_result_refs,
+            is_frozen,
+            stale_results_policy,
+        );
+
+        Some(ScanResultFreezeUndo {
+            is_frozen,
+            previous_frozen_states,
+        })
+    }
+
+    /// Freezes or unfreezes every result on the current page, regardless of the selection. In infinite scroll mode, the page is
+    /// the rows in view. Returns what is needed to undo the batch if it was applied.
+    pub fn toggle_page_scan_results_frozen(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_frozen: bool,
+        stale_results_policy: StaleResultsPolicy,
+    ) -> Option<ScanResultFreezeUndo> {
+        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
+        let page_indices = match element_scanner_results_view_data.read("Element scanner results view data: collect page indices") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data.get_page_indices(),
+            None => return None,
+        };
+
+        if page_indices.is_empty() {
+            return None;
+        }
+
+        if is_frozen
+            && stale_results_policy == StaleResultsPolicy::Confirm
+            && Self::request_stale_results_confirmation(
+                element_scanner_results_view_data.clone(),
+                ElementScannerResultFrameAction::ToggleFreezePage(is_frozen),
+                Some(page_indices.clone()),
+            )
+        {
+            return None;
+        }
+
+        let mut element_scanner_results_view_data =
+            element_scanner_results_view_data.write("Element scanner results view data: set page scan results frozen")?;
+
+        if element_scanner_results_view_data.is_freezing_entries {
+            return None;
+        }
+
+        let mut scan_result_refs = Vec::with_capacity(page_indices.len());
+        let mut previous_frozen_states = Vec::with_capacity(page_indices.len());
+
+        for index in page_indices {
+            if let Some(scan_result) = element_scanner_results_view_data.get_scan_result_mut(index) {
+                let scan_result_ref = scan_result.get_base_result().get_scan_result_ref().clone();
+
+                previous_frozen_states.push((scan_result_ref.clone(), scan_result.get_is_frozen()));
+                scan_result.set_is_frozen_client_only(is_frozen);
+                scan_result_refs.push(scan_result_ref);
+            }
+        }
+
+        if scan_result_refs.is_empty() {
+            return None;
+        }
+
+        element_scanner_results_view_data.is_freezing_entries = true;
+
+        // Drop the write guard before sending the request, as the callback may run synchronously and take the same lock.
+        drop(element_scanner_results_view_data);
+
+        Self::send_freeze_request(
+            element_scanner_results_view_data_clone,
+            engine_unprivileged_state,
+            scan_result_refs,
+            is_frozen,
+            stale_results_policy,
+        );
+
+        Some(ScanResultFreezeUndo {
+            is_frozen,
+            previous_frozen_states,
+        })
+    }
+
+    /// Returns the results changed by a freeze batch to the state they held beforehand. Results are restored by ref, such that
+    /// the undo still applies after scrolling or changing pages. Stale results are included, as the batch already accepted them.
+    pub fn undo_freeze(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        freeze_undo: &ScanResultFreezeUndo,
+    ) {
+        let scan_result_refs = freeze_undo.get_changed_scan_result_refs();
+        let is_frozen = !freeze_undo.is_frozen;
+
+        if scan_result_refs.is_empty() {
+            return;
+        }
+
+        let mut element_scanner_results_view_data_guard = match element_scanner_results_view_data.write("Element scanner results view data: undo freeze") {
+            Some(element_scanner_results_view_data) => element_scanner_results_view_data,
+            None => return,
+        };
+
+        for scan_result_ref in &scan_result_refs {
+            let global_index = scan_result_ref.get_scan_result_global_index();
+
+            if let Some(local_index) = Self::find_local_index_by_global_index(&element_scanner_results_view_data_guard, global_index)
+                && let Some(scan_result) = element_scanner_results_view_data_guard.get_scan_result_mut(local_index)
+            {
+                scan_result.set_is_frozen_client_only(is_frozen);
+            }
+        }
+
+        element_scanner_results_view_data_guard.is_freezing_entries = true;
+
+        // Drop the write guard before sending the request, as the callback may run synchronously and take the same lock.
+        drop(element_scanner_results_view_data_guard);
+
+        Self::send_freeze_request(
+            element_scanner_results_view_data,
+            engine_unprivileged_state,
+            scan_result_refs,
+            is_frozen,
+            StaleResultsPolicy::Include,
+        );
+    }
+
+    /// Gets whether the current page holds results, all of which are frozen.
+    pub fn is_page_frozen(&self) -> bool {
+        let page_indices = self.get_page_indices();
 
-        // Drop the write guard before sending the request. The request may complete quickly and invoke the callback
-        // synchronously, which would otherwise deadlock when it tries to acquire this same lock to update the UI.
-        drop(element_scanner_results_view_data);
+        !page_indices.is_empty()
+            && page_indices
+                .into_iter()
+                .all(|index| self.get_scan_result(index).is_some_and(ScanResult::get_is_frozen))
+    }
 
-        Self::schedule_flag_timeout(element_scanner_results_view_data_clone.clone(), FlagType::FreezingEntries, 5000);
+    /// Gets the selection indices of the loaded results on the current page, leaving out rows hidden by the value filter. In infinite
+    /// scroll mode, the page is the rows in view.
+    fn get_page_indices(&self) -> Vec<usize> {
+        if self.is_infinite_scroll {
+            self.iter_loaded_scan_results()
+                .map(|(index, _scan_result)| index)
+                .filter(|index| self.visible_row_range.contains(&(*index as u64)))
+                .collect()
+        } else {
+            (0..self.get_row_count())
+                .filter_map(|row_position| self.get_row_index(row_position))
+                .collect()
+        }
+    }
+
+    /// Sends a freeze request for results that were already frozen client side, reverting any that the engine fails to toggle.
+    /// Expects `is_freezing_entries` to have been set.
+    fn send_freeze_request(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        scan_result_refs: Vec<ScanResultRef>,
+        is_frozen: bool,
+        stale_results_policy: StaleResultsPolicy,
+    ) {
+        Self::schedule_flag_timeout(element_scanner_results_view_data.clone(), FlagType::FreezingEntries, 5000);
 
         let engine_unprivileged_state = &engine_unprivileged_state;
-        let scan_results_freeze_request = ScanResultsFreezeRequest { scan_result_refs, is_frozen };
+        let scan_results_freeze_request = ScanResultsFreezeRequest {
+            scan_result_refs,
+            is_frozen,
+            allow_stale: stale_results_policy == StaleResultsPolicy::Include,
+        };
 
         scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
             let mut element_scanner_results_view_data =
-                match element_scanner_results_view_data_clone.write("Element scanner results view data: set scan result frozen response") {
+                match element_scanner_results_view_data.write("Element scanner results view data: set selected scan results frozen response") {
                     Some(element_scanner_results_view_data) => element_scanner_results_view_data,
                     None => return,
                 };
@@ -655,9 +2560,9 @@ impl ElementScannerResultsViewData {
                 let global_index = failed_scan_result_ref.get_scan_result_global_index();
 
                 if let Some(local_index) = Self::find_local_index_by_global_index(&element_scanner_results_view_data, global_index) {
-                        if let Some(scan_result) = Arc::make_mut(&mut element_scanner_results_view_data.current_scan_results).get_mut(local_index) {
-                            scan_result.set_is_frozen_client_only(!is_frozen);
-                        }
+                    if let Some(scan_result) = element_scanner_results_view_data.get_scan_result_mut(local_index) {
+                        scan_result.set_is_frozen_client_only(!is_frozen);
+                    }
                 } else {
                     log::warn!("Failed to find scan result to revert client side freeze (global index: {})", global_index);
                 }
@@ -667,91 +2572,106 @@ impl ElementScannerResultsViewData {
         });
     }
 
-    pub fn toggle_selected_scan_results_frozen(
+    /// Holds the given action for confirmation if it targets any loaded stale results, returning whether it was held. The action
+    /// targets the given rows, or the selection if none are given.
+    fn request_stale_results_confirmation(
         element_scanner_results_view_data: Dependency<Self>,
-        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
-        is_frozen: bool,
-    ) {
-        let element_scanner_results_view_data_clone = element_scanner_results_view_data.clone();
-        let scan_result_refs = Self::collect_selected_scan_result_refs(element_scanner_results_view_data.clone());
-
-        if scan_result_refs.is_empty() {
-            return;
-        }
-
+        frame_action: ElementScannerResultFrameAction,
+        target_indices: Option<Vec<usize>>,
+    ) -> bool {
         let mut element_scanner_results_view_data =
-            match element_scanner_results_view_data.write("Element scanner results view data: set selected scan results frozen") {
+            match element_scanner_results_view_data.write("Element scanner results view data: request stale confirmation") {
                 Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-                None => return,
+                None => return false,
             };
+        let target_indices = match target_indices {
+            Some(target_indices) => target_indices,
+            None => element_scanner_results_view_data.selection.iter_indices().collect(),
+        };
+        let stale_result_count = target_indices
+            .into_iter()
+            .filter(|index| {
+                element_scanner_results_view_data
+                    .get_scan_result(*index)
+                    .is_some_and(|scan_result| scan_result.get_base_result().is_stale())
+            })
+            .count();
 
-        if element_scanner_results_view_data.is_freezing_entries {
-            return;
+        if stale_result_count == 0 {
+            return false;
         }
 
-        Self::for_each_selected_scan_result(&mut element_scanner_results_view_data, |scan_result| {
-            scan_result.set_is_frozen_client_only(is_frozen);
+        element_scanner_results_view_data.stale_results_confirmation = Some(StaleResultsConfirmation {
+            frame_action,
+            stale_result_count,
         });
 
-        element_scanner_results_view_data.is_freezing_entries = true;
+        true
+    }
 
-        // Drop the write guard before sending the request. The request may complete quickly and invoke the callback
-        // synchronously, which would otherwise deadlock when it tries to acquire this same lock to update the UI.
-        drop(element_scanner_results_view_data);
+    /// Takes the held stale results confirmation, such that its action can be applied as the user decided.
+    pub fn take_stale_results_confirmation(element_scanner_results_view_data: Dependency<Self>) -> Option<StaleResultsConfirmation> {
+        element_scanner_results_view_data
+            .write("Element scanner results view data: take stale confirmation")
+            .and_then(|mut element_scanner_results_view_data| {
+                element_scanner_results_view_data
+                    .stale_results_confirmation
+                    .take()
+            })
+    }
 
-        Self::schedule_flag_timeout(element_scanner_results_view_data_clone.clone(), FlagType::FreezingEntries, 5000);
+    /// Gets whether any loaded result is over memory that changed since it was snapshotted.
+    pub fn has_stale_scan_results(&self) -> bool {
+        self.iter_loaded_scan_results()
+            .any(|(_index, scan_result)| scan_result.get_base_result().is_stale())
+    }
 
-        let engine_unprivileged_state = &engine_unprivileged_state;
-        let scan_results_freeze_request = ScanResultsFreezeRequest { scan_result_refs, is_frozen };
+    /// Re-snapshots the ranges under stale results. Results over memory that no longer exists are discarded by the engine, which
+    /// then publishes updated results.
+    pub fn revalidate_snapshot(
+        element_scanner_results_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        match element_scanner_results_view_data.write("Element scanner results view data: revalidate snapshot") {
+            Some(mut element_scanner_results_view_data) => {
+                if element_scanner_results_view_data.is_revalidating_snapshot {
+                    return;
+                }
 
-        scan_results_freeze_request.send(engine_unprivileged_state, move |scan_results_freeze_response| {
-            let mut element_scanner_results_view_data =
-                match element_scanner_results_view_data_clone.write("Element scanner results view data: set selected scan results frozen response") {
-                    Some(element_scanner_results_view_data) => element_scanner_results_view_data,
-                    None => return,
-                };
+                element_scanner_results_view_data.is_revalidating_snapshot = true;
+            }
+            None => return,
+        }
 
-            // Revert failures by mapping global -> local, and revert to previous state.
-            for failed_scan_result_ref in scan_results_freeze_response.failed_freeze_toggle_scan_result_refs {
-                let global_index = failed_scan_result_ref.get_scan_result_global_index();
+        let scan_revalidate_request = ScanRevalidateRequest {};
 
-                if let Some(local_index) = Self::find_local_index_by_global_index(&element_scanner_results_view_data, global_index) {
-                    if let Some(scan_result) =
-                        Arc::make_mut(&mut element_scanner_results_view_data.current_scan_results).get_mut(local_index)
-                    {
-                        scan_result.set_is_frozen_client_only(!is_frozen);
-                    }
-                } else {
-                    log::warn!("Failed to find scan result to revert client side freeze (global index: {})", global_index);
-                }
+        scan_revalidate_request.send(&engine_unprivileged_state, move |scan_revalidate_response| {
+            if scan_revalidate_response.discarded_result_count > 0 {
+                log::info!(
+                    "Discarded {} result(s) over memory that no longer exists.",
+                    scan_revalidate_response.discarded_result_count
+                );
             }
 
-            element_scanner_results_view_data.is_freezing_entries = false;
+            if let Some(mut element_scanner_results_view_data) =
+                element_scanner_results_view_data.write("Element scanner results view data: revalidate snapshot response")
+            {
+                element_scanner_results_view_data.is_revalidating_snapshot = false;
+            }
         });
     }
 
-    fn get_selected_results_range(element_scanner_results_view_data: &ElementScannerResultsViewData) -> Option<RangeInclusive<usize>> {
-        let start = element_scanner_results_view_data
-            .selection_index_start
-            .or(element_scanner_results_view_data.selection_index_end)?;
-        let end = element_scanner_results_view_data
-            .selection_index_end
-            .or(element_scanner_results_view_data.selection_index_start)?;
-        let (range_low, range_high) = (start.min(end), start.max(end));
-
-        Some(range_low.max(0) as usize..=range_high.max(0) as usize)
-    }
-
     fn for_each_selected_scan_result(
         element_scanner_results_view_data: &mut ElementScannerResultsViewData,
         mut callback: impl FnMut(&mut ScanResult),
     ) {
-        let Some(range) = Self::get_selected_results_range(element_scanner_results_view_data) else {
-            return;
-        };
+        let selected_indices = element_scanner_results_view_data
+            .selection
+            .iter_indices()
+            .collect::<Vec<_>>();
 
-        for index in range {
-            if let Some(scan_result) = Arc::make_mut(&mut element_scanner_results_view_data.current_scan_results).get_mut(index) {
+        for index in selected_indices {
+            if let Some(scan_result) = element_scanner_results_view_data.get_scan_result_mut(index) {
                 callback(scan_result);
             }
         }
@@ -763,17 +2683,10 @@ impl ElementScannerResultsViewData {
             None => return Vec::new(),
         };
 
-        let Some(range) = Self::get_selected_results_range(&element_scanner_results_view_data) else {
-            return Vec::new();
-        };
-
-        range
-            .filter_map(|index| {
-                element_scanner_results_view_data
-                    .current_scan_results
-                    .get(index)
-            })
-            .map(|scan_result| scan_result.get_base_result().get_scan_result_ref().clone())
+        element_scanner_results_view_data
+            .selection
+            .iter_indices()
+            .filter_map(|index| element_scanner_results_view_data.get_scan_result_ref(index))
             .collect()
     }
 
@@ -781,37 +2694,26 @@ impl ElementScannerResultsViewData {
         element_scanner_results_view_data: Dependency<Self>,
         local_scan_result_indices: &[i32],
     ) -> Vec<ScanResultRef> {
-        Self::collect_scan_result_bases_by_indicies(element_scanner_results_view_data, local_scan_result_indices)
-            .into_iter()
-            .map(|scan_result| scan_result.get_scan_result_ref().clone())
-            .collect()
-    }
-
-    fn collect_scan_result_bases_by_indicies(
-        element_scanner_results_view_data: Dependency<Self>,
-        local_scan_result_indices: &[i32],
-    ) -> Vec<ScanResultBase> {
-        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Collect scan result bases") {
+        let element_scanner_results_view_data = match element_scanner_results_view_data.read("Collect scan result refs") {
             Some(element_scanner_results_view_data) => element_scanner_results_view_data,
             None => return Vec::new(),
         };
-        let scan_results = local_scan_result_indices
-            .iter()
-            .filter_map(|index| {
-                element_scanner_results_view_data
-                    .current_scan_results
-                    .get(*index as usize)
-                    .map(|scan_result| scan_result.get_base_result().clone())
-            })
-            .collect();
 
-        scan_results
+        local_scan_result_indices
+            .iter()
+            .filter_map(|index| element_scanner_results_view_data.get_scan_result_ref(*index as usize))
+            .collect()
     }
 
     fn find_local_index_by_global_index(
         element_scanner_results_view_data: &ElementScannerResultsViewData,
         global_index: u64,
     ) -> Option<usize> {
+        // Selection indices are global rows in infinite scroll mode.
+        if element_scanner_results_view_data.is_infinite_scroll {
+            return Some(global_index as usize);
+        }
+
         element_scanner_results_view_data
             .current_scan_results
             .iter()
@@ -866,3 +2768,698 @@ enum FlagType {
     SettingProperties,
     FreezingEntries,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ElementScannerResultsViewData;
+    use crate::views::element_scanner::results::view_data::scan_results_sort::ScanResultsSortColumn;
+    use crate::views::element_scanner::results::view_data::stale_results_policy::StaleResultsPolicy;
+    use crossbeam_channel::unbounded;
+    use squalr_engine_api::commands::address::address_command::AddressCommand;
+    use squalr_engine_api::commands::address::resolve::address_resolve_response::AddressResolveResponse;
+    use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
+    use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
+    use squalr_engine_api::commands::scan_results::scan_results_command::ScanResultsCommand;
+    use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
+    use squalr_engine_api::commands::unprivileged_command_response::UnprivilegedCommandResponse;
+    use squalr_engine_api::dependency_injection::dependency_container::DependencyContainer;
+    use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
+    use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+    use squalr_engine_api::commands::privileged_command_response::TypedPrivilegedCommandResponse;
+    use squalr_engine_api::commands::scan_results::query::scan_results_query_response::ScanResultsQueryResponse;
+    use squalr_engine_api::commands::scan_results::refresh::scan_results_refresh_response::ScanResultsRefreshResponse;
+    use squalr_engine_api::commands::scan_results::set_property::scan_results_set_property_response::ScanResultsSetPropertyResponse;
+    use squalr_engine_api::events::engine_event::EngineEvent;
+    use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::expressions::address_resolve_error::AddressResolveError;
+    use squalr_engine_api::structures::memory::resolved_address::ResolvedAddress;
+    use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+    use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::time::{Duration, Instant};
+
+    /// Counts scan result queries, leaving them unanswered as if they were still in flight.
+    struct QueryCountingUnprivilegedBindings {
+        query_count: Arc<AtomicUsize>,
+    }
+
+    impl EngineApiUnprivilegedBindings for QueryCountingUnprivilegedBindings {
+        fn dispatch_privileged_command(
+            &self,
+            engine_command: PrivilegedCommand,
+            _callback: Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            if let PrivilegedCommand::Results(ScanResultsCommand::Query { .. }) = engine_command {
+                self.query_count.fetch_add(1, Ordering::SeqCst);
+            }
+
+            Ok(())
+        }
+
+        fn dispatch_unprivileged_command(
+            &self,
+            _engine_command: UnprivilegedCommand,
+            _engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+            _callback: Box<dyn FnOnce(UnprivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            Err("Mock bindings: unprivileged commands not supported in this test".to_string())
+        }
+
+        fn subscribe_to_engine_events(&self) -> Result<crossbeam_channel::Receiver<EngineEvent>, String> {
+            let (_sender, receiver) = unbounded();
+            Ok(receiver)
+        }
+    }
+
+    type PrivilegedCommandCallback = Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>;
+
+    /// Holds on to scan result query callbacks, such that a test can answer them in any order.
+    struct DeferredQueryUnprivilegedBindings {
+        query_callbacks: Arc<Mutex<Vec<PrivilegedCommandCallback>>>,
+    }
+
+    impl EngineApiUnprivilegedBindings for DeferredQueryUnprivilegedBindings {
+        fn dispatch_privileged_command(
+            &self,
+            engine_command: PrivilegedCommand,
+            callback: PrivilegedCommandCallback,
+        ) -> Result<(), String> {
+            if let PrivilegedCommand::Results(ScanResultsCommand::Query { .. }) = engine_command {
+                self.query_callbacks.lock().unwrap().push(callback);
+            }
+
+            Ok(())
+        }
+
+        fn dispatch_unprivileged_command(
+            &self,
+            _engine_command: UnprivilegedCommand,
+            _engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+            _callback: Box<dyn FnOnce(UnprivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            Err("Mock bindings: unprivileged commands not supported in this test".to_string())
+        }
+
+        fn subscribe_to_engine_events(&self) -> Result<crossbeam_channel::Receiver<EngineEvent>, String> {
+            let (_sender, receiver) = unbounded();
+            Ok(receiver)
+        }
+    }
+
+    /// Answers commands immediately, resolving any address to 0x2000 unless it names `client.dll`, which is not loaded. Properties
+    /// set on scan results are recorded, and refreshed results are read back at 0x2000.
+    struct AddressEditUnprivilegedBindings {
+        set_properties: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    impl EngineApiUnprivilegedBindings for AddressEditUnprivilegedBindings {
+        fn dispatch_privileged_command(
+            &self,
+            engine_command: PrivilegedCommand,
+            callback: PrivilegedCommandCallback,
+        ) -> Result<(), String> {
+            match engine_command {
+                PrivilegedCommand::Address(AddressCommand::Resolve { address_resolve_request }) => {
+                    let resolved_address = match address_resolve_request.expression.starts_with("client.dll") {
+                        true => Err(AddressResolveError::UnknownModule {
+                            module_name: "client.dll".to_string(),
+                        }),
+                        false => Ok(ResolvedAddress {
+                            address: 0x2000,
+                            ..Default::default()
+                        }),
+                    };
+
+                    callback(AddressResolveResponse { resolved_address }.to_engine_response());
+                }
+                PrivilegedCommand::Results(ScanResultsCommand::SetProperty { results_set_property_request }) => {
+                    self.set_properties.lock().unwrap().push((
+                        results_set_property_request.field_namespace,
+                        results_set_property_request
+                            .anonymous_value_string
+                            .get_anonymous_value_string()
+                            .to_string(),
+                    ));
+
+                    callback(ScanResultsSetPropertyResponse::default().to_engine_response());
+                }
+                PrivilegedCommand::Results(ScanResultsCommand::Refresh { results_refresh_request }) => {
+                    let scan_results = results_refresh_request
+                        .scan_result_refs
+                        .into_iter()
+                        .map(|scan_result_ref| {
+                            let scan_result_valued = ScanResultValued::new(
+                                0x2000,
+                                DataTypeRef::new("u32"),
+                                String::new(),
+                                None,
+                                Vec::new(),
+                                None,
+                                Vec::new(),
+                                scan_result_ref,
+                            );
+
+                            ScanResult::new(scan_result_valued, String::new(), 0, None, Vec::new(), false)
+                        })
+                        .collect();
+
+                    callback(
+                        ScanResultsRefreshResponse {
+                            scan_results,
+                            scan_generation: 0,
+                        }
+                        .to_engine_response(),
+                    );
+                }
+                _ => {}
+            }
+
+            Ok(())
+        }
+
+        fn dispatch_unprivileged_command(
+            &self,
+            _engine_command: UnprivilegedCommand,
+            _engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+            _callback: Box<dyn FnOnce(UnprivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            Err("Mock bindings: unprivileged commands not supported in this test".to_string())
+        }
+
+        fn subscribe_to_engine_events(&self) -> Result<crossbeam_channel::Receiver<EngineEvent>, String> {
+            let (_sender, receiver) = unbounded();
+            Ok(receiver)
+        }
+    }
+
+    /// Creates a scan result that recently read the given decimal value, or that has not been read yet if `None`.
+    fn create_read_scan_result(
+        global_index: u64,
+        recently_read_value: Option<&str>,
+    ) -> ScanResult {
+        let scan_result_valued = ScanResultValued::new(
+            0x1000 + global_index * 4,
+            DataTypeRef::new("u32"),
+            String::new(),
+            None,
+            Vec::new(),
+            None,
+            Vec::new(),
+            ScanResultRef::new(global_index),
+        );
+        let recently_read_display_values = recently_read_value
+            .map(|value| vec![AnonymousValueString::new(value.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None)])
+            .unwrap_or_default();
+
+        ScanResult::new(scan_result_valued, String::new(), 0, None, recently_read_display_values, false)
+    }
+
+    #[test]
+    fn stale_query_responses_do_not_replace_newer_results() {
+        let query_callbacks = Arc::new(Mutex::new(Vec::new()));
+        let bindings = DeferredQueryUnprivilegedBindings {
+            query_callbacks: query_callbacks.clone(),
+        };
+        let engine_unprivileged_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(bindings)));
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let answer_query = |query_index: usize, scan_generation: u64, result_count: u64| {
+            let callback = query_callbacks.lock().unwrap().remove(query_index);
+
+            callback(
+                ScanResultsQueryResponse {
+                    result_count,
+                    scan_generation,
+                    ..Default::default()
+                }
+                .to_engine_response(),
+            );
+        };
+
+        // A query is sent for the first scan, then a second scan completes while it is still in flight.
+        ElementScannerResultsViewData::query_scan_results(view_data.clone(), engine_unprivileged_state.clone(), false);
+
+        if let Some(mut view_data) = view_data.write("Test select rows") {
+            view_data.selection.select(0);
+            view_data.selection.extend_to(3);
+        }
+
+        ElementScannerResultsViewData::on_scan_results_updated(
+            view_data.clone(),
+            engine_unprivileged_state.clone(),
+            &ScanResultsUpdatedEvent {
+                is_new_scan: false,
+                scan_generation: 2,
+            },
+        );
+
+        // The update must query again rather than wait on the older query, and the old selection no longer applies.
+        assert_eq!(query_callbacks.lock().unwrap().len(), 2);
+        assert!(view_data.read("Test read selection").unwrap().selection.is_empty());
+
+        // The newer response arrives first, followed by the older one.
+        answer_query(1, 2, 10);
+        answer_query(0, 1, 5000);
+
+        let view_data = view_data.read("Test read results").unwrap();
+
+        assert_eq!(view_data.result_count, 10);
+        assert_eq!(view_data.latest_scan_generation, 2);
+        assert!(!view_data.is_querying_scan_results);
+    }
+
+    #[test]
+    fn rapid_page_size_changes_issue_one_query_after_settling() {
+        let query_count = Arc::new(AtomicUsize::new(0));
+        let bindings = QueryCountingUnprivilegedBindings {
+            query_count: query_count.clone(),
+        };
+        let engine_unprivileged_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(bindings)));
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let start = Instant::now();
+        let settle_duration = Duration::from_millis(ElementScannerResultsViewData::PAGE_SIZE_REQUERY_DEBOUNCE_MS);
+
+        // Simulate a resize gesture, with one frame every 16ms and the page size growing by a row every other frame.
+        for frame in 0..40u32 {
+            let is_pending = ElementScannerResultsViewData::set_page_size_override_at(
+                view_data.clone(),
+                engine_unprivileged_state.clone(),
+                Some(20 + frame / 2),
+                start + Duration::from_millis(frame as u64 * 16),
+            );
+
+            assert!(is_pending);
+        }
+
+        assert_eq!(query_count.load(Ordering::SeqCst), 0);
+
+        // The gesture has ended, and the final size jitters within the hysteresis band.
+        let settled_at = start + Duration::from_millis(39 * 16) + settle_duration;
+
+        for (offset_ms, page_size) in [(0, 39), (16, 40), (32, 38), (48, 39)] {
+            ElementScannerResultsViewData::set_page_size_override_at(
+                view_data.clone(),
+                engine_unprivileged_state.clone(),
+                Some(page_size),
+                settled_at + Duration::from_millis(offset_ms),
+            );
+        }
+
+        assert_eq!(query_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn large_copies_are_built_without_blocking_the_frame() {
+        const ROW_COUNT: usize = 100_000;
+        const FRAME_DURATION: Duration = Duration::from_millis(16);
+
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let scan_results = (0..ROW_COUNT)
+            .map(|index| {
+                let scan_result_valued = ScanResultValued::new(
+                    0x1000 + index as u64 * 4,
+                    DataTypeRef::new("u32"),
+                    String::new(),
+                    None,
+                    Vec::new(),
+                    None,
+                    Vec::new(),
+                    ScanResultRef::new(index as u64),
+                );
+
+                ScanResult::new(scan_result_valued, String::new(), 0, None, Vec::new(), false)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(mut view_data) = view_data.write("Test load results") {
+            view_data.current_scan_results = Arc::new(scan_results);
+            view_data.result_count = ROW_COUNT as u64;
+            view_data.selection.select_all(ROW_COUNT);
+        }
+
+        // The frame that asks for the copy only hands it off, rather than building the text itself.
+        let copy_started_at = Instant::now();
+
+        ElementScannerResultsViewData::copy_selected_rows_tsv(view_data.clone(), AnonymousValueStringFormat::Decimal);
+
+        assert!(copy_started_at.elapsed() < FRAME_DURATION);
+        assert!(view_data.read("Test read copy progress").unwrap().copy_progress.is_some());
+
+        // Later frames poll for the text without waiting on it.
+        let mut copied_text = None;
+
+        while copied_text.is_none() && copy_started_at.elapsed() < Duration::from_secs(30) {
+            let frame_started_at = Instant::now();
+
+            copied_text = ElementScannerResultsViewData::take_pending_clipboard_text(view_data.clone());
+
+            assert!(frame_started_at.elapsed() < FRAME_DURATION);
+            std::thread::sleep(FRAME_DURATION);
+        }
+
+        let copied_text = copied_text.expect("The copy did not finish.");
+
+        assert_eq!(copied_text.lines().count(), ROW_COUNT);
+        assert!(copied_text.starts_with("00001000\t"));
+        assert!(view_data.read("Test read copy progress").unwrap().copy_progress.is_none());
+    }
+
+    #[test]
+    fn changed_values_are_highlighted_until_they_fade_out() {
+        let mut view_data = ElementScannerResultsViewData::new();
+        let refreshed_at = Instant::now();
+        let fade_duration = ElementScannerResultsViewData::VALUE_CHANGE_HIGHLIGHT_DURATION;
+
+        view_data.current_scan_results = Arc::new(vec![
+            create_read_scan_result(0, Some("1")),
+            create_read_scan_result(1, Some("2")),
+            create_read_scan_result(2, None),
+        ]);
+
+        let refreshed_scan_results = vec![
+            create_read_scan_result(0, Some("1")),
+            create_read_scan_result(1, Some("3")),
+            create_read_scan_result(2, Some("5")),
+        ];
+
+        view_data.record_value_changes(&refreshed_scan_results, refreshed_at);
+        view_data.current_scan_results = Arc::new(refreshed_scan_results);
+
+        // Only the value that was read before and differs now is highlighted, fading out over the highlight duration.
+        assert_eq!(view_data.get_value_change_strength(0, refreshed_at), 0.0);
+        assert_eq!(view_data.get_value_change_strength(1, refreshed_at), 1.0);
+        assert_eq!(view_data.get_value_change_strength(2, refreshed_at), 0.0);
+        assert!((view_data.get_value_change_strength(1, refreshed_at + fade_duration / 2) - 0.5).abs() < 0.01);
+        assert_eq!(view_data.get_value_change_strength(1, refreshed_at + fade_duration), 0.0);
+
+        // An unchanged refresh keeps highlights that are still fading, but forgets results that are no longer shown.
+        view_data.record_value_changes(&[create_read_scan_result(1, Some("3"))], refreshed_at + fade_duration / 2);
+        assert_eq!(view_data.value_changed_at.len(), 1);
+
+        view_data.record_value_changes(&[create_read_scan_result(0, Some("1"))], refreshed_at + fade_duration / 2);
+        assert!(view_data.value_changed_at.is_empty());
+    }
+
+    #[test]
+    fn freeze_batches_capture_prior_states_such_that_undo_only_restores_changed_results() {
+        let bindings = QueryCountingUnprivilegedBindings {
+            query_count: Arc::new(AtomicUsize::new(0)),
+        };
+        let engine_unprivileged_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(bindings)));
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let read_frozen_states = || {
+            view_data
+                .read("Test read frozen states")
+                .unwrap()
+                .current_scan_results
+                .iter()
+                .map(ScanResult::get_is_frozen)
+                .collect::<Vec<_>>()
+        };
+
+        if let Some(mut view_data) = view_data.write("Test load results") {
+            let mut already_frozen_scan_result = create_read_scan_result(1, Some("2"));
+
+            already_frozen_scan_result.set_is_frozen_client_only(true);
+            view_data.current_scan_results = Arc::new(vec![
+                create_read_scan_result(0, Some("1")),
+                already_frozen_scan_result,
+                create_read_scan_result(2, Some("3")),
+            ]);
+            view_data.result_count = 3;
+            view_data.selection.select_all(3);
+        }
+
+        let freeze_undo = ElementScannerResultsViewData::toggle_selected_scan_results_frozen(
+            view_data.clone(),
+            engine_unprivileged_state.clone(),
+            true,
+            StaleResultsPolicy::Include,
+        )
+        .unwrap();
+
+        assert_eq!(freeze_undo.get_summary(), "Froze 3 addresses");
+        assert_eq!(
+            freeze_undo
+                .previous_frozen_states
+                .iter()
+                .map(|(_scan_result_ref, was_frozen)| *was_frozen)
+                .collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+        assert_eq!(read_frozen_states(), vec![true, true, true]);
+
+        ElementScannerResultsViewData::undo_freeze(view_data.clone(), engine_unprivileged_state, &freeze_undo);
+
+        assert_eq!(read_frozen_states(), vec![false, true, false]);
+    }
+
+    #[test]
+    fn display_format_overrides_apply_to_copies_until_the_next_scan() {
+        let bindings = QueryCountingUnprivilegedBindings {
+            query_count: Arc::new(AtomicUsize::new(0)),
+        };
+        let engine_unprivileged_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(bindings)));
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let scan_results = (0..2u64)
+            .map(|index| {
+                let scan_result_valued = ScanResultValued::new(
+                    0x1000 + index * 4,
+                    DataTypeRef::new("u32"),
+                    String::new(),
+                    None,
+                    vec![
+                        AnonymousValueString::new("26".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+                        AnonymousValueString::new("1A".to_string(), AnonymousValueStringFormat::Hexadecimal, ContainerType::None),
+                    ],
+                    None,
+                    Vec::new(),
+                    ScanResultRef::new(index),
+                );
+
+                ScanResult::new(scan_result_valued, String::new(), 0, None, Vec::new(), false)
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(mut view_data) = view_data.write("Test load results") {
+            view_data.current_scan_results = Arc::new(scan_results);
+            view_data.result_count = 2;
+            view_data.selection.select_all(2);
+        }
+
+        ElementScannerResultsViewData::set_display_format_override(view_data.clone(), 1, Some(AnonymousValueStringFormat::Hexadecimal));
+        ElementScannerResultsViewData::copy_selected_rows_tsv(view_data.clone(), AnonymousValueStringFormat::Decimal);
+
+        let copied_text = ElementScannerResultsViewData::take_pending_clipboard_text(view_data.clone()).unwrap();
+
+        assert_eq!(copied_text, "00001000\t26\t??\t4 Bytes\n00001004\t1A\t??\t4 Bytes");
+
+        // Refreshing values keeps the override, as global indices still refer to the same results.
+        for is_new_scan in [false, true] {
+            ElementScannerResultsViewData::on_scan_results_updated(
+                view_data.clone(),
+                engine_unprivileged_state.clone(),
+                &ScanResultsUpdatedEvent {
+                    is_new_scan,
+                    scan_generation: 0,
+                },
+            );
+
+            let display_format_overrides = &view_data.read("Test read overrides").unwrap().display_format_overrides;
+
+            assert_eq!(display_format_overrides.is_empty(), is_new_scan);
+        }
+    }
+
+    #[test]
+    fn sorting_is_reapplied_after_refreshes_and_the_selection_follows_its_result() {
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let get_global_indices = |view_data: &ElementScannerResultsViewData| {
+            view_data
+                .current_scan_results
+                .iter()
+                .map(|scan_result| {
+                    scan_result
+                        .get_base_result()
+                        .get_scan_result_ref()
+                        .get_scan_result_global_index()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if let Some(mut view_data) = view_data.write("Test results") {
+            view_data.current_scan_results = Arc::new(vec![
+                create_read_scan_result(0, Some("30")),
+                create_read_scan_result(1, Some("10")),
+                create_read_scan_result(2, Some("20")),
+            ]);
+            view_data.selection.select(0);
+        }
+
+        ElementScannerResultsViewData::toggle_sort(view_data.clone(), ScanResultsSortColumn::Value);
+
+        {
+            let view_data = view_data.read("Test sorted results").unwrap();
+
+            assert_eq!(get_global_indices(&view_data), vec![1, 2, 0]);
+            assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![2]);
+        }
+
+        // A refresh arrives in snapshot order, with the selected result now holding the lowest value.
+        if let Some(mut view_data) = view_data.write("Test refreshed results") {
+            view_data.set_current_scan_results(Arc::new(vec![
+                create_read_scan_result(0, Some("5")),
+                create_read_scan_result(1, Some("10")),
+                create_read_scan_result(2, Some("20")),
+            ]));
+
+            assert_eq!(get_global_indices(&view_data), vec![0, 1, 2]);
+            assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![0]);
+        }
+
+        ElementScannerResultsViewData::toggle_sort(view_data.clone(), ScanResultsSortColumn::Value);
+        ElementScannerResultsViewData::toggle_sort(view_data.clone(), ScanResultsSortColumn::Value);
+
+        let view_data = view_data.read("Test unsorted results").unwrap();
+
+        assert!(view_data.sort.is_none());
+        assert_eq!(get_global_indices(&view_data), vec![0, 1, 2]);
+        assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[test]
+    fn value_filters_hide_rows_without_moving_the_selection_off_its_results() {
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+
+        if let Some(mut view_data) = view_data.write("Test results") {
+            view_data.current_scan_results = Arc::new(vec![
+                create_read_scan_result(0, Some("100")),
+                create_read_scan_result(1, Some("25")),
+                create_read_scan_result(2, Some("1000")),
+                create_read_scan_result(3, Some("7")),
+            ]);
+            view_data.selection.select_all(4);
+            view_data.set_value_filter(" 10 ".to_string(), AnonymousValueStringFormat::Decimal);
+
+            // Rows keep their index into the page, and hidden rows are deselected.
+            assert_eq!(view_data.filtered_indices, Some(vec![0, 2]));
+            assert_eq!(view_data.get_row_count(), 2);
+            assert_eq!(view_data.get_row_index(1), Some(2));
+            assert_eq!(view_data.get_row_position(3), None);
+            assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![0, 2]);
+            assert_eq!(view_data.get_page_indices(), vec![0, 2]);
+
+            view_data.selection.clear();
+        }
+
+        ElementScannerResultsViewData::select_all(view_data.clone());
+        assert_eq!(
+            view_data
+                .read("Test read selection")
+                .unwrap()
+                .selection
+                .iter_indices()
+                .collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+
+        // Extending across hidden rows only selects the rows shown.
+        if let Some(mut view_data) = view_data.write("Test extend selection") {
+            view_data.selection.select(2);
+        }
+
+        ElementScannerResultsViewData::extend_scan_result_selection(view_data.clone(), 0);
+
+        if let Some(mut view_data) = view_data.write("Test refreshed results") {
+            assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![0, 2]);
+
+            // Refreshed values are matched again.
+            view_data.set_current_scan_results(Arc::new(vec![
+                create_read_scan_result(0, Some("99")),
+                create_read_scan_result(1, Some("10")),
+                create_read_scan_result(2, Some("1000")),
+                create_read_scan_result(3, Some("7")),
+            ]));
+
+            assert_eq!(view_data.filtered_indices, Some(vec![1, 2]));
+            assert_eq!(view_data.selection.iter_indices().collect::<Vec<_>>(), vec![2]);
+
+            view_data.set_value_filter(String::new(), AnonymousValueStringFormat::Decimal);
+
+            assert_eq!(view_data.filtered_indices, None);
+            assert_eq!(view_data.get_row_count(), 4);
+            assert!(!view_data.is_querying_scan_results);
+        }
+    }
+    #[test]
+    fn address_edits_show_unresolved_addresses_and_move_resolved_results() {
+        let set_properties = Arc::new(Mutex::new(Vec::new()));
+        let bindings = AddressEditUnprivilegedBindings {
+            set_properties: set_properties.clone(),
+        };
+        let engine_unprivileged_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(bindings)));
+        let view_data = DependencyContainer::new().register(ElementScannerResultsViewData::new());
+        let set_address_string = |address_string: &str| {
+            if let Some(mut view_data) = view_data.write("Test enter address") {
+                view_data.address_edit.as_mut().unwrap().address_string = address_string.to_string();
+            }
+        };
+
+        if let Some(mut view_data) = view_data.write("Test load results") {
+            view_data.current_scan_results = Arc::new(vec![
+                create_read_scan_result(0, Some("1")),
+                create_read_scan_result(1, Some("2")),
+            ]);
+            view_data.result_count = 2;
+        }
+
+        ElementScannerResultsViewData::show_change_address_dialog(view_data.clone(), 1);
+
+        let address_edit = view_data
+            .read("Test read address edit")
+            .unwrap()
+            .address_edit
+            .clone()
+            .unwrap();
+
+        assert_eq!(address_edit.address_string, "1004");
+
+        // Addresses that cannot be resolved keep the dialog open with the reason, and nothing is sent.
+        set_address_string("client.dll+4");
+        ElementScannerResultsViewData::apply_address_edit(view_data.clone(), engine_unprivileged_state.clone());
+
+        let address_edit = view_data
+            .read("Test read address edit")
+            .unwrap()
+            .address_edit
+            .clone()
+            .unwrap();
+
+        assert!(!address_edit.is_applying);
+        assert_eq!(
+            address_edit.address_resolution.unwrap().unwrap_err(),
+            AddressResolveError::UnknownModule {
+                module_name: "client.dll".to_string()
+            }
+        );
+        assert!(set_properties.lock().unwrap().is_empty());
+
+        set_address_string("game.exe+1000");
+        ElementScannerResultsViewData::apply_address_edit(view_data.clone(), engine_unprivileged_state.clone());
+
+        let view_data = view_data.read("Test read moved results").unwrap();
+
+        assert!(view_data.address_edit.is_none());
+        assert_eq!(
+            *set_properties.lock().unwrap(),
+            vec![(ScanResult::PROPERTY_NAME_ADDRESS.to_string(), "2000".to_string())]
+        );
+        assert_eq!(view_data.current_scan_results[0].get_address(), 0x1000);
+        assert_eq!(view_data.current_scan_results[1].get_address(), 0x2000);
+    }
+}
@@ -1,2 +1,12 @@
 pub mod element_scanner_result_frame_action;
 pub mod element_scanner_results_view_data;
+pub mod scan_result_address_edit;
+pub mod scan_result_freeze_undo;
+pub mod scan_result_row_navigation;
+pub mod scan_result_selection;
+pub mod scan_result_write_history;
+pub mod scan_results_exporter;
+pub mod scan_results_page_cache;
+pub mod scan_results_sort;
+pub mod scan_results_tsv_builder;
+pub mod stale_results_policy;
@@ -0,0 +1,33 @@
+use crate::models::address_resolution::address_resolver::AddressResolution;
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+
+/// A new address being entered for a scan result in the change address dialog.
+#[derive(Clone)]
+pub struct ScanResultAddressEdit {
+    pub scan_result_ref: ScanResultRef,
+    /// The entered address, which accepts the same expressions as other address inputs, ie `7FF6A000` or `game.exe+1A0`.
+    pub address_string: String,
+    /// Why the entered address could not be resolved, shown in the dialog until the address is edited.
+    pub address_resolution: Option<AddressResolution>,
+    /// Whether the entered address is being resolved and applied, during which it cannot be entered again.
+    pub is_applying: bool,
+}
+
+impl ScanResultAddressEdit {
+    /// Starts editing the address of a scan result, from its module and offset if it is static, or from its absolute address.
+    pub fn new(scan_result: &ScanResult) -> Self {
+        let address_string = if scan_result.is_module() {
+            format!("{}+{:X}", scan_result.get_module(), scan_result.get_module_offset())
+        } else {
+            format!("{:X}", scan_result.get_address())
+        };
+
+        Self {
+            scan_result_ref: scan_result.get_base_result().get_scan_result_ref().clone(),
+            address_string,
+            address_resolution: None,
+            is_applying: false,
+        }
+    }
+}
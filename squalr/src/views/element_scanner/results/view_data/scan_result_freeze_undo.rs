@@ -0,0 +1,58 @@
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+
+/// A batch of scan results that were frozen or unfrozen together, along with the state each held beforehand, such that the batch
+/// can be undone. The prior states are captured when the batch is applied, since they cannot be recovered afterwards.
+#[derive(Clone, Debug)]
+pub struct ScanResultFreezeUndo {
+    /// Whether the batch froze or unfroze its results.
+    pub is_frozen: bool,
+    /// Each result in the batch, paired with whether it was frozen before the batch was applied.
+    pub previous_frozen_states: Vec<(ScanResultRef, bool)>,
+}
+
+impl ScanResultFreezeUndo {
+    /// Gets a summary of the batch, such as "Froze 50 addresses".
+    pub fn get_summary(&self) -> String {
+        let verb = if self.is_frozen { "Froze" } else { "Unfroze" };
+        let address_count = self.previous_frozen_states.len();
+        let noun = if address_count == 1 { "address" } else { "addresses" };
+
+        format!("{} {} {}", verb, address_count, noun)
+    }
+
+    /// Gets the results that the batch changed, all of which are restored to the opposite of the batch state. Results that already
+    /// held the batch state are left out, such that undoing never touches them.
+    pub fn get_changed_scan_result_refs(&self) -> Vec<ScanResultRef> {
+        self.previous_frozen_states
+            .iter()
+            .filter(|(_scan_result_ref, was_frozen)| *was_frozen != self.is_frozen)
+            .map(|(scan_result_ref, _was_frozen)| scan_result_ref.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultFreezeUndo;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+
+    #[test]
+    fn undo_only_restores_results_that_the_batch_changed() {
+        let freeze_undo = ScanResultFreezeUndo {
+            is_frozen: true,
+            previous_frozen_states: vec![
+                (ScanResultRef::new(0), false),
+                (ScanResultRef::new(1), true),
+                (ScanResultRef::new(2), false),
+            ],
+        };
+        let changed_global_indices = freeze_undo
+            .get_changed_scan_result_refs()
+            .iter()
+            .map(ScanResultRef::get_scan_result_global_index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(freeze_undo.get_summary(), "Froze 3 addresses");
+        assert_eq!(changed_global_indices, vec![0, 2]);
+    }
+}
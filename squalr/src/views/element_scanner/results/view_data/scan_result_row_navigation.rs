@@ -0,0 +1,57 @@
+/// A keyboard step that moves the selection cursor through the scan results list.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ScanResultRowNavigation {
+    /// Moves by the given number of rows, ie -1 for the arrow up key.
+    Offset(i64),
+    First,
+    Last,
+}
+
+impl ScanResultRowNavigation {
+    /// Gets the row this step lands on from the given cursor, clamped to the rows shown, or none if no rows are shown. Without a
+    /// cursor, stepping down starts at the first row and stepping up starts at the last.
+    pub fn get_target_row(
+        &self,
+        cursor: Option<usize>,
+        row_count: usize,
+    ) -> Option<usize> {
+        let last_row = row_count.checked_sub(1)?;
+
+        Some(match (self, cursor) {
+            (Self::First, _) => 0,
+            (Self::Last, _) => last_row,
+            (Self::Offset(row_offset), Some(cursor)) => (cursor as i64)
+                .saturating_add(*row_offset)
+                .clamp(0, last_row as i64) as usize,
+            (Self::Offset(row_offset), None) => {
+                if *row_offset < 0 {
+                    last_row
+                } else {
+                    0
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultRowNavigation;
+
+    #[test]
+    fn steps_are_clamped_to_the_rows_shown() {
+        assert_eq!(ScanResultRowNavigation::Offset(1).get_target_row(Some(3), 10), Some(4));
+        assert_eq!(ScanResultRowNavigation::Offset(1).get_target_row(Some(9), 10), Some(9));
+        assert_eq!(ScanResultRowNavigation::Offset(-1).get_target_row(Some(0), 10), Some(0));
+        assert_eq!(ScanResultRowNavigation::Offset(-25).get_target_row(Some(12), 100), Some(0));
+        assert_eq!(ScanResultRowNavigation::Last.get_target_row(Some(0), 10), Some(9));
+        assert_eq!(ScanResultRowNavigation::First.get_target_row(None, 10), Some(0));
+    }
+
+    #[test]
+    fn steps_without_a_cursor_start_from_the_nearest_end() {
+        assert_eq!(ScanResultRowNavigation::Offset(1).get_target_row(None, 10), Some(0));
+        assert_eq!(ScanResultRowNavigation::Offset(-1).get_target_row(None, 10), Some(9));
+        assert_eq!(ScanResultRowNavigation::Offset(1).get_target_row(Some(4), 0), None);
+    }
+}
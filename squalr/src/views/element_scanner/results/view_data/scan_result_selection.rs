@@ -0,0 +1,299 @@
+use std::ops::RangeInclusive;
+
+/// The rows selected in the scan results list, which need not be contiguous. Rows are kept as sorted, disjoint ranges rather than
+/// one entry per row, such that selecting millions of rows in infinite scroll mode stays cheap.
+///
+/// A plain click selects a single row, Ctrl+Click toggles a row, and Shift+Click selects every row between the anchor (the row
+/// last clicked without Shift) and the clicked row. Rows selected before the anchor was set are kept, so Ctrl+Click followed by
+/// Shift+Click adds a second range rather than replacing the first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScanResultSelection {
+    /// The rows selected before the anchor was last set.
+    ranges: Vec<RangeInclusive<usize>>,
+    /// The rows between the anchor and the row last Shift+Clicked, or just the anchor if it was selected by a plain click.
+    extension: Option<RangeInclusive<usize>>,
+    anchor: Option<usize>,
+    /// The row last clicked or moved to, which keyboard navigation moves from.
+    cursor: Option<usize>,
+}
+
+impl ScanResultSelection {
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+        self.extension = None;
+        self.anchor = None;
+        self.cursor = None;
+    }
+
+    /// Selects only the given row, making it the anchor.
+    pub fn select(
+        &mut self,
+        index: usize,
+    ) {
+        self.ranges.clear();
+        self.extension = Some(index..=index);
+        self.anchor = Some(index);
+        self.cursor = Some(index);
+    }
+
+    /// Selects or deselects the given row, leaving the rest of the selection as it is. The row becomes the anchor either way.
+    pub fn toggle(
+        &mut self,
+        index: usize,
+    ) {
+        self.commit_extension();
+
+        if self.contains(index) {
+            Self::remove_range(&mut self.ranges, index..=index);
+        } else {
+            Self::insert_range(&mut self.ranges, index..=index);
+        }
+
+        self.anchor = Some(index);
+        self.cursor = Some(index);
+    }
+
+    /// Selects every row from the anchor to the given row, in addition to the rows selected before the anchor was set.
+    pub fn extend_to(
+        &mut self,
+        index: usize,
+    ) {
+        let anchor = *self.anchor.get_or_insert(index);
+
+        self.extension = Some(anchor.min(index)..=anchor.max(index));
+        self.cursor = Some(index);
+    }
+
+    /// Selects the first `row_count` rows.
+    pub fn select_all(
+        &mut self,
+        row_count: usize,
+    ) {
+        self.clear();
+
+        if row_count > 0 {
+            self.ranges.push(0..=row_count - 1);
+            self.anchor = Some(0);
+            self.cursor = Some(0);
+        }
+    }
+
+    pub fn contains(
+        &self,
+        index: usize,
+    ) -> bool {
+        self.extension
+            .as_ref()
+            .is_some_and(|extension| extension.contains(&index))
+            || Self::find_range(&self.ranges, index).is_ok()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.extension.is_none() && self.ranges.is_empty()
+    }
+
+    /// Gets the row that Shift+Click extends the selection from.
+    pub fn get_anchor(&self) -> Option<usize> {
+        self.anchor
+    }
+
+    /// Gets the row last clicked or moved to, which keyboard navigation moves from.
+    pub fn get_cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    /// Gets the selected rows as sorted, disjoint ranges.
+    pub fn get_ranges(&self) -> Vec<RangeInclusive<usize>> {
+        let mut ranges = self.ranges.clone();
+
+        if let Some(extension) = &self.extension {
+            Self::insert_range(&mut ranges, extension.clone());
+        }
+
+        ranges
+    }
+
+    /// Gets the number of selected rows, without iterating them.
+    pub fn get_selected_count(&self) -> usize {
+        self.get_ranges()
+            .iter()
+            .map(|range| range.end() - range.start() + 1)
+            .sum()
+    }
+
+    /// Iterates the selected rows in display order, regardless of the order in which they were selected.
+    pub fn iter_indices(&self) -> impl Iterator<Item = usize> {
+        self.get_ranges().into_iter().flatten()
+    }
+
+    /// Moves each selected row, along with the anchor and cursor, to the row given by `remap_index`, such as after the rows are
+    /// reordered. Rows that map to nothing are deselected.
+    pub fn remap(
+        &mut self,
+        remap_index: impl Fn(usize) -> Option<usize>,
+    ) {
+        let mut ranges = Vec::new();
+
+        for index in self.iter_indices().filter_map(&remap_index) {
+            Self::insert_range(&mut ranges, index..=index);
+        }
+
+        self.ranges = ranges;
+        self.extension = None;
+        self.anchor = self.anchor.and_then(&remap_index);
+        self.cursor = self.cursor.and_then(&remap_index);
+    }
+
+    /// Folds the extension into the committed ranges, such that changing the anchor keeps the rows it selected.
+    fn commit_extension(&mut self) {
+        if let Some(extension) = self.extension.take() {
+            Self::insert_range(&mut self.ranges, extension);
+        }
+    }
+
+    /// Finds the range holding the given row, or the position at which a range holding it would be inserted.
+    fn find_range(
+        ranges: &[RangeInclusive<usize>],
+        index: usize,
+    ) -> Result<usize, usize> {
+        let position = ranges.partition_point(|range| *range.end() < index);
+
+        match ranges.get(position) {
+            Some(range) if range.contains(&index) => Ok(position),
+            _ => Err(position),
+        }
+    }
+
+    /// Inserts a range, merging it with any ranges it overlaps or touches.
+    fn insert_range(
+        ranges: &mut Vec<RangeInclusive<usize>>,
+        range: RangeInclusive<usize>,
+    ) {
+        let (mut start, mut end) = (*range.start(), *range.end());
+        let first_index = ranges.partition_point(|existing_range| existing_range.end().saturating_add(1) < start);
+        let last_index = ranges.partition_point(|existing_range| *existing_range.start() <= end.saturating_add(1));
+
+        if first_index < last_index {
+            start = start.min(*ranges[first_index].start());
+            end = end.max(*ranges[last_index - 1].end());
+        }
+
+        ranges.splice(first_index..last_index, [start..=end]);
+    }
+
+    /// Removes a single row, splitting the range that holds it if needed.
+    fn remove_range(
+        ranges: &mut Vec<RangeInclusive<usize>>,
+        range: RangeInclusive<usize>,
+    ) {
+        let index = *range.start();
+        let Ok(position) = Self::find_range(ranges, index) else {
+            return;
+        };
+        let (start, end) = (*ranges[position].start(), *ranges[position].end());
+        let mut remaining_ranges = Vec::with_capacity(2);
+
+        if start < index {
+            remaining_ranges.push(start..=index - 1);
+        }
+
+        if index < end {
+            remaining_ranges.push(index + 1..=end);
+        }
+
+        ranges.splice(position..=position, remaining_ranges);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultSelection;
+
+    #[test]
+    fn ctrl_clicks_toggle_rows_and_iterate_in_display_order() {
+        let mut selection = ScanResultSelection::default();
+
+        selection.select(7);
+        selection.toggle(2);
+        selection.toggle(5);
+        selection.toggle(3);
+
+        assert_eq!(selection.iter_indices().collect::<Vec<_>>(), vec![2, 3, 5, 7]);
+
+        selection.toggle(3);
+
+        assert_eq!(selection.iter_indices().collect::<Vec<_>>(), vec![2, 5, 7]);
+        assert_eq!(selection.get_anchor(), Some(3));
+    }
+
+    #[test]
+    fn shift_clicks_extend_from_the_anchor_and_keep_toggled_rows() {
+        let mut selection = ScanResultSelection::default();
+
+        selection.select(1);
+        selection.toggle(10);
+        selection.extend_to(12);
+        assert_eq!(selection.get_ranges(), vec![1..=1, 10..=12]);
+
+        // Extending again moves the end of the same range, rather than adding another.
+        selection.extend_to(8);
+        assert_eq!(selection.get_ranges(), vec![1..=1, 8..=10]);
+        assert_eq!(selection.get_selected_count(), 4);
+
+        // A plain click starts over.
+        selection.select(4);
+        selection.extend_to(2);
+        assert_eq!(selection.get_ranges(), vec![2..=4]);
+        assert_eq!(selection.get_cursor(), Some(2));
+    }
+
+    #[test]
+    fn toggling_rows_within_a_range_splits_it() {
+        let mut selection = ScanResultSelection::default();
+
+        selection.select_all(1_000_000);
+        selection.toggle(500);
+        selection.toggle(0);
+
+        assert_eq!(selection.get_ranges(), vec![1..=499, 501..=999_999]);
+        assert!(!selection.contains(500));
+        assert!(selection.contains(999_999));
+
+        selection.toggle(500);
+
+        assert_eq!(selection.get_ranges(), vec![1..=999_999]);
+    }
+
+    #[test]
+    fn remapping_moves_rows_and_drops_those_without_a_new_row() {
+        let mut selection = ScanResultSelection::default();
+
+        selection.select(0);
+        selection.toggle(1);
+        selection.toggle(3);
+
+        // Reverse four rows, dropping the last one.
+        selection.remap(|index| (index < 3).then(|| 2 - index));
+
+        assert_eq!(selection.get_ranges(), vec![1..=2]);
+        assert_eq!(selection.get_anchor(), None);
+        assert_eq!(selection.get_cursor(), None);
+
+        selection.select(2);
+        selection.remap(|index| Some(index + 5));
+
+        assert_eq!(selection.get_ranges(), vec![7..=7]);
+        assert_eq!(selection.get_anchor(), Some(7));
+    }
+
+    #[test]
+    fn selecting_all_of_nothing_leaves_the_selection_empty() {
+        let mut selection = ScanResultSelection::default();
+
+        selection.select(3);
+        selection.select_all(0);
+
+        assert!(selection.is_empty());
+        assert_eq!(selection.get_anchor(), None);
+    }
+}
@@ -0,0 +1,151 @@
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use std::collections::VecDeque;
+
+/// A value written to a set of scan results, along with the values they held beforehand.
+#[derive(Clone, Debug)]
+pub struct ScanResultValueWrite {
+    pub field_namespace: String,
+    pub scan_result_refs: Vec<ScanResultRef>,
+    /// The values held before the write, paired with scan result refs by position.
+    pub previous_values: Vec<AnonymousValueString>,
+    pub new_value: AnonymousValueString,
+}
+
+impl ScanResultValueWrite {
+    /// Groups the scan result refs by the value they held before the write, such that each distinct value is restored with a
+    /// single request. Refs rejected by `is_ref_valid` are left out.
+    pub fn group_previous_values(
+        &self,
+        is_ref_valid: impl Fn(&ScanResultRef) -> bool,
+    ) -> Vec<(AnonymousValueString, Vec<ScanResultRef>)> {
+        let mut groups: Vec<(AnonymousValueString, Vec<ScanResultRef>)> = Vec::new();
+
+        for (scan_result_ref, previous_value) in self.scan_result_refs.iter().zip(&self.previous_values) {
+            if !is_ref_valid(scan_result_ref) {
+                continue;
+            }
+
+            match groups.iter_mut().find(|(value, _refs)| value == previous_value) {
+                Some((_value, refs)) => refs.push(scan_result_ref.clone()),
+                None => groups.push((previous_value.clone(), vec![scan_result_ref.clone()])),
+            }
+        }
+
+        groups
+    }
+}
+
+/// Bounded undo and redo stacks of values written to scan results from the results view.
+#[derive(Clone, Debug, Default)]
+pub struct ScanResultWriteHistory {
+    undo_stack: VecDeque<ScanResultValueWrite>,
+    redo_stack: Vec<ScanResultValueWrite>,
+}
+
+impl ScanResultWriteHistory {
+    pub const MAX_ENTRIES: usize = 50;
+
+    /// Records a new write. Anything that was undone can no longer be redone, and the oldest write is forgotten once full.
+    pub fn record(
+        &mut self,
+        value_write: ScanResultValueWrite,
+    ) {
+        self.redo_stack.clear();
+        self.push_undo(value_write);
+    }
+
+    pub fn take_undo(&mut self) -> Option<ScanResultValueWrite> {
+        self.undo_stack.pop_back()
+    }
+
+    pub fn take_redo(&mut self) -> Option<ScanResultValueWrite> {
+        self.redo_stack.pop()
+    }
+
+    /// Pushes a write that can be undone, without clearing the redo stack. Used when a write is redone.
+    pub fn push_undo(
+        &mut self,
+        value_write: ScanResultValueWrite,
+    ) {
+        if self.undo_stack.len() >= Self::MAX_ENTRIES {
+            self.undo_stack.pop_front();
+        }
+
+        self.undo_stack.push_back(value_write);
+    }
+
+    pub fn push_redo(
+        &mut self,
+        value_write: ScanResultValueWrite,
+    ) {
+        if self.redo_stack.len() >= Self::MAX_ENTRIES {
+            self.redo_stack.remove(0);
+        }
+
+        self.redo_stack.push(value_write);
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScanResultValueWrite, ScanResultWriteHistory};
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+
+    fn value(text: &str) -> AnonymousValueString {
+        AnonymousValueString::new(text.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None)
+    }
+
+    fn value_write(new_value: &str) -> ScanResultValueWrite {
+        ScanResultValueWrite {
+            field_namespace: "value".to_string(),
+            scan_result_refs: vec![
+                ScanResultRef::new(0),
+                ScanResultRef::new(1),
+                ScanResultRef::new(2),
+            ],
+            previous_values: vec![value("1"), value("2"), value("1")],
+            new_value: value(new_value),
+        }
+    }
+
+    #[test]
+    fn record_is_bounded_and_clears_redo() {
+        let mut write_history = ScanResultWriteHistory::default();
+
+        for write_index in 0..ScanResultWriteHistory::MAX_ENTRIES + 5 {
+            write_history.record(value_write(&write_index.to_string()));
+        }
+
+        let undone_write = write_history.take_undo().unwrap();
+        assert_eq!(undone_write.new_value, value(&(ScanResultWriteHistory::MAX_ENTRIES + 4).to_string()));
+        write_history.push_redo(undone_write.clone());
+        write_history.record(value_write("new"));
+        assert!(write_history.take_redo().is_none());
+
+        let mut undo_count = 0;
+        while write_history.take_undo().is_some() {
+            undo_count += 1;
+        }
+        assert_eq!(undo_count, ScanResultWriteHistory::MAX_ENTRIES);
+    }
+
+    #[test]
+    fn group_previous_values_skips_refs_that_no_longer_resolve() {
+        let groups = value_write("9").group_previous_values(|scan_result_ref| scan_result_ref.get_scan_result_global_index() < 2);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, value("1"));
+        assert_eq!(groups[0].1.len(), 1);
+        assert_eq!(groups[1].0, value("2"));
+        assert_eq!(groups[1].1.len(), 1);
+    }
+}
@@ -0,0 +1,198 @@
+use serde::Serialize;
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+/// The file formats that scan results can be exported to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScanResultsExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ScanResultsExportFormat {
+    pub const ALL: [Self; 2] = [Self::Csv, Self::Json];
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+
+    pub fn get_file_extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+}
+
+/// A single exported scan result, with the same columns as the results list.
+#[derive(Serialize)]
+struct ExportedScanResult<'lifetime> {
+    address: &'lifetime str,
+    value: &'lifetime str,
+    previous_value: &'lifetime str,
+    data_type: &'lifetime str,
+    is_frozen: bool,
+}
+
+/// Writes scan results one row at a time, such that exporting millions of results never holds more than a page of them in memory.
+pub struct ScanResultsExporter<W: Write> {
+    writer: W,
+    export_format: ScanResultsExportFormat,
+    row_count: u64,
+}
+
+impl<W: Write> ScanResultsExporter<W> {
+    const CSV_HEADER: &'static str = "Address,Value,Previous value,Type,Frozen";
+
+    pub fn new(
+        mut writer: W,
+        export_format: ScanResultsExportFormat,
+    ) -> io::Result<Self> {
+        match export_format {
+            ScanResultsExportFormat::Csv => writeln!(writer, "{}", Self::CSV_HEADER)?,
+            ScanResultsExportFormat::Json => write!(writer, "[")?,
+        }
+
+        Ok(Self {
+            writer,
+            export_format,
+            row_count: 0,
+        })
+    }
+
+    /// Writes a row from the address, current value, previous value, and type cells of a scan result.
+    pub fn write_row(
+        &mut self,
+        cells: &[String; 4],
+        is_frozen: bool,
+    ) -> io::Result<()> {
+        let [address, value, previous_value, data_type] = cells;
+
+        match self.export_format {
+            ScanResultsExportFormat::Csv => writeln!(
+                self.writer,
+                "{},{},{},{},{}",
+                Self::escape_csv_field(address),
+                Self::escape_csv_field(value),
+                Self::escape_csv_field(previous_value),
+                Self::escape_csv_field(data_type),
+                is_frozen
+            )?,
+            ScanResultsExportFormat::Json => {
+                let separator = if self.row_count == 0 { "\n  " } else { ",\n  " };
+                let exported_scan_result = ExportedScanResult {
+                    address,
+                    value,
+                    previous_value,
+                    data_type,
+                    is_frozen,
+                };
+
+                self.writer.write_all(separator.as_bytes())?;
+                serde_json::to_writer(&mut self.writer, &exported_scan_result)?;
+            }
+        }
+
+        self.row_count += 1;
+
+        Ok(())
+    }
+
+    pub fn get_row_count(&self) -> u64 {
+        self.row_count
+    }
+
+    /// Closes the exported document and flushes it, returning the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.export_format == ScanResultsExportFormat::Json {
+            writeln!(self.writer, "\n]")?;
+        }
+
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    /// Quotes a field if it contains a character that CSV gives meaning to, doubling any quotes within it.
+    fn escape_csv_field(field: &str) -> Cow<'_, str> {
+        if field.contains([',', '"', '\n', '\r']) {
+            Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+        } else {
+            Cow::Borrowed(field)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScanResultsExportFormat, ScanResultsExporter};
+
+    fn export(export_format: ScanResultsExportFormat) -> String {
+        let mut exporter = ScanResultsExporter::new(Vec::new(), export_format).unwrap();
+
+        exporter
+            .write_row(
+                &[
+                    "game.exe+1A0".to_string(),
+                    "100".to_string(),
+                    "99".to_string(),
+                    "i32".to_string(),
+                ],
+                true,
+            )
+            .unwrap();
+        exporter
+            .write_row(
+                &[
+                    "7FF00010".to_string(),
+                    "1,5".to_string(),
+                    "??".to_string(),
+                    "string \"utf8\"".to_string(),
+                ],
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(exporter.get_row_count(), 2);
+
+        String::from_utf8(exporter.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn csv_exports_quote_fields_containing_separators() {
+        assert_eq!(
+            export(ScanResultsExportFormat::Csv),
+            "Address,Value,Previous value,Type,Frozen\n\
+             game.exe+1A0,100,99,i32,true\n\
+             7FF00010,\"1,5\",??,\"string \"\"utf8\"\"\",false\n"
+        );
+    }
+
+    #[test]
+    fn json_exports_are_an_array_of_rows() {
+        let exported: serde_json::Value = serde_json::from_str(&export(ScanResultsExportFormat::Json)).unwrap();
+
+        assert_eq!(exported.as_array().map(Vec::len), Some(2));
+        assert_eq!(exported[0]["address"], "game.exe+1A0");
+        assert_eq!(exported[0]["is_frozen"], true);
+        assert_eq!(exported[1]["value"], "1,5");
+        assert_eq!(exported[1]["data_type"], "string \"utf8\"");
+    }
+
+    #[test]
+    fn empty_exports_are_still_valid_documents() {
+        let exporter = ScanResultsExporter::new(Vec::new(), ScanResultsExportFormat::Json).unwrap();
+        let exported = String::from_utf8(exporter.finish().unwrap()).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<Vec<serde_json::Value>>(&exported)
+                .unwrap()
+                .len(),
+            0
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A bounded, least recently used cache of scan result pages, keyed by page index.
+/// Pages are only valid for a single page size and results generation, and are discarded when either changes.
+#[derive(Clone)]
+pub struct ScanResultsPageCache {
+    page_size: u64,
+    capacity: usize,
+    generation: u64,
+    pages: HashMap<u64, Arc<Vec<ScanResult>>>,
+    /// Page indices ordered from least to most recently used.
+    lru_order: VecDeque<u64>,
+    /// Pages with a fetch in flight, along with when the fetch was issued.
+    pending_pages: HashMap<u64, Instant>,
+}
+
+impl ScanResultsPageCache {
+    pub const DEFAULT_CAPACITY: usize = 32;
+    /// Fetches that have not completed in this long are assumed lost, such that the page is requested again.
+    const PENDING_TIMEOUT_MS: u64 = 5000;
+
+    pub fn new(
+        page_size: u64,
+        capacity: usize,
+    ) -> Self {
+        Self {
+            page_size: page_size.max(1),
+            capacity: capacity.max(1),
+            generation: 0,
+            pages: HashMap::new(),
+            lru_order: VecDeque::new(),
+            pending_pages: HashMap::new(),
+        }
+    }
+
+    pub fn get_page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Discards all pages and in flight fetches, such that responses to earlier requests are ignored.
+    pub fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.pages.clear();
+        self.lru_order.clear();
+        self.pending_pages.clear();
+    }
+
+    /// Changes the page size, clearing the cache if it differs from the current one.
+    pub fn set_page_size(
+        &mut self,
+        page_size: u64,
+    ) {
+        let page_size = page_size.max(1);
+
+        if page_size != self.page_size {
+            self.page_size = page_size;
+            self.clear();
+        }
+    }
+
+    /// Maps a global row index to the page containing it and the offset within that page.
+    pub fn locate(
+        &self,
+        row_index: u64,
+    ) -> (u64, usize) {
+        (row_index / self.page_size, (row_index % self.page_size) as usize)
+    }
+
+    /// Gets the pages spanned by the given range of global row indices.
+    pub fn get_page_range(
+        &self,
+        row_range: Range<u64>,
+    ) -> Range<u64> {
+        if row_range.is_empty() {
+            return 0..0;
+        }
+
+        (row_range.start / self.page_size)..(row_range.end.saturating_sub(1) / self.page_size + 1)
+    }
+
+    pub fn get_page(
+        &self,
+        page_index: u64,
+    ) -> Option<&Arc<Vec<ScanResult>>> {
+        self.pages.get(&page_index)
+    }
+
+    pub fn get_scan_result(
+        &self,
+        row_index: u64,
+    ) -> Option<&ScanResult> {
+        let (page_index, offset) = self.locate(row_index);
+
+        self.pages.get(&page_index)?.get(offset)
+    }
+
+    pub fn get_scan_result_mut(
+        &mut self,
+        row_index: u64,
+    ) -> Option<&mut ScanResult> {
+        let (page_index, offset) = self.locate(row_index);
+
+        Arc::make_mut(self.pages.get_mut(&page_index)?).get_mut(offset)
+    }
+
+    /// Iterates all cached scan results along with their global row index, in no particular order.
+    pub fn iter_scan_results(&self) -> impl Iterator<Item = (u64, &ScanResult)> {
+        let page_size = self.page_size;
+
+        self.pages.iter().flat_map(move |(page_index, page)| {
+            page.iter()
+                .enumerate()
+                .map(move |(offset, scan_result)| (page_index * page_size + offset as u64, scan_result))
+        })
+    }
+
+    /// Marks a page as recently used, such that it is evicted last.
+    pub fn touch(
+        &mut self,
+        page_index: u64,
+    ) {
+        if !self.pages.contains_key(&page_index) {
+            return;
+        }
+
+        if let Some(position) = self.lru_order.iter().position(|entry| *entry == page_index) {
+            self.lru_order.remove(position);
+        }
+
+        self.lru_order.push_back(page_index);
+    }
+
+    /// Stores a fetched page, returning false if it belongs to a stale generation or page size.
+    pub fn insert_page(
+        &mut self,
+        page_index: u64,
+        page_size: u64,
+        generation: u64,
+        scan_results: Arc<Vec<ScanResult>>,
+    ) -> bool {
+        if generation != self.generation || page_size != self.page_size {
+            return false;
+        }
+
+        self.pending_pages.remove(&page_index);
+        self.pages.insert(page_index, scan_results);
+        self.touch(page_index);
+
+        while self.lru_order.len() > self.capacity {
+            if let Some(evicted_page_index) = self.lru_order.pop_front() {
+                self.pages.remove(&evicted_page_index);
+            }
+        }
+
+        true
+    }
+
+    pub fn mark_pending(
+        &mut self,
+        page_index: u64,
+    ) {
+        self.pending_pages.insert(page_index, Instant::now());
+    }
+
+    pub fn clear_pending(
+        &mut self,
+        page_index: u64,
+    ) {
+        self.pending_pages.remove(&page_index);
+    }
+
+    pub fn is_pending(
+        &self,
+        page_index: u64,
+    ) -> bool {
+        self.pending_pages
+            .get(&page_index)
+            .map(|requested_at| requested_at.elapsed() < Duration::from_millis(Self::PENDING_TIMEOUT_MS))
+            .unwrap_or(false)
+    }
+
+    pub fn has_pending_pages(&self) -> bool {
+        self.pending_pages
+            .keys()
+            .any(|page_index| self.is_pending(*page_index))
+    }
+
+    /// Gets the pages among those given that are neither cached nor being fetched.
+    pub fn get_missing_pages(
+        &self,
+        page_indices: impl IntoIterator<Item = u64>,
+    ) -> Vec<u64> {
+        page_indices
+            .into_iter()
+            .filter(|page_index| !self.pages.contains_key(page_index) && !self.is_pending(*page_index))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultsPageCache;
+    use std::sync::Arc;
+
+    #[test]
+    fn least_recently_used_pages_are_evicted_first() {
+        let mut page_cache = ScanResultsPageCache::new(100, 2);
+        let generation = page_cache.get_generation();
+
+        assert!(page_cache.insert_page(0, 100, generation, Arc::new(vec![])));
+        assert!(page_cache.insert_page(1, 100, generation, Arc::new(vec![])));
+        page_cache.touch(0);
+        assert!(page_cache.insert_page(2, 100, generation, Arc::new(vec![])));
+
+        assert!(page_cache.get_page(0).is_some());
+        assert!(page_cache.get_page(1).is_none());
+        assert!(page_cache.get_page(2).is_some());
+        assert_eq!(page_cache.get_missing_pages(0..3), vec![1]);
+    }
+
+    #[test]
+    fn stale_pages_are_rejected_and_rows_map_to_page_offsets() {
+        let mut page_cache = ScanResultsPageCache::new(40, 4);
+        let stale_generation = page_cache.get_generation();
+
+        page_cache.clear();
+
+        assert!(!page_cache.insert_page(0, 40, stale_generation, Arc::new(vec![])));
+        assert!(!page_cache.insert_page(0, 64, page_cache.get_generation(), Arc::new(vec![])));
+        assert_eq!(page_cache.locate(2_400), (60, 0));
+        assert_eq!(page_cache.locate(2_439), (60, 39));
+        assert_eq!(page_cache.get_page_range(2_400..2_441), 60..62);
+        assert_eq!(page_cache.get_page_range(5..5), 0..0);
+    }
+}
@@ -0,0 +1,208 @@
+use squalr_engine_api::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use std::cmp::Ordering;
+
+/// A column of the scan results list that the current page can be sorted by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanResultsSortColumn {
+    Address,
+    Value,
+    PreviousValue,
+}
+
+/// How the rows of the current page are ordered. Sorting happens on the client over the page shown, so results are never moved
+/// between pages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanResultsSort {
+    pub column: ScanResultsSortColumn,
+    pub is_descending: bool,
+}
+
+impl ScanResultsSort {
+    /// Gets the sort that follows clicking the header of the given column. A new column is sorted ascending, then descending on
+    /// the next click, and the click after that returns to snapshot order.
+    pub fn get_next(
+        current_sort: Option<Self>,
+        column: ScanResultsSortColumn,
+    ) -> Option<Self> {
+        match current_sort {
+            Some(sort) if sort.column == column && !sort.is_descending => Some(Self { column, is_descending: true }),
+            Some(sort) if sort.column == column => None,
+            _ => Some(Self { column, is_descending: false }),
+        }
+    }
+
+    /// Sorts the given results. The sort is stable, such that results holding equal values keep their snapshot order.
+    pub fn sort(
+        &self,
+        scan_results: &mut [ScanResult],
+    ) {
+        scan_results.sort_by(|left, right| {
+            let ordering = self.compare(left, right);
+
+            if self.is_descending { ordering.reverse() } else { ordering }
+        });
+    }
+
+    fn compare(
+        &self,
+        left: &ScanResult,
+        right: &ScanResult,
+    ) -> Ordering {
+        match self.column {
+            ScanResultsSortColumn::Address => left.get_address().cmp(&right.get_address()),
+            ScanResultsSortColumn::Value => Self::compare_values(
+                left,
+                left.get_display_value_string(AnonymousValueStringFormat::Decimal),
+                right,
+                right.get_display_value_string(AnonymousValueStringFormat::Decimal),
+            ),
+            ScanResultsSortColumn::PreviousValue => {
+                Self::compare_values(left, Self::get_previous_value_string(left), right, Self::get_previous_value_string(right))
+            }
+        }
+    }
+
+    /// Compares values of numeric types by the number they hold, and all other values, such as strings and values that could not
+    /// be read, by their text. Numbers sort before text.
+    fn compare_values(
+        left: &ScanResult,
+        left_value_string: &str,
+        right: &ScanResult,
+        right_value_string: &str,
+    ) -> Ordering {
+        match (Self::parse_number(left, left_value_string), Self::parse_number(right, right_value_string)) {
+            (Some(left_number), Some(right_number)) => left_number.total_cmp(&right_number),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => left_value_string.cmp(right_value_string),
+        }
+    }
+
+    fn parse_number(
+        scan_result: &ScanResult,
+        value_string: &str,
+    ) -> Option<f64> {
+        if DataTypeStringEncoded::is_string_data_type_id(scan_result.get_data_type_ref().get_data_type_id()) {
+            return None;
+        }
+
+        value_string.trim().parse::<f64>().ok()
+    }
+
+    fn get_previous_value_string(scan_result: &ScanResult) -> &str {
+        scan_result
+            .get_previous_display_value(AnonymousValueStringFormat::Decimal)
+            .map(|previous_value| previous_value.get_anonymous_value_string())
+            .unwrap_or("??")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScanResultsSort, ScanResultsSortColumn};
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+    use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+
+    fn create_scan_result(
+        global_index: u64,
+        data_type_id: &str,
+        value: &str,
+    ) -> ScanResult {
+        let scan_result_valued = ScanResultValued::new(
+            0x1000 + global_index * 4,
+            DataTypeRef::new(data_type_id),
+            String::new(),
+            None,
+            vec![AnonymousValueString::new(
+                value.to_string(),
+                AnonymousValueStringFormat::Decimal,
+                ContainerType::None,
+            )],
+            None,
+            Vec::new(),
+            ScanResultRef::new(global_index),
+        );
+
+        ScanResult::new(scan_result_valued, String::new(), 0, None, Vec::new(), false)
+    }
+
+    fn get_global_indices(scan_results: &[ScanResult]) -> Vec<u64> {
+        scan_results
+            .iter()
+            .map(|scan_result| {
+                scan_result
+                    .get_base_result()
+                    .get_scan_result_ref()
+                    .get_scan_result_global_index()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn header_clicks_cycle_from_ascending_to_descending_to_snapshot_order() {
+        let ascending = ScanResultsSort::get_next(None, ScanResultsSortColumn::Value);
+        let descending = ScanResultsSort::get_next(ascending, ScanResultsSortColumn::Value);
+
+        assert_eq!(
+            ascending,
+            Some(ScanResultsSort {
+                column: ScanResultsSortColumn::Value,
+                is_descending: false
+            })
+        );
+        assert_eq!(descending.map(|sort| sort.is_descending), Some(true));
+        assert_eq!(ScanResultsSort::get_next(descending, ScanResultsSortColumn::Value), None);
+        assert_eq!(
+            ScanResultsSort::get_next(descending, ScanResultsSortColumn::Address).map(|sort| sort.is_descending),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn numbers_sort_numerically_and_stably_before_text() {
+        let mut scan_results = vec![
+            create_scan_result(0, "u32", "100"),
+            create_scan_result(1, "u32", "??"),
+            create_scan_result(2, "u32", "9"),
+            create_scan_result(3, "f32", "-1.5"),
+            create_scan_result(4, "u32", "9"),
+        ];
+        let ascending = ScanResultsSort {
+            column: ScanResultsSortColumn::Value,
+            is_descending: false,
+        };
+
+        ascending.sort(&mut scan_results);
+        assert_eq!(get_global_indices(&scan_results), vec![3, 2, 4, 0, 1]);
+
+        ScanResultsSort {
+            is_descending: true,
+            ..ascending
+        }
+        .sort(&mut scan_results);
+        assert_eq!(get_global_indices(&scan_results), vec![1, 0, 2, 4, 3]);
+    }
+
+    #[test]
+    fn strings_sort_by_text_even_when_they_hold_digits() {
+        let mut scan_results = vec![
+            create_scan_result(0, "string_utf8", "9"),
+            create_scan_result(1, "string_utf8", "10"),
+        ];
+
+        ScanResultsSort {
+            column: ScanResultsSortColumn::Value,
+            is_descending: false,
+        }
+        .sort(&mut scan_results);
+
+        assert_eq!(get_global_indices(&scan_results), vec![1, 0]);
+    }
+}
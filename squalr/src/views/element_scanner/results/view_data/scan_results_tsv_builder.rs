@@ -0,0 +1,101 @@
+/// A column of copied scan results, along with the width its cells usually take, such that copies can be sized up front.
+pub struct ScanResultsCopyColumn {
+    pub header: &'static str,
+    pub typical_width: usize,
+}
+
+/// Builds tab separated scan result rows into a single string, sized up front from the column descriptors, rather than collecting
+/// each row into its own string and joining them afterwards.
+pub struct ScanResultsTsvBuilder {
+    text: String,
+    row_count: u64,
+}
+
+impl ScanResultsTsvBuilder {
+    pub const COLUMNS: [ScanResultsCopyColumn; 4] = [
+        ScanResultsCopyColumn {
+            header: "Address",
+            typical_width: 16,
+        },
+        ScanResultsCopyColumn {
+            header: "Value",
+            typical_width: 12,
+        },
+        ScanResultsCopyColumn {
+            header: "Previous value",
+            typical_width: 12,
+        },
+        ScanResultsCopyColumn {
+            header: "Type",
+            typical_width: 6,
+        },
+    ];
+
+    /// Creates a builder with room for the given number of rows of typical width. Rows past that simply grow the string.
+    pub fn with_row_capacity(row_capacity: usize) -> Self {
+        Self {
+            text: String::with_capacity(row_capacity.saturating_mul(Self::get_typical_row_width())),
+            row_count: 0,
+        }
+    }
+
+    /// Gets the width of a row of typical cells, including its separators and line break.
+    pub fn get_typical_row_width() -> usize {
+        Self::COLUMNS
+            .iter()
+            .map(|column| column.typical_width + 1)
+            .sum()
+    }
+
+    pub fn get_cell_count(row_count: u64) -> u64 {
+        row_count.saturating_mul(Self::COLUMNS.len() as u64)
+    }
+
+    pub fn push_row(
+        &mut self,
+        cells: &[String],
+    ) {
+        if self.row_count > 0 {
+            self.text.push('\n');
+        }
+
+        for (cell_index, cell) in cells.iter().enumerate() {
+            if cell_index > 0 {
+                self.text.push('\t');
+            }
+
+            self.text.push_str(cell);
+        }
+
+        self.row_count += 1;
+    }
+
+    pub fn finish(self) -> String {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanResultsTsvBuilder;
+
+    #[test]
+    fn rows_are_tab_separated_without_a_trailing_line_break() {
+        let mut tsv_builder = ScanResultsTsvBuilder::with_row_capacity(2);
+
+        tsv_builder.push_row(&[
+            "00001000".to_string(),
+            "5".to_string(),
+            "4".to_string(),
+            "i32".to_string(),
+        ]);
+        tsv_builder.push_row(&[
+            "game.exe+20".to_string(),
+            "7".to_string(),
+            "??".to_string(),
+            "u8".to_string(),
+        ]);
+
+        assert_eq!(tsv_builder.finish(), "00001000\t5\t4\ti32\ngame.exe+20\t7\t??\tu8");
+    }
+}
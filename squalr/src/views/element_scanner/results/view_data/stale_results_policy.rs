@@ -0,0 +1,21 @@
+use crate::views::element_scanner::results::view_data::element_scanner_result_frame_action::ElementScannerResultFrameAction;
+
+/// Decides how freezes and writes treat results whose backing memory changed since it was snapshotted.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StaleResultsPolicy {
+    /// Ask before touching stale results, holding the action until the user decides.
+    Confirm,
+
+    /// Leave stale results untouched, and apply the action to the rest.
+    Skip,
+
+    /// Apply the action to stale results as well.
+    Include,
+}
+
+/// A freeze or write that targets stale results, held until the user decides how to treat them.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StaleResultsConfirmation {
+    pub frame_action: ElementScannerResultFrameAction,
+    pub stale_result_count: usize,
+}
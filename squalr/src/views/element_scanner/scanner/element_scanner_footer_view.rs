@@ -1,11 +1,16 @@
 use crate::{
     app_context::AppContext,
     ui::{draw::icon_draw::IconDraw, widgets::controls::button::Button},
-    views::element_scanner::results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData,
+    views::element_scanner::{
+        results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData, scanner::element_scanner_toolbar_view::ElementScannerToolbarView,
+    },
 };
-use eframe::egui::{Align, Response, RichText, Sense, TextEdit, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Align2, Response, RichText, Sense, TextEdit, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, Rect, Stroke, StrokeKind, pos2, vec2};
 use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::scanning::statistics::scan_filter_statistics::ScanFilterStatistics;
+use squalr_engine_api::structures::scanning::statistics::scan_statistics::ScanStatistics;
+use squalr_engine_api::structures::scanning::statistics::scan_strategy::ScanStrategy;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -30,6 +35,40 @@ impl ElementScannerFooterView {
     pub fn get_height(&self) -> f32 {
         64.0
     }
+
+    /// Lists how the most recent scan was dispatched and what it matched, which decides how the next scan is dispatched.
+    fn draw_scan_details(
+        user_interface: &mut Ui,
+        scan_statistics: &ScanStatistics,
+    ) {
+        let scan_filter_statistics = scan_statistics.get_scan_filter_statistics();
+        let matched_percent = scan_filter_statistics.get_matched_fraction().unwrap_or(0.0) * 100.0;
+        let run_lengths_text = ScanFilterStatistics::RUN_LENGTH_BUCKET_LABELS
+            .iter()
+            .zip(scan_filter_statistics.get_run_length_histogram())
+            .map(|(bucket_label, run_count)| format!("{}: {}", bucket_label, ElementScannerToolbarView::format_count(*run_count)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        user_interface.label(format!(
+            "Scan strategy: {} ({} planned, {} sparse, {} dense region scans)",
+            scan_statistics.get_dominant_strategy().get_name(),
+            ElementScannerToolbarView::format_count(scan_statistics.get_collection_count(ScanStrategy::Planned)),
+            ElementScannerToolbarView::format_count(scan_statistics.get_collection_count(ScanStrategy::Sparse)),
+            ElementScannerToolbarView::format_count(scan_statistics.get_collection_count(ScanStrategy::Dense)),
+        ));
+        user_interface.label(format!(
+            "Matched: {:.2}% of {} elements",
+            matched_percent,
+            ElementScannerToolbarView::format_count(scan_filter_statistics.get_scanned_element_count())
+        ));
+        user_interface.label(format!(
+            "Runs: {} (average length {:.1} elements)",
+            ElementScannerToolbarView::format_count(scan_filter_statistics.get_run_count()),
+            scan_filter_statistics.get_average_run_length()
+        ));
+        user_interface.label(format!("Run lengths: {}", run_lengths_text));
+    }
 }
 
 impl Widget for ElementScannerFooterView {
@@ -65,6 +104,43 @@ impl Widget for ElementScannerFooterView {
             None => return response,
         };
 
+        let bottom_row_builder = UiBuilder::new().max_rect(bottom_row);
+
+        let mut bottom_row_user_interface = user_interface.new_child(bottom_row_builder);
+
+        bottom_row_user_interface.centered_and_justified(|user_interface| {
+            let stats_response = user_interface.label(
+                RichText::new(&element_scanner_view_data.stats_string)
+                    .font(font_id.clone())
+                    .color(theme.foreground),
+            );
+
+            if let Some(scan_statistics) = &element_scanner_view_data.last_scan_statistics {
+                stats_response.on_hover_ui(|user_interface| Self::draw_scan_details(user_interface, scan_statistics));
+            }
+        });
+
+        // Infinite scroll has no pages to navigate, so show the visible rows instead.
+        if element_scanner_view_data.is_infinite_scroll {
+            let visible_row_range = &element_scanner_view_data.visible_row_range;
+            let visible_rows_text = if visible_row_range.is_empty() {
+                format!("rows 0 of {}", ElementScannerToolbarView::format_count(element_scanner_view_data.result_count))
+            } else {
+                format!(
+                    "rows {}\u{2013}{} of {}",
+                    ElementScannerToolbarView::format_count(visible_row_range.start.saturating_add(1)),
+                    ElementScannerToolbarView::format_count(visible_row_range.end),
+                    ElementScannerToolbarView::format_count(element_scanner_view_data.result_count)
+                )
+            };
+
+            user_interface
+                .painter()
+                .text(top_row.center(), Align2::CENTER_CENTER, visible_rows_text, font_id, theme.foreground);
+
+            return response;
+        }
+
         let border_width = 1.0;
         let page_box_width = 160.0;
         let page_box_height = 24.0;
@@ -191,18 +267,6 @@ impl Widget for ElementScannerFooterView {
             should_navigate_last_page = true;
         }
 
-        let bottom_row_builder = UiBuilder::new().max_rect(bottom_row);
-
-        let mut bottom_row_user_interface = user_interface.new_child(bottom_row_builder);
-
-        bottom_row_user_interface.centered_and_justified(|user_interface| {
-            user_interface.label(
-                RichText::new(&element_scanner_view_data.stats_string)
-                    .font(font_id.clone())
-                    .color(theme.foreground),
-            );
-        });
-
         drop(element_scanner_view_data);
 
         if should_navigate_first_page {
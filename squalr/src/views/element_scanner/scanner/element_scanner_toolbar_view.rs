@@ -1,23 +1,43 @@
 use crate::{
     app_context::AppContext,
+    models::command_palette::palette_command::PaletteCommand,
     ui::{
         draw::icon_draw::IconDraw,
         widgets::controls::{
-            button::Button, data_type_selector::data_type_selector_view::DataTypeSelectorView, data_value_box::data_value_box_view::DataValueBoxView,
-            scan_constraint_selector::scan_compare_type_selector_view::ScanCompareTypeSelectorView,
+            button::Button,
+            checkbox::Checkbox,
+            combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView},
+            data_type_selector::data_type_selector_view::DataTypeSelectorView,
+            data_value_box::data_value_box_view::DataValueBoxView,
+            scan_constraint_selector::{
+                floating_point_tolerance_selector_view::FloatingPointToleranceSelectorView, scan_compare_type_selector_view::ScanCompareTypeSelectorView,
+            },
         },
     },
-    views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData,
+    views::element_scanner::scanner::{element_scanner_view_state::ElementScannerViewState, view_data::element_scanner_view_data::ElementScannerViewData},
 };
-use eframe::egui::{Align, Layout, Response, RichText, Sense, Spinner, Ui, UiBuilder, Widget};
+use eframe::egui::{self, Align, Key, KeyboardShortcut, Layout, Modifiers, Popup, PopupCloseBehavior, Response, RichText, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, vec2};
 use squalr_engine_api::{
+    commands::scan::element_scan_preview::element_scan_preview_request::ElementScanPreviewRequest,
     dependency_injection::dependency::Dependency,
+    engine::protocol::engine_capabilities::EngineCapabilities,
     registries::symbols::symbol_registry::SymbolRegistry,
-    structures::scanning::comparisons::scan_compare_type::ScanCompareType,
+    structures::{
+        scanning::comparisons::{scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate},
+        snapshots::snapshot_scan_history::SnapshotScanHistoryItem,
+    },
 };
 use std::sync::Arc;
 
+/// An edit requested from a constraint row's buttons or menu, applied once the view data is no longer borrowed.
+enum ConstraintRowAction {
+    Add,
+    Duplicate(usize),
+    Move(usize, usize),
+    Remove(usize),
+}
+
 #[derive(Clone)]
 pub struct ElementScannerToolbarView {
     app_context: Arc<AppContext>,
@@ -25,6 +45,10 @@ pub struct ElementScannerToolbarView {
 }
 
 impl ElementScannerToolbarView {
+    /// Each bound of a range gets a narrower box than a single value, such that both fit in a constraint row.
+    const BETWEEN_VALUE_BOX_WIDTH: f32 = 144.0;
+    const SCAN_HISTORY_WIDTH: f32 = 212.0;
+
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let element_scanner_view_data = app_context
             .dependency_container
@@ -34,9 +58,71 @@ impl ElementScannerToolbarView {
             element_scanner_view_data,
         };
 
+        instance.register_commands();
+
         instance
     }
 
+    /// Registers the scan buttons with the command palette, disabled in the same states as the buttons.
+    fn register_commands(&self) {
+        let command_registry = &self.app_context.command_registry;
+        let get_scan_in_progress_reason = |element_scanner_view_data: Dependency<ElementScannerViewData>| {
+            move || {
+                element_scanner_view_data
+                    .read("Element scanner command disabled reason")
+                    .filter(|view_data| view_data.view_state == ElementScannerViewState::ScanInProgress)
+                    .map(|_view_data| "A scan is in progress.".to_string())
+            }
+        };
+
+        let element_scanner_view_data = self.element_scanner_view_data.clone();
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        command_registry.register(
+            PaletteCommand::new("element_scanner.new_scan", "Scan", "New Scan", move || {
+                ElementScannerViewData::reset_scan(element_scanner_view_data.clone(), engine_unprivileged_state.clone())
+            })
+            .with_disabled_reason(get_scan_in_progress_reason(self.element_scanner_view_data.clone())),
+        );
+
+        let element_scanner_view_data = self.element_scanner_view_data.clone();
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        command_registry.register(
+            PaletteCommand::new("element_scanner.start_scan", "Scan", "Start Scan", move || {
+                ElementScannerViewData::start_scan(element_scanner_view_data.clone(), engine_unprivileged_state.clone())
+            })
+            .with_disabled_reason(get_scan_in_progress_reason(self.element_scanner_view_data.clone())),
+        );
+
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        command_registry.register(
+            PaletteCommand::new("element_scanner.collect_values", "Scan", "Collect Values", move || {
+                ElementScannerViewData::collect_values(engine_unprivileged_state.clone())
+            })
+            .with_disabled_reason(get_scan_in_progress_reason(self.element_scanner_view_data.clone())),
+        );
+
+        let element_scanner_view_data = self.element_scanner_view_data.clone();
+        let element_scanner_view_data_for_reason = self.element_scanner_view_data.clone();
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        command_registry.register(
+            PaletteCommand::new("element_scanner.cancel_scan", "Scan", "Cancel Scan", move || {
+                ElementScannerViewData::cancel_scan(element_scanner_view_data.clone(), engine_unprivileged_state.clone())
+            })
+            .with_shortcut(KeyboardShortcut::new(Modifiers::NONE, Key::Escape))
+            .with_disabled_reason(move || {
+                element_scanner_view_data_for_reason
+                    .read("Element scanner cancel command disabled reason")
+                    .filter(|view_data| view_data.scan_task_id.is_some())
+                    .is_none()
+                    .then(|| "No scan is running.".to_string())
+            }),
+        );
+    }
+
     pub fn get_top_row_height(&self) -> f32 {
         34.0
     }
@@ -45,16 +131,54 @@ impl ElementScannerToolbarView {
         34.0
     }
 
-    pub fn get_height(&self) -> f32 {
+    /// Gets the height of the constraint rows area. Past half of the available height, the rows scroll instead of growing further.
+    pub fn get_constraint_area_height(
+        &self,
+        available_height: f32,
+    ) -> f32 {
         let item_count = match self
             .element_scanner_view_data
-            .read("Element scanner toolbar view get height")
+            .read("Element scanner toolbar view get constraint area height")
         {
             Some(element_scanner_view_data) => element_scanner_view_data.scan_values_and_constraints.len(),
             None => 1,
         };
+        let constraint_row_height = self.get_constraint_row_height();
+        let max_height = (available_height * 0.5 - self.get_top_row_height()).max(constraint_row_height);
+
+        (constraint_row_height * (item_count as f32)).min(max_height)
+    }
+
+    pub fn get_height(
+        &self,
+        available_height: f32,
+    ) -> f32 {
+        self.get_constraint_area_height(available_height) + self.get_top_row_height()
+    }
+
+    /// Formats a count with thousands separators, ie 2380 => "2,380".
+    pub fn format_count(count: u64) -> String {
+        let digits = count.to_string();
+        let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
 
-        self.get_constraint_row_height() * (item_count as f32) + self.get_top_row_height()
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                formatted.push(',');
+            }
+
+            formatted.push(digit);
+        }
+
+        formatted
+    }
+
+    /// Formats an entry of the scan history, ie "Scan 2: 58,112 results".
+    fn format_scan_history_item(scan_history_item: &SnapshotScanHistoryItem) -> String {
+        format!(
+            "Scan {}: {} results",
+            scan_history_item.get_scan_number(),
+            Self::format_count(scan_history_item.get_result_count())
+        )
     }
 }
 
@@ -64,9 +188,11 @@ impl Widget for ElementScannerToolbarView {
         user_interface: &mut Ui,
     ) -> Response {
         let theme = &self.app_context.theme;
-        let total_height = self.get_height();
+        let available_height = user_interface.available_height();
+        let total_height = self.get_height(available_height);
         let top_row_height = self.get_top_row_height();
         let constraint_row_height = self.get_constraint_row_height();
+        let constraint_area_height = self.get_constraint_area_height(available_height);
 
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(vec2(user_interface.available_width(), total_height), Sense::hover());
 
@@ -95,8 +221,10 @@ impl Widget for ElementScannerToolbarView {
         let mut should_collect_values = false;
         let mut should_start_scan = false;
         let mut should_cancel_scan = false;
-        let mut should_add_new_scan_constraint = false;
-        let mut remove_scan_constraint_index = 0;
+        let mut scan_number_to_revert = None;
+        let mut should_save_scan_snapshot = false;
+        let mut should_compare_scan_snapshot = false;
+        let mut constraint_row_action = None;
 
         let previous_data_type_id = element_scanner_view_data
             .selected_data_type
@@ -170,6 +298,181 @@ impl Widget for ElementScannerToolbarView {
                     }
                 }
 
+                // Advanced scan options.
+                let advanced_options_button = user_interface.add_sized(
+                    button_size,
+                    Button::new_from_theme(theme)
+                        .background_color(Color32::TRANSPARENT)
+                        .with_tooltip_text("Advanced scan options."),
+                );
+                IconDraw::draw(user_interface, advanced_options_button.rect, &theme.icon_library.icon_handle_common_properties);
+
+                Popup::menu(&advanced_options_button)
+                    .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                    .show(|user_interface| {
+                        let is_stride_scan_supported = self
+                            .app_context
+                            .engine_unprivileged_state
+                            .has_capabilities(EngineCapabilities::STRIDE_SCAN);
+
+                        user_interface.add_enabled_ui(is_stride_scan_supported, |user_interface| {
+                            user_interface.horizontal(|user_interface| {
+                                if user_interface
+                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(element_scanner_view_data.is_stride_scan_enabled))
+                                    .clicked()
+                                {
+                                    element_scanner_view_data.is_stride_scan_enabled = !element_scanner_view_data.is_stride_scan_enabled;
+                                }
+
+                                user_interface.label("Stride scan").on_hover_text(
+                                    "Find arrays of structs, where the value of the first constraint repeats every stride bytes. Results are the array base addresses.",
+                                );
+                            });
+                            user_interface.add_enabled_ui(element_scanner_view_data.is_stride_scan_enabled, |user_interface| {
+                                user_interface.horizontal(|user_interface| {
+                                    user_interface.label("Stride");
+                                    user_interface.add(
+                                        TextEdit::singleline(&mut element_scanner_view_data.stride_scan_stride)
+                                            .hint_text("0x150")
+                                            .desired_width(96.0),
+                                    );
+                                });
+                                user_interface.horizontal(|user_interface| {
+                                    user_interface.label("Minimum repeats");
+                                    user_interface.add(TextEdit::singleline(&mut element_scanner_view_data.stride_scan_minimum_repeat_count).desired_width(48.0));
+                                });
+                            });
+                        });
+                    });
+
+                // Scan snapshots, saving results to disk such that a later session can keep the results whose values changed since.
+                let scan_snapshot_button = user_interface.add_sized(
+                    button_size,
+                    Button::new_from_theme(theme)
+                        .background_color(Color32::TRANSPARENT)
+                        .with_tooltip_text("Save or compare against a scan snapshot."),
+                );
+                IconDraw::draw(user_interface, scan_snapshot_button.rect, &theme.icon_library.icon_handle_file_system_save);
+
+                Popup::menu(&scan_snapshot_button)
+                    .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                    .show(|user_interface| {
+                        let is_scan_snapshot_supported = self
+                            .app_context
+                            .engine_unprivileged_state
+                            .has_capabilities(EngineCapabilities::SCAN_SNAPSHOT_FILES);
+                        let is_scan_in_progress = element_scanner_view_data.view_state == ElementScannerViewState::ScanInProgress;
+
+                        user_interface.add_enabled_ui(is_scan_snapshot_supported, |user_interface| {
+                            user_interface.horizontal(|user_interface| {
+                                user_interface.add(
+                                    TextEdit::singleline(&mut element_scanner_view_data.scan_snapshot_file_path)
+                                        .hint_text("Snapshot file (.snap)")
+                                        .desired_width(176.0),
+                                );
+
+                                let has_scan_snapshot_file_path = !element_scanner_view_data.scan_snapshot_file_path.trim().is_empty();
+                                let save_scan_snapshot_button = user_interface.add_sized(
+                                    button_size,
+                                    Button::new_from_theme(theme)
+                                        .background_color(Color32::TRANSPARENT)
+                                        .disabled(!has_scan_snapshot_file_path || is_scan_in_progress || element_scanner_view_data.view_state != ElementScannerViewState::HasResults)
+                                        .with_tooltip_text("Save the results and their values."),
+                                );
+                                IconDraw::draw(user_interface, save_scan_snapshot_button.rect, &theme.icon_library.icon_handle_file_system_save);
+
+                                if save_scan_snapshot_button.clicked() {
+                                    should_save_scan_snapshot = true;
+                                }
+
+                                let compare_scan_snapshot_button = user_interface.add_sized(
+                                    button_size,
+                                    Button::new_from_theme(theme)
+                                        .background_color(Color32::TRANSPARENT)
+                                        .disabled(!has_scan_snapshot_file_path || is_scan_in_progress)
+                                        .with_tooltip_text("Replace the results with those saved, and compare them against their saved values."),
+                                );
+                                IconDraw::draw(user_interface, compare_scan_snapshot_button.rect, &theme.icon_library.icon_handle_file_system_open_folder);
+
+                                if compare_scan_snapshot_button.clicked() {
+                                    should_compare_scan_snapshot = true;
+                                }
+                            });
+                            user_interface.horizontal(|user_interface| {
+                                if user_interface
+                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(element_scanner_view_data.is_scan_snapshot_compare_keeping_matches))
+                                    .clicked()
+                                {
+                                    element_scanner_view_data.is_scan_snapshot_compare_keeping_matches =
+                                        !element_scanner_view_data.is_scan_snapshot_compare_keeping_matches;
+                                }
+
+                                user_interface
+                                    .label("Keep values that match the snapshot")
+                                    .on_hover_text("Compare keeps the results whose values still match the saved values, rather than those that changed.");
+                            });
+                        });
+
+                        if let Some(scan_snapshot_status) = &element_scanner_view_data.scan_snapshot_status {
+                            user_interface.label(
+                                RichText::new(scan_snapshot_status)
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        }
+                    });
+
+                // Scan history, listing the current scan last. Selecting an earlier scan reverts to its results.
+                if let Some(current_scan_history_item) = element_scanner_view_data.scan_history_items.last().copied() {
+                    let scan_history_label = Self::format_scan_history_item(&current_scan_history_item);
+                    let scan_history_items = &element_scanner_view_data.scan_history_items;
+                    let scan_history_width = Self::SCAN_HISTORY_WIDTH;
+                    let scan_history_text = scan_history_items
+                        .iter()
+                        .map(Self::format_scan_history_item)
+                        .collect::<Vec<String>>()
+                        .join(" → ");
+
+                    user_interface.add_space(8.0);
+                    user_interface
+                        .add(
+                            ComboBoxView::new(
+                                self.app_context.clone(),
+                                &scan_history_label,
+                                "element_scanner_scan_history",
+                                None,
+                                |popup_user_interface: &mut Ui, should_close: &mut bool| {
+                                    popup_user_interface.vertical(|user_interface| {
+                                        for scan_history_item in scan_history_items.iter().rev() {
+                                            let scan_history_item_label = Self::format_scan_history_item(scan_history_item);
+
+                                            if user_interface
+                                                .add(ComboBoxItemView::new(
+                                                    self.app_context.clone(),
+                                                    &scan_history_item_label,
+                                                    None,
+                                                    scan_history_width,
+                                                ))
+                                                .clicked()
+                                            {
+                                                if scan_history_item.get_scan_number() != current_scan_history_item.get_scan_number() {
+                                                    scan_number_to_revert = Some(scan_history_item.get_scan_number());
+                                                }
+
+                                                *should_close = true;
+                                            }
+                                        }
+                                    });
+                                },
+                            )
+                            .width(scan_history_width),
+                        )
+                        .on_hover_text(format!(
+                            "{}\nSelecting an earlier scan reverts to its results, discarding the scans after it.",
+                            scan_history_text
+                        ));
+                }
+
                 user_interface.with_layout(Layout::right_to_left(Align::Center), |ui| {
                     if let Some(message) = &element_scanner_view_data.last_error_message {
                         ui.label(
@@ -192,6 +495,32 @@ impl Widget for ElementScannerToolbarView {
                         );
                         ui.add_space(8.0);
                     }
+
+                    if element_scanner_view_data.view_state == ElementScannerViewState::HasResults {
+                        let preview_text = if let Some(preview_result_count) = element_scanner_view_data.scan_preview_result_count {
+                            Some(format!(
+                                "would keep {} of {}",
+                                Self::format_count(preview_result_count),
+                                Self::format_count(element_scanner_view_data.scan_preview_current_result_count)
+                            ))
+                        } else if element_scanner_view_data.scan_preview_current_result_count > ElementScanPreviewRequest::MAX_PREVIEW_RESULT_COUNT {
+                            Some("Preview disabled for large result sets".to_string())
+                        } else {
+                            None
+                        };
+
+                        if let Some(preview_text) = preview_text {
+                            ui.label(
+                                RichText::new(preview_text)
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                            ui.add_space(8.0);
+                        } else if element_scanner_view_data.is_scan_preview_pending {
+                            ui.add(Spinner::new().color(theme.foreground));
+                            ui.add_space(8.0);
+                        }
+                    }
                 });
             });
         });
@@ -210,80 +539,187 @@ impl Widget for ElementScannerToolbarView {
 
         for scan_value_and_constraint in element_scanner_view_data.scan_values_and_constraints.iter_mut() {
             if data_type_changed || !supported_formats.contains(&scan_value_and_constraint.current_scan_value.get_anonymous_value_string_format()) {
-                scan_value_and_constraint
-                    .current_scan_value
-                    .set_anonymous_value_string_format(default_format);
+                scan_value_and_constraint.set_anonymous_value_string_format(default_format);
             }
         }
 
         let selected_data_type = &element_scanner_view_data.selected_data_type.clone();
+        let is_floating_point = symbol_registry.is_floating_point(selected_data_type);
 
         // Constraint rows.
-        for index in 0..element_scanner_view_data.scan_values_and_constraints.len() {
-            let scan_values_and_constraint = &mut element_scanner_view_data.scan_values_and_constraints[index];
-
-            toolbar_user_interface.allocate_ui(vec2(toolbar_user_interface.available_width(), constraint_row_height), |user_interface| {
-                user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
-                    // Scan compare type selector.
-                    user_interface.add_space(8.0);
-                    user_interface.add(ScanCompareTypeSelectorView::new(
-                        self.app_context.clone(),
-                        &mut scan_values_and_constraint.selected_scan_compare_type,
-                        &scan_values_and_constraint.menu_id,
-                    ));
-                    // Scan value (primary).
-                    match &scan_values_and_constraint.selected_scan_compare_type {
-                        ScanCompareType::Relative(_) => {
-                            // Nothing to display for relative scans.
-                        }
-                        _ => {
-                            let data_type_ref = selected_data_type.clone();
-
+        let constraint_count = element_scanner_view_data.scan_values_and_constraints.len();
+        let is_first_scan = element_scanner_view_data.is_first_scan();
+
+        ScrollArea::vertical()
+            .id_salt("element_scanner_constraint_rows")
+            .max_height(constraint_area_height)
+            .auto_shrink([false, true])
+            .show(&mut toolbar_user_interface, |toolbar_user_interface| {
+                for index in 0..constraint_count {
+                    let scan_values_and_constraint = &mut element_scanner_view_data.scan_values_and_constraints[index];
+
+                    toolbar_user_interface.allocate_ui(vec2(toolbar_user_interface.available_width(), constraint_row_height), |user_interface| {
+                        user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                            // Scan compare type selector.
                             user_interface.add_space(8.0);
-                            user_interface.add(DataValueBoxView::new(
-                                self.app_context.clone(),
-                                &mut scan_values_and_constraint.current_scan_value,
-                                &data_type_ref,
-                                false,
-                                true,
-                                "Enter a scan value...",
-                                &format!("data_value_box_scan_value_index_{}", index),
-                            ));
-                        }
-                    }
-
-                    if index == 0 {
-                        let add_new_scan_constraint_button = user_interface.add_sized(
-                            button_size,
-                            Button::new_from_theme(theme)
-                                .background_color(Color32::TRANSPARENT)
-                                .with_tooltip_text("Add new scan constraint."),
-                        );
-                        IconDraw::draw(user_interface, add_new_scan_constraint_button.rect, &theme.icon_library.icon_handle_common_add);
-
-                        if add_new_scan_constraint_button.clicked() {
-                            should_add_new_scan_constraint = true;
-                        }
-                    } else {
-                        let remove_scan_constraint_button = user_interface.add_sized(
-                            button_size,
-                            Button::new_from_theme(theme)
-                                .background_color(Color32::TRANSPARENT)
-                                .with_tooltip_text("Add new scan constraint."),
-                        );
-                        IconDraw::draw(
-                            user_interface,
-                            remove_scan_constraint_button.rect,
-                            &theme.icon_library.icon_handle_common_remove,
-                        );
 
-                        if remove_scan_constraint_button.clicked() {
-                            remove_scan_constraint_index = index;
-                        }
-                    }
-                });
+                            let scan_compare_type_selector = ScanCompareTypeSelectorView::new(
+                                self.app_context.clone(),
+                                &mut scan_values_and_constraint.selected_scan_compare_type,
+                                &scan_values_and_constraint.menu_id,
+                            );
+
+                            if is_first_scan {
+                                user_interface.add(scan_compare_type_selector.first_scan(&mut scan_values_and_constraint.is_unknown_initial_value));
+                            } else {
+                                user_interface.add(scan_compare_type_selector);
+                            }
+
+                            // Scan value (primary).
+                            match &scan_values_and_constraint.selected_scan_compare_type {
+                                _ if is_first_scan && scan_values_and_constraint.is_unknown_initial_value => {
+                                    // Nothing to display when collecting every value.
+                                }
+                                ScanCompareType::Relative(_) => {
+                                    // Nothing to display for relative scans.
+                                }
+                                ScanCompareType::Immediate(ScanCompareTypeImmediate::Between) => {
+                                    let data_type_ref = selected_data_type.clone();
+
+                                    user_interface.add_space(8.0);
+                                    user_interface.add(
+                                        DataValueBoxView::new(
+                                            self.app_context.clone(),
+                                            &mut scan_values_and_constraint.current_scan_value,
+                                            &data_type_ref,
+                                            false,
+                                            true,
+                                            "Lower bound...",
+                                            &format!("data_value_box_scan_value_{}", scan_values_and_constraint.menu_id),
+                                        )
+                                        .width(Self::BETWEEN_VALUE_BOX_WIDTH),
+                                    );
+                                    user_interface.label("to");
+                                    user_interface.add(
+                                        DataValueBoxView::new(
+                                            self.app_context.clone(),
+                                            &mut scan_values_and_constraint.current_scan_value_upper_bound,
+                                            &data_type_ref,
+                                            false,
+                                            true,
+                                            "Upper bound...",
+                                            &format!("data_value_box_scan_value_upper_bound_{}", scan_values_and_constraint.menu_id),
+                                        )
+                                        .width(Self::BETWEEN_VALUE_BOX_WIDTH),
+                                    );
+                                }
+                                _ => {
+                                    let data_type_ref = selected_data_type.clone();
+
+                                    user_interface.add_space(8.0);
+                                    user_interface.add(DataValueBoxView::new(
+                                        self.app_context.clone(),
+                                        &mut scan_values_and_constraint.current_scan_value,
+                                        &data_type_ref,
+                                        false,
+                                        true,
+                                        "Enter a scan value...",
+                                        &format!("data_value_box_scan_value_{}", scan_values_and_constraint.menu_id),
+                                    )
+                                    .allow_value_lists(true));
+                                }
+                            }
+
+                            // Floating point tolerance, for the comparisons that test values for equality.
+                            let is_tolerance_compared = matches!(
+                                scan_values_and_constraint.selected_scan_compare_type,
+                                ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal)
+                                    | ScanCompareType::Immediate(ScanCompareTypeImmediate::NotEqual)
+                                    | ScanCompareType::Delta(_)
+                            );
+
+                            if is_floating_point && is_tolerance_compared && !(is_first_scan && scan_values_and_constraint.is_unknown_initial_value) {
+                                user_interface.add_space(8.0);
+                                user_interface.add(FloatingPointToleranceSelectorView::new(
+                                    self.app_context.clone(),
+                                    &mut scan_values_and_constraint.floating_point_tolerance,
+                                    &format!("floating_point_tolerance_selector_{}", scan_values_and_constraint.menu_id),
+                                ));
+                            }
+
+                            if index == 0 {
+                                let add_new_scan_constraint_button = user_interface.add_sized(
+                                    button_size,
+                                    Button::new_from_theme(theme)
+                                        .background_color(Color32::TRANSPARENT)
+                                        .with_tooltip_text("Add new scan constraint."),
+                                );
+                                IconDraw::draw(user_interface, add_new_scan_constraint_button.rect, &theme.icon_library.icon_handle_common_add);
+
+                                if add_new_scan_constraint_button.clicked() {
+                                    constraint_row_action = Some(ConstraintRowAction::Add);
+                                }
+                            } else {
+                                let remove_scan_constraint_button = user_interface.add_sized(
+                                    button_size,
+                                    Button::new_from_theme(theme)
+                                        .background_color(Color32::TRANSPARENT)
+                                        .with_tooltip_text("Remove scan constraint."),
+                                );
+                                IconDraw::draw(
+                                    user_interface,
+                                    remove_scan_constraint_button.rect,
+                                    &theme.icon_library.icon_handle_common_remove,
+                                );
+
+                                if remove_scan_constraint_button.clicked() {
+                                    constraint_row_action = Some(ConstraintRowAction::Remove(index));
+                                }
+                            }
+
+                            // Row options.
+                            let row_options_button = user_interface.add_sized(
+                                button_size,
+                                Button::new_from_theme(theme)
+                                    .background_color(Color32::TRANSPARENT)
+                                    .with_tooltip_text("Scan constraint options."),
+                            );
+                            IconDraw::draw(user_interface, row_options_button.rect, &theme.icon_library.icon_handle_common_properties);
+
+                            Popup::menu(&row_options_button).show(|user_interface| {
+                                if user_interface.button("Duplicate").clicked() {
+                                    constraint_row_action = Some(ConstraintRowAction::Duplicate(index));
+                                    user_interface.close();
+                                }
+
+                                if user_interface
+                                    .add_enabled(index > 0, egui::Button::new("Move up"))
+                                    .clicked()
+                                {
+                                    constraint_row_action = Some(ConstraintRowAction::Move(index, index - 1));
+                                    user_interface.close();
+                                }
+
+                                if user_interface
+                                    .add_enabled(index + 1 < constraint_count, egui::Button::new("Move down"))
+                                    .clicked()
+                                {
+                                    constraint_row_action = Some(ConstraintRowAction::Move(index, index + 1));
+                                    user_interface.close();
+                                }
+
+                                if user_interface
+                                    .add_enabled(constraint_count > 1, egui::Button::new("Remove"))
+                                    .clicked()
+                                {
+                                    constraint_row_action = Some(ConstraintRowAction::Remove(index));
+                                    user_interface.close();
+                                }
+                            });
+                        });
+                    });
+                }
             });
-        }
 
         drop(element_scanner_view_data);
 
@@ -295,10 +731,31 @@ impl Widget for ElementScannerToolbarView {
             ElementScannerViewData::cancel_scan(self.element_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
         } else if should_start_scan {
             ElementScannerViewData::start_scan(self.element_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
-        } else if should_add_new_scan_constraint {
-            ElementScannerViewData::add_constraint(self.element_scanner_view_data.clone());
-        } else if remove_scan_constraint_index > 0 {
-            ElementScannerViewData::remove_constraint(self.element_scanner_view_data.clone(), remove_scan_constraint_index);
+        } else if should_save_scan_snapshot {
+            ElementScannerViewData::save_scan_snapshot(self.element_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        } else if should_compare_scan_snapshot {
+            ElementScannerViewData::compare_scan_snapshot(self.element_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        } else if let Some(scan_number) = scan_number_to_revert {
+            ElementScannerViewData::revert_scan(
+                self.element_scanner_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                scan_number,
+            );
+        } else if let Some(constraint_row_action) = constraint_row_action {
+            match constraint_row_action {
+                ConstraintRowAction::Add => ElementScannerViewData::add_constraint(self.element_scanner_view_data.clone()),
+                ConstraintRowAction::Duplicate(index) => ElementScannerViewData::duplicate_constraint(self.element_scanner_view_data.clone(), index),
+                ConstraintRowAction::Move(index, target_index) => {
+                    ElementScannerViewData::move_constraint(self.element_scanner_view_data.clone(), index, target_index)
+                }
+                ConstraintRowAction::Remove(index) => ElementScannerViewData::remove_constraint(self.element_scanner_view_data.clone(), index),
+            }
+        }
+
+        if let Some(preview_delay) =
+            ElementScannerViewData::update_scan_preview(self.element_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone())
+        {
+            user_interface.ctx().request_repaint_after(preview_delay);
         }
 
         response
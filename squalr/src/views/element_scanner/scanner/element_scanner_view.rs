@@ -37,6 +37,7 @@ impl ElementScannerView {
             element_scanner_results_view_data.clone(),
             app_context.engine_unprivileged_state.clone(),
         );
+        ElementScannerResultsViewData::register_refresh_job(element_scanner_results_view_data.clone(), &app_context.refresh_scheduler);
         let element_scanner_toolbar_view = ElementScannerToolbarView::new(app_context.clone());
         let element_scanner_results_view = ElementScannerResultsView::new(app_context.clone());
         let element_scanner_footer_view = ElementScannerFooterView::new(app_context.clone());
@@ -103,11 +104,15 @@ mod tests {
     use squalr_engine_api::engine::engine_api_unprivileged_bindings::EngineApiUnprivilegedBindings;
     use squalr_engine_api::commands::privileged_command::PrivilegedCommand;
     use squalr_engine_api::commands::privileged_command_response::PrivilegedCommandResponse;
+    use squalr_engine_api::commands::scan::scan_command::ScanCommand;
     use squalr_engine_api::commands::unprivileged_command::UnprivilegedCommand;
     use squalr_engine_api::commands::unprivileged_command_response::UnprivilegedCommandResponse;
     use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+    use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+    use squalr_engine_api::engine::protocol::engine_protocol_status::EngineProtocolStatus;
+    use squalr_engine_api::engine::protocol::engine_protocol_version::EngineProtocolVersion;
     use squalr_engine_api::events::engine_event::EngineEvent;
-    use std::sync::RwLock;
+    use std::sync::{Mutex, RwLock};
 
     struct MockUnprivilegedBindings;
 
@@ -136,6 +141,74 @@ mod tests {
         }
     }
 
+    /// Mimics an engine from an older minor version, which predates scan previews.
+    struct MockLegacyUnprivilegedBindings;
+
+    impl EngineApiUnprivilegedBindings for MockLegacyUnprivilegedBindings {
+        fn dispatch_privileged_command(
+            &self,
+            _engine_command: PrivilegedCommand,
+            _callback: Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            panic!("Mock bindings: no privileged commands should be sent to a legacy engine in this test")
+        }
+
+        fn dispatch_unprivileged_command(
+            &self,
+            _engine_command: UnprivilegedCommand,
+            _engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+            _callback: Box<dyn FnOnce(UnprivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            Err("Mock bindings: unprivileged commands not supported in this test".to_string())
+        }
+
+        fn subscribe_to_engine_events(&self) -> Result<crossbeam_channel::Receiver<EngineEvent>, String> {
+            let (_sender, receiver) = unbounded();
+            Ok(receiver)
+        }
+
+        fn get_protocol_status(&self) -> EngineProtocolStatus {
+            EngineProtocolStatus::Connected {
+                remote_version: EngineProtocolVersion::new(EngineProtocolVersion::CURRENT.major, 0),
+                capabilities: EngineCapabilities::POINTER_SCAN | EngineCapabilities::STRUCT_SCAN,
+            }
+        }
+    }
+
+    /// Records privileged commands without answering them, such that tests can assert which commands the UI sent.
+    struct MockRecordingUnprivilegedBindings {
+        dispatched_privileged_commands: Arc<Mutex<Vec<PrivilegedCommand>>>,
+    }
+
+    impl EngineApiUnprivilegedBindings for MockRecordingUnprivilegedBindings {
+        fn dispatch_privileged_command(
+            &self,
+            engine_command: PrivilegedCommand,
+            _callback: Box<dyn FnOnce(PrivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            self.dispatched_privileged_commands
+                .lock()
+                .unwrap()
+                .push(engine_command);
+
+            Ok(())
+        }
+
+        fn dispatch_unprivileged_command(
+            &self,
+            _engine_command: UnprivilegedCommand,
+            _engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+            _callback: Box<dyn FnOnce(UnprivilegedCommandResponse) + Send + Sync + 'static>,
+        ) -> Result<(), String> {
+            Err("Mock bindings: unprivileged commands not supported in this test".to_string())
+        }
+
+        fn subscribe_to_engine_events(&self) -> Result<crossbeam_channel::Receiver<EngineEvent>, String> {
+            let (_sender, receiver) = unbounded();
+            Ok(receiver)
+        }
+    }
+
     fn run_frame_with_input(
         ctx: &eframe::egui::Context,
         element_scanner_view: ElementScannerView,
@@ -153,6 +226,130 @@ mod tests {
         let _ = ctx.end_frame();
     }
 
+    fn press_key(
+        key: Key,
+        modifiers: eframe::egui::Modifiers,
+    ) -> Vec<eframe::egui::Event> {
+        [true, false]
+            .into_iter()
+            .map(|pressed| eframe::egui::Event::Key {
+                key,
+                pressed,
+                repeat: false,
+                modifiers,
+                physical_key: None,
+            })
+            .collect()
+    }
+
+    /// Runs a frame with the given events followed by an idle frame, as focus moved backwards is only gained on the next frame.
+    /// Returns the widget events reported by both frames, which are what screen readers announce.
+    fn run_keyboard_frames(
+        ctx: &eframe::egui::Context,
+        element_scanner_view: &ElementScannerView,
+        events: Vec<eframe::egui::Event>,
+    ) -> Vec<eframe::egui::output::OutputEvent> {
+        let mut output_events = vec![];
+
+        for events in [events, vec![]] {
+            ctx.begin_pass(eframe::egui::RawInput {
+                screen_rect: Some(eframe::egui::Rect::from_min_size(
+                    eframe::egui::pos2(0.0, 0.0),
+                    eframe::egui::vec2(800.0, 600.0),
+                )),
+                modifiers: events
+                    .iter()
+                    .find_map(|event| match event {
+                        eframe::egui::Event::Key { modifiers, .. } => Some(*modifiers),
+                        _ => None,
+                    })
+                    .unwrap_or_default(),
+                events,
+                ..Default::default()
+            });
+            eframe::egui::CentralPanel::default().show(ctx, |ui| {
+                ui.add(element_scanner_view.clone());
+            });
+            output_events.extend(ctx.end_pass().platform_output.events);
+        }
+
+        output_events
+    }
+
+    #[test]
+    fn first_scan_can_be_started_with_the_keyboard_only() {
+        let ctx = eframe::egui::Context::default();
+        let theme = Arc::new(Theme::new(&ctx));
+        let docking_manager = Arc::new(RwLock::new(DockingManager::new(DockNode::Window {
+            window_identifier: "dummy".to_string(),
+            is_visible: true,
+        })));
+        let dispatched_privileged_commands = Arc::new(Mutex::new(Vec::new()));
+        let engine_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(MockRecordingUnprivilegedBindings {
+            dispatched_privileged_commands: dispatched_privileged_commands.clone(),
+        })));
+        let app_context = Arc::new(AppContext::new(ctx.clone(), theme, docking_manager, engine_state));
+
+        app_context.dependency_container.register(StructViewerViewData::new());
+        app_context.dependency_container.register(MemoryViewerViewData::new());
+        app_context.dependency_container.register(DisassemblerViewData::new());
+        app_context.dependency_container.register(PointerScannerViewData::new());
+
+        let element_scanner_view = ElementScannerView::new(app_context.clone());
+        let dep = app_context
+            .dependency_container
+            .get_dependency::<ElementScannerViewData>();
+
+        if let Some(mut view_data) = dep.try_write("Seed scan value for keyboard first scan test") {
+            view_data.scan_values_and_constraints[0]
+                .current_scan_value
+                .set_anonymous_value_string(String::new());
+        }
+
+        // Tab forwards through the toolbar until the scan value box of the first constraint row has focus, then type a value.
+        let reached_scan_value_box = (0..16).any(|_| {
+            run_keyboard_frames(&ctx, &element_scanner_view, press_key(Key::Tab, eframe::egui::Modifiers::NONE))
+                .iter()
+                .any(|output_event| {
+                    matches!(output_event, eframe::egui::output::OutputEvent::FocusGained(widget_info)
+                        if widget_info.typ == eframe::egui::WidgetType::TextEdit)
+                })
+        });
+
+        assert!(reached_scan_value_box, "Tab never reached the scan value box.");
+
+        run_keyboard_frames(&ctx, &element_scanner_view, vec![eframe::egui::Event::Text("42".to_string())]);
+
+        // Tab backwards to the start scan button, which announces itself by its tooltip, and activate it with Enter.
+        let reached_start_scan_button = (0..16).any(|_| {
+            run_keyboard_frames(&ctx, &element_scanner_view, press_key(Key::Tab, eframe::egui::Modifiers::SHIFT))
+                .iter()
+                .any(|output_event| {
+                    matches!(output_event, eframe::egui::output::OutputEvent::FocusGained(widget_info)
+                        if widget_info.label.as_deref() == Some("Start scan."))
+                })
+        });
+
+        assert!(reached_start_scan_button, "Shift+Tab never reached the start scan button.");
+
+        run_keyboard_frames(&ctx, &element_scanner_view, press_key(Key::Enter, eframe::egui::Modifiers::NONE));
+
+        let data = dep.read("Assert keyboard first scan value").expect("read view data");
+        assert_eq!(
+            data.scan_values_and_constraints[0]
+                .current_scan_value
+                .get_anonymous_value_string(),
+            "42"
+        );
+        assert!(
+            dispatched_privileged_commands
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|command| matches!(command, PrivilegedCommand::Scan(ScanCommand::New { .. })))
+        );
+    }
+
     #[test]
     fn escape_cancels_scan_and_does_not_hang() {
         let ctx = eframe::egui::Context::default();
@@ -211,4 +408,47 @@ mod tests {
         ));
         assert_eq!(data.last_error_message.as_deref(), Some("Scan canceled."));
     }
+
+    #[test]
+    fn scan_preview_is_skipped_when_engine_lacks_capability() {
+        let create_view_data = |engine_state: Arc<EngineUnprivilegedState>| {
+            let app_context = Arc::new(AppContext::new(
+                eframe::egui::Context::default(),
+                Arc::new(Theme::new(&eframe::egui::Context::default())),
+                Arc::new(RwLock::new(DockingManager::new(DockNode::Window {
+                    window_identifier: "dummy".to_string(),
+                    is_visible: true,
+                }))),
+                engine_state,
+            ));
+            let dep = app_context
+                .dependency_container
+                .register(ElementScannerViewData::new());
+
+            if let Some(mut view_data) = dep.try_write("Seed scan state for scan preview capability test") {
+                view_data.view_state = crate::views::element_scanner::scanner::element_scanner_view_state::ElementScannerViewState::HasResults;
+                view_data.scan_values_and_constraints[0]
+                    .current_scan_value
+                    .set_anonymous_value_string("42".to_string());
+            }
+
+            dep
+        };
+
+        let engine_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(MockUnprivilegedBindings)));
+        let dep = create_view_data(engine_state.clone());
+
+        assert!(ElementScannerViewData::update_scan_preview(dep, engine_state).is_some());
+
+        let legacy_engine_state = EngineUnprivilegedState::new(Arc::new(RwLock::new(MockLegacyUnprivilegedBindings)));
+        let legacy_dep = create_view_data(legacy_engine_state.clone());
+
+        assert!(ElementScannerViewData::update_scan_preview(legacy_dep.clone(), legacy_engine_state).is_none());
+
+        let data = legacy_dep
+            .read("Assert scan preview skipped for legacy engine")
+            .expect("read view data");
+        assert!(!data.is_scan_preview_pending);
+        assert!(data.scan_preview_signature.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 use squalr_engine_api::structures::{
+    data_types::floating_point_tolerance::FloatingPointTolerance,
     data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType},
     scanning::comparisons::{scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate},
 };
@@ -7,6 +8,12 @@ use squalr_engine_api::structures::{
 pub struct ElementScannerValueViewData {
     pub selected_scan_compare_type: ScanCompareType,
     pub current_scan_value: AnonymousValueString,
+    /// The inclusive upper bound when scanning for values between two bounds, where the current scan value is the lower bound.
+    pub current_scan_value_upper_bound: AnonymousValueString,
+    /// Whether a first scan should collect every value rather than compare, such that relative scans can narrow them down after.
+    pub is_unknown_initial_value: bool,
+    /// The tolerance used when comparing floating point values, ie exact, within 0.001, or by integer part alone.
+    pub floating_point_tolerance: FloatingPointTolerance,
     pub menu_id: String,
 }
 
@@ -15,7 +22,26 @@ impl ElementScannerValueViewData {
         Self {
             selected_scan_compare_type: ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
             current_scan_value: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            current_scan_value_upper_bound: AnonymousValueString::new(String::new(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            is_unknown_initial_value: false,
+            floating_point_tolerance: FloatingPointTolerance::default(),
             menu_id,
         }
     }
+
+    /// Sets the format of both the value and the upper bound, such that the bounds of a range are always entered alike.
+    pub fn set_anonymous_value_string_format(
+        &mut self,
+        anonymous_value_string_format: AnonymousValueStringFormat,
+    ) {
+        self.current_scan_value
+            .set_anonymous_value_string_format(anonymous_value_string_format);
+        self.current_scan_value_upper_bound
+            .set_anonymous_value_string_format(anonymous_value_string_format);
+    }
+
+    /// Gets whether this row scans for values between two bounds.
+    pub fn is_between(&self) -> bool {
+        self.selected_scan_compare_type == ScanCompareType::Immediate(ScanCompareTypeImmediate::Between)
+    }
 }
@@ -1,33 +1,51 @@
+use crate::models::appearance::appearance_settings::AppearanceSettings;
+use crate::models::workspace::workspace_scan_constraints::WorkspaceScanConstraints;
 use crate::views::element_scanner::scanner::{
-    element_scanner_view_state::ElementScannerViewState, view_data::element_scanner_value_view_data::ElementScannerValueViewData,
+    element_scanner_view_state::ElementScannerViewState,
+    view_data::{element_scanner_value_view_data::ElementScannerValueViewData, scan_value_expression::ScanValueExpression},
 };
 use squalr_engine_api::{
     commands::{
         privileged_command_request::PrivilegedCommandRequest,
         scan::{
             collect_values::scan_collect_values_request::ScanCollectValuesRequest, element_scan::element_scan_request::ElementScanRequest,
-            new::scan_new_request::ScanNewRequest,
+            element_scan_preview::element_scan_preview_request::ElementScanPreviewRequest, history_list::scan_history_list_request::ScanHistoryListRequest,
+            history_revert::scan_history_revert_request::ScanHistoryRevertRequest, new::scan_new_request::ScanNewRequest,
+            snapshot_compare::scan_snapshot_compare_request::ScanSnapshotCompareRequest, snapshot_save::scan_snapshot_save_request::ScanSnapshotSaveRequest,
+            stride_scan::stride_scan_request::StrideScanRequest,
         },
         trackable_tasks::cancel::trackable_tasks_cancel_request::TrackableTasksCancelRequest,
     },
     dependency_injection::dependency::Dependency,
-    engine::engine_unprivileged_state::EngineUnprivilegedState,
+    engine::{
+        engine_event_listeners::EngineEventSubscription, engine_unprivileged_state::EngineUnprivilegedState, protocol::engine_capabilities::EngineCapabilities,
+    },
     events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent,
     events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent,
     registries::symbols::symbol_registry::SymbolRegistry,
     structures::{
-        data_types::{built_in_types::i32::data_type_i32::DataTypeI32, data_type_ref::DataTypeRef},
-        data_values::anonymous_value_string_format::AnonymousValueStringFormat,
+        data_types::{
+            built_in_types::{aob::data_type_aob::DataTypeAob, i32::data_type_i32::DataTypeI32},
+            data_type_error::DataTypeError,
+            data_type_ref::DataTypeRef,
+            floating_point_tolerance::FloatingPointTolerance,
+        },
+        data_values::{anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat},
         scanning::{
-            comparisons::{scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate},
+            comparisons::{
+                scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate, scan_compare_type_relative::ScanCompareTypeRelative,
+            },
             constraints::anonymous_scan_constraint::AnonymousScanConstraint,
         },
+        snapshots::{scan_snapshot_file::ScanSnapshotFile, snapshot_scan_history::SnapshotScanHistoryItem},
+        tasks::trackable_task_handle::TrackableTaskHandle,
     },
 };
 use std::{
-    sync::{Arc, OnceLock},
+    path::PathBuf,
+    sync::Arc,
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 #[derive(Clone)]
@@ -36,14 +54,36 @@ pub struct ElementScannerViewData {
     pub active_display_format: AnonymousValueStringFormat,
     pub view_state: ElementScannerViewState,
     pub scan_values_and_constraints: Vec<ElementScannerValueViewData>,
+    /// Incremented for each constraint row created, such that menu ids stay unique as rows are duplicated, moved, and removed.
+    pub next_constraint_id: u64,
     pub scan_progress: f32,
     pub scan_task_id: Option<String>,
     pub last_error_message: Option<String>,
+    pub scan_preview_result_count: Option<u64>,
+    pub scan_preview_current_result_count: u64,
+    pub is_scan_preview_pending: bool,
+    pub scan_preview_generation: u64,
+    pub scan_preview_signature: String,
+    pub scan_preview_edit_time: Option<Instant>,
+    /// Keeps the engine event listeners for scan progress and completion registered for as long as this view data lives.
+    pub event_subscriptions: Vec<EngineEventSubscription>,
+    /// Whether scans search for arrays of structs, where the first constraint's value repeats at a fixed stride.
+    pub is_stride_scan_enabled: bool,
+    pub stride_scan_stride: String,
+    pub stride_scan_minimum_repeat_count: String,
+    /// The scans that the current results can be reverted to, oldest first, followed by the current scan.
+    pub scan_history_items: Vec<SnapshotScanHistoryItem>,
+    /// The file that scan results are saved to, or compared against, such that values can be compared across sessions.
+    pub scan_snapshot_file_path: String,
+    /// Whether comparing against a saved snapshot keeps the results whose values still match, rather than those that changed.
+    pub is_scan_snapshot_compare_keeping_matches: bool,
+    /// The outcome of the last scan snapshot save or comparison.
+    pub scan_snapshot_status: Option<String>,
 }
 
 impl ElementScannerViewData {
-    const MAX_CONSTRAINTS: usize = 5;
     const SCAN_TIMEOUT_MS: u64 = 30000;
+    const SCAN_PREVIEW_DEBOUNCE_MS: u64 = 400;
 
     pub fn new() -> Self {
         Self {
@@ -51,9 +91,24 @@ impl ElementScannerViewData {
             active_display_format: AnonymousValueStringFormat::Decimal,
             view_state: ElementScannerViewState::NoResults,
             scan_values_and_constraints: vec![ElementScannerValueViewData::new(Self::create_menu_id(0))],
+            next_constraint_id: 1,
             scan_progress: 0.0,
             scan_task_id: None,
             last_error_message: None,
+            scan_preview_result_count: None,
+            scan_preview_current_result_count: 0,
+            is_scan_preview_pending: false,
+            scan_preview_generation: 0,
+            scan_preview_signature: String::new(),
+            scan_preview_edit_time: None,
+            event_subscriptions: Vec::new(),
+            is_stride_scan_enabled: false,
+            stride_scan_stride: String::new(),
+            stride_scan_minimum_repeat_count: "8".to_string(),
+            scan_history_items: Vec::new(),
+            scan_snapshot_file_path: format!("scan_snapshot.{}", ScanSnapshotFile::FILE_EXTENSION),
+            is_scan_snapshot_compare_keeping_matches: false,
+            scan_snapshot_status: None,
         }
     }
 
@@ -84,10 +139,159 @@ impl ElementScannerViewData {
                 view_data.scan_progress = 0.0;
                 view_data.scan_task_id = None;
                 view_data.last_error_message = None;
+                view_data.scan_history_items.clear();
+            }
+        });
+    }
+
+    /// Queries the scans that the current results can be reverted to.
+    pub fn refresh_scan_history(
+        element_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::SCAN_HISTORY) {
+            return;
+        }
+
+        let scan_history_list_request = ScanHistoryListRequest {};
+
+        scan_history_list_request.send(&engine_unprivileged_state, move |scan_history_list_response| {
+            if let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data refresh scan history response") {
+                view_data.scan_history_items = scan_history_list_response.scan_history_items;
             }
         });
     }
 
+    /// Reverts the current results to those of an earlier scan, discarding the results of every scan after it. The results and
+    /// scan history are refreshed once the engine reports the reverted results.
+    pub fn revert_scan(
+        element_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        scan_number: u64,
+    ) {
+        let element_scanner_view_data_view_state = {
+            match element_scanner_view_data.read("Element scanner view data revert scan") {
+                Some(element_scanner_view_data) => element_scanner_view_data.view_state,
+                None => return,
+            }
+        };
+
+        if element_scanner_view_data_view_state == ElementScannerViewState::ScanInProgress
+            || !engine_unprivileged_state.has_capabilities(EngineCapabilities::SCAN_HISTORY)
+        {
+            return;
+        }
+
+        let scan_history_revert_request = ScanHistoryRevertRequest { scan_number };
+
+        scan_history_revert_request.send(&engine_unprivileged_state, move |scan_history_revert_response| {
+            if !scan_history_revert_response.success
+                && let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data revert scan response")
+            {
+                view_data.last_error_message = Some(format!("Scan {} is no longer in the scan history.", scan_number));
+            }
+        });
+    }
+
+    /// Saves the current results and their values to the scan snapshot file, such that they can be compared against later.
+    pub fn save_scan_snapshot(
+        element_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let Some(file_path) = Self::get_scan_snapshot_file_path(&element_scanner_view_data, &engine_unprivileged_state) else {
+            return;
+        };
+        let scan_snapshot_save_request = ScanSnapshotSaveRequest { file_path };
+
+        scan_snapshot_save_request.send(&engine_unprivileged_state, move |scan_snapshot_save_response| {
+            if let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data save scan snapshot response") {
+                view_data.scan_snapshot_status = Some(match scan_snapshot_save_response.saved_result_count {
+                    Ok(saved_result_count) => format!("Saved {} result(s).", saved_result_count),
+                    Err(error) => error,
+                });
+            }
+        });
+    }
+
+    /// Replaces the current results with those saved to the scan snapshot file, and scans them against the saved values. The
+    /// results and scan history are refreshed once the engine reports the compared results.
+    pub fn compare_scan_snapshot(
+        element_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let Some(file_path) = Self::get_scan_snapshot_file_path(&element_scanner_view_data, &engine_unprivileged_state) else {
+            return;
+        };
+        let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data compare scan snapshot") else {
+            return;
+        };
+        let previous_view_state = view_data.view_state;
+        let scan_snapshot_compare_request = ScanSnapshotCompareRequest {
+            file_path,
+            keep_matching_values: view_data.is_scan_snapshot_compare_keeping_matches,
+        };
+
+        view_data.view_state = ElementScannerViewState::ScanInProgress;
+        view_data.clear_scan_preview();
+        view_data.scan_progress = 0.0;
+        view_data.scan_task_id = None;
+        view_data.last_error_message = None;
+        view_data.scan_snapshot_status = None;
+
+        drop(view_data);
+
+        Self::schedule_scan_timeout(element_scanner_view_data.clone(), engine_unprivileged_state.clone());
+
+        scan_snapshot_compare_request.send(&engine_unprivileged_state, move |scan_snapshot_compare_response| {
+            match scan_snapshot_compare_response.compared_result_count {
+                Ok(compared_result_count) => {
+                    if let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data compare scan snapshot response") {
+                        view_data.scan_snapshot_status = Some(format!("Comparing {} saved result(s).", compared_result_count));
+                    }
+
+                    Self::on_scan_started(element_scanner_view_data, scan_snapshot_compare_response.trackable_task_handle);
+                }
+                Err(error) => {
+                    // Snapshots that fail to load leave the results untouched, so the results that were shown still stand.
+                    if let Some(mut view_data) = element_scanner_view_data.write("Element scanner view data compare scan snapshot response") {
+                        if view_data.view_state == ElementScannerViewState::ScanInProgress {
+                            view_data.view_state = previous_view_state;
+                        }
+
+                        view_data.scan_progress = 0.0;
+                        view_data.scan_snapshot_status = Some(error);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Gets the scan snapshot file to save to or compare against, if the engine supports scan snapshots and no scan is running.
+    fn get_scan_snapshot_file_path(
+        element_scanner_view_data: &Dependency<Self>,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+    ) -> Option<PathBuf> {
+        let mut view_data = element_scanner_view_data.write("Element scanner view data scan snapshot file path")?;
+
+        if view_data.view_state == ElementScannerViewState::ScanInProgress {
+            return None;
+        }
+
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::SCAN_SNAPSHOT_FILES) {
+            view_data.scan_snapshot_status = Some("The connected engine does not support scan snapshots.".to_string());
+            return None;
+        }
+
+        let scan_snapshot_file_path = view_data.scan_snapshot_file_path.trim();
+
+        if scan_snapshot_file_path.is_empty() {
+            view_data.scan_snapshot_status = Some("Enter the path of a scan snapshot file.".to_string());
+            return None;
+        }
+
+        Some(PathBuf::from(scan_snapshot_file_path))
+    }
+
     pub fn collect_values(engine_unprivileged_state: Arc<EngineUnprivilegedState>) {
         // Ensure a snapshot baseline exists before collecting values.
         let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
@@ -102,38 +306,49 @@ impl ElementScannerViewData {
         element_scanner_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) {
-        static POLL_STARTED: OnceLock<()> = OnceLock::new();
-        if POLL_STARTED.set(()).is_err() {
+        let Some(mut view_data) = element_scanner_view_data.write("Element scanner poll scan state") else {
+            return;
+        };
+
+        // Both the scanner and the demo guide start polling, but only the first needs to subscribe.
+        if !view_data.event_subscriptions.is_empty() {
             return;
         }
 
+        let element_scanner_view_data_filter = element_scanner_view_data.clone();
         let element_scanner_view_data_clone = element_scanner_view_data.clone();
-        engine_unprivileged_state.listen_for_engine_event::<TrackableTaskProgressChangedEvent>(move |event| {
-            if let Some(mut view_data) = element_scanner_view_data_clone.write("Element scanner progress update") {
-                let should_update = view_data
-                    .scan_task_id
-                    .as_ref()
-                    .map(|task_id| task_id == &event.task_id)
-                    .unwrap_or(false);
-
-                if should_update {
+        let progress_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<TrackableTaskProgressChangedEvent>(
+            move |event| {
+                element_scanner_view_data_filter
+                    .read("Element scanner progress filter")
+                    .is_some_and(|view_data| view_data.scan_task_id.as_ref() == Some(&event.task_id))
+            },
+            move |event| {
+                if let Some(mut view_data) = element_scanner_view_data_clone.write("Element scanner progress update") {
                     view_data.scan_progress = event.progress;
                 }
-            }
-        });
+            },
+        );
 
-        engine_unprivileged_state.listen_for_engine_event::<ScanResultsUpdatedEvent>(move |scan_results_updated_event| {
-            if scan_results_updated_event.is_new_scan {
-                return;
-            }
+        let element_scanner_view_data_clone = element_scanner_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+        let scan_results_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<ScanResultsUpdatedEvent>(
+            |scan_results_updated_event| !scan_results_updated_event.is_new_scan,
+            move |_scan_results_updated_event| {
+                if let Some(mut element_scanner_view_data) = element_scanner_view_data_clone.write("Element scanner scan state update") {
+                    element_scanner_view_data.view_state = ElementScannerViewState::HasResults;
+                    element_scanner_view_data.scan_progress = 1.0;
+                    element_scanner_view_data.scan_task_id = None;
+                    element_scanner_view_data.last_error_message = None;
+                    element_scanner_view_data.clear_scan_preview();
+                    element_scanner_view_data.promote_unknown_initial_value_constraints();
+                }
 
-            if let Some(mut element_scanner_view_data) = element_scanner_view_data.write("Element scanner scan state update") {
-                element_scanner_view_data.view_state = ElementScannerViewState::HasResults;
-                element_scanner_view_data.scan_progress = 1.0;
-                element_scanner_view_data.scan_task_id = None;
-                element_scanner_view_data.last_error_message = None;
-            }
-        });
+                Self::refresh_scan_history(element_scanner_view_data_clone.clone(), engine_unprivileged_state_clone.clone());
+            },
+        );
+
+        view_data.event_subscriptions = vec![progress_subscription, scan_results_subscription];
     }
 
     pub fn start_scan(
@@ -209,68 +424,71 @@ impl ElementScannerViewData {
                 None => return,
             }
         };
-        let symbol_registry = SymbolRegistry::get_instance();
-        let supported_formats = symbol_registry.get_supported_anonymous_value_string_formats(&element_scanner_view_data.selected_data_type);
-        let default_format = symbol_registry.get_default_anonymous_value_string_format(&element_scanner_view_data.selected_data_type);
 
-        if !supported_formats.contains(&element_scanner_view_data.active_display_format) {
-            element_scanner_view_data.active_display_format = default_format;
-        }
-
-        for scan_value_and_constraint in element_scanner_view_data.scan_values_and_constraints.iter_mut() {
-            if !supported_formats.contains(&scan_value_and_constraint.current_scan_value.get_anonymous_value_string_format()) {
-                scan_value_and_constraint
-                    .current_scan_value
-                    .set_anonymous_value_string_format(default_format);
-            }
-        }
+        element_scanner_view_data.coerce_display_formats();
 
         let data_type_refs = vec![element_scanner_view_data.selected_data_type.clone()];
-        let scan_constraints: Vec<AnonymousScanConstraint> = element_scanner_view_data
-            .scan_values_and_constraints
-            .iter_mut()
-            .filter_map(|scan_value_and_constraint| {
-                // Ensure the value format always matches the currently selected data type.
-                if !supported_formats.contains(&scan_value_and_constraint.current_scan_value.get_anonymous_value_string_format()) {
-                    scan_value_and_constraint
-                        .current_scan_value
-                        .set_anonymous_value_string_format(default_format);
-                }
+        let is_unknown_initial_scan = element_scanner_view_data.is_unknown_initial_value_scan();
+        let scan_constraints = match element_scanner_view_data.collect_scan_constraints() {
+            Ok(scan_constraints) => scan_constraints,
+            Err(error) => {
+                element_scanner_view_data.last_error_message = Some(error);
+                return;
+            }
+        };
 
-                match scan_value_and_constraint.selected_scan_compare_type {
-                    ScanCompareType::Relative(_) => Some(AnonymousScanConstraint::new(scan_value_and_constraint.selected_scan_compare_type, None)),
-                    _ => {
-                        if scan_value_and_constraint
-                            .current_scan_value
-                            .get_anonymous_value_string()
-                            .trim()
-                            .is_empty()
-                        {
-                            None
-                        } else {
-                            Some(AnonymousScanConstraint::new(
-                                scan_value_and_constraint.selected_scan_compare_type,
-                                Some(scan_value_and_constraint.current_scan_value.clone()),
-                            ))
-                        }
-                    }
-                }
-            })
-            .collect();
+        if is_unknown_initial_scan {
+            let unknown_initial_scan_error = if !engine_unprivileged_state.has_capabilities(EngineCapabilities::UNKNOWN_INITIAL_VALUE_SCAN) {
+                Some("The connected engine does not support unknown initial value scans.")
+            } else if element_scanner_view_data.is_stride_scan_enabled {
+                Some("Stride scans require an equal comparison against a value.")
+            } else {
+                None
+            };
 
-        if scan_constraints.is_empty() {
+            if let Some(unknown_initial_scan_error) = unknown_initial_scan_error {
+                element_scanner_view_data.last_error_message = Some(unknown_initial_scan_error.to_string());
+                return;
+            }
+        } else if scan_constraints.is_empty() {
             log::error!("No valid scan constraints provided.");
             if let Some(mut view_data) = element_scanner_view_data_clone.write("Element scanner view data scan constraint error") {
                 view_data.last_error_message = Some("No valid scan constraints provided.".to_string());
             }
             return;
         }
-        let element_scan_request = ElementScanRequest {
-            scan_constraints,
-            data_type_refs,
+
+        if let Err(error) = Self::validate_between_bounds(&element_scanner_view_data.selected_data_type, &scan_constraints) {
+            element_scanner_view_data.last_error_message = Some(error);
+            return;
+        }
+
+        if let Err(error) = Self::validate_byte_patterns(&element_scanner_view_data.selected_data_type, &scan_constraints) {
+            element_scanner_view_data.last_error_message = Some(error);
+            return;
+        }
+
+        // Stride scans compare the first constraint's value alone, so parse them up front to report mistakes before any scan starts.
+        let stride_scan_request = if element_scanner_view_data.is_stride_scan_enabled {
+            let stride_scan_request = if !engine_unprivileged_state.has_capabilities(EngineCapabilities::STRIDE_SCAN) {
+                Err("The connected engine does not support stride scans.".to_string())
+            } else {
+                element_scanner_view_data.create_stride_scan_request(&scan_constraints)
+            };
+
+            match stride_scan_request {
+                Ok(stride_scan_request) => Some(stride_scan_request),
+                Err(error) => {
+                    element_scanner_view_data.last_error_message = Some(error);
+                    return;
+                }
+            }
+        } else {
+            None
         };
 
         element_scanner_view_data.view_state = ElementScannerViewState::ScanInProgress;
+        element_scanner_view_data.clear_scan_preview();
         Self::schedule_scan_timeout(element_scanner_view_data_clone.clone(), engine_unprivileged_state.clone());
         element_scanner_view_data.scan_progress = 0.0;
         element_scanner_view_data.scan_task_id = None;
@@ -278,25 +496,320 @@ impl ElementScannerViewData {
 
         drop(element_scanner_view_data);
 
-        element_scan_request.send(&engine_unprivileged_state, move |scan_execute_response| {
-            if let Some(task_handle) = scan_execute_response.trackable_task_handle.as_ref() {
-                if let Some(mut view_data) = element_scanner_view_data_clone.write("Element scanner task handle") {
-                    view_data.scan_task_id = Some(task_handle.task_identifier.clone());
-                    view_data.scan_progress = task_handle.progress;
+        match stride_scan_request {
+            Some(stride_scan_request) => {
+                stride_scan_request.send(&engine_unprivileged_state, move |stride_scan_response| {
+                    Self::on_scan_started(element_scanner_view_data_clone, stride_scan_response.trackable_task_handle);
+                });
+            }
+            None => {
+                let element_scan_request = ElementScanRequest {
+                    scan_constraints,
+                    data_type_refs,
+                    is_unknown_initial_scan,
+                };
+
+                element_scan_request.send(&engine_unprivileged_state, move |scan_execute_response| {
+                    Self::on_scan_started(element_scanner_view_data_clone, scan_execute_response.trackable_task_handle);
+                });
+            }
+        }
+    }
+
+    /// Tracks the task of a scan that the engine accepted, or reports a scan that it rejected.
+    fn on_scan_started(
+        element_scanner_view_data: Dependency<Self>,
+        trackable_task_handle: Option<TrackableTaskHandle>,
+    ) {
+        if let Some(task_handle) = trackable_task_handle.as_ref()
+            && let Some(mut view_data) = element_scanner_view_data.write("Element scanner task handle")
+        {
+            view_data.scan_task_id = Some(task_handle.task_identifier.clone());
+            view_data.scan_progress = task_handle.progress;
+        }
+
+        if trackable_task_handle.is_none()
+            && let Some(mut element_scanner_view_data) = element_scanner_view_data.write("Element scanner view data start next scan response")
+        {
+            element_scanner_view_data.view_state = ElementScannerViewState::NoResults;
+            element_scanner_view_data.scan_progress = 0.0;
+            element_scanner_view_data.scan_task_id = None;
+            element_scanner_view_data.last_error_message = Some("Scan failed (no process opened or invalid constraints).".to_string());
+        }
+    }
+
+    /// Checks that each range parses, and that its lower bound does not exceed its upper bound, which would otherwise match nothing.
+    fn validate_between_bounds(
+        data_type_ref: &DataTypeRef,
+        scan_constraints: &[AnonymousScanConstraint],
+    ) -> Result<(), String> {
+        let symbol_registry = SymbolRegistry::get_instance();
+
+        for scan_constraint in scan_constraints {
+            if scan_constraint.get_scan_compare_type() != ScanCompareType::Immediate(ScanCompareTypeImmediate::Between) {
+                continue;
+            }
+
+            let scan_constraint = scan_constraint
+                .deanonymize_constraint(data_type_ref, FloatingPointTolerance::default())
+                .ok_or_else(|| "Both bounds of a between scan must be valid values.".to_string())?;
+            let upper_bound_data_value = scan_constraint
+                .get_upper_bound_data_value()
+                .ok_or_else(|| "Between scans require an upper bound.".to_string())?;
+
+            // Compare the bounds with the data type's own comparison, such that signedness, endianness, and floats are all respected.
+            let is_less_than_lower_bound = symbol_registry
+                .get_scalar_compare_func_immediate(&ScanCompareTypeImmediate::LessThan, &scan_constraint)
+                .ok_or_else(|| "The selected data type cannot be scanned for values between two bounds.".to_string())?;
+
+            if is_less_than_lower_bound(upper_bound_data_value.get_value_bytes().as_ptr()) {
+                return Err("The lower bound of a between scan must not be greater than the upper bound.".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that each array of byte pattern parses, and is long and specific enough to scan for, as the engine drops patterns that are not.
+    fn validate_byte_patterns(
+        data_type_ref: &DataTypeRef,
+        scan_constraints: &[AnonymousScanConstraint],
+    ) -> Result<(), String> {
+        if data_type_ref.get_data_type_id() != DataTypeAob::DATA_TYPE_ID {
+            return Ok(());
+        }
+
+        for anonymous_value_string in scan_constraints
+            .iter()
+            .filter_map(|scan_constraint| scan_constraint.get_anonymous_value_string().as_ref())
+        {
+            DataTypeAob::parse_scan_pattern(anonymous_value_string).map_err(|error| match error {
+                DataTypeError::ParseError(message) => message,
+                error => error.to_string(),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Builds a stride scan for the first constraint's value. The stride accepts decimal or `0x` prefixed hexadecimal, and must be
+    /// at least as large as the selected data type, since the elements of an array cannot overlap.
+    fn create_stride_scan_request(
+        &self,
+        scan_constraints: &[AnonymousScanConstraint],
+    ) -> Result<StrideScanRequest, String> {
+        let scan_value = scan_constraints
+            .first()
+            .filter(|scan_constraint| scan_constraint.get_scan_compare_type() == ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal))
+            .and_then(|scan_constraint| scan_constraint.get_anonymous_value_string().clone())
+            .ok_or_else(|| "Stride scans require an equal comparison against a value.".to_string())?;
+        let stride_text = self.stride_scan_stride.trim();
+        let stride = match stride_text
+            .strip_prefix("0x")
+            .or_else(|| stride_text.strip_prefix("0X"))
+        {
+            Some(hexadecimal_stride) => u64::from_str_radix(hexadecimal_stride, 16),
+            None => stride_text.parse::<u64>(),
+        }
+        .map_err(|_error| format!("Invalid stride '{}'.", stride_text))?;
+        let minimum_repeat_count = self
+            .stride_scan_minimum_repeat_count
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .filter(|minimum_repeat_count| *minimum_repeat_count >= 2)
+            .ok_or_else(|| "The minimum repeat count must be a number of at least 2.".to_string())?;
+        let unit_size_in_bytes = SymbolRegistry::get_instance().get_unit_size_in_bytes(&self.selected_data_type);
+
+        if stride < unit_size_in_bytes {
+            return Err(format!("The stride must be at least the {} byte size of the data type.", unit_size_in_bytes));
+        }
+
+        Ok(StrideScanRequest {
+            scan_value,
+            data_type_ref: self.selected_data_type.clone(),
+            stride,
+            minimum_repeat_count,
+        })
+    }
+
+    /// Tracks edits to the scan constraints, and once they settle, asks the engine how many current results the next scan would keep.
+    /// Returns how long until the pending preview should be requested, such that the caller can schedule a repaint.
+    pub fn update_scan_preview(
+        element_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) -> Option<Duration> {
+        let mut view_data = element_scanner_view_data.write("Element scanner update scan preview")?;
+
+        // Older engines do not understand preview requests, so leave the preview blank rather than report a failure on each edit.
+        if view_data.view_state != ElementScannerViewState::HasResults
+            || view_data.is_stride_scan_enabled
+            || !engine_unprivileged_state.has_capabilities(EngineCapabilities::ELEMENT_SCAN_PREVIEW)
+        {
+            return None;
+        }
+
+        let scan_constraints = view_data.collect_scan_constraints().unwrap_or_default();
+        let scan_preview_signature = format!("{:?}{:?}", view_data.selected_data_type, scan_constraints);
+        let debounce = Duration::from_millis(Self::SCAN_PREVIEW_DEBOUNCE_MS);
+
+        if scan_preview_signature != view_data.scan_preview_signature {
+            view_data.clear_scan_preview();
+            view_data.scan_preview_signature = scan_preview_signature;
+            view_data.scan_preview_edit_time = Some(Instant::now());
+
+            return Some(debounce);
+        }
+
+        let edit_time = view_data.scan_preview_edit_time?;
+        let elapsed = edit_time.elapsed();
+
+        if elapsed < debounce {
+            return Some(debounce - elapsed);
+        }
+
+        view_data.scan_preview_edit_time = None;
+
+        if scan_constraints.is_empty() {
+            return None;
+        }
+
+        let scan_preview_generation = view_data.scan_preview_generation;
+        let element_scan_preview_request = ElementScanPreviewRequest {
+            scan_constraints,
+            data_type_refs: vec![view_data.selected_data_type.clone()],
+        };
+
+        view_data.is_scan_preview_pending = true;
+
+        drop(view_data);
+
+        // Commands may execute synchronously in standalone mode, so keep the preview off of the UI thread.
+        thread::spawn(move || {
+            element_scan_preview_request.send(&engine_unprivileged_state, move |element_scan_preview_response| {
+                if let Some(mut view_data) = element_scanner_view_data.write("Element scanner scan preview response") {
+                    // Ignore previews that were superseded by an edit or a scan while they were running.
+                    if view_data.scan_preview_generation != scan_preview_generation || view_data.view_state != ElementScannerViewState::HasResults {
+                        return;
+                    }
+
+                    view_data.is_scan_preview_pending = false;
+                    view_data.scan_preview_result_count = element_scan_preview_response.preview_result_count;
+                    view_data.scan_preview_current_result_count = element_scan_preview_response.current_result_count;
                 }
+            });
+        });
+
+        None
+    }
+
+    fn clear_scan_preview(&mut self) {
+        self.scan_preview_generation = self.scan_preview_generation.wrapping_add(1);
+        self.scan_preview_result_count = None;
+        self.scan_preview_current_result_count = 0;
+        self.is_scan_preview_pending = false;
+        self.scan_preview_edit_time = None;
+    }
+
+    /// Gets whether the next scan is the first, which has no previous values for relative comparisons to compare against.
+    pub fn is_first_scan(&self) -> bool {
+        self.view_state != ElementScannerViewState::HasResults
+    }
+
+    /// Gets whether the next scan collects every value without comparing, because a constraint row asks for an unknown initial value.
+    fn is_unknown_initial_value_scan(&self) -> bool {
+        self.is_first_scan()
+            && self
+                .scan_values_and_constraints
+                .iter()
+                .any(|scan_value_and_constraint| scan_value_and_constraint.is_unknown_initial_value)
+    }
+
+    /// Once an unknown initial value scan has results, switches the rows that asked for it to a relative comparison, since narrowing
+    /// down by how the values changed is the only thing left to do with them.
+    fn promote_unknown_initial_value_constraints(&mut self) {
+        for scan_value_and_constraint in self.scan_values_and_constraints.iter_mut() {
+            if scan_value_and_constraint.is_unknown_initial_value {
+                scan_value_and_constraint.is_unknown_initial_value = false;
+                scan_value_and_constraint.selected_scan_compare_type = ScanCompareType::Relative(ScanCompareTypeRelative::Changed);
             }
+        }
+    }
 
-            if scan_execute_response.trackable_task_handle.is_none() {
-                if let Some(mut element_scanner_view_data) =
-                    element_scanner_view_data_clone.write("Element scanner view data start next scan response")
+    /// Builds scan constraints from the constraint rows, skipping immediate comparisons with no value entered, ranges missing either
+    /// bound, and rows asking for an unknown initial value. Values typed as arithmetic are evaluated, while the rows keep the raw text.
+    /// Fails with the first value that cannot be evaluated, naming its row.
+    fn collect_scan_constraints(&self) -> Result<Vec<AnonymousScanConstraint>, String> {
+        self.scan_values_and_constraints
+            .iter()
+            .enumerate()
+            .filter(|(_index, scan_value_and_constraint)| !scan_value_and_constraint.is_unknown_initial_value)
+            .filter_map(|(index, scan_value_and_constraint)| {
+                let evaluate = |anonymous_value_string: &AnonymousValueString| {
+                    ScanValueExpression::evaluate(&self.selected_data_type, anonymous_value_string)
+                        .map_err(|error| format!("Invalid value in constraint {}: {}", index + 1, error))
+                };
+
+                self.create_scan_constraint(scan_value_and_constraint, evaluate)
+            })
+            .collect()
+    }
+
+    /// Builds the scan constraint for a single constraint row, or None if the row is missing a value. Rows of floating point types
+    /// carry their own tolerance, rather than the tolerance from the scan settings.
+    fn create_scan_constraint(
+        &self,
+        scan_value_and_constraint: &ElementScannerValueViewData,
+        evaluate: impl Fn(&AnonymousValueString) -> Result<AnonymousValueString, String>,
+    ) -> Option<Result<AnonymousScanConstraint, String>> {
+        let is_floating_point = SymbolRegistry::get_instance().is_floating_point(&self.selected_data_type);
+        let scan_constraint = match scan_value_and_constraint.selected_scan_compare_type {
+            ScanCompareType::Relative(_) => Some(Ok(AnonymousScanConstraint::new(scan_value_and_constraint.selected_scan_compare_type, None))),
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Between) => {
+                let is_missing_bound = [
+                    &scan_value_and_constraint.current_scan_value,
+                    &scan_value_and_constraint.current_scan_value_upper_bound,
+                ]
+                .iter()
+                .any(|bound| bound.get_anonymous_value_string().trim().is_empty());
+
+                if is_missing_bound {
+                    None
+                } else {
+                    Some(evaluate(&scan_value_and_constraint.current_scan_value).and_then(|lower_bound| {
+                        Ok(AnonymousScanConstraint::new_between(
+                            lower_bound,
+                            evaluate(&scan_value_and_constraint.current_scan_value_upper_bound)?,
+                        ))
+                    }))
+                }
+            }
+            _ => {
+                if scan_value_and_constraint
+                    .current_scan_value
+                    .get_anonymous_value_string()
+                    .trim()
+                    .is_empty()
                 {
-                    element_scanner_view_data.view_state = ElementScannerViewState::NoResults;
-                    element_scanner_view_data.scan_progress = 0.0;
-                    element_scanner_view_data.scan_task_id = None;
-                    element_scanner_view_data.last_error_message = Some("Scan failed (no process opened or invalid constraints).".to_string());
+                    None
+                } else {
+                    Some(
+                        evaluate(&scan_value_and_constraint.current_scan_value)
+                            .map(|scan_value| AnonymousScanConstraint::new(scan_value_and_constraint.selected_scan_compare_type, Some(scan_value))),
+                    )
                 }
             }
-        });
+        };
+
+        scan_constraint.map(|scan_constraint| {
+            scan_constraint.map(|mut scan_constraint| {
+                if is_floating_point {
+                    scan_constraint.set_floating_point_tolerance(scan_value_and_constraint.floating_point_tolerance);
+                }
+
+                scan_constraint
+            })
+        })
     }
 
     fn schedule_scan_timeout(
@@ -335,6 +848,52 @@ impl ElementScannerViewData {
         });
     }
 
+    /// Sets the format that scan results are displayed in, ie from the toggle in the results header. Formats that the selected data
+    /// type does not support are ignored. Results are already read, so they are only shown in the new format rather than read again.
+    pub fn set_active_display_format(
+        element_scanner_view_data: Dependency<Self>,
+        display_format: AnonymousValueStringFormat,
+    ) {
+        let mut element_scanner_view_data = match element_scanner_view_data.write("Element scanner view data set active display format") {
+            Some(element_scanner_view_data) => element_scanner_view_data,
+            None => return,
+        };
+        let supported_formats = SymbolRegistry::get_instance().get_supported_anonymous_value_string_formats(&element_scanner_view_data.selected_data_type);
+
+        if supported_formats.contains(&display_format) {
+            element_scanner_view_data.active_display_format = display_format;
+        }
+    }
+
+    /// Resets the display format, and that of each constraint value, to the default if the selected data type does not support it.
+    fn coerce_display_formats(&mut self) {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let supported_formats = symbol_registry.get_supported_anonymous_value_string_formats(&self.selected_data_type);
+        let default_format = symbol_registry.get_default_anonymous_value_string_format(&self.selected_data_type);
+
+        if !supported_formats.contains(&self.active_display_format) {
+            self.active_display_format = default_format;
+        }
+
+        for scan_value_and_constraint in self.scan_values_and_constraints.iter_mut() {
+            if !supported_formats.contains(&scan_value_and_constraint.current_scan_value.get_anonymous_value_string_format()) {
+                scan_value_and_constraint.set_anonymous_value_string_format(default_format);
+            }
+        }
+    }
+
+    fn get_max_constraints() -> usize {
+        AppearanceSettings::get_max_scan_constraints() as usize
+    }
+
+    fn allocate_menu_id(&mut self) -> String {
+        let menu_id = Self::create_menu_id(self.next_constraint_id);
+
+        self.next_constraint_id += 1;
+
+        menu_id
+    }
+
     pub fn add_constraint(element_scanner_view_data: Dependency<Self>) {
         let mut element_scanner_view_data = match element_scanner_view_data.write("Element scanner view data add constraint") {
             Some(element_scanner_view_data) => element_scanner_view_data,
@@ -344,25 +903,74 @@ impl ElementScannerViewData {
         let next_index = element_scanner_view_data.scan_values_and_constraints.len();
         let desired_format = element_scanner_view_data.active_display_format;
 
-        if next_index >= Self::MAX_CONSTRAINTS {
+        if next_index >= Self::get_max_constraints() {
             return;
         }
 
+        let mut value_view_data = ElementScannerValueViewData::new(element_scanner_view_data.allocate_menu_id());
+
         // If creating the 2nd constraint, <= is the most common constraint, so default to that for a better UX.
         if next_index == 1 {
-            let mut value_view_data = ElementScannerValueViewData {
-                selected_scan_compare_type: ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual),
-                ..ElementScannerValueViewData::new(Self::create_menu_id(next_index))
-            };
-            value_view_data.current_scan_value.set_anonymous_value_string_format(desired_format);
-            element_scanner_view_data.scan_values_and_constraints.push(value_view_data);
-        } else {
-            let mut value_view_data = ElementScannerValueViewData::new(Self::create_menu_id(next_index));
-            value_view_data.current_scan_value.set_anonymous_value_string_format(desired_format);
-            element_scanner_view_data.scan_values_and_constraints.push(value_view_data);
+            value_view_data.selected_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThanOrEqual);
         }
+
+        value_view_data.set_anonymous_value_string_format(desired_format);
+        element_scanner_view_data.scan_values_and_constraints.push(value_view_data);
     }
 
+    /// Inserts a copy of the constraint at the given index directly below it.
+    pub fn duplicate_constraint(
+        element_scanner_view_data: Dependency<Self>,
+        index: usize,
+    ) {
+        let mut element_scanner_view_data = match element_scanner_view_data.write("Element scanner view data duplicate constraint") {
+            Some(element_scanner_view_data) => element_scanner_view_data,
+            None => return,
+        };
+
+        let constraint_count = element_scanner_view_data.scan_values_and_constraints.len();
+
+        if index >= constraint_count || constraint_count >= Self::get_max_constraints() {
+            return;
+        }
+
+        let mut value_view_data = element_scanner_view_data.scan_values_and_constraints[index].clone();
+
+        value_view_data.menu_id = element_scanner_view_data.allocate_menu_id();
+        element_scanner_view_data
+            .scan_values_and_constraints
+            .insert(index + 1, value_view_data);
+        element_scanner_view_data.coerce_display_formats();
+    }
+
+    /// Moves the constraint at the given index to the target index, shifting the rows in between.
+    pub fn move_constraint(
+        element_scanner_view_data: Dependency<Self>,
+        index: usize,
+        target_index: usize,
+    ) {
+        let mut element_scanner_view_data = match element_scanner_view_data.write("Element scanner view data move constraint") {
+            Some(element_scanner_view_data) => element_scanner_view_data,
+            None => return,
+        };
+
+        let constraint_count = element_scanner_view_data.scan_values_and_constraints.len();
+
+        if index >= constraint_count || target_index >= constraint_count || index == target_index {
+            return;
+        }
+
+        let value_view_data = element_scanner_view_data
+            .scan_values_and_constraints
+            .remove(index);
+
+        element_scanner_view_data
+            .scan_values_and_constraints
+            .insert(target_index, value_view_data);
+        element_scanner_view_data.coerce_display_formats();
+    }
+
+    /// Removes the constraint at the given index, unless it is the only one left.
     pub fn remove_constraint(
         element_scanner_view_data: Dependency<Self>,
         index: usize,
@@ -372,7 +980,9 @@ impl ElementScannerViewData {
             None => return,
         };
 
-        if index <= 0 {
+        let constraint_count = element_scanner_view_data.scan_values_and_constraints.len();
+
+        if index >= constraint_count || constraint_count <= 1 {
             return;
         }
 
@@ -381,7 +991,454 @@ impl ElementScannerViewData {
             .remove(index);
     }
 
-    fn create_menu_id(index: usize) -> String {
-        format!("element_scanner_data_type_selector_{}", index)
+    /// Captures the data type and every constraint row, including rows with no value entered, such that a workspace can restore them.
+    pub fn capture_scan_constraints(&self) -> WorkspaceScanConstraints {
+        WorkspaceScanConstraints {
+            data_type_ref: self.selected_data_type.clone(),
+            display_format: self.active_display_format,
+            constraints: self
+                .scan_values_and_constraints
+                .iter()
+                .map(|scan_value_and_constraint| {
+                    let mut anonymous_scan_constraint = if scan_value_and_constraint.is_between() {
+                        AnonymousScanConstraint::new_between(
+                            scan_value_and_constraint.current_scan_value.clone(),
+                            scan_value_and_constraint.current_scan_value_upper_bound.clone(),
+                        )
+                    } else {
+                        AnonymousScanConstraint::new(
+                            scan_value_and_constraint.selected_scan_compare_type,
+                            Some(scan_value_and_constraint.current_scan_value.clone()),
+                        )
+                    };
+
+                    anonymous_scan_constraint.set_floating_point_tolerance(scan_value_and_constraint.floating_point_tolerance);
+
+                    anonymous_scan_constraint
+                })
+                .collect(),
+        }
+    }
+
+    /// Replaces the data type and constraint rows with those captured by `capture_scan_constraints`. Ignored while a scan runs.
+    pub fn restore_scan_constraints(
+        element_scanner_view_data: Dependency<Self>,
+        scan_constraints: &WorkspaceScanConstraints,
+    ) {
+        let mut element_scanner_view_data = match element_scanner_view_data.write("Element scanner view data restore scan constraints") {
+            Some(element_scanner_view_data) => element_scanner_view_data,
+            None => return,
+        };
+
+        if element_scanner_view_data.view_state == ElementScannerViewState::ScanInProgress {
+            return;
+        }
+
+        let mut scan_values_and_constraints = Vec::new();
+
+        for scan_constraint in scan_constraints
+            .constraints
+            .iter()
+            .take(Self::get_max_constraints().max(1))
+        {
+            let mut value_view_data = ElementScannerValueViewData::new(element_scanner_view_data.allocate_menu_id());
+
+            value_view_data.selected_scan_compare_type = scan_constraint.get_scan_compare_type();
+
+            if let Some(anonymous_value_string) = scan_constraint.get_anonymous_value_string() {
+                value_view_data.current_scan_value = anonymous_value_string.clone();
+            }
+
+            if let Some(anonymous_value_string_upper_bound) = scan_constraint.get_anonymous_value_string_upper_bound() {
+                value_view_data.current_scan_value_upper_bound = anonymous_value_string_upper_bound.clone();
+            }
+
+            if let Some(floating_point_tolerance) = scan_constraint.get_floating_point_tolerance() {
+                value_view_data.floating_point_tolerance = floating_point_tolerance;
+            }
+
+            scan_values_and_constraints.push(value_view_data);
+        }
+
+        if scan_values_and_constraints.is_empty() {
+            scan_values_and_constraints.push(ElementScannerValueViewData::new(element_scanner_view_data.allocate_menu_id()));
+        }
+
+        element_scanner_view_data.selected_data_type = scan_constraints.data_type_ref.clone();
+        element_scanner_view_data.active_display_format = scan_constraints.display_format;
+        element_scanner_view_data.scan_values_and_constraints = scan_values_and_constraints;
+        element_scanner_view_data.coerce_display_formats();
+        element_scanner_view_data.clear_scan_preview();
+    }
+
+    fn create_menu_id(constraint_id: u64) -> String {
+        format!("element_scanner_data_type_selector_{}", constraint_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ElementScannerViewData;
+    use crate::views::element_scanner::scanner::element_scanner_view_state::ElementScannerViewState;
+    use squalr_engine_api::dependency_injection::dependency_container::DependencyContainer;
+    use squalr_engine_api::structures::data_types::{
+        built_in_types::{aob::data_type_aob::DataTypeAob, f32::data_type_f32::DataTypeF32},
+        data_type_ref::DataTypeRef,
+        floating_point_tolerance::FloatingPointTolerance,
+    };
+    use squalr_engine_api::structures::data_values::{
+        anonymous_value_string::AnonymousValueString, anonymous_value_string_format::AnonymousValueStringFormat, container_type::ContainerType,
+    };
+    use squalr_engine_api::structures::scanning::comparisons::{
+        scan_compare_type::ScanCompareType, scan_compare_type_immediate::ScanCompareTypeImmediate, scan_compare_type_relative::ScanCompareTypeRelative,
+    };
+    use squalr_engine_api::structures::scanning::constraints::anonymous_scan_constraint::AnonymousScanConstraint;
+    use std::collections::HashSet;
+
+    fn get_menu_ids(element_scanner_view_data: &ElementScannerViewData) -> Vec<String> {
+        element_scanner_view_data
+            .scan_values_and_constraints
+            .iter()
+            .map(|scan_value_and_constraint| scan_value_and_constraint.menu_id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn active_display_format_ignores_formats_the_data_type_does_not_support() {
+        let element_scanner_view_data = DependencyContainer::new().register(ElementScannerViewData::new());
+        let get_active_display_format = || {
+            element_scanner_view_data
+                .read("Test active display format")
+                .map(|view_data| view_data.active_display_format)
+        };
+
+        ElementScannerViewData::set_active_display_format(element_scanner_view_data.clone(), AnonymousValueStringFormat::Hexadecimal);
+
+        assert_eq!(get_active_display_format(), Some(AnonymousValueStringFormat::Hexadecimal));
+
+        if let Some(mut view_data) = element_scanner_view_data.write("Test select byte array") {
+            view_data.selected_data_type = DataTypeRef::new(DataTypeAob::get_data_type_id());
+        }
+
+        ElementScannerViewData::set_active_display_format(element_scanner_view_data.clone(), AnonymousValueStringFormat::Binary);
+
+        assert_eq!(get_active_display_format(), Some(AnonymousValueStringFormat::Hexadecimal));
+    }
+
+    #[test]
+    fn menu_ids_stay_unique_through_duplicate_reorder_and_remove() {
+        let element_scanner_view_data = DependencyContainer::new().register(ElementScannerViewData::new());
+        let mut seen_menu_ids = HashSet::new();
+
+        ElementScannerViewData::add_constraint(element_scanner_view_data.clone());
+        ElementScannerViewData::add_constraint(element_scanner_view_data.clone());
+        ElementScannerViewData::remove_constraint(element_scanner_view_data.clone(), 1);
+
+        // Ids used to be derived from the row index, so adding after a removal reused the id of the last row.
+        ElementScannerViewData::add_constraint(element_scanner_view_data.clone());
+        ElementScannerViewData::duplicate_constraint(element_scanner_view_data.clone(), 0);
+        ElementScannerViewData::move_constraint(element_scanner_view_data.clone(), 0, 3);
+        ElementScannerViewData::move_constraint(element_scanner_view_data.clone(), 2, 1);
+        ElementScannerViewData::remove_constraint(element_scanner_view_data.clone(), 0);
+        ElementScannerViewData::duplicate_constraint(element_scanner_view_data.clone(), 2);
+
+        let view_data = element_scanner_view_data
+            .read("Element scanner view data test")
+            .expect("The view data should be readable.");
+        let menu_ids = get_menu_ids(&view_data);
+
+        assert_eq!(menu_ids.len(), 4);
+
+        for menu_id in &menu_ids {
+            assert!(seen_menu_ids.insert(menu_id.clone()), "Duplicate menu id {} in {:?}", menu_id, menu_ids);
+        }
+    }
+
+    #[test]
+    fn last_constraint_cannot_be_removed_and_duplicates_copy_the_comparison() {
+        let element_scanner_view_data = DependencyContainer::new().register(ElementScannerViewData::new());
+
+        ElementScannerViewData::remove_constraint(element_scanner_view_data.clone(), 0);
+        ElementScannerViewData::add_constraint(element_scanner_view_data.clone());
+        ElementScannerViewData::duplicate_constraint(element_scanner_view_data.clone(), 1);
+        ElementScannerViewData::move_constraint(element_scanner_view_data.clone(), 2, 0);
+
+        let view_data = element_scanner_view_data
+            .read("Element scanner view data test")
+            .expect("The view data should be readable.");
+        let scan_values_and_constraints = &view_data.scan_values_and_constraints;
+
+        assert_eq!(scan_values_and_constraints.len(), 3);
+        assert_eq!(
+            scan_values_and_constraints[0].selected_scan_compare_type,
+            scan_values_and_constraints[2].selected_scan_compare_type
+        );
+        assert_ne!(
+            scan_values_and_constraints[0].selected_scan_compare_type,
+            scan_values_and_constraints[1].selected_scan_compare_type
+        );
+    }
+
+    #[test]
+    fn restored_scan_constraints_keep_rows_without_values() {
+        let element_scanner_view_data = DependencyContainer::new().register(ElementScannerViewData::new());
+        let mut source_view_data = ElementScannerViewData::new();
+
+        source_view_data.selected_data_type = DataTypeRef::new(DataTypeF32::get_data_type_id());
+        source_view_data.scan_values_and_constraints[0]
+            .current_scan_value
+            .set_anonymous_value_string("1.5".to_string());
+        source_view_data.scan_values_and_constraints.push(source_view_data.scan_values_and_constraints[0].clone());
+        source_view_data.scan_values_and_constraints[1].selected_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan);
+        source_view_data.scan_values_and_constraints[1]
+            .current_scan_value
+            .set_anonymous_value_string(String::new());
+
+        ElementScannerViewData::restore_scan_constraints(element_scanner_view_data.clone(), &source_view_data.capture_scan_constraints());
+
+        let view_data = element_scanner_view_data
+            .read("Element scanner view data test")
+            .expect("The view data should be readable.");
+        let scan_values_and_constraints = &view_data.scan_values_and_constraints;
+
+        assert_eq!(view_data.selected_data_type, DataTypeRef::new(DataTypeF32::get_data_type_id()));
+        assert_eq!(scan_values_and_constraints.len(), 2);
+        assert_eq!(scan_values_and_constraints[0].current_scan_value.get_anonymous_value_string(), "1.5");
+        assert_eq!(
+            scan_values_and_constraints[1].selected_scan_compare_type,
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::LessThan)
+        );
+        assert_ne!(scan_values_and_constraints[0].menu_id, scan_values_and_constraints[1].menu_id);
+    }
+
+    #[test]
+    fn stride_scans_reject_strides_smaller_than_the_data_type() {
+        let mut element_scanner_view_data = ElementScannerViewData::new();
+
+        element_scanner_view_data.selected_data_type = DataTypeRef::new(DataTypeF32::get_data_type_id());
+        element_scanner_view_data.scan_values_and_constraints[0]
+            .current_scan_value
+            .set_anonymous_value_string("1.0".to_string());
+        element_scanner_view_data.stride_scan_stride = "0x150".to_string();
+
+        let scan_constraints = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect("Scan values should evaluate.");
+        let stride_scan_request = element_scanner_view_data
+            .create_stride_scan_request(&scan_constraints)
+            .expect("A stride larger than the data type should be accepted.");
+
+        assert_eq!(stride_scan_request.stride, 0x150);
+        assert_eq!(stride_scan_request.minimum_repeat_count, 8);
+
+        element_scanner_view_data.stride_scan_stride = "2".to_string();
+        assert!(
+            element_scanner_view_data
+                .create_stride_scan_request(&scan_constraints)
+                .is_err()
+        );
+
+        element_scanner_view_data.stride_scan_stride = "4".to_string();
+        element_scanner_view_data.stride_scan_minimum_repeat_count = "1".to_string();
+        assert!(
+            element_scanner_view_data
+                .create_stride_scan_request(&scan_constraints)
+                .is_err()
+        );
+
+        // Only an equal comparison describes a value that repeats.
+        element_scanner_view_data.stride_scan_minimum_repeat_count = "2".to_string();
+        element_scanner_view_data.scan_values_and_constraints[0].selected_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::GreaterThan);
+
+        let scan_constraints = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect("Scan values should evaluate.");
+
+        assert!(
+            element_scanner_view_data
+                .create_stride_scan_request(&scan_constraints)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn between_scans_require_both_bounds_in_order() {
+        let mut element_scanner_view_data = ElementScannerViewData::new();
+        let set_bounds = |element_scanner_view_data: &mut ElementScannerViewData, lower_bound: &str, upper_bound: &str| {
+            let scan_value_and_constraint = &mut element_scanner_view_data.scan_values_and_constraints[0];
+
+            scan_value_and_constraint.selected_scan_compare_type = ScanCompareType::Immediate(ScanCompareTypeImmediate::Between);
+            scan_value_and_constraint
+                .current_scan_value
+                .set_anonymous_value_string(lower_bound.to_string());
+            scan_value_and_constraint
+                .current_scan_value_upper_bound
+                .set_anonymous_value_string(upper_bound.to_string());
+        };
+        let validate = |element_scanner_view_data: &ElementScannerViewData| {
+            ElementScannerViewData::validate_between_bounds(
+                &element_scanner_view_data.selected_data_type,
+                &element_scanner_view_data
+                    .collect_scan_constraints()
+                    .expect("Scan values should evaluate."),
+            )
+        };
+
+        set_bounds(&mut element_scanner_view_data, "5", "");
+        assert!(
+            element_scanner_view_data
+                .collect_scan_constraints()
+                .expect("Scan values should evaluate.")
+                .is_empty()
+        );
+
+        // Bounds compare as signed integers, and equal bounds are a valid range of one value.
+        set_bounds(&mut element_scanner_view_data, "-5", "10");
+        assert!(validate(&element_scanner_view_data).is_ok());
+        set_bounds(&mut element_scanner_view_data, "7", "7");
+        assert!(validate(&element_scanner_view_data).is_ok());
+        set_bounds(&mut element_scanner_view_data, "10", "-5");
+        assert!(validate(&element_scanner_view_data).is_err());
+
+        element_scanner_view_data.selected_data_type = DataTypeRef::new(DataTypeF32::get_data_type_id());
+        set_bounds(&mut element_scanner_view_data, "1.5", "2.5");
+        assert!(validate(&element_scanner_view_data).is_ok());
+        set_bounds(&mut element_scanner_view_data, "2.5", "1.5");
+        assert!(validate(&element_scanner_view_data).is_err());
+    }
+
+    #[test]
+    fn byte_patterns_need_two_bytes_and_a_byte_that_is_not_a_wildcard() {
+        let data_type_ref = DataTypeRef::new(DataTypeAob::DATA_TYPE_ID);
+        let validate = |pattern: &str| {
+            ElementScannerViewData::validate_byte_patterns(
+                &data_type_ref,
+                &[AnonymousScanConstraint::new(
+                    ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal),
+                    Some(AnonymousValueString::new(
+                        pattern.to_string(),
+                        AnonymousValueStringFormat::Hexadecimal,
+                        ContainerType::None,
+                    )),
+                )],
+            )
+        };
+
+        assert!(validate("48 8B ?? 05 ? ?").is_ok());
+        assert!(validate("4? 8B").is_ok());
+        assert!(validate("48").is_err());
+        assert!(validate("?? ?? ?").is_err());
+        assert!(validate("48 8G").is_err());
+    }
+
+    #[test]
+    fn scan_values_are_evaluated_and_bad_expressions_name_their_row() {
+        let mut element_scanner_view_data = ElementScannerViewData::new();
+
+        element_scanner_view_data.scan_values_and_constraints[0]
+            .current_scan_value
+            .set_anonymous_value_string("100*4".to_string());
+
+        let scan_constraints = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect("Scan values should evaluate.");
+
+        assert_eq!(
+            scan_constraints[0]
+                .get_anonymous_value_string()
+                .as_ref()
+                .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string()),
+            Some("400")
+        );
+
+        // The typed expression is kept, so the value box still shows what was entered.
+        assert_eq!(
+            element_scanner_view_data.scan_values_and_constraints[0]
+                .current_scan_value
+                .get_anonymous_value_string(),
+            "100*4"
+        );
+
+        element_scanner_view_data
+            .scan_values_and_constraints
+            .push(element_scanner_view_data.scan_values_and_constraints[0].clone());
+        element_scanner_view_data.scan_values_and_constraints[1]
+            .current_scan_value
+            .set_anonymous_value_string("100*".to_string());
+
+        let error = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect_err("An incomplete expression should not evaluate.");
+
+        assert!(error.starts_with("Invalid value in constraint 2"));
+    }
+
+    #[test]
+    fn float_rows_carry_their_tolerance_into_the_scan_constraint() {
+        let mut element_scanner_view_data = ElementScannerViewData::new();
+
+        element_scanner_view_data.scan_values_and_constraints[0].floating_point_tolerance = FloatingPointTolerance::ToleranceInteger;
+        element_scanner_view_data.scan_values_and_constraints[0]
+            .current_scan_value
+            .set_anonymous_value_string("100".to_string());
+
+        // Integer types have no tolerance, so the scan settings are left to decide.
+        let scan_constraints = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect("Scan values should evaluate.");
+
+        assert_eq!(scan_constraints[0].get_floating_point_tolerance(), None);
+
+        element_scanner_view_data.selected_data_type = DataTypeRef::new(DataTypeF32::DATA_TYPE_ID);
+
+        let scan_constraints = element_scanner_view_data
+            .collect_scan_constraints()
+            .expect("Scan values should evaluate.");
+
+        assert_eq!(
+            scan_constraints[0].get_floating_point_tolerance(),
+            Some(FloatingPointTolerance::ToleranceInteger)
+        );
+    }
+
+    #[test]
+    fn unknown_initial_value_rows_scan_without_constraints_and_become_relative_after() {
+        let mut element_scanner_view_data = ElementScannerViewData::new();
+
+        element_scanner_view_data.scan_values_and_constraints[0].is_unknown_initial_value = true;
+        element_scanner_view_data
+            .scan_values_and_constraints
+            .push(element_scanner_view_data.scan_values_and_constraints[0].clone());
+        element_scanner_view_data.scan_values_and_constraints[1].is_unknown_initial_value = false;
+        element_scanner_view_data.scan_values_and_constraints[1]
+            .current_scan_value
+            .set_anonymous_value_string("5".to_string());
+
+        assert!(element_scanner_view_data.is_unknown_initial_value_scan());
+        assert_eq!(
+            element_scanner_view_data
+                .collect_scan_constraints()
+                .expect("Scan values should evaluate.")
+                .len(),
+            1
+        );
+
+        // Relative comparisons only exist once there are results, so the flag no longer applies to later scans.
+        element_scanner_view_data.view_state = ElementScannerViewState::HasResults;
+        assert!(!element_scanner_view_data.is_unknown_initial_value_scan());
+
+        element_scanner_view_data.promote_unknown_initial_value_constraints();
+
+        let scan_values_and_constraints = &element_scanner_view_data.scan_values_and_constraints;
+
+        assert!(!scan_values_and_constraints[0].is_unknown_initial_value);
+        assert_eq!(
+            scan_values_and_constraints[0].selected_scan_compare_type,
+            ScanCompareType::Relative(ScanCompareTypeRelative::Changed)
+        );
+        assert_eq!(
+            scan_values_and_constraints[1].selected_scan_compare_type,
+            ScanCompareType::Immediate(ScanCompareTypeImmediate::Equal)
+        );
     }
 }
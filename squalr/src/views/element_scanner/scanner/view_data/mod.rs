@@ -1,2 +1,3 @@
 pub mod element_scanner_value_view_data;
 pub mod element_scanner_view_data;
+pub mod scan_value_expression;
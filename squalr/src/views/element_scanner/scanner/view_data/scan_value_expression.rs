@@ -0,0 +1,148 @@
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::aob::data_type_aob::DataTypeAob;
+use squalr_engine_api::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_list::AnonymousValueList;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::expressions::arithmetic_expression::ArithmeticExpression;
+
+/// Evaluates arithmetic typed into a scan value box, ie `100*4`, `0x1F4+8`, or `1<<16`, such that derived values can be scanned for
+/// without working them out by hand.
+pub struct ScanValueExpression {}
+
+impl ScanValueExpression {
+    /// Gets the value to scan for in place of the given scan value. Values that already parse are returned unchanged, as are blank
+    /// values, value lists, arrays, and values of types that are not numbers. Anything else is evaluated as an expression, with
+    /// integer types using exact integer arithmetic, and the result must fit in the data type.
+    pub fn evaluate(
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> Result<AnonymousValueString, String> {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let value_string = anonymous_value_string.get_anonymous_value_string().trim();
+
+        if value_string.is_empty()
+            || !Self::is_numeric_data_type(data_type_ref)
+            || anonymous_value_string.get_container_type() != ContainerType::None
+            || AnonymousValueList::is_value_list(anonymous_value_string)
+            || Self::is_plain_value(data_type_ref, anonymous_value_string)
+        {
+            return Ok(anonymous_value_string.clone());
+        }
+
+        let expression = ArithmeticExpression::parse(value_string).map_err(|error| error.to_string())?;
+        let evaluated_string = if symbol_registry.is_floating_point(data_type_ref) {
+            let value = expression
+                .evaluate(|_reference| None)
+                .map_err(|error| error.to_string())?;
+            let is_in_range = match symbol_registry.get_unit_size_in_bytes(data_type_ref) {
+                4 => (value as f32).is_finite(),
+                _ => value.is_finite(),
+            };
+
+            if !is_in_range {
+                return Err(format!("{} is out of range for {}", value, data_type_ref.get_data_type_id()));
+            }
+
+            value.to_string()
+        } else {
+            expression
+                .evaluate_integer(|_reference| None)
+                .map_err(|error| error.to_string())?
+                .to_string()
+        };
+        let evaluated_value = AnonymousValueString::new(evaluated_string, AnonymousValueStringFormat::Decimal, ContainerType::None);
+
+        match symbol_registry.deanonymize_value_string(data_type_ref, &evaluated_value) {
+            Ok(_data_value) => Ok(evaluated_value),
+            Err(_error) => Err(format!(
+                "{} is out of range for {}",
+                evaluated_value.get_anonymous_value_string(),
+                data_type_ref.get_data_type_id()
+            )),
+        }
+    }
+
+    /// Gets whether the data type holds a single number, rather than text, bytes, or the fields of a struct.
+    fn is_numeric_data_type(data_type_ref: &DataTypeRef) -> bool {
+        let data_type_id = data_type_ref.get_data_type_id();
+
+        !DataTypeStringEncoded::is_string_data_type_id(data_type_id)
+            && data_type_id != DataTypeAob::DATA_TYPE_ID
+            && !SymbolRegistry::get_instance().is_custom_data_type(data_type_ref)
+    }
+
+    /// Gets whether the value parses as is, either in its own format or in the default format of the data type, as the scan does.
+    fn is_plain_value(
+        data_type_ref: &DataTypeRef,
+        anonymous_value_string: &AnonymousValueString,
+    ) -> bool {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let mut default_format_value = anonymous_value_string.clone();
+
+        default_format_value.set_anonymous_value_string_format(symbol_registry.get_default_anonymous_value_string_format(data_type_ref));
+
+        symbol_registry
+            .deanonymize_value_string(data_type_ref, anonymous_value_string)
+            .is_ok()
+            || symbol_registry
+                .deanonymize_value_string(data_type_ref, &default_format_value)
+                .is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScanValueExpression;
+    use squalr_engine_api::structures::data_types::built_in_types::{
+        f32::data_type_f32::DataTypeF32, i8::data_type_i8::DataTypeI8, i32::data_type_i32::DataTypeI32, u8::data_type_u8::DataTypeU8,
+        u64::data_type_u64::DataTypeU64,
+    };
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+
+    fn evaluate(
+        data_type_id: &str,
+        value_string: &str,
+    ) -> Result<String, String> {
+        ScanValueExpression::evaluate(
+            &DataTypeRef::new(data_type_id),
+            &AnonymousValueString::new(value_string.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+        )
+        .map(|anonymous_value_string| anonymous_value_string.get_anonymous_value_string().to_string())
+    }
+
+    #[test]
+    fn expressions_evaluate_with_precedence_hex_and_negative_numbers() {
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "100*4"), Ok("400".to_string()));
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "0x1F4+8"), Ok("508".to_string()));
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "1<<16"), Ok("65536".to_string()));
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "2 + 3 * 4"), Ok("14".to_string()));
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "(2 + 3) * -4"), Ok("-20".to_string()));
+        assert_eq!(evaluate(DataTypeF32::DATA_TYPE_ID, "1.5 * 3"), Ok("4.5".to_string()));
+        assert_eq!(evaluate(DataTypeF32::DATA_TYPE_ID, "-0x10 / 8"), Ok("-2".to_string()));
+
+        // Values that parse as is are left alone, including negative numbers that would otherwise evaluate the same.
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "-42"), Ok("-42".to_string()));
+        assert_eq!(evaluate(DataTypeI32::DATA_TYPE_ID, "1, 2+3"), Ok("1, 2+3".to_string()));
+    }
+
+    #[test]
+    fn results_must_fit_in_the_data_type() {
+        assert_eq!(evaluate(DataTypeU8::DATA_TYPE_ID, "200 + 55"), Ok("255".to_string()));
+        assert!(evaluate(DataTypeU8::DATA_TYPE_ID, "200 + 56").is_err());
+        assert!(evaluate(DataTypeU8::DATA_TYPE_ID, "0 - 1").is_err());
+        assert!(evaluate(DataTypeI8::DATA_TYPE_ID, "-(1 << 7) - 1").is_err());
+        assert_eq!(evaluate(DataTypeU64::DATA_TYPE_ID, "(1 << 64) - 1"), Ok(u64::MAX.to_string()));
+        assert!(evaluate(DataTypeF32::DATA_TYPE_ID, "1e30 * 1e30").is_err());
+
+        // Floats have no shifts, and integers have no fractions.
+        assert!(evaluate(DataTypeF32::DATA_TYPE_ID, "1 << 2").is_err());
+        assert!(evaluate(DataTypeI32::DATA_TYPE_ID, "2.5 * 2").is_err());
+        assert!(evaluate(DataTypeI32::DATA_TYPE_ID, "4 +").is_err());
+    }
+}
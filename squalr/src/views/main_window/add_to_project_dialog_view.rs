@@ -0,0 +1,110 @@
+use crate::app_context::AppContext;
+use crate::ui::widgets::controls::data_type_selector::data_type_selector_view::DataTypeSelectorView;
+use crate::views::main_window::view_data::add_to_project_view_data::{AddToProjectEntryKind, AddToProjectViewData};
+use eframe::egui::{Grid, Response, RichText, TextEdit, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Dialog for adding a single address or code bookmark to the opened project, ie from the memory viewer or disassembler.
+#[derive(Clone)]
+pub struct AddToProjectDialogView {
+    app_context: Arc<AppContext>,
+    add_to_project_view_data: Dependency<AddToProjectViewData>,
+}
+
+impl AddToProjectDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let add_to_project_view_data = app_context
+            .dependency_container
+            .register(AddToProjectViewData::new());
+
+        Self {
+            app_context,
+            add_to_project_view_data,
+        }
+    }
+}
+
+impl Widget for AddToProjectDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let is_dialog_open = self
+            .add_to_project_view_data
+            .read("Add to project dialog state")
+            .map(|view_data| view_data.is_dialog_open)
+            .unwrap_or(false);
+
+        if !is_dialog_open {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_add = false;
+        let mut should_close = false;
+
+        Window::new("Add to project")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.add_to_project_view_data.write("Add to project dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+
+                Grid::new("add_to_project_dialog_fields")
+                    .num_columns(2)
+                    .spacing([8.0, 6.0])
+                    .show(user_interface, |user_interface| {
+                        user_interface.label("Address");
+                        user_interface.label(
+                            RichText::new(view_data.get_display_address())
+                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                .color(theme.hexadecimal_green),
+                        );
+                        user_interface.end_row();
+
+                        user_interface.label("Type");
+                        match view_data.entry_kind {
+                            AddToProjectEntryKind::Address => {
+                                user_interface.add(
+                                    DataTypeSelectorView::new(self.app_context.clone(), &mut view_data.data_type, "add_to_project_data_type_selector")
+                                        .width(220.0),
+                                );
+                            }
+                            AddToProjectEntryKind::CodeBookmark => {
+                                user_interface.label("Code bookmark");
+                            }
+                        }
+                        user_interface.end_row();
+
+                        user_interface.label("Description");
+                        user_interface.add(TextEdit::singleline(&mut view_data.description).desired_width(220.0));
+                        user_interface.end_row();
+                    });
+
+                if let Some(error_message) = &view_data.error_message {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+
+                    if user_interface.button("Add").clicked() {
+                        should_add = true;
+                    }
+                });
+            });
+
+        if should_close {
+            AddToProjectViewData::close_dialog(self.add_to_project_view_data.clone());
+        } else if should_add {
+            AddToProjectViewData::add_to_project(self.add_to_project_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        user_interface.response()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::app_context::AppContext;
+use crate::views::process_selector::view_data::process_selector_view_data::ProcessSelectorViewData;
+use eframe::egui::{Response, RichText, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Asks how to attach to a process that another Squalr instance is already attached to, such that the two never fight over frozen values.
+#[derive(Clone)]
+pub struct AttachConflictDialogView {
+    app_context: Arc<AppContext>,
+    process_selector_view_data: Dependency<ProcessSelectorViewData>,
+}
+
+impl AttachConflictDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let process_selector_view_data = app_context
+            .dependency_container
+            .get_dependency::<ProcessSelectorViewData>();
+
+        Self {
+            app_context,
+            process_selector_view_data,
+        }
+    }
+}
+
+impl Widget for AttachConflictDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let attach_conflict = match self.process_selector_view_data.read("Attach conflict dialog") {
+            Some(view_data) => match &view_data.attach_conflict {
+                Some(attach_conflict) => attach_conflict.clone(),
+                None => return user_interface.response(),
+            },
+            None => return user_interface.response(),
+        };
+
+        let theme = &self.app_context.theme;
+        let mut should_attach_read_only = false;
+        let mut should_take_over = false;
+        let mut should_cancel = false;
+
+        Window::new("Process already attached")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label(format!("{}.", attach_conflict));
+                user_interface.label(
+                    RichText::new("Attach read-only to inspect values without writing, or take over to make the other instance stop freezing.")
+                        .color(theme.foreground_preview),
+                );
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Attach read-only").clicked() {
+                        should_attach_read_only = true;
+                    }
+
+                    if user_interface.button("Take over").clicked() {
+                        should_take_over = true;
+                    }
+
+                    if user_interface.button("Cancel").clicked() {
+                        should_cancel = true;
+                    }
+                });
+            });
+
+        if should_attach_read_only || should_take_over {
+            ProcessSelectorViewData::resolve_attach_conflict(self.process_selector_view_data.clone(), self.app_context.clone(), should_take_over);
+        } else if should_cancel {
+            ProcessSelectorViewData::dismiss_attach_conflict(self.process_selector_view_data.clone());
+        }
+
+        user_interface.response()
+    }
+}
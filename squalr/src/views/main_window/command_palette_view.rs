@@ -0,0 +1,218 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::command_palette_view_data::CommandPaletteViewData;
+use crate::views::main_window::view_data::toasts_view_data::ToastsViewData;
+use eframe::egui::{Align2, Area, Frame, Id, Key, KeyboardShortcut, Modifiers, Order, Response, RichText, ScrollArea, Sense, TextEdit, Ui, Widget, vec2};
+use epaint::{CornerRadius, Margin, Stroke, pos2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// A searchable overlay listing the commands registered by every view, opened with Ctrl+Shift+P.
+#[derive(Clone)]
+pub struct CommandPaletteView {
+    app_context: Arc<AppContext>,
+    command_palette_view_data: Dependency<CommandPaletteViewData>,
+    toasts_view_data: Dependency<ToastsViewData>,
+}
+
+impl CommandPaletteView {
+    pub const TOGGLE_SHORTCUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::SHIFT), Key::P);
+
+    const WIDTH: f32 = 560.0;
+    const ROW_HEIGHT: f32 = 28.0;
+    const MAXIMUM_LIST_HEIGHT: f32 = 360.0;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let command_palette_view_data = app_context
+            .dependency_container
+            .register(CommandPaletteViewData::new());
+        let toasts_view_data = app_context
+            .dependency_container
+            .get_dependency::<ToastsViewData>();
+
+        Self {
+            app_context,
+            command_palette_view_data,
+            toasts_view_data,
+        }
+    }
+}
+
+impl Widget for CommandPaletteView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        if user_interface.input_mut(|input| input.consume_shortcut(&Self::TOGGLE_SHORTCUT)) {
+            CommandPaletteViewData::toggle(self.command_palette_view_data.clone());
+        }
+
+        let mut query = match self.command_palette_view_data.read("Command palette") {
+            Some(view_data) if view_data.is_open => view_data.query.clone(),
+            _ => return user_interface.response(),
+        };
+
+        // Navigation keys are taken before any other view sees them, such that Escape closes the palette rather than cancelling a scan.
+        let (row_delta, should_run_selected, should_close) = user_interface.input_mut(|input| {
+            let row_delta =
+                input.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as i32 - input.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as i32;
+
+            (
+                row_delta,
+                input.consume_key(Modifiers::NONE, Key::Enter),
+                input.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+
+        if should_close {
+            CommandPaletteViewData::close(self.command_palette_view_data.clone());
+
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let command_registry = &self.app_context.command_registry;
+        let mut search_results = command_registry.search(&query);
+        let (mut selected_index, should_focus_query) = match self
+            .command_palette_view_data
+            .write("Command palette move selection")
+        {
+            Some(mut view_data) => {
+                view_data.move_selection(row_delta, search_results.len());
+
+                (view_data.selected_index, view_data.should_focus_query)
+            }
+            None => return user_interface.response(),
+        };
+
+        let mut clicked_index = None;
+        let area_response = Area::new(Id::new("command_palette"))
+            .order(Order::Foreground)
+            .anchor(Align2::CENTER_TOP, vec2(0.0, 72.0))
+            .show(user_interface.ctx(), |user_interface| {
+                Frame::new()
+                    .fill(theme.background_panel)
+                    .stroke(Stroke::new(1.0, theme.submenu_border))
+                    .corner_radius(CornerRadius::same(4))
+                    .inner_margin(Margin::same(8))
+                    .show(user_interface, |user_interface| {
+                        user_interface.set_width(Self::WIDTH);
+
+                        let query_response = user_interface.add(
+                            TextEdit::singleline(&mut query)
+                                .hint_text("Type a command...")
+                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                .desired_width(f32::INFINITY),
+                        );
+
+                        if should_focus_query {
+                            query_response.request_focus();
+                        }
+
+                        // A new query starts from its best match.
+                        if query_response.changed() {
+                            search_results = command_registry.search(&query);
+                            selected_index = 0;
+                        }
+
+                        user_interface.add_space(4.0);
+
+                        if search_results.is_empty() {
+                            user_interface.label(RichText::new("No matching commands.").color(theme.foreground_preview));
+
+                            return;
+                        }
+
+                        ScrollArea::vertical()
+                            .max_height(Self::MAXIMUM_LIST_HEIGHT)
+                            .auto_shrink([false, true])
+                            .show(user_interface, |user_interface| {
+                                for (index, search_result) in search_results.iter().enumerate() {
+                                    let (row_rectangle, row_response) =
+                                        user_interface.allocate_exact_size(vec2(user_interface.available_width(), Self::ROW_HEIGHT), Sense::click());
+                                    let is_enabled = search_result.disabled_reason.is_none();
+                                    let painter = user_interface.painter();
+
+                                    if index == selected_index {
+                                        painter.rect_filled(row_rectangle, CornerRadius::same(2), theme.selected_background);
+
+                                        if row_delta != 0 {
+                                            row_response.scroll_to_me(None);
+                                        }
+                                    } else if row_response.hovered() {
+                                        painter.rect_filled(row_rectangle, CornerRadius::same(2), theme.hover_tint);
+                                    }
+
+                                    let label_color = if is_enabled { theme.foreground } else { theme.foreground_preview };
+                                    let font = theme.font_library.font_noto_sans.font_normal.clone();
+                                    let center_y = row_rectangle.center().y;
+                                    let category_rectangle = painter.text(
+                                        pos2(row_rectangle.min.x + 8.0, center_y),
+                                        Align2::LEFT_CENTER,
+                                        format!("{}:", search_result.command.category),
+                                        font.clone(),
+                                        theme.foreground_preview,
+                                    );
+                                    let label_rectangle = painter.text(
+                                        pos2(category_rectangle.max.x + 6.0, center_y),
+                                        Align2::LEFT_CENTER,
+                                        &search_result.command.label,
+                                        font.clone(),
+                                        label_color,
+                                    );
+
+                                    if let Some(disabled_reason) = &search_result.disabled_reason {
+                                        painter.text(
+                                            pos2(label_rectangle.max.x + 12.0, center_y),
+                                            Align2::LEFT_CENTER,
+                                            disabled_reason,
+                                            font.clone(),
+                                            theme.foreground_preview,
+                                        );
+                                    }
+
+                                    if let Some(shortcut) = &search_result.command.shortcut {
+                                        painter.text(
+                                            pos2(row_rectangle.max.x - 8.0, center_y),
+                                            Align2::RIGHT_CENTER,
+                                            user_interface.ctx().format_shortcut(shortcut),
+                                            font,
+                                            theme.foreground_preview,
+                                        );
+                                    }
+
+                                    if row_response.clicked() {
+                                        clicked_index = Some(index);
+                                    }
+                                }
+                            });
+                    });
+            })
+            .response;
+
+        let run_index = clicked_index.or(should_run_selected.then_some(selected_index));
+        // Disabled commands already show why they cannot run, so choosing one leaves the palette open.
+        let command_to_run = run_index
+            .and_then(|run_index| search_results.get(run_index))
+            .filter(|search_result| search_result.disabled_reason.is_none())
+            .map(|search_result| search_result.command.command_id.clone());
+
+        if command_to_run.is_some() || area_response.clicked_elsewhere() {
+            CommandPaletteViewData::close(self.command_palette_view_data.clone());
+        } else if let Some(mut view_data) = self.command_palette_view_data.write("Command palette update") {
+            view_data.query = query;
+            view_data.selected_index = selected_index;
+            view_data.should_focus_query = false;
+        }
+
+        // Commands run once the palette is closed, through the same requests and deferred frame actions as the buttons they mirror.
+        if let Some(command_id) = command_to_run {
+            if let Err(error) = command_registry.invoke(&command_id) {
+                ToastsViewData::show(self.toasts_view_data.clone(), error);
+            }
+
+            user_interface.ctx().request_repaint();
+        }
+
+        user_interface.response()
+    }
+}
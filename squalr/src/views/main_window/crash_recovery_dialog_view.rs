@@ -0,0 +1,93 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::crash_recovery_view_data::CrashRecoveryViewData;
+use eframe::egui::{Response, RichText, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Shown on launch after a crash, offering to restore what was unsaved and to open the folder containing the crash report.
+#[derive(Clone)]
+pub struct CrashRecoveryDialogView {
+    app_context: Arc<AppContext>,
+    crash_recovery_view_data: Dependency<CrashRecoveryViewData>,
+}
+
+impl CrashRecoveryDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let crash_recovery_view_data = app_context
+            .dependency_container
+            .register(CrashRecoveryViewData::new());
+
+        Self {
+            app_context,
+            crash_recovery_view_data,
+        }
+    }
+}
+
+impl Widget for CrashRecoveryDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let pending_crash = match self.crash_recovery_view_data.read("Crash recovery dialog") {
+            Some(view_data) => match &view_data.pending_crash {
+                Some(pending_crash) => pending_crash.clone(),
+                None => return user_interface.response(),
+            },
+            None => return user_interface.response(),
+        };
+
+        let theme = &self.app_context.theme;
+        let mut should_restore = false;
+        let mut should_dismiss = false;
+        let mut should_open_report_location = false;
+
+        Window::new("Squalr closed unexpectedly")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                match &pending_crash.crash_marker.active_window_id {
+                    Some(active_window_id) => user_interface.label(format!("Squalr crashed last session while drawing {}.", active_window_id)),
+                    None => user_interface.label("Squalr crashed last session."),
+                };
+
+                user_interface
+                    .label(RichText::new(format!("Crash report: {}", pending_crash.crash_marker.report_path.display())).color(theme.foreground_preview));
+
+                match &pending_crash.emergency_save {
+                    Some(emergency_save) => user_interface.label(format!(
+                        "{} unsaved file(s) in project {} were recovered. Restoring overwrites the last saved copies.",
+                        emergency_save.project_files.len(),
+                        emergency_save.project_name
+                    )),
+                    None => user_interface.label("There were no unsaved project changes to recover."),
+                };
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Open crash report location").clicked() {
+                        should_open_report_location = true;
+                    }
+
+                    if user_interface.button("Dismiss").clicked() {
+                        should_dismiss = true;
+                    }
+
+                    if user_interface.button("Restore").clicked() {
+                        should_restore = true;
+                    }
+                });
+            });
+
+        if should_open_report_location {
+            CrashRecoveryViewData::open_crash_report_location();
+        }
+
+        if should_dismiss {
+            CrashRecoveryViewData::dismiss(self.crash_recovery_view_data.clone());
+        } else if should_restore {
+            CrashRecoveryViewData::restore(self.crash_recovery_view_data.clone(), self.app_context.clone());
+        }
+
+        user_interface.response()
+    }
+}
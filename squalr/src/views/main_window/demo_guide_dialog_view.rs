@@ -0,0 +1,119 @@
+use crate::app_context::AppContext;
+use crate::models::demo_target::demo_guide::DemoGuideStep;
+use crate::views::main_window::view_data::demo_guide_view_data::DemoGuideViewData;
+use eframe::egui::{Response, RichText, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Walks through a first scan, a next scan, and a freeze against the demo target, for trying Squalr without a game.
+#[derive(Clone)]
+pub struct DemoGuideDialogView {
+    app_context: Arc<AppContext>,
+    demo_guide_view_data: Dependency<DemoGuideViewData>,
+}
+
+impl DemoGuideDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let demo_guide_view_data = app_context
+            .dependency_container
+            .register(DemoGuideViewData::new());
+
+        DemoGuideViewData::listen_for_scans(demo_guide_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            demo_guide_view_data,
+        }
+    }
+
+    fn get_instructions(
+        step: DemoGuideStep,
+        demo_value: &str,
+    ) -> String {
+        match step {
+            DemoGuideStep::FirstScan => format!(
+                "In the Element Scanner, scan for the demo value, {}, as an i32 that is equal to it. Many addresses will match.",
+                demo_value
+            ),
+            DemoGuideStep::ChangeValue => "Press Change value below. The demo target adds one to its value, as a game would.".to_string(),
+            DemoGuideStep::NextScan => format!("Scan again for the new value, {}. Only the addresses that changed with it remain.", demo_value),
+            DemoGuideStep::Freeze => {
+                "Freeze the remaining results, then press Change value again. A frozen value is written back each time it changes.".to_string()
+            }
+            DemoGuideStep::Complete => "The frozen value held. You are ready to try Squalr on a game.".to_string(),
+        }
+    }
+}
+
+impl Widget for DemoGuideDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let (demo_value, demo_guide, error_message) = match self.demo_guide_view_data.read("Demo guide dialog") {
+            Some(view_data) => {
+                if view_data.demo_target_process.is_none() && view_data.error_message.is_none() {
+                    return user_interface.response();
+                }
+
+                (view_data.demo_value, view_data.demo_guide.clone(), view_data.error_message.clone())
+            }
+            None => return user_interface.response(),
+        };
+
+        let theme = &self.app_context.theme;
+        let demo_value = demo_value
+            .map(|demo_value| demo_value.to_string())
+            .unwrap_or_else(|| "??".to_string());
+        let current_step = demo_guide.get_step();
+        let mut should_change_value = false;
+        let mut should_close = false;
+
+        Window::new("Demo")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                if let Some(error_message) = &error_message {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                } else {
+                    user_interface.label(format!("Demo value: {}", demo_value));
+                    user_interface.separator();
+
+                    for step in DemoGuideStep::ALL {
+                        let (marker, color) = if step == current_step {
+                            ("▶", theme.foreground)
+                        } else if step < current_step {
+                            ("✔", theme.foreground_preview)
+                        } else {
+                            ("•", theme.foreground_preview)
+                        };
+
+                        user_interface.label(RichText::new(format!("{} {}", marker, step.get_title())).color(color));
+                    }
+
+                    user_interface.separator();
+                    user_interface.label(Self::get_instructions(current_step, &demo_value));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if error_message.is_none() && user_interface.button("Change value").clicked() {
+                        should_change_value = true;
+                    }
+
+                    if user_interface.button("Close demo").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_change_value {
+            DemoGuideViewData::change_value(self.demo_guide_view_data.clone());
+        }
+
+        if should_close {
+            DemoGuideViewData::close(self.demo_guide_view_data.clone());
+        }
+
+        user_interface.response()
+    }
+}
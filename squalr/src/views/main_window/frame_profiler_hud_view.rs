@@ -0,0 +1,115 @@
+use crate::app_context::AppContext;
+use crate::ui::frame_profiler::FrameProfiler;
+use eframe::egui::{Grid, Key, Response, RichText, Ui, Widget, Window};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Debug HUD showing what each docked view and frame phase costs, toggled with F12 or from the Help menu.
+#[derive(Clone)]
+pub struct FrameProfilerHudView {
+    app_context: Arc<AppContext>,
+}
+
+impl FrameProfilerHudView {
+    pub const TOGGLE_KEY: Key = Key::F12;
+
+    /// How often the HUD refreshes while nothing else is repainting. Continuous repaints would dominate the costs being measured.
+    const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        Self { app_context }
+    }
+
+    pub fn toggle() {
+        let frame_profiler = FrameProfiler::get_instance();
+
+        frame_profiler.set_enabled(!frame_profiler.is_enabled());
+    }
+
+    /// Writes the current statistics to the log, such that they show up in the output view and in log files attached to issues.
+    pub fn dump_report() {
+        log::info!("Frame profiler report:\n{}", FrameProfiler::get_instance().format_report());
+    }
+}
+
+impl Widget for FrameProfilerHudView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        if user_interface.input(|input| input.key_pressed(Self::TOGGLE_KEY)) {
+            Self::toggle();
+        }
+
+        let frame_profiler = FrameProfiler::get_instance();
+
+        if !frame_profiler.is_enabled() {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let statistics = frame_profiler.get_statistics();
+        let mut should_close = false;
+
+        Window::new("Frame Profiler")
+            .collapsible(true)
+            .resizable(false)
+            .default_pos([16.0, 96.0])
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label(format!(
+                    "Mean and p95 cost over the last {} profiled frames. Press F12 to hide.",
+                    FrameProfiler::ROLLING_WINDOW_FRAME_COUNT
+                ));
+
+                if statistics.is_empty() {
+                    user_interface.label(RichText::new("Waiting for the next frame...").color(theme.foreground_preview));
+                }
+
+                Grid::new("frame_profiler_hud_statistics")
+                    .num_columns(4)
+                    .striped(true)
+                    .spacing([16.0, 4.0])
+                    .show(user_interface, |user_interface| {
+                        user_interface.strong("Scope");
+                        user_interface.strong("Mean (ms)");
+                        user_interface.strong("P95 (ms)");
+                        user_interface.strong("Frames");
+                        user_interface.end_row();
+
+                        for statistics in &statistics {
+                            user_interface.label(&statistics.scope_name);
+                            user_interface.monospace(FrameProfiler::format_milliseconds(statistics.mean));
+                            user_interface.monospace(FrameProfiler::format_milliseconds(statistics.p95));
+                            user_interface.monospace(statistics.sample_count.to_string());
+                            user_interface.end_row();
+                        }
+                    });
+
+                user_interface.separator();
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Copy Report").clicked() {
+                        user_interface.ctx().copy_text(frame_profiler.format_report());
+                    }
+
+                    if user_interface.button("Dump to Log").clicked() {
+                        Self::dump_report();
+                    }
+
+                    if user_interface.button("Close").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_close {
+            frame_profiler.set_enabled(false);
+        }
+
+        user_interface
+            .ctx()
+            .request_repaint_after(Self::REFRESH_INTERVAL);
+
+        user_interface.response()
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{
     app_context::AppContext,
-    ui::widgets::controls::combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView},
-    views::process_selector::view_data::process_selector_view_data::ProcessSelectorViewData,
+    ui::{
+        draw::icon_draw::IconDraw,
+        widgets::controls::{
+            button::Button,
+            combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView},
+        },
+    },
+    views::{main_window::refresh_scheduler_view::RefreshSchedulerView, process_selector::view_data::process_selector_view_data::ProcessSelectorViewData},
 };
-use eframe::egui::{Align, Direction, Layout, Response, Sense, Spinner, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Color32, Direction, Layout, Response, RichText, Sense, Spinner, Ui, UiBuilder, Widget};
 use epaint::{CornerRadius, Rect, vec2};
 use squalr_engine_api::{dependency_injection::dependency::Dependency, events::process::changed::process_changed_event::ProcessChangedEvent};
 use std::{
@@ -15,6 +21,7 @@ use std::{
 pub struct MainShortcutBarView {
     app_context: Arc<AppContext>,
     process_selector_view_data: Dependency<ProcessSelectorViewData>,
+    refresh_scheduler_view: RefreshSchedulerView,
 }
 
 impl MainShortcutBarView {
@@ -22,9 +29,11 @@ impl MainShortcutBarView {
         let process_selector_view_data = app_context
             .dependency_container
             .get_dependency::<ProcessSelectorViewData>();
+        let refresh_scheduler_view = RefreshSchedulerView::new(app_context.clone());
         let instance = Self {
             app_context,
             process_selector_view_data,
+            refresh_scheduler_view,
         };
 
         instance.listen_for_process_change();
@@ -159,7 +168,49 @@ impl Widget for MainShortcutBarView {
             refresh_windowed_processes = true;
         }
 
-        if refresh_windowed_processes {
+        row_user_interface.add_space(4.0);
+        row_user_interface.add(self.refresh_scheduler_view.clone());
+
+        let is_read_only = process_selector_view_data
+            .opened_process
+            .as_ref()
+            .is_some_and(|opened_process| opened_process.get_is_read_only());
+
+        if is_read_only {
+            row_user_interface.add_space(8.0);
+            row_user_interface
+                .label(
+                    RichText::new("Read-only")
+                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                        .color(theme.foreground_preview),
+                )
+                .on_hover_text("Another Squalr instance holds this process, so values are not written or frozen.");
+        }
+
+        let mut dismiss_bitness_notice = false;
+
+        if process_selector_view_data.show_bitness_notice {
+            row_user_interface.add_space(8.0);
+            row_user_interface.label(
+                RichText::new("Attached to a 32-bit process — pointer width and address range adjusted")
+                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                    .color(theme.foreground_preview),
+            );
+
+            let close_response = row_user_interface.add_sized(vec2(24.0, 24.0), Button::new_from_theme(theme).background_color(Color32::TRANSPARENT));
+            IconDraw::draw(&row_user_interface, close_response.rect, &theme.icon_library.icon_handle_close);
+
+            if close_response.clicked() {
+                dismiss_bitness_notice = true;
+            }
+        }
+
+        if dismiss_bitness_notice {
+            // Drop the read lock to free up the data for write lock access.
+            drop(process_selector_view_data);
+
+            ProcessSelectorViewData::dismiss_bitness_notice(self.process_selector_view_data.clone());
+        } else if refresh_windowed_processes {
             // Drop the read lock to free up the data for write lock access.
             drop(process_selector_view_data);
 
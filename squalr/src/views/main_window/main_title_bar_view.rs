@@ -1,9 +1,13 @@
 use crate::app_context::AppContext;
+use crate::models::workspace::workspace::Workspace;
 use crate::ui::draw::icon_draw::IconDraw;
 use crate::ui::widgets::controls::button::Button;
+use crate::ui::widgets::controls::combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView};
+use crate::views::main_window::view_data::workspace_view_data::WorkspaceViewData;
 use eframe::egui::viewport::ViewportCommand;
 use eframe::egui::{Align, Id, Layout, Rect, Response, RichText, Sense, Ui, UiBuilder, Widget, pos2};
 use epaint::{Color32, CornerRadius, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -13,6 +17,14 @@ pub struct MainTitleBarView {
     corner_radius: CornerRadius,
     height: f32,
     title: Rc<String>,
+    workspace_view_data: Dependency<WorkspaceViewData>,
+}
+
+/// A choice made from the workspace switcher, applied once the title bar is done drawing.
+enum WorkspaceSwitcherAction {
+    SwitchTo(Workspace),
+    SaveAs,
+    Open,
 }
 
 impl MainTitleBarView {
@@ -22,11 +34,101 @@ impl MainTitleBarView {
         height: f32,
         title: Rc<String>,
     ) -> Self {
+        let workspace_view_data = app_context
+            .dependency_container
+            .get_dependency::<WorkspaceViewData>();
+
         Self {
             app_context,
             corner_radius,
             height,
             title,
+            workspace_view_data,
+        }
+    }
+
+    /// Adds the dropdown listing the workspaces that can be switched to, along with the save and open actions.
+    fn add_workspace_switcher(
+        &self,
+        user_interface: &mut Ui,
+    ) -> (Response, Option<WorkspaceSwitcherAction>) {
+        let dropdown_width = 224.0;
+        let (label, target_executable) = match self.workspace_view_data.read("Title bar workspace switcher") {
+            Some(view_data) => (
+                match &view_data.active_workspace {
+                    Some(active_workspace) => active_workspace.get_name().to_string(),
+                    None => "No workspace".to_string(),
+                },
+                view_data.target_executable.clone(),
+            ),
+            None => ("No workspace".to_string(), None),
+        };
+        let mut workspace_switcher_action = None;
+        let workspace_switcher = ComboBoxView::new(
+            self.app_context.clone(),
+            &label,
+            "main_title_bar_workspace_switcher",
+            None,
+            |user_interface: &mut Ui, should_close: &mut bool| {
+                // Only read the workspaces folder while the dropdown is open.
+                for workspace in WorkspaceViewData::get_switchable_workspaces(target_executable.as_deref()) {
+                    if user_interface
+                        .add(ComboBoxItemView::new(self.app_context.clone(), workspace.get_name(), None, dropdown_width))
+                        .clicked()
+                    {
+                        workspace_switcher_action = Some(WorkspaceSwitcherAction::SwitchTo(workspace));
+                        *should_close = true;
+
+                        return;
+                    }
+                }
+
+                if user_interface
+                    .add(ComboBoxItemView::new(self.app_context.clone(), "Save workspace as...", None, dropdown_width))
+                    .clicked()
+                {
+                    workspace_switcher_action = Some(WorkspaceSwitcherAction::SaveAs);
+                    *should_close = true;
+
+                    return;
+                }
+
+                if user_interface
+                    .add(ComboBoxItemView::new(self.app_context.clone(), "Open workspace...", None, dropdown_width))
+                    .clicked()
+                {
+                    workspace_switcher_action = Some(WorkspaceSwitcherAction::Open);
+                    *should_close = true;
+                }
+            },
+        )
+        .width(160.0);
+        let response = user_interface.add(workspace_switcher);
+
+        (response, workspace_switcher_action)
+    }
+
+    /// Gets the title, followed by the opened project, which is marked with an asterisk while it has unsaved changes.
+    fn get_title_text(&self) -> String {
+        let opened_project = self
+            .app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+
+        // Never stall a frame waiting on the project, as the title is redrawn constantly.
+        let project_title = match opened_project.try_read() {
+            Ok(opened_project) => opened_project.as_ref().map(|project| {
+                let unsaved_marker = if project.get_has_unsaved_changes() { "*" } else { "" };
+
+                format!("{}{}", project.get_name(), unsaved_marker)
+            }),
+            Err(_) => None,
+        };
+
+        match project_title {
+            Some(project_title) => format!("{} - {}", self.title, project_title),
+            None => self.title.to_string(),
         }
     }
 }
@@ -57,6 +159,7 @@ impl Widget for MainTitleBarView {
             .layout(Layout::left_to_right(Align::Center));
         let mut child_user_interface = user_interface.new_child(builder);
         let mut buttons_rectangle: Option<Rect> = None;
+        let mut workspace_switcher_action = None;
 
         // Hard-clip to the titlebar.
         child_user_interface.set_clip_rect(allocated_size_rectangle);
@@ -71,7 +174,7 @@ impl Widget for MainTitleBarView {
         child_user_interface.add_space(4.0);
 
         child_user_interface.label(
-            RichText::new(self.title.as_ref())
+            RichText::new(self.get_title_text())
                 .font(theme.font_library.font_noto_sans.font_window_title.clone())
                 .color(theme.foreground),
         );
@@ -108,14 +211,29 @@ impl Widget for MainTitleBarView {
                 context.send_viewport_cmd(ViewportCommand::Minimized(true));
             }
 
+            user_interface.add_space(8.0);
+
+            let (workspace_switcher, action) = self.add_workspace_switcher(user_interface);
+
+            workspace_switcher_action = action;
             buttons_rectangle = Some(
                 button_close
                     .rect
                     .union(button_minimize_maximize.rect)
-                    .union(button_minimize.rect),
+                    .union(button_minimize.rect)
+                    .union(workspace_switcher.rect),
             );
         });
 
+        match workspace_switcher_action {
+            Some(WorkspaceSwitcherAction::SwitchTo(workspace)) => {
+                WorkspaceViewData::switch_to_workspace(self.workspace_view_data.clone(), self.app_context.clone(), workspace);
+            }
+            Some(WorkspaceSwitcherAction::SaveAs) => WorkspaceViewData::open_save_as_dialog(self.workspace_view_data.clone()),
+            Some(WorkspaceSwitcherAction::Open) => WorkspaceViewData::open_open_dialog(self.workspace_view_data.clone()),
+            None => {}
+        }
+
         // Drag area = everything left of the buttons, inside the titlebar rect.
         let right_edge = buttons_rectangle
             .map(|rectangle| rectangle.min.x)
@@ -1,19 +1,27 @@
+use crate::models::command_palette::palette_command::PaletteCommand;
+use crate::models::docking::docking_command::DockingCommand;
 use crate::models::toolbar::toolbar_data::ToolbarData;
 use crate::models::toolbar::toolbar_header_item_data::ToolbarHeaderItemData;
 use crate::models::toolbar::toolbar_menu_item_data::ToolbarMenuItemData;
 use crate::ui::widgets::controls::toolbar_menu::toolbar_view::ToolbarView;
 use crate::views::disassembler::disassembler_view::DisassemblerView;
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
+use crate::views::memory_accessors::memory_accessors_view::MemoryAccessorsView;
 use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::views::output::output_view::OutputView;
 use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
 use crate::views::process_selector::process_selector_view::ProcessSelectorView;
 use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
+use crate::views::main_window::frame_profiler_hud_view::FrameProfilerHudView;
+use crate::views::main_window::view_data::demo_guide_view_data::DemoGuideViewData;
+use crate::views::main_window::view_data::restore_modifications_view_data::RestoreModificationsViewData;
+use crate::views::main_window::view_data::update_checker_view_data::UpdateCheckerViewData;
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+use crate::views::watch_expressions::watch_expressions_view::WatchExpressionsView;
 use crate::{app_context::AppContext, models::docking::settings::dockable_window_settings::DockSettingsConfig};
 use eframe::egui::viewport::ViewportCommand;
-use eframe::egui::{Response, Ui, Widget};
+use eframe::egui::{KeyboardShortcut, Modifiers, Response, Ui, Widget};
 use std::sync::{Arc, RwLock};
 
 #[derive(Clone)]
@@ -26,7 +34,11 @@ impl MainToolbarView {
     pub const ACTION_ID_EXIT: &'static str = "exit";
     pub const ACTION_ID_SELECT_PROJECT: &'static str = "select_project";
     pub const ACTION_ID_EXPORT_PROJECT: &'static str = "export_project";
+    pub const ACTION_ID_RESTORE_MODIFICATIONS: &'static str = "restore_modifications";
     pub const ACTION_ID_RESET_LAYOUT: &'static str = "layout_reset";
+    pub const ACTION_ID_OPEN_DEMO_TARGET: &'static str = "open_demo_target";
+    pub const ACTION_ID_CHECK_FOR_UPDATES: &'static str = "check_for_updates";
+    pub const ACTION_ID_TOGGLE_FRAME_PROFILER: &'static str = "toggle_frame_profiler";
 
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let docking_manager_for_process_selector = app_context.docking_manager.clone();
@@ -37,6 +49,8 @@ impl MainToolbarView {
         let docking_manager_for_output = app_context.docking_manager.clone();
         let docking_manager_for_pointer_scanner = app_context.docking_manager.clone();
         let docking_manager_for_element_scanner = app_context.docking_manager.clone();
+        let docking_manager_for_watch_expressions = app_context.docking_manager.clone();
+        let docking_manager_for_memory_accessors = app_context.docking_manager.clone();
         let docking_manager_for_settings = app_context.docking_manager.clone();
 
         let menus = vec![
@@ -45,6 +59,8 @@ impl MainToolbarView {
                 items: vec![
                     ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_SELECT_PROJECT, "Select Project", None),
                     ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_EXPORT_PROJECT, "Export Project as Table...", None),
+                    ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_RESTORE_MODIFICATIONS, "Restore All Modifications...", None).with_separator(),
+                    ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_OPEN_DEMO_TARGET, "Open Demo Target", None).with_separator(),
                     ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_EXIT, "Exit Squalr", None).with_separator(),
                 ]
                 .into(),
@@ -165,6 +181,32 @@ impl MainToolbarView {
                             None
                         })),
                     ),
+                    ToolbarMenuItemData::new(
+                        WatchExpressionsView::WINDOW_ID,
+                        "Watch Expressions",
+                        Some(Box::new(move || {
+                            if let Ok(docking_manager) = docking_manager_for_watch_expressions.read()
+                                && let Some(docked_node) = docking_manager.get_node_by_id(WatchExpressionsView::WINDOW_ID)
+                            {
+                                return Some(docked_node.is_visible());
+                            }
+
+                            None
+                        })),
+                    ),
+                    ToolbarMenuItemData::new(
+                        MemoryAccessorsView::WINDOW_ID,
+                        "Memory Accessors",
+                        Some(Box::new(move || {
+                            if let Ok(docking_manager) = docking_manager_for_memory_accessors.read()
+                                && let Some(docked_node) = docking_manager.get_node_by_id(MemoryAccessorsView::WINDOW_ID)
+                            {
+                                return Some(docked_node.is_visible());
+                            }
+
+                            None
+                        })),
+                    ),
                     ToolbarMenuItemData::new(
                         SettingsView::WINDOW_ID,
                         "Settings",
@@ -199,6 +241,14 @@ impl MainToolbarView {
                 ]
                 .into(),
             },*/
+            ToolbarHeaderItemData {
+                header: "Help".into(),
+                items: vec![
+                    ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_CHECK_FOR_UPDATES, "Check for Updates", None),
+                    ToolbarMenuItemData::new(MainToolbarView::ACTION_ID_TOGGLE_FRAME_PROFILER, "Frame Profiler (F12)", None).with_separator(),
+                ]
+                .into(),
+            },
         ]
         .into();
 
@@ -207,23 +257,100 @@ impl MainToolbarView {
             menus,
         }));
 
+        Self::register_commands(&app_context);
+
         Self {
             app_context,
             menu_toolbar_data,
         }
     }
-}
 
-impl Widget for MainToolbarView {
-    fn ui(
-        self,
-        user_interface: &mut Ui,
-    ) -> Response {
-        let app_context = self.app_context.clone();
-        let callback = &move |selected_id| match selected_id {
+    /// Registers the menu actions with the command palette. Project actions are left out, as the menu does not handle them yet.
+    fn register_commands(app_context: &Arc<AppContext>) {
+        let menu_commands = [
+            ("File", "Restore All Modifications...", MainToolbarView::ACTION_ID_RESTORE_MODIFICATIONS),
+            ("File", "Open Demo Target", MainToolbarView::ACTION_ID_OPEN_DEMO_TARGET),
+            ("File", "Exit Squalr", MainToolbarView::ACTION_ID_EXIT),
+            ("Layout", "Reset Layout", MainToolbarView::ACTION_ID_RESET_LAYOUT),
+            ("Help", "Check for Updates", MainToolbarView::ACTION_ID_CHECK_FOR_UPDATES),
+        ];
+        let windows = [
+            (ProcessSelectorView::WINDOW_ID, "Process Selector"),
+            (ProjectExplorerView::WINDOW_ID, "Project Explorer"),
+            (StructViewerView::WINDOW_ID, "Struct Viewer"),
+            (DisassemblerView::WINDOW_ID, "Disassembler"),
+            (MemoryViewerView::WINDOW_ID, "Memory Viewer"),
+            (OutputView::WINDOW_ID, "Output"),
+            (PointerScannerView::WINDOW_ID, "Pointer Scanner"),
+            (ElementScannerView::WINDOW_ID, "Element Scanner"),
+            (WatchExpressionsView::WINDOW_ID, "Watch Expressions"),
+            (MemoryAccessorsView::WINDOW_ID, "Memory Accessors"),
+            (SettingsView::WINDOW_ID, "Settings"),
+        ];
+
+        for (category, label, action_id) in menu_commands {
+            let app_context_clone = app_context.clone();
+
+            app_context
+                .command_registry
+                .register(PaletteCommand::new(format!("main_toolbar.{}", action_id), category, label, move || {
+                    Self::run_action(&app_context_clone, action_id)
+                }));
+        }
+
+        let app_context_clone = app_context.clone();
+
+        app_context.command_registry.register(
+            PaletteCommand::new(
+                format!("main_toolbar.{}", MainToolbarView::ACTION_ID_TOGGLE_FRAME_PROFILER),
+                "Help",
+                "Toggle Frame Profiler",
+                move || Self::run_action(&app_context_clone, MainToolbarView::ACTION_ID_TOGGLE_FRAME_PROFILER),
+            )
+            .with_shortcut(KeyboardShortcut::new(Modifiers::NONE, FrameProfilerHudView::TOGGLE_KEY)),
+        );
+
+        // Unlike the menu, which toggles windows, the palette only ever shows them, as it is used to go to a window.
+        for (window_id, title) in windows {
+            let app_context_clone = app_context.clone();
+
+            app_context.command_registry.register(PaletteCommand::new(
+                format!("main_toolbar.show.{}", window_id),
+                "Windows",
+                format!("Show {}", title),
+                move || {
+                    app_context_clone.queue_docking_command(DockingCommand::ShowWindow {
+                        window_id: window_id.to_string(),
+                        is_visible: true,
+                    })
+                },
+            ));
+        }
+    }
+
+    /// Runs the action of a menu item, whether it was picked from the menu or from the command palette.
+    fn run_action(
+        app_context: &Arc<AppContext>,
+        action_id: &str,
+    ) {
+        match action_id {
             MainToolbarView::ACTION_ID_EXIT => {
                 app_context.context.send_viewport_cmd(ViewportCommand::Close);
             }
+            MainToolbarView::ACTION_ID_RESTORE_MODIFICATIONS => {
+                let restore_modifications_view_data = app_context
+                    .dependency_container
+                    .get_dependency::<RestoreModificationsViewData>();
+
+                RestoreModificationsViewData::open_dialog(restore_modifications_view_data, app_context.engine_unprivileged_state.clone());
+            }
+            MainToolbarView::ACTION_ID_OPEN_DEMO_TARGET => {
+                let demo_guide_view_data = app_context
+                    .dependency_container
+                    .get_dependency::<DemoGuideViewData>();
+
+                DemoGuideViewData::open_demo_target(demo_guide_view_data, app_context.clone());
+            }
             ProcessSelectorView::WINDOW_ID
             | ProjectExplorerView::WINDOW_ID
             | StructViewerView::WINDOW_ID
@@ -232,29 +359,39 @@ impl Widget for MainToolbarView {
             | OutputView::WINDOW_ID
             | PointerScannerView::WINDOW_ID
             | ElementScannerView::WINDOW_ID
+            | WatchExpressionsView::WINDOW_ID
+            | MemoryAccessorsView::WINDOW_ID
             | SettingsView::WINDOW_ID
             // | "window_disassembly"
             // | "window_code_tracer"
             => {
-                let docking_manager = &app_context.docking_manager;
-
-                if let Ok(mut docking_manager) = docking_manager.write() {
-                    if let Some(is_visible) = docking_manager.get_node_by_id(selected_id).map(|node| node.is_visible()) {
-                        docking_manager.set_window_visible(selected_id, !is_visible);
-                        docking_manager.prepare_for_presentation();
-                    }
-                }
+                app_context.queue_docking_command(DockingCommand::ToggleWindow {
+                    window_id: action_id.to_string(),
+                });
             }
-            MainToolbarView::ACTION_ID_RESET_LAYOUT => match app_context.docking_manager.write() {
-                Ok(mut docking_manager) => {
-                    docking_manager.set_root(DockSettingsConfig::get_default_layout());
-                }
-                Err(error) => {
-                    log::error!("Failed to acquire docking manager to reset layout: {}", error);
-                }
-            },
+            MainToolbarView::ACTION_ID_RESET_LAYOUT => app_context.queue_docking_command(DockingCommand::SetRoot {
+                root_node: DockSettingsConfig::get_default_layout(),
+            }),
+            MainToolbarView::ACTION_ID_CHECK_FOR_UPDATES => {
+                let update_checker_view_data = app_context
+                    .dependency_container
+                    .get_dependency::<UpdateCheckerViewData>();
+
+                UpdateCheckerViewData::check_for_updates(update_checker_view_data, app_context.context.clone(), true);
+            }
+            MainToolbarView::ACTION_ID_TOGGLE_FRAME_PROFILER => FrameProfilerHudView::toggle(),
             _ => {}
-        };
+        }
+    }
+}
+
+impl Widget for MainToolbarView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let app_context = self.app_context.clone();
+        let callback = &move |selected_id: &str| Self::run_action(&app_context, selected_id);
 
         match self.menu_toolbar_data.read() {
             Ok(menu_toolbar_data) => {
@@ -4,10 +4,26 @@ use crate::ui::widgets::docking::dock_root_view_data::DockRootViewData;
 use crate::ui::widgets::docking::docked_window_view::DockedWindowView;
 use crate::views::disassembler::disassembler_view::DisassemblerView;
 use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
+use crate::views::main_window::add_to_project_dialog_view::AddToProjectDialogView;
+use crate::views::main_window::attach_conflict_dialog_view::AttachConflictDialogView;
+use crate::views::main_window::command_palette_view::CommandPaletteView;
+use crate::views::main_window::crash_recovery_dialog_view::CrashRecoveryDialogView;
+use crate::views::main_window::demo_guide_dialog_view::DemoGuideDialogView;
+use crate::views::main_window::frame_profiler_hud_view::FrameProfilerHudView;
 use crate::views::main_window::main_footer_view::MainFooterView;
 use crate::views::main_window::main_shortcut_bar_view::MainShortcutBarView;
 use crate::views::main_window::main_title_bar_view::MainTitleBarView;
 use crate::views::main_window::main_toolbar_view::MainToolbarView;
+use crate::views::main_window::project_item_hotkey_dialog_view::ProjectItemHotkeyDialogView;
+use crate::views::main_window::restore_modifications_dialog_view::RestoreModificationsDialogView;
+use crate::views::main_window::toasts_view::ToastsView;
+use crate::views::main_window::unsaved_changes_dialog_view::UnsavedChangesDialogView;
+use crate::views::main_window::update_banner_view::UpdateBannerView;
+use crate::views::main_window::value_watch_dialog_view::ValueWatchDialogView;
+use crate::views::main_window::workspace_dialog_view::WorkspaceDialogView;
+use crate::views::main_window::write_sequence_dialog_view::WriteSequenceDialogView;
+use crate::views::memory_accessors::memory_accessors_view::MemoryAccessorsView;
+use crate::views::memory_accessors::view_data::memory_accessors_view_data::MemoryAccessorsViewData;
 use crate::views::memory_viewer::memory_viewer_view::MemoryViewerView;
 use crate::views::output::output_view::OutputView;
 use crate::views::pointer_scanner::pointer_scanner_view::PointerScannerView;
@@ -15,9 +31,11 @@ use crate::views::process_selector::process_selector_view::ProcessSelectorView;
 use crate::views::project_explorer::project_explorer_view::ProjectExplorerView;
 use crate::views::settings::settings_view::SettingsView;
 use crate::views::struct_viewer::struct_viewer_view::StructViewerView;
+use crate::views::watch_expressions::watch_expressions_view::WatchExpressionsView;
 use eframe::egui::{Align, Context, Id, Layout, ResizeDirection, Response, Sense, Ui, ViewportCommand, Widget};
 use epaint::CornerRadius;
 use epaint::{Rect, pos2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -27,8 +45,23 @@ pub struct MainWindowView {
     main_title_bar_view: MainTitleBarView,
     main_toolbar_view: MainToolbarView,
     main_shortcut_bar_view: MainShortcutBarView,
+    update_banner_view: UpdateBannerView,
     dock_root_view: DockRootView,
     main_footer_view: MainFooterView,
+    restore_modifications_dialog_view: RestoreModificationsDialogView,
+    add_to_project_dialog_view: AddToProjectDialogView,
+    attach_conflict_dialog_view: AttachConflictDialogView,
+    crash_recovery_dialog_view: CrashRecoveryDialogView,
+    unsaved_changes_dialog_view: UnsavedChangesDialogView,
+    demo_guide_dialog_view: DemoGuideDialogView,
+    write_sequence_dialog_view: WriteSequenceDialogView,
+    value_watch_dialog_view: ValueWatchDialogView,
+    project_item_hotkey_dialog_view: ProjectItemHotkeyDialogView,
+    toasts_view: ToastsView,
+    frame_profiler_hud_view: FrameProfilerHudView,
+    workspace_dialog_view: WorkspaceDialogView,
+    command_palette_view: CommandPaletteView,
+    memory_accessors_view_data: Dependency<MemoryAccessorsViewData>,
     resize_thickness: f32,
 }
 
@@ -38,9 +71,23 @@ impl MainWindowView {
         title: Rc<String>,
         corner_radius: CornerRadius,
     ) -> Self {
+        let restore_modifications_dialog_view = RestoreModificationsDialogView::new(app_context.clone());
+        let add_to_project_dialog_view = AddToProjectDialogView::new(app_context.clone());
+        let attach_conflict_dialog_view = AttachConflictDialogView::new(app_context.clone());
+        let crash_recovery_dialog_view = CrashRecoveryDialogView::new(app_context.clone());
+        let unsaved_changes_dialog_view = UnsavedChangesDialogView::new(app_context.clone());
+        let demo_guide_dialog_view = DemoGuideDialogView::new(app_context.clone());
+        let write_sequence_dialog_view = WriteSequenceDialogView::new(app_context.clone());
+        let value_watch_dialog_view = ValueWatchDialogView::new(app_context.clone());
+        let project_item_hotkey_dialog_view = ProjectItemHotkeyDialogView::new(app_context.clone());
+        let toasts_view = ToastsView::new(app_context.clone());
+        let frame_profiler_hud_view = FrameProfilerHudView::new(app_context.clone());
+        let workspace_dialog_view = WorkspaceDialogView::new(app_context.clone());
+        let command_palette_view = CommandPaletteView::new(app_context.clone());
         let main_title_bar_view = MainTitleBarView::new(app_context.clone(), corner_radius, 32.0, title);
         let main_toolbar_view = MainToolbarView::new(app_context.clone());
         let main_shortcut_bar_view = MainShortcutBarView::new(app_context.clone());
+        let update_banner_view = UpdateBannerView::new(app_context.clone());
         let dock_view_data = Arc::new(DockRootViewData::new());
 
         let app_context_for_output = app_context.clone();
@@ -124,6 +171,24 @@ impl MainWindowView {
             Rc::new("window_element_scanner".to_string()),
         );
 
+        let app_context_for_watch_expressions = app_context.clone();
+        let watch_expressions_view = DockedWindowView::new(
+            app_context_for_watch_expressions.clone(),
+            dock_view_data.clone(),
+            WatchExpressionsView::new(app_context_for_watch_expressions.clone()),
+            Rc::new("Watch Expressions".to_string()),
+            Rc::new("window_watch_expressions".to_string()),
+        );
+
+        let app_context_for_memory_accessors = app_context.clone();
+        let memory_accessors_view = DockedWindowView::new(
+            app_context_for_memory_accessors.clone(),
+            dock_view_data.clone(),
+            MemoryAccessorsView::new(app_context_for_memory_accessors.clone()),
+            Rc::new("Memory Accessors".to_string()),
+            Rc::new("window_memory_accessors".to_string()),
+        );
+
         dock_view_data.set_windows(vec![
             Box::new(output_view),
             Box::new(settings_view),
@@ -134,10 +199,15 @@ impl MainWindowView {
             Box::new(process_selector_view),
             Box::new(element_scanner_view),
             Box::new(pointer_scanner_view),
+            Box::new(watch_expressions_view),
+            Box::new(memory_accessors_view),
         ]);
 
         let dock_root_view = DockRootView::new(app_context.clone(), dock_view_data);
         let main_footer_view = MainFooterView::new(app_context.clone(), corner_radius, 24.0);
+        let memory_accessors_view_data = app_context
+            .dependency_container
+            .get_dependency::<MemoryAccessorsViewData>();
         let resize_thickness = 4.0;
 
         Self {
@@ -145,8 +215,23 @@ impl MainWindowView {
             main_title_bar_view,
             main_toolbar_view,
             main_shortcut_bar_view,
+            update_banner_view,
             dock_root_view,
             main_footer_view,
+            restore_modifications_dialog_view,
+            add_to_project_dialog_view,
+            attach_conflict_dialog_view,
+            crash_recovery_dialog_view,
+            unsaved_changes_dialog_view,
+            demo_guide_dialog_view,
+            write_sequence_dialog_view,
+            value_watch_dialog_view,
+            project_item_hotkey_dialog_view,
+            toasts_view,
+            frame_profiler_hud_view,
+            workspace_dialog_view,
+            command_palette_view,
+            memory_accessors_view_data,
             resize_thickness,
         }
     }
@@ -284,9 +369,12 @@ impl Widget for MainWindowView {
     ) -> Response {
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                // The palette goes first, such that the keys it handles while open are consumed before any other view sees them.
+                user_interface.add(self.command_palette_view);
                 user_interface.add(self.main_title_bar_view);
                 user_interface.add(self.main_toolbar_view);
                 user_interface.add(self.main_shortcut_bar_view);
+                user_interface.add(self.update_banner_view);
 
                 if user_interface.available_rect_before_wrap().is_positive() {
                     user_interface.add_sized(
@@ -299,9 +387,40 @@ impl Widget for MainWindowView {
                 }
 
                 user_interface.add(self.main_footer_view);
+                user_interface.add(self.restore_modifications_dialog_view);
+                user_interface.add(self.add_to_project_dialog_view);
+                user_interface.add(self.attach_conflict_dialog_view);
+                user_interface.add(self.crash_recovery_dialog_view);
+                user_interface.add(self.unsaved_changes_dialog_view);
+                user_interface.add(self.demo_guide_dialog_view);
+                user_interface.add(self.write_sequence_dialog_view);
+                user_interface.add(self.value_watch_dialog_view);
+                user_interface.add(self.project_item_hotkey_dialog_view);
+                user_interface.add(self.toasts_view);
+                user_interface.add(self.workspace_dialog_view);
+                user_interface.add(self.frame_profiler_hud_view);
             })
             .response;
 
+        // Hidden windows are not drawn, so closing the memory accessors window is noticed here instead of by the window itself.
+        let is_memory_accessors_visible = self
+            .app_context
+            .docking_manager
+            .read()
+            .ok()
+            .and_then(|docking_manager| {
+                docking_manager
+                    .get_node_by_id(MemoryAccessorsView::WINDOW_ID)
+                    .map(|docked_node| docked_node.is_visible())
+            })
+            .unwrap_or(false);
+
+        MemoryAccessorsViewData::set_window_visible(
+            self.memory_accessors_view_data.clone(),
+            self.app_context.engine_unprivileged_state.clone(),
+            is_memory_accessors_visible,
+        );
+
         Self::add_resize_handles(&self.app_context.context, user_interface, self.resize_thickness);
 
         response
@@ -1,5 +1,21 @@
+pub mod add_to_project_dialog_view;
+pub mod attach_conflict_dialog_view;
+pub mod command_palette_view;
+pub mod crash_recovery_dialog_view;
+pub mod demo_guide_dialog_view;
+pub mod frame_profiler_hud_view;
 pub mod main_footer_view;
 pub mod main_shortcut_bar_view;
 pub mod main_title_bar_view;
 pub mod main_toolbar_view;
 pub mod main_window_view;
+pub mod project_item_hotkey_dialog_view;
+pub mod refresh_scheduler_view;
+pub mod restore_modifications_dialog_view;
+pub mod toasts_view;
+pub mod unsaved_changes_dialog_view;
+pub mod update_banner_view;
+pub mod value_watch_dialog_view;
+pub mod view_data;
+pub mod workspace_dialog_view;
+pub mod write_sequence_dialog_view;
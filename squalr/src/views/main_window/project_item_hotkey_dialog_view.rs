@@ -0,0 +1,189 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::project_item_hotkeys_view_data::{ProjectItemHotkeyActionKind, ProjectItemHotkeysViewData};
+use eframe::egui::{Button, ComboBox, Event, Grid, Key, Response, RichText, TextEdit, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::hotkeys::hotkey_chord::HotkeyChord;
+use std::sync::Arc;
+
+/// An action requested from the hotkey dialog, deferred until the view data lock is released.
+enum ProjectItemHotkeyDialogAction {
+    Bind,
+    Unbind(HotkeyChord),
+    Close,
+}
+
+/// Dialog for binding global hotkeys that set the value of an address entry, or toggle whether it is frozen.
+#[derive(Clone)]
+pub struct ProjectItemHotkeyDialogView {
+    app_context: Arc<AppContext>,
+    project_item_hotkeys_view_data: Dependency<ProjectItemHotkeysViewData>,
+}
+
+impl ProjectItemHotkeyDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let project_item_hotkeys_view_data = app_context
+            .dependency_container
+            .register(ProjectItemHotkeysViewData::new());
+
+        ProjectItemHotkeysViewData::listen_for_hotkeys(project_item_hotkeys_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            project_item_hotkeys_view_data,
+        }
+    }
+}
+
+impl Widget for ProjectItemHotkeyDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        // Chords are kept registered while the dialog is closed, as hotkeys are pressed while the game has focus.
+        ProjectItemHotkeysViewData::sync_registered_chords(self.project_item_hotkeys_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+
+        let project_item_ref = self
+            .project_item_hotkeys_view_data
+            .read("Project item hotkey dialog state")
+            .and_then(|view_data| view_data.project_item_ref.clone());
+
+        let Some(project_item_ref) = project_item_ref else {
+            return user_interface.response();
+        };
+
+        let theme = &self.app_context.theme;
+        let bound_hotkeys = ProjectItemHotkeysViewData::get_bound_hotkeys(&self.app_context.engine_unprivileged_state, &project_item_ref);
+        let mut action = None;
+
+        Window::new("Hotkeys")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self
+                    .project_item_hotkeys_view_data
+                    .write("Project item hotkey dialog")
+                {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let view_data = &mut *view_data;
+
+                if view_data.is_unsupported {
+                    user_interface.label(RichText::new("Global hotkeys are not supported by the connected engine.").color(theme.error_red));
+                }
+
+                user_interface.label(format!("Hotkeys for '{}'", view_data.project_item_name));
+
+                if bound_hotkeys.is_empty() {
+                    user_interface.label(RichText::new("No hotkeys are bound.").color(theme.foreground_preview));
+                }
+
+                for bound_hotkey in &bound_hotkeys {
+                    user_interface.horizontal(|user_interface| {
+                        if user_interface.button("Remove").clicked() {
+                            action = Some(ProjectItemHotkeyDialogAction::Unbind(bound_hotkey.get_chord().clone()));
+                        }
+
+                        user_interface.label(format!("{}: {}", bound_hotkey.get_chord(), bound_hotkey.get_action().get_description()));
+                    });
+                }
+
+                user_interface.separator();
+
+                // Escape cancels recording, while any other key that can be bound is recorded along with the held modifiers.
+                if view_data.is_recording_chord {
+                    let pressed_key = user_interface.input(|input_state| {
+                        input_state.events.iter().find_map(|event| match event {
+                            Event::Key {
+                                key,
+                                pressed: true,
+                                repeat: false,
+                                modifiers,
+                                ..
+                            } => Some((*key, *modifiers)),
+                            _ => None,
+                        })
+                    });
+
+                    match pressed_key {
+                        Some((Key::Escape, _modifiers)) => view_data.is_recording_chord = false,
+                        Some((key, modifiers)) => view_data.record_chord(modifiers.ctrl, modifiers.shift, modifiers.alt, key.name()),
+                        None => {}
+                    }
+                }
+
+                Grid::new("project_item_hotkey_dialog_fields")
+                    .num_columns(2)
+                    .spacing([8.0, 6.0])
+                    .show(user_interface, |user_interface| {
+                        user_interface.label("Chord");
+                        user_interface.horizontal(|user_interface| {
+                            if view_data.is_recording_chord {
+                                user_interface.label("Press a key...");
+                            } else {
+                                user_interface.add(
+                                    TextEdit::singleline(&mut view_data.chord)
+                                        .hint_text("ie Ctrl+Numpad1")
+                                        .desired_width(140.0),
+                                );
+                            }
+
+                            let record_button_text = if view_data.is_recording_chord { "Cancel" } else { "Record" };
+
+                            if user_interface.add(Button::new(record_button_text)).clicked() {
+                                view_data.is_recording_chord = !view_data.is_recording_chord;
+                            }
+                        });
+                        user_interface.end_row();
+
+                        user_interface.label("Action");
+                        ComboBox::from_id_salt("project_item_hotkey_action_kind")
+                            .width(200.0)
+                            .selected_text(view_data.action_kind.get_display_name())
+                            .show_ui(user_interface, |user_interface| {
+                                for action_kind in ProjectItemHotkeyActionKind::ALL {
+                                    user_interface.selectable_value(&mut view_data.action_kind, action_kind, action_kind.get_display_name());
+                                }
+                            });
+                        user_interface.end_row();
+
+                        if view_data.action_kind == ProjectItemHotkeyActionKind::SetValue {
+                            user_interface.label("Value");
+                            user_interface.add(
+                                TextEdit::singleline(&mut view_data.value)
+                                    .hint_text("ie 999 or 0x3E7")
+                                    .desired_width(200.0),
+                            );
+                            user_interface.end_row();
+                        }
+                    });
+
+                if let Some(error_message) = &view_data.error_message {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Close").clicked() {
+                        action = Some(ProjectItemHotkeyDialogAction::Close);
+                    }
+
+                    if !view_data.is_unsupported && user_interface.button("Bind").clicked() {
+                        action = Some(ProjectItemHotkeyDialogAction::Bind);
+                    }
+                });
+            });
+
+        match action {
+            Some(ProjectItemHotkeyDialogAction::Bind) => {
+                ProjectItemHotkeysViewData::bind_hotkey(self.project_item_hotkeys_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            Some(ProjectItemHotkeyDialogAction::Unbind(chord)) => {
+                ProjectItemHotkeysViewData::unbind_hotkey(self.app_context.engine_unprivileged_state.clone(), project_item_ref, chord);
+            }
+            Some(ProjectItemHotkeyDialogAction::Close) => ProjectItemHotkeysViewData::close_dialog(self.project_item_hotkeys_view_data.clone()),
+            None => {}
+        }
+
+        user_interface.response()
+    }
+}
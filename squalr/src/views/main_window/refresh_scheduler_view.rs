@@ -0,0 +1,101 @@
+use crate::app_context::AppContext;
+use crate::models::refresh_scheduler::refresh_job_queue::RefreshJobQueue;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::widgets::controls::button::Button;
+use crate::ui::widgets::controls::checkbox::Checkbox;
+use eframe::egui::{Color32, DragValue, Popup, PopupCloseBehavior, Response, RichText, Ui, Widget};
+use epaint::vec2;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A shortcut bar button listing every periodic memory read, such that each can be enabled or slowed down, or all paused at once.
+#[derive(Clone)]
+pub struct RefreshSchedulerView {
+    app_context: Arc<AppContext>,
+}
+
+impl RefreshSchedulerView {
+    const MAXIMUM_INTERVAL_MS: u64 = 60_000;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        Self { app_context }
+    }
+}
+
+impl Widget for RefreshSchedulerView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let refresh_scheduler = &self.app_context.refresh_scheduler;
+        let is_paused = refresh_scheduler.is_paused();
+        let refresh_rates_button = user_interface.add_sized(
+            vec2(36.0, 24.0),
+            Button::new_from_theme(theme)
+                .background_color(Color32::TRANSPARENT)
+                .with_tooltip_text("Refresh rates."),
+        );
+
+        IconDraw::draw(user_interface, refresh_rates_button.rect, &theme.icon_library.icon_handle_navigation_refresh);
+
+        Popup::menu(&refresh_rates_button)
+            .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+            .show(|user_interface| {
+                user_interface.horizontal(|user_interface| {
+                    if user_interface
+                        .add(Checkbox::new_from_theme(theme).with_check_state_bool(is_paused))
+                        .clicked()
+                    {
+                        refresh_scheduler.set_paused(!is_paused);
+                    }
+
+                    user_interface
+                        .label("Pause all reads")
+                        .on_hover_text("Stops every view from reading process memory in the background, ie while inspecting a paused game.");
+                });
+
+                user_interface.separator();
+
+                for refresh_job_summary in refresh_scheduler.get_job_summaries() {
+                    user_interface.horizontal(|user_interface| {
+                        if user_interface
+                            .add(Checkbox::new_from_theme(theme).with_check_state_bool(refresh_job_summary.is_enabled))
+                            .clicked()
+                        {
+                            refresh_scheduler.set_job_enabled(refresh_job_summary.job_id, !refresh_job_summary.is_enabled);
+                        }
+
+                        user_interface.label(&refresh_job_summary.name);
+
+                        let mut interval_ms = refresh_job_summary.interval.as_millis() as u64;
+                        let minimum_interval_ms = RefreshJobQueue::MINIMUM_INTERVAL.as_millis() as u64;
+
+                        if user_interface
+                            .add(
+                                DragValue::new(&mut interval_ms)
+                                    .range(minimum_interval_ms..=Self::MAXIMUM_INTERVAL_MS)
+                                    .suffix(" ms"),
+                            )
+                            .changed()
+                        {
+                            refresh_scheduler.set_job_interval(refresh_job_summary.job_id, Duration::from_millis(interval_ms));
+                        }
+                    });
+                }
+            });
+
+        if is_paused {
+            user_interface.add_space(4.0);
+            user_interface
+                .label(
+                    RichText::new("Reads paused")
+                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                        .color(theme.foreground_preview),
+                )
+                .on_hover_text("Background reads are paused. Resume them from the refresh rates menu.");
+        }
+
+        refresh_rates_button
+    }
+}
@@ -0,0 +1,130 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::restore_modifications_view_data::RestoreModificationsViewData;
+use eframe::egui::{Response, RichText, ScrollArea, Spinner, Ui, Widget, Window};
+use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Confirmation dialog for restoring every byte modified this session to its original value.
+#[derive(Clone)]
+pub struct RestoreModificationsDialogView {
+    app_context: Arc<AppContext>,
+    restore_modifications_view_data: Dependency<RestoreModificationsViewData>,
+}
+
+impl RestoreModificationsDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let restore_modifications_view_data = app_context
+            .dependency_container
+            .register(RestoreModificationsViewData::new());
+
+        Self {
+            app_context,
+            restore_modifications_view_data,
+        }
+    }
+}
+
+impl Widget for RestoreModificationsDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let view_data = match self
+            .restore_modifications_view_data
+            .read("Restore modifications dialog")
+        {
+            Some(view_data) => view_data.clone(),
+            None => return user_interface.response(),
+        };
+
+        if !view_data.is_dialog_open {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_restore = false;
+        let mut should_close = false;
+
+        Window::new("Restore all modifications")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                if view_data.is_querying_ledger || view_data.is_restoring {
+                    user_interface.add(Spinner::new().color(theme.foreground));
+                    return;
+                }
+
+                if view_data.is_unsupported {
+                    user_interface.label("The connected engine does not track modifications, so they cannot be restored.");
+
+                    if user_interface.button("Close").clicked() {
+                        should_close = true;
+                    }
+
+                    return;
+                }
+
+                if let Some(restore_results) = &view_data.restore_results {
+                    let restored_count = restore_results
+                        .iter()
+                        .filter(|restore_result| restore_result.success)
+                        .count();
+
+                    user_interface.label(format!("Restored {} of {} address(es).", restored_count, restore_results.len()));
+
+                    ScrollArea::vertical()
+                        .max_height(240.0)
+                        .show(user_interface, |user_interface| {
+                            for restore_result in restore_results {
+                                let status = if restore_result.success { "Restored" } else { "Failed" };
+                                let color = if restore_result.success { theme.foreground } else { theme.error_red };
+
+                                user_interface.label(
+                                    RichText::new(format!("{:016X}  ({} bytes)  {}", restore_result.address, restore_result.byte_count, status)).color(color),
+                                );
+                            }
+                        });
+
+                    if user_interface.button("Close").clicked() {
+                        should_close = true;
+                    }
+
+                    return;
+                }
+
+                if view_data.address_count == 0 {
+                    user_interface.label("No modifications have been made to this process.");
+                } else {
+                    user_interface.label(format!(
+                        "Restore {} address(es) ({}) to their original values? All frozen values will be unfrozen.",
+                        view_data.address_count,
+                        StorageSizeConversions::value_to_metric_size(view_data.byte_count as u128),
+                    ));
+                }
+
+                if view_data.is_capacity_exceeded {
+                    user_interface
+                        .label(RichText::new("The modification ledger is full. Some writes were not recorded and cannot be restored.").color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+
+                    if view_data.address_count > 0 && user_interface.button("Restore").clicked() {
+                        should_restore = true;
+                    }
+                });
+            });
+
+        if should_close {
+            RestoreModificationsViewData::close_dialog(self.restore_modifications_view_data.clone());
+        } else if should_restore {
+            RestoreModificationsViewData::restore_all(self.restore_modifications_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        user_interface.response()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::app_context::AppContext;
+use crate::views::element_scanner::results::element_scanner_results_action_bar_view::ElementScannerResultsActionBarView;
+use crate::views::main_window::view_data::toasts_view_data::ToastsViewData;
+use crate::views::main_window::view_data::value_watches_view_data::ValueWatchesViewData;
+use eframe::egui::{Align2, Area, Frame, Id, Order, Response, RichText, Ui, Widget, vec2};
+use epaint::{CornerRadius, Margin, Stroke};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Shows the toasts of every view in the bottom right of the main window, stacked with the newest at the bottom.
+#[derive(Clone)]
+pub struct ToastsView {
+    app_context: Arc<AppContext>,
+    toasts_view_data: Dependency<ToastsViewData>,
+    value_watches_view_data: Dependency<ValueWatchesViewData>,
+}
+
+impl ToastsView {
+    /// How long a toast takes to fade out at the end of its duration.
+    const FADE_DURATION: Duration = Duration::from_millis(500);
+
+    /// Space kept below the toasts, such that they sit above both the main footer and the footer of a results list docked along
+    /// the bottom of the window.
+    const BOTTOM_MARGIN: f32 = 40.0 + ElementScannerResultsActionBarView::FOOTER_HEIGHT;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let toasts_view_data = app_context.dependency_container.register(ToastsViewData::new());
+        let value_watches_view_data = app_context
+            .dependency_container
+            .get_dependency::<ValueWatchesViewData>();
+
+        Self {
+            app_context,
+            toasts_view_data,
+            value_watches_view_data,
+        }
+    }
+}
+
+impl Widget for ToastsView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let now = Instant::now();
+        let toasts = match self.toasts_view_data.write("Toasts") {
+            Some(mut view_data) => {
+                view_data.remove_expired(now);
+
+                view_data.toasts.clone()
+            }
+            None => return user_interface.response(),
+        };
+
+        // Rows flashing after a watch triggers fade out on the same clock as toasts.
+        let has_flashes = match self.value_watches_view_data.write("Value watch flashes") {
+            Some(mut view_data) => {
+                view_data.remove_expired(now);
+
+                !view_data.triggered_values.is_empty()
+            }
+            None => false,
+        };
+
+        if toasts.is_empty() && !has_flashes {
+            return user_interface.response();
+        }
+
+        // Keep animating until every toast and row flash has finished.
+        user_interface.ctx().request_repaint();
+
+        if toasts.is_empty() {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut clicked_toast_id = None;
+
+        Area::new(Id::new("toasts"))
+            .order(Order::Foreground)
+            .anchor(Align2::RIGHT_BOTTOM, vec2(-16.0, -Self::BOTTOM_MARGIN))
+            .show(user_interface.ctx(), |user_interface| {
+                for toast in &toasts {
+                    let opacity = (toast.get_remaining(now).as_secs_f32() / Self::FADE_DURATION.as_secs_f32()).min(1.0);
+
+                    user_interface.scope(|user_interface| {
+                        user_interface.multiply_opacity(opacity);
+
+                        Frame::new()
+                            .fill(theme.background_panel)
+                            .stroke(Stroke::new(1.0, theme.background_control_warning))
+                            .corner_radius(CornerRadius::same(4))
+                            .inner_margin(Margin::same(8))
+                            .show(user_interface, |user_interface| {
+                                user_interface.set_max_width(320.0);
+                                user_interface.horizontal(|user_interface| {
+                                    user_interface.label(RichText::new(&toast.message).color(theme.foreground));
+
+                                    if let Some(action) = &toast.action
+                                        && user_interface.button(&action.label).clicked()
+                                    {
+                                        clicked_toast_id = Some(toast.toast_id);
+                                    }
+                                });
+                            });
+                    });
+                    user_interface.add_space(4.0);
+                }
+            });
+
+        if let Some(clicked_toast_id) = clicked_toast_id {
+            ToastsViewData::run_action(self.toasts_view_data.clone(), clicked_toast_id);
+        }
+
+        user_interface.response()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::unsaved_changes_view_data::UnsavedChangesViewData;
+use eframe::egui::{Response, RichText, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Asks whether to save the opened project before an action that would otherwise discard its unsaved changes.
+#[derive(Clone)]
+pub struct UnsavedChangesDialogView {
+    app_context: Arc<AppContext>,
+    unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+}
+
+impl UnsavedChangesDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .register(UnsavedChangesViewData::new());
+
+        Self {
+            app_context,
+            unsaved_changes_view_data,
+        }
+    }
+}
+
+impl Widget for UnsavedChangesDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let (pending_action, last_error_message) = match self.unsaved_changes_view_data.read("Unsaved changes dialog") {
+            Some(view_data) => match &view_data.pending_action {
+                Some(pending_action) => (pending_action.clone(), view_data.last_error_message.clone()),
+                None => return user_interface.response(),
+            },
+            None => return user_interface.response(),
+        };
+
+        let theme = &self.app_context.theme;
+        let mut should_save = false;
+        let mut should_discard = false;
+        let mut should_cancel = false;
+
+        Window::new("Unsaved changes")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label(format!(
+                    "The project has unsaved changes. Save them before {}?",
+                    pending_action.get_description()
+                ));
+
+                if let Some(last_error_message) = &last_error_message {
+                    user_interface.label(RichText::new(last_error_message).color(theme.background_control_danger));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Save").clicked() {
+                        should_save = true;
+                    }
+
+                    if user_interface.button("Discard").clicked() {
+                        should_discard = true;
+                    }
+
+                    if user_interface.button("Cancel").clicked() {
+                        should_cancel = true;
+                    }
+                });
+            });
+
+        if should_save {
+            UnsavedChangesViewData::save_and_continue(self.unsaved_changes_view_data.clone(), self.app_context.clone());
+        } else if should_discard {
+            UnsavedChangesViewData::discard_and_continue(self.unsaved_changes_view_data.clone(), self.app_context.clone());
+        } else if should_cancel {
+            UnsavedChangesViewData::cancel(self.unsaved_changes_view_data.clone());
+        }
+
+        user_interface.response()
+    }
+}
@@ -0,0 +1,175 @@
+use crate::app_context::AppContext;
+use crate::models::update_check::release_notes_line::ReleaseNotesLine;
+use crate::views::main_window::view_data::update_checker_view_data::UpdateCheckerViewData;
+use eframe::egui::{Frame, Margin, Response, RichText, ScrollArea, Ui, Widget};
+use squalr_engine::app_provisioner::operations::version_check::release_version::ReleaseVersion;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// A non-intrusive strip beneath the toolbar announcing a newer release, with its release notes and a link to download it.
+#[derive(Clone)]
+pub struct UpdateBannerView {
+    app_context: Arc<AppContext>,
+    update_checker_view_data: Dependency<UpdateCheckerViewData>,
+}
+
+impl UpdateBannerView {
+    const RELEASE_NOTES_MAX_HEIGHT: f32 = 240.0;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let update_checker_view_data = app_context
+            .dependency_container
+            .register(UpdateCheckerViewData::new());
+
+        Self {
+            app_context,
+            update_checker_view_data,
+        }
+    }
+
+    fn draw_release_notes(
+        &self,
+        user_interface: &mut Ui,
+        release_body: &str,
+    ) {
+        let theme = &self.app_context.theme;
+
+        ScrollArea::vertical()
+            .max_height(Self::RELEASE_NOTES_MAX_HEIGHT)
+            .auto_shrink([false, true])
+            .show(user_interface, |user_interface| {
+                for release_notes_line in ReleaseNotesLine::parse_release_notes(release_body) {
+                    match release_notes_line {
+                        ReleaseNotesLine::Heading(heading) => {
+                            user_interface.label(
+                                RichText::new(heading)
+                                    .font(theme.font_library.font_noto_sans.font_header.clone())
+                                    .color(theme.foreground),
+                            );
+                        }
+                        ReleaseNotesLine::Bullet { text, indent_level } => {
+                            user_interface.horizontal(|user_interface| {
+                                user_interface.add_space(12.0 * (indent_level + 1) as f32);
+                                user_interface.label(
+                                    RichText::new(format!("• {}", text))
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                );
+                            });
+                        }
+                        ReleaseNotesLine::Paragraph(text) => {
+                            user_interface.label(
+                                RichText::new(text)
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        }
+                        ReleaseNotesLine::Blank => {
+                            user_interface.add_space(6.0);
+                        }
+                    }
+                }
+            });
+    }
+}
+
+impl Widget for UpdateBannerView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let (available_release, is_release_notes_expanded, manual_check_message) = match self.update_checker_view_data.read("Update banner view") {
+            Some(view_data) => {
+                let available_release = if view_data.is_banner_dismissed {
+                    None
+                } else {
+                    view_data.available_release.clone()
+                };
+
+                (available_release, view_data.is_release_notes_expanded, view_data.manual_check_message.clone())
+            }
+            None => return user_interface.response(),
+        };
+
+        if available_release.is_none() && manual_check_message.is_none() {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_toggle_release_notes = false;
+        let mut should_open_release_page = false;
+        let mut should_dismiss = false;
+
+        let response = Frame::new()
+            .fill(theme.background_control_info_dark)
+            .inner_margin(Margin::symmetric(8, 4))
+            .show(user_interface, |user_interface| {
+                user_interface.set_width(user_interface.available_width());
+
+                user_interface.horizontal(|user_interface| {
+                    let message = match &available_release {
+                        Some(release) => format!(
+                            "Squalr {} is available. You are running {}.",
+                            release.tag_name,
+                            ReleaseVersion::get_current_version()
+                        ),
+                        None => manual_check_message.clone().unwrap_or_default(),
+                    };
+
+                    user_interface.label(
+                        RichText::new(message)
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .color(theme.foreground),
+                    );
+
+                    if let Some(release) = &available_release {
+                        let has_release_notes = release
+                            .body
+                            .as_ref()
+                            .is_some_and(|body| !body.trim().is_empty());
+                        let release_notes_label = if is_release_notes_expanded {
+                            "Hide release notes"
+                        } else {
+                            "View release notes"
+                        };
+
+                        if has_release_notes && user_interface.button(release_notes_label).clicked() {
+                            should_toggle_release_notes = true;
+                        }
+
+                        if release.html_url.is_some() && user_interface.button("Download").clicked() {
+                            should_open_release_page = true;
+                        }
+                    }
+
+                    if user_interface.button("Dismiss").clicked() {
+                        should_dismiss = true;
+                    }
+                });
+
+                if is_release_notes_expanded
+                    && let Some(release_body) = available_release
+                        .as_ref()
+                        .and_then(|release| release.body.as_ref())
+                {
+                    user_interface.add_space(4.0);
+                    self.draw_release_notes(user_interface, release_body);
+                }
+            })
+            .response;
+
+        if should_toggle_release_notes {
+            UpdateCheckerViewData::toggle_release_notes(self.update_checker_view_data.clone());
+        }
+
+        if should_open_release_page && let Some(release) = &available_release {
+            UpdateCheckerViewData::open_release_page(release);
+        }
+
+        if should_dismiss {
+            UpdateCheckerViewData::dismiss(self.update_checker_view_data.clone());
+        }
+
+        response
+    }
+}
@@ -0,0 +1,147 @@
+use crate::app_context::AppContext;
+use crate::ui::widgets::controls::checkbox::Checkbox;
+use crate::views::main_window::view_data::toasts_view_data::ToastsViewData;
+use crate::views::main_window::view_data::value_watches_view_data::{ValueWatchConditionKind, ValueWatchesViewData};
+use eframe::egui::{ComboBox, Grid, Response, RichText, TextEdit, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Dialog for choosing when a watched value notifies the user that it changed.
+#[derive(Clone)]
+pub struct ValueWatchDialogView {
+    app_context: Arc<AppContext>,
+    value_watches_view_data: Dependency<ValueWatchesViewData>,
+}
+
+impl ValueWatchDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let value_watches_view_data = app_context
+            .dependency_container
+            .register(ValueWatchesViewData::new());
+
+        ValueWatchesViewData::listen_for_triggers(
+            value_watches_view_data.clone(),
+            app_context
+                .dependency_container
+                .get_dependency::<ToastsViewData>(),
+            app_context.engine_unprivileged_state.clone(),
+            app_context.context.clone(),
+        );
+
+        Self {
+            app_context,
+            value_watches_view_data,
+        }
+    }
+}
+
+impl Widget for ValueWatchDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let is_dialog_open = self
+            .value_watches_view_data
+            .read("Value watch dialog state")
+            .map(|view_data| view_data.is_dialog_open)
+            .unwrap_or(false);
+
+        if !is_dialog_open {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_start_watch = false;
+        let mut should_close = false;
+
+        Window::new("Notify on change")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.value_watches_view_data.write("Value watch dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let view_data = &mut *view_data;
+
+                if view_data.is_unsupported {
+                    user_interface.label(RichText::new("Change notifications are not supported by the connected engine.").color(theme.error_red));
+                }
+
+                let address_text = if view_data.module_name.is_empty() {
+                    format!("{:X}", view_data.address)
+                } else {
+                    format!("{}+{:X}", view_data.module_name, view_data.address)
+                };
+
+                Grid::new("value_watch_dialog_fields")
+                    .num_columns(2)
+                    .spacing([8.0, 6.0])
+                    .show(user_interface, |user_interface| {
+                        user_interface.label("Address");
+                        user_interface.label(format!("{} ({})", address_text, view_data.data_type_ref));
+                        user_interface.end_row();
+
+                        user_interface.label("Name");
+                        user_interface.add(
+                            TextEdit::singleline(&mut view_data.label)
+                                .hint_text("Shown in the notification")
+                                .desired_width(200.0),
+                        );
+                        user_interface.end_row();
+
+                        user_interface.label("Notify on");
+                        ComboBox::from_id_salt("value_watch_condition_kind")
+                            .width(200.0)
+                            .selected_text(view_data.condition_kind.get_display_name())
+                            .show_ui(user_interface, |user_interface| {
+                                for condition_kind in ValueWatchConditionKind::ALL {
+                                    user_interface.selectable_value(&mut view_data.condition_kind, condition_kind, condition_kind.get_display_name());
+                                }
+                            });
+                        user_interface.end_row();
+
+                        if view_data.condition_kind != ValueWatchConditionKind::AnyChange {
+                            user_interface.label("Value");
+                            user_interface.add(
+                                TextEdit::singleline(&mut view_data.condition_value)
+                                    .hint_text("ie 100 or 0x64")
+                                    .desired_width(200.0),
+                            );
+                            user_interface.end_row();
+                        }
+
+                        user_interface.label("Play sound");
+                        if user_interface
+                            .add(Checkbox::new_from_theme(theme).with_check_state_bool(view_data.play_sound))
+                            .clicked()
+                        {
+                            view_data.play_sound = !view_data.play_sound;
+                        }
+                        user_interface.end_row();
+                    });
+
+                if let Some(error_message) = &view_data.error_message {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+
+                    if !view_data.is_unsupported && user_interface.button("Notify").clicked() {
+                        should_start_watch = true;
+                    }
+                });
+            });
+
+        if should_close {
+            ValueWatchesViewData::close_dialog(self.value_watches_view_data.clone());
+        } else if should_start_watch {
+            ValueWatchesViewData::start_watch(self.value_watches_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        user_interface.response()
+    }
+}
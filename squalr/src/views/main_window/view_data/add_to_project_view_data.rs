@@ -0,0 +1,135 @@
+use crate::ui::converters::data_type_to_string_converter::DataTypeToStringConverter;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_code_bookmark::ProjectItemTypeCodeBookmark;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use std::sync::Arc;
+
+/// The kind of project entry created by the add to project dialog.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AddToProjectEntryKind {
+    /// A typed address entry, as created from scan results.
+    Address,
+    /// An instruction location without a value.
+    CodeBookmark,
+}
+
+#[derive(Clone)]
+pub struct AddToProjectViewData {
+    pub is_dialog_open: bool,
+    pub entry_kind: AddToProjectEntryKind,
+    /// The address of the entry, relative to the module if one is set.
+    pub address: u64,
+    pub module: String,
+    pub data_type: DataTypeRef,
+    pub description: String,
+    pub error_message: Option<String>,
+}
+
+impl AddToProjectViewData {
+    const CODE_BOOKMARK_NAME: &'static str = "Code Bookmark";
+
+    pub fn new() -> Self {
+        Self {
+            is_dialog_open: false,
+            entry_kind: AddToProjectEntryKind::Address,
+            address: 0,
+            module: String::new(),
+            data_type: DataTypeRef::new(DataTypeU8::get_data_type_id()),
+            description: String::new(),
+            error_message: None,
+        }
+    }
+
+    /// Opens the dialog pre-filled with the given entry. The data type is ignored for code bookmarks.
+    pub fn open_dialog(
+        add_to_project_view_data: Dependency<Self>,
+        entry_kind: AddToProjectEntryKind,
+        address: u64,
+        module: &str,
+        data_type: DataTypeRef,
+    ) {
+        if let Some(mut view_data) = add_to_project_view_data.write("Add to project open dialog") {
+            view_data.is_dialog_open = true;
+            view_data.entry_kind = entry_kind;
+            view_data.address = address;
+            view_data.module = module.to_string();
+            view_data.data_type = data_type;
+            view_data.description.clear();
+            view_data.error_message = None;
+        }
+    }
+
+    pub fn close_dialog(add_to_project_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = add_to_project_view_data.write("Add to project close dialog") {
+            view_data.is_dialog_open = false;
+        }
+    }
+
+    /// Adds the entry to the opened project, keeping the dialog open with an error if it could not be added.
+    pub fn add_to_project(
+        add_to_project_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let project_item = match add_to_project_view_data.read("Add to project create entry") {
+            Some(view_data) => view_data.create_project_item(),
+            None => return,
+        };
+        let result = project_item.and_then(|project_item| {
+            engine_unprivileged_state
+                .get_project_manager()
+                .add_project_items(vec![project_item])
+        });
+
+        if let Some(mut view_data) = add_to_project_view_data.write("Add to project result") {
+            match result {
+                Ok(()) => view_data.is_dialog_open = false,
+                Err(error) => view_data.error_message = Some(error),
+            }
+        }
+    }
+
+    /// Gets the address as displayed in the dialog, ie `game.exe+1A0` or an absolute address.
+    pub fn get_display_address(&self) -> String {
+        if self.module.is_empty() {
+            format!("{:016X}", self.address)
+        } else {
+            format!("{}+{:X}", self.module, self.address)
+        }
+    }
+
+    fn create_project_item(&self) -> Result<ProjectItem, String> {
+        match self.entry_kind {
+            AddToProjectEntryKind::Address => {
+                let freeze_value = SymbolRegistry::get_instance()
+                    .get_default_value(&self.data_type)
+                    .ok_or_else(|| format!("The data type '{}' is not registered.", self.data_type.get_data_type_id()))?;
+                let base_name = DataTypeToStringConverter::convert_data_type_to_display_string(self.data_type.get_data_type_id());
+
+                Ok(ProjectItemTypeAddress::new_project_item(
+                    &base_name,
+                    self.address,
+                    &self.module,
+                    &self.description,
+                    freeze_value,
+                ))
+            }
+            AddToProjectEntryKind::CodeBookmark => Ok(ProjectItemTypeCodeBookmark::new_project_item(
+                Self::CODE_BOOKMARK_NAME,
+                self.address,
+                &self.module,
+                &self.description,
+            )),
+        }
+    }
+}
+
+impl Default for AddToProjectViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,87 @@
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+
+/// The state of the command palette overlay, which searches the commands registered by every view.
+#[derive(Clone)]
+pub struct CommandPaletteViewData {
+    pub is_open: bool,
+    pub query: String,
+    /// The highlighted row among the search results, which Enter runs.
+    pub selected_index: usize,
+    /// Set when the palette opens, such that the query box takes keyboard focus on the next frame.
+    pub should_focus_query: bool,
+}
+
+impl CommandPaletteViewData {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            query: String::new(),
+            selected_index: 0,
+            should_focus_query: false,
+        }
+    }
+
+    /// Opens the palette with an empty query, or closes it if it is already open.
+    pub fn toggle(command_palette_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = command_palette_view_data.write("Command palette toggle") {
+            let is_open = !view_data.is_open;
+
+            *view_data = Self::new();
+            view_data.is_open = is_open;
+            view_data.should_focus_query = is_open;
+        }
+    }
+
+    pub fn close(command_palette_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = command_palette_view_data.write("Command palette close") {
+            *view_data = Self::new();
+        }
+    }
+
+    /// Moves the highlighted row by the given number of rows, wrapping around at either end of the results.
+    pub fn move_selection(
+        &mut self,
+        row_delta: i32,
+        result_count: usize,
+    ) {
+        if result_count == 0 {
+            self.selected_index = 0;
+
+            return;
+        }
+
+        let selected_index = self.selected_index.min(result_count - 1) as i64 + row_delta as i64;
+
+        self.selected_index = selected_index.rem_euclid(result_count as i64) as usize;
+    }
+}
+
+impl Default for CommandPaletteViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandPaletteViewData;
+
+    #[test]
+    fn selection_wraps_around_the_results() {
+        let mut command_palette_view_data = CommandPaletteViewData::new();
+
+        command_palette_view_data.move_selection(-1, 3);
+        assert_eq!(command_palette_view_data.selected_index, 2);
+
+        command_palette_view_data.move_selection(1, 3);
+        assert_eq!(command_palette_view_data.selected_index, 0);
+
+        // A selection left past the end by a narrower query moves from the last result.
+        command_palette_view_data.selected_index = 7;
+        command_palette_view_data.move_selection(-1, 3);
+        assert_eq!(command_palette_view_data.selected_index, 1);
+
+        command_palette_view_data.move_selection(1, 0);
+        assert_eq!(command_palette_view_data.selected_index, 0);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::app_context::AppContext;
+use crate::models::crash_recovery::crash_recovery::{CrashRecovery, PendingCrash};
+use crate::models::docking::docking_command::DockingCommand;
+use squalr_engine_api::commands::project::open::project_open_request::ProjectOpenRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CrashRecoveryViewData {
+    /// The crash left behind by the previous session. The dialog is shown for as long as this is set.
+    pub pending_crash: Option<PendingCrash>,
+}
+
+impl CrashRecoveryViewData {
+    pub fn new() -> Self {
+        Self { pending_crash: None }
+    }
+
+    pub fn show_pending_crash(
+        crash_recovery_view_data: Dependency<CrashRecoveryViewData>,
+        pending_crash: Option<PendingCrash>,
+    ) {
+        if let Some(mut view_data) = crash_recovery_view_data.write("Crash recovery show pending crash") {
+            view_data.pending_crash = pending_crash;
+        }
+    }
+
+    /// Brings back the window that was active when Squalr crashed, then writes the emergency save back into the project and reopens it.
+    pub fn restore(
+        crash_recovery_view_data: Dependency<CrashRecoveryViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let pending_crash = match crash_recovery_view_data.write("Crash recovery restore") {
+            Some(mut view_data) => match view_data.pending_crash.take() {
+                Some(pending_crash) => pending_crash,
+                None => return,
+            },
+            None => return,
+        };
+
+        if let Some(active_window_id) = &pending_crash.crash_marker.active_window_id {
+            app_context.queue_docking_command(DockingCommand::ShowWindow {
+                window_id: active_window_id.clone(),
+                is_visible: true,
+            });
+        }
+
+        let emergency_save = match pending_crash.emergency_save {
+            Some(emergency_save) => emergency_save,
+            None => return,
+        };
+
+        match emergency_save.restore_project_files() {
+            Ok(restored_file_count) => {
+                log::info!("Restored {} unsaved file(s) to project {}.", restored_file_count, emergency_save.project_name);
+                CrashRecovery::clear_emergency_save(CrashRecovery::get_crash_directory());
+            }
+            Err(error) => {
+                log::error!("Failed to restore emergency save: {}", error);
+                return;
+            }
+        }
+
+        let project_open_request = ProjectOpenRequest {
+            open_file_browser: false,
+            project_directory_path: emergency_save.project_directory,
+            project_name: None,
+        };
+
+        if project_open_request.project_directory_path.is_none() {
+            return;
+        }
+
+        project_open_request.send(&app_context.engine_unprivileged_state, |project_open_response| {
+            if !project_open_response.success {
+                log::error!("Failed to reopen restored project!")
+            }
+        });
+    }
+
+    pub fn dismiss(crash_recovery_view_data: Dependency<CrashRecoveryViewData>) {
+        if let Some(mut view_data) = crash_recovery_view_data.write("Crash recovery dismiss") {
+            view_data.pending_crash = None;
+        }
+
+        CrashRecovery::clear_emergency_save(CrashRecovery::get_crash_directory());
+    }
+
+    pub fn open_crash_report_location() {
+        #[cfg(any(target_os = "windows", target_os = "macos", target_os = "linux"))]
+        {
+            if let Err(error) = opener::open(CrashRecovery::get_crash_directory()) {
+                log::error!("Failed to open crash report location: {}", error);
+            }
+        }
+    }
+}
+
+impl Default for CrashRecoveryViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,383 @@
+use crate::app_context::AppContext;
+use crate::models::demo_target::demo_guide::DemoGuide;
+use crate::models::demo_target::demo_target_process::DemoTargetProcess;
+use crate::models::docking::docking_command::DockingCommand;
+use crate::views::element_scanner::scanner::element_scanner_view::ElementScannerView;
+use crate::views::process_selector::view_data::process_selector_view_data::ProcessSelectorViewData;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::events::scan_results::updated::scan_results_updated_event::ScanResultsUpdatedEvent;
+use std::process::Command;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct DemoGuideViewData {
+    /// The running demo target. The guide is shown for as long as this is set.
+    pub demo_target_process: Option<Arc<DemoTargetProcess>>,
+
+    /// The value most recently reported by the demo target.
+    pub demo_value: Option<i32>,
+    pub demo_guide: DemoGuide,
+    pub error_message: Option<String>,
+    /// Keeps the listener advancing the guide registered for as long as this view data lives.
+    pub scan_results_subscription: Option<EngineEventSubscription>,
+}
+
+impl DemoGuideViewData {
+    pub fn new() -> Self {
+        Self {
+            demo_target_process: None,
+            demo_value: None,
+            demo_guide: DemoGuide::new(),
+            error_message: None,
+            scan_results_subscription: None,
+        }
+    }
+
+    /// Advances the guide each time a scan completes, while a demo target is running.
+    pub fn listen_for_scans(
+        demo_guide_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let demo_guide_view_data_filter = demo_guide_view_data.clone();
+        let demo_guide_view_data_clone = demo_guide_view_data.clone();
+        let scan_results_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<ScanResultsUpdatedEvent>(
+            move |scan_results_updated_event| {
+                !scan_results_updated_event.is_new_scan
+                    && demo_guide_view_data_filter
+                        .read("Demo guide scan completed filter")
+                        .is_some_and(|view_data| view_data.demo_target_process.is_some())
+            },
+            move |_scan_results_updated_event| {
+                if let Some(mut view_data) = demo_guide_view_data_clone.write("Demo guide scan completed") {
+                    view_data.demo_guide.on_scan_completed();
+                }
+            },
+        );
+
+        // Replacing any previous subscription drops it, such that listening again does not advance the guide twice per scan.
+        if let Some(mut view_data) = demo_guide_view_data.write("Demo guide subscribe") {
+            view_data.scan_results_subscription = Some(scan_results_subscription);
+        }
+    }
+
+    /// Launches a demo target, attaches to it, and shows the guide alongside the element scanner.
+    pub fn open_demo_target(
+        demo_guide_view_data: Dependency<Self>,
+        app_context: Arc<AppContext>,
+    ) {
+        match DemoTargetProcess::create_command() {
+            Ok(command) => Self::open_demo_target_with_command(demo_guide_view_data, app_context, command),
+            Err(error) => Self::show_error(demo_guide_view_data, format!("Failed to locate the Squalr executable: {}", error)),
+        }
+    }
+
+    fn open_demo_target_with_command(
+        demo_guide_view_data: Dependency<Self>,
+        app_context: Arc<AppContext>,
+        command: Command,
+    ) {
+        let demo_guide_view_data_clone = demo_guide_view_data.clone();
+        let demo_target_process = match DemoTargetProcess::spawn(command, move |value| Self::on_value_reported(demo_guide_view_data_clone.clone(), value)) {
+            Ok(demo_target_process) => demo_target_process,
+            Err(error) => {
+                Self::show_error(demo_guide_view_data, format!("Failed to start the demo target: {}", error));
+
+                return;
+            }
+        };
+        let process_id = demo_target_process.get_process_id();
+
+        match demo_guide_view_data.write("Demo guide open demo target") {
+            Some(mut view_data) => {
+                // Replacing a previous demo target drops it, which ends its process.
+                view_data.demo_target_process = Some(Arc::new(demo_target_process));
+                view_data.demo_guide = DemoGuide::new();
+                view_data.error_message = None;
+            }
+            None => return,
+        }
+
+        log::info!("Started demo target (pid {}).", process_id);
+
+        let process_selector_view_data = app_context
+            .dependency_container
+            .get_dependency::<ProcessSelectorViewData>();
+
+        ProcessSelectorViewData::select_process(process_selector_view_data, app_context.clone(), Some(process_id));
+        app_context.queue_docking_command(DockingCommand::ShowWindow {
+            window_id: ElementScannerView::WINDOW_ID.to_string(),
+            is_visible: true,
+        });
+    }
+
+    /// Tells the demo target to increase its value by one, as a game would when the player is hit or picks something up.
+    pub fn change_value(demo_guide_view_data: Dependency<Self>) {
+        let mut view_data = match demo_guide_view_data.write("Demo guide change value") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let Some(demo_target_process) = view_data.demo_target_process.clone() else {
+            return;
+        };
+
+        if let Err(error) = demo_target_process.increase_value() {
+            log::error!("Failed to change the demo target value: {}", error);
+
+            return;
+        }
+
+        let demo_value = view_data.demo_value;
+
+        view_data.demo_guide.on_value_change_requested(demo_value);
+    }
+
+    /// Ends the demo target and hides the guide.
+    pub fn close(demo_guide_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = demo_guide_view_data.write("Demo guide close") {
+            view_data.demo_target_process = None;
+            view_data.demo_value = None;
+            view_data.error_message = None;
+        }
+    }
+
+    fn on_value_reported(
+        demo_guide_view_data: Dependency<Self>,
+        value: i32,
+    ) {
+        if let Some(mut view_data) = demo_guide_view_data.write("Demo guide value reported") {
+            view_data.demo_value = Some(value);
+            view_data.demo_guide.on_value_reported(value);
+        }
+    }
+
+    fn show_error(
+        demo_guide_view_data: Dependency<Self>,
+        error_message: String,
+    ) {
+        log::error!("{}", error_message);
+
+        if let Some(mut view_data) = demo_guide_view_data.write("Demo guide show error") {
+            view_data.error_message = Some(error_message);
+        }
+    }
+}
+
+impl Default for DemoGuideViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DemoGuideViewData;
+    use crate::app_context::AppContext;
+    use crate::models::demo_target::demo_guide::DemoGuideStep;
+    use crate::models::demo_target::demo_target::DemoTarget;
+    use crate::models::docking::docking_manager::DockingManager;
+    use crate::models::docking::hierarchy::dock_node::DockNode;
+    use crate::ui::theme::Theme;
+    use crate::views::element_scanner::results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData;
+    use crate::views::element_scanner::results::view_data::stale_results_policy::StaleResultsPolicy;
+    use crate::views::element_scanner::scanner::element_scanner_view_state::ElementScannerViewState;
+    use crate::views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData;
+    use crate::views::process_selector::view_data::process_selector_view_data::ProcessSelectorViewData;
+    use eframe::egui;
+    use squalr_engine::engine_mode::EngineMode;
+    use squalr_engine::squalr_engine::SqualrEngine;
+    use squalr_engine_api::dependency_injection::dependency::Dependency;
+    use std::process::Command;
+    use std::sync::{Arc, RwLock};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    const DEMO_TARGET_HELPER_VARIABLE: &str = "SQUALR_DEMO_TARGET_HELPER";
+
+    /// Stands in for the Squalr executable in the smoke test, which launches this test binary as its demo target.
+    #[test]
+    #[ignore = "Only runs as the demo target process launched by the demo smoke test."]
+    fn demo_target_helper() {
+        if std::env::var_os(DEMO_TARGET_HELPER_VARIABLE).is_some() {
+            // The test harness leaves the name of this test unterminated on stdout, ahead of the first value report.
+            println!();
+            DemoTarget::run();
+        }
+    }
+
+    fn wait_until(
+        description: &str,
+        mut condition: impl FnMut() -> bool,
+    ) {
+        let deadline = Instant::now() + Duration::from_secs(60);
+
+        while !condition() {
+            assert!(Instant::now() < deadline, "Timed out waiting until {}.", description);
+            thread::sleep(Duration::from_millis(25));
+        }
+    }
+
+    fn read<T: Clone + Send + Sync + 'static, R>(
+        dependency: &Dependency<T>,
+        reader: impl FnOnce(&T) -> R,
+    ) -> R {
+        reader(
+            &dependency
+                .read("Demo smoke test read")
+                .expect("Dependency should be readable."),
+        )
+    }
+
+    fn scan_for_value(
+        squalr_engine: &SqualrEngine,
+        element_scanner_view_data: &Dependency<ElementScannerViewData>,
+        element_scanner_results_view_data: &Dependency<ElementScannerResultsViewData>,
+        app_context: &Arc<AppContext>,
+        value: i32,
+    ) -> u64 {
+        let snapshot = squalr_engine
+            .get_engine_privileged_state()
+            .as_ref()
+            .expect("Standalone engine should have privileged state.")
+            .get_snapshot();
+
+        if let Some(mut view_data) = element_scanner_view_data.write("Demo smoke test set scan value") {
+            view_data.scan_values_and_constraints[0]
+                .current_scan_value
+                .set_anonymous_value_string(value.to_string());
+        }
+
+        ElementScannerViewData::start_scan(element_scanner_view_data.clone(), app_context.engine_unprivileged_state.clone());
+        wait_until("the scan results are shown", || {
+            let scan_generation = snapshot
+                .read()
+                .map(|snapshot| snapshot.get_scan_generation())
+                .unwrap_or_default();
+
+            read(element_scanner_view_data, |view_data| {
+                view_data.view_state == ElementScannerViewState::HasResults
+            }) && read(element_scanner_results_view_data, |view_data| {
+                view_data.latest_scan_generation == scan_generation && !view_data.is_querying_scan_results
+            })
+        });
+
+        read(element_scanner_results_view_data, |view_data| view_data.result_count)
+    }
+
+    /// Walks through the demo from the menu action onwards, against a real engine attached to a real demo target process.
+    #[test]
+    #[cfg_attr(not(windows), ignore = "Opening processes is not yet implemented on this platform.")]
+    fn demo_walkthrough_attaches_scans_and_freezes() {
+        let squalr_engine = SqualrEngine::new(EngineMode::Standalone).expect("Engine should start.");
+        let engine_unprivileged_state = squalr_engine
+            .get_engine_unprivileged_state()
+            .clone()
+            .expect("Standalone engine should have unprivileged state.");
+
+        engine_unprivileged_state.initialize();
+
+        let context = egui::Context::default();
+        let theme = Arc::new(Theme::new(&context));
+        let docking_manager = Arc::new(RwLock::new(DockingManager::new(DockNode::Window {
+            window_identifier: "dummy".to_string(),
+            is_visible: true,
+        })));
+        let app_context = Arc::new(AppContext::new(context, theme, docking_manager, engine_unprivileged_state.clone()));
+        let dependency_container = &app_context.dependency_container;
+        let process_selector_view_data = dependency_container.register(ProcessSelectorViewData::new());
+        let element_scanner_view_data = dependency_container.register(ElementScannerViewData::new());
+        let element_scanner_results_view_data = dependency_container.register(ElementScannerResultsViewData::new());
+        let demo_guide_view_data = dependency_container.register(DemoGuideViewData::new());
+
+        ElementScannerViewData::poll_scan_state(element_scanner_view_data.clone(), engine_unprivileged_state.clone());
+        ElementScannerResultsViewData::poll_scan_results(element_scanner_results_view_data.clone(), engine_unprivileged_state.clone());
+        DemoGuideViewData::listen_for_scans(demo_guide_view_data.clone(), engine_unprivileged_state.clone());
+
+        // Launch this test binary as the demo target, in place of the Squalr executable.
+        let mut command = Command::new(std::env::current_exe().expect("Test executable should be known."));
+
+        command
+            .env(DEMO_TARGET_HELPER_VARIABLE, "1")
+            .arg(format!(
+                "{}::demo_target_helper",
+                module_path!()
+                    .split_once("::")
+                    .map_or(module_path!(), |(_crate_name, path)| path)
+            ))
+            .args(["--exact", "--ignored", "--nocapture", "--test-threads=1"]);
+
+        DemoGuideViewData::open_demo_target_with_command(demo_guide_view_data.clone(), app_context.clone(), command);
+
+        let demo_target_process_id = read(&demo_guide_view_data, |view_data| {
+            view_data
+                .demo_target_process
+                .as_ref()
+                .map(|demo_target_process| demo_target_process.get_process_id())
+        })
+        .expect("Demo target should start.");
+
+        wait_until("the demo target is attached", || {
+            read(&process_selector_view_data, |view_data| {
+                view_data
+                    .opened_process
+                    .as_ref()
+                    .map(|opened_process| opened_process.get_process_id_raw())
+                    == Some(demo_target_process_id)
+            })
+        });
+        wait_until("the demo target reports its value", || {
+            read(&demo_guide_view_data, |view_data| view_data.demo_value.is_some())
+        });
+
+        let initial_value = read(&demo_guide_view_data, |view_data| view_data.demo_value).unwrap_or_default();
+
+        assert!(
+            scan_for_value(
+                &squalr_engine,
+                &element_scanner_view_data,
+                &element_scanner_results_view_data,
+                &app_context,
+                initial_value
+            ) > 0
+        );
+        assert_eq!(
+            read(&demo_guide_view_data, |view_data| view_data.demo_guide.get_step()),
+            DemoGuideStep::ChangeValue
+        );
+
+        DemoGuideViewData::change_value(demo_guide_view_data.clone());
+        wait_until("the demo value changes", || {
+            read(&demo_guide_view_data, |view_data| view_data.demo_value) == Some(initial_value + 1)
+        });
+
+        assert!(
+            scan_for_value(
+                &squalr_engine,
+                &element_scanner_view_data,
+                &element_scanner_results_view_data,
+                &app_context,
+                initial_value + 1
+            ) > 0
+        );
+        assert_eq!(read(&demo_guide_view_data, |view_data| view_data.demo_guide.get_step()), DemoGuideStep::Freeze);
+
+        ElementScannerResultsViewData::select_all(element_scanner_results_view_data.clone());
+        ElementScannerResultsViewData::toggle_selected_scan_results_frozen(
+            element_scanner_results_view_data.clone(),
+            engine_unprivileged_state.clone(),
+            true,
+            StaleResultsPolicy::Confirm,
+        );
+        wait_until("the results are frozen", || {
+            read(&element_scanner_results_view_data, |view_data| !view_data.is_freezing_entries)
+        });
+
+        DemoGuideViewData::change_value(demo_guide_view_data.clone());
+        wait_until("the frozen value is written back", || {
+            read(&demo_guide_view_data, |view_data| view_data.demo_guide.get_step()) == DemoGuideStep::Complete
+        });
+
+        DemoGuideViewData::close(demo_guide_view_data);
+    }
+}
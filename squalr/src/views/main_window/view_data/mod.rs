@@ -0,0 +1,12 @@
+pub mod add_to_project_view_data;
+pub mod command_palette_view_data;
+pub mod crash_recovery_view_data;
+pub mod demo_guide_view_data;
+pub mod project_item_hotkeys_view_data;
+pub mod restore_modifications_view_data;
+pub mod toasts_view_data;
+pub mod unsaved_changes_view_data;
+pub mod update_checker_view_data;
+pub mod value_watches_view_data;
+pub mod workspace_view_data;
+pub mod write_sequence_view_data;
@@ -0,0 +1,413 @@
+use squalr_engine_api::commands::hotkeys::register::hotkeys_register_request::HotkeysRegisterRequest;
+use squalr_engine_api::commands::memory::write_sequence::memory_write_sequence_request::MemoryWriteSequenceRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::project_items::remove_hotkey::project_items_remove_hotkey_request::ProjectItemsRemoveHotkeyRequest;
+use squalr_engine_api::commands::project_items::set_hotkey::project_items_set_hotkey_request::ProjectItemsSetHotkeyRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::events::hotkeys::triggered::hotkey_triggered_event::HotkeyTriggeredEvent;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::hotkeys::hotkey_chord::HotkeyChord;
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::project_item_hotkey::{ProjectItemHotkey, ProjectItemHotkeyAction};
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use squalr_engine_api::structures::projects::write_sequences::write_sequence::WriteSequence;
+use std::sync::Arc;
+
+/// The kind of action being edited in the hotkey dialog.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ProjectItemHotkeyActionKind {
+    SetValue,
+    ToggleFreeze,
+}
+
+impl ProjectItemHotkeyActionKind {
+    pub const ALL: [ProjectItemHotkeyActionKind; 2] = [
+        ProjectItemHotkeyActionKind::SetValue,
+        ProjectItemHotkeyActionKind::ToggleFreeze,
+    ];
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            ProjectItemHotkeyActionKind::SetValue => "Set value",
+            ProjectItemHotkeyActionKind::ToggleFreeze => "Toggle freeze",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ProjectItemHotkeysViewData {
+    /// The address entry that hotkeys are being bound to, or none while the dialog is closed.
+    pub project_item_ref: Option<ProjectItemRef>,
+    pub project_item_name: String,
+    pub is_unsupported: bool,
+    pub chord: String,
+    /// Set while waiting for a key to be pressed, which is then recorded as the chord.
+    pub is_recording_chord: bool,
+    pub action_kind: ProjectItemHotkeyActionKind,
+    pub value: String,
+    pub error_message: Option<String>,
+    /// The chords last registered with the engine, or none if they were never registered.
+    pub registered_chords: Option<Vec<HotkeyChord>>,
+    /// Keeps the engine event listener for pressed hotkeys registered for as long as this view data lives.
+    pub event_subscriptions: Vec<EngineEventSubscription>,
+}
+
+impl ProjectItemHotkeysViewData {
+    pub fn new() -> Self {
+        Self {
+            project_item_ref: None,
+            project_item_name: String::new(),
+            is_unsupported: false,
+            chord: String::new(),
+            is_recording_chord: false,
+            action_kind: ProjectItemHotkeyActionKind::SetValue,
+            value: String::new(),
+            error_message: None,
+            registered_chords: None,
+            event_subscriptions: Vec::new(),
+        }
+    }
+
+    /// Performs the action bound to each pressed chord, regardless of which window has focus.
+    pub fn listen_for_hotkeys(
+        project_item_hotkeys_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+        let triggered_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<HotkeyTriggeredEvent>(
+            |_event| true,
+            move |event| {
+                Self::run_hotkey(&engine_unprivileged_state_clone, &event.chord);
+            },
+        );
+
+        if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys subscribe") {
+            view_data.event_subscriptions = vec![triggered_subscription];
+        }
+    }
+
+    /// Registers the chords bound in the opened project with the engine, whenever they differ from those last registered. Closing
+    /// the project registers no chords, such that its hotkeys stop being listened for.
+    pub fn sync_registered_chords(
+        project_item_hotkeys_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::GLOBAL_HOTKEYS) {
+            return;
+        }
+
+        let mut chords = Self::read_project(&engine_unprivileged_state, Project::get_hotkey_chords).unwrap_or_default();
+
+        chords.sort_by_key(|chord| chord.to_string());
+
+        let is_registered = project_item_hotkeys_view_data
+            .read("Project item hotkeys registered chords")
+            .map(|view_data| view_data.registered_chords.as_ref() == Some(&chords))
+            .unwrap_or(true);
+
+        if is_registered {
+            return;
+        }
+
+        if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys register chords") {
+            view_data.registered_chords = Some(chords.clone());
+        }
+
+        let hotkeys_register_request = HotkeysRegisterRequest { chords };
+
+        hotkeys_register_request.send(&engine_unprivileged_state, |hotkeys_register_response| {
+            if !hotkeys_register_response.is_supported {
+                log::warn!("Global hotkeys are not supported on this platform.");
+            }
+        });
+    }
+
+    /// Opens the dialog to bind hotkeys to the given address entry.
+    pub fn open_dialog(
+        project_item_hotkeys_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        project_item_ref: ProjectItemRef,
+    ) {
+        let is_supported = engine_unprivileged_state.has_capabilities(EngineCapabilities::GLOBAL_HOTKEYS | EngineCapabilities::WRITE_SEQUENCE);
+        let project_item_name = Self::read_project(&engine_unprivileged_state, |project| {
+            project
+                .get_project_item(&project_item_ref)
+                .map(|project_item| project_item.get_field_name())
+        })
+        .flatten()
+        .unwrap_or_default();
+
+        if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys open dialog") {
+            view_data.project_item_ref = Some(project_item_ref);
+            view_data.project_item_name = project_item_name;
+            view_data.is_unsupported = !is_supported;
+            view_data.chord.clear();
+            view_data.is_recording_chord = false;
+            view_data.action_kind = ProjectItemHotkeyActionKind::SetValue;
+            view_data.value.clear();
+            view_data.error_message = None;
+        }
+    }
+
+    pub fn close_dialog(project_item_hotkeys_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys close dialog") {
+            view_data.project_item_ref = None;
+            view_data.is_recording_chord = false;
+        }
+    }
+
+    /// Gets the hotkeys bound to the entry the dialog is open for.
+    pub fn get_bound_hotkeys(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        project_item_ref: &ProjectItemRef,
+    ) -> Vec<ProjectItemHotkey> {
+        Self::read_project(engine_unprivileged_state, |project| {
+            project
+                .get_project_item(project_item_ref)
+                .map(|project_item| project_item.get_hotkeys().to_vec())
+        })
+        .flatten()
+        .unwrap_or_default()
+    }
+
+    /// Records a pressed key, along with the modifiers held with it, as the chord. Keys that cannot be bound are ignored, such that
+    /// recording continues until a key that can be bound is pressed.
+    pub fn record_chord(
+        &mut self,
+        is_ctrl_down: bool,
+        is_shift_down: bool,
+        is_alt_down: bool,
+        key_name: &str,
+    ) {
+        if let Ok(chord) = HotkeyChord::new(is_ctrl_down, is_shift_down, is_alt_down, key_name) {
+            self.chord = chord.to_string();
+            self.is_recording_chord = false;
+        }
+    }
+
+    /// Binds the chord and action configured in the dialog to its entry, replacing the action of a chord the entry already uses.
+    pub fn bind_hotkey(
+        project_item_hotkeys_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let request = match project_item_hotkeys_view_data.read("Project item hotkeys bind") {
+            Some(view_data) => view_data.build_request(),
+            None => return,
+        };
+        let project_items_set_hotkey_request = match request {
+            Ok(project_items_set_hotkey_request) => project_items_set_hotkey_request,
+            Err(error_message) => {
+                if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys bind error") {
+                    view_data.error_message = Some(error_message);
+                }
+
+                return;
+            }
+        };
+
+        project_items_set_hotkey_request.send(&engine_unprivileged_state, move |project_items_set_hotkey_response| {
+            if let Some(mut view_data) = project_item_hotkeys_view_data.write("Project item hotkeys bind response") {
+                if project_items_set_hotkey_response.success {
+                    view_data.chord.clear();
+                    view_data.value.clear();
+                    view_data.error_message = None;
+                } else {
+                    view_data.error_message = Some(
+                        project_items_set_hotkey_response
+                            .error_message
+                            .unwrap_or_else(|| "The hotkey was not bound.".to_string()),
+                    );
+                }
+            }
+        });
+    }
+
+    pub fn unbind_hotkey(
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        project_item_ref: ProjectItemRef,
+        chord: HotkeyChord,
+    ) {
+        let project_items_remove_hotkey_request = ProjectItemsRemoveHotkeyRequest {
+            project_item_path: project_item_ref
+                .get_project_item_path()
+                .to_string_lossy()
+                .to_string(),
+            chord,
+        };
+
+        project_items_remove_hotkey_request.send(&engine_unprivileged_state, move |project_items_remove_hotkey_response| {
+            if !project_items_remove_hotkey_response.success {
+                log::error!("Failed to remove project item hotkey!");
+            }
+        });
+    }
+
+    fn build_request(&self) -> Result<ProjectItemsSetHotkeyRequest, String> {
+        let project_item_ref = self
+            .project_item_ref
+            .as_ref()
+            .ok_or_else(|| "No entry is selected.".to_string())?;
+        let chord = self.chord.parse::<HotkeyChord>()?;
+        let action = match self.action_kind {
+            ProjectItemHotkeyActionKind::SetValue => {
+                let value = self.value.trim();
+
+                if value.is_empty() {
+                    return Err("Enter the value to set.".to_string());
+                }
+
+                let format = if value.starts_with("0x") {
+                    AnonymousValueStringFormat::Hexadecimal
+                } else {
+                    AnonymousValueStringFormat::Decimal
+                };
+
+                ProjectItemHotkeyAction::SetValue {
+                    value: AnonymousValueString::new(value.to_string(), format, ContainerType::None),
+                }
+            }
+            ProjectItemHotkeyActionKind::ToggleFreeze => ProjectItemHotkeyAction::ToggleFreeze,
+        };
+
+        Ok(ProjectItemsSetHotkeyRequest {
+            project_item_path: project_item_ref
+                .get_project_item_path()
+                .to_string_lossy()
+                .to_string(),
+            chord,
+            action,
+        })
+    }
+
+    /// Performs the action bound to a pressed chord, as a write sequence of a single step.
+    fn run_hotkey(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        chord: &HotkeyChord,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::WRITE_SEQUENCE) {
+            log::warn!("Hotkey actions are not supported by the connected engine.");
+            return;
+        }
+
+        let resolved_steps = Self::read_project(engine_unprivileged_state, |project| {
+            let (project_item_ref, hotkey) = project.find_hotkey(chord)?;
+            let project_item_path = project.get_relative_path(project_item_ref.get_project_item_path());
+            let write_sequence = WriteSequence::new(
+                chord.to_string(),
+                vec![hotkey.get_action().to_write_sequence_step(project_item_path)],
+                false,
+                None,
+            );
+
+            Some(write_sequence.resolve(project))
+        })
+        .flatten();
+
+        let steps = match resolved_steps {
+            Some(Ok(steps)) => steps,
+            Some(Err(resolve_errors)) => {
+                for resolve_error in resolve_errors {
+                    log::warn!("The hotkey {} was not run: {}", chord, resolve_error.reason);
+                }
+
+                return;
+            }
+            // The chord may have been unbound since it was last registered.
+            None => return,
+        };
+
+        let memory_write_sequence_request = MemoryWriteSequenceRequest {
+            steps,
+            continue_on_error: false,
+        };
+        let chord = chord.clone();
+
+        memory_write_sequence_request.send(engine_unprivileged_state, move |memory_write_sequence_response| {
+            if memory_write_sequence_response.trackable_task_handle.is_none() {
+                log::warn!("The hotkey {} was not run, as the address could not be read.", chord);
+            }
+        });
+    }
+
+    fn read_project<T>(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        read_project: impl FnOnce(&Project) -> T,
+    ) -> Option<T> {
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let opened_project = match opened_project.read() {
+            Ok(opened_project) => opened_project,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return None;
+            }
+        };
+
+        opened_project.as_ref().map(read_project)
+    }
+}
+
+impl Default for ProjectItemHotkeysViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProjectItemHotkeyActionKind, ProjectItemHotkeysViewData};
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::data_values::container_type::ContainerType;
+    use squalr_engine_api::structures::projects::project_items::project_item_hotkey::ProjectItemHotkeyAction;
+    use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+    use std::path::PathBuf;
+
+    #[test]
+    fn recorded_chords_are_normalized_and_build_a_request() {
+        let mut project_item_hotkeys_view_data = ProjectItemHotkeysViewData::new();
+
+        project_item_hotkeys_view_data.project_item_ref = Some(ProjectItemRef::new(PathBuf::from("health.json")));
+        project_item_hotkeys_view_data.is_recording_chord = true;
+
+        // Keys that cannot be bound do not end recording.
+        project_item_hotkeys_view_data.record_chord(true, false, false, "Escape");
+
+        assert!(project_item_hotkeys_view_data.is_recording_chord);
+
+        project_item_hotkeys_view_data.record_chord(true, false, false, "f5");
+
+        assert!(!project_item_hotkeys_view_data.is_recording_chord);
+        assert_eq!(project_item_hotkeys_view_data.chord, "Ctrl+F5");
+        assert!(project_item_hotkeys_view_data.build_request().is_err());
+
+        project_item_hotkeys_view_data.value = "999".to_string();
+
+        let request = project_item_hotkeys_view_data
+            .build_request()
+            .expect("Request should build.");
+
+        assert_eq!(
+            request.action,
+            ProjectItemHotkeyAction::SetValue {
+                value: AnonymousValueString::new("999".to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+            }
+        );
+
+        project_item_hotkeys_view_data.action_kind = ProjectItemHotkeyActionKind::ToggleFreeze;
+
+        assert_eq!(
+            project_item_hotkeys_view_data
+                .build_request()
+                .map(|request| request.action),
+            Ok(ProjectItemHotkeyAction::ToggleFreeze)
+        );
+    }
+}
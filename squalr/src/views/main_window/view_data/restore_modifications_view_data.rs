@@ -0,0 +1,114 @@
+use squalr_engine_api::commands::memory::ledger::memory_ledger_request::MemoryLedgerRequest;
+use squalr_engine_api::commands::memory::restore::memory_restore_request::MemoryRestoreRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::structures::memory::memory_restore_result::MemoryRestoreResult;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct RestoreModificationsViewData {
+    pub is_dialog_open: bool,
+    pub is_querying_ledger: bool,
+    pub is_restoring: bool,
+    pub is_unsupported: bool,
+    pub address_count: u64,
+    pub byte_count: u64,
+    pub is_capacity_exceeded: bool,
+    pub restore_results: Option<Vec<MemoryRestoreResult>>,
+}
+
+impl RestoreModificationsViewData {
+    pub fn new() -> Self {
+        Self {
+            is_dialog_open: false,
+            is_querying_ledger: false,
+            is_restoring: false,
+            is_unsupported: false,
+            address_count: 0,
+            byte_count: 0,
+            is_capacity_exceeded: false,
+            restore_results: None,
+        }
+    }
+
+    /// Opens the confirmation dialog and queries how many addresses would be restored.
+    pub fn open_dialog(
+        restore_modifications_view_data: Dependency<RestoreModificationsViewData>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let is_supported = engine_unprivileged_state.has_capabilities(EngineCapabilities::MEMORY_LEDGER);
+
+        match restore_modifications_view_data.write("Restore modifications open dialog") {
+            Some(mut view_data) => {
+                view_data.is_dialog_open = true;
+                view_data.is_querying_ledger = is_supported;
+                view_data.is_unsupported = !is_supported;
+                view_data.restore_results = None;
+            }
+            None => return,
+        }
+
+        if !is_supported {
+            return;
+        }
+
+        let memory_ledger_request = MemoryLedgerRequest {};
+
+        // The write guard is dropped above, since the callback may run synchronously.
+        memory_ledger_request.send(&engine_unprivileged_state, move |memory_ledger_response| {
+            if let Some(mut view_data) = restore_modifications_view_data.write("Restore modifications ledger response") {
+                view_data.is_querying_ledger = false;
+                view_data.address_count = memory_ledger_response.address_count;
+                view_data.byte_count = memory_ledger_response.byte_count;
+                view_data.is_capacity_exceeded = memory_ledger_response.is_capacity_exceeded;
+            }
+        });
+    }
+
+    pub fn close_dialog(restore_modifications_view_data: Dependency<RestoreModificationsViewData>) {
+        if let Some(mut view_data) = restore_modifications_view_data.write("Restore modifications close dialog") {
+            view_data.is_dialog_open = false;
+            view_data.restore_results = None;
+        }
+    }
+
+    pub fn restore_all(
+        restore_modifications_view_data: Dependency<RestoreModificationsViewData>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        match restore_modifications_view_data.write("Restore modifications restore all") {
+            Some(mut view_data) => {
+                if view_data.is_restoring {
+                    return;
+                }
+
+                view_data.is_restoring = true;
+            }
+            None => return,
+        }
+
+        let memory_restore_request = MemoryRestoreRequest {};
+
+        memory_restore_request.send(&engine_unprivileged_state, move |memory_restore_response| {
+            if let Some(mut view_data) = restore_modifications_view_data.write("Restore modifications restore response") {
+                let failed_count = memory_restore_response
+                    .restore_results
+                    .iter()
+                    .filter(|restore_result| !restore_result.success)
+                    .count() as u64;
+
+                view_data.is_restoring = false;
+                view_data.address_count = failed_count;
+                view_data.restore_results = Some(memory_restore_response.restore_results);
+            }
+        });
+    }
+}
+
+impl Default for RestoreModificationsViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,202 @@
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A button shown on a toast, such as undoing the action that the toast reports.
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: Arc<dyn Fn() + Send + Sync>,
+}
+
+/// A notification shown in the corner of the main window, which disappears on its own once its duration passes.
+#[derive(Clone)]
+pub struct Toast {
+    pub toast_id: u64,
+    pub message: String,
+    pub shown_at: Instant,
+    pub duration: Duration,
+    pub action: Option<ToastAction>,
+}
+
+impl Toast {
+    pub fn get_remaining(
+        &self,
+        now: Instant,
+    ) -> Duration {
+        self.duration
+            .saturating_sub(now.saturating_duration_since(self.shown_at))
+    }
+}
+
+/// The toasts shown by any view. Each toast keeps its own timer, such that toasts shown in quick succession expire in turn.
+#[derive(Clone)]
+pub struct ToastsViewData {
+    pub toasts: Vec<Toast>,
+    next_toast_id: u64,
+}
+
+impl ToastsViewData {
+    pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+    /// Toasts with an action stay longer, such that there is time to react to them.
+    pub const ACTION_TOAST_DURATION: Duration = Duration::from_secs(8);
+
+    /// The most toasts shown at once. Older toasts are dropped first, such that a rapidly repeated action cannot fill the screen.
+    const MAXIMUM_TOAST_COUNT: usize = 5;
+
+    pub fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            next_toast_id: 0,
+        }
+    }
+
+    pub fn show(
+        toasts_view_data: Dependency<Self>,
+        message: String,
+    ) {
+        if let Some(mut view_data) = toasts_view_data.write("Toasts show") {
+            view_data.push_toast(message, None, Instant::now());
+        }
+    }
+
+    /// Shows a toast with a button that runs the given callback. The toast is dismissed once the button is clicked.
+    pub fn show_with_action(
+        toasts_view_data: Dependency<Self>,
+        message: String,
+        action_label: &str,
+        on_click: impl Fn() + Send + Sync + 'static,
+    ) {
+        let action = ToastAction {
+            label: action_label.to_string(),
+            on_click: Arc::new(on_click),
+        };
+
+        if let Some(mut view_data) = toasts_view_data.write("Toasts show with action") {
+            view_data.push_toast(message, Some(action), Instant::now());
+        }
+    }
+
+    /// Runs the action of the given toast and dismisses it. Does nothing if the toast already expired.
+    pub fn run_action(
+        toasts_view_data: Dependency<Self>,
+        toast_id: u64,
+    ) {
+        let action = match toasts_view_data.write("Toasts run action") {
+            Some(mut view_data) => view_data.take_action(toast_id),
+            None => return,
+        };
+
+        // The guard is dropped before running the action, as the action may show a toast of its own.
+        if let Some(action) = action {
+            (action.on_click)();
+        }
+    }
+
+    /// Drops toasts that have finished showing.
+    pub fn remove_expired(
+        &mut self,
+        now: Instant,
+    ) {
+        self.toasts.retain(|toast| !toast.get_remaining(now).is_zero());
+    }
+
+    fn push_toast(
+        &mut self,
+        message: String,
+        action: Option<ToastAction>,
+        now: Instant,
+    ) {
+        let duration = if action.is_some() {
+            Self::ACTION_TOAST_DURATION
+        } else {
+            Self::TOAST_DURATION
+        };
+
+        self.toasts.push(Toast {
+            toast_id: self.next_toast_id,
+            message,
+            shown_at: now,
+            duration,
+            action,
+        });
+        self.next_toast_id += 1;
+
+        if self.toasts.len() > Self::MAXIMUM_TOAST_COUNT {
+            self.toasts.remove(0);
+        }
+    }
+
+    fn take_action(
+        &mut self,
+        toast_id: u64,
+    ) -> Option<ToastAction> {
+        let toast_index = self
+            .toasts
+            .iter()
+            .position(|toast| toast.toast_id == toast_id)?;
+
+        self.toasts.remove(toast_index).action
+    }
+}
+
+impl Default for ToastsViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ToastAction, ToastsViewData};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn stacked_toasts_expire_on_their_own_timers() {
+        let mut toasts_view_data = ToastsViewData::new();
+        let shown_at = Instant::now();
+        let action_run_count = Arc::new(AtomicU32::new(0));
+        let action_run_count_clone = action_run_count.clone();
+
+        toasts_view_data.push_toast("Value changed.".to_string(), None, shown_at);
+        toasts_view_data.push_toast(
+            "Froze 2 addresses.".to_string(),
+            Some(ToastAction {
+                label: "Undo".to_string(),
+                on_click: Arc::new(move || {
+                    action_run_count_clone.fetch_add(1, Ordering::SeqCst);
+                }),
+            }),
+            shown_at + Duration::from_secs(1),
+        );
+
+        toasts_view_data.remove_expired(shown_at + ToastsViewData::TOAST_DURATION);
+
+        assert_eq!(toasts_view_data.toasts.len(), 1);
+        assert_eq!(toasts_view_data.toasts[0].message, "Froze 2 addresses.");
+
+        let action = toasts_view_data
+            .take_action(toasts_view_data.toasts[0].toast_id)
+            .unwrap();
+        (action.on_click)();
+
+        assert_eq!(action_run_count.load(Ordering::SeqCst), 1);
+        assert!(toasts_view_data.toasts.is_empty());
+        assert!(toasts_view_data.take_action(1).is_none());
+    }
+
+    #[test]
+    fn the_oldest_toast_is_dropped_once_full() {
+        let mut toasts_view_data = ToastsViewData::new();
+        let shown_at = Instant::now();
+
+        for toast_index in 0..ToastsViewData::MAXIMUM_TOAST_COUNT + 1 {
+            toasts_view_data.push_toast(toast_index.to_string(), None, shown_at);
+        }
+
+        assert_eq!(toasts_view_data.toasts.len(), ToastsViewData::MAXIMUM_TOAST_COUNT);
+        assert_eq!(toasts_view_data.toasts[0].message, "1");
+    }
+}
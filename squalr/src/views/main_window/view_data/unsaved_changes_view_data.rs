@@ -0,0 +1,179 @@
+use crate::app_context::AppContext;
+use crate::views::project_explorer::project_selector::view_data::project_selector_view_data::ProjectSelectorViewData;
+use eframe::egui::ViewportCommand;
+use squalr_engine_api::commands::project::save::project_save_request::ProjectSaveRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// An action that would discard the opened project, held back until the user decides what to do with its unsaved changes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnsavedChangesAction {
+    ExitApp,
+    CloseProject,
+    OpenProject { project_directory_path: PathBuf, project_name: String },
+    BrowseForProject,
+    CreateProject,
+}
+
+impl UnsavedChangesAction {
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            UnsavedChangesAction::ExitApp => "exiting",
+            UnsavedChangesAction::CloseProject => "closing the project",
+            UnsavedChangesAction::OpenProject { .. } | UnsavedChangesAction::BrowseForProject => "opening another project",
+            UnsavedChangesAction::CreateProject => "creating a new project",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct UnsavedChangesViewData {
+    /// The action waiting on a save or discard. The dialog is shown for as long as this is set.
+    pub pending_action: Option<UnsavedChangesAction>,
+    pub last_error_message: Option<String>,
+
+    /// Set once the user has chosen to exit, such that the resulting close request is let through.
+    pub is_exit_confirmed: bool,
+}
+
+impl UnsavedChangesViewData {
+    pub fn new() -> Self {
+        Self {
+            pending_action: None,
+            last_error_message: None,
+            is_exit_confirmed: false,
+        }
+    }
+
+    /// Gets whether the opened project has changes that would be lost by closing it. View state, such as selection, never counts.
+    pub fn has_unsaved_changes(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) -> bool {
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+
+        match opened_project.read() {
+            Ok(opened_project) => opened_project
+                .as_ref()
+                .is_some_and(|project| project.get_has_unsaved_changes()),
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Runs the action right away if nothing would be lost, otherwise holds it back and asks whether to save first.
+    pub fn request_action(
+        unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+        app_context: Arc<AppContext>,
+        unsaved_changes_action: UnsavedChangesAction,
+    ) {
+        if !Self::has_unsaved_changes(&app_context.engine_unprivileged_state) {
+            Self::run_action(unsaved_changes_view_data, app_context, unsaved_changes_action);
+            return;
+        }
+
+        if let Some(mut view_data) = unsaved_changes_view_data.write("Unsaved changes request action") {
+            view_data.pending_action = Some(unsaved_changes_action);
+            view_data.last_error_message = None;
+        }
+    }
+
+    /// Called when the window is asked to close. Returns true if the close should be cancelled, in which case the user is asked
+    /// what to do with the unsaved changes instead.
+    pub fn intercept_close_request(
+        unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+        app_context: Arc<AppContext>,
+    ) -> bool {
+        let is_exit_confirmed = unsaved_changes_view_data
+            .read("Unsaved changes intercept close request")
+            .map(|view_data| view_data.is_exit_confirmed)
+            .unwrap_or(false);
+
+        if is_exit_confirmed || !Self::has_unsaved_changes(&app_context.engine_unprivileged_state) {
+            return false;
+        }
+
+        Self::request_action(unsaved_changes_view_data, app_context, UnsavedChangesAction::ExitApp);
+
+        true
+    }
+
+    pub fn save_and_continue(
+        unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let project_save_request = ProjectSaveRequest {};
+        let app_context_clone = app_context.clone();
+
+        project_save_request.send(&app_context.engine_unprivileged_state, move |project_save_response| {
+            if !project_save_response.success {
+                if let Some(mut view_data) = unsaved_changes_view_data.write("Unsaved changes save failed") {
+                    view_data.last_error_message = Some("Failed to save the project. See the output window for details.".to_string());
+                }
+
+                return;
+            }
+
+            Self::discard_and_continue(unsaved_changes_view_data, app_context_clone);
+        });
+    }
+
+    pub fn discard_and_continue(
+        unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let unsaved_changes_action = match unsaved_changes_view_data.write("Unsaved changes discard and continue") {
+            Some(mut view_data) => match view_data.pending_action.take() {
+                Some(unsaved_changes_action) => unsaved_changes_action,
+                None => return,
+            },
+            None => return,
+        };
+
+        Self::run_action(unsaved_changes_view_data, app_context, unsaved_changes_action);
+    }
+
+    pub fn cancel(unsaved_changes_view_data: Dependency<UnsavedChangesViewData>) {
+        if let Some(mut view_data) = unsaved_changes_view_data.write("Unsaved changes cancel") {
+            view_data.pending_action = None;
+            view_data.last_error_message = None;
+        }
+    }
+
+    fn run_action(
+        unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+        app_context: Arc<AppContext>,
+        unsaved_changes_action: UnsavedChangesAction,
+    ) {
+        let project_selector_view_data = app_context
+            .dependency_container
+            .get_dependency::<ProjectSelectorViewData>();
+
+        match unsaved_changes_action {
+            UnsavedChangesAction::ExitApp => {
+                if let Some(mut view_data) = unsaved_changes_view_data.write("Unsaved changes confirm exit") {
+                    view_data.is_exit_confirmed = true;
+                }
+
+                app_context.context.send_viewport_cmd(ViewportCommand::Close);
+            }
+            UnsavedChangesAction::CloseProject => ProjectSelectorViewData::close_current_project(app_context),
+            UnsavedChangesAction::OpenProject {
+                project_directory_path,
+                project_name,
+            } => ProjectSelectorViewData::open_project(project_selector_view_data, app_context, project_directory_path, project_name),
+            UnsavedChangesAction::BrowseForProject => ProjectSelectorViewData::browse_for_project(project_selector_view_data, app_context),
+            UnsavedChangesAction::CreateProject => ProjectSelectorViewData::create_new_project(project_selector_view_data, app_context),
+        }
+    }
+}
+
+impl Default for UnsavedChangesViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
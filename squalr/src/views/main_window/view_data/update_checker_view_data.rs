@@ -0,0 +1,125 @@
+use crate::models::update_check::update_check_settings::UpdateCheckSettings;
+use eframe::egui::Context;
+use squalr_engine::app_provisioner::operations::version_check::github_release_info::GitHubReleaseInfo;
+use squalr_engine::app_provisioner::operations::version_check::release_version::ReleaseVersion;
+use squalr_engine::app_provisioner::operations::version_check::update_check_result::UpdateCheckResult;
+use squalr_engine::app_provisioner::operations::version_check::update_checker::{GitHubReleaseSource, UpdateChecker};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+
+#[derive(Clone)]
+pub struct UpdateCheckerViewData {
+    /// The newer release found by the last check. The banner is shown for as long as this is set and not dismissed.
+    pub available_release: Option<GitHubReleaseInfo>,
+    pub is_checking: bool,
+    pub is_banner_dismissed: bool,
+    pub is_release_notes_expanded: bool,
+    /// The outcome of a check started from the menu that found nothing to offer, since those should not pass silently.
+    pub manual_check_message: Option<String>,
+}
+
+impl UpdateCheckerViewData {
+    pub fn new() -> Self {
+        Self {
+            available_release: None,
+            is_checking: false,
+            is_banner_dismissed: false,
+            is_release_notes_expanded: false,
+            manual_check_message: None,
+        }
+    }
+
+    /// Checks on startup, unless disabled or already checked within the last day.
+    pub fn check_for_updates_on_startup(
+        update_checker_view_data: Dependency<UpdateCheckerViewData>,
+        context: Context,
+    ) {
+        if UpdateCheckSettings::is_automatic_check_due() {
+            Self::check_for_updates(update_checker_view_data, context, false);
+        }
+    }
+
+    /// Queries the latest release on a background thread. Automatic checks fail quietly, as being offline is not worth a prompt.
+    pub fn check_for_updates(
+        update_checker_view_data: Dependency<UpdateCheckerViewData>,
+        context: Context,
+        is_manual: bool,
+    ) {
+        match update_checker_view_data.write("Update checker begin check") {
+            Some(mut view_data) => {
+                if view_data.is_checking {
+                    return;
+                }
+
+                view_data.is_checking = true;
+                view_data.manual_check_message = None;
+            }
+            None => return,
+        }
+
+        std::thread::spawn(move || {
+            let update_check_result = UpdateChecker::check(&GitHubReleaseSource::default(), ReleaseVersion::get_current_version());
+
+            if update_check_result.is_ok() {
+                UpdateCheckSettings::record_update_check();
+            }
+
+            if let Some(mut view_data) = update_checker_view_data.write("Update checker complete check") {
+                view_data.is_checking = false;
+
+                match update_check_result {
+                    Ok(UpdateCheckResult::UpdateAvailable(release)) => {
+                        log::info!("Squalr {} is available.", release.tag_name);
+                        view_data.available_release = Some(release);
+                        view_data.is_banner_dismissed = false;
+                    }
+                    Ok(UpdateCheckResult::UpToDate) => {
+                        if is_manual {
+                            view_data.manual_check_message = Some(format!("Squalr {} is up to date.", ReleaseVersion::get_current_version()));
+                        }
+                    }
+                    Err(error) => {
+                        log::debug!("Update check failed: {}", error);
+
+                        if is_manual {
+                            view_data.manual_check_message = Some("Could not check for updates. Check your connection and try again.".to_string());
+                        }
+                    }
+                }
+            }
+
+            context.request_repaint();
+        });
+    }
+
+    pub fn toggle_release_notes(update_checker_view_data: Dependency<UpdateCheckerViewData>) {
+        if let Some(mut view_data) = update_checker_view_data.write("Update checker toggle release notes") {
+            view_data.is_release_notes_expanded = !view_data.is_release_notes_expanded;
+        }
+    }
+
+    pub fn dismiss(update_checker_view_data: Dependency<UpdateCheckerViewData>) {
+        if let Some(mut view_data) = update_checker_view_data.write("Update checker dismiss") {
+            view_data.is_banner_dismissed = true;
+            view_data.is_release_notes_expanded = false;
+            view_data.manual_check_message = None;
+        }
+    }
+
+    /// Opens the release page in the browser, where the user can download it themselves. Nothing is downloaded automatically.
+    pub fn open_release_page(release: &GitHubReleaseInfo) {
+        let Some(release_page_url) = &release.html_url else {
+            log::error!("The latest release did not include a release page.");
+            return;
+        };
+
+        if let Err(error) = opener::open(release_page_url) {
+            log::error!("Failed to open release page: {}", error);
+        }
+    }
+}
+
+impl Default for UpdateCheckerViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,379 @@
+use crate::models::audio_player::{AudioPlayer, SoundType};
+use crate::views::main_window::view_data::toasts_view_data::ToastsViewData;
+use eframe::egui::Context;
+use squalr_engine_api::commands::memory::list_value_watches::memory_list_value_watches_request::MemoryListValueWatchesRequest;
+use squalr_engine_api::commands::memory::unwatch_value::memory_unwatch_value_request::MemoryUnwatchValueRequest;
+use squalr_engine_api::commands::memory::watch_value::memory_watch_value_request::MemoryWatchValueRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::events::value_watch::triggered::value_watch_triggered_event::ValueWatchTriggeredEvent;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
+use squalr_engine_api::structures::memory::value_watch::ValueWatch;
+use squalr_engine_api::structures::memory::value_watch_condition::ValueWatchCondition;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// The kind of condition being edited in the notify on change dialog.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ValueWatchConditionKind {
+    AnyChange,
+    CrossesThreshold,
+    Equals,
+}
+
+impl ValueWatchConditionKind {
+    pub const ALL: [ValueWatchConditionKind; 3] = [
+        ValueWatchConditionKind::AnyChange,
+        ValueWatchConditionKind::CrossesThreshold,
+        ValueWatchConditionKind::Equals,
+    ];
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            ValueWatchConditionKind::AnyChange => "Any change",
+            ValueWatchConditionKind::CrossesThreshold => "Crosses threshold",
+            ValueWatchConditionKind::Equals => "Equals value",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ValueWatchesViewData {
+    pub is_dialog_open: bool,
+    pub is_unsupported: bool,
+    pub label: String,
+    /// The address of the value being watched, relative to the module if a module name is given.
+    pub address: u64,
+    pub module_name: String,
+    pub data_type_ref: DataTypeRef,
+    pub condition_kind: ValueWatchConditionKind,
+    pub condition_value: String,
+    pub play_sound: bool,
+    pub error_message: Option<String>,
+    /// The watches running in the engine, as of the last time they were listed.
+    pub value_watches: Vec<ValueWatch>,
+    /// Watches that play a sound when they trigger. Sounds are a host concern, so the engine does not know about them.
+    pub sound_watch_ids: HashSet<u64>,
+    /// When each watched value last triggered, keyed by its address and module, such that rows showing it can flash.
+    pub triggered_values: HashMap<(u64, String), Instant>,
+    /// Keeps the engine event listener for triggered watches registered for as long as this view data lives.
+    pub event_subscriptions: Vec<EngineEventSubscription>,
+}
+
+impl ValueWatchesViewData {
+    pub const FLASH_DURATION: Duration = Duration::from_millis(800);
+
+    pub fn new() -> Self {
+        Self {
+            is_dialog_open: false,
+            is_unsupported: false,
+            label: String::new(),
+            address: 0,
+            module_name: String::new(),
+            data_type_ref: DataTypeRef::new("i32"),
+            condition_kind: ValueWatchConditionKind::AnyChange,
+            condition_value: String::new(),
+            play_sound: false,
+            error_message: None,
+            value_watches: Vec::new(),
+            sound_watch_ids: HashSet::new(),
+            triggered_values: HashMap::new(),
+            event_subscriptions: Vec::new(),
+        }
+    }
+
+    /// Turns triggered watches into toasts and row flashes, playing a sound for the watches that asked for one.
+    pub fn listen_for_triggers(
+        value_watches_view_data: Dependency<Self>,
+        toasts_view_data: Dependency<ToastsViewData>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        context: Context,
+    ) {
+        let value_watches_view_data_clone = value_watches_view_data.clone();
+        let triggered_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<ValueWatchTriggeredEvent>(
+            |_event| true,
+            move |event| {
+                let (toast_message, should_play_sound) = match value_watches_view_data_clone.write("Value watch triggered event") {
+                    Some(mut view_data) => (
+                        view_data.on_value_watch_triggered(event, Instant::now()),
+                        view_data.sound_watch_ids.contains(&event.watch_id),
+                    ),
+                    None => return,
+                };
+
+                ToastsViewData::show(toasts_view_data.clone(), toast_message);
+
+                if should_play_sound {
+                    Self::get_audio_player().play_sound(SoundType::Notification);
+                }
+
+                context.request_repaint();
+            },
+        );
+
+        if let Some(mut view_data) = value_watches_view_data.write("Value watches subscribe") {
+            view_data.event_subscriptions = vec![triggered_subscription];
+        }
+    }
+
+    /// Opens the dialog to watch the value at the given address.
+    pub fn open_dialog(
+        value_watches_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        label: String,
+        address: u64,
+        module_name: String,
+        data_type_ref: DataTypeRef,
+    ) {
+        let is_supported = engine_unprivileged_state.has_capabilities(EngineCapabilities::VALUE_WATCH);
+
+        if let Some(mut view_data) = value_watches_view_data.write("Value watches open dialog") {
+            view_data.is_dialog_open = true;
+            view_data.is_unsupported = !is_supported;
+            view_data.label = label;
+            view_data.address = address;
+            view_data.module_name = module_name;
+            view_data.data_type_ref = data_type_ref;
+            view_data.condition_kind = ValueWatchConditionKind::AnyChange;
+            view_data.condition_value.clear();
+            view_data.error_message = None;
+        }
+    }
+
+    pub fn close_dialog(value_watches_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = value_watches_view_data.write("Value watches close dialog") {
+            view_data.is_dialog_open = false;
+        }
+    }
+
+    /// Starts watching the value configured in the dialog, closing it once the engine accepts the watch.
+    pub fn start_watch(
+        value_watches_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (memory_watch_value_request, play_sound) = match value_watches_view_data.read("Value watches start watch") {
+            Some(view_data) => (
+                MemoryWatchValueRequest {
+                    address: view_data.address,
+                    module_name: view_data.module_name.clone(),
+                    data_type_ref: view_data.data_type_ref.clone(),
+                    condition: view_data.build_condition(),
+                    label: view_data.label.clone(),
+                },
+                view_data.play_sound,
+            ),
+            None => return,
+        };
+        let value_watches_view_data_clone = value_watches_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_watch_value_request.send(&engine_unprivileged_state, move |memory_watch_value_response| {
+            if let Some(mut view_data) = value_watches_view_data_clone.write("Value watches start watch response") {
+                match memory_watch_value_response.watch_id {
+                    Some(watch_id) => {
+                        if play_sound {
+                            view_data.sound_watch_ids.insert(watch_id);
+                        }
+
+                        view_data.is_dialog_open = false;
+                        view_data.error_message = None;
+                    }
+                    None => {
+                        view_data.error_message = Some(
+                            memory_watch_value_response
+                                .error_message
+                                .unwrap_or_else(|| "The watch was not started.".to_string()),
+                        );
+                        return;
+                    }
+                }
+            }
+
+            Self::refresh_value_watches(value_watches_view_data_clone, engine_unprivileged_state_clone);
+        });
+    }
+
+    /// Lists the watches running in the engine, ie when the watches menu is opened.
+    pub fn refresh_value_watches(
+        value_watches_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::VALUE_WATCH) {
+            return;
+        }
+
+        let memory_list_value_watches_request = MemoryListValueWatchesRequest {};
+
+        memory_list_value_watches_request.send(&engine_unprivileged_state, move |memory_list_value_watches_response| {
+            if let Some(mut view_data) = value_watches_view_data.write("Value watches refresh response") {
+                let value_watches = memory_list_value_watches_response.value_watches;
+
+                view_data.sound_watch_ids.retain(|watch_id| {
+                    value_watches
+                        .iter()
+                        .any(|value_watch| value_watch.watch_id == *watch_id)
+                });
+                view_data.value_watches = value_watches;
+            }
+        });
+    }
+
+    pub fn stop_watch(
+        value_watches_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        watch_id: u64,
+    ) {
+        if let Some(mut view_data) = value_watches_view_data.write("Value watches stop watch") {
+            view_data
+                .value_watches
+                .retain(|value_watch| value_watch.watch_id != watch_id);
+            view_data.sound_watch_ids.remove(&watch_id);
+        }
+
+        let memory_unwatch_value_request = MemoryUnwatchValueRequest { watch_id };
+        let engine_unprivileged_state_for_refresh = engine_unprivileged_state.clone();
+
+        memory_unwatch_value_request.send(&engine_unprivileged_state, move |_memory_unwatch_value_response| {
+            Self::refresh_value_watches(value_watches_view_data, engine_unprivileged_state_for_refresh);
+        });
+    }
+
+    /// Gets how strongly the rows showing each triggered value should flash, keyed by address and module, from 1 when its watch just
+    /// triggered down to 0 once the flash ends.
+    pub fn get_flash_strengths(
+        &self,
+        now: Instant,
+    ) -> HashMap<(u64, String), f32> {
+        self.triggered_values
+            .iter()
+            .map(|(value_key, triggered_at)| {
+                let elapsed_ratio = now.saturating_duration_since(*triggered_at).as_secs_f32() / Self::FLASH_DURATION.as_secs_f32();
+
+                (value_key.clone(), 1.0 - elapsed_ratio.min(1.0))
+            })
+            .collect()
+    }
+
+    /// Drops row flashes that have finished showing.
+    pub fn remove_expired(
+        &mut self,
+        now: Instant,
+    ) {
+        self.triggered_values
+            .retain(|_value_key, triggered_at| now.saturating_duration_since(*triggered_at) < Self::FLASH_DURATION);
+    }
+
+    /// Flashes the rows showing the triggered value, returning the message to show in a toast.
+    fn on_value_watch_triggered(
+        &mut self,
+        event: &ValueWatchTriggeredEvent,
+        now: Instant,
+    ) -> String {
+        let name = if !event.label.is_empty() {
+            event.label.clone()
+        } else if !event.module_name.is_empty() {
+            format!("{}+{:X}", event.module_name, event.address)
+        } else {
+            format!("{:X}", event.address)
+        };
+
+        self.triggered_values
+            .insert((event.address, event.module_name.clone()), now);
+
+        format!(
+            "{} changed from {} to {}.",
+            name,
+            Self::format_value(&event.previous_value),
+            Self::format_value(&event.current_value)
+        )
+    }
+
+    fn build_condition(&self) -> ValueWatchCondition {
+        let value = self.condition_value.trim();
+        let format = if value.starts_with("0x") {
+            AnonymousValueStringFormat::Hexadecimal
+        } else {
+            AnonymousValueStringFormat::Decimal
+        };
+        let value = AnonymousValueString::new(value.to_string(), format, ContainerType::None);
+
+        match self.condition_kind {
+            ValueWatchConditionKind::AnyChange => ValueWatchCondition::AnyChange,
+            ValueWatchConditionKind::CrossesThreshold => ValueWatchCondition::CrossesThreshold { threshold: value },
+            ValueWatchConditionKind::Equals => ValueWatchCondition::Equals { value },
+        }
+    }
+
+    fn format_value(data_value: &DataValue) -> String {
+        let symbol_registry = SymbolRegistry::get_instance();
+        let format = symbol_registry.get_default_anonymous_value_string_format(data_value.get_data_type_ref());
+
+        match symbol_registry.anonymize_value(data_value, format) {
+            Ok(anonymous_value_string) => anonymous_value_string.get_anonymous_value_string().to_string(),
+            Err(_error) => "??".to_string(),
+        }
+    }
+
+    /// Gets the audio player, created on first use such that no audio device is opened until a watch plays a sound.
+    fn get_audio_player() -> &'static AudioPlayer {
+        static AUDIO_PLAYER: OnceLock<AudioPlayer> = OnceLock::new();
+
+        AUDIO_PLAYER.get_or_init(AudioPlayer::new)
+    }
+}
+
+impl Default for ValueWatchesViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueWatchesViewData;
+    use squalr_engine_api::events::value_watch::triggered::value_watch_triggered_event::ValueWatchTriggeredEvent;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::data_value::DataValue;
+    use std::time::Instant;
+
+    #[test]
+    fn triggered_watches_show_a_toast_and_flash_until_they_expire() {
+        let mut value_watches_view_data = ValueWatchesViewData::new();
+        let triggered_at = Instant::now();
+
+        let toast_message = value_watches_view_data.on_value_watch_triggered(
+            &ValueWatchTriggeredEvent {
+                watch_id: 1,
+                label: String::new(),
+                address: 0x1A0,
+                module_name: "game.exe".to_string(),
+                previous_value: DataValue::new(DataTypeRef::new("i32"), 100i32.to_le_bytes().to_vec()),
+                current_value: DataValue::new(DataTypeRef::new("i32"), 99i32.to_le_bytes().to_vec()),
+            },
+            triggered_at,
+        );
+
+        assert_eq!(toast_message, "game.exe+1A0 changed from 100 to 99.");
+        let flash_strengths = value_watches_view_data.get_flash_strengths(triggered_at);
+
+        assert_eq!(flash_strengths.get(&(0x1A0, "game.exe".to_string())), Some(&1.0));
+        assert_eq!(flash_strengths.get(&(0x1A0, String::new())), None);
+
+        value_watches_view_data.remove_expired(triggered_at + ValueWatchesViewData::FLASH_DURATION);
+
+        assert!(
+            value_watches_view_data
+                .get_flash_strengths(triggered_at)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,434 @@
+use crate::app_context::AppContext;
+use crate::models::docking::docking_command::DockingCommand;
+use crate::models::docking::settings::dockable_window_settings::DockSettingsConfig;
+use crate::models::workspace::workspace::Workspace;
+use crate::models::workspace::workspace_contents::{WorkspaceContents, WorkspaceProjectReference};
+use crate::views::element_scanner::scanner::view_data::element_scanner_view_data::ElementScannerViewData;
+use crate::views::main_window::view_data::unsaved_changes_view_data::{UnsavedChangesAction, UnsavedChangesViewData};
+use squalr_engine_api::commands::data_types::register::data_types_register_request::DataTypesRegisterRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Tracks the workspace in use, loading the one saved for a process when attaching to it, and saving it again on detach or exit.
+#[derive(Clone)]
+pub struct WorkspaceViewData {
+    pub active_workspace: Option<Workspace>,
+    /// The executable name of the attached process, which workspaces saved from now on are keyed to.
+    pub target_executable: Option<String>,
+    /// The workspaces saved for the attached process, offered for the user to pick from when there is more than one.
+    pub workspace_choices: Vec<Workspace>,
+    pub is_save_as_dialog_open: bool,
+    pub save_as_name: String,
+    pub is_open_dialog_open: bool,
+    pub open_directory: String,
+    pub dialog_error: Option<String>,
+    /// Parts of the last loaded workspace that were skipped. The warnings are shown until dismissed.
+    pub load_warnings: Vec<String>,
+}
+
+impl WorkspaceViewData {
+    pub fn new() -> Self {
+        Self {
+            active_workspace: None,
+            target_executable: None,
+            workspace_choices: Vec::new(),
+            is_save_as_dialog_open: false,
+            save_as_name: String::new(),
+            is_open_dialog_open: false,
+            open_directory: String::new(),
+            dialog_error: None,
+            load_warnings: Vec::new(),
+        }
+    }
+
+    pub fn listen_for_process_changes(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let engine_unprivileged_state = app_context.engine_unprivileged_state.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<ProcessChangedEvent>(move |process_changed_event| {
+            let executable_name = process_changed_event
+                .process_info
+                .as_ref()
+                .map(|process_info| process_info.get_name().to_string());
+
+            Self::on_process_changed(workspace_view_data.clone(), app_context.clone(), executable_name);
+        });
+    }
+
+    /// Saves the workspace of the process being detached from, then loads the workspace of the newly attached process. If several
+    /// workspaces were saved for it, the user is asked which one to load instead.
+    pub fn on_process_changed(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+        executable_name: Option<String>,
+    ) {
+        let is_same_target = workspace_view_data
+            .read("Workspace process changed")
+            .is_some_and(|view_data| view_data.target_executable == executable_name);
+
+        if is_same_target {
+            return;
+        }
+
+        Self::save_active_workspace(workspace_view_data.clone(), app_context.clone());
+
+        let matching_workspaces = match &executable_name {
+            Some(executable_name) => Workspace::find_for_target(&Workspace::get_workspaces_directory(), executable_name),
+            None => Vec::new(),
+        };
+
+        if let Some(mut view_data) = workspace_view_data.write("Workspace process changed") {
+            view_data.target_executable = executable_name;
+            view_data.active_workspace = None;
+            view_data.workspace_choices = match matching_workspaces.len() {
+                0 | 1 => Vec::new(),
+                _ => matching_workspaces.clone(),
+            };
+        }
+
+        if let [workspace] = matching_workspaces.as_slice() {
+            Self::load_workspace(workspace_view_data, app_context, workspace.clone());
+        }
+    }
+
+    /// Saves the current working context over the active workspace. Does nothing if no workspace is active.
+    pub fn save_active_workspace(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let active_workspace = match workspace_view_data.read("Workspace save active") {
+            Some(view_data) => view_data.active_workspace.clone(),
+            None => return,
+        };
+        let Some(active_workspace) = active_workspace else {
+            return;
+        };
+        let contents = Self::capture_contents(&app_context);
+
+        match Workspace::save(
+            active_workspace.get_directory(),
+            active_workspace.get_name(),
+            active_workspace.get_target_executable(),
+            &contents,
+        ) {
+            Ok(workspace) => {
+                if let Some(mut view_data) = workspace_view_data.write("Workspace save active") {
+                    view_data.active_workspace = Some(workspace);
+                }
+            }
+            Err(error) => log::error!("Failed to save workspace {}: {}", active_workspace.get_name(), error),
+        }
+    }
+
+    /// Saves the active workspace, then loads the given one in its place.
+    pub fn switch_to_workspace(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+        workspace: Workspace,
+    ) {
+        let is_active_workspace = workspace_view_data
+            .read("Workspace switch")
+            .is_some_and(|view_data| view_data.active_workspace.as_ref() == Some(&workspace));
+
+        if is_active_workspace {
+            return;
+        }
+
+        Self::save_active_workspace(workspace_view_data.clone(), app_context.clone());
+        Self::load_workspace(workspace_view_data, app_context, workspace);
+    }
+
+    /// Loads one of the workspaces offered after attaching to a process that has several.
+    pub fn choose_workspace(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+        choice_index: usize,
+    ) {
+        let workspace = match workspace_view_data.write("Workspace choose") {
+            Some(mut view_data) => {
+                let workspace = view_data.workspace_choices.get(choice_index).cloned();
+
+                view_data.workspace_choices.clear();
+                workspace
+            }
+            None => return,
+        };
+
+        if let Some(workspace) = workspace {
+            Self::load_workspace(workspace_view_data, app_context, workspace);
+        }
+    }
+
+    pub fn dismiss_workspace_choices(workspace_view_data: Dependency<WorkspaceViewData>) {
+        if let Some(mut view_data) = workspace_view_data.write("Workspace dismiss choices") {
+            view_data.workspace_choices.clear();
+        }
+    }
+
+    pub fn dismiss_load_warnings(workspace_view_data: Dependency<WorkspaceViewData>) {
+        if let Some(mut view_data) = workspace_view_data.write("Workspace dismiss load warnings") {
+            view_data.load_warnings.clear();
+        }
+    }
+
+    pub fn open_save_as_dialog(workspace_view_data: Dependency<WorkspaceViewData>) {
+        if let Some(mut view_data) = workspace_view_data.write("Workspace open save as dialog") {
+            view_data.save_as_name = match (&view_data.active_workspace, &view_data.target_executable) {
+                (Some(active_workspace), _) => active_workspace.get_name().to_string(),
+                (None, Some(target_executable)) => target_executable
+                    .rsplit_once('.')
+                    .map(|(stem, _extension)| stem.to_string())
+                    .unwrap_or_else(|| target_executable.clone()),
+                (None, None) => String::new(),
+            };
+            view_data.is_save_as_dialog_open = true;
+            view_data.dialog_error = None;
+        }
+    }
+
+    pub fn open_open_dialog(workspace_view_data: Dependency<WorkspaceViewData>) {
+        if let Some(mut view_data) = workspace_view_data.write("Workspace open open dialog") {
+            view_data.open_directory = Workspace::get_workspaces_directory().display().to_string();
+            view_data.is_open_dialog_open = true;
+            view_data.dialog_error = None;
+        }
+    }
+
+    pub fn close_dialogs(workspace_view_data: Dependency<WorkspaceViewData>) {
+        if let Some(mut view_data) = workspace_view_data.write("Workspace close dialogs") {
+            view_data.is_save_as_dialog_open = false;
+            view_data.is_open_dialog_open = false;
+            view_data.dialog_error = None;
+        }
+    }
+
+    /// Saves the current working context as a new workspace in the workspaces folder, keyed to the attached process, and makes
+    /// it the active workspace. Without an attached process, the workspace keeps the target of the active workspace.
+    pub fn save_workspace_as(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let (name, target_executable, active_workspace) = match workspace_view_data.read("Workspace save as") {
+            Some(view_data) => (
+                view_data.save_as_name.trim().to_string(),
+                view_data.target_executable.clone(),
+                view_data.active_workspace.clone(),
+            ),
+            None => return,
+        };
+        let target_executable = target_executable.or_else(|| {
+            active_workspace
+                .as_ref()
+                .map(|active_workspace| active_workspace.get_target_executable().to_string())
+        });
+        let result = match (name.is_empty(), target_executable) {
+            (true, _) => Err("Enter a name for the workspace.".to_string()),
+            (false, None) => Err("Attach to a process first, such that the workspace knows which executable it belongs to.".to_string()),
+            (false, Some(target_executable)) => {
+                let directory = Workspace::get_workspaces_directory().join(Workspace::create_directory_name(&name));
+                let is_overwriting_other_workspace = active_workspace
+                    .as_ref()
+                    .is_none_or(|active_workspace| active_workspace.get_directory() != directory)
+                    && Workspace::open(&directory).is_ok();
+
+                if is_overwriting_other_workspace {
+                    Err(format!("A workspace named {} already exists.", name))
+                } else {
+                    Workspace::save(&directory, &name, &target_executable, &Self::capture_contents(&app_context))
+                }
+            }
+        };
+
+        if let Some(mut view_data) = workspace_view_data.write("Workspace save as") {
+            match result {
+                Ok(workspace) => {
+                    log::info!("Saved workspace {} to {}.", workspace.get_name(), workspace.get_directory().display());
+                    view_data.active_workspace = Some(workspace);
+                    view_data.is_save_as_dialog_open = false;
+                    view_data.dialog_error = None;
+                }
+                Err(error) => view_data.dialog_error = Some(error),
+            }
+        }
+    }
+
+    /// Opens the workspace in the directory entered in the open dialog, saving the active workspace first.
+    pub fn open_workspace_from_directory(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let open_directory = match workspace_view_data.read("Workspace open from directory") {
+            Some(view_data) => PathBuf::from(view_data.open_directory.trim()),
+            None => return,
+        };
+
+        match Workspace::open(&open_directory) {
+            Ok(workspace) => {
+                Self::close_dialogs(workspace_view_data.clone());
+                Self::switch_to_workspace(workspace_view_data, app_context, workspace);
+            }
+            Err(error) => {
+                if let Some(mut view_data) = workspace_view_data.write("Workspace open from directory") {
+                    view_data.dialog_error = Some(error);
+                }
+            }
+        }
+    }
+
+    /// Gets the workspaces the title bar offers to switch to: those saved for the attached process, or every workspace if none is.
+    pub fn get_switchable_workspaces(target_executable: Option<&str>) -> Vec<Workspace> {
+        let workspaces_directory = Workspace::get_workspaces_directory();
+
+        match target_executable {
+            Some(target_executable) => Workspace::find_for_target(&workspaces_directory, target_executable),
+            None => Workspace::find_all(&workspaces_directory),
+        }
+    }
+
+    fn load_workspace(
+        workspace_view_data: Dependency<WorkspaceViewData>,
+        app_context: Arc<AppContext>,
+        workspace: Workspace,
+    ) {
+        let load_result = workspace.load_contents();
+
+        for warning in &load_result.warnings {
+            log::warn!("{}", warning);
+        }
+
+        Self::apply_contents(&app_context, load_result.contents);
+        log::info!("Loaded workspace {}.", workspace.get_name());
+
+        if let Some(mut view_data) = workspace_view_data.write("Workspace load") {
+            view_data.active_workspace = Some(workspace);
+            view_data.load_warnings = load_result.warnings;
+        }
+    }
+
+    /// Gathers the working context from the opened project, the custom data type registry, the docking layout, and the scanner.
+    fn capture_contents(app_context: &Arc<AppContext>) -> WorkspaceContents {
+        let opened_project = app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let project = match opened_project.read() {
+            Ok(opened_project) => opened_project
+                .as_ref()
+                .and_then(|project| project.get_project_info().get_project_directory())
+                .map(|project_directory| WorkspaceProjectReference { project_directory }),
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                None
+            }
+        };
+        let layout = match app_context.docking_manager.read() {
+            Ok(docking_manager) => Some(DockSettingsConfig {
+                dock_root: docking_manager.get_root().clone(),
+            }),
+            Err(error) => {
+                log::error!("Failed to acquire docking manager lock: {}", error);
+                None
+            }
+        };
+        let scan_constraints = app_context
+            .dependency_container
+            .get_dependency::<ElementScannerViewData>()
+            .read("Workspace capture scan constraints")
+            .map(|element_scanner_view_data| element_scanner_view_data.capture_scan_constraints());
+
+        WorkspaceContents {
+            project,
+            struct_definitions: Some(CustomDataTypeRegistry::get_instance().get_definitions()),
+            layout,
+            scan_constraints,
+        }
+    }
+
+    /// Applies each loaded part of a workspace. Struct definitions are registered alongside the existing ones rather than
+    /// replacing them, since the custom data type registry is shared by every workspace.
+    fn apply_contents(
+        app_context: &Arc<AppContext>,
+        contents: WorkspaceContents,
+    ) {
+        if let Some(struct_definitions) = contents.struct_definitions {
+            let data_types_register_request = DataTypesRegisterRequest {
+                definitions: struct_definitions,
+            };
+
+            data_types_register_request.send(&app_context.engine_unprivileged_state, |data_types_register_response| {
+                for error_message in &data_types_register_response.error_messages {
+                    log::warn!("Skipped a workspace struct definition: {}", error_message);
+                }
+
+                CustomDataTypeRegistry::get_instance().mirror_definitions(data_types_register_response.definitions);
+            });
+        }
+
+        if let Some(layout) = contents.layout {
+            app_context.queue_docking_command(DockingCommand::SetRoot { root_node: layout.dock_root });
+        }
+
+        if let Some(scan_constraints) = contents.scan_constraints {
+            let element_scanner_view_data = app_context
+                .dependency_container
+                .get_dependency::<ElementScannerViewData>();
+
+            ElementScannerViewData::restore_scan_constraints(element_scanner_view_data, &scan_constraints);
+        }
+
+        if let Some(project) = contents.project {
+            Self::open_project(app_context, project.project_directory);
+        }
+    }
+
+    /// Opens the workspace's project, unless it is already open. Unsaved changes to another project are handled as for any other
+    /// project switch.
+    fn open_project(
+        app_context: &Arc<AppContext>,
+        project_directory: PathBuf,
+    ) {
+        let opened_project = app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let is_already_open = opened_project.read().is_ok_and(|opened_project| {
+            opened_project
+                .as_ref()
+                .and_then(|project| project.get_project_info().get_project_directory())
+                .is_some_and(|opened_project_directory| opened_project_directory == project_directory)
+        });
+
+        if is_already_open {
+            return;
+        }
+
+        let project_name = project_directory
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .get_dependency::<UnsavedChangesViewData>();
+
+        UnsavedChangesViewData::request_action(
+            unsaved_changes_view_data,
+            app_context.clone(),
+            UnsavedChangesAction::OpenProject {
+                project_directory_path: project_directory,
+                project_name,
+            },
+        );
+    }
+}
+
+impl Default for WorkspaceViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,637 @@
+use squalr_engine_api::commands::memory::write_sequence::memory_write_sequence_request::MemoryWriteSequenceRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::commands::trackable_tasks::cancel::trackable_tasks_cancel_request::TrackableTasksCancelRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
+use squalr_engine_api::events::write_sequence::finished::write_sequence_finished_event::WriteSequenceFinishedEvent;
+use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::projects::project::Project;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::write_sequences::write_sequence::WriteSequence;
+use squalr_engine_api::structures::projects::write_sequences::write_sequence_step::WriteSequenceStep;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The kind of step being edited in the write sequence dialog.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WriteSequenceStepKind {
+    Write,
+    Delay,
+    ToggleFreeze,
+}
+
+impl WriteSequenceStepKind {
+    pub const ALL: [WriteSequenceStepKind; 3] = [
+        WriteSequenceStepKind::Write,
+        WriteSequenceStepKind::Delay,
+        WriteSequenceStepKind::ToggleFreeze,
+    ];
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            WriteSequenceStepKind::Write => "Write",
+            WriteSequenceStepKind::Delay => "Delay",
+            WriteSequenceStepKind::ToggleFreeze => "Toggle freeze",
+        }
+    }
+}
+
+/// A step as edited in the dialog. Fields unused by the step kind are kept, such that switching kinds does not lose input.
+#[derive(Clone)]
+pub struct WriteSequenceStepDraft {
+    pub step_kind: WriteSequenceStepKind,
+    /// The address entry acted on, relative to the project directory.
+    pub project_item_path: PathBuf,
+    pub value: String,
+    pub duration_ms: String,
+}
+
+impl WriteSequenceStepDraft {
+    pub fn new(step_kind: WriteSequenceStepKind) -> Self {
+        Self {
+            step_kind,
+            project_item_path: PathBuf::new(),
+            value: String::new(),
+            duration_ms: "100".to_string(),
+        }
+    }
+
+    fn from_step(step: &WriteSequenceStep) -> Self {
+        match step {
+            WriteSequenceStep::Write { project_item_path, value } => Self {
+                project_item_path: project_item_path.clone(),
+                value: value.get_anonymous_value_string().to_string(),
+                ..Self::new(WriteSequenceStepKind::Write)
+            },
+            WriteSequenceStep::Delay { duration_ms } => Self {
+                duration_ms: duration_ms.to_string(),
+                ..Self::new(WriteSequenceStepKind::Delay)
+            },
+            WriteSequenceStep::ToggleFreeze { project_item_path } => Self {
+                project_item_path: project_item_path.clone(),
+                ..Self::new(WriteSequenceStepKind::ToggleFreeze)
+            },
+        }
+    }
+
+    fn to_step(
+        &self,
+        step_index: usize,
+    ) -> Result<WriteSequenceStep, String> {
+        match self.step_kind {
+            WriteSequenceStepKind::Write => {
+                let format = if self.value.trim_start().starts_with("0x") {
+                    AnonymousValueStringFormat::Hexadecimal
+                } else {
+                    AnonymousValueStringFormat::Decimal
+                };
+
+                Ok(WriteSequenceStep::Write {
+                    project_item_path: self.project_item_path.clone(),
+                    value: AnonymousValueString::new(self.value.trim().to_string(), format, ContainerType::None),
+                })
+            }
+            WriteSequenceStepKind::Delay => {
+                let duration_ms = self
+                    .duration_ms
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|_| format!("Step {}: '{}' is not a valid delay in milliseconds.", step_index + 1, self.duration_ms))?;
+
+                Ok(WriteSequenceStep::Delay { duration_ms })
+            }
+            WriteSequenceStepKind::ToggleFreeze => Ok(WriteSequenceStep::ToggleFreeze {
+                project_item_path: self.project_item_path.clone(),
+            }),
+        }
+    }
+}
+
+/// An address entry that steps can act on, listed by its path relative to the project directory.
+#[derive(Clone)]
+pub struct WriteSequenceEntryOption {
+    pub project_item_path: PathBuf,
+    pub display_name: String,
+}
+
+#[derive(Clone)]
+pub struct WriteSequenceViewData {
+    pub is_dialog_open: bool,
+    pub is_unsupported: bool,
+    pub write_sequence_names: Vec<String>,
+    /// The name of the saved sequence being edited, or none for a new sequence.
+    pub selected_write_sequence_name: Option<String>,
+    pub name: String,
+    pub steps: Vec<WriteSequenceStepDraft>,
+    pub continue_on_error: bool,
+    pub hotkey: String,
+    pub entry_options: Vec<WriteSequenceEntryOption>,
+    /// The task running the sequence. Empty while the engine has not yet responded with the task.
+    pub running_task_id: Option<String>,
+    /// The last sequence task to finish, such that a task finishing before its start response arrives is not shown as running.
+    pub last_finished_task_id: Option<String>,
+    pub running_step_count: usize,
+    pub completed_step_count: usize,
+    pub status_message: Option<String>,
+    pub error_messages: Vec<String>,
+    /// Keeps the engine event listeners for the running sequence registered for as long as this view data lives.
+    pub event_subscriptions: Vec<EngineEventSubscription>,
+}
+
+impl WriteSequenceViewData {
+    pub fn new() -> Self {
+        Self {
+            is_dialog_open: false,
+            is_unsupported: false,
+            write_sequence_names: Vec::new(),
+            selected_write_sequence_name: None,
+            name: String::new(),
+            steps: Vec::new(),
+            continue_on_error: false,
+            hotkey: String::new(),
+            entry_options: Vec::new(),
+            running_task_id: None,
+            last_finished_task_id: None,
+            running_step_count: 0,
+            completed_step_count: 0,
+            status_message: None,
+            error_messages: Vec::new(),
+            event_subscriptions: Vec::new(),
+        }
+    }
+
+    /// Tracks the progress of the running sequence, such that the dialog can show which step is running.
+    pub fn listen_for_progress(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let write_sequence_view_data_filter = write_sequence_view_data.clone();
+        let write_sequence_view_data_clone = write_sequence_view_data.clone();
+        let progress_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<TrackableTaskProgressChangedEvent>(
+            move |event| {
+                write_sequence_view_data_filter
+                    .read("Write sequence progress filter")
+                    .is_some_and(|view_data| view_data.running_task_id.as_ref() == Some(&event.task_id))
+            },
+            move |event| {
+                if let Some(mut view_data) = write_sequence_view_data_clone.write("Write sequence progress event") {
+                    view_data.completed_step_count = (event.progress / 100.0 * view_data.running_step_count as f32).round() as usize;
+                }
+            },
+        );
+
+        let write_sequence_view_data_filter = write_sequence_view_data.clone();
+        let write_sequence_view_data_clone = write_sequence_view_data.clone();
+
+        // The running task is checked again under the write lock, as it may have changed since the filter read it.
+        let finished_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<WriteSequenceFinishedEvent>(
+            move |event| {
+                write_sequence_view_data_filter
+                    .read("Write sequence finished filter")
+                    .is_some_and(|view_data| view_data.is_running_task(&event.task_id))
+            },
+            move |event| {
+                if let Some(mut view_data) = write_sequence_view_data_clone.write("Write sequence finished event") {
+                    if !view_data.is_running_task(&event.task_id) {
+                        return;
+                    }
+
+                    view_data.running_task_id = None;
+                    view_data.last_finished_task_id = Some(event.task_id.clone());
+                    view_data.completed_step_count = event.completed_step_count;
+                    view_data.error_messages = event
+                        .failed_step_indices
+                        .iter()
+                        .map(|step_index| format!("Step {}: The write failed.", step_index + 1))
+                        .collect();
+                    view_data.status_message = Some(if event.was_cancelled {
+                        format!("Cancelled after {} of {} steps.", event.completed_step_count, view_data.running_step_count)
+                    } else {
+                        format!("Finished {} of {} steps.", event.completed_step_count, view_data.running_step_count)
+                    });
+                }
+            },
+        );
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence subscribe") {
+            view_data.event_subscriptions = vec![progress_subscription, finished_subscription];
+        }
+    }
+
+    /// Determines whether the task is the running sequence, or may be while the engine has not yet responded with its task id.
+    fn is_running_task(
+        &self,
+        task_id: &str,
+    ) -> bool {
+        let is_awaiting_task = self.running_task_id.as_deref() == Some("");
+
+        self.running_task_id.as_deref() == Some(task_id) || is_awaiting_task
+    }
+
+    pub fn open_dialog(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let is_supported = engine_unprivileged_state.has_capabilities(EngineCapabilities::WRITE_SEQUENCE);
+        let (write_sequence_names, entry_options) = Self::read_project(&engine_unprivileged_state, |project| {
+            let write_sequence_names = project
+                .get_project_info()
+                .get_project_manifest()
+                .get_write_sequences()
+                .iter()
+                .map(|write_sequence| write_sequence.get_name().to_string())
+                .collect::<Vec<_>>();
+
+            (write_sequence_names, Self::collect_entry_options(project))
+        })
+        .unwrap_or_default();
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence open dialog") {
+            view_data.is_dialog_open = true;
+            view_data.is_unsupported = !is_supported;
+            view_data.write_sequence_names = write_sequence_names;
+            view_data.entry_options = entry_options;
+            view_data.status_message = None;
+            view_data.error_messages.clear();
+        }
+
+        let first_write_sequence_name = write_sequence_view_data
+            .read("Write sequence first name")
+            .and_then(|view_data| view_data.write_sequence_names.first().cloned());
+
+        match first_write_sequence_name {
+            Some(write_sequence_name) => Self::select_write_sequence(write_sequence_view_data, engine_unprivileged_state, &write_sequence_name),
+            None => Self::new_write_sequence(write_sequence_view_data),
+        }
+    }
+
+    pub fn close_dialog(write_sequence_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence close dialog") {
+            view_data.is_dialog_open = false;
+        }
+    }
+
+    /// Loads a saved sequence into the editor.
+    pub fn select_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        write_sequence_name: &str,
+    ) {
+        let write_sequence = Self::read_project(&engine_unprivileged_state, |project| {
+            project
+                .get_project_info()
+                .get_project_manifest()
+                .get_write_sequence(write_sequence_name)
+                .cloned()
+        })
+        .flatten();
+
+        let Some(write_sequence) = write_sequence else {
+            return;
+        };
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence select") {
+            view_data.selected_write_sequence_name = Some(write_sequence.get_name().to_string());
+            view_data.name = write_sequence.get_name().to_string();
+            view_data.steps = write_sequence
+                .get_steps()
+                .iter()
+                .map(WriteSequenceStepDraft::from_step)
+                .collect();
+            view_data.continue_on_error = write_sequence.get_continue_on_error();
+            view_data.hotkey = write_sequence.get_hotkey().unwrap_or_default().to_string();
+            view_data.error_messages.clear();
+        }
+    }
+
+    pub fn new_write_sequence(write_sequence_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence new") {
+            view_data.selected_write_sequence_name = None;
+            view_data.name = format!("Sequence {}", view_data.write_sequence_names.len() + 1);
+            view_data.steps = vec![WriteSequenceStepDraft::new(WriteSequenceStepKind::Write)];
+            view_data.continue_on_error = false;
+            view_data.hotkey.clear();
+            view_data.error_messages.clear();
+        }
+    }
+
+    /// Saves the edited sequence to the opened project, replacing the sequence it was loaded from.
+    pub fn save_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (write_sequence, previous_name) = match write_sequence_view_data.read("Write sequence save") {
+            Some(view_data) => (view_data.build_write_sequence(), view_data.selected_write_sequence_name.clone()),
+            None => return,
+        };
+        let project_manager = engine_unprivileged_state.get_project_manager();
+        let result = write_sequence.and_then(|write_sequence| {
+            // Renaming a sequence saves it under the new name, so the old one is removed rather than left behind.
+            if let Some(previous_name) = previous_name.filter(|previous_name| previous_name != write_sequence.get_name()) {
+                project_manager
+                    .remove_write_sequence(&previous_name)
+                    .map_err(|error| vec![error])?;
+            }
+
+            let write_sequence_name = write_sequence.get_name().to_string();
+
+            project_manager
+                .set_write_sequence(write_sequence)
+                .map(|_| write_sequence_name)
+                .map_err(|error| vec![error])
+        });
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence save result") {
+            match result {
+                Ok(write_sequence_name) => {
+                    if let Some(previous_name) = &view_data.selected_write_sequence_name {
+                        let previous_name = previous_name.clone();
+                        view_data
+                            .write_sequence_names
+                            .retain(|name| name != &previous_name);
+                    }
+
+                    if !view_data.write_sequence_names.contains(&write_sequence_name) {
+                        view_data.write_sequence_names.push(write_sequence_name.clone());
+                    }
+
+                    view_data.status_message = Some(format!("Saved '{}'.", write_sequence_name));
+                    view_data.selected_write_sequence_name = Some(write_sequence_name);
+                    view_data.error_messages.clear();
+                }
+                Err(error_messages) => view_data.error_messages = error_messages,
+            }
+        }
+    }
+
+    pub fn delete_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let selected_write_sequence_name = match write_sequence_view_data.read("Write sequence delete") {
+            Some(view_data) => view_data.selected_write_sequence_name.clone(),
+            None => return,
+        };
+
+        let Some(selected_write_sequence_name) = selected_write_sequence_name else {
+            Self::new_write_sequence(write_sequence_view_data);
+            return;
+        };
+
+        if let Err(error) = engine_unprivileged_state
+            .get_project_manager()
+            .remove_write_sequence(&selected_write_sequence_name)
+        {
+            if let Some(mut view_data) = write_sequence_view_data.write("Write sequence delete error") {
+                view_data.error_messages = vec![error];
+            }
+
+            return;
+        }
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence delete result") {
+            view_data
+                .write_sequence_names
+                .retain(|name| name != &selected_write_sequence_name);
+            view_data.status_message = Some(format!("Deleted '{}'.", selected_write_sequence_name));
+        }
+
+        Self::new_write_sequence(write_sequence_view_data);
+    }
+
+    /// Runs the sequence being edited, without requiring it to be saved first.
+    pub fn run_edited_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let write_sequence = match write_sequence_view_data.read("Write sequence run edited") {
+            Some(view_data) => view_data.build_write_sequence(),
+            None => return,
+        };
+
+        match write_sequence {
+            Ok(write_sequence) => Self::run_write_sequence(write_sequence_view_data, engine_unprivileged_state, &write_sequence),
+            Err(error_messages) => {
+                if let Some(mut view_data) = write_sequence_view_data.write("Write sequence run edited error") {
+                    view_data.error_messages = error_messages;
+                }
+            }
+        }
+    }
+
+    /// Runs the saved sequence bound to a pressed hotkey, if any. Only one sequence runs at a time.
+    pub fn run_hotkey_write_sequences(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_key_pressed: impl Fn(&str) -> bool,
+    ) {
+        let write_sequence = Self::read_project(&engine_unprivileged_state, |project| {
+            project
+                .get_project_info()
+                .get_project_manifest()
+                .get_write_sequences()
+                .iter()
+                .find(|write_sequence| {
+                    write_sequence
+                        .get_hotkey()
+                        .map(&is_key_pressed)
+                        .unwrap_or(false)
+                })
+                .cloned()
+        })
+        .flatten();
+
+        if let Some(write_sequence) = write_sequence {
+            Self::run_write_sequence(write_sequence_view_data, engine_unprivileged_state, &write_sequence);
+        }
+    }
+
+    fn run_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        write_sequence: &WriteSequence,
+    ) {
+        let is_running = write_sequence_view_data
+            .read("Write sequence is running")
+            .map(|view_data| view_data.running_task_id.is_some())
+            .unwrap_or(true);
+
+        if is_running {
+            return;
+        }
+
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::WRITE_SEQUENCE) {
+            log::warn!("Write sequences are not supported by the connected engine.");
+            return;
+        }
+
+        let resolved_steps = Self::read_project(&engine_unprivileged_state, |project| write_sequence.resolve(project))
+            .unwrap_or_else(|| Err(vec![]))
+            .map_err(|resolve_errors| {
+                if resolve_errors.is_empty() {
+                    vec!["No project is opened.".to_string()]
+                } else {
+                    resolve_errors
+                        .iter()
+                        .map(|resolve_error| resolve_error.to_string())
+                        .collect()
+                }
+            });
+
+        let steps = match resolved_steps {
+            Ok(steps) => steps,
+            Err(error_messages) => {
+                if let Some(mut view_data) = write_sequence_view_data.write("Write sequence resolve error") {
+                    view_data.error_messages = error_messages;
+                    view_data.status_message = Some(format!("'{}' was not run.", write_sequence.get_name()));
+                }
+
+                return;
+            }
+        };
+
+        if let Some(mut view_data) = write_sequence_view_data.write("Write sequence run") {
+            // Mark the sequence as running before the request is sent, such that a held hotkey does not start it twice.
+            view_data.running_task_id = Some(String::new());
+            view_data.running_step_count = steps.len();
+            view_data.completed_step_count = 0;
+            view_data.status_message = Some(format!("Running '{}'.", write_sequence.get_name()));
+            view_data.error_messages.clear();
+        }
+
+        let memory_write_sequence_request = MemoryWriteSequenceRequest {
+            steps,
+            continue_on_error: write_sequence.get_continue_on_error(),
+        };
+        let write_sequence_view_data_clone = write_sequence_view_data.clone();
+
+        memory_write_sequence_request.send(&engine_unprivileged_state, move |memory_write_sequence_response| {
+            if let Some(mut view_data) = write_sequence_view_data_clone.write("Write sequence run response") {
+                let task_id = memory_write_sequence_response
+                    .trackable_task_handle
+                    .map(|trackable_task_handle| trackable_task_handle.task_identifier);
+
+                if task_id.is_some() && task_id == view_data.last_finished_task_id {
+                    return;
+                }
+
+                view_data.running_task_id = task_id;
+
+                if view_data.running_task_id.is_none() {
+                    view_data.status_message = Some("The sequence was not started.".to_string());
+                    view_data.error_messages = memory_write_sequence_response
+                        .unresolved_step_indices
+                        .iter()
+                        .map(|step_index| format!("Step {}: The address could not be read.", step_index + 1))
+                        .collect();
+                }
+            }
+        });
+    }
+
+    pub fn cancel_write_sequence(
+        write_sequence_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let task_id = match write_sequence_view_data.read("Write sequence cancel") {
+            Some(view_data) => view_data.running_task_id.clone(),
+            None => None,
+        };
+
+        let Some(task_id) = task_id.filter(|task_id| !task_id.is_empty()) else {
+            return;
+        };
+
+        // The finished event reports how far the sequence got, so the response is not needed.
+        TrackableTasksCancelRequest { task_id }.send(&engine_unprivileged_state, |_response| {});
+    }
+
+    fn build_write_sequence(&self) -> Result<WriteSequence, Vec<String>> {
+        let name = self.name.trim();
+        let mut error_messages = vec![];
+
+        if name.is_empty() {
+            error_messages.push("The sequence needs a name.".to_string());
+        }
+
+        if self.steps.is_empty() {
+            error_messages.push("The sequence has no steps.".to_string());
+        }
+
+        let hotkey = self.hotkey.trim();
+
+        if !hotkey.is_empty() && eframe::egui::Key::from_name(hotkey).is_none() {
+            error_messages.push(format!("'{}' is not a key name, ie F5.", hotkey));
+        }
+
+        let mut steps = vec![];
+
+        for (step_index, step_draft) in self.steps.iter().enumerate() {
+            match step_draft.to_step(step_index) {
+                Ok(step) => steps.push(step),
+                Err(error_message) => error_messages.push(error_message),
+            }
+        }
+
+        if !error_messages.is_empty() {
+            return Err(error_messages);
+        }
+
+        Ok(WriteSequence::new(
+            name.to_string(),
+            steps,
+            self.continue_on_error,
+            (!hotkey.is_empty()).then(|| hotkey.to_string()),
+        ))
+    }
+
+    fn read_project<T>(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        read_project: impl FnOnce(&Project) -> T,
+    ) -> Option<T> {
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let opened_project = match opened_project.read() {
+            Ok(opened_project) => opened_project,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return None;
+            }
+        };
+
+        opened_project.as_ref().map(read_project)
+    }
+
+    fn collect_entry_options(project: &Project) -> Vec<WriteSequenceEntryOption> {
+        let mut entry_options: Vec<WriteSequenceEntryOption> = project
+            .get_project_items()
+            .iter()
+            .filter(|(_project_item_ref, project_item)| project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID)
+            .map(|(project_item_ref, project_item)| {
+                let project_item_path = project.get_relative_path(project_item_ref.get_project_item_path());
+                let display_name = format!("{} ({})", project_item.get_field_name(), project_item_path.display());
+
+                WriteSequenceEntryOption {
+                    project_item_path,
+                    display_name,
+                }
+            })
+            .collect();
+
+        entry_options.sort_by(|left, right| left.project_item_path.cmp(&right.project_item_path));
+
+        entry_options
+    }
+}
+
+impl Default for WriteSequenceViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
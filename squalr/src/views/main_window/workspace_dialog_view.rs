@@ -0,0 +1,222 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::workspace_view_data::WorkspaceViewData;
+use eframe::egui::{Response, RichText, TextEdit, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// Dialogs for saving and opening workspaces, choosing between the workspaces saved for a process, and reporting skipped parts.
+#[derive(Clone)]
+pub struct WorkspaceDialogView {
+    app_context: Arc<AppContext>,
+    workspace_view_data: Dependency<WorkspaceViewData>,
+}
+
+impl WorkspaceDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let workspace_view_data = app_context
+            .dependency_container
+            .register(WorkspaceViewData::new());
+
+        WorkspaceViewData::listen_for_process_changes(workspace_view_data.clone(), app_context.clone());
+
+        Self {
+            app_context,
+            workspace_view_data,
+        }
+    }
+
+    fn show_workspace_choices(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let (workspace_names, target_executable) = match self.workspace_view_data.read("Workspace choices dialog") {
+            Some(view_data) if !view_data.workspace_choices.is_empty() => (
+                view_data
+                    .workspace_choices
+                    .iter()
+                    .map(|workspace| workspace.get_name().to_string())
+                    .collect::<Vec<_>>(),
+                view_data.target_executable.clone().unwrap_or_default(),
+            ),
+            _ => return,
+        };
+        let mut chosen_index = None;
+        let mut should_dismiss = false;
+
+        Window::new("Choose workspace")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label(format!("Several workspaces were saved for {}. Which one should be loaded?", target_executable));
+
+                for (workspace_index, workspace_name) in workspace_names.iter().enumerate() {
+                    if user_interface.button(workspace_name).clicked() {
+                        chosen_index = Some(workspace_index);
+                    }
+                }
+
+                user_interface.separator();
+
+                if user_interface.button("Don't load a workspace").clicked() {
+                    should_dismiss = true;
+                }
+            });
+
+        if let Some(chosen_index) = chosen_index {
+            WorkspaceViewData::choose_workspace(self.workspace_view_data.clone(), self.app_context.clone(), chosen_index);
+        } else if should_dismiss {
+            WorkspaceViewData::dismiss_workspace_choices(self.workspace_view_data.clone());
+        }
+    }
+
+    fn show_save_as_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let theme = &self.app_context.theme;
+        let mut should_save = false;
+        let mut should_close = false;
+
+        Window::new("Save workspace as")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.workspace_view_data.write("Workspace save as dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let target_text = match (&view_data.target_executable, &view_data.active_workspace) {
+                    (Some(target_executable), _) => format!("The workspace is loaded whenever Squalr attaches to {}.", target_executable),
+                    (None, Some(active_workspace)) => format!(
+                        "The workspace is loaded whenever Squalr attaches to {}.",
+                        active_workspace.get_target_executable()
+                    ),
+                    (None, None) => "Attach to a process first, such that the workspace knows which executable it belongs to.".to_string(),
+                };
+
+                user_interface.label(target_text);
+                user_interface.horizontal(|user_interface| {
+                    user_interface.label("Name");
+                    user_interface.add(TextEdit::singleline(&mut view_data.save_as_name).desired_width(240.0));
+                });
+
+                if let Some(dialog_error) = &view_data.dialog_error {
+                    user_interface.label(RichText::new(dialog_error).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Save").clicked() {
+                        should_save = true;
+                    }
+
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_save {
+            WorkspaceViewData::save_workspace_as(self.workspace_view_data.clone(), self.app_context.clone());
+        } else if should_close {
+            WorkspaceViewData::close_dialogs(self.workspace_view_data.clone());
+        }
+    }
+
+    fn show_open_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let theme = &self.app_context.theme;
+        let mut should_open = false;
+        let mut should_close = false;
+
+        Window::new("Open workspace")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.workspace_view_data.write("Workspace open dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+
+                user_interface.label("The folder holding the workspace's workspace.json file.");
+                user_interface.add(TextEdit::singleline(&mut view_data.open_directory).desired_width(360.0));
+
+                if let Some(dialog_error) = &view_data.dialog_error {
+                    user_interface.label(RichText::new(dialog_error).color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Open").clicked() {
+                        should_open = true;
+                    }
+
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_open {
+            WorkspaceViewData::open_workspace_from_directory(self.workspace_view_data.clone(), self.app_context.clone());
+        } else if should_close {
+            WorkspaceViewData::close_dialogs(self.workspace_view_data.clone());
+        }
+    }
+
+    fn show_load_warnings(
+        &self,
+        user_interface: &mut Ui,
+        load_warnings: &[String],
+    ) {
+        let theme = &self.app_context.theme;
+        let mut should_dismiss = false;
+
+        Window::new("Workspace loaded with warnings")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label("Some parts of the workspace could not be loaded, and were left as they were:");
+
+                for load_warning in load_warnings {
+                    user_interface.label(RichText::new(load_warning).color(theme.error_red));
+                }
+
+                if user_interface.button("OK").clicked() {
+                    should_dismiss = true;
+                }
+            });
+
+        if should_dismiss {
+            WorkspaceViewData::dismiss_load_warnings(self.workspace_view_data.clone());
+        }
+    }
+}
+
+impl Widget for WorkspaceDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let (is_save_as_dialog_open, is_open_dialog_open, load_warnings) = match self.workspace_view_data.read("Workspace dialog state") {
+            Some(view_data) => (view_data.is_save_as_dialog_open, view_data.is_open_dialog_open, view_data.load_warnings.clone()),
+            None => return user_interface.response(),
+        };
+
+        self.show_workspace_choices(user_interface);
+
+        if is_save_as_dialog_open {
+            self.show_save_as_dialog(user_interface);
+        }
+
+        if is_open_dialog_open {
+            self.show_open_dialog(user_interface);
+        }
+
+        if !load_warnings.is_empty() {
+            self.show_load_warnings(user_interface, &load_warnings);
+        }
+
+        user_interface.response()
+    }
+}
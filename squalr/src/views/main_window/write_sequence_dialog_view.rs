@@ -0,0 +1,325 @@
+use crate::app_context::AppContext;
+use crate::ui::widgets::controls::checkbox::Checkbox;
+use crate::views::main_window::view_data::write_sequence_view_data::{WriteSequenceStepDraft, WriteSequenceStepKind, WriteSequenceViewData};
+use eframe::egui::{Button, ComboBox, Event, Grid, Key, Response, RichText, ScrollArea, Spinner, TextEdit, Ui, Widget, Window};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+/// An action requested from the write sequence dialog, deferred until the view data lock is released.
+enum WriteSequenceDialogAction {
+    Select(String),
+    New,
+    Save,
+    Delete,
+    Run,
+    Cancel,
+    Close,
+}
+
+/// Dialog for building, saving, and running timed write sequences of the opened project.
+#[derive(Clone)]
+pub struct WriteSequenceDialogView {
+    app_context: Arc<AppContext>,
+    write_sequence_view_data: Dependency<WriteSequenceViewData>,
+}
+
+impl WriteSequenceDialogView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let write_sequence_view_data = app_context
+            .dependency_container
+            .register(WriteSequenceViewData::new());
+
+        WriteSequenceViewData::listen_for_progress(write_sequence_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            write_sequence_view_data,
+        }
+    }
+
+    /// Runs sequences bound to a hotkey. Hotkeys are ignored while a widget has focus, such that typing does not trigger them.
+    fn handle_hotkeys(
+        &self,
+        user_interface: &Ui,
+    ) {
+        let context = user_interface.ctx();
+
+        let is_key_pressed = context.input(|input_state| {
+            input_state.events.iter().any(|event| {
+                matches!(
+                    event,
+                    Event::Key {
+                        pressed: true,
+                        repeat: false,
+                        ..
+                    }
+                )
+            })
+        });
+
+        if !is_key_pressed || context.memory(|memory| memory.focused().is_some()) {
+            return;
+        }
+
+        WriteSequenceViewData::run_hotkey_write_sequences(
+            self.write_sequence_view_data.clone(),
+            self.app_context.engine_unprivileged_state.clone(),
+            |hotkey| {
+                Key::from_name(hotkey)
+                    .map(|key| context.input(|input_state| input_state.key_pressed(key)))
+                    .unwrap_or(false)
+            },
+        );
+    }
+}
+
+impl Widget for WriteSequenceDialogView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        self.handle_hotkeys(user_interface);
+
+        let is_dialog_open = self
+            .write_sequence_view_data
+            .read("Write sequence dialog state")
+            .map(|view_data| view_data.is_dialog_open)
+            .unwrap_or(false);
+
+        if !is_dialog_open {
+            return user_interface.response();
+        }
+
+        let theme = &self.app_context.theme;
+        let mut action = None;
+
+        Window::new("Write sequences")
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.write_sequence_view_data.write("Write sequence dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let view_data = &mut *view_data;
+
+                if view_data.is_unsupported {
+                    user_interface.label(RichText::new("Write sequences are not supported by the connected engine.").color(theme.error_red));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    let selected_text = view_data
+                        .selected_write_sequence_name
+                        .clone()
+                        .unwrap_or_else(|| "New sequence".to_string());
+
+                    ComboBox::from_id_salt("write_sequence_selector")
+                        .width(240.0)
+                        .selected_text(selected_text)
+                        .show_ui(user_interface, |user_interface| {
+                            for write_sequence_name in &view_data.write_sequence_names {
+                                let is_selected = view_data.selected_write_sequence_name.as_ref() == Some(write_sequence_name);
+
+                                if user_interface
+                                    .selectable_label(is_selected, write_sequence_name)
+                                    .clicked()
+                                {
+                                    action = Some(WriteSequenceDialogAction::Select(write_sequence_name.clone()));
+                                }
+                            }
+                        });
+
+                    if user_interface.button("New").clicked() {
+                        action = Some(WriteSequenceDialogAction::New);
+                    }
+                });
+
+                Grid::new("write_sequence_dialog_fields")
+                    .num_columns(2)
+                    .spacing([8.0, 6.0])
+                    .show(user_interface, |user_interface| {
+                        user_interface.label("Name");
+                        user_interface.add(TextEdit::singleline(&mut view_data.name).desired_width(240.0));
+                        user_interface.end_row();
+
+                        user_interface.label("Hotkey");
+                        user_interface.add(
+                            TextEdit::singleline(&mut view_data.hotkey)
+                                .hint_text("ie F5")
+                                .desired_width(80.0),
+                        );
+                        user_interface.end_row();
+
+                        user_interface.label("Continue on error");
+                        if user_interface
+                            .add(Checkbox::new_from_theme(theme).with_check_state_bool(view_data.continue_on_error))
+                            .clicked()
+                        {
+                            view_data.continue_on_error = !view_data.continue_on_error;
+                        }
+                        user_interface.end_row();
+                    });
+
+                user_interface.separator();
+
+                let mut removed_step_index = None;
+                let mut moved_step_index = None;
+                let step_count = view_data.steps.len();
+
+                ScrollArea::vertical()
+                    .max_height(240.0)
+                    .show(user_interface, |user_interface| {
+                        for (step_index, step) in view_data.steps.iter_mut().enumerate() {
+                            user_interface.horizontal(|user_interface| {
+                                user_interface.label(format!("{}.", step_index + 1));
+
+                                ComboBox::from_id_salt(("write_sequence_step_kind", step_index))
+                                    .width(110.0)
+                                    .selected_text(step.step_kind.get_display_name())
+                                    .show_ui(user_interface, |user_interface| {
+                                        for step_kind in WriteSequenceStepKind::ALL {
+                                            user_interface.selectable_value(&mut step.step_kind, step_kind, step_kind.get_display_name());
+                                        }
+                                    });
+
+                                match step.step_kind {
+                                    WriteSequenceStepKind::Write | WriteSequenceStepKind::ToggleFreeze => {
+                                        let selected_entry_text = view_data
+                                            .entry_options
+                                            .iter()
+                                            .find(|entry_option| entry_option.project_item_path == step.project_item_path)
+                                            .map(|entry_option| entry_option.display_name.clone())
+                                            .unwrap_or_else(|| step.project_item_path.display().to_string());
+
+                                        ComboBox::from_id_salt(("write_sequence_step_entry", step_index))
+                                            .width(200.0)
+                                            .selected_text(selected_entry_text)
+                                            .show_ui(user_interface, |user_interface| {
+                                                for entry_option in &view_data.entry_options {
+                                                    user_interface.selectable_value(
+                                                        &mut step.project_item_path,
+                                                        entry_option.project_item_path.clone(),
+                                                        &entry_option.display_name,
+                                                    );
+                                                }
+                                            });
+
+                                        if step.step_kind == WriteSequenceStepKind::Write {
+                                            user_interface.add(
+                                                TextEdit::singleline(&mut step.value)
+                                                    .hint_text("Value")
+                                                    .desired_width(90.0),
+                                            );
+                                        }
+                                    }
+                                    WriteSequenceStepKind::Delay => {
+                                        user_interface.add(TextEdit::singleline(&mut step.duration_ms).desired_width(70.0));
+                                        user_interface.label("ms");
+                                    }
+                                }
+
+                                if user_interface
+                                    .add_enabled(step_index > 0, Button::new("Up"))
+                                    .clicked()
+                                {
+                                    moved_step_index = Some(step_index);
+                                }
+
+                                if user_interface.button("Remove").clicked() {
+                                    removed_step_index = Some(step_index);
+                                }
+                            });
+                        }
+                    });
+
+                if let Some(moved_step_index) = moved_step_index {
+                    view_data.steps.swap(moved_step_index - 1, moved_step_index);
+                } else if let Some(removed_step_index) = removed_step_index.filter(|step_index| *step_index < step_count) {
+                    view_data.steps.remove(removed_step_index);
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Add write").clicked() {
+                        view_data
+                            .steps
+                            .push(WriteSequenceStepDraft::new(WriteSequenceStepKind::Write));
+                    }
+
+                    if user_interface.button("Add delay").clicked() {
+                        view_data
+                            .steps
+                            .push(WriteSequenceStepDraft::new(WriteSequenceStepKind::Delay));
+                    }
+
+                    if user_interface.button("Add freeze toggle").clicked() {
+                        view_data
+                            .steps
+                            .push(WriteSequenceStepDraft::new(WriteSequenceStepKind::ToggleFreeze));
+                    }
+                });
+
+                user_interface.separator();
+
+                for error_message in &view_data.error_messages {
+                    user_interface.label(RichText::new(error_message).color(theme.error_red));
+                }
+
+                if view_data.running_task_id.is_some() {
+                    user_interface.horizontal(|user_interface| {
+                        user_interface.add(Spinner::new().color(theme.foreground));
+                        user_interface.label(format!(
+                            "Step {} of {}",
+                            (view_data.completed_step_count + 1).min(view_data.running_step_count),
+                            view_data.running_step_count
+                        ));
+
+                        if user_interface.button("Cancel").clicked() {
+                            action = Some(WriteSequenceDialogAction::Cancel);
+                        }
+                    });
+                } else if let Some(status_message) = &view_data.status_message {
+                    user_interface.label(status_message);
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Close").clicked() {
+                        action = Some(WriteSequenceDialogAction::Close);
+                    }
+
+                    if user_interface.button("Delete").clicked() {
+                        action = Some(WriteSequenceDialogAction::Delete);
+                    }
+
+                    if user_interface.button("Save").clicked() {
+                        action = Some(WriteSequenceDialogAction::Save);
+                    }
+
+                    if user_interface
+                        .add_enabled(view_data.running_task_id.is_none() && !view_data.is_unsupported, Button::new("Run"))
+                        .clicked()
+                    {
+                        action = Some(WriteSequenceDialogAction::Run);
+                    }
+                });
+            });
+
+        let write_sequence_view_data = self.write_sequence_view_data.clone();
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+
+        match action {
+            Some(WriteSequenceDialogAction::Select(write_sequence_name)) => {
+                WriteSequenceViewData::select_write_sequence(write_sequence_view_data, engine_unprivileged_state, &write_sequence_name)
+            }
+            Some(WriteSequenceDialogAction::New) => WriteSequenceViewData::new_write_sequence(write_sequence_view_data),
+            Some(WriteSequenceDialogAction::Save) => WriteSequenceViewData::save_write_sequence(write_sequence_view_data, engine_unprivileged_state),
+            Some(WriteSequenceDialogAction::Delete) => WriteSequenceViewData::delete_write_sequence(write_sequence_view_data, engine_unprivileged_state),
+            Some(WriteSequenceDialogAction::Run) => WriteSequenceViewData::run_edited_write_sequence(write_sequence_view_data, engine_unprivileged_state),
+            Some(WriteSequenceDialogAction::Cancel) => WriteSequenceViewData::cancel_write_sequence(write_sequence_view_data, engine_unprivileged_state),
+            Some(WriteSequenceDialogAction::Close) => WriteSequenceViewData::close_dialog(write_sequence_view_data),
+            None => {}
+        }
+
+        user_interface.response()
+    }
+}
@@ -0,0 +1,269 @@
+use crate::app_context::AppContext;
+use crate::models::docking::docking_command::DockingCommand;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::toolbar_layout::ToolbarLayout;
+use crate::ui::widgets::controls::button::Button;
+use crate::views::disassembler::disassembler_view::DisassemblerView;
+use crate::views::disassembler::view_data::disassembler_view_data::DisassemblerViewData;
+use crate::views::memory_accessors::view_data::memory_accessors_view_data::MemoryAccessorsViewData;
+use eframe::egui::{Align, Grid, Label, Layout, Response, RichText, ScrollArea, Sense, Ui, UiBuilder, Widget};
+use epaint::{Color32, CornerRadius, Stroke, StrokeKind, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Lists the instructions found accessing a watched address, along with how often each did so and the registers of the latest access.
+#[derive(Clone)]
+pub struct MemoryAccessorsView {
+    app_context: Arc<AppContext>,
+    memory_accessors_view_data: Dependency<MemoryAccessorsViewData>,
+    disassembler_view_data: Dependency<DisassemblerViewData>,
+}
+
+impl MemoryAccessorsView {
+    pub const WINDOW_ID: &'static str = "window_memory_accessors";
+    const ADDRESS_COLUMN_WIDTH: f32 = 160.0;
+    const REGISTER_COLUMN_COUNT: usize = 4;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let memory_accessors_view_data = app_context
+            .dependency_container
+            .register(MemoryAccessorsViewData::new());
+
+        let disassembler_view_data = app_context
+            .dependency_container
+            .get_dependency::<DisassemblerViewData>();
+
+        MemoryAccessorsViewData::listen_for_watch_events(memory_accessors_view_data.clone(), app_context.engine_unprivileged_state.clone());
+
+        Self {
+            app_context,
+            memory_accessors_view_data,
+            disassembler_view_data,
+        }
+    }
+
+    fn format_address(address: u64) -> String {
+        if address <= u32::MAX as u64 {
+            format!("{:08X}", address)
+        } else {
+            format!("{:016X}", address)
+        }
+    }
+}
+
+impl Widget for MemoryAccessorsView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let toolbar_layout = ToolbarLayout::new(user_interface, &theme.font_library.font_noto_sans.font_normal);
+        let item_height = toolbar_layout.get_item_height();
+        let mut should_stop_watch = false;
+        let mut should_clear_accessors = false;
+        let mut copy_text: Option<String> = None;
+        let mut disassemble_address: Option<u64> = None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                let mut memory_accessors_view_data = match self.memory_accessors_view_data.write("Memory accessors view") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+                let is_watching = memory_accessors_view_data.active_watch_id.is_some();
+
+                // Hits stream in from the engine without any input, so keep redrawing while they can arrive.
+                if is_watching {
+                    user_interface
+                        .ctx()
+                        .request_repaint_after(Duration::from_millis(100));
+
+                    let (banner_rect, _) = user_interface.allocate_exact_size(vec2(user_interface.available_width(), item_height * 1.5), Sense::hover());
+
+                    user_interface
+                        .painter()
+                        .rect_filled(banner_rect, CornerRadius::ZERO, theme.background_control_danger);
+                    user_interface.scope_builder(UiBuilder::new().max_rect(banner_rect.shrink(4.0)), |user_interface| {
+                        user_interface.label(
+                            RichText::new("Page trap active: every access to the watched page stops the target. Expect heavy slowdowns until stopped.")
+                                .color(Color32::WHITE)
+                                .strong(),
+                        );
+                    });
+                }
+
+                user_interface.add_space(ToolbarLayout::PADDING);
+                user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                    let stop_button = user_interface.add_sized(
+                        vec2(item_height, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Stop watching"),
+                    );
+                    IconDraw::draw(user_interface, stop_button.rect, &theme.icon_library.icon_handle_navigation_cancel);
+
+                    if stop_button.clicked() {
+                        should_stop_watch = true;
+                    }
+
+                    let clear_button = user_interface.add_sized(
+                        vec2(item_height, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Clear accessors"),
+                    );
+                    IconDraw::draw(user_interface, clear_button.rect, &theme.icon_library.icon_handle_common_delete);
+
+                    if clear_button.clicked() {
+                        should_clear_accessors = true;
+                    }
+
+                    match memory_accessors_view_data.watched_address {
+                        Some(watched_address) => user_interface.label(format!(
+                            "What {} {} ({} bytes)",
+                            memory_accessors_view_data.watch_type.get_description(),
+                            Self::format_address(watched_address),
+                            memory_accessors_view_data.watched_size
+                        )),
+                        None => user_interface.label("Nothing watched"),
+                    };
+
+                    user_interface.colored_label(theme.foreground_preview, &memory_accessors_view_data.status_message);
+                });
+                user_interface.add_space(ToolbarLayout::PADDING);
+
+                user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                    user_interface.add_sized(vec2(Self::ADDRESS_COLUMN_WIDTH, item_height), Label::new("Instruction"));
+                    user_interface.add_sized(vec2(Self::ADDRESS_COLUMN_WIDTH, item_height), Label::new("Last access"));
+                    user_interface.label("Count");
+                });
+
+                let selected_registers = memory_accessors_view_data
+                    .selected_index
+                    .and_then(|selected_index| memory_accessors_view_data.accessors.get(selected_index))
+                    .and_then(|accessor| accessor.registers.clone());
+                let named_registers = selected_registers
+                    .map(|registers| registers.get_named_registers())
+                    .unwrap_or_default();
+                let register_row_count = named_registers.len().div_ceil(Self::REGISTER_COLUMN_COUNT);
+                let register_panel_height = if named_registers.is_empty() {
+                    0.0
+                } else {
+                    (register_row_count as f32 + 1.0) * item_height + ToolbarLayout::PADDING * 2.0
+                };
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .max_height((user_interface.available_height() - register_panel_height).max(item_height))
+                    .show(user_interface, |user_interface| {
+                        if memory_accessors_view_data.accessors.is_empty() {
+                            user_interface.label(
+                                "Right click a scan result and choose \"Find out what writes to this address\" or \"Find out what accesses this address\" to start a watch.",
+                            );
+                            return;
+                        }
+
+                        let mut clicked_index = None;
+
+                        for (index, accessor) in memory_accessors_view_data.accessors.iter().enumerate() {
+                            let is_selected = memory_accessors_view_data.selected_index == Some(index);
+                            let row_response = user_interface
+                                .with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                                    user_interface.add_sized(
+                                        vec2(Self::ADDRESS_COLUMN_WIDTH, item_height),
+                                        Label::new(RichText::new(Self::format_address(accessor.instruction_address)).color(theme.hexadecimal_green)),
+                                    );
+                                    user_interface.add_sized(
+                                        vec2(Self::ADDRESS_COLUMN_WIDTH, item_height),
+                                        Label::new(RichText::new(Self::format_address(accessor.access_address)).color(theme.hexadecimal_green)),
+                                    );
+                                    user_interface.label(accessor.hit_count.to_string());
+                                })
+                                .response
+                                .interact(Sense::click());
+
+                            if is_selected {
+                                user_interface.painter().rect_stroke(
+                                    row_response.rect,
+                                    CornerRadius::ZERO,
+                                    Stroke::new(1.0, theme.selected_border),
+                                    StrokeKind::Inside,
+                                );
+                            }
+
+                            if row_response.clicked() {
+                                clicked_index = Some(index);
+                            }
+
+                            if row_response.double_clicked() {
+                                disassemble_address = Some(accessor.instruction_address);
+                            }
+
+                            row_response.context_menu(|ui| {
+                                if ui.button("Copy instruction address").clicked() {
+                                    copy_text = Some(Self::format_address(accessor.instruction_address));
+                                    ui.close();
+                                }
+                                if ui.button("Disassemble this instruction").clicked() {
+                                    disassemble_address = Some(accessor.instruction_address);
+                                    ui.close();
+                                }
+                            });
+                        }
+
+                        if clicked_index.is_some() {
+                            memory_accessors_view_data.selected_index = clicked_index;
+                        }
+                    });
+
+                // Registers are as of the most recent access by the selected instruction, taken before it executed.
+                if !named_registers.is_empty() {
+                    user_interface.add_space(ToolbarLayout::PADDING);
+                    user_interface.strong("Registers");
+                    Grid::new("memory_accessors_registers")
+                        .num_columns(Self::REGISTER_COLUMN_COUNT * 2)
+                        .spacing([8.0, 0.0])
+                        .show(user_interface, |user_interface| {
+                            for register_row in named_registers.chunks(Self::REGISTER_COLUMN_COUNT) {
+                                for (register_name, register_value) in register_row {
+                                    user_interface.label(*register_name);
+                                    user_interface.label(
+                                        RichText::new(Self::format_address(*register_value))
+                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                            .color(theme.hexadecimal_green),
+                                    );
+                                }
+
+                                user_interface.end_row();
+                            }
+                        });
+                }
+            })
+            .response;
+
+        if let Some(copy_text) = copy_text {
+            user_interface.ctx().copy_text(copy_text);
+        }
+
+        if let Some(address) = disassemble_address {
+            DisassemblerViewData::set_target_address(self.disassembler_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), address);
+
+            self.app_context
+                .queue_docking_command(DockingCommand::ShowWindow {
+                    window_id: DisassemblerView::WINDOW_ID.to_string(),
+                    is_visible: true,
+                });
+        }
+
+        if should_stop_watch {
+            MemoryAccessorsViewData::stop_watch(self.memory_accessors_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if should_clear_accessors {
+            MemoryAccessorsViewData::clear_accessors(self.memory_accessors_view_data.clone());
+        }
+
+        response
+    }
+}
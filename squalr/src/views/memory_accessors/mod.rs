@@ -0,0 +1,2 @@
+pub mod memory_accessors_view;
+pub mod view_data;
@@ -0,0 +1,275 @@
+use squalr_engine_api::commands::memory::unwatch::memory_unwatch_request::MemoryUnwatchRequest;
+use squalr_engine_api::commands::memory::watch::memory_watch_request::MemoryWatchRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
+use squalr_engine_api::events::memory_watch::hits::memory_watch_hits_event::MemoryWatchHitsEvent;
+use squalr_engine_api::events::memory_watch::stopped::memory_watch_stopped_event::MemoryWatchStoppedEvent;
+use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+use squalr_engine_api::structures::memory::memory_watch_type::MemoryWatchType;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct MemoryAccessorsViewData {
+    pub watched_address: Option<u64>,
+    pub watched_size: u64,
+    pub watch_type: MemoryWatchType,
+    pub active_watch_id: Option<u64>,
+    /// Instructions that accessed the watched address, in the order they were first seen, with running hit counts.
+    pub accessors: Vec<MemoryWatchHit>,
+    pub selected_index: Option<usize>,
+    pub status_message: String,
+    /// Whether the window was open as of the last frame, such that closing it can be told apart from it never having been opened.
+    is_window_visible: bool,
+}
+
+impl MemoryAccessorsViewData {
+    pub fn new() -> Self {
+        Self {
+            watched_address: None,
+            watched_size: 0,
+            watch_type: MemoryWatchType::default(),
+            active_watch_id: None,
+            accessors: Vec::new(),
+            selected_index: None,
+            status_message: String::new(),
+            is_window_visible: false,
+        }
+    }
+
+    pub fn listen_for_watch_events(
+        memory_accessors_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let memory_accessors_view_data_clone = memory_accessors_view_data.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<MemoryWatchHitsEvent>(move |event| {
+            if let Some(mut view_data) = memory_accessors_view_data_clone.write("Memory accessors hits event") {
+                view_data.apply_hits(event.watch_id, &event.hits);
+            }
+        });
+
+        let memory_accessors_view_data_clone = memory_accessors_view_data.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<MemoryWatchStoppedEvent>(move |event| {
+            if let Some(mut view_data) = memory_accessors_view_data_clone.write("Memory accessors stopped event") {
+                let is_active_watch = view_data.active_watch_id == Some(event.watch_id);
+
+                if is_active_watch {
+                    view_data.active_watch_id = None;
+                    view_data.status_message = format!("Watch ended: {}", event.reason);
+                }
+            }
+        });
+    }
+
+    /// Merges reported hits into the accessor list. Reports from watches other than the active one are stale, and are dropped.
+    pub fn apply_hits(
+        &mut self,
+        watch_id: u64,
+        hits: &[MemoryWatchHit],
+    ) {
+        if self.active_watch_id != Some(watch_id) {
+            return;
+        }
+
+        for hit in hits {
+            match self
+                .accessors
+                .iter_mut()
+                .find(|accessor| accessor.instruction_address == hit.instruction_address)
+            {
+                Some(accessor) => {
+                    accessor.access_address = hit.access_address;
+                    accessor.hit_count = accessor.hit_count.saturating_add(hit.hit_count);
+
+                    if hit.registers.is_some() {
+                        accessor.registers = hit.registers.clone();
+                    }
+                }
+                None => self.accessors.push(hit.clone()),
+            }
+        }
+    }
+
+    /// Starts a page trap watch on the given range, replacing any watch that is already running.
+    pub fn start_page_trap_watch(
+        memory_accessors_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        size: u64,
+        watch_type: MemoryWatchType,
+    ) {
+        Self::stop_watch(memory_accessors_view_data.clone(), engine_unprivileged_state.clone());
+
+        let is_supported = engine_unprivileged_state.has_capabilities(EngineCapabilities::MEMORY_WATCH);
+
+        match memory_accessors_view_data.write("Memory accessors start watch") {
+            Some(mut view_data) => {
+                view_data.watched_address = Some(address);
+                view_data.watched_size = size;
+                view_data.watch_type = watch_type;
+                view_data.accessors.clear();
+                view_data.selected_index = None;
+                view_data.status_message = if is_supported {
+                    "Attaching...".to_string()
+                } else {
+                    "Memory watches are not supported by the connected engine.".to_string()
+                };
+            }
+            None => return,
+        }
+
+        if !is_supported {
+            return;
+        }
+
+        let memory_watch_request = MemoryWatchRequest {
+            address,
+            module_name: String::new(),
+            size,
+            watch_type,
+        };
+
+        memory_watch_request.send(&engine_unprivileged_state, move |memory_watch_response| {
+            if let Some(mut view_data) = memory_accessors_view_data.write("Memory accessors watch response") {
+                // A different address may have been watched while this request was in flight.
+                if view_data.watched_address != Some(address) {
+                    return;
+                }
+
+                view_data.active_watch_id = memory_watch_response.watch_id;
+                view_data.status_message = match (memory_watch_response.watch_id, memory_watch_response.error_message) {
+                    (Some(_), _) => "Watching. Every access to the page is trapped, so the target runs slowly until stopped.".to_string(),
+                    (None, Some(error_message)) => format!("Failed to start the watch: {}", error_message),
+                    (None, None) => "Failed to start the watch.".to_string(),
+                };
+            }
+        });
+    }
+
+    pub fn stop_watch(
+        memory_accessors_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let watch_id = match memory_accessors_view_data.write("Memory accessors stop watch") {
+            Some(mut view_data) => match view_data.active_watch_id.take() {
+                Some(watch_id) => {
+                    view_data.status_message = "Stopped.".to_string();
+                    watch_id
+                }
+                None => return,
+            },
+            None => return,
+        };
+
+        let memory_unwatch_request = MemoryUnwatchRequest { watch_id };
+
+        memory_unwatch_request.send(&engine_unprivileged_state, |_memory_unwatch_response| {});
+    }
+
+    /// Stops the active watch once the window is closed, since nothing would show its hits while the target keeps slowing down.
+    pub fn set_window_visible(
+        memory_accessors_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        is_window_visible: bool,
+    ) {
+        let was_window_closed = match memory_accessors_view_data.write("Memory accessors set window visible") {
+            Some(mut view_data) => view_data.update_window_visibility(is_window_visible),
+            None => return,
+        };
+
+        if was_window_closed {
+            Self::stop_watch(memory_accessors_view_data, engine_unprivileged_state);
+        }
+    }
+
+    /// Records whether the window is open, returning whether it was closed since the last update.
+    fn update_window_visibility(
+        &mut self,
+        is_window_visible: bool,
+    ) -> bool {
+        let was_window_closed = self.is_window_visible && !is_window_visible;
+
+        self.is_window_visible = is_window_visible;
+
+        was_window_closed
+    }
+
+    pub fn clear_accessors(memory_accessors_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_accessors_view_data.write("Memory accessors clear") {
+            view_data.accessors.clear();
+            view_data.selected_index = None;
+        }
+    }
+}
+
+impl Default for MemoryAccessorsViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryAccessorsViewData;
+    use squalr_engine_api::structures::memory::memory_watch_hit::MemoryWatchHit;
+    use squalr_engine_api::structures::memory::register_snapshot::RegisterSnapshot;
+
+    fn make_hit(
+        instruction_address: u64,
+        hit_count: u64,
+    ) -> MemoryWatchHit {
+        MemoryWatchHit {
+            instruction_address,
+            access_address: 0x1000,
+            hit_count,
+            registers: None,
+        }
+    }
+
+    #[test]
+    fn hits_accumulate_per_instruction_and_stale_watches_are_ignored() {
+        let mut view_data = MemoryAccessorsViewData::new();
+
+        view_data.active_watch_id = Some(2);
+        view_data.apply_hits(2, &[make_hit(0x400010, 3), make_hit(0x400000, 1)]);
+        view_data.apply_hits(2, &[make_hit(0x400010, 4)]);
+        view_data.apply_hits(1, &[make_hit(0x400020, 9)]);
+
+        assert_eq!(view_data.accessors, vec![make_hit(0x400010, 7), make_hit(0x400000, 1)]);
+    }
+
+    #[test]
+    fn accessors_keep_the_most_recent_registers() {
+        let mut view_data = MemoryAccessorsViewData::new();
+        let registers = RegisterSnapshot {
+            rax: 0x20,
+            ..RegisterSnapshot::default()
+        };
+        let hit_with_registers = MemoryWatchHit {
+            registers: Some(registers.clone()),
+            ..make_hit(0x400010, 1)
+        };
+
+        view_data.active_watch_id = Some(1);
+        view_data.apply_hits(1, &[make_hit(0x400010, 1)]);
+        view_data.apply_hits(1, &[hit_with_registers]);
+        view_data.apply_hits(1, &[make_hit(0x400010, 1)]);
+
+        assert_eq!(view_data.accessors[0].hit_count, 3);
+        assert_eq!(view_data.accessors[0].registers, Some(registers));
+    }
+
+    #[test]
+    fn only_closing_an_open_window_is_reported() {
+        let mut view_data = MemoryAccessorsViewData::new();
+
+        assert!(!view_data.update_window_visibility(false));
+        assert!(!view_data.update_window_visibility(true));
+        assert!(!view_data.update_window_visibility(true));
+        assert!(view_data.update_window_visibility(false));
+        assert!(!view_data.update_window_visibility(false));
+    }
+}
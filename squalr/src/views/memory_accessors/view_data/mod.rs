@@ -0,0 +1 @@
+pub mod memory_accessors_view_data;
@@ -1,16 +1,32 @@
 use crate::app_context::AppContext;
+use crate::models::appearance::appearance_settings::AppearanceSettings;
+use crate::models::docking::docking_command::DockingCommand;
+use crate::ui::line_selection::LineSelection;
+use crate::ui::theme::Theme;
+use crate::ui::toolbar_layout::{ToolbarItem, ToolbarLayout};
+use crate::ui::widgets::controls::address_resolution_label::AddressResolutionLabel;
 use crate::ui::widgets::controls::data_type_selector::data_type_selector_view::DataTypeSelectorView;
+use crate::views::disassembler::disassembler_view::DisassemblerView;
 use crate::views::disassembler::view_data::disassembler_view_data::DisassemblerViewData;
-use crate::views::memory_viewer::view_data::memory_viewer_view_data::MemoryViewerViewData;
+use crate::views::main_window::view_data::add_to_project_view_data::{AddToProjectEntryKind, AddToProjectViewData};
+use crate::ui::widgets::controls::checkbox::Checkbox;
+use crate::views::memory_viewer::view_data::byte_copy_format::ByteCopyFormat;
+use crate::views::memory_viewer::view_data::data_inspector::DataInspector;
+use crate::views::memory_viewer::view_data::memory_viewer_view_data::{MemoryViewerViewData, SearchHighlight};
+use crate::views::process_selector::view_data::process_selector_view_data::ProcessSelectorViewData;
+use eframe::egui::text::LayoutJob;
 use eframe::egui::{
-    Align, CentralPanel, Direction, Layout, Response, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, ViewportBuilder, ViewportId,
-    Widget,
+    Align, Align2, CentralPanel, ComboBox, Direction, Event, Key, Layout, Response, ScrollArea, Sense, Spinner, TextEdit, TextFormat, Ui, UiBuilder,
+    ViewportBuilder, ViewportCommand, ViewportId, Widget, Window,
 };
-use epaint::{CornerRadius, Rect, Stroke, StrokeKind, pos2, vec2};
+use epaint::{Color32, CornerRadius, Pos2, Rect, Stroke, StrokeKind, pos2, vec2};
 use squalr_engine_api::dependency_injection::dependency::Dependency;
 use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::string::encoded::data_type_string_encoded::DataTypeStringEncoded;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::data_value::DataValue;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::memory::bitness::Bitness;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -24,11 +40,14 @@ pub struct MemoryViewerView {
 impl MemoryViewerView {
     pub const WINDOW_ID: &'static str = "window_memory_viewer";
     pub const VIEWPORT_ID: &'static str = "viewport_memory_viewer";
+    const DATA_INSPECTOR_WIDTH: f32 = 300.0;
 
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let memory_viewer_view_data = MemoryViewerViewData::register(&app_context);
         let disassembler_view_data = DisassemblerViewData::register(&app_context);
 
+        MemoryViewerViewData::register_refresh_job(memory_viewer_view_data.clone(), &app_context.refresh_scheduler);
+
         Self {
             app_context,
             memory_viewer_view_data,
@@ -93,6 +112,309 @@ impl MemoryViewerView {
             });
         });
     }
+
+    /// Lays out the address box, go button, and pop-out button, in that order.
+    pub fn layout_toolbar_items(
+        toolbar_layout: &ToolbarLayout,
+        toolbar_rect: Rect,
+    ) -> Vec<Rect> {
+        toolbar_layout.layout_row(
+            toolbar_rect,
+            &[
+                ToolbarItem::new(180.0),
+                ToolbarItem::new(64.0),
+                ToolbarItem::new(84.0),
+            ],
+        )
+    }
+
+    fn format_interval(interval_ms: u64) -> String {
+        if interval_ms.is_multiple_of(1000) {
+            format!("{} s", interval_ms / 1000)
+        } else {
+            format!("{} ms", interval_ms)
+        }
+    }
+
+    /// Builds the text for one row of the hex or ASCII pane, with a background behind selected bytes and bytes matched by the
+    /// current search. The selection is drawn over any search highlight, and bytes changed by the last auto refresh are shown in red.
+    fn build_row_layout_job(
+        row_bytes: &[(Option<u8>, SearchHighlight, bool, bool)],
+        is_ascii: bool,
+        theme: &Theme,
+    ) -> LayoutJob {
+        let mut layout_job = LayoutJob::default();
+        let font_id = &theme.font_library.font_ubuntu_mono_bold.font_normal;
+        let text_color = theme.foreground;
+        let get_background = |search_highlight: SearchHighlight, is_selected: bool| match search_highlight {
+            _ if is_selected => theme.selected_background,
+            SearchHighlight::None => Color32::TRANSPARENT,
+            SearchHighlight::Match => theme.background_control_info_dark,
+            SearchHighlight::CurrentMatch => theme.background_control_warning_dark,
+        };
+
+        for (index, (byte, search_highlight, is_selected, is_changed)) in row_bytes.iter().enumerate() {
+            let text = match (byte, is_ascii) {
+                (Some(byte), false) => format!("{:02X}", byte),
+                (None, false) => "??".to_string(),
+                (Some(byte), true) if (*byte as char).is_ascii_graphic() => (*byte as char).to_string(),
+                (_, true) => ".".to_string(),
+            };
+            let text_format = TextFormat {
+                font_id: font_id.clone(),
+                color: if *is_changed { theme.error_red } else { text_color },
+                background: get_background(*search_highlight, *is_selected),
+                ..TextFormat::default()
+            };
+
+            layout_job.append(&text, 0.0, text_format);
+
+            if is_ascii || index + 1 == row_bytes.len() {
+                continue;
+            }
+
+            // Only shade the separator when it falls inside of a single highlighted or selected run.
+            let separator_highlight = if row_bytes[index + 1].1 == *search_highlight {
+                *search_highlight
+            } else {
+                SearchHighlight::None
+            };
+            let is_separator_selected = *is_selected && row_bytes[index + 1].2;
+            let separator_format = TextFormat {
+                font_id: font_id.clone(),
+                color: text_color,
+                background: get_background(separator_highlight, is_separator_selected),
+                ..TextFormat::default()
+            };
+
+            layout_job.append(" ", 0.0, separator_format);
+        }
+
+        layout_job
+    }
+
+    /// Shows the search bar, returning whether the user requested a search and any all regions match they selected.
+    fn show_search_bar(
+        &self,
+        user_interface: &mut Ui,
+        memory_viewer_view_data: &mut MemoryViewerViewData,
+        should_close_search: &mut bool,
+        search_match_step: &mut Option<bool>,
+    ) -> (bool, Option<u64>) {
+        let theme = &self.app_context.theme;
+        let mut should_search = false;
+        let mut selected_region_match = None;
+
+        user_interface.horizontal(|user_interface| {
+            let hint_text = if memory_viewer_view_data.is_search_ascii { "Text" } else { "Hex bytes, ie 48 8B ?? 05" };
+            let search_box_response = user_interface.add_sized(
+                vec2(240.0, 24.0),
+                TextEdit::singleline(&mut memory_viewer_view_data.search_input)
+                    .hint_text(hint_text)
+                    .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                    .text_color(theme.foreground)
+                    .background_color(theme.background_primary),
+            );
+
+            if memory_viewer_view_data.is_search_focus_pending {
+                memory_viewer_view_data.is_search_focus_pending = false;
+                search_box_response.request_focus();
+            }
+
+            if search_box_response.lost_focus() && user_interface.input(|input_state| input_state.key_pressed(Key::Enter)) {
+                should_search = true;
+            }
+
+            if user_interface
+                .add(Checkbox::new_from_theme(theme).with_check_state_bool(memory_viewer_view_data.is_search_ascii))
+                .clicked()
+            {
+                memory_viewer_view_data.is_search_ascii = !memory_viewer_view_data.is_search_ascii;
+            }
+
+            user_interface.label("ASCII");
+
+            if user_interface
+                .add(Checkbox::new_from_theme(theme).with_check_state_bool(memory_viewer_view_data.is_search_all_regions))
+                .clicked()
+            {
+                memory_viewer_view_data.is_search_all_regions = !memory_viewer_view_data.is_search_all_regions;
+            }
+
+            user_interface.label("Search all regions");
+
+            if user_interface.button("Find").clicked() {
+                should_search = true;
+            }
+
+            if user_interface.button("Previous").on_hover_text("Shift+F3").clicked() {
+                *search_match_step = Some(false);
+            }
+
+            if user_interface.button("Next").on_hover_text("F3").clicked() {
+                *search_match_step = Some(true);
+            }
+
+            if memory_viewer_view_data.is_searching {
+                user_interface.add(Spinner::new().color(theme.foreground));
+                user_interface.label(format!("Searching: {:.0}%", memory_viewer_view_data.search_progress * 100.0));
+            } else if let Some(search_error) = &memory_viewer_view_data.search_error {
+                user_interface.label(eframe::egui::RichText::new(search_error).color(theme.error_red));
+            } else if memory_viewer_view_data.search_pattern_length > 0 {
+                let match_count = memory_viewer_view_data.search_match_addresses.len();
+                let match_label = match memory_viewer_view_data.search_match_index {
+                    Some(match_index) => format!("{} of {} match(es)", match_index + 1, match_count),
+                    None => format!("{} match(es)", match_count),
+                };
+
+                user_interface.label(match_label);
+            }
+
+            if memory_viewer_view_data.is_region_search_pending {
+                user_interface.add(Spinner::new().color(theme.foreground));
+            } else if !memory_viewer_view_data.region_search_matches.is_empty() {
+                let truncated_suffix = if memory_viewer_view_data.is_region_search_truncated { "+" } else { "" };
+
+                ComboBox::from_id_salt("memory_viewer_region_search_matches")
+                    .width(280.0)
+                    .selected_text(format!(
+                        "{}{} match(es) in all regions",
+                        memory_viewer_view_data.region_search_matches.len(),
+                        truncated_suffix
+                    ))
+                    .show_ui(user_interface, |user_interface| {
+                        for region_search_match in &memory_viewer_view_data.region_search_matches {
+                            let region = &region_search_match.region;
+                            let region_label = if region.module_name.is_empty() {
+                                format!("{:016X}  (region {:016X})", region_search_match.address, region.base_address)
+                            } else {
+                                let module_offset = region_search_match
+                                    .address
+                                    .saturating_sub(region.base_address)
+                                    .saturating_add(region.module_offset);
+
+                                format!("{:016X}  ({}+{:X})", region_search_match.address, region.module_name, module_offset)
+                            };
+                            let is_selected = memory_viewer_view_data.target_address == region_search_match.address;
+
+                            if user_interface.selectable_label(is_selected, region_label).clicked() {
+                                selected_region_match = Some(region_search_match.address);
+                            }
+                        }
+                    });
+            }
+
+            if user_interface.button("Close").on_hover_text("Escape").clicked() {
+                *should_close_search = true;
+            }
+        });
+
+        (should_search, selected_region_match)
+    }
+}
+
+impl MemoryViewerView {
+    /// Types hex digits into the byte being edited while the hex pane has focus. Enter writes the byte and Escape discards it.
+    fn handle_byte_edit_input(
+        &self,
+        user_interface: &mut Ui,
+        is_hex_pane_focused: bool,
+    ) {
+        let (typed_text, is_commit_requested, is_cancel_requested) = user_interface.input(|input_state| {
+            let typed_text = input_state
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    Event::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<String>();
+
+            (typed_text, input_state.key_pressed(Key::Enter), input_state.key_pressed(Key::Escape))
+        });
+
+        if is_cancel_requested {
+            MemoryViewerViewData::cancel_byte_edit(self.memory_viewer_view_data.clone());
+        } else if is_hex_pane_focused {
+            if is_commit_requested {
+                MemoryViewerViewData::commit_byte_edit(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            } else if !typed_text.is_empty() {
+                MemoryViewerViewData::type_byte_edit_text(
+                    self.memory_viewer_view_data.clone(),
+                    self.app_context.engine_unprivileged_state.clone(),
+                    &typed_text,
+                );
+            }
+        }
+    }
+
+    /// Asks before writing pasted bytes at the caret. A paste crossing into unreadable memory can only be truncated or aborted.
+    fn show_paste_confirmation_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let paste_confirmation = match self
+            .memory_viewer_view_data
+            .read("Memory viewer paste confirmation")
+        {
+            Some(view_data) => match &view_data.paste_confirmation {
+                Some(paste_confirmation) => paste_confirmation.clone(),
+                None => return,
+            },
+            None => return,
+        };
+        let paste_length = paste_confirmation.bytes.len() as u64;
+        let end_address = paste_confirmation
+            .address
+            .saturating_add(paste_length.saturating_sub(1));
+        let mut should_confirm = false;
+        let mut should_cancel = false;
+
+        Window::new("Paste bytes")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |ui| {
+                ui.label(format!("Write {} bytes to {:X}-{:X}?", paste_length, paste_confirmation.address, end_address));
+
+                if paste_confirmation.is_truncation_required() {
+                    ui.label(
+                        eframe::egui::RichText::new(format!(
+                            "Only the first {} bytes lie in readable memory. The rest of the range crosses into unreadable memory.",
+                            paste_confirmation.accessible_length
+                        ))
+                        .color(self.app_context.theme.error_red),
+                    );
+                }
+
+                ui.horizontal(|ui| {
+                    if paste_confirmation.is_truncation_required() {
+                        if paste_confirmation.accessible_length > 0
+                            && ui
+                                .button(format!("Truncate to {} bytes", paste_confirmation.accessible_length))
+                                .clicked()
+                        {
+                            should_confirm = true;
+                        }
+                        if ui.button("Abort").clicked() {
+                            should_cancel = true;
+                        }
+                    } else {
+                        if ui.button("Paste").clicked() {
+                            should_confirm = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            should_cancel = true;
+                        }
+                    }
+                });
+            });
+
+        if should_confirm {
+            MemoryViewerViewData::confirm_paste(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        } else if should_cancel {
+            MemoryViewerViewData::cancel_paste(self.memory_viewer_view_data.clone());
+        }
+    }
 }
 
 impl Widget for MemoryViewerView {
@@ -152,10 +474,60 @@ impl Widget for MemoryViewerView {
         let mut should_refresh = false;
         let mut jump_to_region_base: Option<u64> = None;
         let mut disassemble_region_base: Option<u64> = None;
+        let mut toggle_breakpoint_address: Option<u64> = None;
+        let mut selected_byte_address: Option<u64> = None;
+        let mut edited_byte_address: Option<u64> = None;
+        let mut selection_begin_address: Option<u64> = None;
+        let mut selection_extend_address: Option<u64> = None;
+        let mut context_menu_byte_address: Option<u64> = None;
+        let mut add_to_project_address: Option<u64> = None;
+        let mut paste_at_address: Option<u64> = None;
+        let mut follow_pointer_address: Option<u64> = None;
+        let mut should_navigate_back = false;
+        let mut is_hex_pane_focused = false;
+        let mut should_search = false;
+        let mut should_close_search = false;
+        let mut search_match_step: Option<bool> = None;
+        let mut selected_region_match: Option<u64> = None;
+        let mut auto_refresh_change: Option<(bool, u64)> = None;
+        let mut inspector_display_data_type: Option<DataTypeRef> = None;
+        let process_bitness = self
+            .app_context
+            .dependency_container
+            .get_dependency::<ProcessSelectorViewData>()
+            .read("Memory viewer process bitness")
+            .and_then(|view_data| {
+                view_data
+                    .opened_process
+                    .as_ref()
+                    .map(|opened_process| opened_process.get_bitness())
+            })
+            .unwrap_or(Bitness::Bit64);
+        let (is_search_open, is_byte_edit_active) = self
+            .memory_viewer_view_data
+            .read("Memory viewer search open state")
+            .map(|view_data| (view_data.is_search_open, view_data.byte_edit.is_some()))
+            .unwrap_or((false, false));
+
+        user_interface.input(|input_state| {
+            if input_state.modifiers.ctrl && input_state.key_pressed(Key::F) {
+                MemoryViewerViewData::open_search(self.memory_viewer_view_data.clone());
+            }
+
+            if input_state.key_pressed(Key::F3) {
+                search_match_step = Some(!input_state.modifiers.shift);
+            }
+
+            // Escape cancels an inline byte edit before it closes the search bar.
+            if is_search_open && !is_byte_edit_active && input_state.key_pressed(Key::Escape) {
+                should_close_search = true;
+            }
+        });
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
-                let toolbar_height = 36.0;
+                let toolbar_layout = ToolbarLayout::new(user_interface, &theme.font_library.font_noto_sans.font_normal);
+                let toolbar_height = toolbar_layout.get_toolbar_height(1);
                 let (toolbar_rect, _) =
                     user_interface.allocate_exact_size(vec2(user_interface.available_width(), toolbar_height), Sense::hover());
 
@@ -171,14 +543,28 @@ impl Widget for MemoryViewerView {
                     None => return,
                 };
 
-                let address_box = Rect::from_min_size(pos2(toolbar_rect.min.x + 8.0, toolbar_rect.min.y + 4.0), vec2(180.0, 28.0));
-                toolbar_ui.put(
+                let toolbar_item_rects = Self::layout_toolbar_items(&toolbar_layout, toolbar_rect);
+                let address_box = toolbar_item_rects[0];
+                let address_response = toolbar_ui.put(
                     address_box,
                     TextEdit::singleline(&mut memory_viewer_view_data.address_input)
+                        .hint_text("game.exe+1A0")
                         .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
                         .text_color(theme.hexadecimal_green)
                         .background_color(theme.background_primary),
                 );
+                let address_response = address_response.on_hover_text(
+                    "Hex addresses and module offsets, ie game.exe+1A2B30. Brackets read a pointer, ie [game.exe+1A2B30]+18, \
+                     and a d suffix marks decimal, ie 256d.",
+                );
+
+                if address_response.changed() {
+                    memory_viewer_view_data.address_resolution = None;
+                }
+
+                if address_response.lost_focus() && toolbar_ui.input(|input_state| input_state.key_pressed(Key::Enter)) {
+                    should_refresh = true;
+                }
 
                 toolbar_ui.painter().rect_stroke(
                     address_box,
@@ -187,7 +573,7 @@ impl Widget for MemoryViewerView {
                     StrokeKind::Inside,
                 );
 
-                let go_button_rect = Rect::from_min_size(pos2(address_box.max.x + 8.0, toolbar_rect.min.y + 4.0), vec2(64.0, 28.0));
+                let go_button_rect = toolbar_item_rects[1];
                 if toolbar_ui
                     .put(
                         go_button_rect,
@@ -206,7 +592,7 @@ impl Widget for MemoryViewerView {
                 );
 
                 let popout_button_text = if self.is_popout { "Dock" } else { "Pop-out" };
-                let popout_button_rect = Rect::from_min_size(pos2(go_button_rect.max.x + 8.0, toolbar_rect.min.y + 4.0), vec2(84.0, 28.0));
+                let popout_button_rect = toolbar_item_rects[2];
                 if toolbar_ui
                     .put(
                         popout_button_rect,
@@ -232,6 +618,36 @@ impl Widget for MemoryViewerView {
                 )
                 .width(180.0));
 
+                let is_auto_refresh_enabled = memory_viewer_view_data.is_auto_refresh_enabled;
+                let auto_refresh_interval_ms = memory_viewer_view_data.auto_refresh_interval_ms;
+
+                toolbar_ui.add_space(8.0);
+
+                if toolbar_ui
+                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(is_auto_refresh_enabled))
+                    .clicked()
+                {
+                    auto_refresh_change = Some((!is_auto_refresh_enabled, auto_refresh_interval_ms));
+                }
+
+                toolbar_ui
+                    .label("Auto refresh")
+                    .on_hover_text("Reads the shown bytes again periodically, highlighting the bytes that changed in red.");
+                ComboBox::from_id_salt("memory_viewer_auto_refresh_interval")
+                    .width(72.0)
+                    .selected_text(Self::format_interval(auto_refresh_interval_ms))
+                    .show_ui(&mut toolbar_ui, |ui| {
+                        for interval_ms in MemoryViewerViewData::AUTO_REFRESH_INTERVALS_MS {
+                            if ui
+                                .selectable_label(interval_ms == auto_refresh_interval_ms, Self::format_interval(interval_ms))
+                                .clicked()
+                            {
+                                auto_refresh_change = Some((is_auto_refresh_enabled, interval_ms));
+                            }
+                        }
+                    });
+                toolbar_ui.add_space(8.0);
+
                 let region_label = format!(
                     "Region: {:016X} (+{} bytes)",
                     memory_viewer_view_data.region_base,
@@ -239,6 +655,11 @@ impl Widget for MemoryViewerView {
                 );
                 toolbar_ui.label(region_label);
 
+                if let Some(address_resolution) = &memory_viewer_view_data.address_resolution {
+                    toolbar_ui.add_space(8.0);
+                    toolbar_ui.add(AddressResolutionLabel::new(theme, address_resolution));
+                }
+
                 if memory_viewer_view_data.regions.is_empty()
                     && !memory_viewer_view_data.is_loading
                     && memory_viewer_view_data.address_input.trim().is_empty()
@@ -246,6 +667,12 @@ impl Widget for MemoryViewerView {
                     should_refresh = true;
                 }
 
+                if memory_viewer_view_data.is_search_open {
+                    user_interface.add_space(4.0);
+                    (should_search, selected_region_match) =
+                        self.show_search_bar(user_interface, &mut memory_viewer_view_data, &mut should_close_search, &mut search_match_step);
+                }
+
                 drop(memory_viewer_view_data);
 
                 user_interface.add_space(4.0);
@@ -277,8 +704,7 @@ impl Widget for MemoryViewerView {
                                 }
 
                                 if let Some(error_message) = &disassembler_view_data.error_message {
-                                    ui.label(error_message);
-                                    return;
+                                    ui.label(eframe::egui::RichText::new(error_message).color(theme.error_red));
                                 }
 
                                 if disassembler_view_data.lines.is_empty() {
@@ -286,18 +712,33 @@ impl Widget for MemoryViewerView {
                                     return;
                                 }
 
-                                for line in &disassembler_view_data.lines {
+                                for (line_index, line) in disassembler_view_data.lines.iter().enumerate() {
                                     let is_highlighted = disassembler_view_data.highlight_address == Some(line.address);
-                                    let row_response = ui
+                                    let has_breakpoint = disassembler_view_data.breakpoint_addresses.contains(&line.address);
+                                    let is_patched = disassembler_view_data.is_patched(line.address);
+                                    let row_inner_response = ui
                                         .allocate_ui_with_layout(
                                             vec2(ui.available_width(), 20.0),
                                             Layout::left_to_right(Align::Min),
                                             |ui| {
+                                                let row_rect = ui.available_rect_before_wrap();
+
+                                                if is_patched {
+                                                    ui.painter()
+                                                        .rect_filled(row_rect, 0.0, theme.background_control_warning_dark);
+                                                }
                                                 if is_highlighted {
-                                                    let row_rect = ui.available_rect_before_wrap();
                                                     ui.painter().rect_filled(row_rect, 0.0, theme.selected_background);
                                                 }
 
+                                                let (gutter_rect, _) =
+                                                    ui.allocate_exact_size(vec2(DisassemblerView::BREAKPOINT_GUTTER_WIDTH, 20.0), Sense::hover());
+
+                                                if has_breakpoint {
+                                                    ui.painter()
+                                                        .circle_filled(gutter_rect.center(), 4.0, theme.error_red);
+                                                }
+
                                                 ui.add_sized(
                                                     vec2(210.0, 20.0),
                                                     eframe::egui::Label::new(
@@ -321,9 +762,22 @@ impl Widget for MemoryViewerView {
                                                         .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
                                                         .color(theme.foreground),
                                                 );
+
+                                                gutter_rect
                                             },
+                                        );
+                                    let row_response = row_inner_response.response;
+                                    let gutter_response = ui
+                                        .interact(
+                                            row_inner_response.inner,
+                                            ui.id().with(("memory_viewer_breakpoint_gutter", line_index)),
+                                            Sense::click(),
                                         )
-                                        .response;
+                                        .on_hover_text(DisassemblerView::BREAKPOINT_GUTTER_TOOLTIP);
+
+                                    if gutter_response.clicked() {
+                                        toggle_breakpoint_address = Some(line.address);
+                                    }
 
                                     if is_highlighted && disassembler_view_data.highlight_pending {
                                         row_response.scroll_to_me(Some(Align::Center));
@@ -385,6 +839,9 @@ impl Widget for MemoryViewerView {
 
                             ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
                                 let highlight_base = memory_viewer_view_data.region_base;
+                                let row_density = AppearanceSettings::get_row_density();
+                                let row_height = row_density.get_row_height();
+                                let row_font = row_density.get_font(&theme.font_library.font_ubuntu_mono_bold);
 
                                 for region in memory_viewer_view_data.regions.iter() {
                                     let base = region.base_address;
@@ -397,39 +854,39 @@ impl Widget for MemoryViewerView {
                                         format!("{}+{:X}", region.module_name, region.module_offset)
                                     };
 
-                                    let (row_rect, response) = ui.allocate_exact_size(vec2(ui.available_width(), 22.0), Sense::click());
+                                    let (row_rect, response) = ui.allocate_exact_size(vec2(ui.available_width(), row_height), Sense::click());
                                     if is_highlight {
                                         ui.painter().rect_filled(row_rect, 0.0, theme.selected_background);
                                     }
                                     let builder = UiBuilder::new().max_rect(row_rect).layout(Layout::left_to_right(Align::Center));
                                     let mut row_ui = ui.new_child(builder);
                                     row_ui.add_sized(
-                                        vec2(120.0, 20.0),
+                                        vec2(120.0, row_height),
                                         eframe::egui::Label::new(
                                             eframe::egui::RichText::new(format!("{:016X}", base))
-                                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                                .font(row_font.clone())
                                                 .color(theme.hexadecimal_green),
                                         ),
                                     );
                                     row_ui.add_sized(
-                                        vec2(120.0, 20.0),
+                                        vec2(120.0, row_height),
                                         eframe::egui::Label::new(
                                             eframe::egui::RichText::new(format!("{:016X}", end))
-                                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                                .font(row_font.clone())
                                                 .color(theme.foreground),
                                         ),
                                     );
                                     row_ui.add_sized(
-                                        vec2(70.0, 20.0),
+                                        vec2(70.0, row_height),
                                         eframe::egui::Label::new(
                                             eframe::egui::RichText::new(region.region_size.to_string())
-                                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                                .font(row_font.clone())
                                                 .color(theme.foreground),
                                         ),
                                     );
                                     row_ui.label(
                                         eframe::egui::RichText::new(module_label)
-                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                            .font(row_font.clone())
                                             .color(theme.foreground),
                                     );
 
@@ -475,8 +932,10 @@ impl Widget for MemoryViewerView {
 
                     ui.add_space(8.0);
 
-                    // Right: hex view
-                    ui.allocate_ui_with_layout(ui.available_size(), Layout::top_down(Align::Min), |ui| {
+                    // Middle: hex view
+                    let hex_view_width = (ui.available_width() - Self::DATA_INSPECTOR_WIDTH - 8.0).max(0.0);
+
+                    ui.allocate_ui_with_layout(vec2(hex_view_width, ui.available_height()), Layout::top_down(Align::Min), |ui| {
                         if memory_viewer_view_data.is_loading {
                             ui.allocate_ui_with_layout(
                                 vec2(ui.available_width(), 32.0),
@@ -493,7 +952,15 @@ impl Widget for MemoryViewerView {
                         let base = memory_viewer_view_data.base_address;
                         let target_address = memory_viewer_view_data.target_address;
                         let bytes = &memory_viewer_view_data.bytes;
+                        let search_highlights = memory_viewer_view_data.get_search_highlights();
+                        let current_match_address = memory_viewer_view_data
+                            .search_match_index
+                            .and_then(|match_index| memory_viewer_view_data.search_match_addresses.get(match_index))
+                            .copied()
+                            .filter(|_| memory_viewer_view_data.is_search_scroll_pending);
+                        let is_target_scroll_pending = memory_viewer_view_data.is_target_scroll_pending;
                         let display_data_type = memory_viewer_view_data.display_data_type.clone();
+                        let byte_edit = memory_viewer_view_data.byte_edit.clone();
 
                         if let Some(error_message) = &memory_viewer_view_data.error_message {
                             ui.label(error_message);
@@ -508,7 +975,7 @@ impl Widget for MemoryViewerView {
                             if unit_size == 0 || max_len == 0 {
                                 "??".to_string()
                             } else {
-                                let read_len = if display_data_type.get_data_type_id() == "string_utf8"
+                                let read_len = if DataTypeStringEncoded::is_string_data_type_id(display_data_type.get_data_type_id())
                                     || display_data_type.get_data_type_id() == "aob"
                                 {
                                     max_len.min(64)
@@ -534,6 +1001,11 @@ impl Widget for MemoryViewerView {
 
                         ui.label("Hex View");
                         ui.label(format!("Value: {}", display_value));
+
+                        if let Some(paste_status) = &memory_viewer_view_data.paste_status {
+                            ui.label(paste_status);
+                        }
+
                         ui.separator();
 
                         ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
@@ -541,24 +1013,22 @@ impl Widget for MemoryViewerView {
                                 let start = row.saturating_mul(bytes_per_row);
                                 let address = base.saturating_add(start as u64);
 
-                                let mut hex_parts = Vec::with_capacity(bytes_per_row);
-                                let mut ascii = String::with_capacity(bytes_per_row);
-
-                                for col in 0..bytes_per_row {
-                                    let index = start.saturating_add(col);
-                                    if let Some(byte) = bytes.get(index).copied() {
-                                        hex_parts.push(format!("{:02X}", byte));
-                                        let ch = byte as char;
-                                        ascii.push(if ch.is_ascii_graphic() { ch } else { '.' });
-                                    } else {
-                                        hex_parts.push("??".to_string());
-                                        ascii.push('.');
-                                    }
-                                }
+                                let row_bytes: Vec<(Option<u8>, SearchHighlight, bool, bool)> = (0..bytes_per_row)
+                                    .map(|col| {
+                                        let index = start.saturating_add(col);
 
-                                let hex = hex_parts.join(" ");
+                                        (
+                                            bytes.get(index).copied(),
+                                            search_highlights.get(index).copied().unwrap_or(SearchHighlight::None),
+                                            memory_viewer_view_data.is_offset_selected(index),
+                                            memory_viewer_view_data.is_byte_changed(index),
+                                        )
+                                    })
+                                    .collect();
+                                let build_row_layout_job = |is_ascii: bool| Self::build_row_layout_job(&row_bytes, is_ascii, theme);
 
-                                ui.horizontal(|ui| {
+                                let row_end_address = address.saturating_add(bytes_per_row as u64);
+                                let row_response = ui.horizontal(|ui| {
                                     ui.add_sized(
                                         vec2(110.0, 20.0),
                                         eframe::egui::Label::new(
@@ -568,38 +1038,429 @@ impl Widget for MemoryViewerView {
                                         ),
                                     );
 
-                                    ui.add_sized(
-                                        vec2(360.0, 20.0),
-                                        eframe::egui::Label::new(
-                                            eframe::egui::RichText::new(hex)
-                                                .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
-                                                .color(theme.foreground),
-                                        ),
-                                    );
+                                    for is_ascii in [false, true] {
+                                        let galley = ui.painter().layout_job(build_row_layout_job(is_ascii));
+                                        let pane_width = if is_ascii { galley.size().x } else { galley.size().x.max(360.0) };
+                                        let pane_sense = if is_ascii { Sense::click() } else { Sense::click_and_drag() };
+                                        let (pane_rect, pane_response) = ui.allocate_exact_size(vec2(pane_width, 20.0), pane_sense);
+                                        let text_position = pos2(pane_rect.left(), pane_rect.center().y - galley.size().y * 0.5);
+                                        // The panes use a monospace font, so every character cell has the same width.
+                                        let char_width = galley.size().x / galley.job.text.chars().count().max(1) as f32;
+                                        let hovered_address = pane_response
+                                            .hover_pos()
+                                            .and_then(|pointer_position| {
+                                                MemoryViewerViewData::get_column_at_offset(
+                                                    pointer_position.x - text_position.x,
+                                                    char_width,
+                                                    bytes_per_row,
+                                                    is_ascii,
+                                                )
+                                            })
+                                            .and_then(|column| memory_viewer_view_data.get_address_at(row, column));
+
+                                        // Dragging across the hex pane selects bytes. Like the disassembler, the selection follows the pointer
+                                        // rather than row hover, as the row that started the drag keeps the hover until release. Positions past
+                                        // either end of a row clamp to its first or last byte.
+                                        let get_address_at_position = |pointer_position: Pos2| {
+                                            let hex_text_width = (bytes_per_row as f32 * 3.0 - 1.0) * char_width;
+                                            let x_offset = (pointer_position.x - text_position.x).clamp(0.0, (hex_text_width - 1.0).max(0.0));
+
+                                            MemoryViewerViewData::get_column_at_offset(x_offset, char_width, bytes_per_row, false)
+                                                .map(|column| address.saturating_add(column as u64))
+                                        };
+
+                                        if !is_ascii && pane_response.drag_started() {
+                                            selection_begin_address = ui
+                                                .input(|input_state| input_state.pointer.press_origin())
+                                                .and_then(get_address_at_position)
+                                                .or(selection_begin_address);
+                                            pane_response.request_focus();
+                                        } else if !is_ascii && memory_viewer_view_data.is_selecting {
+                                            selection_extend_address = ui
+                                                .input(|input_state| input_state.pointer.interact_pos())
+                                                .filter(|pointer_position| pane_rect.y_range().contains(pointer_position.y))
+                                                .and_then(get_address_at_position)
+                                                .or(selection_extend_address);
+                                        }
 
-                                    ui.label(
-                                        eframe::egui::RichText::new(ascii)
-                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
-                                            .color(theme.foreground),
-                                    );
+                                        ui.painter().galley(text_position, galley, theme.foreground);
+
+                                        // Outline the caret byte, which is the byte whose value is shown above the panes.
+                                        if target_address >= address && target_address < row_end_address {
+                                            let column = target_address.saturating_sub(address) as f32;
+                                            let (caret_x, caret_width) = if is_ascii {
+                                                (column * char_width, char_width)
+                                            } else {
+                                                (column * 3.0 * char_width, 2.0 * char_width)
+                                            };
+                                            let caret_rect = Rect::from_min_size(
+                                                pos2(text_position.x + caret_x, pane_rect.top()),
+                                                vec2(caret_width, pane_rect.height()),
+                                            );
+
+                                            ui.painter()
+                                                .rect_stroke(caret_rect, CornerRadius::ZERO, Stroke::new(1.0, theme.selected_border), StrokeKind::Inside);
+                                        }
+
+                                        // Cover the byte being edited with the digits typed so far.
+                                        if let Some(byte_edit) = byte_edit
+                                            .as_ref()
+                                            .filter(|byte_edit| !is_ascii && byte_edit.address >= address && byte_edit.address < row_end_address)
+                                        {
+                                            let column = byte_edit.address.saturating_sub(address) as f32;
+                                            let edit_rect = Rect::from_min_size(
+                                                pos2(text_position.x + column * 3.0 * char_width, pane_rect.top()),
+                                                vec2(2.0 * char_width, pane_rect.height()),
+                                            );
+
+                                            ui.painter()
+                                                .rect_filled(edit_rect, CornerRadius::ZERO, theme.background_control_primary);
+                                            ui.painter().text(
+                                                pos2(edit_rect.left(), edit_rect.center().y),
+                                                Align2::LEFT_CENTER,
+                                                format!("{:_<2}", byte_edit.pending_text),
+                                                theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+                                                theme.foreground,
+                                            );
+                                        }
+
+                                        if pane_response.clicked() {
+                                            // Clicking a hex byte starts editing it inline, while the ASCII pane only moves the caret.
+                                            if is_ascii {
+                                                selected_byte_address = hovered_address.or(selected_byte_address);
+                                            } else {
+                                                edited_byte_address = hovered_address.or(edited_byte_address);
+
+                                                // Focusing the hex pane lets Ctrl+V paste and typed digits edit at the caret.
+                                                pane_response.request_focus();
+                                            }
+                                        }
+
+                                        is_hex_pane_focused |= !is_ascii && pane_response.has_focus();
+
+                                        if pane_response.secondary_clicked() {
+                                            context_menu_byte_address = hovered_address;
+                                        }
+
+                                        let menu_address = context_menu_byte_address.or(memory_viewer_view_data.context_menu_address);
+
+                                        pane_response.context_menu(|ui| {
+                                            let menu_address = match menu_address {
+                                                Some(menu_address) => menu_address,
+                                                None => {
+                                                    ui.close();
+                                                    return;
+                                                }
+                                            };
+
+                                            if ui.button("Copy address").clicked() {
+                                                ui.ctx().copy_text(format!("{:X}", menu_address));
+                                                ui.close();
+                                            }
+                                            for byte_copy_format in ByteCopyFormat::ALL {
+                                                let copy_button = eframe::egui::Button::new(byte_copy_format.get_display_name());
+                                                let has_selection = memory_viewer_view_data.get_selection_range().is_some();
+                                                let copy_response = ui.add_enabled(has_selection, copy_button);
+                                                let copy_response = if byte_copy_format == ByteCopyFormat::SpacedHex {
+                                                    copy_response.on_hover_text("Ctrl+C")
+                                                } else {
+                                                    copy_response
+                                                };
+
+                                                if copy_response.clicked() {
+                                                    if let Some(copy_text) = memory_viewer_view_data.format_selection(byte_copy_format) {
+                                                        ui.ctx().copy_text(copy_text);
+                                                    }
+                                                    ui.close();
+                                                }
+                                            }
+                                            if !is_ascii
+                                                && ui
+                                                    .button("Paste hex bytes here")
+                                                    .on_hover_text("Ctrl+V")
+                                                    .clicked()
+                                            {
+                                                paste_at_address = Some(menu_address);
+                                                ui.close();
+                                            }
+                                            ui.separator();
+                                            if ui
+                                                .button("Follow pointer")
+                                                .on_hover_text("Ctrl+Enter")
+                                                .clicked()
+                                            {
+                                                follow_pointer_address = Some(menu_address);
+                                                ui.close();
+                                            }
+                                            let back_button = eframe::egui::Button::new("Back");
+                                            let has_address_history = !memory_viewer_view_data.address_history.is_empty();
+
+                                            if ui
+                                                .add_enabled(has_address_history, back_button)
+                                                .on_hover_text("Alt+Left")
+                                                .clicked()
+                                            {
+                                                should_navigate_back = true;
+                                                ui.close();
+                                            }
+                                            ui.separator();
+                                            if ui.button("Add this address to project...").clicked() {
+                                                add_to_project_address = Some(menu_address);
+                                                ui.close();
+                                            }
+                                        });
+                                    }
                                 });
+
+                                if current_match_address.is_some_and(|match_address| match_address >= address && match_address < row_end_address) {
+                                    row_response.response.scroll_to_me(Some(Align::Center));
+                                } else if is_target_scroll_pending && target_address >= address && target_address < row_end_address {
+                                    row_response.response.scroll_to_me(Some(Align::TOP));
+                                }
                             }
                         });
                     });
+
+                    ui.add_space(8.0);
+
+                    // Right: data inspector, decoding the bytes at the caret.
+                    ui.allocate_ui_with_layout(ui.available_size(), Layout::top_down(Align::Min), |ui| {
+                        ui.label("Data Inspector");
+                        ui.separator();
+
+                        let data_inspector_rows = DataInspector::inspect(
+                            memory_viewer_view_data.get_bytes_at_caret(),
+                            &memory_viewer_view_data.regions,
+                            process_bitness,
+                        );
+                        let row_font = theme.font_library.font_ubuntu_mono_bold.font_normal.clone();
+
+                        ScrollArea::vertical()
+                            .id_salt("memory_viewer_data_inspector")
+                            .auto_shrink([false, false])
+                            .show(ui, |ui| {
+                                for data_inspector_row in &data_inspector_rows {
+                                    ui.horizontal(|ui| {
+                                        if ui.small_button("Copy").clicked() {
+                                            ui.ctx().copy_text(data_inspector_row.value.clone());
+                                        }
+
+                                        ui.add_sized(
+                                            vec2(72.0, 20.0),
+                                            eframe::egui::Label::new(
+                                                eframe::egui::RichText::new(data_inspector_row.label)
+                                                    .font(row_font.clone())
+                                                    .color(theme.foreground),
+                                            ),
+                                        );
+
+                                        let value_label = eframe::egui::Label::new(
+                                            eframe::egui::RichText::new(&data_inspector_row.value)
+                                                .font(row_font.clone())
+                                                .color(theme.hexadecimal_green),
+                                        )
+                                        .truncate();
+
+                                        match &data_inspector_row.data_type_ref {
+                                            Some(data_type_ref) => {
+                                                let is_displayed = *data_type_ref == memory_viewer_view_data.display_data_type;
+
+                                                if ui
+                                                    .add(value_label.sense(Sense::click()))
+                                                    .on_hover_text("Display memory as this type")
+                                                    .clicked()
+                                                    && !is_displayed
+                                                {
+                                                    inspector_display_data_type = Some(data_type_ref.clone());
+                                                }
+                                            }
+                                            None => {
+                                                ui.add(value_label);
+                                            }
+                                        }
+                                    });
+                                }
+                            });
+                    });
                 });
             })
             .response;
 
+        // Any pending scroll to the current match was applied while drawing the rows above.
+        let is_search_scroll_pending = self
+            .memory_viewer_view_data
+            .read("Memory viewer search scroll state")
+            .map(|view_data| view_data.is_search_scroll_pending)
+            .unwrap_or(false);
+
+        if is_search_scroll_pending {
+            MemoryViewerViewData::clear_search_scroll(self.memory_viewer_view_data.clone());
+        }
+
+        // Likewise, the scroll bringing a newly resolved address to the top of the hex view was applied while drawing its row.
+        let is_target_scroll_pending = self
+            .memory_viewer_view_data
+            .read("Memory viewer target scroll state")
+            .map(|view_data| view_data.is_target_scroll_pending)
+            .unwrap_or(false);
+
+        if is_target_scroll_pending {
+            MemoryViewerViewData::clear_target_scroll(self.memory_viewer_view_data.clone());
+        }
+
+        if let Some((is_enabled, interval_ms)) = auto_refresh_change {
+            MemoryViewerViewData::set_auto_refresh(
+                self.memory_viewer_view_data.clone(),
+                &self.app_context.refresh_scheduler,
+                is_enabled,
+                interval_ms,
+            );
+        }
+
+        if should_close_search {
+            MemoryViewerViewData::close_search(self.memory_viewer_view_data.clone());
+        } else if should_search {
+            MemoryViewerViewData::start_search(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        } else if let Some(is_forward) = search_match_step {
+            MemoryViewerViewData::select_search_match(self.memory_viewer_view_data.clone(), is_forward);
+        } else if let Some(address) = selected_region_match {
+            MemoryViewerViewData::navigate_to_search_match(
+                self.memory_viewer_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                address,
+            );
+        }
+
+        if let Some(display_data_type) = inspector_display_data_type {
+            MemoryViewerViewData::set_display_data_type(self.memory_viewer_view_data.clone(), display_data_type);
+        }
+
+        if let Some(address) = selected_byte_address {
+            MemoryViewerViewData::select_address(self.memory_viewer_view_data.clone(), address);
+        }
+
+        if let Some(address) = selection_begin_address {
+            MemoryViewerViewData::begin_selection(self.memory_viewer_view_data.clone(), address);
+        } else if let Some(address) = selection_extend_address {
+            MemoryViewerViewData::extend_selection(self.memory_viewer_view_data.clone(), address);
+        }
+
+        let (is_selecting, selection_copy_text) = self
+            .memory_viewer_view_data
+            .read("Memory viewer selection state")
+            .map(|view_data| (view_data.is_selecting, view_data.format_selection(ByteCopyFormat::SpacedHex)))
+            .unwrap_or((false, None));
+
+        if is_selecting && !user_interface.input(|input_state| input_state.pointer.primary_down()) {
+            MemoryViewerViewData::end_selection(self.memory_viewer_view_data.clone());
+        }
+
+        if let Some(selection_copy_text) = selection_copy_text.filter(|_| LineSelection::is_copy_requested(user_interface)) {
+            user_interface.ctx().copy_text(selection_copy_text);
+        }
+
+        // Ctrl+Enter follows the pointer at the caret rather than committing an inline byte edit.
+        let (is_follow_pointer_requested, is_navigate_back_requested) = user_interface.input(|input_state| {
+            (
+                input_state.modifiers.ctrl && input_state.key_pressed(Key::Enter),
+                input_state.modifiers.alt && input_state.key_pressed(Key::ArrowLeft),
+            )
+        });
+
+        if is_hex_pane_focused && is_follow_pointer_requested {
+            follow_pointer_address = follow_pointer_address.or_else(|| {
+                self.memory_viewer_view_data
+                    .read("Memory viewer caret address")
+                    .map(|view_data| view_data.target_address)
+            });
+        }
+
+        should_navigate_back |= is_hex_pane_focused && is_navigate_back_requested;
+
+        if let Some(address) = edited_byte_address {
+            MemoryViewerViewData::begin_byte_edit(self.memory_viewer_view_data.clone(), address);
+        } else if is_byte_edit_active && follow_pointer_address.is_none() && !should_navigate_back {
+            self.handle_byte_edit_input(user_interface, is_hex_pane_focused);
+        }
+
+        if let Some(address) = follow_pointer_address {
+            MemoryViewerViewData::follow_pointer(
+                self.memory_viewer_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                address,
+                process_bitness,
+            );
+        } else if should_navigate_back {
+            MemoryViewerViewData::navigate_back(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if let Some(address) = context_menu_byte_address {
+            MemoryViewerViewData::set_context_menu_address(self.memory_viewer_view_data.clone(), address);
+        }
+
+        if let Some(address) = paste_at_address {
+            MemoryViewerViewData::request_paste(self.memory_viewer_view_data.clone(), address);
+            user_interface
+                .ctx()
+                .send_viewport_cmd(ViewportCommand::RequestPaste);
+        }
+
+        let is_paste_requested = self
+            .memory_viewer_view_data
+            .read("Memory viewer paste requested state")
+            .map(|view_data| view_data.is_paste_requested)
+            .unwrap_or(false);
+
+        if is_hex_pane_focused || is_paste_requested {
+            let paste_text = user_interface.input(|input_state| {
+                input_state.events.iter().find_map(|event| match event {
+                    Event::Paste(text) => Some(text.clone()),
+                    _ => None,
+                })
+            });
+
+            if let Some(paste_text) = paste_text {
+                MemoryViewerViewData::begin_paste(self.memory_viewer_view_data.clone(), &paste_text);
+            }
+        }
+
+        self.show_paste_confirmation_dialog(user_interface);
+
+        if let Some(address) = add_to_project_address {
+            let add_to_project_entry = self
+                .memory_viewer_view_data
+                .read("Memory viewer add to project entry")
+                .map(|view_data| (view_data.get_module_relative_address(address), view_data.display_data_type.clone()));
+
+            if let Some(((module_address, module), display_data_type)) = add_to_project_entry {
+                let add_to_project_view_data = self
+                    .app_context
+                    .dependency_container
+                    .get_dependency::<AddToProjectViewData>();
+
+                AddToProjectViewData::open_dialog(add_to_project_view_data, AddToProjectEntryKind::Address, module_address, &module, display_data_type);
+            }
+        }
+
         if let Some(base) = jump_to_region_base {
             MemoryViewerViewData::set_target_address(self.memory_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), base);
             DisassemblerViewData::set_target_address(self.disassembler_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), base);
         }
 
+        if let Some(toggle_breakpoint_address) = toggle_breakpoint_address {
+            DisassemblerViewData::toggle_breakpoint(
+                self.disassembler_view_data.clone(),
+                self.app_context.engine_unprivileged_state.clone(),
+                toggle_breakpoint_address,
+            );
+        }
+
         if let Some(base) = disassemble_region_base {
             DisassemblerViewData::set_target_address(self.disassembler_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), base);
-            if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                docking_manager.set_window_visible(crate::views::disassembler::disassembler_view::DisassemblerView::WINDOW_ID, true);
-            }
+            self.app_context.queue_docking_command(DockingCommand::ShowWindow {
+                window_id: crate::views::disassembler::disassembler_view::DisassemblerView::WINDOW_ID.to_string(),
+                is_visible: true,
+            });
         }
 
         if should_refresh {
@@ -0,0 +1,82 @@
+/// The text formats that bytes selected in the memory viewer can be copied as.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ByteCopyFormat {
+    /// Digits with no separators, ie `DEADBEEF`.
+    HexString,
+    /// Digits with a space between each byte, ie `DE AD BE EF`. This is the form pasted back into the hex view.
+    SpacedHex,
+    /// A C array declaration holding the bytes, with sixteen bytes per line.
+    CArray,
+}
+
+impl ByteCopyFormat {
+    pub const ALL: [Self; 3] = [Self::HexString, Self::SpacedHex, Self::CArray];
+    const C_ARRAY_BYTES_PER_LINE: usize = 16;
+
+    pub fn get_display_name(&self) -> &'static str {
+        match self {
+            Self::HexString => "Copy as hex string",
+            Self::SpacedHex => "Copy as spaced hex",
+            Self::CArray => "Copy as C array",
+        }
+    }
+
+    pub fn format(
+        &self,
+        bytes: &[u8],
+    ) -> String {
+        match self {
+            Self::HexString => bytes.iter().map(|byte| format!("{:02X}", byte)).collect(),
+            Self::SpacedHex => bytes
+                .iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::CArray => {
+                let lines = bytes
+                    .chunks(Self::C_ARRAY_BYTES_PER_LINE)
+                    .map(|line_bytes| {
+                        let line = line_bytes
+                            .iter()
+                            .map(|byte| format!("0x{:02X}", byte))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        format!("    {}", line)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+
+                format!("unsigned char bytes[{}] = {{\n{}\n}};", bytes.len(), lines)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteCopyFormat;
+
+    #[test]
+    fn bytes_format_in_each_copy_format() {
+        let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+
+        assert_eq!(ByteCopyFormat::HexString.format(&bytes), "DEADBEEF");
+        assert_eq!(ByteCopyFormat::SpacedHex.format(&bytes), "DE AD BE EF");
+        assert_eq!(
+            ByteCopyFormat::CArray.format(&bytes),
+            "unsigned char bytes[4] = {\n    0xDE, 0xAD, 0xBE, 0xEF\n};"
+        );
+    }
+
+    #[test]
+    fn c_arrays_wrap_every_sixteen_bytes() {
+        let c_array = ByteCopyFormat::CArray.format(&[0u8; 17]);
+        let lines = c_array.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "unsigned char bytes[17] = {");
+        assert!(lines[1].ends_with("0x00,"));
+        assert_eq!(lines[2], "    0x00");
+    }
+}
@@ -0,0 +1,307 @@
+use squalr_engine_api::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
+use squalr_engine_api::structures::data_types::built_in_types::{
+    f32::data_type_f32::DataTypeF32, f32be::data_type_f32be::DataTypeF32be, f64::data_type_f64::DataTypeF64, f64be::data_type_f64be::DataTypeF64be,
+    i8::data_type_i8::DataTypeI8, i16::data_type_i16::DataTypeI16, i16be::data_type_i16be::DataTypeI16be, i32::data_type_i32::DataTypeI32,
+    i32be::data_type_i32be::DataTypeI32be, i64::data_type_i64::DataTypeI64, i64be::data_type_i64be::DataTypeI64be,
+    string::utf8::data_type_string_utf8::DataTypeStringUtf8, u8::data_type_u8::DataTypeU8, u16::data_type_u16::DataTypeU16,
+    u16be::data_type_u16be::DataTypeU16be, u32::data_type_u32::DataTypeU32, u32be::data_type_u32be::DataTypeU32be, u64::data_type_u64::DataTypeU64,
+    u64be::data_type_u64be::DataTypeU64be,
+};
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+
+/// A single interpretation of the bytes at the memory viewer caret.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataInspectorRow {
+    pub label: &'static str,
+    pub value: String,
+
+    /// The data type to display the viewer as when this row is clicked, if there is one matching this interpretation.
+    pub data_type_ref: Option<DataTypeRef>,
+}
+
+/// Decodes the bytes at the memory viewer caret as every common type at once, such that the display type need not be switched
+/// back and forth to find the interpretation that makes sense.
+pub struct DataInspector {}
+
+impl DataInspector {
+    /// The most bytes decoded by the string previews.
+    const STRING_PREVIEW_LENGTH: usize = 32;
+    const TRUNCATED_VALUE: &'static str = "??";
+
+    /// Decodes the given bytes, which start at the caret. Interpretations wider than the available bytes, ie at the end of a
+    /// region, are shown as unknown. Pointers are resolved against the given regions.
+    pub fn inspect(
+        bytes: &[u8],
+        regions: &[MemoryRegionInfo],
+        bitness: Bitness,
+    ) -> Vec<DataInspectorRow> {
+        let row = |label: &'static str, value: Option<String>, data_type_id: &str| DataInspectorRow {
+            label,
+            value: value.unwrap_or_else(|| Self::TRUNCATED_VALUE.to_string()),
+            data_type_ref: Some(DataTypeRef::new(data_type_id)),
+        };
+
+        vec![
+            row(
+                "i8",
+                Self::read::<1>(bytes).map(|value| i8::from_le_bytes(value).to_string()),
+                DataTypeI8::DATA_TYPE_ID,
+            ),
+            row(
+                "u8",
+                Self::read::<1>(bytes).map(|value| u8::from_le_bytes(value).to_string()),
+                DataTypeU8::DATA_TYPE_ID,
+            ),
+            row(
+                "i16",
+                Self::read::<2>(bytes).map(|value| i16::from_le_bytes(value).to_string()),
+                DataTypeI16::DATA_TYPE_ID,
+            ),
+            row(
+                "i16 (BE)",
+                Self::read::<2>(bytes).map(|value| i16::from_be_bytes(value).to_string()),
+                DataTypeI16be::DATA_TYPE_ID,
+            ),
+            row(
+                "u16",
+                Self::read::<2>(bytes).map(|value| u16::from_le_bytes(value).to_string()),
+                DataTypeU16::DATA_TYPE_ID,
+            ),
+            row(
+                "u16 (BE)",
+                Self::read::<2>(bytes).map(|value| u16::from_be_bytes(value).to_string()),
+                DataTypeU16be::DATA_TYPE_ID,
+            ),
+            row(
+                "i32",
+                Self::read::<4>(bytes).map(|value| i32::from_le_bytes(value).to_string()),
+                DataTypeI32::DATA_TYPE_ID,
+            ),
+            row(
+                "i32 (BE)",
+                Self::read::<4>(bytes).map(|value| i32::from_be_bytes(value).to_string()),
+                DataTypeI32be::DATA_TYPE_ID,
+            ),
+            row(
+                "u32",
+                Self::read::<4>(bytes).map(|value| u32::from_le_bytes(value).to_string()),
+                DataTypeU32::DATA_TYPE_ID,
+            ),
+            row(
+                "u32 (BE)",
+                Self::read::<4>(bytes).map(|value| u32::from_be_bytes(value).to_string()),
+                DataTypeU32be::DATA_TYPE_ID,
+            ),
+            row(
+                "i64",
+                Self::read::<8>(bytes).map(|value| i64::from_le_bytes(value).to_string()),
+                DataTypeI64::DATA_TYPE_ID,
+            ),
+            row(
+                "i64 (BE)",
+                Self::read::<8>(bytes).map(|value| i64::from_be_bytes(value).to_string()),
+                DataTypeI64be::DATA_TYPE_ID,
+            ),
+            row(
+                "u64",
+                Self::read::<8>(bytes).map(|value| u64::from_le_bytes(value).to_string()),
+                DataTypeU64::DATA_TYPE_ID,
+            ),
+            row(
+                "u64 (BE)",
+                Self::read::<8>(bytes).map(|value| u64::from_be_bytes(value).to_string()),
+                DataTypeU64be::DATA_TYPE_ID,
+            ),
+            row(
+                "f32",
+                Self::read::<4>(bytes).map(|value| f32::from_le_bytes(value).to_string()),
+                DataTypeF32::DATA_TYPE_ID,
+            ),
+            row(
+                "f32 (BE)",
+                Self::read::<4>(bytes).map(|value| f32::from_be_bytes(value).to_string()),
+                DataTypeF32be::DATA_TYPE_ID,
+            ),
+            row(
+                "f64",
+                Self::read::<8>(bytes).map(|value| f64::from_le_bytes(value).to_string()),
+                DataTypeF64::DATA_TYPE_ID,
+            ),
+            row(
+                "f64 (BE)",
+                Self::read::<8>(bytes).map(|value| f64::from_be_bytes(value).to_string()),
+                DataTypeF64be::DATA_TYPE_ID,
+            ),
+            row("UTF-8", Self::decode_utf8_preview(bytes), DataTypeStringUtf8::DATA_TYPE_ID),
+            DataInspectorRow {
+                label: "UTF-16",
+                value: Self::decode_utf16_preview(bytes).unwrap_or_else(|| Self::TRUNCATED_VALUE.to_string()),
+                data_type_ref: None,
+            },
+            Self::inspect_pointer(bytes, regions, bitness),
+        ]
+    }
+
+    fn read<const SIZE: usize>(bytes: &[u8]) -> Option<[u8; SIZE]> {
+        bytes.get(..SIZE)?.try_into().ok()
+    }
+
+    /// Decodes text up to the first null byte, showing invalid sequences and control characters as replacements.
+    fn decode_utf8_preview(bytes: &[u8]) -> Option<String> {
+        if bytes.is_empty() {
+            return None;
+        }
+
+        let preview_bytes = &bytes[..bytes.len().min(Self::STRING_PREVIEW_LENGTH)];
+        let text_length = preview_bytes
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(preview_bytes.len());
+
+        Some(Self::replace_control_characters(String::from_utf8_lossy(&preview_bytes[..text_length]).chars()))
+    }
+
+    /// Decodes little endian UTF-16 text up to the first null code unit. A trailing odd byte is ignored.
+    fn decode_utf16_preview(bytes: &[u8]) -> Option<String> {
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        let code_units = bytes[..bytes.len().min(Self::STRING_PREVIEW_LENGTH)]
+            .chunks_exact(2)
+            .map(|code_unit| u16::from_le_bytes([code_unit[0], code_unit[1]]))
+            .take_while(|code_unit| *code_unit != 0);
+
+        Some(Self::replace_control_characters(
+            char::decode_utf16(code_units).map(|character| character.unwrap_or(char::REPLACEMENT_CHARACTER)),
+        ))
+    }
+
+    fn replace_control_characters(characters: impl Iterator<Item = char>) -> String {
+        characters
+            .map(|character| if character.is_control() { '.' } else { character })
+            .collect()
+    }
+
+    /// Reads a pointer of the process width, describing where it points if it lands in a mapped region.
+    fn inspect_pointer(
+        bytes: &[u8],
+        regions: &[MemoryRegionInfo],
+        bitness: Bitness,
+    ) -> DataInspectorRow {
+        let (pointer, data_type_id) = match bitness {
+            Bitness::Bit32 => (Self::read::<4>(bytes).map(|value| u32::from_le_bytes(value) as u64), DataTypeU32::DATA_TYPE_ID),
+            Bitness::Bit64 => (Self::read::<8>(bytes).map(u64::from_le_bytes), DataTypeU64::DATA_TYPE_ID),
+        };
+        let value = match pointer {
+            Some(pointer) => {
+                let region = regions
+                    .iter()
+                    .find(|region| pointer >= region.base_address && pointer - region.base_address < region.region_size);
+
+                match region {
+                    Some(region) if !region.module_name.is_empty() => format!(
+                        "{:X} ({}+{:X})",
+                        pointer,
+                        region.module_name,
+                        pointer - region.base_address + region.module_offset
+                    ),
+                    Some(region) => format!("{:X} (region {:X}+{:X})", pointer, region.base_address, pointer - region.base_address),
+                    None => format!("{:X}", pointer),
+                }
+            }
+            None => Self::TRUNCATED_VALUE.to_string(),
+        };
+
+        DataInspectorRow {
+            label: "Pointer",
+            value,
+            data_type_ref: Some(DataTypeRef::new(data_type_id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DataInspector;
+    use squalr_engine_api::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+
+    fn get_value<'a>(
+        rows: &'a [super::DataInspectorRow],
+        label: &str,
+    ) -> &'a str {
+        &rows.iter().find(|row| row.label == label).unwrap().value
+    }
+
+    #[test]
+    fn bytes_are_decoded_in_both_endiannesses() {
+        let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let rows = DataInspector::inspect(&bytes, &[], Bitness::Bit64);
+
+        assert_eq!(get_value(&rows, "u8"), "1");
+        assert_eq!(get_value(&rows, "u16"), "513");
+        assert_eq!(get_value(&rows, "u16 (BE)"), "258");
+        assert_eq!(get_value(&rows, "u32"), "67305985");
+        assert_eq!(get_value(&rows, "u32 (BE)"), "16909060");
+        assert_eq!(get_value(&rows, "u64 (BE)"), "72623859790382856");
+
+        let float_bytes = 1.5f32.to_be_bytes();
+        let rows = DataInspector::inspect(&float_bytes, &[], Bitness::Bit64);
+
+        assert_eq!(get_value(&rows, "f32 (BE)"), "1.5");
+        assert_eq!(get_value(&rows, "i8"), "63");
+    }
+
+    #[test]
+    fn interpretations_past_the_end_of_the_bytes_are_unknown() {
+        let rows = DataInspector::inspect(&[0xFF, 0xFF, 0x41], &[], Bitness::Bit32);
+
+        assert_eq!(get_value(&rows, "i16"), "-1");
+        assert_eq!(get_value(&rows, "i32"), "??");
+        assert_eq!(get_value(&rows, "f64"), "??");
+        assert_eq!(get_value(&rows, "Pointer"), "??");
+        // The odd trailing byte is dropped, rather than decoded on its own.
+        assert_eq!(get_value(&rows, "UTF-16"), "\u{FFFF}");
+
+        let rows = DataInspector::inspect(&[], &[], Bitness::Bit64);
+
+        assert!(rows.iter().all(|row| row.value == "??"));
+    }
+
+    #[test]
+    fn strings_stop_at_null_and_pointers_resolve_to_modules() {
+        let regions = [
+            MemoryRegionInfo {
+                base_address: 0x40_1000,
+                region_size: 0x1000,
+                module_name: "game.exe".to_string(),
+                module_offset: 0x1000,
+            },
+            MemoryRegionInfo {
+                base_address: 0x80_0000,
+                region_size: 0x1000,
+                module_name: String::new(),
+                module_offset: 0,
+            },
+        ];
+        let text_bytes = [b'H', 0, b'i', 0, 0, 0, b'x', 0];
+        let rows = DataInspector::inspect(&text_bytes, &regions, Bitness::Bit32);
+
+        assert_eq!(get_value(&rows, "UTF-8"), "H");
+        assert_eq!(get_value(&rows, "UTF-16"), "Hi");
+
+        let rows = DataInspector::inspect(&0x40_1234u32.to_le_bytes(), &regions, Bitness::Bit32);
+
+        assert_eq!(get_value(&rows, "Pointer"), "401234 (game.exe+1234)");
+
+        let rows = DataInspector::inspect(&0x80_0010u64.to_le_bytes(), &regions, Bitness::Bit64);
+
+        assert_eq!(get_value(&rows, "Pointer"), "800010 (region 800000+10)");
+
+        let rows = DataInspector::inspect(&0x1234u64.to_le_bytes(), &regions, Bitness::Bit64);
+
+        assert_eq!(get_value(&rows, "Pointer"), "1234");
+    }
+}
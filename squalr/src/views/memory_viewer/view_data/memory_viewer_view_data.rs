@@ -1,21 +1,36 @@
 use crate::app_context::AppContext;
+use crate::models::address_resolution::address_resolver::{AddressResolution, AddressResolver};
+use crate::models::refresh_scheduler::refresh_batch::RefreshBatch;
+use crate::models::refresh_scheduler::refresh_job_handle::RefreshJobHandle;
+use crate::models::refresh_scheduler::refresh_scheduler::RefreshScheduler;
+use crate::views::memory_viewer::view_data::byte_copy_format::ByteCopyFormat;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
 use squalr_engine_api::commands::memory::regions::memory_regions_request::MemoryRegionsRequest;
 use squalr_engine_api::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
-use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::commands::memory::search::memory_search_request::MemorySearchRequest;
+use squalr_engine_api::commands::memory::search::memory_search_response::MemorySearchMatch;
+use squalr_engine_api::commands::memory::write::memory_write_request::MemoryWriteRequest;
 use squalr_engine_api::dependency_injection::dependency::Dependency;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
 use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
 use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::expressions::address_resolve_error::AddressResolveError;
+use squalr_engine_api::structures::memory::bitness::Bitness;
+use squalr_engine_api::structures::memory::byte_pattern::BytePattern;
 use squalr_engine_api::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
 use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct MemoryViewerViewData {
     pub address_input: String,
+    /// The outcome of resolving the committed address input, shown beside it.
+    pub address_resolution: Option<AddressResolution>,
     pub base_address: u64,
     pub target_address: u64,
     pub region_base: u64,
@@ -28,12 +43,117 @@ pub struct MemoryViewerViewData {
     pub open_popout: bool,
     pub is_loading: bool,
     pub error_message: Option<String>,
+    pub is_search_open: bool,
+    pub is_search_focus_pending: bool,
+    pub search_input: String,
+    pub is_search_ascii: bool,
+    pub is_search_all_regions: bool,
+    pub is_searching: bool,
+    pub is_region_search_pending: bool,
+    pub search_progress: f32,
+    pub search_error: Option<String>,
+    pub search_generation: u64,
+    pub region_search_generation: u64,
+    pub search_pattern_length: usize,
+    /// Addresses of matches within the loaded bytes.
+    pub search_match_addresses: Vec<u64>,
+    pub search_match_index: Option<usize>,
+    pub is_search_scroll_pending: bool,
+    /// Whether the hex view should scroll such that the row holding the target address is the first visible row.
+    pub is_target_scroll_pending: bool,
+    pub region_search_matches: Vec<MemorySearchMatch>,
+    pub is_region_search_truncated: bool,
+    /// The address of the byte that was right clicked in the hex or ASCII pane.
+    pub context_menu_address: Option<u64>,
+    /// Whether a paste was requested from the context menu, such that the clipboard text arriving next is pasted at the caret.
+    pub is_paste_requested: bool,
+    pub paste_confirmation: Option<PasteConfirmation>,
+    /// The outcome of the last paste, or why it could not be started.
+    pub paste_status: Option<String>,
+    /// The hex byte being edited inline, if any.
+    pub byte_edit: Option<ByteEdit>,
+    /// The offset into the loaded bytes where the current selection was started.
+    pub selection_start_offset: Option<usize>,
+    /// The offset into the loaded bytes where the current selection ends, which may lie before its start.
+    pub selection_end_offset: Option<usize>,
+    /// Whether a selection is being dragged out, such that moving over other bytes extends it.
+    pub is_selecting: bool,
+    /// The addresses navigated away from by following pointers, most recent last.
+    pub address_history: Vec<u64>,
+    /// Whether the loaded bytes are read again periodically while the viewer is open.
+    pub is_auto_refresh_enabled: bool,
+    pub auto_refresh_interval_ms: u64,
+    /// Whether an auto refresh read is in flight, such that a slow read is not queued again behind itself.
+    pub is_auto_refreshing: bool,
+    /// Which of the loaded bytes changed in the last auto refresh, indexed like the loaded bytes.
+    pub changed_bytes: Vec<bool>,
+    pub refresh_job_handle: Option<RefreshJobHandle>,
+}
+
+/// A hex byte being edited inline, holding the digits typed so far.
+#[derive(Clone)]
+pub struct ByteEdit {
+    pub address: u64,
+    pub pending_text: String,
+}
+
+impl ByteEdit {
+    pub fn new(address: u64) -> Self {
+        Self {
+            address,
+            pending_text: String::new(),
+        }
+    }
+
+    /// Adds a typed character to the pending text, ignoring anything that is not a hex digit. Returns the byte once both of its
+    /// digits have been typed.
+    pub fn push_hex_digit(
+        &mut self,
+        character: char,
+    ) -> Option<u8> {
+        if !character.is_ascii_hexdigit() || self.pending_text.len() >= 2 {
+            return None;
+        }
+
+        self.pending_text.push(character.to_ascii_uppercase());
+
+        if self.pending_text.len() == 2 { self.get_pending_byte() } else { None }
+    }
+
+    /// Gets the byte typed so far, where a single digit is taken as the low nibble.
+    pub fn get_pending_byte(&self) -> Option<u8> {
+        u8::from_str_radix(&self.pending_text, 16).ok()
+    }
+}
+
+/// Bytes parsed from the clipboard, awaiting confirmation before they are written at the caret.
+#[derive(Clone)]
+pub struct PasteConfirmation {
+    pub address: u64,
+    pub bytes: Vec<u8>,
+    /// How many of the bytes land in readable regions. Anything past this crosses into unreadable memory.
+    pub accessible_length: usize,
+}
+
+impl PasteConfirmation {
+    pub fn is_truncation_required(&self) -> bool {
+        self.accessible_length < self.bytes.len()
+    }
+}
+
+/// How the bytes of the memory viewer are highlighted by the current search.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SearchHighlight {
+    None,
+    Match,
+    CurrentMatch,
 }
 
 impl MemoryViewerViewData {
     pub fn new() -> Self {
         Self {
             address_input: String::new(),
+            address_resolution: None,
             base_address: 0,
             target_address: 0,
             region_base: 0,
@@ -46,9 +166,48 @@ impl MemoryViewerViewData {
             open_popout: false,
             is_loading: false,
             error_message: None,
+            is_search_open: false,
+            is_search_focus_pending: false,
+            search_input: String::new(),
+            is_search_ascii: false,
+            is_search_all_regions: false,
+            is_searching: false,
+            is_region_search_pending: false,
+            search_progress: 0.0,
+            search_error: None,
+            search_generation: 0,
+            region_search_generation: 0,
+            search_pattern_length: 0,
+            search_match_addresses: Vec::new(),
+            search_match_index: None,
+            is_search_scroll_pending: false,
+            is_target_scroll_pending: false,
+            region_search_matches: Vec::new(),
+            is_region_search_truncated: false,
+            context_menu_address: None,
+            is_paste_requested: false,
+            paste_confirmation: None,
+            paste_status: None,
+            byte_edit: None,
+            selection_start_offset: None,
+            selection_end_offset: None,
+            is_selecting: false,
+            address_history: Vec::new(),
+            is_auto_refresh_enabled: false,
+            auto_refresh_interval_ms: Self::AUTO_REFRESH_INTERVALS_MS[1],
+            is_auto_refreshing: false,
+            changed_bytes: Vec::new(),
+            refresh_job_handle: None,
         }
     }
 
+    /// Loaded buffers larger than this are searched on a worker thread, with progress reported as each chunk completes.
+    const LOCAL_SEARCH_WORKER_THRESHOLD: usize = 1024 * 1024;
+    const LOCAL_SEARCH_CHUNK_SIZE: usize = 256 * 1024;
+    const REGION_SEARCH_MAX_RESULTS: u64 = 1000;
+    const ADDRESS_HISTORY_MAX_LENGTH: usize = 64;
+    pub const AUTO_REFRESH_INTERVALS_MS: [u64; 4] = [250, 500, 1000, 2000];
+
     pub fn register(app_context: &Arc<AppContext>) -> Dependency<Self> {
         app_context
             .dependency_container
@@ -68,6 +227,129 @@ impl MemoryViewerViewData {
         Self::refresh(memory_viewer_view_data, engine_unprivileged_state);
     }
 
+    /// Registers the job that reads the loaded bytes again while auto refresh is enabled. Registering again keeps the existing job.
+    pub fn register_refresh_job(
+        memory_viewer_view_data: Dependency<Self>,
+        refresh_scheduler: &RefreshScheduler,
+    ) {
+        let auto_refresh_interval_ms = match memory_viewer_view_data.read("Memory viewer refresh job check") {
+            Some(view_data) if view_data.refresh_job_handle.is_none() => view_data.auto_refresh_interval_ms,
+            _ => return,
+        };
+
+        let memory_viewer_view_data_clone = memory_viewer_view_data.clone();
+        let refresh_job_handle =
+            refresh_scheduler.register_job("Memory viewer", Duration::from_millis(auto_refresh_interval_ms), false, move |refresh_batch| {
+                Self::auto_refresh(memory_viewer_view_data_clone.clone(), refresh_batch);
+            });
+
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer register refresh job") {
+            view_data.refresh_job_handle = Some(refresh_job_handle);
+        }
+    }
+
+    pub fn set_auto_refresh(
+        memory_viewer_view_data: Dependency<Self>,
+        refresh_scheduler: &RefreshScheduler,
+        is_enabled: bool,
+        interval_ms: u64,
+    ) {
+        let job_id = match memory_viewer_view_data.write("Memory viewer set auto refresh") {
+            Some(mut view_data) => {
+                view_data.is_auto_refresh_enabled = is_enabled;
+                view_data.auto_refresh_interval_ms = interval_ms;
+
+                if !is_enabled {
+                    view_data.changed_bytes.clear();
+                }
+
+                view_data
+                    .refresh_job_handle
+                    .as_ref()
+                    .map(RefreshJobHandle::get_job_id)
+            }
+            None => return,
+        };
+
+        if let Some(job_id) = job_id {
+            refresh_scheduler.set_job_interval(job_id, Duration::from_millis(interval_ms));
+            refresh_scheduler.set_job_enabled(job_id, is_enabled);
+        }
+    }
+
+    /// Queues reading the loaded bytes again, as long as the viewer is open and not already loading.
+    fn auto_refresh(
+        memory_viewer_view_data: Dependency<Self>,
+        refresh_batch: &mut RefreshBatch,
+    ) {
+        // The write guard is dropped before the read is queued, as the engine may call back synchronously.
+        let (base_address, read_len) = {
+            let mut view_data = match memory_viewer_view_data.write("Memory viewer auto refresh") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if !view_data.is_auto_refresh_enabled
+                || !view_data.open_popout
+                || view_data.is_loading
+                || view_data.is_auto_refreshing
+                || view_data.bytes.is_empty()
+            {
+                return;
+            }
+
+            view_data.is_auto_refreshing = true;
+
+            (view_data.base_address, view_data.bytes.len())
+        };
+        let symbolic_struct_definition = SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
+            DataTypeRef::new(DataTypeU8::get_data_type_id()),
+            ContainerType::ArrayFixed(read_len as u64),
+        )]);
+        let memory_read_request = MemoryReadRequest {
+            address: base_address,
+            module_name: String::new(),
+            symbolic_struct_definition,
+        };
+
+        refresh_batch.read_memory(memory_read_request, move |memory_read_response| {
+            if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer auto refresh response") {
+                view_data.is_auto_refreshing = false;
+
+                if memory_read_response.success {
+                    view_data.apply_auto_refresh(base_address, memory_read_response.valued_struct.get_bytes());
+                }
+            }
+        });
+    }
+
+    /// Replaces the loaded bytes with freshly read ones, marking the bytes that changed. Reads for an address that has since been
+    /// navigated away from, or that came back short, are discarded.
+    fn apply_auto_refresh(
+        &mut self,
+        base_address: u64,
+        bytes: Vec<u8>,
+    ) {
+        if base_address != self.base_address || bytes.len() != self.bytes.len() {
+            return;
+        }
+
+        self.changed_bytes = self
+            .bytes
+            .iter()
+            .zip(&bytes)
+            .map(|(previous_byte, byte)| previous_byte != byte)
+            .collect();
+        self.bytes = bytes;
+    }
+
+    pub fn is_byte_changed(
+        &self,
+        index: usize,
+    ) -> bool {
+        self.changed_bytes.get(index).copied().unwrap_or(false)
+    }
+
     pub fn set_popout_open(
         memory_viewer_view_data: Dependency<Self>,
         is_open: bool,
@@ -81,44 +363,94 @@ impl MemoryViewerViewData {
         memory_viewer_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) {
-        let (address_input, bytes_to_read, auto_select_region) = {
+        let address_input = {
             let mut guard = match memory_viewer_view_data.write("Memory viewer view data refresh") {
                 Some(guard) => guard,
                 None => return,
             };
 
             let address_input = guard.address_input.trim().to_string();
-            let mut auto_select_region = false;
             guard.is_loading = true;
+            guard.is_auto_refreshing = false;
             guard.error_message = None;
-            let bytes_to_read = guard.bytes_per_row.saturating_mul(guard.row_count).max(1);
+            guard.byte_edit = None;
+            guard.clear_selection();
 
             if address_input.is_empty() {
-                auto_select_region = true;
+                guard.address_resolution = None;
             }
 
-            (address_input, bytes_to_read, auto_select_region)
+            address_input
         };
 
-        let memory_regions_request = MemoryRegionsRequest {};
-        let memory_viewer_view_data_clone = memory_viewer_view_data.clone();
+        // With no address entered, the first region is shown instead.
+        if address_input.is_empty() {
+            Self::load_regions(memory_viewer_view_data, engine_unprivileged_state, None);
+            return;
+        }
+
         let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+        let address_input_clone = address_input.clone();
 
-        memory_regions_request.send(&engine_unprivileged_state, move |memory_regions_response| {
-            let mut auto_select_region = auto_select_region;
-            let module_parse = address_input.split_once('+').map(|(module, offset)| (module.trim().to_string(), offset.trim().to_string()));
-            let mut invalid_address = false;
-            let mut target_address = match Conversions::parse_hex_address(address_input.trim()) {
-                Ok(address) => address,
-                Err(_) => {
-                    invalid_address = true;
-                    0
+        AddressResolver::resolve(&engine_unprivileged_state, &address_input, move |address_resolution| {
+            let target_address = address_resolution
+                .as_ref()
+                .ok()
+                .map(|resolved_address| resolved_address.address);
+
+            if let Some(mut memory_viewer_view_data) = memory_viewer_view_data.write("Memory viewer address resolved") {
+                if let Err(error) = &address_resolution {
+                    memory_viewer_view_data.is_loading = false;
+                    memory_viewer_view_data.error_message = Some(Self::describe_address_error(&address_input_clone, error));
                 }
-            };
 
-            if invalid_address {
-                auto_select_region = true;
+                memory_viewer_view_data.address_resolution = Some(address_resolution);
+            }
+
+            if let Some(target_address) = target_address {
+                Self::load_regions(memory_viewer_view_data, engine_unprivileged_state_clone, Some(target_address));
             }
+        });
+    }
+
+    /// Describes why the address input could not be resolved, quoting the part of the input that a syntax error points at.
+    fn describe_address_error(
+        address_input: &str,
+        error: &AddressResolveError,
+    ) -> String {
+        let AddressResolveError::Syntax { message, position } = error else {
+            return error.to_string();
+        };
+        let remaining_input: String = address_input.chars().skip(*position).collect();
+        let token_length = remaining_input
+            .find(|character: char| !(character.is_ascii_alphanumeric() || character == '_' || character == '.'))
+            .unwrap_or(remaining_input.len())
+            .max(remaining_input.chars().next().map_or(0, char::len_utf8));
+
+        match &remaining_input[..token_length] {
+            "" => format!("{} at the end of '{}'", message, address_input),
+            token => format!("{} at '{}' (position {})", message, token, position),
+        }
+    }
+
+    /// Loads the region list, then the bytes around the target address, or around the start of the first region if there is none.
+    fn load_regions(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        target_address: Option<u64>,
+    ) {
+        let bytes_to_read = match memory_viewer_view_data.read("Memory viewer load regions") {
+            Some(memory_viewer_view_data) => memory_viewer_view_data
+                .bytes_per_row
+                .saturating_mul(memory_viewer_view_data.row_count)
+                .max(1),
+            None => return,
+        };
+        let memory_regions_request = MemoryRegionsRequest {};
+        let memory_viewer_view_data_clone = memory_viewer_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_regions_request.send(&engine_unprivileged_state, move |memory_regions_response| {
             let (read_len, read_base_address) = {
                 let mut memory_viewer_view_data = match memory_viewer_view_data_clone.write("Memory viewer regions response") {
                     Some(data) => data,
@@ -136,41 +468,15 @@ impl MemoryViewerViewData {
                     return;
                 }
 
-                if let Some((module, offset_string)) = module_parse.clone() {
-                    let offset = match Conversions::parse_hex_address(&offset_string) {
-                        Ok(offset) => offset,
-                        Err(_) => {
-                            memory_viewer_view_data.is_loading = false;
-                            memory_viewer_view_data.bytes.clear();
-                            memory_viewer_view_data.error_message = Some("Invalid module offset".to_string());
-                            return;
-                        }
-                    };
-
-                    if let Some(region) = memory_regions_response
-                        .regions
-                        .iter()
-                        .find(|region| region.module_name.eq_ignore_ascii_case(&module))
-                    {
-                        let base = region.base_address.saturating_sub(region.module_offset);
-                        target_address = base.saturating_add(offset);
-                    } else {
-                        memory_viewer_view_data.is_loading = false;
-                        memory_viewer_view_data.bytes.clear();
-                        memory_viewer_view_data.error_message = Some("Module not found".to_string());
-                        return;
-                    }
-                }
+                let target_address = match target_address {
+                    Some(target_address) => target_address,
+                    None => {
+                        let first_region_base = memory_regions_response.regions[0].base_address;
+                        memory_viewer_view_data.address_input = format!("{:X}", first_region_base);
 
-                if auto_select_region {
-                    if let Some(region) = memory_regions_response.regions.first() {
-                        target_address = region.base_address;
-                        memory_viewer_view_data.address_input = format!("{:X}", target_address);
-                        if invalid_address {
-                            memory_viewer_view_data.error_message = Some("Invalid address. Showing first region.".to_string());
-                        }
+                        first_region_base
                     }
-                }
+                };
 
                 memory_viewer_view_data.target_address = target_address;
                 memory_viewer_view_data.base_address = target_address & !0xF;
@@ -244,11 +550,1041 @@ impl MemoryViewerViewData {
                             memory_viewer_view_data.error_message = Some("Unreadable memory".to_string());
                         } else {
                             memory_viewer_view_data.bytes = bytes;
+                            memory_viewer_view_data.changed_bytes.clear();
                             memory_viewer_view_data.error_message = None;
+                            memory_viewer_view_data.is_target_scroll_pending = true;
                         }
                     }
                 }
+
+                // The loaded bytes changed, so matches from the previous buffer no longer apply.
+                Self::start_local_search(memory_viewer_view_data);
+            });
+        });
+    }
+
+    pub fn open_search(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer open search") {
+            view_data.is_search_open = true;
+            view_data.is_search_focus_pending = true;
+        }
+    }
+
+    pub fn close_search(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer close search") {
+            view_data.is_search_open = false;
+            view_data.clear_search_results();
+            view_data.clear_region_search_results();
+        }
+    }
+
+    /// Searches the loaded bytes for the search input, and optionally issues an engine-side search across all regions.
+    pub fn start_search(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (byte_pattern, is_search_all_regions) = {
+            let mut view_data = match memory_viewer_view_data.write("Memory viewer start search") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            view_data.clear_region_search_results();
+
+            match view_data.parse_search_pattern() {
+                Ok(byte_pattern) => (byte_pattern, view_data.is_search_all_regions),
+                Err(error) => {
+                    view_data.clear_search_results();
+                    view_data.search_error = Some(error);
+                    return;
+                }
+            }
+        };
+
+        Self::start_local_search(memory_viewer_view_data.clone());
+
+        if !is_search_all_regions {
+            return;
+        }
+
+        let region_search_generation = match memory_viewer_view_data.write("Memory viewer start region search") {
+            Some(mut view_data) => {
+                view_data.is_region_search_pending = true;
+                view_data.region_search_generation
+            }
+            None => return,
+        };
+
+        let memory_search_request = MemorySearchRequest {
+            pattern: byte_pattern,
+            max_results: Self::REGION_SEARCH_MAX_RESULTS,
+        };
+
+        // Searching every region can take a while, and commands execute synchronously in standalone mode.
+        thread::spawn(move || {
+            memory_search_request.send(&engine_unprivileged_state, move |memory_search_response| {
+                if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer region search response") {
+                    if view_data.region_search_generation != region_search_generation {
+                        return;
+                    }
+
+                    view_data.is_region_search_pending = false;
+                    view_data.region_search_matches = memory_search_response.matches;
+                    view_data.is_region_search_truncated = memory_search_response.is_truncated;
+                }
             });
         });
     }
+
+    /// Moves to the next or previous match in the loaded bytes, wrapping around at either end.
+    pub fn select_search_match(
+        memory_viewer_view_data: Dependency<Self>,
+        is_forward: bool,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer select search match") {
+            let match_count = view_data.search_match_addresses.len();
+
+            if match_count == 0 {
+                return;
+            }
+
+            let match_index = match (view_data.search_match_index, is_forward) {
+                (None, true) => 0,
+                (None, false) => match_count - 1,
+                (Some(match_index), true) => (match_index + 1) % match_count,
+                (Some(match_index), false) => (match_index + match_count - 1) % match_count,
+            };
+
+            view_data.select_search_match_index(match_index);
+        }
+    }
+
+    pub fn clear_search_scroll(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer clear search scroll") {
+            view_data.is_search_scroll_pending = false;
+        }
+    }
+
+    pub fn clear_target_scroll(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer clear target scroll") {
+            view_data.is_target_scroll_pending = false;
+        }
+    }
+
+    /// Navigates to a match from the all regions search, reloading the viewer if the match lies outside of the loaded bytes.
+    pub fn navigate_to_search_match(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+    ) {
+        {
+            let mut view_data = match memory_viewer_view_data.write("Memory viewer navigate to search match") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if let Some(match_index) = view_data
+                .search_match_addresses
+                .iter()
+                .position(|match_address| *match_address == address)
+            {
+                view_data.select_search_match_index(match_index);
+                return;
+            }
+        }
+
+        Self::set_target_address(memory_viewer_view_data, engine_unprivileged_state, address);
+    }
+
+    /// Moves the caret to a loaded byte, without reloading the viewer.
+    pub fn select_address(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer select address") {
+            view_data.target_address = address;
+            view_data.address_input = format!("{:X}", address);
+            view_data.byte_edit = None;
+            view_data.clear_selection();
+        }
+    }
+
+    /// Navigates to the address stored in the pointer at the given loaded address, remembering the current address to return to.
+    /// Values that do not point into a known region are reported rather than followed.
+    pub fn follow_pointer(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        bitness: Bitness,
+    ) {
+        let pointer = {
+            let mut view_data = match memory_viewer_view_data.write("Memory viewer follow pointer") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            match view_data.get_pointer_at(address, bitness) {
+                Ok(pointer) => {
+                    let target_address = view_data.target_address;
+
+                    view_data.address_history.push(target_address);
+
+                    if view_data.address_history.len() > Self::ADDRESS_HISTORY_MAX_LENGTH {
+                        view_data.address_history.remove(0);
+                    }
+
+                    pointer
+                }
+                Err(error) => {
+                    view_data.error_message = Some(error);
+                    return;
+                }
+            }
+        };
+
+        Self::set_target_address(memory_viewer_view_data, engine_unprivileged_state, pointer);
+    }
+
+    /// Returns to the address that was shown before the last pointer was followed.
+    pub fn navigate_back(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let previous_address = memory_viewer_view_data
+            .write("Memory viewer navigate back")
+            .and_then(|mut view_data| view_data.address_history.pop());
+
+        if let Some(previous_address) = previous_address {
+            Self::set_target_address(memory_viewer_view_data, engine_unprivileged_state, previous_address);
+        }
+    }
+
+    /// Reads the loaded pointer of the process width at the given address, as long as it points into one of the known regions.
+    pub fn get_pointer_at(
+        &self,
+        address: u64,
+        bitness: Bitness,
+    ) -> Result<u64, String> {
+        let pointer_size = match bitness {
+            Bitness::Bit32 => 4,
+            Bitness::Bit64 => 8,
+        };
+        let pointer_bytes = self
+            .get_loaded_index(address)
+            .and_then(|index| self.bytes.get(index..index.saturating_add(pointer_size)))
+            .ok_or_else(|| format!("The {} bytes of a pointer at {:X} are not loaded.", pointer_size, address))?;
+        let mut pointer_value_bytes = [0u8; 8];
+
+        pointer_value_bytes[..pointer_size].copy_from_slice(pointer_bytes);
+
+        let pointer = u64::from_le_bytes(pointer_value_bytes);
+        let is_in_region = self
+            .regions
+            .iter()
+            .any(|region| pointer >= region.base_address && pointer - region.base_address < region.region_size);
+
+        if is_in_region {
+            Ok(pointer)
+        } else {
+            Err(format!("The value {:X} at {:X} does not point into any memory region.", pointer, address))
+        }
+    }
+
+    pub fn set_display_data_type(
+        memory_viewer_view_data: Dependency<Self>,
+        display_data_type: DataTypeRef,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer set display data type") {
+            view_data.display_data_type = display_data_type;
+        }
+    }
+
+    /// Gets the loaded bytes from the caret onwards.
+    pub fn get_bytes_at_caret(&self) -> &[u8] {
+        match self.target_address.checked_sub(self.base_address) {
+            Some(offset) => self.bytes.get(offset as usize..).unwrap_or_default(),
+            None => &[],
+        }
+    }
+
+    pub fn set_context_menu_address(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer set context menu address") {
+            view_data.context_menu_address = Some(address);
+        }
+    }
+
+    /// Moves the caret to the given byte and waits for the clipboard text, which is requested from the platform asynchronously.
+    pub fn request_paste(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer request paste") {
+            view_data.target_address = address;
+            view_data.address_input = format!("{:X}", address);
+            view_data.is_paste_requested = true;
+        }
+    }
+
+    /// Parses hex text from the clipboard and asks for confirmation before writing it at the caret.
+    pub fn begin_paste(
+        memory_viewer_view_data: Dependency<Self>,
+        paste_text: &str,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer begin paste") {
+            view_data.is_paste_requested = false;
+
+            if view_data.bytes.is_empty() {
+                view_data.paste_status = Some("Select a byte to paste at.".to_string());
+                return;
+            }
+
+            match BytePattern::parse_hex_bytes(paste_text) {
+                Ok(bytes) => {
+                    let address = view_data.target_address;
+                    let accessible_length = view_data.get_accessible_length(address, bytes.len());
+
+                    view_data.paste_status = None;
+                    view_data.paste_confirmation = Some(PasteConfirmation {
+                        address,
+                        bytes,
+                        accessible_length,
+                    });
+                }
+                Err(error) => {
+                    view_data.paste_confirmation = None;
+                    view_data.paste_status = Some(format!("Cannot paste: {}", error));
+                }
+            }
+        }
+    }
+
+    pub fn cancel_paste(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer cancel paste") {
+            view_data.paste_confirmation = None;
+        }
+    }
+
+    /// Writes the confirmed paste, truncated to the readable bytes if it would otherwise cross into unreadable memory.
+    pub fn confirm_paste(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let paste_confirmation = match memory_viewer_view_data.write("Memory viewer confirm paste") {
+            Some(mut view_data) => match view_data.paste_confirmation.take() {
+                Some(paste_confirmation) => paste_confirmation,
+                None => return,
+            },
+            None => return,
+        };
+        let total_length = paste_confirmation.bytes.len();
+        let mut bytes = paste_confirmation.bytes;
+
+        bytes.truncate(paste_confirmation.accessible_length);
+
+        if bytes.is_empty() {
+            return;
+        }
+
+        let write_length = bytes.len();
+        let memory_write_request = MemoryWriteRequest {
+            address: paste_confirmation.address,
+            module_name: String::new(),
+            value: bytes,
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_write_request.send(&engine_unprivileged_state, move |memory_write_response| {
+            if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer paste response") {
+                view_data.paste_status = Some(if !memory_write_response.success {
+                    format!(
+                        "Failed to write {} bytes at {:X}. The memory may not be writable.",
+                        write_length, paste_confirmation.address
+                    )
+                } else if write_length < total_length {
+                    format!(
+                        "Pasted {} of {} bytes at {:X}. The rest crossed into unreadable memory.",
+                        write_length, total_length, paste_confirmation.address
+                    )
+                } else {
+                    format!("Pasted {} bytes at {:X}.", write_length, paste_confirmation.address)
+                });
+            }
+
+            Self::refresh(memory_viewer_view_data, engine_unprivileged_state_clone);
+        });
+    }
+
+    /// Starts editing the byte at the given address inline, moving the caret to it.
+    pub fn begin_byte_edit(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer begin byte edit") {
+            view_data.target_address = address;
+            view_data.address_input = format!("{:X}", address);
+            view_data.byte_edit = Some(ByteEdit::new(address));
+            view_data.clear_selection();
+        }
+    }
+
+    pub fn cancel_byte_edit(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer cancel byte edit") {
+            view_data.byte_edit = None;
+        }
+    }
+
+    /// Types text into the byte being edited. Each byte is written as soon as both of its digits are typed, after which editing
+    /// moves on to the next loaded byte, such that a run of hex digits overwrites consecutive bytes.
+    pub fn type_byte_edit_text(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        text: &str,
+    ) {
+        let mut completed_bytes = Vec::new();
+
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer type byte edit text") {
+            for character in text.chars() {
+                let Some(byte_edit) = view_data.byte_edit.as_mut() else {
+                    break;
+                };
+                let Some(byte) = byte_edit.push_hex_digit(character) else {
+                    continue;
+                };
+                let address = byte_edit.address;
+                let next_address = address.saturating_add(1);
+
+                completed_bytes.push((address, byte));
+
+                // Editing stops at the end of the loaded bytes, as there is no cell past them to move to.
+                if next_address > address && view_data.get_loaded_index(next_address).is_some() {
+                    view_data.target_address = next_address;
+                    view_data.address_input = format!("{:X}", next_address);
+                    view_data.byte_edit = Some(ByteEdit::new(next_address));
+                } else {
+                    view_data.byte_edit = None;
+                }
+            }
+        }
+
+        for (address, byte) in completed_bytes {
+            Self::write_byte(memory_viewer_view_data.clone(), engine_unprivileged_state.clone(), address, byte);
+        }
+    }
+
+    /// Writes the byte being edited and stops editing. A byte with only one digit typed is written with that digit as its low nibble.
+    pub fn commit_byte_edit(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let pending_byte = match memory_viewer_view_data.write("Memory viewer commit byte edit") {
+            Some(mut view_data) => view_data
+                .byte_edit
+                .take()
+                .and_then(|byte_edit| Some((byte_edit.address, byte_edit.get_pending_byte()?))),
+            None => return,
+        };
+
+        if let Some((address, byte)) = pending_byte {
+            Self::write_byte(memory_viewer_view_data, engine_unprivileged_state, address, byte);
+        }
+    }
+
+    /// Writes a single byte, then reloads the row holding it such that the hex view shows what the process now holds.
+    fn write_byte(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+        byte: u8,
+    ) {
+        let memory_write_request = MemoryWriteRequest {
+            address,
+            module_name: String::new(),
+            value: vec![byte],
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        memory_write_request.send(&engine_unprivileged_state, move |memory_write_response| {
+            if !memory_write_response.success {
+                if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer byte edit response") {
+                    view_data.error_message = Some(format!(
+                        "Failed to write {:02X} at {:X}. The memory may be unmapped or protected.",
+                        byte, address
+                    ));
+                }
+
+                return;
+            }
+
+            Self::refresh_row(memory_viewer_view_data, engine_unprivileged_state_clone, address);
+        });
+    }
+
+    /// Reads the loaded row holding the given address again, leaving the rest of the loaded bytes as they are.
+    fn refresh_row(
+        memory_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        address: u64,
+    ) {
+        let (row_address, row_length, base_address) = match memory_viewer_view_data.read("Memory viewer refresh row") {
+            Some(view_data) => match view_data.get_row_range(address) {
+                Some((row_index, row_length)) => (view_data.base_address.saturating_add(row_index as u64), row_length, view_data.base_address),
+                None => return,
+            },
+            None => return,
+        };
+        let memory_read_request = MemoryReadRequest {
+            address: row_address,
+            module_name: String::new(),
+            symbolic_struct_definition: SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
+                DataTypeRef::new(DataTypeU8::get_data_type_id()),
+                ContainerType::ArrayFixed(row_length as u64),
+            )]),
+        };
+
+        memory_read_request.send(&engine_unprivileged_state, move |memory_read_response| {
+            let row_bytes = memory_read_response.valued_struct.get_bytes();
+
+            if !memory_read_response.success || row_bytes.len() != row_length {
+                return;
+            }
+
+            if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer refresh row response") {
+                // The viewer may have moved elsewhere while the row was read, in which case the row no longer belongs to it.
+                if view_data.base_address != base_address {
+                    return;
+                }
+
+                let row_index = row_address.saturating_sub(base_address) as usize;
+
+                if let Some(loaded_row_bytes) = view_data.bytes.get_mut(row_index..row_index + row_length) {
+                    loaded_row_bytes.copy_from_slice(&row_bytes);
+                    view_data.error_message = None;
+                }
+            }
+        });
+    }
+
+    /// Starts selecting from the given loaded byte, moving the caret to it. The selection grows as the drag moves over other bytes.
+    pub fn begin_selection(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer begin selection") {
+            let Some(offset) = view_data.get_clamped_offset(address) else {
+                return;
+            };
+            let caret_address = view_data.base_address.saturating_add(offset as u64);
+
+            view_data.target_address = caret_address;
+            view_data.address_input = format!("{:X}", caret_address);
+            view_data.byte_edit = None;
+            view_data.selection_start_offset = Some(offset);
+            view_data.selection_end_offset = Some(offset);
+            view_data.is_selecting = true;
+        }
+    }
+
+    /// Moves the end of the selection being dragged to the given address, clamped to the loaded bytes.
+    pub fn extend_selection(
+        memory_viewer_view_data: Dependency<Self>,
+        address: u64,
+    ) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer extend selection") {
+            if !view_data.is_selecting {
+                return;
+            }
+
+            if let Some(offset) = view_data.get_clamped_offset(address) {
+                view_data.selection_end_offset = Some(offset);
+            }
+        }
+    }
+
+    pub fn end_selection(memory_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer end selection") {
+            view_data.is_selecting = false;
+        }
+    }
+
+    /// Gets the selected offsets into the loaded bytes, in ascending order and clamped to the bytes that are still loaded.
+    pub fn get_selection_range(&self) -> Option<RangeInclusive<usize>> {
+        let start_offset = self.selection_start_offset?;
+        let end_offset = self.selection_end_offset.unwrap_or(start_offset);
+        let last_offset = self.bytes.len().checked_sub(1)?;
+        let range_low = start_offset.min(end_offset);
+
+        if range_low > last_offset {
+            return None;
+        }
+
+        Some(range_low..=start_offset.max(end_offset).min(last_offset))
+    }
+
+    pub fn is_offset_selected(
+        &self,
+        offset: usize,
+    ) -> bool {
+        self.get_selection_range()
+            .is_some_and(|selection_range| selection_range.contains(&offset))
+    }
+
+    /// Formats the selected bytes for the clipboard, or returns `None` if nothing is selected.
+    pub fn format_selection(
+        &self,
+        byte_copy_format: ByteCopyFormat,
+    ) -> Option<String> {
+        let selection_range = self.get_selection_range()?;
+
+        Some(byte_copy_format.format(&self.bytes[selection_range]))
+    }
+
+    /// Gets the index into the loaded bytes of the given address, if it was loaded.
+    pub fn get_loaded_index(
+        &self,
+        address: u64,
+    ) -> Option<usize> {
+        let index = address.checked_sub(self.base_address)? as usize;
+
+        if index < self.bytes.len() { Some(index) } else { None }
+    }
+
+    /// Gets the index and length of the loaded row holding the given address. The last row is shorter if the read stopped early.
+    fn get_row_range(
+        &self,
+        address: u64,
+    ) -> Option<(usize, usize)> {
+        let index = self.get_loaded_index(address)?;
+        let row_index = index - index % self.bytes_per_row.max(1);
+        let row_length = self.bytes_per_row.min(self.bytes.len() - row_index);
+
+        Some((row_index, row_length))
+    }
+
+    /// Gets how many bytes from the address onwards lie within readable regions, up to the given length. Adjacent regions are followed,
+    /// such that a range spanning several regions is only cut short by a gap between them.
+    pub fn get_accessible_length(
+        &self,
+        address: u64,
+        length: usize,
+    ) -> usize {
+        let end_address = address.saturating_add(length as u64);
+        let mut current_address = address;
+
+        while current_address < end_address {
+            match self
+                .regions
+                .iter()
+                .find(|region| current_address >= region.base_address && current_address < region.base_address.saturating_add(region.region_size))
+            {
+                Some(region) => current_address = region.base_address.saturating_add(region.region_size),
+                None => break,
+            }
+        }
+
+        current_address.min(end_address).saturating_sub(address) as usize
+    }
+
+    /// Maps a horizontal offset into a row of the hex or ASCII pane to the column of the byte under it. Each hex byte is two
+    /// characters followed by a separator, which maps to the byte on its left. Offsets past the last byte map to no column.
+    pub fn get_column_at_offset(
+        x_offset: f32,
+        char_width: f32,
+        bytes_per_row: usize,
+        is_ascii: bool,
+    ) -> Option<usize> {
+        if x_offset < 0.0 || char_width <= 0.0 {
+            return None;
+        }
+
+        let char_index = (x_offset / char_width) as usize;
+        let column = if is_ascii { char_index } else { char_index / 3 };
+
+        if column < bytes_per_row { Some(column) } else { None }
+    }
+
+    /// Gets the address of the loaded byte at the given row and column, if that byte was read.
+    pub fn get_address_at(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> Option<u64> {
+        let index = row.checked_mul(self.bytes_per_row)?.checked_add(column)?;
+
+        if column < self.bytes_per_row && index < self.bytes.len() {
+            Some(self.base_address.saturating_add(index as u64))
+        } else {
+            None
+        }
+    }
+
+    /// Gets the address relative to the module containing it along with the module name, or the address itself if it is not in a module.
+    pub fn get_module_relative_address(
+        &self,
+        address: u64,
+    ) -> (u64, String) {
+        match self.regions.iter().find(|region| {
+            !region.module_name.is_empty() && address >= region.base_address && address < region.base_address.saturating_add(region.region_size)
+        }) {
+            Some(region) => {
+                let module_base = region.base_address.saturating_sub(region.module_offset);
+
+                (address.saturating_sub(module_base), region.module_name.clone())
+            }
+            None => (address, String::new()),
+        }
+    }
+
+    /// Gets how each loaded byte is highlighted by the current search.
+    pub fn get_search_highlights(&self) -> Vec<SearchHighlight> {
+        let mut search_highlights = vec![SearchHighlight::None; self.bytes.len()];
+        let current_match_address = self
+            .search_match_index
+            .and_then(|match_index| self.search_match_addresses.get(match_index))
+            .copied();
+
+        for match_address in &self.search_match_addresses {
+            let start = match_address.saturating_sub(self.base_address) as usize;
+            let end = start.saturating_add(self.search_pattern_length).min(search_highlights.len());
+            let search_highlight = if current_match_address == Some(*match_address) {
+                SearchHighlight::CurrentMatch
+            } else {
+                SearchHighlight::Match
+            };
+
+            for highlight in search_highlights.iter_mut().take(end).skip(start) {
+                // Overlapping matches must not hide the current match.
+                if *highlight != SearchHighlight::CurrentMatch {
+                    *highlight = search_highlight;
+                }
+            }
+        }
+
+        search_highlights
+    }
+
+    fn parse_search_pattern(&self) -> Result<BytePattern, String> {
+        if self.search_input.is_empty() {
+            return Err("Enter hex bytes or text to search for.".to_string());
+        }
+
+        if self.is_search_ascii {
+            Ok(BytePattern::from_ascii(&self.search_input))
+        } else {
+            BytePattern::parse_hex(&self.search_input)
+        }
+    }
+
+    fn clear_selection(&mut self) {
+        self.selection_start_offset = None;
+        self.selection_end_offset = None;
+        self.is_selecting = false;
+    }
+
+    /// Gets the offset into the loaded bytes of the given address, clamped to the first or last loaded byte.
+    fn get_clamped_offset(
+        &self,
+        address: u64,
+    ) -> Option<usize> {
+        let last_offset = self.bytes.len().checked_sub(1)?;
+
+        Some((address.saturating_sub(self.base_address) as usize).min(last_offset))
+    }
+
+    fn clear_search_results(&mut self) {
+        self.search_generation = self.search_generation.wrapping_add(1);
+        self.is_searching = false;
+        self.search_progress = 0.0;
+        self.search_error = None;
+        self.search_pattern_length = 0;
+        self.search_match_addresses.clear();
+        self.search_match_index = None;
+        self.is_search_scroll_pending = false;
+    }
+
+    fn clear_region_search_results(&mut self) {
+        self.region_search_generation = self.region_search_generation.wrapping_add(1);
+        self.is_region_search_pending = false;
+        self.region_search_matches.clear();
+        self.is_region_search_truncated = false;
+    }
+
+    fn select_search_match_index(
+        &mut self,
+        match_index: usize,
+    ) {
+        if let Some(match_address) = self.search_match_addresses.get(match_index).copied() {
+            self.search_match_index = Some(match_index);
+            self.target_address = match_address;
+            self.address_input = format!("{:X}", match_address);
+            self.is_search_scroll_pending = true;
+            self.clear_selection();
+        }
+    }
+
+    /// Searches the loaded bytes for the current search input, if a search is open.
+    fn start_local_search(memory_viewer_view_data: Dependency<Self>) {
+        let (byte_pattern, bytes, base_address, search_generation) = {
+            let mut view_data = match memory_viewer_view_data.write("Memory viewer start local search") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if !view_data.is_search_open {
+                return;
+            }
+
+            view_data.clear_search_results();
+
+            let byte_pattern = match view_data.parse_search_pattern() {
+                Ok(byte_pattern) => byte_pattern,
+                Err(_error) => return,
+            };
+
+            view_data.search_pattern_length = byte_pattern.len();
+
+            if view_data.bytes.len() <= Self::LOCAL_SEARCH_WORKER_THRESHOLD {
+                let match_addresses = Self::find_match_addresses(&byte_pattern, &view_data.bytes, view_data.base_address, 0..view_data.bytes.len());
+
+                view_data.complete_local_search(match_addresses);
+                return;
+            }
+
+            view_data.is_searching = true;
+
+            (byte_pattern, view_data.bytes.clone(), view_data.base_address, view_data.search_generation)
+        };
+
+        thread::spawn(move || {
+            let mut match_addresses = Vec::new();
+            let mut chunk_start = 0;
+
+            while chunk_start < bytes.len() {
+                let chunk_end = chunk_start.saturating_add(Self::LOCAL_SEARCH_CHUNK_SIZE).min(bytes.len());
+
+                match_addresses.extend(Self::find_match_addresses(&byte_pattern, &bytes, base_address, chunk_start..chunk_end));
+                chunk_start = chunk_end;
+
+                match memory_viewer_view_data.write("Memory viewer local search progress") {
+                    // Stop early if the search was superseded by another search or by new bytes being loaded.
+                    Some(view_data) if view_data.search_generation != search_generation => return,
+                    Some(mut view_data) => view_data.search_progress = chunk_end as f32 / bytes.len() as f32,
+                    None => return,
+                }
+            }
+
+            if let Some(mut view_data) = memory_viewer_view_data.write("Memory viewer local search response") {
+                if view_data.search_generation != search_generation {
+                    return;
+                }
+
+                view_data.complete_local_search(match_addresses);
+            }
+        });
+    }
+
+    fn find_match_addresses(
+        byte_pattern: &BytePattern,
+        bytes: &[u8],
+        base_address: u64,
+        offsets: std::ops::Range<usize>,
+    ) -> Vec<u64> {
+        offsets
+            .filter(|offset| byte_pattern.matches_at(bytes, *offset))
+            .map(|offset| base_address.saturating_add(offset as u64))
+            .collect()
+    }
+
+    fn complete_local_search(
+        &mut self,
+        match_addresses: Vec<u64>,
+    ) {
+        self.is_searching = false;
+        self.search_progress = 1.0;
+        self.search_match_addresses = match_addresses;
+
+        // Start from the match at the target address, ie after navigating to a match from the all regions search.
+        if let Some(match_index) = self
+            .search_match_addresses
+            .iter()
+            .position(|match_address| *match_address == self.target_address)
+        {
+            self.select_search_match_index(match_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ByteEdit, MemoryViewerViewData};
+    use crate::views::memory_viewer::view_data::byte_copy_format::ByteCopyFormat;
+    use squalr_engine_api::commands::memory::regions::memory_regions_response::MemoryRegionInfo;
+    use squalr_engine_api::structures::expressions::address_expression::AddressExpression;
+    use squalr_engine_api::structures::expressions::address_resolve_error::AddressResolveError;
+    use squalr_engine_api::structures::memory::bitness::Bitness;
+
+    #[test]
+    fn hex_pane_offsets_map_to_the_clicked_byte() {
+        let mut view_data = MemoryViewerViewData::new();
+
+        view_data.base_address = 0x1000;
+        view_data.bytes = vec![0; 40];
+
+        // Each hex byte spans "XX " at 8 pixels per character.
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(0.0, 8.0, 16, false), Some(0));
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(23.9, 8.0, 16, false), Some(0));
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(24.0, 8.0, 16, false), Some(1));
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(15.0 * 24.0 + 4.0, 8.0, 16, false), Some(15));
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(16.0 * 24.0, 8.0, 16, false), None);
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(5.0 * 8.0 + 1.0, 8.0, 16, true), Some(5));
+        assert_eq!(MemoryViewerViewData::get_column_at_offset(-1.0, 8.0, 16, true), None);
+
+        assert_eq!(view_data.get_address_at(1, 3), Some(0x1013));
+        assert_eq!(view_data.get_address_at(2, 7), Some(0x1027));
+        // Bytes past the end of the read, ie at the end of a region, have no address.
+        assert_eq!(view_data.get_address_at(2, 8), None);
+    }
+
+    #[test]
+    fn paste_length_stops_at_the_first_gap_between_regions() {
+        let mut view_data = MemoryViewerViewData::new();
+        let create_region = |base_address: u64, region_size: u64| MemoryRegionInfo {
+            base_address,
+            region_size,
+            module_name: String::new(),
+            module_offset: 0,
+        };
+
+        view_data.regions = vec![
+            create_region(0x1000, 0x1000),
+            create_region(0x2000, 0x1000),
+            create_region(0x4000, 0x1000),
+        ];
+
+        assert_eq!(view_data.get_accessible_length(0x1FF0, 0x20), 0x20);
+        assert_eq!(view_data.get_accessible_length(0x2FF0, 0x20), 0x10);
+        assert_eq!(view_data.get_accessible_length(0x3000, 0x20), 0);
+        assert_eq!(view_data.get_accessible_length(0x4FFF, 1), 1);
+    }
+
+    #[test]
+    fn byte_edits_complete_after_two_hex_digits() {
+        let mut byte_edit = ByteEdit::new(0x1000);
+
+        assert_eq!(byte_edit.push_hex_digit('g'), None);
+        assert_eq!(byte_edit.push_hex_digit('a'), None);
+        assert_eq!(byte_edit.get_pending_byte(), Some(0x0A));
+        assert_eq!(byte_edit.push_hex_digit('F'), Some(0xAF));
+        assert_eq!(byte_edit.pending_text, "AF");
+        assert_eq!(byte_edit.push_hex_digit('1'), None);
+        assert_eq!(ByteEdit::new(0x1000).get_pending_byte(), None);
+    }
+
+    #[test]
+    fn rows_are_cut_short_at_the_end_of_the_loaded_bytes() {
+        let mut view_data = MemoryViewerViewData::new();
+
+        view_data.base_address = 0x1000;
+        view_data.bytes = vec![0; 40];
+
+        assert_eq!(view_data.get_row_range(0x1013), Some((16, 16)));
+        assert_eq!(view_data.get_row_range(0x1027), Some((32, 8)));
+        assert_eq!(view_data.get_row_range(0x1028), None);
+        assert_eq!(view_data.get_loaded_index(0x0FFF), None);
+    }
+
+    #[test]
+    fn selections_are_ordered_and_clamped_to_the_loaded_bytes() {
+        let mut view_data = MemoryViewerViewData::new();
+
+        view_data.base_address = 0x1000;
+        view_data.bytes = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        view_data.selection_start_offset = view_data.get_clamped_offset(0x1002);
+        view_data.selection_end_offset = view_data.get_clamped_offset(0x0FF0);
+
+        assert_eq!(view_data.get_selection_range(), Some(0..=2));
+        assert_eq!(view_data.format_selection(ByteCopyFormat::SpacedHex).as_deref(), Some("DE AD BE"));
+
+        view_data.selection_end_offset = view_data.get_clamped_offset(0x2000);
+
+        assert_eq!(view_data.format_selection(ByteCopyFormat::HexString).as_deref(), Some("BEEF"));
+
+        // Once the selected bytes are no longer loaded, nothing remains selected.
+        view_data.bytes.truncate(2);
+
+        assert_eq!(view_data.get_selection_range(), None);
+        assert!(!view_data.is_offset_selected(0));
+    }
+    #[test]
+    fn address_errors_quote_the_offending_token() {
+        let describe = |address_input: &str| {
+            let error = AddressExpression::parse(address_input).unwrap_err();
+
+            MemoryViewerViewData::describe_address_error(address_input, &error)
+        };
+
+        assert_eq!(describe("0x12G4 + 8"), "Invalid hexadecimal number '0x12G4' at '0x12G4' (position 0)");
+        assert_eq!(describe("[game.exe+18"), "Unclosed '[' at '[' (position 0)");
+        assert_eq!(describe("game.exe + $10"), "Expected an address, module name, '(', or '[' at '$' (position 11)");
+        assert_eq!(describe("game.exe +"), "Unexpected end of expression at the end of 'game.exe +'");
+        assert_eq!(
+            MemoryViewerViewData::describe_address_error(
+                "[client.dll+10]",
+                &AddressResolveError::UnknownModule {
+                    module_name: "client.dll".to_string()
+                }
+            ),
+            "No module named 'client.dll' is loaded"
+        );
+    }
+    #[test]
+    fn pointers_are_read_at_the_process_width_and_must_land_in_a_region() {
+        let mut view_data = MemoryViewerViewData::new();
+
+        view_data.base_address = 0x1000;
+        view_data.bytes = vec![0x08, 0x20, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x10, 0x10];
+        view_data.regions = vec![MemoryRegionInfo {
+            base_address: 0x2000,
+            region_size: 0x1000,
+            module_name: String::new(),
+            module_offset: 0,
+        }];
+
+        assert_eq!(view_data.get_pointer_at(0x1000, Bitness::Bit32), Ok(0x2008));
+        assert_eq!(
+            view_data.get_pointer_at(0x1000, Bitness::Bit64),
+            Err("The value 100002008 at 1000 does not point into any memory region.".to_string())
+        );
+        assert_eq!(
+            view_data.get_pointer_at(0x1008, Bitness::Bit32),
+            Err("The 4 bytes of a pointer at 1008 are not loaded.".to_string())
+        );
+        assert!(view_data.get_pointer_at(0x0FFF, Bitness::Bit32).is_err());
+    }
+    #[test]
+    fn auto_refresh_marks_the_bytes_that_changed_since_the_last_read() {
+        let mut view_data = MemoryViewerViewData::new();
+
+        view_data.base_address = 0x1000;
+        view_data.bytes = vec![0x10, 0x20, 0x30];
+        view_data.apply_auto_refresh(0x1000, vec![0x10, 0x21, 0x30]);
+
+        assert_eq!(view_data.bytes, vec![0x10, 0x21, 0x30]);
+        assert!(!view_data.is_byte_changed(0));
+        assert!(view_data.is_byte_changed(1));
+
+        // Bytes stay marked for one refresh, until they are read again unchanged.
+        view_data.apply_auto_refresh(0x1000, vec![0x10, 0x21, 0x31]);
+
+        assert!(!view_data.is_byte_changed(1));
+        assert!(view_data.is_byte_changed(2));
+
+        // Reads made before navigating elsewhere are discarded.
+        view_data.apply_auto_refresh(0x2000, vec![0xFF, 0xFF, 0xFF]);
+
+        assert_eq!(view_data.bytes, vec![0x10, 0x21, 0x31]);
+        assert!(!view_data.is_byte_changed(3));
+    }
 }
@@ -1 +1,3 @@
+pub mod byte_copy_format;
+pub mod data_inspector;
 pub mod memory_viewer_view_data;
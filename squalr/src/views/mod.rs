@@ -1,6 +1,7 @@
 pub mod element_scanner;
 pub mod disassembler;
 pub mod main_window;
+pub mod memory_accessors;
 pub mod memory_viewer;
 pub mod output;
 pub mod pointer_scanner;
@@ -8,3 +9,4 @@ pub mod process_selector;
 pub mod project_explorer;
 pub mod settings;
 pub mod struct_viewer;
+pub mod watch_expressions;
@@ -1,6 +1,7 @@
 use crate::app_context::AppContext;
-use eframe::egui::{Align, Layout, Response, RichText, ScrollArea, TextStyle, Ui, UiBuilder, Widget};
-use epaint::Vec2;
+use crate::ui::line_selection::LineSelection;
+use eframe::egui::{Align, Align2, Id, Layout, Response, ScrollArea, Sense, TextStyle, Ui, UiBuilder, Widget};
+use epaint::{Vec2, pos2, vec2};
 use log::Level;
 use std::sync::Arc;
 
@@ -42,6 +43,14 @@ impl Widget for OutputView {
                     let mut inner_user_interface = user_interface.new_child(builder);
 
                     let row_height = inner_user_interface.text_style_height(&TextStyle::Body);
+                    let line_selection_id = Id::new("output_line_selection");
+                    let mut line_selection = inner_user_interface
+                        .ctx()
+                        .data(|data| data.get_temp::<LineSelection>(line_selection_id))
+                        .unwrap_or_default();
+                    let mut should_copy_selected_lines = false;
+
+                    line_selection.clamp_to_line_count(log_history.len());
 
                     ScrollArea::vertical()
                         .id_salt("output")
@@ -58,13 +67,52 @@ impl Widget for OutputView {
                                     Level::Trace => theme.background_control_success,
                                 };
 
-                                inner_user_interface.label(
-                                    RichText::new(&log_message.message)
-                                        .color(color)
-                                        .font(theme.font_library.font_noto_sans.font_normal.clone()),
+                                let (row_rectangle, row_response) =
+                                    inner_user_interface.allocate_exact_size(vec2(inner_user_interface.available_width(), row_height), Sense::click_and_drag());
+
+                                line_selection.handle_row_interaction(inner_user_interface, &row_response, row_index);
+
+                                if line_selection.is_selected(row_index) {
+                                    inner_user_interface
+                                        .painter()
+                                        .rect_filled(row_rectangle, 0.0, theme.background_control_primary_dark);
+                                }
+
+                                inner_user_interface.painter().text(
+                                    pos2(row_rectangle.min.x, row_rectangle.center().y),
+                                    Align2::LEFT_CENTER,
+                                    &log_message.message,
+                                    theme.font_library.font_noto_sans.font_normal.clone(),
+                                    color,
                                 );
+
+                                row_response.context_menu(|user_interface| {
+                                    if user_interface.button("Copy selected lines").clicked() {
+                                        should_copy_selected_lines = true;
+                                        user_interface.close();
+                                    }
+                                    if user_interface.button("Select all").clicked() {
+                                        line_selection.select_all(log_history.len());
+                                        user_interface.close();
+                                    }
+                                });
                             }
                         });
+
+                    if inner_user_interface.ui_contains_pointer() && LineSelection::is_copy_requested(&inner_user_interface) {
+                        should_copy_selected_lines = true;
+                    }
+
+                    if should_copy_selected_lines
+                        && let Some(copy_text) =
+                            line_selection.format_selected_lines(log_history.len(), |row_index| vec![log_history[row_index].message.clone()])
+                    {
+                        inner_user_interface.ctx().copy_text(copy_text);
+                    }
+
+                    inner_user_interface
+                        .ctx()
+                        .data_mut(|data| data.insert_temp(line_selection_id, line_selection));
                 }
             })
             .response;
@@ -1,11 +1,15 @@
 use crate::app_context::AppContext;
+use crate::models::appearance::appearance_settings::AppearanceSettings;
 use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::toolbar_layout::{ToolbarItem, ToolbarLayout};
+use crate::ui::widgets::controls::address_resolution_label::AddressResolutionLabel;
 use crate::ui::widgets::controls::button::Button;
 use crate::ui::widgets::controls::checkbox::Checkbox;
 use crate::ui::widgets::controls::data_type_selector::data_type_selector_view::DataTypeSelectorView;
 use crate::views::pointer_scanner::view_data::pointer_scanner_view_data::PointerScannerViewData;
-use eframe::egui::{Align, Direction, Layout, Response, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, Widget};
+use eframe::egui::{Align, Direction, Key, Layout, Popup, PopupCloseBehavior, Response, ScrollArea, Sense, Spinner, TextEdit, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, Rect, Stroke, StrokeKind, pos2, vec2};
+use squalr_engine_api::conversions::storage_size_conversions::StorageSizeConversions;
 use squalr_engine_api::dependency_injection::dependency::Dependency;
 use std::sync::Arc;
 
@@ -30,6 +34,25 @@ impl PointerScannerView {
             pointer_scanner_view_data,
         }
     }
+
+    /// Lays out the first, previous, page index, next, and last page controls of the footer, in that order.
+    pub fn layout_footer_items(
+        toolbar_layout: &ToolbarLayout,
+        footer_rect: Rect,
+    ) -> Vec<Rect> {
+        let navigation_button_width = 36.0;
+
+        toolbar_layout.layout_row(
+            footer_rect,
+            &[
+                ToolbarItem::new(navigation_button_width),
+                ToolbarItem::attached(navigation_button_width),
+                ToolbarItem::new(96.0),
+                ToolbarItem::new(navigation_button_width),
+                ToolbarItem::attached(navigation_button_width),
+            ],
+        )
+    }
 }
 
 impl Widget for PointerScannerView {
@@ -38,18 +61,29 @@ impl Widget for PointerScannerView {
         user_interface: &mut Ui,
     ) -> Response {
         let theme = &self.app_context.theme;
+        let toolbar_layout = ToolbarLayout::new(user_interface, &theme.font_library.font_noto_sans.font_normal);
+        let item_height = toolbar_layout.get_item_height();
+        let row_density = AppearanceSettings::get_row_density();
+        let row_font = row_density.get_font(&theme.font_library.font_ubuntu_mono_bold);
 
         let mut should_start_scan = false;
+        let mut should_start_rescan = false;
+        let mut should_resolve_target_address = false;
         let mut should_cancel_scan = false;
         let mut should_navigate_first_page = false;
         let mut should_navigate_previous_page = false;
         let mut should_navigate_next_page = false;
         let mut should_navigate_last_page = false;
         let mut pending_page_index_text: Option<String> = None;
+        let mut should_copy_selected_results = false;
+        let mut should_copy_page_as_formatted_text = false;
+        let mut should_add_selected_results_to_project = false;
+        let mut should_save_results = false;
+        let mut should_load_results = false;
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
-                let toolbar_height = 72.0;
+                let toolbar_height = toolbar_layout.get_toolbar_height(2);
                 let (toolbar_rect, _) =
                     user_interface.allocate_exact_size(vec2(user_interface.available_width(), toolbar_height), Sense::hover());
 
@@ -65,9 +99,10 @@ impl Widget for PointerScannerView {
                     None => return,
                 };
 
+                toolbar_ui.add_space(ToolbarLayout::PADDING);
                 toolbar_ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                    ui.add_sized(
-                        vec2(160.0, 28.0),
+                    let target_address_response = ui.add_sized(
+                        vec2(160.0, item_height),
                         TextEdit::singleline(&mut pointer_scanner_view_data.target_address)
                             .hint_text("Target address")
                             .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
@@ -75,6 +110,14 @@ impl Widget for PointerScannerView {
                             .background_color(theme.background_primary),
                     );
 
+                    if target_address_response.changed() {
+                        pointer_scanner_view_data.target_address_resolution = None;
+                    }
+
+                    if target_address_response.lost_focus() && ui.input(|input| input.key_pressed(Key::Enter)) {
+                        should_resolve_target_address = true;
+                    }
+
                     ui.add(DataTypeSelectorView::new(
                         self.app_context.clone(),
                         &mut pointer_scanner_view_data.pointer_data_type,
@@ -82,7 +125,7 @@ impl Widget for PointerScannerView {
                     ));
 
                     ui.add_sized(
-                        vec2(72.0, 28.0),
+                        vec2(72.0, item_height),
                         TextEdit::singleline(&mut pointer_scanner_view_data.max_depth_text)
                             .hint_text("Depth")
                             .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
@@ -90,14 +133,53 @@ impl Widget for PointerScannerView {
                             .background_color(theme.background_primary),
                     );
 
+                    // Offsets that do not parse are shown in red, and the scan refuses to start until they are fixed.
+                    let min_offset_color = if PointerScannerViewData::parse_offset_text(&pointer_scanner_view_data.min_offset_text).is_ok() {
+                        theme.foreground
+                    } else {
+                        theme.error_red
+                    };
+                    let max_offset_color = if PointerScannerViewData::parse_offset_text(&pointer_scanner_view_data.max_offset_text).is_ok() {
+                        theme.foreground
+                    } else {
+                        theme.error_red
+                    };
+                    let max_offsets_per_level_color =
+                        if PointerScannerViewData::parse_max_offsets_per_level_text(&pointer_scanner_view_data.max_offsets_per_level_text).is_ok() {
+                            theme.foreground
+                        } else {
+                            theme.error_red
+                        };
+
                     ui.add_sized(
-                        vec2(88.0, 28.0),
-                        TextEdit::singleline(&mut pointer_scanner_view_data.offset_size_text)
+                        vec2(72.0, item_height),
+                        TextEdit::singleline(&mut pointer_scanner_view_data.min_offset_text)
+                            .hint_text("Min offset")
+                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                            .text_color(min_offset_color)
+                            .background_color(theme.background_primary),
+                    )
+                    .on_hover_text("The smallest offset to follow. Negative offsets reach addresses below the pointer value. Accepts decimal or 0x hex.");
+
+                    ui.add_sized(
+                        vec2(72.0, item_height),
+                        TextEdit::singleline(&mut pointer_scanner_view_data.max_offset_text)
                             .hint_text("Max offset")
                             .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
-                            .text_color(theme.foreground)
+                            .text_color(max_offset_color)
                             .background_color(theme.background_primary),
-                    );
+                    )
+                    .on_hover_text("The largest offset to follow. Accepts decimal or 0x hex.");
+
+                    ui.add_sized(
+                        vec2(72.0, item_height),
+                        TextEdit::singleline(&mut pointer_scanner_view_data.max_offsets_per_level_text)
+                            .hint_text("Per level")
+                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                            .text_color(max_offsets_per_level_color)
+                            .background_color(theme.background_primary),
+                    )
+                    .on_hover_text("The most distinct offsets to follow from each address, keeping those closest to zero. Leave empty for no limit.");
 
                     if ui
                         .add(Checkbox::new_from_theme(theme).with_check_state_bool(pointer_scanner_view_data.scan_statics))
@@ -117,9 +199,74 @@ impl Widget for PointerScannerView {
 
                     ui.label("Heaps");
 
-                    if pointer_scanner_view_data.is_scanning {
+                    let advanced_options_button = ui.add_sized(
+                        vec2(36.0, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Pointer validity options."),
+                    );
+
+                    IconDraw::draw(ui, advanced_options_button.rect, &theme.icon_library.icon_handle_common_properties);
+
+                    Popup::menu(&advanced_options_button)
+                        .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                        .show(|ui| {
+                            ui.label("Pointer alignment");
+                            ui.horizontal(|ui| {
+                                for (pointer_alignment, label) in [(None, "Pointer size"), (Some(8), "8"), (Some(4), "4")] {
+                                    if ui
+                                        .selectable_label(pointer_scanner_view_data.pointer_alignment == pointer_alignment, label)
+                                        .on_hover_text("Only consider pointers stored at addresses that are a multiple of this alignment.")
+                                        .clicked()
+                                    {
+                                        pointer_scanner_view_data.pointer_alignment = pointer_alignment;
+                                    }
+                                }
+                            });
+
+                            ui.separator();
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(pointer_scanner_view_data.require_mapped_targets))
+                                    .clicked()
+                                {
+                                    pointer_scanner_view_data.require_mapped_targets = !pointer_scanner_view_data.require_mapped_targets;
+                                }
+
+                                ui.label("Require targets in mapped memory");
+                            });
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(pointer_scanner_view_data.require_aligned_targets))
+                                    .clicked()
+                                {
+                                    pointer_scanner_view_data.require_aligned_targets = !pointer_scanner_view_data.require_aligned_targets;
+                                }
+
+                                ui.label("Require aligned targets");
+                            });
+
+                            ui.separator();
+                            ui.horizontal(|ui| {
+                                ui.add_sized(
+                                    vec2(88.0, item_height),
+                                    TextEdit::singleline(&mut pointer_scanner_view_data.max_memory_mb_text)
+                                        .hint_text("MB")
+                                        .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                        .text_color(theme.foreground)
+                                        .background_color(theme.background_primary),
+                                )
+                                .on_hover_text(
+                                    "Once the pointer map and levels use this much memory, the scan stops deepening and keeps the results found so far.",
+                                );
+
+                                ui.label("Memory cap (MB)");
+                            });
+                        });
+
+                    if pointer_scanner_view_data.is_scanning || pointer_scanner_view_data.is_rescanning {
                         let stop_button = ui.add_sized(
-                            vec2(88.0, 28.0),
+                            vec2(88.0, item_height),
                             Button::new_from_theme(theme)
                                 .background_color(Color32::TRANSPARENT)
                                 .with_tooltip_text("Cancel pointer scan"),
@@ -130,7 +277,7 @@ impl Widget for PointerScannerView {
                         }
                     } else {
                         let start_button = ui.add_sized(
-                            vec2(88.0, 28.0),
+                            vec2(88.0, item_height),
                             Button::new_from_theme(theme)
                                 .background_color(Color32::TRANSPARENT)
                                 .with_tooltip_text("Start pointer scan"),
@@ -142,12 +289,102 @@ impl Widget for PointerScannerView {
                     }
                 });
 
-                toolbar_ui.add_space(4.0);
+                toolbar_ui.add_space(ToolbarLayout::PADDING);
 
                 toolbar_ui.with_layout(Layout::left_to_right(Align::Center), |ui| {
-                    if pointer_scanner_view_data.is_scanning {
+                    let rescan_target_address_response = ui.add_sized(
+                        vec2(160.0, item_height),
+                        TextEdit::singleline(&mut pointer_scanner_view_data.rescan_target_address)
+                            .hint_text("New target address")
+                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                            .text_color(theme.hexadecimal_green)
+                            .background_color(theme.background_primary),
+                    );
+
+                    if rescan_target_address_response.changed() {
+                        pointer_scanner_view_data.rescan_target_address_resolution = None;
+                    }
+
+                    // Rescanning narrows the current results, so there must be results and no scan replacing them.
+                    let can_rescan =
+                        pointer_scanner_view_data.result_count > 0 && !pointer_scanner_view_data.is_scanning && !pointer_scanner_view_data.is_rescanning;
+                    let rescan_button = ui.add_sized(
+                        vec2(36.0, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .disabled(!can_rescan)
+                            .with_tooltip_text("Rescan results against the new address"),
+                    );
+
+                    IconDraw::draw(ui, rescan_button.rect, &theme.icon_library.icon_handle_navigation_refresh);
+
+                    if rescan_button.clicked() || (can_rescan && rescan_target_address_response.lost_focus() && ui.input(|input| input.key_pressed(Key::Enter)))
+                    {
+                        should_start_rescan = true;
+                    }
+
+                    if let Some(rescan_target_address_resolution) = &pointer_scanner_view_data.rescan_target_address_resolution {
+                        ui.add(AddressResolutionLabel::new(theme, rescan_target_address_resolution));
+                    }
+
+                    ui.add_space(8.0);
+                    ui.add_sized(
+                        vec2(160.0, item_height),
+                        TextEdit::singleline(&mut pointer_scanner_view_data.results_file_path)
+                            .hint_text("Results file (.ptr)")
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .text_color(theme.foreground)
+                            .background_color(theme.background_primary),
+                    );
+
+                    let has_results_file_path = !pointer_scanner_view_data.results_file_path.trim().is_empty();
+                    let is_busy =
+                        pointer_scanner_view_data.is_scanning || pointer_scanner_view_data.is_rescanning || pointer_scanner_view_data.is_transferring_results;
+                    let save_results_button = ui.add_sized(
+                        vec2(36.0, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .disabled(!has_results_file_path || is_busy || pointer_scanner_view_data.result_count == 0)
+                            .with_tooltip_text("Save results..."),
+                    );
+
+                    IconDraw::draw(ui, save_results_button.rect, &theme.icon_library.icon_handle_file_system_save);
+
+                    if save_results_button.clicked() {
+                        should_save_results = true;
+                    }
+
+                    let load_results_button = ui.add_sized(
+                        vec2(36.0, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .disabled(!has_results_file_path || is_busy)
+                            .with_tooltip_text("Load results..."),
+                    );
+
+                    IconDraw::draw(ui, load_results_button.rect, &theme.icon_library.icon_handle_file_system_open_folder);
+
+                    if load_results_button.clicked() {
+                        should_load_results = true;
+                    }
+
+                    ui.add_space(8.0);
+
+                    if let Some(target_address_resolution) = &pointer_scanner_view_data.target_address_resolution {
+                        ui.add(AddressResolutionLabel::new(theme, target_address_resolution));
+                        ui.add_space(8.0);
+                    }
+
+                    if pointer_scanner_view_data.is_scanning || pointer_scanner_view_data.is_rescanning {
                         ui.add(Spinner::new().color(theme.foreground));
-                        ui.label(format!("Progress: {:.0}%", pointer_scanner_view_data.progress * 100.0));
+                        match pointer_scanner_view_data.memory_usage_bytes {
+                            Some(memory_usage_bytes) => ui.label(format!(
+                                "Progress: {:.0}% · {} used",
+                                pointer_scanner_view_data.progress * 100.0,
+                                StorageSizeConversions::value_to_metric_size(memory_usage_bytes as u128)
+                            )),
+                            None => ui.label(format!("Progress: {:.0}%", pointer_scanner_view_data.progress * 100.0)),
+                        };
                     } else {
                         ui.label(&pointer_scanner_view_data.stats_string);
                     }
@@ -157,7 +394,7 @@ impl Widget for PointerScannerView {
 
                 user_interface.add_space(4.0);
 
-                let footer_height = 48.0;
+                let footer_height = toolbar_layout.get_toolbar_height(1);
                 let list_height = (user_interface.available_height() - footer_height).max(32.0);
 
                 let mut selection_start: Option<i32> = None;
@@ -211,7 +448,7 @@ impl Widget for PointerScannerView {
                                 (None, None) => false,
                             };
 
-                            let row_height = 28.0;
+                            let row_height = row_density.get_row_height();
                             let (row_rect, row_response) =
                                 user_interface.allocate_exact_size(vec2(user_interface.available_width(), row_height), Sense::click());
 
@@ -230,18 +467,42 @@ impl Widget for PointerScannerView {
                                 }
                             }
 
-                            let base = if result.is_module() {
-                                format!("{}+{:X}", result.get_module_name(), result.get_module_offset())
-                            } else {
-                                format!("{:016X}", result.get_base_address())
-                            };
+                            // Double clicking a row within a multi-row selection adds the whole selection, rather than only that row.
+                            if row_response.double_clicked() {
+                                if !is_selected {
+                                    selection_start = Some(index as i32);
+                                    selection_end = None;
+                                }
 
-                            let offsets = result
-                                .get_offsets()
-                                .iter()
-                                .map(|offset| format!("{:X}", offset))
-                                .collect::<Vec<_>>()
-                                .join(", ");
+                                should_add_selected_results_to_project = true;
+                            }
+
+                            // Opening the context menu on an unselected row selects it, such that the menu acts on the row it was opened on.
+                            if row_response.secondary_clicked() && !is_selected {
+                                selection_start = Some(index as i32);
+                                selection_end = None;
+                            }
+
+                            row_response.context_menu(|ui| {
+                                if ui.button("Add to project").clicked() {
+                                    should_add_selected_results_to_project = true;
+                                    ui.close();
+                                }
+
+                                ui.separator();
+
+                                if ui.button("Copy selected").clicked() {
+                                    should_copy_selected_results = true;
+                                    ui.close();
+                                }
+
+                                if ui.button("Copy page as formatted text").clicked() {
+                                    should_copy_page_as_formatted_text = true;
+                                    ui.close();
+                                }
+                            });
+
+                            let [base, offsets] = PointerScannerViewData::get_result_cells(result);
 
                             let base_pos = pos2(row_rect.min.x + 8.0, row_rect.center().y);
                             let offsets_pos = pos2(row_rect.min.x + 220.0, row_rect.center().y);
@@ -250,15 +511,15 @@ impl Widget for PointerScannerView {
                                 base_pos,
                                 eframe::egui::Align2::LEFT_CENTER,
                                 base,
-                                theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+                                row_font.clone(),
                                 theme.hexadecimal_green,
                             );
 
                             user_interface.painter().text(
                                 offsets_pos,
                                 eframe::egui::Align2::LEFT_CENTER,
-                                format!("[{}]", offsets),
-                                theme.font_library.font_ubuntu_mono_bold.font_normal.clone(),
+                                offsets,
+                                row_font.clone(),
                                 theme.foreground,
                             );
                         }
@@ -293,10 +554,9 @@ impl Widget for PointerScannerView {
                     None => (0, 0, String::new()),
                 };
 
-                let button_size = vec2(36.0, 28.0);
-                let y_center = footer_rect.center().y - button_size.y * 0.5;
+                let footer_item_rects = Self::layout_footer_items(&toolbar_layout, footer_rect);
 
-                let first_rect = Rect::from_min_size(pos2(footer_rect.min.x + 8.0, y_center), button_size);
+                let first_rect = footer_item_rects[0];
                 let first_button = footer_ui.put(
                     first_rect,
                     Button::new_from_theme(theme)
@@ -308,7 +568,7 @@ impl Widget for PointerScannerView {
                     should_navigate_first_page = true;
                 }
 
-                let prev_rect = Rect::from_min_size(pos2(first_rect.max.x, y_center), button_size);
+                let prev_rect = footer_item_rects[1];
                 let prev_button = footer_ui.put(
                     prev_rect,
                     Button::new_from_theme(theme)
@@ -321,7 +581,7 @@ impl Widget for PointerScannerView {
                 }
 
                 let mut page_text = (current_page_index + 1).to_string();
-                let page_rect = Rect::from_min_size(pos2(prev_rect.max.x + 8.0, y_center + 2.0), vec2(96.0, 24.0));
+                let page_rect = footer_item_rects[2];
                 let page_edit = footer_ui.put(
                     page_rect,
                     TextEdit::singleline(&mut page_text)
@@ -340,7 +600,7 @@ impl Widget for PointerScannerView {
                     pending_page_index_text = Some(page_text);
                 }
 
-                let next_rect = Rect::from_min_size(pos2(page_rect.max.x + 8.0, y_center), button_size);
+                let next_rect = footer_item_rects[3];
                 let next_button = footer_ui.put(
                     next_rect,
                     Button::new_from_theme(theme)
@@ -352,7 +612,7 @@ impl Widget for PointerScannerView {
                     should_navigate_next_page = true;
                 }
 
-                let last_rect = Rect::from_min_size(pos2(next_rect.max.x, y_center), button_size);
+                let last_rect = footer_item_rects[4];
                 let last_button = footer_ui.put(
                     last_rect,
                     Button::new_from_theme(theme)
@@ -374,10 +634,42 @@ impl Widget for PointerScannerView {
             })
             .response;
 
+        let copy_text = if should_copy_selected_results {
+            PointerScannerViewData::copy_selected_results(self.pointer_scanner_view_data.clone())
+        } else if should_copy_page_as_formatted_text {
+            PointerScannerViewData::copy_page_as_formatted_text(self.pointer_scanner_view_data.clone())
+        } else {
+            String::new()
+        };
+
+        if !copy_text.is_empty() {
+            user_interface.ctx().copy_text(copy_text);
+        }
+
         if should_start_scan {
             PointerScannerViewData::start_scan(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
         }
 
+        if should_add_selected_results_to_project {
+            PointerScannerViewData::add_selected_results_to_project(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if should_start_rescan {
+            PointerScannerViewData::start_rescan(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if should_save_results {
+            PointerScannerViewData::save_results(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if should_load_results {
+            PointerScannerViewData::load_results(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
+        if should_resolve_target_address {
+            PointerScannerViewData::resolve_target_address(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+
         if should_cancel_scan {
             PointerScannerViewData::cancel_scan(self.pointer_scanner_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
         }
@@ -1,28 +1,54 @@
+use crate::models::address_resolution::address_resolver::{AddressResolution, AddressResolver};
+use crate::ui::text_table::TextTable;
+use squalr_engine_api::commands::pointer_scan_results::add_to_project::pointer_scan_results_add_to_project_request::PointerScanResultsAddToProjectRequest;
+use squalr_engine_api::commands::pointer_scan_results::load::pointer_scan_results_load_request::PointerScanResultsLoadRequest;
 use squalr_engine_api::commands::pointer_scan_results::query::pointer_scan_results_query_request::PointerScanResultsQueryRequest;
+use squalr_engine_api::commands::pointer_scan_results::save::pointer_scan_results_save_request::PointerScanResultsSaveRequest;
 use squalr_engine_api::commands::scan::pointer_scan::pointer_scan_request::PointerScanRequest;
+use squalr_engine_api::commands::scan::pointer_scan_rescan::pointer_scan_rescan_request::PointerScanRescanRequest;
 use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
 use squalr_engine_api::commands::trackable_tasks::cancel::trackable_tasks_cancel_request::TrackableTasksCancelRequest;
 use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_event_listeners::EngineEventSubscription;
 use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::engine::protocol::engine_capabilities::EngineCapabilities;
 use squalr_engine_api::events::pointer_scan_results::updated::pointer_scan_results_updated_event::PointerScanResultsUpdatedEvent;
 use squalr_engine_api::events::trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent;
+use squalr_engine_api::structures::data_types::built_in_types::i32::data_type_i32::DataTypeI32;
 use squalr_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
 use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
 use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
 use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
 use squalr_engine_api::structures::data_values::container_type::ContainerType;
 use squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+use squalr_engine_api::structures::pointer_scan::pointer_scan_results_file::PointerScanResultsFile;
+use std::num::IntErrorKind;
 use std::ops::RangeInclusive;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct PointerScannerViewData {
     pub target_address: String,
+    pub target_address_resolution: Option<AddressResolution>,
+    /// The address the current results are filtered against by a rescan, ie where the target lives after restarting the game.
+    pub rescan_target_address: String,
+    pub rescan_target_address_resolution: Option<AddressResolution>,
     pub pointer_data_type: DataTypeRef,
     pub max_depth_text: String,
-    pub offset_size_text: String,
+    /// The smallest offset followed from a pointer value, which may be negative to reach addresses below it.
+    pub min_offset_text: String,
+    pub max_offset_text: String,
+    /// The most distinct offsets followed from each address on a level, or empty to follow every offset in range.
+    pub max_offsets_per_level_text: String,
+    /// The most memory, in megabytes, that the pointer map and its levels may use before the scan stops deepening.
+    pub max_memory_mb_text: String,
     pub scan_statics: bool,
     pub scan_heaps: bool,
+    /// The alignment that pointer storage addresses must have, or `None` to use the pointer size of the target.
+    pub pointer_alignment: Option<u64>,
+    pub require_mapped_targets: bool,
+    pub require_aligned_targets: bool,
     pub current_results: Vec<PointerScanResult>,
     pub current_page_index: u64,
     pub last_page_index: u64,
@@ -31,21 +57,45 @@ pub struct PointerScannerViewData {
     pub stats_string: String,
     pub is_querying_results: bool,
     pub is_scanning: bool,
+    /// Whether the current results are being filtered against the rescan target address.
+    pub is_rescanning: bool,
+    /// The `.ptr` file that results are saved to and loaded from.
+    pub results_file_path: String,
+    /// Whether the results are being saved to or loaded from a file, during which neither can start again.
+    pub is_transferring_results: bool,
+    /// The progress of the running scan, from 0 to 1.
     pub progress: f32,
+    /// The memory held by the running scan, as last reported by the engine.
+    pub memory_usage_bytes: Option<u64>,
     pub current_task_id: Option<String>,
     pub selection_index_start: Option<i32>,
     pub selection_index_end: Option<i32>,
+    /// Keeps the engine event listeners for scan progress and results registered for as long as this view data lives.
+    pub event_subscriptions: Vec<EngineEventSubscription>,
 }
 
 impl PointerScannerViewData {
+    /// The largest offset magnitude accepted in either direction. Larger offsets are clamped, as they match nearly every pointer.
+    const MAX_OFFSET_MAGNITUDE: i64 = 0x10_0000;
+    const MAX_OFFSETS_PER_LEVEL: u64 = 0x1000;
+
     pub fn new() -> Self {
         Self {
             target_address: String::new(),
+            target_address_resolution: None,
+            rescan_target_address: String::new(),
+            rescan_target_address_resolution: None,
             pointer_data_type: DataTypeRef::new(DataTypeU64::get_data_type_id()),
             max_depth_text: "3".to_string(),
-            offset_size_text: "512".to_string(),
+            min_offset_text: "0".to_string(),
+            max_offset_text: "512".to_string(),
+            max_offsets_per_level_text: String::new(),
+            max_memory_mb_text: "4000".to_string(),
             scan_statics: true,
             scan_heaps: true,
+            pointer_alignment: None,
+            require_mapped_targets: true,
+            require_aligned_targets: false,
             current_results: Vec::new(),
             current_page_index: 0,
             last_page_index: 0,
@@ -54,10 +104,15 @@ impl PointerScannerViewData {
             stats_string: String::new(),
             is_querying_results: false,
             is_scanning: false,
+            is_rescanning: false,
+            results_file_path: format!("pointer_scan_results.{}", PointerScanResultsFile::FILE_EXTENSION),
+            is_transferring_results: false,
             progress: 0.0,
+            memory_usage_bytes: None,
             current_task_id: None,
             selection_index_start: None,
             selection_index_end: None,
+            event_subscriptions: Vec::new(),
         }
     }
 
@@ -67,47 +122,108 @@ impl PointerScannerViewData {
     ) {
         let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
         let pointer_scanner_view_data_clone = pointer_scanner_view_data.clone();
+        let pointer_scan_results_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<PointerScanResultsUpdatedEvent>(
+            |_event| true,
+            move |_event| {
+                if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan results updated") {
+                    view_data.is_scanning = false;
+                    view_data.is_rescanning = false;
+                }
 
-        engine_unprivileged_state.listen_for_engine_event::<PointerScanResultsUpdatedEvent>(move |_event| {
-            Self::query_results(pointer_scanner_view_data_clone.clone(), engine_unprivileged_state_clone.clone());
-        });
+                Self::query_results(pointer_scanner_view_data_clone.clone(), engine_unprivileged_state_clone.clone());
+            },
+        );
 
-        let _engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+        let pointer_scanner_view_data_filter = pointer_scanner_view_data.clone();
         let pointer_scanner_view_data_clone = pointer_scanner_view_data.clone();
-
-        engine_unprivileged_state.listen_for_engine_event::<TrackableTaskProgressChangedEvent>(move |event| {
-            if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan progress event") {
-                let should_update = view_data
-                    .current_task_id
-                    .as_ref()
-                    .map(|task_id| task_id == &event.task_id)
-                    .unwrap_or(false);
-
-                if should_update {
-                    view_data.progress = event.progress;
-                    if event.progress >= 1.0 {
-                        view_data.is_scanning = false;
-                    }
+        let progress_subscription = engine_unprivileged_state.listen_for_engine_event_filtered::<TrackableTaskProgressChangedEvent>(
+            move |event| {
+                pointer_scanner_view_data_filter
+                    .read("Pointer scan progress filter")
+                    .is_some_and(|view_data| view_data.current_task_id.as_ref() == Some(&event.task_id))
+            },
+            move |event| {
+                // Task progress is reported from 0 to 100. The scan is only finished once its results are updated.
+                if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan progress event") {
+                    view_data.progress = event.progress / 100.0;
+                    view_data.memory_usage_bytes = event.memory_usage_bytes.or(view_data.memory_usage_bytes);
                 }
-            }
-        });
+            },
+        );
+
+        // Replacing any previous subscriptions drops them, such that polling again does not duplicate listeners.
+        if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner subscribe") {
+            view_data.event_subscriptions = vec![pointer_scan_results_subscription, progress_subscription];
+        }
     }
 
     pub fn start_scan(
         pointer_scanner_view_data: Dependency<Self>,
         engine_unprivileged_state: Arc<EngineUnprivilegedState>,
     ) {
-        let (target_address, pointer_data_type, max_depth, offset_size, scan_statics, scan_heaps) = {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::POINTER_SCAN) {
+            if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner start scan unsupported") {
+                view_data.stats_string = "Pointer scans are not supported by the connected engine.".to_string();
+            }
+
+            return;
+        }
+
+        let (
+            target_address,
+            pointer_data_type,
+            max_depth,
+            min_offset,
+            max_offset,
+            max_offsets_per_level,
+            max_memory_bytes,
+            scan_statics,
+            scan_heaps,
+            pointer_alignment,
+            require_mapped_targets,
+            require_aligned_targets,
+        ) = {
             let mut view_data = match pointer_scanner_view_data.write("Pointer scanner start scan") {
                 Some(view_data) => view_data,
                 None => return,
             };
 
+            let offset_options = Self::parse_offset_text(&view_data.min_offset_text).and_then(|min_offset| {
+                let max_offset = Self::parse_offset_text(&view_data.max_offset_text)?;
+                let max_offsets_per_level = Self::parse_max_offsets_per_level_text(&view_data.max_offsets_per_level_text)?;
+
+                if min_offset > max_offset {
+                    return Err("Min offset must not be greater than max offset.".to_string());
+                }
+
+                Ok((min_offset, max_offset, max_offsets_per_level))
+            });
+            let (min_offset, max_offset, max_offsets_per_level) = match offset_options {
+                Ok(offset_options) => offset_options,
+                Err(error) => {
+                    view_data.stats_string = error;
+                    return;
+                }
+            };
+
+            // Clamped values are written back, such that the toolbar shows what the scan actually uses.
+            view_data.min_offset_text = min_offset.to_string();
+            view_data.max_offset_text = max_offset.to_string();
+            view_data.max_offsets_per_level_text = max_offsets_per_level
+                .map(|max_offsets_per_level| max_offsets_per_level.to_string())
+                .unwrap_or_default();
+
             let max_depth = view_data.max_depth_text.parse::<u64>().unwrap_or(3);
-            let offset_size = view_data.offset_size_text.parse::<u64>().unwrap_or(512);
+            let max_memory_bytes = view_data
+                .max_memory_mb_text
+                .trim()
+                .parse::<u64>()
+                .ok()
+                .map(|max_memory_mb| max_memory_mb.saturating_mul(1_000_000));
 
             view_data.is_scanning = true;
             view_data.progress = 0.0;
+            view_data.memory_usage_bytes = None;
             view_data.current_results.clear();
             view_data.current_page_index = 0;
             view_data.last_page_index = 0;
@@ -120,34 +236,247 @@ impl PointerScannerViewData {
                 view_data.target_address.clone(),
                 view_data.pointer_data_type.clone(),
                 max_depth,
-                offset_size,
+                min_offset,
+                max_offset,
+                max_offsets_per_level,
+                max_memory_bytes,
                 view_data.scan_statics,
                 view_data.scan_heaps,
+                view_data.pointer_alignment,
+                view_data.require_mapped_targets,
+                view_data.require_aligned_targets,
             )
         };
 
-        let format = if target_address.trim_start().starts_with("0x") {
-            AnonymousValueStringFormat::Hexadecimal
-        } else {
-            AnonymousValueStringFormat::Decimal
-        };
+        let pointer_scanner_view_data_clone = pointer_scanner_view_data.clone();
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
 
-        let pointer_scan_request = PointerScanRequest {
-            target_address: AnonymousValueString::new(target_address, format, ContainerType::None),
-            pointer_data_type_ref: pointer_data_type,
-            max_depth,
-            offset_size,
-            scan_statics,
-            scan_heaps,
+        AddressResolver::resolve(&engine_unprivileged_state, &target_address, move |address_resolution| {
+            let resolved_target_address = address_resolution
+                .as_ref()
+                .ok()
+                .map(|resolved_address| resolved_address.address);
+
+            if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scanner target resolved") {
+                view_data.target_address_resolution = Some(address_resolution);
+
+                if resolved_target_address.is_none() {
+                    view_data.is_scanning = false;
+                }
+            }
+
+            let Some(resolved_target_address) = resolved_target_address else {
+                return;
+            };
+
+            // The target is resolved here, so the engine only ever receives a plain address.
+            let pointer_scan_request = PointerScanRequest {
+                target_address: AnonymousValueString::new(
+                    resolved_target_address.to_string(),
+                    AnonymousValueStringFormat::Decimal,
+                    ContainerType::None,
+                ),
+                pointer_data_type_ref: pointer_data_type,
+                max_depth,
+                offset_size: max_offset.max(0) as u64,
+                min_offset: Some(min_offset),
+                max_offset: Some(max_offset),
+                max_offsets_per_level,
+                scan_statics,
+                scan_heaps,
+                pointer_alignment,
+                allow_unmapped_targets: !require_mapped_targets,
+                require_aligned_targets,
+                max_memory_bytes,
+            };
+
+            pointer_scan_request.send(&engine_unprivileged_state_clone, move |response| {
+                if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan start response") {
+                    view_data.current_task_id = response
+                        .trackable_task_handle
+                        .as_ref()
+                        .map(|handle| handle.task_identifier.clone());
+                }
+            });
+        });
+    }
+
+    /// Filters the current results against the rescan target address, keeping only the pointer paths that still lead to it. The
+    /// results are replaced in place once the rescan completes.
+    pub fn start_rescan(
+        pointer_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        if !engine_unprivileged_state.has_capabilities(EngineCapabilities::POINTER_SCAN) {
+            if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner start rescan unsupported") {
+                view_data.stats_string = "Pointer scans are not supported by the connected engine.".to_string();
+            }
+
+            return;
+        }
+
+        let (rescan_target_address, pointer_data_type) = {
+            let mut view_data = match pointer_scanner_view_data.write("Pointer scanner start rescan") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if view_data.is_scanning || view_data.is_rescanning || view_data.result_count == 0 {
+                return;
+            }
+
+            view_data.is_rescanning = true;
+            view_data.progress = 0.0;
+            view_data.memory_usage_bytes = None;
+            view_data.selection_index_start = None;
+            view_data.selection_index_end = None;
+
+            (view_data.rescan_target_address.clone(), view_data.pointer_data_type.clone())
         };
 
         let pointer_scanner_view_data_clone = pointer_scanner_view_data.clone();
-        pointer_scan_request.send(&engine_unprivileged_state, move |response| {
-            if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan start response") {
-                view_data.current_task_id = response
-                    .trackable_task_handle
-                    .as_ref()
-                    .map(|handle| handle.task_identifier.clone());
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        AddressResolver::resolve(&engine_unprivileged_state, &rescan_target_address, move |address_resolution| {
+            let resolved_target_address = address_resolution
+                .as_ref()
+                .ok()
+                .map(|resolved_address| resolved_address.address);
+
+            if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scanner rescan target resolved") {
+                view_data.rescan_target_address_resolution = Some(address_resolution);
+
+                if resolved_target_address.is_none() {
+                    view_data.is_rescanning = false;
+                }
+            }
+
+            let Some(resolved_target_address) = resolved_target_address else {
+                return;
+            };
+
+            let pointer_scan_rescan_request = PointerScanRescanRequest {
+                target_address: AnonymousValueString::new(resolved_target_address.to_string(), AnonymousValueStringFormat::Decimal, ContainerType::None),
+                pointer_data_type_ref: pointer_data_type,
+            };
+
+            pointer_scan_rescan_request.send(&engine_unprivileged_state_clone, move |response| {
+                if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan rescan response") {
+                    view_data.current_task_id = response
+                        .trackable_task_handle
+                        .as_ref()
+                        .map(|handle| handle.task_identifier.clone());
+
+                    if view_data.current_task_id.is_none() {
+                        view_data.is_rescanning = false;
+                    }
+                }
+            });
+        });
+    }
+
+    /// Resolves the target address expression on its own, such that it can be checked before starting a scan.
+    /// Saves every result, not just the current page, to the results file.
+    pub fn save_results(
+        pointer_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let file_path = {
+            let mut view_data = match pointer_scanner_view_data.write("Pointer scanner save results") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if view_data.is_transferring_results || view_data.result_count == 0 {
+                return;
+            }
+
+            view_data.is_transferring_results = true;
+            view_data.results_file_path.trim().to_string()
+        };
+
+        let pointer_scan_results_save_request = PointerScanResultsSaveRequest {
+            file_path: PathBuf::from(&file_path),
+        };
+
+        pointer_scan_results_save_request.send(&engine_unprivileged_state, move |response| {
+            if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner save results response") {
+                view_data.is_transferring_results = false;
+                view_data.stats_string = match response.saved_result_count {
+                    Ok(saved_result_count) => format!("Saved {} results to {}", saved_result_count, file_path),
+                    Err(error) => error,
+                };
+            }
+        });
+    }
+
+    /// Replaces the results with those saved in the results file. Once loaded, the engine reports the results as updated, which
+    /// queries the first page. Files that do not fit the opened process are rejected, with the reason shown in place of the stats.
+    pub fn load_results(
+        pointer_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (file_path, previous_page_index) = {
+            let mut view_data = match pointer_scanner_view_data.write("Pointer scanner load results") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+
+            if view_data.is_transferring_results || view_data.is_scanning || view_data.is_rescanning {
+                return;
+            }
+
+            let previous_page_index = view_data.current_page_index;
+
+            // Set before sending, as the update event that queries the loaded results may arrive before the response.
+            view_data.current_page_index = 0;
+            view_data.is_transferring_results = true;
+
+            (view_data.results_file_path.trim().to_string(), previous_page_index)
+        };
+
+        let pointer_scan_results_load_request = PointerScanResultsLoadRequest {
+            file_path: PathBuf::from(file_path),
+        };
+
+        pointer_scan_results_load_request.send(&engine_unprivileged_state, move |response| {
+            if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner load results response") {
+                view_data.is_transferring_results = false;
+
+                match response.loaded_result_count {
+                    Ok(_loaded_result_count) => {
+                        view_data.selection_index_start = None;
+                        view_data.selection_index_end = None;
+                    }
+                    // The current results are left in place, so the page they were shown at is kept too.
+                    Err(error) => {
+                        view_data.current_page_index = previous_page_index;
+                        view_data.stats_string = error;
+                    }
+                }
+            }
+        });
+    }
+
+    pub fn resolve_target_address(
+        pointer_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let target_address = match pointer_scanner_view_data.write("Pointer scanner resolve target") {
+            Some(mut view_data) => {
+                if view_data.target_address.trim().is_empty() {
+                    view_data.target_address_resolution = None;
+                    return;
+                }
+
+                view_data.target_address.clone()
+            }
+            None => return,
+        };
+
+        AddressResolver::resolve(&engine_unprivileged_state, &target_address, move |address_resolution| {
+            if let Some(mut view_data) = pointer_scanner_view_data.write("Pointer scanner target resolved") {
+                view_data.target_address_resolution = Some(address_resolution);
             }
         });
     }
@@ -171,6 +500,7 @@ impl PointerScannerViewData {
         cancel_request.send(&engine_unprivileged_state, move |_response| {
             if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan cancel response") {
                 view_data.is_scanning = false;
+                view_data.is_rescanning = false;
                 view_data.progress = 0.0;
                 view_data.current_task_id = None;
             }
@@ -266,6 +596,8 @@ impl PointerScannerViewData {
         pointer_scan_results_query_request.send(&engine_unprivileged_state, move |response| {
             if let Some(mut view_data) = pointer_scanner_view_data_clone.write("Pointer scan query response") {
                 view_data.is_querying_results = false;
+                // A rescan can leave fewer pages than the page being shown, in which case the engine returns the last page instead.
+                view_data.current_page_index = response.page_index.min(response.last_page_index);
                 view_data.current_results = response.results;
                 view_data.page_size = response.page_size;
                 view_data.result_count = response.result_count;
@@ -273,9 +605,19 @@ impl PointerScannerViewData {
                 view_data.stats_string = format!(
                     "Results: {} (Page {}/{})",
                     response.result_count,
-                    response.page_index + 1,
+                    view_data.current_page_index + 1,
                     response.last_page_index + 1
                 );
+
+                match response.memory_capped_depth {
+                    Some(0) => view_data
+                        .stats_string
+                        .push_str(" · Pointer map limited by memory cap"),
+                    Some(memory_capped_depth) => view_data
+                        .stats_string
+                        .push_str(&format!(" · Depth limited by memory cap (stopped at depth {})", memory_capped_depth)),
+                    None => {}
+                }
             }
         });
     }
@@ -328,23 +670,134 @@ impl PointerScannerViewData {
             .enumerate()
             .filter(|(index, _)| range.contains(index))
             .map(|(_, result)| {
-                let base = if result.is_module() {
-                    format!("{}+{:X}", result.get_module_name(), result.get_module_offset())
-                } else {
-                    format!("{:016X}", result.get_base_address())
-                };
-                let offsets = result
-                    .get_offsets()
-                    .iter()
-                    .map(|offset| format!("{:X}", offset))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                format!("{} -> [{}]", base, offsets)
+                let [base, offsets] = Self::get_result_cells(result);
+
+                format!("{} -> {}", base, offsets)
             })
             .collect::<Vec<_>>()
             .join("\n")
     }
 
+    /// Adds the selected results on the current page to the opened project as pointer entries, in a single request. Entries are
+    /// typed as i32, the same default as the element scanner, as a pointer scan does not know the type of the value it targets.
+    pub fn add_selected_results_to_project(
+        pointer_scanner_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let pointer_scan_results: Vec<PointerScanResult> = {
+            let view_data = match pointer_scanner_view_data.read("Pointer scan add selected results to project") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+            let Some(range) = Self::get_selected_results_range(&view_data) else {
+                return;
+            };
+
+            view_data
+                .current_results
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| range.contains(index))
+                .map(|(_, result)| result.clone())
+                .collect()
+        };
+
+        if pointer_scan_results.is_empty() {
+            return;
+        }
+
+        let pointer_scan_results_add_to_project_request = PointerScanResultsAddToProjectRequest {
+            pointer_scan_results,
+            data_type_ref: DataTypeRef::new(DataTypeI32::get_data_type_id()),
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        pointer_scan_results_add_to_project_request.send(&engine_unprivileged_state, move |response| {
+            if let Err(error) = engine_unprivileged_state_clone
+                .get_project_manager()
+                .add_project_items(response.project_items)
+            {
+                log::warn!("Unable to add pointer scan results: {}", error);
+            }
+        });
+    }
+
+    /// Copies the results of the current page as an aligned text table.
+    pub fn copy_page_as_formatted_text(pointer_scanner_view_data: Dependency<Self>) -> String {
+        let view_data = match pointer_scanner_view_data.read("Pointer scan copy page as formatted text") {
+            Some(view_data) => view_data,
+            None => return String::new(),
+        };
+
+        if view_data.current_results.is_empty() {
+            return String::new();
+        }
+
+        let mut text_table = TextTable::new(vec!["Base".to_string(), "Offsets".to_string()]);
+
+        for result in &view_data.current_results {
+            text_table.add_row(Self::get_result_cells(result).to_vec());
+        }
+
+        text_table.format()
+    }
+
+    /// Gets the base and offsets of a pointer scan result, as shown in the results list.
+    pub fn get_result_cells(result: &PointerScanResult) -> [String; 2] {
+        let base = if result.is_module() {
+            format!("{}+{:X}", result.get_module_name(), result.get_module_offset())
+        } else {
+            format!("{:016X}", result.get_base_address())
+        };
+        let offsets = result
+            .get_offsets()
+            .iter()
+            .map(|offset| PointerScanResult::format_offset(*offset))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        [base, format!("[{}]", offsets)]
+    }
+
+    /// Parses an offset entered in decimal, or in hex with a `0x` prefix, either of which may be negative. The offset is clamped to
+    /// `MAX_OFFSET_MAGNITUDE` in either direction.
+    pub fn parse_offset_text(offset_text: &str) -> Result<i64, String> {
+        let offset_text = offset_text.trim();
+        let (is_negative, magnitude_text) = match offset_text.strip_prefix('-') {
+            Some(magnitude_text) => (true, magnitude_text),
+            None => (false, offset_text),
+        };
+        let magnitude = match magnitude_text
+            .strip_prefix("0x")
+            .or_else(|| magnitude_text.strip_prefix("0X"))
+        {
+            Some(hex_text) => u64::from_str_radix(hex_text, 16),
+            None => magnitude_text.parse::<u64>(),
+        }
+        .or_else(|error| match error.kind() {
+            IntErrorKind::PosOverflow => Ok(u64::MAX),
+            _ => Err(format!("'{}' is not a valid offset.", offset_text)),
+        })?;
+        let magnitude = magnitude.min(Self::MAX_OFFSET_MAGNITUDE as u64) as i64;
+
+        Ok(if is_negative { -magnitude } else { magnitude })
+    }
+
+    /// Parses the most distinct offsets followed per level, where an empty value follows every offset. The count is clamped to
+    /// between one and `MAX_OFFSETS_PER_LEVEL`.
+    pub fn parse_max_offsets_per_level_text(max_offsets_per_level_text: &str) -> Result<Option<u64>, String> {
+        let max_offsets_per_level_text = max_offsets_per_level_text.trim();
+
+        if max_offsets_per_level_text.is_empty() {
+            return Ok(None);
+        }
+
+        max_offsets_per_level_text
+            .parse::<u64>()
+            .map(|max_offsets_per_level| Some(max_offsets_per_level.clamp(1, Self::MAX_OFFSETS_PER_LEVEL)))
+            .map_err(|_| format!("'{}' is not a valid number of offsets per level.", max_offsets_per_level_text))
+    }
+
     fn get_selected_results_range(view_data: &PointerScannerViewData) -> Option<RangeInclusive<usize>> {
         let start = view_data
             .selection_index_start
@@ -357,3 +810,31 @@ impl PointerScannerViewData {
         Some(range_low.max(0) as usize..=range_high.max(0) as usize)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PointerScannerViewData;
+    use squalr_engine_api::structures::pointer_scan::pointer_scan_result::PointerScanResult;
+
+    #[test]
+    fn offsets_parse_as_signed_decimal_or_hex_and_clamp() {
+        assert_eq!(PointerScannerViewData::parse_offset_text("512"), Ok(512));
+        assert_eq!(PointerScannerViewData::parse_offset_text(" -0x10 "), Ok(-0x10));
+        assert_eq!(
+            PointerScannerViewData::parse_offset_text("-99999999999999999999999"),
+            Ok(-PointerScannerViewData::MAX_OFFSET_MAGNITUDE)
+        );
+        assert!(PointerScannerViewData::parse_offset_text("12a").is_err());
+        assert!(PointerScannerViewData::parse_offset_text("").is_err());
+        assert_eq!(PointerScannerViewData::parse_max_offsets_per_level_text(""), Ok(None));
+        assert_eq!(PointerScannerViewData::parse_max_offsets_per_level_text("0"), Ok(Some(1)));
+        assert!(PointerScannerViewData::parse_max_offsets_per_level_text("-1").is_err());
+    }
+
+    #[test]
+    fn negative_offsets_render_with_a_leading_minus() {
+        let pointer_scan_result = PointerScanResult::new(0x1000, String::new(), 0x1000, vec![0x10, (-0x10i64) as u64], false);
+
+        assert_eq!(PointerScannerViewData::get_result_cells(&pointer_scan_result)[1], "[10, -10]");
+    }
+}
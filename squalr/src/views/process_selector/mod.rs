@@ -1,4 +1,5 @@
 pub mod process_entry_view;
 pub mod process_selector_toolbar_view;
 pub mod process_selector_view;
+pub mod process_thumbnail_view;
 pub mod view_data;
@@ -3,13 +3,14 @@ use crate::{
     ui::{draw::icon_draw::IconDraw, widgets::controls::state_layer::StateLayer},
 };
 use eframe::egui::{Align2, Rect, Response, Sense, TextureHandle, Ui, Widget, pos2, vec2};
-use epaint::CornerRadius;
+use epaint::{CornerRadius, Stroke, StrokeKind};
 use std::sync::Arc;
 
 pub struct ProcessEntryView<'lifetime> {
     app_context: Arc<AppContext>,
     label: &'lifetime str,
     icon: Option<TextureHandle>,
+    is_selected: bool,
 }
 
 impl<'lifetime> ProcessEntryView<'lifetime> {
@@ -17,11 +18,13 @@ impl<'lifetime> ProcessEntryView<'lifetime> {
         app_context: Arc<AppContext>,
         label: &'lifetime str,
         icon: Option<TextureHandle>,
+        is_selected: bool,
     ) -> Self {
         Self {
             app_context: app_context,
             label,
             icon,
+            is_selected,
         }
     }
 }
@@ -37,6 +40,18 @@ impl<'a> Widget for ProcessEntryView<'a> {
         let row_height = 28.0;
         let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(vec2(user_interface.available_size().x, row_height), Sense::click());
 
+        if self.is_selected {
+            user_interface
+                .painter()
+                .rect_filled(allocated_size_rectangle, CornerRadius::ZERO, theme.selected_background);
+            user_interface.painter().rect_stroke(
+                allocated_size_rectangle,
+                CornerRadius::ZERO,
+                Stroke::new(1.0, theme.selected_border),
+                StrokeKind::Inside,
+            );
+        }
+
         // Background and state overlay.
         StateLayer {
             bounds_min: allocated_size_rectangle.min,
@@ -50,7 +65,7 @@ impl<'a> Widget for ProcessEntryView<'a> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
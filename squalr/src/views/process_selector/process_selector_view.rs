@@ -1,17 +1,27 @@
 use crate::{
     app_context::AppContext,
     views::process_selector::{
-        process_entry_view::ProcessEntryView, process_selector_toolbar_view::ProcessSelectorToolbarView,
-        view_data::process_selector_view_data::ProcessSelectorViewData,
+        process_entry_view::ProcessEntryView,
+        process_selector_toolbar_view::ProcessSelectorToolbarView,
+        process_thumbnail_view::ProcessThumbnailView,
+        view_data::{filtered_process_list::FilteredProcessList, process_selector_view_data::ProcessSelectorViewData},
     },
 };
-use eframe::egui::{Align, Direction, Layout, Response, ScrollArea, Spinner, Ui, Widget};
-use squalr_engine_api::dependency_injection::dependency::Dependency;
+use eframe::egui::{Align, Direction, Key, Layout, Response, RichText, ScrollArea, Spinner, TextEdit, Ui, Widget};
+use squalr_engine_api::{dependency_injection::dependency::Dependency, structures::processes::process_info::ProcessInfo};
 use std::{
     sync::Arc,
     time::{Duration, Instant},
 };
 
+/// What the user did with the process rows during a frame, applied once the view data read lock is released.
+#[derive(Default)]
+struct ProcessListInteraction {
+    clicked_process_id: Option<u32>,
+    opened_process_id: Option<u32>,
+    shown_thumbnail_process_id: Option<u32>,
+}
+
 #[derive(Clone)]
 pub struct ProcessSelectorView {
     app_context: Arc<AppContext>,
@@ -28,12 +38,70 @@ impl ProcessSelectorView {
             .register(ProcessSelectorViewData::new());
         let process_selector_toolbar_view = ProcessSelectorToolbarView::new(app_context.clone());
 
+        ProcessSelectorViewData::load_last_opened_process_name(process_selector_view_data.clone(), app_context.clone());
+
         Self {
             app_context,
             process_selector_view_data,
             process_selector_toolbar_view,
         }
     }
+
+    /// Adds a labeled group of process rows, recording which row was clicked, double clicked, or is showing its thumbnail.
+    fn add_process_group(
+        &self,
+        user_interface: &mut Ui,
+        process_selector_view_data: &ProcessSelectorViewData,
+        group_label: &str,
+        processes: &[&ProcessInfo],
+        process_list_interaction: &mut ProcessListInteraction,
+    ) {
+        if processes.is_empty() {
+            return;
+        }
+
+        let theme = &self.app_context.theme;
+
+        user_interface.add_space(4.0);
+        user_interface.label(
+            RichText::new(format!("{} ({})", group_label, processes.len()))
+                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                .color(theme.foreground_preview),
+        );
+
+        for process in processes {
+            let process_id = process.get_process_id_raw();
+            let icon = match process.get_icon() {
+                Some(icon) => process_selector_view_data.get_icon(&self.app_context, process_id, icon),
+                None => None,
+            };
+            let is_selected = process_selector_view_data.selected_process_id == Some(process_id);
+            let entry_response = user_interface.add(ProcessEntryView::new(self.app_context.clone(), process.get_name(), icon.clone(), is_selected));
+
+            if entry_response.double_clicked() {
+                process_list_interaction.opened_process_id = Some(process_id);
+            } else if entry_response.clicked() {
+                process_list_interaction.clicked_process_id = Some(process_id);
+            }
+
+            // Tooltips only appear after the hover delay, so this is also what triggers capturing the thumbnail.
+            entry_response.on_hover_ui(|tooltip_user_interface| {
+                process_list_interaction.shown_thumbnail_process_id = Some(process_id);
+                tooltip_user_interface.add(ProcessThumbnailView::new(
+                    self.app_context.clone(),
+                    process.get_name(),
+                    process_id,
+                    icon,
+                    process_selector_view_data
+                        .thumbnail_cache
+                        .get_thumbnail(process_id),
+                    process_selector_view_data
+                        .thumbnail_cache
+                        .get_texture(process_id),
+                ));
+            });
+        }
+    }
 }
 
 impl Widget for ProcessSelectorView {
@@ -45,8 +113,11 @@ impl Widget for ProcessSelectorView {
 
         let theme = self.app_context.theme.clone();
         let mut auto_refresh = false;
+        let mut process_list_interaction = ProcessListInteraction::default();
+        let mut new_filter_text = None;
+        let mut should_reopen_last_process = false;
         let response = user_interface
-            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |mut user_interface| {
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
                 let process_selector_view_data = match self.process_selector_view_data.read("Process selector view") {
                     Some(process_selector_view_data) => process_selector_view_data,
                     None => return,
@@ -62,34 +133,64 @@ impl Widget for ProcessSelectorView {
 
                 user_interface.add(self.process_selector_toolbar_view.clone());
 
-                if !process_selector_view_data.is_awaiting_full_process_list {
-                    ScrollArea::vertical()
-                        .id_salt("process_selector")
-                        .auto_shrink([false, false])
-                        .show(&mut user_interface, |inner_user_interface| {
-                            let mut selected_process = None;
-
-                            for process in &process_selector_view_data.full_process_list {
-                                let icon = match process.get_icon() {
-                                    Some(icon) => process_selector_view_data.get_icon(&self.app_context, process.get_process_id_raw(), icon),
-                                    None => None,
-                                };
-
-                                if inner_user_interface
-                                    .add(ProcessEntryView::new(self.app_context.clone(), process.get_name(), icon))
-                                    .double_clicked()
-                                {
-                                    selected_process = Some(Some(process.get_process_id_raw()));
-                                }
-                            }
+                let windowed_process_ids = process_selector_view_data.get_windowed_process_ids();
+                let filtered_process_list = FilteredProcessList::new(
+                    &process_selector_view_data.full_process_list,
+                    &windowed_process_ids,
+                    &process_selector_view_data.filter_text,
+                );
 
-                            if let Some(selected_process) = selected_process {
-                                drop(process_selector_view_data);
+                user_interface.add_space(4.0);
 
-                                ProcessSelectorViewData::select_process(self.process_selector_view_data.clone(), self.app_context.clone(), selected_process);
-                            }
-                        });
-                } else {
+                let mut filter_text = process_selector_view_data.filter_text.clone();
+                let filter_response = user_interface
+                    .add(
+                        TextEdit::singleline(&mut filter_text)
+                            .hint_text("Filter by name or pid...")
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .text_color(theme.foreground)
+                            .background_color(theme.background_primary)
+                            .desired_width(f32::INFINITY),
+                    )
+                    .on_hover_text("Press Enter to open the selected process, or the first match if the selection is filtered out.");
+
+                if filter_response.changed() {
+                    new_filter_text = Some(filter_text);
+                }
+
+                if filter_response.lost_focus() && user_interface.input(|input_state| input_state.key_pressed(Key::Enter)) {
+                    process_list_interaction.opened_process_id = match process_selector_view_data.selected_process_id {
+                        Some(selected_process_id) if filtered_process_list.contains(selected_process_id) => Some(selected_process_id),
+                        _ => filtered_process_list
+                            .windowed_processes
+                            .iter()
+                            .chain(filtered_process_list.system_processes.iter())
+                            .next()
+                            .map(|process_info| process_info.get_process_id_raw()),
+                    };
+                }
+
+                // The remembered process stays visible while it is not running, so that it is clear what would be reopened.
+                if process_selector_view_data.opened_process.is_none()
+                    && let Some(last_opened_process_name) = &process_selector_view_data.last_opened_process_name
+                {
+                    let is_running = process_selector_view_data
+                        .find_running_process_id(last_opened_process_name)
+                        .is_some();
+
+                    user_interface.add_space(4.0);
+
+                    let reopen_response = user_interface
+                        .add_enabled(is_running, eframe::egui::Button::new(format!("Reopen {}", last_opened_process_name)))
+                        .on_disabled_hover_text(format!("{} is not running.", last_opened_process_name));
+
+                    if reopen_response.clicked() {
+                        should_reopen_last_process = true;
+                    }
+                }
+
+                // Only show the spinner before the first list arrives. Later refreshes swap the list in place, keeping the scroll position.
+                if process_selector_view_data.full_process_list.is_empty() && process_selector_view_data.is_awaiting_full_process_list {
                     user_interface.allocate_ui_with_layout(
                         user_interface.available_size(),
                         Layout::centered_and_justified(Direction::LeftToRight),
@@ -97,10 +198,57 @@ impl Widget for ProcessSelectorView {
                             user_interface.add(Spinner::new().color(theme.foreground));
                         },
                     );
+                } else {
+                    ScrollArea::vertical()
+                        .id_salt("process_selector")
+                        .auto_shrink([false, false])
+                        .show(user_interface, |inner_user_interface| {
+                            self.add_process_group(
+                                inner_user_interface,
+                                &process_selector_view_data,
+                                "Applications",
+                                &filtered_process_list.windowed_processes,
+                                &mut process_list_interaction,
+                            );
+                            self.add_process_group(
+                                inner_user_interface,
+                                &process_selector_view_data,
+                                "System processes",
+                                &filtered_process_list.system_processes,
+                                &mut process_list_interaction,
+                            );
+
+                            if filtered_process_list.is_empty() && !process_selector_view_data.full_process_list.is_empty() {
+                                inner_user_interface.label(RichText::new("No processes match the filter.").color(theme.foreground_preview));
+                            }
+                        });
                 }
             })
             .response;
 
+        if let Some(filter_text) = new_filter_text {
+            ProcessSelectorViewData::set_filter_text(self.process_selector_view_data.clone(), filter_text);
+        }
+
+        if let Some(process_id) = process_list_interaction
+            .clicked_process_id
+            .or(process_list_interaction.opened_process_id)
+        {
+            ProcessSelectorViewData::set_selected_process_id(self.process_selector_view_data.clone(), Some(process_id));
+        }
+
+        if let Some(process_id) = process_list_interaction.opened_process_id {
+            ProcessSelectorViewData::select_process(self.process_selector_view_data.clone(), self.app_context.clone(), Some(process_id));
+        } else if should_reopen_last_process {
+            ProcessSelectorViewData::reopen_last_process(self.process_selector_view_data.clone(), self.app_context.clone());
+        }
+
+        ProcessSelectorViewData::update_thumbnail_preview(
+            self.process_selector_view_data.clone(),
+            self.app_context.clone(),
+            process_list_interaction.shown_thumbnail_process_id,
+        );
+
         if auto_refresh {
             ProcessSelectorViewData::refresh_full_process_list(self.process_selector_view_data.clone(), self.app_context.clone());
         }
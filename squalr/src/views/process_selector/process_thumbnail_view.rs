@@ -0,0 +1,93 @@
+use crate::{app_context::AppContext, ui::draw::icon_draw::IconDraw};
+use eframe::egui::{Align, Image, Layout, Response, RichText, Sense, Spinner, TextureHandle, Ui, Widget, vec2};
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
+use std::sync::Arc;
+
+/// Hover preview for a process selector row, showing a thumbnail of the main window to tell apart processes with the same name.
+pub struct ProcessThumbnailView<'lifetime> {
+    app_context: Arc<AppContext>,
+    process_name: &'lifetime str,
+    process_id: u32,
+    icon: Option<TextureHandle>,
+    thumbnail: Option<&'lifetime ProcessThumbnail>,
+    thumbnail_texture: Option<&'lifetime TextureHandle>,
+}
+
+impl<'lifetime> ProcessThumbnailView<'lifetime> {
+    pub fn new(
+        app_context: Arc<AppContext>,
+        process_name: &'lifetime str,
+        process_id: u32,
+        icon: Option<TextureHandle>,
+        thumbnail: Option<&'lifetime ProcessThumbnail>,
+        thumbnail_texture: Option<&'lifetime TextureHandle>,
+    ) -> Self {
+        Self {
+            app_context,
+            process_name,
+            process_id,
+            icon,
+            thumbnail,
+            thumbnail_texture,
+        }
+    }
+}
+
+impl<'lifetime> Widget for ProcessThumbnailView<'lifetime> {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let icon_size = vec2(16.0, 16.0);
+
+        user_interface
+            .with_layout(Layout::top_down(Align::Min), |user_interface| {
+                user_interface.label(
+                    RichText::new(format!("{} (PID {})", self.process_name, self.process_id))
+                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                        .color(theme.foreground),
+                );
+
+                // Show the capture once uploaded. Otherwise fall back to the icon, with either the reason there is no capture, or a spinner
+                // while the engine is still capturing.
+                if let Some(thumbnail_texture) = self.thumbnail_texture {
+                    let texture_size = thumbnail_texture.size_vec2();
+
+                    user_interface.add(Image::new((thumbnail_texture.id(), texture_size)).sense(Sense::hover()));
+
+                    return;
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if let Some(icon) = &self.icon {
+                        let (icon_rectangle, _response) = user_interface.allocate_exact_size(icon_size, Sense::hover());
+
+                        IconDraw::draw_sized(user_interface, icon_rectangle.center(), icon_size, icon);
+                    }
+
+                    match self
+                        .thumbnail
+                        .and_then(|thumbnail| thumbnail.get_unavailable_reason())
+                    {
+                        Some(unavailable_reason) => {
+                            user_interface.label(
+                                RichText::new(unavailable_reason)
+                                    .font(theme.font_library.font_noto_sans.font_small.clone())
+                                    .color(theme.foreground_preview),
+                            );
+                        }
+                        None => {
+                            user_interface.add(Spinner::new().color(theme.foreground));
+                            user_interface.label(
+                                RichText::new("Capturing preview...")
+                                    .font(theme.font_library.font_noto_sans.font_small.clone())
+                                    .color(theme.foreground_preview),
+                            );
+                        }
+                    }
+                });
+            })
+            .response
+    }
+}
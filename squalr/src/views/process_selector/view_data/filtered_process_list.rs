@@ -0,0 +1,114 @@
+use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+use std::collections::HashSet;
+
+/// The processes of the process selector that pass the filter, split into those that own a window and everything else.
+pub struct FilteredProcessList<'lifetime> {
+    pub windowed_processes: Vec<&'lifetime ProcessInfo>,
+    pub system_processes: Vec<&'lifetime ProcessInfo>,
+}
+
+impl<'lifetime> FilteredProcessList<'lifetime> {
+    /// Filters the process list by a case insensitive name or process id substring. Windowed processes are recognized either by
+    /// their own flag, or by appearing in the windowed process list, since the full list does not always resolve windows.
+    pub fn new(
+        processes: &'lifetime [ProcessInfo],
+        windowed_process_ids: &HashSet<u32>,
+        filter_text: &str,
+    ) -> Self {
+        let filter_text = filter_text.trim().to_lowercase();
+        let mut windowed_processes = Vec::new();
+        let mut system_processes = Vec::new();
+
+        for process_info in processes {
+            if !Self::matches(process_info, &filter_text) {
+                continue;
+            }
+
+            if process_info.get_is_windowed() || windowed_process_ids.contains(&process_info.get_process_id_raw()) {
+                windowed_processes.push(process_info);
+            } else {
+                system_processes.push(process_info);
+            }
+        }
+
+        Self {
+            windowed_processes,
+            system_processes,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.windowed_processes.is_empty() && self.system_processes.is_empty()
+    }
+
+    pub fn contains(
+        &self,
+        process_id: u32,
+    ) -> bool {
+        self.windowed_processes
+            .iter()
+            .chain(self.system_processes.iter())
+            .any(|process_info| process_info.get_process_id_raw() == process_id)
+    }
+
+    /// Expects a lowercase, trimmed filter. An empty filter matches every process.
+    fn matches(
+        process_info: &ProcessInfo,
+        filter_text: &str,
+    ) -> bool {
+        filter_text.is_empty()
+            || process_info.get_name().to_lowercase().contains(filter_text)
+            || process_info
+                .get_process_id_raw()
+                .to_string()
+                .contains(filter_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FilteredProcessList;
+    use squalr_engine_api::structures::processes::process_info::ProcessInfo;
+    use std::collections::HashSet;
+
+    fn create_process_list() -> Vec<ProcessInfo> {
+        vec![
+            ProcessInfo::new(1204, "Game.exe".to_string(), true, None),
+            ProcessInfo::new(88, "svchost.exe".to_string(), false, None),
+            ProcessInfo::new(3120, "notepad.exe".to_string(), false, None),
+        ]
+    }
+
+    #[test]
+    fn filter_matches_names_case_insensitively_and_process_id_substrings() {
+        let process_list = create_process_list();
+        let windowed_process_ids = HashSet::new();
+
+        let by_name = FilteredProcessList::new(&process_list, &windowed_process_ids, " GAME ");
+        assert_eq!(by_name.windowed_processes.len(), 1);
+        assert!(by_name.system_processes.is_empty());
+
+        let by_process_id = FilteredProcessList::new(&process_list, &windowed_process_ids, "12");
+        assert!(by_process_id.contains(1204));
+        assert!(by_process_id.contains(3120));
+        assert!(!by_process_id.contains(88));
+
+        assert!(FilteredProcessList::new(&process_list, &windowed_process_ids, "missing").is_empty());
+    }
+
+    #[test]
+    fn windowed_process_ids_group_processes_the_full_list_did_not_flag() {
+        let process_list = create_process_list();
+        let windowed_process_ids = HashSet::from([3120]);
+        let filtered_process_list = FilteredProcessList::new(&process_list, &windowed_process_ids, "");
+
+        let windowed_names: Vec<&str> = filtered_process_list
+            .windowed_processes
+            .iter()
+            .map(|process_info| process_info.get_name())
+            .collect();
+
+        assert_eq!(windowed_names, vec!["Game.exe", "notepad.exe"]);
+        assert_eq!(filtered_process_list.system_processes.len(), 1);
+    }
+}
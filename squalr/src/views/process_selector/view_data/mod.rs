@@ -1 +1,3 @@
+pub mod filtered_process_list;
 pub mod process_selector_view_data;
+pub mod process_thumbnail_cache;
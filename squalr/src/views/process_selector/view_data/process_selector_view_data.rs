@@ -1,13 +1,20 @@
 use crate::app_context::AppContext;
+use crate::views::process_selector::view_data::process_thumbnail_cache::ProcessThumbnailCache;
 use eframe::egui::TextureOptions;
 use epaint::{ColorImage, TextureHandle};
 use squalr_engine_api::{
     commands::{
         privileged_command_request::PrivilegedCommandRequest,
-        process::{list::process_list_request::ProcessListRequest, open::process_open_request::ProcessOpenRequest},
+        process::{
+            list::process_list_request::ProcessListRequest, open::process_open_request::ProcessOpenRequest,
+            thumbnail::process_thumbnail_request::ProcessThumbnailRequest,
+        },
+        settings::general::{list::general_settings_list_request::GeneralSettingsListRequest, set::general_settings_set_request::GeneralSettingsSetRequest},
     },
     dependency_injection::{dependency::Dependency, write_guard::WriteGuard},
-    structures::processes::{opened_process_info::OpenedProcessInfo, process_icon::ProcessIcon, process_info::ProcessInfo},
+    structures::processes::{
+        opened_process_info::OpenedProcessInfo, process_attach_conflict::ProcessAttachConflict, process_icon::ProcessIcon, process_info::ProcessInfo,
+    },
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -27,9 +34,24 @@ pub struct ProcessSelectorViewData {
     pub is_opening_process: bool,
     pub last_windowed_refresh: Option<Instant>,
     pub last_full_refresh: Option<Instant>,
+    pub show_bitness_notice: bool,
+    /// Another Squalr instance found attached to the process that was last selected, which the user can attach alongside read-only
+    /// or take over.
+    pub attach_conflict: Option<ProcessAttachConflict>,
+    pub thumbnail_cache: ProcessThumbnailCache,
+    /// Narrows the process selector list down by process name or process id.
+    pub filter_text: String,
+    /// The process highlighted in the process selector list. Tracked by process id so that filtering and refreshing the list do not
+    /// move the selection onto another process.
+    pub selected_process_id: Option<u32>,
+    /// The name of the last process that was successfully opened, which the process selector offers to reopen.
+    pub last_opened_process_name: Option<String>,
 }
 
 impl ProcessSelectorViewData {
+    pub const THUMBNAIL_MAX_WIDTH: u32 = 256;
+    pub const THUMBNAIL_MAX_HEIGHT: u32 = 160;
+
     pub fn new() -> Self {
         Self {
             opened_process: None,
@@ -42,6 +64,89 @@ impl ProcessSelectorViewData {
             is_opening_process: false,
             last_windowed_refresh: None,
             last_full_refresh: None,
+            show_bitness_notice: false,
+            attach_conflict: None,
+            thumbnail_cache: ProcessThumbnailCache::default(),
+            filter_text: String::new(),
+            selected_process_id: None,
+            last_opened_process_name: None,
+        }
+    }
+
+    /// Fetches the remembered name of the last opened process from the general settings.
+    pub fn load_last_opened_process_name(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let general_settings_list_request = GeneralSettingsListRequest {};
+        let engine_unprivileged_state = app_context.engine_unprivileged_state.clone();
+
+        general_settings_list_request.send(&engine_unprivileged_state, move |general_settings_list_response| {
+            let general_settings = match general_settings_list_response.general_settings {
+                Ok(general_settings) => general_settings,
+                Err(_) => return,
+            };
+
+            if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data load last opened process name") {
+                process_selector_view_data.last_opened_process_name = general_settings.last_opened_process_name;
+            }
+
+            app_context.context.request_repaint();
+        });
+    }
+
+    /// Finds a running process with the given name, preferring windowed processes, as that is what was most likely opened.
+    pub fn find_running_process_id(
+        &self,
+        process_name: &str,
+    ) -> Option<u32> {
+        self.windowed_process_list
+            .iter()
+            .chain(self.full_process_list.iter())
+            .find(|process_info| process_info.get_name() == process_name)
+            .map(|process_info| process_info.get_process_id_raw())
+    }
+
+    pub fn get_windowed_process_ids(&self) -> HashSet<u32> {
+        self.windowed_process_list
+            .iter()
+            .map(|process_info| process_info.get_process_id_raw())
+            .collect()
+    }
+
+    pub fn set_filter_text(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        filter_text: String,
+    ) {
+        if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data set filter text") {
+            process_selector_view_data.filter_text = filter_text;
+        }
+    }
+
+    pub fn set_selected_process_id(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        selected_process_id: Option<u32>,
+    ) {
+        if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data set selected process id") {
+            process_selector_view_data.selected_process_id = selected_process_id;
+        }
+    }
+
+    /// Opens the running process that matches the name of the last opened process, if there is one.
+    pub fn reopen_last_process(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: Arc<AppContext>,
+    ) {
+        let process_id = match process_selector_view_data.read("Process selector view data reopen last process") {
+            Some(process_selector_view_data) => match &process_selector_view_data.last_opened_process_name {
+                Some(last_opened_process_name) => process_selector_view_data.find_running_process_id(last_opened_process_name),
+                None => None,
+            },
+            None => return,
+        };
+
+        if process_id.is_some() {
+            Self::open_process(process_selector_view_data, app_context, process_id, false, false);
         }
     }
 
@@ -157,30 +262,110 @@ impl ProcessSelectorViewData {
         process_id: Option<u32>,
     ) {
         if process_id.is_some() {
-            let engine_unprivileged_state = app_context.engine_unprivileged_state.clone();
-            let process_open_request = ProcessOpenRequest {
-                process_id,
-                search_name: None,
-                match_case: false,
-            };
+            Self::open_process(process_selector_view_data, app_context, process_id, false, false);
+        } else {
+            Self::set_opened_process_info(process_selector_view_data, &app_context, None)
+        }
+    }
+
+    /// Opens the process that another Squalr instance was found attached to again, either read-only alongside it, or taking over
+    /// its attach lock such that the other instance stops freezing values.
+    pub fn resolve_attach_conflict(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: Arc<AppContext>,
+        takeover: bool,
+    ) {
+        let attach_conflict = match process_selector_view_data.write("Process selector view data resolve attach conflict") {
+            Some(mut process_selector_view_data) => process_selector_view_data.attach_conflict.take(),
+            None => return,
+        };
 
-            match process_selector_view_data.write("Process selector view data select process") {
-                Some(mut process_selector_view_data) => {
-                    if process_selector_view_data.is_opening_process {
-                        return;
-                    }
+        if let Some(attach_conflict) = attach_conflict {
+            Self::open_process(
+                process_selector_view_data,
+                app_context,
+                Some(attach_conflict.get_process_id()),
+                !takeover,
+                takeover,
+            );
+        }
+    }
+
+    pub fn dismiss_attach_conflict(process_selector_view_data: Dependency<ProcessSelectorViewData>) {
+        if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data dismiss attach conflict") {
+            process_selector_view_data.attach_conflict = None;
+        }
+    }
 
-                    process_selector_view_data.is_opening_process = true;
+    fn open_process(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: Arc<AppContext>,
+        process_id: Option<u32>,
+        read_only: bool,
+        takeover: bool,
+    ) {
+        let engine_unprivileged_state = app_context.engine_unprivileged_state.clone();
+        let process_open_request = ProcessOpenRequest {
+            process_id,
+            search_name: None,
+            match_case: false,
+            read_only,
+            takeover,
+        };
+
+        match process_selector_view_data.write("Process selector view data select process") {
+            Some(mut process_selector_view_data) => {
+                if process_selector_view_data.is_opening_process {
+                    return;
                 }
-                None => return,
-            };
 
-            process_open_request.send(&engine_unprivileged_state, move |process_open_response| {
-                Self::set_opened_process_info(process_selector_view_data, &app_context, process_open_response.opened_process_info)
-            });
-        } else {
-            Self::set_opened_process_info(process_selector_view_data, &app_context, None)
+                process_selector_view_data.is_opening_process = true;
+                process_selector_view_data.attach_conflict = None;
+            }
+            None => return,
+        };
+
+        process_open_request.send(&engine_unprivileged_state, move |process_open_response| {
+            // A conflict leaves the current process attached, and waits for the user to choose how to attach to the new one.
+            if let Some(attach_conflict) = process_open_response.attach_conflict {
+                if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data attach conflict") {
+                    process_selector_view_data.is_opening_process = false;
+                    process_selector_view_data.attach_conflict = Some(attach_conflict);
+                }
+
+                app_context.context.request_repaint();
+
+                return;
+            }
+
+            if let Some(opened_process_info) = &process_open_response.opened_process_info {
+                Self::remember_opened_process(process_selector_view_data.clone(), &app_context, opened_process_info.get_name());
+            }
+
+            Self::set_opened_process_info(process_selector_view_data, &app_context, process_open_response.opened_process_info)
+        });
+    }
+
+    /// Persists the name of a successfully opened process, so that it can be offered for reopening on the next startup.
+    fn remember_opened_process(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: &Arc<AppContext>,
+        process_name: &str,
+    ) {
+        if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data remember opened process") {
+            if process_selector_view_data.last_opened_process_name.as_deref() == Some(process_name) {
+                return;
+            }
+
+            process_selector_view_data.last_opened_process_name = Some(process_name.to_string());
         }
+
+        let general_settings_set_request = GeneralSettingsSetRequest {
+            last_opened_process_name: Some(process_name.to_string()),
+            ..GeneralSettingsSetRequest::default()
+        };
+
+        general_settings_set_request.send(&app_context.engine_unprivileged_state, move |_general_settings_set_response| {});
     }
 
     pub fn set_opened_process_info(
@@ -193,6 +378,22 @@ impl ProcessSelectorViewData {
             None => return,
         };
 
+        let previous_process_id = process_selector_view_data
+            .opened_process
+            .as_ref()
+            .map(|opened_process| opened_process.get_process_id_raw());
+        let new_process_id = opened_process
+            .as_ref()
+            .map(|opened_process| opened_process.get_process_id_raw());
+
+        // Only notify once per attach, since this also runs for repeated change events on the same process.
+        if previous_process_id != new_process_id {
+            process_selector_view_data.show_bitness_notice = opened_process
+                .as_ref()
+                .map(|opened_process| opened_process.get_bitness().is_narrower_than_host())
+                .unwrap_or(false);
+        }
+
         process_selector_view_data.is_opening_process = false;
         process_selector_view_data.opened_process = opened_process;
 
@@ -216,6 +417,72 @@ impl ProcessSelectorViewData {
         }
     }
 
+    /// Keeps the window thumbnail of the hovered process up to date, requesting a capture from the engine when it is missing or
+    /// stale, and releases thumbnail textures that are no longer on screen. Called once per frame.
+    pub fn update_thumbnail_preview(
+        process_selector_view_data: Dependency<ProcessSelectorViewData>,
+        app_context: Arc<AppContext>,
+        shown_process_id: Option<u32>,
+    ) {
+        let now = Instant::now();
+
+        let process_id = match process_selector_view_data.write("Process selector view data update thumbnail preview") {
+            Some(mut process_selector_view_data) => {
+                process_selector_view_data
+                    .thumbnail_cache
+                    .evict(shown_process_id, now);
+
+                let process_id = match shown_process_id {
+                    Some(process_id) => process_id,
+                    None => return,
+                };
+
+                process_selector_view_data
+                    .thumbnail_cache
+                    .ensure_texture(&app_context.context, process_id);
+
+                if !process_selector_view_data
+                    .thumbnail_cache
+                    .needs_capture(process_id, now)
+                {
+                    return;
+                }
+
+                process_selector_view_data
+                    .thumbnail_cache
+                    .begin_capture(process_id, now);
+
+                process_id
+            }
+            None => return,
+        };
+        let process_thumbnail_request = ProcessThumbnailRequest {
+            process_id,
+            max_width: Self::THUMBNAIL_MAX_WIDTH,
+            max_height: Self::THUMBNAIL_MAX_HEIGHT,
+        };
+        let engine_unprivileged_state = app_context.engine_unprivileged_state.clone();
+
+        // Capturing happens on the engine side, so the UI keeps showing the previous capture (or a placeholder) until this returns.
+        process_thumbnail_request.send(&engine_unprivileged_state, move |process_thumbnail_response| {
+            if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data thumbnail response") {
+                process_selector_view_data.thumbnail_cache.complete_capture(
+                    process_thumbnail_response.process_id,
+                    process_thumbnail_response.thumbnail,
+                    Instant::now(),
+                );
+            }
+
+            app_context.context.request_repaint();
+        });
+    }
+
+    pub fn dismiss_bitness_notice(process_selector_view_data: Dependency<ProcessSelectorViewData>) {
+        if let Some(mut process_selector_view_data) = process_selector_view_data.write("Process selector view data dismiss bitness notice") {
+            process_selector_view_data.show_bitness_notice = false;
+        }
+    }
+
     pub fn create_and_cache_icon(
         process_selector_view_data: &mut WriteGuard<'_, ProcessSelectorViewData>,
         app_context: &Arc<AppContext>,
@@ -305,6 +572,13 @@ impl ProcessSelectorViewData {
 
         // Remove icons for processes no longer present.
         Self::remove_from_cache(process_selector_view_data, &removed);
+
+        // Drop the selection if the selected process exited, rather than letting it land on whichever process reuses the row.
+        if let Some(selected_process_id) = process_selector_view_data.selected_process_id
+            && removed.contains(&selected_process_id)
+        {
+            process_selector_view_data.selected_process_id = None;
+        }
     }
 
     /// Computes process ID deltas between old/new PID sets.
@@ -0,0 +1,200 @@
+use eframe::egui::{Context, TextureOptions};
+use epaint::{ColorImage, TextureHandle};
+use squalr_engine_api::structures::processes::process_thumbnail::ProcessThumbnail;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// Caches captured window thumbnails for the process selector. Captures are kept briefly so that moving between rows does not
+/// recapture every window, but only the thumbnail currently on screen keeps a texture, so scrolling a long list does not pile up
+/// GPU memory.
+#[derive(Clone, Default)]
+pub struct ProcessThumbnailCache {
+    entries: HashMap<u32, ProcessThumbnailCacheEntry>,
+    pending_captures: HashMap<u32, Instant>,
+}
+
+#[derive(Clone)]
+struct ProcessThumbnailCacheEntry {
+    thumbnail: ProcessThumbnail,
+    captured_at: Instant,
+    texture: Option<TextureHandle>,
+}
+
+impl ProcessThumbnailCache {
+    /// How long a capture is shown before it is refreshed. Stale captures remain visible until the refresh arrives.
+    pub const CAPTURE_LIFETIME: Duration = Duration::from_millis(2000);
+
+    /// How long to wait on a capture before assuming the response was lost and allowing another request.
+    pub const PENDING_CAPTURE_TIMEOUT: Duration = Duration::from_millis(5000);
+
+    pub fn get_thumbnail(
+        &self,
+        process_id: u32,
+    ) -> Option<&ProcessThumbnail> {
+        self.entries.get(&process_id).map(|entry| &entry.thumbnail)
+    }
+
+    pub fn get_texture(
+        &self,
+        process_id: u32,
+    ) -> Option<&TextureHandle> {
+        self.entries
+            .get(&process_id)
+            .and_then(|entry| entry.texture.as_ref())
+    }
+
+    /// Gets whether a new capture should be requested for the given process, ie it is missing or stale, and not already underway.
+    pub fn needs_capture(
+        &self,
+        process_id: u32,
+        now: Instant,
+    ) -> bool {
+        if let Some(requested_at) = self.pending_captures.get(&process_id)
+            && now.duration_since(*requested_at) < Self::PENDING_CAPTURE_TIMEOUT
+        {
+            return false;
+        }
+
+        self.entries
+            .get(&process_id)
+            .map(|entry| now.duration_since(entry.captured_at) >= Self::CAPTURE_LIFETIME)
+            .unwrap_or(true)
+    }
+
+    pub fn begin_capture(
+        &mut self,
+        process_id: u32,
+        now: Instant,
+    ) {
+        self.pending_captures.insert(process_id, now);
+    }
+
+    /// Stores a completed capture, replacing any previous capture and its texture.
+    pub fn complete_capture(
+        &mut self,
+        process_id: u32,
+        thumbnail: ProcessThumbnail,
+        now: Instant,
+    ) {
+        self.pending_captures.remove(&process_id);
+        self.entries.insert(
+            process_id,
+            ProcessThumbnailCacheEntry {
+                thumbnail,
+                captured_at: now,
+                texture: None,
+            },
+        );
+    }
+
+    /// Uploads the thumbnail of the given process to the GPU if it has not been already.
+    pub fn ensure_texture(
+        &mut self,
+        context: &Context,
+        process_id: u32,
+    ) {
+        let entry = match self.entries.get_mut(&process_id) {
+            Some(entry) => entry,
+            None => return,
+        };
+
+        if entry.texture.is_some() {
+            return;
+        }
+
+        if let Some(image) = entry.thumbnail.get_image() {
+            let size = [image.get_width() as usize, image.get_height() as usize];
+
+            entry.texture = Some(context.load_texture(
+                format!("process_thumbnail_{process_id}"),
+                ColorImage::from_rgba_unmultiplied(size, image.get_bytes_rgba()),
+                TextureOptions::LINEAR,
+            ));
+        }
+    }
+
+    /// Releases the textures of every thumbnail except the one on screen, and forgets captures that have long gone stale.
+    /// Textures are freed by egui once their last handle is dropped.
+    pub fn evict(
+        &mut self,
+        shown_process_id: Option<u32>,
+        now: Instant,
+    ) {
+        self.entries.retain(|process_id, entry| {
+            if Some(*process_id) == shown_process_id {
+                return true;
+            }
+
+            entry.texture = None;
+
+            now.duration_since(entry.captured_at) < Self::CAPTURE_LIFETIME * 4
+        });
+        self.pending_captures
+            .retain(|_process_id, requested_at| now.duration_since(*requested_at) < Self::PENDING_CAPTURE_TIMEOUT);
+    }
+
+    pub fn has_textures(&self) -> bool {
+        self.entries.values().any(|entry| entry.texture.is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProcessThumbnailCache;
+    use eframe::egui::Context;
+    use squalr_engine_api::structures::processes::{process_icon::ProcessIcon, process_thumbnail::ProcessThumbnail};
+    use std::time::{Duration, Instant};
+
+    fn create_captured_thumbnail() -> ProcessThumbnail {
+        ProcessThumbnail::Captured(ProcessIcon::new(vec![255u8; 4 * 4 * 4], 4, 4))
+    }
+
+    #[test]
+    fn captures_are_requested_once_until_they_go_stale() {
+        let mut process_thumbnail_cache = ProcessThumbnailCache::default();
+        let now = Instant::now();
+
+        assert!(process_thumbnail_cache.needs_capture(42, now));
+
+        process_thumbnail_cache.begin_capture(42, now);
+        assert!(!process_thumbnail_cache.needs_capture(42, now));
+
+        process_thumbnail_cache.complete_capture(42, ProcessThumbnail::NoWindow, now);
+        assert!(!process_thumbnail_cache.needs_capture(42, now + Duration::from_millis(500)));
+        assert!(process_thumbnail_cache.needs_capture(42, now + ProcessThumbnailCache::CAPTURE_LIFETIME));
+    }
+
+    #[test]
+    fn lost_captures_can_be_retried() {
+        let mut process_thumbnail_cache = ProcessThumbnailCache::default();
+        let now = Instant::now();
+
+        process_thumbnail_cache.begin_capture(7, now);
+
+        assert!(process_thumbnail_cache.needs_capture(7, now + ProcessThumbnailCache::PENDING_CAPTURE_TIMEOUT));
+    }
+
+    #[test]
+    fn only_the_shown_thumbnail_keeps_a_texture() {
+        let context = Context::default();
+        let mut process_thumbnail_cache = ProcessThumbnailCache::default();
+        let now = Instant::now();
+
+        for process_id in [1, 2] {
+            process_thumbnail_cache.complete_capture(process_id, create_captured_thumbnail(), now);
+            process_thumbnail_cache.ensure_texture(&context, process_id);
+        }
+
+        process_thumbnail_cache.evict(Some(2), now);
+
+        assert!(process_thumbnail_cache.get_texture(1).is_none());
+        assert!(process_thumbnail_cache.get_texture(2).is_some());
+        assert!(process_thumbnail_cache.get_thumbnail(1).is_some());
+
+        process_thumbnail_cache.evict(None, now);
+
+        assert!(!process_thumbnail_cache.has_textures());
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
     app_context::AppContext,
     ui::{draw::icon_draw::IconDraw, widgets::controls::button::Button},
-    views::project_explorer::{
-        project_hierarchy::view_data::project_hierarchy_view_data::ProjectHierarchyViewData,
-        project_selector::view_data::project_selector_view_data::ProjectSelectorViewData,
+    views::{
+        main_window::view_data::{
+            unsaved_changes_view_data::{UnsavedChangesAction, UnsavedChangesViewData},
+            write_sequence_view_data::WriteSequenceViewData,
+        },
+        project_explorer::project_hierarchy::view_data::project_hierarchy_view_data::ProjectHierarchyViewData,
     },
 };
 use eframe::egui::{Align, Layout, Response, Sense, Ui, UiBuilder, Widget};
@@ -15,6 +18,8 @@ use std::sync::Arc;
 pub struct ProjectHierarchyToolbarView {
     app_context: Arc<AppContext>,
     _project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+    unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
+    write_sequence_view_data: Dependency<WriteSequenceViewData>,
 }
 
 impl ProjectHierarchyToolbarView {
@@ -22,9 +27,17 @@ impl ProjectHierarchyToolbarView {
         let project_hierarchy_view_data = app_context
             .dependency_container
             .get_dependency::<ProjectHierarchyViewData>();
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .get_dependency::<UnsavedChangesViewData>();
+        let write_sequence_view_data = app_context
+            .dependency_container
+            .get_dependency::<WriteSequenceViewData>();
         let instance = Self {
             app_context,
             _project_hierarchy_view_data: project_hierarchy_view_data,
+            unsaved_changes_view_data,
+            write_sequence_view_data,
         };
 
         instance
@@ -63,7 +76,33 @@ impl Widget for ProjectHierarchyToolbarView {
             IconDraw::draw(user_interface, button_refresh.rect, &theme.icon_library.icon_handle_close);
 
             if button_refresh.clicked() {
-                ProjectSelectorViewData::close_current_project(self.app_context.clone());
+                UnsavedChangesViewData::request_action(self.unsaved_changes_view_data.clone(), self.app_context.clone(), UnsavedChangesAction::CloseProject);
+            }
+
+            // Save project.
+            let button_save = user_interface.add_sized(
+                button_size,
+                Button::new_from_theme(theme)
+                    .with_tooltip_text("Save this project.")
+                    .background_color(Color32::TRANSPARENT),
+            );
+            IconDraw::draw(user_interface, button_save.rect, &theme.icon_library.icon_handle_common_check_mark);
+
+            if button_save.clicked() {
+                ProjectHierarchyViewData::save_project(self.app_context.clone());
+            }
+
+            // Write sequences.
+            let button_write_sequences = user_interface.add_sized(
+                button_size,
+                Button::new_from_theme(theme)
+                    .with_tooltip_text("Build and run timed write sequences.")
+                    .background_color(Color32::TRANSPARENT),
+            );
+            IconDraw::draw(user_interface, button_write_sequences.rect, &theme.icon_library.icon_handle_project_script);
+
+            if button_write_sequences.clicked() {
+                WriteSequenceViewData::open_dialog(self.write_sequence_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
             }
         });
 
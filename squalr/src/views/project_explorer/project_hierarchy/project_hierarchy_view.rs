@@ -1,19 +1,44 @@
 use crate::{
     app_context::AppContext,
+    ui::widgets::controls::check_state::CheckState,
+    views::main_window::view_data::project_item_hotkeys_view_data::ProjectItemHotkeysViewData,
+    views::main_window::view_data::value_watches_view_data::ValueWatchesViewData,
     views::project_explorer::project_hierarchy::{
         project_hierarchy_toolbar_view::ProjectHierarchyToolbarView,
-        view_data::{project_hierarchy_frame_action::ProjectHierarchyFrameAction, project_hierarchy_view_data::ProjectHierarchyViewData},
+        project_item_entry_view::ProjectItemEntryView,
+        view_data::{
+            project_hierarchy_frame_action::ProjectHierarchyFrameAction, project_hierarchy_row::ProjectHierarchyRow,
+            project_hierarchy_view_data::ProjectHierarchyViewData,
+        },
     },
 };
-use eframe::egui::{Align, Layout, Response, Ui, Widget};
-use squalr_engine_api::dependency_injection::dependency::Dependency;
+use eframe::egui::{Align, Button, Key, Layout, Response, ScrollArea, Sense, TextEdit, TextureHandle, Ui, Widget, Window, vec2};
+use squalr_engine_api::{
+    dependency_injection::dependency::Dependency,
+    structures::memory::memory_address_target::MemoryAddressTarget,
+    structures::projects::{
+        project::Project,
+        project_items::{
+            built_in_types::{
+                project_item_type_address::ProjectItemTypeAddress, project_item_type_code_bookmark::ProjectItemTypeCodeBookmark,
+                project_item_type_pointer::ProjectItemTypePointer,
+            },
+            project_item_ref::ProjectItemRef,
+        },
+        project_search::ProjectSearch,
+    },
+};
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Instant;
 
 #[derive(Clone)]
 pub struct ProjectHierarchyView {
-    _app_context: Arc<AppContext>,
+    app_context: Arc<AppContext>,
     project_hierarchy_toolbar_view: ProjectHierarchyToolbarView,
     project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+    project_item_hotkeys_view_data: Dependency<ProjectItemHotkeysViewData>,
+    value_watches_view_data: Dependency<ValueWatchesViewData>,
 }
 
 impl ProjectHierarchyView {
@@ -21,12 +46,190 @@ impl ProjectHierarchyView {
         let project_hierarchy_view_data = app_context
             .dependency_container
             .get_dependency::<ProjectHierarchyViewData>();
+        let project_item_hotkeys_view_data = app_context
+            .dependency_container
+            .get_dependency::<ProjectItemHotkeysViewData>();
+        let value_watches_view_data = app_context
+            .dependency_container
+            .get_dependency::<ValueWatchesViewData>();
         let project_hierarchy_toolbar_view = ProjectHierarchyToolbarView::new(app_context.clone());
 
         Self {
-            _app_context: app_context,
+            app_context,
             project_hierarchy_toolbar_view,
             project_hierarchy_view_data,
+            project_item_hotkeys_view_data,
+            value_watches_view_data,
+        }
+    }
+
+    /// Collects the rows to show, along with the search matches in display order. While searching, only matches and the groups
+    /// containing them are expanded. Other groups are shown collapsed, and can be expanded to browse their entries. Otherwise, all
+    /// groups are shown expanded, except for those collapsed by hand.
+    fn collect_rows(
+        &self,
+        search_query: &str,
+        expanded_group_refs: &HashSet<ProjectItemRef>,
+        collapsed_group_refs: &HashSet<ProjectItemRef>,
+    ) -> (Vec<ProjectHierarchyRow>, Vec<ProjectItemRef>) {
+        let opened_project = self
+            .app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let opened_project = match opened_project.read() {
+            Ok(opened_project) => opened_project,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return (Vec::new(), Vec::new());
+            }
+        };
+        let project = match opened_project.as_ref() {
+            Some(project) => project,
+            None => return (Vec::new(), Vec::new()),
+        };
+        let search_match_refs: Vec<ProjectItemRef> = ProjectSearch::find_matches(project, search_query)
+            .into_iter()
+            .map(|search_match| search_match.get_project_item_ref().clone())
+            .collect();
+        let is_searching = !search_query.trim().is_empty();
+        let is_group_open = |project_item_ref: &ProjectItemRef| {
+            expanded_group_refs.contains(project_item_ref)
+                || search_match_refs.iter().any(|search_match_ref| {
+                    search_match_ref
+                        .get_project_item_path()
+                        .starts_with(project_item_ref.get_project_item_path())
+                })
+        };
+        let project_directory = project
+            .get_project_info()
+            .get_project_directory()
+            .unwrap_or_default();
+        let mut rows: Vec<ProjectHierarchyRow> = project
+            .get_project_items()
+            .iter()
+            .filter_map(|(project_item_ref, project_item)| {
+                let relative_path = project_item_ref
+                    .get_project_item_path()
+                    .strip_prefix(&project_directory)
+                    .ok()?;
+                let depth = relative_path.components().count();
+
+                // The project root is the directory itself, which is implied rather than shown.
+                if depth == 0 {
+                    return None;
+                }
+
+                let project_item_type_id = project_item.get_item_type().get_project_item_type_id();
+                let is_group = project.is_project_group(project_item_ref);
+                let is_search_match = search_match_refs.contains(project_item_ref);
+                let parent_refs: Vec<ProjectItemRef> = project_item_ref
+                    .get_project_item_path()
+                    .ancestors()
+                    .skip(1)
+                    .take(depth - 1)
+                    .map(|ancestor_path| ProjectItemRef::new(ancestor_path.to_path_buf()))
+                    .collect();
+                let is_expanded = is_group
+                    && if is_searching {
+                        is_group_open(project_item_ref)
+                    } else {
+                        !collapsed_group_refs.contains(project_item_ref)
+                    };
+
+                if is_searching {
+                    if !parent_refs.iter().all(&is_group_open) {
+                        return None;
+                    }
+
+                    // Entries in a group expanded by hand are all shown, such that the group can be browsed as usual.
+                    let is_in_expanded_group = parent_refs
+                        .first()
+                        .is_some_and(|parent_ref| expanded_group_refs.contains(parent_ref));
+
+                    if !is_group && !is_search_match && !is_in_expanded_group {
+                        return None;
+                    }
+                } else if parent_refs
+                    .iter()
+                    .any(|parent_ref| collapsed_group_refs.contains(parent_ref))
+                {
+                    return None;
+                }
+
+                let icon = if is_group {
+                    let icon_library = &self.app_context.theme.icon_library;
+
+                    Some(if is_expanded {
+                        icon_library.icon_handle_navigation_down_arrow_small.clone()
+                    } else {
+                        icon_library.icon_handle_navigation_right_arrow_small.clone()
+                    })
+                } else {
+                    self.get_project_item_icon(project_item_type_id)
+                };
+                let is_address = project_item_type_id == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID;
+                let address_target = is_address.then(|| {
+                    MemoryAddressTarget::new(
+                        ProjectItemTypeAddress::get_field_address(project_item),
+                        ProjectItemTypeAddress::get_field_module(project_item),
+                    )
+                });
+                let check_state = if is_group {
+                    ProjectHierarchyRow::get_group_check_state(project, project_item_ref)
+                } else {
+                    CheckState::from_bool(project_item.get_is_enabled())
+                };
+
+                Some(ProjectHierarchyRow {
+                    project_item_ref: project_item_ref.clone(),
+                    name: project_item.get_field_name(),
+                    depth: depth - 1,
+                    icon,
+                    is_group,
+                    is_address,
+                    address_target,
+                    is_expanded,
+                    has_children: is_group && !project.get_descendant_refs(project_item_ref).is_empty(),
+                    is_enabled: project_item.get_is_enabled(),
+                    is_enabled_by_groups: Self::is_enabled_by_groups(project, project_item_ref),
+                    check_state,
+                    is_search_match,
+                })
+            })
+            .collect();
+
+        rows.sort_by(|left, right| {
+            left.project_item_ref
+                .get_project_item_path()
+                .cmp(right.project_item_ref.get_project_item_path())
+        });
+
+        (rows, search_match_refs)
+    }
+
+    fn is_enabled_by_groups(
+        project: &Project,
+        project_item_ref: &ProjectItemRef,
+    ) -> bool {
+        match project_item_ref.get_project_item_path().parent() {
+            Some(parent_path) => project.is_project_item_enabled(&ProjectItemRef::new(parent_path.to_path_buf())),
+            None => true,
+        }
+    }
+
+    fn get_project_item_icon(
+        &self,
+        project_item_type_id: &str,
+    ) -> Option<TextureHandle> {
+        let icon_library = &self.app_context.theme.icon_library;
+
+        if project_item_type_id == ProjectItemTypeCodeBookmark::PROJECT_ITEM_TYPE_ID {
+            Some(icon_library.icon_handle_project_cpu_instruction.clone())
+        } else if project_item_type_id == ProjectItemTypePointer::PROJECT_ITEM_TYPE_ID {
+            Some(icon_library.icon_handle_project_pointer_type.clone())
+        } else {
+            None
         }
     }
 }
@@ -36,20 +239,207 @@ impl Widget for ProjectHierarchyView {
         self,
         user_interface: &mut Ui,
     ) -> Response {
-        let project_hierarchy_frame_action = ProjectHierarchyFrameAction::None;
+        let theme = &self.app_context.theme;
+        let mut project_hierarchy_frame_action = ProjectHierarchyFrameAction::None;
+        let (search_query, expanded_group_refs, collapsed_group_refs, current_match_index, is_scroll_to_current_match_pending, pending_delete_group) = self
+            .project_hierarchy_view_data
+            .read("Project hierarchy view")
+            .map(|project_hierarchy_view_data| {
+                (
+                    project_hierarchy_view_data.search_query.clone(),
+                    project_hierarchy_view_data.expanded_group_refs.clone(),
+                    project_hierarchy_view_data.collapsed_group_refs.clone(),
+                    project_hierarchy_view_data.current_match_index,
+                    project_hierarchy_view_data.is_scroll_to_current_match_pending,
+                    project_hierarchy_view_data.pending_delete_group.clone(),
+                )
+            })
+            .unwrap_or_default();
+        let (rows, search_match_refs) = self.collect_rows(&search_query, &expanded_group_refs, &collapsed_group_refs);
+        let value_flash_strengths = self
+            .value_watches_view_data
+            .read("Project hierarchy value flashes")
+            .map(|view_data| view_data.get_flash_strengths(Instant::now()))
+            .unwrap_or_default();
+        let current_match_ref = match search_match_refs.len() {
+            0 => None,
+            match_count => search_match_refs.get(current_match_index % match_count),
+        };
+        let mut is_search_query_changed = false;
+        let mut should_select_next_match = false;
+        let mut should_clear_search = false;
+        let mut should_cancel_delete_group = false;
+        let mut delete_group_move_children_to_parent: Option<bool> = None;
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
-                let _project_hierarchy_view_data = match self.project_hierarchy_view_data.read("Project hierarchy view") {
-                    Some(project_hierarchy_view_data) => project_hierarchy_view_data,
-                    None => return,
-                };
+                user_interface.add(self.project_hierarchy_toolbar_view.clone());
+
+                user_interface.horizontal(|user_interface| {
+                    let match_count_text = match current_match_ref {
+                        Some(_) => format!("{} / {}", current_match_index % search_match_refs.len() + 1, search_match_refs.len()),
+                        None if search_query.trim().is_empty() => String::new(),
+                        None => "No matches".to_string(),
+                    };
+                    let search_box_width = (user_interface.available_width() - 80.0).max(0.0);
+                    let search_box_response = match self
+                        .project_hierarchy_view_data
+                        .write("Project hierarchy search box")
+                    {
+                        Some(mut project_hierarchy_view_data) => user_interface.add_sized(
+                            vec2(search_box_width, 24.0),
+                            TextEdit::singleline(&mut project_hierarchy_view_data.search_query)
+                                .hint_text("Search entries, addresses, types, and groups")
+                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                .text_color(theme.foreground)
+                                .background_color(theme.background_primary),
+                        ),
+                        None => return,
+                    };
+
+                    if search_box_response.changed() {
+                        is_search_query_changed = true;
+                    }
+
+                    // Enter moves to the next match, keeping focus such that it can be pressed again. Esc clears the search.
+                    if search_box_response.lost_focus() {
+                        if user_interface.input(|input_state| input_state.key_pressed(Key::Enter)) {
+                            should_select_next_match = true;
+                            search_box_response.request_focus();
+                        } else if user_interface.input(|input_state| input_state.key_pressed(Key::Escape)) {
+                            should_clear_search = true;
+                        }
+                    }
+
+                    user_interface.label(match_count_text);
+                });
+
+                ScrollArea::vertical()
+                    .id_salt("project_hierarchy")
+                    .auto_shrink([false, false])
+                    .show(user_interface, |user_interface| {
+                        for row in &rows {
+                            let is_current_match = current_match_ref == Some(&row.project_item_ref);
+                            let flash_strength = row
+                                .address_target
+                                .as_ref()
+                                .and_then(|address_target| value_flash_strengths.get(&(address_target.address, address_target.module_name.clone())))
+                                .copied()
+                                .unwrap_or(0.0);
+                            let row_response = user_interface.add(ProjectItemEntryView::new(
+                                self.app_context.clone(),
+                                row,
+                                is_current_match,
+                                flash_strength,
+                                &mut project_hierarchy_frame_action,
+                            ));
+
+                            if is_current_match && is_scroll_to_current_match_pending {
+                                row_response.scroll_to_me(Some(Align::Center));
+                            }
+                        }
+
+                        // The space below the entries stands for the root of the project, such that items can be dropped out of groups.
+                        let root_area_size = vec2(user_interface.available_width(), user_interface.available_height().max(28.0));
+                        let (_root_area_rectangle, root_area_response) = user_interface.allocate_exact_size(root_area_size, Sense::click());
+
+                        if let Some(dragged_project_item_ref) = root_area_response.dnd_release_payload::<ProjectItemRef>() {
+                            project_hierarchy_frame_action = ProjectHierarchyFrameAction::MoveProjectItem(dragged_project_item_ref.as_ref().clone(), None);
+                        }
 
-                user_interface.add(self.project_hierarchy_toolbar_view);
+                        root_area_response.context_menu(|user_interface| {
+                            if user_interface.add(Button::new("Add group")).clicked() {
+                                project_hierarchy_frame_action = ProjectHierarchyFrameAction::CreateGroup(None);
+                                user_interface.close();
+                            }
+                        });
+                    });
             })
             .response;
 
+        if let Some((_group_ref, group_name)) = &pending_delete_group {
+            Window::new("Delete group")
+                .collapsible(false)
+                .resizable(false)
+                .show(user_interface.ctx(), |user_interface| {
+                    user_interface.label(format!(
+                        "'{}' is not empty. Delete everything within it, or keep its entries by moving them out of the group?",
+                        group_name
+                    ));
+
+                    user_interface.horizontal(|user_interface| {
+                        if user_interface.button("Cancel").clicked() {
+                            should_cancel_delete_group = true;
+                        }
+
+                        if user_interface.button("Move entries out").clicked() {
+                            delete_group_move_children_to_parent = Some(true);
+                        }
+
+                        if user_interface.button("Delete all").clicked() {
+                            delete_group_move_children_to_parent = Some(false);
+                        }
+                    });
+                });
+        }
+
+        if should_cancel_delete_group {
+            ProjectHierarchyViewData::cancel_delete_group(self.project_hierarchy_view_data.clone());
+        } else if let Some(move_children_to_parent) = delete_group_move_children_to_parent {
+            ProjectHierarchyViewData::confirm_delete_group(self.project_hierarchy_view_data.clone(), self.app_context.clone(), move_children_to_parent);
+        }
+
+        if is_scroll_to_current_match_pending
+            && let Some(mut project_hierarchy_view_data) = self
+                .project_hierarchy_view_data
+                .write("Project hierarchy scrolled to current match")
+        {
+            project_hierarchy_view_data.is_scroll_to_current_match_pending = false;
+        }
+
+        if should_clear_search {
+            ProjectHierarchyViewData::clear_search(self.project_hierarchy_view_data.clone());
+        } else if is_search_query_changed {
+            ProjectHierarchyViewData::on_search_query_changed(self.project_hierarchy_view_data.clone());
+        } else if should_select_next_match {
+            ProjectHierarchyViewData::select_next_match(self.project_hierarchy_view_data.clone(), search_match_refs.len());
+        }
+
         match project_hierarchy_frame_action {
             ProjectHierarchyFrameAction::None => {}
+            ProjectHierarchyFrameAction::SetProjectItemEnabled(project_item_ref, is_enabled) => {
+                ProjectHierarchyViewData::set_project_item_enabled(self.app_context.clone(), project_item_ref, is_enabled, false);
+            }
+            ProjectHierarchyFrameAction::SetGroupEnabled(project_item_ref, is_enabled) => {
+                ProjectHierarchyViewData::set_project_item_enabled(self.app_context.clone(), project_item_ref, is_enabled, true);
+            }
+            ProjectHierarchyFrameAction::ToggleGroupExpanded(project_item_ref) => {
+                ProjectHierarchyViewData::toggle_group_expanded(self.project_hierarchy_view_data.clone(), project_item_ref);
+            }
+            ProjectHierarchyFrameAction::MoveProjectItem(project_item_ref, target_group_ref) => {
+                ProjectHierarchyViewData::move_project_item(self.app_context.clone(), project_item_ref, target_group_ref);
+            }
+            ProjectHierarchyFrameAction::CreateGroup(parent_group_ref) => {
+                ProjectHierarchyViewData::create_group(self.app_context.clone(), parent_group_ref);
+            }
+            ProjectHierarchyFrameAction::OpenHotkeyDialog(project_item_ref) => {
+                ProjectItemHotkeysViewData::open_dialog(
+                    self.project_item_hotkeys_view_data.clone(),
+                    self.app_context.engine_unprivileged_state.clone(),
+                    project_item_ref,
+                );
+            }
+            ProjectHierarchyFrameAction::OpenValueWatchDialog(project_item_ref) => {
+                ProjectHierarchyViewData::open_value_watch_dialog(self.app_context.clone(), self.value_watches_view_data.clone(), project_item_ref);
+            }
+            ProjectHierarchyFrameAction::DeleteProjectItem(project_item_ref) => {
+                // Groups with entries ask what to do with them first. Anything else is deleted right away.
+                match rows.iter().find(|row| row.project_item_ref == project_item_ref) {
+                    Some(row) if row.has_children => {
+                        ProjectHierarchyViewData::request_delete_group(self.project_hierarchy_view_data.clone(), project_item_ref, row.name.clone());
+                    }
+                    _ => ProjectHierarchyViewData::delete_project_item(self.app_context.clone(), project_item_ref, false),
+                }
+            }
         }
 
         response
@@ -1,35 +1,67 @@
 use crate::{
     app_context::AppContext,
-    ui::{draw::icon_draw::IconDraw, widgets::controls::state_layer::StateLayer},
-    views::project_explorer::project_hierarchy::view_data::project_hierarchy_frame_action::ProjectHierarchyFrameAction,
+    ui::{
+        draw::icon_draw::IconDraw,
+        widgets::controls::{check_state::CheckState, checkbox::Checkbox, state_layer::StateLayer},
+    },
+    views::project_explorer::project_hierarchy::view_data::{
+        project_hierarchy_frame_action::ProjectHierarchyFrameAction, project_hierarchy_row::ProjectHierarchyRow,
+    },
 };
-use eframe::egui::{Align2, Rect, Response, Sense, TextureHandle, Ui, Widget, pos2, vec2};
+use eframe::egui::{Align2, Button, Rect, Response, Sense, Ui, Widget, pos2, vec2};
 use epaint::{CornerRadius, Stroke, StrokeKind};
-use squalr_engine_api::structures::projects::project_info::ProjectInfo;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
 use std::sync::Arc;
 
 pub struct ProjectItemEntryView<'lifetime> {
     app_context: Arc<AppContext>,
-    project_info: &'lifetime ProjectInfo,
-    icon: Option<TextureHandle>,
+    row: &'lifetime ProjectHierarchyRow,
     is_selected: bool,
-    _project_hierarchy_frame_action: &'lifetime mut ProjectHierarchyFrameAction,
+    /// How strongly the row flashes, as a watch on the value of its entry just triggered.
+    flash_strength: f32,
+    project_hierarchy_frame_action: &'lifetime mut ProjectHierarchyFrameAction,
 }
 
 impl<'lifetime> ProjectItemEntryView<'lifetime> {
     pub fn new(
         app_context: Arc<AppContext>,
-        project_info: &'lifetime ProjectInfo,
-        icon: Option<TextureHandle>,
+        row: &'lifetime ProjectHierarchyRow,
         is_selected: bool,
+        flash_strength: f32,
         project_hierarchy_frame_action: &'lifetime mut ProjectHierarchyFrameAction,
     ) -> Self {
         Self {
             app_context: app_context,
-            project_info,
-            icon,
+            row,
             is_selected,
-            _project_hierarchy_frame_action: project_hierarchy_frame_action,
+            flash_strength,
+            project_hierarchy_frame_action,
+        }
+    }
+
+    /// Gets the group that items dropped onto this row are moved into. Dropping onto an entry moves the item next to it.
+    fn get_drop_target_ref(&self) -> Option<ProjectItemRef> {
+        if self.row.is_group {
+            Some(self.row.project_item_ref.clone())
+        } else {
+            self.row
+                .project_item_ref
+                .get_project_item_path()
+                .parent()
+                .map(|parent_path| ProjectItemRef::new(parent_path.to_path_buf()))
+        }
+    }
+
+    /// Gets whether the dragged item can be dropped onto this row, which is not the case for groups dropped within themselves.
+    fn can_drop(
+        &self,
+        dragged_project_item_ref: &ProjectItemRef,
+    ) -> bool {
+        match self.get_drop_target_ref() {
+            Some(drop_target_ref) => !drop_target_ref
+                .get_project_item_path()
+                .starts_with(dragged_project_item_ref.get_project_item_path()),
+            None => false,
         }
     }
 }
@@ -40,17 +72,38 @@ impl<'lifetime> Widget for ProjectItemEntryView<'lifetime> {
         user_interface: &mut Ui,
     ) -> Response {
         let theme = &self.app_context.theme;
+        let row = self.row;
         let icon_size = vec2(16.0, 16.0);
         let text_left_padding = 4.0;
+        let depth_indent = 16.0;
         let row_height = 28.0;
-        let (allocated_size_rectangle, response) = user_interface.allocate_exact_size(vec2(user_interface.available_size().x, row_height), Sense::click());
+        let (allocated_size_rectangle, response) =
+            user_interface.allocate_exact_size(vec2(user_interface.available_size().x, row_height), Sense::click_and_drag());
+
+        response.dnd_set_drag_payload(row.project_item_ref.clone());
+
+        let is_drop_target = response
+            .dnd_hover_payload::<ProjectItemRef>()
+            .is_some_and(|dragged_project_item_ref| self.can_drop(&dragged_project_item_ref));
 
         if self.is_selected {
             // Draw the background.
             user_interface
                 .painter()
                 .rect_filled(allocated_size_rectangle, CornerRadius::ZERO, theme.selected_background);
+        }
 
+        if self.flash_strength > 0.0 {
+            user_interface.painter().rect_filled(
+                allocated_size_rectangle,
+                CornerRadius::ZERO,
+                theme
+                    .background_control_warning
+                    .gamma_multiply(0.5 * self.flash_strength),
+            );
+        }
+
+        if self.is_selected || is_drop_target {
             // Draw the border.
             user_interface.painter().rect_stroke(
                 allocated_size_rectangle,
@@ -73,32 +126,115 @@ impl<'lifetime> Widget for ProjectItemEntryView<'lifetime> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
-        if response.clicked() {
-            //
+        // Search matches are marked with an accent along the left edge.
+        if row.is_search_match {
+            let accent_rectangle = Rect::from_min_size(allocated_size_rectangle.min, vec2(3.0, allocated_size_rectangle.height()));
+
+            user_interface
+                .painter()
+                .rect_filled(accent_rectangle, CornerRadius::ZERO, theme.border_blue);
+        }
+
+        if response.clicked() && row.is_group {
+            *self.project_hierarchy_frame_action = ProjectHierarchyFrameAction::ToggleGroupExpanded(row.project_item_ref.clone());
+        }
+
+        if let Some(dragged_project_item_ref) = response.dnd_release_payload::<ProjectItemRef>()
+            && self.can_drop(&dragged_project_item_ref)
+        {
+            *self.project_hierarchy_frame_action =
+                ProjectHierarchyFrameAction::MoveProjectItem(dragged_project_item_ref.as_ref().clone(), self.get_drop_target_ref());
+        }
+
+        // Enabled checkbox. This reflects the item's own state, whereas the label is greyed out if any containing group is disabled.
+        // Groups show whether everything within them is enabled, and set everything within them when clicked.
+        let checkbox_size = vec2(Checkbox::WIDTH, Checkbox::HEIGHT);
+        let checkbox_position = pos2(
+            allocated_size_rectangle.min.x + 8.0 + row.depth as f32 * depth_indent,
+            allocated_size_rectangle.center().y - checkbox_size.y * 0.5,
+        );
+        let checkbox_rectangle = Rect::from_min_size(checkbox_position, checkbox_size);
+        let checkbox_tooltip_text = if row.is_group {
+            "Enabled. Enables or disables this group and everything within it."
+        } else {
+            "Enabled. Disabled entries are not refreshed, frozen, or triggered."
+        };
+
+        if user_interface
+            .place(
+                checkbox_rectangle,
+                Checkbox::new_from_theme(theme)
+                    .with_check_state(row.check_state)
+                    .with_tooltip_text(checkbox_tooltip_text),
+            )
+            .clicked()
+        {
+            *self.project_hierarchy_frame_action = if row.is_group {
+                match row.check_state {
+                    CheckState::False => ProjectHierarchyFrameAction::SetGroupEnabled(row.project_item_ref.clone(), true),
+                    CheckState::Mixed | CheckState::True => ProjectHierarchyFrameAction::SetGroupEnabled(row.project_item_ref.clone(), false),
+                }
+            } else {
+                ProjectHierarchyFrameAction::SetProjectItemEnabled(row.project_item_ref.clone(), !row.is_enabled)
+            };
         }
 
         // Draw icon and label inside layout.
-        let icon_pos_x = allocated_size_rectangle.min.x;
+        let icon_pos_x = checkbox_rectangle.max.x + text_left_padding;
         let icon_pos_y = allocated_size_rectangle.center().y - icon_size.y * 0.5;
         let icon_rect = Rect::from_min_size(pos2(icon_pos_x, icon_pos_y), icon_size);
         let text_pos = pos2(icon_rect.max.x + text_left_padding, allocated_size_rectangle.center().y);
 
-        if let Some(icon) = &self.icon {
+        if let Some(icon) = &row.icon {
             IconDraw::draw_sized(user_interface, icon_rect.center(), icon_size, icon);
         }
 
         user_interface.painter().text(
             text_pos,
             Align2::LEFT_CENTER,
-            self.project_info.get_name(),
+            &row.name,
             theme.font_library.font_noto_sans.font_normal.clone(),
-            theme.foreground,
+            if row.is_enabled && row.is_enabled_by_groups {
+                theme.foreground
+            } else {
+                theme.foreground_preview
+            },
         );
 
+        // New groups are created next to entries, or within the group that was clicked.
+        let parent_group_ref = self.get_drop_target_ref();
+
+        response.context_menu(|user_interface| {
+            if user_interface.add(Button::new("Add group")).clicked() {
+                *self.project_hierarchy_frame_action = ProjectHierarchyFrameAction::CreateGroup(parent_group_ref);
+                user_interface.close();
+            }
+
+            if row.is_address && user_interface.add(Button::new("Set hotkey...")).clicked() {
+                *self.project_hierarchy_frame_action = ProjectHierarchyFrameAction::OpenHotkeyDialog(row.project_item_ref.clone());
+                user_interface.close();
+            }
+
+            if row.is_address
+                && user_interface
+                    .add(Button::new("Notify on change..."))
+                    .on_hover_text("Shows a notification whenever this value changes, optionally only past a threshold or to a value.")
+                    .clicked()
+            {
+                *self.project_hierarchy_frame_action = ProjectHierarchyFrameAction::OpenValueWatchDialog(row.project_item_ref.clone());
+                user_interface.close();
+            }
+
+            if user_interface.add(Button::new("Delete")).clicked() {
+                *self.project_hierarchy_frame_action = ProjectHierarchyFrameAction::DeleteProjectItem(row.project_item_ref.clone());
+                user_interface.close();
+            }
+        });
+
         response
     }
 }
@@ -1,2 +1,3 @@
 pub mod project_hierarchy_frame_action;
+pub mod project_hierarchy_row;
 pub mod project_hierarchy_view_data;
@@ -1,4 +1,18 @@
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+
 #[derive(Clone, PartialEq)]
 pub enum ProjectHierarchyFrameAction {
     None,
+    SetProjectItemEnabled(ProjectItemRef, bool),
+    SetGroupEnabled(ProjectItemRef, bool),
+    ToggleGroupExpanded(ProjectItemRef),
+    /// Moves an item into the given group, or to the root of the project if no group is given.
+    MoveProjectItem(ProjectItemRef, Option<ProjectItemRef>),
+    /// Creates a group within the given group, or at the root of the project if no group is given.
+    CreateGroup(Option<ProjectItemRef>),
+    /// Opens the dialog to bind hotkeys to an address entry.
+    OpenHotkeyDialog(ProjectItemRef),
+    /// Opens the dialog to be notified when the value of an address entry changes.
+    OpenValueWatchDialog(ProjectItemRef),
+    DeleteProjectItem(ProjectItemRef),
 }
@@ -0,0 +1,93 @@
+use crate::ui::widgets::controls::check_state::CheckState;
+use eframe::egui::TextureHandle;
+use squalr_engine_api::structures::memory::memory_address_target::MemoryAddressTarget;
+use squalr_engine_api::structures::projects::{project::Project, project_items::project_item_ref::ProjectItemRef};
+
+/// A single row of the project hierarchy, captured such that the project lock is not held while drawing.
+pub struct ProjectHierarchyRow {
+    pub project_item_ref: ProjectItemRef,
+    pub name: String,
+    pub depth: usize,
+    pub icon: Option<TextureHandle>,
+    pub is_group: bool,
+    /// Whether the row is an address entry, which hotkeys and value watches can be bound to.
+    pub is_address: bool,
+    /// The address of an address entry, relative to its module, such that the row flashes when a watch on its value triggers.
+    pub address_target: Option<MemoryAddressTarget>,
+    pub is_expanded: bool,
+    pub has_children: bool,
+    pub is_enabled: bool,
+    pub is_enabled_by_groups: bool,
+    pub check_state: CheckState,
+    pub is_search_match: bool,
+}
+
+impl ProjectHierarchyRow {
+    /// Gets the check state of a group, which is mixed if the group and the items within it are not all enabled or all disabled.
+    pub fn get_group_check_state(
+        project: &Project,
+        group_ref: &ProjectItemRef,
+    ) -> CheckState {
+        let mut enabled_states = project
+            .get_descendant_refs(group_ref)
+            .into_iter()
+            .chain(std::iter::once(group_ref.clone()))
+            .filter_map(|project_item_ref| project.get_project_item(&project_item_ref))
+            .map(|project_item| project_item.get_is_enabled());
+        let is_group_enabled = match enabled_states.next() {
+            Some(is_enabled) => is_enabled,
+            None => return CheckState::False,
+        };
+
+        if enabled_states.all(|is_enabled| is_enabled == is_group_enabled) {
+            CheckState::from_bool(is_group_enabled)
+        } else {
+            CheckState::Mixed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProjectHierarchyRow;
+    use crate::ui::widgets::controls::check_state::CheckState;
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::projects::{
+        project::Project,
+        project_info::ProjectInfo,
+        project_items::{
+            built_in_types::{project_item_type_address::ProjectItemTypeAddress, project_item_type_directory::ProjectItemTypeDirectory},
+            project_item_ref::ProjectItemRef,
+        },
+        project_manifest::ProjectManifest,
+    };
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn groups_are_mixed_until_every_entry_matches_the_group() {
+        let project_directory = PathBuf::from("projects").join("test");
+        let project_root_ref = ProjectItemRef::new(project_directory.clone());
+        let mut project_items = HashMap::new();
+
+        project_items.insert(project_root_ref.clone(), ProjectItemTypeDirectory::new_project_item(&project_root_ref));
+
+        let project_info = ProjectInfo::new(project_directory.join(Project::PROJECT_FILE), None, ProjectManifest::default());
+        let mut project = Project::new(project_info, project_items, project_root_ref);
+        let group_ref = project.add_project_group_with_unique_name("Player");
+        let address_ref = project.add_project_item_to_group_with_unique_name(
+            &group_ref,
+            ProjectItemTypeAddress::new_project_item("health", 0x1000, "", "", DataTypeU32::get_value_from_primitive(100)),
+        );
+
+        assert!(ProjectHierarchyRow::get_group_check_state(&project, &group_ref) == CheckState::True);
+
+        project.set_project_item_enabled(&address_ref, false);
+
+        assert!(ProjectHierarchyRow::get_group_check_state(&project, &group_ref) == CheckState::Mixed);
+
+        project.set_project_item_enabled_recursive(&group_ref, false);
+
+        assert!(ProjectHierarchyRow::get_group_check_state(&project, &group_ref) == CheckState::False);
+    }
+}
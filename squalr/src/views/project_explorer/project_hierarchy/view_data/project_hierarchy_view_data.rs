@@ -1,8 +1,288 @@
+use crate::app_context::AppContext;
+use crate::views::main_window::view_data::value_watches_view_data::ValueWatchesViewData;
+use squalr_engine_api::commands::project::save::project_save_request::ProjectSaveRequest;
+use squalr_engine_api::commands::project_items::create_group::project_items_create_group_request::ProjectItemsCreateGroupRequest;
+use squalr_engine_api::commands::project_items::delete::project_items_delete_request::ProjectItemsDeleteRequest;
+use squalr_engine_api::commands::project_items::enable::project_items_enable_request::ProjectItemsEnableRequest;
+use squalr_engine_api::commands::project_items::move_to_group::project_items_move_to_group_request::ProjectItemsMoveToGroupRequest;
+use squalr_engine_api::commands::unprivileged_command_request::UnprivilegedCommandRequest;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::project_item_ref::ProjectItemRef;
+use std::collections::HashSet;
+use std::sync::Arc;
+
 #[derive(Clone)]
-pub struct ProjectHierarchyViewData {}
+pub struct ProjectHierarchyViewData {
+    /// The text entries are filtered by. This only affects what is shown, as refreshing and freezing apply to all entries.
+    pub search_query: String,
+
+    /// The index of the match that Enter last moved to, within the matches of the current query.
+    pub current_match_index: usize,
+
+    /// Set when the current match changes, such that it is scrolled into view on the next frame.
+    pub is_scroll_to_current_match_pending: bool,
+
+    /// Groups without matches that were expanded while searching. Such groups are otherwise shown collapsed.
+    pub expanded_group_refs: HashSet<ProjectItemRef>,
+
+    /// Groups that were collapsed while browsing. Groups are otherwise shown expanded.
+    pub collapsed_group_refs: HashSet<ProjectItemRef>,
+
+    /// A group with items in it that is about to be deleted, pending whether its items are deleted too or moved out of it.
+    pub pending_delete_group: Option<(ProjectItemRef, String)>,
+}
 
 impl ProjectHierarchyViewData {
+    const NEW_GROUP_NAME: &'static str = "New group";
+
     pub fn new() -> Self {
-        Self {}
+        Self {
+            search_query: String::new(),
+            current_match_index: 0,
+            is_scroll_to_current_match_pending: false,
+            expanded_group_refs: HashSet::new(),
+            collapsed_group_refs: HashSet::new(),
+            pending_delete_group: None,
+        }
+    }
+
+    /// Starts over from the first match, since the matches of the previous query no longer apply.
+    pub fn on_search_query_changed(project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy search query changed") {
+            project_hierarchy_view_data.current_match_index = 0;
+            project_hierarchy_view_data.is_scroll_to_current_match_pending = true;
+            project_hierarchy_view_data.expanded_group_refs.clear();
+        }
+    }
+
+    /// Moves to the next match, wrapping around to the first one.
+    pub fn select_next_match(
+        project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+        match_count: usize,
+    ) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy select next match") {
+            if match_count == 0 {
+                return;
+            }
+
+            project_hierarchy_view_data.current_match_index = (project_hierarchy_view_data.current_match_index + 1) % match_count;
+            project_hierarchy_view_data.is_scroll_to_current_match_pending = true;
+        }
+    }
+
+    pub fn clear_search(project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy clear search") {
+            project_hierarchy_view_data.search_query.clear();
+            project_hierarchy_view_data.current_match_index = 0;
+            project_hierarchy_view_data.is_scroll_to_current_match_pending = false;
+            project_hierarchy_view_data.expanded_group_refs.clear();
+        }
+    }
+
+    /// Expands or collapses a group. Searching tracks expanded groups separately, such that searches do not lose the groups that
+    /// were collapsed while browsing.
+    pub fn toggle_group_expanded(
+        project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+        project_item_ref: ProjectItemRef,
+    ) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy toggle group expanded") {
+            let project_hierarchy_view_data = &mut *project_hierarchy_view_data;
+            let toggled_group_refs = if project_hierarchy_view_data.search_query.trim().is_empty() {
+                &mut project_hierarchy_view_data.collapsed_group_refs
+            } else {
+                &mut project_hierarchy_view_data.expanded_group_refs
+            };
+
+            if !toggled_group_refs.remove(&project_item_ref) {
+                toggled_group_refs.insert(project_item_ref);
+            }
+        }
+    }
+
+    /// Asks whether the items within a group are deleted along with it, or moved into the group containing it.
+    pub fn request_delete_group(
+        project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+        project_item_ref: ProjectItemRef,
+        name: String,
+    ) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy request delete group") {
+            project_hierarchy_view_data.pending_delete_group = Some((project_item_ref, name));
+        }
+    }
+
+    pub fn cancel_delete_group(project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>) {
+        if let Some(mut project_hierarchy_view_data) = project_hierarchy_view_data.write("Project hierarchy cancel delete group") {
+            project_hierarchy_view_data.pending_delete_group = None;
+        }
+    }
+
+    /// Deletes the group that is pending deletion, either along with its items or after moving them into the group containing it.
+    pub fn confirm_delete_group(
+        project_hierarchy_view_data: Dependency<ProjectHierarchyViewData>,
+        app_context: Arc<AppContext>,
+        move_children_to_parent: bool,
+    ) {
+        let pending_delete_group = match project_hierarchy_view_data.write("Project hierarchy confirm delete group") {
+            Some(mut project_hierarchy_view_data) => project_hierarchy_view_data.pending_delete_group.take(),
+            None => return,
+        };
+
+        if let Some((project_item_ref, _name)) = pending_delete_group {
+            Self::delete_project_item(app_context, project_item_ref, move_children_to_parent);
+        }
+    }
+
+    pub fn save_project(app_context: Arc<AppContext>) {
+        let project_save_request = ProjectSaveRequest {};
+
+        project_save_request.send(&app_context.engine_unprivileged_state, move |project_save_response| {
+            if !project_save_response.success {
+                log::error!("Failed to save project!");
+            }
+        });
+    }
+
+    /// Enables or disables a project item. Recursive requests also set every item within a group, such that its check state is no
+    /// longer mixed.
+    pub fn set_project_item_enabled(
+        app_context: Arc<AppContext>,
+        project_item_ref: ProjectItemRef,
+        is_enabled: bool,
+        is_recursive: bool,
+    ) {
+        let project_items_enable_request = ProjectItemsEnableRequest {
+            project_item_paths: vec![
+                project_item_ref
+                    .get_project_item_path()
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            is_enabled,
+            is_recursive,
+        };
+
+        project_items_enable_request.send(&app_context.engine_unprivileged_state, move |project_items_enable_response| {
+            if !project_items_enable_response.success {
+                log::error!("Failed to set project item enabled state!");
+            }
+        });
+    }
+
+    /// Moves a project item into the given group, or to the root of the project if no group is given.
+    pub fn move_project_item(
+        app_context: Arc<AppContext>,
+        project_item_ref: ProjectItemRef,
+        target_group_ref: Option<ProjectItemRef>,
+    ) {
+        let project_items_move_to_group_request = ProjectItemsMoveToGroupRequest {
+            project_item_paths: vec![
+                project_item_ref
+                    .get_project_item_path()
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            target_group_path: target_group_ref.map(|target_group_ref| {
+                target_group_ref
+                    .get_project_item_path()
+                    .to_string_lossy()
+                    .to_string()
+            }),
+        };
+
+        project_items_move_to_group_request.send(&app_context.engine_unprivileged_state, move |project_items_move_to_group_response| {
+            if !project_items_move_to_group_response.success {
+                log::error!("Failed to move project item!");
+            }
+        });
+    }
+
+    /// Creates an empty group within the given group, or at the root of the project if no group is given.
+    pub fn create_group(
+        app_context: Arc<AppContext>,
+        parent_group_ref: Option<ProjectItemRef>,
+    ) {
+        let project_items_create_group_request = ProjectItemsCreateGroupRequest {
+            group_name: Self::NEW_GROUP_NAME.to_string(),
+            parent_group_path: parent_group_ref.map(|parent_group_ref| {
+                parent_group_ref
+                    .get_project_item_path()
+                    .to_string_lossy()
+                    .to_string()
+            }),
+        };
+
+        project_items_create_group_request.send(&app_context.engine_unprivileged_state, move |project_items_create_group_response| {
+            if !project_items_create_group_response.success {
+                log::error!("Failed to create project group!");
+            }
+        });
+    }
+
+    pub fn delete_project_item(
+        app_context: Arc<AppContext>,
+        project_item_ref: ProjectItemRef,
+        move_children_to_parent: bool,
+    ) {
+        let project_items_delete_request = ProjectItemsDeleteRequest {
+            project_item_paths: vec![
+                project_item_ref
+                    .get_project_item_path()
+                    .to_string_lossy()
+                    .to_string(),
+            ],
+            move_children_to_parent,
+        };
+
+        project_items_delete_request.send(&app_context.engine_unprivileged_state, move |project_items_delete_response| {
+            if !project_items_delete_response.success {
+                log::error!("Failed to delete project item!");
+            }
+        });
+    }
+
+    /// Opens the dialog to be notified when the value of an address entry changes, named after the entry.
+    pub fn open_value_watch_dialog(
+        app_context: Arc<AppContext>,
+        value_watches_view_data: Dependency<ValueWatchesViewData>,
+        project_item_ref: ProjectItemRef,
+    ) {
+        let opened_project = app_context
+            .engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let value_watch_target = match opened_project.read() {
+            Ok(opened_project) => opened_project
+                .as_ref()
+                .and_then(|project| project.get_project_item(&project_item_ref))
+                .and_then(|project_item| {
+                    let data_type_ref = ProjectItemTypeAddress::get_field_symbolic_struct_definition_reference(project_item)
+                        .map(|symbolic_struct_ref| DataTypeRef::new(symbolic_struct_ref.get_symbolic_struct_namespace()))?;
+
+                    Some((
+                        project_item.get_field_name(),
+                        ProjectItemTypeAddress::get_field_address(project_item),
+                        ProjectItemTypeAddress::get_field_module(project_item),
+                        data_type_ref,
+                    ))
+                }),
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return;
+            }
+        };
+
+        match value_watch_target {
+            Some((label, address, module_name, data_type_ref)) => ValueWatchesViewData::open_dialog(
+                value_watches_view_data,
+                app_context.engine_unprivileged_state.clone(),
+                label,
+                address,
+                module_name,
+                data_type_ref,
+            ),
+            None => log::error!("Unable to watch the project item, as it is not an address entry with a data type."),
+        }
     }
 }
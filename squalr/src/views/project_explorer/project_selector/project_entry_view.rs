@@ -1,5 +1,6 @@
 use crate::{
     app_context::AppContext,
+    models::appearance::appearance_settings::AppearanceSettings,
     ui::{
         draw::icon_draw::IconDraw,
         widgets::controls::{button::Button, context_menu::context_menu::ContextMenu, state_layer::StateLayer},
@@ -59,9 +60,11 @@ impl<'lifetime> Widget for ProjectEntryView<'lifetime> {
         user_interface: &mut Ui,
     ) -> Response {
         let theme = &self.app_context.theme;
-        let icon_size = vec2(16.0, 16.0);
+        let row_density = AppearanceSettings::get_row_density();
+        let icon_size = vec2(row_density.get_icon_size(), row_density.get_icon_size());
         let text_left_padding = 4.0;
-        let row_height = 32.0;
+        let row_height = row_density.get_row_height();
+        let row_font = row_density.get_font(&theme.font_library.font_noto_sans);
         let button_size = vec2(36.0, row_height);
         let desired_size = vec2(user_interface.available_width(), row_height);
         let (available_size_id, available_size_rect) = user_interface.allocate_space(desired_size);
@@ -94,7 +97,7 @@ impl<'lifetime> Widget for ProjectEntryView<'lifetime> {
             hover_color: theme.hover_tint,
             pressed_color: theme.pressed_tint,
             border_color: theme.background_control_secondary_dark,
-            border_color_focused: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
         }
         .paint(user_interface);
 
@@ -197,7 +200,7 @@ impl<'lifetime> Widget for ProjectEntryView<'lifetime> {
                             };
                             let (rename_project_text, should_highlight_text) = &mut *rename_project_guard;
                             let text_edit = TextEdit::singleline(rename_project_text)
-                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                .font(row_font.clone())
                                 .background_color(theme.background_control)
                                 .text_color(theme.foreground)
                                 .desired_width(f32::INFINITY);
@@ -226,7 +229,7 @@ impl<'lifetime> Widget for ProjectEntryView<'lifetime> {
                             user_interface.add(
                                 Label::new(
                                     RichText::new(self.project_info.get_name())
-                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .font(row_font.clone())
                                         .color(theme.foreground),
                                 )
                                 .selectable(false),
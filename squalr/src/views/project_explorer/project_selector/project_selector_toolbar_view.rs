@@ -1,7 +1,10 @@
 use crate::{
     app_context::AppContext,
     ui::{draw::icon_draw::IconDraw, widgets::controls::button::Button},
-    views::project_explorer::project_selector::view_data::project_selector_view_data::ProjectSelectorViewData,
+    views::{
+        main_window::view_data::unsaved_changes_view_data::{UnsavedChangesAction, UnsavedChangesViewData},
+        project_explorer::project_selector::view_data::project_selector_view_data::ProjectSelectorViewData,
+    },
 };
 use eframe::egui::{Align, Layout, Response, Sense, Ui, UiBuilder, Widget};
 use epaint::{Color32, CornerRadius, vec2};
@@ -12,6 +15,7 @@ use std::sync::Arc;
 pub struct ProjectSelectorToolbarView {
     app_context: Arc<AppContext>,
     project_selector_view_data: Dependency<ProjectSelectorViewData>,
+    unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
 }
 
 impl ProjectSelectorToolbarView {
@@ -19,9 +23,13 @@ impl ProjectSelectorToolbarView {
         let project_selector_view_data = app_context
             .dependency_container
             .get_dependency::<ProjectSelectorViewData>();
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .get_dependency::<UnsavedChangesViewData>();
         let instance = Self {
             app_context,
             project_selector_view_data,
+            unsaved_changes_view_data,
         };
 
         instance
@@ -73,7 +81,11 @@ impl Widget for ProjectSelectorToolbarView {
             IconDraw::draw(user_interface, button_create.rect, &theme.icon_library.icon_handle_file_system_browse_folder);
 
             if button_create.clicked() {
-                ProjectSelectorViewData::browse_for_project(self.project_selector_view_data.clone(), self.app_context.clone());
+                UnsavedChangesViewData::request_action(
+                    self.unsaved_changes_view_data.clone(),
+                    self.app_context.clone(),
+                    UnsavedChangesAction::BrowseForProject,
+                );
             }
 
             // Create project.
@@ -86,7 +98,7 @@ impl Widget for ProjectSelectorToolbarView {
             IconDraw::draw(user_interface, button_create.rect, &theme.icon_library.icon_handle_common_add);
 
             if button_create.clicked() {
-                ProjectSelectorViewData::create_new_project(self.project_selector_view_data.clone(), self.app_context.clone());
+                UnsavedChangesViewData::request_action(self.unsaved_changes_view_data.clone(), self.app_context.clone(), UnsavedChangesAction::CreateProject);
             }
         });
 
@@ -1,9 +1,12 @@
 use crate::{
     app_context::AppContext,
-    views::project_explorer::project_selector::{
-        project_entry_view::ProjectEntryView,
-        project_selector_toolbar_view::ProjectSelectorToolbarView,
-        view_data::{project_selector_frame_action::ProjectSelectorFrameAction, project_selector_view_data::ProjectSelectorViewData},
+    views::{
+        main_window::view_data::unsaved_changes_view_data::{UnsavedChangesAction, UnsavedChangesViewData},
+        project_explorer::project_selector::{
+            project_entry_view::ProjectEntryView,
+            project_selector_toolbar_view::ProjectSelectorToolbarView,
+            view_data::{project_selector_frame_action::ProjectSelectorFrameAction, project_selector_view_data::ProjectSelectorViewData},
+        },
     },
 };
 use eframe::egui::{Align, Layout, Response, ScrollArea, Ui, Widget};
@@ -15,6 +18,7 @@ pub struct ProjectSelectorView {
     app_context: Arc<AppContext>,
     project_selector_toolbar_view: ProjectSelectorToolbarView,
     project_selector_view_data: Dependency<ProjectSelectorViewData>,
+    unsaved_changes_view_data: Dependency<UnsavedChangesViewData>,
 }
 
 impl ProjectSelectorView {
@@ -24,6 +28,9 @@ impl ProjectSelectorView {
         let project_selector_view_data = app_context
             .dependency_container
             .get_dependency::<ProjectSelectorViewData>();
+        let unsaved_changes_view_data = app_context
+            .dependency_container
+            .get_dependency::<UnsavedChangesViewData>();
         let project_selector_toolbar_view = ProjectSelectorToolbarView::new(app_context.clone());
 
         // Perform an initial refresh on boot to load the project list.
@@ -33,6 +40,7 @@ impl ProjectSelectorView {
             app_context,
             project_selector_toolbar_view,
             project_selector_view_data,
+            unsaved_changes_view_data,
         }
     }
 }
@@ -117,11 +125,13 @@ impl Widget for ProjectSelectorView {
                 );
             }
             ProjectSelectorFrameAction::OpenProject(project_directory_path, project_name) => {
-                ProjectSelectorViewData::open_project(
-                    self.project_selector_view_data.clone(),
+                UnsavedChangesViewData::request_action(
+                    self.unsaved_changes_view_data.clone(),
                     self.app_context.clone(),
-                    project_directory_path,
-                    project_name,
+                    UnsavedChangesAction::OpenProject {
+                        project_directory_path,
+                        project_name,
+                    },
                 );
             }
             ProjectSelectorFrameAction::DeleteProject(project_directory_path, project_name) => {
@@ -1,4 +1,6 @@
+pub mod settings_tab_data_types_view;
 pub mod settings_tab_general_view;
 pub mod settings_tab_memory_view;
+pub mod settings_tab_performance_view;
 pub mod settings_tab_scan_view;
 pub mod settings_view;
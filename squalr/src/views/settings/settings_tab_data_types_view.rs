@@ -0,0 +1,191 @@
+use crate::{
+    app_context::AppContext,
+    ui::{
+        draw::icon_draw::IconDraw,
+        widgets::controls::{button::Button, groupbox::GroupBox},
+    },
+};
+use eframe::egui::{Align, Align2, Key, Layout, Response, RichText, TextEdit, Ui, Widget};
+use epaint::{Color32, vec2};
+use squalr_engine_api::commands::data_types::register::data_types_register_request::DataTypesRegisterRequest;
+use squalr_engine_api::commands::data_types::unregister::data_types_unregister_request::DataTypesUnregisterRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use squalr_engine_api::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+use std::sync::{Arc, RwLock};
+
+/// Lists user-defined struct data types, and allows registering new ones from a one line definition.
+/// Registration is sent to the engine, which persists the types; they appear in every data type selector once mirrored back.
+#[derive(Clone)]
+pub struct SettingsTabDataTypesView {
+    app_context: Arc<AppContext>,
+    new_definition_text: Arc<RwLock<String>>,
+    last_error: Arc<RwLock<Option<String>>>,
+}
+
+impl SettingsTabDataTypesView {
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        Self {
+            app_context,
+            new_definition_text: Arc::new(RwLock::new(String::new())),
+            last_error: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    fn register_definition(&self) {
+        let definition_text = match self.new_definition_text.read() {
+            Ok(new_definition_text) => new_definition_text.clone(),
+            Err(_error) => return,
+        };
+        let definition = match definition_text.parse::<CustomDataTypeDefinition>() {
+            Ok(definition) => definition,
+            Err(error) => {
+                self.set_last_error(Some(error));
+                return;
+            }
+        };
+        let data_types_register_request = DataTypesRegisterRequest { definitions: vec![definition] };
+        let settings_view = self.clone();
+
+        data_types_register_request.send(&self.app_context.engine_unprivileged_state, move |data_types_register_response| {
+            CustomDataTypeRegistry::get_instance().mirror_definitions(data_types_register_response.definitions);
+
+            match data_types_register_response.error_messages.into_iter().next() {
+                Some(error_message) => settings_view.set_last_error(Some(error_message)),
+                None => {
+                    if let Ok(mut new_definition_text) = settings_view.new_definition_text.write() {
+                        new_definition_text.clear();
+                    }
+
+                    settings_view.set_last_error(None);
+                }
+            }
+        });
+    }
+
+    fn unregister_data_type(
+        &self,
+        data_type_id: String,
+    ) {
+        let data_types_unregister_request = DataTypesUnregisterRequest { data_type_id };
+
+        data_types_unregister_request.send(&self.app_context.engine_unprivileged_state, move |data_types_unregister_response| {
+            CustomDataTypeRegistry::get_instance().mirror_definitions(data_types_unregister_response.definitions);
+        });
+    }
+
+    fn set_last_error(
+        &self,
+        error: Option<String>,
+    ) {
+        if let Ok(mut last_error) = self.last_error.write() {
+            *last_error = error;
+        }
+    }
+}
+
+impl Widget for SettingsTabDataTypesView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let definitions = CustomDataTypeRegistry::get_instance().get_definitions();
+        let last_error = self
+            .last_error
+            .read()
+            .ok()
+            .and_then(|last_error| last_error.clone());
+        let mut should_register = false;
+        let mut unregister_data_type_id: Option<String> = None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Custom Struct Types", |user_interface| {
+                        user_interface.vertical(|user_interface| {
+                            user_interface.horizontal(|user_interface| {
+                                if let Ok(mut new_definition_text) = self.new_definition_text.write() {
+                                    let definition_edit = user_interface.add_sized(
+                                        vec2(320.0, 28.0),
+                                        TextEdit::singleline(&mut *new_definition_text)
+                                            .hint_text("Entity header = u32 id, u32 flags, f32 hp")
+                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                            .text_color(theme.foreground)
+                                            .background_color(theme.background_primary),
+                                    );
+
+                                    if definition_edit.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                                        should_register = true;
+                                    }
+                                }
+
+                                let add_button = user_interface.add_sized(vec2(80.0, 28.0), Button::new_from_theme(theme));
+
+                                user_interface.painter().text(
+                                    add_button.rect.center(),
+                                    Align2::CENTER_CENTER,
+                                    "Register",
+                                    theme.font_library.font_noto_sans.font_normal.clone(),
+                                    theme.foreground,
+                                );
+
+                                if add_button.clicked() {
+                                    should_register = true;
+                                }
+                            });
+
+                            if let Some(last_error) = &last_error {
+                                user_interface.colored_label(theme.error_red, last_error);
+                            }
+
+                            user_interface.add_space(8.0);
+
+                            if definitions.is_empty() {
+                                user_interface.label(
+                                    RichText::new("No custom types. Use `type xN` to repeat a field, and `?` in scan values to skip a field.")
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                );
+                            }
+
+                            for definition in &definitions {
+                                user_interface.horizontal(|user_interface| {
+                                    let remove_button = user_interface.add_sized(
+                                        vec2(28.0, 28.0),
+                                        Button::new_from_theme(theme)
+                                            .background_color(Color32::TRANSPARENT)
+                                            .with_tooltip_text("Remove type"),
+                                    );
+                                    IconDraw::draw(user_interface, remove_button.rect, &theme.icon_library.icon_handle_common_delete);
+
+                                    if remove_button.clicked() {
+                                        unregister_data_type_id = Some(definition.get_data_type_id());
+                                    }
+
+                                    user_interface.label(
+                                        RichText::new(definition.to_string())
+                                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                });
+                            }
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+            })
+            .response;
+
+        if should_register {
+            self.register_definition();
+        }
+
+        if let Some(data_type_id) = unregister_data_type_id {
+            self.unregister_data_type(data_type_id);
+        }
+
+        response
+    }
+}
@@ -1,9 +1,26 @@
 use crate::{
     app_context::AppContext,
-    models::docking::settings::dockable_window_settings::{DockSettingsConfig, DockableWindowSettings},
-    ui::widgets::controls::{button::Button, groupbox::GroupBox, slider::Slider},
+    models::{
+        appearance::{
+            appearance_settings::AppearanceSettings,
+            row_density::RowDensity,
+            value_color_rules::{ValueColor, ValueColorRule, ValueColorRuleFamily, ValueColorRules},
+        },
+        docking::{
+            docking_command::DockingCommand,
+            settings::dockable_window_settings::{DockSettingsConfig, DockableWindowSettings},
+        },
+        update_check::update_check_settings::UpdateCheckSettings,
+    },
+    ui::widgets::controls::{
+        button::Button,
+        checkbox::Checkbox,
+        combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView},
+        groupbox::GroupBox,
+        slider::Slider,
+    },
 };
-use eframe::egui::{Align, Align2, Layout, Response, RichText, Ui, Widget};
+use eframe::egui::{Align, Align2, ComboBox, Layout, Response, RichText, TextEdit, Ui, Widget};
 use epaint::vec2;
 use squalr_engine_api::{
     commands::{
@@ -14,17 +31,112 @@ use squalr_engine_api::{
 };
 use std::sync::{Arc, RwLock};
 
+/// The text of a value color rule being edited. Bounds are only parsed once the rules are saved.
+#[derive(Clone, Default)]
+struct ValueColorRuleDraft {
+    minimum: String,
+    maximum: String,
+    color: ValueColor,
+}
+
+impl ValueColorRuleDraft {
+    fn from_rule(value_color_rule: &ValueColorRule) -> Self {
+        Self {
+            minimum: value_color_rule
+                .minimum
+                .map(|minimum| minimum.to_string())
+                .unwrap_or_default(),
+            maximum: value_color_rule
+                .maximum
+                .map(|maximum| maximum.to_string())
+                .unwrap_or_default(),
+            color: value_color_rule.color,
+        }
+    }
+
+    fn to_rule(&self) -> Result<ValueColorRule, String> {
+        let minimum = Self::parse_bound(&self.minimum)?;
+        let maximum = Self::parse_bound(&self.maximum)?;
+
+        if let (Some(minimum), Some(maximum)) = (minimum, maximum)
+            && minimum >= maximum
+        {
+            return Err(format!("The minimum {} must be below the maximum {}.", minimum, maximum));
+        }
+
+        Ok(ValueColorRule::new(minimum, maximum, self.color))
+    }
+
+    fn parse_bound(bound: &str) -> Result<Option<f64>, String> {
+        let bound = bound.trim();
+
+        if bound.is_empty() {
+            return Ok(None);
+        }
+
+        match bound.parse::<f64>() {
+            Ok(value) if !value.is_nan() => Ok(Some(value)),
+            _ => Err(format!("'{}' is not a number.", bound)),
+        }
+    }
+}
+
+/// The value color rules being edited, per data type family, in the same order as `ValueColorRuleFamily::ALL`.
+#[derive(Clone, Default)]
+struct ValueColorRulesDraft {
+    rule_drafts: [Vec<ValueColorRuleDraft>; 2],
+    error_message: Option<String>,
+}
+
+impl ValueColorRulesDraft {
+    fn from_rules(value_color_rules: &ValueColorRules) -> Self {
+        Self {
+            rule_drafts: ValueColorRuleFamily::ALL.map(|value_color_rule_family| {
+                value_color_rules
+                    .get_rules(value_color_rule_family)
+                    .iter()
+                    .map(ValueColorRuleDraft::from_rule)
+                    .collect()
+            }),
+            error_message: None,
+        }
+    }
+
+    fn to_rules(&self) -> Result<ValueColorRules, String> {
+        let mut value_color_rules = ValueColorRules::default();
+
+        for (value_color_rule_family, rule_drafts) in ValueColorRuleFamily::ALL.iter().zip(&self.rule_drafts) {
+            let rules = rule_drafts
+                .iter()
+                .map(ValueColorRuleDraft::to_rule)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| format!("{} rule: {}", value_color_rule_family.get_label(), error))?;
+
+            value_color_rules.set_rules(*value_color_rule_family, rules);
+        }
+
+        Ok(value_color_rules)
+    }
+}
+
 #[derive(Clone)]
 pub struct SettingsTabGeneralView {
     app_context: Arc<AppContext>,
     cached_general_settings: Arc<RwLock<GeneralSettings>>,
+    ui_scale_drag_track: Arc<RwLock<Option<(f32, f32)>>>,
+    value_color_rules_draft: Arc<RwLock<ValueColorRulesDraft>>,
 }
 
 impl SettingsTabGeneralView {
+    /// Snaps the UI scale slider to whole 5% increments.
+    const UI_SCALE_STEP_PERCENT: i64 = 5;
+
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let settings_view = Self {
             app_context,
             cached_general_settings: Arc::new(RwLock::new(GeneralSettings::default())),
+            ui_scale_drag_track: Arc::new(RwLock::new(None)),
+            value_color_rules_draft: Arc::new(RwLock::new(ValueColorRulesDraft::from_rules(&AppearanceSettings::get_value_color_rules()))),
         };
 
         settings_view.sync_ui_with_general_settings();
@@ -44,6 +156,114 @@ impl SettingsTabGeneralView {
             }
         });
     }
+
+    /// Sets the slider track, in physical pixels, that the in-progress UI scale drag is measured against.
+    fn set_ui_scale_drag_track(
+        &self,
+        ui_scale_drag_track: Option<(f32, f32)>,
+    ) {
+        if let Ok(mut cached_ui_scale_drag_track) = self.ui_scale_drag_track.write() {
+            *cached_ui_scale_drag_track = ui_scale_drag_track;
+        }
+    }
+
+    /// Draws the editor for value color rules. Edits only take effect once saved, such that half-typed bounds are never applied.
+    fn add_value_color_rules_editor(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let theme = &self.app_context.theme;
+        let mut value_color_rules_draft = match self.value_color_rules_draft.write() {
+            Ok(value_color_rules_draft) => value_color_rules_draft,
+            Err(_error) => return,
+        };
+        let value_color_rules_draft = &mut *value_color_rules_draft;
+
+        user_interface.vertical(|user_interface| {
+            user_interface.label(
+                RichText::new("Minimums are inclusive and maximums are exclusive. Leave a bound empty for no limit. The first matching rule wins.")
+                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                    .color(theme.foreground),
+            );
+
+            for (family_index, value_color_rule_family) in ValueColorRuleFamily::ALL.iter().enumerate() {
+                let rule_drafts = &mut value_color_rules_draft.rule_drafts[family_index];
+                let mut removed_rule_index = None;
+
+                user_interface.add_space(8.0);
+                user_interface.label(
+                    RichText::new(value_color_rule_family.get_label())
+                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                        .color(theme.foreground),
+                );
+
+                for (rule_index, rule_draft) in rule_drafts.iter_mut().enumerate() {
+                    user_interface.horizontal(|user_interface| {
+                        user_interface.add(
+                            TextEdit::singleline(&mut rule_draft.minimum)
+                                .hint_text("Min")
+                                .desired_width(80.0),
+                        );
+                        user_interface.add(
+                            TextEdit::singleline(&mut rule_draft.maximum)
+                                .hint_text("Max")
+                                .desired_width(80.0),
+                        );
+
+                        ComboBox::from_id_salt(("settings_tab_general_value_color", family_index, rule_index))
+                            .width(80.0)
+                            .selected_text(RichText::new(rule_draft.color.get_label()).color(rule_draft.color.get_color(theme)))
+                            .show_ui(user_interface, |user_interface| {
+                                for value_color in ValueColor::ALL {
+                                    user_interface.selectable_value(
+                                        &mut rule_draft.color,
+                                        value_color,
+                                        RichText::new(value_color.get_label()).color(value_color.get_color(theme)),
+                                    );
+                                }
+                            });
+
+                        if user_interface.button("Remove").clicked() {
+                            removed_rule_index = Some(rule_index);
+                        }
+                    });
+                }
+
+                if let Some(removed_rule_index) = removed_rule_index {
+                    rule_drafts.remove(removed_rule_index);
+                }
+
+                if user_interface
+                    .add_enabled(rule_drafts.len() < ValueColorRules::MAX_RULES_PER_FAMILY, eframe::egui::Button::new("Add rule"))
+                    .clicked()
+                {
+                    rule_drafts.push(ValueColorRuleDraft::default());
+                }
+            }
+
+            user_interface.add_space(8.0);
+
+            if let Some(error_message) = &value_color_rules_draft.error_message {
+                user_interface.label(RichText::new(error_message).color(theme.error_red));
+            }
+
+            user_interface.horizontal(|user_interface| {
+                if user_interface.button("Save").clicked() {
+                    match value_color_rules_draft.to_rules() {
+                        Ok(value_color_rules) => {
+                            AppearanceSettings::set_value_color_rules(value_color_rules);
+                            value_color_rules_draft.error_message = None;
+                        }
+                        Err(error) => value_color_rules_draft.error_message = Some(error),
+                    }
+                }
+
+                if user_interface.button("Revert").clicked() {
+                    *value_color_rules_draft = ValueColorRulesDraft::from_rules(&AppearanceSettings::get_value_color_rules());
+                }
+            });
+        });
+    }
 }
 
 impl Widget for SettingsTabGeneralView {
@@ -53,13 +273,208 @@ impl Widget for SettingsTabGeneralView {
     ) -> Response {
         let theme = &self.app_context.theme;
         let cached_general_settings = match self.cached_general_settings.read() {
-            Ok(cached_general_settings) => *cached_general_settings,
+            Ok(cached_general_settings) => cached_general_settings.clone(),
             Err(_error) => GeneralSettings::default(),
         };
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
                 user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Appearance", |user_interface| {
+                        user_interface.vertical(|user_interface| {
+                            user_interface.horizontal(|user_interface| {
+                                let mut ui_scale_percent = (user_interface.ctx().zoom_factor() * 100.0).round() as i64;
+                                let slider = Slider::new_from_theme(theme)
+                                    .current_value(&mut ui_scale_percent)
+                                    .minimum_value((AppearanceSettings::MINIMUM_UI_SCALE * 100.0) as i64)
+                                    .maximum_value((AppearanceSettings::MAXIMUM_UI_SCALE * 100.0) as i64);
+                                let slider_response = user_interface.add(slider);
+
+                                // The scale is previewed live while dragging, which resizes the slider itself. To avoid feedback between the
+                                // scale and the pointer position, the drag is tracked in physical pixels against the track captured at drag start.
+                                let pixels_per_point = user_interface.ctx().pixels_per_point();
+
+                                if slider_response.drag_started() {
+                                    self.set_ui_scale_drag_track(Some((
+                                        slider_response.rect.left() * pixels_per_point,
+                                        slider_response.rect.width() * pixels_per_point,
+                                    )));
+                                }
+
+                                if slider_response.dragged() {
+                                    let ui_scale_drag_track = self
+                                        .ui_scale_drag_track
+                                        .read()
+                                        .ok()
+                                        .and_then(|ui_scale_drag_track| *ui_scale_drag_track);
+                                    let pointer_x = user_interface.input(|input_state| {
+                                        input_state
+                                            .pointer
+                                            .interact_pos()
+                                            .map(|position| position.x * pixels_per_point)
+                                    });
+
+                                    if let (Some((track_left, track_width)), Some(pointer_x)) = (ui_scale_drag_track, pointer_x) {
+                                        let normalized = ((pointer_x - track_left) / track_width.max(1.0)).clamp(0.0, 1.0);
+                                        let minimum_percent = (AppearanceSettings::MINIMUM_UI_SCALE * 100.0) as i64;
+                                        let maximum_percent = (AppearanceSettings::MAXIMUM_UI_SCALE * 100.0) as i64;
+                                        let unsnapped_percent = minimum_percent as f32 + normalized * (maximum_percent - minimum_percent) as f32;
+
+                                        ui_scale_percent = (unsnapped_percent / Self::UI_SCALE_STEP_PERCENT as f32).round() as i64 * Self::UI_SCALE_STEP_PERCENT;
+
+                                        let ui_scale = AppearanceSettings::clamp_ui_scale(ui_scale_percent as f32 / 100.0);
+
+                                        if ui_scale != user_interface.ctx().zoom_factor() {
+                                            user_interface.ctx().set_zoom_factor(ui_scale);
+                                        }
+                                    }
+                                }
+
+                                // Only persist the scale once the drag completes.
+                                if slider_response.drag_stopped() {
+                                    AppearanceSettings::set_ui_scale(user_interface.ctx().zoom_factor());
+                                    self.set_ui_scale_drag_track(None);
+                                }
+
+                                user_interface.add_space(8.0);
+                                user_interface.allocate_ui_with_layout(
+                                    vec2(40.0, user_interface.available_height()),
+                                    Layout::right_to_left(Align::Center),
+                                    |user_interface| {
+                                        user_interface.label(
+                                            RichText::new(format!("{}%", ui_scale_percent))
+                                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                                .color(theme.foreground),
+                                        );
+                                    },
+                                );
+
+                                user_interface.add_space(8.0);
+                                user_interface.label(
+                                    RichText::new("UI Scale")
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                );
+                            });
+                            user_interface.add_space(8.0);
+
+                            let reset_ui_scale_button = user_interface.add_sized(vec2(220.0, 28.0), Button::new_from_theme(theme));
+                            user_interface.painter().text(
+                                reset_ui_scale_button.rect.center(),
+                                Align2::CENTER_CENTER,
+                                "Reset to OS default",
+                                theme.font_library.font_noto_sans.font_normal.clone(),
+                                theme.foreground,
+                            );
+                            if reset_ui_scale_button.clicked() {
+                                user_interface
+                                    .ctx()
+                                    .set_zoom_factor(AppearanceSettings::DEFAULT_UI_SCALE);
+                                AppearanceSettings::set_ui_scale(AppearanceSettings::DEFAULT_UI_SCALE);
+                            }
+                            user_interface.add_space(8.0);
+
+                            user_interface.horizontal(|user_interface| {
+                                user_interface.add(ComboBoxView::new(
+                                    self.app_context.clone(),
+                                    AppearanceSettings::get_row_density().get_label(),
+                                    "settings_tab_general_row_density",
+                                    None,
+                                    |user_interface: &mut Ui, should_close: &mut bool| {
+                                        for row_density in RowDensity::ALL {
+                                            if user_interface
+                                                .add(ComboBoxItemView::new(self.app_context.clone(), row_density.get_label(), None, 220.0))
+                                                .clicked()
+                                            {
+                                                AppearanceSettings::set_row_density(row_density);
+                                                *should_close = true;
+                                                return;
+                                            }
+                                        }
+                                    },
+                                ));
+
+                                user_interface.add_space(8.0);
+                                user_interface.label(
+                                    RichText::new("Row Density")
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                );
+                            });
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+
+                user_interface.add_space(12.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Value Colors", |user_interface| {
+                        self.add_value_color_rules_editor(user_interface);
+                    })
+                    .desired_width(412.0),
+                );
+
+                user_interface.add_space(12.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Process", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            let is_auto_reattach_enabled = cached_general_settings.auto_reattach;
+
+                            if user_interface
+                                .add(Checkbox::new_from_theme(theme).with_check_state_bool(is_auto_reattach_enabled))
+                                .clicked()
+                            {
+                                if let Ok(mut cached_general_settings) = self.cached_general_settings.write() {
+                                    cached_general_settings.auto_reattach = !is_auto_reattach_enabled;
+                                }
+
+                                let general_settings_set_request = GeneralSettingsSetRequest {
+                                    auto_reattach: Some(!is_auto_reattach_enabled),
+                                    ..GeneralSettingsSetRequest::default()
+                                };
+
+                                general_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_general_settings_set_response| {});
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface
+                                .label(
+                                    RichText::new("Reattach when the process restarts")
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                )
+                                .on_hover_text("Results within modules follow the new process, while other results are marked stale.");
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+
+                user_interface.add_space(12.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Updates", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            let is_update_check_enabled = UpdateCheckSettings::get_is_update_check_enabled();
+
+                            if user_interface
+                                .add(Checkbox::new_from_theme(theme).with_check_state_bool(is_update_check_enabled))
+                                .clicked()
+                            {
+                                UpdateCheckSettings::set_is_update_check_enabled(!is_update_check_enabled);
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Check for updates on startup")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+
+                user_interface.add_space(12.0);
                 user_interface.add(
                     GroupBox::new_from_theme(theme, "Developer Debugging", |user_interface| {
                         user_interface.horizontal(|user_interface| {
@@ -129,9 +544,9 @@ impl Widget for SettingsTabGeneralView {
                                 theme.foreground,
                             );
                             if reset_layout_button.clicked() {
-                                if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                                    docking_manager.set_root(DockSettingsConfig::get_default_layout());
-                                }
+                                self.app_context.queue_docking_command(DockingCommand::SetRoot {
+                                    root_node: DockSettingsConfig::get_default_layout(),
+                                });
                             }
 
                             user_interface.add_space(6.0);
@@ -150,9 +565,9 @@ impl Widget for SettingsTabGeneralView {
                                 if !DockableWindowSettings::clear_config_file() {
                                     log::error!("Failed to remove docking_settings.json.");
                                 }
-                                if let Ok(mut docking_manager) = self.app_context.docking_manager.write() {
-                                    docking_manager.set_root(DockSettingsConfig::get_default_layout());
-                                }
+                                self.app_context.queue_docking_command(DockingCommand::SetRoot {
+                                    root_node: DockSettingsConfig::get_default_layout(),
+                                });
                             }
 
                             user_interface.add_space(6.0);
@@ -1,8 +1,10 @@
 use crate::{
     app_context::AppContext,
-    ui::widgets::controls::{checkbox::Checkbox, groupbox::GroupBox},
+    models::address_resolution::address_resolver::AddressResolver,
+    ui::widgets::controls::{button::Button, checkbox::Checkbox, groupbox::GroupBox},
 };
-use eframe::egui::{Align, Layout, Response, RichText, Ui, Widget};
+use eframe::egui::{Align, Align2, Key, Label, Layout, Response, RichText, TextEdit, Ui, Widget};
+use epaint::vec2;
 use squalr_engine_api::{
     commands::{
         memory::regions::memory_regions_request::MemoryRegionsRequest,
@@ -10,7 +12,12 @@ use squalr_engine_api::{
         settings::memory::{list::memory_settings_list_request::MemorySettingsListRequest, set::memory_settings_set_request::MemorySettingsSetRequest},
     },
     conversions::storage_size_conversions::StorageSizeConversions,
-    structures::settings::memory_settings::MemorySettings,
+    engine::engine_unprivileged_state::EngineUnprivilegedState,
+    structures::{
+        memory::{address_space_bounds::AddressSpaceBounds, bitness::Bitness},
+        processes::opened_process_info::OpenedProcessInfo,
+        settings::memory_settings::MemorySettings,
+    },
 };
 use std::sync::{Arc, RwLock};
 
@@ -19,6 +26,10 @@ pub struct SettingsTabMemoryView {
     app_context: Arc<AppContext>,
     cached_memory_settings: Arc<RwLock<MemorySettings>>,
     cached_region_preview: Arc<RwLock<Option<(usize, u64)>>>,
+    custom_range_start_text: Arc<RwLock<String>>,
+    custom_range_end_text: Arc<RwLock<String>>,
+    custom_range_error: Arc<RwLock<Option<String>>>,
+    opened_process_info: Arc<RwLock<Option<OpenedProcessInfo>>>,
 }
 
 impl SettingsTabMemoryView {
@@ -27,6 +38,10 @@ impl SettingsTabMemoryView {
             app_context,
             cached_memory_settings: Arc::new(RwLock::new(MemorySettings::default())),
             cached_region_preview: Arc::new(RwLock::new(None)),
+            custom_range_start_text: Arc::new(RwLock::new(String::new())),
+            custom_range_end_text: Arc::new(RwLock::new(String::new())),
+            custom_range_error: Arc::new(RwLock::new(None)),
+            opened_process_info: Arc::new(RwLock::new(None)),
         };
 
         settings_view.sync_ui_with_memory_settings();
@@ -39,23 +54,68 @@ impl SettingsTabMemoryView {
     fn sync_ui_with_memory_settings(&self) {
         let memory_settings_list_request = MemorySettingsListRequest {};
         let cached_memory_settings = self.cached_memory_settings.clone();
+        let custom_range_start_text = self.custom_range_start_text.clone();
+        let custom_range_end_text = self.custom_range_end_text.clone();
 
         memory_settings_list_request.send(&self.app_context.engine_unprivileged_state, move |scan_results_query_response| {
             if let Ok(memory_settings) = scan_results_query_response.memory_settings {
                 if let Ok(mut cached_memory_settings) = cached_memory_settings.write() {
                     *cached_memory_settings = memory_settings;
                 }
+
+                if !memory_settings.only_query_usermode {
+                    if let Ok(mut custom_range_start_text) = custom_range_start_text.write() {
+                        *custom_range_start_text = format!("{:X}", memory_settings.start_address);
+                    }
+
+                    if let Ok(mut custom_range_end_text) = custom_range_end_text.write() {
+                        *custom_range_end_text = format!("{:X}", memory_settings.end_address);
+                    }
+                }
             }
         });
     }
 
     fn sync_region_preview(&self) {
+        Self::query_region_preview(
+            &self.app_context.engine_unprivileged_state,
+            self.cached_memory_settings.clone(),
+            self.cached_region_preview.clone(),
+        );
+    }
+
+    /// Counts the regions and bytes of the opened process, clipped to the custom range if one is being queried.
+    fn query_region_preview(
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        cached_memory_settings: Arc<RwLock<MemorySettings>>,
+        cached_region_preview: Arc<RwLock<Option<(usize, u64)>>>,
+    ) {
         let memory_regions_request = MemoryRegionsRequest {};
-        let cached_region_preview = self.cached_region_preview.clone();
 
-        memory_regions_request.send(&self.app_context.engine_unprivileged_state, move |response| {
-            let region_count = response.regions.len();
-            let total_bytes = response.regions.iter().map(|region| region.region_size).sum::<u64>();
+        memory_regions_request.send(engine_unprivileged_state, move |response| {
+            let memory_settings = match cached_memory_settings.read() {
+                Ok(cached_memory_settings) => *cached_memory_settings,
+                Err(_error) => MemorySettings::default(),
+            };
+            let (range_start, range_end) = if memory_settings.only_query_usermode {
+                (0, u64::MAX)
+            } else {
+                (memory_settings.start_address, memory_settings.end_address)
+            };
+            let covered_sizes = response
+                .regions
+                .iter()
+                .map(|region| {
+                    let region_end = region.base_address.saturating_add(region.region_size);
+
+                    region_end
+                        .min(range_end)
+                        .saturating_sub(region.base_address.max(range_start))
+                })
+                .filter(|covered_size| *covered_size > 0)
+                .collect::<Vec<u64>>();
+            let region_count = covered_sizes.len();
+            let total_bytes = covered_sizes.iter().sum::<u64>();
 
             if let Ok(mut cached_region_preview) = cached_region_preview.write() {
                 *cached_region_preview = Some((region_count, total_bytes));
@@ -66,24 +126,185 @@ impl SettingsTabMemoryView {
     fn listen_for_process_change(&self) {
         let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
         let engine_unprivileged_state_for_listener = engine_unprivileged_state.clone();
+        let cached_memory_settings = self.cached_memory_settings.clone();
         let cached_region_preview = self.cached_region_preview.clone();
+        let opened_process_info = self.opened_process_info.clone();
 
         engine_unprivileged_state.listen_for_engine_event::<squalr_engine_api::events::process::changed::process_changed_event::ProcessChangedEvent>(
-            move |_| {
-                let memory_regions_request = MemoryRegionsRequest {};
-                let cached_region_preview = cached_region_preview.clone();
-                let engine_unprivileged_state = engine_unprivileged_state_for_listener.clone();
+            move |process_changed_event| {
+                if let Ok(mut opened_process_info) = opened_process_info.write() {
+                    *opened_process_info = process_changed_event.process_info.clone();
+                }
+
+                Self::query_region_preview(
+                    &engine_unprivileged_state_for_listener,
+                    cached_memory_settings.clone(),
+                    cached_region_preview.clone(),
+                );
+            },
+        );
+    }
 
-                memory_regions_request.send(&engine_unprivileged_state, move |response| {
-                    let region_count = response.regions.len();
-                    let total_bytes = response.regions.iter().map(|region| region.region_size).sum::<u64>();
+    /// Fills the custom range inputs, ie from a preset, and clears any error shown for the previous input.
+    fn set_custom_range_text(
+        &self,
+        start_text: &str,
+        end_text: &str,
+    ) {
+        if let Ok(mut custom_range_start_text) = self.custom_range_start_text.write() {
+            *custom_range_start_text = start_text.to_string();
+        }
+
+        if let Ok(mut custom_range_end_text) = self.custom_range_end_text.write() {
+            *custom_range_end_text = end_text.to_string();
+        }
+
+        self.set_custom_range_error(None);
+    }
+
+    fn set_custom_range_error(
+        &self,
+        error: Option<String>,
+    ) {
+        if let Ok(mut custom_range_error) = self.custom_range_error.write() {
+            *custom_range_error = error;
+        }
+    }
 
-                    if let Ok(mut cached_region_preview) = cached_region_preview.write() {
-                        *cached_region_preview = Some((region_count, total_bytes));
+    /// Gets the usermode address bounds of a process of the given bitness, formatted as custom range inputs.
+    fn get_usermode_preset(bitness: Bitness) -> (String, String) {
+        let address_space_bounds = AddressSpaceBounds::for_bitness(
+            bitness,
+            AddressSpaceBounds::USERMODE_64_MIN_ADDRESS,
+            AddressSpaceBounds::USERMODE_64_MAX_ADDRESS,
+        );
+
+        (
+            format!("{:X}", address_space_bounds.get_min_usermode_address()),
+            format!("{:X}", address_space_bounds.get_max_usermode_address()),
+        )
+    }
+
+    /// Resolves the custom range inputs, and switches to querying that range once both resolve to a start below the end that lies
+    /// within the address space of the opened process. Malformed input is shown as an error rather than sent to the engine.
+    fn commit_custom_range(&self) {
+        let start_text = match self.custom_range_start_text.read() {
+            Ok(custom_range_start_text) => custom_range_start_text.clone(),
+            Err(_error) => return,
+        };
+        let end_text = match self.custom_range_end_text.read() {
+            Ok(custom_range_end_text) => custom_range_end_text.clone(),
+            Err(_error) => return,
+        };
+        let engine_unprivileged_state = self.app_context.engine_unprivileged_state.clone();
+        let cached_memory_settings = self.cached_memory_settings.clone();
+        let cached_region_preview = self.cached_region_preview.clone();
+        let custom_range_error = self.custom_range_error.clone();
+        let opened_process_info = match self.opened_process_info.read() {
+            Ok(opened_process_info) => opened_process_info.clone(),
+            Err(_error) => None,
+        };
+
+        AddressResolver::resolve(&self.app_context.engine_unprivileged_state, &start_text, move |start_resolution| {
+            let start_address = match start_resolution {
+                Ok(resolved_address) => resolved_address.address,
+                Err(error) => {
+                    if let Ok(mut custom_range_error) = custom_range_error.write() {
+                        *custom_range_error = Some(format!("Start: {}", error));
                     }
+
+                    return;
+                }
+            };
+
+            let engine_unprivileged_state_for_end = engine_unprivileged_state.clone();
+
+            AddressResolver::resolve(&engine_unprivileged_state_for_end, &end_text, move |end_resolution| {
+                let end_address = match end_resolution {
+                    Ok(resolved_address) => resolved_address.address,
+                    Err(error) => {
+                        if let Ok(mut custom_range_error) = custom_range_error.write() {
+                            *custom_range_error = Some(format!("End: {}", error));
+                        }
+
+                        return;
+                    }
+                };
+
+                if start_address >= end_address {
+                    if let Ok(mut custom_range_error) = custom_range_error.write() {
+                        *custom_range_error = Some(format!("The start ({:X}) must be below the end ({:X})", start_address, end_address));
+                    }
+
+                    return;
+                }
+
+                if let Some(opened_process_info) = &opened_process_info {
+                    let address_space_bounds = AddressSpaceBounds::for_process(
+                        opened_process_info,
+                        AddressSpaceBounds::USERMODE_64_MIN_ADDRESS,
+                        AddressSpaceBounds::USERMODE_64_MAX_ADDRESS,
+                    );
+                    let min_address = address_space_bounds.get_min_usermode_address();
+                    let max_address = address_space_bounds.get_maximum_address();
+
+                    if start_address < min_address || end_address > max_address {
+                        if let Ok(mut custom_range_error) = custom_range_error.write() {
+                            *custom_range_error = Some(format!(
+                                "The range must lie within the address space of {} ({:X}-{:X})",
+                                opened_process_info.get_name(),
+                                min_address,
+                                max_address
+                            ));
+                        }
+
+                        return;
+                    }
+                }
+
+                if let Ok(mut custom_range_error) = custom_range_error.write() {
+                    *custom_range_error = None;
+                }
+
+                if let Ok(mut cached_memory_settings) = cached_memory_settings.write() {
+                    cached_memory_settings.only_query_usermode = false;
+                    cached_memory_settings.start_address = start_address;
+                    cached_memory_settings.end_address = end_address;
+                }
+
+                let memory_settings_set_request = MemorySettingsSetRequest {
+                    only_query_usermode: Some(false),
+                    start_address: Some(start_address),
+                    end_address: Some(end_address),
+                    ..MemorySettingsSetRequest::default()
+                };
+                let engine_unprivileged_state_for_preview = engine_unprivileged_state.clone();
+
+                memory_settings_set_request.send(&engine_unprivileged_state, move |_memory_settings_set_response| {
+                    Self::query_region_preview(&engine_unprivileged_state_for_preview, cached_memory_settings, cached_region_preview);
                 });
-            },
+            });
+        });
+    }
+
+    fn add_text_button(
+        &self,
+        user_interface: &mut Ui,
+        text: &str,
+        width: f32,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let button = user_interface.add_sized(vec2(width, 24.0), Button::new_from_theme(theme));
+
+        user_interface.painter().text(
+            button.rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            theme.font_library.font_noto_sans.font_normal.clone(),
+            theme.foreground,
         );
+
+        button
     }
 }
 
@@ -101,7 +322,12 @@ impl Widget for SettingsTabMemoryView {
             Ok(cached_region_preview) => *cached_region_preview,
             Err(_error) => None,
         };
+        let custom_range_error = match self.custom_range_error.read() {
+            Ok(custom_range_error) => custom_range_error.clone(),
+            Err(_error) => None,
+        };
         let mut preview_dirty = false;
+        let mut should_commit_custom_range = false;
 
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
@@ -484,66 +710,167 @@ impl Widget for SettingsTabMemoryView {
                         .desired_height(320.0),
                     );
                     user_interface.add_space(8.0);
-                user_interface.add(
-                    GroupBox::new_from_theme(theme, "Virtual Memory Querying", |user_interface| {
-                        user_interface.vertical(|user_interface| {
-                            let query_usermode = cached_memory_settings.only_query_usermode;
+                    user_interface.add(
+                        GroupBox::new_from_theme(theme, "Virtual Memory Querying", |user_interface| {
+                            user_interface.vertical(|user_interface| {
+                                let query_usermode = cached_memory_settings.only_query_usermode;
 
-                            user_interface.horizontal(|user_interface| {
-                                if user_interface
-                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(query_usermode))
-                                    .clicked()
-                                {
-                                    if let Ok(mut cached_memory_settings) = self.cached_memory_settings.write() {
-                                        cached_memory_settings.only_query_usermode = true;
+                                user_interface.horizontal(|user_interface| {
+                                    if user_interface
+                                        .add(Checkbox::new_from_theme(theme).with_check_state_bool(query_usermode))
+                                        .clicked()
+                                    {
+                                        if let Ok(mut cached_memory_settings) = self.cached_memory_settings.write() {
+                                            cached_memory_settings.only_query_usermode = true;
+                                        }
+
+                                        let memory_settings_set_request = MemorySettingsSetRequest {
+                                            only_query_usermode: Some(true),
+                                            ..MemorySettingsSetRequest::default()
+                                        };
+
+                                        memory_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_memory_settings_set_response| {});
+                                        preview_dirty = true;
                                     }
 
-                                    let memory_settings_set_request = MemorySettingsSetRequest {
-                                        only_query_usermode: Some(true),
-                                        ..MemorySettingsSetRequest::default()
-                                    };
+                                    user_interface.add_space(8.0);
+                                    user_interface.label(
+                                        RichText::new("Query All Usermode Memory")
+                                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                });
+
+                                user_interface.add_space(4.0);
+
+                                user_interface.horizontal(|user_interface| {
+                                    if user_interface
+                                        .add(Checkbox::new_from_theme(theme).with_check_state_bool(!query_usermode))
+                                        .clicked()
+                                    {
+                                        if let Ok(mut cached_memory_settings) = self.cached_memory_settings.write() {
+                                            cached_memory_settings.only_query_usermode = false;
+                                        }
 
-                                    memory_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_memory_settings_set_response| {});
-                                    preview_dirty = true;
+                                        let memory_settings_set_request = MemorySettingsSetRequest {
+                                            only_query_usermode: Some(false),
+                                            ..MemorySettingsSetRequest::default()
+                                        };
+
+                                        memory_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_memory_settings_set_response| {});
+                                        preview_dirty = true;
+                                    }
+
+                                    user_interface.add_space(8.0);
+                                    user_interface.label(
+                                        RichText::new("Query Custom Range")
+                                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                });
+
+                                user_interface.add_space(8.0);
+
+                                for (label, custom_range_text) in [
+                                    ("Start", &self.custom_range_start_text),
+                                    ("End", &self.custom_range_end_text),
+                                ] {
+                                    user_interface.horizontal(|user_interface| {
+                                        user_interface.add_sized(
+                                            vec2(40.0, 24.0),
+                                            Label::new(
+                                                RichText::new(label)
+                                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                                    .color(theme.foreground),
+                                            ),
+                                        );
+
+                                        if let Ok(mut custom_range_text) = custom_range_text.write() {
+                                            let range_edit = user_interface.add_sized(
+                                                vec2(184.0, 24.0),
+                                                TextEdit::singleline(&mut *custom_range_text)
+                                                    .hint_text("7FF6A000 or game.exe+size")
+                                                    .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                                    .text_color(theme.foreground)
+                                                    .background_color(theme.background_primary),
+                                            );
+
+                                            if range_edit.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                                                should_commit_custom_range = true;
+                                            }
+                                        }
+                                    });
+                                    user_interface.add_space(4.0);
+                                }
+
+                                if let Some(custom_range_error) = &custom_range_error {
+                                    user_interface.colored_label(theme.error_red, custom_range_error);
+                                    user_interface.add_space(4.0);
+                                }
+
+                                if self
+                                    .add_text_button(user_interface, "Apply Range", 228.0)
+                                    .clicked()
+                                {
+                                    should_commit_custom_range = true;
                                 }
 
                                 user_interface.add_space(8.0);
                                 user_interface.label(
-                                    RichText::new("Query All Usermode Memory")
+                                    RichText::new("Presets")
                                         .font(theme.font_library.font_noto_sans.font_normal.clone())
                                         .color(theme.foreground),
                                 );
-                            });
+                                user_interface.add_space(4.0);
 
-                            user_interface.add_space(4.0);
+                                for (preset_label, bitness) in [
+                                    ("32-bit usermode", Bitness::Bit32),
+                                    ("64-bit usermode", Bitness::Bit64),
+                                ] {
+                                    if self
+                                        .add_text_button(user_interface, preset_label, 228.0)
+                                        .clicked()
+                                    {
+                                        let (start_text, end_text) = Self::get_usermode_preset(bitness);
 
-                            user_interface.horizontal(|user_interface| {
-                                if user_interface
-                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(!query_usermode))
-                                    .clicked()
-                                {
-                                    if let Ok(mut cached_memory_settings) = self.cached_memory_settings.write() {
-                                        cached_memory_settings.only_query_usermode = false;
+                                        self.set_custom_range_text(&start_text, &end_text);
+                                        should_commit_custom_range = true;
                                     }
 
-                                    let memory_settings_set_request = MemorySettingsSetRequest {
-                                        only_query_usermode: Some(false),
-                                        ..MemorySettingsSetRequest::default()
+                                    user_interface.add_space(4.0);
+                                }
+
+                                if self
+                                    .add_text_button(user_interface, "Main module only", 228.0)
+                                    .clicked()
+                                {
+                                    let opened_process_name = match self.opened_process_info.read() {
+                                        Ok(opened_process_info) => opened_process_info
+                                            .as_ref()
+                                            .map(|opened_process_info| opened_process_info.get_name().to_string()),
+                                        Err(_error) => None,
                                     };
 
-                                    memory_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_memory_settings_set_response| {});
-                                    preview_dirty = true;
+                                    match opened_process_name {
+                                        Some(opened_process_name) => {
+                                            // Names with spaces or dashes must be quoted to parse as a module name.
+                                            let module_name = if opened_process_name
+                                                .chars()
+                                                .all(|character| character.is_ascii_alphanumeric() || character == '_' || character == '.')
+                                            {
+                                                opened_process_name
+                                            } else {
+                                                format!("\"{}\"", opened_process_name)
+                                            };
+
+                                            self.set_custom_range_text(&module_name, &format!("{}+size", module_name));
+                                            should_commit_custom_range = true;
+                                        }
+                                        None => self.set_custom_range_error(Some("Open a process to scan its main module".to_string())),
+                                    }
                                 }
-
-                                user_interface.add_space(8.0);
-                                user_interface.label(
-                                    RichText::new("Query Custom Range")
-                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
-                                        .color(theme.foreground),
-                                );
                             });
-                        });
-                    })
+                        })
                         .desired_width(256.0)
                         // JIRA: Bugged. I believe these rows are not allocating sufficient available height, and then groupbox treats desired as a suggestion.
                         .desired_height(320.0),
@@ -569,7 +896,41 @@ impl Widget for SettingsTabMemoryView {
                     .desired_width(520.0),
                 );
 
-                if preview_dirty {
+                user_interface.add_space(8.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Symbols", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            if user_interface
+                                .add(Checkbox::new_from_theme(theme).with_check_state_bool(cached_memory_settings.preload_main_module_symbols))
+                                .clicked()
+                            {
+                                let new_value = !cached_memory_settings.preload_main_module_symbols;
+                                if let Ok(mut cached_memory_settings) = self.cached_memory_settings.write() {
+                                    cached_memory_settings.preload_main_module_symbols = new_value;
+                                }
+
+                                let memory_settings_set_request = MemorySettingsSetRequest {
+                                    preload_main_module_symbols: Some(new_value),
+                                    ..MemorySettingsSetRequest::default()
+                                };
+
+                                memory_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_memory_settings_set_response| {});
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Preload symbols for main module on attach")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+                    })
+                    .desired_width(520.0),
+                );
+
+                if should_commit_custom_range {
+                    self.commit_custom_range();
+                } else if preview_dirty {
                     self.sync_region_preview();
                 }
             })
@@ -0,0 +1,424 @@
+use crate::{
+    app_context::AppContext,
+    ui::widgets::controls::{
+        button::Button,
+        combo_box::{combo_box_item_view::ComboBoxItemView, combo_box_view::ComboBoxView},
+        groupbox::GroupBox,
+        slider::Slider,
+    },
+};
+use eframe::egui::{Align, Align2, Layout, Response, RichText, Ui, Widget};
+use epaint::vec2;
+use squalr_engine_api::{
+    commands::{
+        privileged_command_request::PrivilegedCommandRequest,
+        scan::benchmark::scan_benchmark_request::ScanBenchmarkRequest,
+        settings::scan::{list::scan_settings_list_request::ScanSettingsListRequest, set::scan_settings_set_request::ScanSettingsSetRequest},
+        trackable_tasks::cancel::trackable_tasks_cancel_request::TrackableTasksCancelRequest,
+    },
+    engine::protocol::engine_capabilities::EngineCapabilities,
+    events::{
+        scan_benchmark::finished::scan_benchmark_finished_event::ScanBenchmarkFinishedEvent,
+        trackable_task::progress_changed::trackable_task_progress_changed_event::TrackableTaskProgressChangedEvent,
+    },
+    structures::scanning::benchmarks::scan_configuration_benchmark::ScanConfigurationBenchmark,
+    structures::settings::scan_settings::ScanSettings,
+    structures::settings::scan_vector_width::ScanVectorWidth,
+};
+use std::sync::{Arc, RwLock};
+use std::thread;
+
+#[derive(Clone)]
+pub struct SettingsTabPerformanceView {
+    app_context: Arc<AppContext>,
+    cached_scan_settings: Arc<RwLock<ScanSettings>>,
+    /// The task running the benchmark. Empty while the engine has not yet responded with the task.
+    benchmark_task_id: Arc<RwLock<Option<String>>>,
+    benchmark_progress: Arc<RwLock<f32>>,
+    benchmark_results: Arc<RwLock<Vec<ScanConfigurationBenchmark>>>,
+    benchmark_status: Arc<RwLock<Option<String>>>,
+    logical_core_count: u32,
+}
+
+impl SettingsTabPerformanceView {
+    const BENCHMARK_SIZE_MB: u64 = 32;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let logical_core_count = thread::available_parallelism()
+            .map(|logical_core_count| logical_core_count.get() as u32)
+            .unwrap_or(1);
+        let settings_view = Self {
+            app_context,
+            cached_scan_settings: Arc::new(RwLock::new(ScanSettings::default())),
+            benchmark_task_id: Arc::new(RwLock::new(None)),
+            benchmark_progress: Arc::new(RwLock::new(0.0)),
+            benchmark_results: Arc::new(RwLock::new(Vec::new())),
+            benchmark_status: Arc::new(RwLock::new(None)),
+            logical_core_count,
+        };
+
+        settings_view.sync_ui_with_scan_settings();
+        settings_view.listen_for_benchmark();
+
+        settings_view
+    }
+
+    fn sync_ui_with_scan_settings(&self) {
+        let scan_settings_list_request = ScanSettingsListRequest {};
+        let cached_scan_settings = self.cached_scan_settings.clone();
+
+        scan_settings_list_request.send(&self.app_context.engine_unprivileged_state, move |scan_settings_list_response| {
+            if let Ok(scan_settings) = scan_settings_list_response.scan_settings
+                && let Ok(mut cached_scan_settings) = cached_scan_settings.write()
+            {
+                *cached_scan_settings = scan_settings;
+            }
+        });
+    }
+
+    /// Tracks the progress of the running benchmark, and collects its results once it finishes.
+    fn listen_for_benchmark(&self) {
+        let engine_unprivileged_state = &self.app_context.engine_unprivileged_state;
+        let benchmark_task_id = self.benchmark_task_id.clone();
+        let benchmark_progress = self.benchmark_progress.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<TrackableTaskProgressChangedEvent>(move |progress_changed_event| {
+            let is_benchmark_task = benchmark_task_id
+                .read()
+                .is_ok_and(|benchmark_task_id| benchmark_task_id.as_ref() == Some(&progress_changed_event.task_id));
+
+            if is_benchmark_task && let Ok(mut benchmark_progress) = benchmark_progress.write() {
+                *benchmark_progress = progress_changed_event.progress;
+            }
+        });
+
+        let benchmark_task_id = self.benchmark_task_id.clone();
+        let benchmark_results = self.benchmark_results.clone();
+        let benchmark_status = self.benchmark_status.clone();
+
+        engine_unprivileged_state.listen_for_engine_event::<ScanBenchmarkFinishedEvent>(move |scan_benchmark_finished_event| {
+            if let Ok(mut benchmark_task_id) = benchmark_task_id.write() {
+                // The benchmark may finish before the engine has responded with its task id.
+                let is_benchmark_task =
+                    matches!(benchmark_task_id.as_deref(), Some(task_id) if task_id.is_empty() || task_id == scan_benchmark_finished_event.task_id);
+
+                if !is_benchmark_task {
+                    return;
+                }
+
+                *benchmark_task_id = None;
+            }
+
+            if let Ok(mut benchmark_status) = benchmark_status.write() {
+                *benchmark_status = if scan_benchmark_finished_event.was_cancelled {
+                    Some("Benchmark cancelled.".to_string())
+                } else {
+                    None
+                };
+            }
+
+            if let Ok(mut benchmark_results) = benchmark_results.write() {
+                *benchmark_results = scan_benchmark_finished_event
+                    .scan_configuration_benchmarks
+                    .clone();
+            }
+        });
+    }
+
+    fn start_benchmark(&self) {
+        if let Ok(mut benchmark_task_id) = self.benchmark_task_id.write() {
+            *benchmark_task_id = Some(String::new());
+        }
+
+        if let Ok(mut benchmark_progress) = self.benchmark_progress.write() {
+            *benchmark_progress = 0.0;
+        }
+
+        if let Ok(mut benchmark_status) = self.benchmark_status.write() {
+            *benchmark_status = None;
+        }
+
+        let scan_benchmark_request = ScanBenchmarkRequest {
+            size_mb: Self::BENCHMARK_SIZE_MB,
+        };
+        let benchmark_task_id = self.benchmark_task_id.clone();
+        let benchmark_status = self.benchmark_status.clone();
+
+        scan_benchmark_request.send(&self.app_context.engine_unprivileged_state, move |scan_benchmark_response| {
+            if let Ok(mut benchmark_task_id) = benchmark_task_id.write() {
+                // Only fill in the task id if the benchmark has not already finished.
+                if benchmark_task_id.as_deref() != Some("") {
+                    return;
+                }
+
+                match scan_benchmark_response.trackable_task_handle {
+                    Some(trackable_task_handle) => *benchmark_task_id = Some(trackable_task_handle.task_identifier),
+                    None => {
+                        *benchmark_task_id = None;
+
+                        if let Ok(mut benchmark_status) = benchmark_status.write() {
+                            *benchmark_status = Some("Failed to start the benchmark.".to_string());
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    fn cancel_benchmark(&self) {
+        let task_id = match self.benchmark_task_id.read() {
+            Ok(benchmark_task_id) => benchmark_task_id.clone().unwrap_or_default(),
+            Err(_error) => return,
+        };
+
+        if task_id.is_empty() {
+            return;
+        }
+
+        let trackable_tasks_cancel_request = TrackableTasksCancelRequest { task_id };
+
+        trackable_tasks_cancel_request.send(&self.app_context.engine_unprivileged_state, move |_trackable_tasks_cancel_response| {});
+    }
+
+    fn set_scan_configuration(
+        &self,
+        scan_thread_count: Option<u32>,
+        vector_width: Option<ScanVectorWidth>,
+    ) {
+        if let Ok(mut cached_scan_settings) = self.cached_scan_settings.write() {
+            if let Some(scan_thread_count) = scan_thread_count {
+                cached_scan_settings.scan_thread_count = scan_thread_count;
+            }
+
+            if let Some(vector_width) = vector_width {
+                cached_scan_settings.vector_width = vector_width;
+            }
+        }
+
+        let scan_settings_set_request = ScanSettingsSetRequest {
+            scan_thread_count,
+            vector_width,
+            ..ScanSettingsSetRequest::default()
+        };
+
+        scan_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_scan_settings_set_response| {});
+    }
+
+    fn get_vector_width_label(vector_width: ScanVectorWidth) -> &'static str {
+        match vector_width {
+            ScanVectorWidth::Auto => "Auto",
+            ScanVectorWidth::Bytes16 => "16 bytes",
+            ScanVectorWidth::Bytes32 => "32 bytes",
+            ScanVectorWidth::Bytes64 => "64 bytes",
+        }
+    }
+
+    fn add_text_button(
+        &self,
+        user_interface: &mut Ui,
+        text: &str,
+        width: f32,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let button = user_interface.add_sized(vec2(width, 24.0), Button::new_from_theme(theme));
+
+        user_interface.painter().text(
+            button.rect.center(),
+            Align2::CENTER_CENTER,
+            text,
+            theme.font_library.font_noto_sans.font_normal.clone(),
+            theme.foreground,
+        );
+
+        button
+    }
+}
+
+impl Widget for SettingsTabPerformanceView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let cached_scan_settings = match self.cached_scan_settings.read() {
+            Ok(cached_scan_settings) => *cached_scan_settings,
+            Err(_error) => ScanSettings::default(),
+        };
+        let benchmark_task_id = match self.benchmark_task_id.read() {
+            Ok(benchmark_task_id) => benchmark_task_id.clone(),
+            Err(_error) => None,
+        };
+        let benchmark_progress = match self.benchmark_progress.read() {
+            Ok(benchmark_progress) => *benchmark_progress,
+            Err(_error) => 0.0,
+        };
+        let benchmark_results = match self.benchmark_results.read() {
+            Ok(benchmark_results) => benchmark_results.clone(),
+            Err(_error) => Vec::new(),
+        };
+        let benchmark_status = match self.benchmark_status.read() {
+            Ok(benchmark_status) => benchmark_status.clone(),
+            Err(_error) => None,
+        };
+        let is_benchmark_supported = self
+            .app_context
+            .engine_unprivileged_state
+            .has_capabilities(EngineCapabilities::SCAN_BENCHMARK);
+        let fastest_benchmark = benchmark_results
+            .iter()
+            .max_by(|left, right| left.elements_per_second.total_cmp(&right.elements_per_second));
+
+        user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Scan Threads", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            // Zero uses one thread per logical core, so it is shown as the maximum, and the maximum is stored as zero.
+                            let mut value: i64 = match cached_scan_settings.scan_thread_count {
+                                0 => self.logical_core_count as i64,
+                                scan_thread_count => scan_thread_count.min(self.logical_core_count) as i64,
+                            };
+                            let slider = Slider::new_from_theme(theme)
+                                .current_value(&mut value)
+                                .minimum_value(1)
+                                .maximum_value(self.logical_core_count as i64);
+
+                            if user_interface.add(slider).changed() {
+                                let scan_thread_count = if value as u32 >= self.logical_core_count { 0 } else { value as u32 };
+
+                                self.set_scan_configuration(Some(scan_thread_count), None);
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface.allocate_ui_with_layout(
+                                vec2(32.0, user_interface.available_height()),
+                                Layout::right_to_left(Align::Center),
+                                |user_interface| {
+                                    user_interface.label(
+                                        RichText::new(value.to_string())
+                                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                },
+                            );
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Worker threads")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+
+                        user_interface.add_space(8.0);
+                        user_interface.horizontal(|user_interface| {
+                            user_interface.add(ComboBoxView::new(
+                                self.app_context.clone(),
+                                Self::get_vector_width_label(cached_scan_settings.vector_width),
+                                "settings_tab_performance_vector_width",
+                                None,
+                                |user_interface: &mut Ui, should_close: &mut bool| {
+                                    for vector_width in ScanVectorWidth::ALL {
+                                        if user_interface
+                                            .add(ComboBoxItemView::new(
+                                                self.app_context.clone(),
+                                                Self::get_vector_width_label(vector_width),
+                                                None,
+                                                220.0,
+                                            ))
+                                            .clicked()
+                                        {
+                                            self.set_scan_configuration(None, Some(vector_width));
+                                            *should_close = true;
+                                            return;
+                                        }
+                                    }
+                                },
+                            ));
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("SIMD vector width")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+                user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Benchmark", |user_interface| {
+                        if !is_benchmark_supported {
+                            user_interface.colored_label(theme.error_red, "The engine does not support scan benchmarks.");
+                            return;
+                        }
+
+                        user_interface.horizontal(|user_interface| {
+                            if benchmark_task_id.is_some() {
+                                if self.add_text_button(user_interface, "Cancel", 96.0).clicked() {
+                                    self.cancel_benchmark();
+                                }
+
+                                user_interface.add_space(8.0);
+                                user_interface.label(
+                                    RichText::new(format!("Benchmarking... {:.0}%", benchmark_progress))
+                                        .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                        .color(theme.foreground),
+                                );
+                            } else {
+                                if self
+                                    .add_text_button(user_interface, "Benchmark", 96.0)
+                                    .clicked()
+                                {
+                                    self.start_benchmark();
+                                }
+
+                                if let Some(fastest_benchmark) = fastest_benchmark {
+                                    user_interface.add_space(8.0);
+
+                                    if self
+                                        .add_text_button(user_interface, "Use Fastest", 96.0)
+                                        .clicked()
+                                    {
+                                        let scan_thread_count = if fastest_benchmark.scan_thread_count >= self.logical_core_count {
+                                            0
+                                        } else {
+                                            fastest_benchmark.scan_thread_count
+                                        };
+
+                                        self.set_scan_configuration(Some(scan_thread_count), Some(fastest_benchmark.vector_width));
+                                    }
+                                }
+                            }
+                        });
+
+                        if let Some(benchmark_status) = &benchmark_status {
+                            user_interface.add_space(4.0);
+                            user_interface.colored_label(theme.error_red, benchmark_status);
+                        }
+
+                        for scan_configuration_benchmark in &benchmark_results {
+                            let is_fastest = fastest_benchmark == Some(scan_configuration_benchmark);
+                            let color = if is_fastest { theme.hexadecimal_green } else { theme.foreground };
+
+                            user_interface.add_space(4.0);
+                            user_interface.label(
+                                RichText::new(format!(
+                                    "{} thread(s), {}: {:.1} M elements/s",
+                                    scan_configuration_benchmark.scan_thread_count,
+                                    Self::get_vector_width_label(scan_configuration_benchmark.vector_width),
+                                    scan_configuration_benchmark.elements_per_second / 1_000_000.0
+                                ))
+                                .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                .color(color),
+                            );
+                        }
+                    })
+                    .desired_width(412.0),
+                );
+            })
+            .response
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     app_context::AppContext,
+    models::appearance::appearance_settings::AppearanceSettings,
     ui::widgets::controls::{checkbox::Checkbox, combo_box::combo_box_view::ComboBoxView, groupbox::GroupBox, slider::Slider},
 };
 use eframe::egui::{Align, Layout, Response, RichText, Ui, Widget};
@@ -12,6 +13,7 @@ use squalr_engine_api::{
     structures::memory::memory_alignment::MemoryAlignment,
     structures::settings::scan_settings::ScanSettings,
     structures::settings::scan_thread_priority::ScanThreadPriority,
+    structures::settings::value_overflow_policy::ValueOverflowPolicy,
 };
 use std::sync::{Arc, RwLock};
 
@@ -131,6 +133,133 @@ impl Widget for SettingsTabScanView {
                                     .color(theme.foreground),
                             );
                         });
+
+                        user_interface.add_space(8.0);
+                        user_interface.horizontal(|user_interface| {
+                            let overflow_policy_label = match cached_scan_settings.value_overflow_policy {
+                                ValueOverflowPolicy::Saturate => "Saturate",
+                                ValueOverflowPolicy::Wrap => "Wrap",
+                            };
+
+                            user_interface.add(ComboBoxView::new(
+                                self.app_context.clone(),
+                                overflow_policy_label,
+                                "settings_tab_scan_value_overflow_policy",
+                                None,
+                                |user_interface: &mut Ui, should_close: &mut bool| {
+                                    let items = [(ValueOverflowPolicy::Saturate, "Saturate"), (ValueOverflowPolicy::Wrap, "Wrap")];
+
+                                    for (value_overflow_policy, label) in items {
+                                        if user_interface
+                                            .add(crate::ui::widgets::controls::combo_box::combo_box_item_view::ComboBoxItemView::new(
+                                                self.app_context.clone(),
+                                                label,
+                                                None,
+                                                220.0,
+                                            ))
+                                            .clicked()
+                                        {
+                                            if let Ok(mut cached_scan_settings) = self.cached_scan_settings.write() {
+                                                cached_scan_settings.value_overflow_policy = value_overflow_policy;
+                                            }
+
+                                            let scan_settings_set_request = ScanSettingsSetRequest {
+                                                value_overflow_policy: Some(value_overflow_policy),
+                                                ..ScanSettingsSetRequest::default()
+                                            };
+
+                                            scan_settings_set_request.send(&self.app_context.engine_unprivileged_state, move |_response| {});
+                                            *should_close = true;
+                                            return;
+                                        }
+                                    }
+                                },
+                            ));
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Nudge overflow")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+                user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Scan Constraints", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            let mut value: i64 = AppearanceSettings::get_max_scan_constraints() as i64;
+                            let slider = Slider::new_from_theme(theme)
+                                .current_value(&mut value)
+                                .minimum_value(AppearanceSettings::MINIMUM_MAX_SCAN_CONSTRAINTS as i64)
+                                .maximum_value(AppearanceSettings::MAXIMUM_MAX_SCAN_CONSTRAINTS as i64);
+
+                            // This only limits the element scanner rows, so it is kept with the GUI settings rather than sent to the engine.
+                            if user_interface.add(slider).changed() {
+                                AppearanceSettings::set_max_scan_constraints(value as u32);
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface.allocate_ui_with_layout(
+                                vec2(32.0, user_interface.available_height()),
+                                Layout::right_to_left(Align::Center),
+                                |user_interface| {
+                                    user_interface.label(
+                                        RichText::new(value.to_string())
+                                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                },
+                            );
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Max constraint rows")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
+                    })
+                    .desired_width(412.0),
+                );
+                user_interface.add_space(4.0);
+                user_interface.add(
+                    GroupBox::new_from_theme(theme, "Clipboard", |user_interface| {
+                        user_interface.horizontal(|user_interface| {
+                            const CELLS_PER_STEP: u64 = 1_000_000;
+                            let mut value: i64 = (AppearanceSettings::get_max_copied_cells() / CELLS_PER_STEP) as i64;
+                            let slider = Slider::new_from_theme(theme)
+                                .current_value(&mut value)
+                                .minimum_value((AppearanceSettings::MINIMUM_MAX_COPIED_CELLS / CELLS_PER_STEP) as i64)
+                                .maximum_value((AppearanceSettings::MAXIMUM_MAX_COPIED_CELLS / CELLS_PER_STEP) as i64);
+
+                            // Copies are built by the GUI, so their limit is kept with the GUI settings rather than sent to the engine.
+                            if user_interface.add(slider).changed() {
+                                AppearanceSettings::set_max_copied_cells(value as u64 * CELLS_PER_STEP);
+                            }
+
+                            user_interface.add_space(8.0);
+                            user_interface.allocate_ui_with_layout(
+                                vec2(32.0, user_interface.available_height()),
+                                Layout::right_to_left(Align::Center),
+                                |user_interface| {
+                                    user_interface.label(
+                                        RichText::new(format!("{}M", value))
+                                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                            .color(theme.foreground),
+                                    );
+                                },
+                            );
+
+                            user_interface.add_space(8.0);
+                            user_interface.label(
+                                RichText::new("Max copied cells")
+                                    .font(theme.font_library.font_noto_sans.font_normal.clone())
+                                    .color(theme.foreground),
+                            );
+                        });
                     })
                     .desired_width(412.0),
                 );
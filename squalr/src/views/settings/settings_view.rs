@@ -1,9 +1,11 @@
 use crate::{
     app_context::AppContext,
-    models::tab_menu::tab_menu_data::TabMenuData,
+    models::{command_palette::palette_command::PaletteCommand, docking::docking_command::DockingCommand, tab_menu::tab_menu_data::TabMenuData},
     ui::widgets::controls::tab_menu::tab_menu_view::TabMenuView,
     views::settings::{
-        settings_tab_general_view::SettingsTabGeneralView, settings_tab_memory_view::SettingsTabMemoryView, settings_tab_scan_view::SettingsTabScanView,
+        settings_tab_data_types_view::SettingsTabDataTypesView, settings_tab_general_view::SettingsTabGeneralView,
+        settings_tab_memory_view::SettingsTabMemoryView, settings_tab_performance_view::SettingsTabPerformanceView,
+        settings_tab_scan_view::SettingsTabScanView,
     },
 };
 use eframe::egui::{Align, Layout, Response, Ui, Widget};
@@ -22,6 +24,8 @@ pub struct SettingsView {
     settings_tab_general_view: Rc<SettingsTabGeneralView>,
     settings_tab_memory_view: Rc<SettingsTabMemoryView>,
     settings_tab_scan_view: Rc<SettingsTabScanView>,
+    settings_tab_performance_view: Rc<SettingsTabPerformanceView>,
+    settings_tab_data_types_view: Rc<SettingsTabDataTypesView>,
 }
 
 impl SettingsView {
@@ -29,12 +33,23 @@ impl SettingsView {
 
     pub fn new(app_context: Arc<AppContext>) -> Self {
         let tab_menu_data = TabMenuData {
-            headers: vec!["General".to_string(), "Memory".to_string(), "Scan".to_string()].into(),
-            active_tab_index: Rc::new(AtomicI32::new(1)),
+            headers: vec![
+                "General".to_string(),
+                "Memory".to_string(),
+                "Scan".to_string(),
+                "Performance".to_string(),
+                "Data Types".to_string(),
+            ]
+            .into(),
+            active_tab_index: Arc::new(AtomicI32::new(1)),
         };
         let settings_tab_general_view = Rc::new(SettingsTabGeneralView::new(app_context.clone()));
         let settings_tab_memory_view = Rc::new(SettingsTabMemoryView::new(app_context.clone()));
         let settings_tab_scan_view = Rc::new(SettingsTabScanView::new(app_context.clone()));
+        let settings_tab_performance_view = Rc::new(SettingsTabPerformanceView::new(app_context.clone()));
+        let settings_tab_data_types_view = Rc::new(SettingsTabDataTypesView::new(app_context.clone()));
+
+        Self::register_commands(&app_context, &tab_menu_data);
 
         Self {
             app_context,
@@ -42,6 +57,32 @@ impl SettingsView {
             settings_tab_general_view,
             settings_tab_memory_view,
             settings_tab_scan_view,
+            settings_tab_performance_view,
+            settings_tab_data_types_view,
+        }
+    }
+
+    /// Registers a command with the command palette to open each settings tab.
+    fn register_commands(
+        app_context: &Arc<AppContext>,
+        tab_menu_data: &TabMenuData,
+    ) {
+        for (tab_index, header) in tab_menu_data.headers.iter().enumerate() {
+            let app_context_clone = app_context.clone();
+            let active_tab_index = tab_menu_data.active_tab_index.clone();
+
+            app_context.command_registry.register(PaletteCommand::new(
+                format!("settings.open_tab.{}", tab_index),
+                "Settings",
+                format!("Open {} Settings", header),
+                move || {
+                    active_tab_index.store(tab_index as i32, Ordering::Release);
+                    app_context_clone.queue_docking_command(DockingCommand::ShowWindow {
+                        window_id: Self::WINDOW_ID.to_string(),
+                        is_visible: true,
+                    });
+                },
+            ));
         }
     }
 }
@@ -65,6 +106,12 @@ impl Widget for SettingsView {
                     2 => {
                         user_interface.add(self.settings_tab_scan_view.as_ref().clone());
                     }
+                    3 => {
+                        user_interface.add(self.settings_tab_performance_view.as_ref().clone());
+                    }
+                    4 => {
+                        user_interface.add(self.settings_tab_data_types_view.as_ref().clone());
+                    }
                     _ => {
                         user_interface.add(self.settings_tab_general_view.as_ref().clone());
                     }
@@ -1,3 +1,4 @@
+pub mod struct_dissection_entry_view;
 pub mod struct_viewer_entry_view;
 pub mod struct_viewer_view;
 pub mod view_data;
@@ -0,0 +1,108 @@
+use crate::{
+    app_context::AppContext,
+    ui::{draw::icon_draw::IconDraw, widgets::controls::state_layer::StateLayer},
+};
+use eframe::egui::{Align2, Response, Sense, Ui, Widget, vec2};
+use epaint::{CornerRadius, pos2};
+use std::sync::Arc;
+
+/// A row of a dissected struct, ie a field and its value, indented by how many pointers were followed to reach it.
+pub struct StructDissectionEntryView {
+    app_context: Arc<AppContext>,
+    name: String,
+    value: String,
+    depth: usize,
+    /// Whether the struct that this row leads to is expanded, or none if the row does not lead to a struct.
+    is_expanded: Option<bool>,
+    name_splitter_x: f32,
+    value_splitter_x: f32,
+}
+
+impl StructDissectionEntryView {
+    pub fn new(
+        app_context: Arc<AppContext>,
+        name: String,
+        value: String,
+        depth: usize,
+        is_expanded: Option<bool>,
+        name_splitter_x: f32,
+        value_splitter_x: f32,
+    ) -> Self {
+        Self {
+            app_context,
+            name,
+            value,
+            depth,
+            is_expanded,
+            name_splitter_x,
+            value_splitter_x,
+        }
+    }
+}
+
+impl Widget for StructDissectionEntryView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        const INDENT_WIDTH: f32 = 16.0;
+
+        let theme = &self.app_context.theme;
+        let icon_size = vec2(16.0, 16.0);
+        let text_left_padding = 4.0;
+        let row_height = 24.0;
+
+        let desired_size = vec2(user_interface.available_width(), row_height);
+        let (available_size_id, available_size_rect) = user_interface.allocate_space(desired_size);
+        let response = user_interface.interact(available_size_rect, available_size_id, Sense::click());
+
+        StateLayer {
+            bounds_min: available_size_rect.min,
+            bounds_max: available_size_rect.max,
+            enabled: true,
+            pressed: response.is_pointer_button_down_on(),
+            has_hover: response.hovered(),
+            has_focus: response.has_focus(),
+            corner_radius: CornerRadius::ZERO,
+            border_width: 0.0,
+            hover_color: theme.hover_tint,
+            pressed_color: theme.pressed_tint,
+            border_color: theme.background_control_secondary_dark,
+            border_color_focused: theme.focused_border,
+        }
+        .paint(user_interface);
+
+        let row_max_x = available_size_rect.max.x;
+        let center_y = available_size_rect.center().y;
+        let name_position_x = available_size_rect.min.x + self.name_splitter_x + INDENT_WIDTH * self.depth as f32;
+        let value_position_x = self.value_splitter_x.min(row_max_x);
+
+        // Draw the expand arrow in the indent before the name.
+        if let Some(is_expanded) = self.is_expanded {
+            let arrow_icon = if is_expanded {
+                &theme.icon_library.icon_handle_navigation_down_arrow_small
+            } else {
+                &theme.icon_library.icon_handle_navigation_right_arrow_small
+            };
+
+            IconDraw::draw_sized(user_interface, pos2(name_position_x - INDENT_WIDTH * 0.5, center_y), icon_size, arrow_icon);
+        }
+
+        user_interface.painter().text(
+            pos2(name_position_x + text_left_padding, center_y),
+            Align2::LEFT_CENTER,
+            &self.name,
+            theme.font_library.font_noto_sans.font_normal.clone(),
+            theme.foreground,
+        );
+        user_interface.painter().text(
+            pos2(value_position_x + text_left_padding, center_y),
+            Align2::LEFT_CENTER,
+            &self.value,
+            theme.font_library.font_noto_sans.font_normal.clone(),
+            theme.foreground_preview,
+        );
+
+        response
+    }
+}
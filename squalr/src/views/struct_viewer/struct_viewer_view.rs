@@ -1,9 +1,16 @@
+use crate::ui::widgets::controls::address_resolution_label::AddressResolutionLabel;
+use crate::views::struct_viewer::struct_dissection_entry_view::StructDissectionEntryView;
 use crate::views::struct_viewer::struct_viewer_entry_view::StructViewerEntryView;
+use crate::views::struct_viewer::view_data::struct_dissection_tree::{StructDissectionNodeState, StructDissectionTree, StructDissectionTreeNode};
 use crate::views::struct_viewer::view_data::struct_viewer_frame_action::StructViewerFrameAction;
 use crate::{app_context::AppContext, views::struct_viewer::view_data::struct_viewer_view_data::StructViewerViewData};
-use eframe::egui::{Align, CursorIcon, Layout, Response, ScrollArea, Sense, Ui, Widget};
+use eframe::egui::{Align, Button, ComboBox, CursorIcon, Layout, Response, RichText, ScrollArea, Sense, TextEdit, Ui, Widget, Window};
 use epaint::{Rect, pos2};
 use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use squalr_engine_api::registries::symbols::symbol_registry::SymbolRegistry;
+use squalr_engine_api::structures::data_types::custom_types::data_type_custom_struct::CustomStructFieldLayout;
+use squalr_engine_api::structures::data_values::data_value::DataValue;
 use std::sync::Arc;
 
 #[derive(Clone)]
@@ -25,6 +32,299 @@ impl StructViewerView {
             struct_viewer_view_data,
         }
     }
+
+    /// Shows the dialog asking for the base address of a second instance of the focused struct.
+    fn show_new_base_dialog(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let is_new_base_dialog_open = self
+            .struct_viewer_view_data
+            .read("Struct viewer new base dialog state")
+            .map(|view_data| view_data.is_new_base_dialog_open)
+            .unwrap_or(false);
+
+        if !is_new_base_dialog_open {
+            return;
+        }
+
+        let theme = &self.app_context.theme;
+        let mut should_add = false;
+        let mut should_close = false;
+
+        Window::new("Re-add at new base")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                let mut view_data = match self.struct_viewer_view_data.write("Struct viewer new base dialog") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+
+                user_interface.horizontal(|user_interface| {
+                    user_interface.label("Base address");
+
+                    let response = user_interface.add(TextEdit::singleline(&mut view_data.new_base_address).desired_width(220.0));
+
+                    if response.changed() {
+                        view_data.new_base_resolution = None;
+                    }
+
+                    if response.lost_focus() && user_interface.input(|input| input.key_pressed(eframe::egui::Key::Enter)) {
+                        should_add = true;
+                    }
+                });
+
+                if let Some(new_base_resolution) = &view_data.new_base_resolution {
+                    user_interface.add(AddressResolutionLabel::new(theme, new_base_resolution));
+                }
+
+                user_interface.horizontal(|user_interface| {
+                    if user_interface.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+
+                    if user_interface.button("Add").clicked() {
+                        should_add = true;
+                    }
+                });
+            });
+
+        if should_close {
+            StructViewerViewData::close_new_base_dialog(self.struct_viewer_view_data.clone());
+        } else if should_add {
+            StructViewerViewData::add_all_fields_at_new_base(self.struct_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        }
+    }
+
+    /// Shows how many fields the last bulk add created and skipped.
+    fn show_add_fields_summary(
+        &self,
+        user_interface: &mut Ui,
+    ) {
+        let add_fields_summary = match self
+            .struct_viewer_view_data
+            .read("Struct viewer add fields summary")
+            .and_then(|view_data| view_data.add_fields_summary.clone())
+        {
+            Some(add_fields_summary) => add_fields_summary,
+            None => return,
+        };
+        let mut should_close = false;
+
+        Window::new("Add fields to project")
+            .collapsible(false)
+            .resizable(false)
+            .show(user_interface.ctx(), |user_interface| {
+                user_interface.label(add_fields_summary);
+
+                if user_interface.button("OK").clicked() {
+                    should_close = true;
+                }
+            });
+
+        if should_close {
+            StructViewerViewData::close_add_fields_summary(self.struct_viewer_view_data.clone());
+        }
+    }
+
+    /// Shows the controls of dissect data mode, and the dissections saved to the project that can be loaded.
+    fn show_struct_dissection_toolbar(
+        &self,
+        user_interface: &mut Ui,
+        frame_action: &mut StructViewerFrameAction,
+    ) {
+        let struct_dissection_names = StructViewerViewData::get_project_struct_dissections(&self.app_context.engine_unprivileged_state)
+            .iter()
+            .map(|struct_dissection| struct_dissection.get_name().to_string())
+            .collect::<Vec<_>>();
+        let mut view_data = match self
+            .struct_viewer_view_data
+            .write("Struct viewer struct dissection toolbar")
+        {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let is_dissecting = view_data.struct_dissection_tree.is_some();
+
+        if !is_dissecting && struct_dissection_names.is_empty() {
+            return;
+        }
+
+        user_interface.horizontal(|user_interface| {
+            if is_dissecting {
+                if user_interface.button("Refresh").clicked() {
+                    *frame_action = StructViewerFrameAction::RefreshDissection;
+                }
+
+                user_interface.add(
+                    TextEdit::singleline(&mut view_data.struct_dissection_name)
+                        .hint_text("Dissection name")
+                        .desired_width(160.0),
+                );
+
+                if user_interface.button("Save to project").clicked() {
+                    *frame_action = StructViewerFrameAction::SaveDissection;
+                }
+            }
+
+            if !struct_dissection_names.is_empty() {
+                ComboBox::from_id_salt("struct_viewer_load_struct_dissection")
+                    .selected_text("Load dissection")
+                    .show_ui(user_interface, |user_interface| {
+                        for struct_dissection_name in &struct_dissection_names {
+                            if user_interface
+                                .selectable_label(false, struct_dissection_name)
+                                .clicked()
+                            {
+                                *frame_action = StructViewerFrameAction::LoadDissection(struct_dissection_name.clone());
+                            }
+                        }
+                    });
+            }
+
+            if is_dissecting && user_interface.button("Close").clicked() {
+                *frame_action = StructViewerFrameAction::CloseDissection;
+            }
+        });
+
+        if let Some(struct_dissection_status) = &view_data.struct_dissection_status {
+            user_interface.label(RichText::new(struct_dissection_status).color(self.app_context.theme.foreground_preview));
+        }
+    }
+
+    /// Shows the fields of a dissected struct, followed by the structs of any followed pointer fields directly beneath them. Fields
+    /// are indented one level beneath the row of the struct that holds them.
+    fn show_struct_dissection_node(
+        &self,
+        user_interface: &mut Ui,
+        struct_dissection_tree: &StructDissectionTree,
+        path: &[String],
+        name_splitter_x: f32,
+        value_splitter_x: f32,
+        frame_action: &mut StructViewerFrameAction,
+    ) {
+        let Some(node) = struct_dissection_tree.get_node(path) else {
+            return;
+        };
+        let depth = path.len() + 1;
+        let status = match node.state {
+            StructDissectionNodeState::Cycle => Some("Points back to a parent struct"),
+            StructDissectionNodeState::Unreadable => Some("Unreadable memory"),
+            _ if node.bytes.is_empty() => Some("Reading..."),
+            _ => None,
+        };
+
+        if let Some(status) = status {
+            user_interface.add(StructDissectionEntryView::new(
+                self.app_context.clone(),
+                status.to_string(),
+                String::new(),
+                depth,
+                None,
+                name_splitter_x,
+                value_splitter_x,
+            ));
+            return;
+        }
+
+        for field_layout in node
+            .custom_struct
+            .get_field_layouts()
+            .iter()
+            .filter(|field_layout| !field_layout.is_padding)
+        {
+            let mut child_path = path.to_vec();
+
+            child_path.push(field_layout.name.clone());
+
+            let child = struct_dissection_tree.get_node(&child_path);
+            let is_pointer_field = StructDissectionTree::is_pointer_field(field_layout);
+            let name = match child {
+                Some(child) => format!("{} -> {}", field_layout.name, child.custom_struct.get_definition().get_name()),
+                None => field_layout.name.clone(),
+            };
+            let value = match is_pointer_field {
+                true => struct_dissection_tree
+                    .get_pointer_value(path, &field_layout.name)
+                    .map(|pointer_value| format!("0x{:X}", pointer_value))
+                    .unwrap_or_else(|| Self::format_field_value(node, field_layout)),
+                false => Self::format_field_value(node, field_layout),
+            };
+            let row_response = user_interface.add(StructDissectionEntryView::new(
+                self.app_context.clone(),
+                name,
+                value,
+                depth,
+                child.map(|child| child.is_expanded),
+                name_splitter_x,
+                value_splitter_x,
+            ));
+
+            if child.is_some() && row_response.clicked() {
+                *frame_action = StructViewerFrameAction::ToggleDissectionNode(child_path.clone());
+            }
+
+            if is_pointer_field {
+                row_response.context_menu(|user_interface| {
+                    user_interface.menu_button("Dissect as", |user_interface| {
+                        let data_type_ids = CustomDataTypeRegistry::get_instance().get_data_type_ids();
+
+                        if data_type_ids.is_empty() {
+                            user_interface.label("No custom data types are registered.");
+                        }
+
+                        for data_type_id in data_type_ids {
+                            if user_interface.button(&data_type_id).clicked() {
+                                *frame_action = StructViewerFrameAction::FollowDissectionPointer(path.to_vec(), field_layout.name.clone(), data_type_id);
+                                user_interface.close();
+                            }
+                        }
+                    });
+
+                    if user_interface
+                        .add_enabled(child.is_some(), Button::new("Stop following"))
+                        .clicked()
+                    {
+                        *frame_action = StructViewerFrameAction::UnfollowDissectionPointer(child_path.clone());
+                        user_interface.close();
+                    }
+                });
+            }
+
+            if child.is_some_and(|child| child.is_expanded) {
+                self.show_struct_dissection_node(
+                    user_interface,
+                    struct_dissection_tree,
+                    &child_path,
+                    name_splitter_x,
+                    value_splitter_x,
+                    frame_action,
+                );
+            }
+        }
+    }
+
+    fn format_field_value(
+        node: &StructDissectionTreeNode,
+        field_layout: &CustomStructFieldLayout,
+    ) -> String {
+        let start = field_layout.offset as usize;
+        let Some(field_bytes) = node
+            .bytes
+            .get(start..start + field_layout.data_type.get_unit_size_in_bytes() as usize)
+        else {
+            return "??".to_string();
+        };
+        let symbol_registry = SymbolRegistry::get_instance();
+        let data_type_ref = field_layout.data_type.get_ref();
+        let data_value = DataValue::new(data_type_ref.clone(), field_bytes.to_vec());
+
+        symbol_registry
+            .anonymize_value(&data_value, symbol_registry.get_default_anonymous_value_string_format(&data_type_ref))
+            .map(|anonymous_value| anonymous_value.get_anonymous_value_string().to_string())
+            .unwrap_or_else(|_error| "??".to_string())
+    }
 }
 
 impl Widget for StructViewerView {
@@ -41,6 +341,9 @@ impl Widget for StructViewerView {
 
         let mut new_value_splitter_ratio: Option<f32> = None;
 
+        StructViewerViewData::read_struct_dissection(self.struct_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+        self.show_struct_dissection_toolbar(user_interface, &mut frame_action);
+
         let response = user_interface
             .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |mut user_interface| {
                 let struct_viewer_view_data = match self.struct_viewer_view_data.read("Struct viewer view") {
@@ -77,7 +380,39 @@ impl Widget for StructViewerView {
                     .id_salt("struct_viewer")
                     .auto_shrink([false, false])
                     .show(&mut user_interface, |inner_ui| {
-                        if let Some(struct_under_view) = struct_viewer_view_data.struct_under_view.as_ref() {
+                        if let Some(struct_dissection_tree) = struct_viewer_view_data.struct_dissection_tree.as_ref() {
+                            let root = struct_dissection_tree.get_root();
+                            let root_address = match struct_dissection_tree.get_module().is_empty() {
+                                true => format!("{:X}", root.address),
+                                false => format!("{}+{:X}", struct_dissection_tree.get_module(), root.address),
+                            };
+                            let root_response = inner_ui.add(StructDissectionEntryView::new(
+                                self.app_context.clone(),
+                                root.custom_struct.get_definition().get_name().to_string(),
+                                root_address,
+                                0,
+                                Some(root.is_expanded),
+                                ICON_COLUMN_WIDTH + BAR_THICKNESS,
+                                value_splitter_x + BAR_THICKNESS,
+                            ));
+
+                            if root_response.clicked() {
+                                frame_action = StructViewerFrameAction::ToggleDissectionNode(vec![]);
+                            }
+
+                            if root.is_expanded {
+                                self.show_struct_dissection_node(
+                                    inner_ui,
+                                    struct_dissection_tree,
+                                    StructDissectionTree::ROOT_PATH,
+                                    ICON_COLUMN_WIDTH + BAR_THICKNESS,
+                                    value_splitter_x + BAR_THICKNESS,
+                                    &mut frame_action,
+                                );
+                            }
+                        } else if let Some(struct_under_view) = struct_viewer_view_data.struct_under_view.as_ref() {
+                            let has_focused_struct_address = struct_viewer_view_data.focused_struct_address.is_some();
+
                             for field in struct_under_view.get_fields() {
                                 let is_selected = struct_viewer_view_data
                                     .selected_field_name
@@ -85,7 +420,7 @@ impl Widget for StructViewerView {
                                     .unwrap_or_default()
                                     == field.get_name();
 
-                                inner_ui.add(StructViewerEntryView::new(
+                                let row_response = inner_ui.add(StructViewerEntryView::new(
                                     self.app_context.clone(),
                                     &field,
                                     is_selected,
@@ -93,6 +428,33 @@ impl Widget for StructViewerView {
                                     ICON_COLUMN_WIDTH + BAR_THICKNESS,
                                     value_splitter_x + BAR_THICKNESS,
                                 ));
+
+                                // Fields can only become project entries if the struct was focused from an address.
+                                row_response.context_menu(|user_interface| {
+                                    if user_interface
+                                        .add_enabled(has_focused_struct_address, Button::new("Add all fields to project"))
+                                        .clicked()
+                                    {
+                                        frame_action = StructViewerFrameAction::AddAllFieldsToProject;
+                                        user_interface.close();
+                                    }
+
+                                    if user_interface
+                                        .add_enabled(has_focused_struct_address, Button::new("Re-add at new base..."))
+                                        .clicked()
+                                    {
+                                        frame_action = StructViewerFrameAction::OpenNewBaseDialog;
+                                        user_interface.close();
+                                    }
+
+                                    if user_interface
+                                        .add_enabled(has_focused_struct_address, Button::new("Dissect data"))
+                                        .clicked()
+                                    {
+                                        frame_action = StructViewerFrameAction::StartDissection;
+                                        user_interface.close();
+                                    }
+                                });
                             }
                         }
                     });
@@ -146,8 +508,41 @@ impl Widget for StructViewerView {
             StructViewerFrameAction::EditValue(_field, _value) => {
                 //
             }
+            StructViewerFrameAction::AddAllFieldsToProject => {
+                StructViewerViewData::add_all_fields_to_project(self.struct_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            StructViewerFrameAction::OpenNewBaseDialog => {
+                StructViewerViewData::open_new_base_dialog(self.struct_viewer_view_data.clone());
+            }
+            StructViewerFrameAction::StartDissection => {
+                StructViewerViewData::start_struct_dissection(self.struct_viewer_view_data.clone());
+            }
+            StructViewerFrameAction::RefreshDissection => {
+                StructViewerViewData::refresh_struct_dissection(self.struct_viewer_view_data.clone());
+            }
+            StructViewerFrameAction::SaveDissection => {
+                StructViewerViewData::save_struct_dissection(self.struct_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone());
+            }
+            StructViewerFrameAction::LoadDissection(name) => {
+                StructViewerViewData::load_struct_dissection(self.struct_viewer_view_data.clone(), self.app_context.engine_unprivileged_state.clone(), &name);
+            }
+            StructViewerFrameAction::CloseDissection => {
+                StructViewerViewData::close_struct_dissection(self.struct_viewer_view_data.clone());
+            }
+            StructViewerFrameAction::ToggleDissectionNode(path) => {
+                StructViewerViewData::toggle_struct_dissection_node(self.struct_viewer_view_data.clone(), path);
+            }
+            StructViewerFrameAction::FollowDissectionPointer(path, field_name, data_type_id) => {
+                StructViewerViewData::follow_struct_dissection_pointer(self.struct_viewer_view_data.clone(), path, field_name, data_type_id);
+            }
+            StructViewerFrameAction::UnfollowDissectionPointer(path) => {
+                StructViewerViewData::unfollow_struct_dissection_pointer(self.struct_viewer_view_data.clone(), path);
+            }
         }
 
+        self.show_new_base_dialog(user_interface);
+        self.show_add_fields_summary(user_interface);
+
         response
     }
 }
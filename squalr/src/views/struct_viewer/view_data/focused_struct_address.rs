@@ -0,0 +1,34 @@
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+
+/// Where the struct under view lives in memory, such that its fields can be added to the project as address entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FocusedStructAddress {
+    /// The custom struct data type that lays out the fields.
+    pub data_type_ref: DataTypeRef,
+    /// The address of the start of the struct, relative to the module if one is set.
+    pub address: u64,
+    pub module: String,
+}
+
+impl FocusedStructAddress {
+    pub fn new(
+        data_type_ref: DataTypeRef,
+        address: u64,
+        module: &str,
+    ) -> Self {
+        Self {
+            data_type_ref,
+            address,
+            module: module.to_string(),
+        }
+    }
+
+    /// Gets the address as displayed to the user, ie `game.exe+1A0` or an absolute address.
+    pub fn get_display_address(&self) -> String {
+        if self.module.is_empty() {
+            format!("{:X}", self.address)
+        } else {
+            format!("{}+{:X}", self.module, self.address)
+        }
+    }
+}
@@ -1,2 +1,4 @@
+pub mod focused_struct_address;
+pub mod struct_dissection_tree;
 pub mod struct_viewer_frame_action;
 pub mod struct_viewer_view_data;
@@ -0,0 +1,457 @@
+use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+use squalr_engine_api::structures::data_types::built_in_types::u32be::data_type_u32be::DataTypeU32be;
+use squalr_engine_api::structures::data_types::built_in_types::u64::data_type_u64::DataTypeU64;
+use squalr_engine_api::structures::data_types::built_in_types::u64be::data_type_u64be::DataTypeU64be;
+use squalr_engine_api::structures::data_types::custom_types::data_type_custom_struct::{CustomStructFieldLayout, DataTypeCustomStruct};
+use squalr_engine_api::structures::data_types::data_type::DataType;
+use squalr_engine_api::structures::projects::struct_dissections::struct_dissection::{StructDissection, StructDissectionNode, StructDissectionPointer};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Whether the memory of a dissected struct has been read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StructDissectionNodeState {
+    /// Not yet read, either because it was never expanded, or because it was refreshed.
+    Unread,
+    Reading,
+    Read,
+    /// The pointer to the struct is null, or its memory could not be read.
+    Unreadable,
+    /// The struct is at the same address and of the same type as one of its parents, and is not read again.
+    Cycle,
+}
+
+/// A struct within a dissection, keyed in the tree by the pointer field names leading to it from the root.
+#[derive(Clone)]
+pub struct StructDissectionTreeNode {
+    pub custom_struct: Arc<DataTypeCustomStruct>,
+    /// The absolute address of the struct, or for the root, the address relative to the module of the tree.
+    pub address: u64,
+    pub bytes: Vec<u8>,
+    pub state: StructDissectionNodeState,
+    pub is_expanded: bool,
+}
+
+/// A pending read of the memory of a node, tagged with the refresh it belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StructDissectionRead {
+    pub path: Vec<String>,
+    pub address: u64,
+    pub module_name: String,
+    pub size_in_bytes: u64,
+    pub generation: u64,
+}
+
+/// A struct at a base address, along with the structs reached by following its pointer fields. Nodes are only read once they
+/// and all of their parents are expanded, such that a large tree does not dereference every pointer in it on each refresh.
+#[derive(Clone)]
+pub struct StructDissectionTree {
+    module: String,
+    nodes: BTreeMap<Vec<String>, StructDissectionTreeNode>,
+    /// Incremented on each refresh, such that reads from before the refresh are discarded.
+    generation: u64,
+}
+
+impl StructDissectionTree {
+    /// The path of the root struct, which is reached without following any pointers.
+    pub const ROOT_PATH: &[String] = &[];
+
+    pub fn new(
+        custom_struct: Arc<DataTypeCustomStruct>,
+        address: u64,
+        module: &str,
+    ) -> Self {
+        let mut nodes = BTreeMap::new();
+
+        nodes.insert(vec![], Self::create_node(custom_struct, address));
+
+        Self {
+            module: module.to_string(),
+            nodes,
+            generation: 0,
+        }
+    }
+
+    /// Creates a tree from a saved dissection. Structs are laid out from the definitions saved with it, rather than those registered.
+    pub fn from_struct_dissection(struct_dissection: &StructDissection) -> Result<Self, String> {
+        let mut nodes = BTreeMap::new();
+        let mut pending_nodes = vec![(vec![], struct_dissection.get_root())];
+
+        while let Some((path, struct_dissection_node)) = pending_nodes.pop() {
+            let custom_struct = Arc::new(DataTypeCustomStruct::new(struct_dissection_node.get_definition().clone())?);
+
+            for followed_pointer in struct_dissection_node.get_followed_pointers() {
+                let mut child_path: Vec<String> = path.clone();
+
+                child_path.push(followed_pointer.get_field_name().to_string());
+                pending_nodes.push((child_path, followed_pointer.get_node()));
+            }
+
+            nodes.insert(path, Self::create_node(custom_struct, 0));
+        }
+
+        if let Some(root) = nodes.get_mut(Self::ROOT_PATH) {
+            root.address = struct_dissection.get_address();
+        }
+
+        Ok(Self {
+            module: struct_dissection.get_module().to_string(),
+            nodes,
+            generation: 0,
+        })
+    }
+
+    /// Creates a dissection that can be saved to the project, keeping which pointers are followed but not the memory read.
+    pub fn to_struct_dissection(
+        &self,
+        name: &str,
+    ) -> StructDissection {
+        StructDissection::new(
+            name.to_string(),
+            self.get_root().address,
+            self.module.clone(),
+            self.to_struct_dissection_node(Self::ROOT_PATH),
+        )
+    }
+
+    pub fn get_module(&self) -> &str {
+        &self.module
+    }
+
+    pub fn get_root(&self) -> &StructDissectionTreeNode {
+        &self.nodes[Self::ROOT_PATH]
+    }
+
+    pub fn get_node(
+        &self,
+        path: &[String],
+    ) -> Option<&StructDissectionTreeNode> {
+        self.nodes.get(path)
+    }
+
+    /// Gets whether a field holds an address that can be followed, ie a `u32` or `u64` field.
+    pub fn is_pointer_field(field_layout: &CustomStructFieldLayout) -> bool {
+        matches!(
+            field_layout.data_type.get_data_type_id(),
+            DataTypeU32::DATA_TYPE_ID | DataTypeU32be::DATA_TYPE_ID | DataTypeU64::DATA_TYPE_ID | DataTypeU64be::DATA_TYPE_ID
+        )
+    }
+
+    /// Gets the address held by a pointer field of a read node.
+    pub fn get_pointer_value(
+        &self,
+        path: &[String],
+        field_name: &str,
+    ) -> Option<u64> {
+        let node = self.nodes.get(path)?;
+
+        if node.state != StructDissectionNodeState::Read {
+            return None;
+        }
+
+        let field_layout = node
+            .custom_struct
+            .get_field_layouts()
+            .iter()
+            .find(|field_layout| field_layout.name == field_name && Self::is_pointer_field(field_layout))?;
+        let start = field_layout.offset as usize;
+        let field_bytes = node
+            .bytes
+            .get(start..start + field_layout.data_type.get_unit_size_in_bytes() as usize)?;
+
+        match field_layout.data_type.get_data_type_id() {
+            DataTypeU32::DATA_TYPE_ID => Some(u32::from_le_bytes(field_bytes.try_into().ok()?) as u64),
+            DataTypeU32be::DATA_TYPE_ID => Some(u32::from_be_bytes(field_bytes.try_into().ok()?) as u64),
+            DataTypeU64::DATA_TYPE_ID => Some(u64::from_le_bytes(field_bytes.try_into().ok()?)),
+            DataTypeU64be::DATA_TYPE_ID => Some(u64::from_be_bytes(field_bytes.try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    /// Follows a pointer field of a node, showing the memory it points to as the given struct. Any struct previously followed from
+    /// the field is replaced, along with everything followed from it.
+    pub fn follow_pointer(
+        &mut self,
+        path: &[String],
+        field_name: &str,
+        custom_struct: Arc<DataTypeCustomStruct>,
+    ) {
+        if !self.nodes.contains_key(path) {
+            return;
+        }
+
+        let mut child_path = path.to_vec();
+
+        child_path.push(field_name.to_string());
+
+        self.unfollow_pointer(&child_path);
+        self.nodes
+            .insert(child_path, Self::create_node(custom_struct, 0));
+    }
+
+    /// Stops following the pointer leading to the given node, removing it and everything followed from it. The root is kept.
+    pub fn unfollow_pointer(
+        &mut self,
+        path: &[String],
+    ) {
+        if path.is_empty() {
+            return;
+        }
+
+        self.nodes.retain(|node_path, _| !node_path.starts_with(path));
+    }
+
+    pub fn toggle_expanded(
+        &mut self,
+        path: &[String],
+    ) {
+        if let Some(node) = self.nodes.get_mut(path) {
+            node.is_expanded = !node.is_expanded;
+        }
+    }
+
+    /// Marks every node as unread, such that expanded nodes are read again, parents before their children. Values already read are
+    /// kept on display until they are replaced.
+    pub fn refresh(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+
+        for node in self.nodes.values_mut() {
+            node.state = StructDissectionNodeState::Unread;
+        }
+    }
+
+    /// Gets the reads for every unread node that is shown, and marks those nodes as being read. A node is shown once it and all of
+    /// its parents are expanded, and its address is only known once its parent has been read.
+    pub fn take_pending_reads(&mut self) -> Vec<StructDissectionRead> {
+        let pending_paths: Vec<Vec<String>> = self
+            .nodes
+            .iter()
+            .filter(|(path, node)| node.state == StructDissectionNodeState::Unread && self.is_shown(path))
+            .map(|(path, _node)| path.clone())
+            .collect();
+        let mut pending_reads = vec![];
+
+        for path in pending_paths {
+            let Some((parent_path, field_name)) = path
+                .split_last()
+                .map(|(field_name, parent_path)| (parent_path, field_name))
+            else {
+                let root = self.get_root();
+
+                pending_reads.push(StructDissectionRead {
+                    path: path.clone(),
+                    address: root.address,
+                    module_name: self.module.clone(),
+                    size_in_bytes: root.custom_struct.get_unit_size_in_bytes(),
+                    generation: self.generation,
+                });
+                self.set_state(&path, StructDissectionNodeState::Reading);
+                continue;
+            };
+
+            if self.nodes.get(parent_path).map(|parent| parent.state) != Some(StructDissectionNodeState::Read) {
+                continue;
+            }
+
+            let address = self.get_pointer_value(parent_path, field_name).unwrap_or(0);
+            let Some(node) = self.nodes.get_mut(&path) else {
+                continue;
+            };
+
+            node.address = address;
+
+            if address == 0 {
+                node.state = StructDissectionNodeState::Unreadable;
+                continue;
+            }
+
+            if self.is_cycle(&path) {
+                self.set_state(&path, StructDissectionNodeState::Cycle);
+                continue;
+            }
+
+            let size_in_bytes = self.nodes[&path].custom_struct.get_unit_size_in_bytes();
+
+            pending_reads.push(StructDissectionRead {
+                path: path.clone(),
+                address,
+                module_name: String::new(),
+                size_in_bytes,
+                generation: self.generation,
+            });
+            self.set_state(&path, StructDissectionNodeState::Reading);
+        }
+
+        pending_reads
+    }
+
+    /// Applies the bytes read for a node, or marks it unreadable if the read failed. Reads from before the last refresh are discarded.
+    pub fn apply_read(
+        &mut self,
+        struct_dissection_read: &StructDissectionRead,
+        bytes: Option<Vec<u8>>,
+    ) {
+        if struct_dissection_read.generation != self.generation {
+            return;
+        }
+
+        let Some(node) = self.nodes.get_mut(&struct_dissection_read.path) else {
+            return;
+        };
+
+        if node.state != StructDissectionNodeState::Reading {
+            return;
+        }
+
+        match bytes {
+            Some(bytes) if bytes.len() as u64 >= node.custom_struct.get_unit_size_in_bytes() => {
+                node.bytes = bytes;
+                node.state = StructDissectionNodeState::Read;
+            }
+            _ => {
+                node.bytes.clear();
+                node.state = StructDissectionNodeState::Unreadable;
+            }
+        }
+    }
+
+    fn create_node(
+        custom_struct: Arc<DataTypeCustomStruct>,
+        address: u64,
+    ) -> StructDissectionTreeNode {
+        StructDissectionTreeNode {
+            custom_struct,
+            address,
+            bytes: vec![],
+            state: StructDissectionNodeState::Unread,
+            is_expanded: true,
+        }
+    }
+
+    fn set_state(
+        &mut self,
+        path: &[String],
+        state: StructDissectionNodeState,
+    ) {
+        if let Some(node) = self.nodes.get_mut(path) {
+            node.state = state;
+        }
+    }
+
+    fn is_shown(
+        &self,
+        path: &[String],
+    ) -> bool {
+        (0..=path.len()).all(|length| {
+            self.nodes
+                .get(&path[..length])
+                .map(|node| node.is_expanded)
+                .unwrap_or(false)
+        })
+    }
+
+    /// Gets whether any parent of a (non-root) node is the same struct at the same address. The root is compared by its address in
+    /// the module, which only matches a child if the tree has no module.
+    fn is_cycle(
+        &self,
+        path: &[String],
+    ) -> bool {
+        let node = &self.nodes[path];
+        let data_type_id = node.custom_struct.get_definition().get_data_type_id();
+
+        (0..path.len()).any(|length| {
+            let parent = &self.nodes[&path[..length]];
+            let is_absolute = length > 0 || self.module.is_empty();
+
+            is_absolute && parent.address == node.address && parent.custom_struct.get_definition().get_data_type_id() == data_type_id
+        })
+    }
+
+    fn to_struct_dissection_node(
+        &self,
+        path: &[String],
+    ) -> StructDissectionNode {
+        let node = &self.nodes[path];
+        let followed_pointers = node
+            .custom_struct
+            .get_field_layouts()
+            .iter()
+            .filter_map(|field_layout| {
+                let mut child_path = path.to_vec();
+
+                child_path.push(field_layout.name.clone());
+
+                self.nodes
+                    .contains_key(&child_path)
+                    .then(|| StructDissectionPointer::new(&field_layout.name, self.to_struct_dissection_node(&child_path)))
+            })
+            .collect();
+
+        StructDissectionNode::new(node.custom_struct.get_definition().clone(), followed_pointers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StructDissectionNodeState, StructDissectionTree};
+    use squalr_engine_api::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+    use squalr_engine_api::structures::data_types::custom_types::data_type_custom_struct::DataTypeCustomStruct;
+    use std::sync::Arc;
+
+    fn create_custom_struct(definition: &str) -> Arc<DataTypeCustomStruct> {
+        Arc::new(DataTypeCustomStruct::new(definition.parse::<CustomDataTypeDefinition>().unwrap()).unwrap())
+    }
+
+    fn read_node(
+        bytes: Vec<u8>,
+        struct_dissection_tree: &mut StructDissectionTree,
+    ) {
+        let pending_reads = struct_dissection_tree.take_pending_reads();
+
+        assert_eq!(pending_reads.len(), 1);
+        struct_dissection_tree.apply_read(&pending_reads[0], Some(bytes));
+    }
+
+    #[test]
+    fn pointers_are_followed_lazily_and_cycles_are_not_read() {
+        let node = create_custom_struct("Node = u32 value, u64 next");
+        let mut struct_dissection_tree = StructDissectionTree::new(node.clone(), 0x1000, "");
+        let next_path = vec!["next".to_string()];
+
+        // The root points at 0x2000, which points back at the root.
+        read_node([vec![1, 0, 0, 0], 0x2000u64.to_le_bytes().to_vec()].concat(), &mut struct_dissection_tree);
+        struct_dissection_tree.follow_pointer(&[], "next", node.clone());
+
+        let pending_reads = struct_dissection_tree.take_pending_reads();
+
+        assert_eq!(pending_reads.len(), 1);
+        assert_eq!(pending_reads[0].address, 0x2000);
+
+        // Collapsed children are not read, even once refreshed.
+        struct_dissection_tree.toggle_expanded(&next_path);
+        struct_dissection_tree.apply_read(&pending_reads[0], Some([vec![2, 0, 0, 0], 0x1000u64.to_le_bytes().to_vec()].concat()));
+        struct_dissection_tree.refresh();
+        read_node([vec![1, 0, 0, 0], 0x2000u64.to_le_bytes().to_vec()].concat(), &mut struct_dissection_tree);
+
+        assert!(struct_dissection_tree.take_pending_reads().is_empty());
+
+        struct_dissection_tree.toggle_expanded(&next_path);
+        read_node([vec![2, 0, 0, 0], 0x1000u64.to_le_bytes().to_vec()].concat(), &mut struct_dissection_tree);
+        struct_dissection_tree.follow_pointer(&next_path, "next", node.clone());
+
+        assert!(struct_dissection_tree.take_pending_reads().is_empty());
+        assert_eq!(
+            struct_dissection_tree
+                .get_node(&["next".to_string(), "next".to_string()])
+                .map(|node| node.state),
+            Some(StructDissectionNodeState::Cycle)
+        );
+
+        // Saved dissections keep which pointers are followed.
+        let struct_dissection = struct_dissection_tree.to_struct_dissection("Nodes");
+        let loaded_tree = StructDissectionTree::from_struct_dissection(&struct_dissection).unwrap();
+
+        assert_eq!(loaded_tree.to_struct_dissection("Nodes"), struct_dissection);
+        assert_eq!(loaded_tree.get_root().address, 0x1000);
+    }
+}
@@ -5,4 +5,15 @@ pub enum StructViewerFrameAction {
     None,
     SelectField(String),
     EditValue(ValuedStructField, DataValue),
+    AddAllFieldsToProject,
+    OpenNewBaseDialog,
+    StartDissection,
+    RefreshDissection,
+    SaveDissection,
+    LoadDissection(String),
+    CloseDissection,
+    ToggleDissectionNode(Vec<String>),
+    /// Follows the named pointer field of the struct at the given path, as the custom data type of the given id.
+    FollowDissectionPointer(Vec<String>, String, String),
+    UnfollowDissectionPointer(Vec<String>),
 }
@@ -1,3 +1,20 @@
+use crate::models::address_resolution::address_resolver::{AddressResolution, AddressResolver};
+use crate::views::struct_viewer::view_data::focused_struct_address::FocusedStructAddress;
+use crate::views::struct_viewer::view_data::struct_dissection_tree::StructDissectionTree;
+use squalr_engine_api::commands::data_types::register::data_types_register_request::DataTypesRegisterRequest;
+use squalr_engine_api::commands::memory::read::memory_read_request::MemoryReadRequest;
+use squalr_engine_api::commands::privileged_command_request::PrivilegedCommandRequest;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::registries::symbols::custom_data_type_registry::CustomDataTypeRegistry;
+use squalr_engine_api::structures::data_types::built_in_types::u8::data_type_u8::DataTypeU8;
+use squalr_engine_api::structures::data_types::custom_types::data_type_custom_struct::DataTypeCustomStruct;
+use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+use squalr_engine_api::structures::data_values::container_type::ContainerType;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::projects::project_items::project_item::ProjectItem;
+use squalr_engine_api::structures::projects::struct_dissections::struct_dissection::StructDissection;
+use squalr_engine_api::structures::structs::symbolic_field_definition::SymbolicFieldDefinition;
+use squalr_engine_api::structures::structs::symbolic_struct_definition::SymbolicStructDefinition;
 use squalr_engine_api::{
     dependency_injection::dependency::Dependency,
     structures::structs::{valued_struct::ValuedStruct, valued_struct_field::ValuedStructField},
@@ -10,6 +27,21 @@ pub struct StructViewerViewData {
     pub struct_field_modified_callback: Arc<Option<Box<dyn FnOnce(ValuedStructField) + Send + Sync>>>,
     pub selected_field_name: Arc<Option<String>>,
     pub value_splitter_ratio: f32,
+    /// Where the struct under view lives in memory, if it was focused from an address.
+    pub focused_struct_address: Option<FocusedStructAddress>,
+    pub is_new_base_dialog_open: bool,
+    /// The base address typed into the re-add dialog, ie `game.exe+1A0` or an absolute hexadecimal address.
+    pub new_base_address: String,
+    /// The outcome of resolving the base address, shown in the re-add dialog.
+    pub new_base_resolution: Option<AddressResolution>,
+    /// The outcome of the last bulk add, shown until the user dismisses it.
+    pub add_fields_summary: Option<String>,
+    /// The struct being dissected, along with the structs reached from its pointer fields, if dissect data mode is open.
+    pub struct_dissection_tree: Option<StructDissectionTree>,
+    /// The name that the dissection is saved to the project under.
+    pub struct_dissection_name: String,
+    /// The outcome of the last save or load of a dissection.
+    pub struct_dissection_status: Option<String>,
 }
 
 impl StructViewerViewData {
@@ -21,6 +53,14 @@ impl StructViewerViewData {
             struct_field_modified_callback: Arc::new(None),
             selected_field_name: Arc::new(None),
             value_splitter_ratio: Self::DEFAULT_NAME_SPLITTER_RATIO,
+            focused_struct_address: None,
+            is_new_base_dialog_open: false,
+            new_base_address: String::new(),
+            new_base_resolution: None,
+            add_fields_summary: None,
+            struct_dissection_tree: None,
+            struct_dissection_name: String::new(),
+            struct_dissection_status: None,
         }
     }
 
@@ -57,6 +97,21 @@ impl StructViewerViewData {
         struct_viewer_view_data.set_valued_struct_and_callback(Some(valued_struct), Some(valued_struct_field_edited_callback));
     }
 
+    /// Focuses a struct that lives at a known address, such that its fields can be added to the project.
+    pub fn focus_valued_struct_at_address(
+        struct_viewer_view_data: Dependency<Self>,
+        valued_struct: ValuedStruct,
+        focused_struct_address: FocusedStructAddress,
+        valued_struct_field_edited_callback: Box<dyn FnOnce(ValuedStructField) + Send + Sync>,
+    ) {
+        let mut struct_viewer_view_data = match struct_viewer_view_data.try_write("Focus valued struct at address") {
+            Some(struct_viewer_view_data) => struct_viewer_view_data,
+            None => return,
+        };
+        struct_viewer_view_data.set_valued_struct_and_callback(Some(valued_struct), Some(valued_struct_field_edited_callback));
+        struct_viewer_view_data.focused_struct_address = Some(focused_struct_address);
+    }
+
     pub fn focus_valued_structs(
         struct_viewer_view_data: Dependency<Self>,
         valued_structs: Vec<ValuedStruct>,
@@ -86,5 +141,417 @@ impl StructViewerViewData {
     ) {
         self.struct_under_view = Arc::new(valued_struct);
         self.struct_field_modified_callback = Arc::new(valued_struct_field_edited_callback);
+        self.focused_struct_address = None;
+    }
+
+    /// Adds every field of the focused struct to the opened project, grouped under the name of the struct.
+    pub fn add_all_fields_to_project(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let focused_struct_address = match struct_viewer_view_data.read("Struct viewer add all fields") {
+            Some(struct_viewer_view_data) => struct_viewer_view_data.focused_struct_address.clone(),
+            None => return,
+        };
+
+        if let Some(focused_struct_address) = focused_struct_address {
+            Self::add_fields_at_address(struct_viewer_view_data, engine_unprivileged_state, &focused_struct_address);
+        }
+    }
+
+    pub fn open_new_base_dialog(struct_viewer_view_data: Dependency<Self>) {
+        if let Some(mut struct_viewer_view_data) = struct_viewer_view_data.write("Struct viewer open new base dialog") {
+            let Some(display_address) = struct_viewer_view_data
+                .focused_struct_address
+                .as_ref()
+                .map(|focused_struct_address| focused_struct_address.get_display_address())
+            else {
+                return;
+            };
+
+            struct_viewer_view_data.new_base_address = display_address;
+            struct_viewer_view_data.new_base_resolution = None;
+            struct_viewer_view_data.is_new_base_dialog_open = true;
+        }
+    }
+
+    pub fn close_new_base_dialog(struct_viewer_view_data: Dependency<Self>) {
+        if let Some(mut struct_viewer_view_data) = struct_viewer_view_data.write("Struct viewer close new base dialog") {
+            struct_viewer_view_data.is_new_base_dialog_open = false;
+        }
+    }
+
+    pub fn close_add_fields_summary(struct_viewer_view_data: Dependency<Self>) {
+        if let Some(mut struct_viewer_view_data) = struct_viewer_view_data.write("Struct viewer close add fields summary") {
+            struct_viewer_view_data.add_fields_summary = None;
+        }
+    }
+
+    /// Adds every field of the focused struct again, laid out from the base address typed into the re-add dialog. This covers
+    /// a second instance of the same struct elsewhere in memory.
+    pub fn add_all_fields_at_new_base(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let (data_type_ref, new_base_address) = {
+            let mut view_data = match struct_viewer_view_data.write("Struct viewer add all fields at new base") {
+                Some(view_data) => view_data,
+                None => return,
+            };
+            let Some(focused_struct_address) = view_data.focused_struct_address.clone() else {
+                view_data.is_new_base_dialog_open = false;
+                return;
+            };
+
+            (focused_struct_address.data_type_ref, view_data.new_base_address.trim().to_string())
+        };
+        let engine_unprivileged_state_clone = engine_unprivileged_state.clone();
+
+        AddressResolver::resolve(&engine_unprivileged_state, &new_base_address, move |address_resolution| {
+            // Addresses within a module are kept relative to it, such that the added fields still apply once the module moves.
+            let focused_struct_address = address_resolution
+                .as_ref()
+                .ok()
+                .map(|resolved_address| match resolved_address.module_name.is_empty() {
+                    true => FocusedStructAddress::new(data_type_ref.clone(), resolved_address.address, ""),
+                    false => FocusedStructAddress::new(data_type_ref.clone(), resolved_address.module_offset, &resolved_address.module_name),
+                });
+
+            if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer new base resolved") {
+                view_data.new_base_resolution = Some(address_resolution);
+
+                if focused_struct_address.is_some() {
+                    view_data.is_new_base_dialog_open = false;
+                }
+            }
+
+            if let Some(focused_struct_address) = focused_struct_address {
+                Self::add_fields_at_address(struct_viewer_view_data, engine_unprivileged_state_clone, &focused_struct_address);
+            }
+        });
+    }
+
+    /// Opens dissect data mode on the focused struct, such that its pointer fields can be followed into the structs they point to.
+    pub fn start_struct_dissection(struct_viewer_view_data: Dependency<Self>) {
+        let mut view_data = match struct_viewer_view_data.write("Struct viewer start struct dissection") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let Some(focused_struct_address) = view_data.focused_struct_address.clone() else {
+            return;
+        };
+        let data_type_id = focused_struct_address.data_type_ref.get_data_type_id();
+
+        match CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_id) {
+            Some(custom_struct) => {
+                view_data.struct_dissection_name = custom_struct.get_definition().get_name().to_string();
+                view_data.struct_dissection_tree = Some(StructDissectionTree::new(
+                    custom_struct,
+                    focused_struct_address.address,
+                    &focused_struct_address.module,
+                ));
+                view_data.struct_dissection_status = None;
+            }
+            None => {
+                view_data.struct_dissection_status = Some(format!("Unable to dissect: the data type '{}' is no longer registered.", data_type_id));
+            }
+        }
+    }
+
+    pub fn close_struct_dissection(struct_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer close struct dissection") {
+            view_data.struct_dissection_tree = None;
+            view_data.struct_dissection_status = None;
+        }
+    }
+
+    /// Reads every shown struct of the dissection again, parents before the structs their pointers lead to.
+    pub fn refresh_struct_dissection(struct_viewer_view_data: Dependency<Self>) {
+        if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer refresh struct dissection")
+            && let Some(struct_dissection_tree) = view_data.struct_dissection_tree.as_mut()
+        {
+            struct_dissection_tree.refresh();
+        }
+    }
+
+    pub fn toggle_struct_dissection_node(
+        struct_viewer_view_data: Dependency<Self>,
+        path: Vec<String>,
+    ) {
+        if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer toggle struct dissection node")
+            && let Some(struct_dissection_tree) = view_data.struct_dissection_tree.as_mut()
+        {
+            struct_dissection_tree.toggle_expanded(&path);
+        }
+    }
+
+    /// Follows a pointer field of a dissected struct, showing the memory it points to as the given custom struct.
+    pub fn follow_struct_dissection_pointer(
+        struct_viewer_view_data: Dependency<Self>,
+        path: Vec<String>,
+        field_name: String,
+        data_type_id: String,
+    ) {
+        let Some(custom_struct) = CustomDataTypeRegistry::get_instance().get_custom_data_type(&data_type_id) else {
+            return;
+        };
+
+        if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer follow struct dissection pointer")
+            && let Some(struct_dissection_tree) = view_data.struct_dissection_tree.as_mut()
+        {
+            struct_dissection_tree.follow_pointer(&path, &field_name, custom_struct);
+        }
+    }
+
+    pub fn unfollow_struct_dissection_pointer(
+        struct_viewer_view_data: Dependency<Self>,
+        path: Vec<String>,
+    ) {
+        if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer unfollow struct dissection pointer")
+            && let Some(struct_dissection_tree) = view_data.struct_dissection_tree.as_mut()
+        {
+            struct_dissection_tree.unfollow_pointer(&path);
+        }
+    }
+
+    /// Sends a read for each shown struct of the dissection that has not been read since the last refresh. This is called every
+    /// frame, but each struct is only read once per refresh, and only once the struct holding its pointer has been read.
+    pub fn read_struct_dissection(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let pending_reads = match struct_viewer_view_data.try_write("Struct viewer read struct dissection") {
+            Some(mut view_data) => match view_data.struct_dissection_tree.as_mut() {
+                Some(struct_dissection_tree) => struct_dissection_tree.take_pending_reads(),
+                None => return,
+            },
+            None => return,
+        };
+
+        for pending_read in pending_reads {
+            let memory_read_request = MemoryReadRequest {
+                address: pending_read.address,
+                module_name: pending_read.module_name.clone(),
+                symbolic_struct_definition: SymbolicStructDefinition::new_anonymous(vec![SymbolicFieldDefinition::new(
+                    DataTypeRef::new(DataTypeU8::get_data_type_id()),
+                    ContainerType::ArrayFixed(pending_read.size_in_bytes),
+                )]),
+            };
+            let struct_viewer_view_data = struct_viewer_view_data.clone();
+
+            memory_read_request.send(&engine_unprivileged_state, move |memory_read_response| {
+                let bytes = memory_read_response
+                    .success
+                    .then(|| memory_read_response.valued_struct.get_bytes());
+
+                if let Some(mut view_data) = struct_viewer_view_data.write("Struct viewer struct dissection read response")
+                    && let Some(struct_dissection_tree) = view_data.struct_dissection_tree.as_mut()
+                {
+                    struct_dissection_tree.apply_read(&pending_read, bytes);
+                }
+            });
+        }
+    }
+
+    /// Saves the dissection to the opened project, replacing any dissection of the same name.
+    pub fn save_struct_dissection(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+    ) {
+        let mut view_data = match struct_viewer_view_data.write("Struct viewer save struct dissection") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let name = view_data.struct_dissection_name.trim().to_string();
+        let struct_dissection = match view_data.struct_dissection_tree.as_ref() {
+            Some(struct_dissection_tree) => struct_dissection_tree.to_struct_dissection(&name),
+            None => return,
+        };
+
+        if name.is_empty() {
+            view_data.struct_dissection_status = Some("Unable to save: the dissection needs a name.".to_string());
+            return;
+        }
+
+        view_data.struct_dissection_status = Some(
+            match engine_unprivileged_state
+                .get_project_manager()
+                .set_struct_dissection(struct_dissection)
+            {
+                Ok(()) => format!("Saved '{}' to the project.", name),
+                Err(error) => format!("Unable to save: {}", error),
+            },
+        );
+    }
+
+    /// Opens a dissection saved to the opened project. Any of its structs that are not registered as custom data types are
+    /// registered, such that they can be chosen when following other pointers.
+    pub fn load_struct_dissection(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        name: &str,
+    ) {
+        let struct_dissection = Self::get_project_struct_dissections(&engine_unprivileged_state)
+            .into_iter()
+            .find(|struct_dissection| struct_dissection.get_name() == name);
+        let mut view_data = match struct_viewer_view_data.write("Struct viewer load struct dissection") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+        let Some(struct_dissection) = struct_dissection else {
+            view_data.struct_dissection_status = Some(format!("Unable to load: the project has no dissection named '{}'.", name));
+            return;
+        };
+        let custom_data_type_registry = CustomDataTypeRegistry::get_instance();
+        let unregistered_definitions: Vec<_> = struct_dissection
+            .get_definitions()
+            .into_iter()
+            .filter(|definition| {
+                custom_data_type_registry
+                    .get_custom_data_type(&definition.get_data_type_id())
+                    .is_none()
+            })
+            .collect();
+
+        if !unregistered_definitions.is_empty() {
+            let data_types_register_request = DataTypesRegisterRequest {
+                definitions: unregistered_definitions,
+            };
+            let dissection_name = name.to_string();
+
+            data_types_register_request.send(&engine_unprivileged_state, move |data_types_register_response| {
+                for error_message in &data_types_register_response.error_messages {
+                    log::warn!("Unable to register a data type of the dissection '{}': {}", dissection_name, error_message);
+                }
+
+                CustomDataTypeRegistry::get_instance().mirror_definitions(data_types_register_response.definitions);
+            });
+        }
+
+        match StructDissectionTree::from_struct_dissection(&struct_dissection) {
+            Ok(struct_dissection_tree) => {
+                view_data.struct_dissection_tree = Some(struct_dissection_tree);
+                view_data.struct_dissection_name = name.to_string();
+                view_data.struct_dissection_status = None;
+            }
+            Err(error) => view_data.struct_dissection_status = Some(format!("Unable to load: {}", error)),
+        }
+    }
+
+    /// Gets the dissections saved to the opened project, in the order they were created.
+    pub fn get_project_struct_dissections(engine_unprivileged_state: &EngineUnprivilegedState) -> Vec<StructDissection> {
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let opened_project = match opened_project.read() {
+            Ok(opened_project) => opened_project,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return vec![];
+            }
+        };
+
+        opened_project
+            .as_ref()
+            .map(|project| project.get_project_manifest().get_struct_dissections().clone())
+            .unwrap_or_default()
+    }
+
+    /// Creates one address entry per field of the struct, skipping padding. Entries are named after their field, and described as
+    /// the struct name followed by the field name.
+    pub fn create_field_project_items(
+        custom_struct: &DataTypeCustomStruct,
+        address: u64,
+        module: &str,
+    ) -> Vec<ProjectItem> {
+        let struct_name = custom_struct.get_definition().get_name();
+
+        custom_struct
+            .get_field_layouts()
+            .iter()
+            .filter(|field_layout| !field_layout.is_padding)
+            .map(|field_layout| {
+                let data_type = &field_layout.data_type;
+                let freeze_value = data_type.get_default_value(data_type.get_ref());
+
+                ProjectItemTypeAddress::new_project_item(
+                    &field_layout.name,
+                    address.wrapping_add(field_layout.offset),
+                    module,
+                    &format!("{}.{}", struct_name, field_layout.name),
+                    freeze_value,
+                )
+            })
+            .collect()
+    }
+
+    fn add_fields_at_address(
+        struct_viewer_view_data: Dependency<Self>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        focused_struct_address: &FocusedStructAddress,
+    ) {
+        let data_type_id = focused_struct_address.data_type_ref.get_data_type_id();
+        let add_fields_summary = match CustomDataTypeRegistry::get_instance().get_custom_data_type(data_type_id) {
+            Some(custom_struct) => {
+                let struct_name = custom_struct.get_definition().get_name();
+                let project_items = Self::create_field_project_items(&custom_struct, focused_struct_address.address, &focused_struct_address.module);
+
+                match engine_unprivileged_state
+                    .get_project_manager()
+                    .add_project_items_to_new_group(struct_name, project_items)
+                {
+                    Ok(summary) => format!(
+                        "Added {} field(s) of {} at {}. Skipped {} already in the project.",
+                        summary.added_count,
+                        struct_name,
+                        focused_struct_address.get_display_address(),
+                        summary.skipped_count
+                    ),
+                    Err(error) => format!("Unable to add fields: {}", error),
+                }
+            }
+            None => format!("Unable to add fields: the data type '{}' is no longer registered.", data_type_id),
+        };
+
+        if let Some(mut struct_viewer_view_data) = struct_viewer_view_data.write("Struct viewer add fields summary") {
+            struct_viewer_view_data.add_fields_summary = Some(add_fields_summary);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StructViewerViewData;
+    use squalr_engine_api::structures::data_types::custom_types::custom_data_type_definition::CustomDataTypeDefinition;
+    use squalr_engine_api::structures::data_types::custom_types::data_type_custom_struct::DataTypeCustomStruct;
+    use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+
+    #[test]
+    fn field_entries_skip_padding_and_are_offset_from_the_base() {
+        let definition = "Entity header = u32 id, u16 _unused, u16 flags, f32 hp"
+            .parse::<CustomDataTypeDefinition>()
+            .unwrap();
+        let custom_struct = DataTypeCustomStruct::new(definition).unwrap();
+        let project_items = StructViewerViewData::create_field_project_items(&custom_struct, 0x100, "game.exe");
+        let entries = project_items
+            .iter()
+            .map(|project_item| {
+                (
+                    project_item.get_field_name(),
+                    ProjectItemTypeAddress::get_field_address(project_item),
+                    project_item.get_field_description(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            entries,
+            vec![
+                ("id".to_string(), 0x100, "Entity header.id".to_string()),
+                ("flags".to_string(), 0x106, "Entity header.flags".to_string()),
+                ("hp".to_string(), 0x108, "Entity header.hp".to_string()),
+            ]
+        );
+        assert_eq!(ProjectItemTypeAddress::get_field_module(&project_items[0]), "game.exe");
     }
 }
@@ -0,0 +1,2 @@
+pub mod view_data;
+pub mod watch_expressions_view;
@@ -0,0 +1 @@
+pub mod watch_expressions_view_data;
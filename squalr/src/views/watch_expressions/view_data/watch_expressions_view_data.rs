@@ -0,0 +1,366 @@
+use crate::models::refresh_scheduler::refresh_batch::RefreshBatch;
+use crate::models::refresh_scheduler::refresh_job_handle::RefreshJobHandle;
+use crate::models::refresh_scheduler::refresh_scheduler::RefreshScheduler;
+use crate::views::element_scanner::results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData;
+use squalr_engine_api::conversions::conversions_from_primitives::Conversions;
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use squalr_engine_api::engine::engine_unprivileged_state::EngineUnprivilegedState;
+use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+use squalr_engine_api::structures::expressions::arithmetic_expression::ArithmeticExpression;
+use squalr_engine_api::structures::expressions::expression_error::ExpressionError;
+use squalr_engine_api::structures::projects::project_items::built_in_types::project_item_type_address::ProjectItemTypeAddress;
+use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A named address taken from the opened project, which expressions may reference by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchedProjectAddress {
+    pub name: String,
+    pub module: String,
+    pub address: u64,
+}
+
+#[derive(Clone)]
+pub struct WatchExpression {
+    pub name: String,
+    pub expression_string: String,
+    pub parsed_expression: Result<ArithmeticExpression, ExpressionError>,
+    pub latest_result: Option<Result<f64, ExpressionError>>,
+    pub is_graphed: bool,
+    pub history: VecDeque<f64>,
+}
+
+impl WatchExpression {
+    pub fn new(
+        name: &str,
+        expression_string: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            expression_string: expression_string.to_string(),
+            parsed_expression: ArithmeticExpression::parse(expression_string),
+            latest_result: None,
+            is_graphed: false,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Re-parses the expression string after an edit, discarding results computed from the old expression.
+    pub fn reparse(&mut self) {
+        self.parsed_expression = ArithmeticExpression::parse(&self.expression_string);
+        self.latest_result = None;
+        self.history.clear();
+    }
+}
+
+#[derive(Clone)]
+pub struct WatchExpressionsViewData {
+    pub watch_expressions: Vec<WatchExpression>,
+    pub new_expression_name: String,
+    pub new_expression_string: String,
+    /// Keeps the job sampling the expressions registered for as long as this view data lives.
+    pub sampling_job_handle: Option<RefreshJobHandle>,
+}
+
+impl WatchExpressionsViewData {
+    pub const MAX_HISTORY_SAMPLES: usize = 120;
+    /// How often expressions are sampled by default, such that the graphs span 12 seconds of history.
+    const SAMPLING_INTERVAL: Duration = Duration::from_millis(100);
+
+    pub fn new() -> Self {
+        Self {
+            watch_expressions: Vec::new(),
+            new_expression_name: String::new(),
+            new_expression_string: String::new(),
+            sampling_job_handle: None,
+        }
+    }
+
+    pub fn add_expression(watch_expressions_view_data: Dependency<Self>) {
+        let mut view_data = match watch_expressions_view_data.write("Watch expressions add expression") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+
+        if view_data.new_expression_string.trim().is_empty() {
+            return;
+        }
+
+        let name = match view_data.new_expression_name.trim() {
+            "" => format!("Expression {}", view_data.watch_expressions.len() + 1),
+            name => name.to_string(),
+        };
+        let watch_expression = WatchExpression::new(&name, view_data.new_expression_string.trim());
+
+        view_data.watch_expressions.push(watch_expression);
+        view_data.new_expression_name.clear();
+        view_data.new_expression_string.clear();
+    }
+
+    pub fn remove_expression(
+        watch_expressions_view_data: Dependency<Self>,
+        index: usize,
+    ) {
+        let mut view_data = match watch_expressions_view_data.write("Watch expressions remove expression") {
+            Some(view_data) => view_data,
+            None => return,
+        };
+
+        if index < view_data.watch_expressions.len() {
+            view_data.watch_expressions.remove(index);
+        }
+    }
+
+    /// Registers the job that samples every expression on its own cadence. Each sample reads the current values of the scan results
+    /// that the expressions reference, then evaluates the expressions against them.
+    pub fn register_sampling_job(
+        watch_expressions_view_data: Dependency<Self>,
+        element_scanner_results_view_data: Dependency<ElementScannerResultsViewData>,
+        engine_unprivileged_state: Arc<EngineUnprivilegedState>,
+        refresh_scheduler: &RefreshScheduler,
+    ) {
+        if watch_expressions_view_data
+            .read("Watch expressions sampling job check")
+            .is_none_or(|view_data| view_data.sampling_job_handle.is_some())
+        {
+            return;
+        }
+
+        let watch_expressions_view_data_clone = watch_expressions_view_data.clone();
+        let sampling_job_handle = refresh_scheduler.register_job("Watch expression graphs", Self::SAMPLING_INTERVAL, true, move |refresh_batch| {
+            Self::sample_expressions(
+                watch_expressions_view_data_clone.clone(),
+                element_scanner_results_view_data.clone(),
+                &engine_unprivileged_state,
+                refresh_batch,
+            );
+        });
+
+        if let Some(mut view_data) = watch_expressions_view_data.write("Watch expressions register sampling job") {
+            view_data.sampling_job_handle = Some(sampling_job_handle);
+        }
+    }
+
+    /// Queues reading the scan results referenced by the expressions, evaluating the expressions once the values arrive. References
+    /// resolve against the current scan results page, so only the few results an expression names are read, rather than the page.
+    fn sample_expressions(
+        watch_expressions_view_data: Dependency<Self>,
+        element_scanner_results_view_data: Dependency<ElementScannerResultsViewData>,
+        engine_unprivileged_state: &Arc<EngineUnprivilegedState>,
+        refresh_batch: &mut RefreshBatch,
+    ) {
+        let references: Vec<String> = match watch_expressions_view_data.read("Watch expressions collect references") {
+            Some(view_data) if !view_data.watch_expressions.is_empty() => view_data
+                .watch_expressions
+                .iter()
+                .filter_map(|watch_expression| watch_expression.parsed_expression.as_ref().ok())
+                .flat_map(|expression| expression.get_references())
+                .map(str::to_string)
+                .collect(),
+            _ => return,
+        };
+
+        let scan_results = match element_scanner_results_view_data.read("Watch expressions read scan results") {
+            Some(results_view_data) => results_view_data.current_scan_results.clone(),
+            None => return,
+        };
+        let project_addresses = Self::collect_project_addresses(engine_unprivileged_state);
+        let mut scan_result_refs: Vec<ScanResultRef> = Vec::new();
+
+        for reference in &references {
+            if let Some(scan_result) = Self::find_scan_result(reference, &scan_results, &project_addresses) {
+                let scan_result_ref = scan_result.get_base_result().get_scan_result_ref();
+
+                if !scan_result_refs
+                    .iter()
+                    .any(|existing_ref| existing_ref.get_scan_result_global_index() == scan_result_ref.get_scan_result_global_index())
+                {
+                    scan_result_refs.push(scan_result_ref.clone());
+                }
+            }
+        }
+
+        // Nothing to read, ie every reference is unresolved or there are none, so the expressions are evaluated as they are.
+        if scan_result_refs.is_empty() {
+            if let Some(mut view_data) = watch_expressions_view_data.write("Watch expressions evaluate") {
+                Self::evaluate_expressions(&mut view_data.watch_expressions, &scan_results, &project_addresses);
+            }
+
+            return;
+        }
+
+        refresh_batch.refresh_scan_results(scan_result_refs, move |refreshed_scan_results, _scan_generation| {
+            if let Some(mut view_data) = watch_expressions_view_data.write("Watch expressions evaluate") {
+                Self::evaluate_expressions(&mut view_data.watch_expressions, &refreshed_scan_results, &project_addresses);
+            }
+        });
+    }
+
+    /// Evaluates each expression independently, such that one failing expression does not affect the others.
+    pub fn evaluate_expressions(
+        watch_expressions: &mut [WatchExpression],
+        scan_results: &[ScanResult],
+        project_addresses: &[WatchedProjectAddress],
+    ) {
+        let resolve_reference = |name: &str| Self::find_scan_result(name, scan_results, project_addresses).and_then(Self::scan_result_to_f64);
+
+        for watch_expression in watch_expressions.iter_mut() {
+            let result = match &watch_expression.parsed_expression {
+                Ok(expression) => expression.evaluate(resolve_reference),
+                Err(error) => Err(error.clone()),
+            };
+
+            if let Ok(value) = result {
+                if watch_expression.history.len() >= Self::MAX_HISTORY_SAMPLES {
+                    watch_expression.history.pop_front();
+                }
+
+                watch_expression.history.push_back(value);
+            }
+
+            watch_expression.latest_result = Some(result);
+        }
+    }
+
+    /// Resolves a reference by project entry name first, then as `module+offset`, then as an absolute hex address.
+    fn find_scan_result<'a>(
+        reference: &str,
+        scan_results: &'a [ScanResult],
+        project_addresses: &[WatchedProjectAddress],
+    ) -> Option<&'a ScanResult> {
+        let (module, address) = match project_addresses
+            .iter()
+            .find(|project_address| project_address.name.eq_ignore_ascii_case(reference))
+        {
+            Some(project_address) => (project_address.module.as_str(), project_address.address),
+            None => match reference.rsplit_once('+') {
+                Some((module, offset)) => (module.trim(), Conversions::parse_hex_address(offset).ok()?),
+                None => ("", Conversions::parse_hex_address(reference).ok()?),
+            },
+        };
+
+        scan_results.iter().find(|scan_result| {
+            if module.is_empty() {
+                scan_result.get_address() == address
+            } else {
+                scan_result.get_module().eq_ignore_ascii_case(module) && scan_result.get_module_offset() == address
+            }
+        })
+    }
+
+    fn scan_result_to_f64(scan_result: &ScanResult) -> Option<f64> {
+        scan_result
+            .get_current_display_value(AnonymousValueStringFormat::Decimal)
+            .or_else(|| scan_result.get_recently_read_display_value(AnonymousValueStringFormat::Decimal))
+            .and_then(|display_value| {
+                display_value
+                    .get_anonymous_value_string()
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+            })
+    }
+
+    fn collect_project_addresses(engine_unprivileged_state: &Arc<EngineUnprivilegedState>) -> Vec<WatchedProjectAddress> {
+        let opened_project = engine_unprivileged_state
+            .get_project_manager()
+            .get_opened_project();
+        let opened_project = match opened_project.read() {
+            Ok(opened_project) => opened_project,
+            Err(error) => {
+                log::error!("Failed to acquire opened project lock: {}", error);
+                return Vec::new();
+            }
+        };
+
+        let project = match opened_project.as_ref() {
+            Some(project) => project,
+            None => return Vec::new(),
+        };
+
+        project
+            .get_project_items()
+            .values()
+            .filter(|project_item| project_item.get_item_type().get_project_item_type_id() == ProjectItemTypeAddress::PROJECT_ITEM_TYPE_ID)
+            .map(|project_item| WatchedProjectAddress {
+                name: project_item.get_field_name(),
+                module: ProjectItemTypeAddress::get_field_module(project_item),
+                address: ProjectItemTypeAddress::get_field_address(project_item),
+            })
+            .collect()
+    }
+}
+
+impl Default for WatchExpressionsViewData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WatchExpression, WatchExpressionsViewData, WatchedProjectAddress};
+    use squalr_engine_api::structures::data_types::built_in_types::u32::data_type_u32::DataTypeU32;
+    use squalr_engine_api::structures::data_types::data_type_ref::DataTypeRef;
+    use squalr_engine_api::structures::data_values::anonymous_value_string::AnonymousValueString;
+    use squalr_engine_api::structures::data_values::anonymous_value_string_format::AnonymousValueStringFormat;
+    use squalr_engine_api::structures::expressions::expression_error::ExpressionError;
+    use squalr_engine_api::structures::scan_results::scan_result::ScanResult;
+    use squalr_engine_api::structures::scan_results::scan_result_ref::ScanResultRef;
+    use squalr_engine_api::structures::scan_results::scan_result_valued::ScanResultValued;
+
+    fn make_u32_scan_result(
+        address: u64,
+        module: &str,
+        module_offset: u64,
+        value: u32,
+    ) -> ScanResult {
+        let display_value = AnonymousValueString::new(value.to_string(), AnonymousValueStringFormat::Decimal, Default::default());
+        let valued = ScanResultValued::new(
+            address,
+            DataTypeRef::new(DataTypeU32::get_data_type_id()),
+            String::new(),
+            Some(DataTypeU32::get_value_from_primitive(value)),
+            vec![display_value],
+            None,
+            vec![],
+            ScanResultRef::new(0),
+        );
+
+        ScanResult::new(valued, module.to_string(), module_offset, None, vec![], false)
+    }
+
+    #[test]
+    fn expressions_resolve_project_names_and_addresses_with_errors_kept_per_expression() {
+        let scan_results = vec![
+            make_u32_scan_result(0x7FF6A010, "game.exe", 0x10, 25),
+            make_u32_scan_result(0x21BD0034, "", 0, 200),
+        ];
+        let project_addresses = vec![WatchedProjectAddress {
+            name: "hp".to_string(),
+            module: "game.exe".to_string(),
+            address: 0x10,
+        }];
+        let mut watch_expressions = vec![
+            WatchExpression::new("Health", "[HP] / [21BD0034] * 100"),
+            WatchExpression::new("Missing", "[mp] + 1"),
+            WatchExpression::new("Broken", "[hp] *"),
+            WatchExpression::new("Module", "[game.exe+10] - 5"),
+        ];
+
+        WatchExpressionsViewData::evaluate_expressions(&mut watch_expressions, &scan_results, &project_addresses);
+
+        assert_eq!(watch_expressions[0].latest_result, Some(Ok(12.5)));
+        assert_eq!(
+            watch_expressions[1].latest_result,
+            Some(Err(ExpressionError::UnresolvedReference { name: "mp".to_string() }))
+        );
+        assert!(matches!(watch_expressions[2].latest_result, Some(Err(ExpressionError::ParseError { .. }))));
+        assert_eq!(watch_expressions[3].latest_result, Some(Ok(20.0)));
+        assert_eq!(watch_expressions[0].history.len(), 1);
+        assert!(watch_expressions[1].history.is_empty());
+    }
+}
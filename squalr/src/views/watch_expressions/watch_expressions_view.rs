@@ -0,0 +1,235 @@
+use crate::app_context::AppContext;
+use crate::ui::draw::icon_draw::IconDraw;
+use crate::ui::theme::Theme;
+use crate::ui::toolbar_layout::ToolbarLayout;
+use crate::ui::widgets::controls::button::Button;
+use crate::ui::widgets::controls::checkbox::Checkbox;
+use crate::views::element_scanner::results::view_data::element_scanner_results_view_data::ElementScannerResultsViewData;
+use crate::views::watch_expressions::view_data::watch_expressions_view_data::{WatchExpression, WatchExpressionsViewData};
+use eframe::egui::{Align, Key, Layout, Response, ScrollArea, Sense, TextEdit, Ui, Widget};
+use epaint::{Color32, CornerRadius, Pos2, Stroke, pos2, vec2};
+use squalr_engine_api::dependency_injection::dependency::Dependency;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct WatchExpressionsView {
+    app_context: Arc<AppContext>,
+    watch_expressions_view_data: Dependency<WatchExpressionsViewData>,
+}
+
+impl WatchExpressionsView {
+    pub const WINDOW_ID: &'static str = "window_watch_expressions";
+    const GRAPH_HEIGHT: f32 = 48.0;
+
+    pub fn new(app_context: Arc<AppContext>) -> Self {
+        let watch_expressions_view_data = app_context
+            .dependency_container
+            .register(WatchExpressionsViewData::new());
+        let element_scanner_results_view_data = app_context
+            .dependency_container
+            .get_dependency::<ElementScannerResultsViewData>();
+
+        WatchExpressionsViewData::register_sampling_job(
+            watch_expressions_view_data.clone(),
+            element_scanner_results_view_data,
+            app_context.engine_unprivileged_state.clone(),
+            &app_context.refresh_scheduler,
+        );
+
+        Self {
+            app_context,
+            watch_expressions_view_data,
+        }
+    }
+
+    fn format_result(watch_expression: &WatchExpression) -> (String, bool) {
+        match &watch_expression.latest_result {
+            Some(Ok(value)) => (format!("{}", value), false),
+            Some(Err(error)) => (error.to_string(), true),
+            None => match &watch_expression.parsed_expression {
+                Err(error) => (error.to_string(), true),
+                Ok(_) => ("Waiting for refresh...".to_string(), false),
+            },
+        }
+    }
+
+    /// Draws the expression history as a line scaled to fit between its minimum and maximum values.
+    fn draw_graph(
+        user_interface: &mut Ui,
+        theme: &Theme,
+        watch_expression: &WatchExpression,
+    ) {
+        let (graph_rect, _) = user_interface.allocate_exact_size(vec2(user_interface.available_width(), Self::GRAPH_HEIGHT), Sense::hover());
+        let painter = user_interface.painter();
+
+        painter.rect_filled(graph_rect, CornerRadius::ZERO, theme.background_primary);
+
+        if watch_expression.history.len() < 2 {
+            return;
+        }
+
+        let min_value = watch_expression
+            .history
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        let max_value = watch_expression
+            .history
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let value_range = if max_value > min_value { max_value - min_value } else { 1.0 };
+        let plot_rect = graph_rect.shrink(4.0);
+        let step = plot_rect.width() / (WatchExpressionsViewData::MAX_HISTORY_SAMPLES - 1) as f32;
+        let points: Vec<Pos2> = watch_expression
+            .history
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let normalized = ((value - min_value) / value_range) as f32;
+
+                pos2(plot_rect.min.x + index as f32 * step, plot_rect.max.y - normalized * plot_rect.height())
+            })
+            .collect();
+
+        painter.line(points, Stroke::new(1.5, theme.binary_blue));
+    }
+}
+
+impl Widget for WatchExpressionsView {
+    fn ui(
+        self,
+        user_interface: &mut Ui,
+    ) -> Response {
+        let theme = &self.app_context.theme;
+        let toolbar_layout = ToolbarLayout::new(user_interface, &theme.font_library.font_noto_sans.font_normal);
+        let item_height = toolbar_layout.get_item_height();
+        let mut should_add_expression = false;
+        let mut remove_index: Option<usize> = None;
+
+        let response = user_interface
+            .allocate_ui_with_layout(user_interface.available_size(), Layout::top_down(Align::Min), |user_interface| {
+                let mut watch_expressions_view_data = match self.watch_expressions_view_data.write("Watch expressions view") {
+                    Some(view_data) => view_data,
+                    None => return,
+                };
+
+                user_interface.add_space(ToolbarLayout::PADDING);
+                user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                    user_interface.add_sized(
+                        vec2(120.0, item_height),
+                        TextEdit::singleline(&mut watch_expressions_view_data.new_expression_name)
+                            .hint_text("Name")
+                            .font(theme.font_library.font_noto_sans.font_normal.clone())
+                            .text_color(theme.foreground)
+                            .background_color(theme.background_primary),
+                    );
+
+                    let expression_edit = user_interface.add_sized(
+                        vec2((user_interface.available_width() - item_height - 8.0).max(64.0), item_height),
+                        TextEdit::singleline(&mut watch_expressions_view_data.new_expression_string)
+                            .hint_text("[hp] / [hp_max] * 100")
+                            .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                            .text_color(theme.foreground)
+                            .background_color(theme.background_primary),
+                    );
+
+                    if expression_edit.lost_focus() && user_interface.input(|input| input.key_pressed(Key::Enter)) {
+                        should_add_expression = true;
+                    }
+
+                    let add_button = user_interface.add_sized(
+                        vec2(item_height, item_height),
+                        Button::new_from_theme(theme)
+                            .background_color(Color32::TRANSPARENT)
+                            .with_tooltip_text("Add expression"),
+                    );
+                    IconDraw::draw(user_interface, add_button.rect, &theme.icon_library.icon_handle_common_add);
+
+                    if add_button.clicked() {
+                        should_add_expression = true;
+                    }
+                });
+                user_interface.add_space(ToolbarLayout::PADDING);
+
+                ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(user_interface, |user_interface| {
+                        if watch_expressions_view_data.watch_expressions.is_empty() {
+                            user_interface.label("Reference project entries by name, or addresses as [7FF6A000] or [module+offset].");
+                            user_interface.label("Values are sampled from the element scanner results page.");
+                            return;
+                        }
+
+                        for (index, watch_expression) in watch_expressions_view_data
+                            .watch_expressions
+                            .iter_mut()
+                            .enumerate()
+                        {
+                            let (result_text, is_error) = Self::format_result(watch_expression);
+
+                            user_interface.with_layout(Layout::left_to_right(Align::Center), |user_interface| {
+                                user_interface.add_sized(vec2(120.0, item_height), eframe::egui::Label::new(&watch_expression.name).truncate());
+
+                                let expression_edit = user_interface.add_sized(
+                                    vec2(240.0, item_height),
+                                    TextEdit::singleline(&mut watch_expression.expression_string)
+                                        .font(theme.font_library.font_ubuntu_mono_bold.font_normal.clone())
+                                        .text_color(theme.foreground)
+                                        .background_color(theme.background_primary),
+                                );
+
+                                if expression_edit.changed() {
+                                    watch_expression.reparse();
+                                }
+
+                                if user_interface
+                                    .add(Checkbox::new_from_theme(theme).with_check_state_bool(watch_expression.is_graphed))
+                                    .clicked()
+                                {
+                                    watch_expression.is_graphed = !watch_expression.is_graphed;
+                                }
+
+                                user_interface.label("Graph");
+
+                                let remove_button = user_interface.add_sized(
+                                    vec2(item_height, item_height),
+                                    Button::new_from_theme(theme)
+                                        .background_color(Color32::TRANSPARENT)
+                                        .with_tooltip_text("Remove expression"),
+                                );
+                                IconDraw::draw(user_interface, remove_button.rect, &theme.icon_library.icon_handle_common_delete);
+
+                                if remove_button.clicked() {
+                                    remove_index = Some(index);
+                                }
+
+                                user_interface.colored_label(if is_error { theme.error_red } else { theme.hexadecimal_green }, result_text);
+                            });
+
+                            if watch_expression.is_graphed {
+                                Self::draw_graph(user_interface, theme, watch_expression);
+                            }
+
+                            let separator_y = user_interface.cursor().min.y + 1.0;
+
+                            user_interface
+                                .painter()
+                                .hline(user_interface.min_rect().x_range(), separator_y, Stroke::new(1.0, theme.submenu_border));
+                            user_interface.add_space(2.0);
+                        }
+                    });
+            })
+            .response;
+
+        if should_add_expression {
+            WatchExpressionsViewData::add_expression(self.watch_expressions_view_data.clone());
+        }
+
+        if let Some(remove_index) = remove_index {
+            WatchExpressionsViewData::remove_expression(self.watch_expressions_view_data.clone(), remove_index);
+        }
+
+        response
+    }
+}